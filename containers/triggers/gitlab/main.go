@@ -0,0 +1,342 @@
+// Trigger gitlab starts pipeline runs from GitLab webhooks and can report the status of those runs back to the
+// commits that started them.
+//
+// Webhooks reach the trigger through Gofer's external events service at /events/<trigger kind>.
+package main
+
+import (
+	"bufio"
+	"context"
+	"fmt"
+	"os"
+	"strings"
+	"sync"
+
+	"github.com/clintjedwards/gofer/sdk"
+	sdkProto "github.com/clintjedwards/gofer/sdk/proto"
+	"github.com/rs/zerolog/log"
+)
+
+const documentation = "https://clintjedwards.com/gofer/docs/triggers/gitlab/overview"
+
+// Pipeline configuration parameters.
+const (
+	parameterProject             = "project"
+	parameterEvents              = "events"
+	parameterMergeRequestActions = "merge_request_actions"
+	parameterReportStatus        = "report_status"
+)
+
+// Trigger configuration parameters.
+const (
+	configWebhookSecret = "webhook_secret"
+	configAPIURL        = "api_url"
+	configAPIToken      = "api_token"
+	configGoferToken    = "gofer_token"
+)
+
+const defaultAPIURL = "https://gitlab.com/api/v4"
+
+// The events a pipeline can subscribe to. They're named after the "object_kind" of their webhook payloads.
+const (
+	eventPush         = "push"
+	eventTagPush      = "tag_push"
+	eventMergeRequest = "merge_request"
+)
+
+var supportedEvents = map[string]struct{}{
+	eventPush:         {},
+	eventTagPush:      {},
+	eventMergeRequest: {},
+}
+
+// Merge requests also send webhooks for things like approvals and closing which usually shouldn't start a run.
+const defaultMergeRequestActions = "open,reopen,update"
+
+type subscriptionID struct {
+	namespace string
+	pipeline  string
+	label     string
+}
+
+type subscription struct {
+	project             string
+	events              map[string]struct{}
+	mergeRequestActions map[string]struct{}
+	reportStatus        bool
+}
+
+type trigger struct {
+	webhookSecret string
+
+	// Used to report run status back to GitLab; nil if the trigger wasn't given the tokens it needs to do so.
+	gitlab *gitlabClient
+
+	mu            sync.RWMutex
+	subscriptions map[subscriptionID]*subscription
+
+	events chan *sdkProto.CheckResponse
+
+	ctx    context.Context
+	cancel context.CancelFunc
+}
+
+func newTrigger() (*trigger, error) {
+	webhookSecret := sdk.GetConfig(configWebhookSecret)
+	if webhookSecret == "" {
+		return nil, fmt.Errorf("config %q is required", strings.ToUpper(configWebhookSecret))
+	}
+
+	apiURL := sdk.GetConfig(configAPIURL)
+	if apiURL == "" {
+		apiURL = defaultAPIURL
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+
+	newTrigger := &trigger{
+		webhookSecret: webhookSecret,
+		subscriptions: map[subscriptionID]*subscription{},
+		events:        make(chan *sdkProto.CheckResponse, 100),
+		ctx:           ctx,
+		cancel:        cancel,
+	}
+
+	apiToken := sdk.GetConfig(configAPIToken)
+	goferToken := sdk.GetConfig(configGoferToken)
+
+	switch {
+	case apiToken != "" && goferToken != "":
+		newTrigger.gitlab = newGitlabClient(apiURL, apiToken)
+		go newTrigger.watchRuns(goferToken)
+	case apiToken != "" || goferToken != "":
+		log.Warn().Msgf("both %q and %q are needed to report run status to gitlab; status reporting disabled",
+			strings.ToUpper(configAPIToken), strings.ToUpper(configGoferToken))
+	}
+
+	return newTrigger, nil
+}
+
+func (t *trigger) Check(ctx context.Context, request *sdkProto.CheckRequest) (*sdkProto.CheckResponse, error) {
+	select {
+	case <-ctx.Done():
+		return nil, nil
+	case <-t.ctx.Done():
+		return nil, nil
+	case event := <-t.events:
+		return event, nil
+	}
+}
+
+func (t *trigger) Info(ctx context.Context, request *sdkProto.InfoRequest) (*sdkProto.InfoResponse, error) {
+	return sdk.InfoResponse(documentation)
+}
+
+func (t *trigger) Subscribe(ctx context.Context, request *sdkProto.SubscribeRequest) (*sdkProto.SubscribeResponse, error) {
+	newSubscription, err := parseSubscription(request.Config)
+	if err != nil {
+		return nil, err
+	}
+
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	t.subscriptions[subscriptionID{
+		namespace: request.NamespaceId,
+		pipeline:  request.PipelineId,
+		label:     request.PipelineTriggerLabel,
+	}] = newSubscription
+
+	log.Debug().Str("namespace", request.NamespaceId).Str("pipeline", request.PipelineId).
+		Str("label", request.PipelineTriggerLabel).Str("project", newSubscription.project).Msg("subscribed pipeline")
+
+	return &sdkProto.SubscribeResponse{}, nil
+}
+
+func (t *trigger) Unsubscribe(ctx context.Context, request *sdkProto.UnsubscribeRequest) (*sdkProto.UnsubscribeResponse, error) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	delete(t.subscriptions, subscriptionID{
+		namespace: request.NamespaceId,
+		pipeline:  request.PipelineId,
+		label:     request.PipelineTriggerLabel,
+	})
+
+	return &sdkProto.UnsubscribeResponse{}, nil
+}
+
+func (t *trigger) Shutdown(ctx context.Context, request *sdkProto.ShutdownRequest) (*sdkProto.ShutdownResponse, error) {
+	t.cancel()
+	return &sdkProto.ShutdownResponse{}, nil
+}
+
+func (t *trigger) ExternalEvent(ctx context.Context, request *sdkProto.ExternalEventRequest) (*sdkProto.ExternalEventResponse, error) {
+	hook, err := parseWebhook(request.Payload, t.webhookSecret)
+	if err != nil {
+		log.Debug().Err(err).Msg("could not process webhook")
+		return nil, err
+	}
+
+	// Webhooks for events nobody subscribes to, or that only describe things like deleting a branch, are ignored.
+	if hook == nil {
+		return &sdkProto.ExternalEventResponse{}, nil
+	}
+
+	t.mu.RLock()
+	defer t.mu.RUnlock()
+
+	for id, subscription := range t.subscriptions {
+		if !subscription.matches(hook) {
+			continue
+		}
+
+		t.events <- &sdkProto.CheckResponse{
+			Details:              hook.details,
+			NamespaceId:          id.namespace,
+			PipelineId:           id.pipeline,
+			PipelineTriggerLabel: id.label,
+			Result:               sdkProto.CheckResponse_SUCCESS,
+			Metadata:             hook.metadata,
+		}
+
+		if subscription.reportStatus && t.gitlab != nil {
+			go t.gitlab.reportStatus(t.ctx, hook.projectID, hook.commit, id.namespace, id.pipeline, statusPending,
+				"Run queued")
+		}
+	}
+
+	return &sdkProto.ExternalEventResponse{}, nil
+}
+
+// parseSubscription validates a pipeline's configuration for the trigger.
+func parseSubscription(config map[string]string) (*subscription, error) {
+	project := config[parameterProject]
+	if project == "" {
+		return nil, fmt.Errorf("config %q is required; it should be the project's path(ex. my-group/my-project)",
+			parameterProject)
+	}
+
+	eventsRaw := config[parameterEvents]
+	if eventsRaw == "" {
+		return nil, fmt.Errorf("config %q is required; it should be a comma separated list of %s, %s and %s",
+			parameterEvents, eventPush, eventTagPush, eventMergeRequest)
+	}
+
+	events := map[string]struct{}{}
+	for _, event := range strings.Split(eventsRaw, ",") {
+		event = strings.TrimSpace(event)
+		if _, supported := supportedEvents[event]; !supported {
+			return nil, fmt.Errorf("event %q is not supported; must be one of %s, %s or %s", event, eventPush,
+				eventTagPush, eventMergeRequest)
+		}
+		events[event] = struct{}{}
+	}
+
+	actionsRaw := config[parameterMergeRequestActions]
+	if actionsRaw == "" {
+		actionsRaw = defaultMergeRequestActions
+	}
+
+	actions := map[string]struct{}{}
+	for _, action := range strings.Split(actionsRaw, ",") {
+		actions[strings.TrimSpace(action)] = struct{}{}
+	}
+
+	reportStatus := false
+	switch strings.ToLower(config[parameterReportStatus]) {
+	case "", "false":
+	case "true":
+		reportStatus = true
+	default:
+		return nil, fmt.Errorf("config %q must be either 'true' or 'false'", parameterReportStatus)
+	}
+
+	return &subscription{
+		project:             project,
+		events:              events,
+		mergeRequestActions: actions,
+		reportStatus:        reportStatus,
+	}, nil
+}
+
+// matches returns whether the subscription should start a run for the given webhook.
+func (s *subscription) matches(hook *webhook) bool {
+	if !strings.EqualFold(s.project, hook.project) {
+		return false
+	}
+
+	if _, subscribed := s.events[hook.event]; !subscribed {
+		return false
+	}
+
+	if hook.event == eventMergeRequest {
+		if _, subscribed := s.mergeRequestActions[hook.action]; !subscribed {
+			return false
+		}
+	}
+
+	return true
+}
+
+// subscription returns the subscription of the given pipeline trigger label, if it exists.
+func (t *trigger) subscription(namespace, pipeline, label string) (*subscription, bool) {
+	t.mu.RLock()
+	defer t.mu.RUnlock()
+
+	subscription, exists := t.subscriptions[subscriptionID{namespace: namespace, pipeline: pipeline, label: label}]
+	return subscription, exists
+}
+
+func installer() {
+	config := map[string]string{}
+
+	fmt.Println("The GitLab trigger starts runs from GitLab webhooks. Webhooks should be sent to your Gofer " +
+		"instance's external events endpoint(ex. https://gofer.example.com/events/gitlab).")
+
+	config[strings.ToUpper(configWebhookSecret)] = prompt("Webhook secret token(required): ")
+	config[strings.ToUpper(configAPIURL)] = prompt(fmt.Sprintf("GitLab API URL(default %s): ", defaultAPIURL))
+
+	fmt.Println("To report run status back to GitLab commits the trigger needs a GitLab token with the 'api' scope " +
+		"and a Gofer token that can read the runs of subscribed pipelines. Leave these empty to skip status reporting.")
+
+	config[strings.ToUpper(configAPIToken)] = prompt("GitLab API token: ")
+	config[strings.ToUpper(configGoferToken)] = prompt("Gofer API token: ")
+
+	for key, value := range config {
+		if value == "" {
+			delete(config, key)
+		}
+	}
+
+	err := sdk.InstallTrigger(config)
+	if err != nil {
+		log.Fatal().Err(err).Msg("could not install trigger")
+	}
+
+	fmt.Println("GitLab trigger installed")
+}
+
+func prompt(message string) string {
+	fmt.Print(message)
+
+	scanner := bufio.NewScanner(os.Stdin)
+	scanner.Scan()
+
+	return strings.TrimSpace(scanner.Text())
+}
+
+func main() {
+	// The installer is run without any of the trigger's configuration so the trigger itself is only set up to serve.
+	if len(os.Args) == 2 && os.Args[1] == "installer" {
+		sdk.NewTrigger(nil, installer)
+		return
+	}
+
+	newTrigger, err := newTrigger()
+	if err != nil {
+		log.Fatal().Err(err).Msg("could not start trigger")
+	}
+
+	sdk.NewTrigger(newTrigger, installer)
+}
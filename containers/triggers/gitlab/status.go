@@ -0,0 +1,193 @@
+package main
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"os"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/clintjedwards/gofer/sdk"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/metadata"
+)
+
+// Commit statuses as named by the GitLab API.
+const (
+	statusPending  = "pending"
+	statusRunning  = "running"
+	statusSuccess  = "success"
+	statusFailed   = "failed"
+	statusCanceled = "canceled"
+)
+
+type gitlabClient struct {
+	url   string
+	token string
+	http  *http.Client
+}
+
+func newGitlabClient(apiURL, token string) *gitlabClient {
+	return &gitlabClient{
+		url:   strings.TrimSuffix(apiURL, "/"),
+		token: token,
+		http:  &http.Client{Timeout: 10 * time.Second},
+	}
+}
+
+// reportStatus sets the status of a commit. Each pipeline gets its own status on the commit so that projects
+// used by more than one pipeline show all of them.
+func (c *gitlabClient) reportStatus(ctx context.Context, projectID int64, commit, namespace, pipeline, state,
+	description string,
+) {
+	form := url.Values{}
+	form.Set("state", state)
+	form.Set("name", fmt.Sprintf("gofer/%s/%s", namespace, pipeline))
+	form.Set("description", description)
+
+	endpoint := fmt.Sprintf("%s/projects/%d/statuses/%s", c.url, projectID, url.PathEscape(commit))
+
+	request, err := http.NewRequestWithContext(ctx, http.MethodPost, endpoint, strings.NewReader(form.Encode()))
+	if err != nil {
+		log.Error().Err(err).Msg("could not create gitlab status request")
+		return
+	}
+	request.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+	request.Header.Set("PRIVATE-TOKEN", c.token)
+
+	response, err := c.http.Do(request)
+	if err != nil {
+		log.Error().Err(err).Int64("project", projectID).Str("commit", commit).Msg("could not report status to gitlab")
+		return
+	}
+	defer response.Body.Close()
+
+	if response.StatusCode >= 300 {
+		body, _ := io.ReadAll(io.LimitReader(response.Body, 1024))
+		log.Error().Int("status", response.StatusCode).Str("response", string(body)).Int64("project", projectID).
+			Str("commit", commit).Msg("could not report status to gitlab")
+		return
+	}
+
+	log.Debug().Int64("project", projectID).Str("commit", commit).Str("namespace", namespace).
+		Str("pipeline", pipeline).Str("state", state).Msg("reported status to gitlab")
+}
+
+// watchRuns follows Gofer's events to report the status of the runs this trigger started. It uses a durable event
+// consumer so that runs which finish while the trigger is restarting still get their status reported.
+func (t *trigger) watchRuns(goferToken string) {
+	consumer := fmt.Sprintf("trigger_%s", os.Getenv("GOFER_TRIGGER_KIND"))
+
+	for {
+		err := t.followRuns(goferToken, consumer)
+		if t.ctx.Err() != nil {
+			return
+		}
+
+		log.Error().Err(err).Msg("stopped following gofer events; retrying")
+
+		select {
+		case <-t.ctx.Done():
+			return
+		case <-time.After(5 * time.Second): // Don't DOS Gofer if we can't connect
+		}
+	}
+}
+
+func (t *trigger) followRuns(goferToken, consumer string) error {
+	conn, err := sdk.ConnectGofer(t.ctx)
+	if err != nil {
+		return err
+	}
+	defer conn.Close()
+
+	client := proto.NewGoferClient(conn)
+	ctx := metadata.AppendToOutgoingContext(t.ctx, "authorization", "Bearer "+goferToken)
+
+	stream, err := client.ListEvents(ctx, &proto.ListEventsRequest{
+		Follow:   true,
+		Consumer: consumer,
+	})
+	if err != nil {
+		return fmt.Errorf("could not list events: %w", err)
+	}
+
+	for {
+		event, err := stream.Recv()
+		if err != nil {
+			return err
+		}
+
+		var eventID int64
+
+		switch {
+		case event.GetStartedRunEvent() != nil:
+			started := event.GetStartedRunEvent()
+			eventID = started.Metadata.EventId
+			t.reportRun(ctx, client, started.NamespaceId, started.PipelineId, started.RunId, statusRunning,
+				fmt.Sprintf("Run #%d running", started.RunId))
+		case event.GetCompletedRunEvent() != nil:
+			completed := event.GetCompletedRunEvent()
+			eventID = completed.Metadata.EventId
+
+			state, description := statusFailed, fmt.Sprintf("Run #%d failed", completed.RunId)
+			switch completed.State {
+			case proto.EventCompletedRun_SUCCESS:
+				state, description = statusSuccess, fmt.Sprintf("Run #%d succeeded", completed.RunId)
+			case proto.EventCompletedRun_CANCELLED:
+				state, description = statusCanceled, fmt.Sprintf("Run #%d cancelled", completed.RunId)
+			}
+
+			t.reportRun(ctx, client, completed.NamespaceId, completed.PipelineId, completed.RunId, state, description)
+		default:
+			continue
+		}
+
+		_, err = client.AcknowledgeEvent(ctx, &proto.AcknowledgeEventRequest{
+			Consumer: consumer,
+			Id:       eventID,
+		})
+		if err != nil {
+			log.Error().Err(err).Int64("event", eventID).Msg("could not acknowledge event")
+		}
+	}
+}
+
+// reportRun reports the status of a run if it was started by this trigger for a subscription that asked for it.
+func (t *trigger) reportRun(ctx context.Context, client proto.GoferClient, namespace, pipeline string, runID int64,
+	state, description string,
+) {
+	response, err := client.GetRun(ctx, &proto.GetRunRequest{
+		NamespaceId: namespace,
+		PipelineId:  pipeline,
+		Id:          runID,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", namespace).Str("pipeline", pipeline).Int64("run", runID).
+			Msg("could not get run")
+		return
+	}
+
+	run := response.Run
+	if run.TriggerKind != os.Getenv("GOFER_TRIGGER_KIND") {
+		return
+	}
+
+	subscription, exists := t.subscription(namespace, pipeline, run.TriggerName)
+	if !exists || !subscription.reportStatus {
+		return
+	}
+
+	projectID, err := strconv.ParseInt(run.Variables["GOFER_TRIGGER_GITLAB_PROJECT_ID"], 10, 64)
+	commit := run.Variables["GOFER_TRIGGER_GITLAB_COMMIT_SHA"]
+	if err != nil || commit == "" {
+		return
+	}
+
+	t.gitlab.reportStatus(ctx, projectID, commit, namespace, pipeline, state, description)
+}
@@ -0,0 +1,172 @@
+package main
+
+import (
+	"bufio"
+	"bytes"
+	"crypto/subtle"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"strconv"
+	"strings"
+)
+
+// ErrUnauthorized is returned when a webhook doesn't carry the configured secret token.
+var ErrUnauthorized = errors.New("webhook token does not match")
+
+// A commit sha of all zeros is used by GitLab when a ref is created or deleted.
+const nullCommit = "0000000000000000000000000000000000000000"
+
+// webhook holds what the trigger needs from a GitLab webhook.
+type webhook struct {
+	event     string
+	action    string // Only set for merge requests.
+	project   string
+	projectID int64
+	commit    string // The commit the run is for; used to report status.
+	details   string
+	metadata  map[string]string
+}
+
+type gitlabProject struct {
+	ID                int64  `json:"id"`
+	PathWithNamespace string `json:"path_with_namespace"`
+}
+
+type pushPayload struct {
+	ObjectKind  string        `json:"object_kind"`
+	Before      string        `json:"before"`
+	After       string        `json:"after"`
+	Ref         string        `json:"ref"`
+	CheckoutSHA string        `json:"checkout_sha"`
+	UserName    string        `json:"user_name"`
+	UserEmail   string        `json:"user_email"`
+	Username    string        `json:"user_username"`
+	Project     gitlabProject `json:"project"`
+}
+
+type mergeRequestPayload struct {
+	ObjectKind string `json:"object_kind"`
+	User       struct {
+		Username string `json:"username"`
+	} `json:"user"`
+	Project          gitlabProject `json:"project"`
+	ObjectAttributes struct {
+		IID          int64  `json:"iid"`
+		Title        string `json:"title"`
+		URL          string `json:"url"`
+		Action       string `json:"action"`
+		SourceBranch string `json:"source_branch"`
+		TargetBranch string `json:"target_branch"`
+		LastCommit   struct {
+			ID string `json:"id"`
+		} `json:"last_commit"`
+	} `json:"object_attributes"`
+}
+
+// parseWebhook reads a webhook out of the HTTP request forwarded by Gofer's external events service. Webhooks that
+// shouldn't start a run are returned as nil.
+func parseWebhook(payload []byte, secret string) (*webhook, error) {
+	request, err := http.ReadRequest(bufio.NewReader(bytes.NewReader(payload)))
+	if err != nil {
+		return nil, fmt.Errorf("could not read webhook request: %w", err)
+	}
+	defer request.Body.Close()
+
+	if subtle.ConstantTimeCompare([]byte(request.Header.Get("X-Gitlab-Token")), []byte(secret)) != 1 {
+		return nil, ErrUnauthorized
+	}
+
+	decoder := json.NewDecoder(request.Body)
+
+	switch request.Header.Get("X-Gitlab-Event") {
+	case "Push Hook", "Tag Push Hook":
+		push := pushPayload{}
+		err := decoder.Decode(&push)
+		if err != nil {
+			return nil, fmt.Errorf("could not decode push webhook: %w", err)
+		}
+
+		return pushWebhook(&push), nil
+	case "Merge Request Hook":
+		mergeRequest := mergeRequestPayload{}
+		err := decoder.Decode(&mergeRequest)
+		if err != nil {
+			return nil, fmt.Errorf("could not decode merge request webhook: %w", err)
+		}
+
+		return mergeRequestWebhook(&mergeRequest), nil
+	default:
+		return nil, nil
+	}
+}
+
+func pushWebhook(push *pushPayload) *webhook {
+	// Deleted branches and tags have nothing to run against.
+	if push.After == nullCommit || push.CheckoutSHA == "" {
+		return nil
+	}
+
+	metadata := map[string]string{
+		"GOFER_TRIGGER_GITLAB_EVENT":      push.ObjectKind,
+		"GOFER_TRIGGER_GITLAB_PROJECT":    push.Project.PathWithNamespace,
+		"GOFER_TRIGGER_GITLAB_PROJECT_ID": strconv.FormatInt(push.Project.ID, 10),
+		"GOFER_TRIGGER_GITLAB_REF":        push.Ref,
+		"GOFER_TRIGGER_GITLAB_COMMIT_SHA": push.CheckoutSHA,
+		"GOFER_TRIGGER_GITLAB_BEFORE_SHA": push.Before,
+		"GOFER_TRIGGER_GITLAB_USERNAME":   push.Username,
+		"GOFER_TRIGGER_GITLAB_USER_NAME":  push.UserName,
+		"GOFER_TRIGGER_GITLAB_USER_EMAIL": push.UserEmail,
+	}
+
+	details := ""
+
+	switch push.ObjectKind {
+	case eventPush:
+		branch := strings.TrimPrefix(push.Ref, "refs/heads/")
+		metadata["GOFER_TRIGGER_GITLAB_BRANCH"] = branch
+		details = fmt.Sprintf("%s pushed to %s", push.Username, branch)
+	case eventTagPush:
+		tag := strings.TrimPrefix(push.Ref, "refs/tags/")
+		metadata["GOFER_TRIGGER_GITLAB_TAG"] = tag
+		details = fmt.Sprintf("%s pushed tag %s", push.Username, tag)
+	default:
+		return nil
+	}
+
+	return &webhook{
+		event:     push.ObjectKind,
+		project:   push.Project.PathWithNamespace,
+		projectID: push.Project.ID,
+		commit:    push.CheckoutSHA,
+		details:   details,
+		metadata:  metadata,
+	}
+}
+
+func mergeRequestWebhook(mergeRequest *mergeRequestPayload) *webhook {
+	attributes := mergeRequest.ObjectAttributes
+
+	return &webhook{
+		event:     eventMergeRequest,
+		action:    attributes.Action,
+		project:   mergeRequest.Project.PathWithNamespace,
+		projectID: mergeRequest.Project.ID,
+		commit:    attributes.LastCommit.ID,
+		details:   fmt.Sprintf("merge request !%d %s by %s", attributes.IID, attributes.Action, mergeRequest.User.Username),
+		metadata: map[string]string{
+			"GOFER_TRIGGER_GITLAB_EVENT":            eventMergeRequest,
+			"GOFER_TRIGGER_GITLAB_PROJECT":          mergeRequest.Project.PathWithNamespace,
+			"GOFER_TRIGGER_GITLAB_PROJECT_ID":       strconv.FormatInt(mergeRequest.Project.ID, 10),
+			"GOFER_TRIGGER_GITLAB_COMMIT_SHA":       attributes.LastCommit.ID,
+			"GOFER_TRIGGER_GITLAB_USERNAME":         mergeRequest.User.Username,
+			"GOFER_TRIGGER_GITLAB_MR_IID":           strconv.FormatInt(attributes.IID, 10),
+			"GOFER_TRIGGER_GITLAB_MR_ACTION":        attributes.Action,
+			"GOFER_TRIGGER_GITLAB_MR_TITLE":         attributes.Title,
+			"GOFER_TRIGGER_GITLAB_MR_URL":           attributes.URL,
+			"GOFER_TRIGGER_GITLAB_MR_SOURCE_BRANCH": attributes.SourceBranch,
+			"GOFER_TRIGGER_GITLAB_MR_TARGET_BRANCH": attributes.TargetBranch,
+		},
+	}
+}
@@ -0,0 +1,138 @@
+package main
+
+import (
+	"bytes"
+	"errors"
+	"net/http"
+	"testing"
+
+	"github.com/google/go-cmp/cmp"
+)
+
+func serializeRequest(t *testing.T, event, token, body string) []byte {
+	t.Helper()
+
+	request, err := http.NewRequest(http.MethodPost, "http://localhost/events/gitlab", bytes.NewBufferString(body))
+	if err != nil {
+		t.Fatal(err)
+	}
+	request.Header.Set("X-Gitlab-Event", event)
+	request.Header.Set("X-Gitlab-Token", token)
+
+	payload := bytes.Buffer{}
+	err = request.Write(&payload)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	return payload.Bytes()
+}
+
+func TestParseWebhookPush(t *testing.T) {
+	body := `{
+		"object_kind": "push",
+		"before": "95790bf891e76fee5e1747ab589903a6a1f80f22",
+		"after": "da1560886d4f094c3e6c9ef40349f7d38b5d27d7",
+		"ref": "refs/heads/main",
+		"checkout_sha": "da1560886d4f094c3e6c9ef40349f7d38b5d27d7",
+		"user_name": "John Smith",
+		"user_username": "jsmith",
+		"user_email": "john@example.com",
+		"project": {"id": 15, "path_with_namespace": "mike/diaspora"}
+	}`
+
+	hook, err := parseWebhook(serializeRequest(t, "Push Hook", "secret", body), "secret")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	expected := map[string]string{
+		"GOFER_TRIGGER_GITLAB_EVENT":      "push",
+		"GOFER_TRIGGER_GITLAB_PROJECT":    "mike/diaspora",
+		"GOFER_TRIGGER_GITLAB_PROJECT_ID": "15",
+		"GOFER_TRIGGER_GITLAB_REF":        "refs/heads/main",
+		"GOFER_TRIGGER_GITLAB_BRANCH":     "main",
+		"GOFER_TRIGGER_GITLAB_COMMIT_SHA": "da1560886d4f094c3e6c9ef40349f7d38b5d27d7",
+		"GOFER_TRIGGER_GITLAB_BEFORE_SHA": "95790bf891e76fee5e1747ab589903a6a1f80f22",
+		"GOFER_TRIGGER_GITLAB_USERNAME":   "jsmith",
+		"GOFER_TRIGGER_GITLAB_USER_NAME":  "John Smith",
+		"GOFER_TRIGGER_GITLAB_USER_EMAIL": "john@example.com",
+	}
+
+	if diff := cmp.Diff(expected, hook.metadata); diff != "" {
+		t.Errorf("unexpected metadata (-want +got):\n%s", diff)
+	}
+
+	if hook.projectID != 15 || hook.commit != "da1560886d4f094c3e6c9ef40349f7d38b5d27d7" {
+		t.Errorf("unexpected project %d and commit %q", hook.projectID, hook.commit)
+	}
+}
+
+func TestParseWebhookDeletedBranch(t *testing.T) {
+	body := `{
+		"object_kind": "push",
+		"after": "0000000000000000000000000000000000000000",
+		"ref": "refs/heads/feature",
+		"checkout_sha": null,
+		"project": {"id": 15, "path_with_namespace": "mike/diaspora"}
+	}`
+
+	hook, err := parseWebhook(serializeRequest(t, "Push Hook", "secret", body), "secret")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if hook != nil {
+		t.Errorf("expected deleted branch to be ignored; got %+v", hook)
+	}
+}
+
+func TestParseWebhookMergeRequest(t *testing.T) {
+	body := `{
+		"object_kind": "merge_request",
+		"user": {"username": "root"},
+		"project": {"id": 1, "path_with_namespace": "gitlabhq/gitlab-test"},
+		"object_attributes": {
+			"iid": 1,
+			"title": "MS-Viewport",
+			"url": "http://example.com/diaspora/merge_requests/1",
+			"action": "open",
+			"source_branch": "ms-viewport",
+			"target_branch": "master",
+			"last_commit": {"id": "da1560886d4f094c3e6c9ef40349f7d38b5d27d7"}
+		}
+	}`
+
+	hook, err := parseWebhook(serializeRequest(t, "Merge Request Hook", "secret", body), "secret")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if hook.event != eventMergeRequest || hook.action != "open" {
+		t.Errorf("unexpected event %q with action %q", hook.event, hook.action)
+	}
+
+	subscription, err := parseSubscription(map[string]string{
+		"project": "gitlabhq/gitlab-test",
+		"events":  "merge_request",
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if !subscription.matches(hook) {
+		t.Errorf("expected opened merge request to match subscription")
+	}
+
+	hook.action = "approved"
+	if subscription.matches(hook) {
+		t.Errorf("expected approved merge request to not match subscription")
+	}
+}
+
+func TestParseWebhookWrongToken(t *testing.T) {
+	_, err := parseWebhook(serializeRequest(t, "Push Hook", "wrong", "{}"), "secret")
+	if !errors.Is(err, ErrUnauthorized) {
+		t.Errorf("expected unauthorized error; got %v", err)
+	}
+}
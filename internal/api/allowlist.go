@@ -0,0 +1,100 @@
+package api
+
+import (
+	"context"
+	"fmt"
+	"net"
+	"strings"
+
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/peer"
+)
+
+// parseCIDRs parses a list of CIDRs(ex. 10.0.0.0/8).
+func parseCIDRs(cidrs []string) ([]*net.IPNet, error) {
+	networks := []*net.IPNet{}
+	for _, cidr := range cidrs {
+		_, network, err := net.ParseCIDR(cidr)
+		if err != nil {
+			return nil, fmt.Errorf("%q is not a valid CIDR", cidr)
+		}
+
+		networks = append(networks, network)
+	}
+
+	return networks, nil
+}
+
+func containsAddress(networks []*net.IPNet, address net.IP) bool {
+	for _, network := range networks {
+		if network.Contains(address) {
+			return true
+		}
+	}
+
+	return false
+}
+
+// addressAllowed returns whether the address falls within one of the given CIDRs.
+func addressAllowed(address net.IP, cidrs []string) bool {
+	if address == nil {
+		return false
+	}
+
+	networks, err := parseCIDRs(cidrs)
+	if err != nil {
+		return false
+	}
+
+	return containsAddress(networks, address)
+}
+
+// callerAddress returns the address of the caller; nil if it could not be determined. The X-Forwarded-For header is
+// only honored for requests coming from one of the trusted proxies.
+func callerAddress(ctx context.Context, trustedProxies []*net.IPNet) net.IP {
+	caller, ok := peer.FromContext(ctx)
+	if !ok || caller.Addr == nil {
+		return nil
+	}
+
+	host, _, err := net.SplitHostPort(caller.Addr.String())
+	if err != nil {
+		host = caller.Addr.String()
+	}
+
+	address := net.ParseIP(host)
+	if address == nil || !containsAddress(trustedProxies, address) {
+		return address
+	}
+
+	md, _ := metadata.FromIncomingContext(ctx)
+	hops := []string{}
+	for _, header := range md.Get("x-forwarded-for") {
+		hops = append(hops, strings.Split(header, ",")...)
+	}
+
+	// Each proxy appends the address it received the request from, so walk the list backwards; the first address
+	// that isn't a trusted proxy is the caller.
+	for i := len(hops) - 1; i >= 0; i-- {
+		hop := net.ParseIP(strings.TrimSpace(hops[i]))
+		if hop == nil {
+			return address
+		}
+
+		address = hop
+		if !containsAddress(trustedProxies, hop) {
+			return address
+		}
+	}
+
+	return address
+}
+
+// addressString returns the string form of an address; empty if the address is unknown.
+func addressString(address net.IP) string {
+	if address == nil {
+		return ""
+	}
+
+	return address.String()
+}
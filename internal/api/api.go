@@ -6,22 +6,29 @@ import (
 	"crypto/tls"
 	"errors"
 	"fmt"
+	"net"
 	"net/http"
 	"os"
 	"os/signal"
 	"runtime/debug"
 	"strings"
+	"sync"
 	"syscall"
 	"time"
 
 	"github.com/clintjedwards/gofer/internal/config"
 	"github.com/clintjedwards/gofer/internal/eventbus"
+	"github.com/clintjedwards/gofer/internal/latency"
+	logstore "github.com/clintjedwards/gofer/internal/logStore"
+	objectlogs "github.com/clintjedwards/gofer/internal/logStore/object"
+	"github.com/clintjedwards/gofer/internal/loglevel"
 	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/clintjedwards/gofer/internal/objectStore"
 	"github.com/clintjedwards/gofer/internal/scheduler"
 	"github.com/clintjedwards/gofer/internal/secretStore"
 	"github.com/clintjedwards/gofer/internal/storage"
 	"github.com/clintjedwards/gofer/internal/syncmap"
+	"github.com/clintjedwards/gofer/internal/tracing"
 	"github.com/clintjedwards/gofer/proto"
 	"github.com/gorilla/handlers"
 	"github.com/gorilla/mux"
@@ -52,11 +59,45 @@ var (
 	// ErrPipelineRunsInProgress is returned when a request is made against a pipeline with currently in progress runs.
 	ErrPipelineRunsInProgress = errors.New("api: pipeline has runs which are still in progress")
 
+	// ErrShuttingDown is returned when a run is started while the server is draining in-progress runs before shutting
+	// down.
+	ErrShuttingDown = errors.New("api: server is shutting down")
+
 	// ErrPipelineConfigNotValid is returned when a pipeline configuration contains is not valid for the trigger requested.
 	ErrPipelineConfigNotValid = errors.New("api: pipeline configuration is invalid")
 
 	// ErrTriggerNotFound is returned when a pipeline configuration contains a trigger that was not registered with the API.
 	ErrTriggerNotFound = errors.New("api: trigger is not found")
+
+	// ErrTaskNotAllowed is returned when a pipeline configuration contains a task asking for more than the server
+	// allows tasks to ask for.
+	ErrTaskNotAllowed = errors.New("api: task is not allowed")
+
+	// ErrWebhookNotAllowed is returned when a pipeline configuration contains a notification webhook pointing at an
+	// address notifications can't be sent to.
+	ErrWebhookNotAllowed = errors.New("api: webhook is not allowed")
+
+	// ErrCanaryInProgress is returned when a canary is started for a pipeline that already has one in progress.
+	ErrCanaryInProgress = errors.New("api: pipeline already has a canary in progress")
+
+	// ErrNoCanary is returned when a request is made against the canary of a pipeline that doesn't have one.
+	ErrNoCanary = errors.New("api: pipeline has no canary in progress")
+
+	// ErrConcurrencyGroupBusy is returned when a run is started for a pipeline while another pipeline in its
+	// concurrency group has a run in progress.
+	ErrConcurrencyGroupBusy = errors.New("api: concurrency group has a run in progress")
+
+	// ErrRunNotInProgress is returned when a task run is spawned for a run that isn't being executed by this server.
+	ErrRunNotInProgress = errors.New("api: run is not in progress")
+
+	// ErrTaskRunExists is returned when a task run is spawned with the ID of a task run already part of the run.
+	ErrTaskRunExists = errors.New("api: task run already exists")
+
+	// ErrInvalidSpawn is returned when a task run is spawned that refers to task runs that aren't part of the run.
+	ErrInvalidSpawn = errors.New("api: spawned task run refers to unknown task runs")
+
+	// ErrQuotaExceeded is returned when a request would take a namespace over one of its resource limits.
+	ErrQuotaExceeded = errors.New("api: namespace quota exceeded")
 )
 
 type CancelContext struct {
@@ -91,6 +132,13 @@ type API struct {
 	// files with secrets.
 	secretStore secretStore.Engine
 
+	// LogStore is where the logs of task runs are kept. Logs are written while the task run is in progress and can be
+	// followed by readers at the same time.
+	logStore logstore.Engine
+
+	// logArchive holds copies of the logs of finished task runs when archiving is turned on. Nil otherwise.
+	logArchive logstore.Engine
+
 	// TODO(clintjedwards): replace this syncmap with a actually good version once generics catches up.
 	// Triggers is an in-memory map of currently registered triggers. These triggers are registered on startup and
 	// launched as long running containers via the scheduler. Gofer refers to this cache as a way to communicate
@@ -106,11 +154,72 @@ type API struct {
 	// service will not schedule new runs.
 	ignorePipelineRunEvents *atomic.Bool
 
+	// draining is set once the server starts shutting down. No new runs are started while the runs in progress are
+	// given a chance to finish.
+	draining *atomic.Bool
+
+	// runsInProgress counts the runs this server is currently executing.
+	runsInProgress *atomic.Int64
+
+	// oidc validates ID tokens from the identity provider users log in with. Nil if single sign-on is not enabled.
+	oidc *oidcVerifier
+
+	// externalJWT validates JWTs issued by the identity provider of a platform embedding Gofer. Nil if external JWTs
+	// are not enabled.
+	externalJWT *oidcVerifier
+
+	// ldap looks up the directory groups of users that tokens were created for. Nil if ldap is not enabled.
+	ldap *ldapDirectory
+
+	// tokenUsage collects the usage of tokens in memory until it is periodically written to storage.
+	tokenUsage *tokenUsageTracker
+
+	// runConfigs caches the tasks and notifiers of the pipeline config versions runs are started with.
+	runConfigs *runConfigCache
+
+	// trustedProxies are the networks of the reverse proxies whose X-Forwarded-For headers are honored.
+	trustedProxies []*net.IPNet
+
+	// certificates serves the TLS certificate of the main service and external events endpoints and reloads it when
+	// its files change.
+	certificates *certificateReloader
+
 	// events acts as an event bus for the Gofer application. It is used throughout the whole application to give
 	// different parts of the application the ability to listen for and respond to events that might happen in other
 	// parts.
 	events *eventbus.EventBus
 
+	// durations keeps the distributions of run and task run durations for reporting.
+	durations *durationRecorder
+
+	// routeLatencies and queryLatencies track the recent latency of API routes and storage queries so the slowest
+	// can be reported.
+	routeLatencies *latency.Tracker
+	queryLatencies *latency.Tracker
+
+	// logLevels changes which log lines the server writes while it's running.
+	logLevels *loglevel.Filter
+
+	// eventConsumersMu serializes event acknowledgements so that a consumer's position only ever moves forward.
+	eventConsumersMu sync.Mutex
+
+	// concurrencyGroupsMu serializes starting runs for pipelines within a concurrency group so that two runs can't
+	// both find the group free.
+	concurrencyGroupsMu sync.Mutex
+
+	// runQuotaLocks serializes starting runs within a namespace so that two runs can't both fit into the last slot of
+	// the namespace's run quota. Keyed by namespace and guarded by runQuotaLocksMu.
+	runQuotaLocks   map[string]*sync.Mutex
+	runQuotaLocksMu sync.Mutex
+
+	// inProgressRuns holds the runs this server is currently executing so that their task runs can add more tasks to
+	// them. Keyed by namespace, pipeline and run ID.
+	inProgressRuns syncmap.Syncmap[string, *inProgressRun]
+
+	// runNetworks holds the networks of the runs this server is currently executing. Runs without one are left out.
+	// Keyed the same as inProgressRuns.
+	runNetworks syncmap.Syncmap[string, string]
+
 	// We opt out of forward compatibility with this embedded interface. This is required by GRPC.
 	//
 	// We don't embed the "proto.UnimplementedGoferServer" as there should never(I assume this will come back to bite me)
@@ -121,7 +230,10 @@ type API struct {
 }
 
 // NewAPI creates a new instance of the main Gofer API service.
-func NewAPI(config *config.API, storage storage.Engine, scheduler scheduler.Engine, objectStore objectStore.Engine, secretStore secretStore.Engine) (*API, error) {
+func NewAPI(config *config.API, storage storage.Engine, scheduler scheduler.Engine, objectStore objectStore.Engine, secretStore secretStore.Engine, logStore logstore.Engine, logLevels *loglevel.Filter) (*API, error) {
+	queryLatencies := latency.New(slowReportWindow)
+	storage = timedStorage(storage, queryLatencies)
+
 	eventbus, err := eventbus.New(storage, config.EventLogRetention, config.PruneEventsInterval)
 	if err != nil {
 		return nil, fmt.Errorf("could not init event bus: %w", err)
@@ -140,17 +252,123 @@ func NewAPI(config *config.API, storage storage.Engine, scheduler scheduler.Engi
 		scheduler:               scheduler,
 		objectStore:             objectStore,
 		secretStore:             secretStore,
+		logStore:                logStore,
 		ignorePipelineRunEvents: atomic.NewBool(config.IgnorePipelineRunEvents),
+		draining:                atomic.NewBool(false),
+		runsInProgress:          atomic.NewInt64(0),
 		triggers:                syncmap.New[string, *models.Trigger](),
 		notifiers:               syncmap.New[string, *models.Notifier](),
+		inProgressRuns:          syncmap.New[string, *inProgressRun](),
+		runNetworks:             syncmap.New[string, string](),
+		runQuotaLocks:           map[string]*sync.Mutex{},
+		tokenUsage:              newTokenUsageTracker(),
+		runConfigs:              newRunConfigCache(),
+		durations:               newDurationRecorder(),
+		logLevels:               logLevels,
+		routeLatencies:          latency.New(slowReportWindow),
+		queryLatencies:          queryLatencies,
+	}
+
+	newAPI.trustedProxies, err = parseCIDRs(config.Server.TrustedProxies)
+	if err != nil {
+		return nil, fmt.Errorf("could not parse trusted proxies: %w", err)
+	}
+
+	newAPI.certificates, err = newAPI.newCertificateReloader(config.Server.TLSCertPath, config.Server.TLSKeyPath)
+	if err != nil {
+		return nil, fmt.Errorf("could not load TLS certificate: %w", err)
 	}
 
+	if interval := newAPI.certificateReloadInterval(); interval > 0 && newAPI.certificates.certPath != "" {
+		go newAPI.certificates.reloadOnSchedule(ctx, interval)
+	}
+
+	if config.Permissioning != nil && config.Permissioning.OIDC != nil && config.Permissioning.OIDC.Enable {
+		newAPI.oidc, err = newOIDCVerifier(config.Permissioning.OIDC)
+		if err != nil {
+			return nil, fmt.Errorf("could not init oidc: %w", err)
+		}
+	}
+
+	if config.Permissioning != nil && config.Permissioning.ExternalJWT != nil && config.Permissioning.ExternalJWT.Enable {
+		newAPI.externalJWT, err = newExternalJWTVerifier(config.Permissioning.ExternalJWT)
+		if err != nil {
+			return nil, fmt.Errorf("could not init external jwt: %w", err)
+		}
+	}
+
+	if config.Permissioning != nil && config.Permissioning.LDAP != nil && config.Permissioning.LDAP.Enable {
+		newAPI.ldap = newLDAPDirectory(config.Permissioning.LDAP)
+		go newAPI.refreshLDAPTokensOnSchedule()
+	}
+
+	go newAPI.flushTokenUsageOnSchedule()
+	go newAPI.removeExpiredTokensOnSchedule()
+	go newAPI.removeExpiredObjectsOnSchedule()
+	go newAPI.runSchedulesOnSchedule()
+
+	if newAPI.auditLogEnabled() {
+		go newAPI.pruneAuthzDecisionsOnSchedule()
+	}
+
+	if config.TaskRunLogs != nil && config.TaskRunLogs.Archive {
+		if logstore.EngineType(config.TaskRunLogs.Engine) == logstore.EngineObjectStore {
+			log.Warn().Msg("task_run_logs.archive is ignored since the log store engine already keeps logs in the object store")
+		} else {
+			newAPI.logArchive = objectlogs.New(objectStore)
+		}
+	}
+
+	if config.TaskRunLogs != nil && config.TaskRunLogs.BudgetMB > 0 {
+		store, ok := logStore.(logstore.Budgeted)
+		if ok {
+			go newAPI.enforceTaskRunLogBudgetOnSchedule(store)
+		} else {
+			log.Warn().Str("engine", config.TaskRunLogs.Engine).
+				Msg("task_run_logs.budget_mb is ignored since the log store engine does not keep logs on disk")
+		}
+	}
+
+	err = newAPI.startEventSinks()
+	if err != nil {
+		return nil, fmt.Errorf("could not start event sinks: %w", err)
+	}
+
+	err = newAPI.startDurationRecorder()
+	if err != nil {
+		return nil, fmt.Errorf("could not start duration recorder: %w", err)
+	}
+
+	err = newAPI.startNotificationPolicies()
+	if err != nil {
+		return nil, fmt.Errorf("could not start notification policies: %w", err)
+	}
+
+	err = newAPI.startDeploymentHealthChecks()
+	if err != nil {
+		return nil, fmt.Errorf("could not start deployment health checks: %w", err)
+	}
+
+	err = newAPI.startWebhooks()
+	if err != nil {
+		return nil, fmt.Errorf("could not start webhooks: %w", err)
+	}
+
+	newAPI.startDigests()
+
 	err = newAPI.createDefaultNamespace()
 	if err != nil {
 		return nil, fmt.Errorf("could not create default namespace: %w", err)
 	}
 
 	newAPI.installNotifiersFromConfig()
+
+	// Leftover containers only waste resources, so failing to clean them up shouldn't keep Gofer from starting.
+	err = newAPI.reconcileTriggerContainers()
+	if err != nil {
+		log.Error().Err(err).Msg("could not reconcile trigger containers")
+	}
+
 	err = newAPI.installTriggersFromConfig()
 	if err != nil {
 		return nil, fmt.Errorf("could not install triggers from config file: %w", err)
@@ -163,7 +381,7 @@ func NewAPI(config *config.API, storage storage.Engine, scheduler scheduler.Engi
 
 	err = newAPI.restoreTriggerSubscriptions()
 	if err != nil {
-		newAPI.cleanup()
+		newAPI.cleanup(false)
 		return nil, fmt.Errorf("could not restore trigger subscriptions: %w", err)
 	}
 
@@ -184,47 +402,129 @@ func NewAPI(config *config.API, storage storage.Engine, scheduler scheduler.Engi
 	return newAPI, nil
 }
 
-// cleanup gracefully cleans up all goroutines to ensure a clean shutdown.
-func (api *API) cleanup() {
+// cleanup gracefully cleans up all goroutines to ensure a clean shutdown. Runs in progress are given a chance to
+// finish if drain is set; otherwise they're left for the next process to recover.
+func (api *API) cleanup(drain bool) {
 	api.ignorePipelineRunEvents.Store(true)
+	api.draining.Store(true)
 
 	// Send graceful stop to all triggers
 	api.stopTriggers()
 
+	// Give the runs in progress a chance to finish while the API is still up for them to use.
+	if drain {
+		api.drainRuns()
+	}
+
 	// Stop all goroutines which should stop the event processing pipeline and the trigger monitoring.
 	api.context.cancel()
+
+	// Make sure token usage collected since the last flush isn't lost.
+	api.flushTokenUsage()
 }
 
-// StartAPIService starts the Gofer API service and blocks until a SIGINT or SIGTERM is received.
+// drainRuns blocks until every run in progress has finished or the drain timeout has passed. Runs that are still in
+// progress afterwards already have their state saved and are picked back up by findOrphans on the next startup.
+func (api *API) drainRuns() {
+	inProgress := api.runsInProgress.Load()
+	if inProgress == 0 {
+		return
+	}
+
+	log.Info().Int64("runs", inProgress).Str("timeout", api.config.Server.DrainTimeout.String()).
+		Msg("waiting for runs in progress to finish before shutting down")
+
+	deadline := time.After(api.config.Server.DrainTimeout)
+	ticker := time.NewTicker(time.Second)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-deadline:
+			log.Warn().Int64("runs", api.runsInProgress.Load()).
+				Msg("runs still in progress after drain timeout; they will be recovered on next startup")
+			return
+		case <-ticker.C:
+			if api.runsInProgress.Load() == 0 {
+				log.Info().Msg("all runs in progress have finished")
+				return
+			}
+		}
+	}
+}
+
+// StartAPIService starts the Gofer API service and blocks until a SIGINT or SIGTERM is received. On SIGHUP the service
+// is restarted in place instead.
 func (api *API) StartAPIService() {
 	grpcServer, err := api.createGRPCServer()
 	if err != nil {
 		log.Fatal().Err(err).Msg("could not create GRPC service")
 	}
 
-	tlsConfig, err := api.generateTLSConfig(api.config.Server.TLSCertPath, api.config.Server.TLSKeyPath)
-	if err != nil {
-		log.Fatal().Err(err).Msg("could not get proper TLS config")
+	tlsConfig := api.generateTLSConfig()
+
+	if api.mtlsEnabled() {
+		err = enableClientCertificates(tlsConfig, api.config.Permissioning.MTLS)
+		if err != nil {
+			log.Fatal().Err(err).Msg("could not enable client certificate authentication")
+		}
+	}
+
+	router := mux.NewRouter()
+	if api.config.Server.EnableMetrics {
+		router.Handle("/metrics", handlers.MethodHandler{
+			"GET": http.HandlerFunc(api.metricsHandler),
+		})
 	}
 
-	httpServer := wrapGRPCServer(api.config, grpcServer)
+	httpServer := wrapGRPCServer(api.config, grpcServer, router)
 	httpServer.TLSConfig = tlsConfig
 
+	listener, err := api.listen()
+	if err != nil {
+		log.Fatal().Err(err).Msg("could not listen")
+	}
+
 	// Run our server in a goroutine and listen for signals that indicate graceful shutdown
 	go func() {
-		if err := httpServer.ListenAndServeTLS("", ""); err != nil && err != http.ErrServerClosed {
+		if err := httpServer.ServeTLS(listener, "", ""); err != nil && err != http.ErrServerClosed {
 			log.Fatal().Err(err).Msg("server exited abnormally")
 		}
 	}()
 	log.Info().Str("url", api.config.Host).Msg("started gofer grpc/http service")
 
 	c := make(chan os.Signal, 1)
-	signal.Notify(c, syscall.SIGTERM, syscall.SIGINT)
-	<-c
+	signal.Notify(c, syscall.SIGTERM, syscall.SIGINT, syscall.SIGHUP)
+
+	// SIGHUP restarts the service in place(see restart.go). Everything the restart needs is gathered up front so that
+	// the service keeps running if it can't be restarted.
+	var executable string
+	var restartListener *os.File
+	for sig := range c {
+		if sig != syscall.SIGHUP {
+			break
+		}
+
+		executable, err = os.Executable()
+		if err != nil {
+			log.Error().Err(err).Msg("could not find executable to restart; continuing to serve")
+			continue
+		}
+
+		restartListener, err = inheritableListener(listener)
+		if err != nil {
+			log.Error().Err(err).Msg("could not restart; continuing to serve")
+			continue
+		}
+
+		log.Info().Str("executable", executable).Msg("restarting")
+		break
+	}
 
 	// On ctrl-c we need to clean up not only the connections from the GRPC server, but make sure all the currently
-	// running jobs are logged and exited properly.
-	api.cleanup()
+	// running jobs are logged and exited properly. Runs in progress aren't drained on restart since the restarted
+	// process adopts them.
+	api.cleanup(restartListener == nil)
 
 	// Doesn't block if no connections, otherwise will wait until the timeout deadline or connections to finish,
 	// whichever comes first.
@@ -234,21 +534,24 @@ func (api *API) StartAPIService() {
 	err = httpServer.Shutdown(ctx)
 	if err != nil {
 		log.Error().Err(err).Msg("could not shutdown server in timeout specified")
-		return
+	} else {
+		log.Info().Msg("grpc server exited gracefully")
 	}
 
-	log.Info().Msg("grpc server exited gracefully")
+	// The process can't go back to serving at this point, so a failed restart exits for the supervisor to restart it.
+	if restartListener != nil {
+		err := restart(executable, restartListener)
+		log.Fatal().Err(err).Msg("could not restart")
+	}
 }
 
 // wrapGRPCServer returns a combined grpc/http (grpc-web compatible) service with all proper settings;
 // Rather than going through the trouble of setting up a separate proxy and extra for the service in order to server http/grpc/grpc-web
 // this keeps things simple by enabling the operator to deploy a single binary and serve them all from one endpoint.
 // This reduces operational burden, configuration headache and overall just makes for a better time for both client and operator.
-func wrapGRPCServer(config *config.API, grpcServer *grpc.Server) *http.Server {
+func wrapGRPCServer(config *config.API, grpcServer *grpc.Server, router *mux.Router) *http.Server {
 	wrappedGrpc := grpcweb.WrapServer(grpcServer)
 
-	router := mux.NewRouter()
-
 	combinedHandler := http.HandlerFunc(func(resp http.ResponseWriter, req *http.Request) {
 		if strings.Contains(req.Header.Get("Content-Type"), "application/grpc") || wrappedGrpc.IsGrpcWebRequest(req) {
 			wrappedGrpc.ServeHTTP(resp, req)
@@ -376,6 +679,9 @@ func (api *API) findOrphans() {
 //   * If the container is in a finished state: Remove from run cache -> update container state -> clear out logs
 //     -> update logs with new logs.
 //   * If the scheduler has no record of this container ever running then assume the state is unknown.
+//
+// Unfinished runs are only picked back up if the pipeline's recovery policy allows it; otherwise they're failed and
+// possibly started over.
 func (api *API) repairOrphanRun(namespace, pipeline string, runID int64) error {
 	run, err := api.storage.GetRun(storage.GetRunRequest{
 		NamespaceID: namespace,
@@ -386,6 +692,37 @@ func (api *API) repairOrphanRun(namespace, pipeline string, runID int64) error {
 		return err
 	}
 
+	if !run.IsComplete() {
+		parent, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: pipeline})
+		if err != nil {
+			return err
+		}
+
+		policy := parent.RecoveryPolicy
+		if policy == "" {
+			policy = models.RecoveryPolicyResume
+		}
+
+		run.Recovery = &models.RunRecovery{
+			Recovered: time.Now().UnixMilli(),
+			Policy:    policy,
+		}
+
+		if policy != models.RecoveryPolicyResume {
+			return api.abandonOrphanRun(run)
+		}
+
+		err = api.storage.UpdateRun(storage.UpdateRunRequest{Run: run})
+		if err != nil {
+			return err
+		}
+	}
+
+	// Runs being picked back up keep their network so that task runs yet to start join the ones still running.
+	if !run.IsComplete() {
+		api.createRunNetwork(run)
+	}
+
 	taskStatusMap := syncmap.New[string, models.ContainerState]()
 
 	// For each run we also need to handle the individual task runs.
@@ -434,14 +771,15 @@ func (api *API) repairOrphanRun(namespace, pipeline string, runID int64) error {
 		// If the taskrun was waiting to be scheduled then it will not have a schedulerID yet. As such we
 		// need to make sure it gets scheduled as normal.
 		if taskrun.State == models.ContainerStateWaiting || taskrun.State == models.ContainerStateProcessing {
-			go api.reviveLostTaskRun(&taskStatusMap, taskrun)
+			go api.reviveLostTaskRun(&taskStatusMap, taskrun, run.Variables)
 			continue
 		}
 
 		// If it is unfinished and just need to be tracked then we just add log/state trackers onto it.
-		go api.handleLogUpdates(taskrun.SchedulerID, taskrun)
+		ctx := tracing.RunContext(taskrun.CorrelationID)
+		go api.handleLogUpdates(ctx, taskrun.SchedulerID, taskrun)
 		go func() {
-			err = api.waitTaskRunFinish(taskrun.SchedulerID, taskrun)
+			err = api.waitTaskRunFinish(ctx, taskrun.SchedulerID, taskrun)
 			if err != nil {
 				log.Error().Err(err).Str("task", taskrun.ID).
 					Str("pipeline", taskrun.PipelineID).
@@ -459,19 +797,106 @@ func (api *API) repairOrphanRun(namespace, pipeline string, runID int64) error {
 
 	// If run is unfinished then we need to launch a goroutine to track its state.
 	if !run.IsComplete() {
-		go api.monitorRunStatus(run.NamespaceID, run.PipelineID, run.ID, &taskStatusMap) //nolint:errcheck
+		api.runsInProgress.Inc()
+		go func() {
+			defer api.runsInProgress.Dec()
+			defer api.removeRunNetwork(run)
+			// Recovered runs don't take new task runs, so nothing else needs the lock.
+			api.monitorRunStatus(run.NamespaceID, run.PipelineID, run.ID, &taskStatusMap, &sync.Mutex{}) //nolint:errcheck
+		}()
 	}
 
 	return nil
 }
 
-// createGRPCServer creates the gofer grpc server with all the proper settings; TLS enabled.
-func (api *API) createGRPCServer() (*grpc.Server, error) {
-	tlsConfig, err := api.generateTLSConfig(api.config.Server.TLSCertPath, api.config.Server.TLSKeyPath)
+// abandonOrphanRun stops whatever is left of an unfinished orphaned run and fails it instead of picking it back up. If
+// the run's recovery policy calls for it, the run is then started over from the beginning.
+func (api *API) abandonOrphanRun(run *models.Run) error {
+	for _, taskrunID := range run.TaskRuns {
+		taskrun, err := api.storage.GetTaskRun(storage.GetTaskRunRequest{
+			NamespaceID: run.NamespaceID,
+			PipelineID:  run.PipelineID,
+			RunID:       run.ID,
+			ID:          taskrunID,
+		})
+		if err != nil {
+			log.Error().Err(err).Str("pipeline", run.PipelineID).Int64("run", run.ID).
+				Msg("could not get task run while abandoning orphan")
+			continue
+		}
+
+		if taskrun.IsComplete() {
+			continue
+		}
+
+		// Task runs that were still waiting never made it to the scheduler so there is nothing to stop.
+		if taskrun.SchedulerID != "" {
+			err := api.cancelTaskRun(taskrun, true)
+			if err != nil && !errors.Is(err, scheduler.ErrNoSuchContainer) {
+				log.Error().Err(err).Str("task", taskrun.ID).Str("pipeline", taskrun.PipelineID).
+					Int64("run", taskrun.RunID).Msg("could not stop task run while abandoning orphan")
+			}
+		}
+
+		taskrun.SetFinishedAbnormal(models.ContainerStateCancelled, models.TaskRunFailure{
+			Kind:        models.TaskRunFailureKindOrphaned,
+			Description: "Task run was stopped during recovery since its pipeline does not allow resuming runs.",
+		}, 1)
+
+		err = api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
+		if err != nil {
+			log.Error().Err(err).Str("task", taskrun.ID).Str("pipeline", taskrun.PipelineID).
+				Int64("run", taskrun.RunID).Msg("could not update task run state due to storage err")
+		}
+
+		api.events.Publish(models.NewEventCompletedTaskRun(*taskrun))
+	}
+
+	run.SetFailed(models.RunFailureKindInterrupted,
+		"Run was in progress when Gofer stopped unexpectedly and its pipeline does not allow resuming runs.")
+
+	// The run has to be finished before it's started over; pipelines in sequential mode would refuse the new run
+	// otherwise.
+	err := api.storage.UpdateRun(storage.UpdateRunRequest{Run: run})
+	if err != nil {
+		return err
+	}
+
+	api.events.Publish(models.NewEventCompletedRun(*run))
+	go api.startDownstreamRuns(*run)
+
+	log.Warn().Str("namespace", run.NamespaceID).Str("pipeline", run.PipelineID).Int64("run", run.ID).
+		Str("policy", string(run.Recovery.Policy)).Msg("failed run interrupted by unexpected shutdown")
+
+	if run.Recovery.Policy != models.RecoveryPolicyRerun {
+		return nil
+	}
+
+	rerun, err := api.createNewRun(run.NamespaceID, run.PipelineID, run.TriggerKind, run.TriggerName, run.Initiator,
+		run.Only, run.Variables, 0, run.Upstream)
 	if err != nil {
-		return nil, err
+		return fmt.Errorf("could not start interrupted run over: %w", err)
 	}
 
+	run.Recovery.RerunID = rerun.ID
+	run.Failure.Description = fmt.Sprintf("Run was in progress when Gofer stopped unexpectedly; it was started over "+
+		"as run %d.", rerun.ID)
+
+	err = api.storage.UpdateRun(storage.UpdateRunRequest{Run: run})
+	if err != nil {
+		return err
+	}
+
+	log.Info().Str("namespace", run.NamespaceID).Str("pipeline", run.PipelineID).Int64("run", run.ID).
+		Int64("rerun", rerun.ID).Msg("started interrupted run over")
+
+	return nil
+}
+
+// createGRPCServer creates the gofer grpc server with all the proper settings; TLS enabled.
+func (api *API) createGRPCServer() (*grpc.Server, error) {
+	tlsConfig := api.generateTLSConfig()
+
 	panicHandler := func(p interface{}) (err error) {
 		log.Error().Err(err).Interface("panic", p).Bytes("stack", debug.Stack()).Msg("server has encountered a fatal error")
 		return status.Errorf(codes.Unknown, "server has encountered a fatal error and could not process request")
@@ -482,13 +907,19 @@ func (api *API) createGRPCServer() (*grpc.Server, error) {
 		grpc.UnaryInterceptor(
 			grpc_middleware.ChainUnaryServer(
 				grpc_recovery.UnaryServerInterceptor(grpc_recovery.WithRecoveryHandler(panicHandler)),
+				api.traceUnary,
+				api.timeUnary,
+				api.auditUnary,
 				grpc_auth.UnaryServerInterceptor(api.authenticate),
 			),
 		),
 		grpc.StreamInterceptor(
 			grpc_middleware.ChainStreamServer(
 				grpc_recovery.StreamServerInterceptor(grpc_recovery.WithRecoveryHandler(panicHandler)),
+				api.traceStream,
+				api.auditStream,
 				grpc_auth.StreamServerInterceptor(api.authenticate),
+				api.keepaliveStream,
 			),
 		),
 
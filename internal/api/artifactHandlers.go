@@ -0,0 +1,125 @@
+package api
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+
+	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (api *API) ListRunArtifacts(ctx context.Context, request *proto.ListRunArtifactsRequest) (*proto.ListRunArtifactsResponse, error) {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.ListRunArtifactsResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	if request.PipelineId == "" {
+		return &proto.ListRunArtifactsResponse{}, status.Error(codes.FailedPrecondition, "pipeline_id required")
+	}
+
+	if request.RunId == 0 {
+		return &proto.ListRunArtifactsResponse{}, status.Error(codes.FailedPrecondition, "run_id required")
+	}
+
+	artifacts, err := api.storage.GetAllArtifacts(storage.GetAllArtifactsRequest{
+		NamespaceID: request.NamespaceId,
+		PipelineID:  request.PipelineId,
+		RunID:       request.RunId,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Str("pipeline", request.PipelineId).
+			Int64("run", request.RunId).Msg("could not get artifacts")
+		return &proto.ListRunArtifactsResponse{}, status.Error(codes.Internal, "failed to retrieve artifacts from database")
+	}
+
+	protoArtifacts := []*proto.Artifact{}
+	for _, artifact := range artifacts {
+		protoArtifacts = append(protoArtifacts, artifact.ToProto())
+	}
+
+	return &proto.ListRunArtifactsResponse{
+		Artifacts: protoArtifacts,
+	}, nil
+}
+
+// GetArtifactStream sends the content of an artifact in chunks read straight from the object store in the same way
+// as GetPipelineObjectStream. The first message carries the artifact's metadata so clients know its size and
+// checksum before the content arrives.
+func (api *API) GetArtifactStream(request *proto.GetArtifactStreamRequest, stream proto.Gofer_GetArtifactStreamServer) error {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(stream.Context())
+	}
+
+	if !hasAccess(stream.Context(), request.NamespaceId) {
+		return status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	artifact, err := api.storage.GetArtifact(storage.GetArtifactRequest{
+		NamespaceID: request.NamespaceId,
+		PipelineID:  request.PipelineId,
+		RunID:       request.RunId,
+		TaskRunID:   request.TaskRunId,
+		Name:        request.Name,
+	})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return status.Error(codes.NotFound, fmt.Sprintf("could not find artifact %q", request.Name))
+		}
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Str("pipeline", request.PipelineId).
+			Int64("run", request.RunId).Str("artifact", request.Name).Msg("could not get artifact")
+		return status.Error(codes.Internal, "failed to retrieve artifact from database")
+	}
+
+	if artifact.Expired {
+		return status.Error(codes.FailedPrecondition, fmt.Sprintf("artifact %q has expired", request.Name))
+	}
+
+	reader, _, err := api.objectStore.GetObjectStream(artifact.ObjectKey, request.Offset)
+	if err != nil {
+		if errors.Is(err, objectstore.ErrEntityNotFound) {
+			return status.Error(codes.NotFound, fmt.Sprintf("could not find content of artifact %q", request.Name))
+		}
+		if errors.Is(err, objectstore.ErrPreconditionFailure) {
+			return status.Error(codes.FailedPrecondition, fmt.Sprintf("could not read artifact %q; %v", request.Name, err))
+		}
+		return status.Error(codes.Internal, fmt.Sprintf("could not read artifact %q; %v", request.Name, err))
+	}
+	defer reader.Close()
+
+	buffer := make([]byte, objectStreamChunkSize)
+	sent := false
+
+	for {
+		n, readErr := io.ReadFull(reader, buffer)
+		if readErr != nil && !errors.Is(readErr, io.EOF) && !errors.Is(readErr, io.ErrUnexpectedEOF) {
+			return status.Error(codes.Internal, fmt.Sprintf("could not read artifact %q; %v", request.Name, readErr))
+		}
+
+		if n > 0 || !sent {
+			response := &proto.GetArtifactStreamResponse{Content: buffer[:n]}
+			if !sent {
+				response.Artifact = artifact.ToProto()
+			}
+
+			err := stream.Send(response)
+			if err != nil {
+				return err
+			}
+			sent = true
+		}
+
+		if readErr != nil {
+			return nil
+		}
+	}
+}
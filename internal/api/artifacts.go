@@ -0,0 +1,232 @@
+package api
+
+import (
+	"archive/tar"
+	"bufio"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"io"
+	"mime"
+	"net/http"
+	"path"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
+	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// ArtifactKeyFmt is the key of an artifact within its run. Artifacts are stored as run objects so that they're
+// cleaned up along with the rest of the run's objects if the run goes away.
+const ArtifactKeyFmt = "artifact:%s:%s" // artifact:taskrunid:name
+
+// artifactContentTypeDir is the content type of artifacts collected from a directory.
+const artifactContentTypeDir = "application/x-tar"
+
+func artifactObjectKey(namespace, pipeline string, runID int64, taskRun, name string) string {
+	return runObjectKey(namespace, pipeline, fmt.Sprintf(ArtifactKeyFmt, taskRun, name), runID)
+}
+
+// collectArtifacts copies the artifacts declared by the task run's task out of its finished container and into the
+// object store. Artifacts that can't be collected are logged and skipped; they never change the outcome of the task
+// run.
+func (api *API) collectArtifacts(ctx context.Context, schedulerID string, taskRun *models.TaskRun) {
+	for _, declared := range taskRun.Task.Artifacts {
+		artifact, err := api.collectArtifact(ctx, schedulerID, taskRun, declared)
+		if err != nil {
+			logger := log.Error()
+			if errors.Is(err, scheduler.ErrNoSuchFile) || errors.Is(err, ErrQuotaExceeded) {
+				logger = log.Warn()
+			}
+
+			logger.Err(err).Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+				Str("artifact", declared.Name).Str("correlation_id", taskRun.CorrelationID).
+				Msg("could not collect artifact")
+			continue
+		}
+
+		log.Debug().Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("artifact", artifact.Name).Int64("size", artifact.Size).Str("correlation_id", taskRun.CorrelationID).
+			Msg("collected artifact")
+	}
+}
+
+func (api *API) collectArtifact(ctx context.Context, schedulerID string, taskRun *models.TaskRun,
+	declared models.TaskArtifact,
+) (*models.Artifact, error) {
+	err := api.checkObjectQuota(taskRun.NamespaceID, 0)
+	if err != nil {
+		return nil, err
+	}
+
+	copied, err := scheduler.NewTracedEngine(ctx, api.scheduler).CopyFromContainer(scheduler.CopyFromContainerRequest{
+		SchedulerID: schedulerID,
+		Path:        declared.Path,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("could not copy %q from container: %w", declared.Path, err)
+	}
+	defer copied.Archive.Close()
+
+	content := io.Reader(copied.Archive)
+	contentType := artifactContentTypeDir
+
+	// Single files are stored as they are instead of as an archive holding only them.
+	if !copied.IsDir {
+		archive := tar.NewReader(copied.Archive)
+		header, err := archive.Next()
+		if err != nil {
+			return nil, fmt.Errorf("could not read %q from container: %w", declared.Path, err)
+		}
+
+		if header.Typeflag != tar.TypeReg {
+			return nil, fmt.Errorf("%q is neither a regular file nor a directory", declared.Path)
+		}
+
+		reader := bufio.NewReader(archive)
+		contentType = detectContentType(header.Name, reader)
+		content = reader
+	}
+
+	if declared.ContentType != "" {
+		contentType = declared.ContentType
+	}
+
+	key := artifactObjectKey(taskRun.NamespaceID, taskRun.PipelineID, taskRun.RunID, taskRun.ID, declared.Name)
+	checksum := sha256.New()
+
+	size, err := api.objectStore.PutObjectStream(key, io.TeeReader(content, checksum), true)
+	if err != nil {
+		return nil, fmt.Errorf("could not store %q: %w", declared.Path, err)
+	}
+
+	expiry := declared.Expiry
+	if expiry == 0 {
+		expiry = int64(api.config.ObjectStore.ArtifactExpiry)
+	}
+
+	artifact := &models.Artifact{
+		NamespaceID: taskRun.NamespaceID,
+		PipelineID:  taskRun.PipelineID,
+		RunID:       taskRun.RunID,
+		TaskRunID:   taskRun.ID,
+		Name:        declared.Name,
+		Path:        declared.Path,
+		ObjectKey:   key,
+		Size:        size,
+		Checksum:    hex.EncodeToString(checksum.Sum(nil)),
+		ContentType: contentType,
+		IsDir:       copied.IsDir,
+		Expiry:      expiry,
+		Created:     time.Now().UnixMilli(),
+	}
+
+	// A task run that's attempted more than once replaces the artifacts of its earlier attempts.
+	existing, err := api.storage.GetArtifact(storage.GetArtifactRequest{
+		NamespaceID: artifact.NamespaceID,
+		PipelineID:  artifact.PipelineID,
+		RunID:       artifact.RunID,
+		TaskRunID:   artifact.TaskRunID,
+		Name:        artifact.Name,
+	})
+	if err != nil {
+		if !errors.Is(err, storage.ErrEntityNotFound) {
+			return nil, fmt.Errorf("could not get artifact: %w", err)
+		}
+
+		err = api.storage.AddArtifact(storage.AddArtifactRequest{Artifact: artifact})
+		if err != nil {
+			return nil, fmt.Errorf("could not save artifact: %w", err)
+		}
+
+		return artifact, nil
+	}
+
+	artifact.ID = existing.ID
+	err = api.storage.UpdateArtifact(storage.UpdateArtifactRequest{Artifact: artifact})
+	if err != nil {
+		return nil, fmt.Errorf("could not save artifact: %w", err)
+	}
+
+	return artifact, nil
+}
+
+// detectContentType determines the content type of a file by its extension, falling back to sniffing the start of
+// its content.
+func detectContentType(name string, content *bufio.Reader) string {
+	contentType := mime.TypeByExtension(path.Ext(name))
+	if contentType != "" {
+		return contentType
+	}
+
+	// Peek returns what it could read along with the error, which is all that's needed here.
+	head, _ := content.Peek(512)
+	return http.DetectContentType(head)
+}
+
+// handleArtifactExpiry removes the content of artifacts once the pipeline is past their expiry threshold. The record
+// of the artifact is kept so it's clear what was there.
+func (api *API) handleArtifactExpiry(namespace, pipeline string, latestRun int64) {
+	unexpired := []*models.Artifact{}
+	offset := 0
+
+	for {
+		artifacts, err := api.storage.GetAllArtifacts(storage.GetAllArtifactsRequest{
+			NamespaceID:   namespace,
+			PipelineID:    pipeline,
+			UnexpiredOnly: true,
+			Offset:        offset,
+		})
+		if err != nil {
+			log.Error().Err(err).Msg("could not remove old artifacts")
+			return
+		}
+
+		if len(artifacts) == 0 {
+			break
+		}
+
+		unexpired = append(unexpired, artifacts...)
+		offset += len(artifacts)
+	}
+
+	removed := 0
+
+	for _, artifact := range unexpired {
+		if !artifactExpired(artifact, latestRun) {
+			continue
+		}
+
+		err := api.objectStore.DeleteObject(artifact.ObjectKey)
+		if err != nil && !errors.Is(err, objectstore.ErrEntityNotFound) {
+			log.Error().Err(err).Int64("artifact", artifact.ID).Msg("could not remove old artifact")
+			continue
+		}
+
+		artifact.Expired = true
+
+		err = api.storage.UpdateArtifact(storage.UpdateArtifactRequest{Artifact: artifact})
+		if err != nil {
+			log.Error().Err(err).Int64("artifact", artifact.ID).Msg("could not update artifact")
+			continue
+		}
+
+		removed++
+	}
+
+	if removed > 0 {
+		log.Debug().Int("removed_artifacts", removed).Str("namespace", namespace).Str("pipeline", pipeline).
+			Msg("old artifacts removed")
+	}
+}
+
+// artifactExpired returns whether the pipeline has gone through enough runs since the artifact's run for it to
+// expire. Ex. an artifact collected on run #5 with an expiry of 2 expires on run #7.
+func artifactExpired(artifact *models.Artifact, latestRun int64) bool {
+	return latestRun-artifact.RunID >= artifact.Expiry
+}
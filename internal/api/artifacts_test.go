@@ -0,0 +1,54 @@
+package api
+
+import (
+	"bufio"
+	"strings"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestDetectContentType(t *testing.T) {
+	tests := map[string]struct {
+		name     string
+		content  string
+		expected string
+	}{
+		"known extension":           {name: "report.json", content: "not json at all", expected: "application/json"},
+		"unknown extension sniffed": {name: "binary.out", content: "\x7fELF\x02\x01\x01", expected: "application/octet-stream"},
+		"no extension sniffed":      {name: "README", content: "plain words", expected: "text/plain; charset=utf-8"},
+		"empty file":                {name: "empty", content: "", expected: "text/plain; charset=utf-8"},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := detectContentType(tc.name, bufio.NewReader(strings.NewReader(tc.content)))
+			if got != tc.expected {
+				t.Errorf("unexpected content type; want %q; got %q", tc.expected, got)
+			}
+		})
+	}
+}
+
+func TestArtifactExpired(t *testing.T) {
+	tests := map[string]struct {
+		artifact  models.Artifact
+		latestRun int64
+		expected  bool
+	}{
+		"within expiry":  {artifact: models.Artifact{RunID: 5, Expiry: 2}, latestRun: 6, expected: false},
+		"at expiry":      {artifact: models.Artifact{RunID: 5, Expiry: 2}, latestRun: 7, expected: true},
+		"past expiry":    {artifact: models.Artifact{RunID: 5, Expiry: 2}, latestRun: 30, expected: true},
+		"same run":       {artifact: models.Artifact{RunID: 5, Expiry: 1}, latestRun: 5, expected: false},
+		"expires at one": {artifact: models.Artifact{RunID: 5, Expiry: 1}, latestRun: 6, expected: true},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := artifactExpired(&tc.artifact, tc.latestRun)
+			if got != tc.expected {
+				t.Errorf("unexpected expiry for run %d; want %t; got %t", tc.latestRun, tc.expected, got)
+			}
+		})
+	}
+}
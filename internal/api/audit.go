@@ -0,0 +1,226 @@
+package api
+
+import (
+	"context"
+	"strings"
+	"time"
+	"unicode"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+// contextAuditRecord holds the audit record of the current call so that authentication can note who the caller is.
+var contextAuditRecord = goferContextKey("audit_record")
+
+// auditRecord collects the details of a call that are only known during authentication.
+type auditRecord struct {
+	caller    string // The credential the caller authenticated with.
+	namespace string // The namespace the caller's requests default to when one isn't given.
+}
+
+// namespacedRequest and pipelineRequest are implemented by the requests of routes that act on a namespace or
+// pipeline. Routes that act on the namespace or pipeline itself identify it with the request's ID instead.
+type namespacedRequest interface {
+	GetNamespaceId() string
+}
+
+type pipelineRequest interface {
+	GetPipelineId() string
+}
+
+type idRequest interface {
+	GetId() string
+}
+
+func (api *API) auditLogEnabled() bool {
+	return api.config.Permissioning != nil && api.config.Permissioning.AuditLog != nil &&
+		api.config.Permissioning.AuditLog.Enable
+}
+
+// auditUnary records the authorization decision of every unary call in the audit log. It must run before
+// authentication so that calls that fail authentication are recorded as well.
+func (api *API) auditUnary(ctx context.Context, req interface{}, info *grpc.UnaryServerInfo, handler grpc.UnaryHandler) (interface{}, error) {
+	if !api.auditLogEnabled() || isAuthlessMethod(info.FullMethod) {
+		return handler(ctx, req)
+	}
+
+	started := time.Now()
+	record := &auditRecord{}
+
+	resp, err := handler(context.WithValue(ctx, contextAuditRecord, record), req)
+	api.recordAuthzDecision(started, info.FullMethod, record, req, err)
+
+	return resp, err
+}
+
+// auditStream records the authorization decision of every streaming call in the audit log. The resource of the call
+// is taken from the first message the client sends.
+func (api *API) auditStream(srv interface{}, stream grpc.ServerStream, info *grpc.StreamServerInfo, handler grpc.StreamHandler) error {
+	if !api.auditLogEnabled() || isAuthlessMethod(info.FullMethod) {
+		return handler(srv, stream)
+	}
+
+	started := time.Now()
+	record := &auditRecord{}
+
+	audited := &auditedStream{
+		ServerStream: stream,
+		ctx:          context.WithValue(stream.Context(), contextAuditRecord, record),
+	}
+
+	err := handler(srv, audited)
+	api.recordAuthzDecision(started, info.FullMethod, record, audited.request, err)
+
+	return err
+}
+
+// auditedStream remembers the first message received so that it can be used to determine the call's resource.
+type auditedStream struct {
+	grpc.ServerStream
+	ctx     context.Context
+	request interface{}
+}
+
+func (s *auditedStream) Context() context.Context {
+	return s.ctx
+}
+
+func (s *auditedStream) RecvMsg(m interface{}) error {
+	err := s.ServerStream.RecvMsg(m)
+	if err == nil && s.request == nil {
+		s.request = m
+	}
+
+	return err
+}
+
+// recordCaller notes the credential the caller is authenticating with on the call's audit record, if it has one.
+func recordCaller(ctx context.Context, caller string) {
+	record, ok := ctx.Value(contextAuditRecord).(*auditRecord)
+	if !ok {
+		return
+	}
+
+	record.caller = caller
+}
+
+// recordNamespace notes the namespace an authenticated caller's requests default to on the call's audit record, if
+// it has one.
+func recordNamespace(ctx context.Context) {
+	record, ok := ctx.Value(contextAuditRecord).(*auditRecord)
+	if !ok {
+		return
+	}
+
+	record.namespace = determineNamespace(ctx)
+}
+
+func (api *API) recordAuthzDecision(started time.Time, route string, record *auditRecord, req interface{}, err error) {
+	decision := &models.AuthzDecision{
+		Caller:    record.caller,
+		Resource:  requestResource(route, req, record.namespace),
+		Action:    routeAction(route),
+		Route:     route,
+		Allowed:   true,
+		Timestamp: started.UnixMilli(),
+	}
+
+	if code := status.Code(err); code == codes.PermissionDenied || code == codes.Unauthenticated {
+		decision.Allowed = false
+		decision.Reason = status.Convert(err).Message()
+	}
+
+	err = api.storage.AddAuthzDecision(storage.AddAuthzDecisionRequest{AuthzDecision: decision})
+	if err != nil {
+		log.Error().Err(err).Str("route", route).Msg("could not record authorization decision")
+	}
+}
+
+// requestResource returns the namespace and pipeline a request acts on. Ex: "default/simple". Requests that omit the
+// namespace act on the caller's default namespace.
+func requestResource(route string, req interface{}, defaultNamespace string) string {
+	method := route[strings.LastIndex(route, "/")+1:]
+	id, hasID := req.(idRequest)
+
+	namespaced, ok := req.(namespacedRequest)
+	if !ok {
+		if hasID && strings.Contains(method, "Namespace") {
+			return id.GetId()
+		}
+
+		return ""
+	}
+
+	namespace := namespaced.GetNamespaceId()
+	if namespace == "" {
+		namespace = defaultNamespace
+	}
+
+	pipeline := ""
+	if pipelined, ok := req.(pipelineRequest); ok {
+		pipeline = pipelined.GetPipelineId()
+	} else if hasID && strings.Contains(method, "Pipeline") {
+		pipeline = id.GetId()
+	}
+
+	if pipeline == "" {
+		return namespace
+	}
+
+	return namespace + "/" + pipeline
+}
+
+// routeAction returns the leading verb of a route's method name. Ex: "/proto.Gofer/DeletePipeline" returns "delete".
+func routeAction(route string) string {
+	method := route[strings.LastIndex(route, "/")+1:]
+
+	for i, r := range method {
+		if i > 0 && unicode.IsUpper(r) {
+			return strings.ToLower(method[:i])
+		}
+	}
+
+	return strings.ToLower(method)
+}
+
+func isAuthlessMethod(method string) bool {
+	for _, route := range authlessMethods {
+		if method == route {
+			return true
+		}
+	}
+
+	return false
+}
+
+// pruneAuthzDecisionsOnSchedule removes authorization decisions past their retention until the API is shut down.
+func (api *API) pruneAuthzDecisionsOnSchedule() {
+	conf := api.config.Permissioning.AuditLog
+
+	ticker := time.NewTicker(conf.PruneInterval)
+	defer ticker.Stop()
+
+	for {
+		pruned, err := api.storage.DeleteAuthzDecisions(storage.DeleteAuthzDecisionsRequest{
+			Before: time.Now().Add(-conf.Retention).UnixMilli(),
+		})
+		if err != nil {
+			log.Error().Err(err).Msg("could not prune authorization decisions")
+		}
+
+		if pruned > 0 {
+			log.Info().Dur("retention", conf.Retention).Int("total", pruned).Msg("pruned old authorization decisions")
+		}
+
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-ticker.C:
+		}
+	}
+}
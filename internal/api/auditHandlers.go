@@ -0,0 +1,42 @@
+package api
+
+import (
+	"context"
+
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (api *API) ListAuthzDecisions(ctx context.Context, request *proto.ListAuthzDecisionsRequest) (*proto.ListAuthzDecisionsResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.ListAuthzDecisionsResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if !api.auditLogEnabled() {
+		return &proto.ListAuthzDecisionsResponse{}, status.Error(codes.FailedPrecondition, "audit log is not enabled")
+	}
+
+	decisions, err := api.storage.GetAllAuthzDecisions(storage.GetAllAuthzDecisionsRequest{
+		Offset:     int(request.Offset),
+		Limit:      int(request.Limit),
+		Caller:     request.Caller,
+		DeniedOnly: request.DeniedOnly,
+		Since:      request.Since,
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not get authorization decisions")
+		return &proto.ListAuthzDecisionsResponse{}, status.Error(codes.Internal, "failed to retrieve authorization decisions from database")
+	}
+
+	protoDecisions := []*proto.AuthzDecision{}
+	for _, decision := range decisions {
+		protoDecisions = append(protoDecisions, decision.ToProto())
+	}
+
+	return &proto.ListAuthzDecisionsResponse{
+		Decisions: protoDecisions,
+	}, nil
+}
@@ -4,9 +4,11 @@ import (
 	"context"
 	"crypto/rand"
 	"crypto/sha256"
+	"errors"
 	"fmt"
 	"strings"
 
+	"github.com/clintjedwards/gofer/internal/config"
 	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/clintjedwards/gofer/internal/storage"
 	grpc_auth "github.com/grpc-ecosystem/go-grpc-middleware/auth"
@@ -23,13 +25,35 @@ import (
 type goferContextKey string
 
 var (
-	contextUserNamespaces = goferContextKey("namespaces")
-	contextUserKind       = goferContextKey("kind")
+	contextUserNamespaces     = goferContextKey("namespaces")
+	contextUserKind           = goferContextKey("kind")
+	contextUserServiceAccount = goferContextKey("service_account")
+	contextUserAnonymous      = goferContextKey("anonymous")
+	contextUserIdentities     = goferContextKey("identities")
+	contextUserRun            = goferContextKey("run")
 )
 
 var authlessMethods = []string{
 	"/proto.Gofer/BootstrapToken",
 	"/proto.Gofer/GetSystemInfo",
+	"/proto.Gofer/GetAuthConfig",
+	"/proto.Gofer/ExchangeOIDCToken",
+
+	// Extensions authenticate with their own key instead of an API token.
+	"/proto.Gofer/PublishExtensionEvent",
+}
+
+// anonymousMethods are the read-only routes callers without credentials can use to view namespaces that allow
+// anonymous access.
+var anonymousMethods = []string{
+	"/proto.Gofer/GetPipeline",
+	"/proto.Gofer/ListPipelines",
+	"/proto.Gofer/GetRun",
+	"/proto.Gofer/BatchGetRuns",
+	"/proto.Gofer/ListRuns",
+	"/proto.Gofer/GetTaskRun",
+	"/proto.Gofer/ListTaskRuns",
+	"/proto.Gofer/GetTaskRunLogs",
 }
 
 func generateToken(length int) string {
@@ -68,13 +92,22 @@ func getHash(token string) string {
 
 // authenticate is run on every call to verify if the user is allowed to access a given RPC
 func (api *API) authenticate(ctx context.Context) (context.Context, error) {
+	ctx, err := api.authenticateCaller(ctx)
+	if err != nil {
+		return ctx, err
+	}
+
+	recordNamespace(ctx)
+
+	return ctx, nil
+}
+
+func (api *API) authenticateCaller(ctx context.Context) (context.Context, error) {
 	method, _ := grpc.Method(ctx)
 
 	// Exclude routes that don't need authentication
-	for _, route := range authlessMethods {
-		if method == route {
-			return ctx, nil
-		}
+	if isAuthlessMethod(method) {
+		return ctx, nil
 	}
 
 	// If server is in DevMode give context fake admin values
@@ -87,20 +120,218 @@ func (api *API) authenticate(ctx context.Context) (context.Context, error) {
 
 	token, err := grpc_auth.AuthFromMD(ctx, "Bearer")
 	if err != nil {
+		// Callers that didn't include a token might still authenticate with a client certificate.
+		if api.mtlsEnabled() {
+			if cert := verifiedClientCertificate(ctx); cert != nil {
+				return api.authenticateCertificate(ctx, cert)
+			}
+		}
+
+		if api.anonymousAccessEnabled() && isAnonymousMethod(method) {
+			return anonymousContext(ctx), nil
+		}
+
 		return ctx, status.Error(codes.PermissionDenied, "malformed token fmt; should be in form: 'Bearer <token>'")
 	}
 
+	if isJWT(token) {
+		if api.externalJWT != nil && unverifiedIssuer(token) == api.externalJWT.config.Issuer {
+			return api.authenticateExternalJWT(ctx, token)
+		}
+
+		if api.oidc != nil {
+			return api.authenticateOIDC(ctx, token)
+		}
+	}
+
 	storedToken, err := api.getAPIToken(token)
 	if err != nil {
 		return ctx, status.Error(codes.PermissionDenied, "access denied")
 	}
 
+	recordCaller(ctx, models.CallerToken(storedToken.Hash))
+
+	if storedToken.Expired() {
+		return ctx, status.Error(codes.PermissionDenied, "token expired")
+	}
+
+	for _, identity := range tokenIdentities(storedToken) {
+		ctx = withIdentity(ctx, identity)
+	}
+
+	address := callerAddress(ctx, api.trustedProxies)
+	if len(storedToken.AllowedCIDRs) != 0 && !addressAllowed(address, storedToken.AllowedCIDRs) {
+		log.Debug().Str("address", address.String()).Msg("token used from address outside of its allowlist")
+		return ctx, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	api.tokenUsage.record(storedToken.Hash, addressString(address))
+
+	if storedToken.Run != nil {
+		ctx = context.WithValue(ctx, contextUserRun, *storedToken.Run)
+	}
+
+	// Service account tokens always carry the current permissions of their service account.
+	if storedToken.ServiceAccount != "" {
+		return api.serviceAccountContext(ctx, storedToken.ServiceAccount)
+	}
+
 	ctxNamespaces := context.WithValue(ctx, contextUserNamespaces, storedToken.Namespaces)
 	ctxKind := context.WithValue(ctxNamespaces, contextUserKind, string(storedToken.Kind))
 
 	return ctxKind, nil
 }
 
+// serviceAccountContext returns the context of a caller authenticated as the given service account.
+func (api *API) serviceAccountContext(ctx context.Context, id string) (context.Context, error) {
+	account, err := api.storage.GetServiceAccount(storage.GetServiceAccountRequest{
+		ID: id,
+	})
+	if err != nil {
+		log.Debug().Err(err).Str("service_account", id).Msg("could not get service account")
+		return ctx, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	ctx = withIdentity(ctx, models.ServiceAccountInitiator(account.ID))
+
+	ctxAccount := context.WithValue(ctx, contextUserServiceAccount, account.ID)
+	ctxNamespaces := context.WithValue(ctxAccount, contextUserNamespaces, account.Namespaces)
+	ctxKind := context.WithValue(ctxNamespaces, contextUserKind, string(account.Kind))
+
+	return ctxKind, nil
+}
+
+// anonymousContext returns the context of a caller that didn't provide any credentials. Anonymous callers have no
+// namespaces of their own; which namespaces they can view is checked per request by hasReadAccess.
+func anonymousContext(ctx context.Context) context.Context {
+	recordCaller(ctx, models.CallerAnonymous)
+
+	ctxAnonymous := context.WithValue(ctx, contextUserAnonymous, true)
+	ctxNamespaces := context.WithValue(ctxAnonymous, contextUserNamespaces, []string{})
+	ctxKind := context.WithValue(ctxNamespaces, contextUserKind, string(models.TokenKindClient))
+
+	return ctxKind
+}
+
+func (api *API) anonymousAccessEnabled() bool {
+	return api.config.Permissioning != nil && api.config.Permissioning.AllowAnonymous
+}
+
+func isAnonymousMethod(method string) bool {
+	for _, route := range anonymousMethods {
+		if method == route {
+			return true
+		}
+	}
+
+	return false
+}
+
+func isAnonymous(ctx context.Context) bool {
+	anonymous, _ := ctx.Value(contextUserAnonymous).(bool)
+	return anonymous
+}
+
+func (api *API) mtlsEnabled() bool {
+	return api.config.Permissioning != nil && api.config.Permissioning.MTLS != nil &&
+		api.config.Permissioning.MTLS.Enable
+}
+
+// withIdentity adds to the identities the caller is known by. Identities are what pipeline owners are matched
+// against, so only identities that were verified(a directory user, an OIDC or JWT subject, a client certificate or a
+// service account) should ever be added.
+func withIdentity(ctx context.Context, identity string) context.Context {
+	identities, _ := ctx.Value(contextUserIdentities).([]string)
+
+	updated := make([]string, 0, len(identities)+1)
+	updated = append(updated, identities...)
+	updated = append(updated, identity)
+
+	return context.WithValue(ctx, contextUserIdentities, updated)
+}
+
+// callerServiceAccount returns the service account the caller authenticated as; empty if the caller is not a service
+// account.
+func callerServiceAccount(ctx context.Context) string {
+	account, _ := ctx.Value(contextUserServiceAccount).(string)
+	return account
+}
+
+// callerRun returns the run the caller's token was created for, if it was created for one.
+func callerRun(ctx context.Context) (models.TokenRun, bool) {
+	run, present := ctx.Value(contextUserRun).(models.TokenRun)
+	return run, present
+}
+
+// canSpawnTaskRun returns whether the caller may add task runs to the given run. Only the run's own task runs(using
+// the token they were given) and management users can.
+func canSpawnTaskRun(ctx context.Context, namespace, pipeline string, run int64) bool {
+	if isManagementUser(ctx) {
+		return true
+	}
+
+	tokenRun, present := callerRun(ctx)
+	if !present {
+		return false
+	}
+
+	return tokenRun == models.TokenRun{NamespaceID: namespace, PipelineID: pipeline, RunID: run}
+}
+
+// runInitiator returns the initiator recorded on runs started by the caller.
+func runInitiator(ctx context.Context) string {
+	account := callerServiceAccount(ctx)
+	if account == "" {
+		return ""
+	}
+
+	return models.ServiceAccountInitiator(account)
+}
+
+// groupPermissions returns the combined permissions of all the mapped groups a user belongs to. Returns false if the
+// user belongs to no mapped groups.
+func groupPermissions(mappings []config.GroupMapping, groups []string) (kind models.TokenKind, namespaces []string, ok bool) {
+	return combinedPermissions(matchingGroupMappings(mappings, groups))
+}
+
+// matchingGroupMappings returns the mappings of the groups a user belongs to.
+func matchingGroupMappings(mappings []config.GroupMapping, groups []string) []config.GroupMapping {
+	matching := []config.GroupMapping{}
+
+	for _, mapping := range mappings {
+		for _, group := range groups {
+			if group == mapping.Group {
+				matching = append(matching, mapping)
+			}
+		}
+	}
+
+	return matching
+}
+
+// combinedPermissions returns the combined permissions of the given mappings. Returns false if there are none.
+//
+// If the mappings are of different kinds, the most privileged kind wins.
+func combinedPermissions(mappings []config.GroupMapping) (kind models.TokenKind, namespaces []string, ok bool) {
+	kind = models.TokenKindClient
+	namespaces = []string{}
+
+	for _, mapping := range mappings {
+		ok = true
+		switch {
+		case strings.EqualFold(mapping.Kind, string(models.TokenKindManagement)):
+			kind = models.TokenKindManagement
+		case strings.EqualFold(mapping.Kind, string(models.TokenKindNamespaceAdmin)):
+			if kind != models.TokenKindManagement {
+				kind = models.TokenKindNamespaceAdmin
+			}
+		}
+		namespaces = append(namespaces, mapping.Namespaces...)
+	}
+
+	return kind, namespaces, ok
+}
+
 // hasAccess is a convenience function for common routes that checks first for management key and then
 // if the namespace is valid.
 func hasAccess(ctx context.Context, namespace string) bool {
@@ -111,6 +342,26 @@ func hasAccess(ctx context.Context, namespace string) bool {
 	return hasNamespaceAccess(ctx, namespace)
 }
 
+// hasReadAccess returns whether the caller is able to view the pipelines, runs and logs of the given namespace. All
+// authenticated callers are able to view every namespace unless namespace isolation is enabled, in which case they're
+// only able to view the namespaces they have access to. Anonymous callers are only able to view namespaces that allow
+// anonymous access.
+func (api *API) hasReadAccess(ctx context.Context, namespace string) bool {
+	if !isAnonymous(ctx) {
+		return api.namespaceVisible(ctx, namespace)
+	}
+
+	storedNamespace, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: namespace})
+	if err != nil {
+		if !errors.Is(err, storage.ErrEntityNotFound) {
+			log.Error().Err(err).Str("namespace", namespace).Msg("could not get namespace")
+		}
+		return false
+	}
+
+	return storedNamespace.AllowAnonymous && storedNamespace.Deleted == 0
+}
+
 func hasNamespaceAccess(ctx context.Context, namespace string) bool {
 	namespaces, present := ctx.Value(contextUserNamespaces).([]string)
 	if !present {
@@ -135,3 +386,88 @@ func isManagementUser(ctx context.Context) bool {
 
 	return strings.EqualFold(kind, string(models.TokenKindManagement))
 }
+
+// isNamespaceAdmin returns whether the caller is able to manage the given namespace. Management users are able to
+// manage all namespaces.
+func isNamespaceAdmin(ctx context.Context, namespace string) bool {
+	if isManagementUser(ctx) {
+		return true
+	}
+
+	kind, present := ctx.Value(contextUserKind).(string)
+	if !present {
+		log.Error().Msg("kind field missing from context in request")
+		return false
+	}
+
+	if !strings.EqualFold(kind, string(models.TokenKindNamespaceAdmin)) {
+		return false
+	}
+
+	return hasNamespaceAccess(ctx, namespace)
+}
+
+// tokenIdentities returns the identities the holder of the given token has proven. Only identities Gofer verified
+// itself count; metadata is whatever the creator of the token chose to attach and is never treated as an identity.
+func tokenIdentities(token *models.Token) []string {
+	identities := []string{models.CallerToken(token.Hash)}
+
+	if token.LDAPUser != "" {
+		identities = append(identities, models.CallerUser(token.LDAPUser))
+	}
+
+	if token.OIDCSubject != "" {
+		identities = append(identities, models.CallerOIDC(token.OIDCSubject))
+	}
+
+	return identities
+}
+
+// canModifyPipeline returns whether the caller is able to deploy new configuration to or abandon the given pipeline.
+// Protected pipelines can only be modified by their owners and admins of their namespace.
+func canModifyPipeline(ctx context.Context, pipeline *models.Pipeline) bool {
+	if !pipeline.Protected {
+		return true
+	}
+
+	if isNamespaceAdmin(ctx, pipeline.Namespace) {
+		return true
+	}
+
+	identities, _ := ctx.Value(contextUserIdentities).([]string)
+	for _, owner := range pipeline.Owners {
+		for _, identity := range identities {
+			if owner == identity {
+				return true
+			}
+		}
+	}
+
+	return false
+}
+
+// canDelegate returns whether the caller is able to hand out the given permissions to others(ex. by creating a
+// token). Management users can hand out anything; namespace admins can hand out anything but management access for
+// the namespaces they manage.
+func canDelegate(ctx context.Context, kind models.TokenKind, namespaces []string) bool {
+	if isManagementUser(ctx) {
+		return true
+	}
+
+	callerKind, _ := ctx.Value(contextUserKind).(string)
+	if !strings.EqualFold(callerKind, string(models.TokenKindNamespaceAdmin)) {
+		return false
+	}
+
+	if kind == models.TokenKindManagement {
+		return false
+	}
+
+	for _, namespace := range namespaces {
+		if !isNamespaceAdmin(ctx, namespace) {
+			return false
+		}
+	}
+
+	return true
+}
@@ -0,0 +1,361 @@
+package api
+
+import (
+	"context"
+	"crypto/x509"
+	"net"
+	"net/url"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/google/go-cmp/cmp"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/peer"
+)
+
+func TestGroupPermissions(t *testing.T) {
+	mappings := []config.GroupMapping{
+		{Group: "admins", Kind: "management"},
+		{Group: "team-a", Namespaces: []string{"team-a"}},
+		{Group: "team-b", Kind: "client", Namespaces: []string{"team-b", "shared"}},
+		{Group: "team-c-leads", Kind: "namespace_admin", Namespaces: []string{"team-c"}},
+	}
+
+	kind, namespaces, ok := groupPermissions(mappings, []string{"team-a", "team-b", "unmapped"})
+	if !ok {
+		t.Fatal("expected user to be granted access")
+	}
+	if kind != models.TokenKindClient {
+		t.Errorf("expected kind %q; got %q", models.TokenKindClient, kind)
+	}
+	if diff := cmp.Diff([]string{"team-a", "team-b", "shared"}, namespaces); diff != "" {
+		t.Errorf("unexpected namespaces (-want +got):\n%s", diff)
+	}
+
+	kind, _, _ = groupPermissions(mappings, []string{"admins"})
+	if kind != models.TokenKindManagement {
+		t.Errorf("expected kind %q; got %q", models.TokenKindManagement, kind)
+	}
+
+	kind, _, _ = groupPermissions(mappings, []string{"team-a", "team-c-leads"})
+	if kind != models.TokenKindNamespaceAdmin {
+		t.Errorf("expected kind %q; got %q", models.TokenKindNamespaceAdmin, kind)
+	}
+
+	kind, _, _ = groupPermissions(mappings, []string{"admins", "team-c-leads"})
+	if kind != models.TokenKindManagement {
+		t.Errorf("expected kind %q; got %q", models.TokenKindManagement, kind)
+	}
+
+	_, _, ok = groupPermissions(mappings, []string{"unmapped"})
+	if ok {
+		t.Errorf("expected user without mapped groups to be denied access")
+	}
+}
+
+func TestCanDelegate(t *testing.T) {
+	userContext := func(kind models.TokenKind, namespaces ...string) context.Context {
+		ctx := context.WithValue(context.Background(), contextUserNamespaces, namespaces)
+		return context.WithValue(ctx, contextUserKind, string(kind))
+	}
+
+	tests := map[string]struct {
+		ctx        context.Context
+		kind       models.TokenKind
+		namespaces []string
+		want       bool
+	}{
+		"management can delegate management": {
+			ctx:  userContext(models.TokenKindManagement),
+			kind: models.TokenKindManagement, want: true,
+		},
+		"namespace admin can delegate own namespaces": {
+			ctx:  userContext(models.TokenKindNamespaceAdmin, "team-a", "team-b"),
+			kind: models.TokenKindClient, namespaces: []string{"team-a"}, want: true,
+		},
+		"namespace admin can delegate namespace admin": {
+			ctx:  userContext(models.TokenKindNamespaceAdmin, "team-a"),
+			kind: models.TokenKindNamespaceAdmin, namespaces: []string{"team-a"}, want: true,
+		},
+		"namespace admin cannot delegate other namespaces": {
+			ctx:  userContext(models.TokenKindNamespaceAdmin, "team-a"),
+			kind: models.TokenKindClient, namespaces: []string{"team-a", "team-b"}, want: false,
+		},
+		"namespace admin cannot delegate management": {
+			ctx:  userContext(models.TokenKindNamespaceAdmin, "team-a"),
+			kind: models.TokenKindManagement, namespaces: []string{"team-a"}, want: false,
+		},
+		"client cannot delegate": {
+			ctx:  userContext(models.TokenKindClient, "team-a"),
+			kind: models.TokenKindClient, namespaces: []string{"team-a"}, want: false,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := canDelegate(test.ctx, test.kind, test.namespaces)
+			if got != test.want {
+				t.Errorf("expected %v; got %v", test.want, got)
+			}
+		})
+	}
+}
+
+func TestCanSpawnTaskRun(t *testing.T) {
+	userContext := func(kind models.TokenKind, run *models.TokenRun) context.Context {
+		ctx := context.WithValue(context.Background(), contextUserNamespaces, []string{"team-a"})
+		ctx = context.WithValue(ctx, contextUserKind, string(kind))
+		if run != nil {
+			ctx = context.WithValue(ctx, contextUserRun, *run)
+		}
+		return ctx
+	}
+
+	runToken := &models.TokenRun{NamespaceID: "team-a", PipelineID: "build", RunID: 3}
+
+	tests := map[string]struct {
+		ctx      context.Context
+		pipeline string
+		run      int64
+		want     bool
+	}{
+		"run token can add to its own run": {
+			ctx:      userContext(models.TokenKindClient, runToken),
+			pipeline: "build", run: 3, want: true,
+		},
+		"run token cannot add to another run": {
+			ctx:      userContext(models.TokenKindClient, runToken),
+			pipeline: "build", run: 4, want: false,
+		},
+		"run token cannot add to another pipeline": {
+			ctx:      userContext(models.TokenKindClient, runToken),
+			pipeline: "deploy", run: 3, want: false,
+		},
+		"client with namespace access cannot add": {
+			ctx:      userContext(models.TokenKindClient, nil),
+			pipeline: "build", run: 3, want: false,
+		},
+		"namespace admin cannot add": {
+			ctx:      userContext(models.TokenKindNamespaceAdmin, nil),
+			pipeline: "build", run: 3, want: false,
+		},
+		"management can add": {
+			ctx:      userContext(models.TokenKindManagement, nil),
+			pipeline: "build", run: 3, want: true,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := canSpawnTaskRun(test.ctx, "team-a", test.pipeline, test.run)
+			if got != test.want {
+				t.Errorf("expected %v; got %v", test.want, got)
+			}
+		})
+	}
+}
+
+func TestCanModifyPipeline(t *testing.T) {
+	userContext := func(kind models.TokenKind, identities ...string) context.Context {
+		ctx := context.WithValue(context.Background(), contextUserNamespaces, []string{"team-a"})
+		ctx = context.WithValue(ctx, contextUserKind, string(kind))
+		for _, identity := range identities {
+			ctx = withIdentity(ctx, identity)
+		}
+		return ctx
+	}
+
+	protected := &models.Pipeline{
+		Namespace: "team-a",
+		Owners:    []string{"user:alice", "service_account:release-bot"},
+		Protected: true,
+	}
+
+	tests := map[string]struct {
+		ctx      context.Context
+		pipeline *models.Pipeline
+		want     bool
+	}{
+		"anyone can modify unprotected pipelines": {
+			ctx:      userContext(models.TokenKindClient, "user:bob"),
+			pipeline: &models.Pipeline{Namespace: "team-a", Owners: []string{"user:alice"}}, want: true,
+		},
+		"owner can modify protected pipeline": {
+			ctx:      userContext(models.TokenKindClient, "token:abc", "user:alice"),
+			pipeline: protected, want: true,
+		},
+		"service account owner can modify protected pipeline": {
+			ctx:      userContext(models.TokenKindClient, "token:abc", "service_account:release-bot"),
+			pipeline: protected, want: true,
+		},
+		"namespace admin can modify protected pipeline": {
+			ctx:      userContext(models.TokenKindNamespaceAdmin, "user:carol"),
+			pipeline: protected, want: true,
+		},
+		"non-owner cannot modify protected pipeline": {
+			ctx:      userContext(models.TokenKindClient, "token:abc", "user:bob"),
+			pipeline: protected, want: false,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := canModifyPipeline(test.ctx, test.pipeline)
+			if got != test.want {
+				t.Errorf("expected %v; got %v", test.want, got)
+			}
+		})
+	}
+}
+
+func TestTokenIdentitiesModifyPipeline(t *testing.T) {
+	protected := &models.Pipeline{
+		Namespace: "team-a",
+		Owners:    []string{"user:alice", "oidc:alice"},
+		Protected: true,
+	}
+
+	tests := map[string]struct {
+		token *models.Token
+		want  bool
+	}{
+		"token with a spoofed name is denied": {
+			token: &models.Token{Hash: "abc", Metadata: map[string]string{"name": "alice"}}, want: false,
+		},
+		"directory user token is allowed": {
+			token: &models.Token{Hash: "abc", LDAPUser: "alice"}, want: true,
+		},
+		"oidc token is allowed": {
+			token: &models.Token{Hash: "abc", OIDCSubject: "alice"}, want: true,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			ctx := context.WithValue(context.Background(), contextUserNamespaces, []string{"team-a"})
+			ctx = context.WithValue(ctx, contextUserKind, string(models.TokenKindClient))
+			for _, identity := range tokenIdentities(test.token) {
+				ctx = withIdentity(ctx, identity)
+			}
+
+			got := canModifyPipeline(ctx, protected)
+			if got != test.want {
+				t.Errorf("expected %v; got %v", test.want, got)
+			}
+		})
+	}
+}
+
+func TestCertificateMapping(t *testing.T) {
+	mappings := []config.CertificateMapping{
+		{SAN: "spiffe://example.com/ci-runner", ServiceAccount: "ci-runner"},
+		{SAN: "deployer.example.com", Kind: "client", Namespaces: []string{"team-a"}},
+	}
+
+	runnerURI, _ := url.Parse("spiffe://example.com/ci-runner")
+
+	mapping, ok := certificateMapping(mappings, &x509.Certificate{URIs: []*url.URL{runnerURI}})
+	if !ok || mapping.ServiceAccount != "ci-runner" {
+		t.Errorf("expected certificate to map to service account %q; got %+v", "ci-runner", mapping)
+	}
+
+	mapping, ok = certificateMapping(mappings, &x509.Certificate{DNSNames: []string{"other.example.com", "deployer.example.com"}})
+	if !ok || mapping.SAN != "deployer.example.com" {
+		t.Errorf("expected certificate to map to %q; got %+v", "deployer.example.com", mapping)
+	}
+
+	_, ok = certificateMapping(mappings, &x509.Certificate{DNSNames: []string{"other.example.com"}})
+	if ok {
+		t.Errorf("expected certificate without mapped names to be denied access")
+	}
+}
+
+func TestCallerAddress(t *testing.T) {
+	trustedProxies, err := parseCIDRs([]string{"10.0.0.0/8"})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	callerContext := func(remote string, forwardedFor ...string) context.Context {
+		ctx := peer.NewContext(context.Background(), &peer.Peer{Addr: &net.TCPAddr{IP: net.ParseIP(remote), Port: 443}})
+		if len(forwardedFor) == 0 {
+			return ctx
+		}
+		return metadata.NewIncomingContext(ctx, metadata.Pairs("x-forwarded-for", forwardedFor[0]))
+	}
+
+	tests := map[string]struct {
+		ctx  context.Context
+		want string
+	}{
+		"direct caller":                  {ctx: callerContext("192.0.2.10"), want: "192.0.2.10"},
+		"untrusted proxy header ignored": {ctx: callerContext("192.0.2.10", "198.51.100.7"), want: "192.0.2.10"},
+		"trusted proxy":                  {ctx: callerContext("10.0.0.5", "198.51.100.7"), want: "198.51.100.7"},
+		"spoofed header before proxy":    {ctx: callerContext("10.0.0.5", "203.0.113.1, 198.51.100.7, 10.0.0.6"), want: "198.51.100.7"},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := callerAddress(test.ctx, trustedProxies)
+			if got.String() != test.want {
+				t.Errorf("expected address %q; got %q", test.want, got)
+			}
+		})
+	}
+
+	if !addressAllowed(net.ParseIP("198.51.100.7"), []string{"198.51.100.0/24"}) {
+		t.Errorf("expected address within allowlist to be allowed")
+	}
+
+	if addressAllowed(net.ParseIP("203.0.113.1"), []string{"198.51.100.0/24"}) {
+		t.Errorf("expected address outside allowlist to be denied")
+	}
+}
+
+func TestAuditResource(t *testing.T) {
+	tests := map[string]struct {
+		route    string
+		request  interface{}
+		resource string
+		action   string
+	}{
+		"pipeline route": {
+			route:    "/proto.Gofer/DisablePipeline",
+			request:  &proto.DisablePipelineRequest{NamespaceId: "team-a", Id: "simple"},
+			resource: "team-a/simple",
+			action:   "disable",
+		},
+		"run route": {
+			route:    "/proto.Gofer/GetRun",
+			request:  &proto.GetRunRequest{PipelineId: "simple", Id: 1},
+			resource: "default/simple",
+			action:   "get",
+		},
+		"namespace route": {
+			route:    "/proto.Gofer/UpdateNamespace",
+			request:  &proto.UpdateNamespaceRequest{Id: "team-a"},
+			resource: "team-a",
+			action:   "update",
+		},
+		"unscoped route": {
+			route:    "/proto.Gofer/CreateToken",
+			request:  &proto.CreateTokenRequest{},
+			resource: "",
+			action:   "create",
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			if resource := requestResource(test.route, test.request, "default"); resource != test.resource {
+				t.Errorf("expected resource %q; got %q", test.resource, resource)
+			}
+
+			if action := routeAction(test.route); action != test.action {
+				t.Errorf("expected action %q; got %q", test.action, action)
+			}
+		})
+	}
+}
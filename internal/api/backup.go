@@ -0,0 +1,137 @@
+package api
+
+import (
+	"archive/tar"
+	"bufio"
+	"compress/gzip"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/clintjedwards/gofer/proto"
+)
+
+// The names of the database files within a backup tarball.
+const (
+	BackupStorageFile     = "storage.db"
+	BackupSecretStoreFile = "secret_store.db"
+	BackupObjectStoreFile = "object_store.db"
+)
+
+// backupChunkSize is the size of the chunks a backup is streamed to the client in.
+const backupChunkSize = 1024 * 1024
+
+type backupSource struct {
+	name   string
+	backup func(io.Writer) error
+}
+
+// writeBackup writes a gzipped tarball containing a copy of each database to the writer. Each database is first
+// copied to a temporary file since the size of each file needs to be known before it can be added to the tarball.
+func (api *API) writeBackup(w io.Writer, includeObjects bool) error {
+	sources := []backupSource{
+		{BackupStorageFile, api.storage.Backup},
+		{BackupSecretStoreFile, api.secretStore.Backup},
+	}
+
+	if includeObjects {
+		sources = append(sources, backupSource{BackupObjectStoreFile, api.objectStore.Backup})
+	}
+
+	tmpDir, err := os.MkdirTemp("", "gofer-backup-")
+	if err != nil {
+		return err
+	}
+	defer os.RemoveAll(tmpDir)
+
+	gzipWriter := gzip.NewWriter(w)
+	tarWriter := tar.NewWriter(gzipWriter)
+
+	for _, source := range sources {
+		path := filepath.Join(tmpDir, source.name)
+
+		err := backupToFile(path, source.backup)
+		if err != nil {
+			return fmt.Errorf("could not back up %s: %w", source.name, err)
+		}
+
+		err = addFileToTar(tarWriter, source.name, path)
+		if err != nil {
+			return fmt.Errorf("could not add %s to backup: %w", source.name, err)
+		}
+
+		// Remove each copy as soon as it's been sent so that we don't hold a copy of every database on disk at once.
+		_ = os.Remove(path)
+	}
+
+	if err := tarWriter.Close(); err != nil {
+		return err
+	}
+
+	return gzipWriter.Close()
+}
+
+func backupToFile(path string, backup func(io.Writer) error) error {
+	file, err := os.OpenFile(path, os.O_CREATE|os.O_WRONLY|os.O_TRUNC, 0600)
+	if err != nil {
+		return err
+	}
+	defer file.Close()
+
+	err = backup(file)
+	if err != nil {
+		return err
+	}
+
+	return file.Sync()
+}
+
+func addFileToTar(tarWriter *tar.Writer, name, path string) error {
+	file, err := os.Open(path)
+	if err != nil {
+		return err
+	}
+	defer file.Close()
+
+	stat, err := file.Stat()
+	if err != nil {
+		return err
+	}
+
+	err = tarWriter.WriteHeader(&tar.Header{
+		Name:    name,
+		Mode:    0600,
+		Size:    stat.Size(),
+		ModTime: time.Now(),
+	})
+	if err != nil {
+		return err
+	}
+
+	_, err = io.Copy(tarWriter, file)
+	return err
+}
+
+// backupStreamWriter sends everything written to it to the client as backup chunks.
+type backupStreamWriter struct {
+	stream proto.Gofer_CreateBackupServer
+}
+
+func (w *backupStreamWriter) Write(p []byte) (int, error) {
+	err := w.stream.Send(&proto.CreateBackupResponse{
+		Chunk: p,
+	})
+	if err != nil {
+		return 0, err
+	}
+
+	return len(p), nil
+}
+
+// newBackupStreamWriter returns a writer that buffers writes into evenly sized chunks before sending them. The
+// returned writer needs to be flushed once writing is done.
+func newBackupStreamWriter(stream proto.Gofer_CreateBackupServer) *bufio.Writer {
+	return bufio.NewWriterSize(&backupStreamWriter{stream: stream}, backupChunkSize)
+}
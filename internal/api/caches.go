@@ -0,0 +1,387 @@
+package api
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"path"
+	"regexp"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
+	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// CacheKeyFmt is the key of a single path of a cache within its pipeline. Caches aren't listed among the pipeline's
+// objects so they're never evicted by the pipeline object limit; they have a budget of their own instead.
+const CacheKeyFmt = "cache:%d:%s:%s:%d" // cache:runid:taskrunid:name:pathindex
+
+// ErrCacheOverBudget is returned when a cache is larger than the whole cache budget of its pipeline.
+var ErrCacheOverBudget = errors.New("api: cache is larger than the pipeline's cache budget")
+
+// cacheKeyVariable matches the references to run variables within a cache key. Ex: "go-modules-{{ BRANCH }}"
+var cacheKeyVariable = regexp.MustCompile(`{{\s*([A-Za-z0-9_]+)\s*}}`)
+
+// expandCacheKey fills in the run variables referenced by a cache key. Variables the run doesn't have are left empty.
+func expandCacheKey(key string, vars map[string]string) string {
+	return cacheKeyVariable.ReplaceAllStringFunc(key, func(reference string) string {
+		return vars[cacheKeyVariable.FindStringSubmatch(reference)[1]]
+	})
+}
+
+// taskRunCache follows a cache of a task run from being restored before the task run starts to being saved after it
+// succeeds.
+type taskRunCache struct {
+	declared models.TaskCache
+	key      string        // The declared key with the run's variables filled in.
+	restored *models.Cache // The cache that was restored; nil if there was none to restore.
+}
+
+// getAllCaches returns every cache of the pipeline, most recently saved first.
+func (api *API) getAllCaches(namespace, pipeline string) ([]*models.Cache, error) {
+	caches := []*models.Cache{}
+	offset := 0
+
+	for {
+		page, err := api.storage.GetAllCaches(storage.GetAllCachesRequest{
+			NamespaceID: namespace,
+			PipelineID:  pipeline,
+			Offset:      offset,
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		if len(page) == 0 {
+			break
+		}
+
+		caches = append(caches, page...)
+		offset += len(page)
+	}
+
+	sort.SliceStable(caches, func(i, j int) bool { return caches[i].Created > caches[j].Created })
+
+	return caches, nil
+}
+
+// matchCache returns the cache to restore for a key out of caches ordered from most recently saved. The cache saved
+// under the key itself wins, after that the restore keys are tried in order with the most recently saved cache whose
+// key starts with one of them being restored.
+func matchCache(caches []*models.Cache, key string, restoreKeys []string) *models.Cache {
+	for _, cache := range caches {
+		if cache.Key == key {
+			return cache
+		}
+	}
+
+	for _, prefix := range restoreKeys {
+		for _, cache := range caches {
+			if strings.HasPrefix(cache.Key, prefix) {
+				return cache
+			}
+		}
+	}
+
+	return nil
+}
+
+// restoreCaches finds the caches a task run asks for and opens their content so that it can be put in place before
+// the task run's container starts. A task run always starts, with or without its caches; anything that goes wrong is
+// only logged. The returned function closes the content and must be called once the container has started.
+func (api *API) restoreCaches(taskRun *models.TaskRun, vars map[string]string) ([]*taskRunCache, []scheduler.ContainerFiles, func()) {
+	caches := []*taskRunCache{}
+	files := []scheduler.ContainerFiles{}
+	readers := []io.Closer{}
+
+	closeAll := func() {
+		for _, reader := range readers {
+			reader.Close()
+		}
+	}
+
+	if len(taskRun.Task.Caches) == 0 {
+		return caches, files, closeAll
+	}
+
+	saved, err := api.getAllCaches(taskRun.NamespaceID, taskRun.PipelineID)
+	if err != nil {
+		log.Error().Err(err).Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("correlation_id", taskRun.CorrelationID).Msg("could not get caches; task run starts without them")
+		saved = []*models.Cache{}
+	}
+
+	for _, declared := range taskRun.Task.Caches {
+		cache := &taskRunCache{
+			declared: declared,
+			key:      expandCacheKey(declared.Key, vars),
+		}
+		caches = append(caches, cache)
+
+		restoreKeys := []string{}
+		for _, restoreKey := range declared.RestoreKeys {
+			restoreKeys = append(restoreKeys, expandCacheKey(restoreKey, vars))
+		}
+
+		match := matchCache(saved, cache.key, restoreKeys)
+		if match == nil {
+			log.Debug().Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+				Str("cache", declared.Name).Str("key", cache.key).Msg("no cache to restore")
+			continue
+		}
+
+		declaredPaths := map[string]struct{}{}
+		for _, declaredPath := range declared.Paths {
+			declaredPaths[declaredPath] = struct{}{}
+		}
+
+		for _, cachePath := range match.Paths {
+			// Paths the task no longer asks for are left out.
+			if _, exists := declaredPaths[cachePath.Path]; !exists {
+				continue
+			}
+
+			reader, _, err := api.objectStore.GetObjectStream(cachePath.ObjectKey, 0)
+			if err != nil {
+				log.Warn().Err(err).Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).
+					Int64("run", taskRun.RunID).Str("cache", declared.Name).Str("path", cachePath.Path).
+					Msg("could not read cache content; path not restored")
+				continue
+			}
+			readers = append(readers, reader)
+
+			// Archives are named relative to the parent of the path they were copied from.
+			files = append(files, scheduler.ContainerFiles{
+				Path:    path.Dir(path.Clean(cachePath.Path)),
+				Archive: reader,
+			})
+		}
+
+		cache.restored = match
+
+		match.LastUsed = time.Now().UnixMilli()
+		err := api.storage.UpdateCache(storage.UpdateCacheRequest{Cache: match})
+		if err != nil {
+			log.Error().Err(err).Int64("cache", match.ID).Msg("could not update cache")
+		}
+
+		log.Info().Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("cache", declared.Name).Str("key", cache.key).Str("restored_key", match.Key).
+			Str("correlation_id", taskRun.CorrelationID).Msg("restored cache")
+	}
+
+	return caches, files, closeAll
+}
+
+// saveCaches saves the caches of a task run that succeeded. Caches restored from their exact key are left as they
+// are; saving them again would only store what is already there.
+func (api *API) saveCaches(ctx context.Context, schedulerID string, taskRun *models.TaskRun, caches []*taskRunCache) {
+	for _, cache := range caches {
+		if cache.restored != nil && cache.restored.Key == cache.key {
+			continue
+		}
+
+		saved, err := api.saveCache(ctx, schedulerID, taskRun, cache)
+		if err != nil {
+			logger := log.Error()
+			if errors.Is(err, scheduler.ErrNoSuchFile) || errors.Is(err, ErrQuotaExceeded) ||
+				errors.Is(err, ErrCacheOverBudget) {
+				logger = log.Warn()
+			}
+
+			logger.Err(err).Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+				Str("cache", cache.declared.Name).Str("key", cache.key).Str("correlation_id", taskRun.CorrelationID).
+				Msg("could not save cache")
+			continue
+		}
+
+		log.Info().Str("task", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("cache", cache.declared.Name).Str("key", cache.key).Int64("size", saved.Size).
+			Str("correlation_id", taskRun.CorrelationID).Msg("saved cache")
+
+		api.enforceCacheBudget(taskRun.NamespaceID, taskRun.PipelineID, saved.ID)
+	}
+}
+
+func (api *API) saveCache(ctx context.Context, schedulerID string, taskRun *models.TaskRun,
+	cache *taskRunCache,
+) (*models.Cache, error) {
+	err := api.checkObjectQuota(taskRun.NamespaceID, 0)
+	if err != nil {
+		return nil, err
+	}
+
+	saved := &models.Cache{
+		NamespaceID: taskRun.NamespaceID,
+		PipelineID:  taskRun.PipelineID,
+		Key:         cache.key,
+		Paths:       []models.CachePath{},
+	}
+
+	for index, cachePath := range cache.declared.Paths {
+		copied, err := scheduler.NewTracedEngine(ctx, api.scheduler).CopyFromContainer(scheduler.CopyFromContainerRequest{
+			SchedulerID: schedulerID,
+			Path:        cachePath,
+		})
+		if err != nil {
+			// Tools don't always create the directories they cache into; there's simply nothing to save then.
+			if errors.Is(err, scheduler.ErrNoSuchFile) {
+				continue
+			}
+
+			api.removeCacheContent(saved, nil)
+			return nil, fmt.Errorf("could not copy %q from container: %w", cachePath, err)
+		}
+
+		key := pipelineObjectKey(taskRun.NamespaceID, taskRun.PipelineID,
+			fmt.Sprintf(CacheKeyFmt, taskRun.RunID, taskRun.ID, cache.declared.Name, index))
+
+		size, err := api.objectStore.PutObjectStream(key, copied.Archive, true)
+		copied.Archive.Close()
+		if err != nil {
+			api.removeCacheContent(saved, nil)
+			return nil, fmt.Errorf("could not store %q: %w", cachePath, err)
+		}
+
+		saved.Paths = append(saved.Paths, models.CachePath{Path: cachePath, ObjectKey: key, Size: size})
+		saved.Size += size
+	}
+
+	if len(saved.Paths) == 0 {
+		return nil, fmt.Errorf("none of the cache's paths exist: %w", scheduler.ErrNoSuchFile)
+	}
+
+	budget := api.config.ObjectStore.CacheBudgetMB * 1024 * 1024
+	if budget > 0 && saved.Size > budget {
+		api.removeCacheContent(saved, nil)
+		return nil, fmt.Errorf("%w; cache is %d bytes and the budget is %d bytes", ErrCacheOverBudget, saved.Size,
+			budget)
+	}
+
+	now := time.Now().UnixMilli()
+	saved.Created = now
+	saved.LastUsed = now
+
+	existing, err := api.storage.GetCache(storage.GetCacheRequest{
+		NamespaceID: saved.NamespaceID,
+		PipelineID:  saved.PipelineID,
+		Key:         saved.Key,
+	})
+	if err != nil {
+		if !errors.Is(err, storage.ErrEntityNotFound) {
+			api.removeCacheContent(saved, nil)
+			return nil, fmt.Errorf("could not get cache: %w", err)
+		}
+
+		err = api.storage.AddCache(storage.AddCacheRequest{Cache: saved})
+		if err != nil {
+			api.removeCacheContent(saved, nil)
+			return nil, fmt.Errorf("could not save cache: %w", err)
+		}
+
+		return saved, nil
+	}
+
+	// Another task run saved the same key first; the newer content replaces it.
+	saved.ID = existing.ID
+	err = api.storage.UpdateCache(storage.UpdateCacheRequest{Cache: saved})
+	if err != nil {
+		api.removeCacheContent(saved, nil)
+		return nil, fmt.Errorf("could not save cache: %w", err)
+	}
+
+	api.removeCacheContent(existing, saved)
+
+	return saved, nil
+}
+
+// removeCacheContent removes the content of a cache's paths from the object store, leaving out any content that's
+// shared with the cache that replaced it.
+func (api *API) removeCacheContent(cache *models.Cache, replacement *models.Cache) {
+	keep := map[string]struct{}{}
+	if replacement != nil {
+		for _, cachePath := range replacement.Paths {
+			keep[cachePath.ObjectKey] = struct{}{}
+		}
+	}
+
+	for _, cachePath := range cache.Paths {
+		if _, exists := keep[cachePath.ObjectKey]; exists {
+			continue
+		}
+
+		err := api.objectStore.DeleteObject(cachePath.ObjectKey)
+		if err != nil && !errors.Is(err, objectstore.ErrEntityNotFound) {
+			log.Error().Err(err).Str("key", cachePath.ObjectKey).Msg("could not remove cache content")
+		}
+	}
+}
+
+// enforceCacheBudget removes the least recently used caches of a pipeline until its caches fit within the cache
+// budget. The given cache, the one just saved, is always kept.
+func (api *API) enforceCacheBudget(namespace, pipeline string, keep int64) {
+	budget := api.config.ObjectStore.CacheBudgetMB * 1024 * 1024
+	if budget <= 0 {
+		return
+	}
+
+	caches, err := api.getAllCaches(namespace, pipeline)
+	if err != nil {
+		log.Error().Err(err).Str("namespace", namespace).Str("pipeline", pipeline).Msg("could not enforce cache budget")
+		return
+	}
+
+	for _, cache := range cachesOverBudget(caches, budget, keep) {
+		err := api.storage.DeleteCache(storage.DeleteCacheRequest{
+			NamespaceID: namespace,
+			PipelineID:  pipeline,
+			ID:          cache.ID,
+		})
+		if err != nil && !errors.Is(err, storage.ErrEntityNotFound) {
+			log.Error().Err(err).Int64("cache", cache.ID).Msg("could not remove cache")
+			continue
+		}
+
+		api.removeCacheContent(cache, nil)
+
+		log.Debug().Str("namespace", namespace).Str("pipeline", pipeline).Str("key", cache.Key).
+			Int64("size", cache.Size).Int64("budget", budget).Msg("removed least recently used cache")
+	}
+}
+
+// cachesOverBudget returns the caches to remove, least recently used first, for the rest to fit within the budget.
+// The cache with the given ID is never returned.
+func cachesOverBudget(caches []*models.Cache, budget int64, keep int64) []*models.Cache {
+	total := int64(0)
+	for _, cache := range caches {
+		total += cache.Size
+	}
+
+	leastRecentlyUsed := make([]*models.Cache, len(caches))
+	copy(leastRecentlyUsed, caches)
+	sort.SliceStable(leastRecentlyUsed, func(i, j int) bool {
+		return leastRecentlyUsed[i].LastUsed < leastRecentlyUsed[j].LastUsed
+	})
+
+	remove := []*models.Cache{}
+	for _, cache := range leastRecentlyUsed {
+		if total <= budget {
+			break
+		}
+
+		if cache.ID == keep {
+			continue
+		}
+
+		remove = append(remove, cache)
+		total -= cache.Size
+	}
+
+	return remove
+}
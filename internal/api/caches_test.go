@@ -0,0 +1,111 @@
+package api
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestExpandCacheKey(t *testing.T) {
+	vars := map[string]string{
+		"BRANCH":       "main",
+		"GOFER_RUN_ID": "5",
+	}
+
+	tests := map[string]struct {
+		key      string
+		expected string
+	}{
+		"no variables":     {key: "go-modules", expected: "go-modules"},
+		"single variable":  {key: "go-modules-{{ BRANCH }}", expected: "go-modules-main"},
+		"no spaces":        {key: "go-modules-{{BRANCH}}", expected: "go-modules-main"},
+		"many variables":   {key: "{{ BRANCH }}-{{ GOFER_RUN_ID }}", expected: "main-5"},
+		"missing variable": {key: "go-modules-{{ TAG }}", expected: "go-modules-"},
+		"not a reference":  {key: "go-modules-{ BRANCH }", expected: "go-modules-{ BRANCH }"},
+		"shell style":      {key: "go-modules-$BRANCH", expected: "go-modules-$BRANCH"},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := expandCacheKey(tc.key, vars)
+			if got != tc.expected {
+				t.Errorf("unexpected key; want %q; got %q", tc.expected, got)
+			}
+		})
+	}
+}
+
+func TestMatchCache(t *testing.T) {
+	// Ordered from most recently saved.
+	caches := []*models.Cache{
+		{ID: 4, Key: "go-modules-feature-abc"},
+		{ID: 3, Key: "go-modules-main-def"},
+		{ID: 2, Key: "go-modules-main-abc"},
+		{ID: 1, Key: "node-modules-main"},
+	}
+
+	tests := map[string]struct {
+		key         string
+		restoreKeys []string
+		expected    int64
+	}{
+		"exact key":                {key: "go-modules-main-abc", restoreKeys: []string{"go-modules-"}, expected: 2},
+		"newest prefix match":      {key: "go-modules-main-xyz", restoreKeys: []string{"go-modules-main-"}, expected: 3},
+		"restore keys in order":    {key: "go-modules-dev-xyz", restoreKeys: []string{"go-modules-dev-", "go-modules-"}, expected: 4},
+		"no match":                 {key: "rust-target", restoreKeys: []string{"rust-"}, expected: 0},
+		"no restore keys no match": {key: "go-modules-main", expected: 0},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := matchCache(caches, tc.key, tc.restoreKeys)
+
+			gotID := int64(0)
+			if got != nil {
+				gotID = got.ID
+			}
+
+			if gotID != tc.expected {
+				t.Errorf("unexpected cache; want %d; got %d", tc.expected, gotID)
+			}
+		})
+	}
+}
+
+func TestCachesOverBudget(t *testing.T) {
+	caches := []*models.Cache{
+		{ID: 3, Size: 40, LastUsed: 300},
+		{ID: 2, Size: 40, LastUsed: 100},
+		{ID: 1, Size: 40, LastUsed: 200},
+	}
+
+	tests := map[string]struct {
+		budget   int64
+		keep     int64
+		expected []int64
+	}{
+		"within budget":             {budget: 120, keep: 3, expected: []int64{}},
+		"least recently used first": {budget: 80, keep: 3, expected: []int64{2}},
+		"many removed":              {budget: 40, keep: 3, expected: []int64{2, 1}},
+		"kept cache never removed":  {budget: 40, keep: 2, expected: []int64{1, 3}},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := []int64{}
+			for _, cache := range cachesOverBudget(caches, tc.budget, tc.keep) {
+				got = append(got, cache.ID)
+			}
+
+			if len(got) != len(tc.expected) {
+				t.Fatalf("unexpected caches removed; want %v; got %v", tc.expected, got)
+			}
+
+			for i := range got {
+				if got[i] != tc.expected[i] {
+					t.Fatalf("unexpected caches removed; want %v; got %v", tc.expected, got)
+				}
+			}
+		})
+	}
+}
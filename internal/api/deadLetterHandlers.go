@@ -0,0 +1,125 @@
+package api
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (api *API) ListDeadLetters(ctx context.Context, request *proto.ListDeadLettersRequest) (*proto.ListDeadLettersResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.ListDeadLettersResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	deadLetters, err := api.storage.GetAllDeadLetters(storage.GetAllDeadLettersRequest{
+		Offset: int(request.Offset),
+		Limit:  int(request.Limit),
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not get dead letters")
+		return &proto.ListDeadLettersResponse{}, status.Error(codes.Internal, "failed to retrieve dead letters from database")
+	}
+
+	protoDeadLetters := []*proto.DeadLetter{}
+	for _, deadLetter := range deadLetters {
+		protoDeadLetters = append(protoDeadLetters, deadLetter.ToProto())
+	}
+
+	return &proto.ListDeadLettersResponse{
+		DeadLetters: protoDeadLetters,
+	}, nil
+}
+
+func (api *API) GetDeadLetter(ctx context.Context, request *proto.GetDeadLetterRequest) (*proto.GetDeadLetterResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.GetDeadLetterResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.Id == 0 {
+		return &proto.GetDeadLetterResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	deadLetter, err := api.storage.GetDeadLetter(storage.GetDeadLetterRequest{ID: request.Id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.GetDeadLetterResponse{}, status.Error(codes.NotFound, "dead letter not found")
+		}
+		log.Error().Err(err).Int64("id", request.Id).Msg("could not get dead letter")
+		return &proto.GetDeadLetterResponse{}, status.Error(codes.Internal, "failed to retrieve dead letter from database")
+	}
+
+	return &proto.GetDeadLetterResponse{
+		DeadLetter: deadLetter.ToProto(),
+	}, nil
+}
+
+func (api *API) RedriveDeadLetter(ctx context.Context, request *proto.RedriveDeadLetterRequest) (*proto.RedriveDeadLetterResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.RedriveDeadLetterResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.Id == 0 {
+		return &proto.RedriveDeadLetterResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	deadLetter, err := api.storage.GetDeadLetter(storage.GetDeadLetterRequest{ID: request.Id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.RedriveDeadLetterResponse{}, status.Error(codes.NotFound, "dead letter not found")
+		}
+		log.Error().Err(err).Int64("id", request.Id).Msg("could not get dead letter")
+		return &proto.RedriveDeadLetterResponse{}, status.Error(codes.Internal, "failed to retrieve dead letter from database")
+	}
+
+	redriveErr := api.redriveDeadLetter(deadLetter)
+	if redriveErr != nil {
+		deadLetter.Attempts++
+		deadLetter.Error = webhookDeliveryError(redriveErr)
+		deadLetter.Updated = time.Now().UnixMilli()
+
+		err = api.storage.UpdateDeadLetter(storage.UpdateDeadLetterRequest{DeadLetter: deadLetter})
+		if err != nil {
+			log.Error().Err(err).Int64("id", request.Id).Msg("could not update dead letter")
+		}
+
+		return &proto.RedriveDeadLetterResponse{}, status.Errorf(codes.Unavailable, "could not redrive dead letter: %s", deadLetter.Error)
+	}
+
+	err = api.storage.DeleteDeadLetter(storage.DeleteDeadLetterRequest{ID: request.Id})
+	if err != nil {
+		log.Error().Err(err).Int64("id", request.Id).Msg("could not delete redriven dead letter")
+		return &proto.RedriveDeadLetterResponse{}, status.Errorf(codes.Internal, "dead letter was redriven but could not be removed: %v", err)
+	}
+
+	log.Info().Int64("id", request.Id).Str("kind", string(deadLetter.Kind)).Str("target", deadLetter.Target).
+		Msg("redrove dead letter")
+
+	return &proto.RedriveDeadLetterResponse{}, nil
+}
+
+func (api *API) DeleteDeadLetter(ctx context.Context, request *proto.DeleteDeadLetterRequest) (*proto.DeleteDeadLetterResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.DeleteDeadLetterResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.Id == 0 {
+		return &proto.DeleteDeadLetterResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	err := api.storage.DeleteDeadLetter(storage.DeleteDeadLetterRequest{ID: request.Id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.DeleteDeadLetterResponse{}, status.Error(codes.NotFound, "dead letter not found")
+		}
+		log.Error().Err(err).Int64("id", request.Id).Msg("could not delete dead letter")
+		return &proto.DeleteDeadLetterResponse{}, status.Errorf(codes.Internal, "could not delete dead letter: %v", err)
+	}
+
+	return &proto.DeleteDeadLetterResponse{}, nil
+}
@@ -0,0 +1,85 @@
+package api
+
+import (
+	"encoding/json"
+	"fmt"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/eventsink"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// addDeadLetter stores a dispatch that failed after being retried so that it can be sent again later.
+func (api *API) addDeadLetter(deadLetter *models.DeadLetter, dispatchErr error) {
+	now := time.Now().UnixMilli()
+
+	// Dead letters are readable through the API, so the body of a failed response is never part of them.
+	deadLetter.Error = webhookDeliveryError(dispatchErr)
+	deadLetter.Created = now
+	deadLetter.Updated = now
+
+	err := api.storage.AddDeadLetter(storage.AddDeadLetterRequest{DeadLetter: deadLetter})
+	if err != nil {
+		log.Error().Err(err).Str("kind", string(deadLetter.Kind)).Str("target", deadLetter.Target).
+			Str("pipeline", deadLetter.PipelineID).Msg("could not save dead letter; dispatch has been dropped")
+		return
+	}
+
+	log.Warn().Err(dispatchErr).Int64("id", deadLetter.ID).Str("kind", string(deadLetter.Kind)).
+		Str("target", deadLetter.Target).Str("pipeline", deadLetter.PipelineID).
+		Msg("dispatch failed; saved as dead letter")
+}
+
+// redriveDeadLetter attempts a dead letter's dispatch once more.
+func (api *API) redriveDeadLetter(deadLetter *models.DeadLetter) error {
+	switch deadLetter.Kind {
+	case models.DeadLetterKindRunNotification, models.DeadLetterKindDigest:
+		// Dead letters are replayed against the address they were meant for, which gets the same checks as when it was
+		// first sent to.
+		client := newWebhookClient(parseNetworks(api.config.WebhookAllowedNetworks))
+		_, err := eventsink.Post(client, deadLetter.Target, "application/json", nil, []byte(deadLetter.Payload))
+		return err
+
+	case models.DeadLetterKindTriggerSubscription:
+		pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{
+			NamespaceID: deadLetter.NamespaceID,
+			ID:          deadLetter.PipelineID,
+		})
+		if err != nil {
+			return fmt.Errorf("could not get pipeline; %w", err)
+		}
+
+		// The subscription is made from the pipeline's current configuration since it may have changed since the
+		// dispatch failed.
+		subscription, exists := pipeline.Triggers[deadLetter.Target]
+		if !exists {
+			return fmt.Errorf("pipeline no longer has trigger %q", deadLetter.Target)
+		}
+
+		err = api.subscribeTrigger(pipeline.Namespace, pipeline.ID, &subscription)
+		if err != nil {
+			return err
+		}
+
+		subscription.State = models.PipelineTriggerStateActive
+		pipeline.Triggers[subscription.Label] = subscription
+
+		return api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: pipeline})
+
+	default:
+		return fmt.Errorf("dead letters of kind %q can not be redriven", deadLetter.Kind)
+	}
+}
+
+// triggerSubscriptionPayload returns the JSON recorded for a failed trigger subscription. The configuration is kept
+// as written in the pipeline; secrets are never interpolated into it.
+func triggerSubscriptionPayload(subscription models.PipelineTriggerConfig) string {
+	payload, err := json.Marshal(subscription)
+	if err != nil {
+		return ""
+	}
+
+	return string(payload)
+}
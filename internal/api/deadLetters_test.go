@@ -0,0 +1,33 @@
+package api
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestRedriveDeadLetterRefusesInternalAddresses(t *testing.T) {
+	reached := false
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		reached = true
+	}))
+	defer server.Close()
+
+	api := &API{config: &config.API{}}
+
+	err := api.redriveDeadLetter(&models.DeadLetter{
+		Kind:    models.DeadLetterKindRunNotification,
+		Target:  server.URL,
+		Payload: "{}",
+	})
+	if err == nil {
+		t.Fatal("expected redrive to a loopback address to be refused")
+	}
+
+	if reached {
+		t.Error("expected dead letter not to be sent")
+	}
+}
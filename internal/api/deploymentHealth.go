@@ -0,0 +1,174 @@
+package api
+
+import (
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// deploymentRollbackPollInterval is how often a failed deployment checks whether the pipeline's remaining runs have
+// finished so that it can be rolled back.
+const deploymentRollbackPollInterval = 5 * time.Second
+
+// startDeploymentHealthChecks counts every completed run towards the health check of the config version its pipeline
+// most recently deployed. Deployments that fail their check are rolled back to the config version used before them.
+func (api *API) startDeploymentHealthChecks() error {
+	subscription, err := api.events.Subscribe(models.CompletedRunEvent)
+	if err != nil {
+		return err
+	}
+
+	go func() {
+		defer api.events.Unsubscribe(subscription)
+
+		for {
+			select {
+			case <-api.context.ctx.Done():
+				return
+			case event := <-subscription.Events:
+				evt, ok := event.(*models.EventCompletedRun)
+				if !ok {
+					continue
+				}
+
+				api.checkDeploymentHealth(evt)
+			}
+		}
+	}()
+
+	return nil
+}
+
+func (api *API) checkDeploymentHealth(event *models.EventCompletedRun) {
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{
+		NamespaceID: event.NamespaceID,
+		ID:          event.PipelineID,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", event.PipelineID).Msg("could not get pipeline to check deployment health")
+		return
+	}
+
+	deployment := pipeline.Deployment
+	if deployment == nil {
+		return
+	}
+
+	run, err := api.storage.GetRun(storage.GetRunRequest{
+		NamespaceID: event.NamespaceID,
+		PipelineID:  event.PipelineID,
+		ID:          event.RunID,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", event.PipelineID).Int64("run", event.RunID).
+			Msg("could not get run to check deployment health")
+		return
+	}
+
+	// Runs started before the deployment or that tried out a canary didn't use the deployed config version.
+	if run.Started < deployment.Started || run.CanaryVersion != 0 {
+		return
+	}
+
+	result := deployment.Record(event.State)
+	if result != models.DeploymentResultPending {
+		pipeline.Deployment = nil
+	}
+
+	err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: pipeline})
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", event.PipelineID).Msg("could not update deployment health")
+		return
+	}
+
+	switch result {
+	case models.DeploymentResultHealthy:
+		log.Info().Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+			Int64("version", deployment.Version).Msg("config deployment passed its health check")
+	case models.DeploymentResultFailed:
+		log.Warn().Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+			Int64("version", deployment.Version).Int64("run", run.ID).
+			Msg("config deployment failed its health check; rolling back")
+		go api.rollBackDeployment(pipeline.Namespace, pipeline.ID, *deployment, run.ID)
+	}
+}
+
+// rollBackDeployment disables the pipeline, waits for its remaining runs to finish and then deploys the config version
+// that was used before the failed deployment. If the rollback can't be finished the pipeline is left disabled so that
+// the failed deployment stops running.
+func (api *API) rollBackDeployment(namespace, id string, deployment models.PipelineDeployment, runID int64) {
+	logger := log.With().Str("namespace", namespace).Str("pipeline", id).Int64("from_version", deployment.Version).
+		Int64("to_version", deployment.PreviousVersion).Logger()
+
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: id})
+	if err != nil {
+		logger.Error().Err(err).Msg("could not get pipeline to roll back")
+		return
+	}
+
+	if pipeline.State == models.PipelineStateActive {
+		pipeline.State = models.PipelineStateDisabled
+
+		err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: pipeline})
+		if err != nil {
+			logger.Error().Err(err).Msg("could not disable pipeline to roll back")
+			return
+		}
+
+		api.events.Publish(models.NewEventDisabledPipeline(*pipeline))
+	}
+
+	for api.hasActiveRuns(namespace, id) {
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-time.After(deploymentRollbackPollInterval):
+		}
+	}
+
+	version, err := api.storage.GetPipelineConfigVersion(storage.GetPipelineConfigVersionRequest{
+		NamespaceID: namespace,
+		PipelineID:  id,
+		Version:     deployment.PreviousVersion,
+	})
+	if err != nil {
+		logger.Error().Err(err).Msg("could not get config version to roll back to; pipeline left disabled")
+		return
+	}
+
+	pipeline, err = api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: id})
+	if err != nil {
+		logger.Error().Err(err).Msg("could not get pipeline to roll back")
+		return
+	}
+
+	// Someone deployed something else while we were waiting; theirs wins.
+	if pipeline.ConfigVersion != deployment.Version {
+		logger.Info().Msg("pipeline config changed while waiting to roll back; skipping rollback")
+		return
+	}
+
+	updatedPipeline, err := api.updatePipelineFromConfig(version.Location, namespace, id, &version.Config)
+	if err != nil {
+		logger.Error().Err(err).Msg("could not roll back pipeline config; pipeline left disabled")
+		return
+	}
+
+	// The version rolled back to already proved itself before it was replaced.
+	updatedPipeline.Deployment = nil
+	updatedPipeline.State = models.PipelineStateActive
+
+	err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: updatedPipeline})
+	if err != nil {
+		logger.Error().Err(err).Msg("could not enable pipeline after rolling back; pipeline left disabled")
+		return
+	}
+
+	api.events.Publish(models.NewEventEnabledPipeline(*updatedPipeline))
+	api.events.Publish(models.NewEventRolledBackPipeline(namespace, id, runID, deployment.Version,
+		deployment.PreviousVersion))
+
+	logger.Info().Msg("rolled back failed config deployment")
+}
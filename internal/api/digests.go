@@ -0,0 +1,267 @@
+package api
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// digestCounts tallies runs by their state.
+type digestCounts struct {
+	Runs       int64 `json:"runs"`
+	Succeeded  int64 `json:"succeeded"`
+	Failed     int64 `json:"failed"`
+	Cancelled  int64 `json:"cancelled"`
+	InProgress int64 `json:"in_progress"`
+}
+
+func (c *digestCounts) add(state models.RunState) {
+	c.Runs++
+
+	switch state {
+	case models.RunSuccess:
+		c.Succeeded++
+	case models.RunFailed:
+		c.Failed++
+	case models.RunCancelled:
+		c.Cancelled++
+	default:
+		c.InProgress++
+	}
+}
+
+type digestPipeline struct {
+	PipelineID string `json:"pipeline_id"`
+	digestCounts
+	FailedRuns []int64 `json:"failed_runs"` // IDs of the pipeline's failed runs.
+}
+
+// runDigest is the body posted to a digest's webhooks.
+type runDigest struct {
+	Digest      string           `json:"digest"`
+	NamespaceID string           `json:"namespace_id"`
+	Period      string           `json:"period"`
+	From        int64            `json:"from"` // Start of the period covered in epoch milli.
+	To          int64            `json:"to"`   // End of the period covered in epoch milli.
+	Summary     string           `json:"summary"`
+	Totals      digestCounts     `json:"totals"`
+	Pipelines   []digestPipeline `json:"pipelines"` // Only pipelines that had runs are included.
+}
+
+// nextDigestTime returns the first time after now that the digest should be sent.
+func nextDigestTime(digest config.Digest, now time.Time) time.Time {
+	now = now.UTC()
+	next := time.Date(now.Year(), now.Month(), now.Day(), digest.Hour, 0, 0, 0, time.UTC)
+
+	days := 1
+	if strings.ToLower(digest.Period) == "weekly" {
+		days = 7
+
+		weekday := time.Monday
+		if digest.Weekday != "" {
+			weekday = config.Weekdays[strings.ToLower(digest.Weekday)]
+		}
+
+		next = next.AddDate(0, 0, (int(weekday)-int(next.Weekday())+7)%7)
+	}
+
+	if !next.After(now) {
+		next = next.AddDate(0, 0, days)
+	}
+
+	return next
+}
+
+// digestPeriodStart returns the start of the period a digest sent at the given time covers.
+func digestPeriodStart(digest config.Digest, sent time.Time) time.Time {
+	if strings.ToLower(digest.Period) == "weekly" {
+		return sent.AddDate(0, 0, -7)
+	}
+
+	return sent.AddDate(0, 0, -1)
+}
+
+// startDigests sends each configured digest on its schedule. Digests that come due while the server is down are not
+// sent once it's back up.
+func (api *API) startDigests() {
+	conf := api.config.Digests
+	if conf == nil || len(conf.Digests) == 0 {
+		return
+	}
+
+	client := newWebhookClient(parseNetworks(api.config.WebhookAllowedNetworks))
+
+	for _, digest := range conf.Digests {
+		go func(digest config.Digest) {
+			for {
+				next := nextDigestTime(digest, time.Now())
+
+				select {
+				case <-api.context.ctx.Done():
+					return
+				case <-time.After(time.Until(next)):
+				}
+
+				api.sendDigest(client, digest, digestPeriodStart(digest, next), next)
+			}
+		}(digest)
+	}
+}
+
+func (api *API) sendDigest(client *http.Client, digest config.Digest, from, to time.Time) {
+	summary, err := api.summarizeRuns(digest, from, to)
+	if err != nil {
+		log.Error().Err(err).Str("digest", digest.Name).Msg("could not summarize runs for digest")
+		return
+	}
+
+	body, err := json.Marshal(summary)
+	if err != nil {
+		log.Error().Err(err).Str("digest", digest.Name).Msg("could not encode digest")
+		return
+	}
+
+	for _, webhook := range digest.Webhooks {
+		go api.sendNotification(client, webhook, models.DeadLetter{
+			Kind:        models.DeadLetterKindDigest,
+			NamespaceID: digest.Namespace,
+		}, body)
+	}
+
+	log.Info().Str("digest", digest.Name).Str("namespace", digest.Namespace).Int64("runs", summary.Totals.Runs).
+		Msg("sent digest")
+}
+
+// summarizeRuns tallies the runs of every pipeline in the digest's namespace that started within the given period.
+func (api *API) summarizeRuns(digest config.Digest, from, to time.Time) (*runDigest, error) {
+	summary := &runDigest{
+		Digest:      digest.Name,
+		NamespaceID: digest.Namespace,
+		Period:      strings.ToLower(digest.Period),
+		From:        from.UnixMilli(),
+		To:          to.UnixMilli(),
+		Pipelines:   []digestPipeline{},
+	}
+
+	offset := 0
+	for {
+		pipelines, err := api.storage.GetAllPipelines(storage.GetAllPipelinesRequest{
+			Offset:      offset,
+			NamespaceID: digest.Namespace,
+		})
+		if err != nil {
+			return nil, fmt.Errorf("could not get pipelines; %w", err)
+		}
+
+		if len(pipelines) == 0 {
+			break
+		}
+
+		for _, pipeline := range pipelines {
+			pipelineSummary, err := api.summarizePipelineRuns(pipeline, from, to)
+			if err != nil {
+				return nil, err
+			}
+
+			if pipelineSummary.Runs == 0 {
+				continue
+			}
+
+			summary.Pipelines = append(summary.Pipelines, *pipelineSummary)
+		}
+
+		offset += len(pipelines)
+	}
+
+	sort.Slice(summary.Pipelines, func(i, j int) bool {
+		return summary.Pipelines[i].PipelineID < summary.Pipelines[j].PipelineID
+	})
+
+	failing := []string{}
+	for _, pipeline := range summary.Pipelines {
+		summary.Totals.Runs += pipeline.Runs
+		summary.Totals.Succeeded += pipeline.Succeeded
+		summary.Totals.Failed += pipeline.Failed
+		summary.Totals.Cancelled += pipeline.Cancelled
+		summary.Totals.InProgress += pipeline.InProgress
+
+		if pipeline.Failed > 0 {
+			failing = append(failing, pipeline.PipelineID)
+		}
+	}
+
+	summary.Summary = formatDigestSummary(summary, failing)
+
+	return summary, nil
+}
+
+func (api *API) summarizePipelineRuns(pipeline *models.Pipeline, from, to time.Time) (*digestPipeline, error) {
+	summary := &digestPipeline{
+		PipelineID: pipeline.ID,
+		FailedRuns: []int64{},
+	}
+
+	// Runs are returned newest first so we can stop as soon as we reach runs started before the period.
+	offset := 0
+	for {
+		runs, err := api.storage.GetAllRuns(storage.GetAllRunsRequest{
+			Offset:      offset,
+			NamespaceID: pipeline.Namespace,
+			PipelineID:  pipeline.ID,
+		})
+		if err != nil {
+			return nil, fmt.Errorf("could not get runs for pipeline %q; %w", pipeline.ID, err)
+		}
+
+		if len(runs) == 0 {
+			return summary, nil
+		}
+
+		for _, run := range runs {
+			if run.Started < from.UnixMilli() {
+				return summary, nil
+			}
+
+			if run.Started >= to.UnixMilli() {
+				continue
+			}
+
+			summary.add(run.State)
+			if run.State == models.RunFailed {
+				summary.FailedRuns = append(summary.FailedRuns, run.ID)
+			}
+		}
+
+		offset += len(runs)
+	}
+}
+
+// formatDigestSummary returns a one line description of the digest meant for chat messages.
+func formatDigestSummary(summary *runDigest, failing []string) string {
+	period := "day"
+	if summary.Period == "weekly" {
+		period = "week"
+	}
+
+	if summary.Totals.Runs == 0 {
+		return fmt.Sprintf("%s: no runs over the last %s.", summary.NamespaceID, period)
+	}
+
+	text := fmt.Sprintf("%s: %d runs over the last %s; %d succeeded, %d failed, %d cancelled, %d in progress.",
+		summary.NamespaceID, summary.Totals.Runs, period, summary.Totals.Succeeded, summary.Totals.Failed,
+		summary.Totals.Cancelled, summary.Totals.InProgress)
+
+	if len(failing) > 0 {
+		text += fmt.Sprintf(" Pipelines with failures: %s.", strings.Join(failing, ", "))
+	}
+
+	return text
+}
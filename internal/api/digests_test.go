@@ -0,0 +1,48 @@
+package api
+
+import (
+	"testing"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+)
+
+func TestNextDigestTime(t *testing.T) {
+	// Wednesday
+	now := time.Date(2022, time.June, 15, 10, 30, 0, 0, time.UTC)
+
+	tests := map[string]struct {
+		digest config.Digest
+		want   time.Time
+	}{
+		"daily later today": {
+			digest: config.Digest{Period: "daily", Hour: 17},
+			want:   time.Date(2022, time.June, 15, 17, 0, 0, 0, time.UTC),
+		},
+		"daily already sent today": {
+			digest: config.Digest{Period: "daily", Hour: 9},
+			want:   time.Date(2022, time.June, 16, 9, 0, 0, 0, time.UTC),
+		},
+		"weekly defaults to monday": {
+			digest: config.Digest{Period: "weekly", Hour: 9},
+			want:   time.Date(2022, time.June, 20, 9, 0, 0, 0, time.UTC),
+		},
+		"weekly later today": {
+			digest: config.Digest{Period: "weekly", Hour: 12, Weekday: "Wednesday"},
+			want:   time.Date(2022, time.June, 15, 12, 0, 0, 0, time.UTC),
+		},
+		"weekly already sent today": {
+			digest: config.Digest{Period: "weekly", Hour: 9, Weekday: "wednesday"},
+			want:   time.Date(2022, time.June, 22, 9, 0, 0, 0, time.UTC),
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := nextDigestTime(tc.digest, now)
+			if !got.Equal(tc.want) {
+				t.Errorf("want %s; got %s", tc.want, got)
+			}
+		})
+	}
+}
@@ -0,0 +1,79 @@
+package api
+
+import (
+	"errors"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+// eventConsumerPrefix separates the cursors of event consumers from the cursors of event sinks.
+const eventConsumerPrefix = "consumer:"
+
+// eventCursor returns the cursor with the given ID. Cursors that don't exist yet are created after the most recent
+// event so that new consumers only receive events published from then on.
+func (api *API) eventCursor(id string) (*models.EventCursor, error) {
+	cursor, err := api.storage.GetEventCursor(storage.GetEventCursorRequest{ID: id})
+	if err == nil {
+		return cursor, nil
+	}
+
+	if !errors.Is(err, storage.ErrEntityNotFound) {
+		return nil, err
+	}
+
+	cursor = &models.EventCursor{
+		ID:      id,
+		Updated: time.Now().UnixMilli(),
+	}
+
+	latestID, err := api.latestEventID()
+	if err != nil {
+		return nil, err
+	}
+
+	cursor.EventID = latestID
+
+	err = api.storage.UpdateEventCursor(storage.UpdateEventCursorRequest{EventCursor: cursor})
+	if err != nil {
+		return nil, err
+	}
+
+	return cursor, nil
+}
+
+// latestEventID returns the ID of the most recently published event or 0 if there are no events.
+func (api *API) latestEventID() (int64, error) {
+	latest, err := api.storage.GetAllEvents(storage.GetAllEventsRequest{Limit: 1, Reverse: true})
+	if err != nil {
+		return 0, err
+	}
+
+	if len(latest) == 0 {
+		return 0, nil
+	}
+
+	return latest[0].GetID(), nil
+}
+
+// acknowledgeEvent moves an event consumer's cursor forward to the given event. Acknowledging an event that is at or
+// before the consumer's current position does nothing so that consumers can safely acknowledge out of order.
+func (api *API) acknowledgeEvent(consumer string, id int64) error {
+	api.eventConsumersMu.Lock()
+	defer api.eventConsumersMu.Unlock()
+
+	cursor, err := api.storage.GetEventCursor(storage.GetEventCursorRequest{ID: eventConsumerPrefix + consumer})
+	if err != nil {
+		return err
+	}
+
+	if id <= cursor.EventID {
+		return nil
+	}
+
+	cursor.EventID = id
+	cursor.Updated = time.Now().UnixMilli()
+
+	return api.storage.UpdateEventCursor(storage.UpdateEventCursorRequest{EventCursor: cursor})
+}
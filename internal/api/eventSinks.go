@@ -0,0 +1,136 @@
+package api
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/eventsink"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+const (
+	// eventSinkPollInterval is how often sinks check for new events even if they haven't been told of any.
+	eventSinkPollInterval = 30 * time.Second
+
+	// eventSinkMinRetryInterval is how long a failing sink initially waits before retrying.
+	eventSinkMinRetryInterval = time.Second
+)
+
+// startEventSinks begins delivering events to all configured sinks. Every sink is delivered to separately so that a
+// slow or unavailable sink only falls behind itself; it never holds up the event bus or the other sinks.
+func (api *API) startEventSinks() error {
+	conf := api.config.EventSinks
+	if conf == nil || len(conf.Sinks) == 0 {
+		return nil
+	}
+
+	wakeups := []chan struct{}{}
+
+	for _, sinkConfig := range conf.Sinks {
+		sink, err := eventsink.New(sinkConfig)
+		if err != nil {
+			return fmt.Errorf("could not init sink %q: %w", sinkConfig.Name, err)
+		}
+
+		wakeup := make(chan struct{}, 1)
+		wakeups = append(wakeups, wakeup)
+
+		go api.deliverToEventSink(sinkConfig.Name, sink, wakeup)
+	}
+
+	subscription, err := api.events.Subscribe(models.AnyEvent)
+	if err != nil {
+		return err
+	}
+
+	// Wake sinks as events are published. Sinks read events from storage themselves so waking them never blocks.
+	go func() {
+		defer api.events.Unsubscribe(subscription)
+
+		for {
+			select {
+			case <-api.context.ctx.Done():
+				return
+			case <-subscription.Events:
+				for _, wakeup := range wakeups {
+					select {
+					case wakeup <- struct{}{}:
+					default:
+					}
+				}
+			}
+		}
+	}()
+
+	return nil
+}
+
+// deliverToEventSink sends events to a sink in order until the API is shut down. Events are read from storage after
+// the last event the sink acknowledged, so events that fail to be delivered are retried until they succeed.
+func (api *API) deliverToEventSink(name string, sink eventsink.Sink, wakeup <-chan struct{}) {
+	defer sink.Close() // nolint: errcheck
+
+	cursor, err := api.eventCursor("sink:" + name)
+	if err != nil {
+		log.Error().Err(err).Str("sink", name).Msg("could not get event sink position; sink disabled")
+		return
+	}
+
+	retryInterval := eventSinkMinRetryInterval
+
+	for {
+		events, err := api.storage.GetAllEvents(storage.GetAllEventsRequest{
+			Offset: int(cursor.EventID),
+			Limit:  api.config.EventSinks.BatchSize,
+		})
+		if err != nil {
+			log.Error().Err(err).Str("sink", name).Msg("could not get events for event sink")
+		}
+
+		if len(events) == 0 {
+			select {
+			case <-api.context.ctx.Done():
+				return
+			case <-wakeup:
+			case <-time.After(eventSinkPollInterval):
+			}
+			continue
+		}
+
+		if first := events[0].GetID(); first > cursor.EventID+1 && cursor.EventID != 0 {
+			log.Warn().Str("sink", name).Int64("from", cursor.EventID+1).Int64("to", first-1).
+				Msg("events were pruned before they could be delivered to event sink")
+		}
+
+		err = sink.Send(events)
+		if err != nil {
+			log.Error().Err(err).Str("sink", name).Dur("retry_in", retryInterval).
+				Msg("could not deliver events to event sink")
+
+			select {
+			case <-api.context.ctx.Done():
+				return
+			case <-time.After(retryInterval):
+			}
+
+			retryInterval *= 2
+			if retryInterval > api.config.EventSinks.MaxRetryInterval {
+				retryInterval = api.config.EventSinks.MaxRetryInterval
+			}
+			continue
+		}
+
+		retryInterval = eventSinkMinRetryInterval
+
+		cursor.EventID = events[len(events)-1].GetID()
+		cursor.Updated = time.Now().UnixMilli()
+
+		err = api.storage.UpdateEventCursor(storage.UpdateEventCursorRequest{EventCursor: cursor})
+		if err != nil {
+			// The events will be delivered again which is allowed by the at-least-once guarantee.
+			log.Error().Err(err).Str("sink", name).Msg("could not save event sink position")
+		}
+	}
+}
@@ -2,11 +2,15 @@ package api
 
 import (
 	"context"
+	"crypto/subtle"
 	"errors"
+	"strings"
 
 	"github.com/clintjedwards/gofer/internal/eventbus"
 	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
 	"github.com/clintjedwards/gofer/proto"
+	grpc_auth "github.com/grpc-ecosystem/go-grpc-middleware/auth"
 	"github.com/rs/zerolog/log"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
@@ -26,6 +30,11 @@ func (api *API) GetEvent(ctx context.Context, request *proto.GetEventRequest) (*
 		return &proto.GetEventResponse{}, status.Error(codes.Internal, "failed to retrieve event from database")
 	}
 
+	// Events the caller isn't allowed to see are reported as missing so that their existence isn't given away.
+	if !api.eventVisible(ctx, event) {
+		return &proto.GetEventResponse{}, status.Error(codes.FailedPrecondition, "event not found")
+	}
+
 	switch evt := event.(type) {
 	case *models.EventCreatedNamespace:
 		return &proto.GetEventResponse{
@@ -62,6 +71,13 @@ func (api *API) GetEvent(ctx context.Context, request *proto.GetEventRequest) (*
 				AbandonedPipelineEvent: evt.ToProto(),
 			},
 		}, nil
+	case *models.EventRolledBackPipeline:
+		return &proto.GetEventResponse{
+			Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+			Event: &proto.GetEventResponse_RolledBackPipelineEvent{
+				RolledBackPipelineEvent: evt.ToProto(),
+			},
+		}, nil
 	case *models.EventStartedRun:
 		return &proto.GetEventResponse{
 			Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
@@ -118,6 +134,27 @@ func (api *API) GetEvent(ctx context.Context, request *proto.GetEventRequest) (*
 				ResolvedTriggerEvent: evt.ToProto(),
 			},
 		}, nil
+	case *models.EventRevokedUserTokens:
+		return &proto.GetEventResponse{
+			Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+			Event: &proto.GetEventResponse_RevokedUserTokensEvent{
+				RevokedUserTokensEvent: evt.ToProto(),
+			},
+		}, nil
+	case *models.EventExpiredPipelineObject:
+		return &proto.GetEventResponse{
+			Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+			Event: &proto.GetEventResponse_ExpiredPipelineObjectEvent{
+				ExpiredPipelineObjectEvent: evt.ToProto(),
+			},
+		}, nil
+	case *models.EventExtension:
+		return &proto.GetEventResponse{
+			Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+			Event: &proto.GetEventResponse_ExtensionEvent{
+				ExtensionEvent: evt.ToProto(),
+			},
+		}, nil
 	default:
 		log.Error().Int64("id", evt.GetID()).Str("kind", string(evt.GetKind())).Msg("could not serialize event")
 		return nil, status.Error(codes.Internal, "could not serialize event")
@@ -125,7 +162,28 @@ func (api *API) GetEvent(ctx context.Context, request *proto.GetEventRequest) (*
 }
 
 func (api *API) ListEvents(request *proto.ListEventsRequest, stream proto.Gofer_ListEventsServer) error {
-	historicalEvents := api.events.GetAll(request.Reverse)
+	if request.Reverse && (request.FromId != 0 || request.FromTime != 0 || request.Consumer != "") {
+		return status.Error(codes.FailedPrecondition, "from_id, from_time and consumer cannot be used with reverse")
+	}
+
+	if request.FromId != 0 && request.Consumer != "" {
+		return status.Error(codes.FailedPrecondition, "from_id cannot be used with consumer")
+	}
+
+	var historicalEvents <-chan models.Event
+	switch {
+	case request.Consumer != "":
+		cursor, err := api.eventCursor(eventConsumerPrefix + request.Consumer)
+		if err != nil {
+			log.Error().Err(err).Str("consumer", request.Consumer).Msg("could not get event consumer")
+			return status.Errorf(codes.Internal, "could not get event consumer: %v", err)
+		}
+		historicalEvents = api.events.GetAllAfter(cursor.EventID)
+	case request.FromId > 0:
+		historicalEvents = api.events.GetAllAfter(request.FromId - 1)
+	default:
+		historicalEvents = api.events.GetAll(request.Reverse)
+	}
 
 	subscription, err := api.events.Subscribe(models.AnyEvent)
 	if err != nil {
@@ -133,6 +191,10 @@ func (api *API) ListEvents(request *proto.ListEventsRequest, stream proto.Gofer_
 	}
 	defer api.events.Unsubscribe(subscription)
 
+	// lastID is the most recent event sent so that events which were published while we were sending historical
+	// events aren't sent twice.
+	var lastID int64
+
 	// God help me. Huge switch statements like this seem to be the only way to do this.
 	// We do two separate switch statements because we want the historical events to drain out first typically.
 historicalLoop:
@@ -147,6 +209,16 @@ historicalLoop:
 				break historicalLoop
 			}
 
+			if event.GetEmitted() < request.FromTime {
+				continue
+			}
+
+			lastID = event.GetID()
+
+			if !api.eventVisible(stream.Context(), event) {
+				continue
+			}
+
 			switch evt := event.(type) {
 			case *models.EventCreatedNamespace:
 				err := stream.Send(&proto.ListEventsResponse{
@@ -223,6 +295,21 @@ historicalLoop:
 					return status.Errorf(codes.Internal, "could not send event: %v", err)
 				}
 				continue
+			case *models.EventRolledBackPipeline:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_RolledBackPipelineEvent{
+						RolledBackPipelineEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
+				continue
 			case *models.EventStartedRun:
 				err := stream.Send(&proto.ListEventsResponse{
 					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
@@ -343,6 +430,51 @@ historicalLoop:
 					return status.Errorf(codes.Internal, "could not send event: %v", err)
 				}
 				continue
+			case *models.EventRevokedUserTokens:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_RevokedUserTokensEvent{
+						RevokedUserTokensEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
+				continue
+			case *models.EventExpiredPipelineObject:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_ExpiredPipelineObjectEvent{
+						ExpiredPipelineObjectEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
+				continue
+			case *models.EventExtension:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_ExtensionEvent{
+						ExtensionEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
+				continue
 			}
 		}
 	}
@@ -364,6 +496,14 @@ historicalLoop:
 				return nil
 			}
 
+			if event.GetID() <= lastID {
+				continue
+			}
+
+			if !api.eventVisible(stream.Context(), event) {
+				continue
+			}
+
 			switch evt := event.(type) {
 			case *models.EventCreatedNamespace:
 				err := stream.Send(&proto.ListEventsResponse{
@@ -435,6 +575,20 @@ historicalLoop:
 					log.Error().Err(err).Msg("could not send event")
 					return status.Errorf(codes.Internal, "could not send event: %v", err)
 				}
+			case *models.EventRolledBackPipeline:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_RolledBackPipelineEvent{
+						RolledBackPipelineEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
 			case *models.EventStartedRun:
 				err := stream.Send(&proto.ListEventsResponse{
 					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
@@ -547,6 +701,48 @@ historicalLoop:
 					log.Error().Err(err).Msg("could not send event")
 					return status.Errorf(codes.Internal, "could not send event: %v", err)
 				}
+			case *models.EventRevokedUserTokens:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_RevokedUserTokensEvent{
+						RevokedUserTokensEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
+			case *models.EventExpiredPipelineObject:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_ExpiredPipelineObjectEvent{
+						ExpiredPipelineObjectEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
+			case *models.EventExtension:
+				err := stream.Send(&proto.ListEventsResponse{
+					Kind: proto.EventType(proto.EventType_value[string(evt.GetKind())]),
+					Event: &proto.ListEventsResponse_ExtensionEvent{
+						ExtensionEvent: evt.ToProto(),
+					},
+				})
+				if err != nil {
+					if status.Code(err) == codes.Unavailable {
+						return nil
+					}
+					log.Error().Err(err).Msg("could not send event")
+					return status.Errorf(codes.Internal, "could not send event: %v", err)
+				}
 			}
 		default:
 			if !request.Follow {
@@ -555,3 +751,138 @@ historicalLoop:
 		}
 	}
 }
+
+func (api *API) AcknowledgeEvent(ctx context.Context, request *proto.AcknowledgeEventRequest) (*proto.AcknowledgeEventResponse, error) {
+	if request.Consumer == "" {
+		return &proto.AcknowledgeEventResponse{}, status.Error(codes.FailedPrecondition, "consumer required")
+	}
+
+	if request.Id <= 0 {
+		return &proto.AcknowledgeEventResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	latestID, err := api.latestEventID()
+	if err != nil {
+		log.Error().Err(err).Msg("could not get latest event")
+		return &proto.AcknowledgeEventResponse{}, status.Errorf(codes.Internal, "could not get latest event: %v", err)
+	}
+
+	if request.Id > latestID {
+		return &proto.AcknowledgeEventResponse{}, status.Error(codes.FailedPrecondition, "event has not been published")
+	}
+
+	err = api.acknowledgeEvent(request.Consumer, request.Id)
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.AcknowledgeEventResponse{}, status.Error(codes.FailedPrecondition, "could not find event consumer")
+		}
+		log.Error().Err(err).Str("consumer", request.Consumer).Msg("could not acknowledge event")
+		return &proto.AcknowledgeEventResponse{}, status.Errorf(codes.Internal, "could not acknowledge event: %v", err)
+	}
+
+	return &proto.AcknowledgeEventResponse{}, nil
+}
+
+func (api *API) ListEventConsumers(ctx context.Context, request *proto.ListEventConsumersRequest) (*proto.ListEventConsumersResponse, error) {
+	// Consumers are shared by everyone so their names could give away what other namespaces are up to.
+	if api.namespaceIsolationEnabled() && !isManagementUser(ctx) {
+		return &proto.ListEventConsumersResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	cursors, err := api.storage.GetAllEventCursors(storage.GetAllEventCursorsRequest{})
+	if err != nil {
+		log.Error().Err(err).Msg("could not get event consumers")
+		return &proto.ListEventConsumersResponse{}, status.Errorf(codes.Internal, "could not get event consumers: %v", err)
+	}
+
+	protoConsumers := []*proto.EventConsumer{}
+	for _, cursor := range cursors {
+		if !strings.HasPrefix(cursor.ID, eventConsumerPrefix) {
+			continue
+		}
+
+		protoConsumers = append(protoConsumers, &proto.EventConsumer{
+			Name:           strings.TrimPrefix(cursor.ID, eventConsumerPrefix),
+			AcknowledgedId: cursor.EventID,
+			Updated:        cursor.Updated,
+		})
+	}
+
+	return &proto.ListEventConsumersResponse{
+		Consumers: protoConsumers,
+	}, nil
+}
+
+func (api *API) DeleteEventConsumer(ctx context.Context, request *proto.DeleteEventConsumerRequest) (*proto.DeleteEventConsumerResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.DeleteEventConsumerResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.Name == "" {
+		return &proto.DeleteEventConsumerResponse{}, status.Error(codes.FailedPrecondition, "name required")
+	}
+
+	err := api.storage.DeleteEventCursor(storage.DeleteEventCursorRequest{ID: eventConsumerPrefix + request.Name})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.DeleteEventConsumerResponse{}, status.Error(codes.FailedPrecondition, "could not find event consumer")
+		}
+		log.Error().Err(err).Str("consumer", request.Name).Msg("could not delete event consumer")
+		return &proto.DeleteEventConsumerResponse{}, status.Errorf(codes.Internal, "could not delete event consumer: %v", err)
+	}
+
+	log.Info().Str("consumer", request.Name).Msg("deleted event consumer")
+	return &proto.DeleteEventConsumerResponse{}, nil
+}
+
+func (api *API) PublishExtensionEvent(ctx context.Context, request *proto.PublishExtensionEventRequest) (*proto.PublishExtensionEventResponse, error) {
+	if request.Extension == "" {
+		return &proto.PublishExtensionEventResponse{}, status.Error(codes.FailedPrecondition, "extension required")
+	}
+
+	key, err := grpc_auth.AuthFromMD(ctx, "Bearer")
+	if err != nil {
+		return &proto.PublishExtensionEventResponse{}, status.Error(codes.PermissionDenied, "malformed token fmt; should be in form: 'Bearer <token>'")
+	}
+
+	trigger, exists := api.triggers.Get(request.Extension)
+	if !exists || subtle.ConstantTimeCompare([]byte(key), []byte(trigger.Key)) != 1 {
+		return &proto.PublishExtensionEventResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	if request.Kind == "" || strings.ContainsAny(request.Kind, "/ ") {
+		return &proto.PublishExtensionEventResponse{}, status.Error(codes.FailedPrecondition,
+			"kind required and must not contain slashes or spaces")
+	}
+
+	if request.Summary == "" {
+		return &proto.PublishExtensionEventResponse{}, status.Error(codes.FailedPrecondition, "summary required")
+	}
+
+	if request.PipelineId != "" {
+		if request.NamespaceId == "" {
+			return &proto.PublishExtensionEventResponse{}, status.Error(codes.FailedPrecondition,
+				"namespace_id required when pipeline_id is set")
+		}
+
+		_, err := api.storage.GetPipeline(storage.GetPipelineRequest{
+			NamespaceID: request.NamespaceId,
+			ID:          request.PipelineId,
+		})
+		if err != nil {
+			if errors.Is(err, storage.ErrEntityNotFound) {
+				return &proto.PublishExtensionEventResponse{}, status.Error(codes.FailedPrecondition, "pipeline not found")
+			}
+			log.Error().Err(err).Msg("could not get pipeline")
+			return &proto.PublishExtensionEventResponse{}, status.Errorf(codes.Internal, "could not get pipeline: %v", err)
+		}
+	}
+
+	event := models.NewEventExtension(request.Extension, request.Kind, request.Summary, request.Details,
+		request.NamespaceId, request.PipelineId)
+	api.events.Publish(event)
+
+	return &proto.PublishExtensionEventResponse{
+		Id: event.GetID(),
+	}, nil
+}
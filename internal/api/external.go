@@ -23,10 +23,7 @@ func StartExternalEventsService(config *config.API, api *API) {
 		"POST": http.HandlerFunc(api.externalEventsHandler),
 	})
 
-	tlsConfig, err := api.generateTLSConfig(api.config.Server.TLSCertPath, api.config.Server.TLSKeyPath)
-	if err != nil {
-		log.Fatal().Err(err).Msg("could not get proper TLS config")
-	}
+	tlsConfig := api.generateTLSConfig()
 
 	httpServer := http.Server{
 		Addr:         config.ExternalEventsAPI.Host,
@@ -39,7 +36,8 @@ func StartExternalEventsService(config *config.API, api *API) {
 
 	// Run our server in a goroutine and listen for signals that indicate graceful shutdown
 	go func() {
-		if err := httpServer.ListenAndServeTLS(config.Server.TLSCertPath, config.Server.TLSKeyPath); err != nil && err != http.ErrServerClosed {
+		// The certificate is served by the TLS config so that it can be reloaded.
+		if err := httpServer.ListenAndServeTLS("", ""); err != nil && err != http.ErrServerClosed {
 			log.Fatal().Err(err).Msg("server exited abnormally")
 		}
 	}()
@@ -54,7 +52,7 @@ func StartExternalEventsService(config *config.API, api *API) {
 	ctx, cancel := context.WithTimeout(context.Background(), config.Server.ShutdownTimeout) // shutdown gracefully
 	defer cancel()
 
-	err = httpServer.Shutdown(ctx)
+	err := httpServer.Shutdown(ctx)
 	if err != nil {
 		log.Error().Err(err).Msg("could not shutdown server in timeout specified")
 		return
@@ -0,0 +1,71 @@
+package api
+
+import (
+	"context"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// newExternalJWTVerifier returns a verifier for JWTs issued by the identity provider of a platform embedding Gofer.
+// Unlike single sign-on, the provider isn't required to support OpenID Connect discovery so the location of its
+// signing keys is configured directly.
+func newExternalJWTVerifier(conf *config.ExternalJWT) (*oidcVerifier, error) {
+	verifier := &oidcVerifier{
+		config: &config.OIDC{
+			Issuer:        conf.Issuer,
+			ClientID:      conf.Audience,
+			GroupsClaim:   conf.GroupsClaim,
+			GroupMappings: conf.GroupMappings,
+		},
+		discovery: oidcDiscovery{
+			Issuer:  conf.Issuer,
+			JWKSURI: conf.JWKSURL,
+		},
+		client: &http.Client{Timeout: 10 * time.Second},
+		keys:   map[string]signingKey{},
+	}
+
+	err := verifier.refreshKeys()
+	if err != nil {
+		return nil, fmt.Errorf("could not retrieve signing keys from %q: %w", conf.JWKSURL, err)
+	}
+
+	return verifier, nil
+}
+
+// unverifiedIssuer returns the "iss" claim of a JWT without verifying the token. It is only used to decide which
+// verifier a token should be checked by.
+func unverifiedIssuer(token string) string {
+	parts := strings.Split(token, ".")
+	if len(parts) != 3 {
+		return ""
+	}
+
+	payload, err := base64.RawURLEncoding.DecodeString(parts[1])
+	if err != nil {
+		return ""
+	}
+
+	claims := struct {
+		Issuer string `json:"iss"`
+	}{}
+	err = json.Unmarshal(payload, &claims)
+	if err != nil {
+		return ""
+	}
+
+	return claims.Issuer
+}
+
+// authenticateExternalJWT verifies a JWT issued by the identity provider of a platform embedding Gofer and populates
+// the context with the permissions mapped from the caller's groups.
+func (api *API) authenticateExternalJWT(ctx context.Context, token string) (context.Context, error) {
+	return authenticateJWT(ctx, api.externalJWT, token, models.CallerExternalJWT)
+}
@@ -0,0 +1,103 @@
+package api
+
+import (
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/rs/zerolog/log"
+)
+
+// qualifyImage returns the image reference with the registry and repository path docker fills in when they are left
+// out; ex. "ubuntu:latest" becomes "docker.io/library/ubuntu:latest".
+func qualifyImage(image string) string {
+	first, _, found := strings.Cut(image, "/")
+	if !found {
+		return "docker.io/library/" + image
+	}
+
+	// Docker treats the first part of the reference as a registry only if it looks like a hostname.
+	if strings.ContainsAny(first, ".:") || first == "localhost" {
+		return image
+	}
+
+	return "docker.io/" + image
+}
+
+// hasImagePrefix returns whether the image reference starts with the given registry or repository path. The prefix has
+// to end where a part of the reference does so that "docker.io/library/go" doesn't match "docker.io/library/golang".
+func hasImagePrefix(image, prefix string) bool {
+	if !strings.HasPrefix(image, prefix) {
+		return false
+	}
+
+	rest := strings.TrimPrefix(image, prefix)
+	return rest == "" || strings.ContainsAny(rest[:1], "/:@")
+}
+
+// rewriteImage returns the image reference that should be pulled in place of the given one. The most specific
+// rewrite for the namespace is used; if there isn't one the most specific rewrite without a namespace is. Images no
+// rewrite matches are returned unchanged.
+func rewriteImage(rewrites []config.ImageRewrite, namespace, image string) string {
+	qualified := qualifyImage(image)
+
+	var match *config.ImageRewrite
+	matchFrom := ""
+
+	for i := range rewrites {
+		rewrite := &rewrites[i]
+
+		if rewrite.Namespace != "" && rewrite.Namespace != namespace {
+			continue
+		}
+
+		from := strings.Trim(rewrite.From, "/")
+		if !hasImagePrefix(qualified, from) {
+			continue
+		}
+
+		if match != nil {
+			if match.Namespace != "" && rewrite.Namespace == "" {
+				continue
+			}
+
+			if match.Namespace == rewrite.Namespace && len(from) <= len(matchFrom) {
+				continue
+			}
+		}
+
+		match = rewrite
+		matchFrom = from
+	}
+
+	if match == nil {
+		return image
+	}
+
+	return strings.TrimRight(match.To, "/") + strings.TrimPrefix(qualified, matchFrom)
+}
+
+// schedulerImage returns the image the scheduler should pull for a container started on behalf of the given namespace.
+// Containers that don't belong to a namespace(ex. triggers) pass an empty namespace.
+func (api *API) schedulerImage(namespace, image string) string {
+	if api.config.Scheduler == nil || len(api.config.Scheduler.ImageRewrites) == 0 {
+		return image
+	}
+
+	rewritten := rewriteImage(api.config.Scheduler.ImageRewrites, namespace, image)
+	if rewritten != image {
+		log.Debug().Str("namespace", namespace).Str("image", image).Str("rewritten", rewritten).
+			Msg("rewrote image reference")
+	}
+
+	return rewritten
+}
+
+// imageMaxAge returns how long an image already on the scheduler's host is used before it's pulled again.
+func (api *API) imageMaxAge() time.Duration {
+	if api.config.Scheduler == nil {
+		return 0
+	}
+
+	return api.config.Scheduler.ImageMaxAge
+}
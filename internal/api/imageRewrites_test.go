@@ -0,0 +1,66 @@
+package api
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/config"
+)
+
+func TestRewriteImage(t *testing.T) {
+	rewrites := []config.ImageRewrite{
+		{From: "docker.io", To: "registry.internal/dockerhub"},
+		{From: "docker.io/library/golang", To: "registry.internal/golang"},
+		{From: "ghcr.io", To: "registry.internal/ghcr/"},
+		{From: "ghcr.io", To: "registry.internal/team-a/ghcr", Namespace: "team_a"},
+	}
+
+	tests := map[string]struct {
+		namespace string
+		image     string
+		want      string
+	}{
+		"official image": {
+			image: "ubuntu:latest",
+			want:  "registry.internal/dockerhub/library/ubuntu:latest",
+		},
+		"docker hub user image": {
+			image: "clintjedwards/gofer:1.0",
+			want:  "registry.internal/dockerhub/clintjedwards/gofer:1.0",
+		},
+		"most specific rewrite": {
+			image: "golang:1.18",
+			want:  "registry.internal/golang:1.18",
+		},
+		"partial path is not a match": {
+			image: "go:1.18",
+			want:  "registry.internal/dockerhub/library/go:1.18",
+		},
+		"namespace rewrite": {
+			namespace: "team_a",
+			image:     "ghcr.io/clintjedwards/debug@sha256:abc",
+			want:      "registry.internal/team-a/ghcr/clintjedwards/debug@sha256:abc",
+		},
+		"other namespace uses global rewrite": {
+			namespace: "team_b",
+			image:     "ghcr.io/clintjedwards/debug",
+			want:      "registry.internal/ghcr/clintjedwards/debug",
+		},
+		"no match": {
+			image: "quay.io/coreos/etcd",
+			want:  "quay.io/coreos/etcd",
+		},
+		"registry with port": {
+			image: "localhost:5000/debug",
+			want:  "localhost:5000/debug",
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := rewriteImage(rewrites, tc.namespace, tc.image)
+			if got != tc.want {
+				t.Errorf("want %q; got %q", tc.want, got)
+			}
+		})
+	}
+}
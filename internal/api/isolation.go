@@ -0,0 +1,95 @@
+package api
+
+import (
+	"context"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func (api *API) namespaceIsolationEnabled() bool {
+	return api.config.Permissioning != nil && api.config.Permissioning.NamespaceIsolation != nil &&
+		api.config.Permissioning.NamespaceIsolation.Enable
+}
+
+// namespaceVisible returns whether an authenticated caller is able to see the given namespace and what is within it.
+// Every namespace is visible unless namespace isolation is enabled.
+func (api *API) namespaceVisible(ctx context.Context, namespace string) bool {
+	if !api.namespaceIsolationEnabled() {
+		return true
+	}
+
+	return hasAccess(ctx, namespace)
+}
+
+// eventNamespace returns the namespace an event happened within. Events that don't belong to a namespace(ex. the
+// revocation of a user's tokens) return false.
+func eventNamespace(event models.Event) (string, bool) {
+	switch evt := event.(type) {
+	case *models.EventCreatedNamespace:
+		return evt.NamespaceID, true
+	case *models.EventDisabledPipeline:
+		return evt.NamespaceID, true
+	case *models.EventEnabledPipeline:
+		return evt.NamespaceID, true
+	case *models.EventCreatedPipeline:
+		return evt.NamespaceID, true
+	case *models.EventAbandonedPipeline:
+		return evt.NamespaceID, true
+	case *models.EventRolledBackPipeline:
+		return evt.NamespaceID, true
+	case *models.EventStartedRun:
+		return evt.NamespaceID, true
+	case *models.EventCompletedRun:
+		return evt.NamespaceID, true
+	case *models.EventStartedTaskRun:
+		return evt.NamespaceID, true
+	case *models.EventScheduledTaskRun:
+		return evt.NamespaceID, true
+	case *models.EventCompletedTaskRun:
+		return evt.NamespaceID, true
+	case *models.EventFiredTrigger:
+		return evt.Namespace, true
+	case *models.EventProcessedTrigger:
+		return evt.Namespace, true
+	case *models.EventResolvedTrigger:
+		return evt.Namespace, true
+	case *models.EventExpiredPipelineObject:
+		return evt.NamespaceID, true
+	case *models.EventExtension:
+		return evt.NamespaceID, evt.NamespaceID != ""
+	default:
+		return "", false
+	}
+}
+
+// eventVisible returns whether the caller is able to see the given event. With namespace isolation enabled callers only
+// see the events of the namespaces they have access to; events that don't belong to a namespace are only visible to
+// management users.
+func (api *API) eventVisible(ctx context.Context, event models.Event) bool {
+	if !api.namespaceIsolationEnabled() {
+		return true
+	}
+
+	namespace, ok := eventNamespace(event)
+	if !ok {
+		return isManagementUser(ctx)
+	}
+
+	return hasAccess(ctx, namespace)
+}
+
+// namespaceSchedulerLabels returns the labels the operator has configured for the task run containers of the given
+// namespace.
+func (api *API) namespaceSchedulerLabels(namespace string) map[string]string {
+	if !api.namespaceIsolationEnabled() {
+		return nil
+	}
+
+	for _, schedulerLabels := range api.config.Permissioning.NamespaceIsolation.SchedulerLabels {
+		if schedulerLabels.Namespace == namespace {
+			return schedulerLabels.Labels
+		}
+	}
+
+	return nil
+}
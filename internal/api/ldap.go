@@ -0,0 +1,175 @@
+package api
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/ldap"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// errLDAPUserNotFound is returned when a user could not be found within the directory.
+var errLDAPUserNotFound = errors.New("user not found in directory")
+
+// ldapDirectory looks up the groups of directory users.
+type ldapDirectory struct {
+	config config.LDAP
+}
+
+func newLDAPDirectory(conf *config.LDAP) *ldapDirectory {
+	defaults := config.DefaultLDAPConfig()
+
+	directory := &ldapDirectory{config: *conf}
+	if directory.config.UserFilter == "" {
+		directory.config.UserFilter = defaults.UserFilter
+	}
+	if directory.config.GroupAttribute == "" {
+		directory.config.GroupAttribute = defaults.GroupAttribute
+	}
+	if directory.config.RefreshInterval == 0 {
+		directory.config.RefreshInterval = defaults.RefreshInterval
+	}
+
+	return directory
+}
+
+// groups returns the groups the given user belongs to. Each group is returned both as its full DN and as the value
+// of the DN's first component so that mappings can use either.
+func (d *ldapDirectory) groups(username string) ([]string, error) {
+	conn, err := ldap.Dial(d.config.URL, nil)
+	if err != nil {
+		return nil, fmt.Errorf("could not connect to directory: %w", err)
+	}
+	defer conn.Close()
+
+	err = conn.Bind(d.config.BindDN, d.config.BindPassword)
+	if err != nil {
+		return nil, fmt.Errorf("could not bind to directory: %w", err)
+	}
+
+	filter := strings.ReplaceAll(d.config.UserFilter, "{username}", ldap.EscapeFilter(username))
+	entries, err := conn.Search(d.config.UserBaseDN, filter, []string{d.config.GroupAttribute})
+	if err != nil {
+		return nil, fmt.Errorf("could not search directory: %w", err)
+	}
+
+	if len(entries) == 0 {
+		return nil, errLDAPUserNotFound
+	}
+
+	if len(entries) > 1 {
+		return nil, fmt.Errorf("found %d directory entries for user %q; user_filter must match a single user",
+			len(entries), username)
+	}
+
+	groups := []string{}
+	for _, dn := range entries[0].Get(d.config.GroupAttribute) {
+		groups = append(groups, dn)
+		if name := firstRDNValue(dn); name != "" && name != dn {
+			groups = append(groups, name)
+		}
+	}
+
+	return groups, nil
+}
+
+// permissions returns the kind and namespaces the given user is entitled to.
+func (d *ldapDirectory) permissions(username string) (kind models.TokenKind, namespaces []string, ok bool, err error) {
+	groups, err := d.groups(username)
+	if err != nil {
+		return "", nil, false, err
+	}
+
+	kind, namespaces, ok = groupPermissions(d.config.GroupMappings, groups)
+	return kind, namespaces, ok, nil
+}
+
+// firstRDNValue returns the value of the first component of a DN. Ex: "CN=admins,OU=Groups,DC=example" returns
+// "admins".
+func firstRDNValue(dn string) string {
+	end := len(dn)
+	for i := 0; i < len(dn); i++ {
+		if dn[i] == '\\' {
+			i++
+			continue
+		}
+		if dn[i] == ',' || dn[i] == '+' {
+			end = i
+			break
+		}
+	}
+
+	_, value, found := strings.Cut(dn[:end], "=")
+	if !found {
+		return ""
+	}
+
+	return strings.TrimSpace(value)
+}
+
+// refreshLDAPTokens updates the kind and namespaces of all tokens created for directory users to match the user's
+// current group membership. Tokens of users that no longer exist or no longer belong to any mapped groups lose all
+// access.
+func (api *API) refreshLDAPTokens() {
+	offset := 0
+	for {
+		tokens, err := api.storage.GetAllTokens(storage.GetAllTokensRequest{
+			Offset: offset,
+		})
+		if err != nil {
+			log.Error().Err(err).Msg("could not get tokens for ldap refresh")
+			return
+		}
+
+		if len(tokens) == 0 {
+			return
+		}
+		offset += len(tokens)
+
+		for _, token := range tokens {
+			if token.LDAPUser == "" {
+				continue
+			}
+
+			kind, namespaces, ok, err := api.ldap.permissions(token.LDAPUser)
+			if err != nil && !errors.Is(err, errLDAPUserNotFound) {
+				log.Error().Err(err).Str("user", token.LDAPUser).Msg("could not refresh ldap token permissions")
+				continue
+			}
+
+			if !ok {
+				log.Warn().Str("user", token.LDAPUser).
+					Msg("ldap user no longer belongs to any mapped groups; removing token permissions")
+				kind, namespaces = models.TokenKindClient, []string{}
+			}
+
+			token.Kind = kind
+			token.Namespaces = namespaces
+
+			err = api.storage.UpdateToken(storage.UpdateTokenRequest{Token: token})
+			if err != nil {
+				log.Error().Err(err).Str("user", token.LDAPUser).Msg("could not update ldap token permissions")
+			}
+		}
+	}
+}
+
+// refreshLDAPTokensOnSchedule refreshes the permissions of directory user tokens until the API is shut down.
+func (api *API) refreshLDAPTokensOnSchedule() {
+	ticker := time.NewTicker(api.ldap.config.RefreshInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-ticker.C:
+			api.refreshLDAPTokens()
+		}
+	}
+}
@@ -0,0 +1,322 @@
+package api
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
+)
+
+// durationMetric describes a duration Gofer keeps a distribution of.
+type durationMetric struct {
+	name string // The name of the metric in the stats API.
+	help string
+}
+
+// prometheusName is the name the metric is exported under on the /metrics endpoint.
+func (m durationMetric) prometheusName() string {
+	return "gofer_" + m.name + "_seconds"
+}
+
+var (
+	metricQueueWait   = durationMetric{"task_run_queue_wait", "Time task runs spent waiting to be started, excluding image pulls."}
+	metricImagePull   = durationMetric{"task_run_image_pull", "Time spent pulling the images of task runs."}
+	metricTaskRun     = durationMetric{"task_run_duration", "Time task runs spent running."}
+	metricRunDuration = durationMetric{"run_duration", "Time from the start of a run until its last task run finished."}
+)
+
+var durationMetrics = []durationMetric{metricQueueWait, metricImagePull, metricTaskRun, metricRunDuration}
+
+// durationBuckets are the upper bounds in seconds of the histogram buckets durations are counted in. They range from
+// a second to a few hours since that covers everything from an image pull to a long build.
+var durationBuckets = []float64{1, 5, 15, 30, 60, 120, 300, 600, 1200, 1800, 3600, 7200, 14400}
+
+// histogram counts observations in the durationBuckets. The final count is for observations larger than every bucket.
+type histogram struct {
+	counts []int64
+	count  int64
+	sum    float64 // In seconds.
+}
+
+func newHistogram() *histogram {
+	return &histogram{
+		counts: make([]int64, len(durationBuckets)+1),
+	}
+}
+
+func (h *histogram) observe(duration time.Duration) {
+	seconds := duration.Seconds()
+
+	bucket := sort.SearchFloat64s(durationBuckets, seconds)
+	h.counts[bucket]++
+	h.count++
+	h.sum += seconds
+}
+
+// quantile estimates the given quantile(ex. 0.9) by assuming observations are spread evenly within their bucket.
+// Observations larger than every bucket are estimated as the largest bucket.
+func (h *histogram) quantile(q float64) time.Duration {
+	if h.count == 0 {
+		return 0
+	}
+
+	rank := q * float64(h.count)
+
+	var cumulative int64
+	for bucket, count := range h.counts {
+		if float64(cumulative+count) < rank || count == 0 {
+			cumulative += count
+			continue
+		}
+
+		if bucket == len(durationBuckets) {
+			return time.Duration(durationBuckets[len(durationBuckets)-1] * float64(time.Second))
+		}
+
+		lower := 0.0
+		if bucket > 0 {
+			lower = durationBuckets[bucket-1]
+		}
+		upper := durationBuckets[bucket]
+
+		seconds := lower + (upper-lower)*(rank-float64(cumulative))/float64(count)
+		return time.Duration(seconds * float64(time.Second))
+	}
+
+	return time.Duration(durationBuckets[len(durationBuckets)-1] * float64(time.Second))
+}
+
+// seriesKey identifies a single histogram.
+type seriesKey struct {
+	metric    string
+	namespace string
+	pipeline  string
+	status    string
+}
+
+// durationRecorder keeps the distributions of run and task run durations since the server started, separated by
+// pipeline and the final status of the run or task run.
+type durationRecorder struct {
+	mu     sync.Mutex
+	series map[seriesKey]*histogram
+}
+
+func newDurationRecorder() *durationRecorder {
+	return &durationRecorder{
+		series: map[seriesKey]*histogram{},
+	}
+}
+
+func (r *durationRecorder) observe(metric durationMetric, namespace, pipeline, status string, duration time.Duration) {
+	if duration < 0 {
+		return
+	}
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	key := seriesKey{metric: metric.name, namespace: namespace, pipeline: pipeline, status: status}
+
+	series, exists := r.series[key]
+	if !exists {
+		series = newHistogram()
+		r.series[key] = series
+	}
+
+	series.observe(duration)
+}
+
+// sortedKeys returns the keys of all series ordered by metric then labels. Must be called with the lock held.
+func (r *durationRecorder) sortedKeys() []seriesKey {
+	keys := make([]seriesKey, 0, len(r.series))
+	for key := range r.series {
+		keys = append(keys, key)
+	}
+
+	sort.Slice(keys, func(i, j int) bool {
+		a, b := keys[i], keys[j]
+		if a.metric != b.metric {
+			return a.metric < b.metric
+		}
+		if a.namespace != b.namespace {
+			return a.namespace < b.namespace
+		}
+		if a.pipeline != b.pipeline {
+			return a.pipeline < b.pipeline
+		}
+		return a.status < b.status
+	})
+
+	return keys
+}
+
+// distributions returns a summary of every series the caller is allowed to see.
+func (r *durationRecorder) distributions(include func(namespace string) bool) []*proto.DurationDistribution {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	distributions := []*proto.DurationDistribution{}
+	for _, key := range r.sortedKeys() {
+		if !include(key.namespace) {
+			continue
+		}
+
+		series := r.series[key]
+		distributions = append(distributions, &proto.DurationDistribution{
+			Metric:      key.metric,
+			NamespaceId: key.namespace,
+			PipelineId:  key.pipeline,
+			Status:      key.status,
+			Count:       series.count,
+			Sum:         int64(series.sum * 1000),
+			P50:         series.quantile(0.5).Milliseconds(),
+			P90:         series.quantile(0.9).Milliseconds(),
+			P99:         series.quantile(0.99).Milliseconds(),
+		})
+	}
+
+	return distributions
+}
+
+var labelValueEscaper = strings.NewReplacer(`\`, `\\`, `"`, `\"`, "\n", `\n`)
+
+// writePrometheus writes all series in the Prometheus text exposition format.
+func (r *durationRecorder) writePrometheus(w io.Writer) error {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	keys := r.sortedKeys()
+
+	for _, metric := range durationMetrics {
+		name := metric.prometheusName()
+
+		_, err := fmt.Fprintf(w, "# HELP %s %s\n# TYPE %s histogram\n", name, metric.help, name)
+		if err != nil {
+			return err
+		}
+
+		for _, key := range keys {
+			if key.metric != metric.name {
+				continue
+			}
+
+			series := r.series[key]
+			labels := fmt.Sprintf(`namespace="%s",pipeline="%s",status="%s"`,
+				labelValueEscaper.Replace(key.namespace),
+				labelValueEscaper.Replace(key.pipeline),
+				labelValueEscaper.Replace(key.status))
+
+			var cumulative int64
+			for bucket, bound := range durationBuckets {
+				cumulative += series.counts[bucket]
+				fmt.Fprintf(w, "%s_bucket{%s,le=\"%s\"} %d\n", name, labels,
+					strconv.FormatFloat(bound, 'g', -1, 64), cumulative)
+			}
+			fmt.Fprintf(w, "%s_bucket{%s,le=\"+Inf\"} %d\n", name, labels, series.count)
+			fmt.Fprintf(w, "%s_sum{%s} %s\n", name, labels, strconv.FormatFloat(series.sum, 'g', -1, 64))
+			_, err = fmt.Fprintf(w, "%s_count{%s} %d\n", name, labels, series.count)
+			if err != nil {
+				return err
+			}
+		}
+	}
+
+	return nil
+}
+
+// metricsHandler serves the duration distributions for Prometheus to scrape.
+func (api *API) metricsHandler(w http.ResponseWriter, _ *http.Request) {
+	w.Header().Set("Content-Type", "text/plain; version=0.0.4")
+
+	err := api.durations.writePrometheus(w)
+	if err != nil {
+		log.Error().Err(err).Msg("could not write metrics")
+	}
+}
+
+// startDurationRecorder records the durations of runs and task runs as they complete.
+func (api *API) startDurationRecorder() error {
+	subscription, err := api.events.Subscribe(models.AnyEvent)
+	if err != nil {
+		return err
+	}
+
+	go func() {
+		defer api.events.Unsubscribe(subscription)
+
+		for {
+			select {
+			case <-api.context.ctx.Done():
+				return
+			case event := <-subscription.Events:
+				switch evt := event.(type) {
+				case *models.EventCompletedTaskRun:
+					api.recordTaskRunDurations(evt)
+				case *models.EventCompletedRun:
+					api.recordRunDuration(evt)
+				}
+			}
+		}
+	}()
+
+	return nil
+}
+
+func (api *API) recordTaskRunDurations(event *models.EventCompletedTaskRun) {
+	taskRun, err := api.storage.GetTaskRun(storage.GetTaskRunRequest{
+		NamespaceID: event.NamespaceID,
+		PipelineID:  event.PipelineID,
+		RunID:       event.RunID,
+		ID:          event.TaskRunID,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("task_run", event.TaskRunID).Msg("could not get task run to record its durations")
+		return
+	}
+
+	// Task runs that were never started(ex. skipped) have no durations worth recording.
+	if taskRun.Started == 0 {
+		return
+	}
+
+	status := string(taskRun.State)
+	imagePull := time.Duration(taskRun.ImagePull) * time.Millisecond
+	queueWait := time.Duration(taskRun.Started-taskRun.Created)*time.Millisecond - imagePull
+
+	api.durations.observe(metricQueueWait, taskRun.NamespaceID, taskRun.PipelineID, status, queueWait)
+	if taskRun.ImagePull > 0 {
+		api.durations.observe(metricImagePull, taskRun.NamespaceID, taskRun.PipelineID, status, imagePull)
+	}
+	if taskRun.Ended != 0 {
+		api.durations.observe(metricTaskRun, taskRun.NamespaceID, taskRun.PipelineID, status,
+			time.Duration(taskRun.Ended-taskRun.Started)*time.Millisecond)
+	}
+}
+
+func (api *API) recordRunDuration(event *models.EventCompletedRun) {
+	run, err := api.storage.GetRun(storage.GetRunRequest{
+		NamespaceID: event.NamespaceID,
+		PipelineID:  event.PipelineID,
+		ID:          event.RunID,
+	})
+	if err != nil {
+		log.Error().Err(err).Int64("run", event.RunID).Msg("could not get run to record its duration")
+		return
+	}
+
+	if run.Ended == 0 {
+		return
+	}
+
+	api.durations.observe(metricRunDuration, run.NamespaceID, run.PipelineID, string(run.State),
+		time.Duration(run.Ended-run.Started)*time.Millisecond)
+}
@@ -0,0 +1,58 @@
+package api
+
+import (
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestHistogramQuantile(t *testing.T) {
+	h := newHistogram()
+
+	// Ten observations between 1s and 5s and ten between 60s and 120s.
+	for i := 0; i < 10; i++ {
+		h.observe(3 * time.Second)
+		h.observe(90 * time.Second)
+	}
+
+	tests := map[float64]time.Duration{
+		0.5:  5 * time.Second,
+		0.75: 90 * time.Second,
+		1:    120 * time.Second,
+	}
+
+	for q, want := range tests {
+		if got := h.quantile(q); got != want {
+			t.Errorf("quantile %v: want %s; got %s", q, want, got)
+		}
+	}
+}
+
+func TestWritePrometheus(t *testing.T) {
+	recorder := newDurationRecorder()
+	recorder.observe(metricRunDuration, "default", "simple", "SUCCESS", 90*time.Second)
+	recorder.observe(metricRunDuration, "default", "simple", "SUCCESS", 2*time.Hour+time.Minute)
+
+	output := &strings.Builder{}
+	err := recorder.writePrometheus(output)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	want := []string{
+		"# TYPE gofer_run_duration_seconds histogram",
+		`gofer_run_duration_seconds_bucket{namespace="default",pipeline="simple",status="SUCCESS",le="60"} 0`,
+		`gofer_run_duration_seconds_bucket{namespace="default",pipeline="simple",status="SUCCESS",le="120"} 1`,
+		`gofer_run_duration_seconds_bucket{namespace="default",pipeline="simple",status="SUCCESS",le="7200"} 1`,
+		`gofer_run_duration_seconds_bucket{namespace="default",pipeline="simple",status="SUCCESS",le="14400"} 2`,
+		`gofer_run_duration_seconds_bucket{namespace="default",pipeline="simple",status="SUCCESS",le="+Inf"} 2`,
+		`gofer_run_duration_seconds_sum{namespace="default",pipeline="simple",status="SUCCESS"} 7350`,
+		`gofer_run_duration_seconds_count{namespace="default",pipeline="simple",status="SUCCESS"} 2`,
+	}
+
+	for _, line := range want {
+		if !strings.Contains(output.String(), line+"\n") {
+			t.Errorf("missing line %q in output:\n%s", line, output.String())
+		}
+	}
+}
@@ -0,0 +1,108 @@
+package api
+
+import (
+	"context"
+	"crypto/tls"
+	"crypto/x509"
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/credentials"
+	"google.golang.org/grpc/peer"
+	"google.golang.org/grpc/status"
+)
+
+// enableClientCertificates configures the TLS config to verify client certificates against the configured CA.
+// Clients aren't required to present a certificate since they might authenticate with a token instead.
+func enableClientCertificates(tlsConfig *tls.Config, conf *config.MTLS) error {
+	caCerts, err := os.ReadFile(conf.CAPath)
+	if err != nil {
+		return fmt.Errorf("could not read mtls ca file: %w", err)
+	}
+
+	pool := x509.NewCertPool()
+	if !pool.AppendCertsFromPEM(caCerts) {
+		return fmt.Errorf("could not find any certificates in mtls ca file %q", conf.CAPath)
+	}
+
+	tlsConfig.ClientCAs = pool
+	tlsConfig.ClientAuth = tls.VerifyClientCertIfGiven
+
+	return nil
+}
+
+// verifiedClientCertificate returns the client certificate presented by the caller. Returns nil if the caller did
+// not present a certificate that was verified against the configured CA.
+func verifiedClientCertificate(ctx context.Context) *x509.Certificate {
+	caller, ok := peer.FromContext(ctx)
+	if !ok {
+		return nil
+	}
+
+	tlsInfo, ok := caller.AuthInfo.(credentials.TLSInfo)
+	if !ok {
+		return nil
+	}
+
+	if len(tlsInfo.State.VerifiedChains) == 0 || len(tlsInfo.State.VerifiedChains[0]) == 0 {
+		return nil
+	}
+
+	return tlsInfo.State.VerifiedChains[0][0]
+}
+
+// certificateMapping returns the first mapping matching one of the certificate's subject alternative names.
+func certificateMapping(mappings []config.CertificateMapping, cert *x509.Certificate) (config.CertificateMapping, bool) {
+	sans := []string{}
+	sans = append(sans, cert.DNSNames...)
+	sans = append(sans, cert.EmailAddresses...)
+	for _, uri := range cert.URIs {
+		sans = append(sans, uri.String())
+	}
+
+	for _, mapping := range mappings {
+		for _, san := range sans {
+			if san == mapping.SAN {
+				return mapping, true
+			}
+		}
+	}
+
+	return config.CertificateMapping{}, false
+}
+
+// authenticateCertificate authenticates the caller by their verified client certificate.
+func (api *API) authenticateCertificate(ctx context.Context, cert *x509.Certificate) (context.Context, error) {
+	mapping, ok := certificateMapping(api.config.Permissioning.MTLS.CertificateMappings, cert)
+	if !ok {
+		log.Debug().Str("subject", cert.Subject.String()).Msg("client certificate does not match any mappings")
+		return ctx, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	recordCaller(ctx, models.CallerCertificate(mapping.SAN))
+	ctx = withIdentity(ctx, models.CallerCertificate(mapping.SAN))
+
+	if mapping.ServiceAccount != "" {
+		return api.serviceAccountContext(ctx, mapping.ServiceAccount)
+	}
+
+	kind := models.TokenKindClient
+	if mapping.Kind != "" {
+		kind = models.TokenKind(strings.ToUpper(mapping.Kind))
+	}
+
+	namespaces := mapping.Namespaces
+	if namespaces == nil {
+		namespaces = []string{}
+	}
+
+	ctxNamespaces := context.WithValue(ctx, contextUserNamespaces, namespaces)
+	ctxKind := context.WithValue(ctxNamespaces, contextUserKind, string(kind))
+
+	return ctxKind, nil
+}
@@ -2,7 +2,12 @@ package api
 
 import (
 	"context"
+	"fmt"
+	"strings"
+	"time"
 
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
 	"github.com/rs/zerolog/log"
 )
 
@@ -40,3 +45,245 @@ func determineNamespace(ctx context.Context) string {
 
 	return namespaceDefaultID
 }
+
+// getAllNamespaces returns every namespace by paging through the storage layer.
+func (api *API) getAllNamespaces() ([]*models.Namespace, error) {
+	namespaces := []*models.Namespace{}
+
+	for {
+		page, err := api.storage.GetAllNamespaces(storage.GetAllNamespacesRequest{
+			Offset: len(namespaces),
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		if len(page) == 0 {
+			return namespaces, nil
+		}
+
+		namespaces = append(namespaces, page...)
+	}
+}
+
+// getAllPipelines returns every pipeline within a namespace by paging through the storage layer.
+func (api *API) getAllPipelines(namespace string) ([]*models.Pipeline, error) {
+	pipelines := []*models.Pipeline{}
+
+	for {
+		page, err := api.storage.GetAllPipelines(storage.GetAllPipelinesRequest{
+			NamespaceID: namespace,
+			Offset:      len(pipelines),
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		if len(page) == 0 {
+			return pipelines, nil
+		}
+
+		pipelines = append(pipelines, page...)
+	}
+}
+
+// namespaceUsage calculates the current resource consumption of a namespace in the same units as its quota.
+func (api *API) namespaceUsage(namespace string) (*models.NamespaceQuota, error) {
+	usage := &models.NamespaceQuota{}
+
+	pipelines, err := api.getAllPipelines(namespace)
+	if err != nil {
+		return nil, fmt.Errorf("could not get pipelines: %w", err)
+	}
+
+	usage.Pipelines = int64(len(pipelines))
+
+	usage.ObjectBytes, err = api.namespaceObjectBytes(namespace, pipelines)
+	if err != nil {
+		return nil, err
+	}
+
+	secrets, err := api.getNamespaceSecretKeys(namespace)
+	if err != nil {
+		return nil, err
+	}
+
+	usage.Secrets = int64(len(secrets))
+
+	usage.RunsPerDay, usage.ConcurrentRuns, err = api.namespaceRunUsage(namespace)
+	if err != nil {
+		return nil, err
+	}
+
+	return usage, nil
+}
+
+// getNamespaceSecretKeys returns the secret store keys of every secret that belongs to the namespace's pipelines.
+func (api *API) getNamespaceSecretKeys(namespace string) ([]string, error) {
+	keys, err := api.secretStore.ListSecretKeys(namespace + "_")
+	if err != nil {
+		return nil, fmt.Errorf("could not list secrets: %w", err)
+	}
+
+	namespaces, err := api.getAllNamespaces()
+	if err != nil {
+		return nil, fmt.Errorf("could not get namespaces: %w", err)
+	}
+
+	return filterNamespaceSecretKeys(namespace, keys, namespaces), nil
+}
+
+// filterNamespaceSecretKeys narrows keys starting with the namespace's prefix down to the ones that belong to it.
+// Namespace IDs may contain underscores, so the keys of other namespaces(ex. "team_a" for "team") and of registry
+// credentials(for a namespace called "registry") can start with the same prefix. Those keys are recognized by their
+// longer prefix and left out.
+func filterNamespaceSecretKeys(namespace string, keys []string, namespaces []*models.Namespace) []string {
+	prefix := namespace + "_"
+
+	excluded := []string{}
+	for _, other := range namespaces {
+		for _, otherPrefix := range []string{other.ID + "_", registryCredentialKey(other.ID, "")} {
+			if len(otherPrefix) > len(prefix) && strings.HasPrefix(otherPrefix, prefix) {
+				excluded = append(excluded, otherPrefix)
+			}
+		}
+	}
+
+	secrets := []string{}
+	for _, key := range keys {
+		if !strings.HasPrefix(key, prefix) || hasAnyPrefix(key, excluded) {
+			continue
+		}
+		secrets = append(secrets, key)
+	}
+
+	return secrets
+}
+
+func hasAnyPrefix(value string, prefixes []string) bool {
+	for _, prefix := range prefixes {
+		if strings.HasPrefix(value, prefix) {
+			return true
+		}
+	}
+
+	return false
+}
+
+// namespaceObjectBytes calculates the total size of all pipeline objects, run objects, artifacts and caches stored
+// for the given pipelines.
+func (api *API) namespaceObjectBytes(namespace string, pipelines []*models.Pipeline) (int64, error) {
+	total := int64(0)
+
+	for _, pipeline := range pipelines {
+		for _, key := range pipeline.Objects {
+			total += api.objectSize(pipelineObjectKey(namespace, pipeline.ID, key))
+		}
+
+		// Only the most recent runs can still have objects since run objects expire after a certain amount of runs.
+		runs, err := api.storage.GetAllRuns(storage.GetAllRunsRequest{
+			NamespaceID: namespace,
+			PipelineID:  pipeline.ID,
+			Limit:       api.config.ObjectStore.RunObjectExpiry,
+		})
+		if err != nil {
+			return 0, fmt.Errorf("could not get runs for pipeline %q: %w", pipeline.ID, err)
+		}
+
+		for _, run := range runs {
+			if run.ObjectsExpired {
+				continue
+			}
+
+			for _, key := range run.Objects {
+				total += api.objectSize(runObjectKey(namespace, pipeline.ID, key, run.ID))
+			}
+		}
+
+		offset := 0
+		for {
+			artifacts, err := api.storage.GetAllArtifacts(storage.GetAllArtifactsRequest{
+				NamespaceID:   namespace,
+				PipelineID:    pipeline.ID,
+				UnexpiredOnly: true,
+				Offset:        offset,
+			})
+			if err != nil {
+				return 0, fmt.Errorf("could not get artifacts for pipeline %q: %w", pipeline.ID, err)
+			}
+
+			if len(artifacts) == 0 {
+				break
+			}
+
+			for _, artifact := range artifacts {
+				total += artifact.Size
+			}
+			offset += len(artifacts)
+		}
+
+		caches, err := api.getAllCaches(namespace, pipeline.ID)
+		if err != nil {
+			return 0, fmt.Errorf("could not get caches for pipeline %q: %w", pipeline.ID, err)
+		}
+
+		for _, cache := range caches {
+			total += cache.Size
+		}
+	}
+
+	return total, nil
+}
+
+// namespaceRunUsage returns the amount of runs started within the last 24 hours and the amount of runs currently in
+// progress for the given namespace, based on the stored state of its runs.
+//
+// The runs of each pipeline are read newest first and reading stops at the first finished run started more than 24
+// hours ago, which keeps this from walking through the entire run history on every run start. Runs of abandoned
+// pipelines don't count as in progress since nothing will ever finish them.
+func (api *API) namespaceRunUsage(namespace string) (runsPerDay int64, concurrentRuns int64, err error) {
+	pipelines, err := api.getAllPipelines(namespace)
+	if err != nil {
+		return 0, 0, fmt.Errorf("could not get pipelines: %w", err)
+	}
+
+	dayAgo := time.Now().Add(-24 * time.Hour).UnixMilli()
+
+	for _, pipeline := range pipelines {
+		offset := 0
+
+	pages:
+		for {
+			runs, err := api.storage.GetAllRuns(storage.GetAllRunsRequest{
+				NamespaceID: namespace,
+				PipelineID:  pipeline.ID,
+				Offset:      offset,
+			})
+			if err != nil {
+				return 0, 0, fmt.Errorf("could not get runs for pipeline %q: %w", pipeline.ID, err)
+			}
+
+			if len(runs) == 0 {
+				break
+			}
+
+			for _, run := range runs {
+				if run.Started < dayAgo && run.IsComplete() {
+					break pages
+				}
+
+				if run.Started >= dayAgo {
+					runsPerDay++
+				}
+
+				if !run.IsComplete() && pipeline.State != models.PipelineStateAbandoned {
+					concurrentRuns++
+				}
+			}
+
+			offset += len(runs)
+		}
+	}
+
+	return runsPerDay, concurrentRuns, nil
+}
@@ -18,6 +18,10 @@ func (api *API) GetNamespace(ctx context.Context, request *proto.GetNamespaceReq
 		return &proto.GetNamespaceResponse{}, status.Error(codes.FailedPrecondition, "id required")
 	}
 
+	if !api.namespaceVisible(ctx, request.Id) {
+		return &proto.GetNamespaceResponse{}, status.Error(codes.FailedPrecondition, "namespace not found")
+	}
+
 	namespace, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: request.Id})
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityNotFound) {
@@ -39,6 +43,10 @@ func (api *API) ListNamespaces(ctx context.Context, request *proto.ListNamespace
 
 	protoNamespaces := []*proto.Namespace{}
 	for _, namespace := range namespaces {
+		if !api.namespaceVisible(ctx, namespace.ID) {
+			continue
+		}
+
 		protoNamespaces = append(protoNamespaces, namespace.ToProto())
 	}
 
@@ -76,14 +84,15 @@ func (api *API) CreateNamespace(ctx context.Context, request *proto.CreateNamesp
 }
 
 func (api *API) UpdateNamespace(ctx context.Context, request *proto.UpdateNamespaceRequest) (*proto.UpdateNamespaceResponse, error) {
-	if !isManagementUser(ctx) {
-		return &proto.UpdateNamespaceResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
-	}
-
 	if request.Id == "" {
 		return &proto.UpdateNamespaceResponse{}, status.Error(codes.FailedPrecondition, "id required")
 	}
 
+	if !isNamespaceAdmin(ctx, request.Id) {
+		return &proto.UpdateNamespaceResponse{}, status.Error(codes.PermissionDenied,
+			"management token or namespace admin token required for this action")
+	}
+
 	// Get the old namespace first so that we can store the old values that we need before inserting
 	// the new values from the content buffer.
 	updatedNamespace, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: request.Id})
@@ -94,8 +103,20 @@ func (api *API) UpdateNamespace(ctx context.Context, request *proto.UpdateNamesp
 		return &proto.UpdateNamespaceResponse{}, err
 	}
 
+	// Exposing a namespace to callers without credentials is left to management users.
+	if request.AllowAnonymous != updatedNamespace.AllowAnonymous && !isManagementUser(ctx) {
+		return &proto.UpdateNamespaceResponse{}, status.Error(codes.PermissionDenied,
+			"management token required to change anonymous access")
+	}
+
+	if request.ObjectExpiry < 0 {
+		return &proto.UpdateNamespaceResponse{}, status.Error(codes.FailedPrecondition, "object expiry cannot be negative")
+	}
+
 	updatedNamespace.Name = request.Name
 	updatedNamespace.Description = request.Description
+	updatedNamespace.AllowAnonymous = request.AllowAnonymous
+	updatedNamespace.ObjectExpiry = request.ObjectExpiry
 
 	err = api.storage.UpdateNamespace(storage.UpdateNamespaceRequest{Namespace: updatedNamespace})
 	if err != nil {
@@ -141,3 +162,78 @@ func (api *API) DeleteNamespace(ctx context.Context, request *proto.DeleteNamesp
 	log.Info().Interface("namespace", updatedNamespace).Msg("deleted namespace")
 	return &proto.DeleteNamespaceResponse{}, nil
 }
+
+func (api *API) GetNamespaceQuota(ctx context.Context, request *proto.GetNamespaceQuotaRequest) (*proto.GetNamespaceQuotaResponse, error) {
+	if request.Id == "" {
+		request.Id = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.Id) {
+		return &proto.GetNamespaceQuotaResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	namespace, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: request.Id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.GetNamespaceQuotaResponse{}, status.Error(codes.FailedPrecondition, "namespace not found")
+		}
+		log.Error().Err(err).Msg("could not get namespace")
+		return &proto.GetNamespaceQuotaResponse{}, status.Error(codes.Internal, "failed to retrieve namespace from database")
+	}
+
+	usage, err := api.namespaceUsage(namespace.ID)
+	if err != nil {
+		log.Error().Err(err).Str("namespace", namespace.ID).Msg("could not calculate namespace usage")
+		return &proto.GetNamespaceQuotaResponse{}, status.Errorf(codes.Internal, "could not calculate namespace usage: %v", err)
+	}
+
+	return &proto.GetNamespaceQuotaResponse{
+		Quota: namespace.Quota.ToProto(),
+		Usage: usage.ToProto(),
+	}, nil
+}
+
+func (api *API) UpdateNamespaceQuota(ctx context.Context, request *proto.UpdateNamespaceQuotaRequest) (*proto.UpdateNamespaceQuotaResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.UpdateNamespaceQuotaResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.Id == "" {
+		return &proto.UpdateNamespaceQuotaResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	if request.Quota == nil {
+		return &proto.UpdateNamespaceQuotaResponse{}, status.Error(codes.FailedPrecondition, "quota required")
+	}
+
+	namespace, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: request.Id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.UpdateNamespaceQuotaResponse{}, status.Error(codes.FailedPrecondition, "could not find namespace")
+		}
+		return &proto.UpdateNamespaceQuotaResponse{}, err
+	}
+
+	quota := models.NamespaceQuota{}
+	quota.FromProto(request.Quota)
+
+	if quota.Pipelines < 0 || quota.RunsPerDay < 0 || quota.ConcurrentRuns < 0 || quota.ObjectBytes < 0 ||
+		quota.Secrets < 0 {
+		return &proto.UpdateNamespaceQuotaResponse{}, status.Error(codes.FailedPrecondition, "quota limits cannot be negative")
+	}
+
+	namespace.Quota = quota
+
+	err = api.storage.UpdateNamespace(storage.UpdateNamespaceRequest{Namespace: namespace})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.UpdateNamespaceQuotaResponse{}, status.Error(codes.FailedPrecondition, "could not find namespace")
+		}
+		return &proto.UpdateNamespaceQuotaResponse{}, err
+	}
+
+	log.Info().Str("namespace", namespace.ID).Interface("quota", quota).Msg("updated namespace quota")
+	return &proto.UpdateNamespaceQuotaResponse{
+		Quota: namespace.Quota.ToProto(),
+	}, nil
+}
@@ -0,0 +1,48 @@
+package api
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestFilterNamespaceSecretKeys(t *testing.T) {
+	namespaces := []*models.Namespace{{ID: "team"}, {ID: "team_a"}, {ID: "registry"}, {ID: "default"}}
+
+	tests := map[string]struct {
+		namespace string
+		keys      []string
+		want      []string
+	}{
+		"own secrets": {
+			namespace: "default",
+			keys:      []string{"default_simple_key", "default_simple_other"},
+			want:      []string{"default_simple_key", "default_simple_other"},
+		},
+		"namespace sharing the prefix": {
+			namespace: "team",
+			keys:      []string{"team_build_key", "team_a_deploy_key"},
+			want:      []string{"team_build_key"},
+		},
+		"longer namespace": {
+			namespace: "team_a",
+			keys:      []string{"team_a_deploy_key"},
+			want:      []string{"team_a_deploy_key"},
+		},
+		"registry credentials": {
+			namespace: "registry",
+			keys:      []string{"registry_build_key", "registry_default_ghcr.io"},
+			want:      []string{"registry_build_key"},
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := filterNamespaceSecretKeys(tc.namespace, tc.keys, namespaces)
+			if !reflect.DeepEqual(got, tc.want) {
+				t.Errorf("expected %v; got %v", tc.want, got)
+			}
+		})
+	}
+}
@@ -0,0 +1,216 @@
+package api
+
+import (
+	"encoding/json"
+	"fmt"
+	"net"
+	"net/http"
+	"net/url"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/eventsink"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+const (
+	// notificationAttempts is how many times a notification is sent to a webhook before giving up.
+	notificationAttempts = 3
+
+	// notificationRetryInterval is how long to wait before the first retry. It doubles after every attempt.
+	notificationRetryInterval = 5 * time.Second
+)
+
+// runNotification is the body posted to a notification policy's webhooks.
+type runNotification struct {
+	Reason      models.NotificationReason `json:"reason"`
+	NamespaceID string                    `json:"namespace_id"`
+	PipelineID  string                    `json:"pipeline_id"`
+	RunID       int64                     `json:"run_id"`
+	State       models.RunState           `json:"state"`
+	Failures    int64                     `json:"previous_failures"` // Failed runs in a row before this one.
+	Ended       int64                     `json:"ended"`
+}
+
+// startNotificationPolicies evaluates the notification policy of a pipeline every time one of its runs completes.
+// Policies are evaluated here instead of by notifiers so that they work the same no matter what is installed.
+func (api *API) startNotificationPolicies() error {
+	subscription, err := api.events.Subscribe(models.CompletedRunEvent)
+	if err != nil {
+		return err
+	}
+
+	// Notification webhooks are written into pipeline configs by namespace users, so they get the same protection
+	// against reaching into the server's own network as regular webhooks.
+	client := newWebhookClient(parseNetworks(api.config.WebhookAllowedNetworks))
+
+	go func() {
+		defer api.events.Unsubscribe(subscription)
+
+		for {
+			select {
+			case <-api.context.ctx.Done():
+				return
+			case event := <-subscription.Events:
+				evt, ok := event.(*models.EventCompletedRun)
+				if !ok {
+					continue
+				}
+
+				api.evaluateNotificationPolicy(client, evt)
+			}
+		}
+	}()
+
+	return nil
+}
+
+func (api *API) evaluateNotificationPolicy(client *http.Client, event *models.EventCompletedRun) {
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{
+		NamespaceID: event.NamespaceID,
+		ID:          event.PipelineID,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", event.PipelineID).Msg("could not get pipeline to evaluate notification policy")
+		return
+	}
+
+	policy := pipeline.NotificationPolicy
+	if policy == nil {
+		return
+	}
+
+	failures, err := api.previousRunFailures(event.NamespaceID, event.PipelineID, event.RunID, policy)
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", event.PipelineID).Int64("run", event.RunID).
+			Msg("could not get previous runs to evaluate notification policy")
+		return
+	}
+
+	reason := policy.Evaluate(event.State, failures)
+	if reason == models.NotificationReasonNone {
+		return
+	}
+
+	body, err := json.Marshal(runNotification{
+		Reason:      reason,
+		NamespaceID: event.NamespaceID,
+		PipelineID:  event.PipelineID,
+		RunID:       event.RunID,
+		State:       event.State,
+		Failures:    failures,
+		Ended:       event.Emitted,
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not encode run notification")
+		return
+	}
+
+	for _, webhook := range policy.Webhooks {
+		go api.sendNotification(client, webhook, models.DeadLetter{
+			Kind:        models.DeadLetterKindRunNotification,
+			NamespaceID: event.NamespaceID,
+			PipelineID:  event.PipelineID,
+		}, body)
+	}
+}
+
+// previousRunFailures counts the runs that failed in a row right before the given run. Counting stops once there
+// are enough failures for the policy to make a decision. Unfinished and cancelled runs are passed over.
+func (api *API) previousRunFailures(namespace, pipeline string, runID int64, policy *models.NotificationPolicy) (int64, error) {
+	enough := policy.OnSuccessAfterFailures
+	if enough < 1 {
+		enough = 1
+	}
+
+	var failures int64
+	offset := 0
+
+	for {
+		runs, err := api.storage.GetAllRuns(storage.GetAllRunsRequest{
+			NamespaceID: namespace,
+			PipelineID:  pipeline,
+			Offset:      offset,
+		})
+		if err != nil {
+			return 0, err
+		}
+
+		if len(runs) == 0 {
+			return failures, nil
+		}
+
+		for _, run := range runs {
+			if run.ID >= runID {
+				continue
+			}
+
+			switch run.State {
+			case models.RunFailed:
+				failures++
+				if failures >= enough {
+					return failures, nil
+				}
+			case models.RunSuccess:
+				return failures, nil
+			}
+		}
+
+		offset += len(runs)
+	}
+}
+
+// configNotificationPolicyIsValid checks that none of the webhooks of a notification policy point at an address
+// notifications can't be sent to. Names are checked once they're resolved on delivery; addresses can be refused
+// straight away.
+func (api *API) configNotificationPolicyIsValid(policy *models.NotificationPolicy) error {
+	if policy == nil {
+		return nil
+	}
+
+	allowed := parseNetworks(api.config.WebhookAllowedNetworks)
+	for _, webhook := range policy.Webhooks {
+		parsed, err := url.Parse(webhook)
+		if err != nil {
+			return fmt.Errorf("notification policy webhook %q is not valid; %w", webhook, ErrWebhookNotAllowed)
+		}
+
+		ip := net.ParseIP(parsed.Hostname())
+		if ip != nil && !webhookDestinationAllowed(ip, allowed) {
+			return fmt.Errorf("notification policy webhook %q can't be sent to private, loopback or link-local "+
+				"addresses; %w", webhook, ErrWebhookNotAllowed)
+		}
+	}
+
+	return nil
+}
+
+// sendNotification posts the body to a webhook, retrying if it fails. Notifications that still fail are saved as a dead
+// letter based on the one given.
+func (api *API) sendNotification(client *http.Client, webhook string, deadLetter models.DeadLetter, body []byte) {
+	retryInterval := notificationRetryInterval
+
+	for attempt := 1; ; attempt++ {
+		_, err := eventsink.Post(client, webhook, "application/json", nil, body)
+		if err == nil {
+			return
+		}
+
+		if attempt == notificationAttempts {
+			deadLetter.Target = webhook
+			deadLetter.Payload = string(body)
+			deadLetter.Attempts = notificationAttempts
+			api.addDeadLetter(&deadLetter, err)
+			return
+		}
+
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-time.After(retryInterval):
+		}
+
+		retryInterval *= 2
+	}
+}
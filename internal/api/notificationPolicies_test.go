@@ -0,0 +1,40 @@
+package api
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestConfigNotificationPolicyIsValid(t *testing.T) {
+	api := &API{config: &config.API{WebhookAllowedNetworks: []string{"10.20.0.0/16"}}}
+
+	tests := map[string]struct {
+		webhook string
+		valid   bool
+	}{
+		"public address":           {webhook: "https://chat.example.com/hooks/deploys", valid: true},
+		"loopback":                 {webhook: "http://127.0.0.1:8080/internal", valid: false},
+		"ipv6 loopback":            {webhook: "http://[::1]/internal", valid: false},
+		"cloud metadata":           {webhook: "http://169.254.169.254/latest/meta-data/", valid: false},
+		"private":                  {webhook: "http://192.168.1.10/hook", valid: false},
+		"private in allowed range": {webhook: "http://10.20.3.4/hook", valid: true},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			err := api.configNotificationPolicyIsValid(&models.NotificationPolicy{
+				OnFailure: true,
+				Webhooks:  []string{tc.webhook},
+			})
+			if tc.valid && err != nil {
+				t.Errorf("expected webhook to be allowed; got %v", err)
+			}
+			if !tc.valid && !errors.Is(err, ErrWebhookNotAllowed) {
+				t.Errorf("expected webhook to be refused; got %v", err)
+			}
+		})
+	}
+}
@@ -0,0 +1,145 @@
+package api
+
+import (
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// defaultObjectExpiryCheckInterval is used when the object store config leaves the expiry check interval unset.
+const defaultObjectExpiryCheckInterval = time.Minute
+
+// pipelineObjectExpiry returns when an object put with the given expiry in seconds should expire in epoch
+// milliseconds; 0 if it shouldn't. Objects that don't ask for an expiry of their own get their namespace's.
+func (api *API) pipelineObjectExpiry(namespace string, expiresAfter int64) (int64, error) {
+	if expiresAfter < 0 {
+		return 0, nil
+	}
+
+	if expiresAfter == 0 {
+		storedNamespace, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: namespace})
+		if err != nil {
+			return 0, fmt.Errorf("could not get namespace: %w", err)
+		}
+
+		expiresAfter = storedNamespace.ObjectExpiry
+	}
+
+	if expiresAfter <= 0 {
+		return 0, nil
+	}
+
+	return time.Now().Add(time.Duration(expiresAfter) * time.Second).UnixMilli(), nil
+}
+
+// removeExpiredObjectsOnSchedule removes pipeline objects past their expiry until the API is shut down.
+func (api *API) removeExpiredObjectsOnSchedule() {
+	interval := api.config.ObjectStore.ExpiryCheckInterval
+	if interval <= 0 {
+		interval = defaultObjectExpiryCheckInterval
+	}
+
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-ticker.C:
+			api.removeExpiredObjects()
+		}
+	}
+}
+
+// removeExpiredObjects deletes every pipeline object whose expiry has passed and publishes an event for each.
+func (api *API) removeExpiredObjects() {
+	namespaces, err := api.getAllNamespaces()
+	if err != nil {
+		log.Error().Err(err).Msg("could not get namespaces to remove expired objects")
+		return
+	}
+
+	now := time.Now().UnixMilli()
+
+	for _, namespace := range namespaces {
+		pipelines, err := api.getAllPipelines(namespace.ID)
+		if err != nil {
+			log.Error().Err(err).Str("namespace", namespace.ID).Msg("could not get pipelines to remove expired objects")
+			continue
+		}
+
+		for _, pipeline := range pipelines {
+			expired := expiredPipelineObjects(pipeline, now)
+			if len(expired) == 0 {
+				continue
+			}
+
+			api.removePipelineObjects(pipeline, expired)
+		}
+	}
+}
+
+// expiredPipelineObjects returns the keys of the pipeline's objects that have expired by the given time.
+func expiredPipelineObjects(pipeline *models.Pipeline, now int64) []string {
+	expired := []string{}
+
+	for _, key := range pipeline.Objects {
+		expires, exists := pipeline.ObjectExpiries[key]
+		if !exists || expires > now {
+			continue
+		}
+
+		expired = append(expired, key)
+	}
+
+	return expired
+}
+
+func (api *API) removePipelineObjects(pipeline *models.Pipeline, keys []string) {
+	removed := map[string]struct{}{}
+
+	for _, key := range keys {
+		err := api.objectStore.DeleteObject(pipelineObjectKey(pipeline.Namespace, pipeline.ID, key))
+		if err != nil && !errors.Is(err, objectstore.ErrEntityNotFound) {
+			log.Error().Err(err).Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+				Str("key", key).Msg("could not remove expired object")
+			continue
+		}
+
+		removed[key] = struct{}{}
+	}
+
+	if len(removed) == 0 {
+		return
+	}
+
+	objects := []string{}
+	for _, key := range pipeline.Objects {
+		if _, exists := removed[key]; exists {
+			delete(pipeline.ObjectExpiries, key)
+			continue
+		}
+		objects = append(objects, key)
+	}
+	pipeline.Objects = objects
+
+	err := api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: pipeline})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+			Msg("could not update pipeline after removing expired objects")
+		return
+	}
+
+	for key := range removed {
+		api.events.Publish(models.NewEventExpiredPipelineObject(pipeline.Namespace, pipeline.ID, key))
+	}
+
+	log.Info().Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).Int("total", len(removed)).
+		Msg("removed expired pipeline objects")
+}
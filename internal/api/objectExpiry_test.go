@@ -0,0 +1,27 @@
+package api
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/google/go-cmp/cmp"
+)
+
+func TestExpiredPipelineObjects(t *testing.T) {
+	pipeline := &models.Pipeline{
+		Objects: []string{"fresh", "stale", "forever", "exact"},
+		ObjectExpiries: map[string]int64{
+			"fresh":   2000,
+			"stale":   500,
+			"exact":   1000,
+			"evicted": 10,
+		},
+	}
+
+	got := expiredPipelineObjects(pipeline, 1000)
+	want := []string{"stale", "exact"}
+
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("unexpected expired objects (-want +got):\n%s", diff)
+	}
+}
@@ -1,9 +1,18 @@
 package api
 
 import (
+	"errors"
 	"fmt"
+	"io"
+	"sort"
+	"strconv"
+	"strings"
 
+	objectlogs "github.com/clintjedwards/gofer/internal/logStore/object"
+	"github.com/clintjedwards/gofer/internal/models"
+	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
 	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
 )
 
 const (
@@ -19,13 +28,34 @@ func runObjectKey(namespace, pipeline, key string, runID int64) string {
 	return fmt.Sprintf(ObjectRunKeyFmt, namespace, pipeline, runID, key)
 }
 
-// addPipelineObject adds an object to the pipeline specific object registry.
-// If this registry is at the limit it removes the least recently added pipeline object and
-// puts the new item on top.
-func (api *API) addPipelineObject(namespace, pipeline, key string, content []byte, force bool) (string, error) {
-	err := api.objectStore.PutObject(pipelineObjectKey(namespace, pipeline, key), content, force)
+// objectSize returns the number of bytes an object takes up within the object store without reading it; 0 if it
+// doesn't exist.
+func (api *API) objectSize(key string) int64 {
+	keys, err := api.objectStore.ListObjectKeys(key)
 	if err != nil {
-		return "", err
+		return 0
+	}
+
+	return keys[key]
+}
+
+// addPipelineObject adds an object to the pipeline specific object registry, streaming the content into the object
+// store as it is read. If this registry is at the limit it removes the least recently added pipeline object and
+// puts the new item on top. The object expires after the given number of seconds; 0 uses the namespace's object
+// expiry and a negative number keeps it until it is evicted.
+//
+// Returns the size of the stored object and the key of the object that was evicted, if any.
+func (api *API) addPipelineObject(namespace, pipeline, key string, content io.Reader, force bool,
+	expiresAfter int64,
+) (int64, string, error) {
+	expires, err := api.pipelineObjectExpiry(namespace, expiresAfter)
+	if err != nil {
+		return 0, "", err
+	}
+
+	size, err := api.objectStore.PutObjectStream(pipelineObjectKey(namespace, pipeline, key), content, force)
+	if err != nil {
+		return 0, "", err
 	}
 
 	currentPipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{
@@ -34,7 +64,7 @@ func (api *API) addPipelineObject(namespace, pipeline, key string, content []byt
 	})
 	if err != nil {
 		_ = api.objectStore.DeleteObject(pipelineObjectKey(namespace, pipeline, key))
-		return "", err
+		return 0, "", err
 	}
 
 	isCurrentKey := false
@@ -48,6 +78,7 @@ func (api *API) addPipelineObject(namespace, pipeline, key string, content []byt
 	if len(currentPipeline.Objects) >= api.config.ObjectStore.PipelineObjectLimit && !isCurrentKey {
 		_ = api.objectStore.DeleteObject(pipelineObjectKey(namespace, pipeline, currentPipeline.Objects[len(currentPipeline.Objects)-1]))
 		evictedObject = currentPipeline.Objects[len(currentPipeline.Objects)-1]
+		delete(currentPipeline.ObjectExpiries, evictedObject)
 		currentPipeline.Objects[len(currentPipeline.Objects)-1] = "" // zero value to prevent memory leaks
 		currentPipeline.Objects = currentPipeline.Objects[:len(currentPipeline.Objects)-1]
 	}
@@ -56,13 +87,22 @@ func (api *API) addPipelineObject(namespace, pipeline, key string, content []byt
 		currentPipeline.Objects = append([]string{key}, currentPipeline.Objects...)
 	}
 
+	if expires == 0 {
+		delete(currentPipeline.ObjectExpiries, key)
+	} else {
+		if currentPipeline.ObjectExpiries == nil {
+			currentPipeline.ObjectExpiries = map[string]int64{}
+		}
+		currentPipeline.ObjectExpiries[key] = expires
+	}
+
 	err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: currentPipeline})
 	if err != nil {
 		_ = api.objectStore.DeleteObject(pipelineObjectKey(namespace, pipeline, key))
-		return "", err
+		return 0, "", err
 	}
 
-	return evictedObject, nil
+	return size, evictedObject, nil
 }
 
 // addRunObject simply adds an object for a specific pipeline run. Run objects only last over a set number of runs.
@@ -88,3 +128,138 @@ func (api *API) addRunObject(namespace, pipeline, key string, runID int64, conte
 
 	return nil
 }
+
+// orphanedObject is an object whose pipeline or run no longer exists.
+type orphanedObject struct {
+	key    string
+	bytes  int64
+	reason string
+}
+
+// findOrphanedObjects returns every object whose pipeline or run no longer exists. Deleting a pipeline or a run leaves
+// its objects behind so without this they stay in the object store forever.
+//
+// Object keys can't be split back into their parts since IDs are allowed to contain the separator. Instead each key
+// is matched against the key prefixes of the pipelines that still exist.
+func (api *API) findOrphanedObjects() ([]orphanedObject, error) {
+	keys, err := api.objectStore.ListObjectKeys("")
+	if err != nil {
+		return nil, fmt.Errorf("could not list objects: %w", err)
+	}
+
+	namespaces, err := api.getAllNamespaces()
+	if err != nil {
+		return nil, fmt.Errorf("could not get namespaces: %w", err)
+	}
+
+	pipelines := map[string]*models.Pipeline{} // Pipelines by the prefix of their object keys.
+	for _, namespace := range namespaces {
+		namespacePipelines, err := api.getAllPipelines(namespace.ID)
+		if err != nil {
+			return nil, fmt.Errorf("could not get pipelines for namespace %q: %w", namespace.ID, err)
+		}
+
+		for _, pipeline := range namespacePipelines {
+			pipelines[pipelineObjectKey(namespace.ID, pipeline.ID, "")] = pipeline
+		}
+	}
+
+	orphans := []orphanedObject{}
+	for key, size := range keys {
+		// Task run logs kept in the object store are removed along with their task runs instead.
+		if strings.HasPrefix(key, objectlogs.KeyPrefix) {
+			continue
+		}
+
+		pipeline, remainder := matchObjectPipeline(pipelines, key)
+		if pipeline == nil {
+			orphans = append(orphans, orphanedObject{key: key, bytes: size, reason: "pipeline no longer exists"})
+			continue
+		}
+
+		if isPipelineObject(pipeline, remainder) {
+			continue
+		}
+
+		// Anything else under the pipeline should be a run object. Keys that don't look like one are left alone since
+		// there is no way to tell who they belong to.
+		rawRunID, _, found := strings.Cut(remainder, "_")
+		if !found {
+			continue
+		}
+
+		runID, err := strconv.ParseInt(rawRunID, 10, 64)
+		if err != nil {
+			continue
+		}
+
+		_, err = api.storage.GetRun(storage.GetRunRequest{NamespaceID: pipeline.Namespace, PipelineID: pipeline.ID, ID: runID})
+		if err == nil {
+			continue
+		}
+
+		if !errors.Is(err, storage.ErrEntityNotFound) {
+			return nil, fmt.Errorf("could not get run %d for pipeline %q: %w", runID, pipeline.ID, err)
+		}
+
+		orphans = append(orphans, orphanedObject{key: key, bytes: size, reason: "run no longer exists"})
+	}
+
+	sort.Slice(orphans, func(i, j int) bool { return orphans[i].key < orphans[j].key })
+
+	return orphans, nil
+}
+
+// matchObjectPipeline returns the pipeline an object key belongs to along with the rest of the key. The longest
+// matching prefix wins so that a pipeline is never mistaken for another pipeline whose ID it starts with.
+func matchObjectPipeline(pipelines map[string]*models.Pipeline, key string) (*models.Pipeline, string) {
+	for i := len(key) - 1; i >= 0; i-- {
+		if key[i] != '_' {
+			continue
+		}
+
+		pipeline, exists := pipelines[key[:i+1]]
+		if exists {
+			return pipeline, key[i+1:]
+		}
+	}
+
+	return nil, ""
+}
+
+func isPipelineObject(pipeline *models.Pipeline, key string) bool {
+	for _, object := range pipeline.Objects {
+		if object == key {
+			return true
+		}
+	}
+
+	return false
+}
+
+// collectOrphanedObjects removes every object whose pipeline or run no longer exists and returns what was removed.
+// A dry run only returns the objects that would have been removed.
+func (api *API) collectOrphanedObjects(dryRun bool) ([]orphanedObject, error) {
+	orphans, err := api.findOrphanedObjects()
+	if err != nil {
+		return nil, err
+	}
+
+	if dryRun {
+		return orphans, nil
+	}
+
+	var bytes int64
+	for _, orphan := range orphans {
+		err := api.objectStore.DeleteObject(orphan.key)
+		if err != nil && !errors.Is(err, objectstore.ErrEntityNotFound) {
+			return nil, fmt.Errorf("could not delete object %q: %w", orphan.key, err)
+		}
+
+		bytes += orphan.bytes
+	}
+
+	log.Info().Int("objects", len(orphans)).Int64("bytes", bytes).Msg("removed orphaned objects")
+
+	return orphans, nil
+}
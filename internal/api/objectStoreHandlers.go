@@ -1,12 +1,15 @@
 package api
 
 import (
+	"bytes"
 	"context"
 	"errors"
 	"fmt"
+	"io"
 
 	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
 	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
 )
@@ -30,6 +33,63 @@ func (api *API) GetPipelineObject(ctx context.Context, request *proto.GetPipelin
 	}, nil
 }
 
+// objectStreamChunkSize is the most content sent in a single message when streaming an object to a client.
+const objectStreamChunkSize = 1024 * 1024 // 1MiB
+
+// GetPipelineObjectStream sends the object in chunks read straight from the object store so that neither side has to
+// hold the entire object in memory. Clients that lose their connection can pick up where they left off by passing
+// the number of bytes they already have as the offset.
+func (api *API) GetPipelineObjectStream(request *proto.GetPipelineObjectStreamRequest, stream proto.Gofer_GetPipelineObjectStreamServer) error {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(stream.Context())
+	}
+
+	if !hasAccess(stream.Context(), request.NamespaceId) {
+		return status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	reader, size, err := api.objectStore.GetObjectStream(pipelineObjectKey(request.NamespaceId, request.PipelineId,
+		request.Key), request.Offset)
+	if err != nil {
+		if errors.Is(err, objectstore.ErrEntityNotFound) {
+			return status.Error(codes.NotFound, fmt.Sprintf("could not find object %q", request.Key))
+		}
+		if errors.Is(err, objectstore.ErrPreconditionFailure) {
+			return status.Error(codes.FailedPrecondition, fmt.Sprintf("could not read object %q; %v", request.Key, err))
+		}
+		return status.Error(codes.Internal, fmt.Sprintf("could not read object %q; %v", request.Key, err))
+	}
+	defer reader.Close()
+
+	buffer := make([]byte, objectStreamChunkSize)
+	sent := false
+
+	for {
+		n, readErr := io.ReadFull(reader, buffer)
+		if readErr != nil && !errors.Is(readErr, io.EOF) && !errors.Is(readErr, io.ErrUnexpectedEOF) {
+			return status.Error(codes.Internal, fmt.Sprintf("could not read object %q; %v", request.Key, readErr))
+		}
+
+		// The first message carries the size of the object so it has to be sent even if there is no content left.
+		if n > 0 || !sent {
+			response := &proto.GetPipelineObjectStreamResponse{Content: buffer[:n]}
+			if !sent {
+				response.Size = size
+			}
+
+			err := stream.Send(response)
+			if err != nil {
+				return err
+			}
+			sent = true
+		}
+
+		if readErr != nil {
+			return nil
+		}
+	}
+}
+
 func (api *API) PutPipelineObject(ctx context.Context, request *proto.PutPipelineObjectRequest) (*proto.PutPipelineObjectResponse, error) {
 	if request.NamespaceId == "" {
 		request.NamespaceId = determineNamespace(ctx)
@@ -39,8 +99,14 @@ func (api *API) PutPipelineObject(ctx context.Context, request *proto.PutPipelin
 		return &proto.PutPipelineObjectResponse{}, status.Error(codes.PermissionDenied, "access denied")
 	}
 
-	evictedObject, err := api.addPipelineObject(request.NamespaceId,
-		request.PipelineId, request.Key, request.Content, request.Force)
+	err := api.checkObjectQuota(request.NamespaceId, int64(len(request.Content))-
+		api.objectSize(pipelineObjectKey(request.NamespaceId, request.PipelineId, request.Key)))
+	if err != nil {
+		return &proto.PutPipelineObjectResponse{}, objectQuotaError(request.Key, err)
+	}
+
+	size, evictedObject, err := api.addPipelineObject(request.NamespaceId,
+		request.PipelineId, request.Key, bytes.NewReader(request.Content), request.Force, request.ExpiresAfter)
 	if err != nil {
 		if errors.Is(err, objectstore.ErrEntityExists) {
 			return &proto.PutPipelineObjectResponse{}, status.Error(codes.FailedPrecondition,
@@ -50,12 +116,100 @@ func (api *API) PutPipelineObject(ctx context.Context, request *proto.PutPipelin
 	}
 
 	return &proto.PutPipelineObjectResponse{
-		Bytes:         int64(len(request.Content)),
+		Bytes:         size,
 		ObjectLimit:   int64(api.config.ObjectStore.PipelineObjectLimit),
 		ObjectEvicted: evictedObject,
 	}, nil
 }
 
+// PutPipelineObjectStream reads the object in chunks so that neither the client nor the server has to hold the entire
+// object in memory; chunks are written to the object store as they arrive. The object only replaces the one stored
+// under its key once the client has finished sending it, so a connection that is reset midway leaves nothing behind
+// and the upload can be safely retried.
+func (api *API) PutPipelineObjectStream(stream proto.Gofer_PutPipelineObjectStreamServer) error {
+	request, err := stream.Recv()
+	if err != nil {
+		return status.Error(codes.InvalidArgument, fmt.Sprintf("could not read object; %v", err))
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(stream.Context())
+	}
+
+	if !hasAccess(stream.Context(), request.NamespaceId) {
+		return status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	err = api.checkObjectQuota(request.NamespaceId, 0)
+	if err != nil {
+		return objectQuotaError(request.Key, err)
+	}
+
+	content := &objectUploadReader{stream: stream, pending: request.Content}
+
+	size, evictedObject, err := api.addPipelineObject(request.NamespaceId,
+		request.PipelineId, request.Key, content, request.Force, request.ExpiresAfter)
+	if err != nil {
+		// The client went away before the object was fully sent.
+		if content.err != nil {
+			return content.err
+		}
+		if errors.Is(err, objectstore.ErrEntityExists) {
+			return status.Error(codes.FailedPrecondition,
+				fmt.Sprintf("object already exists for key %q; try using the 'force' to overwrite", request.Key))
+		}
+		return status.Error(codes.Internal, fmt.Sprintf("could not put object %q; %v", request.Key, err))
+	}
+
+	return stream.SendAndClose(&proto.PutPipelineObjectResponse{
+		Bytes:         size,
+		ObjectLimit:   int64(api.config.ObjectStore.PipelineObjectLimit),
+		ObjectEvicted: evictedObject,
+	})
+}
+
+// objectUploadReader reads the content of an object as it arrives on an upload stream.
+type objectUploadReader struct {
+	stream  proto.Gofer_PutPipelineObjectStreamServer
+	pending []byte
+	done    bool
+	err     error // The error that ended the stream early, if any.
+}
+
+func (r *objectUploadReader) Read(p []byte) (int, error) {
+	for len(r.pending) == 0 {
+		if r.done {
+			return 0, io.EOF
+		}
+
+		chunk, err := r.stream.Recv()
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				r.done = true
+				continue
+			}
+
+			r.err = err
+			return 0, err
+		}
+
+		r.pending = chunk.Content
+	}
+
+	n := copy(p, r.pending)
+	r.pending = r.pending[n:]
+	return n, nil
+}
+
+// objectQuotaError converts the error returned when checking a namespace's object quota into a status error.
+func objectQuotaError(key string, err error) error {
+	if errors.Is(err, ErrQuotaExceeded) {
+		return status.Error(codes.ResourceExhausted, fmt.Sprintf("could not put object %q; %v", key, err))
+	}
+
+	return status.Error(codes.Internal, fmt.Sprintf("could not put object %q; %v", key, err))
+}
+
 func (api *API) DeletePipelineObject(ctx context.Context, request *proto.DeletePipelineObjectRequest) (*proto.DeletePipelineObjectResponse, error) {
 	if request.NamespaceId == "" {
 		request.NamespaceId = determineNamespace(ctx)
@@ -101,7 +255,13 @@ func (api *API) PutRunObject(ctx context.Context, request *proto.PutRunObjectReq
 		return &proto.PutRunObjectResponse{}, status.Error(codes.PermissionDenied, "access denied")
 	}
 
-	err := api.addRunObject(request.NamespaceId,
+	err := api.checkObjectQuota(request.NamespaceId, int64(len(request.Content))-
+		api.objectSize(runObjectKey(request.NamespaceId, request.PipelineId, request.Key, request.RunId)))
+	if err != nil {
+		return &proto.PutRunObjectResponse{}, objectQuotaError(request.Key, err)
+	}
+
+	err = api.addRunObject(request.NamespaceId,
 		request.PipelineId, request.Key, request.RunId, request.Content, request.Force)
 	if err != nil {
 		if errors.Is(err, objectstore.ErrEntityExists) {
@@ -116,6 +276,55 @@ func (api *API) PutRunObject(ctx context.Context, request *proto.PutRunObjectReq
 	}, nil
 }
 
+// PutRunObjectStream reads the object in chunks so clients don't have to hold the entire object in memory. Much like
+// its pipeline counterpart the object is only written once the client has finished sending it.
+func (api *API) PutRunObjectStream(stream proto.Gofer_PutRunObjectStreamServer) error {
+	request, err := stream.Recv()
+	if err != nil {
+		return status.Error(codes.InvalidArgument, fmt.Sprintf("could not read object; %v", err))
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(stream.Context())
+	}
+
+	if !hasAccess(stream.Context(), request.NamespaceId) {
+		return status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	content := bytes.NewBuffer(request.Content)
+	for {
+		chunk, err := stream.Recv()
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				break
+			}
+			return err
+		}
+		content.Write(chunk.Content)
+	}
+
+	err = api.checkObjectQuota(request.NamespaceId, int64(content.Len())-
+		api.objectSize(runObjectKey(request.NamespaceId, request.PipelineId, request.Key, request.RunId)))
+	if err != nil {
+		return objectQuotaError(request.Key, err)
+	}
+
+	err = api.addRunObject(request.NamespaceId,
+		request.PipelineId, request.Key, request.RunId, content.Bytes(), request.Force)
+	if err != nil {
+		if errors.Is(err, objectstore.ErrEntityExists) {
+			return status.Error(codes.FailedPrecondition,
+				fmt.Sprintf("object already exists for key %q; try using the '--force' flag to overwrite", request.Key))
+		}
+		return status.Error(codes.Internal, fmt.Sprintf("could not put object %q; %v", request.Key, err))
+	}
+
+	return stream.SendAndClose(&proto.PutRunObjectResponse{
+		Bytes: int64(content.Len()),
+	})
+}
+
 func (api *API) DeleteRunObject(ctx context.Context, request *proto.DeleteRunObjectRequest) (*proto.DeleteRunObjectResponse, error) {
 	if request.NamespaceId == "" {
 		request.NamespaceId = determineNamespace(ctx)
@@ -132,3 +341,29 @@ func (api *API) DeleteRunObject(ctx context.Context, request *proto.DeleteRunObj
 
 	return &proto.DeleteRunObjectResponse{}, nil
 }
+
+func (api *API) CollectOrphanedObjects(ctx context.Context, request *proto.CollectOrphanedObjectsRequest) (*proto.CollectOrphanedObjectsResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.CollectOrphanedObjectsResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	orphans, err := api.collectOrphanedObjects(request.DryRun)
+	if err != nil {
+		log.Error().Err(err).Msg("could not collect orphaned objects")
+		return &proto.CollectOrphanedObjectsResponse{}, status.Error(codes.Internal, fmt.Sprintf("could not collect orphaned objects; %v", err))
+	}
+
+	response := &proto.CollectOrphanedObjectsResponse{
+		Objects: []*proto.OrphanedObject{},
+	}
+	for _, orphan := range orphans {
+		response.Objects = append(response.Objects, &proto.OrphanedObject{
+			Key:    orphan.key,
+			Bytes:  orphan.bytes,
+			Reason: orphan.reason,
+		})
+		response.Bytes += orphan.bytes
+	}
+
+	return response, nil
+}
@@ -0,0 +1,43 @@
+package api
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestMatchObjectPipeline(t *testing.T) {
+	pipelines := map[string]*models.Pipeline{
+		pipelineObjectKey("default", "build", ""):     {ID: "build", Namespace: "default"},
+		pipelineObjectKey("default", "build_app", ""): {ID: "build_app", Namespace: "default"},
+	}
+
+	tests := map[string]struct {
+		key       string
+		pipeline  string
+		remainder string
+	}{
+		"pipeline object":          {pipelineObjectKey("default", "build", "cache"), "build", "cache"},
+		"run object":               {runObjectKey("default", "build", "cache", 4), "build", "4_cache"},
+		"longest prefix":           {pipelineObjectKey("default", "build_app", "cache"), "build_app", "cache"},
+		"deleted pipeline":         {pipelineObjectKey("default", "deploy", "cache"), "", ""},
+		"deleted namespace":        {pipelineObjectKey("other", "build", "cache"), "", ""},
+		"key containing separator": {pipelineObjectKey("default", "build", "go_cache"), "build", "go_cache"},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			pipeline, remainder := matchObjectPipeline(pipelines, tc.key)
+
+			id := ""
+			if pipeline != nil {
+				id = pipeline.ID
+			}
+
+			if id != tc.pipeline || remainder != tc.remainder {
+				t.Errorf("expected pipeline %q with remainder %q; got pipeline %q with remainder %q",
+					tc.pipeline, tc.remainder, id, remainder)
+			}
+		})
+	}
+}
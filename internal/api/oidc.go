@@ -0,0 +1,527 @@
+package api
+
+import (
+	"context"
+	"crypto"
+	"crypto/ecdsa"
+	"crypto/elliptic"
+	"crypto/rsa"
+	"crypto/sha256"
+	"crypto/sha512"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"hash"
+	"math/big"
+	"net/http"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const (
+	// oidcClockSkew is the amount of leeway given when checking the time based claims of an ID token.
+	oidcClockSkew = time.Minute
+
+	// oidcKeyRefreshInterval is the minimum amount of time between refreshes of the provider's signing keys. This
+	// prevents tokens with made up key IDs from causing a request to the provider each.
+	oidcKeyRefreshInterval = time.Minute
+)
+
+// oidcDiscovery is the subset of the identity provider's discovery document that Gofer uses.
+type oidcDiscovery struct {
+	Issuer                      string `json:"issuer"`
+	DeviceAuthorizationEndpoint string `json:"device_authorization_endpoint"`
+	TokenEndpoint               string `json:"token_endpoint"`
+	JWKSURI                     string `json:"jwks_uri"`
+}
+
+// oidcVerifier validates ID tokens issued by the configured identity provider and maps the groups of the user to
+// Gofer permissions.
+type oidcVerifier struct {
+	config    *config.OIDC
+	discovery oidcDiscovery
+	client    *http.Client
+
+	mu        sync.Mutex
+	keys      map[string]signingKey // Signing keys of the provider by key ID.
+	refreshed time.Time             // Last time the signing keys were retrieved.
+}
+
+// signingKey is a signing key published by the provider.
+type signingKey struct {
+	key crypto.PublicKey
+
+	// alg is the algorithm the provider published the key for. Empty if the key may be used with any algorithm that
+	// fits it.
+	alg string
+}
+
+func newOIDCVerifier(conf *config.OIDC) (*oidcVerifier, error) {
+	verifier := &oidcVerifier{
+		config: conf,
+		client: &http.Client{Timeout: 10 * time.Second},
+		keys:   map[string]signingKey{},
+	}
+
+	discoveryURL := strings.TrimSuffix(conf.Issuer, "/") + "/.well-known/openid-configuration"
+	err := verifier.getJSON(discoveryURL, &verifier.discovery)
+	if err != nil {
+		return nil, fmt.Errorf("could not retrieve discovery document from %q: %w", discoveryURL, err)
+	}
+
+	if verifier.discovery.Issuer != conf.Issuer {
+		return nil, fmt.Errorf("issuer %q in discovery document does not match configured issuer %q",
+			verifier.discovery.Issuer, conf.Issuer)
+	}
+
+	err = verifier.refreshKeys()
+	if err != nil {
+		return nil, fmt.Errorf("could not retrieve signing keys: %w", err)
+	}
+
+	return verifier, nil
+}
+
+func (v *oidcVerifier) getJSON(url string, target interface{}) error {
+	resp, err := v.client.Get(url)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("unexpected status %q", resp.Status)
+	}
+
+	return json.NewDecoder(resp.Body).Decode(target)
+}
+
+// jsonWebKey is a single key from the provider's JWKS document.
+type jsonWebKey struct {
+	Kid string `json:"kid"`
+	Kty string `json:"kty"`
+	Use string `json:"use"`
+	Alg string `json:"alg"`
+	N   string `json:"n"`
+	E   string `json:"e"`
+	Crv string `json:"crv"`
+	X   string `json:"x"`
+	Y   string `json:"y"`
+}
+
+func (k *jsonWebKey) publicKey() (crypto.PublicKey, error) {
+	switch k.Kty {
+	case "RSA":
+		n, err := base64.RawURLEncoding.DecodeString(k.N)
+		if err != nil {
+			return nil, err
+		}
+		e, err := base64.RawURLEncoding.DecodeString(k.E)
+		if err != nil {
+			return nil, err
+		}
+
+		modulus := new(big.Int).SetBytes(n)
+		if modulus.Sign() == 0 {
+			return nil, fmt.Errorf("missing rsa modulus")
+		}
+
+		// The exponent has to fit an int and be odd; anything else is either malformed or a key that can't be used
+		// to verify signatures safely.
+		exponent := new(big.Int).SetBytes(e)
+		if exponent.BitLen() > 31 || exponent.Int64() < 3 || exponent.Bit(0) == 0 {
+			return nil, fmt.Errorf("invalid rsa exponent")
+		}
+
+		return &rsa.PublicKey{
+			N: modulus,
+			E: int(exponent.Int64()),
+		}, nil
+	case "EC":
+		var curve elliptic.Curve
+		switch k.Crv {
+		case "P-256":
+			curve = elliptic.P256()
+		case "P-384":
+			curve = elliptic.P384()
+		case "P-521":
+			curve = elliptic.P521()
+		default:
+			return nil, fmt.Errorf("unsupported curve %q", k.Crv)
+		}
+
+		x, err := base64.RawURLEncoding.DecodeString(k.X)
+		if err != nil {
+			return nil, err
+		}
+		y, err := base64.RawURLEncoding.DecodeString(k.Y)
+		if err != nil {
+			return nil, err
+		}
+
+		publicKey := &ecdsa.PublicKey{
+			Curve: curve,
+			X:     new(big.Int).SetBytes(x),
+			Y:     new(big.Int).SetBytes(y),
+		}
+
+		if !curve.IsOnCurve(publicKey.X, publicKey.Y) {
+			return nil, fmt.Errorf("ec point is not on curve %q", k.Crv)
+		}
+
+		return publicKey, nil
+	default:
+		return nil, fmt.Errorf("unsupported key type %q", k.Kty)
+	}
+}
+
+// refreshKeys replaces the cached signing keys with the ones currently published by the provider.
+func (v *oidcVerifier) refreshKeys() error {
+	jwks := struct {
+		Keys []jsonWebKey `json:"keys"`
+	}{}
+
+	err := v.getJSON(v.discovery.JWKSURI, &jwks)
+	if err != nil {
+		return err
+	}
+
+	keys := map[string]signingKey{}
+	for _, key := range jwks.Keys {
+		if key.Use != "" && key.Use != "sig" {
+			continue
+		}
+
+		publicKey, err := key.publicKey()
+		if err != nil {
+			log.Debug().Err(err).Str("kid", key.Kid).Msg("skipping unusable oidc signing key")
+			continue
+		}
+
+		keys[key.Kid] = signingKey{key: publicKey, alg: key.Alg}
+	}
+
+	v.mu.Lock()
+	v.keys = keys
+	v.refreshed = time.Now()
+	v.mu.Unlock()
+
+	return nil
+}
+
+// key returns the signing key with the given ID. Providers rotate their keys, so the keys are refreshed when an
+// unknown key ID is encountered.
+func (v *oidcVerifier) key(kid string) (signingKey, error) {
+	v.mu.Lock()
+	key, exists := v.keys[kid]
+	refreshed := v.refreshed
+	v.mu.Unlock()
+
+	if exists {
+		return key, nil
+	}
+
+	if time.Since(refreshed) < oidcKeyRefreshInterval {
+		return signingKey{}, fmt.Errorf("unknown signing key %q", kid)
+	}
+
+	err := v.refreshKeys()
+	if err != nil {
+		return signingKey{}, err
+	}
+
+	v.mu.Lock()
+	defer v.mu.Unlock()
+
+	key, exists = v.keys[kid]
+	if !exists {
+		return signingKey{}, fmt.Errorf("unknown signing key %q", kid)
+	}
+
+	return key, nil
+}
+
+// stringOrList handles claims(ex. "aud") that may either be a single string or a list of strings.
+type stringOrList []string
+
+func (a *stringOrList) UnmarshalJSON(data []byte) error {
+	var single string
+	if err := json.Unmarshal(data, &single); err == nil {
+		*a = []string{single}
+		return nil
+	}
+
+	var list []string
+	if err := json.Unmarshal(data, &list); err != nil {
+		return err
+	}
+
+	*a = list
+	return nil
+}
+
+// oidcClaims are the claims of a verified ID token that Gofer uses.
+type oidcClaims struct {
+	Subject string
+	Email   string
+	Groups  []string
+
+	// ClaimMappings are the configured claim mappings the token matched.
+	ClaimMappings []config.ClaimMapping
+}
+
+// verify checks the signature and the standard claims of the given ID token and returns the user's claims.
+func (v *oidcVerifier) verify(rawToken string) (*oidcClaims, error) {
+	parts := strings.Split(rawToken, ".")
+	if len(parts) != 3 {
+		return nil, fmt.Errorf("malformed token")
+	}
+
+	headerRaw, err := base64.RawURLEncoding.DecodeString(parts[0])
+	if err != nil {
+		return nil, fmt.Errorf("malformed token header: %w", err)
+	}
+
+	header := struct {
+		Alg string `json:"alg"`
+		Kid string `json:"kid"`
+	}{}
+	err = json.Unmarshal(headerRaw, &header)
+	if err != nil {
+		return nil, fmt.Errorf("malformed token header: %w", err)
+	}
+
+	signature, err := base64.RawURLEncoding.DecodeString(parts[2])
+	if err != nil {
+		return nil, fmt.Errorf("malformed token signature: %w", err)
+	}
+
+	key, err := v.key(header.Kid)
+	if err != nil {
+		return nil, err
+	}
+
+	// Keys published for one algorithm must not be usable with another one the token names instead.
+	if key.alg != "" && key.alg != header.Alg {
+		return nil, fmt.Errorf("token signed with %q; signing key %q is for %q", header.Alg, header.Kid, key.alg)
+	}
+
+	err = verifySignature(header.Alg, key.key, []byte(parts[0]+"."+parts[1]), signature)
+	if err != nil {
+		return nil, err
+	}
+
+	payload, err := base64.RawURLEncoding.DecodeString(parts[1])
+	if err != nil {
+		return nil, fmt.Errorf("malformed token payload: %w", err)
+	}
+
+	standardClaims := struct {
+		Issuer    string       `json:"iss"`
+		Subject   string       `json:"sub"`
+		Audience  stringOrList `json:"aud"`
+		Expiry    int64        `json:"exp"`
+		NotBefore int64        `json:"nbf"`
+		Email     string       `json:"email"`
+	}{}
+	err = json.Unmarshal(payload, &standardClaims)
+	if err != nil {
+		return nil, fmt.Errorf("malformed token payload: %w", err)
+	}
+
+	if standardClaims.Issuer != v.config.Issuer {
+		return nil, fmt.Errorf("token issued by %q; expected %q", standardClaims.Issuer, v.config.Issuer)
+	}
+
+	audienceMatches := false
+	for _, audience := range standardClaims.Audience {
+		if audience == v.config.ClientID {
+			audienceMatches = true
+			break
+		}
+	}
+	if !audienceMatches {
+		return nil, fmt.Errorf("token was not issued for client %q", v.config.ClientID)
+	}
+
+	now := time.Now()
+	if standardClaims.Expiry == 0 || now.After(time.Unix(standardClaims.Expiry, 0).Add(oidcClockSkew)) {
+		return nil, fmt.Errorf("token expired")
+	}
+	if standardClaims.NotBefore != 0 && now.Add(oidcClockSkew).Before(time.Unix(standardClaims.NotBefore, 0)) {
+		return nil, fmt.Errorf("token not valid yet")
+	}
+
+	allClaims := map[string]json.RawMessage{}
+	err = json.Unmarshal(payload, &allClaims)
+	if err != nil {
+		return nil, fmt.Errorf("malformed token payload: %w", err)
+	}
+
+	groupsClaim := v.config.GroupsClaim
+	if groupsClaim == "" {
+		groupsClaim = "groups"
+	}
+
+	groups := stringOrList{}
+	if rawGroups, exists := allClaims[groupsClaim]; exists {
+		err = json.Unmarshal(rawGroups, &groups)
+		if err != nil {
+			return nil, fmt.Errorf("malformed %q claim: %w", groupsClaim, err)
+		}
+	}
+
+	var claimMappings []config.ClaimMapping
+	for _, mapping := range v.config.ClaimMappings {
+		if rawClaim, exists := allClaims[mapping.Claim]; exists && claimMatches(rawClaim, mapping.Value) {
+			claimMappings = append(claimMappings, mapping)
+		}
+	}
+
+	return &oidcClaims{
+		Subject:       standardClaims.Subject,
+		Email:         standardClaims.Email,
+		Groups:        groups,
+		ClaimMappings: claimMappings,
+	}, nil
+}
+
+// claimMatches returns whether the given claim is or contains the value. Claims that aren't strings or lists of
+// strings are compared in their JSON form.
+func claimMatches(rawClaim json.RawMessage, value string) bool {
+	values := stringOrList{}
+	err := json.Unmarshal(rawClaim, &values)
+	if err != nil {
+		return strings.TrimSpace(string(rawClaim)) == value
+	}
+
+	for _, claim := range values {
+		if claim == value {
+			return true
+		}
+	}
+
+	return false
+}
+
+// permissions returns the combined permissions of every group and claim mapping the user matches. ok is false if
+// they match none.
+func (v *oidcVerifier) permissions(claims *oidcClaims) (kind models.TokenKind, namespaces []string, ok bool) {
+	mappings := matchingGroupMappings(v.config.GroupMappings, claims.Groups)
+	for _, mapping := range claims.ClaimMappings {
+		mappings = append(mappings, config.GroupMapping{
+			Group:      mapping.Claim,
+			Kind:       mapping.Kind,
+			Namespaces: mapping.Namespaces,
+		})
+	}
+
+	return combinedPermissions(mappings)
+}
+
+// ecdsaCurves is the curve each ECDSA signing algorithm is defined for.
+var ecdsaCurves = map[string]elliptic.Curve{
+	"ES256": elliptic.P256(),
+	"ES384": elliptic.P384(),
+	"ES512": elliptic.P521(),
+}
+
+func verifySignature(alg string, key crypto.PublicKey, signed, signature []byte) error {
+	if len(alg) != 5 {
+		return fmt.Errorf("unsupported signing algorithm %q", alg)
+	}
+
+	var hasher hash.Hash
+	var hashType crypto.Hash
+	switch alg[2:] {
+	case "256":
+		hasher, hashType = sha256.New(), crypto.SHA256
+	case "384":
+		hasher, hashType = sha512.New384(), crypto.SHA384
+	case "512":
+		hasher, hashType = sha512.New(), crypto.SHA512
+	default:
+		return fmt.Errorf("unsupported signing algorithm %q", alg)
+	}
+	hasher.Write(signed)
+	digest := hasher.Sum(nil)
+
+	switch {
+	case strings.HasPrefix(alg, "RS"):
+		rsaKey, ok := key.(*rsa.PublicKey)
+		if !ok {
+			return fmt.Errorf("signing algorithm %q does not match key type", alg)
+		}
+		err := rsa.VerifyPKCS1v15(rsaKey, hashType, digest, signature)
+		if err != nil {
+			return fmt.Errorf("invalid token signature")
+		}
+	case strings.HasPrefix(alg, "ES"):
+		ecKey, ok := key.(*ecdsa.PublicKey)
+		if !ok {
+			return fmt.Errorf("signing algorithm %q does not match key type", alg)
+		}
+
+		if ecKey.Curve != ecdsaCurves[alg] {
+			return fmt.Errorf("signing algorithm %q does not match key curve %q", alg, ecKey.Curve.Params().Name)
+		}
+
+		size := (ecKey.Curve.Params().BitSize + 7) / 8
+		if len(signature) != 2*size {
+			return fmt.Errorf("invalid token signature")
+		}
+
+		r := new(big.Int).SetBytes(signature[:size])
+		s := new(big.Int).SetBytes(signature[size:])
+		if !ecdsa.Verify(ecKey, digest, r, s) {
+			return fmt.Errorf("invalid token signature")
+		}
+	default:
+		return fmt.Errorf("unsupported signing algorithm %q", alg)
+	}
+
+	return nil
+}
+
+// isJWT returns whether the token is in JWT form. Gofer's own tokens are hex strings and never contain dots.
+func isJWT(token string) bool {
+	return strings.Count(token, ".") == 2
+}
+
+// authenticateOIDC verifies an ID token and populates the context with the permissions mapped from the user's groups.
+func (api *API) authenticateOIDC(ctx context.Context, token string) (context.Context, error) {
+	return authenticateJWT(ctx, api.oidc, token, models.CallerOIDC)
+}
+
+// authenticateJWT verifies a JWT with the given verifier and populates the context with the permissions mapped from
+// the caller's groups. The caller function determines how the caller is referred to in the audit log and by pipeline
+// owners.
+func authenticateJWT(ctx context.Context, verifier *oidcVerifier, token string, caller func(subject string) string) (context.Context, error) {
+	claims, err := verifier.verify(token)
+	if err != nil {
+		log.Debug().Err(err).Str("issuer", verifier.config.Issuer).Msg("could not verify jwt")
+		return ctx, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	recordCaller(ctx, caller(claims.Subject))
+	ctx = withIdentity(ctx, caller(claims.Subject))
+
+	kind, namespaces, ok := verifier.permissions(claims)
+	if !ok {
+		log.Debug().Str("issuer", verifier.config.Issuer).Str("subject", claims.Subject).Strs("groups", claims.Groups).
+			Msg("jwt subject does not match any group or claim mappings")
+		return ctx, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	ctxNamespaces := context.WithValue(ctx, contextUserNamespaces, namespaces)
+	ctxKind := context.WithValue(ctxNamespaces, contextUserKind, string(kind))
+
+	return ctxKind, nil
+}
@@ -0,0 +1,344 @@
+package api
+
+import (
+	"crypto"
+	"crypto/ecdsa"
+	"crypto/elliptic"
+	"crypto/rand"
+	"crypto/rsa"
+	"crypto/sha256"
+	"crypto/sha512"
+	"encoding/base64"
+	"encoding/json"
+	"math/big"
+	"testing"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/google/go-cmp/cmp"
+)
+
+func signTestToken(t *testing.T, key *rsa.PrivateKey, kid string, claims map[string]interface{}) string {
+	header, _ := json.Marshal(map[string]string{"alg": "RS256", "kid": kid})
+	payload, _ := json.Marshal(claims)
+
+	signed := base64.RawURLEncoding.EncodeToString(header) + "." + base64.RawURLEncoding.EncodeToString(payload)
+	digest := sha256.Sum256([]byte(signed))
+
+	signature, err := rsa.SignPKCS1v15(rand.Reader, key, crypto.SHA256, digest[:])
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	return signed + "." + base64.RawURLEncoding.EncodeToString(signature)
+}
+
+func signTestECToken(t *testing.T, key *ecdsa.PrivateKey, alg, kid string, claims map[string]interface{}) string {
+	header, _ := json.Marshal(map[string]string{"alg": alg, "kid": kid})
+	payload, _ := json.Marshal(claims)
+
+	signed := base64.RawURLEncoding.EncodeToString(header) + "." + base64.RawURLEncoding.EncodeToString(payload)
+
+	var digest []byte
+	switch alg {
+	case "ES256":
+		sum := sha256.Sum256([]byte(signed))
+		digest = sum[:]
+	case "ES384":
+		sum := sha512.Sum384([]byte(signed))
+		digest = sum[:]
+	default:
+		sum := sha512.Sum512([]byte(signed))
+		digest = sum[:]
+	}
+
+	r, s, err := ecdsa.Sign(rand.Reader, key, digest)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	size := (key.Curve.Params().BitSize + 7) / 8
+	signature := make([]byte, 2*size)
+	r.FillBytes(signature[:size])
+	s.FillBytes(signature[size:])
+
+	return signed + "." + base64.RawURLEncoding.EncodeToString(signature)
+}
+
+func TestOIDCVerify(t *testing.T) {
+	key, err := rsa.GenerateKey(rand.Reader, 2048)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	otherKey, err := rsa.GenerateKey(rand.Reader, 2048)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	verifier := &oidcVerifier{
+		config: &config.OIDC{
+			Issuer:      "https://idp.example.com",
+			ClientID:    "gofer",
+			GroupsClaim: "groups",
+		},
+		keys:      map[string]signingKey{"test": {key: &key.PublicKey}},
+		refreshed: time.Now(),
+	}
+
+	validClaims := func() map[string]interface{} {
+		return map[string]interface{}{
+			"iss":    "https://idp.example.com",
+			"sub":    "user1",
+			"aud":    "gofer",
+			"exp":    time.Now().Add(time.Hour).Unix(),
+			"groups": []string{"admins", "team-a"},
+		}
+	}
+
+	claims, err := verifier.verify(signTestToken(t, key, "test", validClaims()))
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if diff := cmp.Diff(&oidcClaims{Subject: "user1", Groups: []string{"admins", "team-a"}}, claims); diff != "" {
+		t.Errorf("unexpected claims (-want +got):\n%s", diff)
+	}
+
+	tests := map[string]struct {
+		key    *rsa.PrivateKey
+		kid    string
+		modify func(map[string]interface{})
+	}{
+		"wrong signature": {key: otherKey, kid: "test", modify: func(map[string]interface{}) {}},
+		"unknown key":     {key: key, kid: "unknown", modify: func(map[string]interface{}) {}},
+		"wrong issuer":    {key: key, kid: "test", modify: func(c map[string]interface{}) { c["iss"] = "https://evil.example.com" }},
+		"wrong audience":  {key: key, kid: "test", modify: func(c map[string]interface{}) { c["aud"] = []string{"other"} }},
+		"expired":         {key: key, kid: "test", modify: func(c map[string]interface{}) { c["exp"] = time.Now().Add(-time.Hour).Unix() }},
+		"missing expiry":  {key: key, kid: "test", modify: func(c map[string]interface{}) { delete(c, "exp") }},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			claims := validClaims()
+			test.modify(claims)
+
+			_, err := verifier.verify(signTestToken(t, test.key, test.kid, claims))
+			if err == nil {
+				t.Errorf("expected token to be rejected")
+			}
+		})
+	}
+}
+
+func TestOIDCVerifySigningKeys(t *testing.T) {
+	rsaKey, err := rsa.GenerateKey(rand.Reader, 2048)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	p256Key, err := ecdsa.GenerateKey(elliptic.P256(), rand.Reader)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	p384Key, err := ecdsa.GenerateKey(elliptic.P384(), rand.Reader)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	verifier := &oidcVerifier{
+		config: &config.OIDC{
+			Issuer:   "https://idp.example.com",
+			ClientID: "gofer",
+		},
+		keys: map[string]signingKey{
+			"rsa":       {key: &rsaKey.PublicKey, alg: "RS256"},
+			"rsa-rs384": {key: &rsaKey.PublicKey, alg: "RS384"},
+			"p256":      {key: &p256Key.PublicKey, alg: "ES256"},
+			"p384":      {key: &p384Key.PublicKey},
+		},
+		refreshed: time.Now(),
+	}
+
+	claims := map[string]interface{}{
+		"iss": "https://idp.example.com",
+		"sub": "user1",
+		"aud": "gofer",
+		"exp": time.Now().Add(time.Hour).Unix(),
+	}
+
+	tests := map[string]struct {
+		token string
+		valid bool
+	}{
+		"rsa key used for its algorithm": {
+			token: signTestToken(t, rsaKey, "rsa", claims), valid: true,
+		},
+		"ec key used for its algorithm": {
+			token: signTestECToken(t, p256Key, "ES256", "p256", claims), valid: true,
+		},
+		"ec key without algorithm used for its curve": {
+			token: signTestECToken(t, p384Key, "ES384", "p384", claims), valid: true,
+		},
+		"key published for another algorithm": {
+			token: signTestToken(t, rsaKey, "rsa-rs384", claims), valid: false,
+		},
+		"ec algorithm not matching key curve": {
+			token: signTestECToken(t, p384Key, "ES256", "p384", claims), valid: false,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			_, err := verifier.verify(test.token)
+			if test.valid && err != nil {
+				t.Errorf("expected token to be accepted; got %v", err)
+			}
+			if !test.valid && err == nil {
+				t.Errorf("expected token to be rejected")
+			}
+		})
+	}
+}
+
+func TestJSONWebKeyPublicKey(t *testing.T) {
+	encode := func(value *big.Int) string {
+		return base64.RawURLEncoding.EncodeToString(value.Bytes())
+	}
+
+	rsaKey, err := rsa.GenerateKey(rand.Reader, 2048)
+	if err != nil {
+		t.Fatal(err)
+	}
+	modulus := encode(rsaKey.N)
+
+	ecKey, err := ecdsa.GenerateKey(elliptic.P256(), rand.Reader)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	tests := map[string]struct {
+		key   jsonWebKey
+		valid bool
+	}{
+		"rsa key": {
+			key:   jsonWebKey{Kty: "RSA", N: modulus, E: encode(big.NewInt(65537))},
+			valid: true,
+		},
+		"rsa exponent larger than an int": {
+			key:   jsonWebKey{Kty: "RSA", N: modulus, E: encode(new(big.Int).Lsh(big.NewInt(1), 64))},
+			valid: false,
+		},
+		"rsa exponent truncated to a valid one": {
+			key:   jsonWebKey{Kty: "RSA", N: modulus, E: encode(new(big.Int).Add(new(big.Int).Lsh(big.NewInt(1), 64), big.NewInt(65537)))},
+			valid: false,
+		},
+		"rsa exponent of one": {
+			key:   jsonWebKey{Kty: "RSA", N: modulus, E: encode(big.NewInt(1))},
+			valid: false,
+		},
+		"even rsa exponent": {
+			key:   jsonWebKey{Kty: "RSA", N: modulus, E: encode(big.NewInt(65536))},
+			valid: false,
+		},
+		"missing rsa modulus": {
+			key:   jsonWebKey{Kty: "RSA", E: encode(big.NewInt(65537))},
+			valid: false,
+		},
+		"ec key": {
+			key:   jsonWebKey{Kty: "EC", Crv: "P-256", X: encode(ecKey.X), Y: encode(ecKey.Y)},
+			valid: true,
+		},
+		"ec point not on curve": {
+			key:   jsonWebKey{Kty: "EC", Crv: "P-256", X: encode(ecKey.X), Y: encode(new(big.Int).Add(ecKey.Y, big.NewInt(1)))},
+			valid: false,
+		},
+		"ec point on another curve": {
+			key:   jsonWebKey{Kty: "EC", Crv: "P-384", X: encode(ecKey.X), Y: encode(ecKey.Y)},
+			valid: false,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			_, err := test.key.publicKey()
+			if test.valid && err != nil {
+				t.Errorf("expected key to be accepted; got %v", err)
+			}
+			if !test.valid && err == nil {
+				t.Errorf("expected key to be rejected")
+			}
+		})
+	}
+}
+
+func TestOIDCClaimMappings(t *testing.T) {
+	key, err := rsa.GenerateKey(rand.Reader, 2048)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	verifier := &oidcVerifier{
+		config: &config.OIDC{
+			Issuer:      "https://idp.example.com",
+			ClientID:    "gofer",
+			GroupsClaim: "groups",
+			GroupMappings: []config.GroupMapping{
+				{Group: "team-a", Kind: "client", Namespaces: []string{"team-a"}},
+			},
+			ClaimMappings: []config.ClaimMapping{
+				{Claim: "department", Value: "platform", Kind: "namespace_admin", Namespaces: []string{"platform"}},
+				{Claim: "roles", Value: "ops", Kind: "client", Namespaces: []string{"ops"}},
+				{Claim: "email_verified", Value: "true", Kind: "client", Namespaces: []string{"verified"}},
+				{Claim: "department", Value: "finance", Kind: "management"},
+			},
+		},
+		keys:      map[string]signingKey{"test": {key: &key.PublicKey}},
+		refreshed: time.Now(),
+	}
+
+	claims, err := verifier.verify(signTestToken(t, key, "test", map[string]interface{}{
+		"iss":            "https://idp.example.com",
+		"sub":            "user1",
+		"aud":            "gofer",
+		"exp":            time.Now().Add(time.Hour).Unix(),
+		"groups":         []string{"team-a"},
+		"department":     "platform",
+		"roles":          []string{"dev", "ops"},
+		"email_verified": true,
+	}))
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	kind, namespaces, ok := verifier.permissions(claims)
+	if !ok {
+		t.Fatal("expected user to match mappings")
+	}
+
+	if kind != models.TokenKindNamespaceAdmin {
+		t.Errorf("expected kind %q; got %q", models.TokenKindNamespaceAdmin, kind)
+	}
+
+	if diff := cmp.Diff([]string{"team-a", "platform", "ops", "verified"}, namespaces); diff != "" {
+		t.Errorf("unexpected namespaces (-want +got):\n%s", diff)
+	}
+}
+
+func TestUnverifiedIssuer(t *testing.T) {
+	key, err := rsa.GenerateKey(rand.Reader, 2048)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	token := signTestToken(t, key, "test", map[string]interface{}{"iss": "https://auth.example.com"})
+	if issuer := unverifiedIssuer(token); issuer != "https://auth.example.com" {
+		t.Errorf("expected issuer %q; got %q", "https://auth.example.com", issuer)
+	}
+
+	if issuer := unverifiedIssuer("not.a.jwt"); issuer != "" {
+		t.Errorf("expected no issuer for malformed token; got %q", issuer)
+	}
+}
@@ -0,0 +1,177 @@
+package api
+
+import (
+	"encoding/json"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/proto"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+// OpenAPISpec generates an OpenAPI(v3) document describing the Gofer API. Since Gofer is a GRPC service the spec
+// describes each RPC as a POST request to its GRPC-Web path and the request/response bodies as the JSON
+// representation of their protobuf messages(as encoded by protojson).
+//
+// The spec is generated straight from the compiled protobuf descriptors so it does not require a running server.
+func OpenAPISpec() ([]byte, error) {
+	version, commit := parseVersion(appVersion)
+
+	schemas := map[string]interface{}{}
+	paths := map[string]interface{}{}
+
+	services := proto.File_gofer_proto.Services()
+	for i := 0; i < services.Len(); i++ {
+		service := services.Get(i)
+		methods := service.Methods()
+
+		for j := 0; j < methods.Len(); j++ {
+			method := methods.Get(j)
+
+			addMessageSchema(schemas, method.Input())
+			addMessageSchema(schemas, method.Output())
+
+			description := ""
+			switch {
+			case method.IsStreamingServer():
+				description = "Returns a stream of response messages."
+			case method.IsStreamingClient():
+				description = "Accepts a stream of request messages."
+			}
+
+			paths[fmt.Sprintf("/%s/%s", service.FullName(), method.Name())] = map[string]interface{}{
+				"post": map[string]interface{}{
+					"operationId": string(method.Name()),
+					"tags":        []string{string(service.Name())},
+					"description": description,
+					"requestBody": map[string]interface{}{
+						"required": true,
+						"content": map[string]interface{}{
+							"application/json": map[string]interface{}{
+								"schema": schemaRef(method.Input().FullName()),
+							},
+						},
+					},
+					"responses": map[string]interface{}{
+						"200": map[string]interface{}{
+							"description": "OK",
+							"content": map[string]interface{}{
+								"application/json": map[string]interface{}{
+									"schema": schemaRef(method.Output().FullName()),
+								},
+							},
+						},
+					},
+				},
+			}
+		}
+	}
+
+	spec := map[string]interface{}{
+		"openapi": "3.0.3",
+		"info": map[string]interface{}{
+			"title":       "Gofer",
+			"description": fmt.Sprintf("Gofer API; generated from commit %s.", commit),
+			"version":     version,
+		},
+		"paths": paths,
+		"components": map[string]interface{}{
+			"securitySchemes": map[string]interface{}{
+				"bearerAuth": map[string]interface{}{
+					"type":   "http",
+					"scheme": "bearer",
+				},
+			},
+			"schemas": schemas,
+		},
+		"security": []map[string]interface{}{
+			{"bearerAuth": []string{}},
+		},
+	}
+
+	return json.MarshalIndent(spec, "", "  ")
+}
+
+func schemaRef(name protoreflect.FullName) map[string]interface{} {
+	return map[string]interface{}{
+		"$ref": "#/components/schemas/" + string(name),
+	}
+}
+
+// addMessageSchema adds the schema for a message and all of the messages and enums it references.
+func addMessageSchema(schemas map[string]interface{}, message protoreflect.MessageDescriptor) {
+	if _, exists := schemas[string(message.FullName())]; exists {
+		return
+	}
+
+	properties := map[string]interface{}{}
+	schemas[string(message.FullName())] = map[string]interface{}{
+		"type":       "object",
+		"properties": properties,
+	}
+
+	fields := message.Fields()
+	for i := 0; i < fields.Len(); i++ {
+		field := fields.Get(i)
+
+		switch {
+		case field.IsMap():
+			properties[field.JSONName()] = map[string]interface{}{
+				"type":                 "object",
+				"additionalProperties": fieldSchema(schemas, field.MapValue()),
+			}
+		case field.IsList():
+			properties[field.JSONName()] = map[string]interface{}{
+				"type":  "array",
+				"items": fieldSchema(schemas, field),
+			}
+		default:
+			properties[field.JSONName()] = fieldSchema(schemas, field)
+		}
+	}
+}
+
+func addEnumSchema(schemas map[string]interface{}, enum protoreflect.EnumDescriptor) {
+	if _, exists := schemas[string(enum.FullName())]; exists {
+		return
+	}
+
+	values := []string{}
+	for i := 0; i < enum.Values().Len(); i++ {
+		values = append(values, string(enum.Values().Get(i).Name()))
+	}
+
+	schemas[string(enum.FullName())] = map[string]interface{}{
+		"type": "string",
+		"enum": values,
+	}
+}
+
+// fieldSchema returns the schema of a single(non-repeated) value of the given field.
+func fieldSchema(schemas map[string]interface{}, field protoreflect.FieldDescriptor) map[string]interface{} {
+	switch field.Kind() {
+	case protoreflect.BoolKind:
+		return map[string]interface{}{"type": "boolean"}
+	case protoreflect.Int32Kind, protoreflect.Sint32Kind, protoreflect.Sfixed32Kind,
+		protoreflect.Uint32Kind, protoreflect.Fixed32Kind:
+		return map[string]interface{}{"type": "integer", "format": "int32"}
+	// The JSON encoding of protobuf writes 64 bit integers as strings since they don't fit a JavaScript number.
+	case protoreflect.Int64Kind, protoreflect.Sint64Kind, protoreflect.Sfixed64Kind:
+		return map[string]interface{}{"type": "string", "format": "int64"}
+	case protoreflect.Uint64Kind, protoreflect.Fixed64Kind:
+		return map[string]interface{}{"type": "string", "format": "uint64"}
+	case protoreflect.FloatKind:
+		return map[string]interface{}{"type": "number", "format": "float"}
+	case protoreflect.DoubleKind:
+		return map[string]interface{}{"type": "number", "format": "double"}
+	case protoreflect.BytesKind:
+		return map[string]interface{}{"type": "string", "format": "byte"}
+	case protoreflect.EnumKind:
+		addEnumSchema(schemas, field.Enum())
+		return schemaRef(field.Enum().FullName())
+	case protoreflect.MessageKind, protoreflect.GroupKind:
+		addMessageSchema(schemas, field.Message())
+		return schemaRef(field.Message().FullName())
+	default:
+		return map[string]interface{}{"type": "string"}
+	}
+}
@@ -0,0 +1,49 @@
+package api
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/google/go-cmp/cmp"
+)
+
+func TestOpenAPISpec(t *testing.T) {
+	raw, err := OpenAPISpec()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	spec := struct {
+		Paths map[string]struct {
+			Post struct {
+				RequestBody struct {
+					Content map[string]interface{} `json:"content"`
+				} `json:"requestBody"`
+			} `json:"post"`
+		} `json:"paths"`
+		Components struct {
+			Schemas map[string]struct {
+				Properties map[string]interface{} `json:"properties"`
+			} `json:"schemas"`
+		} `json:"components"`
+	}{}
+	err = json.Unmarshal(raw, &spec)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	path, exists := spec.Paths["/proto.Gofer/GetRun"]
+	if !exists {
+		t.Fatal("expected spec to describe GetRun")
+	}
+
+	if _, exists := path.Post.RequestBody.Content["application/json"]; !exists {
+		t.Errorf("expected request body to be described as json; got %v", path.Post.RequestBody.Content)
+	}
+
+	// protojson encodes 64 bit integers as strings.
+	want := map[string]interface{}{"type": "string", "format": "int64"}
+	if diff := cmp.Diff(want, spec.Components.Schemas["proto.GetRunRequest"].Properties["id"]); diff != "" {
+		t.Errorf("unexpected schema for int64 field (-want +got):\n%s", diff)
+	}
+}
@@ -107,13 +107,45 @@ func (api *API) configTriggersIsValid(triggers []models.PipelineTriggerConfig) e
 	return nil
 }
 
+// errPipelineProtectedMsg is returned to callers attempting to modify a protected pipeline they don't own.
+const errPipelineProtectedMsg = "pipeline is protected; only its owners and namespace admins can modify it"
+
+// canModifyStoredPipeline returns whether the caller is able to modify the given pipeline. Pipelines that can't be
+// retrieved are left for the rest of the request to report on.
+func (api *API) canModifyStoredPipeline(ctx context.Context, namespace, id string) bool {
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: id})
+	if err != nil {
+		return true
+	}
+
+	return canModifyPipeline(ctx, pipeline)
+}
+
 // createPipeline creates a new pipeline based on configuration. It also attempts to subscribe the proper triggers
 // with the given configs. If this step fails the pipeline is still created, but it's state is in a disabled mode.
-func (api *API) createPipeline(location string, config *models.PipelineConfig) (*models.Pipeline, error) {
+//
+// If the pipeline is created by a service account, the service account becomes the owner of the pipeline.
+func (api *API) createPipeline(location string, config *models.PipelineConfig, serviceAccount string) (*models.Pipeline, error) {
 	newPipeline := models.NewPipeline(location, config)
 	newPipeline.State = models.PipelineStateActive
+	newPipeline.ServiceAccount = serviceAccount
+
+	err := api.checkPipelineQuota(newPipeline.Namespace)
+	if err != nil {
+		return nil, err
+	}
+
+	err = api.configTriggersIsValid(config.Triggers)
+	if err != nil {
+		return nil, err
+	}
+
+	err = api.configTasksIsValid(newPipeline.Namespace, newPipeline.ID, config.Tasks)
+	if err != nil {
+		return nil, err
+	}
 
-	err := api.configTriggersIsValid(config.Triggers)
+	err = api.configNotificationPolicyIsValid(config.NotificationPolicy)
 	if err != nil {
 		return nil, err
 	}
@@ -143,6 +175,8 @@ func (api *API) createPipeline(location string, config *models.PipelineConfig) (
 			Msg("successfully subscribed trigger")
 	}
 
+	newPipeline.ConfigVersion = api.recordPipelineConfigVersion(newPipeline.Namespace, newPipeline.ID, location, config)
+
 	err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{
 		Pipeline: newPipeline,
 	})
@@ -214,6 +248,17 @@ func (api *API) unsubscribeTrigger(subscription models.PipelineTriggerConfig, pi
 // We attempt to update trigger subscriptions by matching user provided names in both profiles.
 // If the names don't match or the settings are different then we must unsubscribe and resubscribe the triggers.
 func (api *API) updatePipeline(url, namespace, id string, hclConfig *models.HCLPipelineConfig) (*models.Pipeline, error) {
+	config, err := models.FromHCL(hclConfig)
+	if err != nil {
+		return nil, fmt.Errorf("could not parse config file; %w", err)
+	}
+
+	return api.updatePipelineFromConfig(url, namespace, id, config)
+}
+
+// updatePipelineFromConfig replaces the configuration of a disabled pipeline with the given config and records the
+// config as a new version.
+func (api *API) updatePipelineFromConfig(url, namespace, id string, config *models.PipelineConfig) (*models.Pipeline, error) {
 	// Get the old pipeline first so that we can store the old values that we need before inserting
 	// the new values from the content buffer.
 	currentPipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{
@@ -224,10 +269,10 @@ func (api *API) updatePipeline(url, namespace, id string, hclConfig *models.HCLP
 		return nil, fmt.Errorf("could not get previous pipeline: %w", err)
 	}
 
-	// Check to make sure id provided in HCLConfig is the same as the ID we're attempting to change
-	if hclConfig.ID != currentPipeline.ID {
+	// Check to make sure id provided in config is the same as the ID we're attempting to change
+	if config.ID != currentPipeline.ID {
 		return nil, fmt.Errorf("id in config %q is not equal to id in update request %q; pipeline ids cannot be updated",
-			hclConfig.ID, currentPipeline.ID)
+			config.ID, currentPipeline.ID)
 	}
 
 	if currentPipeline.State == models.PipelineStateAbandoned {
@@ -243,11 +288,6 @@ func (api *API) updatePipeline(url, namespace, id string, hclConfig *models.HCLP
 		return nil, ErrPipelineRunsInProgress
 	}
 
-	config, err := models.FromHCL(hclConfig)
-	if err != nil {
-		return nil, fmt.Errorf("could not parse config file; %w", err)
-	}
-
 	// store currentTriggers so we can compare them to potential to figure out which need to be unsubscribed.
 	currentTriggerMap := currentPipeline.Triggers
 	currentTriggers := []models.PipelineTriggerConfig{}
@@ -268,6 +308,16 @@ func (api *API) updatePipeline(url, namespace, id string, hclConfig *models.HCLP
 		return nil, err
 	}
 
+	err = api.configTasksIsValid(currentPipeline.Namespace, currentPipeline.ID, config.Tasks)
+	if err != nil {
+		return nil, err
+	}
+
+	err = api.configNotificationPolicyIsValid(config.NotificationPolicy)
+	if err != nil {
+		return nil, err
+	}
+
 	// Find the list of triggers we should unsubscribe by comparing what we have currently to the list of unchanged
 	// triggers.
 	// 2) For anything new that shows up we add to a subscribe list
@@ -308,11 +358,25 @@ func (api *API) updatePipeline(url, namespace, id string, hclConfig *models.HCLP
 
 	currentPipeline.Triggers = currentTriggerMap
 
+	// A canary is only ever compared against the config version that was current when it started.
+	currentPipeline.Canary = nil
+	previousVersion := currentPipeline.ConfigVersion
+	currentPipeline.ConfigVersion = api.recordPipelineConfigVersion(currentPipeline.Namespace, currentPipeline.ID, url, config)
+
+	// Pipelines without a recorded config version have nothing a failed deployment could be rolled back to.
+	currentPipeline.Deployment = nil
+	if config.DeploymentHealth != nil && previousVersion != 0 && currentPipeline.ConfigVersion != 0 {
+		currentPipeline.Deployment = models.NewPipelineDeployment(currentPipeline.ConfigVersion, previousVersion,
+			*config.DeploymentHealth)
+	}
+
 	err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: currentPipeline})
 	if err != nil {
 		return nil, err
 	}
 
+	api.runConfigs.forget(currentPipeline.Namespace, currentPipeline.ID)
+
 	if configErrs.ErrorOrNil() != nil {
 		return nil, fmt.Errorf("pipeline configuration error: %v; %w", configErrs, ErrPipelineConfigNotValid)
 	}
@@ -320,6 +384,165 @@ func (api *API) updatePipeline(url, namespace, id string, hclConfig *models.HCLP
 	return currentPipeline, nil
 }
 
+// startPipelineCanary records the given config as a canary version of the pipeline and starts trying it out on some of
+// the pipeline's runs. The pipeline itself keeps its current config version.
+func (api *API) startPipelineCanary(location, namespace, id string, config *models.PipelineConfig,
+	percentage int64, manualOnly bool,
+) (*models.Pipeline, error) {
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: id})
+	if err != nil {
+		return nil, err
+	}
+
+	if config.ID != pipeline.ID {
+		return nil, fmt.Errorf("id in config %q is not equal to id in canary request %q; pipeline ids cannot be updated",
+			config.ID, pipeline.ID)
+	}
+
+	if pipeline.State == models.PipelineStateAbandoned {
+		return nil, ErrPipelineAbandoned
+	}
+
+	if pipeline.Canary != nil {
+		return nil, ErrCanaryInProgress
+	}
+
+	// The canary's triggers only take effect once it's promoted, but there is no reason to let a canary that can never
+	// be promoted get started.
+	err = api.configTriggersIsValid(config.Triggers)
+	if err != nil {
+		return nil, err
+	}
+
+	err = api.configTasksIsValid(pipeline.Namespace, pipeline.ID, config.Tasks)
+	if err != nil {
+		return nil, err
+	}
+
+	err = api.configNotificationPolicyIsValid(config.NotificationPolicy)
+	if err != nil {
+		return nil, err
+	}
+
+	version := models.NewPipelineConfigVersion(pipeline.Namespace, pipeline.ID, location, *config)
+	version.Canary = true
+
+	err = api.storage.AddPipelineConfigVersion(storage.AddPipelineConfigVersionRequest{PipelineConfigVersion: version})
+	if err != nil {
+		return nil, fmt.Errorf("could not record config version: %w", err)
+	}
+
+	pipeline.Canary = models.NewPipelineCanary(version.Version, percentage, manualOnly)
+
+	err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: pipeline})
+	if err != nil {
+		return nil, err
+	}
+
+	return pipeline, nil
+}
+
+// promotePipelineCanary updates a disabled pipeline to the config version its canary is trying out. Just like a
+// rollback, the promoted config is recorded as a new config version.
+func (api *API) promotePipelineCanary(namespace, id string) (*models.Pipeline, error) {
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: id})
+	if err != nil {
+		return nil, err
+	}
+
+	if pipeline.Canary == nil {
+		return nil, ErrNoCanary
+	}
+
+	version, err := api.storage.GetPipelineConfigVersion(storage.GetPipelineConfigVersionRequest{
+		NamespaceID: namespace,
+		PipelineID:  id,
+		Version:     pipeline.Canary.Version,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("could not get canary config version %d: %w", pipeline.Canary.Version, err)
+	}
+
+	return api.updatePipelineFromConfig(version.Location, namespace, id, &version.Config)
+}
+
+// abortPipelineCanary stops a pipeline's canary. Runs already using the canary version finish with it.
+func (api *API) abortPipelineCanary(namespace, id string) (*models.Pipeline, error) {
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: id})
+	if err != nil {
+		return nil, err
+	}
+
+	if pipeline.Canary == nil {
+		return nil, ErrNoCanary
+	}
+
+	pipeline.Canary = nil
+
+	err = api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: pipeline})
+	if err != nil {
+		return nil, err
+	}
+
+	return pipeline, nil
+}
+
+// recordPipelineConfigVersion stores the given config as the newest version of a pipeline's configuration and returns
+// its version number. Failing to record a version does not fail the create or update that produced it; 0 is returned
+// instead.
+func (api *API) recordPipelineConfigVersion(namespace, id, location string, config *models.PipelineConfig) int64 {
+	version := models.NewPipelineConfigVersion(namespace, id, location, *config)
+
+	err := api.storage.AddPipelineConfigVersion(storage.AddPipelineConfigVersionRequest{
+		PipelineConfigVersion: version,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", namespace).Str("pipeline", id).Msg("could not record pipeline config version")
+		return 0
+	}
+
+	return version.Version
+}
+
+// previousPipelineConfigVersion returns the version the pipeline used before its current one, which is the version
+// that is rolled back to when no specific version is requested. Canary versions are skipped since the pipeline never
+// used them.
+func (api *API) previousPipelineConfigVersion(namespace, id string) (*models.PipelineConfigVersion, error) {
+	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespace, ID: id})
+	if err != nil {
+		return nil, err
+	}
+
+	versions, err := api.storage.GetAllPipelineConfigVersions(storage.GetAllPipelineConfigVersionsRequest{
+		NamespaceID: namespace,
+		PipelineID:  id,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	// Pipelines that haven't been updated since config versions started being tracked use their newest version.
+	passedCurrent := false
+	for _, version := range versions {
+		if version.Canary {
+			continue
+		}
+
+		if pipeline.ConfigVersion == 0 && !passedCurrent {
+			passedCurrent = true
+			continue
+		}
+
+		if pipeline.ConfigVersion != 0 && version.Version >= pipeline.ConfigVersion {
+			continue
+		}
+
+		return version, nil
+	}
+
+	return nil, storage.ErrEntityNotFound
+}
+
 // findTriggerDifferences returns the trigger subscriptions that should be removed and should be added. It compares
 // the incoming trigger list to the current trigger list and uses the name, kind, and config to determine the difference
 // between them.
@@ -388,6 +611,28 @@ func (api *API) hasActiveRuns(namespace, id string) bool {
 	return false
 }
 
+// concurrencyGroupBusy returns ErrConcurrencyGroupBusy if any pipeline within the given namespace and concurrency
+// group has a run in progress.
+func (api *API) concurrencyGroupBusy(namespace, group string) error {
+	pipelines, err := api.getAllPipelines(namespace)
+	if err != nil {
+		return fmt.Errorf("could not get pipelines: %w", err)
+	}
+
+	for _, pipeline := range pipelines {
+		if pipeline.ConcurrencyGroup != group {
+			continue
+		}
+
+		if api.hasActiveRuns(namespace, pipeline.ID) {
+			return fmt.Errorf("%w; pipeline %q in group %q is still running", ErrConcurrencyGroupBusy,
+				pipeline.ID, group)
+		}
+	}
+
+	return nil
+}
+
 // subscribeTrigger takes a pipeline config requested trigger and communicates with the trigger container
 // in order appropriately make sure the trigger is aware for the pipeline.
 func (api *API) subscribeTrigger(namespaceID, pipelineID string, config *models.PipelineTriggerConfig) error {
@@ -23,6 +23,10 @@ func (api *API) GetPipeline(ctx context.Context, request *proto.GetPipelineReque
 		request.NamespaceId = determineNamespace(ctx)
 	}
 
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.GetPipelineResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: request.NamespaceId, ID: request.Id})
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityNotFound) {
@@ -139,6 +143,10 @@ func (api *API) ListPipelines(ctx context.Context, request *proto.ListPipelinesR
 		request.NamespaceId = determineNamespace(ctx)
 	}
 
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.ListPipelinesResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	pipelines, err := api.storage.GetAllPipelines(storage.GetAllPipelinesRequest{
 		NamespaceID: request.NamespaceId,
 		Offset:      int(request.Offset),
@@ -192,16 +200,24 @@ func (api *API) CreatePipelineRaw(ctx context.Context, request *proto.CreatePipe
 			status.Errorf(codes.FailedPrecondition, "could not parse config file; %v", err)
 	}
 
-	newPipeline, err := api.createPipeline(request.Path, config)
+	newPipeline, err := api.createPipeline(request.Path, config, callerServiceAccount(ctx))
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityExists) {
 			return &proto.CreatePipelineRawResponse{}, status.Errorf(codes.AlreadyExists,
 				"pipeline id already exists; please try again.")
 		}
+		if errors.Is(err, ErrQuotaExceeded) {
+			return &proto.CreatePipelineRawResponse{}, status.Errorf(codes.ResourceExhausted,
+				"could not create pipeline; %v", err)
+		}
 		if errors.Is(err, ErrTriggerNotFound) {
 			return &proto.CreatePipelineRawResponse{}, status.Errorf(codes.FailedPrecondition,
 				"could not create pipeline; %v;", err)
 		}
+		if errors.Is(err, ErrTaskNotAllowed) || errors.Is(err, ErrWebhookNotAllowed) {
+			return &proto.CreatePipelineRawResponse{}, status.Errorf(codes.FailedPrecondition,
+				"could not create pipeline; %v;", err)
+		}
 		if errors.Is(err, ErrPipelineConfigNotValid) {
 			return &proto.CreatePipelineRawResponse{}, status.Errorf(codes.FailedPrecondition,
 				"pipeline creation encountered errors due to configuration; the pipeline has been created, but put into"+
@@ -247,17 +263,25 @@ func (api *API) CreatePipelineByURL(ctx context.Context, request *proto.CreatePi
 			"could not parse config file; %v", err)
 	}
 
-	newPipeline, err := api.createPipeline(request.Url, config)
+	newPipeline, err := api.createPipeline(request.Url, config, callerServiceAccount(ctx))
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityExists) {
 			log.Debug().Err(err).Msg("pipeline id conflict")
 			return &proto.CreatePipelineByURLResponse{}, status.Errorf(codes.AlreadyExists,
 				"pipeline id already exists; please try again.")
 		}
+		if errors.Is(err, ErrQuotaExceeded) {
+			return &proto.CreatePipelineByURLResponse{}, status.Errorf(codes.ResourceExhausted,
+				"could not create pipeline; %v", err)
+		}
 		if errors.Is(err, ErrTriggerNotFound) {
 			return &proto.CreatePipelineByURLResponse{}, status.Errorf(codes.FailedPrecondition,
 				"could not create pipeline; %v;", err)
 		}
+		if errors.Is(err, ErrTaskNotAllowed) || errors.Is(err, ErrWebhookNotAllowed) {
+			return &proto.CreatePipelineByURLResponse{}, status.Errorf(codes.FailedPrecondition,
+				"could not create pipeline; %v;", err)
+		}
 		if errors.Is(err, ErrPipelineConfigNotValid) {
 			return &proto.CreatePipelineByURLResponse{}, status.Errorf(codes.FailedPrecondition,
 				"pipeline creation encountered errors due to configuration; the pipeline has been created, but put into"+
@@ -285,6 +309,10 @@ func (api *API) UpdatePipelineRaw(ctx context.Context, request *proto.UpdatePipe
 		return &proto.UpdatePipelineRawResponse{}, status.Error(codes.PermissionDenied, "access denied")
 	}
 
+	if !api.canModifyStoredPipeline(ctx, request.NamespaceId, request.Id) {
+		return &proto.UpdatePipelineRawResponse{}, status.Error(codes.PermissionDenied, errPipelineProtectedMsg)
+	}
+
 	if len(request.Content) == 0 {
 		return &proto.UpdatePipelineRawResponse{}, status.Error(codes.FailedPrecondition, "content required")
 	}
@@ -312,6 +340,9 @@ func (api *API) UpdatePipelineRaw(ctx context.Context, request *proto.UpdatePipe
 		if errors.Is(err, ErrTriggerNotFound) {
 			return &proto.UpdatePipelineRawResponse{}, status.Errorf(codes.FailedPrecondition, "could not update pipeline; %v", err)
 		}
+		if errors.Is(err, ErrTaskNotAllowed) || errors.Is(err, ErrWebhookNotAllowed) {
+			return &proto.UpdatePipelineRawResponse{}, status.Errorf(codes.FailedPrecondition, "could not update pipeline; %v", err)
+		}
 		if errors.Is(err, ErrPipelineConfigNotValid) {
 			return &proto.UpdatePipelineRawResponse{}, status.Errorf(codes.FailedPrecondition, "could not update pipeline; %v", err)
 		}
@@ -341,6 +372,10 @@ func (api *API) UpdatePipelineByURL(ctx context.Context, request *proto.UpdatePi
 		return &proto.UpdatePipelineByURLResponse{}, status.Error(codes.PermissionDenied, "access denied")
 	}
 
+	if !api.canModifyStoredPipeline(ctx, request.NamespaceId, request.Id) {
+		return &proto.UpdatePipelineByURLResponse{}, status.Error(codes.PermissionDenied, errPipelineProtectedMsg)
+	}
+
 	hclConfig, err := api.processConfigurationByURL(request.Url)
 	if err != nil {
 		return &proto.UpdatePipelineByURLResponse{}, status.Errorf(codes.FailedPrecondition, "could not parse config file; %v", err)
@@ -363,6 +398,9 @@ func (api *API) UpdatePipelineByURL(ctx context.Context, request *proto.UpdatePi
 		if errors.Is(err, ErrTriggerNotFound) {
 			return &proto.UpdatePipelineByURLResponse{}, status.Errorf(codes.FailedPrecondition, "could not update pipeline; %v", err)
 		}
+		if errors.Is(err, ErrTaskNotAllowed) || errors.Is(err, ErrWebhookNotAllowed) {
+			return &proto.UpdatePipelineByURLResponse{}, status.Errorf(codes.FailedPrecondition, "could not update pipeline; %v", err)
+		}
 		if errors.Is(err, ErrPipelineConfigNotValid) {
 			return &proto.UpdatePipelineByURLResponse{}, status.Errorf(codes.FailedPrecondition, "could not update pipeline; %v", err)
 		}
@@ -375,6 +413,275 @@ func (api *API) UpdatePipelineByURL(ctx context.Context, request *proto.UpdatePi
 	}, nil
 }
 
+func (api *API) ListPipelineConfigVersions(ctx context.Context, request *proto.ListPipelineConfigVersionsRequest) (*proto.ListPipelineConfigVersionsResponse, error) {
+	if request.Id == "" {
+		return &proto.ListPipelineConfigVersionsResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.ListPipelineConfigVersionsResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	versions, err := api.storage.GetAllPipelineConfigVersions(storage.GetAllPipelineConfigVersionsRequest{
+		NamespaceID: request.NamespaceId,
+		PipelineID:  request.Id,
+		Offset:      int(request.Offset),
+		Limit:       int(request.Limit),
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not get pipeline config versions")
+		return &proto.ListPipelineConfigVersionsResponse{}, status.Error(codes.Internal, "failed to retrieve pipeline config versions from database")
+	}
+
+	protoVersions := []*proto.PipelineConfigVersion{}
+	for _, version := range versions {
+		protoVersions = append(protoVersions, version.ToProto())
+	}
+
+	return &proto.ListPipelineConfigVersionsResponse{
+		Versions: protoVersions,
+	}, nil
+}
+
+func (api *API) RollbackPipelineConfig(ctx context.Context, request *proto.RollbackPipelineConfigRequest) (*proto.RollbackPipelineConfigResponse, error) {
+	if request.Id == "" {
+		return &proto.RollbackPipelineConfigResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.RollbackPipelineConfigResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	if !api.canModifyStoredPipeline(ctx, request.NamespaceId, request.Id) {
+		return &proto.RollbackPipelineConfigResponse{}, status.Error(codes.PermissionDenied, errPipelineProtectedMsg)
+	}
+
+	var version *models.PipelineConfigVersion
+	var err error
+	if request.Version == 0 {
+		version, err = api.previousPipelineConfigVersion(request.NamespaceId, request.Id)
+	} else {
+		version, err = api.storage.GetPipelineConfigVersion(storage.GetPipelineConfigVersionRequest{
+			NamespaceID: request.NamespaceId,
+			PipelineID:  request.Id,
+			Version:     request.Version,
+		})
+	}
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			if request.Version == 0 {
+				return &proto.RollbackPipelineConfigResponse{}, status.Errorf(codes.FailedPrecondition,
+					"pipeline %q has no previous config version to roll back to", request.Id)
+			}
+			return &proto.RollbackPipelineConfigResponse{}, status.Errorf(codes.NotFound,
+				"config version %d for pipeline %q does not exist", request.Version, request.Id)
+		}
+		log.Error().Err(err).Msg("could not get pipeline config version")
+		return &proto.RollbackPipelineConfigResponse{}, status.Error(codes.Internal, "failed to retrieve pipeline config version from database")
+	}
+
+	updatedPipeline, err := api.updatePipelineFromConfig(version.Location, request.NamespaceId, request.Id, &version.Config)
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.RollbackPipelineConfigResponse{}, status.Errorf(codes.NotFound, "pipeline with id %q does not exist", request.Id)
+		}
+		if errors.Is(err, ErrPipelineActive) {
+			return &proto.RollbackPipelineConfigResponse{}, status.Error(codes.FailedPrecondition, "pipeline must be in state 'disabled' before running rollback")
+		}
+		if errors.Is(err, ErrPipelineAbandoned) {
+			return &proto.RollbackPipelineConfigResponse{}, status.Error(codes.FailedPrecondition, "pipeline cannot be abandoned")
+		}
+		if errors.Is(err, ErrPipelineRunsInProgress) {
+			return &proto.RollbackPipelineConfigResponse{}, status.Error(codes.FailedPrecondition, "pipeline must have no in progress runs before running rollback")
+		}
+		if errors.Is(err, ErrTriggerNotFound) {
+			return &proto.RollbackPipelineConfigResponse{}, status.Errorf(codes.FailedPrecondition, "could not roll back pipeline; %v", err)
+		}
+		if errors.Is(err, ErrTaskNotAllowed) || errors.Is(err, ErrWebhookNotAllowed) {
+			return &proto.RollbackPipelineConfigResponse{}, status.Errorf(codes.FailedPrecondition, "could not roll back pipeline; %v", err)
+		}
+		if errors.Is(err, ErrPipelineConfigNotValid) {
+			return &proto.RollbackPipelineConfigResponse{}, status.Errorf(codes.FailedPrecondition, "could not roll back pipeline; %v", err)
+		}
+		return &proto.RollbackPipelineConfigResponse{}, status.Errorf(codes.Internal, "could not roll back pipeline; %v", err)
+	}
+
+	log.Info().Interface("pipeline", updatedPipeline).Int64("version", version.Version).Msg("rolled back pipeline config")
+	return &proto.RollbackPipelineConfigResponse{
+		Pipeline: updatedPipeline.ToProto(),
+		Version:  version.Version,
+	}, nil
+}
+
+func (api *API) StartPipelineCanary(ctx context.Context, request *proto.StartPipelineCanaryRequest) (*proto.StartPipelineCanaryResponse, error) {
+	if request.Id == "" {
+		return &proto.StartPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.StartPipelineCanaryResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	if !api.canModifyStoredPipeline(ctx, request.NamespaceId, request.Id) {
+		return &proto.StartPipelineCanaryResponse{}, status.Error(codes.PermissionDenied, errPipelineProtectedMsg)
+	}
+
+	if len(request.Content) == 0 {
+		return &proto.StartPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "content required")
+	}
+
+	if request.ManualOnly && request.Percentage != 0 {
+		return &proto.StartPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition,
+			"percentage cannot be combined with manual_only")
+	}
+
+	if !request.ManualOnly && (request.Percentage < 1 || request.Percentage > 100) {
+		return &proto.StartPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition,
+			"percentage must be between 1 and 100 unless manual_only is set")
+	}
+
+	hclConfig := models.HCLPipelineConfig{}
+	err := hclConfig.FromBytes(request.Content, request.Path)
+	if err != nil {
+		return &proto.StartPipelineCanaryResponse{}, status.Errorf(codes.FailedPrecondition, "could not parse config file; %v", err)
+	}
+
+	config, err := models.FromHCL(&hclConfig)
+	if err != nil {
+		return &proto.StartPipelineCanaryResponse{}, status.Errorf(codes.FailedPrecondition, "could not parse config file; %v", err)
+	}
+
+	pipeline, err := api.startPipelineCanary(request.Path, request.NamespaceId, request.Id, config,
+		request.Percentage, request.ManualOnly)
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.StartPipelineCanaryResponse{}, status.Errorf(codes.NotFound, "pipeline with id %q does not exist", request.Id)
+		}
+		if errors.Is(err, ErrPipelineAbandoned) {
+			return &proto.StartPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "pipeline cannot be abandoned")
+		}
+		if errors.Is(err, ErrCanaryInProgress) {
+			return &proto.StartPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition,
+				"pipeline already has a canary in progress; promote or abort it first")
+		}
+		if errors.Is(err, ErrTriggerNotFound) {
+			return &proto.StartPipelineCanaryResponse{}, status.Errorf(codes.FailedPrecondition, "could not start canary; %v", err)
+		}
+		if errors.Is(err, ErrTaskNotAllowed) || errors.Is(err, ErrWebhookNotAllowed) {
+			return &proto.StartPipelineCanaryResponse{}, status.Errorf(codes.FailedPrecondition, "could not start canary; %v", err)
+		}
+		return &proto.StartPipelineCanaryResponse{}, status.Errorf(codes.Internal, "could not start canary; %v", err)
+	}
+
+	log.Info().Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).Int64("version", pipeline.Canary.Version).
+		Int64("percentage", pipeline.Canary.Percentage).Bool("manual_only", pipeline.Canary.ManualOnly).
+		Msg("started pipeline canary")
+	return &proto.StartPipelineCanaryResponse{
+		Pipeline: pipeline.ToProto(),
+		Version:  pipeline.Canary.Version,
+	}, nil
+}
+
+func (api *API) PromotePipelineCanary(ctx context.Context, request *proto.PromotePipelineCanaryRequest) (*proto.PromotePipelineCanaryResponse, error) {
+	if request.Id == "" {
+		return &proto.PromotePipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.PromotePipelineCanaryResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	if !api.canModifyStoredPipeline(ctx, request.NamespaceId, request.Id) {
+		return &proto.PromotePipelineCanaryResponse{}, status.Error(codes.PermissionDenied, errPipelineProtectedMsg)
+	}
+
+	promotedPipeline, err := api.promotePipelineCanary(request.NamespaceId, request.Id)
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Errorf(codes.NotFound, "could not promote canary; %v", err)
+		}
+		if errors.Is(err, ErrNoCanary) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "pipeline has no canary in progress")
+		}
+		if errors.Is(err, ErrPipelineActive) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "pipeline must be in state 'disabled' before promoting its canary")
+		}
+		if errors.Is(err, ErrPipelineAbandoned) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "pipeline cannot be abandoned")
+		}
+		if errors.Is(err, ErrPipelineRunsInProgress) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "pipeline must have no in progress runs before promoting its canary")
+		}
+		if errors.Is(err, ErrTriggerNotFound) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Errorf(codes.FailedPrecondition, "could not promote canary; %v", err)
+		}
+		if errors.Is(err, ErrTaskNotAllowed) || errors.Is(err, ErrWebhookNotAllowed) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Errorf(codes.FailedPrecondition, "could not promote canary; %v", err)
+		}
+		if errors.Is(err, ErrPipelineConfigNotValid) {
+			return &proto.PromotePipelineCanaryResponse{}, status.Errorf(codes.FailedPrecondition, "could not promote canary; %v", err)
+		}
+		return &proto.PromotePipelineCanaryResponse{}, status.Errorf(codes.Internal, "could not promote canary; %v", err)
+	}
+
+	log.Info().Str("namespace", promotedPipeline.Namespace).Str("pipeline", promotedPipeline.ID).
+		Int64("version", promotedPipeline.ConfigVersion).Msg("promoted pipeline canary")
+	return &proto.PromotePipelineCanaryResponse{
+		Pipeline: promotedPipeline.ToProto(),
+	}, nil
+}
+
+func (api *API) AbortPipelineCanary(ctx context.Context, request *proto.AbortPipelineCanaryRequest) (*proto.AbortPipelineCanaryResponse, error) {
+	if request.Id == "" {
+		return &proto.AbortPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.AbortPipelineCanaryResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	if !api.canModifyStoredPipeline(ctx, request.NamespaceId, request.Id) {
+		return &proto.AbortPipelineCanaryResponse{}, status.Error(codes.PermissionDenied, errPipelineProtectedMsg)
+	}
+
+	pipeline, err := api.abortPipelineCanary(request.NamespaceId, request.Id)
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.AbortPipelineCanaryResponse{}, status.Errorf(codes.NotFound, "pipeline with id %q does not exist", request.Id)
+		}
+		if errors.Is(err, ErrNoCanary) {
+			return &proto.AbortPipelineCanaryResponse{}, status.Error(codes.FailedPrecondition, "pipeline has no canary in progress")
+		}
+		log.Error().Err(err).Msg("could not abort pipeline canary")
+		return &proto.AbortPipelineCanaryResponse{}, status.Errorf(codes.Internal, "could not abort canary; %v", err)
+	}
+
+	log.Info().Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).Msg("aborted pipeline canary")
+	return &proto.AbortPipelineCanaryResponse{
+		Pipeline: pipeline.ToProto(),
+	}, nil
+}
+
 func (api *API) AbandonPipeline(ctx context.Context, request *proto.AbandonPipelineRequest) (*proto.AbandonPipelineResponse, error) {
 	if request.Id == "" {
 		return &proto.AbandonPipelineResponse{}, status.Error(codes.FailedPrecondition, "id required")
@@ -397,6 +704,10 @@ func (api *API) AbandonPipeline(ctx context.Context, request *proto.AbandonPipel
 		return &proto.AbandonPipelineResponse{}, status.Error(codes.Internal, "failed to retrieve pipeline from database")
 	}
 
+	if !canModifyPipeline(ctx, pipeline) {
+		return &proto.AbandonPipelineResponse{}, status.Error(codes.PermissionDenied, errPipelineProtectedMsg)
+	}
+
 	err = api.unsubscribeAllTriggers(pipeline)
 	if err != nil {
 		return nil, err
@@ -414,6 +725,7 @@ func (api *API) AbandonPipeline(ctx context.Context, request *proto.AbandonPipel
 		return &proto.AbandonPipelineResponse{}, status.Errorf(codes.Internal, "could not abandon pipeline: %q", request.Id)
 	}
 
+	api.runConfigs.forget(pipeline.Namespace, pipeline.ID)
 	api.events.Publish(models.NewEventAbandonedPipeline(*pipeline))
 
 	return &proto.AbandonPipelineResponse{}, nil
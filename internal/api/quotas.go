@@ -0,0 +1,155 @@
+package api
+
+import (
+	"errors"
+	"fmt"
+	"sync"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+// namespaceQuota returns the quota of the given namespace. Namespaces that no longer exist are treated as unlimited
+// so that the request fails on its own merit further along.
+func (api *API) namespaceQuota(namespace string) (*models.NamespaceQuota, error) {
+	ns, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: namespace})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &models.NamespaceQuota{}, nil
+		}
+		return nil, fmt.Errorf("could not get namespace %q: %w", namespace, err)
+	}
+
+	return &ns.Quota, nil
+}
+
+// checkPipelineQuota returns ErrQuotaExceeded if the namespace can't hold another pipeline.
+func (api *API) checkPipelineQuota(namespace string) error {
+	quota, err := api.namespaceQuota(namespace)
+	if err != nil {
+		return err
+	}
+
+	if quota.Pipelines == 0 {
+		return nil
+	}
+
+	pipelines, err := api.getAllPipelines(namespace)
+	if err != nil {
+		return fmt.Errorf("could not get pipelines: %w", err)
+	}
+
+	if int64(len(pipelines)) >= quota.Pipelines {
+		return fmt.Errorf("%w; namespace %q is limited to %d pipelines", ErrQuotaExceeded, namespace, quota.Pipelines)
+	}
+
+	return nil
+}
+
+// lockRunQuota locks the run quota of the given namespace and returns the function that unlocks it. Callers should
+// hold the lock from checking the quota until the new run has been stored.
+func (api *API) lockRunQuota(namespace string) (unlock func()) {
+	api.runQuotaLocksMu.Lock()
+	mu, exists := api.runQuotaLocks[namespace]
+	if !exists {
+		mu = &sync.Mutex{}
+		api.runQuotaLocks[namespace] = mu
+	}
+	api.runQuotaLocksMu.Unlock()
+
+	mu.Lock()
+	return mu.Unlock
+}
+
+// checkRunQuota returns ErrQuotaExceeded if the namespace can't start another run, either because too many runs are
+// already in progress or because too many runs were started within the last 24 hours.
+func (api *API) checkRunQuota(namespace string) error {
+	quota, err := api.namespaceQuota(namespace)
+	if err != nil {
+		return err
+	}
+
+	if quota.RunsPerDay == 0 && quota.ConcurrentRuns == 0 {
+		return nil
+	}
+
+	runsPerDay, concurrentRuns, err := api.namespaceRunUsage(namespace)
+	if err != nil {
+		return err
+	}
+
+	if quota.ConcurrentRuns != 0 && concurrentRuns >= quota.ConcurrentRuns {
+		return fmt.Errorf("%w; namespace %q is limited to %d concurrent runs", ErrQuotaExceeded, namespace,
+			quota.ConcurrentRuns)
+	}
+
+	if quota.RunsPerDay != 0 && runsPerDay >= quota.RunsPerDay {
+		return fmt.Errorf("%w; namespace %q is limited to %d runs per day", ErrQuotaExceeded, namespace,
+			quota.RunsPerDay)
+	}
+
+	return nil
+}
+
+// checkObjectQuota returns ErrQuotaExceeded if adding the given amount of bytes to the object store would take the
+// namespace over its limit. Objects that replace an existing object should pass the difference in size.
+//
+// Objects that are streamed in don't have a known size until they've been stored, so they're checked with a size of
+// zero; this lets the namespace go over its limit by a single object at most.
+func (api *API) checkObjectQuota(namespace string, bytes int64) error {
+	quota, err := api.namespaceQuota(namespace)
+	if err != nil {
+		return err
+	}
+
+	if quota.ObjectBytes == 0 {
+		return nil
+	}
+
+	pipelines, err := api.getAllPipelines(namespace)
+	if err != nil {
+		return fmt.Errorf("could not get pipelines: %w", err)
+	}
+
+	used, err := api.namespaceObjectBytes(namespace, pipelines)
+	if err != nil {
+		return err
+	}
+
+	if used >= quota.ObjectBytes || used+bytes > quota.ObjectBytes {
+		return fmt.Errorf("%w; namespace %q is limited to %d object bytes and is using %d", ErrQuotaExceeded,
+			namespace, quota.ObjectBytes, used)
+	}
+
+	return nil
+}
+
+// checkSecretQuota returns ErrQuotaExceeded if the namespace can't hold another secret. Replacing an existing secret
+// is always allowed.
+func (api *API) checkSecretQuota(namespace, key string) error {
+	quota, err := api.namespaceQuota(namespace)
+	if err != nil {
+		return err
+	}
+
+	if quota.Secrets == 0 {
+		return nil
+	}
+
+	secrets, err := api.getNamespaceSecretKeys(namespace)
+	if err != nil {
+		return err
+	}
+
+	for _, secret := range secrets {
+		if secret == key {
+			return nil
+		}
+	}
+
+	if int64(len(secrets)) >= quota.Secrets {
+		return fmt.Errorf("%w; namespace %q is limited to %d secrets", ErrQuotaExceeded, namespace, quota.Secrets)
+	}
+
+	return nil
+}
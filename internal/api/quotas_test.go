@@ -0,0 +1,90 @@
+package api
+
+import (
+	"errors"
+	"io/ioutil"
+	"os"
+	"sync"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/internal/storage/bolt"
+)
+
+func TestRunQuotaConcurrentStarts(t *testing.T) {
+	file, err := ioutil.TempFile("", "bolt-")
+	if err != nil {
+		t.Fatal(err)
+	}
+	file.Close()
+	os.Remove(file.Name())
+	defer os.Remove(file.Name())
+
+	db, err := bolt.New(file.Name(), 100)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	const limit = 3
+
+	namespace := models.NewNamespace("test", "test", "")
+	namespace.Quota.ConcurrentRuns = limit
+	err = db.AddNamespace(storage.AddNamespaceRequest{Namespace: namespace})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = db.AddPipeline(storage.AddPipelineRequest{Pipeline: &models.Pipeline{
+		ID:        "simple",
+		Namespace: "test",
+		State:     models.PipelineStateActive,
+	}})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	api := &API{storage: &db, runQuotaLocks: map[string]*sync.Mutex{}}
+
+	// Mirrors createNewRun: the quota stays locked from the check until the run is stored.
+	startRun := func() error {
+		unlock := api.lockRunQuota("test")
+		defer unlock()
+
+		err := api.checkRunQuota("test")
+		if err != nil {
+			return err
+		}
+
+		return db.AddRun(storage.AddRunRequest{Run: models.NewRun("simple", "test", "manual", "manual", nil, nil)})
+	}
+
+	var wg sync.WaitGroup
+	errs := make(chan error, limit+1)
+	for i := 0; i < limit+1; i++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			errs <- startRun()
+		}()
+	}
+	wg.Wait()
+	close(errs)
+
+	started, refused := 0, 0
+	for err := range errs {
+		switch {
+		case err == nil:
+			started++
+		case errors.Is(err, ErrQuotaExceeded):
+			refused++
+		default:
+			t.Fatalf("unexpected error: %v", err)
+		}
+	}
+
+	if started != limit || refused != 1 {
+		t.Errorf("expected %d runs to start and 1 to be refused; got %d started and %d refused", limit, started,
+			refused)
+	}
+}
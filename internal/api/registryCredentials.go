@@ -0,0 +1,80 @@
+package api
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+const (
+	RegistryCredentialKeyFmt = "registry_%s_%s" // registry_namespaceid_registry
+)
+
+// registryCredentialKey returns the secret store key the password of a namespace's registry credential is kept under.
+func registryCredentialKey(namespace, registry string) string {
+	return fmt.Sprintf(RegistryCredentialKeyFmt, namespace, registry)
+}
+
+// normalizeRegistry returns the registry the way credentials are stored and matched; ex. "https://ghcr.io/myorg/"
+// becomes "ghcr.io/myorg".
+func normalizeRegistry(registry string) string {
+	registry = strings.TrimSpace(registry)
+	registry = strings.TrimPrefix(registry, "https://")
+	registry = strings.TrimPrefix(registry, "http://")
+	return strings.Trim(registry, "/")
+}
+
+// matchRegistryCredential returns the credential for the most specific registry or repository path the image is
+// from; nil if none of them match.
+func matchRegistryCredential(credentials []*models.RegistryCredential, image string) *models.RegistryCredential {
+	qualified := qualifyImage(image)
+
+	var match *models.RegistryCredential
+	for _, credential := range credentials {
+		if !hasImagePrefix(qualified, credential.Registry) {
+			continue
+		}
+
+		if match != nil && len(credential.Registry) <= len(match.Registry) {
+			continue
+		}
+
+		match = credential
+	}
+
+	return match
+}
+
+// registryAuth returns the login the scheduler should use to pull a task run's image. A login given within the
+// pipeline's config takes priority; otherwise the namespace's credential for the image's registry is used, if it has
+// one.
+func (api *API) registryAuth(namespace, image string, auth models.RegistryAuth) (user, pass string) {
+	if auth.User != "" {
+		return auth.User, parseInterpolationSyntax("secret", auth.Pass)
+	}
+
+	credentials, err := api.storage.GetAllRegistryCredentials(storage.GetAllRegistryCredentialsRequest{
+		NamespaceID: namespace,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", namespace).Msg("could not get registry credentials")
+		return "", ""
+	}
+
+	credential := matchRegistryCredential(credentials, image)
+	if credential == nil {
+		return "", ""
+	}
+
+	pass, err = api.secretStore.GetSecret(registryCredentialKey(namespace, credential.Registry))
+	if err != nil {
+		log.Error().Err(err).Str("namespace", namespace).Str("registry", credential.Registry).
+			Msg("could not get registry credential password")
+		return "", ""
+	}
+
+	return credential.User, pass
+}
@@ -0,0 +1,179 @@
+package api
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (api *API) ListRegistryCredentials(ctx context.Context, request *proto.ListRegistryCredentialsRequest) (*proto.ListRegistryCredentialsResponse, error) {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.ListRegistryCredentialsResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	credentials, err := api.storage.GetAllRegistryCredentials(storage.GetAllRegistryCredentialsRequest{
+		NamespaceID: request.NamespaceId,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Msg("could not get registry credentials")
+		return &proto.ListRegistryCredentialsResponse{}, status.Error(codes.Internal,
+			"failed to retrieve registry credentials from database")
+	}
+
+	protoCredentials := []*proto.RegistryCredential{}
+	for _, credential := range credentials {
+		protoCredentials = append(protoCredentials, credential.ToProto())
+	}
+
+	return &proto.ListRegistryCredentialsResponse{
+		Credentials: protoCredentials,
+	}, nil
+}
+
+func (api *API) PutRegistryCredential(ctx context.Context, request *proto.PutRegistryCredentialRequest) (*proto.PutRegistryCredentialResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.PutRegistryCredentialResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	registry := normalizeRegistry(request.Registry)
+	if registry == "" {
+		return &proto.PutRegistryCredentialResponse{}, status.Error(codes.FailedPrecondition, "registry required")
+	}
+
+	if request.User == "" || request.Pass == "" {
+		return &proto.PutRegistryCredentialResponse{}, status.Error(codes.FailedPrecondition, "user and pass required")
+	}
+
+	_, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: request.NamespaceId})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.PutRegistryCredentialResponse{}, status.Errorf(codes.NotFound, "namespace %q not found",
+				request.NamespaceId)
+		}
+		log.Error().Err(err).Msg("could not get namespace from storage")
+		return &proto.PutRegistryCredentialResponse{}, status.Errorf(codes.Internal, "could not get namespace %q",
+			request.NamespaceId)
+	}
+
+	credentials, err := api.storage.GetAllRegistryCredentials(storage.GetAllRegistryCredentialsRequest{
+		NamespaceID: request.NamespaceId,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Msg("could not get registry credentials")
+		return &proto.PutRegistryCredentialResponse{}, status.Error(codes.Internal,
+			"failed to retrieve registry credentials from database")
+	}
+
+	var credential *models.RegistryCredential
+	for _, existing := range credentials {
+		if existing.Registry == registry {
+			credential = existing
+			break
+		}
+	}
+
+	// The password goes into the secret store first so that a credential is never stored without one.
+	err = api.secretStore.PutSecret(registryCredentialKey(request.NamespaceId, registry), request.Pass, true)
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Str("registry", registry).
+			Msg("could not save registry credential password")
+		return &proto.PutRegistryCredentialResponse{}, status.Error(codes.Internal, "could not save registry credential")
+	}
+
+	now := time.Now().UnixMilli()
+
+	if credential == nil {
+		credential = &models.RegistryCredential{
+			NamespaceID: request.NamespaceId,
+			Registry:    registry,
+			User:        request.User,
+			Created:     now,
+			Updated:     now,
+		}
+		err = api.storage.AddRegistryCredential(storage.AddRegistryCredentialRequest{RegistryCredential: credential})
+	} else {
+		credential.User = request.User
+		credential.Updated = now
+		err = api.storage.UpdateRegistryCredential(storage.UpdateRegistryCredentialRequest{
+			RegistryCredential: credential,
+		})
+	}
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Str("registry", registry).
+			Msg("could not save registry credential")
+		return &proto.PutRegistryCredentialResponse{}, status.Error(codes.Internal, "could not save registry credential")
+	}
+
+	log.Info().Str("namespace", credential.NamespaceID).Str("registry", credential.Registry).
+		Str("user", credential.User).Msg("put registry credential")
+
+	return &proto.PutRegistryCredentialResponse{
+		Credential: credential.ToProto(),
+	}, nil
+}
+
+func (api *API) DeleteRegistryCredential(ctx context.Context, request *proto.DeleteRegistryCredentialRequest) (*proto.DeleteRegistryCredentialResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.DeleteRegistryCredentialResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	registry := normalizeRegistry(request.Registry)
+
+	credentials, err := api.storage.GetAllRegistryCredentials(storage.GetAllRegistryCredentialsRequest{
+		NamespaceID: request.NamespaceId,
+	})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Msg("could not get registry credentials")
+		return &proto.DeleteRegistryCredentialResponse{}, status.Error(codes.Internal,
+			"failed to retrieve registry credentials from database")
+	}
+
+	var credential *models.RegistryCredential
+	for _, existing := range credentials {
+		if existing.Registry == registry {
+			credential = existing
+			break
+		}
+	}
+
+	if credential == nil {
+		return &proto.DeleteRegistryCredentialResponse{}, status.Errorf(codes.NotFound,
+			"no registry credential for %q in namespace %q", registry, request.NamespaceId)
+	}
+
+	err = api.storage.DeleteRegistryCredential(storage.DeleteRegistryCredentialRequest{ID: credential.ID})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Str("registry", registry).
+			Msg("could not delete registry credential")
+		return &proto.DeleteRegistryCredentialResponse{}, status.Error(codes.Internal,
+			"could not delete registry credential")
+	}
+
+	err = api.secretStore.DeleteSecret(registryCredentialKey(request.NamespaceId, registry))
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Str("registry", registry).
+			Msg("could not delete registry credential password")
+	}
+
+	log.Info().Str("namespace", request.NamespaceId).Str("registry", registry).Msg("deleted registry credential")
+	return &proto.DeleteRegistryCredentialResponse{}, nil
+}
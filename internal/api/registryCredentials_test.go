@@ -0,0 +1,57 @@
+package api
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestMatchRegistryCredential(t *testing.T) {
+	credentials := []*models.RegistryCredential{
+		{ID: 1, Registry: "ghcr.io"},
+		{ID: 2, Registry: "ghcr.io/myorg"},
+		{ID: 3, Registry: "docker.io/library"},
+	}
+
+	tests := map[string]struct {
+		image    string
+		expected int64
+	}{
+		"registry":                {image: "ghcr.io/someone/app:latest", expected: 1},
+		"most specific path":      {image: "ghcr.io/myorg/app:latest", expected: 2},
+		"path must end on a part": {image: "ghcr.io/myorganization/app:latest", expected: 1},
+		"official image":          {image: "ubuntu:latest", expected: 3},
+		"no match":                {image: "quay.io/someone/app:latest", expected: 0},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := matchRegistryCredential(credentials, tc.image)
+
+			gotID := int64(0)
+			if got != nil {
+				gotID = got.ID
+			}
+
+			if gotID != tc.expected {
+				t.Errorf("unexpected credential; want %d; got %d", tc.expected, gotID)
+			}
+		})
+	}
+}
+
+func TestNormalizeRegistry(t *testing.T) {
+	tests := map[string]string{
+		"ghcr.io":                "ghcr.io",
+		" ghcr.io/myorg/ ":       "ghcr.io/myorg",
+		"https://ghcr.io/myorg":  "ghcr.io/myorg",
+		"http://localhost:5000/": "localhost:5000",
+	}
+
+	for registry, expected := range tests {
+		got := normalizeRegistry(registry)
+		if got != expected {
+			t.Errorf("unexpected registry for %q; want %q; got %q", registry, expected, got)
+		}
+	}
+}
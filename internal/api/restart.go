@@ -0,0 +1,103 @@
+package api
+
+import (
+	"errors"
+	"fmt"
+	"net"
+	"os"
+	"strconv"
+	"strings"
+	"syscall"
+
+	"github.com/rs/zerolog/log"
+)
+
+// Restarts replace the running binary(usually with a freshly upgraded one) in place. They aren't zero-downtime:
+// connections aren't refused, but they wait until the new process is up and long lived streams are cut. On SIGHUP the process stops taking on new work, gives open requests a chance to finish and then
+// re-executes itself in place. The PID stays the same, so process supervisors(ex. systemd or a container runtime)
+// never see the service exit. The listening socket is kept open across the exec so connections made in the meantime
+// wait in its backlog instead of being refused.
+//
+// Runs in progress aren't drained or handed over; the restarted process adopts them through the same recovery it
+// would run after any other restart. Followed log streams are ended with codes.Unavailable so that clients know to
+// reconnect and continue where they left off. Attach sessions can't be resumed and are simply ended.
+
+// envInheritedListener holds the file descriptor of the listening socket passed along by a restart.
+const envInheritedListener = "GOFER_INHERITED_LISTENER"
+
+// listen returns the socket the service accepts connections on. A process started by a restart uses the socket it
+// inherited.
+func (api *API) listen() (net.Listener, error) {
+	value := os.Getenv(envInheritedListener)
+	if value == "" {
+		return net.Listen("tcp", api.config.Host)
+	}
+	_ = os.Unsetenv(envInheritedListener)
+
+	listener, err := inheritedListener(value)
+	if err != nil {
+		return nil, err
+	}
+
+	log.Info().Str("url", listener.Addr().String()).Msg("using listener handed over by restart")
+
+	return listener, nil
+}
+
+// inheritedListener recreates the listening socket from the file descriptor it was passed as.
+func inheritedListener(fd string) (net.Listener, error) {
+	number, err := strconv.ParseUint(fd, 10, 64)
+	if err != nil {
+		return nil, fmt.Errorf("could not parse inherited listener %q: %w", fd, err)
+	}
+
+	// FileListener makes its own copy of the socket so the inherited descriptor is closed to keep it from being
+	// passed along again on the next restart.
+	file := os.NewFile(uintptr(number), "listener")
+	defer file.Close()
+
+	listener, err := net.FileListener(file)
+	if err != nil {
+		return nil, fmt.Errorf("could not use inherited listener: %w", err)
+	}
+
+	return listener, nil
+}
+
+// inheritableListener returns a copy of the listening socket which stays open once the process is re-executed.
+func inheritableListener(listener net.Listener) (*os.File, error) {
+	tcpListener, ok := listener.(*net.TCPListener)
+	if !ok {
+		return nil, errors.New("listener can not be handed over")
+	}
+
+	// File returns a duplicate of the socket so shutting down the server doesn't close it. Go marks every descriptor
+	// it opens to be closed on exec; that flag is cleared for this one.
+	file, err := tcpListener.File()
+	if err != nil {
+		return nil, fmt.Errorf("could not get listener file: %w", err)
+	}
+
+	_, _, errno := syscall.Syscall(syscall.SYS_FCNTL, file.Fd(), syscall.F_SETFD, 0)
+	if errno != 0 {
+		file.Close()
+		return nil, fmt.Errorf("could not keep listener open across restart: %w", errno)
+	}
+
+	return file, nil
+}
+
+// restart replaces the current process with a new execution of the given executable using the same arguments and
+// passes along the listening socket. It only returns if the process could not be replaced.
+func restart(executable string, listener *os.File) error {
+	env := []string{}
+	for _, variable := range os.Environ() {
+		if strings.HasPrefix(variable, envInheritedListener+"=") {
+			continue
+		}
+		env = append(env, variable)
+	}
+	env = append(env, fmt.Sprintf("%s=%d", envInheritedListener, listener.Fd()))
+
+	return syscall.Exec(executable, os.Args, env)
+}
@@ -0,0 +1,70 @@
+package api
+
+import (
+	"net"
+	"strconv"
+	"syscall"
+	"testing"
+)
+
+func TestListenerHandover(t *testing.T) {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		t.Fatal(err)
+	}
+	address := listener.Addr().String()
+
+	file, err := inheritableListener(listener)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	flags, _, errno := syscall.Syscall(syscall.SYS_FCNTL, file.Fd(), syscall.F_GETFD, 0)
+	if errno != 0 {
+		t.Fatal(errno)
+	}
+
+	if flags&syscall.FD_CLOEXEC != 0 {
+		t.Fatal("expected listener handed over by restart to stay open across exec")
+	}
+
+	// The server closes its own listener when shutting down; the handed over socket has to keep the address.
+	listener.Close()
+
+	// The restarted process gets its own copy of the descriptor.
+	fd, err := syscall.Dup(int(file.Fd()))
+	if err != nil {
+		t.Fatal(err)
+	}
+	file.Close()
+
+	inherited, err := inheritedListener(strconv.Itoa(fd))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer inherited.Close()
+
+	if inherited.Addr().String() != address {
+		t.Fatalf("expected inherited listener on %s; got %s", address, inherited.Addr())
+	}
+
+	go func() {
+		conn, err := net.Dial("tcp", address)
+		if err == nil {
+			conn.Close()
+		}
+	}()
+
+	conn, err := inherited.Accept()
+	if err != nil {
+		t.Fatalf("could not accept connection on inherited listener: %v", err)
+	}
+	conn.Close()
+}
+
+func TestInheritedListenerInvalid(t *testing.T) {
+	_, err := inheritedListener("not a descriptor")
+	if err == nil {
+		t.Fatal("expected error for invalid descriptor")
+	}
+}
@@ -0,0 +1,123 @@
+package api
+
+import (
+	"fmt"
+	"sync"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+// runConfig is the part of a pipeline's config that a run is executed from. It is shared between runs and must not
+// be modified.
+type runConfig struct {
+	Tasks     map[string]models.Task
+	Notifiers map[string]models.PipelineNotifierConfig
+}
+
+type runConfigKey struct {
+	namespace string
+	pipeline  string
+	version   int64
+}
+
+// runConfigCache keeps the tasks and notifiers of the pipeline config versions runs are started with so that starting
+// a run doesn't have to read and decode the whole config from storage again. Recorded config versions never change, so
+// entries don't go stale; a pipeline's entries are dropped whenever it is deployed or abandoned so that only versions
+// that are still likely to be used are kept.
+type runConfigCache struct {
+	mu      sync.RWMutex
+	configs map[runConfigKey]*runConfig
+}
+
+func newRunConfigCache() *runConfigCache {
+	return &runConfigCache{
+		configs: map[runConfigKey]*runConfig{},
+	}
+}
+
+func (c *runConfigCache) get(key runConfigKey) (*runConfig, bool) {
+	c.mu.RLock()
+	defer c.mu.RUnlock()
+
+	config, exists := c.configs[key]
+	return config, exists
+}
+
+func (c *runConfigCache) set(key runConfigKey, config *runConfig) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	c.configs[key] = config
+}
+
+// warm caches the current config version of a pipeline that was already read from storage.
+func (c *runConfigCache) warm(pipeline *models.Pipeline) {
+	if pipeline.ConfigVersion == 0 {
+		return
+	}
+
+	key := runConfigKey{namespace: pipeline.Namespace, pipeline: pipeline.ID, version: pipeline.ConfigVersion}
+	if _, exists := c.get(key); exists {
+		return
+	}
+
+	c.set(key, &runConfig{Tasks: pipeline.Tasks, Notifiers: pipeline.Notifiers})
+}
+
+// forget drops every cached config version of a pipeline.
+func (c *runConfigCache) forget(namespace, pipeline string) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	for key := range c.configs {
+		if key.namespace == namespace && key.pipeline == pipeline {
+			delete(c.configs, key)
+		}
+	}
+}
+
+// runConfig returns the tasks and notifiers of the config version the given run was started with. Runs that use a
+// canary version get the canary's tasks and notifiers instead of those of the pipeline's current config version.
+func (api *API) runConfig(run *models.Run) (*runConfig, error) {
+	version := run.ConfigVersion
+
+	// Runs from before config versions were recorded on runs only know about their canary.
+	if version == 0 {
+		version = run.CanaryVersion
+	}
+
+	// Without a recorded config version the best we can do is the pipeline's current config.
+	if version == 0 {
+		pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{
+			NamespaceID: run.NamespaceID,
+			ID:          run.PipelineID,
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		return &runConfig{Tasks: pipeline.Tasks, Notifiers: pipeline.Notifiers}, nil
+	}
+
+	key := runConfigKey{namespace: run.NamespaceID, pipeline: run.PipelineID, version: version}
+	if config, exists := api.runConfigs.get(key); exists {
+		return config, nil
+	}
+
+	configVersion, err := api.storage.GetPipelineConfigVersion(storage.GetPipelineConfigVersionRequest{
+		NamespaceID: run.NamespaceID,
+		PipelineID:  run.PipelineID,
+		Version:     version,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("could not get config version %d: %w", version, err)
+	}
+
+	pipeline := models.NewPipeline(configVersion.Location, &configVersion.Config)
+	config := &runConfig{Tasks: pipeline.Tasks, Notifiers: pipeline.Notifiers}
+
+	api.runConfigs.set(key, config)
+
+	return config, nil
+}
@@ -0,0 +1,37 @@
+package api
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestRunConfigCacheForget(t *testing.T) {
+	cache := newRunConfigCache()
+
+	cache.warm(&models.Pipeline{Namespace: "default", ID: "simple", ConfigVersion: 1})
+	cache.warm(&models.Pipeline{Namespace: "default", ID: "simple", ConfigVersion: 2})
+	cache.warm(&models.Pipeline{Namespace: "default", ID: "other", ConfigVersion: 1})
+	cache.warm(&models.Pipeline{Namespace: "default", ID: "unversioned"})
+
+	cache.forget("default", "simple")
+
+	tests := map[string]struct {
+		key    runConfigKey
+		cached bool
+	}{
+		"forgotten_version":    {key: runConfigKey{namespace: "default", pipeline: "simple", version: 1}, cached: false},
+		"forgotten_latest":     {key: runConfigKey{namespace: "default", pipeline: "simple", version: 2}, cached: false},
+		"other_pipeline":       {key: runConfigKey{namespace: "default", pipeline: "other", version: 1}, cached: true},
+		"unversioned_pipeline": {key: runConfigKey{namespace: "default", pipeline: "unversioned"}, cached: false},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			_, cached := cache.get(test.key)
+			if cached != test.cached {
+				t.Errorf("incorrect cache state; want %t got %t", test.cached, cached)
+			}
+		})
+	}
+}
@@ -0,0 +1,68 @@
+package api
+
+import (
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/clintjedwards/gofer/internal/tracing"
+	"github.com/rs/zerolog/log"
+)
+
+func (api *API) runNetworksEnabled() bool {
+	return api.config.Scheduler != nil && api.config.Scheduler.RunNetworks
+}
+
+// createRunNetwork creates the network the task runs of a run share so that they can reach each other by task ID. If
+// the network can't be created the run goes on without one and its task runs use the scheduler's default network.
+func (api *API) createRunNetwork(run *models.Run) {
+	if !api.runNetworksEnabled() {
+		return
+	}
+
+	id := fmt.Sprintf(RUNNETWORKIDFORMAT, run.NamespaceID, run.PipelineID, run.ID)
+
+	err := scheduler.NewTracedEngine(tracing.RunContext(run.CorrelationID), api.scheduler).CreateNetwork(
+		scheduler.CreateNetworkRequest{
+			ID: id,
+			Labels: mergeMaps(api.namespaceSchedulerLabels(run.NamespaceID), map[string]string{
+				"gofer.namespace":      run.NamespaceID,
+				"gofer.pipeline":       run.PipelineID,
+				"gofer.run":            strconv.FormatInt(run.ID, 10),
+				"gofer.correlation_id": run.CorrelationID,
+			}),
+		})
+	if err != nil {
+		log.Error().Err(err).Str("network", id).Str("pipeline", run.PipelineID).Int64("run", run.ID).
+			Str("correlation_id", run.CorrelationID).
+			Msg("could not create run network; task runs will use the default network")
+		return
+	}
+
+	api.runNetworks.Set(inProgressRunKey(run.NamespaceID, run.PipelineID, run.ID), id)
+}
+
+// removeRunNetwork removes the network of a run once all of its task runs have finished.
+func (api *API) removeRunNetwork(run *models.Run) {
+	key := inProgressRunKey(run.NamespaceID, run.PipelineID, run.ID)
+
+	id, exists := api.runNetworks.Get(key)
+	if !exists {
+		return
+	}
+	api.runNetworks.Delete(key)
+
+	err := scheduler.NewTracedEngine(tracing.RunContext(run.CorrelationID), api.scheduler).RemoveNetwork(
+		scheduler.RemoveNetworkRequest{ID: id})
+	if err != nil {
+		log.Error().Err(err).Str("network", id).Str("pipeline", run.PipelineID).Int64("run", run.ID).
+			Str("correlation_id", run.CorrelationID).Msg("could not remove run network")
+	}
+}
+
+// taskRunNetwork returns the network a task run's container joins; empty if its run doesn't have one.
+func (api *API) taskRunNetwork(taskRun *models.TaskRun) string {
+	id, _ := api.runNetworks.Get(inProgressRunKey(taskRun.NamespaceID, taskRun.PipelineID, taskRun.RunID))
+	return id
+}
@@ -2,24 +2,35 @@ package api
 
 import (
 	"bufio"
+	"context"
 	"errors"
 	"fmt"
-	"os"
+	"io"
+	"math/rand"
 	"strconv"
 	"strings"
 	"sync"
 	"time"
 
+	logstore "github.com/clintjedwards/gofer/internal/logStore"
 	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/clintjedwards/gofer/internal/scheduler"
 	"github.com/clintjedwards/gofer/internal/storage"
 	"github.com/clintjedwards/gofer/internal/syncmap"
+	"github.com/clintjedwards/gofer/internal/tracing"
 	"github.com/rs/zerolog/log"
 )
 
 const (
 	TASKCONTAINERIDFORMAT = "%s_%d_%s" // pipelineid_runid_taskrunid
 
+	// ISOLATEDTASKCONTAINERIDFORMAT is used when namespace isolation is enabled so that the containers of pipelines
+	// with the same ID in different namespaces can't be mistaken for one another.
+	ISOLATEDTASKCONTAINERIDFORMAT = "%s.%s_%d_%s" // namespaceid.pipelineid_runid_taskrunid
+
+	// RUNNETWORKIDFORMAT is the name of the network the task runs of a run share when run networks are enabled.
+	RUNNETWORKIDFORMAT = "gofer.%s.%s_%d" // gofer.namespaceid.pipelineid_runid
+
 	// GOFEREOF is a special string marker we include at the end of log files.
 	// It denotes that no further logs will be written. This is to provide the functionality for downstream
 	// applications to follow log files and not also have to monitor the container for state to know when
@@ -43,8 +54,10 @@ func mergeMaps[KeyType comparable, ValueType any](maps ...map[KeyType]ValueType)
 
 // startTaskRun starts a specific task run and updates the taskrun with either a failed or running state once
 // complete. This function updates the taskRun provided to it automatically.
-func (api *API) startTaskRun(sc scheduler.StartContainerRequest, taskRun *models.TaskRun) (string, error) {
-	containerInfo, err := api.scheduler.StartContainer(sc)
+func (api *API) startTaskRun(ctx context.Context, sc scheduler.StartContainerRequest, taskRun *models.TaskRun) (string, error) {
+	db := storage.NewTracedEngine(ctx, api.storage)
+
+	containerInfo, err := scheduler.NewTracedEngine(ctx, api.scheduler).StartContainer(sc)
 	if err != nil {
 		taskRun.SetFinishedAbnormal(models.ContainerStateFailed,
 			models.TaskRunFailure{
@@ -53,13 +66,13 @@ func (api *API) startTaskRun(sc scheduler.StartContainerRequest, taskRun *models
 			},
 			1)
 
-		storageErr := api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskRun})
+		storageErr := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskRun})
 		if storageErr != nil {
 			log.Error().Err(err).Msg("could not update run")
 		}
 
 		if containerInfo.SchedulerID != "" {
-			go api.handleLogUpdates(containerInfo.SchedulerID, taskRun)
+			go api.handleLogUpdates(ctx, containerInfo.SchedulerID, taskRun)
 		}
 
 		return "", err
@@ -68,7 +81,8 @@ func (api *API) startTaskRun(sc scheduler.StartContainerRequest, taskRun *models
 	taskRun.State = models.ContainerStateRunning
 	taskRun.SchedulerID = containerInfo.SchedulerID
 	taskRun.Started = time.Now().UnixMilli()
-	err = api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskRun})
+	taskRun.ImagePull = containerInfo.ImagePullDuration.Milliseconds()
+	err = db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskRun})
 	if err != nil {
 		return "", err
 	}
@@ -79,20 +93,29 @@ func (api *API) startTaskRun(sc scheduler.StartContainerRequest, taskRun *models
 // monitorTaskRun attaches state and log monitoring goroutines to track taskrun state and progress over time.
 // It automatically updates the provided taskrun with the resulting state change(s).
 // This function will block until the taskrun has reached a finished state.
-func (api *API) monitorTaskRun(schedulerID string, taskRun *models.TaskRun) error {
-	go api.handleLogUpdates(schedulerID, taskRun)
-	err := api.waitTaskRunFinish(schedulerID, taskRun)
+func (api *API) monitorTaskRun(ctx context.Context, schedulerID string, taskRun *models.TaskRun) error {
+	go api.handleLogUpdates(ctx, schedulerID, taskRun)
+	err := api.waitTaskRunFinish(ctx, schedulerID, taskRun)
 	if err != nil {
 		log.Error().Err(err).Str("task", taskRun.ID).
 			Str("pipeline", taskRun.PipelineID).
-			Int64("run", taskRun.RunID).Msg("could not get state for container update")
+			Int64("run", taskRun.RunID).Str("correlation_id", taskRun.CorrelationID).
+			Msg("could not get state for container update")
+	}
+
+	// Artifacts are collected before the task run is recorded as finished so that they're available to anything
+	// waiting on it.
+	if err == nil && len(taskRun.Task.Artifacts) > 0 &&
+		(taskRun.State == models.ContainerStateSuccess || taskRun.State == models.ContainerStateFailed) {
+		api.collectArtifacts(ctx, schedulerID, taskRun)
 	}
 
-	err = api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskRun})
+	err = storage.NewTracedEngine(ctx, api.storage).UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskRun})
 	if err != nil {
 		log.Error().Err(err).Str("task", taskRun.ID).
 			Str("pipeline", taskRun.PipelineID).
-			Int64("run", taskRun.RunID).Msg("could not update task run state due to storage err")
+			Int64("run", taskRun.RunID).Str("correlation_id", taskRun.CorrelationID).
+			Msg("could not update task run state due to storage err")
 		return err
 	}
 
@@ -102,7 +125,16 @@ func (api *API) monitorTaskRun(schedulerID string, taskRun *models.TaskRun) erro
 // waitTaskRunFinish monitors the task run's container for many possible states. Depending on the state
 // it will update the task run with that specific state and then exit. Until it reaches a terminal state
 // this function will block.
-func (api *API) waitTaskRunFinish(schedulerID string, taskRun *models.TaskRun) error {
+//
+// The wait is traced as a single span instead of a span for every time the scheduler is polled.
+func (api *API) waitTaskRunFinish(ctx context.Context, schedulerID string, taskRun *models.TaskRun) (err error) {
+	_, span := tracing.Start(ctx, "scheduler.WaitContainer", tracing.String("gofer.scheduler_id", schedulerID))
+	defer func() {
+		span.SetAttributes(tracing.String("gofer.task_run.state", string(taskRun.State)))
+		span.RecordError(err)
+		span.End()
+	}()
+
 	for {
 		state, err := api.scheduler.GetState(scheduler.GetStateRequest{
 			SchedulerID: schedulerID,
@@ -154,8 +186,8 @@ func (api *API) waitTaskRunFinish(schedulerID string, taskRun *models.TaskRun) e
 
 // handleLogUpdates monitors for and stores logs for a given run. If run again for a particular log file it will
 // truncate previous logs and repopulate logs with logs from scheduler.
-func (api *API) handleLogUpdates(schedulerID string, currentTaskRun *models.TaskRun) {
-	logReader, err := api.scheduler.GetLogs(scheduler.GetLogsRequest{
+func (api *API) handleLogUpdates(ctx context.Context, schedulerID string, currentTaskRun *models.TaskRun) {
+	logReader, err := scheduler.NewTracedEngine(ctx, api.scheduler).GetLogs(scheduler.GetLogsRequest{
 		SchedulerID: schedulerID,
 	})
 	if err != nil {
@@ -163,23 +195,65 @@ func (api *API) handleLogUpdates(schedulerID string, currentTaskRun *models.Task
 		return
 	}
 
-	logfile, err := os.Create(api.taskRunLogFilePath(currentTaskRun))
+	logfile, err := api.logStore.Create(api.taskRunLogKey(currentTaskRun))
 	if err != nil {
-		log.Error().Err(err).Msg("Could not open task run log file for writing")
+		log.Error().Err(err).Msg("Could not open task run log for writing")
 		return
 	}
 
+	lineNum := 0
+	lastCheckpoint := int64(0)
+
+	limit := api.taskRunLogLimit()
+	written := int64(0)
+	dropped := int64(0)
+
 	scanner := bufio.NewScanner(logReader)
 	for scanner.Scan() {
-		_, _ = logfile.WriteString(scanner.Text() + "\n")
+		line := scanner.Text() + "\n"
+
+		// Once a task run reaches its log limit we keep reading so the container isn't blocked on writing its logs,
+		// but everything past the limit is dropped.
+		if limit > 0 && written+int64(len(line)) > limit {
+			if dropped == 0 {
+				_, _ = io.WriteString(logfile, taskRunLogTruncatedMarker(api.config.TaskRunLogs.MaxSizeMB))
+			}
+			dropped++
+			continue
+		}
+
+		lineNum++
+
+		// We record a checkpoint of which line we're on roughly every second so that readers are able to
+		// seek to logs by time without us having to alter the log lines themselves.
+		now := time.Now()
+		if now.Unix() != lastCheckpoint {
+			_ = logfile.Checkpoint(now, int64(lineNum))
+			lastCheckpoint = now.Unix()
+		}
+
+		_, _ = io.WriteString(logfile, line)
+		written += int64(len(line))
+	}
+
+	if dropped > 0 {
+		_, _ = io.WriteString(logfile, taskRunLogDroppedMarker(dropped))
+		log.Warn().Str("pipeline", currentTaskRun.PipelineID).Int64("run", currentTaskRun.RunID).
+			Str("task", currentTaskRun.ID).Int64("dropped_lines", dropped).Msg("task run reached its log limit")
 	}
 
 	// When the reader is finished we place a special marker to signify that this file is finished with.
 	// This allows other readers of the file within Gofer to know the difference between a file that is still being
 	// written to and a file that will not be written to any further.
-	_, _ = logfile.WriteString(GOFEREOF)
+	_, _ = io.WriteString(logfile, GOFEREOF)
 
-	logfile.Close()
+	err = logfile.Close()
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", currentTaskRun.PipelineID).Int64("run", currentTaskRun.RunID).
+			Str("task", currentTaskRun.ID).Msg("Could not finish task run log")
+	} else {
+		api.archiveTaskRunLogs(currentTaskRun)
+	}
 
 	err = scanner.Err()
 	if err != nil {
@@ -187,11 +261,73 @@ func (api *API) handleLogUpdates(schedulerID string, currentTaskRun *models.Task
 	}
 }
 
-func (api *API) taskRunLogFilePath(taskRun *models.TaskRun) string {
-	const TASKRUNFILEPATH = "%s/%s_%d_%s" // folder/pipelineid_runid_taskrunid
+// taskRunLogKey returns the key a task run's log is stored under within the log store.
+func (api *API) taskRunLogKey(taskRun *models.TaskRun) string {
+	const TASKRUNLOGKEY = "%s_%d_%s" // pipelineid_runid_taskrunid
 
-	return fmt.Sprintf(TASKRUNFILEPATH, api.config.TaskRunLogsDir,
-		taskRun.PipelineID, taskRun.RunID, taskRun.ID)
+	return fmt.Sprintf(TASKRUNLOGKEY, taskRun.PipelineID, taskRun.RunID, taskRun.ID)
+}
+
+// taskRunLogStartLine returns the first line number(starting at 1) that should be returned to readers of a task run's
+// log file. Tail limits the result to the last N lines of the file and since limits the result to lines written at
+// or after the given epoch millisecond time. When both are given the more restrictive one wins.
+func (api *API) taskRunLogStartLine(logs logstore.Engine, taskRun *models.TaskRun, tail, since int64) (int64, error) {
+	startLine := int64(1)
+
+	if tail == 0 && since == 0 {
+		return startLine, nil
+	}
+
+	file, err := logs.Open(context.Background(), api.taskRunLogKey(taskRun), false)
+	if err != nil {
+		return 0, err
+	}
+	defer file.Close()
+
+	total, err := countLogLines(file)
+	if err != nil {
+		return 0, err
+	}
+
+	if tail > 0 && total-tail+1 > startLine {
+		startLine = total - tail + 1
+	}
+
+	if since > 0 {
+		sinceLine, err := logs.LineSince(api.taskRunLogKey(taskRun), since)
+		if err != nil {
+			return 0, err
+		}
+
+		// If no lines have been written since that time we start after the last line so that only new lines are
+		// returned.
+		if sinceLine == 0 {
+			sinceLine = total + 1
+		}
+
+		if sinceLine > startLine {
+			startLine = sinceLine
+		}
+	}
+
+	return startLine, nil
+}
+
+// countLogLines returns the amount of log lines currently written to a log file. Since every log line is newline
+// terminated and the GOFEREOF marker is not, this is simply the number of newlines in the file.
+func countLogLines(file io.Reader) (int64, error) {
+	count := int64(0)
+	reader := bufio.NewReader(file)
+	for {
+		_, err := reader.ReadBytes('\n')
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				return count, nil
+			}
+			return 0, err
+		}
+		count++
+	}
 }
 
 // interpolateVars takes in a map of mixed plaintext and raw secret/store strings and populates it with the fetched
@@ -274,7 +410,14 @@ func parseInterpolationSyntax(prefix, variable string) string {
 
 // reviveLostTaskRun attempts to re-run as taskrun that has somehow been orphaned. It is used for taskruns
 // that have not been scheduled yet, but will be after other task runs have finished.
-func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.ContainerState], taskrun *models.TaskRun) {
+func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.ContainerState], taskrun *models.TaskRun,
+	vars map[string]string,
+) {
+	ctx, span := startTaskRunSpan(taskrun)
+	defer endTaskRunSpan(span, taskrun)
+
+	db := storage.NewTracedEngine(ctx, api.storage)
+
 	taskStatusMap.Set(taskrun.Task.ID, taskrun.State)
 	api.events.Publish(models.NewEventStartedTaskRun(*taskrun))
 
@@ -297,7 +440,7 @@ func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.
 				Description: fmt.Sprintf("Task could not be run due to unmet dependencies: %v", err),
 			}, 1)
 
-		err := api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
+		err := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
 		if err != nil {
 			log.Error().Err(err).Msg("could not update task run")
 		}
@@ -306,6 +449,26 @@ func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.
 		return
 	}
 
+	if api.skipUnmetCondition(db, taskStatusMap, taskrun, vars) {
+		return
+	}
+
+	// Privileges can be taken away by the server's admins after the pipeline was registered.
+	err := checkTaskPrivileges(taskrun.NamespaceID, taskrun.PipelineID, &taskrun.Task, api.taskPrivilegesAllowlist())
+	if err != nil {
+		taskrun.SetFinishedAbnormal(models.ContainerStateFailed, models.TaskRunFailure{
+			Kind:        models.TaskRunFailureKindFailedPrecondition,
+			Description: fmt.Sprintf("Task could not be run; %v", err),
+		}, 1)
+		err := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
+		if err != nil {
+			log.Error().Err(err).Msg("could not update task run")
+		}
+		taskStatusMap.Set(taskrun.Task.ID, taskrun.State)
+		api.events.Publish(models.NewEventCompletedTaskRun(*taskrun))
+		return
+	}
+
 	// First we attempt to find any pipeline/secret store variables and replace them with the correct var.
 	parsedEnvVars, err := api.interpolateVars(taskrun.NamespaceID, taskrun.PipelineID, taskrun.EnvVars)
 	if err != nil {
@@ -313,7 +476,7 @@ func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.
 			Kind:        models.TaskRunFailureKindFailedPrecondition,
 			Description: fmt.Sprintf("Task could not be run due to unmet dependencies; could not find one or more keys: %v", err),
 		}, 1)
-		err := api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
+		err := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
 		if err != nil {
 			log.Error().Err(err).Msg("could not update task run")
 		}
@@ -329,7 +492,7 @@ func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.
 			Kind:        models.TaskRunFailureKindFailedPrecondition,
 			Description: fmt.Sprintf("Task could not be run due to unmet dependencies; could not find one or more keys: %v", err),
 		}, 1)
-		err := api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
+		err := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskrun})
 		if err != nil {
 			log.Error().Err(err).Msg("could not update task run")
 		}
@@ -340,12 +503,11 @@ func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.
 
 	// We create a run level token here that we pass into each task run. This allows task runs to perform actions
 	// against the API.
-	key, tokenObject, err := api.createNewAPIToken(models.TokenKindClient, []string{taskrun.NamespaceID},
-		map[string]string{"description": "temporary run token"})
+	key, tokenObject, err := api.createRunToken(taskrun.NamespaceID, taskrun.PipelineID, taskrun.RunID)
 	if err != nil {
 		log.Error().Err(err).Msg("could not create token")
 	} else {
-		defer api.storage.DeleteToken(storage.DeleteTokenRequest{
+		defer db.DeleteToken(storage.DeleteTokenRequest{
 			Hash: tokenObject.Hash,
 		})
 	}
@@ -354,34 +516,47 @@ func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.
 		"GOFER_API_TOKEN": key, // We use this token to give task runs the ability to interact with Gofer.
 	}
 
-	schedulerID, err := api.startTaskRun(scheduler.StartContainerRequest{
-		ID:        fmt.Sprintf(TASKCONTAINERIDFORMAT, taskrun.PipelineID, taskrun.RunID, taskrun.ID),
-		ImageName: taskrun.Image,
+	image := api.schedulerImage(taskrun.NamespaceID, taskrun.Image)
+	registryUser, registryPass := api.registryAuth(taskrun.NamespaceID, image, taskrun.RegistryAuth)
+
+	schedulerID, err := api.startTaskRun(ctx, scheduler.StartContainerRequest{
+		ID:        api.taskRunContainerID(taskrun),
+		ImageName: image,
 		EnvVars:   mergeMaps(taskrun.Secrets, parsedEnvVars),
+		Labels:    api.taskRunContainerLabels(taskrun),
 		Exec: scheduler.Exec{
 			Shell:  taskrun.Exec.Shell,
 			Script: taskrun.Exec.Script,
 		},
-		RegistryUser: taskrun.RegistryAuth.User,
-		RegistryPass: parseInterpolationSyntax("secret", taskrun.RegistryAuth.Pass),
+		RegistryUser: registryUser,
+		RegistryPass: registryPass,
+		ImageMaxAge:  api.imageMaxAge(),
+		Resources:    taskRunResources(taskrun.Resources, api.taskResourceLimits()),
+		GPUs:         taskRunGPUs(taskrun.GPUs),
+		Privileged:   taskrun.Privileged,
+		CapAdd:       taskrun.CapAdd,
+		Network:      api.taskRunNetwork(taskrun),
+		NetworkAlias: taskrun.Task.ID,
 	}, taskrun)
 	if err != nil {
 		log.Error().Err(err).Str("id", taskrun.ID).
-			Str("pipeline", taskrun.PipelineID).Int64("run", taskrun.RunID).Msg("task run could not be started")
+			Str("pipeline", taskrun.PipelineID).Int64("run", taskrun.RunID).Str("correlation_id", taskrun.CorrelationID).
+			Msg("task run could not be started")
 		taskStatusMap.Set(taskrun.Task.ID, taskrun.State)
 		api.events.Publish(models.NewEventCompletedTaskRun(*taskrun))
 		return
 	}
 	log.Info().Str("id", taskrun.ID).Str("pipeline", taskrun.PipelineID).
-		Int64("run", taskrun.RunID).Msg("started task run")
+		Int64("run", taskrun.RunID).Str("correlation_id", taskrun.CorrelationID).Msg("started task run")
 
 	api.events.Publish(models.NewEventScheduledTaskRun(*taskrun))
 	taskStatusMap.Set(taskrun.Task.ID, taskrun.State)
 
-	err = api.monitorTaskRun(schedulerID, taskrun)
+	err = api.monitorTaskRun(ctx, schedulerID, taskrun)
 	if err != nil {
 		log.Error().Err(err).Str("id", taskrun.ID).
 			Str("pipeline", taskrun.PipelineID).Int64("run", taskrun.RunID).
+			Str("correlation_id", taskrun.CorrelationID).
 			Msg("task run monitor encountered an error")
 		taskStatusMap.Set(taskrun.Task.ID, taskrun.State)
 		api.events.Publish(models.NewEventCompletedTaskRun(*taskrun))
@@ -391,14 +566,27 @@ func (api *API) reviveLostTaskRun(taskStatusMap *syncmap.Syncmap[string, models.
 	taskStatusMap.Set(taskrun.Task.ID, taskrun.State)
 	api.events.Publish(models.NewEventCompletedTaskRun(*taskrun))
 
-	log.Info().Str("id", taskrun.ID).Str("status", string(taskrun.State)).Msg("finished task run")
+	log.Info().Str("id", taskrun.ID).Str("status", string(taskrun.State)).
+		Str("correlation_id", taskrun.CorrelationID).Msg("finished task run")
 }
 
 // createNewTaskRun launches a brand new task run as part of a larger run for a specific task.
 // It blocks until the taskrun has gone through the full lifecycle or waiting, running, and then finally
 // is finished.
 func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.ContainerState], run models.Run, task models.Task, token string) {
-	newTaskRun := models.NewTaskRun(run, task)
+	api.launchTaskRun(taskStatusMap, run, models.NewTaskRun(run, task), token)
+}
+
+// launchTaskRun takes a task run that has yet to be stored and takes it through its full lifecycle. It's split from
+// createNewTaskRun so that callers can fill in details of the task run before it's launched.
+func (api *API) launchTaskRun(taskStatusMap *syncmap.Syncmap[string, models.ContainerState], run models.Run, newTaskRun *models.TaskRun, token string) {
+	task := newTaskRun.Task
+
+	ctx, span := startTaskRunSpan(newTaskRun)
+	defer endTaskRunSpan(span, newTaskRun)
+
+	db := storage.NewTracedEngine(ctx, api.storage)
+
 	api.events.Publish(models.NewEventStartedTaskRun(*newTaskRun))
 
 	// These environment variables are present on every task run
@@ -407,6 +595,8 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 		"GOFER_RUN_ID":      strconv.Itoa(int(run.ID)),
 		"GOFER_TASK_ID":     task.ID,
 		"GOFER_TASK_IMAGE":  task.Image,
+
+		"GOFER_CORRELATION_ID": run.CorrelationID,
 	}
 
 	// We need to combine the environment variables we get from multiple sources in order to pass them finally to the
@@ -415,11 +605,13 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 	// are passed into the mergeMaps function determines the priority in reverse order. Last in the stack will overwrite
 	// any conflicts from the others.
 	//
-	// 1) We first pass in the Gofer specific envvars as these are the most replaceable on the totem pole.
-	// 2) We pass in the task specific envvars defined by the user in the pipeline config.
-	// 3) Lastly we pass in the trigger's defined envvars, these are the most variable and most important since
+	// 1) We first pass in the envvars the operator has configured for every task run. These are only defaults meant to
+	// fit the environment Gofer runs in, so everything else is able to replace them.
+	// 2) We pass in the Gofer specific envvars.
+	// 3) We pass in the task specific envvars defined by the user in the pipeline config.
+	// 4) Lastly we pass in the trigger's defined envvars, these are the most variable and most important since
 	// they map back to the user's intent for a specific run.
-	envVars := mergeMaps(RunSpecificVars, task.EnvVars, run.Variables)
+	envVars := mergeMaps(api.config.TaskRunEnvVars, RunSpecificVars, task.EnvVars, run.Variables)
 
 	// We need to remove any envvars that have been added with an empty key
 	for key := range envVars {
@@ -435,7 +627,7 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 	}
 	newTaskRun.State = models.ContainerStateWaiting
 
-	err := api.storage.AddTaskRun(storage.AddTaskRunRequest{TaskRun: newTaskRun})
+	err := db.AddTaskRun(storage.AddTaskRunRequest{TaskRun: newTaskRun})
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityExists) {
 			log.Error().Err(err).Msg("could not add task run")
@@ -466,7 +658,7 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 				Description: fmt.Sprintf("Task could not be run due to unmet dependencies: %v", err),
 			}, 1)
 
-		err = api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
+		err = db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
 		if err != nil {
 			log.Error().Err(err).Msg("could not update task run")
 		}
@@ -475,6 +667,28 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 		return
 	}
 
+	// Tasks with a condition are only run if the run's variables meet it.
+	if api.skipUnmetCondition(db, taskStatusMap, newTaskRun, run.Variables) {
+		return
+	}
+
+	// Privileges can be taken away by the server's admins after the pipeline was registered.
+	err = checkTaskPrivileges(newTaskRun.NamespaceID, newTaskRun.PipelineID, &newTaskRun.Task,
+		api.taskPrivilegesAllowlist())
+	if err != nil {
+		newTaskRun.SetFinishedAbnormal(models.ContainerStateFailed, models.TaskRunFailure{
+			Kind:        models.TaskRunFailureKindFailedPrecondition,
+			Description: fmt.Sprintf("Task could not be run; %v", err),
+		}, 1)
+		err := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
+		if err != nil {
+			log.Error().Err(err).Msg("could not update task run")
+		}
+		taskStatusMap.Set(newTaskRun.Task.ID, newTaskRun.State)
+		api.events.Publish(models.NewEventCompletedTaskRun(*newTaskRun))
+		return
+	}
+
 	// First we attempt to find any pipeline/secret store variables and replace them with the correct var.
 	parsedEnvVars, err := api.interpolateVars(newTaskRun.NamespaceID, newTaskRun.PipelineID, newTaskRun.EnvVars)
 	if err != nil {
@@ -483,7 +697,7 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 			Description: fmt.Sprintf("Task could not be run due to unmet dependencies; "+
 				"could not find one or more keys in store: %v", err),
 		}, 1)
-		err := api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
+		err := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
 		if err != nil {
 			log.Error().Err(err).Msg("could not update task run")
 		}
@@ -500,7 +714,7 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 			Description: fmt.Sprintf("Task could not be run due to unmet dependencies; "+
 				"could not find one or more keys in store: %v", err),
 		}, 1)
-		err := api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
+		err := db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
 		if err != nil {
 			log.Error().Err(err).Msg("could not update task run")
 		}
@@ -509,46 +723,187 @@ func (api *API) createNewTaskRun(taskStatusMap *syncmap.Syncmap[string, models.C
 		return
 	}
 
-	// Finally start the task run.
-	schedulerID, err := api.startTaskRun(scheduler.StartContainerRequest{
-		ID:        fmt.Sprintf(TASKCONTAINERIDFORMAT, newTaskRun.PipelineID, newTaskRun.RunID, newTaskRun.ID),
-		ImageName: newTaskRun.Image,
-		EnvVars:   mergeMaps(newTaskRun.Secrets, parsedEnvVars),
+	// Finally start the task run. Task runs that fail are attempted again for as long as they have retries left.
+	for {
+		api.attemptTaskRun(ctx, taskStatusMap, newTaskRun, parsedEnvVars)
+
+		if !newTaskRun.CanRetry() {
+			break
+		}
+
+		backoff := newTaskRun.RetryBackoff()
+		log.Info().Str("id", newTaskRun.ID).Str("pipeline", newTaskRun.PipelineID).
+			Int64("run", newTaskRun.RunID).Int64("attempt", newTaskRun.Attempt).Dur("backoff", backoff).
+			Str("correlation_id", newTaskRun.CorrelationID).Msg("retrying failed task run")
+
+		newTaskRun.NextAttempt()
+		err = db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: newTaskRun})
+		if err != nil {
+			log.Error().Err(err).Msg("could not update task run")
+		}
+		taskStatusMap.Set(newTaskRun.Task.ID, newTaskRun.State)
+
+		time.Sleep(backoff)
+	}
+
+	taskStatusMap.Set(newTaskRun.Task.ID, newTaskRun.State)
+	api.events.Publish(models.NewEventCompletedTaskRun(*newTaskRun))
+
+	log.Info().Str("id", newTaskRun.ID).Str("status", string(newTaskRun.State)).
+		Str("correlation_id", newTaskRun.CorrelationID).Msg("finished task run")
+}
+
+// attemptTaskRun starts the task run's container and blocks until it has finished, leaving the result on the task run.
+func (api *API) attemptTaskRun(ctx context.Context, taskStatusMap *syncmap.Syncmap[string, models.ContainerState],
+	taskRun *models.TaskRun, envVars map[string]string,
+) {
+	caches, cacheFiles, closeCaches := api.restoreCaches(taskRun, envVars)
+	defer closeCaches()
+
+	image := api.schedulerImage(taskRun.NamespaceID, taskRun.Image)
+	registryUser, registryPass := api.registryAuth(taskRun.NamespaceID, image, taskRun.RegistryAuth)
+
+	schedulerID, err := api.startTaskRun(ctx, scheduler.StartContainerRequest{
+		ID:        api.taskRunContainerID(taskRun),
+		ImageName: image,
+		EnvVars:   mergeMaps(taskRun.Secrets, envVars),
+		Labels:    api.taskRunContainerLabels(taskRun),
 		Exec: scheduler.Exec{
-			Shell:  newTaskRun.Exec.Shell,
-			Script: newTaskRun.Exec.Script,
+			Shell:  taskRun.Exec.Shell,
+			Script: taskRun.Exec.Script,
 		},
-		RegistryUser: newTaskRun.RegistryAuth.User,
-		RegistryPass: parseInterpolationSyntax("secret", newTaskRun.RegistryAuth.Pass),
-	}, newTaskRun)
+		RegistryUser: registryUser,
+		RegistryPass: registryPass,
+		ImageMaxAge:  api.imageMaxAge(),
+		Files:        cacheFiles,
+		Resources:    taskRunResources(taskRun.Resources, api.taskResourceLimits()),
+		GPUs:         taskRunGPUs(taskRun.GPUs),
+		Privileged:   taskRun.Privileged,
+		CapAdd:       taskRun.CapAdd,
+		Network:      api.taskRunNetwork(taskRun),
+		NetworkAlias: taskRun.Task.ID,
+	}, taskRun)
 	if err != nil {
-		log.Error().Err(err).Str("id", newTaskRun.ID).Str("pipeline", newTaskRun.PipelineID).
-			Int64("run", newTaskRun.RunID).Msg("task run could not be started")
-		taskStatusMap.Set(newTaskRun.Task.ID, newTaskRun.State)
-		api.events.Publish(models.NewEventCompletedTaskRun(*newTaskRun))
+		log.Error().Err(err).Str("id", taskRun.ID).Str("pipeline", taskRun.PipelineID).
+			Int64("run", taskRun.RunID).Int64("attempt", taskRun.Attempt).Str("correlation_id", taskRun.CorrelationID).
+			Msg("task run could not be started")
 		return
 	}
-	log.Info().Str("id", newTaskRun.ID).Str("pipeline", newTaskRun.PipelineID).
-		Int64("run", newTaskRun.RunID).Msg("started task run")
+	log.Info().Str("id", taskRun.ID).Str("pipeline", taskRun.PipelineID).
+		Int64("run", taskRun.RunID).Int64("attempt", taskRun.Attempt).Str("correlation_id", taskRun.CorrelationID).
+		Msg("started task run")
 
-	api.events.Publish(models.NewEventScheduledTaskRun(*newTaskRun))
-	taskStatusMap.Set(newTaskRun.Task.ID, newTaskRun.State)
+	api.events.Publish(models.NewEventScheduledTaskRun(*taskRun))
+	taskStatusMap.Set(taskRun.Task.ID, taskRun.State)
 
 	// Block until taskrun status can be logged
-	err = api.monitorTaskRun(schedulerID, newTaskRun)
+	err = api.monitorTaskRun(ctx, schedulerID, taskRun)
 	if err != nil {
-		log.Error().Err(err).Str("id", newTaskRun.ID).Str("pipeline", newTaskRun.PipelineID).
-			Int64("run", newTaskRun.RunID).Msg("task run monitor encountered an error")
-		taskStatusMap.Set(newTaskRun.Task.ID, newTaskRun.State)
-		api.events.Publish(models.NewEventCompletedTaskRun(*newTaskRun))
+		log.Error().Err(err).Str("id", taskRun.ID).Str("pipeline", taskRun.PipelineID).
+			Int64("run", taskRun.RunID).Int64("attempt", taskRun.Attempt).Str("correlation_id", taskRun.CorrelationID).
+			Msg("task run monitor encountered an error")
 		return
 	}
 
-	taskStatusMap.Set(newTaskRun.Task.ID, newTaskRun.State)
-	api.events.Publish(models.NewEventCompletedTaskRun(*newTaskRun))
+	// Caches are only saved from task runs that succeeded; a failed task run may have left them half written.
+	if taskRun.State == models.ContainerStateSuccess && len(caches) > 0 {
+		api.saveCaches(ctx, schedulerID, taskRun, caches)
+	}
+}
 
-	log.Info().Str("id", newTaskRun.ID).Str("status", string(newTaskRun.State)).
-		Msg("finished task run")
+// skipUnmetCondition skips the task run if the run's variables don't meet the task's condition. Returns whether the
+// task run was skipped.
+func (api *API) skipUnmetCondition(db storage.Engine, taskStatusMap *syncmap.Syncmap[string, models.ContainerState],
+	taskRun *models.TaskRun, vars map[string]string,
+) bool {
+	shouldRun, err := taskRun.EvaluateCondition(vars)
+	if err == nil && shouldRun {
+		return false
+	}
+
+	failure := models.TaskRunFailure{
+		Kind:        models.TaskRunFailureKindConditionNotMet,
+		Description: fmt.Sprintf("Task was skipped because its condition %q was not met.", taskRun.When),
+	}
+	if err != nil {
+		failure.Description = fmt.Sprintf("Task was skipped because its condition could not be evaluated: %v", err)
+	}
+
+	taskRun.SetFinishedAbnormal(models.ContainerStateSkipped, failure, 0)
+
+	err = db.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: taskRun})
+	if err != nil {
+		log.Error().Err(err).Msg("could not update task run")
+	}
+	taskStatusMap.Set(taskRun.Task.ID, models.ContainerStateSkipped)
+	api.events.Publish(models.NewEventCompletedTaskRun(*taskRun))
+
+	return true
+}
+
+// taskRunContainerID returns the name of a task run's container.
+func (api *API) taskRunContainerID(taskRun *models.TaskRun) string {
+	if api.namespaceIsolationEnabled() {
+		return fmt.Sprintf(ISOLATEDTASKCONTAINERIDFORMAT, taskRun.NamespaceID, taskRun.PipelineID, taskRun.RunID, taskRun.ID)
+	}
+
+	return fmt.Sprintf(TASKCONTAINERIDFORMAT, taskRun.PipelineID, taskRun.RunID, taskRun.ID)
+}
+
+// taskRunContainerLabels returns the labels attached to a task run's container. Labels the operator configured for the
+// task run's namespace are included, but can never replace Gofer's own.
+func (api *API) taskRunContainerLabels(taskRun *models.TaskRun) map[string]string {
+	return mergeMaps(api.namespaceSchedulerLabels(taskRun.NamespaceID), map[string]string{
+		"gofer.namespace":      taskRun.NamespaceID,
+		"gofer.pipeline":       taskRun.PipelineID,
+		"gofer.run":            strconv.FormatInt(taskRun.RunID, 10),
+		"gofer.task_run":       taskRun.ID,
+		"gofer.correlation_id": taskRun.CorrelationID,
+	})
+}
+
+// downstreamTasks returns the given task along with every task that depends on it either directly or through other
+// tasks. If the filter is not empty only tasks found in the filter are returned.
+func downstreamTasks(tasks map[string]models.Task, from string, filter map[string]struct{}) (map[string]struct{}, error) {
+	if _, exists := tasks[from]; !exists {
+		return nil, fmt.Errorf("task %q not found in pipeline", from)
+	}
+
+	if len(filter) != 0 {
+		if _, exists := filter[from]; !exists {
+			return nil, fmt.Errorf("task %q was not part of the previous run", from)
+		}
+	}
+
+	downstream := map[string]struct{}{from: {}}
+
+	// Keep sweeping through the tasks until no new dependents are found.
+	for {
+		added := false
+		for id, task := range tasks {
+			if _, exists := downstream[id]; exists {
+				continue
+			}
+
+			if len(filter) != 0 {
+				if _, exists := filter[id]; !exists {
+					continue
+				}
+			}
+
+			for parent := range task.DependsOn {
+				if _, exists := downstream[parent]; exists {
+					downstream[id] = struct{}{}
+					added = true
+					break
+				}
+			}
+		}
+
+		if !added {
+			return downstream, nil
+		}
+	}
 }
 
 // parentTasksFinished checks to see if all parents dependencies are in a finished state.
@@ -662,9 +1017,13 @@ func (api *API) addNotifiersAsTasks(notifiers map[string]models.PipelineNotifier
 }
 
 // createNewRun starts a new run and launches the goroutines responsible for running tasks.
-func (api *API) createNewRun(namespaceID, pipelineID, triggerKind, triggerName string,
-	taskFilter map[string]struct{}, vars map[string]string,
+func (api *API) createNewRun(namespaceID, pipelineID, triggerKind, triggerName, initiator string,
+	taskFilter map[string]struct{}, vars map[string]string, resumedFrom int64, upstream *models.RunUpstream,
 ) (*models.Run, error) {
+	if api.draining.Load() {
+		return nil, ErrShuttingDown
+	}
+
 	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{NamespaceID: namespaceID, ID: pipelineID})
 	if err != nil {
 		return nil, err
@@ -674,6 +1033,16 @@ func (api *API) createNewRun(namespaceID, pipelineID, triggerKind, triggerName s
 		return nil, ErrPipelineNotActive
 	}
 
+	// The namespace's run quota has to stay locked until the new run is stored, otherwise concurrent requests could
+	// all find room for one more run.
+	unlock := api.lockRunQuota(namespaceID)
+	defer unlock()
+
+	err = api.checkRunQuota(namespaceID)
+	if err != nil {
+		return nil, err
+	}
+
 	if pipeline.Sequential && pipeline.LastRunID != 0 {
 		latestRun, err := api.storage.GetRun(storage.GetRunRequest{
 			NamespaceID: namespaceID,
@@ -689,7 +1058,44 @@ func (api *API) createNewRun(namespaceID, pipelineID, triggerKind, triggerName s
 		}
 	}
 
+	// The group has to stay locked until the new run is stored, otherwise another pipeline in the group could start a
+	// run in between.
+	if pipeline.ConcurrencyGroup != "" {
+		api.concurrencyGroupsMu.Lock()
+		defer api.concurrencyGroupsMu.Unlock()
+
+		err = api.concurrencyGroupBusy(namespaceID, pipeline.ConcurrencyGroup)
+		if err != nil {
+			return nil, err
+		}
+	}
+
 	newRun := models.NewRun(pipelineID, pipeline.Namespace, triggerKind, triggerName, taskFilter, vars)
+	newRun.ResumedFrom = resumedFrom
+	newRun.Initiator = initiator
+	newRun.Upstream = upstream
+
+	// Resumed runs keep the tasks of the run they resume from; otherwise the pipeline's canary, if any, decides which
+	// config version the run takes its tasks from.
+	if resumedFrom != 0 {
+		resumedRun, err := api.storage.GetRun(storage.GetRunRequest{
+			NamespaceID: namespaceID,
+			PipelineID:  pipelineID,
+			ID:          resumedFrom,
+		})
+		if err != nil {
+			return nil, fmt.Errorf("could not get run %d to resume from: %w", resumedFrom, err)
+		}
+
+		newRun.CanaryVersion = resumedRun.CanaryVersion
+		newRun.ConfigVersion = resumedRun.ConfigVersion
+	} else if pipeline.Canary != nil && pipeline.Canary.Selects(triggerKind == "manual", rand.Int63n(100)) {
+		newRun.CanaryVersion = pipeline.Canary.Version
+		newRun.ConfigVersion = pipeline.Canary.Version
+	} else {
+		newRun.ConfigVersion = pipeline.ConfigVersion
+		api.runConfigs.warm(pipeline)
+	}
 
 	err = api.storage.AddRun(storage.AddRunRequest{Run: newRun})
 	if err != nil {
@@ -700,10 +1106,17 @@ func (api *API) createNewRun(namespaceID, pipelineID, triggerKind, triggerName s
 		return nil, fmt.Errorf("could not add run; %w", err)
 	}
 
-	go api.events.Publish(models.NewEventStartedRun(*newRun)) // Publish that the run is currently in motion.
-	go api.handleRunObjectExpiry(namespaceID, pipelineID)     // Run objects expire after a given amount of runs.
-	go api.handleRunLogExpiry(namespaceID, pipelineID)        // Run logs expire after a given amount of runs.
-	go api.executeTaskTree(newRun)                            // Launch a tree of goroutines to handle task run dependencies.
+	go api.events.Publish(models.NewEventStartedRun(*newRun))       // Publish that the run is currently in motion.
+	go api.handleRunObjectExpiry(namespaceID, pipelineID)           // Run objects expire after a given amount of runs.
+	go api.handleRunLogExpiry(namespaceID, pipelineID)              // Run logs expire after a given amount of runs.
+	go api.handleArtifactExpiry(namespaceID, pipelineID, newRun.ID) // Artifacts expire after a given amount of runs.
+
+	// Launch a tree of goroutines to handle task run dependencies.
+	api.runsInProgress.Inc()
+	go func() {
+		defer api.runsInProgress.Dec()
+		api.executeTaskTree(newRun)
+	}()
 
 	return newRun, nil
 }
@@ -769,7 +1182,7 @@ func (api *API) handleRunLogExpiry(namespaceID, pipelineID string) {
 		removedFiles := []string{}
 		for _, taskRun := range taskRuns {
 			taskRun := taskRun
-			err := os.Remove(api.taskRunLogFilePath(taskRun))
+			err := api.deleteTaskRunLogs(taskRun)
 			if err != nil {
 				log.Debug().Err(err).Msg("could not remove task run log file")
 			}
@@ -779,7 +1192,7 @@ func (api *API) handleRunLogExpiry(namespaceID, pipelineID string) {
 			if err != nil {
 				log.Error().Err(err).Msg("could not update run")
 			}
-			removedFiles = append(removedFiles, api.taskRunLogFilePath(taskRun))
+			removedFiles = append(removedFiles, api.taskRunLogKey(taskRun))
 		}
 
 		log.Debug().Strs("removed_files", removedFiles).Int("run_age_limit", limit).Int64("run_id", run.ID).Msg("old run logs removed")
@@ -841,19 +1254,25 @@ func (api *API) handleRunObjectExpiry(namespace, pipeline string) {
 // executeTaskTree creates all downstream task runs for a particular run. After creating all task runs it
 // then blocks and monitors the run until it is finished.
 func (api *API) executeTaskTree(run *models.Run) {
-	pipeline, err := api.storage.GetPipeline(storage.GetPipelineRequest{
-		NamespaceID: run.NamespaceID,
-		ID:          run.PipelineID,
-	})
+	// The run's span is the root of its trace. Task runs join it through the run's correlation ID.
+	_, span := tracing.StartRun(run.CorrelationID, "run",
+		tracing.String("gofer.namespace", run.NamespaceID),
+		tracing.String("gofer.pipeline", run.PipelineID),
+		tracing.Int64("gofer.run", run.ID),
+		tracing.String("gofer.correlation_id", run.CorrelationID),
+	)
+	defer span.End()
+
+	pipeline, err := api.runConfig(run)
 	if err != nil {
-		log.Error().Err(err).Msg("could not get pipeline in order to run task tree")
+		log.Error().Err(err).Msg("could not get pipeline config in order to run task tree")
+		span.RecordError(err)
 		return
 	}
 
 	// We create a run level token here that we pass into each task run. This allows task runs to perform actions
 	// against the API.
-	key, tokenObject, err := api.createNewAPIToken(models.TokenKindClient, []string{run.NamespaceID},
-		map[string]string{"description": "temporary run token"})
+	key, tokenObject, err := api.createRunToken(run.NamespaceID, run.PipelineID, run.ID)
 	if err != nil {
 		log.Error().Err(err).Msg("could not create token")
 	} else {
@@ -872,6 +1291,46 @@ func (api *API) executeTaskTree(run *models.Run) {
 
 	taskStatusMap := syncmap.New[string, models.ContainerState]()
 
+	// The network is created before any task runs are so that they all end up on it.
+	api.createRunNetwork(run)
+	defer api.removeRunNetwork(run)
+
+	// Task runs can add tasks to the run while it's in progress, so we keep what they need to do so around until the
+	// run has finished.
+	runKey := inProgressRunKey(run.NamespaceID, run.PipelineID, run.ID)
+	inProgress := &inProgressRun{
+		run:       *run,
+		statusMap: &taskStatusMap,
+		token:     key,
+	}
+	api.inProgressRuns.Set(runKey, inProgress)
+	defer api.inProgressRuns.Delete(runKey)
+
+	// Resumed runs only run some of the tasks again; the rest keep the results they had in the previous run so that
+	// the tasks being run again can still check their dependencies.
+	if run.ResumedFrom != 0 {
+		previousTaskRuns, err := api.storage.GetAllTaskRuns(storage.GetAllTaskRunsRequest{
+			NamespaceID: run.NamespaceID,
+			PipelineID:  run.PipelineID,
+			RunID:       run.ResumedFrom,
+		})
+		if err != nil {
+			log.Error().Err(err).Int64("resumed_from", run.ResumedFrom).Msg("could not get task runs of previous run")
+		}
+
+		for _, taskRun := range previousTaskRuns {
+			if _, exists := run.Only[taskRun.Task.ID]; exists {
+				continue
+			}
+
+			if _, exists := pipeline.Tasks[taskRun.Task.ID]; !exists {
+				continue
+			}
+
+			taskStatusMap.Set(taskRun.Task.ID, taskRun.State)
+		}
+	}
+
 	for id, task := range runnableTasks {
 		// If it already exists in the status map, skip it
 		_, exists := taskStatusMap.Get(id)
@@ -896,7 +1355,8 @@ func (api *API) executeTaskTree(run *models.Run) {
 
 		for parentTaskName := range task.DependsOn {
 			_, exists := run.Only[parentTaskName]
-			if !exists {
+			_, resumed := taskStatusMap.Get(parentTaskName)
+			if !exists && !resumed {
 				run.SetFailed("Precondition Failure", "A task to be executed depends on a task that isn't in the 'only' filter")
 				err := api.storage.UpdateRun(storage.UpdateRunRequest{Run: run})
 				if err != nil {
@@ -910,16 +1370,22 @@ func (api *API) executeTaskTree(run *models.Run) {
 		taskStatusMap.Set(task.ID, models.ContainerStateProcessing)
 	}
 
-	err = api.monitorRunStatus(run.NamespaceID, run.PipelineID, run.ID, &taskStatusMap)
+	err = api.monitorRunStatus(run.NamespaceID, run.PipelineID, run.ID, &taskStatusMap, &inProgress.mu)
 	if err != nil {
 		log.Error().Err(err).Msg("could not update run status")
+		span.RecordError(err)
 		return
 	}
 }
 
 // monitorRunStatus takes into account all task run statuses that are currently being run and then determines
 // the final run status based on all finished task run statuses. It will block until all task runs have finished.
-func (api *API) monitorRunStatus(namespaceID, pipelineID string, runID int64, statusMap *syncmap.Syncmap[string, models.ContainerState]) error {
+//
+// The statuses are read while holding mu, which is also held while task runs are added to the run, so that a task run
+// can't be added between the run being found finished and it being marked as such.
+func (api *API) monitorRunStatus(namespaceID, pipelineID string, runID int64,
+	statusMap *syncmap.Syncmap[string, models.ContainerState], mu *sync.Mutex,
+) error {
 	run, err := api.storage.GetRun(storage.GetRunRequest{
 		NamespaceID: namespaceID,
 		PipelineID:  pipelineID,
@@ -941,6 +1407,7 @@ func (api *API) monitorRunStatus(namespaceID, pipelineID string, runID int64, st
 	cancelled := 0
 	for {
 		time.Sleep(time.Second * 3)
+		mu.Lock()
 		statusList := []models.ContainerState{}
 		for _, key := range statusMap.Keys() {
 			status, exists := statusMap.Get(key)
@@ -950,6 +1417,7 @@ func (api *API) monitorRunStatus(namespaceID, pipelineID string, runID int64, st
 
 			statusList = append(statusList, status)
 		}
+		mu.Unlock()
 
 		failures = 0
 		finished = 0
@@ -1000,9 +1468,10 @@ func (api *API) monitorRunStatus(namespaceID, pipelineID string, runID int64, st
 	}
 
 	api.events.Publish(models.NewEventCompletedRun(*run))
+	go api.startDownstreamRuns(*run)
 
 	log.Info().Int64("id", run.ID).Str("pipeline", run.PipelineID).
-		Str("result", string(run.State)).Msg("finished run")
+		Str("result", string(run.State)).Str("correlation_id", run.CorrelationID).Msg("finished run")
 
 	return nil
 }
@@ -1011,18 +1480,20 @@ func (api *API) monitorRunStatus(namespaceID, pipelineID string, runID int64, st
 // It then waits until the goroutine monitoring run health gets to the correct state.
 // This causes the function to block for a bit, while it waits for the correct run status.
 func (api *API) cancelRun(run *models.Run, description string, force bool) error {
-	taskRuns, err := api.storage.GetAllTaskRuns(storage.GetAllTaskRunsRequest{
-		NamespaceID: run.NamespaceID,
-		PipelineID:  run.PipelineID,
-		RunID:       run.ID,
-	})
-	if err != nil {
-		return err
-	}
-
 	// Because of how state updates work we need to wait for the run to be settled by
 	// the goroutine that controls this before we update the description.
+	//
+	// Task runs are looked up again every time around since task runs that are being retried start new containers.
 	for {
+		taskRuns, err := api.storage.GetAllTaskRuns(storage.GetAllTaskRunsRequest{
+			NamespaceID: run.NamespaceID,
+			PipelineID:  run.PipelineID,
+			RunID:       run.ID,
+		})
+		if err != nil {
+			return err
+		}
+
 		for _, taskrun := range taskRuns {
 			if taskrun.State != models.ContainerStateRunning {
 				continue
@@ -1045,7 +1516,7 @@ func (api *API) cancelRun(run *models.Run, description string, force bool) error
 				log.Error().Err(err).
 					Str("id", taskrun.ID).
 					Str("pipeline", taskrun.PipelineID).
-					Int64("run", taskrun.RunID).
+					Int64("run", taskrun.RunID).Str("correlation_id", taskrun.CorrelationID).
 					Msg("could not cancel task run")
 			}
 		}
@@ -1082,7 +1553,11 @@ func (api *API) cancelRun(run *models.Run, description string, force bool) error
 	}
 }
 
-func (api *API) cancelAllRuns(namespaceID, pipelineID, description string, force bool) ([]int64, error) {
+// cancelAllRuns cancels all in-progress runs for a pipeline. If states are given only runs currently in one of
+// those states are cancelled.
+func (api *API) cancelAllRuns(namespaceID, pipelineID, description string, force bool,
+	states []models.RunState,
+) ([]int64, error) {
 	type runkey struct {
 		namespace string
 		pipeline  string
@@ -1159,6 +1634,10 @@ func (api *API) cancelAllRuns(namespaceID, pipelineID, description string, force
 			continue
 		}
 
+		if len(states) > 0 && !containsRunState(states, run.State) {
+			continue
+		}
+
 		// Launch to routines to handle all in-progress run cancellations. We call cancelrun and then wait until the
 		// state has reached a "finished" state.
 		cancelledRunList = append(cancelledRunList, run.ID)
@@ -1190,6 +1669,16 @@ func (api *API) cancelAllRuns(namespaceID, pipelineID, description string, force
 	return cancelledRunList, nil
 }
 
+func containsRunState(states []models.RunState, state models.RunState) bool {
+	for _, s := range states {
+		if s == state {
+			return true
+		}
+	}
+
+	return false
+}
+
 func sliceToSet(elements []string) map[string]struct{} {
 	elementMap := make(map[string]struct{})
 	for _, s := range elements {
@@ -1197,3 +1686,84 @@ func sliceToSet(elements []string) map[string]struct{} {
 	}
 	return elementMap
 }
+
+// createRunToken creates the temporary token task runs of the given run use to talk to Gofer. The token only has access
+// to the run's namespace and is the only kind of token allowed to add task runs to the run.
+func (api *API) createRunToken(namespace, pipeline string, run int64) (key string, token *models.Token, err error) {
+	return api.createNewAPITokenFrom(models.Token{
+		Kind:       models.TokenKindClient,
+		Namespaces: []string{namespace},
+		Metadata:   map[string]string{"description": "temporary run token"},
+		Run: &models.TokenRun{
+			NamespaceID: namespace,
+			PipelineID:  pipeline,
+			RunID:       run,
+		},
+	})
+}
+
+// inProgressRun holds what's needed to add task runs to a run that is still being executed.
+type inProgressRun struct {
+	run       models.Run
+	statusMap *syncmap.Syncmap[string, models.ContainerState]
+	token     string
+
+	// mu serializes adding task runs so that two task runs can't be added with the same ID, and keeps task runs from
+	// being added while the run monitor decides whether the run has finished.
+	mu sync.Mutex
+}
+
+func inProgressRunKey(namespace, pipeline string, run int64) string {
+	return fmt.Sprintf("%s_%s_%d", namespace, pipeline, run)
+}
+
+// spawnTaskRun adds a task to a run that is still in progress. The task may only depend on tasks that are already
+// part of the run, which keeps the run free of cycles.
+func (api *API) spawnTaskRun(inProgress *inProgressRun, task models.Task, spawnedBy string) (*models.TaskRun, error) {
+	inProgress.mu.Lock()
+	defer inProgress.mu.Unlock()
+
+	if _, exists := inProgress.statusMap.Get(task.ID); exists {
+		return nil, fmt.Errorf("%w; task %q is already part of the run", ErrTaskRunExists, task.ID)
+	}
+
+	for parent := range task.DependsOn {
+		if _, exists := inProgress.statusMap.Get(parent); !exists {
+			return nil, fmt.Errorf("%w; task depends on %q which is not part of the run", ErrInvalidSpawn, parent)
+		}
+	}
+
+	if spawnedBy != "" {
+		if _, exists := inProgress.statusMap.Get(spawnedBy); !exists {
+			return nil, fmt.Errorf("%w; spawning task run %q is not part of the run", ErrInvalidSpawn, spawnedBy)
+		}
+	}
+
+	// Once every task run has finished the run is about to be marked as finished, so it's too late to add to it.
+	finished := true
+	for _, id := range inProgress.statusMap.Keys() {
+		state, _ := inProgress.statusMap.Get(id)
+		if state == models.ContainerStateRunning ||
+			state == models.ContainerStateProcessing ||
+			state == models.ContainerStateWaiting {
+			finished = false
+			break
+		}
+	}
+	if finished {
+		return nil, fmt.Errorf("%w; all task runs have finished", ErrRunNotInProgress)
+	}
+
+	newTaskRun := models.NewTaskRun(inProgress.run, task)
+	newTaskRun.SpawnedBy = spawnedBy
+
+	// The task run is changed as it's launched, so the caller gets a copy of it as it was before.
+	spawned := *newTaskRun
+
+	// Put an initial entry into taskstatusmap so the run monitor knows to wait on it. This has to happen before the
+	// task run is launched, which updates the entry as it goes.
+	inProgress.statusMap.Set(task.ID, models.ContainerStateProcessing)
+	go api.launchTaskRun(inProgress.statusMap, inProgress.run, newTaskRun, inProgress.token)
+
+	return &spawned, nil
+}
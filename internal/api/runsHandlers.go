@@ -17,6 +17,10 @@ func (api *API) GetRun(ctx context.Context, request *proto.GetRunRequest) (*prot
 		request.NamespaceId = determineNamespace(ctx)
 	}
 
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.GetRunResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	run, err := api.storage.GetRun(storage.GetRunRequest{
 		NamespaceID: request.NamespaceId,
 		PipelineID:  request.PipelineId,
@@ -42,6 +46,10 @@ func (api *API) BatchGetRuns(ctx context.Context, request *proto.BatchGetRunsReq
 		request.NamespaceId = determineNamespace(ctx)
 	}
 
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.BatchGetRunsResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	if len(request.Ids) == 0 {
 		return &proto.BatchGetRunsResponse{}, status.Error(codes.FailedPrecondition, "at least one ID required")
 	}
@@ -78,6 +86,10 @@ func (api *API) ListRuns(ctx context.Context, request *proto.ListRunsRequest) (*
 		request.NamespaceId = determineNamespace(ctx)
 	}
 
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.ListRunsResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	runs, err := api.storage.GetAllRuns(storage.GetAllRunsRequest{
 		NamespaceID: request.NamespaceId,
 		PipelineID:  request.PipelineId,
@@ -117,7 +129,7 @@ func (api *API) StartRun(ctx context.Context, request *proto.StartRunRequest) (*
 	}
 
 	newRun, err := api.createNewRun(request.NamespaceId,
-		request.PipelineId, "manual", "via_api", sliceToSet(request.Only), request.Variables)
+		request.PipelineId, "manual", "via_api", runInitiator(ctx), sliceToSet(request.Only), request.Variables, 0, nil)
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityNotFound) {
 			return &proto.StartRunResponse{}, status.Errorf(codes.NotFound, "could not create run; %v", err)
@@ -129,6 +141,16 @@ func (api *API) StartRun(ctx context.Context, request *proto.StartRunRequest) (*
 			return &proto.StartRunResponse{}, status.Error(codes.FailedPrecondition,
 				"could not create run; pipeline is not active")
 		}
+		if errors.Is(err, ErrShuttingDown) {
+			return &proto.StartRunResponse{}, status.Error(codes.Unavailable,
+				"could not create run; server is shutting down")
+		}
+		if errors.Is(err, ErrQuotaExceeded) {
+			return &proto.StartRunResponse{}, status.Errorf(codes.ResourceExhausted, "could not create run; %v", err)
+		}
+		if errors.Is(err, ErrConcurrencyGroupBusy) {
+			return &proto.StartRunResponse{}, status.Errorf(codes.FailedPrecondition, "could not create run; %v", err)
+		}
 		if errors.Is(err, ErrPipelineRunsInProgress) {
 			return &proto.StartRunResponse{}, status.Error(codes.FailedPrecondition, "could not create run; pipeline is in sequential mode and a run is already in progress")
 		}
@@ -136,6 +158,8 @@ func (api *API) StartRun(ctx context.Context, request *proto.StartRunRequest) (*
 		return &proto.StartRunResponse{}, status.Errorf(codes.Internal, "could not create run; %v", err)
 	}
 
+	linkRunSpan(ctx, newRun)
+
 	// Emit a new resolve trigger so that manually initiated runs still count as a trigger.
 	resolvedTriggerEvent := models.NewEventResolvedTrigger(request.NamespaceId, request.PipelineId, newRun.TriggerName,
 		models.TriggerResult{
@@ -182,7 +206,29 @@ func (api *API) RetryRun(ctx context.Context, request *proto.RetryRunRequest) (*
 			"failed to retrieve run %d from database", request.Id)
 	}
 
-	newRun, err := api.createNewRun(request.NamespaceId, request.PipelineId, "manual", "via_api", run.Only, run.Variables)
+	only := run.Only
+	resumedFrom := int64(0)
+
+	if request.FromTask != "" {
+		pipeline, err := api.runConfig(run)
+		if err != nil {
+			if errors.Is(err, storage.ErrEntityNotFound) {
+				return &proto.RetryRunResponse{}, status.Error(codes.NotFound, "could not create run; pipeline not found")
+			}
+			log.Error().Err(err).Msg("could not get pipeline")
+			return &proto.RetryRunResponse{}, status.Errorf(codes.Internal, "could not get pipeline; %v", err)
+		}
+
+		only, err = downstreamTasks(pipeline.Tasks, request.FromTask, run.Only)
+		if err != nil {
+			return &proto.RetryRunResponse{}, status.Errorf(codes.FailedPrecondition, "could not resume run; %v", err)
+		}
+
+		resumedFrom = run.ID
+	}
+
+	newRun, err := api.createNewRun(request.NamespaceId, request.PipelineId, "manual", "via_api", runInitiator(ctx),
+		only, run.Variables, resumedFrom, nil)
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityNotFound) {
 			return &proto.RetryRunResponse{}, status.Error(codes.NotFound, "could not create run; pipeline not found")
@@ -194,10 +240,22 @@ func (api *API) RetryRun(ctx context.Context, request *proto.RetryRunRequest) (*
 			return &proto.RetryRunResponse{}, status.Error(codes.FailedPrecondition,
 				"could not create run; pipeline is not active")
 		}
+		if errors.Is(err, ErrShuttingDown) {
+			return &proto.RetryRunResponse{}, status.Error(codes.Unavailable,
+				"could not create run; server is shutting down")
+		}
+		if errors.Is(err, ErrQuotaExceeded) {
+			return &proto.RetryRunResponse{}, status.Errorf(codes.ResourceExhausted, "could not create run; %v", err)
+		}
+		if errors.Is(err, ErrConcurrencyGroupBusy) {
+			return &proto.RetryRunResponse{}, status.Errorf(codes.FailedPrecondition, "could not create run; %v", err)
+		}
 		log.Error().Err(err).Msg("could not create run")
 		return &proto.RetryRunResponse{}, status.Errorf(codes.Internal, "could not create run; %v", err)
 	}
 
+	linkRunSpan(ctx, newRun)
+
 	return &proto.RetryRunResponse{
 		Run: newRun.ToProto(),
 	}, nil
@@ -254,7 +312,13 @@ func (api *API) CancelAllRuns(ctx context.Context, request *proto.CancelAllRunsR
 		return &proto.CancelAllRunsResponse{}, status.Error(codes.PermissionDenied, "access denied")
 	}
 
-	runList, err := api.cancelAllRuns(request.NamespaceId, request.PipelineId, "Run cancelled via API.", request.Force)
+	states := []models.RunState{}
+	for _, state := range request.States {
+		states = append(states, models.RunState(state.String()))
+	}
+
+	runList, err := api.cancelAllRuns(request.NamespaceId, request.PipelineId, "Run cancelled via API.", request.Force,
+		states)
 	if err != nil {
 		if errors.Is(err, storage.ErrEntityNotFound) {
 			return &proto.CancelAllRunsResponse{}, status.Error(codes.FailedPrecondition, "pipeline not found")
@@ -3,6 +3,7 @@ package api
 import (
 	"testing"
 
+	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/google/go-cmp/cmp"
 )
 
@@ -41,3 +42,43 @@ func TestParseInterpolationSyntax(t *testing.T) {
 		})
 	}
 }
+
+func TestDownstreamTasks(t *testing.T) {
+	tasks := map[string]models.Task{
+		"build":  {ID: "build"},
+		"lint":   {ID: "lint"},
+		"test":   {ID: "test", DependsOn: map[string]models.RequiredParentState{"build": models.RequiredParentStateSuccess}},
+		"deploy": {ID: "deploy", DependsOn: map[string]models.RequiredParentState{"test": models.RequiredParentStateSuccess}},
+	}
+
+	tests := map[string]struct {
+		from     string
+		filter   map[string]struct{}
+		expected map[string]struct{}
+	}{
+		"transitive": {from: "build", expected: map[string]struct{}{"build": {}, "test": {}, "deploy": {}}},
+		"leaf":       {from: "deploy", expected: map[string]struct{}{"deploy": {}}},
+		"filtered": {
+			from:     "build",
+			filter:   map[string]struct{}{"build": {}, "test": {}},
+			expected: map[string]struct{}{"build": {}, "test": {}},
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			result, err := downstreamTasks(tasks, test.from, test.filter)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			if diff := cmp.Diff(test.expected, result); diff != "" {
+				t.Errorf("unexpected tasks (-want +got):\n%s", diff)
+			}
+		})
+	}
+
+	if _, err := downstreamTasks(tasks, "missing", nil); err == nil {
+		t.Errorf("expected error for task not in pipeline")
+	}
+}
@@ -0,0 +1,206 @@
+package api
+
+import (
+	"errors"
+	"math/rand"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cron"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+const (
+	// scheduleCheckInterval is how often pipeline schedules are checked for runs that are due.
+	scheduleCheckInterval = 15 * time.Second
+
+	// A schedule that has been due for longer than this is treated as missed rather than late; this is usually because
+	// Gofer was down at the time. Late runs are always started, missed runs depend on the schedule's policy.
+	scheduleMissedAfter = time.Minute
+)
+
+// runSchedulesOnSchedule starts the runs of pipeline schedules as they become due until the API is shut down.
+func (api *API) runSchedulesOnSchedule() {
+	ticker := time.NewTicker(scheduleCheckInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-ticker.C:
+			api.runDueSchedules()
+		}
+	}
+}
+
+// runDueSchedules goes through the schedules of every pipeline and starts a run for those that are due.
+func (api *API) runDueSchedules() {
+	// Schedules aren't advanced while runs can't be started, so that the times they were due are handled by their
+	// missed run policy once runs can be started again.
+	if api.ignorePipelineRunEvents.Load() || api.draining.Load() {
+		return
+	}
+
+	namespaces, err := api.getAllNamespaces()
+	if err != nil {
+		log.Error().Err(err).Msg("could not get namespaces to run pipeline schedules")
+		return
+	}
+
+	now := time.Now()
+
+	for _, namespace := range namespaces {
+		pipelines, err := api.getAllPipelines(namespace.ID)
+		if err != nil {
+			log.Error().Err(err).Str("namespace", namespace.ID).Msg("could not get pipelines to run pipeline schedules")
+			continue
+		}
+
+		for _, pipeline := range pipelines {
+			if len(pipeline.Schedules) == 0 {
+				continue
+			}
+
+			api.runDuePipelineSchedules(pipeline, now)
+		}
+	}
+}
+
+// runDuePipelineSchedules starts a run for each of the pipeline's schedules that are due. The time each schedule was
+// last due is stored before any run is started so that a schedule can't start the same run twice.
+func (api *API) runDuePipelineSchedules(pipeline *models.Pipeline, now time.Time) {
+	if pipeline.ScheduleState == nil {
+		pipeline.ScheduleState = map[string]int64{}
+	}
+
+	changed := false
+	due := map[string]time.Time{}
+
+	for label, schedule := range pipeline.Schedules {
+		// Schedules that are new start counting from now instead of from the dawn of time.
+		lastDue, exists := pipeline.ScheduleState[label]
+		if !exists || lastDue == 0 {
+			pipeline.ScheduleState[label] = now.UnixMilli()
+			changed = true
+			continue
+		}
+
+		run, latest, err := dueSchedule(schedule, time.UnixMilli(lastDue), now)
+		if err != nil {
+			log.Error().Err(err).Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+				Str("schedule", label).Msg("could not work out when pipeline schedule is due")
+			continue
+		}
+
+		if latest.UnixMilli() == lastDue {
+			continue
+		}
+
+		pipeline.ScheduleState[label] = latest.UnixMilli()
+		changed = true
+
+		// Times a schedule was due while its pipeline wasn't active are skipped rather than caught up on later.
+		if run && pipeline.State == models.PipelineStateActive {
+			due[label] = latest
+		} else if run {
+			log.Debug().Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).Str("schedule", label).
+				Msg("pipeline schedule run skipped because it is not active")
+		}
+	}
+
+	if !changed {
+		return
+	}
+
+	err := api.storage.UpdatePipeline(storage.UpdatePipelineRequest{Pipeline: pipeline})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+			Msg("could not update pipeline schedule state")
+		return
+	}
+
+	for label, dueTime := range due {
+		go api.startScheduledRun(pipeline.Namespace, pipeline.ID, pipeline.ServiceAccount, pipeline.Schedules[label],
+			dueTime)
+	}
+}
+
+// dueSchedule works out whether a schedule should start a run given the last time it was due. It returns the latest
+// time the schedule has been due, which is the given last due time if it hasn't been due since. When the schedule has
+// been due more than once since, only a single run is started for the latest time.
+func dueSchedule(schedule models.PipelineSchedule, lastDue, now time.Time) (bool, time.Time, error) {
+	expression, err := cron.Parse(schedule.Cron)
+	if err != nil {
+		return false, lastDue, err
+	}
+
+	location, err := schedule.Location()
+	if err != nil {
+		return false, lastDue, err
+	}
+
+	latest := lastDue
+	for {
+		next := expression.Next(latest.In(location))
+		if next.IsZero() || next.After(now) {
+			break
+		}
+
+		latest = next
+	}
+
+	if latest.Equal(lastDue) {
+		return false, lastDue, nil
+	}
+
+	if now.Sub(latest) <= scheduleMissedAfter {
+		return true, latest, nil
+	}
+
+	return schedule.MissedRuns == models.MissedRunPolicyRunOnce, latest, nil
+}
+
+// startScheduledRun starts the run of a schedule that was due at the given time after waiting for the schedule's
+// jitter.
+func (api *API) startScheduledRun(namespace, pipeline, serviceAccount string, schedule models.PipelineSchedule,
+	due time.Time,
+) {
+	if schedule.Jitter > 0 {
+		delay := time.Duration(rand.Int63n(schedule.Jitter * int64(time.Second)))
+
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-time.After(delay):
+		}
+	}
+
+	initiator := ""
+	if serviceAccount != "" {
+		initiator = models.ServiceAccountInitiator(serviceAccount)
+	}
+
+	vars := map[string]string{
+		"GOFER_SCHEDULE":     schedule.Label,
+		"GOFER_SCHEDULE_DUE": due.Format(time.RFC3339),
+	}
+
+	run, err := api.createNewRun(namespace, pipeline, "schedule", schedule.Label, initiator, map[string]struct{}{},
+		vars, 0, nil)
+	if err != nil {
+		logger := log.Error()
+		if errors.Is(err, ErrPipelineNotActive) || errors.Is(err, ErrPipelineRunsInProgress) ||
+			errors.Is(err, ErrConcurrencyGroupBusy) || errors.Is(err, ErrQuotaExceeded) || errors.Is(err, ErrShuttingDown) {
+			logger = log.Warn()
+		}
+
+		logger.Err(err).Str("namespace", namespace).Str("pipeline", pipeline).Str("schedule", schedule.Label).
+			Msg("could not start scheduled run")
+		return
+	}
+
+	log.Info().Str("namespace", namespace).Str("pipeline", pipeline).Str("schedule", schedule.Label).
+		Int64("run", run.ID).Time("due", due).Msg("started scheduled run")
+}
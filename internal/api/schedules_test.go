@@ -0,0 +1,70 @@
+package api
+
+import (
+	"testing"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestDueSchedule(t *testing.T) {
+	hourly := models.PipelineSchedule{Label: "hourly", Cron: "0 * * * *", MissedRuns: models.MissedRunPolicySkip}
+	catchUp := models.PipelineSchedule{Label: "catch_up", Cron: "0 * * * *", MissedRuns: models.MissedRunPolicyRunOnce}
+
+	lastDue := time.Date(2022, 5, 1, 10, 0, 0, 0, time.UTC)
+
+	tests := map[string]struct {
+		schedule models.PipelineSchedule
+		now      time.Time
+		run      bool
+		latest   time.Time
+	}{
+		"not due yet": {
+			schedule: hourly,
+			now:      time.Date(2022, 5, 1, 10, 59, 0, 0, time.UTC),
+			run:      false,
+			latest:   lastDue,
+		},
+		"due": {
+			schedule: hourly,
+			now:      time.Date(2022, 5, 1, 11, 0, 15, 0, time.UTC),
+			run:      true,
+			latest:   time.Date(2022, 5, 1, 11, 0, 0, 0, time.UTC),
+		},
+		"missed and skipped": {
+			schedule: hourly,
+			now:      time.Date(2022, 5, 1, 13, 30, 0, 0, time.UTC),
+			run:      false,
+			latest:   time.Date(2022, 5, 1, 13, 0, 0, 0, time.UTC),
+		},
+		"missed and caught up once": {
+			schedule: catchUp,
+			now:      time.Date(2022, 5, 1, 13, 30, 0, 0, time.UTC),
+			run:      true,
+			latest:   time.Date(2022, 5, 1, 13, 0, 0, 0, time.UTC),
+		},
+		"due after being missed": {
+			schedule: hourly,
+			now:      time.Date(2022, 5, 1, 14, 0, 30, 0, time.UTC),
+			run:      true,
+			latest:   time.Date(2022, 5, 1, 14, 0, 0, 0, time.UTC),
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			run, latest, err := dueSchedule(test.schedule, lastDue, test.now)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			if run != test.run {
+				t.Errorf("expected run to be %t; got %t", test.run, run)
+			}
+
+			if !latest.Equal(test.latest) {
+				t.Errorf("expected latest due time %s; got %s", test.latest, latest)
+			}
+		})
+	}
+}
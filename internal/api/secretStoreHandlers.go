@@ -2,8 +2,12 @@ package api
 
 import (
 	"context"
+	"errors"
+	"fmt"
 
+	"github.com/clintjedwards/gofer/internal/secretStore"
 	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
 )
@@ -19,6 +23,9 @@ func (api *API) GetSecret(ctx context.Context, request *proto.GetSecretRequest)
 
 	content, err := api.secretStore.GetSecret(secretKey(request.NamespaceId, request.PipelineId, request.Key))
 	if err != nil {
+		if errors.Is(err, secretStore.ErrEntityNotFound) {
+			return &proto.GetSecretResponse{}, status.Errorf(codes.NotFound, "secret %q not found", request.Key)
+		}
 		return &proto.GetSecretResponse{}, err
 	}
 
@@ -36,7 +43,18 @@ func (api *API) PutSecret(ctx context.Context, request *proto.PutSecretRequest)
 		return &proto.PutSecretResponse{}, status.Error(codes.PermissionDenied, "access denied")
 	}
 
-	err := api.secretStore.PutSecret(secretKey(request.NamespaceId, request.PipelineId, request.Key), request.Content, request.Force)
+	key := secretKey(request.NamespaceId, request.PipelineId, request.Key)
+
+	err := api.checkSecretQuota(request.NamespaceId, key)
+	if err != nil {
+		if errors.Is(err, ErrQuotaExceeded) {
+			return &proto.PutSecretResponse{}, status.Errorf(codes.ResourceExhausted, "could not put secret %q; %v",
+				request.Key, err)
+		}
+		return &proto.PutSecretResponse{}, status.Errorf(codes.Internal, "could not put secret %q; %v", request.Key, err)
+	}
+
+	err = api.secretStore.PutSecret(key, request.Content, request.Force)
 	if err != nil {
 		return &proto.PutSecretResponse{}, err
 	}
@@ -62,3 +80,20 @@ func (api *API) DeleteSecret(ctx context.Context, request *proto.DeleteSecretReq
 
 	return &proto.DeleteSecretResponse{}, nil
 }
+
+func (api *API) ReencryptSecrets(ctx context.Context, request *proto.ReencryptSecretsRequest) (*proto.ReencryptSecretsResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.ReencryptSecretsResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	reencrypted, err := api.secretStore.ReencryptSecrets()
+	if err != nil {
+		log.Error().Err(err).Int64("reencrypted", reencrypted).Msg("could not re-encrypt secrets")
+		return &proto.ReencryptSecretsResponse{}, status.Error(codes.Internal, fmt.Sprintf("could not re-encrypt secrets; %v", err))
+	}
+
+	log.Info().Int64("reencrypted", reencrypted).Msg("re-encrypted secrets with current encryption key")
+	return &proto.ReencryptSecretsResponse{
+		Reencrypted: reencrypted,
+	}, nil
+}
@@ -1,6 +1,7 @@
 package api
 
 import (
+	"errors"
 	"fmt"
 	"strings"
 
@@ -20,9 +21,25 @@ func parseVersion(versionString string) (version, commit string) {
 }
 
 func (api *API) createNewAPIToken(kind models.TokenKind, namespaces []string, metadata map[string]string) (key string, token *models.Token, err error) {
+	return api.createNewAPITokenFrom(models.Token{
+		Kind:       kind,
+		Namespaces: namespaces,
+		Metadata:   metadata,
+	})
+}
+
+// createNewAPITokenFrom creates a new token with the same permissions, allowlist, metadata and owner(directory user or
+// service account) as the given token.
+func (api *API) createNewAPITokenFrom(settings models.Token) (key string, token *models.Token, err error) {
 	key, hash := api.generateNewAPIToken()
 
-	newToken := models.NewToken(hash, kind, namespaces, metadata)
+	newToken := models.NewToken(hash, settings.Kind, settings.Namespaces, settings.Metadata)
+	newToken.LDAPUser = settings.LDAPUser
+	newToken.ServiceAccount = settings.ServiceAccount
+	newToken.OIDCSubject = settings.OIDCSubject
+	newToken.AllowedCIDRs = settings.AllowedCIDRs
+	newToken.Expires = settings.Expires
+	newToken.Run = settings.Run
 
 	err = api.storage.AddToken(storage.AddTokenRequest{
 		Token: newToken,
@@ -33,3 +50,84 @@ func (api *API) createNewAPIToken(kind models.TokenKind, namespaces []string, me
 
 	return key, newToken, nil
 }
+
+// deleteServiceAccountTokens removes all tokens belonging to the given service account.
+func (api *API) deleteServiceAccountTokens(id string) error {
+	hashes := []string{}
+
+	offset := 0
+	for {
+		tokens, err := api.storage.GetAllTokens(storage.GetAllTokensRequest{
+			Offset: offset,
+		})
+		if err != nil {
+			return err
+		}
+
+		if len(tokens) == 0 {
+			break
+		}
+		offset += len(tokens)
+
+		for _, token := range tokens {
+			if token.ServiceAccount == id {
+				hashes = append(hashes, token.Hash)
+			}
+		}
+	}
+
+	for _, hash := range hashes {
+		err := api.storage.DeleteToken(storage.DeleteTokenRequest{Hash: hash})
+		if err != nil && !errors.Is(err, storage.ErrEntityNotFound) {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// tokenBelongsToUser returns true if the token was created for the given user; either as a directory user token or
+// by being named after the user through its metadata.
+func tokenBelongsToUser(token *models.Token, user string) bool {
+	return token.LDAPUser == user || token.Metadata["name"] == user
+}
+
+// deleteUserTokens removes all tokens belonging to the given user and returns the number of tokens removed.
+func (api *API) deleteUserTokens(user string) (int64, error) {
+	hashes := []string{}
+
+	offset := 0
+	for {
+		tokens, err := api.storage.GetAllTokens(storage.GetAllTokensRequest{
+			Offset: offset,
+		})
+		if err != nil {
+			return 0, err
+		}
+
+		if len(tokens) == 0 {
+			break
+		}
+		offset += len(tokens)
+
+		for _, token := range tokens {
+			if tokenBelongsToUser(token, user) {
+				hashes = append(hashes, token.Hash)
+			}
+		}
+	}
+
+	var deleted int64
+	for _, hash := range hashes {
+		err := api.storage.DeleteToken(storage.DeleteTokenRequest{Hash: hash})
+		if err != nil {
+			if errors.Is(err, storage.ErrEntityNotFound) {
+				continue
+			}
+			return deleted, err
+		}
+		deleted++
+	}
+
+	return deleted, nil
+}
@@ -0,0 +1,184 @@
+package api
+
+import (
+	"context"
+	"errors"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+// ListServiceAccounts returns all service accounts for management users. Namespace admins only see the service
+// accounts they are able to manage.
+func (api *API) ListServiceAccounts(ctx context.Context, request *proto.ListServiceAccountsRequest) (*proto.ListServiceAccountsResponse, error) {
+	accounts, err := api.storage.GetAllServiceAccounts(storage.GetAllServiceAccountsRequest{
+		Offset: int(request.Offset),
+		Limit:  int(request.Limit),
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not get service accounts")
+		return &proto.ListServiceAccountsResponse{}, status.Error(codes.Internal, "failed to retrieve service accounts from database")
+	}
+
+	protoAccounts := []*proto.ServiceAccount{}
+	for _, account := range accounts {
+		if !canDelegate(ctx, account.Kind, account.Namespaces) {
+			continue
+		}
+
+		protoAccounts = append(protoAccounts, account.ToProto())
+	}
+
+	return &proto.ListServiceAccountsResponse{
+		ServiceAccounts: protoAccounts,
+	}, nil
+}
+
+func (api *API) GetServiceAccount(ctx context.Context, request *proto.GetServiceAccountRequest) (*proto.GetServiceAccountResponse, error) {
+	if request.Id == "" {
+		return &proto.GetServiceAccountResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	account, err := api.getManagedServiceAccount(ctx, request.Id)
+	if err != nil {
+		return &proto.GetServiceAccountResponse{}, err
+	}
+
+	return &proto.GetServiceAccountResponse{ServiceAccount: account.ToProto()}, nil
+}
+
+func (api *API) CreateServiceAccount(ctx context.Context, request *proto.CreateServiceAccountRequest) (*proto.CreateServiceAccountResponse, error) {
+	if !canDelegate(ctx, models.TokenKind(request.Kind.String()), request.Namespaces) {
+		return &proto.CreateServiceAccountResponse{}, status.Error(codes.PermissionDenied,
+			"management token or namespace admin token for all requested namespaces required for this action")
+	}
+
+	if request.Id == "" {
+		return &proto.CreateServiceAccountResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	for _, namespace := range request.Namespaces {
+		_, err := api.storage.GetNamespace(storage.GetNamespaceRequest{ID: namespace})
+		if err != nil {
+			if errors.Is(err, storage.ErrEntityNotFound) {
+				return &proto.CreateServiceAccountResponse{},
+					status.Errorf(codes.NotFound, "namespace %q not found", namespace)
+			}
+			return &proto.CreateServiceAccountResponse{},
+				status.Errorf(codes.Internal, "could not create service account: %v", err)
+		}
+	}
+
+	newAccount := models.NewServiceAccount(request.Id, request.Description,
+		models.TokenKind(request.Kind.String()), request.Namespaces)
+
+	err := api.storage.AddServiceAccount(storage.AddServiceAccountRequest{
+		ServiceAccount: newAccount,
+	})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityExists) {
+			return &proto.CreateServiceAccountResponse{}, status.Error(codes.AlreadyExists, "service account already exists")
+		}
+		log.Error().Err(err).Msg("could not add service account")
+		return &proto.CreateServiceAccountResponse{}, status.Errorf(codes.Internal, "could not create service account: %v", err)
+	}
+
+	log.Info().Interface("service_account", newAccount).Msg("created new service account")
+	return &proto.CreateServiceAccountResponse{
+		ServiceAccount: newAccount.ToProto(),
+	}, nil
+}
+
+func (api *API) DeleteServiceAccount(ctx context.Context, request *proto.DeleteServiceAccountRequest) (*proto.DeleteServiceAccountResponse, error) {
+	if request.Id == "" {
+		return &proto.DeleteServiceAccountResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	_, err := api.getManagedServiceAccount(ctx, request.Id)
+	if err != nil {
+		return &proto.DeleteServiceAccountResponse{}, err
+	}
+
+	// The service account is removed first so that its tokens stop working immediately, even if removing one of
+	// them fails below.
+	err = api.storage.DeleteServiceAccount(storage.DeleteServiceAccountRequest{ID: request.Id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.DeleteServiceAccountResponse{}, status.Error(codes.NotFound, "service account not found")
+		}
+		log.Error().Err(err).Msg("could not delete service account")
+		return &proto.DeleteServiceAccountResponse{}, status.Errorf(codes.Internal, "could not delete service account: %v", err)
+	}
+
+	err = api.deleteServiceAccountTokens(request.Id)
+	if err != nil {
+		log.Error().Err(err).Str("service_account", request.Id).Msg("could not delete service account tokens")
+		return &proto.DeleteServiceAccountResponse{}, status.Errorf(codes.Internal,
+			"service account deleted but could not remove its tokens: %v", err)
+	}
+
+	return &proto.DeleteServiceAccountResponse{}, nil
+}
+
+func (api *API) CreateServiceAccountToken(ctx context.Context, request *proto.CreateServiceAccountTokenRequest) (*proto.CreateServiceAccountTokenResponse, error) {
+	if request.Id == "" {
+		return &proto.CreateServiceAccountTokenResponse{}, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	_, err := parseCIDRs(request.AllowedCidrs)
+	if err != nil {
+		return &proto.CreateServiceAccountTokenResponse{}, status.Errorf(codes.FailedPrecondition, "invalid allowed cidrs; %v", err)
+	}
+
+	expires, err := tokenExpiry(request.Ttl)
+	if err != nil {
+		return &proto.CreateServiceAccountTokenResponse{}, status.Errorf(codes.FailedPrecondition, "invalid ttl; %v", err)
+	}
+
+	account, err := api.getManagedServiceAccount(ctx, request.Id)
+	if err != nil {
+		return &proto.CreateServiceAccountTokenResponse{}, err
+	}
+
+	key, token, err := api.createNewAPITokenFrom(models.Token{
+		Kind:           account.Kind,
+		Namespaces:     account.Namespaces,
+		Metadata:       request.Metadata,
+		ServiceAccount: account.ID,
+		AllowedCIDRs:   request.AllowedCidrs,
+		Expires:        expires,
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not create API token")
+		return &proto.CreateServiceAccountTokenResponse{}, status.Errorf(codes.Internal, "could not create token: %v", err)
+	}
+
+	return &proto.CreateServiceAccountTokenResponse{
+		Details: token.ToProto(),
+		Token:   key,
+	}, nil
+}
+
+// getManagedServiceAccount returns the given service account if the caller is able to manage it. The error returned is
+// a grpc status error.
+func (api *API) getManagedServiceAccount(ctx context.Context, id string) (*models.ServiceAccount, error) {
+	account, err := api.storage.GetServiceAccount(storage.GetServiceAccountRequest{ID: id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return nil, status.Error(codes.NotFound, "service account not found")
+		}
+		log.Error().Err(err).Msg("could not get service account")
+		return nil, status.Error(codes.Internal, "failed to retrieve service account from database")
+	}
+
+	if !canDelegate(ctx, account.Kind, account.Namespaces) {
+		return nil, status.Error(codes.PermissionDenied,
+			"management token or namespace admin token for all of the service account's namespaces required for this action")
+	}
+
+	return account, nil
+}
@@ -3,10 +3,15 @@ package api
 import (
 	"context"
 	"errors"
+	"time"
 
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/loglevel"
 	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/clintjedwards/gofer/internal/storage"
 	"github.com/clintjedwards/gofer/proto"
+	grpc_auth "github.com/grpc-ecosystem/go-grpc-middleware/auth"
+	"github.com/rs/zerolog"
 	"github.com/rs/zerolog/log"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
@@ -22,9 +27,48 @@ func (api *API) GetSystemInfo(context context.Context, request *proto.GetSystemI
 		FrontendEnabled:         false,
 		Version:                 version,
 		IgnorePipelineRunEvents: api.ignorePipelineRunEvents.Load(),
+		SchemaVersion:           storage.SchemaVersion,
 	}, nil
 }
 
+func (api *API) GetAuthConfig(ctx context.Context, request *proto.GetAuthConfigRequest) (*proto.GetAuthConfigResponse, error) {
+	if api.oidc == nil {
+		return &proto.GetAuthConfigResponse{}, nil
+	}
+
+	scopes := api.oidc.config.Scopes
+	if len(scopes) == 0 {
+		scopes = config.DefaultOIDCConfig().Scopes
+	}
+
+	return &proto.GetAuthConfigResponse{
+		OidcEnabled:                 true,
+		Issuer:                      api.oidc.config.Issuer,
+		ClientId:                    api.oidc.config.ClientID,
+		Scopes:                      scopes,
+		DeviceAuthorizationEndpoint: api.oidc.discovery.DeviceAuthorizationEndpoint,
+		TokenEndpoint:               api.oidc.discovery.TokenEndpoint,
+	}, nil
+}
+
+func (api *API) GetSystemStats(ctx context.Context, request *proto.GetSystemStatsRequest) (*proto.GetSystemStatsResponse, error) {
+	stats, err := api.systemStats(ctx)
+	if err != nil {
+		log.Error().Err(err).Msg("could not calculate system stats")
+		return &proto.GetSystemStatsResponse{}, status.Errorf(codes.Internal, "could not calculate system stats: %v", err)
+	}
+
+	return stats, nil
+}
+
+func (api *API) GetSlowReport(ctx context.Context, request *proto.GetSlowReportRequest) (*proto.GetSlowReportResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.GetSlowReportResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	return api.slowReport(int(request.Limit)), nil
+}
+
 func (api *API) ToggleEventIngress(ctx context.Context, request *proto.ToggleEventIngressRequest) (*proto.ToggleEventIngressResponse, error) {
 	if !isManagementUser(ctx) {
 		return &proto.ToggleEventIngressResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
@@ -37,6 +81,103 @@ func (api *API) ToggleEventIngress(ctx context.Context, request *proto.ToggleEve
 	}, nil
 }
 
+func (api *API) GetLogLevel(ctx context.Context, request *proto.GetLogLevelRequest) (*proto.GetLogLevelResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.GetLogLevelResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if api.logLevels == nil {
+		return &proto.GetLogLevelResponse{}, status.Error(codes.FailedPrecondition, "log levels can not be changed on this server")
+	}
+
+	level, modules := logLevelsToProto(api.logLevels.Levels())
+
+	return &proto.GetLogLevelResponse{
+		Level:   level,
+		Modules: modules,
+	}, nil
+}
+
+func (api *API) UpdateLogLevel(ctx context.Context, request *proto.UpdateLogLevelRequest) (*proto.UpdateLogLevelResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.UpdateLogLevelResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if api.logLevels == nil {
+		return &proto.UpdateLogLevelResponse{}, status.Error(codes.FailedPrecondition, "log levels can not be changed on this server")
+	}
+
+	level, modules := api.logLevels.Levels()
+
+	if request.Level != "" {
+		parsed, err := loglevel.Parse(request.Level)
+		if err != nil {
+			return &proto.UpdateLogLevelResponse{}, status.Error(codes.FailedPrecondition, err.Error())
+		}
+		level = parsed
+	}
+
+	if request.ResetModules {
+		modules = map[string]zerolog.Level{}
+	}
+
+	for module, moduleLevel := range request.Modules {
+		if module == "" {
+			return &proto.UpdateLogLevelResponse{}, status.Error(codes.FailedPrecondition, "module required")
+		}
+
+		if moduleLevel == "" {
+			delete(modules, module)
+			continue
+		}
+
+		parsed, err := loglevel.Parse(moduleLevel)
+		if err != nil {
+			return &proto.UpdateLogLevelResponse{}, status.Errorf(codes.FailedPrecondition, "module %q: %v", module, err)
+		}
+		modules[module] = parsed
+	}
+
+	api.logLevels.Set(level, modules)
+
+	levelName, moduleNames := logLevelsToProto(api.logLevels.Levels())
+	log.Info().Str("level", levelName).Interface("modules", moduleNames).Msg("updated log level")
+
+	return &proto.UpdateLogLevelResponse{
+		Level:   levelName,
+		Modules: moduleNames,
+	}, nil
+}
+
+func logLevelsToProto(level zerolog.Level, modules map[string]zerolog.Level) (string, map[string]string) {
+	moduleNames := make(map[string]string, len(modules))
+	for module, moduleLevel := range modules {
+		moduleNames[module] = moduleLevel.String()
+	}
+
+	return level.String(), moduleNames
+}
+
+func (api *API) CreateBackup(request *proto.CreateBackupRequest, stream proto.Gofer_CreateBackupServer) error {
+	if !isManagementUser(stream.Context()) {
+		return status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	writer := newBackupStreamWriter(stream)
+
+	err := api.writeBackup(writer, !request.SkipObjects)
+	if err == nil {
+		err = writer.Flush()
+	}
+	if err != nil {
+		log.Error().Err(err).Msg("could not create backup")
+		return status.Errorf(codes.Internal, "could not create backup: %v", err)
+	}
+
+	log.Info().Bool("skip_objects", request.SkipObjects).Msg("created backup")
+	return nil
+}
+
 func (api *API) RepairOrphan(ctx context.Context, request *proto.RepairOrphanRequest) (*proto.RepairOrphanResponse, error) {
 	if !isManagementUser(ctx) {
 		return &proto.RepairOrphanResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
@@ -60,8 +201,27 @@ func (api *API) RepairOrphan(ctx context.Context, request *proto.RepairOrphanReq
 }
 
 func (api *API) CreateToken(ctx context.Context, request *proto.CreateTokenRequest) (*proto.CreateTokenResponse, error) {
-	if !isManagementUser(ctx) {
-		return &proto.CreateTokenResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	_, err := parseCIDRs(request.AllowedCidrs)
+	if err != nil {
+		return &proto.CreateTokenResponse{}, status.Errorf(codes.FailedPrecondition, "invalid allowed cidrs; %v", err)
+	}
+
+	expires, err := tokenExpiry(request.Ttl)
+	if err != nil {
+		return &proto.CreateTokenResponse{}, status.Errorf(codes.FailedPrecondition, "invalid ttl; %v", err)
+	}
+
+	if request.LdapUser != "" {
+		if !isManagementUser(ctx) {
+			return &proto.CreateTokenResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+		}
+
+		return api.createLDAPUserToken(request, expires)
+	}
+
+	if !canDelegate(ctx, models.TokenKind(request.Kind.String()), request.Namespaces) {
+		return &proto.CreateTokenResponse{}, status.Error(codes.PermissionDenied,
+			"management token or namespace admin token for all requested namespaces required for this action")
 	}
 
 	for _, namespace := range request.Namespaces {
@@ -76,7 +236,53 @@ func (api *API) CreateToken(ctx context.Context, request *proto.CreateTokenReque
 		}
 	}
 
-	key, token, err := api.createNewAPIToken(models.TokenKind(request.Kind.String()), request.Namespaces, request.Metadata)
+	key, token, err := api.createNewAPITokenFrom(models.Token{
+		Kind:         models.TokenKind(request.Kind.String()),
+		Namespaces:   request.Namespaces,
+		Metadata:     request.Metadata,
+		AllowedCIDRs: request.AllowedCidrs,
+		Expires:      expires,
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not create API token")
+		return &proto.CreateTokenResponse{}, status.Errorf(codes.Internal, "could not create token: %v", err)
+	}
+
+	return &proto.CreateTokenResponse{
+		Details: token.ToProto(),
+		Token:   key,
+	}, nil
+}
+
+// createLDAPUserToken creates a token whose kind and namespaces are derived from the directory groups of the user.
+func (api *API) createLDAPUserToken(request *proto.CreateTokenRequest, expires int64) (*proto.CreateTokenResponse, error) {
+	if api.ldap == nil {
+		return &proto.CreateTokenResponse{}, status.Error(codes.FailedPrecondition, "ldap is not enabled")
+	}
+
+	kind, namespaces, ok, err := api.ldap.permissions(request.LdapUser)
+	if err != nil {
+		if errors.Is(err, errLDAPUserNotFound) {
+			return &proto.CreateTokenResponse{},
+				status.Errorf(codes.NotFound, "user %q not found in directory", request.LdapUser)
+		}
+		log.Error().Err(err).Str("user", request.LdapUser).Msg("could not look up ldap user")
+		return &proto.CreateTokenResponse{}, status.Errorf(codes.Internal, "could not look up user: %v", err)
+	}
+
+	if !ok {
+		return &proto.CreateTokenResponse{},
+			status.Errorf(codes.FailedPrecondition, "user %q does not belong to any mapped groups", request.LdapUser)
+	}
+
+	key, token, err := api.createNewAPITokenFrom(models.Token{
+		Kind:         kind,
+		Namespaces:   namespaces,
+		Metadata:     request.Metadata,
+		LDAPUser:     request.LdapUser,
+		AllowedCIDRs: request.AllowedCidrs,
+		Expires:      expires,
+	})
 	if err != nil {
 		log.Error().Err(err).Msg("could not create API token")
 		return &proto.CreateTokenResponse{}, status.Errorf(codes.Internal, "could not create token: %v", err)
@@ -110,16 +316,89 @@ func (api *API) GetToken(ctx context.Context, request *proto.GetTokenRequest) (*
 	}, nil
 }
 
-func (api *API) DeleteToken(ctx context.Context, request *proto.DeleteTokenRequest) (*proto.DeleteTokenResponse, error) {
-	if !isManagementUser(ctx) {
-		return &proto.DeleteTokenResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+// ListTokens returns all tokens for management users. Namespace admins only see the tokens they are able to manage.
+func (api *API) ListTokens(ctx context.Context, request *proto.ListTokensRequest) (*proto.ListTokensResponse, error) {
+	tokens, err := api.storage.GetAllTokens(storage.GetAllTokensRequest{
+		Offset: int(request.Offset),
+		Limit:  int(request.Limit),
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not get tokens")
+		return &proto.ListTokensResponse{}, status.Error(codes.Internal, "failed to retrieve tokens from database")
+	}
+
+	protoTokens := []*proto.Token{}
+	for _, token := range tokens {
+		if !canDelegate(ctx, token.Kind, token.Namespaces) {
+			continue
+		}
+
+		if request.UnusedSince != 0 && token.LastUsed >= request.UnusedSince {
+			continue
+		}
+
+		if request.MinRequestsLastDay != 0 && token.RequestsLastDay() < request.MinRequestsLastDay {
+			continue
+		}
+
+		protoTokens = append(protoTokens, token.ToProto())
+	}
+
+	return &proto.ListTokensResponse{
+		Tokens: protoTokens,
+	}, nil
+}
+
+func (api *API) ListExpiringTokens(ctx context.Context, request *proto.ListExpiringTokensRequest) (*proto.ListExpiringTokensResponse, error) {
+	if request.Within <= 0 {
+		return &proto.ListExpiringTokensResponse{}, status.Error(codes.FailedPrecondition, "within must be greater than 0")
 	}
 
+	tokens, err := api.expiringTokens(time.Now().Add(time.Duration(request.Within) * time.Second).UnixMilli())
+	if err != nil {
+		log.Error().Err(err).Msg("could not get tokens")
+		return &proto.ListExpiringTokensResponse{}, status.Error(codes.Internal, "failed to retrieve tokens from database")
+	}
+
+	protoTokens := []*proto.Token{}
+	for _, token := range tokens {
+		if !canDelegate(ctx, token.Kind, token.Namespaces) {
+			continue
+		}
+
+		protoTokens = append(protoTokens, token.ToProto())
+	}
+
+	return &proto.ListExpiringTokensResponse{
+		Tokens: protoTokens,
+	}, nil
+}
+
+func (api *API) DeleteToken(ctx context.Context, request *proto.DeleteTokenRequest) (*proto.DeleteTokenResponse, error) {
 	if request.Token == "" {
 		return &proto.DeleteTokenResponse{}, status.Error(codes.FailedPrecondition, "token required")
 	}
 
 	hash := getHash(request.Token)
+
+	if !isManagementUser(ctx) {
+		token, err := api.storage.GetToken(storage.GetTokenRequest{
+			Hash: hash,
+		})
+		if err != nil {
+			if errors.Is(err, storage.ErrEntityNotFound) {
+				return &proto.DeleteTokenResponse{}, status.Error(codes.FailedPrecondition, "token not found")
+			}
+			log.Error().Err(err).Msg("could not get token")
+			return &proto.DeleteTokenResponse{}, status.Error(codes.Internal, "failed to retrieve token from database")
+		}
+
+		if !canDelegate(ctx, token.Kind, token.Namespaces) {
+			return &proto.DeleteTokenResponse{}, status.Error(codes.PermissionDenied,
+				"management token or namespace admin token for all of the token's namespaces required for this action")
+		}
+	}
+
 	err := api.storage.DeleteToken(storage.DeleteTokenRequest{
 		Hash: hash,
 	})
@@ -131,6 +410,153 @@ func (api *API) DeleteToken(ctx context.Context, request *proto.DeleteTokenReque
 	return &proto.DeleteTokenResponse{}, nil
 }
 
+func (api *API) DeleteUserTokens(ctx context.Context, request *proto.DeleteUserTokensRequest) (*proto.DeleteUserTokensResponse, error) {
+	if !isManagementUser(ctx) {
+		return &proto.DeleteUserTokensResponse{}, status.Error(codes.PermissionDenied, "management token required for this action")
+	}
+
+	if request.User == "" {
+		return &proto.DeleteUserTokensResponse{}, status.Error(codes.FailedPrecondition, "user required")
+	}
+
+	deleted, err := api.deleteUserTokens(request.User)
+	if err != nil {
+		log.Error().Err(err).Str("user", request.User).Int64("deleted", deleted).Msg("could not delete user tokens")
+		return &proto.DeleteUserTokensResponse{}, status.Errorf(codes.Internal,
+			"could not delete user tokens; %d tokens were deleted before the failure: %v", deleted, err)
+	}
+
+	api.events.Publish(models.NewEventRevokedUserTokens(request.User, deleted))
+
+	log.Info().Str("user", request.User).Int64("deleted", deleted).Msg("revoked user tokens")
+
+	return &proto.DeleteUserTokensResponse{Deleted: deleted}, nil
+}
+
+func (api *API) RotateToken(ctx context.Context, request *proto.RotateTokenRequest) (*proto.RotateTokenResponse, error) {
+	if request.Token == "" {
+		return &proto.RotateTokenResponse{}, status.Error(codes.FailedPrecondition, "token required")
+	}
+
+	hash := getHash(request.Token)
+	token, err := api.storage.GetToken(storage.GetTokenRequest{
+		Hash: hash,
+	})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.RotateTokenResponse{}, status.Error(codes.FailedPrecondition, "token not found")
+		}
+		log.Error().Err(err).Msg("could not get token")
+		return &proto.RotateTokenResponse{}, status.Error(codes.Internal, "failed to retrieve token from database")
+	}
+
+	if !canDelegate(ctx, token.Kind, token.Namespaces) {
+		callerToken, _ := grpc_auth.AuthFromMD(ctx, "Bearer")
+		if callerToken != request.Token {
+			return &proto.RotateTokenResponse{}, status.Error(codes.PermissionDenied,
+				"management or namespace admin token required to rotate tokens other than your own")
+		}
+	}
+
+	if request.GracePeriod < 0 {
+		return &proto.RotateTokenResponse{}, status.Error(codes.FailedPrecondition, "grace period cannot be negative")
+	}
+
+	if token.Expired() {
+		return &proto.RotateTokenResponse{}, status.Error(codes.FailedPrecondition, "token expired")
+	}
+
+	if token.ReplacedBy != "" {
+		return &proto.RotateTokenResponse{}, status.Error(codes.FailedPrecondition, "token has already been rotated")
+	}
+
+	// Tokens that expire are replaced with one that lasts just as long. Single sign-on tokens are the exception; their
+	// permissions are only checked when they're exchanged, so they keep their expiry to make sure users log in again.
+	settings := *token
+	if token.OIDCSubject == "" && token.Expires != 0 {
+		settings.Expires = time.Now().Add(token.Lifetime()).UnixMilli()
+	}
+
+	key, newToken, err := api.createNewAPITokenFrom(settings)
+	if err != nil {
+		log.Error().Err(err).Msg("could not create API token")
+		return &proto.RotateTokenResponse{}, status.Errorf(codes.Internal, "could not create token: %v", err)
+	}
+
+	if request.GracePeriod > 0 {
+		graceExpires := time.Now().Add(time.Duration(request.GracePeriod) * time.Second).UnixMilli()
+		if token.Expires == 0 || graceExpires < token.Expires {
+			token.Expires = graceExpires
+		}
+		token.ReplacedBy = newToken.Hash
+
+		err = api.storage.UpdateToken(storage.UpdateTokenRequest{Token: token})
+	} else {
+		err = api.storage.DeleteToken(storage.DeleteTokenRequest{
+			Hash: hash,
+		})
+	}
+	if err != nil {
+		log.Error().Err(err).Msg("could not retire rotated token")
+
+		// Don't leave two valid tokens around; the caller still holds the old one and can try again.
+		_ = api.storage.DeleteToken(storage.DeleteTokenRequest{Hash: newToken.Hash})
+		return &proto.RotateTokenResponse{}, status.Errorf(codes.Internal, "could not retire old token: %v", err)
+	}
+
+	return &proto.RotateTokenResponse{
+		Details: newToken.ToProto(),
+		Token:   key,
+	}, nil
+}
+
+func (api *API) ExchangeOIDCToken(ctx context.Context, request *proto.ExchangeOIDCTokenRequest) (*proto.ExchangeOIDCTokenResponse, error) {
+	if api.oidc == nil {
+		return &proto.ExchangeOIDCTokenResponse{}, status.Error(codes.FailedPrecondition, "single sign-on is not enabled")
+	}
+
+	if request.IdToken == "" {
+		return &proto.ExchangeOIDCTokenResponse{}, status.Error(codes.FailedPrecondition, "id token required")
+	}
+
+	claims, err := api.oidc.verify(request.IdToken)
+	if err != nil {
+		log.Debug().Err(err).Str("issuer", api.oidc.config.Issuer).Msg("could not verify id token for exchange")
+		return &proto.ExchangeOIDCTokenResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	kind, namespaces, ok := api.oidc.permissions(claims)
+	if !ok {
+		log.Debug().Str("subject", claims.Subject).Strs("groups", claims.Groups).
+			Msg("oidc user does not match any group or claim mappings")
+		return &proto.ExchangeOIDCTokenResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	metadata := map[string]string{"description": "single sign-on login"}
+	if claims.Email != "" {
+		metadata["email"] = claims.Email
+	}
+
+	key, token, err := api.createNewAPITokenFrom(models.Token{
+		Kind:        kind,
+		Namespaces:  namespaces,
+		Metadata:    metadata,
+		OIDCSubject: claims.Subject,
+		Expires:     time.Now().Add(api.oidc.config.TokenTTL).UnixMilli(),
+	})
+	if err != nil {
+		log.Error().Err(err).Msg("could not create API token")
+		return &proto.ExchangeOIDCTokenResponse{}, status.Errorf(codes.Internal, "could not create token: %v", err)
+	}
+
+	log.Info().Str("subject", claims.Subject).Str("kind", string(kind)).Msg("exchanged id token for api token")
+
+	return &proto.ExchangeOIDCTokenResponse{
+		Details: token.ToProto(),
+		Token:   key,
+	}, nil
+}
+
 func (api *API) BootstrapToken(ctx context.Context, request *proto.BootstrapTokenRequest) (*proto.BootstrapTokenResponse, error) {
 	tokens, err := api.storage.GetAllTokens(storage.GetAllTokensRequest{
 		Limit: 1,
@@ -0,0 +1,70 @@
+package api
+
+import (
+	"context"
+	"path"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/latency"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"google.golang.org/grpc"
+)
+
+const (
+	// slowReportWindow is how far back the slow report looks.
+	slowReportWindow = 15 * time.Minute
+
+	// slowReportLimit is the default amount of routes and queries included in the slow report.
+	slowReportLimit = 10
+)
+
+// timedStorage wraps the storage engine so that the latency of every query is tracked.
+func timedStorage(engine storage.Engine, queries *latency.Tracker) storage.Engine {
+	return storage.NewTimedEngine(engine, queries.Observe)
+}
+
+// timeUnary tracks the latency of every unary call. Streaming calls are left out since they stay open for as long as
+// the client wants(ex. following logs) and would always be reported as the slowest routes.
+func (api *API) timeUnary(ctx context.Context, req interface{}, info *grpc.UnaryServerInfo, handler grpc.UnaryHandler) (interface{}, error) {
+	started := time.Now()
+	resp, err := handler(ctx, req)
+	api.routeLatencies.Observe(path.Base(info.FullMethod), time.Since(started))
+
+	return resp, err
+}
+
+// slowReport returns the slowest routes and queries within the window.
+func (api *API) slowReport(limit int) *proto.GetSlowReportResponse {
+	if limit <= 0 {
+		limit = slowReportLimit
+	}
+
+	now := time.Now()
+
+	return &proto.GetSlowReportResponse{
+		Window:  slowReportWindow.Milliseconds(),
+		Routes:  latencySummariesToProto(api.routeLatencies.Report(now), limit),
+		Queries: latencySummariesToProto(api.queryLatencies.Report(now), limit),
+	}
+}
+
+func latencySummariesToProto(summaries []latency.Summary, limit int) []*proto.LatencySummary {
+	if len(summaries) > limit {
+		summaries = summaries[:limit]
+	}
+
+	protoSummaries := []*proto.LatencySummary{}
+	for _, summary := range summaries {
+		protoSummaries = append(protoSummaries, &proto.LatencySummary{
+			Name:  summary.Name,
+			Count: summary.Count,
+			P50:   summary.P50.Microseconds(),
+			P95:   summary.P95.Microseconds(),
+			P99:   summary.P99.Microseconds(),
+			Max:   summary.Max.Microseconds(),
+		})
+	}
+
+	return protoSummaries
+}
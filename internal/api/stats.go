@@ -0,0 +1,158 @@
+package api
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+)
+
+// statsWindows are the time windows system stats are reported for, from smallest to largest.
+var statsWindows = []struct {
+	name     string
+	duration time.Duration
+}{
+	{"1h", time.Hour},
+	{"24h", time.Hour * 24},
+}
+
+// systemStats summarizes the runs of every pipeline within the namespaces the caller has access to. Runs are read
+// from newest to oldest so that only runs started within the largest window have to be read.
+func (api *API) systemStats(ctx context.Context) (*proto.GetSystemStatsResponse, error) {
+	now := time.Now()
+	oldest := now.Add(-statsWindows[len(statsWindows)-1].duration).UnixMilli()
+
+	stats := &proto.GetSystemStatsResponse{}
+	durations := make([]int64, len(statsWindows))
+	finished := make([]int64, len(statsWindows))
+
+	for _, window := range statsWindows {
+		stats.Windows = append(stats.Windows, &proto.SystemStatsWindow{
+			Name:     window.name,
+			Duration: window.duration.Milliseconds(),
+		})
+	}
+
+	namespaces, err := api.getAllNamespaces()
+	if err != nil {
+		return nil, fmt.Errorf("could not get namespaces: %w", err)
+	}
+
+	for _, namespace := range namespaces {
+		if !hasAccess(ctx, namespace.ID) {
+			continue
+		}
+
+		pipelines, err := api.getAllPipelines(namespace.ID)
+		if err != nil {
+			return nil, fmt.Errorf("could not get pipelines: %w", err)
+		}
+
+		for _, pipeline := range pipelines {
+			runs, err := api.getRunsSince(namespace.ID, pipeline.ID, oldest)
+			if err != nil {
+				return nil, fmt.Errorf("could not get runs for pipeline %q: %w", pipeline.ID, err)
+			}
+
+			for _, run := range runs {
+				for i, window := range statsWindows {
+					if run.Started < now.Add(-window.duration).UnixMilli() {
+						continue
+					}
+
+					windowStats := stats.Windows[i]
+					windowStats.RunsStarted++
+
+					switch run.State {
+					case models.RunSuccess:
+						windowStats.RunsSucceeded++
+					case models.RunFailed:
+						windowStats.RunsFailed++
+					case models.RunCancelled:
+						windowStats.RunsCancelled++
+					}
+
+					if run.Ended != 0 {
+						durations[i] += run.Ended - run.Started
+						finished[i]++
+					}
+				}
+
+				if run.IsComplete() {
+					continue
+				}
+
+				if run.State == models.RunRunning {
+					stats.RunsRunning++
+				} else {
+					stats.RunsQueued++
+				}
+
+				taskRuns, err := api.storage.GetAllTaskRuns(storage.GetAllTaskRunsRequest{
+					NamespaceID: namespace.ID,
+					PipelineID:  pipeline.ID,
+					RunID:       run.ID,
+				})
+				if err != nil {
+					return nil, fmt.Errorf("could not get task runs for run %d: %w", run.ID, err)
+				}
+
+				for _, taskRun := range taskRuns {
+					switch taskRun.State {
+					case models.ContainerStateProcessing, models.ContainerStateWaiting:
+						stats.TaskRunsQueued++
+					case models.ContainerStateRunning:
+						stats.TaskRunsRunning++
+					}
+				}
+			}
+		}
+	}
+
+	for i, window := range stats.Windows {
+		if finished[i] > 0 {
+			window.AverageRunDuration = durations[i] / finished[i]
+		}
+	}
+
+	stats.Durations = api.durations.distributions(func(namespace string) bool {
+		return hasAccess(ctx, namespace)
+	})
+
+	return stats, nil
+}
+
+// getRunsSince returns all runs of a pipeline that were started at or after the given time in epoch milliseconds.
+func (api *API) getRunsSince(namespace, pipeline string, since int64) ([]*models.Run, error) {
+	runs := []*models.Run{}
+	offset := 0
+
+	for {
+		page, err := api.storage.GetAllRuns(storage.GetAllRunsRequest{
+			NamespaceID: namespace,
+			PipelineID:  pipeline,
+			Offset:      offset,
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		if len(page) == 0 {
+			return runs, nil
+		}
+
+		// Runs are returned newest first so the first run that's too old means all following ones are too.
+		for _, run := range page {
+			if run.Started < since {
+				return runs, nil
+			}
+
+			runs = append(runs, run)
+		}
+
+		offset += len(page)
+	}
+}
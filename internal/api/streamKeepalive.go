@@ -0,0 +1,158 @@
+package api
+
+import (
+	"context"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/proto"
+	"go.uber.org/atomic"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+// defaultStreamKeepalive is used when the server config leaves the keepalive interval unset.
+const defaultStreamKeepalive = 30 * time.Second
+
+// streamKeepalives are the messages sent to keep each long lived stream open while it has nothing else to send, keyed
+// by the stream's full method name. Clients skip any message that has keepalive set.
+var streamKeepalives = map[string]func() interface{}{
+	"/proto.Gofer/ListEvents":      func() interface{} { return &proto.ListEventsResponse{Keepalive: true} },
+	"/proto.Gofer/GetTaskRunLogs":  func() interface{} { return &proto.GetTaskRunLogsResponse{Keepalive: true} },
+	"/proto.Gofer/AttachToTaskRun": func() interface{} { return &proto.AttachToTaskRunOutput{Keepalive: true} },
+}
+
+// keepaliveStream sends keepalive messages on long lived streams that have gone quiet so that proxies and load
+// balancers in front of Gofer don't silently drop them. Streams that stay idle past the configured idle timeout are
+// closed with a status telling the client why.
+func (api *API) keepaliveStream(srv interface{}, stream grpc.ServerStream, info *grpc.StreamServerInfo, handler grpc.StreamHandler) error {
+	newKeepalive, ok := streamKeepalives[info.FullMethod]
+	if !ok {
+		return handler(srv, stream)
+	}
+
+	interval := api.streamKeepaliveInterval()
+	idleTimeout := api.config.Server.StreamIdleTimeout
+	if interval <= 0 && idleTimeout <= 0 {
+		return handler(srv, stream)
+	}
+
+	ctx, cancel := context.WithCancel(stream.Context())
+	defer cancel()
+
+	kept := &keptAliveStream{
+		ServerStream: stream,
+		ctx:          ctx,
+		lastActive:   atomic.NewInt64(time.Now().UnixNano()),
+	}
+
+	idled := atomic.NewBool(false)
+
+	go func() {
+		ticker := time.NewTicker(streamCheckInterval(interval, idleTimeout))
+		defer ticker.Stop()
+
+		lastKeepalive := time.Time{}
+
+		for {
+			select {
+			case <-ctx.Done():
+				return
+			case <-ticker.C:
+				lastActive := kept.lastActivity()
+
+				if idleTimeout > 0 && time.Since(lastActive) >= idleTimeout {
+					idled.Store(true)
+					cancel()
+					return
+				}
+
+				if lastKeepalive.After(lastActive) {
+					lastActive = lastKeepalive
+				}
+
+				if interval <= 0 || time.Since(lastActive) < interval {
+					continue
+				}
+
+				if err := kept.sendKeepalive(newKeepalive()); err != nil {
+					cancel()
+					return
+				}
+				lastKeepalive = time.Now()
+			}
+		}
+	}()
+
+	err := handler(srv, kept)
+	if idled.Load() {
+		return status.Errorf(codes.DeadlineExceeded, "stream closed after being idle for %s", idleTimeout)
+	}
+
+	return err
+}
+
+// streamKeepaliveInterval returns how often quiet streams should send a keepalive; 0 if they shouldn't.
+func (api *API) streamKeepaliveInterval() time.Duration {
+	if api.config.Server.StreamKeepalive == 0 {
+		return defaultStreamKeepalive
+	}
+
+	if api.config.Server.StreamKeepalive < 0 {
+		return 0
+	}
+
+	return api.config.Server.StreamKeepalive
+}
+
+// streamCheckInterval is how often a stream is checked for whether it needs a keepalive or has been idle too long.
+func streamCheckInterval(interval, idleTimeout time.Duration) time.Duration {
+	switch {
+	case interval <= 0:
+		return idleTimeout
+	case idleTimeout <= 0 || interval < idleTimeout:
+		return interval
+	default:
+		return idleTimeout
+	}
+}
+
+// keptAliveStream tracks when a stream last sent or received a message other than a keepalive. Sends are serialized
+// since gRPC doesn't allow a stream to send from more than one goroutine at once.
+type keptAliveStream struct {
+	grpc.ServerStream
+	ctx        context.Context
+	mu         sync.Mutex
+	lastActive *atomic.Int64
+}
+
+func (s *keptAliveStream) Context() context.Context {
+	return s.ctx
+}
+
+func (s *keptAliveStream) SendMsg(m interface{}) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	err := s.ServerStream.SendMsg(m)
+	s.lastActive.Store(time.Now().UnixNano())
+	return err
+}
+
+func (s *keptAliveStream) RecvMsg(m interface{}) error {
+	err := s.ServerStream.RecvMsg(m)
+	s.lastActive.Store(time.Now().UnixNano())
+	return err
+}
+
+func (s *keptAliveStream) sendKeepalive(m interface{}) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	return s.ServerStream.SendMsg(m)
+}
+
+func (s *keptAliveStream) lastActivity() time.Time {
+	return time.Unix(0, s.lastActive.Load())
+}
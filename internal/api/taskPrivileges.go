@@ -0,0 +1,77 @@
+package api
+
+import (
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// taskPrivilegesAllowlist returns the privileges the server's admins allow tasks to ask for.
+func (api *API) taskPrivilegesAllowlist() []config.TaskPrivileges {
+	if api.config.Scheduler == nil {
+		return nil
+	}
+
+	return api.config.Scheduler.TaskPrivileges
+}
+
+// checkTaskPrivileges returns an error if a task asks to run privileged or with capabilities that aren't allowed for
+// its pipeline. A privileged allowance covers every capability.
+func checkTaskPrivileges(namespace, pipeline string, task *models.Task, allowlist []config.TaskPrivileges) error {
+	if !task.Privileged && len(task.CapAdd) == 0 {
+		return nil
+	}
+
+	privileged := false
+	capabilities := map[string]struct{}{}
+
+	for _, allowed := range allowlist {
+		if allowed.Namespace != namespace || !privilegesCoverPipeline(allowed.Pipelines, pipeline) {
+			continue
+		}
+
+		if allowed.Privileged {
+			privileged = true
+		}
+
+		for _, capability := range allowed.Capabilities {
+			capabilities[models.NormalizeCapability(capability)] = struct{}{}
+		}
+	}
+
+	if task.Privileged && !privileged {
+		return fmt.Errorf("task %q is not allowed to run privileged in pipeline %q; %w", task.ID, pipeline,
+			ErrTaskNotAllowed)
+	}
+
+	if privileged {
+		return nil
+	}
+
+	_, all := capabilities["ALL"]
+	for _, capability := range task.CapAdd {
+		if _, exists := capabilities[models.NormalizeCapability(capability)]; exists || all {
+			continue
+		}
+
+		return fmt.Errorf("task %q is not allowed to add capability %q in pipeline %q; %w", task.ID, capability,
+			pipeline, ErrTaskNotAllowed)
+	}
+
+	return nil
+}
+
+func privilegesCoverPipeline(pipelines []string, pipeline string) bool {
+	if len(pipelines) == 0 {
+		return true
+	}
+
+	for _, allowed := range pipelines {
+		if allowed == pipeline {
+			return true
+		}
+	}
+
+	return false
+}
@@ -0,0 +1,68 @@
+package api
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestCheckTaskPrivileges(t *testing.T) {
+	allowlist := []config.TaskPrivileges{
+		{Namespace: "builds", Pipelines: []string{"image_builder"}, Privileged: true},
+		{Namespace: "builds", Capabilities: []string{"NET_ADMIN", "cap_sys_ptrace"}},
+		{Namespace: "network", Capabilities: []string{"ALL"}},
+	}
+
+	tests := map[string]struct {
+		namespace string
+		pipeline  string
+		task      models.Task
+		allowed   bool
+	}{
+		"no privileges asked for": {
+			namespace: "default", pipeline: "simple", task: models.Task{}, allowed: true,
+		},
+		"privileged for allowed pipeline": {
+			namespace: "builds", pipeline: "image_builder", task: models.Task{Privileged: true}, allowed: true,
+		},
+		"privileged for other pipeline": {
+			namespace: "builds", pipeline: "tests", task: models.Task{Privileged: true}, allowed: false,
+		},
+		"privileged covers capabilities": {
+			namespace: "builds", pipeline: "image_builder", task: models.Task{CapAdd: []string{"SYS_ADMIN"}}, allowed: true,
+		},
+		"allowed capability": {
+			namespace: "builds", pipeline: "tests", task: models.Task{CapAdd: []string{"NET_ADMIN"}}, allowed: true,
+		},
+		"capability names are normalized": {
+			namespace: "builds", pipeline: "tests", task: models.Task{CapAdd: []string{"CAP_SYS_PTRACE"}}, allowed: true,
+		},
+		"capability not allowed": {
+			namespace: "builds", pipeline: "tests", task: models.Task{CapAdd: []string{"SYS_ADMIN"}}, allowed: false,
+		},
+		"all capabilities": {
+			namespace: "network", pipeline: "tests", task: models.Task{CapAdd: []string{"SYS_ADMIN"}}, allowed: true,
+		},
+		"all capabilities is not privileged": {
+			namespace: "network", pipeline: "tests", task: models.Task{Privileged: true}, allowed: false,
+		},
+		"other namespace": {
+			namespace: "default", pipeline: "image_builder", task: models.Task{CapAdd: []string{"NET_ADMIN"}}, allowed: false,
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			err := checkTaskPrivileges(tc.namespace, tc.pipeline, &tc.task, allowlist)
+			if tc.allowed && err != nil {
+				t.Fatalf("expected task to be allowed; got %v", err)
+			}
+
+			if !tc.allowed && !errors.Is(err, ErrTaskNotAllowed) {
+				t.Fatalf("expected task to not be allowed; got %v", err)
+			}
+		})
+	}
+}
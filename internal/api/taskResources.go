@@ -0,0 +1,112 @@
+package api
+
+import (
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/scheduler"
+)
+
+// taskResourceLimits returns the server's defaults and maxima for the resources of task runs; nil if there are none.
+func (api *API) taskResourceLimits() *config.TaskResources {
+	if api.config.Scheduler == nil {
+		return nil
+	}
+
+	return api.config.Scheduler.TaskResources
+}
+
+// configTasksIsValid checks that none of the tasks of a pipeline ask for more than the server allows tasks to ask for.
+func (api *API) configTasksIsValid(namespace, pipeline string, tasks []models.Task) error {
+	for _, task := range tasks {
+		err := checkTaskResources(task.ID, task.Resources, api.taskResourceLimits())
+		if err != nil {
+			return err
+		}
+
+		err = checkTaskPrivileges(namespace, pipeline, &task, api.taskPrivilegesAllowlist())
+		if err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// checkTaskResources returns an error if a task asks for more CPU or memory than the server's maxima.
+func checkTaskResources(id string, resources models.TaskResources, limits *config.TaskResources) error {
+	if limits == nil {
+		return nil
+	}
+
+	if limits.MaxCPU > 0 && (resources.CPURequest > limits.MaxCPU || resources.CPULimit > limits.MaxCPU) {
+		return fmt.Errorf("task %q asks for more than the %v CPU cores tasks are allowed; %w", id, limits.MaxCPU,
+			ErrTaskNotAllowed)
+	}
+
+	if limits.MaxMemoryMB > 0 &&
+		(resources.MemoryRequestMB > limits.MaxMemoryMB || resources.MemoryLimitMB > limits.MaxMemoryMB) {
+		return fmt.Errorf("task %q asks for more than the %dMB of memory tasks are allowed; %w", id,
+			limits.MaxMemoryMB, ErrTaskNotAllowed)
+	}
+
+	return nil
+}
+
+// taskRunResources works out the CPU and memory of a task run's container. Whatever the task leaves out is filled in
+// from the server's defaults and everything is held to the server's maxima, even for tasks that were registered
+// before the maxima were lowered.
+func taskRunResources(resources models.TaskResources, limits *config.TaskResources) scheduler.Resources {
+	if limits == nil {
+		limits = &config.TaskResources{}
+	}
+
+	cpuRequest, cpuLimit := resolveResource(resources.CPURequest, resources.CPULimit, limits.DefaultCPURequest,
+		limits.DefaultCPULimit, limits.MaxCPU)
+	memoryRequest, memoryLimit := resolveResource(resources.MemoryRequestMB, resources.MemoryLimitMB,
+		limits.DefaultMemoryRequestMB, limits.DefaultMemoryLimitMB, limits.MaxMemoryMB)
+
+	return scheduler.Resources{
+		CPURequest:    cpuRequest,
+		CPULimit:      cpuLimit,
+		MemoryRequest: memoryRequest * 1024 * 1024,
+		MemoryLimit:   memoryLimit * 1024 * 1024,
+	}
+}
+
+// resolveResource returns the request and limit of a single resource. A zero value means unset everywhere.
+func resolveResource[T int64 | float64](request, limit, defaultRequest, defaultLimit, max T) (T, T) {
+	if request == 0 {
+		request = defaultRequest
+	}
+
+	if limit == 0 {
+		limit = defaultLimit
+	}
+
+	if max > 0 && (limit == 0 || limit > max) {
+		limit = max
+	}
+
+	// A default request can end up above a limit the task set itself.
+	if limit > 0 && request > limit {
+		request = limit
+	}
+
+	return request, limit
+}
+
+// taskRunGPUs returns the GPUs a task run's container asks for; nil if it doesn't need any.
+func taskRunGPUs(gpus *models.TaskGPUs) *scheduler.GPURequest {
+	if gpus == nil {
+		return nil
+	}
+
+	return &scheduler.GPURequest{
+		Count:        int(gpus.Count),
+		DeviceIDs:    gpus.DeviceIDs,
+		Driver:       gpus.Driver,
+		Capabilities: gpus.Capabilities,
+	}
+}
@@ -0,0 +1,92 @@
+package api
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/google/go-cmp/cmp"
+)
+
+func TestTaskRunResources(t *testing.T) {
+	const mb = 1024 * 1024
+
+	tests := map[string]struct {
+		resources models.TaskResources
+		limits    *config.TaskResources
+		expected  scheduler.Resources
+	}{
+		"nothing set": {
+			resources: models.TaskResources{},
+			limits:    nil,
+			expected:  scheduler.Resources{},
+		},
+		"task only": {
+			resources: models.TaskResources{CPURequest: 0.5, CPULimit: 2, MemoryRequestMB: 256, MemoryLimitMB: 512},
+			limits:    &config.TaskResources{},
+			expected:  scheduler.Resources{CPURequest: 0.5, CPULimit: 2, MemoryRequest: 256 * mb, MemoryLimit: 512 * mb},
+		},
+		"defaults fill in": {
+			resources: models.TaskResources{CPULimit: 2},
+			limits:    &config.TaskResources{DefaultCPURequest: 1, DefaultCPULimit: 1, DefaultMemoryLimitMB: 1024},
+			expected:  scheduler.Resources{CPURequest: 1, CPULimit: 2, MemoryLimit: 1024 * mb},
+		},
+		"max limits unlimited tasks": {
+			resources: models.TaskResources{},
+			limits:    &config.TaskResources{MaxCPU: 4, MaxMemoryMB: 8192},
+			expected:  scheduler.Resources{CPULimit: 4, MemoryLimit: 8192 * mb},
+		},
+		"max lowers limits": {
+			resources: models.TaskResources{CPURequest: 6, CPULimit: 8, MemoryLimitMB: 16384},
+			limits:    &config.TaskResources{MaxCPU: 4, MaxMemoryMB: 8192},
+			expected:  scheduler.Resources{CPURequest: 4, CPULimit: 4, MemoryLimit: 8192 * mb},
+		},
+		"default request above task limit": {
+			resources: models.TaskResources{CPULimit: 0.5, MemoryLimitMB: 128},
+			limits:    &config.TaskResources{DefaultCPURequest: 1, DefaultMemoryRequestMB: 256},
+			expected:  scheduler.Resources{CPURequest: 0.5, CPULimit: 0.5, MemoryRequest: 128 * mb, MemoryLimit: 128 * mb},
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			got := taskRunResources(tc.resources, tc.limits)
+
+			diff := cmp.Diff(tc.expected, got)
+			if diff != "" {
+				t.Errorf("result is different than expected(-want +got):\n%s", diff)
+			}
+		})
+	}
+}
+
+func TestCheckTaskResources(t *testing.T) {
+	limits := &config.TaskResources{MaxCPU: 4, MaxMemoryMB: 8192}
+
+	tests := map[string]struct {
+		resources models.TaskResources
+		allowed   bool
+	}{
+		"nothing asked for":  {resources: models.TaskResources{}, allowed: true},
+		"at the maximum":     {resources: models.TaskResources{CPULimit: 4, MemoryLimitMB: 8192}, allowed: true},
+		"cpu over maximum":   {resources: models.TaskResources{CPULimit: 4.5}, allowed: false},
+		"request over max":   {resources: models.TaskResources{CPURequest: 5}, allowed: false},
+		"memory over max":    {resources: models.TaskResources{MemoryLimitMB: 8193}, allowed: false},
+		"memory request max": {resources: models.TaskResources{MemoryRequestMB: 9000}, allowed: false},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			err := checkTaskResources("test", tc.resources, limits)
+			if tc.allowed && err != nil {
+				t.Fatalf("expected task to be allowed; got %v", err)
+			}
+
+			if !tc.allowed && !errors.Is(err, ErrTaskNotAllowed) {
+				t.Fatalf("expected task to not be allowed; got %v", err)
+			}
+		})
+	}
+}
@@ -5,6 +5,7 @@ import (
 
 	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/clintjedwards/gofer/internal/tracing"
 )
 
 // cancelTaskRun calls upon the scheduler to terminate a specific container. The urgency of this request is
@@ -18,7 +19,7 @@ func (api *API) cancelTaskRun(taskRun *models.TaskRun, force bool) error {
 		timeout = time.Millisecond * 500
 	}
 
-	err := api.scheduler.StopContainer(scheduler.StopContainerRequest{
+	err := scheduler.NewTracedEngine(tracing.RunContext(taskRun.CorrelationID), api.scheduler).StopContainer(scheduler.StopContainerRequest{
 		SchedulerID: taskRun.SchedulerID,
 		Timeout:     timeout,
 	})
@@ -1,13 +1,15 @@
 package api
 
 import (
+	"bufio"
 	"context"
 	"errors"
-	"os"
+	"io"
 
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/scheduler"
 	"github.com/clintjedwards/gofer/internal/storage"
 	"github.com/clintjedwards/gofer/proto"
-	"github.com/nxadm/tail"
 	"github.com/rs/zerolog/log"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/status"
@@ -22,6 +24,10 @@ func (api *API) GetTaskRun(ctx context.Context, request *proto.GetTaskRunRequest
 		request.NamespaceId = determineNamespace(ctx)
 	}
 
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.GetTaskRunResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	taskRun, err := api.storage.GetTaskRun(storage.GetTaskRunRequest{
 		NamespaceID: request.NamespaceId,
 		PipelineID:  request.PipelineId,
@@ -48,6 +54,10 @@ func (api *API) ListTaskRuns(ctx context.Context, request *proto.ListTaskRunsReq
 		request.NamespaceId = determineNamespace(ctx)
 	}
 
+	if !api.hasReadAccess(ctx, request.NamespaceId) {
+		return &proto.ListTaskRunsResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	taskRuns, err := api.storage.GetAllTaskRuns(storage.GetAllTaskRunsRequest{
 		NamespaceID: request.NamespaceId,
 		PipelineID:  request.PipelineId,
@@ -111,6 +121,69 @@ func (api *API) CancelTaskRun(ctx context.Context, request *proto.CancelTaskRunR
 	return &proto.CancelTaskRunResponse{}, nil
 }
 
+func (api *API) SpawnTaskRun(ctx context.Context, request *proto.SpawnTaskRunRequest) (*proto.SpawnTaskRunResponse, error) {
+	if request.PipelineId == "" {
+		return &proto.SpawnTaskRunResponse{}, status.Error(codes.FailedPrecondition, "pipeline required")
+	}
+
+	if request.RunId == 0 {
+		return &proto.SpawnTaskRunResponse{}, status.Error(codes.FailedPrecondition, "run required")
+	}
+
+	if request.Task == nil {
+		return &proto.SpawnTaskRunResponse{}, status.Error(codes.FailedPrecondition, "task required")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.SpawnTaskRunResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	if !canSpawnTaskRun(ctx, request.NamespaceId, request.PipelineId, request.RunId) {
+		return &proto.SpawnTaskRunResponse{}, status.Error(codes.PermissionDenied,
+			"access denied; task runs can only be added using the token of the run they are added to")
+	}
+
+	task := models.Task{}
+	task.FromProto(request.Task)
+
+	err := task.Validate()
+	if err != nil {
+		return &proto.SpawnTaskRunResponse{}, status.Errorf(codes.FailedPrecondition, "task is not valid; %v", err)
+	}
+
+	err = checkTaskResources(task.ID, task.Resources, api.taskResourceLimits())
+	if err != nil {
+		return &proto.SpawnTaskRunResponse{}, status.Errorf(codes.FailedPrecondition, "task is not allowed; %v", err)
+	}
+
+	err = checkTaskPrivileges(request.NamespaceId, request.PipelineId, &task, api.taskPrivilegesAllowlist())
+	if err != nil {
+		return &proto.SpawnTaskRunResponse{}, status.Errorf(codes.FailedPrecondition, "task is not allowed; %v", err)
+	}
+
+	inProgress, exists := api.inProgressRuns.Get(inProgressRunKey(request.NamespaceId, request.PipelineId, request.RunId))
+	if !exists {
+		return &proto.SpawnTaskRunResponse{}, status.Error(codes.FailedPrecondition, "run is not in progress")
+	}
+
+	taskRun, err := api.spawnTaskRun(inProgress, task, request.SpawnedBy)
+	if err != nil {
+		if errors.Is(err, ErrTaskRunExists) {
+			return &proto.SpawnTaskRunResponse{}, status.Error(codes.AlreadyExists, err.Error())
+		}
+		return &proto.SpawnTaskRunResponse{}, status.Error(codes.FailedPrecondition, err.Error())
+	}
+
+	log.Info().Str("id", taskRun.ID).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+		Str("spawned_by", taskRun.SpawnedBy).Str("correlation_id", taskRun.CorrelationID).Msg("spawned task run")
+
+	return &proto.SpawnTaskRunResponse{TaskRun: taskRun.ToProto()}, nil
+}
+
 func (api *API) GetTaskRunLogs(request *proto.GetTaskRunLogsRequest, stream proto.Gofer_GetTaskRunLogsServer) error {
 	if request.Id == "" {
 		return status.Error(codes.FailedPrecondition, "id required")
@@ -128,6 +201,10 @@ func (api *API) GetTaskRunLogs(request *proto.GetTaskRunLogsRequest, stream prot
 		request.NamespaceId = determineNamespace(stream.Context())
 	}
 
+	if !api.hasReadAccess(stream.Context(), request.NamespaceId) {
+		return status.Error(codes.PermissionDenied, "access denied")
+	}
+
 	taskRun, err := api.storage.GetTaskRun(storage.GetTaskRunRequest{
 		NamespaceID: request.NamespaceId,
 		PipelineID:  request.PipelineId,
@@ -146,50 +223,103 @@ func (api *API) GetTaskRunLogs(request *proto.GetTaskRunLogsRequest, stream prot
 		return status.Error(codes.FailedPrecondition, "task run logs have expired and are no longer available.")
 	}
 
+	key := api.taskRunLogKey(taskRun)
+	logs := api.logStore
+
+	// Logs of finished task runs can also be removed to keep all logs within the server's budget, in which case they
+	// might still be read from the archive.
+	if !taskRun.LogsRemoved && taskRun.IsComplete() {
+		logs, err = api.taskRunLogStore(taskRun)
+		if err != nil {
+			log.Error().Err(err).
+				Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+				Str("task", taskRun.ID).Msg("error checking for task run logs")
+			return status.Errorf(codes.Internal, "error reading task run log file: %v", err)
+		}
+
+		taskRun.LogsRemoved = logs == nil
+	}
+
 	if taskRun.LogsRemoved {
 		return status.Error(codes.FailedPrecondition, "task run logs have been removed and are no longer available.")
 	}
 
-	file, err := tail.TailFile(api.taskRunLogFilePath(taskRun), tail.Config{Follow: true, Logger: tail.DiscardingLogger})
+	startLine, err := api.taskRunLogStartLine(logs, taskRun, request.Tail, request.Since)
 	if err != nil {
 		log.Error().Err(err).
 			Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
-			Str("task", taskRun.ID).Msg("error opening task run log file")
-		return status.Errorf(codes.Internal, "error opening task run log file: %v", err)
+			Str("task", taskRun.ID).Msg("error reading task run log file")
+		return status.Errorf(codes.Internal, "error reading task run log file: %v", err)
 	}
 
-	for {
+	// The log is closed once the client goes away or the API is shutting down.
+	ctx, cancel := context.WithCancel(stream.Context())
+	defer cancel()
+	go func() {
 		select {
-		case <-stream.Context().Done():
-			_ = file.Stop()
-			return nil
+		case <-ctx.Done():
 		case <-api.context.ctx.Done():
-			_ = file.Stop()
+			cancel()
+		}
+	}()
+
+	// Clients from before following was optional don't set it and expect the log to be followed.
+	follow := request.Follow == nil || *request.Follow
+
+	file, err := logs.Open(ctx, key, follow)
+	if err != nil {
+		log.Error().Err(err).
+			Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("task", taskRun.ID).Msg("error opening task run log file")
+		return status.Errorf(codes.Internal, "error opening task run log file: %v", err)
+	}
+	defer file.Close()
+
+	lineNum := int64(0)
+	scanner := bufio.NewScanner(file)
+	for scanner.Scan() {
+		lineNum++
+
+		// We insert a special EOF delimiter at the end of each file to signify that there are no more logs to be
+		// written. When reading these files from other applications this is an indicator that
+		// we have reached the end of the log file and no more logs will be added.
+		// In this case when streaming the file back to the client we look out for this marker to understand when
+		// to stop the stream.
+		if scanner.Text() == GOFEREOF {
 			return nil
-		case line := <-file.Lines:
-			// We insert a special EOF delimiter at the end of each file to signify that there are no more logs to be
-			// written. When reading these files from other applications this is an indicator that
-			// we have reached the end of the log file and no more logs will be added.
-			// In this case when streaming the file back to the client we look out for this marker to understand when
-			// to stop the stream.
-			if line.Text == GOFEREOF {
-				_ = file.Stop()
-				return nil
-			}
+		}
 
-			// Otherwise stream the file line by line to the client
-			err = stream.Send(&proto.GetTaskRunLogsResponse{
-				LogLine: line.Text,
-				LineNum: int64(line.Num),
-			})
-			if err != nil {
-				log.Error().Err(err).Int("line_number", int(line.Num)).
-					Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
-					Str("task", taskRun.ID).Msg("error sending log stream to client")
-				return status.Errorf(codes.Internal, "error sending log stream: %v", err)
-			}
+		if lineNum < startLine {
+			continue
+		}
+
+		// Otherwise stream the file line by line to the client
+		err = stream.Send(&proto.GetTaskRunLogsResponse{
+			LogLine: scanner.Text(),
+			LineNum: lineNum,
+		})
+		if err != nil {
+			log.Error().Err(err).Int64("line_number", lineNum).
+				Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+				Str("task", taskRun.ID).Msg("error sending log stream to client")
+			return status.Errorf(codes.Internal, "error sending log stream: %v", err)
 		}
 	}
+
+	// Followed logs end early once the client goes away or the API is shutting down.
+	if err := scanner.Err(); err != nil && ctx.Err() == nil {
+		log.Error().Err(err).
+			Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("task", taskRun.ID).Msg("error reading task run log file")
+		return status.Errorf(codes.Internal, "error reading task run log file: %v", err)
+	}
+
+	// Clients following the logs reconnect on Unavailable, which lets them continue through a restart.
+	if api.context.ctx.Err() != nil && stream.Context().Err() == nil {
+		return status.Error(codes.Unavailable, "server is shutting down; reconnect to continue reading logs")
+	}
+
+	return nil
 }
 
 func (api *API) DeleteTaskRunLogs(ctx context.Context, request *proto.DeleteTaskRunLogsRequest) (*proto.DeleteTaskRunLogsResponse, error) {
@@ -232,7 +362,7 @@ func (api *API) DeleteTaskRunLogs(ctx context.Context, request *proto.DeleteTask
 	}
 
 	taskRun.LogsRemoved = true
-	err = os.Remove(api.taskRunLogFilePath(taskRun))
+	err = api.deleteTaskRunLogs(taskRun)
 	if err != nil {
 		return &proto.DeleteTaskRunLogsResponse{}, status.Errorf(codes.Internal, "could not remove task run log file: %v", err)
 	}
@@ -248,3 +378,133 @@ func (api *API) DeleteTaskRunLogs(ctx context.Context, request *proto.DeleteTask
 
 	return &proto.DeleteTaskRunLogsResponse{}, nil
 }
+
+func (api *API) AttachToTaskRun(stream proto.Gofer_AttachToTaskRunServer) error {
+	msg, err := stream.Recv()
+	if err != nil {
+		return status.Errorf(codes.InvalidArgument, "could not read start message: %v", err)
+	}
+
+	request := msg.GetStart()
+	if request == nil {
+		return status.Error(codes.InvalidArgument, "first message must be a start message")
+	}
+
+	if request.Id == "" {
+		return status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	if request.PipelineId == "" {
+		return status.Error(codes.FailedPrecondition, "pipeline required")
+	}
+
+	if request.RunId == 0 {
+		return status.Error(codes.FailedPrecondition, "run required")
+	}
+
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(stream.Context())
+	}
+
+	if !hasAccess(stream.Context(), request.NamespaceId) {
+		return status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	taskRun, err := api.storage.GetTaskRun(storage.GetTaskRunRequest{
+		NamespaceID: request.NamespaceId,
+		PipelineID:  request.PipelineId,
+		RunID:       request.RunId,
+		ID:          request.Id,
+	})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return status.Error(codes.FailedPrecondition, "task run not found")
+		}
+		log.Error().Err(err).Msg("could not get task run")
+		return status.Error(codes.Internal, "failed to retrieve task run from database")
+	}
+
+	if taskRun.State != models.ContainerStateRunning {
+		return status.Errorf(codes.FailedPrecondition, "task run is not running; current state is %s", taskRun.State)
+	}
+
+	command := request.Command
+	if len(command) == 0 {
+		command = []string{"sh"}
+	}
+
+	attachment, err := api.scheduler.AttachContainer(scheduler.AttachContainerRequest{
+		SchedulerID: taskRun.SchedulerID,
+		Command:     command,
+		Width:       uint(request.Width),
+		Height:      uint(request.Height),
+	})
+	if err != nil {
+		if errors.Is(err, scheduler.ErrNoSuchContainer) {
+			return status.Error(codes.FailedPrecondition, "task run container could not be found")
+		}
+		log.Error().Err(err).Str("task_run", taskRun.ID).Msg("could not attach to task run")
+		return status.Errorf(codes.Internal, "could not attach to task run: %v", err)
+	}
+	defer attachment.Conn.Close()
+
+	// Forward terminal input and resizes from the client until it hangs up; closing the connection also ends the
+	// output loop below.
+	clientDone := make(chan struct{})
+	go func() {
+		defer attachment.Conn.Close()
+		defer close(clientDone)
+
+		for {
+			msg, err := stream.Recv()
+			if err != nil {
+				return
+			}
+
+			switch request := msg.Request.(type) {
+			case *proto.AttachToTaskRunRequest_Input:
+				if _, err := attachment.Conn.Write(request.Input.Input); err != nil {
+					return
+				}
+			case *proto.AttachToTaskRunRequest_Resize:
+				err := attachment.Resize(uint(request.Resize.Width), uint(request.Resize.Height))
+				if err != nil {
+					log.Debug().Err(err).Str("task_run", taskRun.ID).Msg("could not resize terminal")
+				}
+			}
+		}
+	}()
+
+	// Closing the connection once the stream ends, for instance because it sat idle for too long, ends both loops.
+	go func() {
+		<-stream.Context().Done()
+		attachment.Conn.Close()
+	}()
+
+	buffer := make([]byte, 4096)
+	for {
+		n, err := attachment.Conn.Read(buffer)
+		if n > 0 {
+			if err := stream.Send(&proto.AttachToTaskRunOutput{Output: buffer[:n]}); err != nil {
+				return err
+			}
+		}
+
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				return nil
+			}
+
+			// The connection was closed because the client hung up or the stream ended.
+			select {
+			case <-clientDone:
+				return nil
+			case <-stream.Context().Done():
+				return nil
+			default:
+			}
+
+			return status.Errorf(codes.Internal, "could not read from task run: %v", err)
+		}
+	}
+}
@@ -0,0 +1,155 @@
+package api
+
+import (
+	"errors"
+	"fmt"
+	"time"
+
+	logstore "github.com/clintjedwards/gofer/internal/logStore"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// bytesPerMB is used to convert the task run log size settings(which are in megabytes) to bytes.
+const bytesPerMB = 1024 * 1024
+
+// taskRunLogLimit returns the most bytes a single task run is allowed to log. 0 means no limit.
+func (api *API) taskRunLogLimit() int64 {
+	if api.config.TaskRunLogs == nil {
+		return 0
+	}
+
+	return api.config.TaskRunLogs.MaxSizeMB * bytesPerMB
+}
+
+// taskRunLogTruncatedMarker is written in place of the logs dropped once a task run reaches its log limit.
+func taskRunLogTruncatedMarker(limitMB int64) string {
+	return fmt.Sprintf("[gofer] Log truncated: task run logs are limited to %d MB; further lines are dropped.\n", limitMB)
+}
+
+// taskRunLogDroppedMarker is written once a truncated task run has finished logging.
+func taskRunLogDroppedMarker(dropped int64) string {
+	return fmt.Sprintf("[gofer] %d lines were dropped.\n", dropped)
+}
+
+// enforceTaskRunLogBudget removes the logs of the oldest finished task runs until the logs of all task runs combined
+// fit within the budget.
+func (api *API) enforceTaskRunLogBudget(store logstore.Budgeted) {
+	budget := api.config.TaskRunLogs.BudgetMB * bytesPerMB
+
+	usage, err := store.EnforceBudget(budget)
+	if err != nil {
+		log.Error().Err(err).Msg("could not keep task run logs within budget")
+		return
+	}
+
+	if usage.Removed > 0 {
+		log.Info().Int("removed", usage.Removed).Int64("freed_bytes", usage.Freed).Int64("usage_bytes", usage.Used).
+			Int64("budget_bytes", budget).Msg("removed oldest task run logs to stay within budget")
+	}
+
+	if usage.Used > budget {
+		log.Warn().Int64("usage_bytes", usage.Used).Int64("budget_bytes", budget).
+			Msg("task run logs are over budget but no more finished logs can be removed")
+	}
+}
+
+// enforceTaskRunLogBudgetOnSchedule keeps task run logs within their budget until the API is shut down.
+func (api *API) enforceTaskRunLogBudgetOnSchedule(store logstore.Budgeted) {
+	ticker := time.NewTicker(api.config.TaskRunLogs.BudgetCheckInterval)
+	defer ticker.Stop()
+
+	for {
+		api.enforceTaskRunLogBudget(store)
+
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-ticker.C:
+		}
+	}
+}
+
+// archiveTaskRunLogs copies the finished logs of a task run to the object store so that they can still be read once
+// they've been removed from the server's disk.
+func (api *API) archiveTaskRunLogs(taskRun *models.TaskRun) {
+	if api.logArchive == nil {
+		return
+	}
+
+	key := api.taskRunLogKey(taskRun)
+
+	err := logstore.Copy(api.logStore, api.logArchive, key)
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("task", taskRun.ID).Msg("could not archive task run logs")
+		return
+	}
+
+	// The task run is still being updated as it finishes, so only the archived flag is set; the update leaves every
+	// field left at its zero value untouched.
+	err = api.storage.UpdateTaskRun(storage.UpdateTaskRunRequest{TaskRun: &models.TaskRun{
+		NamespaceID:  taskRun.NamespaceID,
+		PipelineID:   taskRun.PipelineID,
+		RunID:        taskRun.RunID,
+		ID:           taskRun.ID,
+		LogsArchived: true,
+	}})
+	if err != nil {
+		log.Error().Err(err).Str("pipeline", taskRun.PipelineID).Int64("run", taskRun.RunID).
+			Str("task", taskRun.ID).Msg("could not mark task run logs as archived")
+	}
+}
+
+// taskRunLogStore returns the log store a task run's logs can be read from. The logs of finished task runs that are no
+// longer on the server's disk are read from the archive if they were archived. Returns nil if the logs can't be found
+// in either.
+func (api *API) taskRunLogStore(taskRun *models.TaskRun) (logstore.Engine, error) {
+	key := api.taskRunLogKey(taskRun)
+
+	exists, err := api.logStore.Exists(key)
+	if err != nil {
+		return nil, err
+	}
+
+	if exists {
+		return api.logStore, nil
+	}
+
+	if api.logArchive == nil || !taskRun.LogsArchived {
+		return nil, nil
+	}
+
+	exists, err = api.logArchive.Exists(key)
+	if err != nil {
+		return nil, err
+	}
+
+	if exists {
+		return api.logArchive, nil
+	}
+
+	return nil, nil
+}
+
+// deleteTaskRunLogs removes a task run's logs along with their archived copy.
+func (api *API) deleteTaskRunLogs(taskRun *models.TaskRun) error {
+	key := api.taskRunLogKey(taskRun)
+
+	err := api.logStore.Delete(key)
+	if err != nil && !errors.Is(err, logstore.ErrEntityNotFound) {
+		return err
+	}
+
+	if api.logArchive == nil || !taskRun.LogsArchived {
+		return nil
+	}
+
+	err = api.logArchive.Delete(key)
+	if err != nil && !errors.Is(err, logstore.ErrEntityNotFound) {
+		return err
+	}
+
+	return nil
+}
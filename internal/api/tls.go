@@ -1,11 +1,17 @@
 package api
 
 import (
+	"context"
 	"crypto/tls"
 	"fmt"
 	"io/ioutil"
+	"os"
+	"sync"
+	"time"
 
 	_ "embed"
+
+	"github.com/rs/zerolog/log"
 )
 
 // We use these functions to supply TLS for various services that require it. To make development easy
@@ -18,34 +24,157 @@ var devtlscert []byte
 //go:embed localhost.key
 var devtlskey []byte
 
-// generateTLSConfig returns TLS config object necessary for HTTPS loaded from files. If server is in devmode and
-// no cert is provided it instead loads certificates from embedded files for ease of development.
-func (api *API) generateTLSConfig(certPath, keyPath string) (*tls.Config, error) {
-	var serverCert tls.Certificate
-	var err error
+// defaultCertificateReloadInterval is how often certificate files are checked for changes when the server's TLS
+// settings don't say.
+const defaultCertificateReloadInterval = time.Minute
+
+// certificateReloader serves the certificate Gofer presents to clients. Certificates loaded from files are loaded
+// again when the files change so that rotated certificates(ex. renewed by certbot) are used without a restart.
+type certificateReloader struct {
+	certPath string
+	keyPath  string
+
+	mu          sync.RWMutex
+	certificate *tls.Certificate
+	modified    time.Time // The most recent modification time of the certificate and key files when they were loaded.
+}
 
+// newCertificateReloader returns a certificateReloader for the given files. If the server is in devmode and no cert
+// is provided it instead serves the embedded development certificate, which is never reloaded.
+func (api *API) newCertificateReloader(certPath, keyPath string) (*certificateReloader, error) {
 	if api.config.Server.DevMode && certPath == "" {
-		serverCert, err = tls.X509KeyPair(devtlscert, devtlskey)
+		serverCert, err := tls.X509KeyPair(devtlscert, devtlskey)
 		if err != nil {
 			return nil, err
 		}
-	} else {
-		if certPath == "" || keyPath == "" {
-			return nil, fmt.Errorf("TLS cert and key cannot be empty")
-		}
 
-		serverCert, err = tls.LoadX509KeyPair(certPath, keyPath)
+		return &certificateReloader{certificate: &serverCert}, nil
+	}
+
+	if certPath == "" || keyPath == "" {
+		return nil, fmt.Errorf("TLS cert and key cannot be empty")
+	}
+
+	reloader := &certificateReloader{
+		certPath: certPath,
+		keyPath:  keyPath,
+	}
+
+	_, err := reloader.reload()
+	if err != nil {
+		return nil, err
+	}
+
+	return reloader, nil
+}
+
+// lastModified returns the most recent modification time of the certificate and key files.
+func (r *certificateReloader) lastModified() (time.Time, error) {
+	var latest time.Time
+
+	for _, path := range []string{r.certPath, r.keyPath} {
+		info, err := os.Stat(path)
 		if err != nil {
-			return nil, err
+			return time.Time{}, err
+		}
+
+		if info.ModTime().After(latest) {
+			latest = info.ModTime()
+		}
+	}
+
+	return latest, nil
+}
+
+// reload loads the certificate again if its files changed since they were last loaded. It returns whether a new
+// certificate was loaded. The current certificate keeps being served if the new one can't be loaded.
+func (r *certificateReloader) reload() (bool, error) {
+	modified, err := r.lastModified()
+	if err != nil {
+		return false, err
+	}
+
+	r.mu.RLock()
+	unchanged := r.certificate != nil && !modified.After(r.modified)
+	r.mu.RUnlock()
+
+	if unchanged {
+		return false, nil
+	}
+
+	serverCert, err := tls.LoadX509KeyPair(r.certPath, r.keyPath)
+	if err != nil {
+		return false, err
+	}
+
+	r.mu.Lock()
+	r.certificate = &serverCert
+	r.modified = modified
+	r.mu.Unlock()
+
+	return true, nil
+}
+
+// reloadOnSchedule checks the certificate files for changes every interval until the context is cancelled.
+func (r *certificateReloader) reloadOnSchedule(ctx context.Context, interval time.Duration) {
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+			reloaded, err := r.reload()
+			if err != nil {
+				log.Error().Err(err).Str("cert", r.certPath).Msg("could not reload TLS certificate; still using the previous one")
+				continue
+			}
+
+			if reloaded {
+				log.Info().Str("cert", r.certPath).Msg("reloaded TLS certificate")
+			}
 		}
 	}
+}
 
+// GetCertificate implements tls.Config.GetCertificate.
+func (r *certificateReloader) GetCertificate(*tls.ClientHelloInfo) (*tls.Certificate, error) {
+	r.mu.RLock()
+	defer r.mu.RUnlock()
+
+	return r.certificate, nil
+}
+
+// generateTLSConfig returns TLS config object necessary for HTTPS. The certificate is served by the API's certificate
+// reloader and the versions and cipher suites accepted are those of the server's TLS settings.
+func (api *API) generateTLSConfig() *tls.Config {
 	tlsConfig := &tls.Config{
-		Certificates: []tls.Certificate{serverCert},
-		ClientAuth:   tls.NoClientCert,
+		GetCertificate: api.certificates.GetCertificate,
+		ClientAuth:     tls.NoClientCert,
+		MinVersion:     tls.VersionTLS12,
+	}
+
+	if api.config.Server.TLS != nil {
+		tlsConfig.MinVersion = api.config.Server.TLS.Version()
+		tlsConfig.CipherSuites = api.config.Server.TLS.CipherSuiteIDs()
+	}
+
+	return tlsConfig
+}
+
+// certificateReloadInterval returns how often the server's certificate files should be checked for changes; 0 if they
+// shouldn't be.
+func (api *API) certificateReloadInterval() time.Duration {
+	if api.config.Server.TLS == nil || api.config.Server.TLS.ReloadInterval == 0 {
+		return defaultCertificateReloadInterval
+	}
+
+	if api.config.Server.TLS.ReloadInterval < 0 {
+		return 0
 	}
 
-	return tlsConfig, nil
+	return api.config.Server.TLS.ReloadInterval
 }
 
 // getTLSFiles returns certificates suppled from file paths. If server is in devmode and no cert is provided
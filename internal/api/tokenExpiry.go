@@ -0,0 +1,98 @@
+package api
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// tokenExpiryCheckInterval is how often expired tokens are removed. Expired tokens stop working right away; they're
+// only removed so they don't pile up.
+const tokenExpiryCheckInterval = time.Hour
+
+// tokenExpiry returns when a token created with the given TTL in seconds should expire in epoch milliseconds; 0 if it
+// shouldn't.
+func tokenExpiry(ttl int64) (int64, error) {
+	if ttl < 0 {
+		return 0, fmt.Errorf("ttl cannot be negative")
+	}
+
+	if ttl == 0 {
+		return 0, nil
+	}
+
+	return time.Now().Add(time.Duration(ttl) * time.Second).UnixMilli(), nil
+}
+
+// expiringTokens returns every token that expires before the given time in epoch milliseconds, ordered by when they
+// expire.
+func (api *API) expiringTokens(before int64) ([]*models.Token, error) {
+	expiring := []*models.Token{}
+
+	offset := 0
+	for {
+		tokens, err := api.storage.GetAllTokens(storage.GetAllTokensRequest{
+			Offset: offset,
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		if len(tokens) == 0 {
+			break
+		}
+		offset += len(tokens)
+
+		for _, token := range tokens {
+			if token.Expires != 0 && token.Expires < before {
+				expiring = append(expiring, token)
+			}
+		}
+	}
+
+	sort.Slice(expiring, func(i, j int) bool {
+		return expiring[i].Expires < expiring[j].Expires
+	})
+
+	return expiring, nil
+}
+
+// removeExpiredTokensOnSchedule removes tokens past their expiry until the API is shut down.
+func (api *API) removeExpiredTokensOnSchedule() {
+	ticker := time.NewTicker(tokenExpiryCheckInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-ticker.C:
+			api.removeExpiredTokens()
+		}
+	}
+}
+
+// removeExpiredTokens deletes every token whose expiry has passed.
+func (api *API) removeExpiredTokens() {
+	expired, err := api.expiringTokens(time.Now().UnixMilli() + 1)
+	if err != nil {
+		log.Error().Err(err).Msg("could not get tokens to remove expired tokens")
+		return
+	}
+
+	for _, token := range expired {
+		err := api.storage.DeleteToken(storage.DeleteTokenRequest{Hash: token.Hash})
+		if err != nil && !errors.Is(err, storage.ErrEntityNotFound) {
+			log.Error().Err(err).Msg("could not remove expired token")
+		}
+	}
+
+	if len(expired) > 0 {
+		log.Debug().Int("removed_tokens", len(expired)).Msg("expired tokens removed")
+	}
+}
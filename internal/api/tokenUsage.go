@@ -0,0 +1,97 @@
+package api
+
+import (
+	"errors"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+// tokenUsageFlushInterval is how often the usage of tokens collected in memory is written to storage.
+const tokenUsageFlushInterval = time.Minute
+
+// tokenUsage is the usage of a single token that hasn't been written to storage yet.
+type tokenUsage struct {
+	lastUsed       int64
+	address        string
+	hourlyRequests map[int64]int64
+}
+
+// tokenUsageTracker collects the usage of tokens in memory so that storage isn't written to on every request.
+type tokenUsageTracker struct {
+	mu      sync.Mutex
+	pending map[string]*tokenUsage // Usage by token hash.
+}
+
+func newTokenUsageTracker() *tokenUsageTracker {
+	return &tokenUsageTracker{
+		pending: map[string]*tokenUsage{},
+	}
+}
+
+// record counts a request made with the token with the given hash from the given address.
+func (t *tokenUsageTracker) record(hash, address string) {
+	now := time.Now()
+
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	usage, exists := t.pending[hash]
+	if !exists {
+		usage = &tokenUsage{hourlyRequests: map[int64]int64{}}
+		t.pending[hash] = usage
+	}
+
+	usage.lastUsed = now.UnixMilli()
+	usage.address = address
+	usage.hourlyRequests[now.Truncate(time.Hour).UnixMilli()]++
+}
+
+// drain returns all collected usage and resets the tracker.
+func (t *tokenUsageTracker) drain() map[string]*tokenUsage {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	pending := t.pending
+	t.pending = map[string]*tokenUsage{}
+
+	return pending
+}
+
+// flushTokenUsage writes the usage collected since the last flush to storage.
+func (api *API) flushTokenUsage() {
+	for hash, usage := range api.tokenUsage.drain() {
+		token, err := api.storage.GetToken(storage.GetTokenRequest{Hash: hash})
+		if err != nil {
+			// The token might have been deleted since it was used.
+			if !errors.Is(err, storage.ErrEntityNotFound) {
+				log.Error().Err(err).Msg("could not get token to record usage")
+			}
+			continue
+		}
+
+		token.RecordUsage(usage.lastUsed, usage.address, usage.hourlyRequests)
+
+		err = api.storage.UpdateToken(storage.UpdateTokenRequest{Token: token})
+		if err != nil && !errors.Is(err, storage.ErrEntityNotFound) {
+			log.Error().Err(err).Msg("could not record token usage")
+		}
+	}
+}
+
+// flushTokenUsageOnSchedule writes token usage to storage until the API is shut down.
+func (api *API) flushTokenUsageOnSchedule() {
+	ticker := time.NewTicker(tokenUsageFlushInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-ticker.C:
+			api.flushTokenUsage()
+		}
+	}
+}
@@ -0,0 +1,106 @@
+package api
+
+import (
+	"context"
+	"errors"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/tracing"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+// traceUnary records every unary call as a span. Callers that send a W3C "traceparent" header have the span added
+// to their own trace.
+func (api *API) traceUnary(ctx context.Context, req interface{}, info *grpc.UnaryServerInfo, handler grpc.UnaryHandler) (interface{}, error) {
+	if !tracing.Enabled() {
+		return handler(ctx, req)
+	}
+
+	ctx, span := startCallSpan(ctx, info.FullMethod)
+	defer span.End()
+
+	resp, err := handler(ctx, req)
+	endCallSpan(span, err)
+
+	return resp, err
+}
+
+// traceStream records every streaming call as a span.
+func (api *API) traceStream(srv interface{}, stream grpc.ServerStream, info *grpc.StreamServerInfo, handler grpc.StreamHandler) error {
+	if !tracing.Enabled() {
+		return handler(srv, stream)
+	}
+
+	ctx, span := startCallSpan(stream.Context(), info.FullMethod)
+	defer span.End()
+
+	err := handler(srv, &tracedStream{ServerStream: stream, ctx: ctx})
+	endCallSpan(span, err)
+
+	return err
+}
+
+// tracedStream replaces the stream's context with one that holds the call's span.
+type tracedStream struct {
+	grpc.ServerStream
+	ctx context.Context
+}
+
+func (s *tracedStream) Context() context.Context {
+	return s.ctx
+}
+
+func startCallSpan(ctx context.Context, method string) (context.Context, *tracing.Span) {
+	md, _ := metadata.FromIncomingContext(ctx)
+	if traceparent := md.Get("traceparent"); len(traceparent) > 0 {
+		if sc, ok := tracing.ParseTraceparent(traceparent[0]); ok {
+			ctx = tracing.ContextWithSpanContext(ctx, sc)
+		}
+	}
+
+	return tracing.StartWithKind(ctx, method, tracing.KindServer,
+		tracing.String("rpc.system", "grpc"),
+		tracing.String("rpc.method", method),
+	)
+}
+
+func endCallSpan(span *tracing.Span, err error) {
+	span.SetAttributes(tracing.Int64("rpc.grpc.status_code", int64(status.Code(err))))
+	span.RecordError(err)
+}
+
+// startTaskRunSpan starts the span covering a task run. It's a child of the span covering the task run's run.
+func startTaskRunSpan(taskRun *models.TaskRun) (context.Context, *tracing.Span) {
+	return tracing.Start(tracing.RunContext(taskRun.CorrelationID), "task_run",
+		tracing.String("gofer.namespace", taskRun.NamespaceID),
+		tracing.String("gofer.pipeline", taskRun.PipelineID),
+		tracing.Int64("gofer.run", taskRun.RunID),
+		tracing.String("gofer.task_run", taskRun.ID),
+		tracing.String("gofer.image", taskRun.Image),
+	)
+}
+
+// endTaskRunSpan ends the span covering a task run with the state the task run finished in.
+func endTaskRunSpan(span *tracing.Span, taskRun *models.TaskRun) {
+	span.SetAttributes(
+		tracing.String("gofer.task_run.state", string(taskRun.State)),
+		tracing.Int64("gofer.task_run.exit_code", int64(taskRun.ExitCode)),
+		tracing.Int64("gofer.task_run.attempt", taskRun.Attempt),
+	)
+
+	if taskRun.Failure.Description != "" {
+		span.RecordError(errors.New(taskRun.Failure.Description))
+	}
+
+	span.End()
+}
+
+// linkRunSpan links the span within the context to the span covering the given run. Runs are traced separately
+// from the calls that start them, so the link is the way to get from one to the other.
+func linkRunSpan(ctx context.Context, run *models.Run) {
+	span := tracing.SpanFromContext(ctx)
+	span.SetAttributes(tracing.String("gofer.correlation_id", run.CorrelationID))
+	span.AddLink(tracing.RunSpanContext(run.CorrelationID))
+}
@@ -13,6 +13,7 @@ import (
 	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/clintjedwards/gofer/internal/scheduler"
 	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/sdk"
 	sdkProto "github.com/clintjedwards/gofer/sdk/proto"
 	"github.com/rs/zerolog/log"
 	"google.golang.org/grpc/codes"
@@ -23,6 +24,31 @@ import (
 // The containerID format dictates what the container name will be of the trigger.
 const TRIGGERCONTAINERIDFORMAT = "trigger_%s" // trigger_<triggerKind>
 
+// MinTriggerAPIVersion is the oldest version of the trigger API this version of Gofer is able to talk to. The newest is
+// the version implemented by the SDK Gofer is built with.
+const MinTriggerAPIVersion = 1
+
+// CheckTriggerAPIVersion returns an error if this version of Gofer isn't able to talk to a trigger that implements the
+// given version of the trigger API.
+func CheckTriggerAPIVersion(version int64) error {
+	// Triggers built before versions were reported implement the first version.
+	if version == 0 {
+		version = 1
+	}
+
+	if version < MinTriggerAPIVersion {
+		return fmt.Errorf("trigger implements API version %d but Gofer requires at least version %d; "+
+			"use a version of the trigger built with a newer SDK", version, MinTriggerAPIVersion)
+	}
+
+	if version > sdk.APIVersion {
+		return fmt.Errorf("trigger implements API version %d but Gofer only supports up to version %d; "+
+			"upgrade Gofer or use an older version of the trigger", version, sdk.APIVersion)
+	}
+
+	return nil
+}
+
 func (api *API) installTriggersFromConfig() error {
 	for _, trigger := range api.config.Triggers.RegisteredTriggers {
 		_, exists := api.triggers.Get(trigger.Kind)
@@ -38,6 +64,59 @@ func (api *API) installTriggersFromConfig() error {
 	return nil
 }
 
+// reconcileTriggerContainers compares the trigger containers the scheduler still knows about against the triggers that
+// are installed. Containers left behind by triggers that have since been uninstalled are removed; triggers that are
+// installed but no longer have a running container are reported and then started along with every other trigger.
+func (api *API) reconcileTriggerContainers() error {
+	installedTriggers, err := api.storage.GetAllTriggers(storage.GetAllTriggersRequest{})
+	if err != nil {
+		return err
+	}
+
+	installed := map[string]struct{}{}
+	for _, trigger := range installedTriggers {
+		installed[fmt.Sprintf(TRIGGERCONTAINERIDFORMAT, trigger.Kind)] = struct{}{}
+	}
+	for _, trigger := range api.config.Triggers.RegisteredTriggers {
+		installed[fmt.Sprintf(TRIGGERCONTAINERIDFORMAT, trigger.Kind)] = struct{}{}
+	}
+
+	resp, err := api.scheduler.ListContainers(scheduler.ListContainersRequest{
+		Prefix: fmt.Sprintf(TRIGGERCONTAINERIDFORMAT, ""),
+	})
+	if err != nil {
+		return fmt.Errorf("could not list trigger containers: %w", err)
+	}
+
+	running := map[string]struct{}{}
+	for _, container := range resp.Containers {
+		if _, exists := installed[container.ID]; exists {
+			if container.Running {
+				running[container.ID] = struct{}{}
+			}
+			continue
+		}
+
+		err := api.scheduler.RemoveContainer(scheduler.RemoveContainerRequest{
+			SchedulerID: container.SchedulerID,
+		})
+		if err != nil && !errors.Is(err, scheduler.ErrNoSuchContainer) {
+			log.Error().Err(err).Str("container", container.ID).Msg("could not remove container of uninstalled trigger")
+			continue
+		}
+
+		log.Info().Str("container", container.ID).Msg("removed container of uninstalled trigger")
+	}
+
+	for id := range installed {
+		if _, exists := running[id]; !exists {
+			log.Warn().Str("container", id).Msg("container for installed trigger is missing or stopped; it will be restarted")
+		}
+	}
+
+	return nil
+}
+
 // startTriggers attempts to start each installed trigger. It is run on startup when we're attempting to reestablish
 // all needed triggers.
 func (api *API) startTriggers() error {
@@ -83,21 +162,23 @@ func (api *API) startTrigger(trigger *config.Trigger, triggerKey string) error {
 	// We first include the Gofer defined environment variables and then the operator configured environment
 	// variables.
 	envVars := mergeMaps(map[string]string{
-		"GOFER_TRIGGER_TLS_CERT":  string(cert),
-		"GOFER_TRIGGER_TLS_KEY":   string(key),
-		"GOFER_TRIGGER_KIND":      trigger.Kind,
-		"GOFER_TRIGGER_LOG_LEVEL": api.config.LogLevel,
-		"GOFER_TRIGGER_KEY":       triggerKey,
+		"GOFER_TRIGGER_TLS_CERT":   string(cert),
+		"GOFER_TRIGGER_TLS_KEY":    string(key),
+		"GOFER_TRIGGER_KIND":       trigger.Kind,
+		"GOFER_TRIGGER_LOG_LEVEL":  api.config.LogLevel,
+		"GOFER_TRIGGER_KEY":        triggerKey,
+		"GOFER_TRIGGER_GOFER_HOST": api.config.Host,
 	}, configVars)
 
 	log.Info().Str("name", trigger.Kind).Msg("starting trigger")
 	sc := scheduler.StartContainerRequest{
 		ID:               fmt.Sprintf(TRIGGERCONTAINERIDFORMAT, trigger.Kind),
-		ImageName:        trigger.Image,
+		ImageName:        api.schedulerImage("", trigger.Image),
 		EnvVars:          envVars,
 		RegistryUser:     trigger.User,
 		RegistryPass:     trigger.Pass,
 		EnableNetworking: true,
+		ImageMaxAge:      api.imageMaxAge(),
 	}
 
 	resp, err := api.scheduler.StartContainer(sc)
@@ -160,8 +241,14 @@ func (api *API) startTrigger(trigger *config.Trigger, triggerKey string) error {
 		Started:       time.Now().UnixMilli(),
 		State:         models.ContainerStateRunning,
 		Documentation: info.Documentation,
+		APIVersion:    info.ApiVersion,
 	})
 
+	err = CheckTriggerAPIVersion(info.ApiVersion)
+	if err != nil {
+		log.Warn().Err(err).Str("kind", trigger.Kind).Msg("trigger may not work correctly with this version of Gofer")
+	}
+
 	log.Info().
 		Str("kind", trigger.Kind).
 		Str("id", resp.SchedulerID).
@@ -282,6 +369,14 @@ func (api *API) restoreTriggerSubscriptions() error {
 				log.Error().Err(err).Str("trigger_label", subscription.Label).Str("trigger_kind", subscription.Kind).
 					Str("pipeline", pipeline.ID).Str("namespace", pipeline.Namespace).
 					Msg("could not restore subscription; error contacting trigger")
+				api.addDeadLetter(&models.DeadLetter{
+					Kind:        models.DeadLetterKindTriggerSubscription,
+					NamespaceID: pipeline.Namespace,
+					PipelineID:  pipeline.ID,
+					Target:      subscription.Label,
+					Payload:     triggerSubscriptionPayload(subscription),
+					Attempts:    1,
+				}, err)
 				continue
 			}
 
@@ -410,8 +505,13 @@ func (api *API) processTriggerEvents() error {
 					Msg("could not process trigger event; could not find trigger label within pipeline")
 			}
 
+			initiator := ""
+			if pipeline.ServiceAccount != "" {
+				initiator = models.ServiceAccountInitiator(pipeline.ServiceAccount)
+			}
+
 			_, err = api.createNewRun(pipeline.Namespace, pipeline.ID, triggerSubscription.Kind,
-				event.Label, map[string]struct{}{}, event.TriggerMetadata)
+				event.Label, initiator, map[string]struct{}{}, event.TriggerMetadata, 0, nil)
 			if err != nil {
 				if errors.Is(err, ErrPipelineNotActive) {
 					log.Debug().Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
@@ -419,6 +519,18 @@ func (api *API) processTriggerEvents() error {
 					continue
 				}
 
+				if errors.Is(err, ErrConcurrencyGroupBusy) {
+					log.Debug().Err(err).Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+						Str("trigger", triggerSubscription.Kind).Msg("pipeline trigger run skipped because its concurrency group is busy")
+					continue
+				}
+
+				if errors.Is(err, ErrQuotaExceeded) {
+					log.Warn().Err(err).Str("namespace", pipeline.Namespace).Str("pipeline", pipeline.ID).
+						Str("trigger", triggerSubscription.Kind).Msg("pipeline trigger run skipped because of namespace quota")
+					continue
+				}
+
 				log.Error().Err(err).Msg("could not create run from trigger event")
 				continue
 			}
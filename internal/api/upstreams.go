@@ -0,0 +1,109 @@
+package api
+
+import (
+	"errors"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/rs/zerolog/log"
+)
+
+// maxUpstreamDepth is how long a chain of runs started by upstream pipelines can get. It stops pipelines that run
+// after each other from starting runs forever.
+const maxUpstreamDepth = 10
+
+// startDownstreamRuns starts a run of every pipeline in the namespace that runs after the pipeline of the given
+// finished run.
+func (api *API) startDownstreamRuns(upstreamRun models.Run) {
+	if api.ignorePipelineRunEvents.Load() || api.draining.Load() {
+		return
+	}
+
+	pipelines, err := api.getAllPipelines(upstreamRun.NamespaceID)
+	if err != nil {
+		log.Error().Err(err).Str("namespace", upstreamRun.NamespaceID).
+			Msg("could not get pipelines to start downstream runs")
+		return
+	}
+
+	chain := upstreamChain(upstreamRun)
+	vars := upstreamRunVars(upstreamRun, chain)
+
+	for _, pipeline := range pipelines {
+		upstream, exists := pipeline.Upstreams[upstreamRun.PipelineID]
+		if !exists || !upstream.Wants(&upstreamRun) {
+			continue
+		}
+
+		if chain.Depth() > maxUpstreamDepth {
+			log.Warn().Str("namespace", upstreamRun.NamespaceID).Str("pipeline", pipeline.ID).
+				Str("upstream_pipeline", upstreamRun.PipelineID).Int64("upstream_run", upstreamRun.ID).
+				Int("max_depth", maxUpstreamDepth).Msg("downstream run skipped because the chain of pipelines is too long")
+			continue
+		}
+
+		if chain.Includes(pipeline.ID) {
+			log.Warn().Str("namespace", upstreamRun.NamespaceID).Str("pipeline", pipeline.ID).
+				Str("upstream_pipeline", upstreamRun.PipelineID).Int64("upstream_run", upstreamRun.ID).
+				Str("chain_correlation_id", chain.CorrelationID).
+				Msg("downstream run skipped because the pipeline already ran earlier in the chain")
+			continue
+		}
+
+		initiator := ""
+		if pipeline.ServiceAccount != "" {
+			initiator = models.ServiceAccountInitiator(pipeline.ServiceAccount)
+		}
+
+		run, err := api.createNewRun(upstreamRun.NamespaceID, pipeline.ID, "pipeline", upstreamRun.PipelineID,
+			initiator, map[string]struct{}{}, vars, 0, chain)
+		if err != nil {
+			logger := log.Error()
+			if errors.Is(err, ErrPipelineNotActive) || errors.Is(err, ErrPipelineRunsInProgress) ||
+				errors.Is(err, ErrConcurrencyGroupBusy) || errors.Is(err, ErrQuotaExceeded) || errors.Is(err, ErrShuttingDown) {
+				logger = log.Warn()
+			}
+
+			logger.Err(err).Str("namespace", upstreamRun.NamespaceID).Str("pipeline", pipeline.ID).
+				Str("upstream_pipeline", upstreamRun.PipelineID).Int64("upstream_run", upstreamRun.ID).
+				Msg("could not start downstream run")
+			continue
+		}
+
+		log.Info().Str("namespace", upstreamRun.NamespaceID).Str("pipeline", pipeline.ID).Int64("run", run.ID).
+			Str("upstream_pipeline", upstreamRun.PipelineID).Int64("upstream_run", upstreamRun.ID).
+			Msg("started downstream run")
+	}
+}
+
+// upstreamChain returns the chain of upstream runs that downstream runs of the given run are part of. The chain is
+// kept on the run itself instead of in its variables so that it can't be changed by whoever starts the first run.
+func upstreamChain(upstreamRun models.Run) *models.RunUpstream {
+	if upstreamRun.Upstream == nil {
+		return &models.RunUpstream{
+			CorrelationID: upstreamRun.CorrelationID,
+			Pipelines:     []string{upstreamRun.PipelineID},
+		}
+	}
+
+	pipelines := make([]string, 0, len(upstreamRun.Upstream.Pipelines)+1)
+	pipelines = append(pipelines, upstreamRun.Upstream.Pipelines...)
+	pipelines = append(pipelines, upstreamRun.PipelineID)
+
+	return &models.RunUpstream{
+		CorrelationID: upstreamRun.Upstream.CorrelationID,
+		Pipelines:     pipelines,
+	}
+}
+
+// upstreamRunVars returns the variables a downstream run is started with to describe the upstream run that started it.
+func upstreamRunVars(upstreamRun models.Run, chain *models.RunUpstream) map[string]string {
+	return map[string]string{
+		"GOFER_UPSTREAM_PIPELINE":       upstreamRun.PipelineID,
+		"GOFER_UPSTREAM_RUN":            strconv.FormatInt(upstreamRun.ID, 10),
+		"GOFER_UPSTREAM_STATE":          string(upstreamRun.State),
+		"GOFER_UPSTREAM_TRIGGER":        upstreamRun.TriggerName,
+		"GOFER_UPSTREAM_CORRELATION_ID": upstreamRun.CorrelationID,
+		"GOFER_UPSTREAM_DEPTH":          strconv.Itoa(chain.Depth()),
+	}
+}
@@ -0,0 +1,115 @@
+package api
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/google/go-cmp/cmp"
+)
+
+func TestUpstreamRunVars(t *testing.T) {
+	tests := map[string]struct {
+		run  models.Run
+		vars map[string]string
+	}{
+		"started by a trigger": {
+			run: models.Run{
+				ID:            4,
+				PipelineID:    "build",
+				State:         models.RunSuccess,
+				TriggerName:   "every_push",
+				CorrelationID: "abc",
+				Variables:     map[string]string{"BRANCH": "main"},
+			},
+			vars: map[string]string{
+				"GOFER_UPSTREAM_PIPELINE":       "build",
+				"GOFER_UPSTREAM_RUN":            "4",
+				"GOFER_UPSTREAM_STATE":          "SUCCESS",
+				"GOFER_UPSTREAM_TRIGGER":        "every_push",
+				"GOFER_UPSTREAM_CORRELATION_ID": "abc",
+				"GOFER_UPSTREAM_DEPTH":          "1",
+			},
+		},
+		"started by another upstream": {
+			run: models.Run{
+				ID:            9,
+				PipelineID:    "test",
+				State:         models.RunFailed,
+				TriggerName:   "build",
+				CorrelationID: "def",
+				Upstream:      &models.RunUpstream{CorrelationID: "abc", Pipelines: []string{"lint", "build", "package"}},
+			},
+			vars: map[string]string{
+				"GOFER_UPSTREAM_PIPELINE":       "test",
+				"GOFER_UPSTREAM_RUN":            "9",
+				"GOFER_UPSTREAM_STATE":          "FAILED",
+				"GOFER_UPSTREAM_TRIGGER":        "build",
+				"GOFER_UPSTREAM_CORRELATION_ID": "def",
+				"GOFER_UPSTREAM_DEPTH":          "4",
+			},
+		},
+		"depth set by the user is ignored": {
+			run: models.Run{
+				ID:            2,
+				PipelineID:    "build",
+				State:         models.RunSuccess,
+				TriggerName:   "via_api",
+				CorrelationID: "ghi",
+				Variables:     map[string]string{"GOFER_UPSTREAM_DEPTH": "-100"},
+			},
+			vars: map[string]string{
+				"GOFER_UPSTREAM_PIPELINE":       "build",
+				"GOFER_UPSTREAM_RUN":            "2",
+				"GOFER_UPSTREAM_STATE":          "SUCCESS",
+				"GOFER_UPSTREAM_TRIGGER":        "via_api",
+				"GOFER_UPSTREAM_CORRELATION_ID": "ghi",
+				"GOFER_UPSTREAM_DEPTH":          "1",
+			},
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			vars := upstreamRunVars(tc.run, upstreamChain(tc.run))
+			if diff := cmp.Diff(tc.vars, vars); diff != "" {
+				t.Errorf("unexpected vars (-want +got):\n%s", diff)
+			}
+		})
+	}
+}
+
+func TestUpstreamChain(t *testing.T) {
+	// A fans out to B and C, both of which have A as a downstream pipeline.
+	first := models.Run{PipelineID: "a", CorrelationID: "abc"}
+
+	fanOut := upstreamChain(first)
+	if diff := cmp.Diff(&models.RunUpstream{CorrelationID: "abc", Pipelines: []string{"a"}}, fanOut); diff != "" {
+		t.Errorf("unexpected chain (-want +got):\n%s", diff)
+	}
+
+	for _, pipeline := range []string{"b", "c"} {
+		downstream := models.Run{PipelineID: pipeline, CorrelationID: "def", Upstream: fanOut}
+
+		chain := upstreamChain(downstream)
+		if chain.CorrelationID != "abc" {
+			t.Errorf("expected chain to keep correlation ID of its first run; got %q", chain.CorrelationID)
+		}
+
+		if chain.Depth() != 2 {
+			t.Errorf("expected depth 2; got %d", chain.Depth())
+		}
+
+		if !chain.Includes("a") {
+			t.Errorf("expected pipeline %q to be found earlier in the chain after %q", "a", pipeline)
+		}
+
+		if chain.Includes("d") {
+			t.Errorf("expected pipeline %q to not be part of the chain", "d")
+		}
+	}
+
+	// Chains of sibling runs are kept apart.
+	if fanOut.Includes("b") {
+		t.Errorf("expected chain of the first run to be left unchanged")
+	}
+}
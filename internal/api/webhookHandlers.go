@@ -0,0 +1,247 @@
+package api
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/rs/zerolog/log"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+func (api *API) ListWebhooks(ctx context.Context, request *proto.ListWebhooksRequest) (*proto.ListWebhooksResponse, error) {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.ListWebhooksResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	webhooks, err := api.storage.GetAllWebhooks(storage.GetAllWebhooksRequest{NamespaceID: request.NamespaceId})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Msg("could not get webhooks")
+		return &proto.ListWebhooksResponse{}, status.Error(codes.Internal, "failed to retrieve webhooks from database")
+	}
+
+	protoWebhooks := []*proto.Webhook{}
+	for _, webhook := range webhooks {
+		protoWebhooks = append(protoWebhooks, webhook.ToProto())
+	}
+
+	return &proto.ListWebhooksResponse{
+		Webhooks: protoWebhooks,
+	}, nil
+}
+
+func (api *API) GetWebhook(ctx context.Context, request *proto.GetWebhookRequest) (*proto.GetWebhookResponse, error) {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.GetWebhookResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	webhook, err := api.getWebhook(request.NamespaceId, request.Id)
+	if err != nil {
+		return &proto.GetWebhookResponse{}, err
+	}
+
+	return &proto.GetWebhookResponse{
+		Webhook: webhook.ToProto(),
+	}, nil
+}
+
+func (api *API) CreateWebhook(ctx context.Context, request *proto.CreateWebhookRequest) (*proto.CreateWebhookResponse, error) {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.CreateWebhookResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	parsedURL, err := url.Parse(request.Url)
+	if err != nil || parsedURL.Host == "" || (parsedURL.Scheme != "http" && parsedURL.Scheme != "https") {
+		return &proto.CreateWebhookResponse{}, status.Errorf(codes.FailedPrecondition,
+			"url %q is not valid; must be an http or https address", request.Url)
+	}
+
+	// Names are checked once they're resolved on delivery; addresses can be refused straight away.
+	ip := net.ParseIP(parsedURL.Hostname())
+	if ip != nil && !webhookDestinationAllowed(ip, parseNetworks(api.config.WebhookAllowedNetworks)) {
+		return &proto.CreateWebhookResponse{}, status.Errorf(codes.FailedPrecondition,
+			"url %q is not allowed; webhooks can't be sent to private, loopback or link-local addresses", request.Url)
+	}
+
+	events, err := parseWebhookEvents(request.Events)
+	if err != nil {
+		return &proto.CreateWebhookResponse{}, status.Error(codes.FailedPrecondition, err.Error())
+	}
+
+	if request.PipelineId != "" {
+		_, err := api.storage.GetPipeline(storage.GetPipelineRequest{
+			NamespaceID: request.NamespaceId,
+			ID:          request.PipelineId,
+		})
+		if err != nil {
+			if errors.Is(err, storage.ErrEntityNotFound) {
+				return &proto.CreateWebhookResponse{}, status.Errorf(codes.NotFound, "pipeline %q not found",
+					request.PipelineId)
+			}
+			log.Error().Err(err).Msg("could not get pipeline from storage")
+			return &proto.CreateWebhookResponse{}, status.Errorf(codes.Internal, "could not get pipeline %q",
+				request.PipelineId)
+		}
+	}
+
+	secret := request.Secret
+	if secret == "" {
+		secret = generateToken(32)
+	}
+
+	webhook := &models.Webhook{
+		NamespaceID: request.NamespaceId,
+		PipelineID:  request.PipelineId,
+		URL:         request.Url,
+		Secret:      secret,
+		Events:      events,
+		Created:     time.Now().UnixMilli(),
+	}
+
+	err = api.storage.AddWebhook(storage.AddWebhookRequest{Webhook: webhook})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", request.NamespaceId).Msg("could not save webhook")
+		return &proto.CreateWebhookResponse{}, status.Error(codes.Internal, "could not save webhook")
+	}
+
+	log.Info().Int64("id", webhook.ID).Str("namespace", webhook.NamespaceID).Str("pipeline", webhook.PipelineID).
+		Str("url", webhook.URL).Msg("created webhook")
+
+	return &proto.CreateWebhookResponse{
+		Webhook: webhook.ToProto(),
+		Secret:  secret,
+	}, nil
+}
+
+func (api *API) DeleteWebhook(ctx context.Context, request *proto.DeleteWebhookRequest) (*proto.DeleteWebhookResponse, error) {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.DeleteWebhookResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	_, err := api.getWebhook(request.NamespaceId, request.Id)
+	if err != nil {
+		return &proto.DeleteWebhookResponse{}, err
+	}
+
+	err = api.storage.DeleteWebhook(storage.DeleteWebhookRequest{ID: request.Id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return &proto.DeleteWebhookResponse{}, status.Error(codes.NotFound, "webhook not found")
+		}
+		log.Error().Err(err).Int64("id", request.Id).Msg("could not delete webhook")
+		return &proto.DeleteWebhookResponse{}, status.Error(codes.Internal, "could not delete webhook")
+	}
+
+	log.Info().Int64("id", request.Id).Str("namespace", request.NamespaceId).Msg("deleted webhook")
+
+	return &proto.DeleteWebhookResponse{}, nil
+}
+
+func (api *API) ListWebhookDeliveries(ctx context.Context, request *proto.ListWebhookDeliveriesRequest) (*proto.ListWebhookDeliveriesResponse, error) {
+	if request.NamespaceId == "" {
+		request.NamespaceId = determineNamespace(ctx)
+	}
+
+	if !hasAccess(ctx, request.NamespaceId) {
+		return &proto.ListWebhookDeliveriesResponse{}, status.Error(codes.PermissionDenied, "access denied")
+	}
+
+	_, err := api.getWebhook(request.NamespaceId, request.WebhookId)
+	if err != nil {
+		return &proto.ListWebhookDeliveriesResponse{}, err
+	}
+
+	deliveries, err := api.storage.GetAllWebhookDeliveries(storage.GetAllWebhookDeliveriesRequest{
+		WebhookID: request.WebhookId,
+		Offset:    int(request.Offset),
+		Limit:     int(request.Limit),
+	})
+	if err != nil {
+		log.Error().Err(err).Int64("webhook", request.WebhookId).Msg("could not get webhook deliveries")
+		return &proto.ListWebhookDeliveriesResponse{}, status.Error(codes.Internal,
+			"failed to retrieve webhook deliveries from database")
+	}
+
+	protoDeliveries := []*proto.WebhookDelivery{}
+	for _, delivery := range deliveries {
+		protoDeliveries = append(protoDeliveries, delivery.ToProto())
+	}
+
+	return &proto.ListWebhookDeliveriesResponse{
+		Deliveries: protoDeliveries,
+	}, nil
+}
+
+// getWebhook returns the webhook with the given id as long as it belongs to the given namespace. Errors returned are
+// GRPC status errors.
+func (api *API) getWebhook(namespace string, id int64) (*models.Webhook, error) {
+	if id == 0 {
+		return nil, status.Error(codes.FailedPrecondition, "id required")
+	}
+
+	webhook, err := api.storage.GetWebhook(storage.GetWebhookRequest{ID: id})
+	if err != nil {
+		if errors.Is(err, storage.ErrEntityNotFound) {
+			return nil, status.Error(codes.NotFound, "webhook not found")
+		}
+		log.Error().Err(err).Int64("id", id).Msg("could not get webhook")
+		return nil, status.Error(codes.Internal, "failed to retrieve webhook from database")
+	}
+
+	// Webhooks of other namespaces are reported as missing so their existence isn't leaked.
+	if webhook.NamespaceID != namespace {
+		return nil, status.Error(codes.NotFound, "webhook not found")
+	}
+
+	return webhook, nil
+}
+
+// parseWebhookEvents checks that the events are ones webhooks can be registered for.
+func parseWebhookEvents(rawEvents []string) ([]models.EventType, error) {
+	events := []models.EventType{}
+
+	for _, raw := range rawEvents {
+		event := models.EventType(strings.ToUpper(raw))
+
+		supported := false
+		for _, webhookEvent := range models.WebhookEvents {
+			if event == webhookEvent {
+				supported = true
+				break
+			}
+		}
+
+		if !supported {
+			return nil, fmt.Errorf("event %q is not supported; must be one of %s or %s", raw,
+				models.StartedRunEvent, models.CompletedRunEvent)
+		}
+
+		events = append(events, event)
+	}
+
+	return events, nil
+}
@@ -0,0 +1,280 @@
+package api
+
+import (
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"net/http"
+	"strconv"
+	"syscall"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/eventsink"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/rs/zerolog/log"
+)
+
+const (
+	// webhookAttempts is how many times a delivery is sent to a webhook before it's marked as failed.
+	webhookAttempts = 5
+
+	// webhookRetryInterval is how long to wait before the first retry. It doubles after every attempt.
+	webhookRetryInterval = 5 * time.Second
+
+	// webhookTimeout is how long a webhook has to respond to a delivery.
+	webhookTimeout = 10 * time.Second
+
+	// webhookDeliveryHistory is how many of the most recent deliveries are kept for each webhook.
+	webhookDeliveryHistory = 100
+)
+
+// webhookPayload is the body posted to webhooks.
+type webhookPayload struct {
+	Event       models.EventType `json:"event"`
+	WebhookID   int64            `json:"webhook_id"`
+	DeliveryID  int64            `json:"delivery_id"`
+	NamespaceID string           `json:"namespace_id"`
+	PipelineID  string           `json:"pipeline_id"`
+	RunID       int64            `json:"run_id"`
+	State       models.RunState  `json:"state,omitempty"` // Only set for completed runs.
+	Emitted     int64            `json:"emitted"`
+}
+
+// startWebhooks delivers run lifecycle events to the webhooks registered for them.
+func (api *API) startWebhooks() error {
+	subscription, err := api.events.Subscribe(models.AnyEvent)
+	if err != nil {
+		return err
+	}
+
+	client := newWebhookClient(parseNetworks(api.config.WebhookAllowedNetworks))
+
+	go func() {
+		defer api.events.Unsubscribe(subscription)
+
+		for {
+			select {
+			case <-api.context.ctx.Done():
+				return
+			case event := <-subscription.Events:
+				switch evt := event.(type) {
+				case *models.EventStartedRun:
+					api.dispatchWebhooks(client, webhookPayload{
+						Event:       evt.Kind,
+						NamespaceID: evt.NamespaceID,
+						PipelineID:  evt.PipelineID,
+						RunID:       evt.RunID,
+						Emitted:     evt.Emitted,
+					})
+				case *models.EventCompletedRun:
+					api.dispatchWebhooks(client, webhookPayload{
+						Event:       evt.Kind,
+						NamespaceID: evt.NamespaceID,
+						PipelineID:  evt.PipelineID,
+						RunID:       evt.RunID,
+						State:       evt.State,
+						Emitted:     evt.Emitted,
+					})
+				}
+			}
+		}
+	}()
+
+	return nil
+}
+
+// dispatchWebhooks records a delivery for every webhook that wants the event and sends them.
+func (api *API) dispatchWebhooks(client *http.Client, payload webhookPayload) {
+	webhooks, err := api.storage.GetAllWebhooks(storage.GetAllWebhooksRequest{NamespaceID: payload.NamespaceID})
+	if err != nil {
+		log.Error().Err(err).Str("namespace", payload.NamespaceID).Msg("could not get webhooks")
+		return
+	}
+
+	for _, webhook := range webhooks {
+		if !webhook.Wants(payload.PipelineID, payload.Event) {
+			continue
+		}
+
+		now := time.Now().UnixMilli()
+		delivery := &models.WebhookDelivery{
+			WebhookID:   webhook.ID,
+			NamespaceID: payload.NamespaceID,
+			PipelineID:  payload.PipelineID,
+			RunID:       payload.RunID,
+			Event:       payload.Event,
+			State:       models.WebhookDeliveryStatePending,
+			Created:     now,
+			Updated:     now,
+		}
+
+		// The delivery is saved first so that its ID can be part of the payload.
+		err := api.storage.AddWebhookDelivery(storage.AddWebhookDeliveryRequest{WebhookDelivery: delivery})
+		if err != nil {
+			log.Error().Err(err).Int64("webhook", webhook.ID).Msg("could not save webhook delivery; delivery dropped")
+			continue
+		}
+
+		payload.WebhookID = webhook.ID
+		payload.DeliveryID = delivery.ID
+
+		body, err := json.Marshal(payload)
+		if err != nil {
+			log.Error().Err(err).Msg("could not encode webhook payload")
+			continue
+		}
+		delivery.Payload = string(body)
+
+		go api.sendWebhook(client, *webhook, delivery)
+	}
+}
+
+// sendWebhook posts a delivery to a webhook, retrying if it fails, and records the outcome in the delivery history.
+func (api *API) sendWebhook(client *http.Client, webhook models.Webhook, delivery *models.WebhookDelivery) {
+	headers := map[string]string{
+		"X-Gofer-Event":     string(delivery.Event),
+		"X-Gofer-Delivery":  strconv.FormatInt(delivery.ID, 10),
+		"X-Gofer-Signature": signWebhookPayload(webhook.Secret, []byte(delivery.Payload)),
+	}
+
+	retryInterval := webhookRetryInterval
+
+	for {
+		_, err := eventsink.Post(client, webhook.URL, "application/json", headers, []byte(delivery.Payload))
+
+		delivery.Attempts++
+		delivery.Updated = time.Now().UnixMilli()
+
+		switch {
+		case err == nil:
+			delivery.State = models.WebhookDeliveryStateSucceeded
+			delivery.Error = ""
+		case delivery.Attempts >= webhookAttempts:
+			delivery.State = models.WebhookDeliveryStateFailed
+			delivery.Error = webhookDeliveryError(err)
+		default:
+			delivery.Error = webhookDeliveryError(err)
+		}
+
+		updateErr := api.storage.UpdateWebhookDelivery(storage.UpdateWebhookDeliveryRequest{WebhookDelivery: delivery})
+		if updateErr != nil {
+			log.Error().Err(updateErr).Int64("webhook", webhook.ID).Int64("delivery", delivery.ID).
+				Msg("could not update webhook delivery")
+		}
+
+		if delivery.State != models.WebhookDeliveryStatePending {
+			break
+		}
+
+		select {
+		case <-api.context.ctx.Done():
+			return
+		case <-time.After(retryInterval):
+		}
+
+		retryInterval *= 2
+	}
+
+	if delivery.State == models.WebhookDeliveryStateFailed {
+		log.Warn().Str("error", delivery.Error).Int64("webhook", webhook.ID).Int64("delivery", delivery.ID).
+			Str("url", webhook.URL).Msg("webhook delivery failed")
+	}
+
+	_, err := api.storage.DeleteWebhookDeliveries(storage.DeleteWebhookDeliveriesRequest{
+		WebhookID: webhook.ID,
+		Keep:      webhookDeliveryHistory,
+	})
+	if err != nil {
+		log.Error().Err(err).Int64("webhook", webhook.ID).Msg("could not prune webhook delivery history")
+	}
+}
+
+// webhookDeliveryError returns the error recorded for a failed delivery. Delivery history is readable by anyone in the
+// namespace so the body of the response is never part of it.
+func webhookDeliveryError(err error) string {
+	var statusErr *eventsink.StatusError
+	if errors.As(err, &statusErr) {
+		return fmt.Sprintf("received status %d", statusErr.StatusCode)
+	}
+
+	return err.Error()
+}
+
+// newWebhookClient returns the client deliveries are sent with. Webhook URLs are chosen by namespace users, so the
+// client refuses to connect to addresses within the server's own network unless they're part of the allowed
+// networks. The address is checked after the host is resolved so that names pointing at internal addresses are caught
+// too. Redirects are never followed since they could lead anywhere.
+func newWebhookClient(allowed []*net.IPNet) *http.Client {
+	dialer := &net.Dialer{
+		Timeout: webhookTimeout,
+		Control: func(_, address string, _ syscall.RawConn) error {
+			host, _, err := net.SplitHostPort(address)
+			if err != nil {
+				return err
+			}
+
+			ip := net.ParseIP(host)
+			if ip == nil || !webhookDestinationAllowed(ip, allowed) {
+				return fmt.Errorf("delivery to %s is not allowed", host)
+			}
+
+			return nil
+		},
+	}
+
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	transport.DialContext = dialer.DialContext
+
+	// A proxy would be dialed instead of the destination, which would skip the check above.
+	transport.Proxy = nil
+
+	return &http.Client{
+		Timeout:   webhookTimeout,
+		Transport: transport,
+		CheckRedirect: func(*http.Request, []*http.Request) error {
+			return http.ErrUseLastResponse
+		},
+	}
+}
+
+// webhookDestinationAllowed reports whether deliveries may be sent to the address. Private, loopback, link-local
+// (which includes cloud metadata services), multicast and unspecified addresses are refused unless they're part of
+// the allowed networks.
+func webhookDestinationAllowed(ip net.IP, allowed []*net.IPNet) bool {
+	for _, network := range allowed {
+		if network.Contains(ip) {
+			return true
+		}
+	}
+
+	return !(ip.IsPrivate() || ip.IsLoopback() || ip.IsLinkLocalUnicast() || ip.IsLinkLocalMulticast() ||
+		ip.IsInterfaceLocalMulticast() || ip.IsMulticast() || ip.IsUnspecified())
+}
+
+// parseNetworks parses CIDR ranges that have already been validated by the config.
+func parseNetworks(cidrs []string) []*net.IPNet {
+	networks := []*net.IPNet{}
+	for _, cidr := range cidrs {
+		_, network, err := net.ParseCIDR(cidr)
+		if err != nil {
+			continue
+		}
+		networks = append(networks, network)
+	}
+
+	return networks
+}
+
+// signWebhookPayload returns the value of the signature header for a payload: the hex encoded HMAC-SHA256 of the
+// payload keyed with the webhook's secret.
+func signWebhookPayload(secret string, payload []byte) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	mac.Write(payload)
+
+	return "sha256=" + hex.EncodeToString(mac.Sum(nil))
+}
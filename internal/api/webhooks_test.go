@@ -0,0 +1,137 @@
+package api
+
+import (
+	"errors"
+	"net"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/eventsink"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+func TestSignWebhookPayload(t *testing.T) {
+	got := signWebhookPayload("It's a Secret to Everybody", []byte("Hello, World!"))
+	want := "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+
+	if got != want {
+		t.Errorf("expected signature %q; got %q", want, got)
+	}
+}
+
+func TestWebhookWants(t *testing.T) {
+	tests := map[string]struct {
+		webhook  models.Webhook
+		pipeline string
+		event    models.EventType
+		want     bool
+	}{
+		"whole namespace and all events": {
+			webhook:  models.Webhook{},
+			pipeline: "simple",
+			event:    models.CompletedRunEvent,
+			want:     true,
+		},
+		"other pipeline": {
+			webhook:  models.Webhook{PipelineID: "deploy"},
+			pipeline: "simple",
+			event:    models.CompletedRunEvent,
+			want:     false,
+		},
+		"unwanted event": {
+			webhook:  models.Webhook{PipelineID: "simple", Events: []models.EventType{models.CompletedRunEvent}},
+			pipeline: "simple",
+			event:    models.StartedRunEvent,
+			want:     false,
+		},
+		"wanted event": {
+			webhook:  models.Webhook{PipelineID: "simple", Events: []models.EventType{models.CompletedRunEvent}},
+			pipeline: "simple",
+			event:    models.CompletedRunEvent,
+			want:     true,
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			if got := tc.webhook.Wants(tc.pipeline, tc.event); got != tc.want {
+				t.Errorf("expected %t; got %t", tc.want, got)
+			}
+		})
+	}
+}
+
+func TestWebhookDestinationAllowed(t *testing.T) {
+	allowed := parseNetworks([]string{"10.20.0.0/16"})
+
+	tests := map[string]struct {
+		ip   string
+		want bool
+	}{
+		"public":              {ip: "93.184.216.34", want: true},
+		"loopback":            {ip: "127.0.0.1", want: false},
+		"ipv6 loopback":       {ip: "::1", want: false},
+		"private":             {ip: "192.168.1.10", want: false},
+		"metadata service":    {ip: "169.254.169.254", want: false},
+		"ipv4 mapped private": {ip: "::ffff:10.0.0.1", want: false},
+		"unspecified":         {ip: "0.0.0.0", want: false},
+		"allowed network":     {ip: "10.20.3.4", want: true},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			if got := webhookDestinationAllowed(net.ParseIP(tc.ip), allowed); got != tc.want {
+				t.Errorf("expected %t; got %t", tc.want, got)
+			}
+		})
+	}
+}
+
+func TestWebhookClientRefusesInternalAddresses(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer server.Close()
+
+	_, err := eventsink.Post(newWebhookClient(nil), server.URL, "application/json", nil, []byte("{}"))
+	if err == nil {
+		t.Fatal("expected delivery to a loopback address to be refused")
+	}
+}
+
+func TestWebhookClientDoesNotFollowRedirects(t *testing.T) {
+	redirected := false
+
+	mux := http.NewServeMux()
+	mux.HandleFunc("/hook", func(w http.ResponseWriter, r *http.Request) {
+		http.Redirect(w, r, "/internal", http.StatusFound)
+	})
+	mux.HandleFunc("/internal", func(w http.ResponseWriter, r *http.Request) {
+		redirected = true
+	})
+
+	server := httptest.NewServer(mux)
+	defer server.Close()
+
+	client := newWebhookClient(parseNetworks([]string{"127.0.0.0/8"}))
+
+	_, err := eventsink.Post(client, server.URL+"/hook", "application/json", nil, []byte("{}"))
+
+	var statusErr *eventsink.StatusError
+	if !errors.As(err, &statusErr) || statusErr.StatusCode != http.StatusFound {
+		t.Fatalf("expected status error for redirect; got %v", err)
+	}
+
+	if redirected {
+		t.Error("expected redirect not to be followed")
+	}
+}
+
+func TestWebhookDeliveryError(t *testing.T) {
+	err := &eventsink.StatusError{StatusCode: http.StatusForbidden, Body: []byte("internal secret")}
+
+	if got := webhookDeliveryError(err); got != "received status 403" {
+		t.Errorf("expected only the status code to be recorded; got %q", got)
+	}
+}
@@ -7,10 +7,16 @@ import (
 
 	"github.com/clintjedwards/gofer/internal/api"
 	"github.com/clintjedwards/gofer/internal/config"
+	logstore "github.com/clintjedwards/gofer/internal/logStore"
+	filelogs "github.com/clintjedwards/gofer/internal/logStore/file"
+	objectlogs "github.com/clintjedwards/gofer/internal/logStore/object"
+	"github.com/clintjedwards/gofer/internal/loglevel"
 	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
 	boltos "github.com/clintjedwards/gofer/internal/objectStore/bolt"
 	"github.com/clintjedwards/gofer/internal/scheduler"
 	"github.com/clintjedwards/gofer/internal/scheduler/docker"
+	"github.com/clintjedwards/gofer/internal/scheduler/local"
+	"github.com/clintjedwards/gofer/internal/scheduler/podman"
 	"github.com/clintjedwards/gofer/internal/secretStore"
 	boltsecret "github.com/clintjedwards/gofer/internal/secretStore/bolt"
 	"github.com/clintjedwards/gofer/internal/storage"
@@ -19,7 +25,7 @@ import (
 )
 
 // StartServices initializes all required services.
-func StartServices(config *config.API) {
+func StartServices(config *config.API, logLevels *loglevel.Filter) {
 	if config.Server.DevMode {
 		log.Warn().Msg("server in development mode; not for use in production")
 	}
@@ -52,7 +58,14 @@ func StartServices(config *config.API) {
 
 	log.Info().Str("engine", config.SecretStore.Engine).Msg("secret store engine initialized")
 
-	newAPI, err := api.NewAPI(config, newStorage, newScheduler, newObjectStore, newSecretStore)
+	newLogStore, err := initLogStore(config, newObjectStore)
+	if err != nil {
+		log.Fatal().Err(err).Msg("could not init logStore")
+	}
+
+	log.Info().Str("engine", config.TaskRunLogs.Engine).Msg("log store engine initialized")
+
+	newAPI, err := api.NewAPI(config, newStorage, newScheduler, newObjectStore, newSecretStore, newLogStore, logLevels)
 	if err != nil {
 		log.Fatal().Err(err).Msg("could not init api")
 	}
@@ -78,6 +91,22 @@ func initStorage(config *config.Database) (storage.Engine, error) {
 	}
 }
 
+// OpenStorage opens the configured database for commands that work on it directly while the server is stopped. The
+// returned function closes the database.
+func OpenStorage(config *config.Database) (storage.Engine, func() error, error) {
+	switch storage.EngineType(config.Engine) {
+	case storage.StorageEngineBoltDB:
+		boltStorageEngine, err := bolt.New(config.BoltDB.Path, config.MaxResultsLimit)
+		if err != nil {
+			return nil, nil, err
+		}
+
+		return &boltStorageEngine, boltStorageEngine.Close, nil
+	default:
+		return nil, nil, fmt.Errorf("storage backend %q not implemented", config.Engine)
+	}
+}
+
 func initObjectStore(config *config.ObjectStore) (objectstore.Engine, error) {
 	switch objectstore.EngineType(config.Engine) {
 	case objectstore.EngineBolt:
@@ -95,7 +124,8 @@ func initObjectStore(config *config.ObjectStore) (objectstore.Engine, error) {
 func initSecretStore(config *config.SecretStore) (secretStore.Engine, error) {
 	switch secretStore.EngineType(config.Engine) {
 	case secretStore.EngineBolt:
-		engine, err := boltsecret.New(config.BoltDB.Path, config.BoltDB.EncryptionKey)
+		engine, err := boltsecret.New(config.BoltDB.Path, config.BoltDB.EncryptionKey,
+			config.BoltDB.PreviousEncryptionKeys...)
 		if err != nil {
 			return nil, err
 		}
@@ -106,6 +136,26 @@ func initSecretStore(config *config.SecretStore) (secretStore.Engine, error) {
 	}
 }
 
+func initLogStore(config *config.API, objectStore objectstore.Engine) (logstore.Engine, error) {
+	switch logstore.EngineType(config.TaskRunLogs.Engine) {
+	case logstore.EngineFile:
+		engine, err := filelogs.New(config.TaskRunLogsDir, filelogs.Options{
+			Compress:          config.TaskRunLogs.Compress,
+			CompressionFormat: config.TaskRunLogs.CompressionFormat,
+			RotateSize:        config.TaskRunLogs.RotateSizeMB * 1024 * 1024,
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		return engine, nil
+	case logstore.EngineObjectStore:
+		return objectlogs.New(objectStore), nil
+	default:
+		return nil, fmt.Errorf("log store backend %q not implemented", config.TaskRunLogs.Engine)
+	}
+}
+
 func initScheduler(config *config.Scheduler) (scheduler.Engine, error) {
 	switch scheduler.EngineType(config.Engine) {
 	case scheduler.EngineDocker:
@@ -115,6 +165,32 @@ func initScheduler(config *config.Scheduler) (scheduler.Engine, error) {
 		}
 
 		return &engine, err
+	case scheduler.EnginePodman:
+		if config.Podman == nil {
+			return nil, fmt.Errorf("scheduler engine %q requires a podman block", config.Engine)
+		}
+
+		engine, err := podman.New(config.Podman.Socket, config.Podman.Prune, config.Podman.PruneInterval)
+		if err != nil {
+			return nil, err
+		}
+
+		return &engine, err
+	case scheduler.EngineLocal:
+		if config.Local == nil || config.Local.WorkDir == "" {
+			return nil, fmt.Errorf("scheduler engine %q requires a local block with a work_dir", config.Engine)
+		}
+
+		if config.Local.Prune && config.Local.PruneInterval <= 0 {
+			return nil, fmt.Errorf("scheduler.local.prune_interval must be greater than zero")
+		}
+
+		engine, err := local.New(config.Local.WorkDir, config.Local.Prune, config.Local.PruneInterval)
+		if err != nil {
+			return nil, err
+		}
+
+		return engine, nil
 	default:
 		return nil, fmt.Errorf("scheduler backend %q not implemented", config.Engine)
 	}
@@ -0,0 +1,98 @@
+package app
+
+import (
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/internal/storage/bolt"
+)
+
+// ServiceCheck is the result of attempting to connect to one of Gofer's backing services.
+type ServiceCheck struct {
+	Service string
+	Engine  string
+	Err     error
+}
+
+// CheckServices attempts to initialize each of the backing services defined within the config without
+// starting the API. Each service is closed again right after it has been successfully initialized.
+//
+// Storage is only read; initializing it would migrate the database to this version's schema.
+func CheckServices(conf *config.API) []ServiceCheck {
+	checks := []ServiceCheck{}
+
+	status, err := CheckSchema(conf.Database)
+	if err == nil {
+		err = status.Compatible()
+	}
+	checks = append(checks, ServiceCheck{Service: "storage", Engine: conf.Database.Engine, Err: err})
+
+	// We turn off pruning since it would start a long running background process we have no need for.
+	schedulerConf := *conf.Scheduler
+	if schedulerConf.Docker != nil {
+		dockerConf := *schedulerConf.Docker
+		dockerConf.Prune = false
+		schedulerConf.Docker = &dockerConf
+	}
+	if schedulerConf.Podman != nil {
+		podmanConf := *schedulerConf.Podman
+		podmanConf.Prune = false
+		schedulerConf.Podman = &podmanConf
+	}
+	if schedulerConf.Local != nil {
+		localConf := *schedulerConf.Local
+		localConf.Prune = false
+		schedulerConf.Local = &localConf
+	}
+
+	_, err = initScheduler(&schedulerConf)
+	checks = append(checks, ServiceCheck{Service: "scheduler", Engine: conf.Scheduler.Engine, Err: err})
+
+	objectStore, err := initObjectStore(conf.ObjectStore)
+	if err == nil {
+		closeService(objectStore)
+	}
+	checks = append(checks, ServiceCheck{Service: "object store", Engine: conf.ObjectStore.Engine, Err: err})
+
+	secretStore, err := initSecretStore(conf.SecretStore)
+	if err == nil {
+		closeService(secretStore)
+	}
+	checks = append(checks, ServiceCheck{Service: "secret store", Engine: conf.SecretStore.Engine, Err: err})
+
+	return checks
+}
+
+// closeService closes the given service if it holds resources that can be closed(ex. database file locks).
+func closeService(service interface{}) {
+	closer, ok := service.(interface{ Close() error })
+	if !ok {
+		return
+	}
+
+	_ = closer.Close()
+}
+
+// CheckSchema compares the schema of the configured database against the one this version of Gofer expects without
+// changing the database.
+func CheckSchema(conf *config.Database) (storage.SchemaStatus, error) {
+	switch storage.EngineType(conf.Engine) {
+	case storage.StorageEngineBoltDB:
+		return bolt.InspectSchema(conf.BoltDB.Path)
+	default:
+		return storage.SchemaStatus{}, fmt.Errorf("storage backend %q not implemented", conf.Engine)
+	}
+}
+
+// SchemaStatus returns how a database of the configured engine with the given schema version compares to the schema
+// this version of Gofer expects. It's used when the database itself can't be read, like while a Gofer server has it
+// open.
+func SchemaStatus(conf *config.Database, version int) (storage.SchemaStatus, error) {
+	switch storage.EngineType(conf.Engine) {
+	case storage.StorageEngineBoltDB:
+		return bolt.NewSchemaStatus(version), nil
+	default:
+		return storage.SchemaStatus{}, fmt.Errorf("storage backend %q not implemented", conf.Engine)
+	}
+}
@@ -3,14 +3,18 @@
 package cl
 
 import (
+	"context"
 	"crypto/tls"
 	"fmt"
 	"log"
+	"os"
 	"strings"
+	"time"
 
 	"github.com/clintjedwards/gofer/internal/config"
 	"github.com/clintjedwards/polyfmt"
 	"github.com/fatih/color"
+	grpc_retry "github.com/grpc-ecosystem/go-grpc-middleware/retry"
 	"github.com/spf13/cobra"
 	"google.golang.org/grpc"
 	"google.golang.org/grpc/credentials"
@@ -42,7 +46,24 @@ func (s *Harness) Connect() (*grpc.ClientConn, error) {
 	}
 
 	opt = append(opt, grpc.WithTransportCredentials(credentials.NewTLS(tlsConf)))
-	conn, err := grpc.Dial(fmt.Sprintf("%s:%s", host, port), opt...)
+	opt = append(opt, grpc.WithChainUnaryInterceptor(
+		requestTimeoutInterceptor(s.Config.RequestTimeout),
+		grpc_retry.UnaryClientInterceptor(
+			grpc_retry.WithMax(s.Config.Retries),
+			grpc_retry.WithBackoff(grpc_retry.BackoffExponential(time.Millisecond*100)),
+		),
+	))
+
+	ctx := context.Background()
+	if s.Config.ConnectTimeout > 0 {
+		var cancel context.CancelFunc
+		ctx, cancel = context.WithTimeout(ctx, s.Config.ConnectTimeout)
+		defer cancel()
+
+		opt = append(opt, grpc.WithBlock())
+	}
+
+	conn, err := grpc.DialContext(ctx, fmt.Sprintf("%s:%s", host, port), opt...)
 	if err != nil {
 		return nil, fmt.Errorf("could not connect to server: %w", err)
 	}
@@ -50,6 +71,23 @@ func (s *Harness) Connect() (*grpc.ClientConn, error) {
 	return conn, nil
 }
 
+// requestTimeoutInterceptor limits the time a unary request may take, including all of its retries. Requests which
+// already have a deadline are left alone.
+func requestTimeoutInterceptor(timeout time.Duration) grpc.UnaryClientInterceptor {
+	return func(ctx context.Context, method string, req, reply interface{}, cc *grpc.ClientConn,
+		invoker grpc.UnaryInvoker, opts ...grpc.CallOption,
+	) error {
+		if _, hasDeadline := ctx.Deadline(); timeout <= 0 || hasDeadline {
+			return invoker(ctx, method, req, reply, cc, opts...)
+		}
+
+		ctx, cancel := context.WithTimeout(ctx, timeout)
+		defer cancel()
+
+		return invoker(ctx, method, req, reply, cc, opts...)
+	}
+}
+
 // Init harness for command line functions, used to provide different functionality during the life of a command line run.
 func InitState(cmd *cobra.Command) {
 	// Including these in the pre run hook instead of in the enclosing/parent command definition
@@ -64,13 +102,17 @@ func InitState(cmd *cobra.Command) {
 
 	// This is a hack. Because the start command needs to use the --config global variable for its own purposes
 	// we tell it to skip parsing the as if its a CLI config and supply it with some defaults.
+	// The validate-config command reads the same server configuration so it gets the same treatment.
 	if cmd.Name() == "start" && cmd.Parent().Name() == "service" {
 		State.Config = &config.CLI{
 			Format: "silent",
 		}
+	} else if cmd.Name() == "validate-config" && cmd.Parent().Name() == "service" {
+		State.Config = config.DefaultCLIConfig()
 	} else {
 		config, _ := cmd.Flags().GetString("config")
-		State.NewConfig(config)
+		context, _ := cmd.Flags().GetString("context")
+		State.NewConfig(config, context)
 	}
 
 	// Initiate the formatter(this controls the command line output)
@@ -79,9 +121,14 @@ func InitState(cmd *cobra.Command) {
 		State.Config.Format = format
 	}
 
-	State.NewFormatter()
-
 	overlayGlobalFlags(cmd)
+
+	// Structured output is meant to be read by scripts so the spinner and status messages are left out of it.
+	if State.Structured() {
+		State.Config.Format = "silent"
+	}
+
+	State.NewFormatter()
 }
 
 // Flags are the last possible way to provide variables to the command line. For global variables we allow the user
@@ -90,17 +137,43 @@ func InitState(cmd *cobra.Command) {
 // whatever those variables are.
 func overlayGlobalFlags(cmd *cobra.Command) {
 	// Now we include all other global flags into the config. Flags are always highest on the variable chain.
+	// The NO_COLOR(https://no-color.org) environment variable is honored the same way as the flag.
+	if os.Getenv("NO_COLOR") != "" {
+		State.Config.NoColor = true
+	}
+
 	noColor, _ := cmd.Flags().GetBool("no-color")
 	if noColor {
-		color.NoColor = true // turn off color globally
 		State.Config.NoColor = noColor
 	}
 
+	if State.Config.NoColor {
+		color.NoColor = true // turn off color globally
+	}
+
+	noPager, _ := cmd.Flags().GetBool("no-pager")
+	if noPager {
+		State.Config.NoPager = noPager
+	}
+
 	detail, _ := cmd.Flags().GetBool("detail")
 	if detail {
 		State.Config.Detail = detail
 	}
 
+	output, _ := cmd.Flags().GetString("output")
+	if output != "" {
+		State.Config.Output = output
+	}
+
+	if State.Config.Output == "" {
+		State.Config.Output = OutputTable
+	}
+
+	if State.Config.Output != OutputTable && !State.Structured() {
+		log.Fatalf("output format %q is not valid; accepted values are 'table', 'json', 'yaml'", State.Config.Output)
+	}
+
 	namespace, _ := cmd.Flags().GetString("namespace")
 	if namespace != "" {
 		State.Config.Namespace = namespace
@@ -110,6 +183,21 @@ func overlayGlobalFlags(cmd *cobra.Command) {
 	if host != "" {
 		State.Config.Host = host
 	}
+
+	requestTimeout, _ := cmd.Flags().GetDuration("request-timeout")
+	if requestTimeout != 0 {
+		State.Config.RequestTimeout = requestTimeout
+	}
+
+	connectTimeout, _ := cmd.Flags().GetDuration("connect-timeout")
+	if connectTimeout != 0 {
+		State.Config.ConnectTimeout = connectTimeout
+	}
+
+	if cmd.Flags().Changed("retries") {
+		retries, _ := cmd.Flags().GetUint("retries")
+		State.Config.Retries = retries
+	}
 }
 
 func (s *Harness) NewFormatter() {
@@ -121,8 +209,8 @@ func (s *Harness) NewFormatter() {
 	s.Fmt = clifmt
 }
 
-func (s *Harness) NewConfig(configPath string) {
-	config, err := config.InitCLIConfig(configPath)
+func (s *Harness) NewConfig(configPath, context string) {
+	config, err := config.InitCLIConfig(configPath, context)
 	if err != nil {
 		log.Fatal(err)
 	}
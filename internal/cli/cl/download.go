@@ -0,0 +1,98 @@
+package cl
+
+import (
+	"errors"
+	"fmt"
+	"io"
+	"time"
+
+	"github.com/dustin/go-humanize"
+)
+
+// downloadAttempts is the amount of times a download is resumed before giving up on a connection that keeps getting
+// reset.
+const downloadAttempts = 3
+
+// Downloader represents a single attempt at streaming an object from the server.
+type Downloader interface {
+	// Recv returns the next piece of the object along with the total size of the object, which is only known once
+	// the first piece has been received. Returns io.EOF once the object has been received in full.
+	Recv() (chunk []byte, size int64, err error)
+}
+
+// Download streams an object from the server into the destination, only keeping a single chunk in memory at a time.
+// The download starts at the given offset so that an object that was partially downloaded before can be finished.
+// When progress is true it is reported through the formatter as each chunk is received.
+//
+// If the connection is reset during the download, it is resumed from the last byte received using a fresh
+// downloader. Returns the total number of bytes written to the destination.
+func (s *Harness) Download(destination io.Writer, offset int64, progress bool,
+	newDownloader func(offset int64) (Downloader, error),
+) (int64, error) {
+	received := offset
+
+	var err error
+	for attempt := 1; attempt <= downloadAttempts; attempt++ {
+		if attempt > 1 {
+			if progress {
+				s.Fmt.Print(fmt.Sprintf("Connection reset; resuming download (attempt %d/%d)", attempt, downloadAttempts))
+			}
+			time.Sleep(time.Second * time.Duration(attempt))
+		}
+
+		err = s.download(destination, &received, progress, newDownloader)
+		if err == nil {
+			return received - offset, nil
+		}
+
+		if !isConnectionReset(err) {
+			return received - offset, err
+		}
+	}
+
+	return received - offset, err
+}
+
+func (s *Harness) download(destination io.Writer, received *int64, progress bool,
+	newDownloader func(offset int64) (Downloader, error),
+) error {
+	downloader, err := newDownloader(*received)
+	if err != nil {
+		return err
+	}
+
+	size := int64(0)
+
+	for {
+		chunk, chunkSize, err := downloader.Recv()
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				return nil
+			}
+			return err
+		}
+
+		if chunkSize > 0 {
+			size = chunkSize
+		}
+
+		_, err = destination.Write(chunk)
+		if err != nil {
+			return err
+		}
+
+		*received += int64(len(chunk))
+		if progress {
+			s.Fmt.Print(downloadProgress(*received, size))
+		}
+	}
+}
+
+func downloadProgress(received, size int64) string {
+	if size <= 0 {
+		return fmt.Sprintf("Downloading object (%s)", humanize.Bytes(uint64(received)))
+	}
+
+	return fmt.Sprintf("Downloading object %s/%s (%d%%)",
+		humanize.Bytes(uint64(received)), humanize.Bytes(uint64(size)), received*100/size)
+}
@@ -0,0 +1,91 @@
+package cl
+
+import (
+	"context"
+	"io"
+	"time"
+
+	"github.com/clintjedwards/gofer/proto"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const (
+	// logReconnectInterval is how long to wait before reconnecting to a log stream the server ended early.
+	logReconnectInterval = time.Second
+
+	// logReconnectTimeout is how long to keep trying to reconnect before giving up.
+	logReconnectTimeout = time.Minute * 2
+)
+
+// StreamTaskRunLogs calls handle for every log line the request returns. When following logs, streams the server ends
+// early(ex. while it restarts) are reconnected and continue after the last line received.
+func StreamTaskRunLogs(ctx context.Context, client proto.GoferClient, request *proto.GetTaskRunLogsRequest,
+	handle func(line string),
+) error {
+	following := request.Follow == nil || *request.Follow
+
+	lastLine := int64(0)
+	var reconnectDeadline time.Time
+
+	for {
+		received := lastLine
+
+		err := readTaskRunLogs(ctx, client, request, &lastLine, handle)
+		if err == nil {
+			return nil
+		}
+
+		if !following || status.Code(err) != codes.Unavailable || ctx.Err() != nil {
+			return err
+		}
+
+		// Every interruption of a stream that made progress gets the full time to reconnect.
+		if lastLine > received {
+			reconnectDeadline = time.Time{}
+		}
+
+		if reconnectDeadline.IsZero() {
+			reconnectDeadline = time.Now().Add(logReconnectTimeout)
+		}
+		if time.Now().After(reconnectDeadline) {
+			return err
+		}
+
+		select {
+		case <-ctx.Done():
+			return ctx.Err()
+		case <-time.After(logReconnectInterval):
+		}
+
+		// Lines already received are skipped by their line number, so tail and since no longer apply.
+		request.Tail = 0
+		request.Since = 0
+	}
+}
+
+func readTaskRunLogs(ctx context.Context, client proto.GoferClient, request *proto.GetTaskRunLogsRequest,
+	lastLine *int64, handle func(line string),
+) error {
+	stream, err := client.GetTaskRunLogs(ctx, request)
+	if err != nil {
+		return err
+	}
+
+	for {
+		resp, err := stream.Recv()
+		if err != nil {
+			if err == io.EOF {
+				return nil
+			}
+			return err
+		}
+
+		if resp.Keepalive || resp.LineNum <= *lastLine {
+			continue
+		}
+
+		*lastLine = resp.LineNum
+		handle(resp.LogLine)
+	}
+}
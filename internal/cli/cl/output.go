@@ -0,0 +1,94 @@
+package cl
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"os"
+	"reflect"
+
+	"google.golang.org/protobuf/encoding/protojson"
+	protov2 "google.golang.org/protobuf/proto"
+	"gopkg.in/yaml.v2"
+)
+
+// Output formats accepted by the --output flag.
+const (
+	OutputTable = "table"
+	OutputJSON  = "json"
+	OutputYAML  = "yaml"
+)
+
+// Structured reports whether the user asked for data to be printed as json or yaml instead of for humans.
+func (s *Harness) Structured() bool {
+	return s.Config.Output == OutputJSON || s.Config.Output == OutputYAML
+}
+
+// PrintStructured prints the given data to stdout in the requested output format. Protobuf messages(and slices of
+// them) are encoded with the same field names the API uses so that scripts can rely on them.
+func (s *Harness) PrintStructured(data interface{}) error {
+	generic, err := toGeneric(data)
+	if err != nil {
+		return fmt.Errorf("could not encode output: %w", err)
+	}
+
+	var output []byte
+	switch s.Config.Output {
+	case OutputJSON:
+		output, err = json.MarshalIndent(generic, "", "  ")
+		output = append(output, '\n')
+	case OutputYAML:
+		output, err = yaml.Marshal(generic)
+	default:
+		return fmt.Errorf("output format %q is not structured", s.Config.Output)
+	}
+	if err != nil {
+		return fmt.Errorf("could not encode output: %w", err)
+	}
+
+	_, err = os.Stdout.Write(output)
+	return err
+}
+
+// toGeneric converts data into plain maps, slices and values so that it encodes the same way as json or yaml.
+func toGeneric(data interface{}) (interface{}, error) {
+	if message, ok := data.(protov2.Message); ok {
+		raw, err := protojson.MarshalOptions{UseProtoNames: true, EmitUnpopulated: true}.Marshal(message)
+		if err != nil {
+			return nil, err
+		}
+
+		return decodeGeneric(raw)
+	}
+
+	value := reflect.ValueOf(data)
+	if value.Kind() == reflect.Slice {
+		list := []interface{}{}
+		for i := 0; i < value.Len(); i++ {
+			item, err := toGeneric(value.Index(i).Interface())
+			if err != nil {
+				return nil, err
+			}
+			list = append(list, item)
+		}
+		return list, nil
+	}
+
+	raw, err := json.Marshal(data)
+	if err != nil {
+		return nil, err
+	}
+
+	return decodeGeneric(raw)
+}
+
+// decodeGeneric decodes json while keeping numbers as they were written; large numbers like timestamps would
+// otherwise be printed in scientific notation.
+func decodeGeneric(raw []byte) (interface{}, error) {
+	decoder := json.NewDecoder(bytes.NewReader(raw))
+	decoder.UseNumber()
+
+	var generic interface{}
+	err := decoder.Decode(&generic)
+	return generic, err
+}
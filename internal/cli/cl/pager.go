@@ -0,0 +1,81 @@
+package cl
+
+import (
+	"os"
+	"os/exec"
+	"strings"
+
+	"golang.org/x/term"
+)
+
+// defaultPager is used when neither the configuration nor the $PAGER environment variable name a pager. The flags
+// make less quit right away if the output fits on one screen and keep colors intact.
+const defaultPager = "less -FRX"
+
+// PrintPaged prints the output through a pager so that long listings can be scrolled and searched. The pager is only
+// used when printing pretty output to a terminal and the output does not fit on the screen; otherwise the output is
+// printed as usual.
+//
+// Since the pager takes over the terminal the formatter is finished before the pager is started and a new one is
+// created once the user quits the pager.
+func (s *Harness) PrintPaged(output string) {
+	pager := s.pager()
+	if pager == nil || !s.exceedsScreen(output) {
+		s.Fmt.Println(output)
+		return
+	}
+
+	s.Fmt.Finish()
+
+	pager.Stdin = strings.NewReader(output)
+	pager.Stdout = os.Stdout
+	pager.Stderr = os.Stderr
+
+	err := pager.Run()
+
+	s.NewFormatter()
+
+	if err != nil {
+		s.Fmt.Println(output)
+	}
+}
+
+// pager returns the pager command that should be used or nil if output should not be paged.
+func (s *Harness) pager() *exec.Cmd {
+	if s.Config.NoPager || s.Config.Format != "pretty" {
+		return nil
+	}
+
+	if !term.IsTerminal(int(os.Stdout.Fd())) {
+		return nil
+	}
+
+	command := s.Config.Pager
+	if command == "" {
+		command = os.Getenv("PAGER")
+	}
+	if command == "" {
+		command = defaultPager
+	}
+
+	args := strings.Fields(command)
+	if len(args) == 0 {
+		return nil
+	}
+
+	path, err := exec.LookPath(args[0])
+	if err != nil {
+		return nil
+	}
+
+	return exec.Command(path, args[1:]...)
+}
+
+func (s *Harness) exceedsScreen(output string) bool {
+	_, height, err := term.GetSize(int(os.Stdout.Fd()))
+	if err != nil {
+		return false
+	}
+
+	return strings.Count(output, "\n")+1 > height
+}
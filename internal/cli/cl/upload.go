@@ -0,0 +1,118 @@
+package cl
+
+import (
+	"errors"
+	"fmt"
+	"io"
+	"time"
+
+	"github.com/dustin/go-humanize"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+const (
+	// uploadChunkSize is the size of each piece of an object sent to the server.
+	uploadChunkSize = 1024 * 1024 // 1MiB
+
+	// uploadAttempts is the amount of times an upload is started before giving up on a connection that keeps
+	// getting reset.
+	uploadAttempts = 3
+)
+
+// Uploader represents a single attempt at streaming an object to the server.
+type Uploader interface {
+	// Send sends the next piece of the object.
+	Send(chunk []byte) error
+
+	// Close tells the server the object has been sent in full and waits for it to be stored.
+	Close() error
+}
+
+// Upload streams the source to the server in chunks, only keeping a single chunk in memory at a time. Progress is
+// reported through the formatter as each chunk is sent.
+//
+// If the connection is reset during the upload, the source is rewound and the upload is started again using a fresh
+// uploader.
+func (s *Harness) Upload(source io.ReadSeeker, size int64, newUploader func() (Uploader, error)) error {
+	var err error
+	for attempt := 1; attempt <= uploadAttempts; attempt++ {
+		if attempt > 1 {
+			s.Fmt.Print(fmt.Sprintf("Connection reset; retrying upload (attempt %d/%d)", attempt, uploadAttempts))
+			time.Sleep(time.Second * time.Duration(attempt))
+		}
+
+		if _, err = source.Seek(0, io.SeekStart); err != nil {
+			return err
+		}
+
+		err = s.upload(source, size, newUploader)
+		if err == nil {
+			return nil
+		}
+
+		if !isConnectionReset(err) {
+			return err
+		}
+	}
+
+	return err
+}
+
+func (s *Harness) upload(source io.Reader, size int64, newUploader func() (Uploader, error)) error {
+	uploader, err := newUploader()
+	if err != nil {
+		return err
+	}
+
+	buffer := make([]byte, uploadChunkSize)
+	sent := int64(0)
+
+	for {
+		n, readErr := io.ReadFull(source, buffer)
+		if readErr != nil && !errors.Is(readErr, io.EOF) && !errors.Is(readErr, io.ErrUnexpectedEOF) {
+			return readErr
+		}
+
+		// The first message carries the object's metadata so it has to be sent even if the object is empty.
+		if n > 0 || sent == 0 {
+			err := uploader.Send(buffer[:n])
+			if err != nil {
+				// The server has ended the stream; the reason is returned when we close our side.
+				if errors.Is(err, io.EOF) {
+					return uploader.Close()
+				}
+				return err
+			}
+
+			sent += int64(n)
+			s.Fmt.Print(uploadProgress(sent, size))
+		}
+
+		if readErr != nil {
+			break
+		}
+	}
+
+	return uploader.Close()
+}
+
+func uploadProgress(sent, size int64) string {
+	if size <= 0 {
+		return fmt.Sprintf("Uploading object (%s)", humanize.Bytes(uint64(sent)))
+	}
+
+	return fmt.Sprintf("Uploading object %s/%s (%d%%)",
+		humanize.Bytes(uint64(sent)), humanize.Bytes(uint64(size)), sent*100/size)
+}
+
+// isConnectionReset returns whether the upload failed because the connection to the server was interrupted rather
+// than the server rejecting it.
+func isConnectionReset(err error) bool {
+	switch status.Code(err) {
+	case codes.Unavailable, codes.Aborted:
+		return true
+	default:
+		return false
+	}
+}
@@ -0,0 +1,31 @@
+package context
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdContext = &cobra.Command{
+	Use:   "context",
+	Short: "Manage CLI configuration contexts",
+	Long: `Manage CLI configuration contexts.
+
+Contexts are named groups of connection settings(host, token and namespace) stored within the CLI configuration
+file. They allow you to easily switch between different Gofer servers.
+
+Contexts are defined in the configuration file like so:
+
+current_context = "dev"
+
+context "dev" {
+  host      = "localhost:8080"
+  token     = "example_token"
+  namespace = "default"
+}
+
+context "prod" {
+  host  = "gofer.example.com:443"
+  token = "example_token"
+}
+
+The context used for a single command can be overridden by the --context flag.`,
+}
@@ -0,0 +1,96 @@
+package context
+
+import (
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+)
+
+var cmdContextList = &cobra.Command{
+	Use:     "list",
+	Short:   "List all configured contexts",
+	Example: `$ gofer context list`,
+	RunE:    contextList,
+}
+
+func init() {
+	CmdContext.AddCommand(cmdContextList)
+}
+
+func contextList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+
+	if cl.State.Structured() {
+		contexts := []map[string]interface{}{}
+		for _, context := range cl.State.Config.Contexts {
+			contexts = append(contexts, map[string]interface{}{
+				"name":      context.Name,
+				"host":      context.Host,
+				"namespace": context.Namespace,
+				"current":   context.Name == cl.State.Config.CurrentContext,
+			})
+		}
+
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(contexts)
+	}
+
+	data := [][]string{}
+	for _, context := range cl.State.Config.Contexts {
+		current := ""
+		if context.Name == cl.State.Config.CurrentContext {
+			current = "*"
+		}
+
+		data = append(data, []string{
+			current,
+			context.Name,
+			context.Host,
+			context.Namespace,
+		})
+	}
+
+	table := formatTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func formatTable(data [][]string, color bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Current", "Name", "Host", "Namespace"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if color {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgGreenColor),
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
@@ -0,0 +1,86 @@
+package context
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/hashicorp/hcl/v2"
+	"github.com/hashicorp/hcl/v2/hclwrite"
+	"github.com/spf13/cobra"
+	"github.com/zclconf/go-cty/cty"
+)
+
+var cmdContextUse = &cobra.Command{
+	Use:   "use <name>",
+	Short: "Set the default context",
+	Long: `Set the default context.
+
+Updates the current_context setting within the CLI configuration file so that all further commands use the
+settings of the given context.`,
+	Example: `$ gofer context use prod`,
+	RunE:    contextUse,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdContext.AddCommand(cmdContextUse)
+}
+
+func contextUse(cmd *cobra.Command, args []string) error {
+	name := args[0]
+
+	configFlag, _ := cmd.Flags().GetString("config")
+
+	cl.State.Fmt.Print("Updating current context")
+
+	_, err := cl.State.Config.GetContext(name)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	path := config.CLIConfigPath(configFlag)
+	if path == "" {
+		err := fmt.Errorf("could not find CLI configuration file")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	err = setCurrentContext(path, name)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not update configuration file: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Switched to context %q", name))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+// setCurrentContext rewrites the current_context attribute of the given configuration file in place, leaving
+// the rest of the file untouched.
+func setCurrentContext(path, name string) error {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return err
+	}
+
+	file, diags := hclwrite.ParseConfig(content, path, hcl.InitialPos)
+	if diags.HasErrors() {
+		return diags
+	}
+
+	file.Body().SetAttributeValue("current_context", cty.StringVal(name))
+
+	stat, err := os.Stat(path)
+	if err != nil {
+		return err
+	}
+
+	return os.WriteFile(path, file.Bytes(), stat.Mode())
+}
@@ -0,0 +1,267 @@
+package dashboard
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"strings"
+	"time"
+	"unicode/utf8"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+	"google.golang.org/grpc/metadata"
+)
+
+var CmdDashboard = &cobra.Command{
+	Use:   "dashboard",
+	Short: "Browse namespaces, pipelines, runs and logs interactively",
+	Long: `Browse namespaces, pipelines, runs and logs interactively.
+
+The dashboard opens on the pipelines of the current namespace. Selecting a pipeline shows its recent runs, selecting a
+run shows its task runs and selecting a task run follows its logs. The current view is reloaded every few seconds.
+
+Keys:
+  ↑/k, ↓/j     move the selection (scrolls when viewing logs)
+  enter/l      open the selected item
+  esc/h        go back to the previous view
+  r            reload the current view
+  q            quit`,
+	Example: `$ gofer dashboard
+$ gofer dashboard --namespace my_namespace`,
+	RunE: dashboard,
+	Args: cobra.ExactArgs(0),
+}
+
+// refreshInterval is how often the current view is reloaded from the server.
+const refreshInterval = time.Second * 3
+
+// Escape sequences used to take over the terminal and give it back afterwards.
+const (
+	enterAltScreen = "\x1b[?1049h"
+	exitAltScreen  = "\x1b[?1049l"
+	hideCursor     = "\x1b[?25l"
+	showCursor     = "\x1b[?25h"
+)
+
+func dashboard(_ *cobra.Command, _ []string) error {
+	// We draw the screen ourselves so the formatter is not used.
+	cl.State.Fmt.Finish()
+
+	if !term.IsTerminal(int(os.Stdin.Fd())) || !term.IsTerminal(int(os.Stdout.Fd())) {
+		err := fmt.Errorf("the dashboard requires an interactive terminal")
+		fmt.Println(err)
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+	defer conn.Close()
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx, cancel := context.WithCancel(metadata.NewOutgoingContext(context.Background(), md))
+	defer cancel()
+
+	oldState, err := term.MakeRaw(int(os.Stdin.Fd()))
+	if err != nil {
+		fmt.Printf("could not set up terminal: %v\n", err)
+		return err
+	}
+	defer func() { _ = term.Restore(int(os.Stdin.Fd()), oldState) }()
+
+	fmt.Print(enterAltScreen + hideCursor)
+	defer fmt.Print(showCursor + exitAltScreen)
+
+	b := &board{
+		ctx:    ctx,
+		client: proto.NewGoferClient(conn),
+		redraw: make(chan struct{}, 1),
+	}
+
+	// Going back from the pipelines of the current namespace leads to the list of all namespaces.
+	b.push(newNamespacesView(b))
+	b.push(newPipelinesView(b, cl.State.Config.Namespace))
+
+	return b.run()
+}
+
+// board holds the stack of views the user drilled down through; only the top one is shown.
+type board struct {
+	ctx    context.Context
+	client proto.GoferClient
+
+	// redraw is signaled by views that change in the background, like logs being followed.
+	redraw chan struct{}
+	stack  []view
+}
+
+func (b *board) current() view {
+	return b.stack[len(b.stack)-1]
+}
+
+func (b *board) push(v view) {
+	b.stack = append(b.stack, v)
+	v.load()
+}
+
+func (b *board) pop() {
+	if len(b.stack) == 1 {
+		return
+	}
+
+	b.current().close()
+	b.stack = b.stack[:len(b.stack)-1]
+	b.current().load()
+}
+
+// requestRedraw asks for the screen to be drawn again; requests made while one is pending are merged.
+func (b *board) requestRedraw() {
+	select {
+	case b.redraw <- struct{}{}:
+	default:
+	}
+}
+
+func (b *board) run() error {
+	keys := make(chan key)
+	go readKeys(os.Stdin, keys)
+
+	ticker := time.NewTicker(refreshInterval)
+	defer ticker.Stop()
+
+	defer func() {
+		for _, v := range b.stack {
+			v.close()
+		}
+	}()
+
+	for {
+		b.render()
+
+		select {
+		case k, ok := <-keys:
+			if !ok {
+				return nil
+			}
+
+			switch k {
+			case keyQuit:
+				return nil
+			case keyUp:
+				b.current().move(-1)
+			case keyDown:
+				b.current().move(1)
+			case keyOpen:
+				if next := b.current().open(); next != nil {
+					b.push(next)
+				}
+			case keyBack:
+				b.pop()
+			case keyRefresh:
+				b.current().load()
+			case keyUnknown:
+			}
+		case <-ticker.C:
+			b.current().load()
+		case <-b.redraw:
+		}
+	}
+}
+
+// render draws the whole screen: the path of views at the top, the current view and the key help at the bottom.
+func (b *board) render() {
+	width, height, err := term.GetSize(int(os.Stdout.Fd()))
+	if err != nil || width <= 0 || height <= 3 {
+		width, height = 80, 24
+	}
+
+	titles := []string{}
+	for _, v := range b.stack {
+		titles = append(titles, v.title())
+	}
+
+	lines := []string{"\x1b[1m" + fit("gofer :: "+strings.Join(titles, " > "), width) + "\x1b[0m", ""}
+	lines = append(lines, b.current().render(width, height-3)...)
+
+	for len(lines) < height-1 {
+		lines = append(lines, "")
+	}
+
+	lines = append(lines, "\x1b[2m"+fit("↑/k up  ↓/j down  enter open  esc back  r reload  q quit", width)+"\x1b[0m")
+
+	// Raw mode doesn't translate newlines, so every line returns the cursor to the start itself. Lines are cleared
+	// as they're overwritten instead of clearing the whole screen up front to avoid flickering.
+	var frame strings.Builder
+	frame.WriteString("\x1b[H")
+	for i, line := range lines {
+		frame.WriteString(line + "\x1b[K")
+		if i < len(lines)-1 {
+			frame.WriteString("\r\n")
+		}
+	}
+	frame.WriteString("\x1b[J")
+
+	fmt.Print(frame.String())
+}
+
+// fit cuts the line down to the given width.
+func fit(line string, width int) string {
+	if utf8.RuneCountInString(line) <= width {
+		return line
+	}
+
+	return string([]rune(line)[:width])
+}
+
+// key is a single key press, reduced to the actions the dashboard knows about.
+type key int
+
+const (
+	keyUnknown key = iota
+	keyUp
+	keyDown
+	keyOpen
+	keyBack
+	keyRefresh
+	keyQuit
+)
+
+// readKeys turns the input of a terminal in raw mode into key presses until the input is closed.
+func readKeys(in io.Reader, keys chan<- key) {
+	defer close(keys)
+
+	buf := make([]byte, 16)
+	for {
+		n, err := in.Read(buf)
+		if err != nil {
+			return
+		}
+
+		keys <- parseKey(buf[:n])
+	}
+}
+
+func parseKey(input []byte) key {
+	switch string(input) {
+	case "k", "\x1b[A", "\x1bOA":
+		return keyUp
+	case "j", "\x1b[B", "\x1bOB":
+		return keyDown
+	case "\r", "\n", "l", "\x1b[C", "\x1bOC":
+		return keyOpen
+	case "\x1b", "h", "\x7f", "\x1b[D", "\x1bOD":
+		return keyBack
+	case "r":
+		return keyRefresh
+	case "q", "\x03":
+		return keyQuit
+	default:
+		return keyUnknown
+	}
+}
@@ -0,0 +1,385 @@
+package dashboard
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+	"sync"
+
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+)
+
+// view is a single screen of the dashboard.
+type view interface {
+	// title is the view's part of the path shown at the top of the screen.
+	title() string
+
+	// load retrieves the view's content from the server again.
+	load()
+
+	// render returns at most height lines, each at most width wide.
+	render(width, height int) []string
+
+	// move moves the selection by the given amount of lines.
+	move(delta int)
+
+	// open returns the view for the selected line or nil if there is nothing to open.
+	open() view
+
+	// close stops anything the view does in the background.
+	close()
+}
+
+// item is a single selectable line of a list view.
+type item struct {
+	id   string
+	text string
+}
+
+// listView is a selectable list of items, like pipelines or runs.
+type listView struct {
+	name   string
+	header string
+	fetch  func() ([]item, error)
+	next   func(selected item) view
+
+	items  []item
+	err    error
+	cursor int
+
+	// offset is the index of the first item shown; it moves along once the cursor leaves the screen.
+	offset int
+}
+
+func (v *listView) title() string { return v.name }
+
+func (v *listView) load() {
+	selected := ""
+	if v.cursor < len(v.items) {
+		selected = v.items[v.cursor].id
+	}
+
+	items, err := v.fetch()
+	v.err = err
+	if err != nil {
+		return
+	}
+	v.items = items
+
+	// Keep the same item selected even if the list shifted around it, like when a new run shows up at the top.
+	v.cursor = 0
+	for i, item := range v.items {
+		if item.id == selected {
+			v.cursor = i
+			break
+		}
+	}
+}
+
+func (v *listView) render(width, height int) []string {
+	lines := []string{fit(v.header, width)}
+
+	if v.err != nil {
+		return append(lines, "", fit(fmt.Sprintf("could not load %s: %v", v.name, v.err), width))
+	}
+
+	if len(v.items) == 0 {
+		return append(lines, "", fit("Nothing here yet", width))
+	}
+
+	rows := height - 1
+	if v.cursor < v.offset {
+		v.offset = v.cursor
+	}
+	if v.cursor >= v.offset+rows {
+		v.offset = v.cursor - rows + 1
+	}
+
+	for i := v.offset; i < len(v.items) && i < v.offset+rows; i++ {
+		line := fit(v.items[i].text, width)
+		if i == v.cursor {
+			line = "\x1b[7m" + line + strings.Repeat(" ", width-len([]rune(line))) + "\x1b[0m"
+		}
+		lines = append(lines, line)
+	}
+
+	return lines
+}
+
+func (v *listView) move(delta int) {
+	v.cursor += delta
+	if v.cursor >= len(v.items) {
+		v.cursor = len(v.items) - 1
+	}
+	if v.cursor < 0 {
+		v.cursor = 0
+	}
+}
+
+func (v *listView) open() view {
+	if v.next == nil || v.cursor >= len(v.items) {
+		return nil
+	}
+
+	return v.next(v.items[v.cursor])
+}
+
+func (v *listView) close() {}
+
+func newNamespacesView(b *board) view {
+	return &listView{
+		name:   "namespaces",
+		header: fmt.Sprintf("%-30s %-30s %s", "ID", "NAME", "CREATED"),
+		fetch: func() ([]item, error) {
+			resp, err := b.client.ListNamespaces(b.ctx, &proto.ListNamespacesRequest{})
+			if err != nil {
+				return nil, err
+			}
+
+			items := []item{}
+			for _, namespace := range resp.Namespaces {
+				text := fmt.Sprintf("%-30s %-30s %s", namespace.Id, namespace.Name,
+					cliformat.UnixMilli(namespace.Created, "Unknown", false))
+				items = append(items, item{id: namespace.Id, text: text})
+			}
+			return items, nil
+		},
+		next: func(selected item) view {
+			return newPipelinesView(b, selected.id)
+		},
+	}
+}
+
+func newPipelinesView(b *board, namespace string) view {
+	return &listView{
+		name:   namespace,
+		header: fmt.Sprintf("%-30s %-10s %-20s %s", "PIPELINE", "STATE", "LAST RUN", "NAME"),
+		fetch: func() ([]item, error) {
+			resp, err := b.client.ListPipelines(b.ctx, &proto.ListPipelinesRequest{
+				NamespaceId: namespace,
+			})
+			if err != nil {
+				return nil, err
+			}
+
+			items := []item{}
+			for _, pipeline := range resp.Pipelines {
+				text := fmt.Sprintf("%-30s %-10s %-20s %s", pipeline.Id, strings.ToLower(pipeline.State.String()),
+					cliformat.UnixMilli(pipeline.LastRunTime, "Never", false), pipeline.Name)
+				items = append(items, item{id: pipeline.Id, text: text})
+			}
+			return items, nil
+		},
+		next: func(selected item) view {
+			return newRunsView(b, namespace, selected.id)
+		},
+	}
+}
+
+// dashboardRunLimit is the amount of recent runs shown for a pipeline.
+const dashboardRunLimit = 50
+
+func newRunsView(b *board, namespace, pipeline string) view {
+	return &listView{
+		name:   pipeline,
+		header: fmt.Sprintf("%-8s %-12s %-20s %-12s %s", "RUN", "STATE", "STARTED", "DURATION", "TRIGGERED BY"),
+		fetch: func() ([]item, error) {
+			resp, err := b.client.ListRuns(b.ctx, &proto.ListRunsRequest{
+				NamespaceId: namespace,
+				PipelineId:  pipeline,
+				Limit:       dashboardRunLimit,
+			})
+			if err != nil {
+				return nil, err
+			}
+
+			items := []item{}
+			for _, run := range resp.Runs {
+				text := fmt.Sprintf("%-8s %-12s %-20s %-12s %s", fmt.Sprintf("#%d", run.Id),
+					strings.ToLower(run.State.String()), cliformat.UnixMilli(run.Started, "Not yet", false),
+					cliformat.Duration(run.Started, run.Ended), run.TriggerName)
+				items = append(items, item{id: strconv.FormatInt(run.Id, 10), text: text})
+			}
+			return items, nil
+		},
+		next: func(selected item) view {
+			id, _ := strconv.ParseInt(selected.id, 10, 64)
+			return newTaskRunsView(b, namespace, pipeline, id)
+		},
+	}
+}
+
+func newTaskRunsView(b *board, namespace, pipeline string, run int64) view {
+	return &listView{
+		name:   fmt.Sprintf("#%d", run),
+		header: fmt.Sprintf("%-30s %-12s %-20s %s", "TASK RUN", "STATE", "STARTED", "DURATION"),
+		fetch: func() ([]item, error) {
+			resp, err := b.client.ListTaskRuns(b.ctx, &proto.ListTaskRunsRequest{
+				NamespaceId: namespace,
+				PipelineId:  pipeline,
+				RunId:       run,
+			})
+			if err != nil {
+				return nil, err
+			}
+
+			items := []item{}
+			for _, taskRun := range resp.TaskRuns {
+				text := fmt.Sprintf("%-30s %-12s %-20s %s", taskRun.Id, strings.ToLower(taskRun.State.String()),
+					cliformat.UnixMilli(taskRun.Started, "Not yet", false),
+					cliformat.Duration(taskRun.Started, taskRun.Ended))
+				items = append(items, item{id: taskRun.Id, text: text})
+			}
+			return items, nil
+		},
+		next: func(selected item) view {
+			return newLogsView(b, &proto.GetTaskRunLogsRequest{
+				NamespaceId: namespace,
+				PipelineId:  pipeline,
+				RunId:       run,
+				Id:          selected.id,
+			})
+		},
+	}
+}
+
+// maxLogLines is the amount of log lines kept in memory by the logs view; older lines are dropped.
+const maxLogLines = 5000
+
+// logsView follows the logs of a task run.
+type logsView struct {
+	board   *board
+	request *proto.GetTaskRunLogsRequest
+	cancel  context.CancelFunc
+
+	mu    sync.Mutex
+	lines []string
+	err   error
+	done  bool
+
+	// scroll is the amount of lines the view is scrolled up from the end of the logs; zero follows new lines.
+	scroll int
+}
+
+func newLogsView(b *board, request *proto.GetTaskRunLogsRequest) view {
+	follow := true
+	request.Follow = &follow
+	return &logsView{
+		board:   b,
+		request: request,
+	}
+}
+
+func (v *logsView) title() string { return v.request.Id }
+
+// load starts following the logs the first time it's called; afterwards new lines arrive on their own.
+func (v *logsView) load() {
+	if v.cancel != nil {
+		return
+	}
+
+	ctx, cancel := context.WithCancel(v.board.ctx)
+	v.cancel = cancel
+
+	go v.follow(ctx)
+}
+
+func (v *logsView) follow(ctx context.Context) {
+	stream, err := v.board.client.GetTaskRunLogs(ctx, v.request)
+	if err != nil {
+		v.finish(err)
+		return
+	}
+
+	for {
+		resp, err := stream.Recv()
+		if err != nil {
+			if err == io.EOF || ctx.Err() != nil {
+				err = nil
+			}
+			v.finish(err)
+			return
+		}
+
+		if resp.Keepalive {
+			continue
+		}
+
+		v.mu.Lock()
+		v.lines = append(v.lines, strings.ReplaceAll(resp.LogLine, "\t", "    "))
+		if len(v.lines) > maxLogLines {
+			v.lines = v.lines[len(v.lines)-maxLogLines:]
+		}
+		v.mu.Unlock()
+
+		v.board.requestRedraw()
+	}
+}
+
+func (v *logsView) finish(err error) {
+	v.mu.Lock()
+	v.err = err
+	v.done = true
+	v.mu.Unlock()
+
+	v.board.requestRedraw()
+}
+
+func (v *logsView) render(width, height int) []string {
+	v.mu.Lock()
+	defer v.mu.Unlock()
+
+	status := "following logs"
+	if v.done {
+		status = "end of logs"
+	}
+	if v.err != nil {
+		status = fmt.Sprintf("could not get logs: %v", v.err)
+	}
+	if v.scroll > 0 {
+		status += fmt.Sprintf(" (scrolled up %d lines)", v.scroll)
+	}
+
+	rows := height - 1
+	if v.scroll > len(v.lines)-rows {
+		v.scroll = len(v.lines) - rows
+	}
+	if v.scroll < 0 {
+		v.scroll = 0
+	}
+
+	end := len(v.lines) - v.scroll
+	start := end - rows
+	if start < 0 {
+		start = 0
+	}
+
+	lines := []string{fit(status, width)}
+	for _, line := range v.lines[start:end] {
+		lines = append(lines, fit(line, width))
+	}
+
+	return lines
+}
+
+func (v *logsView) move(delta int) {
+	v.mu.Lock()
+	defer v.mu.Unlock()
+
+	// Moving up scrolls back through the logs; render keeps the scroll within the logs we have.
+	v.scroll -= delta
+	if v.scroll < 0 {
+		v.scroll = 0
+	}
+}
+
+func (v *logsView) open() view { return nil }
+
+func (v *logsView) close() {
+	if v.cancel != nil {
+		v.cancel()
+	}
+}
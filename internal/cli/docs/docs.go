@@ -0,0 +1,10 @@
+package docs
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdDocs = &cobra.Command{
+	Use:   "docs",
+	Short: "Generate documentation for Gofer",
+}
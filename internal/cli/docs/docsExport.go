@@ -0,0 +1,137 @@
+package docs
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/spf13/cobra"
+)
+
+var cmdDocsExport = &cobra.Command{
+	Use:   "export",
+	Short: "Export the CLI command reference as markdown",
+	Long: `Export the CLI command reference as markdown.
+
+Writes one markdown file per command into the output directory. The files link to each other and can be published
+as is.`,
+	Example: `$ gofer docs export
+$ gofer docs export --out ./website/docs/cli`,
+	RunE: docsExport,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdDocsExport.Flags().StringP("out", "o", "./docs", "directory to write the documentation files to")
+	CmdDocs.AddCommand(cmdDocsExport)
+}
+
+func docsExport(cmd *cobra.Command, _ []string) error {
+	out, _ := cmd.Flags().GetString("out")
+
+	cl.State.Fmt.Print("Generating documentation")
+
+	err := os.MkdirAll(out, 0755)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not create output directory: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	count, err := writeDocsTree(cmd.Root(), out)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not write documentation: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Wrote %d files to %q", count, out))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+// writeDocsTree writes the documentation for the given command and all of its children. Returns the amount
+// of files written.
+func writeDocsTree(cmd *cobra.Command, dir string) (int, error) {
+	count := 0
+
+	for _, child := range cmd.Commands() {
+		if !child.IsAvailableCommand() || child.IsAdditionalHelpTopicCommand() {
+			continue
+		}
+
+		written, err := writeDocsTree(child, dir)
+		if err != nil {
+			return count, err
+		}
+		count += written
+	}
+
+	path := filepath.Join(dir, docsFilename(cmd))
+	err := os.WriteFile(path, []byte(commandMarkdown(cmd)), 0644)
+	if err != nil {
+		return count, err
+	}
+
+	return count + 1, nil
+}
+
+func docsFilename(cmd *cobra.Command) string {
+	return strings.ReplaceAll(cmd.CommandPath(), " ", "_") + ".md"
+}
+
+// commandMarkdown renders the documentation page for a single command.
+func commandMarkdown(cmd *cobra.Command) string {
+	cmd.InitDefaultHelpCmd()
+	cmd.InitDefaultHelpFlag()
+
+	b := &strings.Builder{}
+
+	fmt.Fprintf(b, "## %s\n\n", cmd.CommandPath())
+	fmt.Fprintf(b, "%s\n\n", cmd.Short)
+
+	if cmd.Long != "" {
+		fmt.Fprintf(b, "### Synopsis\n\n%s\n\n", cmd.Long)
+	}
+
+	if cmd.Runnable() {
+		fmt.Fprintf(b, "```\n%s\n```\n\n", cmd.UseLine())
+	}
+
+	if cmd.Example != "" {
+		fmt.Fprintf(b, "### Examples\n\n```\n%s\n```\n\n", cmd.Example)
+	}
+
+	if flags := cmd.NonInheritedFlags(); flags.HasAvailableFlags() {
+		fmt.Fprintf(b, "### Options\n\n```\n%s```\n\n", flags.FlagUsages())
+	}
+
+	if flags := cmd.InheritedFlags(); flags.HasAvailableFlags() {
+		fmt.Fprintf(b, "### Options inherited from parent commands\n\n```\n%s```\n\n", flags.FlagUsages())
+	}
+
+	seeAlso := []string{}
+	if cmd.HasParent() {
+		parent := cmd.Parent()
+		seeAlso = append(seeAlso, fmt.Sprintf("- [%s](%s) - %s", parent.CommandPath(), docsFilename(parent), parent.Short))
+	}
+
+	children := cmd.Commands()
+	sort.Slice(children, func(i, j int) bool { return children[i].Name() < children[j].Name() })
+
+	for _, child := range children {
+		if !child.IsAvailableCommand() || child.IsAdditionalHelpTopicCommand() {
+			continue
+		}
+		seeAlso = append(seeAlso, fmt.Sprintf("- [%s](%s) - %s", child.CommandPath(), docsFilename(child), child.Short))
+	}
+
+	if len(seeAlso) > 0 {
+		fmt.Fprintf(b, "### SEE ALSO\n\n%s\n", strings.Join(seeAlso, "\n"))
+	}
+
+	return b.String()
+}
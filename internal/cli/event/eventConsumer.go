@@ -0,0 +1,21 @@
+package event
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdEventConsumer = &cobra.Command{
+	Use:   "consumer",
+	Short: "Manage event consumers",
+	Long: `Manage event consumers.
+
+Event consumers are named, persistent subscriptions to Gofer's events. Gofer remembers the last event each consumer
+has acknowledged so that a consumer which goes offline can pick up where it left off instead of missing events.
+
+Consumers are created the first time they are used to list events(ex. gofer event list --consumer <name>) and start
+with the next event published.`,
+}
+
+func init() {
+	CmdEvent.AddCommand(CmdEventConsumer)
+}
@@ -0,0 +1,66 @@
+package event
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdEventConsumerAck = &cobra.Command{
+	Use:   "ack <name> <event_id>",
+	Short: "Acknowledge events for a consumer",
+	Long: `Acknowledge events for a consumer.
+
+Acknowledging an event acknowledges all events before it as well. The next time the consumer lists events it will
+start with the event after the acknowledged one.`,
+	Example: `$ gofer event consumer ack notifications 1500`,
+	RunE:    eventConsumerAck,
+	Args:    cobra.ExactArgs(2),
+}
+
+func init() {
+	CmdEventConsumer.AddCommand(cmdEventConsumerAck)
+}
+
+func eventConsumerAck(_ *cobra.Command, args []string) error {
+	name := args[0]
+
+	id, err := strconv.ParseInt(args[1], 10, 64)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse event id: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Acknowledging event")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	_, err = client.AcknowledgeEvent(ctx, &proto.AcknowledgeEventRequest{
+		Consumer: name,
+		Id:       id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not acknowledge event: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Acknowledged events up to %d for consumer %q", id, name))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,56 @@
+package event
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdEventConsumerDelete = &cobra.Command{
+	Use:   "delete <name>",
+	Short: "Delete event consumer",
+	Long: `Delete event consumer.
+
+The next time a consumer with the same name is used it will start with the next event published.`,
+	Example: `$ gofer event consumer delete notifications`,
+	RunE:    eventConsumerDelete,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdEventConsumer.AddCommand(cmdEventConsumerDelete)
+}
+
+func eventConsumerDelete(_ *cobra.Command, args []string) error {
+	name := args[0]
+
+	cl.State.Fmt.Print("Deleting event consumer")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	_, err = client.DeleteEventConsumer(ctx, &proto.DeleteEventConsumerRequest{
+		Name: name,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not delete event consumer: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Deleted event consumer %q", name))
+	cl.State.Fmt.Finish()
+	return nil
+}
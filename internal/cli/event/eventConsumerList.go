@@ -0,0 +1,105 @@
+package event
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdEventConsumerList = &cobra.Command{
+	Use:     "list",
+	Short:   "List all event consumers",
+	Example: `$ gofer event consumer list`,
+	RunE:    eventConsumerList,
+	Args:    cobra.ExactArgs(0),
+}
+
+func init() {
+	CmdEventConsumer.AddCommand(cmdEventConsumerList)
+}
+
+func eventConsumerList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+
+	cl.State.Fmt.Print("Retrieving event consumers")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListEventConsumers(ctx, &proto.ListEventConsumersRequest{})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list event consumers: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Consumers)
+	}
+
+	data := [][]string{}
+	for _, consumer := range resp.Consumers {
+		data = append(data, []string{
+			consumer.Name,
+			fmt.Sprint(consumer.AcknowledgedId),
+			cliformat.UnixMilli(consumer.Updated, "Never", detail),
+		})
+	}
+
+	table := formatConsumerTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func formatConsumerTable(data [][]string, color bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Name", "Acknowledged", "Updated"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if color {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
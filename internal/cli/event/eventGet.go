@@ -57,6 +57,11 @@ func eventGet(_ *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp)
+	}
+
 	output, err := formatEvent(resp, cl.State.Config.Detail)
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not render event: %v", err))
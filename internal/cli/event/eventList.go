@@ -5,6 +5,8 @@ import (
 	"encoding/json"
 	"fmt"
 	"io"
+	"strings"
+	"time"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
 	"github.com/clintjedwards/gofer/proto"
@@ -19,8 +21,20 @@ var cmdEventList = &cobra.Command{
 	Short: "List all events",
 	Long: `List all events.
 
-Returns events from oldest to newest.`,
-	Example: `$ gofer events list`,
+Returns events from oldest to newest.
+
+Events can be replayed from a specific event ID or point in time with the --from-id and --since flags. Combined
+with --follow this allows a consumer to catch up on the events it missed before continuing with new events.
+
+The --consumer flag resumes from the last event acknowledged by the named event consumer. Events shown this way
+still need to be acknowledged with "gofer event consumer ack".
+
+When printing to a terminal, listings longer than the screen are shown through a pager unless the --follow flag
+is used.`,
+	Example: `$ gofer events list
+$ gofer events list --from-id 1500 --follow
+$ gofer events list --since 2h
+$ gofer events list --consumer notifications`,
 	RunE:    eventList,
 	Args:    cobra.ExactArgs(0),
 }
@@ -28,6 +42,9 @@ Returns events from oldest to newest.`,
 func init() {
 	cmdEventList.Flags().BoolP("reverse", "r", false, "Sort events from newest to oldest")
 	cmdEventList.Flags().BoolP("follow", "f", false, "Continuously wait for more events; does not work with reverse")
+	cmdEventList.Flags().Int64("from-id", 0, "Only list events with this ID or higher; does not work with reverse")
+	cmdEventList.Flags().Duration("since", 0, "Only list events emitted within the given duration (ex. 10m, 1h); does not work with reverse")
+	cmdEventList.Flags().String("consumer", "", "Only list events after the last event acknowledged by this consumer")
 	CmdEvent.AddCommand(cmdEventList)
 }
 
@@ -44,6 +61,26 @@ func eventList(cmd *cobra.Command, _ []string) error {
 		return err
 	}
 
+	fromID, err := cmd.Flags().GetInt64("from-id")
+	if err != nil {
+		return err
+	}
+
+	since, err := cmd.Flags().GetDuration("since")
+	if err != nil {
+		return err
+	}
+
+	consumer, err := cmd.Flags().GetString("consumer")
+	if err != nil {
+		return err
+	}
+
+	var fromTime int64
+	if since > 0 {
+		fromTime = time.Now().Add(-since).UnixMilli()
+	}
+
 	conn, err := cl.State.Connect()
 	if err != nil {
 		cl.State.Fmt.PrintErr(err)
@@ -56,8 +93,11 @@ func eventList(cmd *cobra.Command, _ []string) error {
 	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
 	ctx := metadata.NewOutgoingContext(context.Background(), md)
 	stream, err := client.ListEvents(ctx, &proto.ListEventsRequest{
-		Reverse: reverse,
-		Follow:  follow,
+		Reverse:  reverse,
+		Follow:   follow,
+		FromId:   fromID,
+		FromTime: fromTime,
+		Consumer: consumer,
 	})
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list events: %v", err))
@@ -65,6 +105,10 @@ func eventList(cmd *cobra.Command, _ []string) error {
 		return err
 	}
 
+	// When following we print events as they come in, otherwise we collect them so they can be paged through.
+	events := &strings.Builder{}
+	structuredEvents := []*proto.ListEventsResponse{}
+
 	for {
 		resp, err := stream.Recv()
 		if err != nil {
@@ -76,7 +120,34 @@ func eventList(cmd *cobra.Command, _ []string) error {
 			break
 		}
 
-		cl.State.Fmt.Println(printEvent(resp))
+		if resp.Keepalive {
+			continue
+		}
+
+		if cl.State.Structured() {
+			if follow {
+				_ = cl.State.PrintStructured(resp)
+				continue
+			}
+			structuredEvents = append(structuredEvents, resp)
+			continue
+		}
+
+		if follow {
+			cl.State.Fmt.Println(printEvent(resp))
+			continue
+		}
+
+		events.WriteString(printEvent(resp) + "\n")
+	}
+
+	if cl.State.Structured() && !follow {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(structuredEvents)
+	}
+
+	if events.Len() > 0 {
+		cl.State.PrintPaged(strings.TrimSuffix(events.String(), "\n"))
 	}
 
 	cl.State.Fmt.Finish()
@@ -0,0 +1,180 @@
+package event
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/protobuf/encoding/protojson"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+var cmdEventTail = &cobra.Command{
+	Use:   "tail",
+	Short: "Stream new events as they happen",
+	Long: `Stream new events as they happen.
+
+Only events emitted after the command was started are shown. Events can be narrowed down by kind, namespace and
+pipeline; the kind flag can be given multiple times to match any of the given kinds.
+
+Passing the global --format json flag will print each event as a single line of JSON.`,
+	Example: `$ gofer event tail
+$ gofer event tail --kind started_run --kind completed_run
+$ gofer event tail --namespace default --pipeline simple_test_pipeline
+$ gofer event tail --format json`,
+	RunE: eventTail,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdEventTail.Flags().StringSliceP("kind", "k", []string{}, "only show events of the given kind (ex. started_run)")
+	cmdEventTail.Flags().StringP("pipeline", "p", "", "only show events for the given pipeline")
+	CmdEvent.AddCommand(cmdEventTail)
+}
+
+// eventFilter decides which events are shown to the user. Empty fields match everything.
+type eventFilter struct {
+	kinds     map[proto.EventType]struct{}
+	namespace string
+	pipeline  string
+}
+
+func eventTail(cmd *cobra.Command, _ []string) error {
+	// We don't use the formatter here since we want each event printed as soon as we get it and
+	// the output to be easily piped into other tools.
+	cl.State.Fmt.Finish()
+
+	kinds, _ := cmd.Flags().GetStringSlice("kind")
+	pipeline, _ := cmd.Flags().GetString("pipeline")
+
+	filter := eventFilter{
+		kinds:    map[proto.EventType]struct{}{},
+		pipeline: pipeline,
+	}
+
+	for _, kind := range kinds {
+		value, exists := proto.EventType_value[strings.ToUpper(kind)]
+		if !exists {
+			err := fmt.Errorf("event kind %q is not valid", kind)
+			fmt.Println(err)
+			return err
+		}
+		filter.kinds[proto.EventType(value)] = struct{}{}
+	}
+
+	// The namespace flag is a global flag which is always populated from the config, so we only filter
+	// on it if the user explicitly asked us to.
+	if cmd.Flags().Changed("namespace") {
+		filter.namespace = cl.State.Config.Namespace
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+	defer conn.Close()
+
+	client := proto.NewGoferClient(conn)
+
+	// Gofer first replays all historical events before streaming new ones; we skip anything that was
+	// emitted before we started tailing.
+	started := time.Now().UnixMilli()
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	stream, err := client.ListEvents(ctx, &proto.ListEventsRequest{
+		Follow: true,
+	})
+	if err != nil {
+		fmt.Printf("could not tail events: %v\n", err)
+		return err
+	}
+
+	for {
+		resp, err := stream.Recv()
+		if err != nil {
+			if err == io.EOF {
+				return nil
+			}
+			fmt.Printf("could not get events: %v\n", err)
+			return err
+		}
+
+		event := eventMessage(resp)
+		if event == nil {
+			continue
+		}
+
+		if event.GetMetadata().Emitted < started {
+			continue
+		}
+
+		if !filter.matches(resp.Kind, event) {
+			continue
+		}
+
+		if cl.State.Config.Format == "json" {
+			output, err := protojson.Marshal(resp)
+			if err != nil {
+				fmt.Printf("could not format event: %v\n", err)
+				continue
+			}
+			fmt.Println(string(output))
+			continue
+		}
+
+		fmt.Println(printEvent(resp))
+	}
+}
+
+// event represents the fields all event types have in common.
+type event interface {
+	GetMetadata() *proto.Metadata
+}
+
+// eventMessage returns the underlying event stored within the response's oneof field.
+func eventMessage(resp *proto.ListEventsResponse) event {
+	message := resp.ProtoReflect()
+	field := message.WhichOneof(message.Descriptor().Oneofs().ByName(protoreflect.Name("event")))
+	if field == nil {
+		return nil
+	}
+
+	evt, ok := message.Get(field).Message().Interface().(event)
+	if !ok {
+		return nil
+	}
+
+	return evt
+}
+
+func (f *eventFilter) matches(kind proto.EventType, evt event) bool {
+	if len(f.kinds) > 0 {
+		if _, exists := f.kinds[kind]; !exists {
+			return false
+		}
+	}
+
+	if f.namespace != "" {
+		namespaced, ok := evt.(interface{ GetNamespaceId() string })
+		if !ok || namespaced.GetNamespaceId() != f.namespace {
+			return false
+		}
+	}
+
+	if f.pipeline != "" {
+		pipelined, ok := evt.(interface{ GetPipelineId() string })
+		if !ok || pipelined.GetPipelineId() != f.pipeline {
+			return false
+		}
+	}
+
+	return true
+}
@@ -0,0 +1,262 @@
+package fetch
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+	"google.golang.org/grpc/metadata"
+)
+
+var CmdFetch = &cobra.Command{
+	Use:   "fetch <pipeline> [run] [task_run]",
+	Short: "Get details on a pipeline, run or task run using partial identifiers",
+	Long: `Get details on a pipeline, run or task run using partial identifiers.
+
+Each identifier is fuzzy matched against the pipelines, runs and task runs within the namespace. An exact match is
+always preferred; otherwise identifiers that start with, contain, or have the given characters in order are matched.
+
+If more than one match exists you'll be asked to pick from a list of the closest matches. When not running in a
+terminal ambiguous identifiers produce an error instead.`,
+	Example: `$ gofer fetch simple
+$ gofer fetch simple 2
+$ gofer fetch smp_tst 23 trun`,
+	RunE: fetch,
+	Args: cobra.RangeArgs(1, 3),
+}
+
+// maxCandidates is the maximum amount of matches shown to the user when picking between them.
+const maxCandidates = 20
+
+func fetch(cmd *cobra.Command, args []string) error {
+	cl.State.Fmt.Print("Searching for matches")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	pipelineIDs, err := listPipelineIDs(ctx, client)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list pipelines: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	pipelineID, err := pick("pipeline", args[0], pipelineIDs)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if len(args) == 1 {
+		return runCommand(cmd, []string{"pipeline", "get"}, []string{pipelineID})
+	}
+
+	runsResp, err := client.ListRuns(ctx, &proto.ListRunsRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipelineID,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list runs: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	runIDs := []string{}
+	for _, run := range runsResp.Runs {
+		runIDs = append(runIDs, strconv.Itoa(int(run.Id)))
+	}
+
+	runID, err := pick("run", args[1], runIDs)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if len(args) == 2 {
+		return runCommand(cmd, []string{"run", "get"}, []string{pipelineID, runID})
+	}
+
+	id, _ := strconv.Atoi(runID)
+
+	taskRunsResp, err := client.ListTaskRuns(ctx, &proto.ListTaskRunsRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipelineID,
+		RunId:       int64(id),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list task runs: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	taskRunIDs := []string{}
+	for _, taskRun := range taskRunsResp.TaskRuns {
+		taskRunIDs = append(taskRunIDs, taskRun.Id)
+	}
+
+	taskRunID, err := pick("task run", args[2], taskRunIDs)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	return runCommand(cmd, []string{"taskrun", "get"}, []string{pipelineID, runID, taskRunID})
+}
+
+// runCommand hands off the now fully resolved identifiers to the command that normally displays them.
+func runCommand(cmd *cobra.Command, path, args []string) error {
+	target, _, err := cmd.Root().Find(path)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	return target.RunE(target, args)
+}
+
+func listPipelineIDs(ctx context.Context, client proto.GoferClient) ([]string, error) {
+	ids := []string{}
+	offset := 0
+
+	for {
+		resp, err := client.ListPipelines(ctx, &proto.ListPipelinesRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			Offset:      int64(offset),
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		for _, pipeline := range resp.Pipelines {
+			ids = append(ids, pipeline.Id)
+		}
+
+		if len(resp.Pipelines) == 0 {
+			return ids, nil
+		}
+
+		offset += len(resp.Pipelines)
+	}
+}
+
+// pick returns the candidate which best matches the query. If there is no single best match the user is asked to
+// choose between the closest ones.
+func pick(kind, query string, candidates []string) (string, error) {
+	matches := fuzzyMatch(query, candidates)
+
+	if len(matches) == 0 {
+		return "", fmt.Errorf("no %s matches %q", kind, query)
+	}
+
+	if len(matches) == 1 || strings.EqualFold(matches[0], query) {
+		return matches[0], nil
+	}
+
+	if len(matches) > maxCandidates {
+		matches = matches[:maxCandidates]
+	}
+
+	if cl.State.Config.Format != "pretty" || !term.IsTerminal(int(os.Stdin.Fd())) {
+		return "", fmt.Errorf("%q matches more than one %s: %s", query, kind, strings.Join(matches, ", "))
+	}
+
+	cl.State.Fmt.Finish()
+
+	fmt.Printf("Multiple %ss match %q:\n", kind, query)
+	for i, match := range matches {
+		fmt.Printf("  %d) %s\n", i+1, match)
+	}
+
+	var input string
+	fmt.Printf("Select a %s [1-%d]: ", kind, len(matches))
+	fmt.Scanln(&input)
+
+	cl.State.NewFormatter()
+
+	selection, err := strconv.Atoi(strings.TrimSpace(input))
+	if err != nil || selection < 1 || selection > len(matches) {
+		return "", fmt.Errorf("%q is not a valid selection", input)
+	}
+
+	return matches[selection-1], nil
+}
+
+// fuzzyMatch returns all candidates which match the query, ordered from best to worst match. Candidates that are an
+// exact match come first followed by those that start with the query, contain the query and lastly those that
+// contain the characters of the query in order.
+func fuzzyMatch(query string, candidates []string) []string {
+	type match struct {
+		candidate string
+		score     int
+	}
+
+	query = strings.ToLower(query)
+	matches := []match{}
+
+	for _, candidate := range candidates {
+		lower := strings.ToLower(candidate)
+
+		switch {
+		case lower == query:
+			matches = append(matches, match{candidate, 0})
+		case strings.HasPrefix(lower, query):
+			matches = append(matches, match{candidate, 1})
+		case strings.Contains(lower, query):
+			matches = append(matches, match{candidate, 2})
+		case isSubsequence(query, lower):
+			matches = append(matches, match{candidate, 3})
+		}
+	}
+
+	sort.SliceStable(matches, func(i, j int) bool {
+		if matches[i].score != matches[j].score {
+			return matches[i].score < matches[j].score
+		}
+		if len(matches[i].candidate) != len(matches[j].candidate) {
+			return len(matches[i].candidate) < len(matches[j].candidate)
+		}
+		return matches[i].candidate < matches[j].candidate
+	})
+
+	results := []string{}
+	for _, m := range matches {
+		results = append(results, m.candidate)
+	}
+
+	return results
+}
+
+// isSubsequence returns whether all characters of query appear in value in the same order.
+func isSubsequence(query, value string) bool {
+	remaining := []rune(query)
+	for _, char := range value {
+		if len(remaining) == 0 {
+			break
+		}
+		if char == remaining[0] {
+			remaining = remaining[1:]
+		}
+	}
+
+	return len(remaining) == 0
+}
@@ -0,0 +1,78 @@
+package format
+
+import (
+	"fmt"
+	"strings"
+)
+
+// ColumnName returns the name used to refer to a table column from the command line. (ex. "Last Run" -> "last_run")
+func ColumnName(header string) string {
+	return strings.ReplaceAll(strings.ToLower(header), " ", "_")
+}
+
+// SelectColumns narrows the table down to only the given columns, in the order they were given. If no columns are
+// given the table is returned unchanged.
+func SelectColumns(headers []string, rows [][]string, columns []string) ([]string, [][]string, error) {
+	if len(columns) == 0 {
+		return headers, rows, nil
+	}
+
+	indexes := []int{}
+	for _, column := range columns {
+		index := -1
+		for i, header := range headers {
+			if ColumnName(header) == ColumnName(column) {
+				index = i
+				break
+			}
+		}
+
+		if index == -1 {
+			return nil, nil, fmt.Errorf("column %q is not valid; accepted values are %s", column,
+				strings.Join(ColumnNames(headers), ", "))
+		}
+
+		indexes = append(indexes, index)
+	}
+
+	selectedHeaders := []string{}
+	for _, index := range indexes {
+		selectedHeaders = append(selectedHeaders, headers[index])
+	}
+
+	selectedRows := [][]string{}
+	for _, row := range rows {
+		selectedRow := []string{}
+		for _, index := range indexes {
+			selectedRow = append(selectedRow, row[index])
+		}
+		selectedRows = append(selectedRows, selectedRow)
+	}
+
+	return selectedHeaders, selectedRows, nil
+}
+
+// ColumnNames returns the command line names of all the given headers.
+func ColumnNames(headers []string) []string {
+	names := []string{}
+	for _, header := range headers {
+		names = append(names, ColumnName(header))
+	}
+
+	return names
+}
+
+// SortBy parses the value of a sort flag. The value must be one of the given keys and may be prefixed with '-' to
+// sort in descending order.
+func SortBy(value string, keys []string) (key string, descending bool, err error) {
+	key = ColumnName(strings.TrimPrefix(value, "-"))
+	descending = strings.HasPrefix(value, "-")
+
+	for _, k := range keys {
+		if k == key {
+			return key, descending, nil
+		}
+	}
+
+	return "", false, fmt.Errorf("cannot sort by %q; accepted values are %s", value, strings.Join(keys, ", "))
+}
@@ -0,0 +1,301 @@
+package login
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/hashicorp/hcl/v2"
+	"github.com/hashicorp/hcl/v2/hclwrite"
+	"github.com/spf13/cobra"
+	"github.com/zclconf/go-cty/cty"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/status"
+)
+
+var CmdLogin = &cobra.Command{
+	Use:   "login",
+	Short: "Log in through the identity provider configured for single sign-on",
+	Long: `Log in through the identity provider configured for single sign-on.
+
+Starts the OAuth device authorization flow against the identity provider the Gofer server is configured with. You'll
+be given a link and a code to confirm the login with in your browser; the browser is opened for you when possible.
+
+Once the login completes the ID token issued by the identity provider is exchanged for a short-lived Gofer token,
+which is saved as the token within the CLI configuration file(or within the current context if one is in use). How
+long the token lasts is decided by the server; run login again once it expires.
+
+The server must have single sign-on enabled; otherwise ask an administrator for a token instead.`,
+	Example: `$ gofer login
+$ gofer login --no-browser`,
+	RunE: login,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	CmdLogin.Flags().Bool("no-browser", false, "do not attempt to open the login page in a browser")
+}
+
+// deviceAuthorization is the identity provider's response to a device authorization request(RFC 8628).
+type deviceAuthorization struct {
+	DeviceCode              string `json:"device_code"`
+	UserCode                string `json:"user_code"`
+	VerificationURI         string `json:"verification_uri"`
+	VerificationURIComplete string `json:"verification_uri_complete"`
+	ExpiresIn               int64  `json:"expires_in"`
+	Interval                int64  `json:"interval"`
+}
+
+// tokenResponse is the identity provider's response to a token request; on failure only Error is set.
+type tokenResponse struct {
+	IDToken          string `json:"id_token"`
+	Error            string `json:"error"`
+	ErrorDescription string `json:"error_description"`
+}
+
+func login(cmd *cobra.Command, _ []string) error {
+	noBrowser, _ := cmd.Flags().GetBool("no-browser")
+	configFlag, _ := cmd.Flags().GetString("config")
+
+	cl.State.Fmt.Print("Retrieving login settings")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	authConfig, err := client.GetAuthConfig(context.Background(), &proto.GetAuthConfigRequest{})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not retrieve login settings: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if !authConfig.OidcEnabled {
+		err := fmt.Errorf("server does not have single sign-on enabled; ask an administrator for a token instead")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if authConfig.DeviceAuthorizationEndpoint == "" {
+		err := fmt.Errorf("identity provider %q does not support the device authorization flow", authConfig.Issuer)
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	httpClient := &http.Client{Timeout: 30 * time.Second}
+
+	device, err := requestDeviceCode(httpClient, authConfig)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not start login: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	loginURL := device.VerificationURIComplete
+	if loginURL == "" {
+		loginURL = device.VerificationURI
+	}
+
+	cl.State.Fmt.Println(fmt.Sprintf("Confirm the login at %s using the code %s",
+		color.BlueString(loginURL), color.YellowString(device.UserCode)))
+
+	if !noBrowser {
+		_ = openBrowser(loginURL)
+	}
+
+	cl.State.Fmt.Print("Waiting for login to complete")
+
+	idToken, err := pollForToken(httpClient, authConfig, device)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not log in: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Exchanging ID token")
+
+	token := idToken
+	expires := ""
+
+	exchanged, err := client.ExchangeOIDCToken(context.Background(), &proto.ExchangeOIDCTokenRequest{
+		IdToken: idToken,
+	})
+	switch {
+	case err == nil:
+		token = exchanged.Token
+		expires = format.UnixMilli(exchanged.Details.Expires, "Never", cl.State.Config.Detail)
+	case status.Code(err) == codes.Unimplemented:
+		// Servers that predate token exchange accept ID tokens directly.
+	default:
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not exchange ID token: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	path := config.CLIConfigPath(configFlag)
+	if path == "" {
+		cl.State.Fmt.PrintErr("could not find CLI configuration file to save the token to; create one with 'gofer config init'")
+		cl.State.Fmt.Finish()
+		return fmt.Errorf("could not find CLI configuration file")
+	}
+
+	err = setConfigToken(path, cl.State.Config.CurrentContext, token)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not save token to configuration file %q: %v", path, err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if expires != "" {
+		cl.State.Fmt.Println(fmt.Sprintf("Token expires %s", expires))
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Logged in; saved token to configuration file %q", path))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+// requestDeviceCode starts the device authorization flow.
+func requestDeviceCode(client *http.Client, authConfig *proto.GetAuthConfigResponse) (*deviceAuthorization, error) {
+	resp, err := client.PostForm(authConfig.DeviceAuthorizationEndpoint, url.Values{
+		"client_id": {authConfig.ClientId},
+		"scope":     {strings.Join(authConfig.Scopes, " ")},
+	})
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		errResp := tokenResponse{}
+		_ = json.NewDecoder(resp.Body).Decode(&errResp)
+		return nil, fmt.Errorf("unexpected status %q: %s %s", resp.Status, errResp.Error, errResp.ErrorDescription)
+	}
+
+	device := &deviceAuthorization{}
+	err = json.NewDecoder(resp.Body).Decode(device)
+	if err != nil {
+		return nil, err
+	}
+
+	return device, nil
+}
+
+// pollForToken polls the identity provider until the user completes the login and returns the issued ID token.
+func pollForToken(client *http.Client, authConfig *proto.GetAuthConfigResponse, device *deviceAuthorization) (string, error) {
+	interval := time.Duration(device.Interval) * time.Second
+	if interval == 0 {
+		interval = 5 * time.Second
+	}
+
+	expiresIn := time.Duration(device.ExpiresIn) * time.Second
+	if expiresIn == 0 {
+		expiresIn = 10 * time.Minute
+	}
+	deadline := time.Now().Add(expiresIn)
+
+	for time.Now().Before(deadline) {
+		time.Sleep(interval)
+
+		resp, err := client.PostForm(authConfig.TokenEndpoint, url.Values{
+			"grant_type":  {"urn:ietf:params:oauth:grant-type:device_code"},
+			"device_code": {device.DeviceCode},
+			"client_id":   {authConfig.ClientId},
+		})
+		if err != nil {
+			return "", err
+		}
+
+		token := tokenResponse{}
+		err = json.NewDecoder(resp.Body).Decode(&token)
+		resp.Body.Close()
+		if err != nil {
+			return "", fmt.Errorf("could not parse token response: %w", err)
+		}
+
+		switch token.Error {
+		case "":
+			if token.IDToken == "" {
+				return "", fmt.Errorf("identity provider did not return an ID token; make sure the 'openid' scope is requested")
+			}
+			return token.IDToken, nil
+		case "authorization_pending":
+			continue
+		case "slow_down":
+			interval += 5 * time.Second
+			continue
+		case "access_denied":
+			return "", fmt.Errorf("login was denied")
+		case "expired_token":
+			return "", fmt.Errorf("login expired before it was completed")
+		default:
+			return "", fmt.Errorf("%s: %s", token.Error, token.ErrorDescription)
+		}
+	}
+
+	return "", fmt.Errorf("login expired before it was completed")
+}
+
+// openBrowser attempts to open the given URL in the user's default browser.
+func openBrowser(target string) error {
+	switch runtime.GOOS {
+	case "darwin":
+		return exec.Command("open", target).Start()
+	case "windows":
+		return exec.Command("rundll32", "url.dll,FileProtocolHandler", target).Start()
+	default:
+		return exec.Command("xdg-open", target).Start()
+	}
+}
+
+// setConfigToken rewrites the token attribute of the given configuration file in place, leaving the rest of the file
+// untouched. If a context name is given the token of that context is set instead.
+func setConfigToken(path, contextName, token string) error {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return err
+	}
+
+	file, diags := hclwrite.ParseConfig(content, path, hcl.InitialPos)
+	if diags.HasErrors() {
+		return diags
+	}
+
+	body := file.Body()
+	if contextName != "" {
+		block := body.FirstMatchingBlock("context", []string{contextName})
+		if block == nil {
+			return fmt.Errorf("context %q not found in configuration file", contextName)
+		}
+		body = block.Body()
+	}
+
+	body.SetAttributeValue("token", cty.StringVal(token))
+
+	stat, err := os.Stat(path)
+	if err != nil {
+		return err
+	}
+
+	return os.WriteFile(path, file.Bytes(), stat.Mode())
+}
@@ -61,6 +61,11 @@ func namespaceCreate(cmd *cobra.Command, args []string) error {
 		cl.State.Fmt.Finish()
 		return err
 	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Namespace)
+	}
 	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Created namespace: [%s] %q", resp.Namespace.Id, resp.Namespace.Name))
 	cl.State.Fmt.Finish()
 	return nil
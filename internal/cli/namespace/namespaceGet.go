@@ -53,6 +53,11 @@ func namespaceGet(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Namespace)
+	}
+
 	output, err := formatNamespace(resp.Namespace, detail)
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not render namespace: %v", err))
@@ -66,19 +71,21 @@ func namespaceGet(cmd *cobra.Command, args []string) error {
 }
 
 type data struct {
-	ID          string
-	Name        string
-	Description string
-	Created     string
-	Deleted     string
+	ID             string
+	Name           string
+	Description    string
+	Created        string
+	Deleted        string
+	AllowAnonymous bool
 }
 
 func formatNamespace(namespace *proto.Namespace, detail bool) (string, error) {
 	data := data{
-		ID:          color.BlueString(namespace.Id),
-		Name:        namespace.Name,
-		Description: namespace.Description,
-		Created:     cliformat.UnixMilli(namespace.Created, "Never", detail),
+		ID:             color.BlueString(namespace.Id),
+		Name:           namespace.Name,
+		Description:    namespace.Description,
+		Created:        cliformat.UnixMilli(namespace.Created, "Never", detail),
+		AllowAnonymous: namespace.AllowAnonymous,
 	}
 
 	if namespace.Deleted != 0 {
@@ -88,7 +95,11 @@ func formatNamespace(namespace *proto.Namespace, detail bool) (string, error) {
 
 	const formatTmpl = `[{{.ID}}] {{.Name}} :: Created {{.Created}} {{if .Deleted}}:: {{.Deleted}}{{- end}}
 
-  {{.Description}}`
+  {{.Description}}
+{{- if .AllowAnonymous}}
+
+  Anonymous read-only access is allowed.
+{{- end}}`
 
 	var tpl bytes.Buffer
 	t := template.Must(template.New("tmp").Parse(formatTmpl))
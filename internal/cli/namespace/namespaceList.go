@@ -62,6 +62,11 @@ func namespaceList(cmd *cobra.Command, _ []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Namespaces)
+	}
+
 	data := [][]string{}
 	for _, namespace := range resp.Namespaces {
 		data = append(data, []string{
@@ -0,0 +1,26 @@
+package namespace
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdNamespaceQuota = &cobra.Command{
+	Use:   "quota",
+	Short: "Manage namespace resource quotas",
+	Long: `Manage namespace resource quotas.
+
+Quotas limit the amount of resources a single namespace can consume. The following resources can be limited:
+
+  - pipelines: The total amount of pipelines.
+  - runs per day: The amount of runs started within the last 24 hours.
+  - concurrent runs: The amount of runs in progress at the same time.
+  - object bytes: The total size of all pipeline and run objects.
+  - secrets: The total amount of secrets.
+
+A limit of zero means the resource is unlimited. Requests that would take a namespace over one of its limits are
+rejected; runs started by triggers are skipped instead. Current usage is shown by 'gofer namespace quota get'.`,
+}
+
+func init() {
+	CmdNamespace.AddCommand(CmdNamespaceQuota)
+}
@@ -0,0 +1,128 @@
+package namespace
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/dustin/go-humanize"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdNamespaceQuotaGet = &cobra.Command{
+	Use:   "get [id]",
+	Short: "Get the quota and current usage of a namespace",
+	Long: `Get the quota and current usage of a namespace.
+
+If no namespace id is given the namespace from your configuration is used.`,
+	Example: `$ gofer namespace quota get
+$ gofer namespace quota get new_namespace`,
+	RunE: namespaceQuotaGet,
+	Args: cobra.MaximumNArgs(1),
+}
+
+func init() {
+	CmdNamespaceQuota.AddCommand(cmdNamespaceQuotaGet)
+}
+
+func namespaceQuotaGet(cmd *cobra.Command, args []string) error {
+	id := cl.State.Config.Namespace
+	if len(args) > 0 {
+		id = args[0]
+	}
+
+	noColor, _ := cmd.Flags().GetBool("no-color")
+
+	cl.State.Fmt.Print("Retrieving namespace quota")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.GetNamespaceQuota(ctx, &proto.GetNamespaceQuotaRequest{
+		Id: id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get namespace quota: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp)
+	}
+
+	data := [][]string{
+		quotaRow("Pipelines", resp.Usage.Pipelines, resp.Quota.Pipelines, strconv.FormatInt),
+		quotaRow("Runs per day", resp.Usage.RunsPerDay, resp.Quota.RunsPerDay, strconv.FormatInt),
+		quotaRow("Concurrent runs", resp.Usage.ConcurrentRuns, resp.Quota.ConcurrentRuns, strconv.FormatInt),
+		quotaRow("Object bytes", resp.Usage.ObjectBytes, resp.Quota.ObjectBytes, func(i int64, _ int) string {
+			return humanize.Bytes(uint64(i))
+		}),
+		quotaRow("Secrets", resp.Usage.Secrets, resp.Quota.Secrets, strconv.FormatInt),
+	}
+
+	cl.State.Fmt.Println(formatQuotaTable(data, !noColor))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func quotaRow(resource string, usage, limit int64, format func(int64, int) string) []string {
+	limitStr := "Unlimited"
+	percent := ""
+	if limit > 0 {
+		limitStr = format(limit, 10)
+		percent = fmt.Sprintf("%d%%", usage*100/limit)
+	}
+
+	return []string{resource, format(usage, 10), limitStr, percent}
+}
+
+func formatQuotaTable(data [][]string, color bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Resource", "Usage", "Limit", "Used"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if color {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
@@ -0,0 +1,96 @@
+package namespace
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdNamespaceQuotaSet = &cobra.Command{
+	Use:   "set <id>",
+	Short: "Set the quota of a namespace",
+	Long: `Set the quota of a namespace.
+
+Only the limits passed as flags are changed; all other limits keep their current value. Setting a limit to zero
+removes it.
+
+Requires a management token.`,
+	Example: `$ gofer namespace quota set new_namespace --pipelines 50 --concurrent-runs 5
+$ gofer namespace quota set new_namespace --object-bytes 1073741824
+$ gofer namespace quota set new_namespace --runs-per-day 0`,
+	RunE: namespaceQuotaSet,
+	Args: cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdNamespaceQuotaSet.Flags().Int64("pipelines", 0, "maximum amount of pipelines")
+	cmdNamespaceQuotaSet.Flags().Int64("runs-per-day", 0, "maximum amount of runs started within 24 hours")
+	cmdNamespaceQuotaSet.Flags().Int64("concurrent-runs", 0, "maximum amount of runs in progress at the same time")
+	cmdNamespaceQuotaSet.Flags().Int64("object-bytes", 0, "maximum total size of all objects in bytes")
+	cmdNamespaceQuotaSet.Flags().Int64("secrets", 0, "maximum amount of secrets")
+	CmdNamespaceQuota.AddCommand(cmdNamespaceQuotaSet)
+}
+
+func namespaceQuotaSet(cmd *cobra.Command, args []string) error {
+	id := args[0]
+
+	cl.State.Fmt.Print("Updating namespace quota")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	// We grab the current quota first so that we only change the limits the user actually passed in.
+	current, err := client.GetNamespaceQuota(ctx, &proto.GetNamespaceQuotaRequest{
+		Id: id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get namespace quota: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	quota := current.Quota
+
+	limits := map[string]*int64{
+		"pipelines":       &quota.Pipelines,
+		"runs-per-day":    &quota.RunsPerDay,
+		"concurrent-runs": &quota.ConcurrentRuns,
+		"object-bytes":    &quota.ObjectBytes,
+		"secrets":         &quota.Secrets,
+	}
+
+	for flag, limit := range limits {
+		if !cmd.Flags().Changed(flag) {
+			continue
+		}
+
+		*limit, _ = cmd.Flags().GetInt64(flag)
+	}
+
+	_, err = client.UpdateNamespaceQuota(ctx, &proto.UpdateNamespaceQuotaRequest{
+		Id:    id,
+		Quota: quota,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not update namespace quota: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Updated quota for namespace %q", id))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -13,7 +13,9 @@ import (
 var cmdNamespaceUpdate = &cobra.Command{
 	Use:     "update <id>",
 	Short:   "Update details on a specific namespace",
-	Example: `$ gofer namespace update old_namespace --name="New name"`,
+	Example: `$ gofer namespace update old_namespace --name="New name"
+$ gofer namespace update public_namespace --allow-anonymous
+$ gofer namespace update scratch_namespace --object-expiry=72h`,
 	RunE:    namespaceUpdate,
 	Args:    cobra.ExactArgs(1),
 }
@@ -21,6 +23,10 @@ var cmdNamespaceUpdate = &cobra.Command{
 func init() {
 	cmdNamespaceUpdate.Flags().StringP("name", "n", "", "Human readable name for namespace")
 	cmdNamespaceUpdate.Flags().StringP("description", "d", "", "Description on use for namespace")
+	cmdNamespaceUpdate.Flags().Bool("allow-anonymous", false,
+		"Allow callers without a token to view the namespace's pipelines, runs and logs; requires the server to allow anonymous access")
+	cmdNamespaceUpdate.Flags().Duration("object-expiry", 0,
+		"How long pipeline objects are kept unless they were stored with an expiry of their own; 0 keeps them until they are evicted")
 	CmdNamespace.AddCommand(cmdNamespaceUpdate)
 }
 
@@ -34,6 +40,14 @@ func namespaceUpdate(cmd *cobra.Command, args []string) error {
 	if err != nil {
 		return err
 	}
+	allowAnonymous, err := cmd.Flags().GetBool("allow-anonymous")
+	if err != nil {
+		return err
+	}
+	objectExpiry, err := cmd.Flags().GetDuration("object-expiry")
+	if err != nil {
+		return err
+	}
 
 	cl.State.Fmt.Print("Retrieving namespace")
 
@@ -65,10 +79,21 @@ func namespaceUpdate(cmd *cobra.Command, args []string) error {
 		description = n.Namespace.Description
 	}
 
-	resp, err := client.UpdateNamespace(context.Background(), &proto.UpdateNamespaceRequest{
-		Id:          id,
-		Name:        name,
-		Description: description,
+	if !cmd.Flags().Changed("allow-anonymous") {
+		allowAnonymous = n.Namespace.AllowAnonymous
+	}
+
+	objectExpirySeconds := int64(objectExpiry.Seconds())
+	if !cmd.Flags().Changed("object-expiry") {
+		objectExpirySeconds = n.Namespace.ObjectExpiry
+	}
+
+	resp, err := client.UpdateNamespace(ctx, &proto.UpdateNamespaceRequest{
+		Id:             id,
+		Name:           name,
+		Description:    description,
+		AllowAnonymous: allowAnonymous,
+		ObjectExpiry:   objectExpirySeconds,
 	})
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not update namespace: %v", err))
@@ -50,6 +50,11 @@ func notifierGet(_ *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Notifier)
+	}
+
 	cl.State.Fmt.Println(formatNotifierInfo(notifierInfo{
 		Kind:          color.YellowString(resp.Notifier.Kind),
 		Image:         resp.Notifier.Image,
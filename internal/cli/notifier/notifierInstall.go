@@ -27,22 +27,47 @@ var cmdNotifierInstall = &cobra.Command{
 
 1) Gofer attempts to run the notifier container locally and connects the user's terminal to stdout/in/err
 2) The notifier container will walk the user through the installation steps required for the notifier
-3) The notifier container will attempt to install the notifier into Gofer on behalf of the user.`,
-	Example: `$ gofer notifier install log gchr.io/clintjedwards/gofer-containers/notifiers/log`,
-	RunE:    notifierInstall,
-	Args:    cobra.ExactArgs(3),
+3) The notifier container will attempt to install the notifier into Gofer on behalf of the user.
+
+Passing --from-manifest instead installs every notifier listed in the given HCL manifest file without any prompts.
+Notifiers that are already installed are reinstalled with the settings from the manifest. The manifest uses the same
+settings as the registered_notifiers block of the server configuration:
+
+  notifier "<kind>" {
+    image    = "<image>"
+    env_vars = {
+      "<key>": "<value>"
+    }
+  }`,
+	Example: `$ gofer notifier install log gchr.io/clintjedwards/gofer-containers/notifiers/log
+$ gofer notifier install --from-manifest extensions.hcl`,
+	RunE: notifierInstall,
+	Args: cobra.RangeArgs(0, 2),
 }
 
 func init() {
 	cmdNotifierInstall.Flags().StringP("host", "h", "", "URL of Gofer server")
 	cmdNotifierInstall.Flags().StringP("user", "u", "", "The username needed for authentication to docker image repository")
 	cmdNotifierInstall.Flags().StringP("pass", "p", "", "The password needed for authentication to docker image repository")
+	cmdNotifierInstall.Flags().String("from-manifest", "", "Install all notifiers listed in the given manifest file")
 	CmdNotifier.AddCommand(cmdNotifierInstall)
 }
 
 func notifierInstall(cmd *cobra.Command, args []string) error {
 	ctx := context.Background()
 
+	manifest, _ := cmd.Flags().GetString("from-manifest")
+	if manifest != "" {
+		return notifierInstallManifest(manifest)
+	}
+
+	if len(args) != 2 {
+		err := fmt.Errorf("requires a kind and image or the --from-manifest flag")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
 	kind := args[0]
 	image := args[1]
 
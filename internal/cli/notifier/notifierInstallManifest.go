@@ -0,0 +1,105 @@
+package notifier
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/proto"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+// notifierInstallManifest installs every notifier listed in the manifest. Notifiers that are already installed are
+// reinstalled so that they pick up the settings from the manifest.
+func notifierInstallManifest(path string) error {
+	cl.State.Fmt.Print("Reading manifest")
+
+	manifest, err := config.LoadExtensionManifest(path)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read manifest %q: %v", path, err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	failed := 0
+	for _, notifier := range manifest.Notifiers {
+		cl.State.Fmt.Print(fmt.Sprintf("Installing notifier %q", notifier.Kind))
+
+		action, err := installNotifier(ctx, client, notifier)
+		if err != nil {
+			failed++
+			cl.State.Fmt.Println(fmt.Sprintf("  ✗ %s: %v", notifier.Kind, err))
+			continue
+		}
+
+		cl.State.Fmt.Println(fmt.Sprintf("  ✓ %s: %s", notifier.Kind, action))
+	}
+
+	if len(manifest.Triggers) > 0 {
+		cl.State.Fmt.Println(fmt.Sprintf("\n  The manifest also lists %d trigger(s); install them with %q",
+			len(manifest.Triggers), "gofer trigger install --from-manifest "+path))
+	}
+
+	if failed > 0 {
+		err := fmt.Errorf("%d of %d notifiers could not be installed", failed, len(manifest.Notifiers))
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Installed %d notifier(s) from manifest", len(manifest.Notifiers)))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+// installNotifier installs the notifier, replacing it if a notifier of the same kind already exists. Returns a
+// description of what was done.
+func installNotifier(ctx context.Context, client proto.GoferClient, notifier config.Notifier) (string, error) {
+	request := &proto.InstallNotifierRequest{
+		Notifier: &proto.NotifierConfig{
+			Kind:    notifier.Kind,
+			Image:   notifier.Image,
+			User:    notifier.User,
+			Pass:    notifier.Pass,
+			EnvVars: notifier.EnvVars,
+		},
+	}
+
+	action := "installed"
+
+	_, err := client.GetNotifier(ctx, &proto.GetNotifierRequest{Kind: notifier.Kind})
+	if err != nil && status.Code(err) != codes.NotFound {
+		return "", err
+	}
+
+	if err == nil {
+		_, err = client.UninstallNotifier(ctx, &proto.UninstallNotifierRequest{Kind: notifier.Kind})
+		if err != nil {
+			return "", fmt.Errorf("could not remove previous install: %w", err)
+		}
+		action = "updated"
+	}
+
+	_, err = client.InstallNotifier(ctx, request)
+	if err != nil {
+		return "", err
+	}
+
+	return action, nil
+}
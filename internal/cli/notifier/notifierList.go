@@ -44,6 +44,11 @@ func notifierList(_ *cobra.Command, _ []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Notifiers)
+	}
+
 	data := [][]string{}
 	for _, notifier := range resp.Notifiers {
 		data = append(data, []string{
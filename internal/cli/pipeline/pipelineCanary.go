@@ -0,0 +1,21 @@
+package pipeline
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdPipelineCanary = &cobra.Command{
+	Use:   "canary",
+	Short: "Try out new pipeline configuration on some runs before rolling it out",
+	Long: `Try out new pipeline configuration on some runs before rolling it out.
+
+A canary registers a new config version for a pipeline without replacing the one it currently uses. Instead a
+percentage of new runs(or only runs started manually) use the tasks of the new version while the rest of the runs,
+including those started by triggers, keep using the current one. Once satisfied the canary can be promoted, which
+updates the pipeline to the new version, or aborted.
+`,
+}
+
+func init() {
+	CmdPipeline.AddCommand(CmdPipelineCanary)
+}
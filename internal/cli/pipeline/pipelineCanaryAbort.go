@@ -0,0 +1,60 @@
+package pipeline
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdPipelineCanaryAbort = &cobra.Command{
+	Use:   "abort <id>",
+	Short: "Stop a pipeline's canary",
+	Long: `Stop a pipeline's canary.
+
+All new runs use the pipeline's current config version again. Runs already using the canary's config version finish
+with it.`,
+	Example: `$ gofer pipeline canary abort simple_test_pipeline`,
+	RunE:    pipelineCanaryAbort,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdPipelineCanary.AddCommand(cmdPipelineCanaryAbort)
+}
+
+func pipelineCanaryAbort(_ *cobra.Command, args []string) error {
+	id := args[0]
+
+	cl.State.Fmt.Print("Aborting canary")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	_, err = client.AbortPipelineCanary(ctx, &proto.AbortPipelineCanaryRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not abort canary: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Aborted canary of pipeline [%s]", id))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
@@ -0,0 +1,93 @@
+package pipeline
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdPipelineCanaryPromote = &cobra.Command{
+	Use:   "promote <id>",
+	Short: "Update a pipeline to the config version its canary is trying out",
+	Long: `Update a pipeline to the config version its canary is trying out.
+
+Warning! Just like updating, promoting a canary requires disabling that pipeline and pausing all trigger events.
+This may cause those events while the canary is being promoted to be discarded.
+
+The promoted config is recorded as a new config version.`,
+	Example: `$ gofer pipeline canary promote simple_test_pipeline`,
+	RunE:    pipelineCanaryPromote,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdPipelineCanaryPromote.Flags().BoolP("force", "f", false, "Stop all runs and promote the canary immediately")
+	cmdPipelineCanaryPromote.Flags().BoolP("graceful-stop", "g", false,
+		"Stop all runs gracefully; sends a SIGTERM to all task runs for all in-progress runs and then waits for them to stop.")
+	CmdPipelineCanary.AddCommand(cmdPipelineCanaryPromote)
+}
+
+func pipelineCanaryPromote(cmd *cobra.Command, args []string) error {
+	id := args[0]
+
+	force, _ := cmd.Flags().GetBool("force")
+	gracefully, _ := cmd.Flags().GetBool("graceful-stop")
+
+	cl.State.Fmt.Print("Promoting canary")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	err = prepareForUpdate(ctx, client, id, force, gracefully)
+	if err != nil {
+		return err
+	}
+
+	cl.State.Fmt.Print("Promoting canary")
+
+	resp, err := client.PromotePipelineCanary(ctx, &proto.PromotePipelineCanaryRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not promote canary: %v", err))
+		cl.State.Fmt.Println(fmt.Sprintf("  The pipeline is still disabled; enable it with %s",
+			color.YellowString("gofer pipeline enable %s", id)))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Promoted canary of pipeline [%s] %q; it now uses config version %d",
+		resp.Pipeline.Id, resp.Pipeline.Name, resp.Pipeline.ConfigVersion))
+
+	cl.State.Fmt.Print("Enabling pipeline")
+
+	_, err = client.EnablePipeline(ctx, &proto.EnablePipelineRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not enable pipeline: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("Enabled pipeline")
+	cl.State.Fmt.Finish()
+
+	return nil
+}
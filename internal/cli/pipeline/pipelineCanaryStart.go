@@ -0,0 +1,95 @@
+package pipeline
+
+import (
+	"context"
+	"fmt"
+	"os"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdPipelineCanaryStart = &cobra.Command{
+	Use:   "start <id> <file>",
+	Short: "Start trying out a new pipeline config file on some of the pipeline's runs",
+	Long: `Start trying out a new pipeline config file on some of the pipeline's runs.
+
+Either --percentage or --manual-only must be given. Only the tasks of a run come from the new config; triggers and all
+other settings stay those of the current config until the canary is promoted. Unlike updating, starting a canary does
+not require disabling the pipeline.`,
+	Example: `$ gofer pipeline canary start simple_test_pipeline ./gofer/test.hcl --percentage 10
+$ gofer pipeline canary start simple_test_pipeline ./gofer/test.hcl --manual-only`,
+	RunE: pipelineCanaryStart,
+	Args: cobra.ExactArgs(2),
+}
+
+func init() {
+	cmdPipelineCanaryStart.Flags().Int64P("percentage", "p", 0, "The percentage of new runs that use the new config(1-100)")
+	cmdPipelineCanaryStart.Flags().Bool("manual-only", false, "Only runs started manually use the new config")
+	CmdPipelineCanary.AddCommand(cmdPipelineCanaryStart)
+}
+
+func pipelineCanaryStart(cmd *cobra.Command, args []string) error {
+	id := args[0]
+	input := args[1]
+
+	percentage, _ := cmd.Flags().GetInt64("percentage")
+	manualOnly, _ := cmd.Flags().GetBool("manual-only")
+
+	if manualOnly == (percentage != 0) {
+		err := fmt.Errorf("exactly one of --percentage or --manual-only must be given")
+		fmt.Println(err)
+		return err
+	}
+
+	cl.State.Fmt.Print("Starting canary")
+
+	file, err := os.ReadFile(input)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read file: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	resp, err := client.StartPipelineCanary(ctx, &proto.StartPipelineCanaryRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+		Content:     file,
+		Path:        input,
+		Percentage:  percentage,
+		ManualOnly:  manualOnly,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not start canary: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if manualOnly {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Started canary of config version %d for pipeline [%s]; manually started runs use it",
+			resp.Version, resp.Pipeline.Id))
+	} else {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Started canary of config version %d for pipeline [%s]; %d%% of runs use it",
+			resp.Version, resp.Pipeline.Id, percentage))
+	}
+	cl.State.Fmt.Println(fmt.Sprintf("  Promote it with %s or abort it with %s",
+		color.YellowString("gofer pipeline canary promote %s", id), color.YellowString("gofer pipeline canary abort %s", id)))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
@@ -0,0 +1,19 @@
+package pipeline
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdPipelineConfig = &cobra.Command{
+	Use:   "config",
+	Short: "Manage pipeline configuration versions",
+	Long: `Manage pipeline configuration versions.
+
+Every time a pipeline is created, updated or rolled back Gofer records the configuration it was registered with as a
+new version. Past versions can be listed and rolled back to.
+`,
+}
+
+func init() {
+	CmdPipeline.AddCommand(CmdPipelineConfig)
+}
@@ -0,0 +1,97 @@
+package pipeline
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdPipelineConfigList = &cobra.Command{
+	Use:   "list <pipeline>",
+	Short: "List all registered config versions of a pipeline",
+	Long: `List all registered config versions of a pipeline.
+
+Versions are listed newest first; the first version listed is the one the pipeline currently uses.`,
+	Example: `$ gofer pipeline config list simple_test_pipeline`,
+	RunE:    pipelineConfigList,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdPipelineConfigList.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	CmdPipelineConfig.AddCommand(cmdPipelineConfigList)
+}
+
+func pipelineConfigList(cmd *cobra.Command, args []string) error {
+	id := args[0]
+
+	limit, err := cmd.Flags().GetInt("limit")
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Retrieving config versions")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	resp, err := client.ListPipelineConfigVersions(ctx, &proto.ListPipelineConfigVersionsRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+		Limit:       int64(limit),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list config versions: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Versions)
+	}
+
+	if len(resp.Versions) == 0 {
+		cl.State.Fmt.Println("No config versions found")
+		cl.State.Fmt.Finish()
+		return nil
+	}
+
+	data := [][]string{}
+	for _, version := range resp.Versions {
+		number := strconv.FormatInt(version.Version, 10)
+		if version.Canary {
+			number += " (canary)"
+		}
+
+		data = append(data, []string{
+			number,
+			cliformat.UnixMilli(version.Created, "Unknown", cl.State.Config.Detail),
+			version.Location,
+			cliformat.SliceJoin(version.Tasks, "None"),
+		})
+	}
+
+	table := formatTable([]string{"Version", "Registered", "Location", "Tasks"}, data, !cl.State.Config.NoColor)
+
+	cl.State.PrintPaged(table)
+	cl.State.Fmt.Finish()
+
+	return nil
+}
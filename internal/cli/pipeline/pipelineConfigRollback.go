@@ -0,0 +1,110 @@
+package pipeline
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdPipelineConfigRollback = &cobra.Command{
+	Use:   "rollback <pipeline> [version]",
+	Short: "Roll back a pipeline to a previously registered config version",
+	Long: `Roll back a pipeline to a previously registered config version.
+
+Without a version the pipeline is rolled back to the version before the one it currently uses. Use
+"gofer pipeline config list" to see all available versions.
+
+Warning! Just like updating, rolling back a pipeline requires disabling that pipeline and pausing all trigger events.
+This may cause those events while the pipeline is being rolled back to be discarded.
+
+The rollback itself is recorded as a new config version.`,
+	Example: `$ gofer pipeline config rollback simple_test_pipeline
+$ gofer pipeline config rollback simple_test_pipeline 3`,
+	RunE: pipelineConfigRollback,
+	Args: cobra.RangeArgs(1, 2),
+}
+
+func init() {
+	cmdPipelineConfigRollback.Flags().BoolP("force", "f", false, "Stop all runs and roll back pipeline immediately")
+	cmdPipelineConfigRollback.Flags().BoolP("graceful-stop", "g", false,
+		"Stop all runs gracefully; sends a SIGTERM to all task runs for all in-progress runs and then waits for them to stop.")
+	CmdPipelineConfig.AddCommand(cmdPipelineConfigRollback)
+}
+
+func pipelineConfigRollback(cmd *cobra.Command, args []string) error {
+	id := args[0]
+
+	version := 0
+	if len(args) == 2 {
+		var err error
+		version, err = strconv.Atoi(args[1])
+		if err != nil || version < 1 {
+			err = fmt.Errorf("version %q must be a positive number", args[1])
+			fmt.Println(err)
+			return err
+		}
+	}
+
+	force, _ := cmd.Flags().GetBool("force")
+	gracefully, _ := cmd.Flags().GetBool("graceful-stop")
+
+	cl.State.Fmt.Print("Rolling back pipeline")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	err = prepareForUpdate(ctx, client, id, force, gracefully)
+	if err != nil {
+		return err
+	}
+
+	cl.State.Fmt.Print("Rolling back pipeline")
+
+	resp, err := client.RollbackPipelineConfig(ctx, &proto.RollbackPipelineConfigRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+		Version:     int64(version),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not roll back pipeline: %v", err))
+		cl.State.Fmt.Println(fmt.Sprintf("  The pipeline is still disabled; enable it with %s",
+			color.YellowString("gofer pipeline enable %s", id)))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Rolled back pipeline [%s] %q to config version %d",
+		resp.Pipeline.Id, resp.Pipeline.Name, resp.Version))
+
+	cl.State.Fmt.Print("Enabling pipeline")
+
+	_, err = client.EnablePipeline(ctx, &proto.EnablePipelineRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not enable pipeline: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("Enabled pipeline")
+	cl.State.Fmt.Finish()
+
+	return nil
+}
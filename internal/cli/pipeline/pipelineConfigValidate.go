@@ -0,0 +1,157 @@
+package pipeline
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/hashicorp/go-multierror"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+var cmdPipelineConfigValidate = &cobra.Command{
+	Use:   "validate <path>",
+	Short: "Check a pipeline config for problems without registering it",
+	Long: `Check a pipeline config for problems without registering it.
+
+The config is parsed and checked the same way 'gofer pipeline create' and 'gofer pipeline update' would; this includes
+identifiers, task dependencies that form a cycle and values using interpolation syntax Gofer won't fill in
+(ex. a misspelled secrets{{ key }}). Every problem found is listed.
+
+Secrets the config uses are then looked up on the server so that missing ones are found before a run needs them.
+Pass --offline to skip this.`,
+	Example: `$ gofer pipeline config validate myPipeline.hcl
+$ gofer pipeline config validate myPipeline.hcl --offline`,
+	RunE: pipelineConfigValidate,
+	Args: cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdPipelineConfigValidate.Flags().Bool("offline", false, "don't look up the config's secrets on the server")
+	CmdPipelineConfig.AddCommand(cmdPipelineConfigValidate)
+}
+
+func pipelineConfigValidate(cmd *cobra.Command, args []string) error {
+	path := args[0]
+	offline, _ := cmd.Flags().GetBool("offline")
+
+	cl.State.Fmt.Print(fmt.Sprintf("Validating pipeline config %q", path))
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read file: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	hclConfig := models.HCLPipelineConfig{}
+	err = hclConfig.FromBytes(content, path)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	var problems error
+
+	err = hclConfig.Validate()
+	if err != nil {
+		problems = multierror.Append(problems, err)
+	}
+
+	// The rest of the checks need the full config which can't be built if the HCL config itself is invalid.
+	if problems == nil {
+		config, err := models.FromHCL(&hclConfig)
+		if err != nil {
+			problems = multierror.Append(problems, err)
+		} else {
+			problems = multierror.Append(problems, validateInterpolations(config, offline)).ErrorOrNil()
+		}
+	}
+
+	if problems != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("Config %q has problems:", path))
+		if merr, ok := problems.(*multierror.Error); ok {
+			for _, err := range merr.Errors {
+				cl.State.Fmt.PrintErr("  " + err.Error())
+			}
+		}
+		cl.State.Fmt.Finish()
+		return fmt.Errorf("config %q has problems", path)
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Config %q is valid!", path))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+// validateInterpolations checks that every value written in interpolation syntax is filled in by Gofer and, unless
+// offline, that the secrets they refer to exist.
+func validateInterpolations(config *models.PipelineConfig, offline bool) error {
+	var result error
+
+	secrets := []models.Interpolation{}
+	for _, interpolation := range config.Interpolations() {
+		err := interpolation.Validate()
+		if err != nil {
+			result = multierror.Append(result, err)
+			continue
+		}
+
+		if interpolation.Prefix == "secret" {
+			secrets = append(secrets, interpolation)
+		}
+	}
+
+	if offline || len(secrets) == 0 {
+		return result
+	}
+
+	cl.State.Fmt.Print("Looking up secrets")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		return multierror.Append(result, fmt.Errorf("could not look up secrets: %w", err))
+	}
+	defer conn.Close()
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	// Pipelines registered without a namespace end up in the default namespace.
+	namespace := config.Namespace
+	if namespace == "" {
+		namespace = "default"
+	}
+
+	for _, secret := range secrets {
+		_, err := client.GetSecret(ctx, &proto.GetSecretRequest{
+			NamespaceId: namespace,
+			PipelineId:  config.ID,
+			Key:         secret.Key,
+		})
+		if err == nil {
+			continue
+		}
+
+		if status.Code(err) == codes.NotFound {
+			result = multierror.Append(result, fmt.Errorf("%s: secret %q does not exist; add it with "+
+				"'gofer pipeline secret put %s %s=<value>'", secret.Location, secret.Key, config.ID, secret.Key))
+			continue
+		}
+
+		// Looking up the rest of the secrets would most likely fail the same way.
+		return multierror.Append(result, errors.New("could not look up secrets: "+status.Convert(err).Message()))
+	}
+
+	return result
+}
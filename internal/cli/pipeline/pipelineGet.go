@@ -7,6 +7,7 @@ import (
 	"io"
 	"sort"
 	"strconv"
+	"strings"
 	"text/template"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
@@ -56,6 +57,11 @@ func pipelineGet(_ *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Pipeline)
+	}
+
 	output, err := formatPipeline(client, resp.Pipeline, cl.State.Config.Detail)
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not render pipeline: %v", err))
@@ -160,6 +166,27 @@ type data struct {
 	Created     string
 	LastRun     string
 	Location    string
+	Owner       string
+	Protected   bool
+	Owners      string
+	Version     int64
+	Canary      string
+	Deployment  string
+	Group       string
+	Schedules   []scheduleData
+	Upstreams   []upstreamData
+}
+
+type upstreamData struct {
+	Pipeline string
+	States   string
+}
+
+type scheduleData struct {
+	Label   string
+	Cron    string
+	NextDue string
+	LastDue string
 }
 
 type runData struct {
@@ -265,6 +292,38 @@ func formatPipeline(client proto.GoferClient, pipeline *proto.Pipeline, detail b
 
 	sort.Slice(notifierDataList, func(i, j int) bool { return notifierDataList[i].Label < notifierDataList[j].Label })
 
+	scheduleDataList := []scheduleData{}
+	for _, schedule := range pipeline.Schedules {
+		cron := schedule.Cron
+		if schedule.Timezone != "" {
+			cron = fmt.Sprintf("%s %s", cron, schedule.Timezone)
+		}
+
+		scheduleDataList = append(scheduleDataList, scheduleData{
+			Label:   color.BlueString(schedule.Label),
+			Cron:    color.YellowString(cron),
+			NextDue: format.UnixMilli(schedule.NextDue, "Never", detail),
+			LastDue: format.UnixMilli(schedule.LastDue, "Never", detail),
+		})
+	}
+
+	sort.Slice(scheduleDataList, func(i, j int) bool { return scheduleDataList[i].Label < scheduleDataList[j].Label })
+
+	upstreamDataList := []upstreamData{}
+	for _, upstream := range pipeline.Upstreams {
+		states := []string{}
+		for _, state := range upstream.States {
+			states = append(states, strings.ToLower(state.String()))
+		}
+
+		upstreamDataList = append(upstreamDataList, upstreamData{
+			Pipeline: color.BlueString(upstream.Pipeline),
+			States:   strings.Join(states, ", "),
+		})
+	}
+
+	sort.Slice(upstreamDataList, func(i, j int) bool { return upstreamDataList[i].Pipeline < upstreamDataList[j].Pipeline })
+
 	tasks := []taskData{}
 	for _, task := range pipeline.Tasks {
 		tasks = append(tasks, taskData{
@@ -290,6 +349,28 @@ func formatPipeline(client proto.GoferClient, pipeline *proto.Pipeline, detail b
 		Created:     format.UnixMilli(pipeline.Created, "Never", detail),
 		LastRun:     format.UnixMilli(pipeline.LastRunTime, "Never", detail),
 		Location:    pipeline.Location,
+		Owner:       pipeline.ServiceAccount,
+		Protected:   pipeline.Protected,
+		Owners:      format.SliceJoin(pipeline.Owners, "None"),
+		Version:     pipeline.ConfigVersion,
+		Group:       pipeline.ConcurrencyGroup,
+		Schedules:   scheduleDataList,
+		Upstreams:   upstreamDataList,
+	}
+
+	if pipeline.Canary != nil {
+		share := fmt.Sprintf("%d%% of runs", pipeline.Canary.Percentage)
+		if pipeline.Canary.ManualOnly {
+			share = "manually started runs"
+		}
+		data.Canary = fmt.Sprintf("config version %d for %s since %s", pipeline.Canary.Version, share,
+			format.UnixMilli(pipeline.Canary.Started, "", detail))
+	}
+
+	if pipeline.Deployment != nil {
+		data.Deployment = fmt.Sprintf("config version %d has %d of %d required successful runs; rolls back to version %d on failure",
+			pipeline.Deployment.Version, pipeline.Deployment.Successes, pipeline.Deployment.RequiredSuccesses,
+			pipeline.Deployment.PreviousVersion)
 	}
 
 	const formatTmpl = `[{{.ID}}] {{.Name}} :: {{.State}}
@@ -339,9 +420,50 @@ func formatPipeline(client proto.GoferClient, pipeline *proto.Pipeline, detail b
     {{- end}}
   {{- end}}
 
+  {{- if .Schedules }}
+
+  ⏲ Schedules:
+    {{- range $schedule := .Schedules}}
+    ⏱ {{ $schedule.Label }} ({{ $schedule.Cron }}) next due {{ $schedule.NextDue }}; last due {{ $schedule.LastDue }}
+    {{- end}}
+  {{- end}}
+
+  {{- if .Upstreams }}
+
+  ⇢ Runs After:
+    {{- range $upstream := .Upstreams}}
+    ↳ {{ $upstream.Pipeline }} finishes ({{ $upstream.States }})
+    {{- end}}
+  {{- end}}
+
 {{- if .Location }}
 
-  ☍ Config Location: {{.Location}}
+  ☍ Config Location: {{.Location}} {{- if .Version }} (version {{.Version}}) {{- end}}
+{{- end}}
+
+{{- if .Canary }}
+
+  🐤 Canary: {{.Canary}}
+{{- end}}
+
+{{- if .Deployment }}
+
+  ⛑ Deployment health: {{.Deployment}}
+{{- end}}
+
+{{- if .Owner }}
+
+  ⚙ Owned by service account: {{.Owner}}
+{{- end}}
+
+{{- if .Protected }}
+
+  🔒 Protected; owners: [{{.Owners}}]
+{{- end}}
+
+{{- if .Group }}
+
+  ⛓ Concurrency group: {{.Group}}
 {{- end}}
 
 Created {{.Created}} | Last Run {{.LastRun}} | Health {{.Health}}`
@@ -0,0 +1,258 @@
+package pipeline
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdPipelineGraph = &cobra.Command{
+	Use:   "graph <id>",
+	Short: "Render the task dependency graph of a pipeline",
+	Long: `Render the task dependency graph of a pipeline.
+
+The graph can be rendered as plain text(ascii) for quick viewing within the terminal or as Graphviz(dot) and
+Mermaid(mermaid) source for use in other tools.
+
+Passing the --status flag will overlay the state of each task from the pipeline's latest run.`,
+	Example: `$ gofer pipeline graph simple_test_pipeline
+$ gofer pipeline graph simple_test_pipeline --status
+$ gofer pipeline graph simple_test_pipeline --graph-format dot | dot -Tpng > graph.png`,
+	RunE: pipelineGraph,
+	Args: cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdPipelineGraph.Flags().String("graph-format", "ascii",
+		"graph output format; accepted values are 'ascii', 'dot', 'mermaid'")
+	cmdPipelineGraph.Flags().BoolP("status", "s", false, "overlay task statuses from the latest run")
+	CmdPipeline.AddCommand(cmdPipelineGraph)
+}
+
+func pipelineGraph(cmd *cobra.Command, args []string) error {
+	id := args[0]
+
+	graphFormat, _ := cmd.Flags().GetString("graph-format")
+	status, _ := cmd.Flags().GetBool("status")
+
+	switch graphFormat {
+	case "ascii", "dot", "mermaid":
+	default:
+		err := fmt.Errorf("graph format %q is not supported; accepted values are 'ascii', 'dot', 'mermaid'", graphFormat)
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Retrieving pipeline")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.GetPipeline(ctx, &proto.GetPipelineRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get pipeline: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	// states maps task ids to the state of their task run within the latest run.
+	states := map[string]string{}
+
+	if status && resp.Pipeline.LastRunId != 0 {
+		taskRunsResp, err := client.ListTaskRuns(ctx, &proto.ListTaskRunsRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			PipelineId:  resp.Pipeline.Id,
+			RunId:       resp.Pipeline.LastRunId,
+		})
+		if err != nil {
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not get task runs for latest run: %v", err))
+			cl.State.Fmt.Finish()
+			return err
+		}
+
+		for _, taskRun := range taskRunsResp.TaskRuns {
+			states[taskRun.Id] = taskRun.State.String()
+		}
+	}
+
+	var output string
+	switch graphFormat {
+	case "dot":
+		output = formatGraphDot(resp.Pipeline, states)
+	case "mermaid":
+		output = formatGraphMermaid(resp.Pipeline, states)
+	default:
+		output = formatGraphASCII(resp.Pipeline, states)
+	}
+
+	cl.State.Fmt.Println(output)
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+// graphLevels returns the tasks of a pipeline grouped by their depth within the graph. Tasks without dependencies
+// are at level zero; every other task sits one level below its deepest parent.
+func graphLevels(tasks map[string]*proto.Task) [][]string {
+	depths := map[string]int{}
+	visiting := map[string]bool{}
+
+	var depth func(id string) int
+	depth = func(id string) int {
+		if d, exists := depths[id]; exists {
+			return d
+		}
+
+		// Pipelines with dependency cycles are refused when registered, but a cycle must not send the recursion on
+		// forever either way. The task that closes the cycle is treated as if it had no dependencies.
+		if visiting[id] {
+			return 0
+		}
+		visiting[id] = true
+		defer delete(visiting, id)
+
+		deepest := 0
+		task, exists := tasks[id]
+		if exists {
+			for parent := range task.DependsOn {
+				if d := depth(parent) + 1; d > deepest {
+					deepest = d
+				}
+			}
+		}
+
+		depths[id] = deepest
+		return deepest
+	}
+
+	levels := [][]string{}
+	for id := range tasks {
+		d := depth(id)
+		for len(levels) <= d {
+			levels = append(levels, []string{})
+		}
+		levels[d] = append(levels[d], id)
+	}
+
+	for _, level := range levels {
+		sort.Strings(level)
+	}
+
+	return levels
+}
+
+// sortedParents returns the parents of a task in a stable order.
+func sortedParents(task *proto.Task) []string {
+	parents := []string{}
+	for parent := range task.DependsOn {
+		parents = append(parents, parent)
+	}
+	sort.Strings(parents)
+
+	return parents
+}
+
+func formatGraphASCII(pipeline *proto.Pipeline, states map[string]string) string {
+	var b strings.Builder
+
+	fmt.Fprintf(&b, "[%s] %s\n", color.BlueString(pipeline.Id), pipeline.Name)
+
+	for i, level := range graphLevels(pipeline.Tasks) {
+		fmt.Fprintf(&b, "\n  Stage %d\n", i+1)
+		for _, id := range level {
+			task := pipeline.Tasks[id]
+
+			line := fmt.Sprintf("    • %s", color.BlueString(id))
+			if state, exists := states[id]; exists {
+				line += " :: " + format.TaskRunState(state)
+			}
+			b.WriteString(line + "\n")
+
+			for _, parent := range sortedParents(task) {
+				fmt.Fprintf(&b, "        ↳ after %s (%s)\n", parent,
+					strings.ToLower(task.DependsOn[parent].String()))
+			}
+		}
+	}
+
+	return strings.TrimSuffix(b.String(), "\n")
+}
+
+func formatGraphDot(pipeline *proto.Pipeline, states map[string]string) string {
+	var b strings.Builder
+
+	fmt.Fprintf(&b, "digraph %q {\n", pipeline.Id)
+	b.WriteString("  rankdir=LR;\n")
+
+	for _, level := range graphLevels(pipeline.Tasks) {
+		for _, id := range level {
+			label := id
+			if state, exists := states[id]; exists {
+				label = fmt.Sprintf("%s\\n%s", id, state)
+			}
+			fmt.Fprintf(&b, "  %q [label=\"%s\"];\n", id, label)
+		}
+	}
+
+	for _, level := range graphLevels(pipeline.Tasks) {
+		for _, id := range level {
+			task := pipeline.Tasks[id]
+			for _, parent := range sortedParents(task) {
+				fmt.Fprintf(&b, "  %q -> %q [label=%q];\n", parent, id,
+					strings.ToLower(task.DependsOn[parent].String()))
+			}
+		}
+	}
+
+	b.WriteString("}")
+
+	return b.String()
+}
+
+func formatGraphMermaid(pipeline *proto.Pipeline, states map[string]string) string {
+	var b strings.Builder
+
+	b.WriteString("graph LR\n")
+
+	for _, level := range graphLevels(pipeline.Tasks) {
+		for _, id := range level {
+			label := id
+			if state, exists := states[id]; exists {
+				label = fmt.Sprintf("%s (%s)", id, state)
+			}
+			fmt.Fprintf(&b, "  %s[\"%s\"]\n", id, label)
+		}
+	}
+
+	for _, level := range graphLevels(pipeline.Tasks) {
+		for _, id := range level {
+			task := pipeline.Tasks[id]
+			for _, parent := range sortedParents(task) {
+				fmt.Fprintf(&b, "  %s -->|%s| %s\n", parent,
+					strings.ToLower(task.DependsOn[parent].String()), id)
+			}
+		}
+	}
+
+	return strings.TrimSuffix(b.String(), "\n")
+}
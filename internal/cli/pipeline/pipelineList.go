@@ -3,6 +3,7 @@ package pipeline
 import (
 	"context"
 	"fmt"
+	"sort"
 	"strings"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
@@ -25,16 +26,35 @@ Health shows a quick glimpse into how the last 5 builds performed.
   * Unstable = There is a failure in the last 5 builds.
   * Poor = Past 5 builds have all failed.
   * Good = Past 5 builds have all passed.
+
+The --columns flag controls which columns are shown and in which order. The --sort-by flag sorts the returned pipelines
+by the given column; prefix the column with '-' to sort in descending order.
 `,
-	Example: `$ gofer pipeline list`,
-	RunE:    pipelineList,
+	Example: `$ gofer pipeline list
+$ gofer pipeline list --columns id,state,last_run
+$ gofer pipeline list --sort-by -last_run`,
+	RunE: pipelineList,
 }
 
 func init() {
 	cmdPipelineList.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	cmdPipelineList.Flags().StringSlice("columns", []string{}, "only show the given columns (ex. id,name,state)")
+	cmdPipelineList.Flags().String("sort-by", "", "sort pipelines by the given column; prefix with '-' for descending order")
 	CmdPipeline.AddCommand(cmdPipelineList)
 }
 
+// pipelineHeaders are the columns shown by the pipeline list command.
+var pipelineHeaders = []string{"ID", "Name", "State", "Health", "Created", "Last Run", "Triggers"}
+
+// pipelineSorters are the columns pipelines can be sorted by.
+var pipelineSorters = map[string]func(a, b *proto.Pipeline) bool{
+	"id":       func(a, b *proto.Pipeline) bool { return a.Id < b.Id },
+	"name":     func(a, b *proto.Pipeline) bool { return a.Name < b.Name },
+	"state":    func(a, b *proto.Pipeline) bool { return a.State.String() < b.State.String() },
+	"created":  func(a, b *proto.Pipeline) bool { return a.Created < b.Created },
+	"last_run": func(a, b *proto.Pipeline) bool { return a.LastRunTime < b.LastRunTime },
+}
+
 func pipelineList(cmd *cobra.Command, _ []string) error {
 	cl.State.Fmt.Print("Retrieving pipelines")
 
@@ -45,6 +65,25 @@ func pipelineList(cmd *cobra.Command, _ []string) error {
 		return err
 	}
 
+	columns, _ := cmd.Flags().GetStringSlice("columns")
+	sortBy, _ := cmd.Flags().GetString("sort-by")
+
+	sortKeys := []string{}
+	for key := range pipelineSorters {
+		sortKeys = append(sortKeys, key)
+	}
+	sort.Strings(sortKeys)
+
+	sortKey, descending := "", false
+	if sortBy != "" {
+		sortKey, descending, err = cliformat.SortBy(sortBy, sortKeys)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
 	conn, err := cl.State.Connect()
 	if err != nil {
 		cl.State.Fmt.PrintErr(err)
@@ -66,12 +105,27 @@ func pipelineList(cmd *cobra.Command, _ []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Pipelines)
+	}
+
 	if len(resp.Pipelines) == 0 {
 		cl.State.Fmt.Println("No pipelines found")
 		cl.State.Fmt.Finish()
 		return err
 	}
 
+	if sortKey != "" {
+		less := pipelineSorters[sortKey]
+		sort.SliceStable(resp.Pipelines, func(i, j int) bool {
+			if descending {
+				return less(resp.Pipelines[j], resp.Pipelines[i])
+			}
+			return less(resp.Pipelines[i], resp.Pipelines[j])
+		})
+	}
+
 	data := [][]string{}
 	for _, pipeline := range resp.Pipelines {
 		triggerList := []string{}
@@ -102,19 +156,26 @@ func pipelineList(cmd *cobra.Command, _ []string) error {
 		})
 	}
 
-	table := formatTable(data, !cl.State.Config.NoColor)
+	headers, data, err := cliformat.SelectColumns(pipelineHeaders, data, columns)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	table := formatTable(headers, data, !cl.State.Config.NoColor)
 
-	cl.State.Fmt.Println(table)
+	cl.State.PrintPaged(table)
 	cl.State.Fmt.Finish()
 
 	return nil
 }
 
-func formatTable(data [][]string, color bool) string {
+func formatTable(headers []string, data [][]string, color bool) string {
 	tableString := &strings.Builder{}
 	table := tablewriter.NewWriter(tableString)
 
-	table.SetHeader([]string{"ID", "Name", "State", "Health", "Created", "Last Run", "Triggers"})
+	table.SetHeader(headers)
 	table.SetAlignment(tablewriter.ALIGN_LEFT)
 	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
 	table.SetHeaderLine(true)
@@ -126,24 +187,19 @@ func formatTable(data [][]string, color bool) string {
 	table.SetCenterSeparator("")
 
 	if color {
-		table.SetHeaderColor(
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-		)
-		table.SetColumnColor(
-			tablewriter.Color(tablewriter.FgYellowColor),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-		)
+		headerColors := []tablewriter.Colors{}
+		columnColors := []tablewriter.Colors{}
+		for _, header := range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+			if header == "ID" {
+				columnColors = append(columnColors, tablewriter.Color(tablewriter.FgYellowColor))
+				continue
+			}
+			columnColors = append(columnColors, tablewriter.Color(0))
+		}
+
+		table.SetHeaderColor(headerColors...)
+		table.SetColumnColor(columnColors...)
 	}
 
 	table.AppendBulk(data)
@@ -19,25 +19,46 @@ var cmdPipelineSecretPut = &cobra.Command{
 	Short: "Write a secret to the secret store",
 	Long: `Write a secret to the secret store.
 
-You can store both regular text values or read in entire files using the '@' prefix.
+You can store both regular text values or read in entire files using the '@' prefix or the --from-file flag.
+
+Passing '-' as the secret value reads the secret from stdin. This makes it easy to store multi-line secrets
+(ex. PEM keys) without needing to escape them.
 `,
 	Example: `$ gofer pipeline secret put simple_test_pipeline my_key=my_value
-$ gofer pipeline secret put simple_test_pipeline my_key=@/test/folder/file_path`,
+$ gofer pipeline secret put simple_test_pipeline my_key=@/test/folder/file_path
+$ gofer pipeline secret put simple_test_pipeline my_key --from-file /test/folder/file_path
+$ cat key.pem | gofer pipeline secret put simple_test_pipeline my_key -`,
 	RunE: pipelineSecretStorePut,
-	Args: cobra.ExactArgs(2),
+	Args: cobra.RangeArgs(2, 3),
 }
 
 func init() {
 	cmdPipelineSecretPut.Flags().BoolP("force", "f", false, "replace value if exists")
+	cmdPipelineSecretPut.Flags().String("from-file", "", "read the secret from the given file")
 	CmdPipelineSecret.AddCommand(cmdPipelineSecretPut)
 }
 
 func pipelineSecretStorePut(cmd *cobra.Command, args []string) error {
 	pipelineID := args[0]
-	keyValueStr := args[1]
 
-	key, value, ok := strings.Cut(keyValueStr, "=")
-	if !ok {
+	fromFile, _ := cmd.Flags().GetString("from-file")
+
+	key, value, ok := strings.Cut(args[1], "=")
+
+	switch {
+	case fromFile != "":
+		if ok || len(args) > 2 {
+			fmt.Println("A secret value cannot be given along with the --from-file flag")
+			return fmt.Errorf("a secret value cannot be given along with the --from-file flag")
+		}
+		value = "@" + fromFile
+	case len(args) > 2:
+		if ok {
+			fmt.Println("Key-value pair malformed; should be in format: <key>=<value>")
+			return fmt.Errorf("Key-value pair malformed; should be in format <key>=<value>")
+		}
+		value = args[2]
+	case !ok:
 		fmt.Println("Key-value pair malformed; should be in format: <key>=<value>")
 		return fmt.Errorf("Key-value pair malformed; should be in format <key>=<value>")
 	}
@@ -49,7 +70,14 @@ func pipelineSecretStorePut(cmd *cobra.Command, args []string) error {
 	}
 
 	secret := bytes.NewBuffer([]byte{})
-	if strings.HasPrefix(value, "@") {
+	switch {
+	case value == "-":
+		if _, err = io.Copy(secret, os.Stdin); err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+	case strings.HasPrefix(value, "@"):
 		file, err := os.Open(value[1:])
 		if err != nil {
 			cl.State.Fmt.PrintErr(err)
@@ -62,11 +90,11 @@ func pipelineSecretStorePut(cmd *cobra.Command, args []string) error {
 			cl.State.Fmt.Finish()
 			return err
 		}
-	} else {
+	default:
 		secret.WriteString(value)
 	}
 
-	cl.State.Fmt.Print("Uploading object")
+	cl.State.Fmt.Print("Uploading secret")
 
 	conn, err := cl.State.Connect()
 	if err != nil {
@@ -87,7 +115,7 @@ func pipelineSecretStorePut(cmd *cobra.Command, args []string) error {
 		Force:       force,
 	})
 	if err != nil {
-		cl.State.Fmt.PrintErr(fmt.Sprintf("could not upload object: %v", err))
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not upload secret: %v", err))
 		cl.State.Fmt.Finish()
 		return err
 	}
@@ -2,67 +2,155 @@ package pipeline
 
 import (
 	"context"
-	"encoding/binary"
 	"fmt"
+	"io"
 	"os"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
 	"github.com/clintjedwards/gofer/proto"
+	"github.com/dustin/go-humanize"
 	"github.com/spf13/cobra"
 	"google.golang.org/grpc/metadata"
 )
 
 var cmdPipelineStoreGet = &cobra.Command{
-	Use:     "get <pipeline_id> <key>",
-	Short:   "Read an object from the pipeline store",
-	Example: `$ gofer pipeline store get simple_test_pipeline my_key`,
-	RunE:    pipelineStoreGet,
-	Args:    cobra.ExactArgs(2),
+	Use:   "get <pipeline_id> <key>",
+	Short: "Read an object from the pipeline store",
+	Long: `Read an object from the pipeline store.
+
+Objects are streamed from Gofer in chunks so they never need to fit in memory. By default the object is written to
+stdout; use '--file' to write it to a file instead. If the connection is reset the download is automatically resumed
+from where it left off, and '--resume' finishes a download to a file that was interrupted previously.
+`,
+	Example: `$ gofer pipeline store get simple_test_pipeline my_key
+$ gofer pipeline store get simple_test_pipeline my_cache --file=/tmp/cache.tar.gz
+$ gofer pipeline store get simple_test_pipeline my_cache --file=/tmp/cache.tar.gz --resume`,
+	RunE: pipelineStoreGet,
+	Args: cobra.ExactArgs(2),
 }
 
 func init() {
 	cmdPipelineStoreGet.Flags().BoolP("stringify", "s", false, "Attempt to print the object as a string")
+	_ = cmdPipelineStoreGet.Flags().MarkDeprecated("stringify", "objects are always written exactly as they are stored")
+	cmdPipelineStoreGet.Flags().StringP("file", "f", "", "Write the object to the given file instead of stdout")
+	cmdPipelineStoreGet.Flags().Bool("resume", false, "Continue an interrupted download into the file given by '--file'")
 	CmdPipelineStore.AddCommand(cmdPipelineStoreGet)
 }
 
 func pipelineStoreGet(cmd *cobra.Command, args []string) error {
-	// We don't use the formatter here because we may want to redirect the object we get into
-	// a file or similar situation.
-	cl.State.Fmt.Finish()
 	pipelineID := args[0]
 	key := args[1]
 
-	conn, err := cl.State.Connect()
+	path, err := cmd.Flags().GetString("file")
 	if err != nil {
 		fmt.Println(err)
 		return err
 	}
 
+	resume, err := cmd.Flags().GetBool("resume")
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+
+	if resume && path == "" {
+		fmt.Println("--resume can only be used along with --file")
+		return fmt.Errorf("--resume can only be used along with --file")
+	}
+
+	// We don't use the formatter when writing to stdout because we may want to redirect the object we get into
+	// a file or similar situation.
+	if path == "" {
+		cl.State.Fmt.Finish()
+	}
+
+	var destination io.Writer = os.Stdout
+	offset := int64(0)
+
+	if path != "" {
+		flags := os.O_CREATE | os.O_WRONLY | os.O_TRUNC
+		if resume {
+			flags = os.O_CREATE | os.O_WRONLY | os.O_APPEND
+		}
+
+		file, err := os.OpenFile(path, flags, 0o644)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+		defer file.Close()
+
+		if resume {
+			info, err := file.Stat()
+			if err != nil {
+				cl.State.Fmt.PrintErr(err)
+				cl.State.Fmt.Finish()
+				return err
+			}
+			offset = info.Size()
+		}
+
+		destination = file
+		cl.State.Fmt.Print("Downloading object")
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		if path == "" {
+			fmt.Println(err)
+			return err
+		}
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
 	client := proto.NewGoferClient(conn)
 
 	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
 	ctx := metadata.NewOutgoingContext(context.Background(), md)
-	resp, err := client.GetPipelineObject(ctx, &proto.GetPipelineObjectRequest{
-		NamespaceId: cl.State.Config.Namespace,
-		PipelineId:  pipelineID,
-		Key:         key,
+
+	received, err := cl.State.Download(destination, offset, path != "", func(offset int64) (cl.Downloader, error) {
+		stream, err := client.GetPipelineObjectStream(ctx, &proto.GetPipelineObjectStreamRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			PipelineId:  pipelineID,
+			Key:         key,
+			Offset:      offset,
+		})
+		if err != nil {
+			return nil, err
+		}
+		return &pipelineObjectDownloader{stream: stream}, nil
 	})
 	if err != nil {
-		fmt.Printf("could not read object: %v\n", err)
+		if path == "" {
+			fmt.Printf("could not read object: %v\n", err)
+			return err
+		}
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not download object: %v", err))
+		cl.State.Fmt.Finish()
 		return err
 	}
 
-	stringify, err := cmd.Flags().GetBool("stringify")
-	if err != nil {
-		fmt.Println(err)
-		return err
+	if path != "" {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Downloaded %s to %s", humanize.Bytes(uint64(received)), path))
+		cl.State.Fmt.Finish()
 	}
 
-	if stringify {
-		fmt.Printf("%s", resp.Content)
-	} else {
-		_ = binary.Write(os.Stdout, binary.LittleEndian, resp.Content)
+	return nil
+}
+
+// pipelineObjectDownloader receives a pipeline object from the server in chunks.
+type pipelineObjectDownloader struct {
+	stream proto.Gofer_GetPipelineObjectStreamClient
+}
+
+func (d *pipelineObjectDownloader) Recv() ([]byte, int64, error) {
+	resp, err := d.stream.Recv()
+	if err != nil {
+		return nil, 0, err
 	}
 
-	return nil
+	return resp.Content, resp.Size, nil
 }
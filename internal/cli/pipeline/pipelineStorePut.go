@@ -1,7 +1,6 @@
 package pipeline
 
 import (
-	"bytes"
 	"context"
 	"fmt"
 	"io"
@@ -23,16 +22,23 @@ The pipeline store allows storage of objects as key-values pairs that many runs
 level objects are kept forever until the limit of number of pipeline objects is reached(this may be different depending
 on configuration). Once this limit is reached the _oldest_ object will be removed to make space for the new object.
 
-You can store both regular text values or read in entire files using the '@' prefix.
+Objects can also be given an expiry with '--expires-after', after which Gofer removes them on its own. Objects stored
+without one use their namespace's object expiry, if it has one.
+
+You can store both regular text values or read in entire files using the '@' prefix. Files are streamed to Gofer in
+chunks so they never need to fit in memory; if the connection is reset the upload is automatically retried.
 `,
 	Example: `$ gofer pipeline store put simple_test_pipeline my_key=my_value
-$ gofer pipeline store put simple_test_pipeline my_key=@/test/folder/file_path`,
+$ gofer pipeline store put simple_test_pipeline my_key=@/test/folder/file_path
+$ gofer pipeline store put simple_test_pipeline my_key=my_value --expires-after=24h`,
 	RunE: pipelineStorePut,
 	Args: cobra.ExactArgs(2),
 }
 
 func init() {
 	cmdPipelineStorePut.Flags().BoolP("force", "f", false, "replace value if exists")
+	cmdPipelineStorePut.Flags().Duration("expires-after", 0,
+		"remove the object after this long; defaults to the namespace's object expiry and a negative value never expires")
 	CmdPipelineStore.AddCommand(cmdPipelineStorePut)
 }
 
@@ -51,7 +57,23 @@ func pipelineStorePut(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
-	object := bytes.NewBuffer([]byte{})
+	expiresAfter, err := cmd.Flags().GetDuration("expires-after")
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+
+	// Sub-second expiries would be rounded down to the namespace default.
+	expiresAfterSeconds := int64(expiresAfter.Seconds())
+	if expiresAfter > 0 && expiresAfterSeconds == 0 {
+		expiresAfterSeconds = 1
+	}
+	if expiresAfter < 0 {
+		expiresAfterSeconds = -1
+	}
+
+	var object io.ReadSeeker
+	size := int64(len(value))
 	if strings.HasPrefix(value, "@") {
 		file, err := os.Open(value[1:])
 		if err != nil {
@@ -60,13 +82,18 @@ func pipelineStorePut(cmd *cobra.Command, args []string) error {
 			return err
 		}
 		defer file.Close()
-		if _, err = io.Copy(object, file); err != nil {
+
+		info, err := file.Stat()
+		if err != nil {
 			cl.State.Fmt.PrintErr(err)
 			cl.State.Fmt.Finish()
 			return err
 		}
+
+		object = file
+		size = info.Size()
 	} else {
-		object.WriteString(value)
+		object = strings.NewReader(value)
 	}
 
 	cl.State.Fmt.Print("Uploading object")
@@ -82,12 +109,24 @@ func pipelineStorePut(cmd *cobra.Command, args []string) error {
 
 	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
 	ctx := metadata.NewOutgoingContext(context.Background(), md)
-	resp, err := client.PutPipelineObject(ctx, &proto.PutPipelineObjectRequest{
-		NamespaceId: cl.State.Config.Namespace,
-		PipelineId:  pipelineID,
-		Key:         key,
-		Content:     object.Bytes(),
-		Force:       force,
+
+	uploader := &pipelineObjectUploader{
+		request: &proto.PutPipelineObjectRequest{
+			NamespaceId:  cl.State.Config.Namespace,
+			PipelineId:   pipelineID,
+			Key:          key,
+			Force:        force,
+			ExpiresAfter: expiresAfterSeconds,
+		},
+	}
+	err = cl.State.Upload(object, size, func() (cl.Uploader, error) {
+		stream, err := client.PutPipelineObjectStream(ctx)
+		if err != nil {
+			return nil, err
+		}
+		uploader.stream = stream
+		uploader.sent = false
+		return uploader, nil
 	})
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not upload object: %v", err))
@@ -95,6 +134,7 @@ func pipelineStorePut(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
+	resp := uploader.resp
 	if resp.ObjectEvicted != "" {
 		cl.State.Fmt.Println(fmt.Sprintf("Object evicted due to pipeline object limit(%d): %q", resp.ObjectLimit, resp.ObjectEvicted))
 	}
@@ -104,3 +144,32 @@ func pipelineStorePut(cmd *cobra.Command, args []string) error {
 
 	return nil
 }
+
+// pipelineObjectUploader sends a pipeline object to the server in chunks; only the first message of each attempt
+// includes the object's metadata.
+type pipelineObjectUploader struct {
+	request *proto.PutPipelineObjectRequest
+	stream  proto.Gofer_PutPipelineObjectStreamClient
+	resp    *proto.PutPipelineObjectResponse
+	sent    bool
+}
+
+func (u *pipelineObjectUploader) Send(chunk []byte) error {
+	if u.sent {
+		return u.stream.Send(&proto.PutPipelineObjectRequest{Content: chunk})
+	}
+
+	u.sent = true
+	u.request.Content = chunk
+	return u.stream.Send(u.request)
+}
+
+func (u *pipelineObjectUploader) Close() error {
+	resp, err := u.stream.CloseAndRecv()
+	if err != nil {
+		return err
+	}
+
+	u.resp = resp
+	return nil
+}
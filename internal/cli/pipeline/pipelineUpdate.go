@@ -31,10 +31,17 @@ at a single file or folder will both work.
 
 Remote configuration file syntax is based off hashicorp's go-getter syntax(https://github.com/hashicorp/go-getter#protocol-specific-options).
 Allowing the user to use many remote protocols and pass in options.
+
+Passing --diff compares a local configuration file with the live configuration of the pipeline before anything is
+changed. Settings that changed and tasks, triggers, notifiers, schedules and upstreams that were added(+),
+removed(-) or changed(~) are listed and you'll be asked to confirm the update. Pass --yes to skip the confirmation
+(ex. within CI).
 `,
 	Example: `$ gofer pipeline update aup3gq github.com/clintjedwards/gofer.git//gofer
 $ gofer pipeline update simple_test_pipeline somefile.hcl
-$ gofer pipeline update simple_test_pipeline ./gofer/test.hcl`,
+$ gofer pipeline update simple_test_pipeline ./gofer/test.hcl
+$ gofer pipeline update simple_test_pipeline ./gofer/test.hcl --diff
+$ gofer pipeline update simple_test_pipeline ./gofer/test.hcl --diff --yes`,
 	RunE: pipelineUpdate,
 	Args: cobra.ExactArgs(2),
 }
@@ -43,6 +50,9 @@ func init() {
 	cmdPipelineUpdate.Flags().BoolP("force", "f", false, "Stop all runs and update pipeline immediately")
 	cmdPipelineUpdate.Flags().BoolP("graceful-stop", "g", false,
 		"Stop all runs gracefully; sends a SIGTERM to all task runs for all in-progress runs and then waits for them to stop.")
+	cmdPipelineUpdate.Flags().Bool("diff", false,
+		"Show how the config differs from the live config and ask for confirmation before updating")
+	cmdPipelineUpdate.Flags().BoolP("yes", "y", false, "Skip the confirmation prompt when using --diff")
 	CmdPipeline.AddCommand(cmdPipelineUpdate)
 }
 
@@ -61,6 +71,9 @@ func pipelineUpdate(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
+	showDiff, _ := cmd.Flags().GetBool("diff")
+	yes, _ := cmd.Flags().GetBool("yes")
+
 	cl.State.Fmt.Print("Updating pipeline")
 
 	conn, err := cl.State.Connect()
@@ -72,70 +85,25 @@ func pipelineUpdate(cmd *cobra.Command, args []string) error {
 
 	client := proto.NewGoferClient(conn)
 
-	cl.State.Fmt.Print("Disabling pipeline")
-
 	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
 	ctx := metadata.NewOutgoingContext(context.Background(), md)
-	_, err = client.DisablePipeline(ctx, &proto.DisablePipelineRequest{
-		NamespaceId: cl.State.Config.Namespace,
-		Id:          id,
-	})
-	if err != nil {
-		cl.State.Fmt.PrintErr(fmt.Sprintf("could not disable pipeline: %v", err))
-		cl.State.Fmt.Finish()
-		return err
-	}
-
-	cl.State.Fmt.PrintSuccess("Disabled pipeline")
-
-	if force {
-		cl.State.Fmt.Print("Force cancelling pipeline runs")
 
-		_, err = client.CancelAllRuns(ctx, &proto.CancelAllRunsRequest{PipelineId: id, Force: true})
+	// The diff is shown before the pipeline is disabled so that declining the changes leaves the pipeline untouched.
+	if showDiff {
+		proceed, err := confirmPipelineDiff(ctx, client, id, input, yes)
 		if err != nil {
-			cl.State.Fmt.PrintErr(fmt.Sprintf("could not force cancel pipeline: %v", err))
-			cl.State.Fmt.Finish()
 			return err
 		}
-	}
-
-	if gracefully {
-		cl.State.Fmt.Print("Cancelling pipeline runs")
-
-		_, err = client.CancelAllRuns(ctx, &proto.CancelAllRunsRequest{PipelineId: id, Force: false})
-		if err != nil {
-			cl.State.Fmt.PrintErr(fmt.Sprintf("could not gracefully cancel pipeline: %v", err))
-			cl.State.Fmt.Finish()
-			return err
+		if !proceed {
+			return nil
 		}
 	}
 
-	cl.State.Fmt.Print("Waiting for in-progress runs to stop")
-	for {
-		resp, err := client.ListRuns(ctx, &proto.ListRunsRequest{PipelineId: id, Offset: 0, Limit: 10})
-		if err != nil {
-			cl.State.Fmt.PrintErr(fmt.Sprintf("could not get run list for pipeline: %v", err))
-			time.Sleep(time.Second * 3)
-			continue
-		}
-
-		hasRunningJob := false
-		for _, run := range resp.Runs {
-			if run.State != proto.Run_FAILED && run.State != proto.Run_CANCELLED && run.State != proto.Run_SUCCESS {
-				hasRunningJob = true
-			}
-		}
-
-		if hasRunningJob {
-			time.Sleep(time.Second * 5)
-			continue
-		}
-
-		break
+	err = prepareForUpdate(ctx, client, id, force, gracefully)
+	if err != nil {
+		return err
 	}
 
-	cl.State.Fmt.PrintSuccess("Checked for runs in-progress")
-
 	cl.State.Fmt.Print("Updating pipeline")
 	if strings.HasSuffix(strings.ToLower(input), ".hcl") {
 		file, err := os.ReadFile(input)
@@ -202,3 +170,71 @@ func pipelineUpdate(cmd *cobra.Command, args []string) error {
 
 	return nil
 }
+
+// prepareForUpdate disables the pipeline, optionally cancels its runs, and waits until no runs are in progress so that
+// the pipeline's configuration can be changed. Errors are printed before being returned.
+func prepareForUpdate(ctx context.Context, client proto.GoferClient, id string, force, gracefully bool) error {
+	cl.State.Fmt.Print("Disabling pipeline")
+
+	_, err := client.DisablePipeline(ctx, &proto.DisablePipelineRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not disable pipeline: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("Disabled pipeline")
+
+	if force {
+		cl.State.Fmt.Print("Force cancelling pipeline runs")
+
+		_, err = client.CancelAllRuns(ctx, &proto.CancelAllRunsRequest{PipelineId: id, Force: true})
+		if err != nil {
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not force cancel pipeline: %v", err))
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
+	if gracefully {
+		cl.State.Fmt.Print("Cancelling pipeline runs")
+
+		_, err = client.CancelAllRuns(ctx, &proto.CancelAllRunsRequest{PipelineId: id, Force: false})
+		if err != nil {
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not gracefully cancel pipeline: %v", err))
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
+	cl.State.Fmt.Print("Waiting for in-progress runs to stop")
+	for {
+		resp, err := client.ListRuns(ctx, &proto.ListRunsRequest{PipelineId: id, Offset: 0, Limit: 10})
+		if err != nil {
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not get run list for pipeline: %v", err))
+			time.Sleep(time.Second * 3)
+			continue
+		}
+
+		hasRunningJob := false
+		for _, run := range resp.Runs {
+			if run.State != proto.Run_FAILED && run.State != proto.Run_CANCELLED && run.State != proto.Run_SUCCESS {
+				hasRunningJob = true
+			}
+		}
+
+		if hasRunningJob {
+			time.Sleep(time.Second * 5)
+			continue
+		}
+
+		break
+	}
+
+	cl.State.Fmt.PrintSuccess("Checked for runs in-progress")
+
+	return nil
+}
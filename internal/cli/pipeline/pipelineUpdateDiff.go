@@ -0,0 +1,248 @@
+package pipeline
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"reflect"
+	"sort"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"google.golang.org/protobuf/encoding/protojson"
+)
+
+// diffSettings are the pipeline settings that come from its config; all other settings are managed by Gofer.
+var diffSettings = []string{
+	"name", "description", "sequential", "owners", "protected", "notification_policy", "recovery_policy",
+	"deployment_health", "concurrency_group",
+}
+
+// diffCollections are the parts of a pipeline config made up of labeled items. Fields of those items which are
+// managed by Gofer instead of the config are ignored.
+var diffCollections = []struct {
+	name    string
+	title   string
+	ignored []string
+}{
+	{name: "tasks", title: "Tasks"},
+	{name: "triggers", title: "Triggers", ignored: []string{"state"}},
+	{name: "notifiers", title: "Notifiers"},
+	{name: "schedules", title: "Schedules", ignored: []string{"last_due", "next_due"}},
+	{name: "upstreams", title: "Upstreams"},
+}
+
+// confirmPipelineDiff shows how the config at path differs from the live config of the pipeline and asks the user
+// whether to go through with the update, unless yes is set. Errors are printed before being returned.
+func confirmPipelineDiff(ctx context.Context, client proto.GoferClient, id, path string, yes bool) (bool, error) {
+	if !strings.HasSuffix(strings.ToLower(path), ".hcl") {
+		err := errors.New("--diff only supports local config files")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return false, err
+	}
+
+	cl.State.Fmt.Print("Comparing config with the live pipeline")
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read file: %v", err))
+		cl.State.Fmt.Finish()
+		return false, err
+	}
+
+	hclConfig := models.HCLPipelineConfig{}
+	err = hclConfig.FromBytes(content, path)
+	if err == nil {
+		err = hclConfig.Validate()
+	}
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse config: %v", err))
+		cl.State.Fmt.Finish()
+		return false, err
+	}
+
+	config, err := models.FromHCL(&hclConfig)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse config: %v", err))
+		cl.State.Fmt.Finish()
+		return false, err
+	}
+
+	resp, err := client.GetPipeline(ctx, &proto.GetPipelineRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get pipeline: %v", err))
+		cl.State.Fmt.Finish()
+		return false, err
+	}
+
+	changes, err := diffPipelines(resp.Pipeline, models.NewPipeline(path, config).ToProto())
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not compare configs: %v", err))
+		cl.State.Fmt.Finish()
+		return false, err
+	}
+
+	if len(changes) == 0 {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Config %q is the same as the live config; nothing to update", path))
+		cl.State.Fmt.Finish()
+		return false, nil
+	}
+
+	cl.State.Fmt.Println(fmt.Sprintf("Changes to pipeline %s:\n\n%s\n", id, strings.Join(changes, "\n")))
+
+	if yes {
+		return true, nil
+	}
+
+	cl.State.Fmt.Finish()
+
+	var input string
+	fmt.Print("Apply these changes? [y/N]: ")
+	fmt.Scanln(&input)
+	if !strings.EqualFold(input, "y") && !strings.EqualFold(input, "yes") {
+		fmt.Println("Aborted; pipeline was not updated")
+		return false, nil
+	}
+
+	cl.State.NewFormatter()
+
+	return true, nil
+}
+
+// diffPipelines returns a line for every setting changed between the live and the proposed pipeline and for every
+// task, trigger, notifier, schedule or upstream added, removed or changed; grouped by what they belong to.
+func diffPipelines(live, proposed *proto.Pipeline) ([]string, error) {
+	before, err := pipelineToMap(live)
+	if err != nil {
+		return nil, err
+	}
+
+	after, err := pipelineToMap(proposed)
+	if err != nil {
+		return nil, err
+	}
+
+	lines := []string{}
+
+	settings := []string{}
+	for _, setting := range diffSettings {
+		if reflect.DeepEqual(before[setting], after[setting]) {
+			continue
+		}
+		settings = append(settings, fmt.Sprintf("  %s %s: %s → %s", color.YellowString("~"), setting,
+			compactJSON(before[setting]), compactJSON(after[setting])))
+	}
+	if len(settings) > 0 {
+		lines = append(lines, "Settings:")
+		lines = append(lines, settings...)
+	}
+
+	for _, collection := range diffCollections {
+		beforeItems, _ := before[collection.name].(map[string]interface{})
+		afterItems, _ := after[collection.name].(map[string]interface{})
+
+		items := []string{}
+		for _, label := range unionKeys(beforeItems, afterItems) {
+			beforeItem, existed := beforeItems[label]
+			afterItem, exists := afterItems[label]
+
+			switch {
+			case !existed:
+				items = append(items, fmt.Sprintf("  %s %s", color.GreenString("+"), label))
+			case !exists:
+				items = append(items, fmt.Sprintf("  %s %s", color.RedString("-"), label))
+			default:
+				fields := changedFields(beforeItem, afterItem, collection.ignored)
+				if len(fields) > 0 {
+					items = append(items, fmt.Sprintf("  %s %s (%s)", color.YellowString("~"), label,
+						strings.Join(fields, ", ")))
+				}
+			}
+		}
+
+		if len(items) > 0 {
+			lines = append(lines, collection.title+":")
+			lines = append(lines, items...)
+		}
+	}
+
+	return lines, nil
+}
+
+// pipelineToMap converts the pipeline into the same shape it has in structured output so that it can be compared
+// field by field.
+func pipelineToMap(pipeline *proto.Pipeline) (map[string]interface{}, error) {
+	raw, err := protojson.MarshalOptions{UseProtoNames: true, EmitUnpopulated: true}.Marshal(pipeline)
+	if err != nil {
+		return nil, err
+	}
+
+	result := map[string]interface{}{}
+	err = json.Unmarshal(raw, &result)
+	if err != nil {
+		return nil, err
+	}
+
+	return result, nil
+}
+
+// changedFields returns the sorted names of the fields which differ between two items, leaving out ignored fields.
+func changedFields(before, after interface{}, ignored []string) []string {
+	beforeFields, _ := before.(map[string]interface{})
+	afterFields, _ := after.(map[string]interface{})
+
+	fields := []string{}
+	for _, field := range unionKeys(beforeFields, afterFields) {
+		if contains(ignored, field) {
+			continue
+		}
+		if !reflect.DeepEqual(beforeFields[field], afterFields[field]) {
+			fields = append(fields, field)
+		}
+	}
+
+	return fields
+}
+
+func unionKeys(a, b map[string]interface{}) []string {
+	keys := []string{}
+	for key := range a {
+		keys = append(keys, key)
+	}
+	for key := range b {
+		if _, exists := a[key]; !exists {
+			keys = append(keys, key)
+		}
+	}
+	sort.Strings(keys)
+
+	return keys
+}
+
+func contains(list []string, value string) bool {
+	for _, item := range list {
+		if item == value {
+			return true
+		}
+	}
+
+	return false
+}
+
+func compactJSON(value interface{}) string {
+	raw, err := json.Marshal(value)
+	if err != nil {
+		return fmt.Sprint(value)
+	}
+
+	return string(raw)
+}
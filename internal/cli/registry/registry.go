@@ -0,0 +1,21 @@
+package registry
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdRegistry = &cobra.Command{
+	Use:   "registry",
+	Short: "Manage container registry credentials",
+	Long: `Manage container registry credentials.
+
+Registry credentials are logins for container registries that are kept by Gofer for a namespace. When a task run's
+image is pulled from a registry the namespace has a credential for, the scheduler logs in with it. This means that
+pipeline configs don't need to include registry logins of their own. A login given within a task's config takes
+priority over the namespace's credential.
+
+A credential can be for a whole registry(ex. ghcr.io) or for a repository path within one(ex. ghcr.io/myorg); the most
+specific credential matching an image is used. Passwords are kept within the secret store and are never shown.
+
+Adding and removing credentials requires a management token.`,
+}
@@ -0,0 +1,57 @@
+package registry
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdRegistryDelete = &cobra.Command{
+	Use:   "delete <registry>",
+	Short: "Remove the credential for a registry",
+	Long: `Remove the credential for a registry from the namespace.
+
+Requires a management token.`,
+	Example: `$ gofer registry delete ghcr.io/myorg`,
+	RunE:    registryDelete,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdRegistry.AddCommand(cmdRegistryDelete)
+}
+
+func registryDelete(_ *cobra.Command, args []string) error {
+	registry := args[0]
+
+	cl.State.Fmt.Print("Deleting registry credential")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	_, err = client.DeleteRegistryCredential(ctx, &proto.DeleteRegistryCredentialRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Registry:    registry,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not delete registry credential: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Deleted credential for registry %q", registry))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,109 @@
+package registry
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdRegistryList = &cobra.Command{
+	Use:     "list",
+	Short:   "List the registry credentials of the namespace",
+	Example: `$ gofer registry list`,
+	RunE:    registryList,
+}
+
+func init() {
+	CmdRegistry.AddCommand(cmdRegistryList)
+}
+
+func registryList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+
+	cl.State.Fmt.Print("Retrieving registry credentials")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListRegistryCredentials(ctx, &proto.ListRegistryCredentialsRequest{
+		NamespaceId: cl.State.Config.Namespace,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list registry credentials: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Credentials)
+	}
+
+	data := [][]string{}
+	for _, credential := range resp.Credentials {
+		data = append(data, []string{
+			credential.Registry,
+			credential.User,
+			cliformat.UnixMilli(credential.Created, "Unknown", detail),
+			cliformat.UnixMilli(credential.Updated, "Never", detail),
+		})
+	}
+
+	table := formatTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func formatTable(data [][]string, color bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Registry", "User", "Created", "Updated"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if color {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
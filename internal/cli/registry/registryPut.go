@@ -0,0 +1,74 @@
+package registry
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdRegistryPut = &cobra.Command{
+	Use:   "put <registry> <user>",
+	Short: "Add or replace the credential for a registry",
+	Long: `Add or replace the credential for a registry within the namespace.
+
+The password is read from stdin so that it doesn't end up in your shell's history.
+
+Requires a management token.`,
+	Example: `$ echo $GHCR_TOKEN | gofer registry put ghcr.io my_user
+$ cat token.txt | gofer registry put ghcr.io/myorg my_user`,
+	RunE: registryPut,
+	Args: cobra.ExactArgs(2),
+}
+
+func init() {
+	CmdRegistry.AddCommand(cmdRegistryPut)
+}
+
+func registryPut(_ *cobra.Command, args []string) error {
+	registry := args[0]
+	user := args[1]
+
+	pass := bytes.NewBuffer([]byte{})
+	if _, err := io.Copy(pass, os.Stdin); err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Saving registry credential")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.PutRegistryCredential(ctx, &proto.PutRegistryCredentialRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Registry:    registry,
+		User:        user,
+		Pass:        strings.TrimSpace(pass.String()),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not save registry credential: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Saved credential for registry %q", resp.Credential.Registry))
+	cl.State.Fmt.Finish()
+	return nil
+}
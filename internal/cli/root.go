@@ -9,14 +9,21 @@ import (
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
 	"github.com/clintjedwards/gofer/internal/cli/config"
+	clicontext "github.com/clintjedwards/gofer/internal/cli/context"
+	"github.com/clintjedwards/gofer/internal/cli/dashboard"
+	"github.com/clintjedwards/gofer/internal/cli/docs"
 	"github.com/clintjedwards/gofer/internal/cli/event"
+	"github.com/clintjedwards/gofer/internal/cli/fetch"
+	"github.com/clintjedwards/gofer/internal/cli/login"
 	"github.com/clintjedwards/gofer/internal/cli/namespace"
 	"github.com/clintjedwards/gofer/internal/cli/notifier"
 	"github.com/clintjedwards/gofer/internal/cli/pipeline"
+	"github.com/clintjedwards/gofer/internal/cli/registry"
 	"github.com/clintjedwards/gofer/internal/cli/run"
 	"github.com/clintjedwards/gofer/internal/cli/service"
 	taskrun "github.com/clintjedwards/gofer/internal/cli/taskRun"
 	"github.com/clintjedwards/gofer/internal/cli/trigger"
+	"github.com/clintjedwards/gofer/internal/cli/webhook"
 	"github.com/spf13/cobra"
 )
 
@@ -48,13 +55,26 @@ func init() {
 	RootCmd.AddCommand(config.CmdConfig)
 	RootCmd.AddCommand(namespace.CmdNamespace)
 	RootCmd.AddCommand(event.CmdEvent)
+	RootCmd.AddCommand(webhook.CmdWebhook)
+	RootCmd.AddCommand(registry.CmdRegistry)
+	RootCmd.AddCommand(clicontext.CmdContext)
+	RootCmd.AddCommand(dashboard.CmdDashboard)
+	RootCmd.AddCommand(docs.CmdDocs)
+	RootCmd.AddCommand(fetch.CmdFetch)
+	RootCmd.AddCommand(login.CmdLogin)
 
 	RootCmd.PersistentFlags().String("config", "", "configuration file path")
+	RootCmd.PersistentFlags().String("context", "", "specify which configuration context the command should use")
 	RootCmd.PersistentFlags().Bool("detail", false, "show extra detail for some commands (ex. Exact time instead of humanized)")
 	RootCmd.PersistentFlags().String("format", "", "output format; accepted values are 'pretty', 'json', 'silent'")
+	RootCmd.PersistentFlags().String("output", "", "how retrieved data is printed; accepted values are 'table', 'json', 'yaml'")
 	RootCmd.PersistentFlags().String("namespace", "", "specify which namespace the command should be run against")
 	RootCmd.PersistentFlags().Bool("no-color", false, "disable color output")
+	RootCmd.PersistentFlags().Bool("no-pager", false, "do not pipe long listings through a pager")
 	RootCmd.PersistentFlags().String("host", "", "specify the URL of the server to communicate to")
+	RootCmd.PersistentFlags().Duration("request-timeout", 0, "maximum amount of time a request may take (ex. 30s); 0 means no timeout")
+	RootCmd.PersistentFlags().Duration("connect-timeout", 0, "maximum amount of time to wait for a connection to the server (ex. 5s)")
+	RootCmd.PersistentFlags().Uint("retries", 0, "amount of times a request is retried when the server is unavailable")
 }
 
 // Execute adds all child commands to the root command and sets flags appropriately.
@@ -0,0 +1,22 @@
+package run
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdRunArtifacts = &cobra.Command{
+	Use:   "artifacts",
+	Short: "Manage run artifacts",
+	Long: `Manage run artifacts.
+
+Artifacts are files or directories that tasks declare as their output. Gofer collects them from a task run's
+container once it finishes and keeps them along with their size, checksum, and content type. Directories are kept as
+a tar archive.
+
+Like run objects, the content of artifacts is removed after a certain number of runs; the record of them is kept.
+`,
+}
+
+func init() {
+	CmdRun.AddCommand(CmdRunArtifacts)
+}
@@ -0,0 +1,166 @@
+package run
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"hash"
+	"io"
+	"os"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/dustin/go-humanize"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdRunArtifactsDownload = &cobra.Command{
+	Use:   "download <pipeline_id> <run_id> <task_id> <name>",
+	Short: "Download an artifact collected during a run",
+	Long: `Download an artifact collected during a run.
+
+Artifacts are streamed from Gofer in chunks so they never need to fit in memory. By default the artifact is written
+to stdout; use '--file' to write it to a file instead. Artifacts collected from a directory are downloaded as a tar
+archive.
+
+The content downloaded is checked against the artifact's checksum.
+`,
+	Example: `$ gofer run artifacts download simple_test_pipeline 5 build binary --file=./binary
+$ gofer run artifacts download simple_test_pipeline 5 test reports | tar -x`,
+	RunE: runArtifactsDownload,
+	Args: cobra.ExactArgs(4),
+}
+
+func init() {
+	cmdRunArtifactsDownload.Flags().StringP("file", "f", "", "Write the artifact to the given file instead of stdout")
+	CmdRunArtifacts.AddCommand(cmdRunArtifactsDownload)
+}
+
+func runArtifactsDownload(cmd *cobra.Command, args []string) error {
+	pipelineID := args[0]
+	runID, err := strconv.Atoi(args[1])
+	if err != nil {
+		return err
+	}
+	taskID := args[2]
+	name := args[3]
+
+	path, err := cmd.Flags().GetString("file")
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+
+	// We don't use the formatter when writing to stdout because we may want to redirect the artifact we get into
+	// a file or similar situation.
+	if path == "" {
+		cl.State.Fmt.Finish()
+	}
+
+	var destination io.Writer = os.Stdout
+
+	if path != "" {
+		file, err := os.OpenFile(path, os.O_CREATE|os.O_WRONLY|os.O_TRUNC, 0o644)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+		defer file.Close()
+
+		destination = file
+		cl.State.Fmt.Print("Downloading artifact")
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		if path == "" {
+			fmt.Println(err)
+			return err
+		}
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	checksum := sha256.New()
+	var artifact *proto.Artifact
+
+	received, err := cl.State.Download(io.MultiWriter(destination, checksum), 0, path != "",
+		func(offset int64) (cl.Downloader, error) {
+			stream, err := client.GetArtifactStream(ctx, &proto.GetArtifactStreamRequest{
+				NamespaceId: cl.State.Config.Namespace,
+				PipelineId:  pipelineID,
+				RunId:       int64(runID),
+				TaskRunId:   taskID,
+				Name:        name,
+				Offset:      offset,
+			})
+			if err != nil {
+				return nil, err
+			}
+			return &artifactDownloader{stream: stream, artifact: &artifact}, nil
+		})
+	if err == nil {
+		err = verifyArtifactChecksum(artifact, checksum)
+	}
+	if err != nil {
+		if path == "" {
+			fmt.Printf("could not download artifact: %v\n", err)
+			return err
+		}
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not download artifact: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if path != "" {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Downloaded %s to %s", humanize.Bytes(uint64(received)), path))
+		cl.State.Fmt.Finish()
+	}
+
+	return nil
+}
+
+// verifyArtifactChecksum checks that the content received is the content that was collected.
+func verifyArtifactChecksum(artifact *proto.Artifact, checksum hash.Hash) error {
+	if artifact == nil || artifact.Checksum == "" {
+		return nil
+	}
+
+	got := hex.EncodeToString(checksum.Sum(nil))
+	if got != artifact.Checksum {
+		return fmt.Errorf("checksum mismatch; expected %s but downloaded content has %s", artifact.Checksum, got)
+	}
+
+	return nil
+}
+
+// artifactDownloader receives an artifact from the server in chunks, keeping the artifact's metadata sent along
+// with the first chunk.
+type artifactDownloader struct {
+	stream   proto.Gofer_GetArtifactStreamClient
+	artifact **proto.Artifact
+}
+
+func (d *artifactDownloader) Recv() ([]byte, int64, error) {
+	resp, err := d.stream.Recv()
+	if err != nil {
+		return nil, 0, err
+	}
+
+	if resp.Artifact == nil {
+		return resp.Content, 0, nil
+	}
+
+	*d.artifact = resp.Artifact
+	return resp.Content, resp.Artifact.Size, nil
+}
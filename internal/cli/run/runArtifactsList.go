@@ -0,0 +1,103 @@
+package run
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/dustin/go-humanize"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdRunArtifactsList = &cobra.Command{
+	Use:     "list <pipeline_id> <run_id>",
+	Short:   "List all artifacts collected during a run",
+	Example: `$ gofer run artifacts list simple_test_pipeline 5`,
+	RunE:    runArtifactsList,
+	Args:    cobra.ExactArgs(2),
+}
+
+func init() {
+	CmdRunArtifacts.AddCommand(cmdRunArtifactsList)
+}
+
+func runArtifactsList(_ *cobra.Command, args []string) error {
+	pipelineID := args[0]
+	runID, err := strconv.Atoi(args[1])
+	if err != nil {
+		return err
+	}
+
+	cl.State.Fmt.Print("Retrieving artifacts")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	resp, err := client.ListRunArtifacts(ctx, &proto.ListRunArtifactsRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipelineID,
+		RunId:       int64(runID),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list artifacts: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Artifacts)
+	}
+
+	if len(resp.Artifacts) == 0 {
+		cl.State.Fmt.Println(fmt.Sprintf("No artifacts found for run #%d", runID))
+		cl.State.Fmt.Finish()
+		return nil
+	}
+
+	data := [][]string{}
+	for _, artifact := range resp.Artifacts {
+		size := humanize.Bytes(uint64(artifact.Size))
+		if artifact.Expired {
+			size = color.RedString("expired")
+		}
+
+		// The full checksum is too long for a table; a prefix is enough to tell artifacts apart.
+		checksum := artifact.Checksum
+		if len(checksum) > 12 {
+			checksum = checksum[:12]
+		}
+
+		data = append(data, []string{
+			artifact.TaskRunId,
+			artifact.Name,
+			artifact.Path,
+			size,
+			artifact.ContentType,
+			checksum,
+			cliformat.UnixMilli(artifact.Created, "Unknown", cl.State.Config.Detail),
+		})
+	}
+
+	table := formatTable([]string{"Task", "Name", "Path", "Size", "Content Type", "Checksum", "Collected"}, data,
+		!cl.State.Config.NoColor)
+
+	cl.State.Fmt.Println(fmt.Sprintf("  Artifacts for run %s\n\n%s", color.BlueString("#"+args[1]), table))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
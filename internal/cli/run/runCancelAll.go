@@ -3,26 +3,48 @@ package run
 import (
 	"context"
 	"fmt"
+	"os"
+	"strconv"
+	"strings"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
 	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
 	"github.com/spf13/cobra"
+	"golang.org/x/term"
 	"google.golang.org/grpc/metadata"
 )
 
 var cmdRunCancelAll = &cobra.Command{
-	Use:     "cancel-all <pipeline_id>",
-	Short:   "CancelAll cancels all run for a given pipeline",
-	Example: `$ gofer run cancel-all simple_test_pipeline`,
-	RunE:    runCancelAll,
-	Args:    cobra.ExactArgs(1),
+	Use:   "cancel-all <pipeline_id>",
+	Short: "CancelAll cancels all run for a given pipeline",
+	Long: `CancelAll cancels all run for a given pipeline.
+
+The runs that are about to be cancelled are listed and you'll be asked to confirm before anything is cancelled. The
+confirmation is skipped when passing --yes or when the command isn't run from a terminal(ex. in a script). Passing
+the --state flag limits cancellation to runs in that state: 'running' for runs that are currently executing and
+'pending' for runs that are still waiting to be scheduled.`,
+	Example: `$ gofer run cancel-all simple_test_pipeline
+$ gofer run cancel-all simple_test_pipeline --state pending
+$ gofer run cancel-all simple_test_pipeline --yes`,
+	RunE: runCancelAll,
+	Args: cobra.ExactArgs(1),
 }
 
 func init() {
 	cmdRunCancelAll.Flags().BoolP("force", "f", false, "Stop run and child taskrun containers immediately (SIGKILL)")
+	cmdRunCancelAll.Flags().StringP("state", "s", "", "only cancel runs in the given state; accepted values are 'running', 'pending'")
+	cmdRunCancelAll.Flags().BoolP("yes", "y", false, "skip the confirmation prompt")
 	CmdRun.AddCommand(cmdRunCancelAll)
 }
 
+// cancelStates maps the user facing state names to the run states they represent.
+var cancelStates = map[string][]proto.Run_State{
+	"running": {proto.Run_RUNNING},
+	"pending": {proto.Run_PROCESSING, proto.Run_WAITING},
+}
+
 func runCancelAll(cmd *cobra.Command, args []string) error {
 	pipelineID := args[0]
 
@@ -32,7 +54,22 @@ func runCancelAll(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
-	cl.State.Fmt.Print("Cancelling all runs")
+	state, _ := cmd.Flags().GetString("state")
+	yes, _ := cmd.Flags().GetBool("yes")
+
+	states := []proto.Run_State{}
+	if state != "" {
+		var exists bool
+		states, exists = cancelStates[strings.ToLower(state)]
+		if !exists {
+			err := fmt.Errorf("state %q is not valid; accepted values are 'running', 'pending'", state)
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
+	cl.State.Fmt.Print("Retrieving in-progress runs")
 
 	conn, err := cl.State.Connect()
 	if err != nil {
@@ -45,10 +82,43 @@ func runCancelAll(cmd *cobra.Command, args []string) error {
 
 	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
 	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	pending, err := listCancellableRuns(ctx, client, pipelineID, states)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list runs: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if len(pending) == 0 {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("No in-progress runs found for pipeline %s", pipelineID))
+		cl.State.Fmt.Finish()
+		return nil
+	}
+
+	// There's no one to answer the prompt when input doesn't come from a terminal.
+	if !yes && term.IsTerminal(int(os.Stdin.Fd())) {
+		cl.State.Fmt.Println(fmt.Sprintf("The following runs will be cancelled:\n\n%s", formatCancelTable(pending)))
+		cl.State.Fmt.Finish()
+
+		var input string
+		fmt.Print("Continue? [y/N]: ")
+		fmt.Scanln(&input)
+		if !strings.EqualFold(input, "y") && !strings.EqualFold(input, "yes") {
+			fmt.Println("Aborted; no runs were cancelled")
+			return nil
+		}
+
+		cl.State.NewFormatter()
+	}
+
+	cl.State.Fmt.Print("Cancelling runs")
+
 	resp, err := client.CancelAllRuns(ctx, &proto.CancelAllRunsRequest{
 		NamespaceId: cl.State.Config.Namespace,
 		PipelineId:  pipelineID,
 		Force:       force,
+		States:      states,
 	})
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not cancel runs: %v", err))
@@ -56,8 +126,125 @@ func runCancelAll(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
-	cl.State.Fmt.PrintSuccess(fmt.Sprintf("cancelled all in-progress runs %v", resp.Runs))
+	if len(resp.Runs) == 0 {
+		cl.State.Fmt.PrintSuccess("No runs needed to be cancelled")
+		cl.State.Fmt.Finish()
+		return nil
+	}
+
+	// Retrieve the runs once more so the summary reflects their state after cancellation.
+	cancelled := []*proto.Run{}
+	for _, id := range resp.Runs {
+		runResp, err := client.GetRun(ctx, &proto.GetRunRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			PipelineId:  pipelineID,
+			Id:          id,
+		})
+		if err != nil {
+			cancelled = append(cancelled, &proto.Run{Id: id})
+			continue
+		}
+		cancelled = append(cancelled, runResp.Run)
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Cancelled %d run(s)", len(resp.Runs)))
+	cl.State.Fmt.Println(formatCancelTable(cancelled))
 	cl.State.Fmt.Finish()
 
 	return nil
 }
+
+// listCancellableRuns returns every run of the pipeline that would be cancelled. Runs are listed a page at a time
+// since in-progress runs can be anywhere in the pipeline's history.
+func listCancellableRuns(ctx context.Context, client proto.GoferClient, pipelineID string, states []proto.Run_State,
+) ([]*proto.Run, error) {
+	runs := []*proto.Run{}
+	offset := 0
+
+	for {
+		resp, err := client.ListRuns(ctx, &proto.ListRunsRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			PipelineId:  pipelineID,
+			Offset:      int64(offset),
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		for _, run := range resp.Runs {
+			if isCancellable(run.State, states) {
+				runs = append(runs, run)
+			}
+		}
+
+		if len(resp.Runs) == 0 {
+			return runs, nil
+		}
+
+		offset += len(resp.Runs)
+	}
+}
+
+// isCancellable returns whether a run is still in progress and, if states are given, in one of the given states.
+func isCancellable(state proto.Run_State, states []proto.Run_State) bool {
+	switch state {
+	case proto.Run_PROCESSING, proto.Run_WAITING, proto.Run_RUNNING:
+	default:
+		return false
+	}
+
+	if len(states) == 0 {
+		return true
+	}
+
+	for _, s := range states {
+		if s == state {
+			return true
+		}
+	}
+
+	return false
+}
+
+func formatCancelTable(runs []*proto.Run) string {
+	data := [][]string{}
+	for _, run := range runs {
+		data = append(data, []string{
+			strconv.Itoa(int(run.Id)),
+			cliformat.UnixMilli(run.Started, "Not yet", cl.State.Config.Detail),
+			cliformat.RunState(run.State.String()),
+		})
+	}
+
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"ID", "Started", "State"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if !cl.State.Config.NoColor {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
@@ -64,6 +64,11 @@ func runGet(_ *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Run)
+	}
+
 	taskRuns, err := client.ListTaskRuns(ctx, &proto.ListTaskRunsRequest{
 		PipelineId: resp.Run.PipelineId,
 		RunId:      resp.Run.Id,
@@ -91,6 +96,11 @@ type data struct {
 	Objects        string
 	ObjectsExpired bool
 	Only           bool
+	ResumedFrom    string
+	Recovery       string
+	CanaryVersion  int64
+	Initiator      string
+	CorrelationID  string
 	TaskRuns       []taskRunData
 }
 
@@ -143,12 +153,46 @@ func formatRunInfo(run *proto.Run, taskRuns []*proto.TaskRun, detail bool) strin
 		Objects:        format.SliceJoin(run.Objects, "None"),
 		ObjectsExpired: false,
 		Only:           len(run.Only) > 0,
+		Initiator:      color.MagentaString(run.Initiator),
+		CorrelationID:  run.CorrelationId,
+		CanaryVersion:  run.CanaryVersion,
 		TaskRuns:       taskRunList,
 	}
 
+	if run.ResumedFrom != 0 {
+		data.ResumedFrom = color.BlueString("#" + strconv.Itoa(int(run.ResumedFrom)))
+	}
+
+	if run.Recovery != nil {
+		switch {
+		case run.Recovery.RerunId != 0:
+			data.Recovery = "was started over as run " + color.BlueString("#"+strconv.Itoa(int(run.Recovery.RerunId)))
+		case run.Recovery.Policy == proto.Pipeline_RESUME:
+			data.Recovery = "was resumed"
+		default:
+			data.Recovery = "was failed"
+		}
+		data.Recovery += " " + format.UnixMilli(run.Recovery.Recovered, "", detail)
+	}
+
 	const formatTmpl = `Run {{.ID}} for Pipeline {{.PipelineID}} :: {{.State}}
 
   Triggered via {{.TriggerName}} ({{.TriggerKind}}) {{.Started}} and ran for {{.Duration}}
+  {{- if .Initiator}}
+  Initiated by {{.Initiator}}
+  {{- end}}
+  {{- if .ResumedFrom}}
+  Resumed from run {{.ResumedFrom}}; tasks that were not run again kept their previous results
+  {{- end}}
+  {{- if .Recovery}}
+  Interrupted by an unexpected Gofer shutdown and {{.Recovery}}
+  {{- end}}
+  {{- if .CanaryVersion}}
+  Ran the tasks of canary config version {{.CanaryVersion}}
+  {{- end}}
+  {{- if .CorrelationID}}
+  Correlation ID: {{.CorrelationID}}
+  {{- end}}
 
   {{- if .TaskRuns}}
 
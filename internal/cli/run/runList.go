@@ -3,8 +3,10 @@ package run
 import (
 	"context"
 	"fmt"
+	"sort"
 	"strconv"
 	"strings"
+	"time"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
 	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
@@ -20,17 +22,50 @@ var cmdRunList = &cobra.Command{
 	Short: "List all runs",
 	Long: `List all runs.
 
-A short listing of all currently started runs.`,
-	Example: `$ gofer run list simple_test_pipeline`,
-	RunE:    runList,
-	Args:    cobra.ExactArgs(1),
+A short listing of all currently started runs.
+
+The --columns flag controls which columns are shown and in which order. The --sort-by flag sorts the returned runs by
+the given column; prefix the column with '-' to sort in descending order.`,
+	Example: `$ gofer run list simple_test_pipeline
+$ gofer run list simple_test_pipeline --columns id,result,duration
+$ gofer run list simple_test_pipeline --sort-by -duration`,
+	RunE: runList,
+	Args: cobra.ExactArgs(1),
 }
 
 func init() {
 	cmdRunList.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	cmdRunList.Flags().StringSlice("columns", []string{}, "only show the given columns (ex. id,started,result)")
+	cmdRunList.Flags().String("sort-by", "", "sort runs by the given column; prefix with '-' for descending order")
 	CmdRun.AddCommand(cmdRunList)
 }
 
+// runHeaders are the columns shown by the run list command.
+var runHeaders = []string{"ID", "Started", "Ended", "Duration", "Result", "Triggered By"}
+
+// runSorters are the columns runs can be sorted by.
+var runSorters = map[string]func(a, b *proto.Run) bool{
+	"id":           func(a, b *proto.Run) bool { return a.Id < b.Id },
+	"started":      func(a, b *proto.Run) bool { return a.Started < b.Started },
+	"ended":        func(a, b *proto.Run) bool { return a.Ended < b.Ended },
+	"duration":     func(a, b *proto.Run) bool { return runDuration(a) < runDuration(b) },
+	"result":       func(a, b *proto.Run) bool { return a.State.String() < b.State.String() },
+	"triggered_by": func(a, b *proto.Run) bool { return a.TriggerName < b.TriggerName },
+}
+
+func runDuration(run *proto.Run) time.Duration {
+	if run.Started == 0 {
+		return 0
+	}
+
+	end := time.Now()
+	if run.Ended != 0 {
+		end = time.UnixMilli(run.Ended)
+	}
+
+	return end.Sub(time.UnixMilli(run.Started))
+}
+
 func runList(cmd *cobra.Command, args []string) error {
 	pipelineID := args[0]
 
@@ -43,6 +78,25 @@ func runList(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
+	columns, _ := cmd.Flags().GetStringSlice("columns")
+	sortBy, _ := cmd.Flags().GetString("sort-by")
+
+	sortKeys := []string{}
+	for key := range runSorters {
+		sortKeys = append(sortKeys, key)
+	}
+	sort.Strings(sortKeys)
+
+	sortKey, descending := "", false
+	if sortBy != "" {
+		sortKey, descending, err = cliformat.SortBy(sortBy, sortKeys)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
 	conn, err := cl.State.Connect()
 	if err != nil {
 		cl.State.Fmt.PrintErr(err)
@@ -66,12 +120,27 @@ func runList(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Runs)
+	}
+
 	if len(resp.Runs) == 0 {
 		cl.State.Fmt.Println(fmt.Sprintf("No runs found for pipeline %s", pipelineID))
 		cl.State.Fmt.Finish()
 		return err
 	}
 
+	if sortKey != "" {
+		less := runSorters[sortKey]
+		sort.SliceStable(resp.Runs, func(i, j int) bool {
+			if descending {
+				return less(resp.Runs[j], resp.Runs[i])
+			}
+			return less(resp.Runs[i], resp.Runs[j])
+		})
+	}
+
 	data := [][]string{}
 	for _, run := range resp.Runs {
 		id := strconv.Itoa(int(run.Id))
@@ -86,19 +155,26 @@ func runList(cmd *cobra.Command, args []string) error {
 		})
 	}
 
-	table := formatTable(data, !cl.State.Config.NoColor)
+	headers, data, err := cliformat.SelectColumns(runHeaders, data, columns)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	table := formatTable(headers, data, !cl.State.Config.NoColor)
 
-	cl.State.Fmt.Println(fmt.Sprintf("  Runs for pipeline %s\n\n%s", color.BlueString(pipelineID), table))
+	cl.State.PrintPaged(fmt.Sprintf("  Runs for pipeline %s\n\n%s", color.BlueString(pipelineID), table))
 	cl.State.Fmt.Finish()
 
 	return nil
 }
 
-func formatTable(data [][]string, color bool) string {
+func formatTable(headers []string, data [][]string, color bool) string {
 	tableString := &strings.Builder{}
 	table := tablewriter.NewWriter(tableString)
 
-	table.SetHeader([]string{"ID", "Started", "Ended", "Duration", "Result", "Triggered By"})
+	table.SetHeader(headers)
 	table.SetAlignment(tablewriter.ALIGN_LEFT)
 	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
 	table.SetHeaderLine(true)
@@ -110,22 +186,19 @@ func formatTable(data [][]string, color bool) string {
 	table.SetCenterSeparator("")
 
 	if color {
-		table.SetHeaderColor(
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-		)
-		table.SetColumnColor(
-			tablewriter.Color(tablewriter.FgYellowColor),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-		)
+		headerColors := []tablewriter.Colors{}
+		columnColors := []tablewriter.Colors{}
+		for _, header := range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+			if header == "ID" {
+				columnColors = append(columnColors, tablewriter.Color(tablewriter.FgYellowColor))
+				continue
+			}
+			columnColors = append(columnColors, tablewriter.Color(0))
+		}
+
+		table.SetHeaderColor(headerColors...)
+		table.SetColumnColor(columnColors...)
 	}
 
 	table.AppendBulk(data)
@@ -0,0 +1,232 @@
+package run
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strconv"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+var cmdRunLogs = &cobra.Command{
+	Use:   "logs <pipeline> <id>",
+	Short: "Examine logs for all task runs within a run",
+	Long: `Examine logs for all task runs within a run.
+
+By default the logs of each finished task run are printed one after another in the order they were started. Passing
+the --follow flag will instead stream the logs of all task runs as they are written, interleaving them as they come
+in, until the run has finished.
+
+Each log line is prefixed by the id of the task run it came from.`,
+	Example: `$ gofer run logs simple_test_pipeline 23
+$ gofer run logs simple_test_pipeline 23 --follow`,
+	RunE: runLogs,
+	Args: cobra.ExactArgs(2),
+}
+
+func init() {
+	cmdRunLogs.Flags().BoolP("follow", "f", false, "Continuously stream logs until the run has finished")
+	CmdRun.AddCommand(cmdRunLogs)
+}
+
+func runLogs(cmd *cobra.Command, args []string) error {
+	// We don't use the formatter here because we may want to redirect logs we get into
+	// a file or such.
+	cl.State.Fmt.Finish()
+
+	follow, _ := cmd.Flags().GetBool("follow")
+
+	pipelineID := args[0]
+
+	idRaw := args[1]
+	id, err := strconv.Atoi(idRaw)
+	if err != nil {
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+	defer conn.Close()
+
+	client := proto.NewGoferClient(conn)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx = metadata.NewOutgoingContext(ctx, md)
+
+	if follow {
+		return followRunLogs(ctx, client, pipelineID, int64(id))
+	}
+
+	resp, err := client.ListTaskRuns(ctx, &proto.ListTaskRunsRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipelineID,
+		RunId:       int64(id),
+	})
+	if err != nil {
+		fmt.Printf("could not list task runs: %v\n", err)
+		return err
+	}
+
+	sort.Slice(resp.TaskRuns, func(i, j int) bool { return resp.TaskRuns[i].Started < resp.TaskRuns[j].Started })
+
+	for _, protoTaskRun := range resp.TaskRuns {
+		taskRun := models.TaskRun{}
+		taskRun.FromProto(protoTaskRun)
+
+		if !hasLogs(&taskRun) {
+			continue
+		}
+
+		if !taskRun.IsComplete() {
+			fmt.Printf("%s task run is still in progress; use --follow to stream its logs\n",
+				logPrefix(taskRun.ID))
+			continue
+		}
+
+		err := streamTaskRunLogs(ctx, client, &taskRun, false, nil)
+		if err != nil {
+			fmt.Printf("could not get logs for task run %q: %v\n", taskRun.ID, err)
+			return err
+		}
+	}
+
+	return nil
+}
+
+// followRunLogs streams the logs for every task run within a run concurrently. Since task runs may be created
+// after we start following, we periodically check for new task runs until the run itself is complete.
+func followRunLogs(ctx context.Context, client proto.GoferClient, pipelineID string, runID int64) error {
+	// The output lock makes sure lines from different task runs don't get jumbled together.
+	outputLock := sync.Mutex{}
+	wg := sync.WaitGroup{}
+	following := map[string]struct{}{}
+
+	for {
+		runResp, err := client.GetRun(ctx, &proto.GetRunRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			PipelineId:  pipelineID,
+			Id:          runID,
+		})
+		if err != nil {
+			// The server may be restarting; the task runs already being followed reconnect on their own.
+			if status.Code(err) == codes.Unavailable {
+				time.Sleep(time.Second * 3)
+				continue
+			}
+			fmt.Printf("could not get run: %v\n", err)
+			return err
+		}
+
+		taskRunsResp, err := client.ListTaskRuns(ctx, &proto.ListTaskRunsRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			PipelineId:  pipelineID,
+			RunId:       runID,
+		})
+		if err != nil {
+			// The server may be restarting; the task runs already being followed reconnect on their own.
+			if status.Code(err) == codes.Unavailable {
+				time.Sleep(time.Second * 3)
+				continue
+			}
+			fmt.Printf("could not list task runs: %v\n", err)
+			return err
+		}
+
+		for _, protoTaskRun := range taskRunsResp.TaskRuns {
+			taskRun := models.TaskRun{}
+			taskRun.FromProto(protoTaskRun)
+
+			if _, exists := following[taskRun.ID]; exists {
+				continue
+			}
+
+			if !hasLogs(&taskRun) {
+				continue
+			}
+
+			following[taskRun.ID] = struct{}{}
+
+			wg.Add(1)
+			go func() {
+				defer wg.Done()
+				err := streamTaskRunLogs(ctx, client, &taskRun, true, &outputLock)
+				if err != nil {
+					outputLock.Lock()
+					fmt.Printf("%s could not get logs: %v\n", logPrefix(taskRun.ID), err)
+					outputLock.Unlock()
+				}
+			}()
+		}
+
+		run := models.Run{}
+		run.FromProto(runResp.Run)
+
+		if run.IsComplete() {
+			break
+		}
+
+		time.Sleep(time.Second * 3)
+	}
+
+	wg.Wait()
+
+	return nil
+}
+
+// hasLogs returns whether a task run has gotten far enough to have produced logs. Task runs that were skipped or
+// never scheduled will never have logs to stream.
+func hasLogs(taskRun *models.TaskRun) bool {
+	if taskRun.State == models.ContainerStateSkipped {
+		return false
+	}
+
+	if taskRun.LogsExpired || taskRun.LogsRemoved {
+		return false
+	}
+
+	return taskRun.SchedulerID != ""
+}
+
+func logPrefix(taskRunID string) string {
+	return color.BlueString("[%s]", taskRunID)
+}
+
+// streamTaskRunLogs prints all log lines of a single task run prefixed by its id. If a lock is given it is held
+// while printing each line.
+func streamTaskRunLogs(ctx context.Context, client proto.GoferClient, taskRun *models.TaskRun, follow bool,
+	lock *sync.Mutex,
+) error {
+	prefix := logPrefix(taskRun.ID)
+
+	return cl.StreamTaskRunLogs(ctx, client, &proto.GetTaskRunLogsRequest{
+		NamespaceId: taskRun.NamespaceID,
+		PipelineId:  taskRun.PipelineID,
+		RunId:       taskRun.RunID,
+		Id:          taskRun.ID,
+		Follow:      &follow,
+	}, func(line string) {
+		if lock != nil {
+			lock.Lock()
+		}
+		fmt.Printf("%s %s\n", prefix, line)
+		if lock != nil {
+			lock.Unlock()
+		}
+	})
+}
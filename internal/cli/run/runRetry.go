@@ -0,0 +1,85 @@
+package run
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdRunRetry = &cobra.Command{
+	Use:   "retry <pipeline> <id>",
+	Short: "Start a new run using the settings of a previous run",
+	Long: `Start a new run using the settings of a previous run.
+
+The new run uses the same variables and task filter as the previous run. Passing the --from-task flag resumes the
+previous run instead: only the given task and the tasks that depend on it are run again, all other tasks keep the
+results they had in the previous run.`,
+	Example: `$ gofer run retry simple_test_pipeline 3
+$ gofer run retry simple_test_pipeline 3 --from-task deploy`,
+	RunE: runRetry,
+	Args: cobra.ExactArgs(2),
+}
+
+func init() {
+	cmdRunRetry.Flags().String("from-task", "", "Resume the run from the given task instead of running all tasks again")
+	CmdRun.AddCommand(cmdRunRetry)
+}
+
+func runRetry(cmd *cobra.Command, args []string) error {
+	pipelineID := args[0]
+	idRaw := args[1]
+	id, err := strconv.Atoi(idRaw)
+	if err != nil {
+		return err
+	}
+
+	fromTask, _ := cmd.Flags().GetString("from-task")
+
+	cl.State.Fmt.Print("Creating run")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.RetryRun(ctx, &proto.RetryRunRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipelineID,
+		Id:          int64(id),
+		FromTask:    fromTask,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not retry run: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Run)
+	}
+
+	if fromTask != "" {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Resumed run (%d) from task %s as new run (%d)", id, fromTask, resp.Run.Id))
+	} else {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Retried run (%d) as new run (%d)", id, resp.Run.Id))
+	}
+
+	cl.State.Fmt.Println(fmt.Sprintf("\n  View details of your new run: %s", color.YellowString("gofer run get %s %d", resp.Run.PipelineId, resp.Run.Id)))
+	cl.State.Fmt.Println(fmt.Sprintf("  List all task runs: %s", color.YellowString("gofer taskrun list %s %d", resp.Run.PipelineId, resp.Run.Id)))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
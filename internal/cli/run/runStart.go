@@ -2,30 +2,48 @@ package run
 
 import (
 	"context"
+	"errors"
 	"fmt"
+	"sort"
+	"strings"
+	"time"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/internal/models"
 	"github.com/clintjedwards/gofer/proto"
 	"github.com/fatih/color"
+	"github.com/olekukonko/tablewriter"
 	"github.com/spf13/cobra"
 	"google.golang.org/grpc/metadata"
 )
 
 var cmdRunStart = &cobra.Command{
-	Use:     "start <pipeline_id>",
-	Short:   "Start a new run",
-	Example: `$ gofer run start simple_test_pipeline`,
-	RunE:    runStart,
-	Args:    cobra.ExactArgs(1),
+	Use:   "start <pipeline_id>",
+	Short: "Start a new run",
+	Long: `Start a new run.
+
+Passing the --wait flag blocks until the run has finished, printing each task run's state as it changes and a summary
+once the run is complete. When waiting, the command exits with a non-zero exit code if the run did not finish
+successfully or if the --timeout was reached first. Reaching the timeout does not cancel the run.`,
+	Example: `$ gofer run start simple_test_pipeline
+$ gofer run start simple_test_pipeline --wait
+$ gofer run start simple_test_pipeline --wait --timeout 30m`,
+	RunE: runStart,
+	Args: cobra.ExactArgs(1),
 }
 
 func init() {
 	cmdRunStart.Flags().StringSliceP("only", "o", []string{}, "Run only theses tasks")
+	cmdRunStart.Flags().BoolP("wait", "w", false, "Wait for the run to finish before returning")
+	cmdRunStart.Flags().Duration("timeout", 0, "Maximum amount of time to wait for the run to finish; 0 waits forever")
 	CmdRun.AddCommand(cmdRunStart)
 }
 
 func runStart(cmd *cobra.Command, args []string) error {
 	only, _ := cmd.Flags().GetStringSlice("only")
+	wait, _ := cmd.Flags().GetBool("wait")
+	timeout, _ := cmd.Flags().GetDuration("timeout")
 
 	pipelineID := args[0]
 
@@ -53,10 +71,161 @@ func runStart(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
+	// When waiting the finished run is printed instead.
+	if cl.State.Structured() && !wait {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Run)
+	}
+
 	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Started new run (%d) for pipeline %s", resp.Run.Id, pipelineID))
+
+	if wait {
+		return waitForRun(ctx, client, resp.Run, timeout)
+	}
+
 	cl.State.Fmt.Println(fmt.Sprintf("\n  View details of your new run: %s", color.YellowString("gofer run get %s %d", resp.Run.PipelineId, resp.Run.Id)))
 	cl.State.Fmt.Println(fmt.Sprintf("  List all task runs: %s", color.YellowString("gofer taskrun list %s %d", resp.Run.PipelineId, resp.Run.Id)))
 	cl.State.Fmt.Finish()
 
 	return nil
 }
+
+// waitForRun polls the run until it is complete, printing the state of each task run whenever it changes. Returns an
+// error if the run was not successful so that the command exits with a non-zero exit code.
+func waitForRun(ctx context.Context, client proto.GoferClient, run *proto.Run, timeout time.Duration) error {
+	if timeout > 0 {
+		var cancel context.CancelFunc
+		ctx, cancel = context.WithTimeout(ctx, timeout)
+		defer cancel()
+	}
+
+	states := map[string]proto.TaskRun_State{}
+	taskRuns := []*proto.TaskRun{}
+
+	for {
+		cl.State.Fmt.Print(fmt.Sprintf("Waiting for run (%d) to finish", run.Id))
+
+		runResp, err := client.GetRun(ctx, &proto.GetRunRequest{
+			NamespaceId: run.NamespaceId,
+			PipelineId:  run.PipelineId,
+			Id:          run.Id,
+		})
+		if err != nil {
+			return waitError(ctx, run, timeout, fmt.Errorf("could not get run: %w", err))
+		}
+		run = runResp.Run
+
+		taskRunsResp, err := client.ListTaskRuns(ctx, &proto.ListTaskRunsRequest{
+			NamespaceId: run.NamespaceId,
+			PipelineId:  run.PipelineId,
+			RunId:       run.Id,
+		})
+		if err != nil {
+			return waitError(ctx, run, timeout, fmt.Errorf("could not list task runs: %w", err))
+		}
+		taskRuns = taskRunsResp.TaskRuns
+
+		sort.Slice(taskRuns, func(i, j int) bool { return taskRuns[i].Started < taskRuns[j].Started })
+
+		for _, taskRun := range taskRuns {
+			if state, exists := states[taskRun.Id]; exists && state == taskRun.State {
+				continue
+			}
+			states[taskRun.Id] = taskRun.State
+			cl.State.Fmt.Println(fmt.Sprintf("  %s %s", logPrefix(taskRun.Id),
+				cliformat.TaskRunState(taskRun.State.String())))
+		}
+
+		runModel := models.Run{}
+		runModel.FromProto(run)
+		if runModel.IsComplete() {
+			break
+		}
+
+		select {
+		case <-ctx.Done():
+			return waitError(ctx, run, timeout, ctx.Err())
+		case <-time.After(time.Second * 3):
+		}
+	}
+
+	if cl.State.Structured() {
+		if err := cl.State.PrintStructured(run); err != nil {
+			return err
+		}
+	} else {
+		cl.State.Fmt.Println(fmt.Sprintf("\n%s", formatWaitTable(taskRuns)))
+	}
+
+	duration := cliformat.Duration(run.Started, run.Ended)
+
+	if run.State != proto.Run_SUCCESS {
+		err := fmt.Errorf("run (%d) finished with state %s after %s", run.Id, run.State, duration)
+		if run.Failure != nil && run.Failure.Description != "" {
+			err = fmt.Errorf("%w: %s", err, run.Failure.Description)
+		}
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Run (%d) finished successfully after %s", run.Id, duration))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+// waitError reports why we stopped waiting for a run; hitting the timeout gets a friendlier message since the run
+// itself is still going.
+func waitError(ctx context.Context, run *proto.Run, timeout time.Duration, err error) error {
+	if errors.Is(ctx.Err(), context.DeadlineExceeded) {
+		err = fmt.Errorf("timed out after %s waiting for run (%d) to finish; the run was not cancelled", timeout, run.Id)
+	}
+
+	cl.State.Fmt.PrintErr(err)
+	cl.State.Fmt.Finish()
+	return err
+}
+
+func formatWaitTable(taskRuns []*proto.TaskRun) string {
+	data := [][]string{}
+	for _, taskRun := range taskRuns {
+		data = append(data, []string{
+			taskRun.Id,
+			cliformat.Duration(taskRun.Started, taskRun.Ended),
+			cliformat.TaskRunState(taskRun.State.String()),
+		})
+	}
+
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Task Run", "Duration", "State"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if !cl.State.Config.NoColor {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
@@ -1,7 +1,6 @@
 package run
 
 import (
-	"bytes"
 	"context"
 	"fmt"
 	"io"
@@ -27,7 +26,8 @@ configuration). This run limit is related to the number of runs in a pipeline.
 
 For instance, after a run is 10 runs old, gofer may clean up its objects.
 
-You can store both regular text values or read in entire files using the '@' prefix.`,
+You can store both regular text values or read in entire files using the '@' prefix. Files are streamed to Gofer in
+chunks so they never need to fit in memory; if the connection is reset the upload is automatically retried.`,
 	Example: `$ gofer store put simple_test_pipeline my_key=my_value
 $ gofer store put simple_test_pipeline my_key=@file_path`,
 	RunE: storePut,
@@ -60,7 +60,8 @@ func storePut(cmd *cobra.Command, args []string) error {
 		return fmt.Errorf("Key-value pair malformed; should be <key>=<value>")
 	}
 
-	object := bytes.NewBuffer([]byte{})
+	var object io.ReadSeeker
+	size := int64(len(value))
 	if strings.HasPrefix(value, "@") {
 		file, err := os.Open(value[1:])
 		if err != nil {
@@ -69,13 +70,18 @@ func storePut(cmd *cobra.Command, args []string) error {
 			return err
 		}
 		defer file.Close()
-		if _, err = io.Copy(object, file); err != nil {
+
+		info, err := file.Stat()
+		if err != nil {
 			cl.State.Fmt.PrintErr(err)
 			cl.State.Fmt.Finish()
 			return err
 		}
+
+		object = file
+		size = info.Size()
 	} else {
-		object.WriteString(value)
+		object = strings.NewReader(value)
 	}
 
 	cl.State.Fmt.Print("Uploading object")
@@ -91,13 +97,24 @@ func storePut(cmd *cobra.Command, args []string) error {
 
 	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
 	ctx := metadata.NewOutgoingContext(context.Background(), md)
-	resp, err := client.PutRunObject(ctx, &proto.PutRunObjectRequest{
-		NamespaceId: cl.State.Config.Namespace,
-		Key:         key,
-		Content:     object.Bytes(),
-		PipelineId:  pipelineID,
-		RunId:       int64(runID),
-		Force:       force,
+
+	uploader := &runObjectUploader{
+		request: &proto.PutRunObjectRequest{
+			NamespaceId: cl.State.Config.Namespace,
+			PipelineId:  pipelineID,
+			RunId:       int64(runID),
+			Key:         key,
+			Force:       force,
+		},
+	}
+	err = cl.State.Upload(object, size, func() (cl.Uploader, error) {
+		stream, err := client.PutRunObjectStream(ctx)
+		if err != nil {
+			return nil, err
+		}
+		uploader.stream = stream
+		uploader.sent = false
+		return uploader, nil
 	})
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not upload object: %v", err))
@@ -105,8 +122,39 @@ func storePut(cmd *cobra.Command, args []string) error {
 		return err
 	}
 
+	resp := uploader.resp
+
 	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Uploaded %d bytes", resp.Bytes))
 	cl.State.Fmt.Finish()
 
 	return nil
 }
+
+// runObjectUploader sends a run object to the server in chunks; only the first message of each attempt includes the
+// object's metadata.
+type runObjectUploader struct {
+	request *proto.PutRunObjectRequest
+	stream  proto.Gofer_PutRunObjectStreamClient
+	resp    *proto.PutRunObjectResponse
+	sent    bool
+}
+
+func (u *runObjectUploader) Send(chunk []byte) error {
+	if u.sent {
+		return u.stream.Send(&proto.PutRunObjectRequest{Content: chunk})
+	}
+
+	u.sent = true
+	u.request.Content = chunk
+	return u.stream.Send(u.request)
+}
+
+func (u *runObjectUploader) Close() error {
+	resp, err := u.stream.CloseAndRecv()
+	if err != nil {
+		return err
+	}
+
+	u.resp = resp
+	return nil
+}
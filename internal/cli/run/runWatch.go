@@ -0,0 +1,262 @@
+package run
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdRunWatch = &cobra.Command{
+	Use:   "watch <pipeline> <id>",
+	Short: "Watch a run until it finishes",
+	Long: `Watch a run until it finishes.
+
+Shows the run's task runs and redraws them in place whenever one of them changes state. When the output is not a
+terminal(ex. within CI) a line is printed for every state change instead.
+
+The command exits once the run is complete, with a non-zero exit code if the run did not finish successfully.`,
+	Example: `$ gofer run watch simple_test_pipeline 23`,
+	RunE:    runWatch,
+	Args:    cobra.ExactArgs(2),
+}
+
+func init() {
+	CmdRun.AddCommand(cmdRunWatch)
+}
+
+// watchRefreshInterval is how often the run is retrieved even without any new events; this keeps durations current
+// and makes sure the view catches up if the event stream is interrupted.
+const watchRefreshInterval = time.Second * 5
+
+func runWatch(_ *cobra.Command, args []string) error {
+	pipelineID := args[0]
+
+	id, err := strconv.ParseInt(args[1], 10, 64)
+	if err != nil {
+		return err
+	}
+
+	cl.State.Fmt.Print("Retrieving run")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx, cancel := context.WithCancel(metadata.NewOutgoingContext(context.Background(), md))
+	defer cancel()
+
+	// We subscribe before retrieving the run so that no state change can happen in between the two unnoticed.
+	stream, err := client.ListEvents(ctx, &proto.ListEventsRequest{
+		Follow:   true,
+		FromTime: time.Now().UnixMilli(),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not subscribe to events: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	changes := make(chan struct{}, 1)
+	go watchRunEvents(stream, cl.State.Config.Namespace, pipelineID, id, changes)
+
+	view := &runView{
+		out:         os.Stdout,
+		interactive: cl.State.Config.Format == "pretty" && term.IsTerminal(int(os.Stdout.Fd())),
+		states:      map[string]proto.TaskRun_State{},
+	}
+
+	ticker := time.NewTicker(watchRefreshInterval)
+	defer ticker.Stop()
+
+	var run *proto.Run
+	for {
+		var taskRuns []*proto.TaskRun
+		run, taskRuns, err = getRunWithTaskRuns(ctx, client, pipelineID, id)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+
+		if view.interactive && view.lines == 0 {
+			// The spinner would fight with the view over the terminal so it is stopped before the first render.
+			cl.State.Fmt.Finish()
+		}
+
+		view.render(run, taskRuns)
+
+		runModel := models.Run{}
+		runModel.FromProto(run)
+		if runModel.IsComplete() {
+			break
+		}
+
+		select {
+		case <-changes:
+		case <-ticker.C:
+		}
+	}
+
+	if view.interactive {
+		cl.State.NewFormatter()
+	}
+
+	if cl.State.Structured() {
+		if err := cl.State.PrintStructured(run); err != nil {
+			return err
+		}
+	}
+
+	duration := cliformat.Duration(run.Started, run.Ended)
+
+	if run.State != proto.Run_SUCCESS {
+		err := fmt.Errorf("run (%d) finished with state %s after %s", run.Id, run.State, duration)
+		if run.Failure != nil && run.Failure.Description != "" {
+			err = fmt.Errorf("%w: %s", err, run.Failure.Description)
+		}
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Run (%d) finished successfully after %s", run.Id, duration))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+// watchRunEvents reads the event stream and signals on changes whenever an event for the given run arrives. Signals
+// are dropped while one is still pending since a single refresh picks up all changes. If the stream fails the watch
+// falls back to refreshing periodically.
+func watchRunEvents(stream proto.Gofer_ListEventsClient, namespace, pipeline string, run int64,
+	changes chan<- struct{},
+) {
+	for {
+		resp, err := stream.Recv()
+		if err != nil {
+			return
+		}
+
+		if resp.Keepalive || !isRunEvent(resp, namespace, pipeline, run) {
+			continue
+		}
+
+		select {
+		case changes <- struct{}{}:
+		default:
+		}
+	}
+}
+
+// isRunEvent reports whether the event is about the given run or one of its task runs.
+func isRunEvent(resp *proto.ListEventsResponse, namespace, pipeline string, run int64) bool {
+	switch event := resp.Event.(type) {
+	case *proto.ListEventsResponse_CompletedRunEvent:
+		e := event.CompletedRunEvent
+		return e.NamespaceId == namespace && e.PipelineId == pipeline && e.RunId == run
+	case *proto.ListEventsResponse_ScheduledTaskRunEvent:
+		e := event.ScheduledTaskRunEvent
+		return e.NamespaceId == namespace && e.PipelineId == pipeline && e.RunId == run
+	case *proto.ListEventsResponse_StartedTaskRunEvent:
+		e := event.StartedTaskRunEvent
+		return e.NamespaceId == namespace && e.PipelineId == pipeline && e.RunId == run
+	case *proto.ListEventsResponse_CompletedTaskRunEvent:
+		e := event.CompletedTaskRunEvent
+		return e.NamespaceId == namespace && e.PipelineId == pipeline && e.RunId == run
+	default:
+		return false
+	}
+}
+
+func getRunWithTaskRuns(ctx context.Context, client proto.GoferClient, pipeline string, id int64,
+) (*proto.Run, []*proto.TaskRun, error) {
+	runResp, err := client.GetRun(ctx, &proto.GetRunRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipeline,
+		Id:          id,
+	})
+	if err != nil {
+		return nil, nil, fmt.Errorf("could not get run: %w", err)
+	}
+
+	taskRunsResp, err := client.ListTaskRuns(ctx, &proto.ListTaskRunsRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipeline,
+		RunId:       id,
+	})
+	if err != nil {
+		return nil, nil, fmt.Errorf("could not list task runs: %w", err)
+	}
+
+	taskRuns := taskRunsResp.TaskRuns
+
+	// Task runs that haven't started yet are shown last so the order stays stable as the run progresses.
+	sort.Slice(taskRuns, func(i, j int) bool {
+		if (taskRuns[i].Started == 0) != (taskRuns[j].Started == 0) {
+			return taskRuns[j].Started == 0
+		}
+		if taskRuns[i].Started != taskRuns[j].Started {
+			return taskRuns[i].Started < taskRuns[j].Started
+		}
+		return taskRuns[i].Id < taskRuns[j].Id
+	})
+
+	return runResp.Run, taskRuns, nil
+}
+
+// runView displays the state of a run. Interactive views redraw the whole table in place; otherwise only the task
+// runs whose state changed since the last render are printed.
+type runView struct {
+	out         io.Writer
+	interactive bool
+
+	// lines is the amount of lines printed by the last interactive render.
+	lines  int
+	states map[string]proto.TaskRun_State
+}
+
+func (v *runView) render(run *proto.Run, taskRuns []*proto.TaskRun) {
+	if !v.interactive {
+		for _, taskRun := range taskRuns {
+			if state, exists := v.states[taskRun.Id]; exists && state == taskRun.State {
+				continue
+			}
+			v.states[taskRun.Id] = taskRun.State
+			cl.State.Fmt.Println(fmt.Sprintf("  %s %s", logPrefix(taskRun.Id),
+				cliformat.TaskRunState(taskRun.State.String())))
+		}
+		return
+	}
+
+	output := fmt.Sprintf("Run %s for pipeline %s :: %s :: %s\n\n%s",
+		color.BlueString("#%d", run.Id), color.BlueString(run.PipelineId), cliformat.RunState(run.State.String()),
+		cliformat.Duration(run.Started, run.Ended), formatWaitTable(taskRuns))
+
+	// Move the cursor back to the start of the previous render and clear everything below it.
+	if v.lines > 0 {
+		fmt.Fprintf(v.out, "\x1b[%dA\x1b[J", v.lines)
+	}
+
+	fmt.Fprint(v.out, output)
+	v.lines = strings.Count(output, "\n")
+}
@@ -0,0 +1,14 @@
+package account
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdAccount = &cobra.Command{
+	Use:   "account",
+	Short: "Manage service accounts",
+	Long: `Manage service accounts.
+
+Service accounts are identities meant for automation rather than people. A service account holds the permissions
+for all of its tokens, owns the pipelines it creates, and is recorded as the initiator of the runs it starts.`,
+}
@@ -0,0 +1,78 @@
+package account
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdAccountCreate = &cobra.Command{
+	Use:   "create <id> <management|client|namespace_admin>",
+	Short: "Create a new service account",
+	Long: `Create a new service account.
+
+The kind and namespaces of the service account apply to all of its tokens. Use 'gofer service account token' to
+create tokens for the service account once it exists.`,
+	Example: `$ gofer service account create deploy-bot client --namespaces default,my_team
+$ gofer service account create deploy-bot client --description="deploys from ci"`,
+	RunE: accountCreate,
+	Args: cobra.ExactArgs(2),
+}
+
+func init() {
+	cmdAccountCreate.Flags().StringSliceP("namespaces", "n", []string{"default"}, "namespaces this service account will have access to. If not specified namespace is default")
+	cmdAccountCreate.Flags().StringP("description", "d", "", "description on use for service account")
+	CmdAccount.AddCommand(cmdAccountCreate)
+}
+
+func accountCreate(cmd *cobra.Command, args []string) error {
+	id := args[0]
+	kind := args[1]
+	namespaces, _ := cmd.Flags().GetStringSlice("namespaces")
+	description, _ := cmd.Flags().GetString("description")
+
+	if kind != "management" && kind != "client" && kind != "namespace_admin" {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("invalid kind %q", kind))
+		cl.State.Fmt.Finish()
+		return fmt.Errorf("invalid kind")
+	}
+
+	cl.State.Fmt.Print("Creating service account")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.CreateServiceAccount(ctx, &proto.CreateServiceAccountRequest{
+		Id:          id,
+		Description: description,
+		Kind:        proto.Token_Kind(proto.Token_Kind_value[strings.ToUpper(kind)]),
+		Namespaces:  namespaces,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not create service account: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.ServiceAccount)
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Created service account: [%s]", resp.ServiceAccount.Id))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,57 @@
+package account
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdAccountDelete = &cobra.Command{
+	Use:   "delete <id>",
+	Short: "Delete a service account",
+	Long: `Delete a service account.
+
+All tokens belonging to the service account are deleted along with it. Pipelines it owns and runs it started keep
+referring to it.`,
+	Example: `$ gofer service account delete deploy-bot`,
+	RunE:    accountDelete,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdAccount.AddCommand(cmdAccountDelete)
+}
+
+func accountDelete(_ *cobra.Command, args []string) error {
+	id := args[0]
+
+	cl.State.Fmt.Print("Deleting service account")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	_, err = client.DeleteServiceAccount(ctx, &proto.DeleteServiceAccountRequest{
+		Id: id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not delete service account: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Deleted service account: [%s]", id))
+	cl.State.Fmt.Finish()
+	return nil
+}
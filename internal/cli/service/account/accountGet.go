@@ -0,0 +1,98 @@
+package account
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"strings"
+	"text/template"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdAccountGet = &cobra.Command{
+	Use:     "get <id>",
+	Short:   "Get details on a specific service account",
+	Example: `$ gofer service account get deploy-bot`,
+	RunE:    accountGet,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdAccount.AddCommand(cmdAccountGet)
+}
+
+func accountGet(cmd *cobra.Command, args []string) error {
+	id := args[0]
+
+	detail, _ := cmd.Flags().GetBool("detail")
+
+	cl.State.Fmt.Print("Retrieving service account")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.GetServiceAccount(ctx, &proto.GetServiceAccountRequest{
+		Id: id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get service account: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.ServiceAccount)
+	}
+
+	cl.State.Fmt.Println(formatAccount(resp.ServiceAccount, detail))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+type data struct {
+	ID          string
+	Kind        string
+	Namespaces  string
+	Description string
+	Created     string
+}
+
+func formatAccount(account *proto.ServiceAccount, detail bool) string {
+	data := data{
+		ID:          color.BlueString(account.Id),
+		Kind:        strings.ToLower(account.Kind.String()),
+		Namespaces:  strings.Join(account.Namespaces, ", "),
+		Description: account.Description,
+		Created:     cliformat.UnixMilli(account.Created, "Never", detail),
+	}
+
+	const formatTmpl = `[{{.ID}}] {{.Kind}} :: Created {{.Created}}
+{{- if .Namespaces}}
+
+  Namespaces: {{.Namespaces}}
+{{- end}}
+{{- if .Description}}
+
+  {{.Description}}
+{{- end}}`
+
+	var tpl bytes.Buffer
+	t := template.Must(template.New("tmp").Parse(formatTmpl))
+	_ = t.Execute(&tpl, data)
+	return tpl.String()
+}
@@ -0,0 +1,120 @@
+package account
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdAccountList = &cobra.Command{
+	Use:     "list",
+	Short:   "List all service accounts",
+	Example: `$ gofer service account list`,
+	RunE:    accountList,
+}
+
+func init() {
+	cmdAccountList.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	CmdAccount.AddCommand(cmdAccountList)
+}
+
+func accountList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+
+	cl.State.Fmt.Print("Retrieving service accounts")
+
+	limit, err := cmd.Flags().GetInt("limit")
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListServiceAccounts(ctx, &proto.ListServiceAccountsRequest{
+		Limit: int64(limit),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list service accounts: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.ServiceAccounts)
+	}
+
+	data := [][]string{}
+	for _, account := range resp.ServiceAccounts {
+		data = append(data, []string{
+			account.Id,
+			strings.ToLower(account.Kind.String()),
+			strings.Join(account.Namespaces, ", "),
+			account.Description,
+			cliformat.UnixMilli(account.Created, "Never", detail),
+		})
+	}
+
+	table := formatTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func formatTable(data [][]string, color bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"ID", "Kind", "Namespaces", "Description", "Created"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if color {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
@@ -0,0 +1,80 @@
+package account
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdAccountToken = &cobra.Command{
+	Use:   "token <id>",
+	Short: "Create a new token for a service account",
+	Long: `Create a new token for a service account.
+
+A service account can hold multiple tokens at once. To rotate a token without downtime, either create a new token,
+switch over to it and then delete the old one with 'gofer service token delete', or rotate it with a grace period
+using 'gofer service token rotate --grace-period'.`,
+	Example: `$ gofer service account token deploy-bot --metadata host:ci-runner-1
+$ gofer service account token deploy-bot --allowed-cidrs 10.20.0.0/16
+$ gofer service account token deploy-bot --ttl 2160h`,
+	RunE:    accountToken,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdAccountToken.Flags().StringSliceP("metadata", "m", []string{}, "metadata about the token, useful for attaching where it is used and other details. Format = key:value")
+	cmdAccountToken.Flags().StringSlice("allowed-cidrs", []string{}, "only allow the token to be used from these networks. Ex: 10.0.0.0/8")
+	cmdAccountToken.Flags().Duration("ttl", 0, "how long the token lasts before it expires; never expires if not specified")
+	CmdAccount.AddCommand(cmdAccountToken)
+}
+
+func accountToken(cmd *cobra.Command, args []string) error {
+	id := args[0]
+	metadataSlice, _ := cmd.Flags().GetStringSlice("metadata")
+	allowedCIDRs, _ := cmd.Flags().GetStringSlice("allowed-cidrs")
+	ttl, _ := cmd.Flags().GetDuration("ttl")
+
+	tokenMetadata := map[string]string{}
+	for _, keyPairString := range metadataSlice {
+		key, value, ok := strings.Cut(keyPairString, ":")
+		if !ok {
+			continue
+		}
+
+		tokenMetadata[key] = value
+	}
+
+	cl.State.Fmt.Print("Creating token")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.CreateServiceAccountToken(ctx, &proto.CreateServiceAccountTokenRequest{
+		Id:           id,
+		Metadata:     tokenMetadata,
+		AllowedCidrs: allowedCIDRs,
+		Ttl:          int64(ttl.Seconds()),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not create token: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Token: %s", resp.Token))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,15 @@
+package audit
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdAudit = &cobra.Command{
+	Use:   "audit",
+	Short: "Review the authorization audit log",
+	Long: `Review the authorization audit log.
+
+When enabled, Gofer records every authorization decision(who called which route, on what, and whether they were
+allowed) so that access can be reviewed later. Tokens are referred to by their hash, which can be found with
+"gofer service token get".`,
+}
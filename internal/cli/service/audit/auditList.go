@@ -0,0 +1,162 @@
+package audit
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdAuditList = &cobra.Command{
+	Use:   "list",
+	Short: "List authorization decisions",
+	Long:  `List authorization decisions, newest first.`,
+	Example: `$ gofer service audit list
+$ gofer service audit list --denied --since 24h
+$ gofer service audit list --caller token:<hash>`,
+	RunE: auditList,
+}
+
+func init() {
+	cmdAuditList.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	cmdAuditList.Flags().String("caller", "", "only show decisions for the given caller. Ex: token:<hash>, oidc:<subject>")
+	cmdAuditList.Flags().Bool("denied", false, "only show decisions in which the caller was denied")
+	cmdAuditList.Flags().Duration("since", 0, "only show decisions made within the given duration (ex. 10m, 1h)")
+	CmdAudit.AddCommand(cmdAuditList)
+}
+
+func auditList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+	caller, _ := cmd.Flags().GetString("caller")
+	denied, _ := cmd.Flags().GetBool("denied")
+	sinceDuration, _ := cmd.Flags().GetDuration("since")
+
+	cl.State.Fmt.Print("Retrieving authorization decisions")
+
+	limit, err := cmd.Flags().GetInt("limit")
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	since := int64(0)
+	if sinceDuration > 0 {
+		since = time.Now().Add(-sinceDuration).UnixMilli()
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListAuthzDecisions(ctx, &proto.ListAuthzDecisionsRequest{
+		Limit:      int64(limit),
+		Caller:     caller,
+		DeniedOnly: denied,
+		Since:      since,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list authorization decisions: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Decisions)
+	}
+
+	data := [][]string{}
+	for _, decision := range resp.Decisions {
+		data = append(data, []string{
+			cliformat.UnixMilli(decision.Timestamp, "Unknown", detail),
+			decision.Caller,
+			decision.Action,
+			decision.Resource,
+			decision.Route,
+			formatResult(decision, !noColor),
+		})
+	}
+
+	table := formatTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func formatResult(decision *proto.AuthzDecision, colorize bool) string {
+	if decision.Allowed {
+		if colorize {
+			return color.GreenString("Allowed")
+		}
+		return "Allowed"
+	}
+
+	result := "Denied"
+	if colorize {
+		result = color.RedString(result)
+	}
+
+	if decision.Reason != "" {
+		result = fmt.Sprintf("%s: %s", result, decision.Reason)
+	}
+
+	return result
+}
+
+func formatTable(data [][]string, colorize bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Time", "Caller", "Action", "Resource", "Route", "Result"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if colorize {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(0),
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
@@ -0,0 +1,15 @@
+package deadletter
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdDeadLetter = &cobra.Command{
+	Use:   "dead-letter",
+	Short: "Inspect and redrive failed dispatches",
+	Long: `Inspect and redrive failed dispatches.
+
+When Gofer can't deliver a notification to a webhook or restore a pipeline's trigger subscription, even after
+retrying, the dispatch is saved as a dead letter instead of being dropped. Once the receiving end is fixed dead
+letters can be redriven to attempt them again.`,
+}
@@ -0,0 +1,59 @@
+package deadletter
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdDeadLetterDelete = &cobra.Command{
+	Use:     "delete <id>",
+	Short:   "Delete a dead letter without attempting it again",
+	Example: `$ gofer service dead-letter delete 3`,
+	RunE:    deadLetterDelete,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdDeadLetter.AddCommand(cmdDeadLetterDelete)
+}
+
+func deadLetterDelete(_ *cobra.Command, args []string) error {
+	id, err := strconv.ParseInt(args[0], 10, 64)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse dead letter id: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Deleting dead letter")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	_, err = client.DeleteDeadLetter(ctx, &proto.DeleteDeadLetterRequest{
+		Id: id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not delete dead letter: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Deleted dead letter: [%d]", id))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,112 @@
+package deadletter
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+	"text/template"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdDeadLetterGet = &cobra.Command{
+	Use:     "get <id>",
+	Short:   "Get details on a specific dead letter",
+	Example: `$ gofer service dead-letter get 3`,
+	RunE:    deadLetterGet,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdDeadLetter.AddCommand(cmdDeadLetterGet)
+}
+
+func deadLetterGet(cmd *cobra.Command, args []string) error {
+	detail, _ := cmd.Flags().GetBool("detail")
+
+	id, err := strconv.ParseInt(args[0], 10, 64)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse dead letter id: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Retrieving dead letter")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.GetDeadLetter(ctx, &proto.GetDeadLetterRequest{
+		Id: id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get dead letter: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.DeadLetter)
+	}
+
+	cl.State.Fmt.Println(formatDeadLetter(resp.DeadLetter, detail))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+type data struct {
+	ID       string
+	Kind     string
+	Resource string
+	Target   string
+	Attempts int64
+	Created  string
+	Updated  string
+	Error    string
+	Payload  string
+}
+
+func formatDeadLetter(deadLetter *proto.DeadLetter, detail bool) string {
+	data := data{
+		ID:       color.BlueString(strconv.FormatInt(deadLetter.Id, 10)),
+		Kind:     strings.ToLower(deadLetter.Kind.String()),
+		Resource: formatResource(deadLetter),
+		Target:   deadLetter.Target,
+		Attempts: deadLetter.Attempts,
+		Created:  cliformat.UnixMilli(deadLetter.Created, "Never", detail),
+		Updated:  cliformat.UnixMilli(deadLetter.Updated, "Never", detail),
+		Error:    color.RedString(deadLetter.Error),
+		Payload:  deadLetter.Payload,
+	}
+
+	const formatTmpl = `[{{.ID}}] {{.Kind}} for {{.Resource}} :: Failed {{.Created}}
+
+  Target: {{.Target}}
+  Attempts: {{.Attempts}} (last attempt {{.Updated}})
+  Error: {{.Error}}
+{{- if .Payload}}
+
+  Payload: {{.Payload}}
+{{- end}}`
+
+	var tpl bytes.Buffer
+	t := template.Must(template.New("tmp").Parse(formatTmpl))
+	_ = t.Execute(&tpl, data)
+	return tpl.String()
+}
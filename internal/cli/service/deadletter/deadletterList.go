@@ -0,0 +1,135 @@
+package deadletter
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdDeadLetterList = &cobra.Command{
+	Use:     "list",
+	Short:   "List dead letters",
+	Long:    `List dead letters, newest first.`,
+	Example: `$ gofer service dead-letter list`,
+	RunE:    deadLetterList,
+}
+
+func init() {
+	cmdDeadLetterList.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	CmdDeadLetter.AddCommand(cmdDeadLetterList)
+}
+
+func deadLetterList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+
+	cl.State.Fmt.Print("Retrieving dead letters")
+
+	limit, err := cmd.Flags().GetInt("limit")
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListDeadLetters(ctx, &proto.ListDeadLettersRequest{
+		Limit: int64(limit),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list dead letters: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.DeadLetters)
+	}
+
+	data := [][]string{}
+	for _, deadLetter := range resp.DeadLetters {
+		data = append(data, []string{
+			strconv.FormatInt(deadLetter.Id, 10),
+			strings.ToLower(deadLetter.Kind.String()),
+			formatResource(deadLetter),
+			deadLetter.Target,
+			strconv.FormatInt(deadLetter.Attempts, 10),
+			cliformat.UnixMilli(deadLetter.Updated, "Never", detail),
+		})
+	}
+
+	table := formatTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+// formatResource returns the pipeline a dead letter is about, or just the namespace for dispatches about a whole
+// namespace.
+func formatResource(deadLetter *proto.DeadLetter) string {
+	if deadLetter.PipelineId == "" {
+		return deadLetter.NamespaceId
+	}
+
+	return fmt.Sprintf("%s/%s", deadLetter.NamespaceId, deadLetter.PipelineId)
+}
+
+func formatTable(data [][]string, colorize bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"ID", "Kind", "Resource", "Target", "Attempts", "Last Attempt"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if colorize {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
@@ -0,0 +1,64 @@
+package deadletter
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdDeadLetterRedrive = &cobra.Command{
+	Use:   "redrive <id>",
+	Short: "Attempt a dead letter's dispatch again",
+	Long: `Attempt a dead letter's dispatch again.
+
+The dead letter is removed if the dispatch succeeds. Redriving a trigger subscription subscribes the pipeline using
+its current configuration and marks the trigger as active again; the pipeline itself stays disabled until it's enabled
+with "gofer pipeline enable".`,
+	Example: `$ gofer service dead-letter redrive 3`,
+	RunE:    deadLetterRedrive,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdDeadLetter.AddCommand(cmdDeadLetterRedrive)
+}
+
+func deadLetterRedrive(_ *cobra.Command, args []string) error {
+	id, err := strconv.ParseInt(args[0], 10, 64)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse dead letter id: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Redriving dead letter")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	_, err = client.RedriveDeadLetter(ctx, &proto.RedriveDeadLetterRequest{
+		Id: id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not redrive dead letter: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Redrove dead letter: [%d]", id))
+	cl.State.Fmt.Finish()
+	return nil
+}
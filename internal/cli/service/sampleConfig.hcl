@@ -30,6 +30,8 @@ object_store {
   }
   pipeline_object_limit = 10
   run_object_expiry     = 20
+  artifact_expiry       = 20
+  cache_budget_mb       = 5000
 }
 
 secret_store {
@@ -57,9 +59,7 @@ server {
 }
 
 triggers {
-  stop_timeout         = "5m"
-  healthcheck_interval = "30s"
-  tls_cert_path        = "./localhost.crt"
-  tls_key_path         = "./localhost.key"
+  tls_cert_path = "./localhost.crt"
+  tls_key_path  = "./localhost.key"
 }
 
@@ -1,6 +1,9 @@
 package service
 
 import (
+	"github.com/clintjedwards/gofer/internal/cli/service/account"
+	"github.com/clintjedwards/gofer/internal/cli/service/audit"
+	"github.com/clintjedwards/gofer/internal/cli/service/deadletter"
 	"github.com/clintjedwards/gofer/internal/cli/service/token"
 	"github.com/spf13/cobra"
 )
@@ -14,5 +17,8 @@ These commands help with managing and running the Gofer service.`,
 }
 
 func init() {
+	CmdService.AddCommand(account.CmdAccount)
+	CmdService.AddCommand(audit.CmdAudit)
+	CmdService.AddCommand(deadletter.CmdDeadLetter)
 	CmdService.AddCommand(token.CmdToken)
 }
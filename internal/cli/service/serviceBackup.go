@@ -0,0 +1,119 @@
+package service
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/dustin/go-humanize"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdServiceBackup = &cobra.Command{
+	Use:   "backup",
+	Short: "Download a backup of the server's databases",
+	Long: `Download a backup of the server's databases.
+
+The backup is a gzipped tarball containing a consistent copy of the main database, the secret store and the object
+store. The server keeps running while the backup is taken. The object store only holds cached artifacts and is
+usually by far the largest database, so it can be left out with --skip-objects.
+
+Requires a management token. Backups contain all secrets(encrypted with the server's encryption key) and tokens so
+store them somewhere safe. Use "gofer service restore" to restore a backup.`,
+	Example: `$ gofer service backup
+$ gofer service backup --out /backups/gofer.tar.gz --skip-objects`,
+	RunE: serviceBackup,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdServiceBackup.Flags().StringP("out", "o", "", "file path to write the backup to (default gofer-backup-<timestamp>.tar.gz)")
+	cmdServiceBackup.Flags().Bool("skip-objects", false, "leave the object store out of the backup")
+	CmdService.AddCommand(cmdServiceBackup)
+}
+
+func serviceBackup(cmd *cobra.Command, _ []string) error {
+	out, _ := cmd.Flags().GetString("out")
+	skipObjects, _ := cmd.Flags().GetBool("skip-objects")
+
+	if out == "" {
+		out = fmt.Sprintf("gofer-backup-%d.tar.gz", time.Now().Unix())
+	}
+
+	cl.State.Fmt.Print("Creating backup")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	stream, err := client.CreateBackup(ctx, &proto.CreateBackupRequest{
+		SkipObjects: skipObjects,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not create backup: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	// Write to a temporary file first so that a failed backup never leaves a partial file at the given path.
+	tmpPath := out + ".partial"
+	file, err := os.OpenFile(tmpPath, os.O_CREATE|os.O_WRONLY|os.O_TRUNC, 0600)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not create backup file: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+	defer os.Remove(tmpPath)
+
+	written := uint64(0)
+	for {
+		resp, err := stream.Recv()
+		if err != nil {
+			if err == io.EOF {
+				break
+			}
+			file.Close()
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not download backup: %v", err))
+			cl.State.Fmt.Finish()
+			return err
+		}
+
+		_, err = file.Write(resp.Chunk)
+		if err != nil {
+			file.Close()
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not write backup: %v", err))
+			cl.State.Fmt.Finish()
+			return err
+		}
+
+		written += uint64(len(resp.Chunk))
+		cl.State.Fmt.Print(fmt.Sprintf("Downloading backup (%s)", humanize.Bytes(written)))
+	}
+
+	err = file.Close()
+	if err == nil {
+		err = os.Rename(tmpPath, out)
+	}
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not write backup: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Wrote backup (%s) to %q", humanize.Bytes(written), out))
+	cl.State.Fmt.Finish()
+	return nil
+}
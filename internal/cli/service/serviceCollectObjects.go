@@ -0,0 +1,119 @@
+package service
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/dustin/go-humanize"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdServiceCollectObjects = &cobra.Command{
+	Use:   "collect-objects",
+	Short: "Remove objects whose pipeline or run no longer exists",
+	Long: `Remove objects whose pipeline or run no longer exists.
+
+Deleting a pipeline or run doesn't remove the objects stored for it, leaving them in the object store forever. This
+command finds those objects and removes them.
+
+Use --dry-run to see which objects would be removed without removing them.`,
+	Example: `$ gofer service collect-objects --dry-run
+$ gofer service collect-objects`,
+	RunE: serviceCollectObjects,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdServiceCollectObjects.Flags().Bool("dry-run", false, "only list the objects that would be removed")
+	CmdService.AddCommand(cmdServiceCollectObjects)
+}
+
+func serviceCollectObjects(cmd *cobra.Command, _ []string) error {
+	dryRun, _ := cmd.Flags().GetBool("dry-run")
+
+	cl.State.Fmt.Print("Collecting orphaned objects")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.CollectOrphanedObjects(ctx, &proto.CollectOrphanedObjectsRequest{
+		DryRun: dryRun,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not collect orphaned objects: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if len(resp.Objects) == 0 {
+		cl.State.Fmt.PrintSuccess("No orphaned objects found")
+		cl.State.Fmt.Finish()
+		return nil
+	}
+
+	cl.State.Fmt.Println(formatOrphanedObjects(resp.Objects, !cl.State.Config.NoColor))
+
+	if dryRun {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Found %d orphaned objects(%s); nothing was removed",
+			len(resp.Objects), humanize.Bytes(uint64(resp.Bytes))))
+	} else {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Removed %d orphaned objects(%s)",
+			len(resp.Objects), humanize.Bytes(uint64(resp.Bytes))))
+	}
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+func formatOrphanedObjects(objects []*proto.OrphanedObject, colorize bool) string {
+	data := [][]string{}
+	for _, object := range objects {
+		data = append(data, []string{
+			object.Key,
+			humanize.Bytes(uint64(object.Bytes)),
+			object.Reason,
+		})
+	}
+
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	headers := []string{"Key", "Size", "Reason"}
+
+	table.SetHeader(headers)
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if colorize {
+		headerColors := []tablewriter.Colors{}
+		for range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+		}
+		table.SetHeaderColor(headerColors...)
+	}
+
+	table.AppendBulk(data)
+	table.Render()
+
+	return tableString.String()
+}
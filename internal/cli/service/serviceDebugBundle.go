@@ -0,0 +1,250 @@
+package service
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"reflect"
+	"runtime"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/protobuf/encoding/protojson"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+var cmdServiceDebugBundle = &cobra.Command{
+	Use:   "debug-bundle",
+	Short: "Gather diagnostic information into a tarball for bug reports",
+	Long: `Gather diagnostic information into a tarball for bug reports.
+
+The bundle contains:
+  - Metadata about the machine the command was run on.
+  - The server's system information.
+  - The most recent events.
+  - The state of all installed triggers and notifiers along with the scheduler containers they run in.
+  - The CLI configuration and, if the --server-config flag is given, the server configuration.
+
+All secret values(tokens, keys and passwords) are redacted from the configuration. Information that could not be
+gathered is listed in the bundle's errors.txt file instead of failing the whole bundle.`,
+	Example: `$ gofer service debug-bundle
+$ gofer service debug-bundle --out /tmp/gofer-debug.tar.gz --server-config /etc/gofer/gofer.hcl`,
+	RunE: serviceDebugBundle,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdServiceDebugBundle.Flags().StringP("out", "o", "", "file path to write the bundle to (default gofer-debug-<timestamp>.tar.gz)")
+	cmdServiceDebugBundle.Flags().String("server-config", "", "path to the server configuration file to include")
+	cmdServiceDebugBundle.Flags().Int("events", 500, "the amount of recent events to include")
+	CmdService.AddCommand(cmdServiceDebugBundle)
+}
+
+// debugBundle collects the files that make up the bundle. Failures to gather a file are recorded instead of aborting.
+type debugBundle struct {
+	files  map[string][]byte
+	order  []string
+	errors []string
+}
+
+func (b *debugBundle) add(name string, content []byte) {
+	b.files[name] = content
+	b.order = append(b.order, name)
+}
+
+func (b *debugBundle) fail(name string, err error) {
+	b.errors = append(b.errors, fmt.Sprintf("%s: %v", name, err))
+}
+
+func serviceDebugBundle(cmd *cobra.Command, _ []string) error {
+	out, _ := cmd.Flags().GetString("out")
+	serverConfigPath, _ := cmd.Flags().GetString("server-config")
+	eventLimit, _ := cmd.Flags().GetInt("events")
+
+	now := time.Now()
+	if out == "" {
+		out = fmt.Sprintf("gofer-debug-%d.tar.gz", now.Unix())
+	}
+
+	bundle := &debugBundle{
+		files: map[string][]byte{},
+	}
+
+	cl.State.Fmt.Print("Gathering metadata")
+
+	hostname, _ := os.Hostname()
+	meta, _ := json.MarshalIndent(map[string]string{
+		"generated":  now.Format(time.RFC3339),
+		"hostname":   hostname,
+		"os":         runtime.GOOS,
+		"arch":       runtime.GOARCH,
+		"go_version": runtime.Version(),
+		"host":       cl.State.Config.Host,
+		"namespace":  cl.State.Config.Namespace,
+	}, "", "  ")
+	bundle.add("metadata.json", meta)
+
+	cliConfig := &strings.Builder{}
+	writeConfig(cliConfig, "", reflect.ValueOf(cl.State.Config))
+	bundle.add("cli_config.txt", []byte(cliConfig.String()))
+
+	if serverConfigPath != "" {
+		conf, err := config.InitAPIConfig(serverConfigPath)
+		if err != nil {
+			bundle.fail("server_config.txt", err)
+		} else {
+			serverConfig := &strings.Builder{}
+			writeConfig(serverConfig, "", reflect.ValueOf(conf))
+			bundle.add("server_config.txt", []byte(serverConfig.String()))
+		}
+	}
+
+	cl.State.Fmt.Print("Gathering server information")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		bundle.fail("connect", err)
+	} else {
+		defer conn.Close()
+
+		client := proto.NewGoferClient(conn)
+
+		md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+		ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+		systemInfo, err := client.GetSystemInfo(ctx, &proto.GetSystemInfoRequest{})
+		addMessage(bundle, "system_info.json", systemInfo, err)
+
+		triggers, err := client.ListTriggers(ctx, &proto.ListTriggersRequest{})
+		addMessage(bundle, "triggers.json", triggers, err)
+
+		notifiers, err := client.ListNotifiers(ctx, &proto.ListNotifiersRequest{})
+		addMessage(bundle, "notifiers.json", notifiers, err)
+
+		cl.State.Fmt.Print("Gathering recent events")
+
+		events, err := recentEvents(ctx, client, eventLimit)
+		if err != nil {
+			bundle.fail("events.json", err)
+		} else {
+			bundle.add("events.json", events)
+		}
+	}
+
+	if len(bundle.errors) > 0 {
+		bundle.add("errors.txt", []byte(strings.Join(bundle.errors, "\n")+"\n"))
+	}
+
+	cl.State.Fmt.Print("Writing bundle")
+
+	err = writeBundle(out, now, bundle)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not write bundle: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	for _, e := range bundle.errors {
+		cl.State.Fmt.Println(fmt.Sprintf("  could not gather %s", e))
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Wrote debug bundle to %q", out))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func addMessage(bundle *debugBundle, name string, message protoreflect.ProtoMessage, err error) {
+	if err != nil {
+		bundle.fail(name, err)
+		return
+	}
+
+	content, err := protojson.MarshalOptions{Multiline: true}.Marshal(message)
+	if err != nil {
+		bundle.fail(name, err)
+		return
+	}
+
+	bundle.add(name, content)
+}
+
+// recentEvents returns the most recent events, newest first, as a JSON array.
+func recentEvents(ctx context.Context, client proto.GoferClient, limit int) ([]byte, error) {
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+
+	stream, err := client.ListEvents(ctx, &proto.ListEventsRequest{
+		Reverse: true,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	events := []json.RawMessage{}
+	for len(events) < limit {
+		resp, err := stream.Recv()
+		if err != nil {
+			if err == io.EOF {
+				break
+			}
+			return nil, err
+		}
+
+		if resp.Keepalive {
+			continue
+		}
+
+		event, err := protojson.Marshal(resp)
+		if err != nil {
+			return nil, err
+		}
+
+		events = append(events, event)
+	}
+
+	return json.MarshalIndent(events, "", "  ")
+}
+
+func writeBundle(path string, modified time.Time, bundle *debugBundle) error {
+	file, err := os.Create(path)
+	if err != nil {
+		return err
+	}
+	defer file.Close()
+
+	gzipWriter := gzip.NewWriter(file)
+	tarWriter := tar.NewWriter(gzipWriter)
+
+	for _, name := range bundle.order {
+		content := bundle.files[name]
+
+		err := tarWriter.WriteHeader(&tar.Header{
+			Name:    name,
+			Mode:    0644,
+			Size:    int64(len(content)),
+			ModTime: modified,
+		})
+		if err != nil {
+			return err
+		}
+
+		if _, err := tarWriter.Write(content); err != nil {
+			return err
+		}
+	}
+
+	if err := tarWriter.Close(); err != nil {
+		return err
+	}
+
+	return gzipWriter.Close()
+}
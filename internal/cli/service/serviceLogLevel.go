@@ -0,0 +1,113 @@
+package service
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdServiceLogLevel = &cobra.Command{
+	Use:   "log-level [level]",
+	Short: "View or change the level the server logs at",
+	Long: `View or change the level the server logs at without restarting it.
+
+Levels can also be set for individual modules. A module is the package a log line came from(ex. "scheduler/docker"
+or "api") and setting a level for a module also sets it for every module beneath it. Set a module to an empty level to
+have it go back to the overall level.
+
+Changes only last until the server is restarted.
+
+Valid levels are debug, info, warn, error, fatal and panic.`,
+	Example: `$ gofer service log-level
+$ gofer service log-level info
+$ gofer service log-level --module scheduler/docker=debug
+$ gofer service log-level --module scheduler/docker=
+$ gofer service log-level info --reset-modules`,
+	RunE: serviceLogLevel,
+	Args: cobra.MaximumNArgs(1),
+}
+
+func init() {
+	cmdServiceLogLevel.Flags().StringToStringP("module", "m", map[string]string{},
+		"set the level of a module; may be repeated")
+	cmdServiceLogLevel.Flags().Bool("reset-modules", false, "remove the levels of all modules")
+	CmdService.AddCommand(cmdServiceLogLevel)
+}
+
+func serviceLogLevel(cmd *cobra.Command, args []string) error {
+	modules, _ := cmd.Flags().GetStringToString("module")
+	resetModules, _ := cmd.Flags().GetBool("reset-modules")
+
+	level := ""
+	if len(args) > 0 {
+		level = args[0]
+	}
+
+	cl.State.Fmt.Print("Retrieving log level")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	if level == "" && len(modules) == 0 && !resetModules {
+		resp, err := client.GetLogLevel(ctx, &proto.GetLogLevelRequest{})
+		if err != nil {
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not get log level: %v", err))
+			cl.State.Fmt.Finish()
+			return err
+		}
+
+		cl.State.Fmt.Println(formatLogLevels(resp.Level, resp.Modules))
+		cl.State.Fmt.Finish()
+		return nil
+	}
+
+	resp, err := client.UpdateLogLevel(ctx, &proto.UpdateLogLevelRequest{
+		Level:        level,
+		Modules:      modules,
+		ResetModules: resetModules,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not update log level: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("Updated log level")
+	cl.State.Fmt.Println(formatLogLevels(resp.Level, resp.Modules))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+func formatLogLevels(level string, modules map[string]string) string {
+	var text strings.Builder
+	fmt.Fprintf(&text, "Logging at %s", color.YellowString(level))
+
+	names := make([]string, 0, len(modules))
+	for module := range modules {
+		names = append(names, module)
+	}
+	sort.Strings(names)
+
+	for _, module := range names {
+		fmt.Fprintf(&text, "\n  • %s :: %s", color.BlueString(module), color.YellowString(modules[module]))
+	}
+
+	return text.String()
+}
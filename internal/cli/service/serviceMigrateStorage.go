@@ -0,0 +1,137 @@
+package service
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/app"
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/spf13/cobra"
+)
+
+var cmdServiceMigrateStorage = &cobra.Command{
+	Use:   "migrate-storage",
+	Short: "Copy all of the server's state into another database",
+	Long: `Copy all of the server's state into another database.
+
+Copies namespaces, pipelines, pipeline config versions, runs, task runs, events, event cursors, dead letters, tokens,
+service accounts, authorization decisions, triggers and notifiers from the database in the server configuration(see the
+--server-config flag) into the database described by the configuration given with --to-config. Only the 'database'
+block of the destination configuration is used. The destination database must be empty.
+
+Once everything has been copied the number of records of each kind in both databases are compared to verify the
+copy. Events are given new IDs in the destination; event cursors are moved to match.
+
+The migration works on the databases directly, so it must be run on the machine the server runs on and the server
+must be stopped first. Point the server at the new database once the migration has finished.`,
+	Example: `$ gofer service migrate-storage --to-config /etc/gofer/new-database.hcl
+$ gofer service migrate-storage --server-config /etc/gofer/gofer.hcl --to-config /etc/gofer/new-database.hcl`,
+	RunE: serviceMigrateStorage,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdServiceMigrateStorage.Flags().String("server-config", "", "path to the server configuration file")
+	cmdServiceMigrateStorage.Flags().String("to-config", "", "path to a server configuration file describing the destination database")
+	_ = cmdServiceMigrateStorage.MarkFlagRequired("to-config")
+	CmdService.AddCommand(cmdServiceMigrateStorage)
+}
+
+func serviceMigrateStorage(cmd *cobra.Command, _ []string) error {
+	serverConfigPath, _ := cmd.Flags().GetString("server-config")
+	destinationConfigPath, _ := cmd.Flags().GetString("to-config")
+
+	cl.State.Fmt.Print("Reading server configuration")
+
+	sourceConfig, err := config.InitAPIConfig(serverConfigPath)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read server configuration: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	destinationConfig, err := config.InitAPIConfig(destinationConfigPath)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read destination configuration: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if sourceConfig.Database.Engine == destinationConfig.Database.Engine &&
+		sourceConfig.Database.BoltDB.Path == destinationConfig.Database.BoltDB.Path {
+		err := fmt.Errorf("source and destination are the same database")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	for _, target := range []string{sourceConfig.Database.BoltDB.Path, destinationConfig.Database.BoltDB.Path} {
+		err := ensureNotInUse(target)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
+	cl.State.Fmt.Print("Opening databases")
+
+	source, closeSource, err := app.OpenStorage(sourceConfig.Database)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not open source database: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+	defer closeSource() // nolint: errcheck
+
+	destination, closeDestination, err := app.OpenStorage(destinationConfig.Database)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not open destination database: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+	defer closeDestination() // nolint: errcheck
+
+	copied, err := storage.Copy(source, destination, func(kind string, count int) {
+		cl.State.Fmt.Print(fmt.Sprintf("Copying %s (%d)", kind, count))
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not migrate storage: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Verifying copy")
+
+	sourceCounts, err := storage.Count(source)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not verify source database: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	destinationCounts, err := storage.Count(destination)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not verify destination database: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if diff := sourceCounts.Diff(destinationCounts); len(diff) != 0 {
+		err := fmt.Errorf("destination does not match source(source != destination); %s", strings.Join(diff, ", "))
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	for _, kind := range storage.CopyKinds {
+		cl.State.Fmt.Println(fmt.Sprintf("  ✓ %d %s", copied[kind], kind))
+	}
+
+	cl.State.Fmt.PrintSuccess("Migrated storage; update the server configuration to use the new database")
+	cl.State.Fmt.Finish()
+
+	return nil
+}
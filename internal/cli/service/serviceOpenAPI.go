@@ -0,0 +1,57 @@
+package service
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/clintjedwards/gofer/internal/api"
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/spf13/cobra"
+)
+
+var cmdServiceOpenAPI = &cobra.Command{
+	Use:   "openapi",
+	Short: "Export the OpenAPI spec for the Gofer API",
+	Long: `Export the OpenAPI spec for the Gofer API.
+
+The spec is generated from the API definitions compiled into this binary, so no running server is needed. By default
+the spec is printed to stdout.`,
+	Example: `$ gofer service openapi
+$ gofer service openapi --out spec.json`,
+	RunE: serviceOpenAPI,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdServiceOpenAPI.Flags().StringP("out", "o", "", "file path to write the spec to")
+	CmdService.AddCommand(cmdServiceOpenAPI)
+}
+
+func serviceOpenAPI(cmd *cobra.Command, _ []string) error {
+	out, _ := cmd.Flags().GetString("out")
+
+	spec, err := api.OpenAPISpec()
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not generate spec: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if out == "" {
+		// We skip the formatter so the spec can be piped into other tools.
+		cl.State.Fmt.Finish()
+		fmt.Println(string(spec))
+		return nil
+	}
+
+	err = os.WriteFile(out, spec, 0644)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not write spec: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Wrote OpenAPI spec to %q", out))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,153 @@
+package service
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/api"
+	"github.com/clintjedwards/gofer/internal/app"
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/storage"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdServicePreflight = &cobra.Command{
+	Use:   "preflight [path]",
+	Short: "Check whether this version of Gofer can take over from the one currently running",
+	Long: `Check whether this version of Gofer can take over from the one currently running.
+
+Meant to be run with the new Gofer binary before it replaces the old one. It loads the server configuration the same
+way the start command does and checks:
+  - The database schema isn't newer than this version supports, and lists the migrations that will be applied on
+    startup.
+  - The installed triggers implement a version of the trigger API this version supports.
+  - The configuration doesn't use deprecated settings.
+
+The database can't be read while a Gofer server has it open, so the schema version is asked of the running server
+instead. When no server can be reached the database is read directly and triggers aren't checked.`,
+	Example: `$ gofer service preflight
+$ gofer service preflight /etc/gofer/gofer.hcl`,
+	RunE: servicePreflight,
+	Args: cobra.MaximumNArgs(1),
+}
+
+func init() {
+	CmdService.AddCommand(cmdServicePreflight)
+}
+
+func servicePreflight(cmd *cobra.Command, args []string) error {
+	configPath, _ := cmd.Flags().GetString("config")
+	if len(args) > 0 {
+		configPath = args[0]
+	}
+
+	cl.State.Fmt.Print("Loading configuration")
+
+	conf, err := config.InitAPIConfig(configPath)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not load configuration: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("Configuration loaded")
+
+	failed := false
+
+	deprecations, err := config.FindAPIDeprecations(configPath)
+	if err != nil {
+		failed = true
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not check configuration for deprecated settings: %v", err))
+	}
+	for _, deprecation := range deprecations {
+		cl.State.Fmt.Println(color.YellowString("Deprecated setting %s", deprecation))
+	}
+	if err == nil && len(deprecations) == 0 {
+		cl.State.Fmt.PrintSuccess("No deprecated settings")
+	}
+
+	cl.State.Fmt.Print("Contacting running server")
+
+	var (
+		systemInfo *proto.GetSystemInfoResponse
+		triggers   *proto.ListTriggersResponse
+	)
+
+	conn, err := cl.State.Connect()
+	if err == nil {
+		defer conn.Close()
+
+		client := proto.NewGoferClient(conn)
+
+		md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+		ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+		systemInfo, err = client.GetSystemInfo(ctx, &proto.GetSystemInfoRequest{})
+		if err == nil {
+			var listErr error
+			triggers, listErr = client.ListTriggers(ctx, &proto.ListTriggersRequest{})
+			if listErr != nil {
+				cl.State.Fmt.Println(color.YellowString("Could not list triggers(%v); skipping trigger checks", listErr))
+			}
+		}
+	}
+	if err != nil {
+		cl.State.Fmt.Println(color.YellowString("Could not reach a running server(%v); reading the database directly "+
+			"and skipping trigger checks", err))
+	}
+
+	var status storage.SchemaStatus
+	if systemInfo != nil {
+		// Servers from before schema versions were reported use the first version.
+		version := int(systemInfo.SchemaVersion)
+		if version == 0 {
+			version = 1
+		}
+
+		status, err = app.SchemaStatus(conf.Database, version)
+	} else {
+		status, err = app.CheckSchema(conf.Database)
+	}
+
+	switch {
+	case err != nil:
+		failed = true
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not check database schema: %v", err))
+	case status.Compatible() != nil:
+		failed = true
+		cl.State.Fmt.PrintErr(status.Compatible())
+	default:
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("Database schema version %d is supported(this version uses %d)",
+			status.Version, storage.SchemaVersion))
+		for _, migration := range status.PendingMigrations {
+			cl.State.Fmt.Println(fmt.Sprintf("  pending migration %s", migration))
+		}
+	}
+
+	if triggers != nil {
+		for _, trigger := range triggers.Triggers {
+			err := api.CheckTriggerAPIVersion(trigger.ApiVersion)
+			if err != nil {
+				failed = true
+				cl.State.Fmt.PrintErr(fmt.Sprintf("Trigger %s: %v", trigger.Kind, err))
+				continue
+			}
+			cl.State.Fmt.PrintSuccess(fmt.Sprintf("Trigger %s is supported", trigger.Kind))
+		}
+	}
+
+	if failed {
+		err := fmt.Errorf("this version of Gofer is not ready to take over")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("This version of Gofer is ready to take over")
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,56 @@
+package service
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdServiceReencryptSecrets = &cobra.Command{
+	Use:   "reencrypt-secrets",
+	Short: "Re-encrypt all secrets with the secret store's current encryption key",
+	Long: `Re-encrypt all secrets with the secret store's current encryption key.
+
+To rotate the secret store's encryption key, move the current key into 'previous_encryption_keys', set
+'encryption_key' to the new key and restart Gofer. Secrets encrypted with a previous key stay readable, so pipelines
+keep working while this command re-encrypts them with the new key. Once it has finished the previous key can be
+removed from the configuration.`,
+	Example: `$ gofer service reencrypt-secrets`,
+	RunE:    serviceReencryptSecrets,
+	Args:    cobra.ExactArgs(0),
+}
+
+func init() {
+	CmdService.AddCommand(cmdServiceReencryptSecrets)
+}
+
+func serviceReencryptSecrets(_ *cobra.Command, _ []string) error {
+	cl.State.Fmt.Print("Re-encrypting secrets")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ReencryptSecrets(ctx, &proto.ReencryptSecretsRequest{})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not re-encrypt secrets: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Re-encrypted %d secrets", resp.Reencrypted))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
@@ -0,0 +1,188 @@
+package service
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/api"
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/spf13/cobra"
+	bolt "go.etcd.io/bbolt"
+)
+
+var cmdServiceRestore = &cobra.Command{
+	Use:   "restore <path>",
+	Short: "Restore the server's databases from a backup",
+	Long: `Restore the server's databases from a backup created by "gofer service backup".
+
+Restoring replaces the database files of the server, so it must be run on the machine the server runs on and the
+server must be stopped first. The location of each database is taken from the server configuration(see the
+--server-config flag). Databases that are not part of the backup, or that are skipped with --skip-objects, are left
+untouched.
+
+The secret store is encrypted with the server's encryption key; the restored server must use the same key as the
+server the backup was taken from.`,
+	Example: `$ gofer service restore gofer-backup-1650000000.tar.gz
+$ gofer service restore /backups/gofer.tar.gz --server-config /etc/gofer/gofer.hcl --skip-objects`,
+	RunE: serviceRestore,
+	Args: cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdServiceRestore.Flags().String("server-config", "", "path to the server configuration file")
+	cmdServiceRestore.Flags().Bool("skip-objects", false, "leave the current object store in place")
+	CmdService.AddCommand(cmdServiceRestore)
+}
+
+func serviceRestore(cmd *cobra.Command, args []string) error {
+	path := args[0]
+	serverConfigPath, _ := cmd.Flags().GetString("server-config")
+	skipObjects, _ := cmd.Flags().GetBool("skip-objects")
+
+	cl.State.Fmt.Print("Reading server configuration")
+
+	conf, err := config.InitAPIConfig(serverConfigPath)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read server configuration: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	targets := map[string]string{
+		api.BackupStorageFile:     conf.Database.BoltDB.Path,
+		api.BackupSecretStoreFile: conf.SecretStore.BoltDB.Path,
+	}
+
+	if !skipObjects {
+		targets[api.BackupObjectStoreFile] = conf.ObjectStore.BoltDB.Path
+	}
+
+	for _, target := range targets {
+		err := ensureNotInUse(target)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
+	cl.State.Fmt.Print("Restoring backup")
+
+	restored, err := restoreBackup(path, targets)
+	for _, target := range restored {
+		cl.State.Fmt.Println(fmt.Sprintf("  ✓ restored %s", target))
+	}
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not restore backup: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if len(restored) == 0 {
+		err := fmt.Errorf("backup %q does not contain any databases", path)
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Restored backup %q", path))
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+// ensureNotInUse makes sure no running server holds the database open. Bolt only allows a single process to open a
+// database so failing to get the lock means the server is still running.
+func ensureNotInUse(path string) error {
+	if _, err := os.Stat(path); errors.Is(err, os.ErrNotExist) {
+		return nil
+	}
+
+	db, err := bolt.Open(path, 0600, &bolt.Options{Timeout: time.Second})
+	if err != nil {
+		return fmt.Errorf("could not open database %q; make sure the Gofer server is stopped: %w", path, err)
+	}
+
+	return db.Close()
+}
+
+// restoreBackup extracts each database found in the backup to its target path. Each database is first extracted next
+// to its target and then moved in place so that an interrupted restore never leaves a partially written database.
+// Returns the paths that were restored.
+func restoreBackup(path string, targets map[string]string) ([]string, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	gzipReader, err := gzip.NewReader(file)
+	if err != nil {
+		return nil, err
+	}
+	defer gzipReader.Close()
+
+	tarReader := tar.NewReader(gzipReader)
+	restored := []string{}
+
+	for {
+		header, err := tarReader.Next()
+		if err != nil {
+			if err == io.EOF {
+				return restored, nil
+			}
+			return restored, err
+		}
+
+		target, exists := targets[header.Name]
+		if !exists {
+			continue
+		}
+
+		err = extractFile(tarReader, target)
+		if err != nil {
+			return restored, fmt.Errorf("could not restore %s: %w", header.Name, err)
+		}
+
+		restored = append(restored, target)
+	}
+}
+
+func extractFile(source io.Reader, target string) error {
+	err := os.MkdirAll(filepath.Dir(target), 0755)
+	if err != nil {
+		return err
+	}
+
+	tmpPath := target + ".restore"
+	file, err := os.OpenFile(tmpPath, os.O_CREATE|os.O_WRONLY|os.O_TRUNC, 0600)
+	if err != nil {
+		return err
+	}
+	defer os.Remove(tmpPath)
+
+	_, err = io.Copy(file, source)
+	if err != nil {
+		file.Close()
+		return err
+	}
+
+	err = file.Sync()
+	if err != nil {
+		file.Close()
+		return err
+	}
+
+	err = file.Close()
+	if err != nil {
+		return err
+	}
+
+	return os.Rename(tmpPath, target)
+}
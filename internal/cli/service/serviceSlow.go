@@ -0,0 +1,119 @@
+package service
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdServiceSlow = &cobra.Command{
+	Use:   "slow",
+	Short: "Show the slowest API routes and storage queries",
+	Long: `Show the slowest API routes and storage queries.
+
+Reports the p50, p95 and p99 latency of the API routes and storage queries called over the last few minutes, ordered
+by their p99 latency. Streaming routes(ex. following logs) are not included since they stay open for as long as the
+client wants.
+
+Latencies are only kept in memory and start over when the server is restarted.`,
+	Example: `$ gofer service slow
+$ gofer service slow --limit 20`,
+	RunE: serviceSlow,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdServiceSlow.Flags().IntP("limit", "l", 10, "the most routes and queries to show")
+	CmdService.AddCommand(cmdServiceSlow)
+}
+
+func serviceSlow(cmd *cobra.Command, _ []string) error {
+	limit, _ := cmd.Flags().GetInt("limit")
+
+	cl.State.Fmt.Print("Retrieving slow report")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.GetSlowReport(ctx, &proto.GetSlowReportRequest{
+		Limit: int64(limit),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get slow report: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	window := time.Duration(resp.Window) * time.Millisecond
+
+	cl.State.Fmt.Println(fmt.Sprintf("Routes over the last %s:\n\n%s", window,
+		formatLatencies(resp.Routes, !cl.State.Config.NoColor)))
+	cl.State.Fmt.Println(fmt.Sprintf("Storage queries over the last %s:\n\n%s", window,
+		formatLatencies(resp.Queries, !cl.State.Config.NoColor)))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+func formatLatencies(summaries []*proto.LatencySummary, colorize bool) string {
+	data := [][]string{}
+	for _, summary := range summaries {
+		data = append(data, []string{
+			summary.Name,
+			strconv.Itoa(int(summary.Count)),
+			formatLatency(summary.P50),
+			formatLatency(summary.P95),
+			formatLatency(summary.P99),
+			formatLatency(summary.Max),
+		})
+	}
+
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	headers := []string{"Name", "Count", "p50", "p95", "p99", "Max"}
+
+	table.SetHeader(headers)
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if colorize {
+		headerColors := []tablewriter.Colors{}
+		for range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+		}
+		table.SetHeaderColor(headerColors...)
+	}
+
+	table.AppendBulk(data)
+	table.Render()
+
+	return tableString.String()
+}
+
+func formatLatency(microseconds int64) string {
+	return (time.Duration(microseconds) * time.Microsecond).String()
+}
@@ -1,11 +1,15 @@
 package service
 
 import (
+	"io"
 	"os"
 
 	"github.com/clintjedwards/gofer/internal/app"
 	"github.com/clintjedwards/gofer/internal/cli/cl"
 	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/logexport"
+	"github.com/clintjedwards/gofer/internal/loglevel"
+	"github.com/clintjedwards/gofer/internal/tracing"
 	"github.com/rs/zerolog"
 	"github.com/rs/zerolog/log"
 
@@ -18,7 +22,11 @@ var cmdServiceStart = &cobra.Command{
 	Long: `Start the Gofer GRPC/HTTP combined server.
 
 Gofer runs as a GRPC backend combined with GRPC-WEB/HTTP. Running this command attempts to start the long
-running service. This command will block and only gracefully stop on SIGINT or SIGTERM signals`,
+running service. This command will block and only gracefully stop on SIGINT or SIGTERM signals.
+
+Sending SIGHUP restarts the service in place(ex. after upgrading the binary). This is not a zero-downtime restart:
+requests wait until the service is back up, runs in progress go through the same recovery as after any other
+restart, followed logs have to reconnect and attach sessions are ended.`,
 	RunE: serverStart,
 }
 
@@ -35,9 +43,32 @@ func serverStart(cmd *cobra.Command, _ []string) error {
 		log.Fatal().Err(err).Msg("error in config initialization")
 	}
 
-	setupLogging(conf.LogLevel, conf.Server.DevMode)
+	var logLevels *loglevel.Filter
+	if conf.LogExport != nil && conf.LogExport.Enable {
+		exporter := logexport.New(conf.LogExport)
+		defer exporter.Close() // nolint: errcheck
+
+		logLevels = setupLogging(conf.LogLevel, conf.Server.DevMode, exporter)
+	} else {
+		logLevels = setupLogging(conf.LogLevel, conf.Server.DevMode)
+	}
+
+	deprecations, err := config.FindAPIDeprecations(configPath)
+	if err != nil {
+		log.Warn().Err(err).Msg("could not check configuration for deprecated settings")
+	}
+	for _, deprecation := range deprecations {
+		log.Warn().Str("setting", deprecation.Setting).Str("env_var", deprecation.EnvVar).
+			Msg("deprecated setting; " + deprecation.Message)
+	}
+
+	if conf.Tracing != nil && conf.Tracing.Enable {
+		tracer := tracing.Init(conf.Tracing)
+		defer tracer.Close() // nolint: errcheck
+	}
+
 	setup(conf.Server.TmpDir)
-	app.StartServices(conf)
+	app.StartServices(conf, logLevels)
 
 	return nil
 }
@@ -72,13 +103,25 @@ func setup(dirs ...string) {
 	}
 }
 
-func setupLogging(loglevel string, pretty bool) {
+// setupLogging configures the global logger. Logs are always written to the terminal and additionally to any of the
+// exporters given. The returned filter is used to change log levels while the server is running.
+func setupLogging(level string, pretty bool, exporters ...io.Writer) *loglevel.Filter {
 	zerolog.TimeFieldFormat = zerolog.TimeFormatUnix
 	log.Logger = log.With().Caller().Logger()
-	zerolog.SetGlobalLevel(parseLogLevel(loglevel))
+
+	var output io.Writer = os.Stderr
 	if pretty {
-		log.Logger = log.Output(zerolog.ConsoleWriter{Out: os.Stderr})
+		output = zerolog.ConsoleWriter{Out: os.Stderr}
 	}
+
+	if len(exporters) > 0 {
+		output = zerolog.MultiLevelWriter(append([]io.Writer{output}, exporters...)...)
+	}
+
+	filter := loglevel.NewFilter(output, parseLogLevel(level))
+	log.Logger = log.Output(filter)
+
+	return filter
 }
 
 func parseLogLevel(loglevel string) zerolog.Level {
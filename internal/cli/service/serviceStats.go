@@ -0,0 +1,187 @@
+package service
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdServiceStats = &cobra.Command{
+	Use:   "stats",
+	Short: "Summarize run throughput, failure rates and queue depth",
+	Long: `Summarize run throughput, failure rates and queue depth.
+
+Shows how many runs were started within the last hour and day along with how many of them succeeded, failed or were
+cancelled and how long finished runs took on average. The queue shows the runs and task runs that are currently waiting
+to be scheduled or running.
+
+Durations show the distribution of task run queue wait, image pull time, task run duration and run duration for each
+pipeline and final status since the server was started.
+
+Only pipelines within namespaces your token has access to are taken into account.`,
+	Example: `$ gofer service stats`,
+	RunE:    serviceStats,
+	Args:    cobra.ExactArgs(0),
+}
+
+func init() {
+	CmdService.AddCommand(cmdServiceStats)
+}
+
+func serviceStats(_ *cobra.Command, _ []string) error {
+	cl.State.Fmt.Print("Calculating stats")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.GetSystemStats(ctx, &proto.GetSystemStatsRequest{})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get stats: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp)
+	}
+
+	cl.State.Fmt.Println(formatStats(resp, !cl.State.Config.NoColor))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+func formatStats(stats *proto.GetSystemStatsResponse, colorize bool) string {
+	data := [][]string{}
+	for _, window := range stats.Windows {
+		data = append(data, []string{
+			window.Name,
+			strconv.Itoa(int(window.RunsStarted)),
+			strconv.Itoa(int(window.RunsSucceeded)),
+			strconv.Itoa(int(window.RunsFailed)),
+			strconv.Itoa(int(window.RunsCancelled)),
+			failureRate(window),
+			averageDuration(window.AverageRunDuration),
+		})
+	}
+
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	headers := []string{"Window", "Runs", "Succeeded", "Failed", "Cancelled", "Failure Rate", "Avg Duration"}
+
+	table.SetHeader(headers)
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if colorize {
+		headerColors := []tablewriter.Colors{}
+		for range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+		}
+		table.SetHeaderColor(headerColors...)
+	}
+
+	table.AppendBulk(data)
+	table.Render()
+
+	queue := fmt.Sprintf(`
+  Queue:
+    Runs: %s queued :: %s running
+    Task Runs: %s queued :: %s running`,
+		color.YellowString("%d", stats.RunsQueued), color.BlueString("%d", stats.RunsRunning),
+		color.YellowString("%d", stats.TaskRunsQueued), color.BlueString("%d", stats.TaskRunsRunning))
+
+	if len(stats.Durations) == 0 {
+		return tableString.String() + queue
+	}
+
+	return tableString.String() + queue + "\n\n" + formatDurations(stats.Durations, colorize)
+}
+
+func formatDurations(distributions []*proto.DurationDistribution, colorize bool) string {
+	data := [][]string{}
+	for _, distribution := range distributions {
+		data = append(data, []string{
+			distribution.NamespaceId + "/" + distribution.PipelineId,
+			distribution.Metric,
+			distribution.Status,
+			strconv.Itoa(int(distribution.Count)),
+			averageDuration(distribution.P50),
+			averageDuration(distribution.P90),
+			averageDuration(distribution.P99),
+		})
+	}
+
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	headers := []string{"Pipeline", "Duration", "Status", "Count", "p50", "p90", "p99"}
+
+	table.SetHeader(headers)
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if colorize {
+		headerColors := []tablewriter.Colors{}
+		for range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+		}
+		table.SetHeaderColor(headerColors...)
+	}
+
+	table.AppendBulk(data)
+	table.Render()
+
+	return tableString.String()
+}
+
+// failureRate returns the percentage of finished runs within the window that failed.
+func failureRate(window *proto.SystemStatsWindow) string {
+	finished := window.RunsSucceeded + window.RunsFailed + window.RunsCancelled
+	if finished == 0 {
+		return "-"
+	}
+
+	return fmt.Sprintf("%.1f%%", float64(window.RunsFailed)/float64(finished)*100)
+}
+
+func averageDuration(milliseconds int64) string {
+	if milliseconds == 0 {
+		return "-"
+	}
+
+	return (time.Duration(milliseconds) * time.Millisecond).Truncate(time.Second).String()
+}
@@ -0,0 +1,226 @@
+package service
+
+import (
+	"crypto/tls"
+	"fmt"
+	"net"
+	"reflect"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/app"
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/spf13/cobra"
+)
+
+var cmdServiceValidateConfig = &cobra.Command{
+	Use:   "validate-config [path]",
+	Short: "Validate the Gofer server configuration without starting the server",
+	Long: `Validate the Gofer server configuration without starting the server.
+
+Loads the server configuration the same way the start command does(config file, then environment variables) and
+checks that:
+  - The bind addresses can be parsed.
+  - The TLS certificates and keys can be read.
+  - The storage, scheduler, object store and secret store backends can be reached.
+
+Finally the fully resolved configuration is printed with all secret values masked.
+
+Backends that keep an exclusive lock on their files(ex. bolt) cannot be checked while a Gofer server
+using the same files is running.`,
+	Example: `$ gofer service validate-config
+$ gofer service validate-config /etc/gofer/gofer.hcl`,
+	RunE: serviceValidateConfig,
+	Args: cobra.MaximumNArgs(1),
+}
+
+func init() {
+	CmdService.AddCommand(cmdServiceValidateConfig)
+}
+
+func serviceValidateConfig(cmd *cobra.Command, args []string) error {
+	configPath, _ := cmd.Flags().GetString("config")
+	if len(args) > 0 {
+		configPath = args[0]
+	}
+
+	cl.State.Fmt.Print("Loading configuration")
+
+	conf, err := config.InitAPIConfig(configPath)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not load configuration: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("Configuration loaded")
+
+	failed := false
+
+	report := func(check string, err error) {
+		if err != nil {
+			failed = true
+			cl.State.Fmt.PrintErr(fmt.Sprintf("%s: %v", check, err))
+			return
+		}
+		cl.State.Fmt.PrintSuccess(check)
+	}
+
+	report("Bind address "+conf.Host, validateBindAddress(conf.Host))
+	if conf.ExternalEventsAPI.Enable {
+		report("External events bind address "+conf.ExternalEventsAPI.Host,
+			validateBindAddress(conf.ExternalEventsAPI.Host))
+	}
+
+	report("Server TLS files", validateTLSFiles(conf.Server.DevMode, conf.Server.TLSCertPath, conf.Server.TLSKeyPath))
+	report("Trigger TLS files", validateTLSFiles(conf.Server.DevMode, conf.Triggers.TLSCertPath, conf.Triggers.TLSKeyPath))
+
+	for _, check := range app.CheckServices(conf) {
+		report(fmt.Sprintf("Connected to %s (%s)", check.Service, check.Engine), check.Err)
+	}
+
+	resolved := &strings.Builder{}
+	writeConfig(resolved, "", reflect.ValueOf(conf))
+
+	cl.State.Fmt.Println(strings.TrimSuffix(resolved.String(), "\n"))
+
+	if failed {
+		err := fmt.Errorf("configuration is not valid")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess("Configuration is valid")
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func validateBindAddress(address string) error {
+	_, port, err := net.SplitHostPort(address)
+	if err != nil {
+		return err
+	}
+
+	_, err = net.LookupPort("tcp", port)
+	if err != nil {
+		return err
+	}
+
+	return nil
+}
+
+// validateTLSFiles mirrors how the server loads its certificates; in dev mode the embedded development certificates
+// are used when no certificate is given.
+func validateTLSFiles(devMode bool, certPath, keyPath string) error {
+	if devMode && certPath == "" {
+		return nil
+	}
+
+	if certPath == "" || keyPath == "" {
+		return fmt.Errorf("TLS cert and key cannot be empty")
+	}
+
+	_, err := tls.LoadX509KeyPair(certPath, keyPath)
+	if err != nil {
+		return err
+	}
+
+	return nil
+}
+
+// writeConfig writes the given configuration as a flat list of "key = value" lines, using the same names as the
+// configuration file. Fields tagged with `mask:"true"` contain secrets and have their value masked. Maps tagged with
+// `mask:"values"`(ex. HTTP headers) have only their keys printed.
+func writeConfig(out *strings.Builder, prefix string, value reflect.Value) {
+	if value.Kind() == reflect.Pointer {
+		if value.IsNil() {
+			return
+		}
+		value = value.Elem()
+	}
+
+	valueType := value.Type()
+	for i := 0; i < valueType.NumField(); i++ {
+		field := valueType.Field(i)
+
+		name, kind, _ := strings.Cut(field.Tag.Get("hcl"), ",")
+		if name == "" || kind == "label" {
+			continue
+		}
+
+		fieldValue := value.Field(i)
+
+		// Durations are read in through a sister string field; we print the parsed duration instead.
+		if strings.HasSuffix(field.Name, "HCL") {
+			fieldValue = value.FieldByName(strings.TrimSuffix(field.Name, "HCL"))
+		}
+
+		key := name
+		if prefix != "" {
+			key = prefix + "." + name
+		}
+
+		if kind == "block" {
+			if fieldValue.Kind() == reflect.Slice {
+				for j := 0; j < fieldValue.Len(); j++ {
+					writeConfig(out, key+"."+blockLabel(fieldValue.Index(j)), fieldValue.Index(j))
+				}
+				continue
+			}
+
+			writeConfig(out, key, fieldValue)
+			continue
+		}
+
+		if field.Tag.Get("mask") == "true" && !fieldValue.IsZero() {
+			fmt.Fprintf(out, "%s = %q\n", key, "********")
+			continue
+		}
+
+		if field.Tag.Get("mask") == "values" && fieldValue.Kind() == reflect.Map {
+			fmt.Fprintf(out, "%s = %s\n", key, maskMapValues(fieldValue))
+			continue
+		}
+
+		switch v := fieldValue.Interface().(type) {
+		case string:
+			fmt.Fprintf(out, "%s = %q\n", key, v)
+		case time.Duration:
+			fmt.Fprintf(out, "%s = %q\n", key, v.String())
+		default:
+			fmt.Fprintf(out, "%s = %v\n", key, v)
+		}
+	}
+}
+
+// maskMapValues formats a map the same way as %v but with every value masked.
+func maskMapValues(value reflect.Value) string {
+	keys := []string{}
+	for _, key := range value.MapKeys() {
+		keys = append(keys, fmt.Sprint(key.Interface()))
+	}
+	sort.Strings(keys)
+
+	entries := []string{}
+	for _, key := range keys {
+		entries = append(entries, key+":********")
+	}
+
+	return "map[" + strings.Join(entries, " ") + "]"
+}
+
+// blockLabel returns the value of the label field of a labeled block.
+func blockLabel(value reflect.Value) string {
+	valueType := value.Type()
+	for i := 0; i < valueType.NumField(); i++ {
+		_, kind, _ := strings.Cut(valueType.Field(i).Tag.Get("hcl"), ",")
+		if kind == "label" {
+			return fmt.Sprint(value.Field(i).Interface())
+		}
+	}
+
+	return ""
+}
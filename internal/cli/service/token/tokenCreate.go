@@ -12,15 +12,43 @@ import (
 )
 
 var cmdTokenCreate = &cobra.Command{
-	Use:   "create <management|client>",
+	Use:   "create <management|client|namespace_admin>",
 	Short: "Create new API token",
-	RunE:  tokenCreate,
-	Args:  cobra.ExactArgs(1),
+	Long: `Create new API token.
+
+Namespace admin tokens have the same access as client tokens, but can additionally manage their namespaces without
+a management token. They can update the namespaces themselves and create or delete any non-management tokens and
+service accounts that only have access to those namespaces.
+
+Passing the --from flag creates the token with the same kind, namespaces, metadata, allowed CIDRs and TTL as an
+existing token, which you will be asked to paste. Any of these passed as flags replace the ones copied from the existing token.
+
+Passing the --ttl flag makes the token expire once the given duration has passed. Tokens that expire are removed
+automatically; see 'gofer service token expiring' to find tokens that are about to.
+
+Passing the --allowed-cidrs flag limits the networks the token can be used from. If Gofer is behind a reverse proxy,
+the proxy must be listed in the server's trusted_proxies setting for the caller's address to be determined.
+
+Passing the --ldap-user flag creates the token for a directory user instead. The kind and namespaces of the token are
+derived from the user's directory groups and kept up to date as their group membership changes. This requires the
+server to have ldap enabled.`,
+	Example: `$ gofer service token create client --namespaces default,my_team
+$ gofer service token create namespace_admin --namespaces my_team
+$ gofer service token create client --namespaces my_team --allowed-cidrs 10.20.0.0/16
+$ gofer service token create client --namespaces my_team --ttl 720h
+$ gofer service token create --from --metadata name:ci
+$ gofer service token create --ldap-user jdoe --metadata name:jdoe`,
+	RunE: tokenCreate,
+	Args: cobra.RangeArgs(0, 1),
 }
 
 func init() {
 	cmdTokenCreate.Flags().StringSliceP("namespaces", "n", []string{"default"}, "namespaces this key will have access to. If not specified namespace is default")
 	cmdTokenCreate.Flags().StringSliceP("metadata", "m", []string{}, "metadata about the token, useful for attaching a name, team, and other details. Format = key:value")
+	cmdTokenCreate.Flags().Bool("from", false, "copy the settings of an existing token")
+	cmdTokenCreate.Flags().String("ldap-user", "", "create the token for a directory user; permissions are derived from their groups")
+	cmdTokenCreate.Flags().StringSlice("allowed-cidrs", []string{}, "only allow the token to be used from these networks. Ex: 10.0.0.0/8")
+	cmdTokenCreate.Flags().Duration("ttl", 0, "how long the token lasts before it expires; never expires if not specified")
 	CmdToken.AddCommand(cmdTokenCreate)
 }
 
@@ -43,14 +71,44 @@ func tokenCreate(cmd *cobra.Command, args []string) error {
 	namespaces, _ := cmd.Flags().GetStringSlice("namespaces")
 	metadataSlice, _ := cmd.Flags().GetStringSlice("metadata")
 	tokenMetadata := metadataToMap(metadataSlice)
+	from, _ := cmd.Flags().GetBool("from")
+	ldapUser, _ := cmd.Flags().GetString("ldap-user")
+	allowedCIDRs, _ := cmd.Flags().GetStringSlice("allowed-cidrs")
+	ttl, _ := cmd.Flags().GetDuration("ttl")
 
-	cl.State.Fmt.Print("Creating Token")
+	if ldapUser != "" && (from || len(args) != 0 || cmd.Flags().Changed("namespaces")) {
+		err := fmt.Errorf("--ldap-user cannot be combined with a token kind, namespaces or the --from flag")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
 
-	kind := args[0]
-	if kind != "management" && kind != "client" {
-		cl.State.Fmt.PrintErr(fmt.Sprintf("invalid kind %q", kind))
+	if !from && ldapUser == "" && len(args) != 1 {
+		err := fmt.Errorf("requires a token kind, the --from flag or the --ldap-user flag")
+		cl.State.Fmt.PrintErr(err)
 		cl.State.Fmt.Finish()
-		return fmt.Errorf("invalid kind")
+		return err
+	}
+
+	cl.State.Fmt.Print("Creating Token")
+
+	request := &proto.CreateTokenRequest{
+		Metadata:     tokenMetadata,
+		Namespaces:   namespaces,
+		LdapUser:     ldapUser,
+		AllowedCidrs: allowedCIDRs,
+		Ttl:          int64(ttl.Seconds()),
+	}
+
+	if len(args) == 1 {
+		kind := args[0]
+		if kind != "management" && kind != "client" && kind != "namespace_admin" {
+			cl.State.Fmt.PrintErr(fmt.Sprintf("invalid kind %q", kind))
+			cl.State.Fmt.Finish()
+			return fmt.Errorf("invalid kind")
+		}
+
+		request.Kind = proto.CreateTokenRequest_Kind(proto.CreateTokenRequest_Kind_value[strings.ToUpper(kind)])
 	}
 
 	conn, err := cl.State.Connect()
@@ -64,19 +122,66 @@ func tokenCreate(cmd *cobra.Command, args []string) error {
 
 	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
 	ctx := metadata.NewOutgoingContext(context.Background(), md)
-	resp, err := client.CreateToken(ctx, &proto.CreateTokenRequest{
-		Kind:       proto.CreateTokenRequest_Kind(proto.CreateTokenRequest_Kind_value[string(kind)]),
-		Metadata:   tokenMetadata,
-		Namespaces: namespaces,
-	})
+
+	if from {
+		cl.State.Fmt.Finish()
+
+		var input string
+
+		fmt.Print("Please paste the token to copy: ")
+		fmt.Scanln(&input)
+
+		cl.State.NewFormatter()
+
+		existing, err := client.GetToken(ctx, &proto.GetTokenRequest{
+			Token: input,
+		})
+		if err != nil {
+			cl.State.Fmt.PrintErr(fmt.Sprintf("could not get token to copy: %v", err))
+			cl.State.Fmt.Finish()
+			return err
+		}
+
+		copyTokenSettings(cmd, request, existing.Details)
+	}
+
+	resp, err := client.CreateToken(ctx, request)
 	if err != nil {
 		cl.State.Fmt.PrintErr(fmt.Sprintf("could not get token: %v", err))
 		cl.State.Fmt.Finish()
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp)
+	}
+
 	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Token: %s", resp.Token))
 	cl.State.Fmt.Finish()
 
 	return nil
 }
+
+// copyTokenSettings fills in the settings of the existing token for anything that wasn't explicitly set by the user.
+func copyTokenSettings(cmd *cobra.Command, request *proto.CreateTokenRequest, existing *proto.Token) {
+	if request.Kind == proto.CreateTokenRequest_UNKNOWN {
+		request.Kind = proto.CreateTokenRequest_Kind(existing.Kind)
+	}
+
+	if !cmd.Flags().Changed("namespaces") {
+		request.Namespaces = existing.Namespaces
+	}
+
+	if !cmd.Flags().Changed("metadata") {
+		request.Metadata = existing.Metadata
+	}
+
+	if !cmd.Flags().Changed("allowed-cidrs") {
+		request.AllowedCidrs = existing.AllowedCidrs
+	}
+
+	if !cmd.Flags().Changed("ttl") && existing.Expires != 0 {
+		request.Ttl = (existing.Expires - existing.Created) / 1000
+	}
+}
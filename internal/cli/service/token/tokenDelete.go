@@ -13,14 +13,26 @@ import (
 var cmdTokenDelete = &cobra.Command{
 	Use:   "delete",
 	Short: "Delete specific token",
-	RunE:  tokenDelete,
+	Long: `Delete specific token.
+
+Passing the --user flag instead deletes all tokens belonging to that user in one operation. A token belongs to a user
+if it was created for that directory user or if its "name" metadata matches.`,
+	Example: `$ gofer service token delete
+$ gofer service token delete --user jdoe`,
+	RunE: tokenDelete,
 }
 
 func init() {
+	cmdTokenDelete.Flags().String("user", "", "delete all tokens belonging to this user")
 	CmdToken.AddCommand(cmdTokenDelete)
 }
 
-func tokenDelete(_ *cobra.Command, _ []string) error {
+func tokenDelete(cmd *cobra.Command, _ []string) error {
+	user, _ := cmd.Flags().GetString("user")
+	if user != "" {
+		return tokenDeleteUser(user)
+	}
+
 	cl.State.Fmt.Print("Deleting token")
 	cl.State.Fmt.Finish()
 
@@ -56,3 +68,32 @@ func tokenDelete(_ *cobra.Command, _ []string) error {
 
 	return nil
 }
+
+func tokenDeleteUser(user string) error {
+	cl.State.Fmt.Print("Deleting tokens")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.DeleteUserTokens(ctx, &proto.DeleteUserTokensRequest{
+		User: user,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not delete tokens: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Deleted %d tokens belonging to %q", resp.Deleted, user))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
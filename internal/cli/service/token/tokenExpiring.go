@@ -0,0 +1,139 @@
+package token
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdTokenExpiring = &cobra.Command{
+	Use:   "expiring",
+	Short: "List tokens that are about to expire",
+	Long: `List tokens that are about to expire, soonest first.
+
+Tokens that have already expired are included until they're removed. Tokens that were rotated with a grace period show
+the token that replaced them. Namespace admins only see the tokens they are able to manage.`,
+	Example: `$ gofer service token expiring
+$ gofer service token expiring --within 24h`,
+	RunE: tokenExpiring,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdTokenExpiring.Flags().Duration("within", 7*24*time.Hour, "show tokens that expire within the given duration")
+	CmdToken.AddCommand(cmdTokenExpiring)
+}
+
+func tokenExpiring(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+	within, _ := cmd.Flags().GetDuration("within")
+
+	cl.State.Fmt.Print("Retrieving expiring tokens")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListExpiringTokens(ctx, &proto.ListExpiringTokensRequest{
+		Within: int64(within.Seconds()),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list expiring tokens: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Tokens)
+	}
+
+	if len(resp.Tokens) == 0 {
+		cl.State.Fmt.PrintSuccess(fmt.Sprintf("No tokens expire within %s", within))
+		cl.State.Fmt.Finish()
+		return nil
+	}
+
+	data := [][]string{}
+	for _, token := range resp.Tokens {
+		data = append(data, []string{
+			shortHash(token.Hash, detail),
+			strings.ToLower(token.Kind.String()),
+			strings.Join(token.Namespaces, ", "),
+			tokenOwner(token),
+			cliformat.UnixMilli(token.Expires, "Never", detail),
+			shortHash(token.ReplacedBy, detail),
+		})
+	}
+
+	table := formatExpiringTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+// shortHash shortens token hashes so that tables stay readable; the full hash is shown in detail mode.
+func shortHash(hash string, detail bool) string {
+	if !detail && len(hash) > 12 {
+		return hash[:12]
+	}
+
+	return hash
+}
+
+func formatExpiringTable(data [][]string, color bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Hash", "Kind", "Namespaces", "Owner", "Expires", "Replaced By"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if color {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
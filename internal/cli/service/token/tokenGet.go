@@ -51,6 +51,11 @@ func tokenGet(_ *cobra.Command, _ []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Details)
+	}
+
 	cl.State.Fmt.Println(resp.Details)
 	cl.State.Fmt.Finish()
 
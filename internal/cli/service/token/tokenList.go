@@ -0,0 +1,159 @@
+package token
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdTokenList = &cobra.Command{
+	Use:   "list",
+	Short: "List all tokens",
+	Long: `List all tokens along with their usage.
+
+Usage is useful for finding credentials that should be removed; --unused-for finds dormant tokens while
+--min-daily-requests finds tokens that are being used heavily.`,
+	Example: `$ gofer service token list
+$ gofer service token list --unused-for 2160h
+$ gofer service token list --min-daily-requests 10000`,
+	RunE: tokenList,
+}
+
+func init() {
+	cmdTokenList.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	cmdTokenList.Flags().Duration("unused-for", 0, "only show tokens that haven't been used within the given duration (ex. 720h)")
+	cmdTokenList.Flags().Int64("min-daily-requests", 0, "only show tokens that made at least this many requests within the last day")
+	CmdToken.AddCommand(cmdTokenList)
+}
+
+func tokenList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+	unusedFor, _ := cmd.Flags().GetDuration("unused-for")
+	minDailyRequests, _ := cmd.Flags().GetInt64("min-daily-requests")
+
+	cl.State.Fmt.Print("Retrieving tokens")
+
+	limit, err := cmd.Flags().GetInt("limit")
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	unusedSince := int64(0)
+	if unusedFor > 0 {
+		unusedSince = time.Now().Add(-unusedFor).UnixMilli()
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListTokens(ctx, &proto.ListTokensRequest{
+		Limit:              int64(limit),
+		UnusedSince:        unusedSince,
+		MinRequestsLastDay: minDailyRequests,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list tokens: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Tokens)
+	}
+
+	data := [][]string{}
+	for _, token := range resp.Tokens {
+		data = append(data, []string{
+			shortHash(token.Hash, detail),
+			strings.ToLower(token.Kind.String()),
+			strings.Join(token.Namespaces, ", "),
+			tokenOwner(token),
+			cliformat.UnixMilli(token.LastUsed, "Never", detail),
+			token.LastUsedAddress,
+			strconv.FormatInt(token.RequestsLastDay, 10),
+		})
+	}
+
+	table := formatTable(data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+// tokenOwner returns who or what the token was created for, if known.
+func tokenOwner(token *proto.Token) string {
+	switch {
+	case token.ServiceAccount != "":
+		return "service_account:" + token.ServiceAccount
+	case token.LdapUser != "":
+		return token.LdapUser
+	case token.OidcSubject != "":
+		return "oidc:" + token.OidcSubject
+	default:
+		return token.Metadata["name"]
+	}
+}
+
+func formatTable(data [][]string, color bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader([]string{"Hash", "Kind", "Namespaces", "Owner", "Last Used", "Last Address", "Requests(24h)"})
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if color {
+		table.SetHeaderColor(
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+			tablewriter.Color(tablewriter.FgBlueColor),
+		)
+		table.SetColumnColor(
+			tablewriter.Color(tablewriter.FgYellowColor),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+			tablewriter.Color(0),
+		)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
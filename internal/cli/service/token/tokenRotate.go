@@ -0,0 +1,161 @@
+package token
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/hashicorp/hcl/v2"
+	"github.com/hashicorp/hcl/v2/hclwrite"
+	"github.com/spf13/cobra"
+	"github.com/zclconf/go-cty/cty"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdTokenRotate = &cobra.Command{
+	Use:   "rotate",
+	Short: "Replace a token with a new one",
+	Long: `Replace a token with a new one.
+
+The new token has the same kind, namespaces and metadata as the old one. Tokens that expire are replaced with one that
+lasts just as long. The new token is only shown once, so make sure to store it.
+
+The old token stops working immediately unless --grace-period is given, in which case it keeps working for that long
+so that everything using it can be moved over to the new token.
+
+Pass --current to rotate the token the CLI is currently using instead of pasting one; the CLI configuration file is
+updated with the new token automatically. Client tokens are only able to rotate themselves.`,
+	Example: `$ gofer service token rotate
+$ gofer service token rotate --current
+$ gofer service token rotate --grace-period 24h`,
+	RunE: tokenRotate,
+	Args: cobra.ExactArgs(0),
+}
+
+func init() {
+	cmdTokenRotate.Flags().Bool("current", false, "rotate the token currently in use by the CLI")
+	cmdTokenRotate.Flags().Duration("grace-period", 0, "how long the old token keeps working after being rotated")
+	CmdToken.AddCommand(cmdTokenRotate)
+}
+
+func tokenRotate(cmd *cobra.Command, _ []string) error {
+	current, _ := cmd.Flags().GetBool("current")
+	gracePeriod, _ := cmd.Flags().GetDuration("grace-period")
+	configFlag, _ := cmd.Flags().GetString("config")
+
+	cl.State.Fmt.Print("Rotating token")
+
+	input := cl.State.Config.Token
+	if !current {
+		cl.State.Fmt.Finish()
+
+		fmt.Print("Please paste the token to rotate: ")
+		fmt.Scanln(&input)
+
+		cl.State.NewFormatter()
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.RotateToken(ctx, &proto.RotateTokenRequest{
+		Token:       input,
+		GracePeriod: int64(gracePeriod.Seconds()),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not rotate token: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Token: %s", resp.Token))
+	if gracePeriod > 0 {
+		cl.State.Fmt.Println(fmt.Sprintf("  This is the only time the new token will be shown; the old token stops working in %s.",
+			gracePeriod))
+	} else {
+		cl.State.Fmt.Println("  This is the only time the new token will be shown; the old token no longer works.")
+	}
+
+	if input == cl.State.Config.Token {
+		path := config.CLIConfigPath(configFlag)
+		updated, err := replaceConfigToken(path, input, resp.Token)
+		switch {
+		case err != nil:
+			cl.State.Fmt.Println(fmt.Sprintf("  Could not update the token in configuration file %q: %v", path, err))
+		case updated:
+			cl.State.Fmt.Println(fmt.Sprintf("  Updated the token in configuration file %q", path))
+		default:
+			cl.State.Fmt.Println("  The rotated token was not found in the configuration file; update it wherever it's set")
+		}
+	}
+
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+// replaceConfigToken rewrites every token attribute within the given configuration file that is set to the old
+// token, including those within contexts, leaving the rest of the file untouched. Returns whether the file contained
+// the old token.
+func replaceConfigToken(path, oldToken, newToken string) (bool, error) {
+	if path == "" {
+		return false, nil
+	}
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return false, err
+	}
+
+	file, diags := hclwrite.ParseConfig(content, path, hcl.InitialPos)
+	if diags.HasErrors() {
+		return false, diags
+	}
+
+	bodies := []*hclwrite.Body{file.Body()}
+	for _, block := range file.Body().Blocks() {
+		if block.Type() == "context" {
+			bodies = append(bodies, block.Body())
+		}
+	}
+
+	updated := false
+	for _, body := range bodies {
+		attribute := body.GetAttribute("token")
+		if attribute == nil {
+			continue
+		}
+
+		value := strings.TrimSpace(string(attribute.Expr().BuildTokens(nil).Bytes()))
+		if value != strconv.Quote(oldToken) {
+			continue
+		}
+
+		body.SetAttributeValue("token", cty.StringVal(newToken))
+		updated = true
+	}
+
+	if !updated {
+		return false, nil
+	}
+
+	stat, err := os.Stat(path)
+	if err != nil {
+		return false, err
+	}
+
+	return true, os.WriteFile(path, file.Bytes(), stat.Mode())
+}
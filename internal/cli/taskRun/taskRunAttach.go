@@ -0,0 +1,186 @@
+package taskrun
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"os"
+	"os/signal"
+	"strconv"
+	"sync"
+	"syscall"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdTaskRunAttach = &cobra.Command{
+	Use:   "attach <pipeline> <run> <id> [-- command...]",
+	Short: "Run a command inside a running task run's container",
+	Long: `Run a command inside a running task run's container.
+
+Starts the given command(defaults to "sh") inside the container and connects your terminal to it. The local terminal
+is put in raw mode so that control characters(ex. Ctrl-C) are passed through to the command and interactive programs
+like vim and htop work as expected; changes to the size of your terminal window are forwarded as well.
+
+The session ends when the command exits. Your terminal is restored once the session ends.`,
+	Example: `$ gofer taskrun attach simple_test_pipeline 23 example_task
+$ gofer taskrun attach simple_test_pipeline 23 example_task -- bash -l`,
+	RunE: taskRunAttach,
+	Args: cobra.MinimumNArgs(3),
+}
+
+func init() {
+	CmdTaskRun.AddCommand(cmdTaskRunAttach)
+}
+
+func taskRunAttach(_ *cobra.Command, args []string) error {
+	// The command takes over the terminal so we don't use the formatter here.
+	cl.State.Fmt.Finish()
+
+	pipelineID := args[0]
+
+	runID, err := strconv.Atoi(args[1])
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+
+	id := args[2]
+	command := args[3:]
+
+	stdin := int(os.Stdin.Fd())
+	if !term.IsTerminal(stdin) {
+		err := fmt.Errorf("attach requires an interactive terminal")
+		fmt.Println(err)
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		fmt.Println(err)
+		return err
+	}
+	defer conn.Close()
+
+	client := proto.NewGoferClient(conn)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx = metadata.NewOutgoingContext(ctx, md)
+
+	stream, err := client.AttachToTaskRun(ctx)
+	if err != nil {
+		fmt.Printf("could not attach to task run: %v\n", err)
+		return err
+	}
+
+	width, height, _ := term.GetSize(stdin)
+
+	err = stream.Send(&proto.AttachToTaskRunRequest{
+		Request: &proto.AttachToTaskRunRequest_Start{
+			Start: &proto.AttachToTaskRunStart{
+				NamespaceId: cl.State.Config.Namespace,
+				PipelineId:  pipelineID,
+				RunId:       int64(runID),
+				Id:          id,
+				Command:     command,
+				Width:       uint32(width),
+				Height:      uint32(height),
+			},
+		},
+	})
+	if err != nil {
+		fmt.Printf("could not attach to task run: %v\n", err)
+		return err
+	}
+
+	oldState, err := term.MakeRaw(stdin)
+	if err != nil {
+		fmt.Printf("could not put terminal into raw mode: %v\n", err)
+		return err
+	}
+	defer func() { _ = term.Restore(stdin, oldState) }()
+
+	// Input and resizes are sent from separate goroutines, but a stream only allows a single sender at a time.
+	sendLock := sync.Mutex{}
+	send := func(request *proto.AttachToTaskRunRequest) error {
+		sendLock.Lock()
+		defer sendLock.Unlock()
+		return stream.Send(request)
+	}
+
+	// Forward changes to the terminal's size.
+	resize := make(chan os.Signal, 1)
+	signal.Notify(resize, syscall.SIGWINCH)
+	defer signal.Stop(resize)
+
+	go func() {
+		for range resize {
+			width, height, err := term.GetSize(stdin)
+			if err != nil {
+				continue
+			}
+
+			_ = send(&proto.AttachToTaskRunRequest{
+				Request: &proto.AttachToTaskRunRequest_Resize{
+					Resize: &proto.AttachToTaskRunResize{
+						Width:  uint32(width),
+						Height: uint32(height),
+					},
+				},
+			})
+		}
+	}()
+
+	// Forward everything typed as is; in raw mode this includes control characters.
+	go func() {
+		buffer := make([]byte, 1024)
+		for {
+			n, err := os.Stdin.Read(buffer)
+			if n > 0 {
+				sendErr := send(&proto.AttachToTaskRunRequest{
+					Request: &proto.AttachToTaskRunRequest_Input{
+						Input: &proto.AttachToTaskRunInput{
+							Input: buffer[:n],
+						},
+					},
+				})
+				if sendErr != nil {
+					return
+				}
+			}
+
+			if err != nil {
+				sendLock.Lock()
+				_ = stream.CloseSend()
+				sendLock.Unlock()
+				return
+			}
+		}
+	}()
+
+	for {
+		resp, err := stream.Recv()
+		if err != nil {
+			if err == io.EOF {
+				return nil
+			}
+
+			_ = term.Restore(stdin, oldState)
+			fmt.Printf("\nconnection to task run lost: %v\n", err)
+			return err
+		}
+
+		if resp.Keepalive {
+			continue
+		}
+
+		_, _ = os.Stdout.Write(resp.Output)
+	}
+}
@@ -63,6 +63,11 @@ func taskrunGet(_ *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.TaskRun)
+	}
+
 	cl.State.Fmt.Println(formatTaskRunInfo(resp.TaskRun, cl.State.Config.Detail))
 	cl.State.Fmt.Finish()
 
@@ -85,6 +90,17 @@ type data struct {
 	RunID      string
 	TaskRunCmd string
 	ImageName  string
+	Attempt    string
+	Attempts   []attemptData
+	SpawnedBy  string
+}
+
+type attemptData struct {
+	Attempt  int64
+	Started  string
+	Duration string
+	ExitCode int64
+	Reason   string
 }
 
 func formatParents(dependencyMap map[string]proto.TaskRequiredParentState) []string {
@@ -113,11 +129,35 @@ func formatTaskRunInfo(taskRun *proto.TaskRun, detail bool) string {
 		ImageName:  taskRun.Task.Image,
 	}
 
+	if taskRun.SpawnedBy != "" {
+		data.SpawnedBy = color.BlueString(taskRun.SpawnedBy)
+	}
+
+	if taskRun.Task.Retries > 0 {
+		data.Attempt = fmt.Sprintf("%d of %d", taskRun.Attempt, taskRun.Task.Retries+1)
+	}
+
+	for _, attempt := range taskRun.PreviousAttempts {
+		data.Attempts = append(data.Attempts, attemptData{
+			Attempt:  attempt.Attempt,
+			Started:  format.UnixMilli(attempt.Started, "Never", detail),
+			Duration: format.Duration(attempt.Started, attempt.Ended),
+			ExitCode: attempt.ExitCode,
+			Reason:   attempt.Failure.Description,
+		})
+	}
+
 	const formatTmpl = `TaskRun {{.ID}} :: {{.State}}
 
   ✏ Pipeline {{.PipelineID}} | Run {{.RunID}}
   ✏ Started {{.Started}} and ran for {{.Duration}}
   ✏ {{.ImageName}}
+{{- if .Attempt}}
+  ✏ Attempt {{.Attempt}}
+{{- end}}
+{{- if .SpawnedBy}}
+  ✏ Spawned by {{.SpawnedBy}}
+{{- end}}
 
 {{- if .Parents}}
 
@@ -134,6 +174,14 @@ func formatTaskRunInfo(taskRun *proto.TaskRun, detail bool) string {
     | Kind: {{.Failure.Kind}}
     | Reason: {{.Failure.Description}}
 {{- end}}
+
+{{- if .Attempts}}
+
+  ↻ Previous Attempts:
+  {{- range $attempt := .Attempts}}
+    | #{{$attempt.Attempt}} started {{$attempt.Started}}, ran for {{$attempt.Duration}}; exit code {{$attempt.ExitCode}}: {{$attempt.Reason}}
+  {{- end}}
+{{- end}}
 {{- if .EnvVars}}
 
   $ Environment Variables:
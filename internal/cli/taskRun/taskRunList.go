@@ -6,6 +6,7 @@ import (
 	"sort"
 	"strconv"
 	"strings"
+	"time"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
 	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
@@ -21,17 +22,49 @@ var cmdTaskRunList = &cobra.Command{
 	Short: "List all taskruns",
 	Long: `List all taskruns.
 
-A short listing of all task runs for a specific run.`,
-	Example: `$ gofer taskrun list simple_test_pipeline 15`,
-	RunE:    taskrunList,
-	Args:    cobra.ExactArgs(2),
+A short listing of all task runs for a specific run.
+
+The --columns flag controls which columns are shown and in which order. The --sort-by flag sorts the task runs by the
+given column(default: started); prefix the column with '-' to sort in descending order.`,
+	Example: `$ gofer taskrun list simple_test_pipeline 15
+$ gofer taskrun list simple_test_pipeline 15 --columns id,state
+$ gofer taskrun list simple_test_pipeline 15 --sort-by -duration`,
+	RunE: taskrunList,
+	Args: cobra.ExactArgs(2),
 }
 
 func init() {
+	cmdTaskRunList.Flags().StringSlice("columns", []string{}, "only show the given columns (ex. id,duration,state)")
+	cmdTaskRunList.Flags().String("sort-by", "started", "sort task runs by the given column; prefix with '-' for descending order")
 	CmdTaskRun.AddCommand(cmdTaskRunList)
 }
 
-func taskrunList(_ *cobra.Command, args []string) error {
+// taskRunHeaders are the columns shown by the task run list command.
+var taskRunHeaders = []string{"ID", "Started", "Ended", "Duration", "State"}
+
+// taskRunSorters are the columns task runs can be sorted by.
+var taskRunSorters = map[string]func(a, b *proto.TaskRun) bool{
+	"id":       func(a, b *proto.TaskRun) bool { return a.Id < b.Id },
+	"started":  func(a, b *proto.TaskRun) bool { return a.Started < b.Started },
+	"ended":    func(a, b *proto.TaskRun) bool { return a.Ended < b.Ended },
+	"duration": func(a, b *proto.TaskRun) bool { return taskRunDuration(a) < taskRunDuration(b) },
+	"state":    func(a, b *proto.TaskRun) bool { return a.State.String() < b.State.String() },
+}
+
+func taskRunDuration(taskRun *proto.TaskRun) time.Duration {
+	if taskRun.Started == 0 {
+		return 0
+	}
+
+	end := time.Now()
+	if taskRun.Ended != 0 {
+		end = time.UnixMilli(taskRun.Ended)
+	}
+
+	return end.Sub(time.UnixMilli(taskRun.Started))
+}
+
+func taskrunList(cmd *cobra.Command, args []string) error {
 	pipelineID := args[0]
 	runIDRaw := args[1]
 	runID, err := strconv.Atoi(runIDRaw)
@@ -39,6 +72,25 @@ func taskrunList(_ *cobra.Command, args []string) error {
 		return err
 	}
 
+	columns, _ := cmd.Flags().GetStringSlice("columns")
+	sortBy, _ := cmd.Flags().GetString("sort-by")
+
+	sortKeys := []string{}
+	for key := range taskRunSorters {
+		sortKeys = append(sortKeys, key)
+	}
+	sort.Strings(sortKeys)
+
+	sortKey, descending := "", false
+	if sortBy != "" {
+		sortKey, descending, err = cliformat.SortBy(sortBy, sortKeys)
+		if err != nil {
+			cl.State.Fmt.PrintErr(err)
+			cl.State.Fmt.Finish()
+			return err
+		}
+	}
+
 	cl.State.Fmt.Print("Retrieving taskruns")
 
 	conn, err := cl.State.Connect()
@@ -63,10 +115,22 @@ func taskrunList(_ *cobra.Command, args []string) error {
 		return err
 	}
 
-	data := [][]string{}
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.TaskRuns)
+	}
 
-	sort.Slice(resp.TaskRuns, func(i, j int) bool { return resp.TaskRuns[i].Started < resp.TaskRuns[j].Started })
+	if sortKey != "" {
+		less := taskRunSorters[sortKey]
+		sort.SliceStable(resp.TaskRuns, func(i, j int) bool {
+			if descending {
+				return less(resp.TaskRuns[j], resp.TaskRuns[i])
+			}
+			return less(resp.TaskRuns[i], resp.TaskRuns[j])
+		})
+	}
 
+	data := [][]string{}
 	for _, taskrun := range resp.TaskRuns {
 		data = append(data, []string{
 			taskrun.Id,
@@ -77,20 +141,27 @@ func taskrunList(_ *cobra.Command, args []string) error {
 		})
 	}
 
-	table := formatTable(data, !cl.State.Config.NoColor)
+	headers, data, err := cliformat.SelectColumns(taskRunHeaders, data, columns)
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	table := formatTable(headers, data, !cl.State.Config.NoColor)
 
-	cl.State.Fmt.Println(fmt.Sprintf("  TaskRuns for run %s, pipeline %s\n\n%s",
+	cl.State.PrintPaged(fmt.Sprintf("  TaskRuns for run %s, pipeline %s\n\n%s",
 		color.BlueString("#"+runIDRaw), color.BlueString(pipelineID), table))
 	cl.State.Fmt.Finish()
 
 	return nil
 }
 
-func formatTable(data [][]string, color bool) string {
+func formatTable(headers []string, data [][]string, color bool) string {
 	tableString := &strings.Builder{}
 	table := tablewriter.NewWriter(tableString)
 
-	table.SetHeader([]string{"ID", "Started", "Ended", "Duration", "State"})
+	table.SetHeader(headers)
 	table.SetAlignment(tablewriter.ALIGN_LEFT)
 	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
 	table.SetHeaderLine(true)
@@ -102,20 +173,19 @@ func formatTable(data [][]string, color bool) string {
 	table.SetCenterSeparator("")
 
 	if color {
-		table.SetHeaderColor(
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-			tablewriter.Color(tablewriter.FgBlueColor),
-		)
-		table.SetColumnColor(
-			tablewriter.Color(tablewriter.FgYellowColor),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-			tablewriter.Color(0),
-		)
+		headerColors := []tablewriter.Colors{}
+		columnColors := []tablewriter.Colors{}
+		for _, header := range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+			if header == "ID" {
+				columnColors = append(columnColors, tablewriter.Color(tablewriter.FgYellowColor))
+				continue
+			}
+			columnColors = append(columnColors, tablewriter.Color(0))
+		}
+
+		table.SetHeaderColor(headerColors...)
+		table.SetColumnColor(columnColors...)
 	}
 
 	table.AppendBulk(data)
@@ -3,31 +3,46 @@ package taskrun
 import (
 	"context"
 	"fmt"
-	"io"
 	"strconv"
+	"time"
 
 	"github.com/clintjedwards/gofer/internal/cli/cl"
 	"github.com/clintjedwards/gofer/proto"
 	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
 )
 
 var cmdTaskRunLogs = &cobra.Command{
-	Use:     "logs <pipeline> <run> <id>",
-	Short:   "Examine logs for a particular taskrun/container",
-	Example: `$ gofer taskrun logs simple_test_pipeline 23 example_task`,
-	RunE:    taskrunLogs,
-	Args:    cobra.ExactArgs(3),
+	Use:   "logs <pipeline> <run> <id>",
+	Short: "Examine logs for a particular taskrun/container",
+	Example: `$ gofer taskrun logs simple_test_pipeline 23 example_task
+$ gofer taskrun logs simple_test_pipeline 23 example_task --tail 50
+$ gofer taskrun logs simple_test_pipeline 23 example_task --since 10m --follow`,
+	RunE: taskrunLogs,
+	Args: cobra.ExactArgs(3),
 }
 
 func init() {
+	cmdTaskRunLogs.Flags().BoolP("follow", "f", false, "Continuously stream new logs until the task run has finished")
+	cmdTaskRunLogs.Flags().Int64P("tail", "n", 0, "Only show the last N lines of the logs")
+	cmdTaskRunLogs.Flags().Duration("since", 0, "Only show logs written within the given duration (ex. 10m, 1h)")
 	CmdTaskRun.AddCommand(cmdTaskRunLogs)
 }
 
-func taskrunLogs(_ *cobra.Command, args []string) error {
+func taskrunLogs(cmd *cobra.Command, args []string) error {
 	// We don't use the formatter here because we may want to redirect logs we get into
 	// a file or such.
 	cl.State.Fmt.Finish()
 
+	follow, _ := cmd.Flags().GetBool("follow")
+	tail, _ := cmd.Flags().GetInt64("tail")
+	sinceDuration, _ := cmd.Flags().GetDuration("since")
+
+	since := int64(0)
+	if sinceDuration > 0 {
+		since = time.Now().Add(-sinceDuration).UnixMilli()
+	}
+
 	pipeline := args[0]
 
 	runIDRaw := args[1]
@@ -50,29 +65,24 @@ func taskrunLogs(_ *cobra.Command, args []string) error {
 	ctx, cancel := context.WithCancel(context.Background())
 	defer cancel()
 
-	stream, err := client.GetTaskRunLogs(ctx, &proto.GetTaskRunLogsRequest{
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx = metadata.NewOutgoingContext(ctx, md)
+
+	err = cl.StreamTaskRunLogs(ctx, client, &proto.GetTaskRunLogsRequest{
 		NamespaceId: cl.State.Config.Namespace,
 		RunId:       int64(runID),
 		PipelineId:  pipeline,
 		Id:          id,
+		Follow:      &follow,
+		Tail:        tail,
+		Since:       since,
+	}, func(line string) {
+		fmt.Println(line)
 	})
 	if err != nil {
-		fmt.Printf("could not get logs for task run: %v\n", err)
+		fmt.Printf("could not get logs: %v\n", err)
 		return err
 	}
 
-	for {
-		resp, err := stream.Recv()
-		if err != nil {
-			if err == io.EOF {
-				break
-			}
-			fmt.Printf("could not get logs: %v\n", err)
-			return nil
-		}
-
-		fmt.Println(resp.LogLine)
-	}
-
 	return nil
 }
@@ -0,0 +1,159 @@
+package taskrun
+
+import (
+	"context"
+	"encoding/base64"
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdTaskRunSpawn = &cobra.Command{
+	Use:   "spawn <pipeline> <run> <id> <image>",
+	Short: "Add a task run to a run in progress",
+	Long: `Add a task run to a run that is still in progress.
+
+This is meant to be used from within a task run to fan out into more tasks based on what it finds; for example one task
+run per changed service. Task runs are given a token scoped to their namespace through the GOFER_API_TOKEN environment
+variable which can be used to authenticate.
+
+The new task run's id must not already be part of the run and it can only depend on task runs that are. Dependencies
+are given as "<task_id>" or "<task_id>:<state>" where state is one of "any", "successful" or "failure"; the default
+is "successful". Environment variables may refer to secrets and the run store just like those of tasks defined in the
+pipeline config.
+
+When run from within a task run, the spawning task run is taken from the GOFER_TASK_ID environment variable.`,
+	Example: `$ gofer taskrun spawn simple_test_pipeline 23 build_api ubuntu:latest --depends-on detect_changes \
+    --env SERVICE=api --script ./build.sh
+$ GOFER_CLI_TOKEN=$GOFER_API_TOKEN gofer taskrun spawn $GOFER_PIPELINE_ID $GOFER_RUN_ID build_api ubuntu:latest`,
+	RunE: taskrunSpawn,
+	Args: cobra.ExactArgs(4),
+}
+
+func init() {
+	cmdTaskRunSpawn.Flags().StringP("description", "d", "", "Description of the task")
+	cmdTaskRunSpawn.Flags().StringSlice("depends-on", []string{}, "Task runs that must finish before this one starts")
+	cmdTaskRunSpawn.Flags().StringSliceP("env", "e", []string{}, "Environment variables to pass to the task run(KEY=VALUE)")
+	cmdTaskRunSpawn.Flags().StringP("script", "s", "", "Path to a script to run within the container")
+	cmdTaskRunSpawn.Flags().String("shell", "/bin/sh", "Shell used to run the script")
+	cmdTaskRunSpawn.Flags().Int64("retries", 0, "How many more times the task run is attempted after it fails")
+	cmdTaskRunSpawn.Flags().String("spawned-by", os.Getenv("GOFER_TASK_ID"), "The task run spawning this one")
+	CmdTaskRun.AddCommand(cmdTaskRunSpawn)
+}
+
+func taskrunSpawn(cmd *cobra.Command, args []string) error {
+	pipeline := args[0]
+
+	runIDRaw := args[1]
+	runID, err := strconv.Atoi(runIDRaw)
+	if err != nil {
+		return err
+	}
+
+	id := args[2]
+	image := args[3]
+
+	description, _ := cmd.Flags().GetString("description")
+	dependsOnRaw, _ := cmd.Flags().GetStringSlice("depends-on")
+	envRaw, _ := cmd.Flags().GetStringSlice("env")
+	scriptPath, _ := cmd.Flags().GetString("script")
+	shell, _ := cmd.Flags().GetString("shell")
+	retries, _ := cmd.Flags().GetInt64("retries")
+	spawnedBy, _ := cmd.Flags().GetString("spawned-by")
+
+	dependsOn := map[string]proto.TaskRequiredParentState{}
+	for _, dependency := range dependsOnRaw {
+		parent, state, _ := strings.Cut(dependency, ":")
+		switch strings.ToLower(state) {
+		case "", "successful", "success":
+			dependsOn[parent] = proto.TaskRequiredParentState_SUCCESSFUL
+		case "failure", "failed":
+			dependsOn[parent] = proto.TaskRequiredParentState_FAILURE
+		case "any":
+			dependsOn[parent] = proto.TaskRequiredParentState_ANY
+		default:
+			err := fmt.Errorf("dependency %q has unknown state %q; must be one of 'any', 'successful' or 'failure'",
+				parent, state)
+			fmt.Println(err)
+			return err
+		}
+	}
+
+	envVars := map[string]string{}
+	for _, env := range envRaw {
+		key, value, found := strings.Cut(env, "=")
+		if !found {
+			err := fmt.Errorf("environment variable %q must be in the form KEY=VALUE", env)
+			fmt.Println(err)
+			return err
+		}
+		envVars[key] = value
+	}
+
+	var exec *proto.Exec
+	if scriptPath != "" {
+		script, err := os.ReadFile(scriptPath)
+		if err != nil {
+			fmt.Println(fmt.Errorf("could not read script %q: %w", scriptPath, err))
+			return err
+		}
+
+		exec = &proto.Exec{
+			Shell:  shell,
+			Script: base64.StdEncoding.EncodeToString(script),
+		}
+	}
+
+	cl.State.Fmt.Print("Spawning taskrun")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.SpawnTaskRun(ctx, &proto.SpawnTaskRunRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipeline,
+		RunId:       int64(runID),
+		Task: &proto.Task{
+			Id:          id,
+			Description: description,
+			Image:       image,
+			DependsOn:   dependsOn,
+			EnvVars:     envVars,
+			Exec:        exec,
+			Retries:     retries,
+		},
+		SpawnedBy: spawnedBy,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not spawn taskrun: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.TaskRun)
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Spawned task run %s for run #%d", resp.TaskRun.Id, runID))
+	cl.State.Fmt.Println(fmt.Sprintf("\n  View details of your task run: %s",
+		color.YellowString("gofer taskrun get %s %d %s", pipeline, runID, resp.TaskRun.Id)))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
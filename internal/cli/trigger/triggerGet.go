@@ -51,6 +51,11 @@ func triggerGet(_ *cobra.Command, args []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Trigger)
+	}
+
 	cl.State.Fmt.Println(formatTriggerInfo(triggerInfo{
 		Kind:          color.YellowString(resp.Trigger.Kind),
 		State:         cliformat.TriggerState(resp.Trigger.State.String()),
@@ -27,22 +27,47 @@ var cmdTriggerInstall = &cobra.Command{
 
 1) Gofer attempts to run the trigger container locally and connects the user's terminal to stdout/in/err
 2) The trigger container will walk the user through the installation steps required for the trigger
-3) The trigger container will attempt to install the trigger into Gofer on behalf of the user.`,
-	Example: `$ gofer trigger install cron gchr.io/clintjedwards/gofer-containers/triggers/cron`,
-	RunE:    triggerInstall,
-	Args:    cobra.ExactArgs(3),
+3) The trigger container will attempt to install the trigger into Gofer on behalf of the user.
+
+Passing --from-manifest instead installs every trigger listed in the given HCL manifest file without any prompts.
+Triggers that are already installed are reinstalled with the settings from the manifest. The manifest uses the same
+settings as the registered_triggers block of the server configuration:
+
+  trigger "<kind>" {
+    image    = "<image>"
+    env_vars = {
+      "<key>": "<value>"
+    }
+  }`,
+	Example: `$ gofer trigger install cron gchr.io/clintjedwards/gofer-containers/triggers/cron
+$ gofer trigger install --from-manifest extensions.hcl`,
+	RunE: triggerInstall,
+	Args: cobra.RangeArgs(0, 2),
 }
 
 func init() {
 	cmdTriggerInstall.Flags().StringP("host", "h", "", "URL of Gofer server")
 	cmdTriggerInstall.Flags().StringP("user", "u", "", "The username needed for authentication to docker image repository")
 	cmdTriggerInstall.Flags().StringP("pass", "p", "", "The password needed for authentication to docker image repository")
+	cmdTriggerInstall.Flags().String("from-manifest", "", "Install all triggers listed in the given manifest file")
 	CmdTrigger.AddCommand(cmdTriggerInstall)
 }
 
 func triggerInstall(cmd *cobra.Command, args []string) error {
 	ctx := context.Background()
 
+	manifest, _ := cmd.Flags().GetString("from-manifest")
+	if manifest != "" {
+		return triggerInstallManifest(manifest)
+	}
+
+	if len(args) != 2 {
+		err := fmt.Errorf("requires a kind and image or the --from-manifest flag")
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
 	kind := args[0]
 	image := args[1]
 
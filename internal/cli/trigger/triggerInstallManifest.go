@@ -0,0 +1,105 @@
+package trigger
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/proto"
+	"google.golang.org/grpc/codes"
+	"google.golang.org/grpc/metadata"
+	"google.golang.org/grpc/status"
+)
+
+// triggerInstallManifest installs every trigger listed in the manifest. Triggers that are already installed are
+// reinstalled so that they pick up the settings from the manifest.
+func triggerInstallManifest(path string) error {
+	cl.State.Fmt.Print("Reading manifest")
+
+	manifest, err := config.LoadExtensionManifest(path)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not read manifest %q: %v", path, err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+
+	failed := 0
+	for _, trigger := range manifest.Triggers {
+		cl.State.Fmt.Print(fmt.Sprintf("Installing trigger %q", trigger.Kind))
+
+		action, err := installTrigger(ctx, client, trigger)
+		if err != nil {
+			failed++
+			cl.State.Fmt.Println(fmt.Sprintf("  ✗ %s: %v", trigger.Kind, err))
+			continue
+		}
+
+		cl.State.Fmt.Println(fmt.Sprintf("  ✓ %s: %s", trigger.Kind, action))
+	}
+
+	if len(manifest.Notifiers) > 0 {
+		cl.State.Fmt.Println(fmt.Sprintf("\n  The manifest also lists %d notifier(s); install them with %q",
+			len(manifest.Notifiers), "gofer notifier install --from-manifest "+path))
+	}
+
+	if failed > 0 {
+		err := fmt.Errorf("%d of %d triggers could not be installed", failed, len(manifest.Triggers))
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Installed %d trigger(s) from manifest", len(manifest.Triggers)))
+	cl.State.Fmt.Finish()
+
+	return nil
+}
+
+// installTrigger installs the trigger, replacing it if a trigger of the same kind already exists. Returns a
+// description of what was done.
+func installTrigger(ctx context.Context, client proto.GoferClient, trigger config.Trigger) (string, error) {
+	request := &proto.InstallTriggerRequest{
+		Trigger: &proto.TriggerConfig{
+			Kind:    trigger.Kind,
+			Image:   trigger.Image,
+			User:    trigger.User,
+			Pass:    trigger.Pass,
+			EnvVars: trigger.EnvVars,
+		},
+	}
+
+	action := "installed"
+
+	_, err := client.GetTrigger(ctx, &proto.GetTriggerRequest{Kind: trigger.Kind})
+	if err != nil && status.Code(err) != codes.NotFound {
+		return "", err
+	}
+
+	if err == nil {
+		_, err = client.UninstallTrigger(ctx, &proto.UninstallTriggerRequest{Kind: trigger.Kind})
+		if err != nil {
+			return "", fmt.Errorf("could not remove previous install: %w", err)
+		}
+		action = "updated"
+	}
+
+	_, err = client.InstallTrigger(ctx, request)
+	if err != nil {
+		return "", err
+	}
+
+	return action, nil
+}
@@ -45,6 +45,11 @@ func triggerList(_ *cobra.Command, _ []string) error {
 		return err
 	}
 
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Triggers)
+	}
+
 	data := [][]string{}
 	for _, trigger := range resp.Triggers {
 		data = append(data, []string{
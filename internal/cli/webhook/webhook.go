@@ -0,0 +1,18 @@
+package webhook
+
+import (
+	"github.com/spf13/cobra"
+)
+
+var CmdWebhook = &cobra.Command{
+	Use:   "webhook",
+	Short: "Manage outbound webhooks",
+	Long: `Manage outbound webhooks.
+
+Webhooks are sent a POST with a JSON body whenever a run of the namespace(or a single pipeline) starts or completes.
+Every delivery carries an "X-Gofer-Signature" header containing "sha256=" followed by the hex encoded HMAC-SHA256 of
+the body, keyed with the webhook's secret, so the receiving end can verify it came from Gofer.
+
+Deliveries that fail are retried with backoff. The outcome of the most recent deliveries to each webhook is kept and
+can be viewed with "gofer webhook deliveries".`,
+}
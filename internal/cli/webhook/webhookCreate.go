@@ -0,0 +1,83 @@
+package webhook
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdWebhookCreate = &cobra.Command{
+	Use:   "create <url>",
+	Short: "Register a new webhook",
+	Long: `Register a new webhook for the namespace.
+
+Webhooks are told about every pipeline in the namespace unless a pipeline is given and about both STARTED_RUN and
+COMPLETED_RUN events unless events are given.
+
+A secret used to sign deliveries is generated unless one is given. It's only shown once.`,
+	Example: `$ gofer webhook create https://example.com/hooks/gofer
+$ gofer webhook create https://example.com/hooks/gofer --pipeline simple_test_pipeline --events COMPLETED_RUN`,
+	RunE: webhookCreate,
+	Args: cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdWebhookCreate.Flags().StringP("pipeline", "p", "", "Only send events of this pipeline")
+	cmdWebhookCreate.Flags().StringSliceP("events", "e", []string{}, "Events to send; STARTED_RUN and/or COMPLETED_RUN")
+	cmdWebhookCreate.Flags().StringP("secret", "s", "", "Key used to sign deliveries; generated if empty")
+	CmdWebhook.AddCommand(cmdWebhookCreate)
+}
+
+func webhookCreate(cmd *cobra.Command, args []string) error {
+	url := args[0]
+
+	pipeline, _ := cmd.Flags().GetString("pipeline")
+	events, _ := cmd.Flags().GetStringSlice("events")
+	secret, _ := cmd.Flags().GetString("secret")
+
+	cl.State.Fmt.Print("Creating webhook")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.CreateWebhook(ctx, &proto.CreateWebhookRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		PipelineId:  pipeline,
+		Url:         url,
+		Events:      events,
+		Secret:      secret,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not create webhook: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp)
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Created webhook: [%d] %s", resp.Webhook.Id, resp.Webhook.Url))
+
+	if secret == "" {
+		cl.State.Fmt.Println(fmt.Sprintf("\n  Secret: %s\n\n  %s", color.YellowString(resp.Secret),
+			"Store the secret now; it can't be retrieved later."))
+	}
+
+	cl.State.Fmt.Finish()
+	return nil
+}
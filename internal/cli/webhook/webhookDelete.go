@@ -0,0 +1,60 @@
+package webhook
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdWebhookDelete = &cobra.Command{
+	Use:     "delete <id>",
+	Short:   "Delete a webhook along with its delivery history",
+	Example: `$ gofer webhook delete 3`,
+	RunE:    webhookDelete,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	CmdWebhook.AddCommand(cmdWebhookDelete)
+}
+
+func webhookDelete(_ *cobra.Command, args []string) error {
+	id, err := strconv.ParseInt(args[0], 10, 64)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse webhook id: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Deleting webhook")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	_, err = client.DeleteWebhook(ctx, &proto.DeleteWebhookRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		Id:          id,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not delete webhook: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.PrintSuccess(fmt.Sprintf("Deleted webhook: [%d]", id))
+	cl.State.Fmt.Finish()
+	return nil
+}
@@ -0,0 +1,111 @@
+package webhook
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/fatih/color"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdWebhookDeliveries = &cobra.Command{
+	Use:   "deliveries <id>",
+	Short: "List the most recent deliveries to a webhook",
+	Long: `List the most recent deliveries to a webhook, newest first.
+
+Deliveries that are still being retried are shown as pending. The error of a delivery is the one from its most recent
+attempt.`,
+	Example: `$ gofer webhook deliveries 3`,
+	RunE:    webhookDeliveries,
+	Args:    cobra.ExactArgs(1),
+}
+
+func init() {
+	cmdWebhookDeliveries.Flags().IntP("limit", "l", 10, "limit the amount of results returned")
+	CmdWebhook.AddCommand(cmdWebhookDeliveries)
+}
+
+func webhookDeliveries(cmd *cobra.Command, args []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+	limit, _ := cmd.Flags().GetInt("limit")
+
+	id, err := strconv.ParseInt(args[0], 10, 64)
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not parse webhook id: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	cl.State.Fmt.Print("Retrieving webhook deliveries")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListWebhookDeliveries(ctx, &proto.ListWebhookDeliveriesRequest{
+		NamespaceId: cl.State.Config.Namespace,
+		WebhookId:   id,
+		Limit:       int64(limit),
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list webhook deliveries: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Deliveries)
+	}
+
+	data := [][]string{}
+	for _, delivery := range resp.Deliveries {
+		data = append(data, []string{
+			strconv.FormatInt(delivery.Id, 10),
+			delivery.Event,
+			fmt.Sprintf("%s #%d", delivery.PipelineId, delivery.RunId),
+			colorizeDeliveryState(delivery.State, !noColor),
+			strconv.FormatInt(delivery.Attempts, 10),
+			cliformat.UnixMilli(delivery.Updated, "Never", detail),
+			delivery.Error,
+		})
+	}
+
+	table := formatTable([]string{"ID", "Event", "Run", "State", "Attempts", "Last Attempt", "Error"}, data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func colorizeDeliveryState(state proto.WebhookDelivery_State, colorize bool) string {
+	value := strings.ToLower(state.String())
+	if !colorize {
+		return value
+	}
+
+	switch state {
+	case proto.WebhookDelivery_SUCCEEDED:
+		return color.GreenString(value)
+	case proto.WebhookDelivery_FAILED:
+		return color.RedString(value)
+	case proto.WebhookDelivery_PENDING:
+		return color.YellowString(value)
+	default:
+		return value
+	}
+}
@@ -0,0 +1,120 @@
+package webhook
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/cli/cl"
+	cliformat "github.com/clintjedwards/gofer/internal/cli/format"
+	"github.com/clintjedwards/gofer/proto"
+	"github.com/olekukonko/tablewriter"
+	"github.com/spf13/cobra"
+	"google.golang.org/grpc/metadata"
+)
+
+var cmdWebhookList = &cobra.Command{
+	Use:     "list",
+	Short:   "List the webhooks of the namespace",
+	Example: `$ gofer webhook list`,
+	RunE:    webhookList,
+}
+
+func init() {
+	CmdWebhook.AddCommand(cmdWebhookList)
+}
+
+func webhookList(cmd *cobra.Command, _ []string) error {
+	noColor, _ := cmd.Flags().GetBool("no-color")
+	detail, _ := cmd.Flags().GetBool("detail")
+
+	cl.State.Fmt.Print("Retrieving webhooks")
+
+	conn, err := cl.State.Connect()
+	if err != nil {
+		cl.State.Fmt.PrintErr(err)
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	client := proto.NewGoferClient(conn)
+
+	md := metadata.Pairs("Authorization", "Bearer "+cl.State.Config.Token)
+	ctx := metadata.NewOutgoingContext(context.Background(), md)
+	resp, err := client.ListWebhooks(ctx, &proto.ListWebhooksRequest{
+		NamespaceId: cl.State.Config.Namespace,
+	})
+	if err != nil {
+		cl.State.Fmt.PrintErr(fmt.Sprintf("could not list webhooks: %v", err))
+		cl.State.Fmt.Finish()
+		return err
+	}
+
+	if cl.State.Structured() {
+		cl.State.Fmt.Finish()
+		return cl.State.PrintStructured(resp.Webhooks)
+	}
+
+	data := [][]string{}
+	for _, webhook := range resp.Webhooks {
+		pipeline := webhook.PipelineId
+		if pipeline == "" {
+			pipeline = "All"
+		}
+
+		events := strings.Join(webhook.Events, ", ")
+		if events == "" {
+			events = "All"
+		}
+
+		data = append(data, []string{
+			strconv.FormatInt(webhook.Id, 10),
+			webhook.Url,
+			pipeline,
+			events,
+			cliformat.UnixMilli(webhook.Created, "Unknown", detail),
+		})
+	}
+
+	table := formatTable([]string{"ID", "URL", "Pipeline", "Events", "Created"}, data, !noColor)
+
+	cl.State.Fmt.Println(table)
+	cl.State.Fmt.Finish()
+	return nil
+}
+
+func formatTable(headers []string, data [][]string, colorize bool) string {
+	tableString := &strings.Builder{}
+	table := tablewriter.NewWriter(tableString)
+
+	table.SetHeader(headers)
+	table.SetAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderAlignment(tablewriter.ALIGN_LEFT)
+	table.SetHeaderLine(true)
+	table.SetBorder(false)
+	table.SetAutoFormatHeaders(false)
+	table.SetRowSeparator("―")
+	table.SetRowLine(false)
+	table.SetColumnSeparator("")
+	table.SetCenterSeparator("")
+
+	if colorize {
+		headerColors := []tablewriter.Colors{}
+		columnColors := []tablewriter.Colors{tablewriter.Color(tablewriter.FgYellowColor)}
+		for i := range headers {
+			headerColors = append(headerColors, tablewriter.Color(tablewriter.FgBlueColor))
+			if i > 0 {
+				columnColors = append(columnColors, tablewriter.Color(0))
+			}
+		}
+
+		table.SetHeaderColor(headerColors...)
+		table.SetColumnColor(columnColors...)
+	}
+
+	table.AppendBulk(data)
+
+	table.Render()
+	return tableString.String()
+}
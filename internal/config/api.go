@@ -3,7 +3,9 @@ package config
 import (
 	"encoding/json"
 	"fmt"
+	"net"
 	"os"
+	"strings"
 	"time"
 
 	"github.com/hashicorp/hcl/v2/hclsimple"
@@ -27,7 +29,7 @@ type API struct {
 	EventLogRetentionHCL string `ignored:"true" hcl:"event_log_retention,optional"`
 
 	// Key used for encryption of secret values specific to Gofer operation. Ex. API tokens, trigger keys, etc
-	EncryptionKey string `split_words:"true" hcl:"encryption_key,optional"`
+	EncryptionKey string `split_words:"true" hcl:"encryption_key,optional" mask:"true"`
 
 	// How often the background process for pruning events should run.
 	PruneEventsInterval time.Duration `split_words:"true"`
@@ -59,7 +61,20 @@ type API struct {
 	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
 	TaskRunStopTimeoutHCL string `ignored:"true" hcl:"task_run_stop_timeout,optional"`
 
+	// TaskRunEnvVars are injected into every task run(ex. HTTPS_PROXY or the path of a CA bundle). They take the
+	// lowest precedence; variables with the same name set by Gofer, the pipeline config or the run replace them.
+	TaskRunEnvVars map[string]string `split_words:"true" hcl:"task_run_env_vars,optional"`
+
+	// WebhookAllowedNetworks are CIDR ranges outbound webhooks may be delivered to even though they're private,
+	// loopback or link-local addresses. Deliveries to those addresses are refused otherwise.
+	WebhookAllowedNetworks []string `split_words:"true" hcl:"webhook_allowed_networks,optional"`
+
 	ExternalEventsAPI *ExternalEventsAPI `split_words:"true" hcl:"external_events_api,block"`
+	EventSinks        *EventSinks        `split_words:"true" hcl:"event_sinks,block"`
+	LogExport         *LogExport         `split_words:"true" hcl:"log_export,block"`
+	Tracing           *Tracing           `hcl:"tracing,block"`
+	TaskRunLogs       *TaskRunLogs       `split_words:"true" hcl:"task_run_logs,block"`
+	Digests           *Digests           `hcl:"digests,block"`
 	Database          *Database          `hcl:"database,block"`
 	ObjectStore       *ObjectStore       `hcl:"object_store,block"`
 	SecretStore       *SecretStore       `hcl:"secret_store,block"`
@@ -67,6 +82,7 @@ type API struct {
 	Server            *Server            `hcl:"server,block"`
 	Triggers          *Triggers          `hcl:"triggers,block"`
 	Notifiers         *Notifiers         `hcl:"notifiers,block"`
+	Permissioning     *Permissioning     `hcl:"permissioning,block"`
 }
 
 func DefaultAPIConfig() *API {
@@ -80,6 +96,11 @@ func DefaultAPIConfig() *API {
 		TaskRunLogsDir:          "/tmp",
 		TaskRunStopTimeout:      mustParseDuration("5m"),
 		ExternalEventsAPI:       DefaultExternalEventsAPIConfig(),
+		EventSinks:              DefaultEventSinksConfig(),
+		LogExport:               DefaultLogExportConfig(),
+		Tracing:                 DefaultTracingConfig(),
+		TaskRunLogs:             DefaultTaskRunLogsConfig(),
+		Digests:                 DefaultDigestsConfig(),
 		Database:                DefaultDatabaseConfig(),
 		ObjectStore:             DefaultObjectStoreConfig(),
 		SecretStore:             DefaultSecretStoreConfig(),
@@ -87,6 +108,7 @@ func DefaultAPIConfig() *API {
 		Server:                  DefaultServerConfig(),
 		Triggers:                DefaultTriggersConfig(),
 		Notifiers:               DefaultNotifiersConfig(),
+		Permissioning:           DefaultPermissioningConfig(),
 	}
 }
 
@@ -103,11 +125,47 @@ type Server struct {
 	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
 	ShutdownTimeoutHCL string `ignored:"true" hcl:"shutdown_timeout,optional"`
 
+	// How long Gofer waits for runs that are in progress to finish before shutting down. Runs that don't finish in
+	// time are picked back up when Gofer starts again.
+	DrainTimeout time.Duration `split_words:"true"`
+
+	// DrainTimeoutHCL is the HCL compatible counter part to DrainTimeout. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	DrainTimeoutHCL string `ignored:"true" hcl:"drain_timeout,optional"`
+
+	// StreamKeepalive is how often the task run log, task run attach and event streams send a keepalive message while
+	// they have nothing else to send. This stops proxies and load balancers from closing long lived streams for being
+	// idle. A negative duration turns keepalives off.
+	StreamKeepalive time.Duration `split_words:"true"`
+
+	// StreamKeepaliveHCL is the HCL compatible counter part to StreamKeepalive. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	StreamKeepaliveHCL string `ignored:"true" hcl:"stream_keepalive,optional"`
+
+	// StreamIdleTimeout is how long the task run log, task run attach and event streams can go without sending or
+	// receiving anything other than keepalives before Gofer closes them. The client is told why the stream was closed.
+	// Zero leaves idle streams open.
+	StreamIdleTimeout time.Duration `split_words:"true"`
+
+	// StreamIdleTimeoutHCL is the HCL compatible counter part to StreamIdleTimeout. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	StreamIdleTimeoutHCL string `ignored:"true" hcl:"stream_idle_timeout,optional"`
+
 	TLSCertPath string `split_words:"true" hcl:"tls_cert_path,optional"`
 	TLSKeyPath  string `split_words:"true" hcl:"tls_key_path,optional"`
 
 	// Temporary storage for downloaded pipeline configs.
 	TmpDir string `split_words:"true" hcl:"tmp_dir,optional"`
+
+	// TrustedProxies is a list of CIDRs of the reverse proxies in front of Gofer. The X-Forwarded-For header is only
+	// used to determine the address of a caller when the request comes from one of these proxies.
+	TrustedProxies []string `split_words:"true" hcl:"trusted_proxies,optional"`
+
+	// EnableMetrics serves run and task run duration metrics in the Prometheus format on the /metrics path.
+	EnableMetrics bool `split_words:"true" hcl:"enable_metrics,optional"`
+
+	// TLS controls the TLS versions and cipher suites accepted and how often certificates are reloaded.
+	TLS *ServerTLS `hcl:"tls,block"`
 }
 
 // DefaultServerConfig returns a pre-populated configuration struct that is used as the base for super imposing user configuration
@@ -116,7 +174,11 @@ func DefaultServerConfig() *Server {
 	return &Server{
 		DevMode:         true,
 		ShutdownTimeout: mustParseDuration("15s"),
+		DrainTimeout:    mustParseDuration("2m"),
+		StreamKeepalive: mustParseDuration("30s"),
 		TmpDir:          "/tmp",
+		TrustedProxies:  []string{},
+		TLS:             DefaultServerTLSConfig(),
 	}
 }
 
@@ -169,7 +231,7 @@ type Trigger struct {
 	User string `json:"user" hcl:"user,optional"`
 
 	// The password for the docker repository; if needed.
-	Pass string `json:"pass" hcl:"pass,optional"`
+	Pass string `json:"pass" hcl:"pass,optional" mask:"true"`
 
 	// Environment variables to pass to the trigger container. This is used to pass runtime settings to the container.
 	EnvVars map[string]string `json:"env_vars" hcl:"env_vars,optional"`
@@ -217,7 +279,7 @@ type Notifier struct {
 	User string `json:"user" hcl:"user,optional"`
 
 	// The password for the docker repository; if needed.
-	Pass string `json:"pass" hcl:"pass,optional"`
+	Pass string `json:"pass" hcl:"pass,optional" mask:"true"`
 
 	// Environment variables to pass to the trigger container. This is used to pass runtime settings to the container.
 	EnvVars map[string]string `json:"env_vars" hcl:"env_vars,optional"`
@@ -334,6 +396,18 @@ func (c *API) convertDurationFromHCL() {
 		c.Server.ShutdownTimeout = mustParseDuration(c.Server.ShutdownTimeoutHCL)
 	}
 
+	if c.Server != nil && c.Server.DrainTimeoutHCL != "" {
+		c.Server.DrainTimeout = mustParseDuration(c.Server.DrainTimeoutHCL)
+	}
+
+	if c.Server != nil && c.Server.StreamKeepaliveHCL != "" {
+		c.Server.StreamKeepalive = mustParseDuration(c.Server.StreamKeepaliveHCL)
+	}
+
+	if c.Server != nil && c.Server.StreamIdleTimeoutHCL != "" {
+		c.Server.StreamIdleTimeout = mustParseDuration(c.Server.StreamIdleTimeoutHCL)
+	}
+
 	if c != nil && c.Triggers.HealthcheckIntervalHCL != "" {
 		c.Triggers.HealthcheckInterval = mustParseDuration(c.Triggers.HealthcheckIntervalHCL)
 	}
@@ -354,9 +428,69 @@ func (c *API) convertDurationFromHCL() {
 		c.Triggers.StopTimeout = mustParseDuration(c.Triggers.StopTimeoutHCL)
 	}
 
+	if c.Permissioning != nil && c.Permissioning.OIDC != nil && c.Permissioning.OIDC.TokenTTLHCL != "" {
+		c.Permissioning.OIDC.TokenTTL = mustParseDuration(c.Permissioning.OIDC.TokenTTLHCL)
+	}
+
+	if c.Permissioning != nil && c.Permissioning.LDAP != nil && c.Permissioning.LDAP.RefreshIntervalHCL != "" {
+		c.Permissioning.LDAP.RefreshInterval = mustParseDuration(c.Permissioning.LDAP.RefreshIntervalHCL)
+	}
+
+	if c.Permissioning != nil && c.Permissioning.AuditLog != nil && c.Permissioning.AuditLog.RetentionHCL != "" {
+		c.Permissioning.AuditLog.Retention = mustParseDuration(c.Permissioning.AuditLog.RetentionHCL)
+	}
+
+	if c.Permissioning != nil && c.Permissioning.AuditLog != nil && c.Permissioning.AuditLog.PruneIntervalHCL != "" {
+		c.Permissioning.AuditLog.PruneInterval = mustParseDuration(c.Permissioning.AuditLog.PruneIntervalHCL)
+	}
+
+	if c.EventSinks != nil && c.EventSinks.MaxRetryIntervalHCL != "" {
+		c.EventSinks.MaxRetryInterval = mustParseDuration(c.EventSinks.MaxRetryIntervalHCL)
+	}
+
+	if c.EventSinks != nil {
+		for i := range c.EventSinks.Sinks {
+			if c.EventSinks.Sinks[i].TimeoutHCL != "" {
+				c.EventSinks.Sinks[i].Timeout = mustParseDuration(c.EventSinks.Sinks[i].TimeoutHCL)
+			}
+		}
+	}
+
+	if c.LogExport != nil && c.LogExport.FlushIntervalHCL != "" {
+		c.LogExport.FlushInterval = mustParseDuration(c.LogExport.FlushIntervalHCL)
+	}
+
+	if c.Tracing != nil && c.Tracing.FlushIntervalHCL != "" {
+		c.Tracing.FlushInterval = mustParseDuration(c.Tracing.FlushIntervalHCL)
+	}
+
+	if c.TaskRunLogs != nil && c.TaskRunLogs.BudgetCheckIntervalHCL != "" {
+		c.TaskRunLogs.BudgetCheckInterval = mustParseDuration(c.TaskRunLogs.BudgetCheckIntervalHCL)
+	}
+
+	if c.Scheduler != nil && c.Scheduler.ImageMaxAgeHCL != "" {
+		c.Scheduler.ImageMaxAge = mustParseDuration(c.Scheduler.ImageMaxAgeHCL)
+	}
+
+	if c.Server != nil && c.Server.TLS != nil && c.Server.TLS.ReloadIntervalHCL != "" {
+		c.Server.TLS.ReloadInterval = mustParseDuration(c.Server.TLS.ReloadIntervalHCL)
+	}
+
 	if c.Scheduler != nil && c.Scheduler.Docker.PruneIntervalHCL != "" {
 		c.Scheduler.Docker.PruneInterval = mustParseDuration(c.Scheduler.Docker.PruneIntervalHCL)
 	}
+
+	if c.Scheduler != nil && c.Scheduler.Podman != nil && c.Scheduler.Podman.PruneIntervalHCL != "" {
+		c.Scheduler.Podman.PruneInterval = mustParseDuration(c.Scheduler.Podman.PruneIntervalHCL)
+	}
+
+	if c.Scheduler != nil && c.Scheduler.Local != nil && c.Scheduler.Local.PruneIntervalHCL != "" {
+		c.Scheduler.Local.PruneInterval = mustParseDuration(c.Scheduler.Local.PruneIntervalHCL)
+	}
+
+	if c.ObjectStore != nil && c.ObjectStore.ExpiryCheckIntervalHCL != "" {
+		c.ObjectStore.ExpiryCheckInterval = mustParseDuration(c.ObjectStore.ExpiryCheckIntervalHCL)
+	}
 }
 
 // Get the final configuration for the server.
@@ -374,17 +508,7 @@ func InitAPIConfig(userDefinedPath string) (*API, error) {
 	// First we initiate the default values for the config.
 	config := DefaultAPIConfig()
 
-	possibleConfigPaths := []string{userDefinedPath, "/etc/gofer/gofer.hcl"}
-
-	path := searchFilePaths(possibleConfigPaths...)
-
-	// envVars top all other entries so if its not empty we just insert it over the current path
-	// regardless of if we found one.
-	envPath := os.Getenv("GOFER_CONFIG_PATH")
-	if envPath != "" {
-		path = envPath
-	}
-
+	path := apiConfigPath(userDefinedPath)
 	if path != "" {
 		err := config.FromFile(path)
 		if err != nil {
@@ -409,6 +533,22 @@ func InitAPIConfig(userDefinedPath string) (*API, error) {
 	return config, nil
 }
 
+// apiConfigPath returns the path of the configuration file to load or an empty string if there is none.
+func apiConfigPath(userDefinedPath string) string {
+	possibleConfigPaths := []string{userDefinedPath, "/etc/gofer/gofer.hcl"}
+
+	path := searchFilePaths(possibleConfigPaths...)
+
+	// envVars top all other entries so if its not empty we just insert it over the current path
+	// regardless of if we found one.
+	envPath := os.Getenv("GOFER_CONFIG_PATH")
+	if envPath != "" {
+		path = envPath
+	}
+
+	return path
+}
+
 func (c *API) validate() error {
 	if c.SecretStore != nil && c.SecretStore.BoltDB != nil {
 
@@ -419,6 +559,134 @@ func (c *API) validate() error {
 		if !c.Server.DevMode && c.SecretStore.BoltDB.EncryptionKey == "changemechangemechangemechangeme" {
 			return fmt.Errorf("encryption_key cannot be left as default; must be changed to a 32 character random string")
 		}
+
+		for _, key := range c.SecretStore.BoltDB.PreviousEncryptionKeys {
+			if len(key) != 32 {
+				return fmt.Errorf("previous_encryption_keys must each be a 32 character random string")
+			}
+		}
+	}
+
+	if c.Server != nil {
+		for _, proxy := range c.Server.TrustedProxies {
+			_, _, err := net.ParseCIDR(proxy)
+			if err != nil {
+				return fmt.Errorf("server.trusted_proxies: %q is not a valid CIDR; %w", proxy, err)
+			}
+		}
+
+		if c.Server.TLS != nil {
+			err := c.Server.TLS.validate()
+			if err != nil {
+				return err
+			}
+		}
+	}
+
+	if c.Scheduler != nil {
+		err := c.Scheduler.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.EventSinks != nil {
+		err := c.EventSinks.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.LogExport != nil && c.LogExport.Enable {
+		err := c.LogExport.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.Tracing != nil && c.Tracing.Enable {
+		err := c.Tracing.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	for key := range c.TaskRunEnvVars {
+		if strings.TrimSpace(key) == "" {
+			return fmt.Errorf("task_run_env_vars cannot contain an empty variable name")
+		}
+
+		if strings.HasPrefix(strings.ToUpper(key), "GOFER_") {
+			return fmt.Errorf("task_run_env_vars: %q cannot start with 'GOFER_'; those variables are reserved for Gofer", key)
+		}
+	}
+
+	for _, network := range c.WebhookAllowedNetworks {
+		_, _, err := net.ParseCIDR(network)
+		if err != nil {
+			return fmt.Errorf("webhook_allowed_networks: %q is not a valid CIDR range", network)
+		}
+	}
+
+	if c.TaskRunLogs != nil {
+		err := c.TaskRunLogs.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.Digests != nil {
+		err := c.Digests.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.Permissioning != nil && c.Permissioning.OIDC != nil && c.Permissioning.OIDC.Enable {
+		err := c.Permissioning.OIDC.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.Permissioning != nil && c.Permissioning.ExternalJWT != nil && c.Permissioning.ExternalJWT.Enable {
+		err := c.Permissioning.ExternalJWT.validate()
+		if err != nil {
+			return err
+		}
+
+		if c.Permissioning.OIDC != nil && c.Permissioning.OIDC.Enable &&
+			c.Permissioning.OIDC.Issuer == c.Permissioning.ExternalJWT.Issuer {
+			return fmt.Errorf("permissioning.external_jwt.issuer cannot be the same as permissioning.oidc.issuer")
+		}
+	}
+
+	if c.Permissioning != nil && c.Permissioning.LDAP != nil && c.Permissioning.LDAP.Enable {
+		err := c.Permissioning.LDAP.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.Permissioning != nil && c.Permissioning.MTLS != nil && c.Permissioning.MTLS.Enable {
+		err := c.Permissioning.MTLS.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.Permissioning != nil && c.Permissioning.AuditLog != nil && c.Permissioning.AuditLog.Enable {
+		err := c.Permissioning.AuditLog.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if c.Permissioning != nil && c.Permissioning.NamespaceIsolation != nil && c.Permissioning.NamespaceIsolation.Enable {
+		err := c.Permissioning.NamespaceIsolation.validate()
+		if err != nil {
+			return err
+		}
 	}
 
 	return nil
@@ -51,6 +51,8 @@ func TestAPISampleFromFile(t *testing.T) {
 			},
 			PipelineObjectLimit: 10,
 			RunObjectExpiry:     20,
+			ArtifactExpiry:      20,
+			CacheBudgetMB:       5000,
 		},
 
 		SecretStore: &SecretStore{
@@ -80,12 +82,8 @@ func TestAPISampleFromFile(t *testing.T) {
 		},
 
 		Triggers: &Triggers{
-			StopTimeout:            time.Minute * 5,
-			StopTimeoutHCL:         "5m",
-			HealthcheckInterval:    time.Second * 30,
-			HealthcheckIntervalHCL: "30s",
-			TLSCertPath:            "./localhost.crt",
-			TLSKeyPath:             "./localhost.key",
+			TLSCertPath: "./localhost.crt",
+			TLSKeyPath:  "./localhost.key",
 		},
 	}
 
@@ -140,6 +138,13 @@ func TestAPISampleOverwriteWithEnvs(t *testing.T) {
 			Host:   "localhost:8081",
 		},
 
+		// Blocks missing from the sample config are created empty while reading the environment.
+		EventSinks:  &EventSinks{},
+		LogExport:   &LogExport{},
+		Tracing:     &Tracing{},
+		TaskRunLogs: &TaskRunLogs{},
+		Digests:     &Digests{},
+
 		Database: &Database{
 			Engine:          "bolt",
 			MaxResultsLimit: 1000,
@@ -155,6 +160,8 @@ func TestAPISampleOverwriteWithEnvs(t *testing.T) {
 			},
 			PipelineObjectLimit: 10,
 			RunObjectExpiry:     1000,
+			ArtifactExpiry:      20,
+			CacheBudgetMB:       5000,
 		},
 
 		SecretStore: &SecretStore{
@@ -172,6 +179,7 @@ func TestAPISampleOverwriteWithEnvs(t *testing.T) {
 				PruneInterval:    time.Hour * 24,
 				PruneIntervalHCL: "24h",
 			},
+			TaskResources: &TaskResources{},
 		},
 
 		Server: &Server{
@@ -181,18 +189,24 @@ func TestAPISampleOverwriteWithEnvs(t *testing.T) {
 			TLSCertPath:        "./test",
 			TLSKeyPath:         "./localhost.key",
 			TmpDir:             "/tmp",
+			TLS:                &ServerTLS{},
 		},
 
 		Triggers: &Triggers{
-			StopTimeout:            time.Minute * 5,
-			StopTimeoutHCL:         "5m",
-			HealthcheckInterval:    time.Second * 30,
-			HealthcheckIntervalHCL: "30s",
-			TLSCertPath:            "./test",
-			TLSKeyPath:             "./localhost.key",
+			TLSCertPath: "./test",
+			TLSKeyPath:  "./localhost.key",
 		},
 
 		Notifiers: &Notifiers{},
+
+		Permissioning: &Permissioning{
+			OIDC:               &OIDC{},
+			ExternalJWT:        &ExternalJWT{},
+			LDAP:               &LDAP{},
+			MTLS:               &MTLS{},
+			AuditLog:           &AuditLog{},
+			NamespaceIsolation: &NamespaceIsolation{},
+		},
 	}
 
 	diff := cmp.Diff(expected, hclconf)
@@ -232,7 +246,10 @@ func TestSetTriggersViaEnv(t *testing.T) {
 			BoltDB: &BoltDBSecret{},
 		},
 		Scheduler: &Scheduler{
-			Docker: &Docker{},
+			Docker:        &Docker{},
+			Podman:        &Podman{},
+			Local:         &Local{},
+			TaskResources: &TaskResources{},
 		},
 		Server: &Server{},
 		Triggers: &Triggers{
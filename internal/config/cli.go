@@ -3,6 +3,7 @@ package config
 import (
 	"fmt"
 	"os"
+	"time"
 
 	"github.com/hashicorp/hcl/v2/hclsimple"
 	"github.com/kelseyhightower/envconfig"
@@ -14,7 +15,50 @@ type CLI struct {
 	Format    string `hcl:"format,optional"`
 	Host      string `hcl:"host,optional"`
 	NoColor   bool   `split_words:"true" hcl:"no_color,optional"`
-	Token     string `hcl:"token,optional"`
+	Token     string `hcl:"token,optional" mask:"true"`
+
+	// Output controls how commands print the data they retrieve. "table" prints it for humans while "json" and "yaml"
+	// print it as structured data for scripts.
+	Output string `hcl:"output,optional"`
+
+	// Pager is the command long listings are piped through when printed to a terminal. Defaults to $PAGER and then
+	// "less -FRX".
+	Pager   string `hcl:"pager,optional"`
+	NoPager bool   `split_words:"true" hcl:"no_pager,optional"`
+
+	// RequestTimeout is the maximum amount of time a single request(including retries) may take. Streaming requests
+	// like following logs are not limited. Zero means requests never time out.
+	RequestTimeout time.Duration `split_words:"true"`
+
+	// RequestTimeoutHCL is the HCL compatible counter part to RequestTimeout. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	RequestTimeoutHCL string `ignored:"true" hcl:"request_timeout,optional"`
+
+	// ConnectTimeout is the maximum amount of time to wait for a connection to the server. Zero means the connection
+	// is established in the background and errors surface on the first request instead.
+	ConnectTimeout time.Duration `split_words:"true"`
+
+	// ConnectTimeoutHCL is the HCL compatible counter part to ConnectTimeout. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	ConnectTimeoutHCL string `ignored:"true" hcl:"connect_timeout,optional"`
+
+	// Retries is the amount of times a request is retried when the server is unavailable.
+	Retries uint `hcl:"retries,optional"`
+
+	// CurrentContext is the name of the context whose settings are used by default.
+	CurrentContext string `split_words:"true" hcl:"current_context,optional"`
+
+	// Contexts are named groups of connection settings which allow the user to easily switch between different
+	// Gofer servers (ex. dev and prod).
+	Contexts []Context `ignored:"true" hcl:"context,block"`
+}
+
+// Context is a named collection of settings that overlay the base CLI configuration when in use.
+type Context struct {
+	Name      string `hcl:"name,label"`
+	Host      string `hcl:"host,optional"`
+	Token     string `hcl:"token,optional" mask:"true"`
+	Namespace string `hcl:"namespace,optional"`
 }
 
 // DefaultCLIConfig returns a pre-populated configuration struct that is used as the base for super imposing user configuration
@@ -23,6 +67,7 @@ func DefaultCLIConfig() *CLI {
 	return &CLI{
 		Host:   "localhost:8080",
 		Format: "pretty",
+		Output: "table",
 	}
 }
 
@@ -62,23 +107,56 @@ func (c *CLI) FromFile(path string) error {
 // convertDurationFromHCL attempts to move the string value of a duration written in HCL to
 // the real time.Duration type. This is needed due to advanced types like time.Duration being not handled particularly
 // well during HCL parsing: https://github.com/hashicorp/hcl/issues/202
-func (c *CLI) convertDurationFromHCL() {}
+func (c *CLI) convertDurationFromHCL() {
+	if c.RequestTimeoutHCL != "" {
+		c.RequestTimeout = mustParseDuration(c.RequestTimeoutHCL)
+	}
 
-// Get the final configuration for the CLI.
-// This involves correctly finding and ordering different possible paths for the configuration file.
-//
-// 1) The function is intended to be called with paths gleaned from the -config flag
-// 2) Then combine that with possible other config locations that the user might store a config file.
-// 3) Then try to see if the user has set an envvar for the config file, which overrides
-// all previous config file paths.
-// 4) Finally, pass back whatever is deemed the final config path from that process.
-//
-// We then use that path data to find the config file and read it in via HCL parsers. Once that is finished
-// we then take any configuration from the environment and superimpose that on top of the final config struct.
-func InitCLIConfig(flagPath string) (*CLI, error) {
-	// First we initiate the default values for the config.
-	config := DefaultCLIConfig()
+	if c.ConnectTimeoutHCL != "" {
+		c.ConnectTimeout = mustParseDuration(c.ConnectTimeoutHCL)
+	}
+}
+
+// GetContext returns the context with the given name.
+func (c *CLI) GetContext(name string) (*Context, error) {
+	for _, context := range c.Contexts {
+		if context.Name == name {
+			context := context
+			return &context, nil
+		}
+	}
+
+	return nil, fmt.Errorf("context %q not found in configuration", name)
+}
+
+// ApplyContext overwrites the connection settings within the configuration with the ones from the named context.
+// Settings that are not set within the context are left as is.
+func (c *CLI) ApplyContext(name string) error {
+	context, err := c.GetContext(name)
+	if err != nil {
+		return err
+	}
 
+	if context.Host != "" {
+		c.Host = context.Host
+	}
+
+	if context.Token != "" {
+		c.Token = context.Token
+	}
+
+	if context.Namespace != "" {
+		c.Namespace = context.Namespace
+	}
+
+	c.CurrentContext = name
+
+	return nil
+}
+
+// CLIConfigPath returns the path of the configuration file the CLI should use, searching through the possible
+// configuration locations in order. Returns an empty string if no configuration file could be found.
+func CLIConfigPath(flagPath string) string {
 	homeDir, _ := os.UserHomeDir()
 	possibleConfigPaths := []string{
 		flagPath,
@@ -95,6 +173,29 @@ func InitCLIConfig(flagPath string) (*CLI, error) {
 		path = envPath
 	}
 
+	return path
+}
+
+// Get the final configuration for the CLI.
+// This involves correctly finding and ordering different possible paths for the configuration file.
+//
+// 1) The function is intended to be called with paths gleaned from the -config flag
+// 2) Then combine that with possible other config locations that the user might store a config file.
+// 3) Then try to see if the user has set an envvar for the config file, which overrides
+// all previous config file paths.
+// 4) Finally, pass back whatever is deemed the final config path from that process.
+//
+// We then use that path data to find the config file and read it in via HCL parsers. Once that is finished
+// we then take any configuration from the environment and superimpose that on top of the final config struct.
+//
+// If a context is in use(either passed in via the --context flag, the GOFER_CLI_CURRENT_CONTEXT envvar or the
+// current_context setting) its settings are superimposed on top of the configuration file before the environment.
+func InitCLIConfig(flagPath, flagContext string) (*CLI, error) {
+	// First we initiate the default values for the config.
+	config := DefaultCLIConfig()
+
+	path := CLIConfigPath(flagPath)
+
 	if path != "" {
 		err := config.FromFile(path)
 		if err != nil {
@@ -102,6 +203,21 @@ func InitCLIConfig(flagPath string) (*CLI, error) {
 		}
 	}
 
+	contextName := config.CurrentContext
+	if envContext := os.Getenv("GOFER_CLI_CURRENT_CONTEXT"); envContext != "" {
+		contextName = envContext
+	}
+	if flagContext != "" {
+		contextName = flagContext
+	}
+
+	if contextName != "" {
+		err := config.ApplyContext(contextName)
+		if err != nil {
+			return nil, err
+		}
+	}
+
 	err := config.FromEnv()
 	if err != nil {
 		return nil, err
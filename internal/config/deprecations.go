@@ -0,0 +1,78 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/hashicorp/hcl/v2/hclparse"
+	"github.com/hashicorp/hcl/v2/hclsyntax"
+)
+
+// Deprecation describes a setting that is still accepted but will be removed in a future version of Gofer.
+type Deprecation struct {
+	Setting string // The setting as written in the configuration file(ex. "triggers.stop_timeout").
+	EnvVar  string // The environment variable that sets the same setting.
+	Message string // Why the setting is deprecated and what to do instead.
+}
+
+// deprecations lists every deprecated setting. Settings stay listed for at least one release before being removed
+// so operators get a chance to move off of them.
+var deprecations = []Deprecation{
+	{
+		Setting: "triggers.stop_timeout",
+		EnvVar:  "GOFER_TRIGGERS_STOP_TIMEOUT",
+		Message: "has no effect and will be removed; remove it from your configuration",
+	},
+	{
+		Setting: "triggers.healthcheck_interval",
+		EnvVar:  "GOFER_TRIGGERS_HEALTHCHECK_INTERVAL",
+		Message: "has no effect and will be removed; remove it from your configuration",
+	},
+}
+
+func (d Deprecation) String() string {
+	return fmt.Sprintf("%s(%s) %s", d.Setting, d.EnvVar, d.Message)
+}
+
+// FindAPIDeprecations returns the deprecated settings that are set by the server configuration file or environment.
+// The configuration file is found the same way InitAPIConfig finds it.
+func FindAPIDeprecations(userDefinedPath string) ([]Deprecation, error) {
+	var body *hclsyntax.Body
+
+	path := apiConfigPath(userDefinedPath)
+	if path != "" {
+		file, diags := hclparse.NewParser().ParseHCLFile(path)
+		if diags.HasErrors() {
+			return nil, fmt.Errorf("could not parse file: %w", diags)
+		}
+
+		body, _ = file.Body.(*hclsyntax.Body)
+	}
+
+	found := []Deprecation{}
+	for _, deprecation := range deprecations {
+		_, inEnv := os.LookupEnv(deprecation.EnvVar)
+		if inEnv || (body != nil && hasSetting(body, strings.Split(deprecation.Setting, "."))) {
+			found = append(found, deprecation)
+		}
+	}
+
+	return found, nil
+}
+
+// hasSetting reports whether the setting at the given path(block types followed by an attribute name) is set.
+func hasSetting(body *hclsyntax.Body, path []string) bool {
+	if len(path) == 1 {
+		_, exists := body.Attributes[path[0]]
+		return exists
+	}
+
+	for _, block := range body.Blocks {
+		if block.Type == path[0] && hasSetting(block.Body, path[1:]) {
+			return true
+		}
+	}
+
+	return false
+}
@@ -0,0 +1,111 @@
+package config
+
+import (
+	"fmt"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// Digests periodically send a summary of the runs within a namespace instead of a message per run. Each digest covers
+// the runs started during the day or week leading up to when it's sent.
+//
+//	digests {
+//	  digest "team_a_morning" {
+//	    namespace = "team_a"
+//	    period    = "daily"
+//	    hour      = 9
+//	    webhooks  = ["https://chat.mydomain.com/hooks/team-a"]
+//	  }
+//	}
+type Digests struct {
+	Digests []Digest `ignored:"true" hcl:"digest,block"`
+}
+
+func DefaultDigestsConfig() *Digests {
+	return &Digests{
+		Digests: []Digest{},
+	}
+}
+
+// Digest is a single summary sent on a schedule.
+type Digest struct {
+	// Name uniquely identifies the digest.
+	Name string `hcl:"name,label"`
+
+	// Namespace is the ID of the namespace whose runs are summarized.
+	Namespace string `hcl:"namespace"`
+
+	// Period is either "daily" or "weekly".
+	Period string `hcl:"period"`
+
+	// Hour is the hour of the day(0-23, UTC) the digest is sent at.
+	Hour int `hcl:"hour"`
+
+	// Weekday is the day weekly digests are sent on. Defaults to "monday".
+	Weekday string `hcl:"weekday,optional"`
+
+	// Webhooks are the URLs the digest is posted to.
+	Webhooks []string `hcl:"webhooks"`
+}
+
+// Weekdays maps the accepted values of a digest's weekday to their time.Weekday.
+var Weekdays = map[string]time.Weekday{
+	"sunday":    time.Sunday,
+	"monday":    time.Monday,
+	"tuesday":   time.Tuesday,
+	"wednesday": time.Wednesday,
+	"thursday":  time.Thursday,
+	"friday":    time.Friday,
+	"saturday":  time.Saturday,
+}
+
+func (c *Digests) validate() error {
+	names := map[string]struct{}{}
+	for _, digest := range c.Digests {
+		if _, exists := names[digest.Name]; exists {
+			return fmt.Errorf("digests.digest %q: names must be unique", digest.Name)
+		}
+		names[digest.Name] = struct{}{}
+
+		err := digest.validate()
+		if err != nil {
+			return fmt.Errorf("digests.digest %q: %w", digest.Name, err)
+		}
+	}
+
+	return nil
+}
+
+func (c *Digest) validate() error {
+	if c.Namespace == "" {
+		return fmt.Errorf("namespace must be set")
+	}
+
+	switch strings.ToLower(c.Period) {
+	case "daily":
+	case "weekly":
+		if _, exists := Weekdays[strings.ToLower(c.Weekday)]; c.Weekday != "" && !exists {
+			return fmt.Errorf("weekday %q is not valid; must be a day of the week(ex. monday)", c.Weekday)
+		}
+	default:
+		return fmt.Errorf("period %q is not supported; must be one of daily or weekly", c.Period)
+	}
+
+	if c.Hour < 0 || c.Hour > 23 {
+		return fmt.Errorf("hour must be between 0 and 23")
+	}
+
+	if len(c.Webhooks) == 0 {
+		return fmt.Errorf("at least one webhook must be set")
+	}
+
+	for _, webhook := range c.Webhooks {
+		parsedURL, err := url.Parse(webhook)
+		if err != nil || parsedURL.Host == "" {
+			return fmt.Errorf("webhook %q is not valid", webhook)
+		}
+	}
+
+	return nil
+}
@@ -0,0 +1,64 @@
+package config
+
+import (
+	"fmt"
+	"net/url"
+	"time"
+)
+
+// LogExport ships the server's logs to an OpenTelemetry collector(or any other OTLP endpoint) in addition to writing
+// them to the terminal. Logs are sent using OTLP over HTTP with JSON encoding.
+//
+//	log_export {
+//	  enable   = true
+//	  endpoint = "https://otel-collector.mydomain.com:4318/v1/logs"
+//	}
+type LogExport struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// Endpoint is the full URL logs are posted to. Collectors usually receive logs on port 4318 at /v1/logs.
+	Endpoint string `hcl:"endpoint,optional"`
+
+	// Headers are added to every request. Ex: Authorization
+	Headers map[string]string `hcl:"headers,optional" mask:"values"`
+
+	// ServiceName is reported as the "service.name" resource attribute of every log.
+	ServiceName string `split_words:"true" hcl:"service_name,optional"`
+
+	// BatchSize is the maximum amount of logs sent in a single request.
+	BatchSize int `split_words:"true" hcl:"batch_size,optional"`
+
+	// FlushInterval is how often logs are sent if there aren't enough to fill a batch.
+	FlushInterval time.Duration `split_words:"true"`
+
+	// FlushIntervalHCL is the HCL compatible counter part to FlushInterval. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	FlushIntervalHCL string `ignored:"true" hcl:"flush_interval,optional"`
+}
+
+func DefaultLogExportConfig() *LogExport {
+	return &LogExport{
+		Enable:        false,
+		Headers:       map[string]string{},
+		ServiceName:   "gofer",
+		BatchSize:     100,
+		FlushInterval: mustParseDuration("5s"),
+	}
+}
+
+func (c *LogExport) validate() error {
+	endpoint, err := url.Parse(c.Endpoint)
+	if err != nil || endpoint.Host == "" {
+		return fmt.Errorf("log_export.endpoint must be a valid url; got %q", c.Endpoint)
+	}
+
+	if c.BatchSize < 1 {
+		return fmt.Errorf("log_export.batch_size must be greater than 0")
+	}
+
+	if c.FlushInterval <= 0 {
+		return fmt.Errorf("log_export.flush_interval must be greater than 0")
+	}
+
+	return nil
+}
@@ -0,0 +1,38 @@
+package config
+
+import (
+	"github.com/hashicorp/hcl/v2/hclsimple"
+)
+
+// ExtensionManifest is a declarative list of triggers and notifiers to install on a Gofer server. It allows new Gofer
+// instances to be bootstrapped with the same set of extensions and settings every time.
+//
+// Triggers and notifiers use the same settings as the "registered_triggers" and "registered_notifiers" blocks in the
+// server configuration:
+//
+//	trigger "cron" {
+//	  image = "ghcr.io/clintjedwards/gofer-containers/triggers/cron:latest"
+//	}
+//
+//	notifier "slack" {
+//	  image    = "ghcr.io/clintjedwards/gofer-containers/notifiers/slack:latest"
+//	  env_vars = {
+//	    "SLACK_TOKEN": "example"
+//	  }
+//	}
+type ExtensionManifest struct {
+	Triggers  []Trigger  `hcl:"trigger,block"`
+	Notifiers []Notifier `hcl:"notifier,block"`
+}
+
+// LoadExtensionManifest parses the HCL manifest file at the given path.
+func LoadExtensionManifest(path string) (*ExtensionManifest, error) {
+	manifest := &ExtensionManifest{}
+
+	err := hclsimple.DecodeFile(path, nil, manifest)
+	if err != nil {
+		return nil, err
+	}
+
+	return manifest, nil
+}
@@ -1,5 +1,7 @@
 package config
 
+import "time"
+
 // ObjectStore defines config settings for gofer ObjectStore. The ObjectStore stores temporary objects for pipelines and
 // runs.
 type ObjectStore struct {
@@ -18,6 +20,22 @@ type ObjectStore struct {
 	// Ex. an object stored on run number #5 with an expiry of 2 will be deleted on run #7 regardless of run
 	// health.
 	RunObjectExpiry int `split_words:"true" hcl:"run_object_expiry,optional"`
+
+	// Artifacts collected from task runs last for a certain number of runs in the same way as run objects. Tasks can
+	// override this for their own artifacts.
+	ArtifactExpiry int `split_words:"true" hcl:"artifact_expiry,optional"`
+
+	// CacheBudgetMB is the most space the caches of a single pipeline are allowed to take up in megabytes. Once it's
+	// reached the least recently used caches are removed to make room. 0 means there is no budget.
+	CacheBudgetMB int64 `split_words:"true" hcl:"cache_budget_mb,optional"`
+
+	// ExpiryCheckInterval is how often pipeline objects are checked for whether they have expired. Defaults to a
+	// minute when unset.
+	ExpiryCheckInterval time.Duration `split_words:"true"`
+
+	// ExpiryCheckIntervalHCL is the HCL compatible counter part to ExpiryCheckInterval. It allows the parsing of a
+	// string to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	ExpiryCheckIntervalHCL string `ignored:"true" hcl:"expiry_check_interval,optional"`
 }
 
 func DefaultObjectStoreConfig() *ObjectStore {
@@ -28,5 +46,7 @@ func DefaultObjectStoreConfig() *ObjectStore {
 		},
 		PipelineObjectLimit: 10,
 		RunObjectExpiry:     20,
+		ArtifactExpiry:      20,
+		CacheBudgetMB:       5000,
 	}
 }
@@ -0,0 +1,428 @@
+package config
+
+import (
+	"fmt"
+	"strings"
+	"time"
+)
+
+// Permissioning controls how users authenticate with Gofer and what they're allowed to do once authenticated.
+// Static API tokens are always accepted; the settings below enable additional ways to authenticate.
+type Permissioning struct {
+	// AllowAnonymous lets namespaces opt into granting callers without credentials read-only access to their
+	// pipelines, runs and logs. Namespaces never allow anonymous access unless this is enabled.
+	AllowAnonymous bool `split_words:"true" hcl:"allow_anonymous,optional"`
+
+	OIDC        *OIDC        `hcl:"oidc,block"`
+	ExternalJWT *ExternalJWT `split_words:"true" hcl:"external_jwt,block"`
+	LDAP        *LDAP        `hcl:"ldap,block"`
+	MTLS        *MTLS        `hcl:"mtls,block"`
+
+	AuditLog *AuditLog `split_words:"true" hcl:"audit_log,block"`
+
+	NamespaceIsolation *NamespaceIsolation `split_words:"true" hcl:"namespace_isolation,block"`
+}
+
+func DefaultPermissioningConfig() *Permissioning {
+	return &Permissioning{
+		AllowAnonymous: false,
+
+		OIDC:        DefaultOIDCConfig(),
+		ExternalJWT: DefaultExternalJWTConfig(),
+		LDAP:        DefaultLDAPConfig(),
+		MTLS:        DefaultMTLSConfig(),
+
+		AuditLog: DefaultAuditLogConfig(),
+
+		NamespaceIsolation: DefaultNamespaceIsolationConfig(),
+	}
+}
+
+// OIDC allows users to log in through an OpenID Connect identity provider(via "gofer login") instead of being handed
+// a static token. Logging in exchanges the ID token issued by the provider for a short-lived Gofer token; ID tokens are
+// also accepted as API tokens directly. The permissions of the user are determined by the groups they belong to
+// within the provider and the claims of their ID token.
+type OIDC struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// Issuer is the URL of the identity provider. The provider's endpoints are discovered from
+	// <issuer>/.well-known/openid-configuration. Ex: https://accounts.google.com
+	Issuer string `hcl:"issuer,optional"`
+
+	// ClientID is the ID of the client registered with the identity provider for Gofer. The client must allow the
+	// device authorization grant and ID tokens must be issued with the client ID as their audience.
+	ClientID string `split_words:"true" hcl:"client_id,optional"`
+
+	// Scopes requested by the CLI during login. The scopes must cause the provider to include the groups claim in the
+	// ID token.
+	Scopes []string `hcl:"scopes,optional"`
+
+	// GroupsClaim is the name of the ID token claim that lists the groups the user belongs to.
+	GroupsClaim string `split_words:"true" hcl:"groups_claim,optional"`
+
+	// GroupMappings map identity provider groups to Gofer permissions. A user gets the combined permissions of all the
+	// groups they belong to; users that belong to no mapped groups are denied access.
+	GroupMappings []GroupMapping `ignored:"true" hcl:"group_mapping,block"`
+
+	// ClaimMappings map the values of other ID token claims to Gofer permissions. Users get the combined permissions
+	// of every group and claim mapping they match.
+	ClaimMappings []ClaimMapping `ignored:"true" hcl:"claim_mapping,block"`
+
+	// TokenTTL is how long the tokens users get in exchange for their ID token last before they have to log in again.
+	TokenTTL time.Duration `split_words:"true"`
+
+	// TokenTTLHCL is the HCL compatible counter part to TokenTTL. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	TokenTTLHCL string `ignored:"true" hcl:"token_ttl,optional"`
+}
+
+func DefaultOIDCConfig() *OIDC {
+	return &OIDC{
+		Enable:        false,
+		Scopes:        []string{"openid", "profile", "email", "groups"},
+		GroupsClaim:   "groups",
+		GroupMappings: []GroupMapping{},
+		ClaimMappings: []ClaimMapping{},
+		TokenTTL:      mustParseDuration("12h"),
+	}
+}
+
+// GroupMapping grants the members of a group the same permissions as a token with the given kind and namespaces.
+//
+//	group_mapping "gofer-admins" {
+//	  kind = "management"
+//	}
+//
+//	group_mapping "team-a" {
+//	  kind       = "client"
+//	  namespaces = ["team-a"]
+//	}
+type GroupMapping struct {
+	Group      string   `hcl:"group,label"`
+	Kind       string   `hcl:"kind,optional"` // One of "management", "client" or "namespace_admin"; defaults to "client".
+	Namespaces []string `hcl:"namespaces,optional"`
+}
+
+// ClaimMapping grants users whose ID token has a claim with the given value the same permissions as a token with the
+// given kind and namespaces. Claims that hold a list match if any of their entries is the value; claims that hold a
+// bool or number are compared in their JSON form(ex. "true").
+//
+//	claim_mapping "department" {
+//	  value      = "platform"
+//	  kind       = "namespace_admin"
+//	  namespaces = ["platform"]
+//	}
+type ClaimMapping struct {
+	Claim      string   `hcl:"claim,label"`
+	Value      string   `hcl:"value"`
+	Kind       string   `hcl:"kind,optional"` // One of "management", "client" or "namespace_admin"; defaults to "client".
+	Namespaces []string `hcl:"namespaces,optional"`
+}
+
+func (c *OIDC) validate() error {
+	if c.Issuer == "" {
+		return fmt.Errorf("permissioning.oidc.issuer must be set when oidc is enabled")
+	}
+
+	if c.ClientID == "" {
+		return fmt.Errorf("permissioning.oidc.client_id must be set when oidc is enabled")
+	}
+
+	if c.TokenTTL <= 0 {
+		return fmt.Errorf("permissioning.oidc.token_ttl must be greater than 0")
+	}
+
+	for _, mapping := range c.ClaimMappings {
+		if mapping.Kind != "" && !validMappingKind(mapping.Kind) {
+			return fmt.Errorf("permissioning.oidc.claim_mapping %q: kind must be one of 'management', 'client' or "+
+				"'namespace_admin'; got %q", mapping.Claim, mapping.Kind)
+		}
+	}
+
+	return validateGroupMappings("permissioning.oidc", c.GroupMappings)
+}
+
+func validateGroupMappings(block string, mappings []GroupMapping) error {
+	for _, mapping := range mappings {
+		if mapping.Kind != "" && !validMappingKind(mapping.Kind) {
+			return fmt.Errorf("%s.group_mapping %q: kind must be one of 'management', 'client' or 'namespace_admin'; got %q",
+				block, mapping.Group, mapping.Kind)
+		}
+	}
+
+	return nil
+}
+
+func validMappingKind(kind string) bool {
+	return strings.EqualFold(kind, "management") || strings.EqualFold(kind, "client") ||
+		strings.EqualFold(kind, "namespace_admin")
+}
+
+// ExternalJWT allows platforms embedding Gofer to authenticate their users with JWTs issued by their own identity
+// provider instead of minting Gofer tokens. JWTs from the configured issuer are accepted as API tokens and the
+// permissions of the caller are determined by the groups(or roles) listed in the token's claims.
+type ExternalJWT struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// Issuer is the value the "iss" claim of accepted tokens must have. Ex: https://auth.myplatform.com
+	Issuer string `hcl:"issuer,optional"`
+
+	// Audience is the value the "aud" claim of accepted tokens must contain.
+	Audience string `hcl:"audience,optional"`
+
+	// JWKSURL is the URL of the JSON web key set holding the keys tokens are signed with.
+	// Ex: https://auth.myplatform.com/.well-known/jwks.json
+	JWKSURL string `split_words:"true" hcl:"jwks_url,optional"`
+
+	// GroupsClaim is the name of the claim that lists the groups or roles of the caller.
+	GroupsClaim string `split_words:"true" hcl:"groups_claim,optional"`
+
+	// GroupMappings map the groups listed in tokens to Gofer permissions. A caller gets the combined permissions of
+	// all the groups they belong to; callers that belong to no mapped groups are denied access.
+	GroupMappings []GroupMapping `ignored:"true" hcl:"group_mapping,block"`
+}
+
+func DefaultExternalJWTConfig() *ExternalJWT {
+	return &ExternalJWT{
+		Enable:        false,
+		GroupsClaim:   "groups",
+		GroupMappings: []GroupMapping{},
+	}
+}
+
+func (c *ExternalJWT) validate() error {
+	if c.Issuer == "" {
+		return fmt.Errorf("permissioning.external_jwt.issuer must be set when external_jwt is enabled")
+	}
+
+	if c.Audience == "" {
+		return fmt.Errorf("permissioning.external_jwt.audience must be set when external_jwt is enabled")
+	}
+
+	if c.JWKSURL == "" {
+		return fmt.Errorf("permissioning.external_jwt.jwks_url must be set when external_jwt is enabled")
+	}
+
+	return validateGroupMappings("permissioning.external_jwt", c.GroupMappings)
+}
+
+// LDAP allows tokens to be created for directory users(ex. Active Directory) instead of with a fixed kind and
+// namespaces. The permissions of such tokens are derived from the groups the user belongs to and are refreshed on a
+// schedule so that changes in group membership are picked up without recreating tokens.
+type LDAP struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// URL of the directory server. Both ldap:// and ldaps:// are supported. Ex: ldaps://ldap.mydomain.com
+	URL string `hcl:"url,optional"`
+
+	// BindDN and BindPassword are the credentials of the account used to look up users.
+	BindDN       string `split_words:"true" hcl:"bind_dn,optional"`
+	BindPassword string `split_words:"true" hcl:"bind_password,optional" mask:"true"`
+
+	// UserBaseDN is the DN under which users are searched for. Ex: ou=people,dc=mydomain,dc=com
+	UserBaseDN string `split_words:"true" hcl:"user_base_dn,optional"`
+
+	// UserFilter is the filter used to find a user; "{username}" is replaced with the escaped username.
+	// Defaults to "(uid={username})".
+	UserFilter string `split_words:"true" hcl:"user_filter,optional"`
+
+	// GroupAttribute is the attribute of the user's entry that lists the groups they belong to. Groups are matched
+	// against group mappings by their full DN or by the value of the DN's first component(ex. the CN).
+	// Defaults to "memberOf".
+	GroupAttribute string `split_words:"true" hcl:"group_attribute,optional"`
+
+	// RefreshInterval is how often the permissions of tokens created for directory users are refreshed.
+	// Defaults to an hour.
+	RefreshInterval time.Duration `split_words:"true"`
+
+	// RefreshIntervalHCL is the HCL compatible counter part to RefreshInterval. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	RefreshIntervalHCL string `ignored:"true" hcl:"refresh_interval,optional"`
+
+	// GroupMappings map directory groups to Gofer permissions. A user gets the combined permissions of all the groups
+	// they belong to; tokens of users that belong to no mapped groups lose all access.
+	GroupMappings []GroupMapping `ignored:"true" hcl:"group_mapping,block"`
+}
+
+func DefaultLDAPConfig() *LDAP {
+	return &LDAP{
+		Enable:          false,
+		UserFilter:      "(uid={username})",
+		GroupAttribute:  "memberOf",
+		RefreshInterval: mustParseDuration("1h"),
+		GroupMappings:   []GroupMapping{},
+	}
+}
+
+func (c *LDAP) validate() error {
+	if c.URL == "" {
+		return fmt.Errorf("permissioning.ldap.url must be set when ldap is enabled")
+	}
+
+	if c.UserBaseDN == "" {
+		return fmt.Errorf("permissioning.ldap.user_base_dn must be set when ldap is enabled")
+	}
+
+	if c.UserFilter != "" && !strings.Contains(c.UserFilter, "{username}") {
+		return fmt.Errorf("permissioning.ldap.user_filter must contain '{username}'")
+	}
+
+	if c.RefreshInterval < 0 {
+		return fmt.Errorf("permissioning.ldap.refresh_interval cannot be negative")
+	}
+
+	return validateGroupMappings("permissioning.ldap", c.GroupMappings)
+}
+
+// MTLS allows callers to authenticate with a client certificate instead of a token. Certificates must be signed by the
+// configured CA and are mapped to Gofer permissions by their subject alternative names. Requests that include a token
+// are authenticated by the token instead.
+type MTLS struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// CAPath is the file path of the PEM encoded CA certificate(s) client certificates are verified against.
+	CAPath string `split_words:"true" hcl:"ca_path,optional"`
+
+	// CertificateMappings map client certificate subject alternative names to Gofer permissions. Certificates that
+	// match no mappings are denied access.
+	CertificateMappings []CertificateMapping `ignored:"true" hcl:"certificate_mapping,block"`
+}
+
+func DefaultMTLSConfig() *MTLS {
+	return &MTLS{
+		Enable:              false,
+		CertificateMappings: []CertificateMapping{},
+	}
+}
+
+// CertificateMapping grants the holder of a client certificate with the given subject alternative name(DNS name,
+// URI or email address) either the permissions of a service account or the same permissions as a token with the
+// given kind and namespaces.
+//
+//	certificate_mapping "spiffe://mydomain.com/ci-runner" {
+//	  service_account = "ci-runner"
+//	}
+//
+//	certificate_mapping "deployer.mydomain.com" {
+//	  kind       = "client"
+//	  namespaces = ["team-a"]
+//	}
+type CertificateMapping struct {
+	SAN            string   `hcl:"san,label"`
+	ServiceAccount string   `hcl:"service_account,optional"`
+	Kind           string   `hcl:"kind,optional"` // One of "management", "client" or "namespace_admin"; defaults to "client".
+	Namespaces     []string `hcl:"namespaces,optional"`
+}
+
+func (c *MTLS) validate() error {
+	if c.CAPath == "" {
+		return fmt.Errorf("permissioning.mtls.ca_path must be set when mtls is enabled")
+	}
+
+	for _, mapping := range c.CertificateMappings {
+		if mapping.ServiceAccount != "" && (mapping.Kind != "" || len(mapping.Namespaces) != 0) {
+			return fmt.Errorf("permissioning.mtls.certificate_mapping %q: service_account cannot be combined with kind or namespaces",
+				mapping.SAN)
+		}
+
+		if mapping.Kind != "" && !validMappingKind(mapping.Kind) {
+			return fmt.Errorf("permissioning.mtls.certificate_mapping %q: kind must be one of 'management', 'client' or 'namespace_admin'; got %q",
+				mapping.SAN, mapping.Kind)
+		}
+	}
+
+	return nil
+}
+
+// AuditLog records every authorization decision(who called which route, on what, and whether they were allowed) to
+// the database so that access can be reviewed later. The audit log is kept separately from the event log and has its
+// own retention.
+type AuditLog struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// Retention controls how long authorization decisions are kept before being discarded.
+	Retention time.Duration
+
+	// RetentionHCL is the HCL compatible counter part to Retention. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	RetentionHCL string `ignored:"true" hcl:"retention,optional"`
+
+	// How often the background process for pruning decisions past their retention should run.
+	PruneInterval time.Duration `split_words:"true"`
+
+	// PruneIntervalHCL is the HCL compatible counter part to PruneInterval. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	PruneIntervalHCL string `ignored:"true" hcl:"prune_interval,optional"`
+}
+
+func DefaultAuditLogConfig() *AuditLog {
+	return &AuditLog{
+		Enable:        false,
+		Retention:     mustParseDuration("2160h"), // 2160 hours is roughly 3 months.
+		PruneInterval: mustParseDuration("1h"),
+	}
+}
+
+func (c *AuditLog) validate() error {
+	if c.Retention <= 0 {
+		return fmt.Errorf("permissioning.audit_log.retention must be a positive duration")
+	}
+
+	if c.PruneInterval <= 0 {
+		return fmt.Errorf("permissioning.audit_log.prune_interval must be a positive duration")
+	}
+
+	return nil
+}
+
+// NamespaceIsolation removes all visibility across namespaces so that teams that don't trust each other are able to
+// share a single Gofer instance. Once enabled, callers are only able to see the namespaces they have access to(and the
+// pipelines, runs, logs and events within them); only management users are able to see everything.
+type NamespaceIsolation struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// SchedulerLabels are attached to the containers of every task run within a namespace in addition to the labels
+	// Gofer attaches itself. Schedulers are able to use them to keep the containers of different namespaces apart(ex.
+	// by placing them on separate nodes).
+	SchedulerLabels []NamespaceSchedulerLabels `ignored:"true" hcl:"scheduler_labels,block"`
+}
+
+func DefaultNamespaceIsolationConfig() *NamespaceIsolation {
+	return &NamespaceIsolation{
+		Enable:          false,
+		SchedulerLabels: []NamespaceSchedulerLabels{},
+	}
+}
+
+// NamespaceSchedulerLabels are the labels attached to the task run containers of a single namespace.
+//
+//	scheduler_labels "team-a" {
+//	  labels = {
+//	    "node_pool" = "team-a"
+//	  }
+//	}
+type NamespaceSchedulerLabels struct {
+	Namespace string            `hcl:"namespace,label"`
+	Labels    map[string]string `hcl:"labels"`
+}
+
+func (c *NamespaceIsolation) validate() error {
+	seen := map[string]struct{}{}
+
+	for _, schedulerLabels := range c.SchedulerLabels {
+		if _, exists := seen[schedulerLabels.Namespace]; exists {
+			return fmt.Errorf("permissioning.namespace_isolation.scheduler_labels %q: defined more than once",
+				schedulerLabels.Namespace)
+		}
+		seen[schedulerLabels.Namespace] = struct{}{}
+
+		for key := range schedulerLabels.Labels {
+			if strings.HasPrefix(key, "gofer.") {
+				return fmt.Errorf("permissioning.namespace_isolation.scheduler_labels %q: label %q cannot start with 'gofer.'; "+
+					"those labels are reserved for Gofer", schedulerLabels.Namespace, key)
+			}
+		}
+	}
+
+	return nil
+}
@@ -1,19 +1,219 @@
 package config
 
-import "time"
+import (
+	"fmt"
+	"strings"
+	"time"
+)
 
 // Scheduler defines config settings for gofer scheduler. The scheduler is the backend for how containers are run.
 type Scheduler struct {
 	// The database engine used by the scheduler
-	// possible values are: docker
+	// possible values are: docker, podman, local
 	Engine string  `hcl:"engine,optional"`
 	Docker *Docker `hcl:"docker,block"`
+	Podman *Podman `hcl:"podman,block"`
+	Local  *Local  `hcl:"local,block"`
+
+	// ImageRewrites point image references at a different registry before the scheduler pulls them. This lets
+	// pipelines keep referring to public images while installs without internet access pull from a mirror.
+	ImageRewrites []ImageRewrite `ignored:"true" hcl:"image_rewrite,block"`
+
+	// TaskResources are the CPU and memory task runs get when their task doesn't ask for any and the most they're
+	// allowed to ask for.
+	TaskResources *TaskResources `split_words:"true" hcl:"task_resources,block"`
+
+	// TaskPrivileges allow the tasks of a namespace to run privileged or with extra Linux capabilities. Tasks can't
+	// ask for either unless they're allowed here.
+	TaskPrivileges []TaskPrivileges `ignored:"true" hcl:"task_privileges,block"`
+
+	// RunNetworks gives each run a network of its own that its task runs share. Task runs can reach each other by
+	// their task ID and can't be reached by the task runs of other runs. Every network takes up an address range on
+	// the scheduler's host, which limits how many runs can be in progress at once.
+	RunNetworks bool `split_words:"true" hcl:"run_networks,optional"`
+
+	// ImageMaxAge is how long an image already on the scheduler's host is used before it's pulled again. This keeps
+	// mutable tags(ex. "latest") reasonably fresh without pulling on every task run. Zero never pulls an image that's
+	// already there.
+	ImageMaxAge time.Duration `split_words:"true"`
+
+	// ImageMaxAgeHCL is the HCL compatible counter part to ImageMaxAge. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	ImageMaxAgeHCL string `ignored:"true" hcl:"image_max_age,optional"`
+}
+
+// TaskPrivileges allows the tasks of a namespace to ask for more than what containers are given by default.
+//
+//	task_privileges "builds" {
+//	  pipelines    = ["image_builder"]
+//	  capabilities = ["SYS_ADMIN"]
+//	}
+type TaskPrivileges struct {
+	// Namespace is the namespace whose tasks are allowed the privileges.
+	Namespace string `hcl:"namespace,label"`
+
+	// Pipelines limits the privileges to these pipelines of the namespace. Every pipeline of the namespace when empty.
+	Pipelines []string `hcl:"pipelines,optional"`
+
+	// Privileged allows tasks to run privileged; which gives them every capability and access to the host's devices.
+	Privileged bool `hcl:"privileged,optional"`
+
+	// Capabilities are the Linux capabilities tasks are allowed to add(ex. "SYS_ADMIN").
+	Capabilities []string `hcl:"capabilities,optional"`
+}
+
+func (p *TaskPrivileges) validate() error {
+	if strings.TrimSpace(p.Namespace) == "" {
+		return fmt.Errorf("scheduler.task_privileges: namespace cannot be empty")
+	}
+
+	if !p.Privileged && len(p.Capabilities) == 0 {
+		return fmt.Errorf("scheduler.task_privileges %q: must allow privileged or at least one capability", p.Namespace)
+	}
+
+	for _, pipeline := range p.Pipelines {
+		if strings.TrimSpace(pipeline) == "" {
+			return fmt.Errorf("scheduler.task_privileges %q: pipelines cannot be empty", p.Namespace)
+		}
+	}
+
+	for _, capability := range p.Capabilities {
+		if strings.TrimSpace(capability) == "" {
+			return fmt.Errorf("scheduler.task_privileges %q: capabilities cannot be empty", p.Namespace)
+		}
+	}
+
+	return nil
+}
+
+// TaskResources are the defaults and maxima for the CPU and memory of task run containers. Zero leaves a setting
+// unset; without a default or a maximum a task run's container can use as much as the host has.
+//
+//	task_resources {
+//	  default_cpu_limit       = 1
+//	  default_memory_limit_mb = 1024
+//	  max_cpu                 = 4
+//	  max_memory_mb           = 8192
+//	}
+type TaskResources struct {
+	DefaultCPURequest      float64 `split_words:"true" hcl:"default_cpu_request,optional"` // In CPU cores.
+	DefaultCPULimit        float64 `split_words:"true" hcl:"default_cpu_limit,optional"`   // In CPU cores.
+	DefaultMemoryRequestMB int64   `split_words:"true" hcl:"default_memory_request_mb,optional"`
+	DefaultMemoryLimitMB   int64   `split_words:"true" hcl:"default_memory_limit_mb,optional"`
+
+	// MaxCPU is the most CPU cores a task can ask for. Task runs of tasks that don't set a limit are limited to it.
+	MaxCPU float64 `split_words:"true" hcl:"max_cpu,optional"`
+
+	// MaxMemoryMB is the most memory a task can ask for. Task runs of tasks that don't set a limit are limited to it.
+	MaxMemoryMB int64 `split_words:"true" hcl:"max_memory_mb,optional"`
+}
+
+func (r *TaskResources) validate() error {
+	if r.DefaultCPURequest < 0 || r.DefaultCPULimit < 0 || r.MaxCPU < 0 {
+		return fmt.Errorf("scheduler.task_resources: cpu cannot be negative")
+	}
+
+	if r.DefaultMemoryRequestMB < 0 || r.DefaultMemoryLimitMB < 0 || r.MaxMemoryMB < 0 {
+		return fmt.Errorf("scheduler.task_resources: memory cannot be negative")
+	}
+
+	if r.DefaultCPULimit != 0 && r.DefaultCPURequest > r.DefaultCPULimit {
+		return fmt.Errorf("scheduler.task_resources: default_cpu_request cannot be more than default_cpu_limit")
+	}
+
+	if r.DefaultMemoryLimitMB != 0 && r.DefaultMemoryRequestMB > r.DefaultMemoryLimitMB {
+		return fmt.Errorf("scheduler.task_resources: default_memory_request_mb cannot be more than " +
+			"default_memory_limit_mb")
+	}
+
+	if r.MaxCPU != 0 && (r.DefaultCPURequest > r.MaxCPU || r.DefaultCPULimit > r.MaxCPU) {
+		return fmt.Errorf("scheduler.task_resources: cpu defaults cannot be more than max_cpu")
+	}
+
+	if r.MaxMemoryMB != 0 && (r.DefaultMemoryRequestMB > r.MaxMemoryMB || r.DefaultMemoryLimitMB > r.MaxMemoryMB) {
+		return fmt.Errorf("scheduler.task_resources: memory defaults cannot be more than max_memory_mb")
+	}
+
+	return nil
+}
+
+// ImageRewrite replaces the start of matching image references with the start of a mirror's.
+//
+//	image_rewrite "docker.io" {
+//	  to = "registry.mydomain.com/dockerhub"
+//	}
+//
+//	image_rewrite "ghcr.io" {
+//	  namespace = "team_a"
+//	  to        = "registry.mydomain.com/team-a/ghcr"
+//	}
+type ImageRewrite struct {
+	// From is the registry(and optionally repository path) to rewrite. Images without a registry are treated as
+	// being from "docker.io" and official images as being under "docker.io/library".
+	From string `hcl:"from,label"`
+
+	// To is what From is replaced with.
+	To string `hcl:"to"`
+
+	// Namespace limits the rewrite to the task runs of a single namespace. Rewrites for a namespace take priority over
+	// rewrites without one. Triggers don't belong to a namespace and only use rewrites without one.
+	Namespace string `hcl:"namespace,optional"`
+}
+
+func (s *Scheduler) validate() error {
+	rewrites := map[string]struct{}{}
+
+	for _, rewrite := range s.ImageRewrites {
+		from := strings.Trim(rewrite.From, "/")
+		if from == "" {
+			return fmt.Errorf("scheduler.image_rewrite: from cannot be empty")
+		}
+
+		if strings.Contains(rewrite.From, "://") || strings.Contains(rewrite.To, "://") {
+			return fmt.Errorf("scheduler.image_rewrite %q: registries must be given without a scheme", rewrite.From)
+		}
+
+		if strings.Trim(rewrite.To, "/") == "" {
+			return fmt.Errorf("scheduler.image_rewrite %q: to cannot be empty", rewrite.From)
+		}
+
+		key := rewrite.Namespace + "|" + from
+		if _, exists := rewrites[key]; exists {
+			return fmt.Errorf("scheduler.image_rewrite %q: duplicate rewrite for namespace %q", rewrite.From,
+				rewrite.Namespace)
+		}
+		rewrites[key] = struct{}{}
+	}
+
+	if s.ImageMaxAge < 0 {
+		return fmt.Errorf("scheduler.image_max_age cannot be negative")
+	}
+
+	if s.TaskResources != nil {
+		err := s.TaskResources.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	for _, privileges := range s.TaskPrivileges {
+		err := privileges.validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	return nil
 }
 
 func DefaultSchedulerConfig() *Scheduler {
 	return &Scheduler{
 		Engine: "docker",
 		Docker: DefaultDockerConfig(),
+		Podman: DefaultPodmanConfig(),
+		Local:  DefaultLocalConfig(),
+
+		TaskResources: &TaskResources{},
 	}
 }
 
@@ -35,3 +235,51 @@ func DefaultDockerConfig() *Docker {
 		PruneInterval: mustParseDuration("24h"),
 	}
 }
+
+type Podman struct {
+	// Socket is the address of podman's REST API service(ex. `podman system service`). Rootless installs usually serve
+	// it from unix://$XDG_RUNTIME_DIR/podman/podman.sock.
+	Socket string `hcl:"socket,optional"`
+
+	// Prune periodically removes containers that are no longer running to avoid filling the local disk.
+	Prune bool `hcl:"prune,optional"`
+
+	// The period of time in between runs of the prune job.
+	PruneInterval time.Duration `split_words:"true"`
+
+	// PruneIntervalHCL is the HCL compatible counter part to PruneInterval. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	PruneIntervalHCL string `ignored:"true" hcl:"prune_interval,optional"`
+}
+
+func DefaultPodmanConfig() *Podman {
+	return &Podman{
+		Socket:        "unix:///run/podman/podman.sock",
+		Prune:         false,
+		PruneInterval: mustParseDuration("24h"),
+	}
+}
+
+// Local runs containers as plain processes on the machine Gofer runs on.
+type Local struct {
+	// WorkDir holds the working directory and output of each process.
+	WorkDir string `split_words:"true" hcl:"work_dir,optional"`
+
+	// Prune periodically removes processes that have exited along with their directories.
+	Prune bool `hcl:"prune,optional"`
+
+	// The period of time in between runs of the prune job.
+	PruneInterval time.Duration `split_words:"true"`
+
+	// PruneIntervalHCL is the HCL compatible counter part to PruneInterval. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	PruneIntervalHCL string `ignored:"true" hcl:"prune_interval,optional"`
+}
+
+func DefaultLocalConfig() *Local {
+	return &Local{
+		WorkDir:       "/tmp/gofer-local",
+		Prune:         false,
+		PruneInterval: mustParseDuration("24h"),
+	}
+}
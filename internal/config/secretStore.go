@@ -4,7 +4,10 @@ package config
 type BoltDBSecret struct {
 	Path string `hcl:"path,optional"` // file path for database file
 	// EncryptionKey is a 32-bit random string of characters used to encrypt data at rest.
-	EncryptionKey string `split_words:"true" hcl:"encryption_key,optional"`
+	EncryptionKey string `split_words:"true" hcl:"encryption_key,optional" mask:"true"`
+	// PreviousEncryptionKeys are encryption keys that were replaced by EncryptionKey. They are only used to read
+	// secrets that haven't been re-encrypted with the current key yet.
+	PreviousEncryptionKeys []string `split_words:"true" hcl:"previous_encryption_keys,optional" mask:"true"`
 }
 
 // SecretStore defines the configuration for Gofer's secret backend.
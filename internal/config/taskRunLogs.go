@@ -0,0 +1,99 @@
+package config
+
+import (
+	"fmt"
+	"time"
+)
+
+// TaskRunLogs controls where task run logs are kept and how much space they are allowed to take up.
+//
+//	task_run_logs {
+//	  engine             = "file"
+//	  max_size_mb        = 100
+//	  compress           = true
+//	  compression_format = "gzip"
+//	  rotate_size_mb     = 10
+//	  budget_mb          = 20000
+//	  archive            = true
+//	}
+type TaskRunLogs struct {
+	// Engine is where task run logs are kept. The accepted values are "file", which keeps them in TaskRunLogsDir, and
+	// "object_store", which keeps them in the object store so they can be read from any Gofer instance sharing it.
+	Engine string `hcl:"engine,optional"`
+
+	// MaxSizeMB is the most a single task run is allowed to log in megabytes. Once reached, the rest of the task run's
+	// logs are dropped and a marker is written in their place. 0 means no limit.
+	MaxSizeMB int64 `split_words:"true" hcl:"max_size_mb,optional"`
+
+	// Compress compresses the logs of task runs once they have finished, along with any segments they were rotated
+	// into. Compressed logs are decompressed transparently when read. Only used by the file engine.
+	Compress bool `hcl:"compress,optional"`
+
+	// CompressionFormat is the format logs are compressed with; one of "zstd" or "gzip". Logs compressed with either
+	// can always be read, so the format can be changed at any time. Only used by the file engine.
+	CompressionFormat string `split_words:"true" hcl:"compression_format,optional"`
+
+	// RotateSizeMB is the size in megabytes at which the log of a task run still in progress is moved into a segment
+	// of its own and continued in a new file, so that long running task runs can have their earlier logs compressed
+	// while they're still running. 0 means logs are never rotated. Only used by the file engine.
+	RotateSizeMB int64 `split_words:"true" hcl:"rotate_size_mb,optional"`
+
+	// BudgetMB is the most disk space the logs of all task runs combined are allowed to take up in megabytes. When
+	// exceeded the logs of the oldest finished task runs are removed until usage is back within the budget.
+	// 0 means no limit. Only used by the file engine.
+	BudgetMB int64 `split_words:"true" hcl:"budget_mb,optional"`
+
+	// Archive copies the logs of task runs to the object store once they have finished, so that they can still be
+	// read after they've been removed from the server's disk(for example to stay within the budget) or the server has
+	// been replaced. Only used by the file engine; the object_store engine already keeps logs there.
+	Archive bool `hcl:"archive,optional"`
+
+	// BudgetCheckInterval is how often log usage is checked against the budget.
+	BudgetCheckInterval time.Duration `split_words:"true"`
+
+	// BudgetCheckIntervalHCL is the HCL compatible counter part to BudgetCheckInterval. It allows the parsing of a
+	// string to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	BudgetCheckIntervalHCL string `ignored:"true" hcl:"budget_check_interval,optional"`
+}
+
+func DefaultTaskRunLogsConfig() *TaskRunLogs {
+	return &TaskRunLogs{
+		Engine:              "file",
+		MaxSizeMB:           100,
+		Compress:            true,
+		CompressionFormat:   "zstd",
+		RotateSizeMB:        0,
+		BudgetMB:            0,
+		BudgetCheckInterval: mustParseDuration("5m"),
+		Archive:             false,
+	}
+}
+
+func (c *TaskRunLogs) validate() error {
+	if c.Engine != "file" && c.Engine != "object_store" {
+		return fmt.Errorf("task_run_logs.engine must be one of \"file\" or \"object_store\"; got %q", c.Engine)
+	}
+
+	if c.MaxSizeMB < 0 {
+		return fmt.Errorf("task_run_logs.max_size_mb cannot be negative")
+	}
+
+	if c.CompressionFormat != "zstd" && c.CompressionFormat != "gzip" {
+		return fmt.Errorf("task_run_logs.compression_format must be one of \"zstd\" or \"gzip\"; got %q",
+			c.CompressionFormat)
+	}
+
+	if c.RotateSizeMB < 0 {
+		return fmt.Errorf("task_run_logs.rotate_size_mb cannot be negative")
+	}
+
+	if c.BudgetMB < 0 {
+		return fmt.Errorf("task_run_logs.budget_mb cannot be negative")
+	}
+
+	if c.BudgetMB > 0 && c.BudgetCheckInterval <= 0 {
+		return fmt.Errorf("task_run_logs.budget_check_interval must be greater than 0")
+	}
+
+	return nil
+}
@@ -0,0 +1,100 @@
+package config
+
+import (
+	"crypto/tls"
+	"fmt"
+	"strings"
+	"time"
+)
+
+// TLSVersions maps the accepted values of min_version to their crypto/tls counterparts.
+var TLSVersions = map[string]uint16{
+	"1.2": tls.VersionTLS12,
+	"1.3": tls.VersionTLS13,
+}
+
+// ServerTLS controls how the main service and external events endpoints negotiate TLS.
+//
+//	tls {
+//	  min_version     = "1.2"
+//	  cipher_suites   = ["TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384", "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384"]
+//	  reload_interval = "1m"
+//	}
+type ServerTLS struct {
+	// MinVersion is the oldest TLS version accepted from clients; either "1.2" or "1.3". Defaults to "1.2".
+	MinVersion string `split_words:"true" hcl:"min_version,optional"`
+
+	// CipherSuites limits the cipher suites offered for TLS 1.2 connections to the ones named(ex.
+	// TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384). Only suites Go considers secure are accepted. TLS 1.3 suites can't be
+	// configured. Leave empty to use Go's defaults.
+	CipherSuites []string `split_words:"true" hcl:"cipher_suites,optional"`
+
+	// ReloadInterval is how often the certificate and key files are checked for changes. Changed files are loaded
+	// without a restart so that rotated certificates are picked up. Defaults to a minute; use a negative duration("-1s")
+	// to never reload them.
+	ReloadInterval time.Duration `split_words:"true"`
+
+	// ReloadIntervalHCL is the HCL compatible counter part to ReloadInterval. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	ReloadIntervalHCL string `ignored:"true" hcl:"reload_interval,optional"`
+}
+
+func DefaultServerTLSConfig() *ServerTLS {
+	return &ServerTLS{
+		MinVersion:     "1.2",
+		CipherSuites:   []string{},
+		ReloadInterval: mustParseDuration("1m"),
+	}
+}
+
+// CipherSuiteIDs returns the crypto/tls IDs of the configured cipher suites. Names are expected to have been validated.
+func (t *ServerTLS) CipherSuiteIDs() []uint16 {
+	if len(t.CipherSuites) == 0 {
+		return nil
+	}
+
+	suites := map[string]uint16{}
+	for _, suite := range tls.CipherSuites() {
+		suites[suite.Name] = suite.ID
+	}
+
+	ids := []uint16{}
+	for _, name := range t.CipherSuites {
+		ids = append(ids, suites[strings.ToUpper(name)])
+	}
+
+	return ids
+}
+
+// Version returns the crypto/tls counterpart of the configured minimum TLS version.
+func (t *ServerTLS) Version() uint16 {
+	version, exists := TLSVersions[t.MinVersion]
+	if !exists {
+		return tls.VersionTLS12
+	}
+
+	return version
+}
+
+func (t *ServerTLS) validate() error {
+	if _, exists := TLSVersions[t.MinVersion]; !exists && t.MinVersion != "" {
+		return fmt.Errorf("server.tls.min_version %q must be either \"1.2\" or \"1.3\"", t.MinVersion)
+	}
+
+	suites := map[string]struct{}{}
+	for _, suite := range tls.CipherSuites() {
+		suites[suite.Name] = struct{}{}
+	}
+
+	for _, name := range t.CipherSuites {
+		if _, exists := suites[strings.ToUpper(name)]; !exists {
+			return fmt.Errorf("server.tls.cipher_suites: %q is not a supported cipher suite", name)
+		}
+	}
+
+	if len(t.CipherSuites) > 0 && t.MinVersion == "1.3" {
+		return fmt.Errorf("server.tls.cipher_suites has no effect when min_version is \"1.3\"")
+	}
+
+	return nil
+}
@@ -0,0 +1,74 @@
+package config
+
+import (
+	"fmt"
+	"net/url"
+	"time"
+)
+
+// Tracing records the work the server does as spans and sends them to an OpenTelemetry collector(or any other OTLP
+// endpoint). API requests, runs, task runs, scheduler calls and the storage calls made while running pipelines are all
+// traced. Spans are sent using OTLP over HTTP with JSON encoding.
+//
+//	tracing {
+//	  enable   = true
+//	  endpoint = "https://otel-collector.mydomain.com:4318/v1/traces"
+//	}
+type Tracing struct {
+	Enable bool `hcl:"enable,optional"`
+
+	// Endpoint is the full URL spans are posted to. Collectors usually receive spans on port 4318 at /v1/traces.
+	Endpoint string `hcl:"endpoint,optional"`
+
+	// Headers are added to every request. Ex: Authorization
+	Headers map[string]string `hcl:"headers,optional" mask:"values"`
+
+	// ServiceName is reported as the "service.name" resource attribute of every span.
+	ServiceName string `split_words:"true" hcl:"service_name,optional"`
+
+	// SampleRatio is the fraction of traces that are recorded, from 0 to 1. Whether a trace is recorded is decided
+	// once for the entire trace, so traces are never recorded partially.
+	SampleRatio float64 `split_words:"true" hcl:"sample_ratio,optional"`
+
+	// BatchSize is the maximum amount of spans sent in a single request.
+	BatchSize int `split_words:"true" hcl:"batch_size,optional"`
+
+	// FlushInterval is how often spans are sent if there aren't enough to fill a batch.
+	FlushInterval time.Duration `split_words:"true"`
+
+	// FlushIntervalHCL is the HCL compatible counter part to FlushInterval. It allows the parsing of a string
+	// to a time.Duration since HCL does not support parsing directly into a time.Duration.
+	FlushIntervalHCL string `ignored:"true" hcl:"flush_interval,optional"`
+}
+
+func DefaultTracingConfig() *Tracing {
+	return &Tracing{
+		Enable:        false,
+		Headers:       map[string]string{},
+		ServiceName:   "gofer",
+		SampleRatio:   1,
+		BatchSize:     100,
+		FlushInterval: mustParseDuration("5s"),
+	}
+}
+
+func (c *Tracing) validate() error {
+	endpoint, err := url.Parse(c.Endpoint)
+	if err != nil || endpoint.Host == "" {
+		return fmt.Errorf("tracing.endpoint must be a valid url; got %q", c.Endpoint)
+	}
+
+	if c.SampleRatio < 0 || c.SampleRatio > 1 {
+		return fmt.Errorf("tracing.sample_ratio must be between 0 and 1")
+	}
+
+	if c.BatchSize < 1 {
+		return fmt.Errorf("tracing.batch_size must be greater than 0")
+	}
+
+	if c.FlushInterval <= 0 {
+		return fmt.Errorf("tracing.flush_interval must be greater than 0")
+	}
+
+	return nil
+}
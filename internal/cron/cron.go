@@ -0,0 +1,223 @@
+// Package cron parses standard five field cron expressions and works out when they're next due. It's used to run
+// pipelines on a schedule without depending on an external trigger.
+package cron
+
+import (
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// ErrInvalidExpression is returned when a cron expression could not be parsed.
+var ErrInvalidExpression = errors.New("cron: invalid expression")
+
+// How far ahead Next looks for a time that matches before giving up. Expressions that can't ever match, like the 30th
+// of February, would otherwise be searched forever.
+const searchLimitYears = 5
+
+// Schedule is a parsed cron expression. Each field holds the values it matches as bits.
+type Schedule struct {
+	minute uint64
+	hour   uint64
+	dom    uint64
+	month  uint64
+	dow    uint64
+
+	// Standard cron matches a day if either the day of month or the day of week matches, unless one of them is "*".
+	domWildcard bool
+	dowWildcard bool
+}
+
+type field struct {
+	name  string
+	min   int
+	max   int
+	names map[string]int
+}
+
+var (
+	minuteField = field{name: "minute", min: 0, max: 59}
+	hourField   = field{name: "hour", min: 0, max: 23}
+	domField    = field{name: "day of month", min: 1, max: 31}
+	monthField  = field{name: "month", min: 1, max: 12, names: map[string]int{
+		"jan": 1, "feb": 2, "mar": 3, "apr": 4, "may": 5, "jun": 6,
+		"jul": 7, "aug": 8, "sep": 9, "oct": 10, "nov": 11, "dec": 12,
+	}}
+	// Both 0 and 7 are Sunday.
+	dowField = field{name: "day of week", min: 0, max: 7, names: map[string]int{
+		"sun": 0, "mon": 1, "tue": 2, "wed": 3, "thu": 4, "fri": 5, "sat": 6,
+	}}
+)
+
+var macros = map[string]string{
+	"@yearly":   "0 0 1 1 *",
+	"@annually": "0 0 1 1 *",
+	"@monthly":  "0 0 1 * *",
+	"@weekly":   "0 0 * * 0",
+	"@daily":    "0 0 * * *",
+	"@midnight": "0 0 * * *",
+	"@hourly":   "0 * * * *",
+}
+
+// Parse parses a cron expression made up of five fields: minute, hour, day of month, month and day of week. Fields
+// can be "*", a value, a range("1-5"), a step("*/15" or "0-30/10") or a comma separated list of those. Months and
+// days of the week can also be given by their first three letters. The macros @yearly, @annually, @monthly, @weekly,
+// @daily, @midnight and @hourly are accepted in place of an expression.
+func Parse(expression string) (*Schedule, error) {
+	expression = strings.TrimSpace(expression)
+	if macro, exists := macros[strings.ToLower(expression)]; exists {
+		expression = macro
+	}
+
+	fields := strings.Fields(expression)
+	if len(fields) != 5 {
+		return nil, fmt.Errorf("%w; %q must have 5 fields(minute hour day_of_month month day_of_week); found %d",
+			ErrInvalidExpression, expression, len(fields))
+	}
+
+	schedule := &Schedule{}
+	var err error
+
+	if schedule.minute, err = parseField(fields[0], minuteField); err != nil {
+		return nil, err
+	}
+	if schedule.hour, err = parseField(fields[1], hourField); err != nil {
+		return nil, err
+	}
+	if schedule.dom, err = parseField(fields[2], domField); err != nil {
+		return nil, err
+	}
+	if schedule.month, err = parseField(fields[3], monthField); err != nil {
+		return nil, err
+	}
+	if schedule.dow, err = parseField(fields[4], dowField); err != nil {
+		return nil, err
+	}
+
+	if schedule.dow&(1<<7) != 0 {
+		schedule.dow |= 1 << 0
+	}
+
+	schedule.domWildcard = fields[2] == "*"
+	schedule.dowWildcard = fields[4] == "*"
+
+	return schedule, nil
+}
+
+func parseField(value string, f field) (uint64, error) {
+	var bits uint64
+
+	for _, part := range strings.Split(value, ",") {
+		rangePart, stepPart, hasStep := strings.Cut(part, "/")
+
+		step := 1
+		if hasStep {
+			var err error
+			step, err = strconv.Atoi(stepPart)
+			if err != nil || step < 1 {
+				return 0, fmt.Errorf("%w; %s step %q must be a positive number", ErrInvalidExpression, f.name, stepPart)
+			}
+		}
+
+		start, end := f.min, f.max
+		switch {
+		case rangePart == "*":
+		case strings.Contains(rangePart, "-"):
+			startPart, endPart, _ := strings.Cut(rangePart, "-")
+
+			var err error
+			if start, err = parseValue(startPart, f); err != nil {
+				return 0, err
+			}
+			if end, err = parseValue(endPart, f); err != nil {
+				return 0, err
+			}
+
+			if start > end {
+				return 0, fmt.Errorf("%w; %s range %q starts after it ends", ErrInvalidExpression, f.name, rangePart)
+			}
+		default:
+			var err error
+			if start, err = parseValue(rangePart, f); err != nil {
+				return 0, err
+			}
+
+			// A single value with a step("5/15") runs from that value to the end of the field.
+			end = start
+			if hasStep {
+				end = f.max
+			}
+		}
+
+		for i := start; i <= end; i += step {
+			bits |= 1 << uint(i)
+		}
+	}
+
+	return bits, nil
+}
+
+func parseValue(value string, f field) (int, error) {
+	if number, exists := f.names[strings.ToLower(value)]; exists {
+		return number, nil
+	}
+
+	number, err := strconv.Atoi(value)
+	if err != nil {
+		return 0, fmt.Errorf("%w; %s value %q is not a number", ErrInvalidExpression, f.name, value)
+	}
+
+	if number < f.min || number > f.max {
+		return 0, fmt.Errorf("%w; %s value %d must be between %d and %d", ErrInvalidExpression, f.name, number,
+			f.min, f.max)
+	}
+
+	return number, nil
+}
+
+// Next returns the first time after the given time that the schedule is due, in the given time's location. The zero
+// time is returned if the schedule isn't due within the next few years.
+func (s *Schedule) Next(after time.Time) time.Time {
+	loc := after.Location()
+	t := after.Truncate(time.Minute).Add(time.Minute)
+	limit := t.Year() + searchLimitYears
+
+	for t.Year() <= limit {
+		if s.month&(1<<uint(t.Month())) == 0 {
+			t = time.Date(t.Year(), t.Month()+1, 1, 0, 0, 0, 0, loc)
+			continue
+		}
+
+		if !s.dayMatches(t) {
+			t = time.Date(t.Year(), t.Month(), t.Day()+1, 0, 0, 0, 0, loc)
+			continue
+		}
+
+		if s.hour&(1<<uint(t.Hour())) == 0 {
+			t = time.Date(t.Year(), t.Month(), t.Day(), t.Hour(), 0, 0, 0, loc).Add(time.Hour)
+			continue
+		}
+
+		if s.minute&(1<<uint(t.Minute())) == 0 {
+			t = t.Add(time.Minute)
+			continue
+		}
+
+		return t
+	}
+
+	return time.Time{}
+}
+
+func (s *Schedule) dayMatches(t time.Time) bool {
+	domMatch := s.dom&(1<<uint(t.Day())) != 0
+	dowMatch := s.dow&(1<<uint(t.Weekday())) != 0
+
+	if s.domWildcard || s.dowWildcard {
+		return domMatch && dowMatch
+	}
+
+	return domMatch || dowMatch
+}
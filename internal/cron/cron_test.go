@@ -0,0 +1,104 @@
+package cron
+
+import (
+	"errors"
+	"testing"
+	"time"
+)
+
+func TestNext(t *testing.T) {
+	newYork, err := time.LoadLocation("America/New_York")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	tests := map[string]struct {
+		expression string
+		after      time.Time
+		expected   time.Time
+	}{
+		"every minute": {
+			expression: "* * * * *",
+			after:      time.Date(2022, 5, 1, 10, 30, 15, 0, time.UTC),
+			expected:   time.Date(2022, 5, 1, 10, 31, 0, 0, time.UTC),
+		},
+		"step": {
+			expression: "*/15 * * * *",
+			after:      time.Date(2022, 5, 1, 10, 31, 0, 0, time.UTC),
+			expected:   time.Date(2022, 5, 1, 10, 45, 0, 0, time.UTC),
+		},
+		"next day": {
+			expression: "0 2 * * *",
+			after:      time.Date(2022, 5, 1, 3, 0, 0, 0, time.UTC),
+			expected:   time.Date(2022, 5, 2, 2, 0, 0, 0, time.UTC),
+		},
+		"weekdays by name": {
+			expression: "30 9 * * mon-fri",
+			after:      time.Date(2022, 4, 29, 10, 0, 0, 0, time.UTC), // Friday
+			expected:   time.Date(2022, 5, 2, 9, 30, 0, 0, time.UTC),
+		},
+		"sunday as 7": {
+			expression: "0 0 * * 7",
+			after:      time.Date(2022, 5, 2, 0, 0, 0, 0, time.UTC), // Monday
+			expected:   time.Date(2022, 5, 8, 0, 0, 0, 0, time.UTC),
+		},
+		"day of month or day of week": {
+			expression: "0 0 15 * fri",
+			after:      time.Date(2022, 5, 7, 0, 0, 0, 0, time.UTC), // Saturday
+			expected:   time.Date(2022, 5, 13, 0, 0, 0, 0, time.UTC),
+		},
+		"next year": {
+			expression: "@yearly",
+			after:      time.Date(2022, 5, 1, 0, 0, 0, 0, time.UTC),
+			expected:   time.Date(2023, 1, 1, 0, 0, 0, 0, time.UTC),
+		},
+		"leap day": {
+			expression: "0 0 29 feb *",
+			after:      time.Date(2022, 3, 1, 0, 0, 0, 0, time.UTC),
+			expected:   time.Date(2024, 2, 29, 0, 0, 0, 0, time.UTC),
+		},
+		"timezone": {
+			expression: "0 9 * * *",
+			after:      time.Date(2022, 5, 1, 10, 0, 0, 0, newYork),
+			expected:   time.Date(2022, 5, 2, 9, 0, 0, 0, newYork),
+		},
+		"never": {
+			expression: "0 0 30 feb *",
+			after:      time.Date(2022, 5, 1, 0, 0, 0, 0, time.UTC),
+			expected:   time.Time{},
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			schedule, err := Parse(test.expression)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			got := schedule.Next(test.after)
+			if !got.Equal(test.expected) {
+				t.Errorf("expected %s; got %s", test.expected, got)
+			}
+		})
+	}
+}
+
+func TestParseInvalid(t *testing.T) {
+	tests := []string{
+		"* * * *",
+		"60 * * * *",
+		"* 24 * * *",
+		"* * 0 * *",
+		"* * * 13 *",
+		"*/0 * * * *",
+		"5-1 * * * *",
+		"* * * * someday",
+	}
+
+	for _, expression := range tests {
+		if _, err := Parse(expression); !errors.Is(err, ErrInvalidExpression) {
+			t.Errorf("expected %q to be invalid; got %v", expression, err)
+		}
+	}
+}
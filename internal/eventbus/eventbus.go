@@ -178,6 +178,40 @@ func (eb *EventBus) GetAll(reverse bool) <-chan models.Event {
 	return events
 }
 
+// GetAllAfter returns all events with an ID greater than the one given, from oldest to newest.
+func (eb *EventBus) GetAllAfter(id int64) <-chan models.Event {
+	events := make(chan models.Event, 10)
+
+	go func() {
+		lastID := id
+
+		for {
+			eventList, err := eb.storage.GetAllEvents(storage.GetAllEventsRequest{
+				Offset: int(lastID),
+				Limit:  10,
+			})
+			if err != nil {
+				log.Error().Err(err).Msg("could not get events")
+				close(events)
+				return
+			}
+
+			if len(eventList) == 0 {
+				close(events)
+				return
+			}
+
+			for _, event := range eventList {
+				events <- event
+			}
+
+			lastID = eventList[len(eventList)-1].GetID()
+		}
+	}()
+
+	return events
+}
+
 // Get returns a single event by id. Returns a eventbus.ErrEventNotFound if the event could not be located.
 func (eb *EventBus) Get(id int64) (models.Event, error) {
 	event, err := eb.storage.GetEvent(storage.GetEventRequest{
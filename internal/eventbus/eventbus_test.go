@@ -178,6 +178,38 @@ func TestGetAllOffset(t *testing.T) {
 	}
 }
 
+func TestGetAllAfter(t *testing.T) {
+	db := mustOpenDB()
+
+	eb, err := New(db, time.Second*5, time.Minute*5)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	eventList := []models.Event{}
+	for i := 0; i < 25; i++ {
+		newEvent := models.NewEventStartedRun(models.Run{})
+		eb.Publish(newEvent)
+		eventList = append(eventList, newEvent)
+	}
+
+	events := eb.GetAllAfter(eventList[4].GetID())
+
+	count := 5
+	for event := range events {
+		if event.GetID() != eventList[count].GetID() {
+			t.Errorf("published event id and new event id do no match; published %d; new %d",
+				event.GetID(), eventList[count].GetID())
+		}
+
+		count++
+	}
+
+	if count != len(eventList) {
+		t.Errorf("expected to receive %d events; received %d", len(eventList)-5, count-5)
+	}
+}
+
 func TestGetAllReverse(t *testing.T) {
 	db := mustOpenDB()
 
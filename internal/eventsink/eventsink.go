@@ -0,0 +1,42 @@
+// Package eventsink delivers Gofer events to external systems.
+package eventsink
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// defaultTimeout is how long a single delivery attempt is allowed to take when the sink doesn't specify.
+const defaultTimeout = 10 * time.Second
+
+// Sink is an external system that events are delivered to.
+type Sink interface {
+	// Send delivers the events in order. Events are only considered delivered if Send returns without error;
+	// otherwise they will all be sent again.
+	Send(events []models.Event) error
+
+	// Close releases any connections held by the sink.
+	Close() error
+}
+
+// New returns the sink described by the given config.
+func New(conf config.EventSink) (Sink, error) {
+	if conf.Timeout == 0 {
+		conf.Timeout = defaultTimeout
+	}
+
+	switch strings.ToLower(conf.Kind) {
+	case "webhook":
+		return newWebhook(conf), nil
+	case "kafka":
+		return newKafka(conf), nil
+	case "nats":
+		return newNATS(conf)
+	default:
+		return nil, fmt.Errorf("sink kind %q is not supported", conf.Kind)
+	}
+}
@@ -0,0 +1,84 @@
+package eventsink
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// kafka produces events to a Kafka topic through a Kafka REST proxy using the v2 API. Each event becomes a record
+// keyed by its kind.
+type kafka struct {
+	url     string
+	headers map[string]string
+	client  *http.Client
+}
+
+// kafkaRecords is the body of a v2 API produce request.
+type kafkaRecords struct {
+	Records []kafkaRecord `json:"records"`
+}
+
+type kafkaRecord struct {
+	Key   string       `json:"key"`
+	Value models.Event `json:"value"`
+}
+
+// kafkaOffsets is the body of a v2 API produce response. Records that failed to be produced have an error set.
+type kafkaOffsets struct {
+	Offsets []struct {
+		ErrorCode *int   `json:"error_code"`
+		Error     string `json:"error"`
+	} `json:"offsets"`
+}
+
+func newKafka(conf config.EventSink) *kafka {
+	return &kafka{
+		url:     strings.TrimSuffix(conf.URL, "/") + "/topics/" + url.PathEscape(conf.Topic),
+		headers: conf.Headers,
+		client:  &http.Client{Timeout: conf.Timeout},
+	}
+}
+
+func (k *kafka) Send(events []models.Event) error {
+	records := kafkaRecords{Records: []kafkaRecord{}}
+	for _, event := range events {
+		records.Records = append(records.Records, kafkaRecord{
+			Key:   string(event.GetKind()),
+			Value: event,
+		})
+	}
+
+	body, err := json.Marshal(records)
+	if err != nil {
+		return err
+	}
+
+	response, err := Post(k.client, k.url, "application/vnd.kafka.json.v2+json", k.headers, body)
+	if err != nil {
+		return err
+	}
+
+	offsets := kafkaOffsets{}
+	err = json.Unmarshal(response, &offsets)
+	if err != nil {
+		return fmt.Errorf("could not parse response: %w", err)
+	}
+
+	for _, offset := range offsets.Offsets {
+		if offset.ErrorCode != nil {
+			return fmt.Errorf("could not produce record: %d %s", *offset.ErrorCode, offset.Error)
+		}
+	}
+
+	return nil
+}
+
+func (k *kafka) Close() error {
+	return nil
+}
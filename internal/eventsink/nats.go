@@ -0,0 +1,201 @@
+package eventsink
+
+import (
+	"bufio"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net"
+	"net/url"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// NATS uses a simple text based protocol(https://docs.nats.io/reference/reference-protocols/nats-protocol). Only
+// the small subset needed to publish is implemented here.
+
+// nats publishes each event as a message on a NATS subject. Core NATS doesn't acknowledge published messages, so
+// after publishing a batch the sink waits on a PING round trip to be sure the server processed everything before it.
+type nats struct {
+	mu      sync.Mutex
+	address string
+	user    string
+	pass    string
+	subject string
+	timeout time.Duration
+
+	// conn is the current connection to the server; nil if not connected. Connections are made lazily and dropped
+	// on any error so that the next send reconnects.
+	conn   net.Conn
+	reader *bufio.Reader
+}
+
+// natsConnect is the options sent to the server upon connecting.
+type natsConnect struct {
+	Verbose  bool   `json:"verbose"`
+	Pedantic bool   `json:"pedantic"`
+	Name     string `json:"name"`
+	User     string `json:"user,omitempty"`
+	Pass     string `json:"pass,omitempty"`
+}
+
+func newNATS(conf config.EventSink) (*nats, error) {
+	parsedURL, err := url.Parse(conf.URL)
+	if err != nil {
+		return nil, err
+	}
+
+	if parsedURL.Scheme != "nats" {
+		return nil, fmt.Errorf("url scheme %q is not supported; must be nats://", parsedURL.Scheme)
+	}
+
+	address := parsedURL.Host
+	if parsedURL.Port() == "" {
+		address = net.JoinHostPort(parsedURL.Hostname(), "4222")
+	}
+
+	pass, _ := parsedURL.User.Password()
+
+	return &nats{
+		address: address,
+		user:    parsedURL.User.Username(),
+		pass:    pass,
+		subject: conf.Topic,
+		timeout: conf.Timeout,
+	}, nil
+}
+
+func (n *nats) Send(events []models.Event) error {
+	n.mu.Lock()
+	defer n.mu.Unlock()
+
+	err := n.send(events)
+	if err != nil {
+		n.disconnect()
+		return err
+	}
+
+	return nil
+}
+
+func (n *nats) send(events []models.Event) error {
+	if n.conn == nil {
+		err := n.connect()
+		if err != nil {
+			return fmt.Errorf("could not connect to %s: %w", n.address, err)
+		}
+	}
+
+	err := n.conn.SetDeadline(time.Now().Add(n.timeout))
+	if err != nil {
+		return err
+	}
+
+	writer := bufio.NewWriter(n.conn)
+	for _, event := range events {
+		payload, err := json.Marshal(event)
+		if err != nil {
+			return err
+		}
+
+		fmt.Fprintf(writer, "PUB %s %d\r\n", n.subject, len(payload))
+		_, _ = writer.Write(payload)
+		_, _ = writer.WriteString("\r\n")
+	}
+	_, _ = writer.WriteString("PING\r\n")
+
+	err = writer.Flush()
+	if err != nil {
+		return err
+	}
+
+	return n.waitForPong()
+}
+
+// connect dials the server, reads its greeting and introduces the client.
+func (n *nats) connect() error {
+	conn, err := net.DialTimeout("tcp", n.address, n.timeout)
+	if err != nil {
+		return err
+	}
+
+	n.conn = conn
+	n.reader = bufio.NewReader(conn)
+
+	err = conn.SetDeadline(time.Now().Add(n.timeout))
+	if err != nil {
+		return err
+	}
+
+	line, err := n.readLine()
+	if err != nil {
+		return err
+	}
+
+	if !strings.HasPrefix(line, "INFO ") {
+		return fmt.Errorf("unexpected greeting from server: %q", line)
+	}
+
+	options, err := json.Marshal(natsConnect{Name: "gofer", User: n.user, Pass: n.pass})
+	if err != nil {
+		return err
+	}
+
+	_, err = fmt.Fprintf(conn, "CONNECT %s\r\n", options)
+	return err
+}
+
+// waitForPong reads from the server until it answers the client's PING. Since the server processes messages in
+// order, a PONG means every message published before the PING was accepted.
+func (n *nats) waitForPong() error {
+	for {
+		line, err := n.readLine()
+		if err != nil {
+			return err
+		}
+
+		switch {
+		case line == "PONG":
+			return nil
+		case line == "PING":
+			_, err = n.conn.Write([]byte("PONG\r\n"))
+			if err != nil {
+				return err
+			}
+		case strings.HasPrefix(line, "-ERR"):
+			return errors.New(strings.TrimSpace(strings.TrimPrefix(line, "-ERR")))
+		}
+		// Anything else(ex. INFO updates and +OK) is of no interest to a publisher.
+	}
+}
+
+func (n *nats) readLine() (string, error) {
+	line, err := n.reader.ReadString('\n')
+	if err != nil {
+		return "", err
+	}
+
+	return strings.TrimRight(line, "\r\n"), nil
+}
+
+func (n *nats) disconnect() {
+	if n.conn == nil {
+		return
+	}
+
+	_ = n.conn.Close()
+	n.conn = nil
+	n.reader = nil
+}
+
+func (n *nats) Close() error {
+	n.mu.Lock()
+	defer n.mu.Unlock()
+
+	n.disconnect()
+	return nil
+}
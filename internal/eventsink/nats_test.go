@@ -0,0 +1,105 @@
+package eventsink
+
+import (
+	"bufio"
+	"net"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/google/go-cmp/cmp"
+)
+
+// fakeNATSServer accepts a single client and records the commands it sends until the client disconnects.
+func fakeNATSServer(t *testing.T, listener net.Listener, commands chan<- string) {
+	defer close(commands)
+
+	conn, err := listener.Accept()
+	if err != nil {
+		t.Error(err)
+		return
+	}
+	defer conn.Close()
+
+	_, _ = conn.Write([]byte("INFO {\"server_id\":\"test\"}\r\n"))
+
+	reader := bufio.NewReader(conn)
+	for {
+		line, err := reader.ReadString('\n')
+		if err != nil {
+			return
+		}
+		line = strings.TrimRight(line, "\r\n")
+
+		if strings.HasPrefix(line, "PUB ") {
+			payload, err := reader.ReadString('\n')
+			if err != nil {
+				return
+			}
+			line = line + " " + strings.TrimRight(payload, "\r\n")
+		}
+
+		if line == "PING" {
+			_, _ = conn.Write([]byte("PONG\r\n"))
+		}
+
+		commands <- line
+	}
+}
+
+func TestNATSSend(t *testing.T) {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer listener.Close()
+
+	commands := make(chan string, 10)
+	go fakeNATSServer(t, listener, commands)
+
+	sink, err := newNATS(config.EventSink{
+		URL:     "nats://gofer:secret@" + listener.Addr().String(),
+		Topic:   "gofer.events",
+		Timeout: 5 * time.Second,
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	event := &models.EventCreatedNamespace{
+		Metadata: models.Metadata{
+			EventID: 1,
+			Kind:    models.CreatedNamespaceEvent,
+			Emitted: 1000,
+		},
+		NamespaceID: "default",
+	}
+
+	err = sink.Send([]models.Event{event})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = sink.Close()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	got := []string{}
+	for command := range commands {
+		got = append(got, command)
+	}
+
+	payload := `{"event_id":1,"kind":"CREATED_NAMESPACE","emitted":1000,"NamespaceID":"default"}`
+	want := []string{
+		`CONNECT {"verbose":false,"pedantic":false,"name":"gofer","user":"gofer","pass":"secret"}`,
+		"PUB gofer.events 80 " + payload,
+		"PING",
+	}
+
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("unexpected commands (-want +got):\n%s", diff)
+	}
+}
@@ -0,0 +1,85 @@
+package eventsink
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// webhook sends events to an HTTP endpoint as a JSON array. Any 2xx response means the events were received.
+type webhook struct {
+	url     string
+	headers map[string]string
+	client  *http.Client
+}
+
+func newWebhook(conf config.EventSink) *webhook {
+	return &webhook{
+		url:     conf.URL,
+		headers: conf.Headers,
+		client:  &http.Client{Timeout: conf.Timeout},
+	}
+}
+
+func (w *webhook) Send(events []models.Event) error {
+	body, err := json.Marshal(events)
+	if err != nil {
+		return err
+	}
+
+	_, err = Post(w.client, w.url, "application/json", w.headers, body)
+	return err
+}
+
+func (w *webhook) Close() error {
+	return nil
+}
+
+// maxResponseSize limits how much of a response is read.
+const maxResponseSize = 1 << 20
+
+// StatusError is returned by Post when the response isn't a success.
+type StatusError struct {
+	StatusCode int
+	Body       []byte
+}
+
+func (e *StatusError) Error() string {
+	return fmt.Sprintf("received status %d: %s", e.StatusCode, bytes.TrimSpace(e.Body))
+}
+
+// Post sends the body to the given url and returns the body of the response. Returns an error if the response isn't
+// a success.
+func Post(client *http.Client, url, contentType string, headers map[string]string, body []byte) ([]byte, error) {
+	request, err := http.NewRequest(http.MethodPost, url, bytes.NewReader(body))
+	if err != nil {
+		return nil, err
+	}
+
+	request.Header.Set("Content-Type", contentType)
+	for key, value := range headers {
+		request.Header.Set(key, value)
+	}
+
+	response, err := client.Do(request)
+	if err != nil {
+		return nil, err
+	}
+	defer response.Body.Close()
+
+	responseBody, err := io.ReadAll(io.LimitReader(response.Body, maxResponseSize))
+	if err != nil {
+		return nil, err
+	}
+
+	if response.StatusCode < 200 || response.StatusCode > 299 {
+		return nil, &StatusError{StatusCode: response.StatusCode, Body: responseBody}
+	}
+
+	return responseBody, nil
+}
@@ -0,0 +1,134 @@
+// Package latency keeps the recent latencies of server operations(ex. API routes or storage queries) so that the
+// slowest of them can be reported.
+package latency
+
+import (
+	"math"
+	"sort"
+	"sync"
+	"time"
+)
+
+// maxSamples is the most samples kept for a single operation. Operations called more often than this within the
+// window are summarized using only their most recent samples.
+const maxSamples = 1024
+
+type sample struct {
+	observed time.Time
+	duration time.Duration
+}
+
+// series is a ring buffer of the most recent samples of an operation.
+type series struct {
+	samples []sample
+	next    int
+}
+
+func (s *series) add(sample sample) {
+	if len(s.samples) < maxSamples {
+		s.samples = append(s.samples, sample)
+		return
+	}
+
+	s.samples[s.next] = sample
+	s.next = (s.next + 1) % maxSamples
+}
+
+// Summary describes the latency of an operation over the tracker's window.
+type Summary struct {
+	Name  string
+	Count int64
+	P50   time.Duration
+	P95   time.Duration
+	P99   time.Duration
+	Max   time.Duration
+}
+
+// Tracker keeps the latencies of operations observed within a rolling window.
+type Tracker struct {
+	window time.Duration
+
+	mu     sync.Mutex
+	series map[string]*series
+}
+
+func New(window time.Duration) *Tracker {
+	return &Tracker{
+		window: window,
+		series: map[string]*series{},
+	}
+}
+
+// Window returns how far back the tracker reports on.
+func (t *Tracker) Window() time.Duration {
+	return t.window
+}
+
+// Observe records a single call of the named operation.
+func (t *Tracker) Observe(name string, duration time.Duration) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	operation, exists := t.series[name]
+	if !exists {
+		operation = &series{}
+		t.series[name] = operation
+	}
+
+	operation.add(sample{observed: time.Now(), duration: duration})
+}
+
+// Report summarizes every operation called within the window ending at the given time, slowest first. Operations
+// are ordered by their p99 latency.
+func (t *Tracker) Report(now time.Time) []Summary {
+	cutoff := now.Add(-t.window)
+
+	t.mu.Lock()
+	defer t.mu.Unlock()
+
+	summaries := []Summary{}
+	for name, operation := range t.series {
+		durations := []time.Duration{}
+		for _, sample := range operation.samples {
+			if sample.observed.After(cutoff) {
+				durations = append(durations, sample.duration)
+			}
+		}
+
+		// Operations that haven't been called in a while are dropped so that the tracker doesn't keep growing.
+		if len(durations) == 0 {
+			delete(t.series, name)
+			continue
+		}
+
+		sort.Slice(durations, func(i, j int) bool { return durations[i] < durations[j] })
+
+		summaries = append(summaries, Summary{
+			Name:  name,
+			Count: int64(len(durations)),
+			P50:   quantile(durations, 0.5),
+			P95:   quantile(durations, 0.95),
+			P99:   quantile(durations, 0.99),
+			Max:   durations[len(durations)-1],
+		})
+	}
+
+	sort.Slice(summaries, func(i, j int) bool {
+		if summaries[i].P99 != summaries[j].P99 {
+			return summaries[i].P99 > summaries[j].P99
+		}
+		return summaries[i].Name < summaries[j].Name
+	})
+
+	return summaries
+}
+
+// quantile returns the given quantile(ex. 0.95) of the sorted durations using the nearest rank.
+func quantile(sorted []time.Duration, q float64) time.Duration {
+	rank := int(math.Ceil(q*float64(len(sorted)))) - 1
+	if rank < 0 {
+		rank = 0
+	}
+
+	return sorted[rank]
+}
@@ -0,0 +1,33 @@
+package latency
+
+import (
+	"testing"
+	"time"
+
+	"github.com/google/go-cmp/cmp"
+)
+
+func TestReport(t *testing.T) {
+	tracker := New(time.Minute)
+
+	for i := 1; i <= 100; i++ {
+		tracker.Observe("GetRun", time.Duration(i)*time.Millisecond)
+	}
+	tracker.Observe("GetPipeline", 500*time.Millisecond)
+
+	got := tracker.Report(time.Now())
+	want := []Summary{
+		{Name: "GetPipeline", Count: 1, P50: 500 * time.Millisecond, P95: 500 * time.Millisecond,
+			P99: 500 * time.Millisecond, Max: 500 * time.Millisecond},
+		{Name: "GetRun", Count: 100, P50: 50 * time.Millisecond, P95: 95 * time.Millisecond,
+			P99: 99 * time.Millisecond, Max: 100 * time.Millisecond},
+	}
+
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("unexpected report (-want +got):\n%s", diff)
+	}
+
+	if got := tracker.Report(time.Now().Add(2 * time.Minute)); len(got) != 0 {
+		t.Errorf("expected operations outside of the window to be dropped; got %d", len(got))
+	}
+}
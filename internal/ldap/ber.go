@@ -0,0 +1,177 @@
+package ldap
+
+import (
+	"bufio"
+	"bytes"
+	"errors"
+	"fmt"
+	"io"
+)
+
+// LDAP messages are encoded using the Basic Encoding Rules(BER). Only the small subset of BER needed to bind and
+// search is implemented here.
+
+const (
+	classApplication byte = 0x40
+	classContext     byte = 0x80
+	constructed      byte = 0x20
+)
+
+const (
+	tagBoolean     byte = 0x01
+	tagInteger     byte = 0x02
+	tagOctetString byte = 0x04
+	tagEnumerated  byte = 0x0a
+	tagSequence    byte = 0x10 | constructed
+)
+
+// maxPacketSize limits the size of a single message read from the server.
+const maxPacketSize = 16 << 20
+
+// element is a single decoded BER element.
+type element struct {
+	tag     byte
+	content []byte
+}
+
+func encodeLength(length int) []byte {
+	if length < 0x80 {
+		return []byte{byte(length)}
+	}
+
+	lengthBytes := []byte{}
+	for length > 0 {
+		lengthBytes = append([]byte{byte(length)}, lengthBytes...)
+		length >>= 8
+	}
+
+	return append([]byte{0x80 | byte(len(lengthBytes))}, lengthBytes...)
+}
+
+func encode(tag byte, content []byte) []byte {
+	encoded := append([]byte{tag}, encodeLength(len(content))...)
+	return append(encoded, content...)
+}
+
+func encodeConstructed(tag byte, children ...[]byte) []byte {
+	content := []byte{}
+	for _, child := range children {
+		content = append(content, child...)
+	}
+
+	return encode(tag, content)
+}
+
+func encodeString(tag byte, value string) []byte {
+	return encode(tag, []byte(value))
+}
+
+func encodeInteger(tag byte, value int64) []byte {
+	content := []byte{}
+	for {
+		content = append([]byte{byte(value)}, content...)
+		value >>= 8
+
+		// Stop once the remaining value is only sign extension of what has been written.
+		if (value == 0 && content[0]&0x80 == 0) || (value == -1 && content[0]&0x80 != 0) {
+			break
+		}
+	}
+
+	return encode(tag, content)
+}
+
+func encodeBoolean(value bool) []byte {
+	if value {
+		return encode(tagBoolean, []byte{0xff})
+	}
+
+	return encode(tagBoolean, []byte{0x00})
+}
+
+func decodeInteger(content []byte) (int64, error) {
+	if len(content) == 0 || len(content) > 8 {
+		return 0, fmt.Errorf("invalid integer length %d", len(content))
+	}
+
+	value := int64(int8(content[0]))
+	for _, b := range content[1:] {
+		value = value<<8 | int64(b)
+	}
+
+	return value, nil
+}
+
+// readElement reads a single complete element from the reader. Returns io.EOF only if the reader is exhausted
+// before the element starts.
+func readElement(reader *bufio.Reader) (*element, error) {
+	tag, err := reader.ReadByte()
+	if err != nil {
+		return nil, err
+	}
+
+	e, err := readElementBody(reader, tag)
+	if errors.Is(err, io.EOF) {
+		return nil, io.ErrUnexpectedEOF
+	}
+
+	return e, err
+}
+
+func readElementBody(reader *bufio.Reader, tag byte) (*element, error) {
+	if tag&0x1f == 0x1f {
+		return nil, errors.New("multi-byte tags are not supported")
+	}
+
+	first, err := reader.ReadByte()
+	if err != nil {
+		return nil, err
+	}
+
+	length := int(first)
+	if first&0x80 != 0 {
+		count := int(first & 0x7f)
+		if count == 0 || count > 4 {
+			return nil, fmt.Errorf("unsupported length encoding")
+		}
+
+		length = 0
+		for i := 0; i < count; i++ {
+			b, err := reader.ReadByte()
+			if err != nil {
+				return nil, err
+			}
+			length = length<<8 | int(b)
+		}
+	}
+
+	if length > maxPacketSize {
+		return nil, fmt.Errorf("message of %d bytes exceeds maximum size", length)
+	}
+
+	content := make([]byte, length)
+	_, err = io.ReadFull(reader, content)
+	if err != nil {
+		return nil, err
+	}
+
+	return &element{tag: tag, content: content}, nil
+}
+
+// children decodes the content of a constructed element into its child elements.
+func (e *element) children() ([]*element, error) {
+	reader := bufio.NewReader(bytes.NewReader(e.content))
+
+	children := []*element{}
+	for {
+		child, err := readElement(reader)
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				return children, nil
+			}
+			return nil, err
+		}
+
+		children = append(children, child)
+	}
+}
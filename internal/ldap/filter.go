@@ -0,0 +1,146 @@
+package ldap
+
+import (
+	"encoding/hex"
+	"fmt"
+	"strings"
+)
+
+// Filter tags as defined by RFC 4511.
+const (
+	filterAnd      byte = classContext | constructed | 0
+	filterOr       byte = classContext | constructed | 1
+	filterNot      byte = classContext | constructed | 2
+	filterEquality byte = classContext | constructed | 3
+	filterPresent  byte = classContext | 7
+)
+
+// EscapeFilter escapes a value so that it can be safely placed within a filter(RFC 4515).
+func EscapeFilter(value string) string {
+	builder := strings.Builder{}
+	for i := 0; i < len(value); i++ {
+		switch c := value[i]; c {
+		case '\\', '*', '(', ')', 0:
+			fmt.Fprintf(&builder, "\\%02x", c)
+		default:
+			builder.WriteByte(c)
+		}
+	}
+
+	return builder.String()
+}
+
+// compileFilter converts a string filter(ex. "(&(objectClass=person)(uid=jdoe))") into its BER encoding. Only the
+// and, or, not, equality and presence filters are supported.
+func compileFilter(filter string) ([]byte, error) {
+	encoded, rest, err := compileFilterPart(filter)
+	if err != nil {
+		return nil, fmt.Errorf("invalid filter %q: %w", filter, err)
+	}
+
+	if rest != "" {
+		return nil, fmt.Errorf("invalid filter %q: unexpected trailing characters %q", filter, rest)
+	}
+
+	return encoded, nil
+}
+
+func compileFilterPart(filter string) (encoded []byte, rest string, err error) {
+	if !strings.HasPrefix(filter, "(") {
+		return nil, "", fmt.Errorf("filter must start with '('")
+	}
+	filter = filter[1:]
+
+	if filter == "" {
+		return nil, "", fmt.Errorf("unexpected end of filter")
+	}
+
+	switch filter[0] {
+	case '&', '|':
+		tag := filterAnd
+		if filter[0] == '|' {
+			tag = filterOr
+		}
+
+		rest = filter[1:]
+		children := [][]byte{}
+		for strings.HasPrefix(rest, "(") {
+			var child []byte
+			child, rest, err = compileFilterPart(rest)
+			if err != nil {
+				return nil, "", err
+			}
+			children = append(children, child)
+		}
+
+		if !strings.HasPrefix(rest, ")") {
+			return nil, "", fmt.Errorf("missing ')'")
+		}
+
+		return encodeConstructed(tag, children...), rest[1:], nil
+	case '!':
+		var child []byte
+		child, rest, err = compileFilterPart(filter[1:])
+		if err != nil {
+			return nil, "", err
+		}
+
+		if !strings.HasPrefix(rest, ")") {
+			return nil, "", fmt.Errorf("missing ')'")
+		}
+
+		return encodeConstructed(filterNot, child), rest[1:], nil
+	default:
+		end := strings.Index(filter, ")")
+		if end == -1 {
+			return nil, "", fmt.Errorf("missing ')'")
+		}
+
+		attribute, value, found := strings.Cut(filter[:end], "=")
+		if !found || attribute == "" {
+			return nil, "", fmt.Errorf("expected attribute=value")
+		}
+
+		if value == "*" {
+			return encodeString(filterPresent, attribute), filter[end+1:], nil
+		}
+
+		if strings.Contains(value, "*") {
+			return nil, "", fmt.Errorf("substring filters are not supported")
+		}
+
+		unescaped, err := unescapeFilterValue(value)
+		if err != nil {
+			return nil, "", err
+		}
+
+		return encodeConstructed(filterEquality,
+			encodeString(tagOctetString, attribute),
+			encodeString(tagOctetString, unescaped),
+		), filter[end+1:], nil
+	}
+}
+
+func unescapeFilterValue(value string) (string, error) {
+	builder := strings.Builder{}
+	for i := 0; i < len(value); i++ {
+		if value[i] != '\\' {
+			builder.WriteByte(value[i])
+			continue
+		}
+
+		if i+3 > len(value) {
+			return "", fmt.Errorf("invalid escape sequence in %q", value)
+		}
+
+		decoded, err := hex.DecodeString(value[i+1 : i+3])
+		if err != nil {
+			return "", fmt.Errorf("invalid escape sequence in %q", value)
+		}
+
+		builder.Write(decoded)
+		i += 2
+	}
+
+	return builder.String(), nil
+}
@@ -0,0 +1,317 @@
+// Package ldap implements a minimal LDAPv3 client which is able to bind and search. It is used to look up the
+// directory groups users belong to.
+package ldap
+
+import (
+	"bufio"
+	"crypto/tls"
+	"fmt"
+	"net"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// Protocol operation tags as defined by RFC 4511.
+const (
+	opBindRequest     byte = classApplication | constructed | 0
+	opBindResponse    byte = classApplication | constructed | 1
+	opUnbindRequest   byte = classApplication | 2
+	opSearchRequest   byte = classApplication | constructed | 3
+	opSearchEntry     byte = classApplication | constructed | 4
+	opSearchDone      byte = classApplication | constructed | 5
+	opSearchReference byte = classApplication | constructed | 19
+	authSimple        byte = classContext | 0
+)
+
+const (
+	protocolVersion   int64 = 3
+	scopeWholeSubtree int64 = 2
+	derefAliasesNever int64 = 0
+	resultCodeSuccess int64 = 0
+)
+
+const (
+	defaultLDAPPort  = "389"
+	defaultLDAPSPort = "636"
+	defaultTimeout   = 10 * time.Second
+)
+
+// Entry is a single object returned by a search.
+type Entry struct {
+	DN         string
+	Attributes map[string][]string
+}
+
+// Get returns the values of the given attribute; attribute names are case insensitive.
+func (e *Entry) Get(attribute string) []string {
+	for name, values := range e.Attributes {
+		if strings.EqualFold(name, attribute) {
+			return values
+		}
+	}
+
+	return nil
+}
+
+// Conn is a connection to an LDAP server. Conn is not safe for concurrent use.
+type Conn struct {
+	conn      net.Conn
+	reader    *bufio.Reader
+	messageID int64
+	timeout   time.Duration
+}
+
+// Dial connects to the server at the given URL. Both ldap:// and ldaps:// URLs are supported.
+func Dial(rawURL string, tlsConfig *tls.Config) (*Conn, error) {
+	parsedURL, err := url.Parse(rawURL)
+	if err != nil {
+		return nil, fmt.Errorf("invalid url %q: %w", rawURL, err)
+	}
+
+	host := parsedURL.Host
+	dialer := &net.Dialer{Timeout: defaultTimeout}
+
+	var conn net.Conn
+	switch parsedURL.Scheme {
+	case "ldap":
+		if parsedURL.Port() == "" {
+			host = net.JoinHostPort(parsedURL.Hostname(), defaultLDAPPort)
+		}
+		conn, err = dialer.Dial("tcp", host)
+	case "ldaps":
+		if parsedURL.Port() == "" {
+			host = net.JoinHostPort(parsedURL.Hostname(), defaultLDAPSPort)
+		}
+		if tlsConfig == nil {
+			tlsConfig = &tls.Config{}
+		}
+		if tlsConfig.ServerName == "" {
+			tlsConfig = tlsConfig.Clone()
+			tlsConfig.ServerName = parsedURL.Hostname()
+		}
+		conn, err = tls.DialWithDialer(dialer, "tcp", host, tlsConfig)
+	default:
+		return nil, fmt.Errorf("unsupported url scheme %q; must be one of 'ldap' or 'ldaps'", parsedURL.Scheme)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	return &Conn{
+		conn:    conn,
+		reader:  bufio.NewReader(conn),
+		timeout: defaultTimeout,
+	}, nil
+}
+
+// Close unbinds and closes the connection.
+func (c *Conn) Close() error {
+	_ = c.send(encode(opUnbindRequest, nil))
+	return c.conn.Close()
+}
+
+func (c *Conn) send(op []byte) error {
+	c.messageID++
+
+	message := encodeConstructed(tagSequence, encodeInteger(tagInteger, c.messageID), op)
+
+	_ = c.conn.SetDeadline(time.Now().Add(c.timeout))
+	_, err := c.conn.Write(message)
+	return err
+}
+
+// receive reads the next message for the current request and returns its protocol operation.
+func (c *Conn) receive() (*element, error) {
+	for {
+		_ = c.conn.SetDeadline(time.Now().Add(c.timeout))
+
+		message, err := readElement(c.reader)
+		if err != nil {
+			return nil, err
+		}
+
+		parts, err := message.children()
+		if err != nil {
+			return nil, err
+		}
+
+		if len(parts) < 2 || parts[0].tag != tagInteger {
+			return nil, fmt.Errorf("malformed message")
+		}
+
+		id, err := decodeInteger(parts[0].content)
+		if err != nil {
+			return nil, err
+		}
+
+		// Unsolicited notifications(ex. notice of disconnection) use message ID 0.
+		if id == 0 {
+			return nil, fmt.Errorf("connection closed by server")
+		}
+
+		if id != c.messageID {
+			continue
+		}
+
+		return parts[1], nil
+	}
+}
+
+// checkResult returns an error if the LDAPResult within the given operation is not a success.
+func checkResult(op *element) error {
+	parts, err := op.children()
+	if err != nil {
+		return err
+	}
+
+	if len(parts) < 3 || parts[0].tag != tagEnumerated {
+		return fmt.Errorf("malformed result")
+	}
+
+	code, err := decodeInteger(parts[0].content)
+	if err != nil {
+		return err
+	}
+
+	if code != resultCodeSuccess {
+		return &ResultError{Code: code, Message: string(parts[2].content)}
+	}
+
+	return nil
+}
+
+// ResultError is returned when the server responds with a non-success result code.
+type ResultError struct {
+	Code    int64
+	Message string
+}
+
+func (e *ResultError) Error() string {
+	if e.Message == "" {
+		return fmt.Sprintf("ldap result code %d", e.Code)
+	}
+
+	return fmt.Sprintf("ldap result code %d: %s", e.Code, e.Message)
+}
+
+// Bind authenticates the connection using a simple bind.
+func (c *Conn) Bind(dn, password string) error {
+	err := c.send(encodeConstructed(opBindRequest,
+		encodeInteger(tagInteger, protocolVersion),
+		encodeString(tagOctetString, dn),
+		encodeString(authSimple, password),
+	))
+	if err != nil {
+		return err
+	}
+
+	op, err := c.receive()
+	if err != nil {
+		return err
+	}
+
+	if op.tag != opBindResponse {
+		return fmt.Errorf("unexpected response to bind request")
+	}
+
+	return checkResult(op)
+}
+
+// Search returns all entries below the base DN that match the filter. Only the given attributes are returned.
+func (c *Conn) Search(baseDN, filter string, attributes []string) ([]*Entry, error) {
+	compiledFilter, err := compileFilter(filter)
+	if err != nil {
+		return nil, err
+	}
+
+	encodedAttributes := [][]byte{}
+	for _, attribute := range attributes {
+		encodedAttributes = append(encodedAttributes, encodeString(tagOctetString, attribute))
+	}
+
+	err = c.send(encodeConstructed(opSearchRequest,
+		encodeString(tagOctetString, baseDN),
+		encodeInteger(tagEnumerated, scopeWholeSubtree),
+		encodeInteger(tagEnumerated, derefAliasesNever),
+		encodeInteger(tagInteger, 0), // size limit
+		encodeInteger(tagInteger, 0), // time limit
+		encodeBoolean(false),         // types only
+		compiledFilter,
+		encodeConstructed(tagSequence, encodedAttributes...),
+	))
+	if err != nil {
+		return nil, err
+	}
+
+	entries := []*Entry{}
+	for {
+		op, err := c.receive()
+		if err != nil {
+			return nil, err
+		}
+
+		switch op.tag {
+		case opSearchEntry:
+			entry, err := parseEntry(op)
+			if err != nil {
+				return nil, err
+			}
+			entries = append(entries, entry)
+		case opSearchReference:
+			continue
+		case opSearchDone:
+			err := checkResult(op)
+			if err != nil {
+				return nil, err
+			}
+			return entries, nil
+		default:
+			return nil, fmt.Errorf("unexpected response to search request")
+		}
+	}
+}
+
+func parseEntry(op *element) (*Entry, error) {
+	parts, err := op.children()
+	if err != nil {
+		return nil, err
+	}
+
+	if len(parts) != 2 {
+		return nil, fmt.Errorf("malformed search entry")
+	}
+
+	entry := &Entry{
+		DN:         string(parts[0].content),
+		Attributes: map[string][]string{},
+	}
+
+	attributes, err := parts[1].children()
+	if err != nil {
+		return nil, err
+	}
+
+	for _, attribute := range attributes {
+		attributeParts, err := attribute.children()
+		if err != nil {
+			return nil, err
+		}
+
+		if len(attributeParts) != 2 {
+			return nil, fmt.Errorf("malformed search entry attribute")
+		}
+
+		values, err := attributeParts[1].children()
+		if err != nil {
+			return nil, err
+		}
+
+		name := string(attributeParts[0].content)
+		for _, value := range values {
+			entry.Attributes[name] = append(entry.Attributes[name], string(value.content))
+		}
+	}
+
+	return entry, nil
+}
@@ -0,0 +1,78 @@
+package ldap
+
+import (
+	"bytes"
+	"testing"
+)
+
+func TestEncodeInteger(t *testing.T) {
+	tests := map[int64][]byte{
+		0:    {0x02, 0x01, 0x00},
+		3:    {0x02, 0x01, 0x03},
+		127:  {0x02, 0x01, 0x7f},
+		128:  {0x02, 0x02, 0x00, 0x80},
+		256:  {0x02, 0x02, 0x01, 0x00},
+		-1:   {0x02, 0x01, 0xff},
+		-129: {0x02, 0x02, 0xff, 0x7f},
+	}
+
+	for value, expected := range tests {
+		encoded := encodeInteger(tagInteger, value)
+		if !bytes.Equal(encoded, expected) {
+			t.Errorf("encodeInteger(%d) = %x; want %x", value, encoded, expected)
+		}
+
+		decoded, err := decodeInteger(encoded[2:])
+		if err != nil {
+			t.Fatal(err)
+		}
+		if decoded != value {
+			t.Errorf("decodeInteger(%x) = %d; want %d", encoded[2:], decoded, value)
+		}
+	}
+}
+
+func TestCompileFilter(t *testing.T) {
+	equality := func(attribute, value string) []byte {
+		return encodeConstructed(filterEquality, encodeString(tagOctetString, attribute), encodeString(tagOctetString, value))
+	}
+
+	tests := []struct {
+		filter   string
+		expected []byte
+	}{
+		{filter: "(uid=jdoe)", expected: equality("uid", "jdoe")},
+		{filter: "(cn=a\\2ab)", expected: equality("cn", "a*b")},
+		{filter: "(objectClass=*)", expected: encodeString(filterPresent, "objectClass")},
+		{
+			filter:   "(&(objectClass=person)(uid=jdoe))",
+			expected: encodeConstructed(filterAnd, equality("objectClass", "person"), equality("uid", "jdoe")),
+		},
+		{
+			filter:   "(|(uid=jdoe)(!(uid=admin)))",
+			expected: encodeConstructed(filterOr, equality("uid", "jdoe"), encodeConstructed(filterNot, equality("uid", "admin"))),
+		},
+		{
+			filter:   "(sAMAccountName=" + EscapeFilter("a)(uid=*") + ")",
+			expected: equality("sAMAccountName", "a)(uid=*"),
+		},
+	}
+
+	for _, test := range tests {
+		compiled, err := compileFilter(test.filter)
+		if err != nil {
+			t.Errorf("compileFilter(%q) returned error: %v", test.filter, err)
+			continue
+		}
+
+		if !bytes.Equal(compiled, test.expected) {
+			t.Errorf("compileFilter(%q) = %x; want %x", test.filter, compiled, test.expected)
+		}
+	}
+	for _, filter := range []string{"uid=jdoe", "(uid=jdoe", "(uid=jdoe))", "(uid=j*)", "(&(uid=jdoe)", "(cn=\\zz)"} {
+		_, err := compileFilter(filter)
+		if err == nil {
+			t.Errorf("expected compileFilter(%q) to return an error", filter)
+		}
+	}
+}
@@ -0,0 +1,693 @@
+// Package file stores task run logs as files within a directory on the server's disk.
+package file
+
+import (
+	"compress/gzip"
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+
+	logstore "github.com/clintjedwards/gofer/internal/logStore"
+	"github.com/klauspost/compress/zstd"
+	"github.com/nxadm/tail"
+)
+
+// settleTime is how long a finished log is left alone before it can be removed to stay within the budget. This keeps
+// logs from being removed out from under their compression.
+const settleTime = time.Minute
+
+// segmentSuffix marks the segments a log has been rotated into: <key>.part1, <key>.part2, and so on.
+const segmentSuffix = ".part"
+
+// compression is a format finished logs can be compressed with.
+type compression struct {
+	extension string
+	newWriter func(w io.Writer) (io.WriteCloser, error)
+	newReader func(r io.Reader) (io.ReadCloser, error)
+}
+
+var compressions = map[string]compression{
+	"zstd": {
+		extension: ".zst",
+		newWriter: func(w io.Writer) (io.WriteCloser, error) { return zstd.NewWriter(w) },
+		newReader: func(r io.Reader) (io.ReadCloser, error) {
+			decoder, err := zstd.NewReader(r)
+			if err != nil {
+				return nil, err
+			}
+			return decoder.IOReadCloser(), nil
+		},
+	},
+	"gzip": {
+		extension: ".gz",
+		newWriter: func(w io.Writer) (io.WriteCloser, error) { return gzip.NewWriter(w), nil },
+		newReader: func(r io.Reader) (io.ReadCloser, error) { return gzip.NewReader(r) },
+	},
+}
+
+// compressionFormats is the order compressed copies of a log are looked for in. Logs compressed in any of these
+// formats can be read no matter which format the store is currently configured with.
+var compressionFormats = []string{"zstd", "gzip"}
+
+// Options controls how a Store writes logs.
+type Options struct {
+	// Compress compresses logs once they're finished and segments once they've been rotated.
+	Compress bool
+
+	// CompressionFormat is the format used to compress logs; one of "zstd" or "gzip". Defaults to "zstd".
+	CompressionFormat string
+
+	// RotateSize is the size in bytes at which the log being written is moved into a segment of its own and
+	// continued in a new file. 0 means logs are never rotated.
+	RotateSize int64
+}
+
+// Store keeps each log as a file named after its key. Alongside each log is an index file(<key>.index) which holds
+// its checkpoints. Large logs can be rotated into segments(<key>.part<n>) as they're written and finished logs and
+// segments can be compressed with zstd(.zst) or gzip(.gz); they're stitched back together and decompressed
+// transparently when read.
+type Store struct {
+	dir         string
+	compress    bool
+	compression compression
+	rotateSize  int64
+
+	mu      sync.Mutex
+	writing map[string]struct{} // Keys of the logs currently being written by this process.
+}
+
+func New(dir string, options Options) (*Store, error) {
+	info, err := os.Stat(dir)
+	if err != nil {
+		return nil, fmt.Errorf("could not access log directory %q: %w", dir, err)
+	}
+
+	if !info.IsDir() {
+		return nil, fmt.Errorf("log directory %q is not a directory", dir)
+	}
+
+	if options.CompressionFormat == "" {
+		options.CompressionFormat = "zstd"
+	}
+
+	format, exists := compressions[options.CompressionFormat]
+	if !exists {
+		return nil, fmt.Errorf("compression format %q not supported", options.CompressionFormat)
+	}
+
+	return &Store{
+		dir:         dir,
+		compress:    options.Compress,
+		compression: format,
+		rotateSize:  options.RotateSize,
+		writing:     map[string]struct{}{},
+	}, nil
+}
+
+func (store *Store) path(key string) string {
+	return filepath.Join(store.dir, key)
+}
+
+func (store *Store) segmentPath(key string, segment int) string {
+	return fmt.Sprintf("%s%s%d", store.path(key), segmentSuffix, segment)
+}
+
+func (store *Store) indexPath(key string) string {
+	return store.path(key) + ".index"
+}
+
+// findCompressed returns the compressed copy of the file at path, in whichever supported format it was compressed.
+func findCompressed(path string) (string, bool) {
+	for _, format := range compressionFormats {
+		compressedPath := path + compressions[format].extension
+		if _, err := os.Stat(compressedPath); err == nil {
+			return compressedPath, true
+		}
+	}
+
+	return "", false
+}
+
+// findStored returns where the file at path is currently kept; a compressed copy is preferred since the original is
+// only removed after the copy is complete.
+func findStored(path string) (string, bool) {
+	if compressedPath, exists := findCompressed(path); exists {
+		return compressedPath, true
+	}
+
+	if _, err := os.Stat(path); err == nil {
+		return path, true
+	}
+
+	return "", false
+}
+
+func (store *Store) isCompressed(key string) bool {
+	_, compressed := findCompressed(store.path(key))
+	return compressed
+}
+
+// segments returns the segments the log has been rotated into, oldest first.
+func (store *Store) segments(key string) []string {
+	segments := []string{}
+	for segment := 1; ; segment++ {
+		path := store.segmentPath(key, segment)
+		if _, exists := findStored(path); !exists {
+			return segments
+		}
+
+		segments = append(segments, path)
+	}
+}
+
+// files returns every file currently making up the log, not including its index.
+func (store *Store) files(key string) []string {
+	files := []string{}
+	for _, path := range append(store.segments(key), store.path(key)) {
+		if compressedPath, exists := findCompressed(path); exists {
+			files = append(files, compressedPath)
+		}
+		if _, err := os.Stat(path); err == nil {
+			files = append(files, path)
+		}
+	}
+
+	return files
+}
+
+type writer struct {
+	store    *Store
+	key      string
+	file     *os.File
+	index    *os.File
+	size     int64 // Bytes written to the current file since the last rotation.
+	segments int   // Segments rotated out so far.
+}
+
+func (store *Store) Create(key string) (logstore.Writer, error) {
+	// Compressed copies and segments of a previous log would otherwise be read along with the new one.
+	for _, path := range store.files(key) {
+		_ = os.Remove(path)
+	}
+
+	file, err := os.Create(store.path(key))
+	if err != nil {
+		return nil, err
+	}
+
+	// The index only powers seeking through logs by time, so if we can't create it we simply continue on without.
+	index, err := os.Create(store.indexPath(key))
+	if err != nil {
+		index = nil
+	}
+
+	store.mu.Lock()
+	store.writing[key] = struct{}{}
+	store.mu.Unlock()
+
+	return &writer{
+		store: store,
+		key:   key,
+		file:  file,
+		index: index,
+	}, nil
+}
+
+func (w *writer) Write(p []byte) (int, error) {
+	if w.store.rotateSize > 0 && w.size > 0 && w.size+int64(len(p)) > w.store.rotateSize {
+		err := w.rotate()
+		if err != nil {
+			return 0, err
+		}
+	}
+
+	n, err := w.file.Write(p)
+	w.size += int64(n)
+	return n, err
+}
+
+// rotate moves everything written since the last rotation into the next segment and continues the log in a new file.
+// Rotation happens between writes so lines are never split across segments.
+func (w *writer) rotate() error {
+	err := w.file.Close()
+	if err != nil {
+		return err
+	}
+
+	w.segments++
+	segmentPath := w.store.segmentPath(w.key, w.segments)
+
+	err = os.Rename(w.store.path(w.key), segmentPath)
+	if err != nil {
+		return err
+	}
+
+	file, err := os.Create(w.store.path(w.key))
+	if err != nil {
+		return err
+	}
+
+	w.file = file
+	w.size = 0
+
+	if w.store.compress {
+		// A segment that couldn't be compressed is still read just fine, so the log carries on regardless.
+		_ = w.store.compressFile(segmentPath)
+	}
+
+	return nil
+}
+
+func (w *writer) Checkpoint(at time.Time, line int64) error {
+	if w.index == nil {
+		return nil
+	}
+
+	_, err := fmt.Fprintf(w.index, "%d %d\n", at.UnixMilli(), line)
+	return err
+}
+
+func (w *writer) Close() error {
+	defer func() {
+		w.store.mu.Lock()
+		delete(w.store.writing, w.key)
+		w.store.mu.Unlock()
+	}()
+
+	if w.index != nil {
+		_ = w.index.Close()
+	}
+
+	err := w.file.Close()
+	if err != nil {
+		return err
+	}
+
+	if !w.store.compress {
+		return nil
+	}
+
+	return w.store.compressFile(w.store.path(w.key))
+}
+
+// compressFile replaces a finished log or segment with a compressed copy. The copy is written under a temporary name
+// and renamed into place so that readers only ever see the original or the complete copy.
+func (store *Store) compressFile(path string) error {
+	compressedPath := path + store.compression.extension
+	tmpPath := compressedPath + ".tmp"
+
+	src, err := os.Open(path)
+	if err != nil {
+		return err
+	}
+	defer src.Close()
+
+	dst, err := os.Create(tmpPath)
+	if err != nil {
+		return err
+	}
+
+	encoder, err := store.compression.newWriter(dst)
+	if err != nil {
+		dst.Close()
+		_ = os.Remove(tmpPath)
+		return err
+	}
+
+	_, err = io.Copy(encoder, src)
+	if err == nil {
+		err = encoder.Close()
+	}
+	if closeErr := dst.Close(); err == nil {
+		err = closeErr
+	}
+	if err != nil {
+		_ = os.Remove(tmpPath)
+		return err
+	}
+
+	err = os.Rename(tmpPath, compressedPath)
+	if err != nil {
+		_ = os.Remove(tmpPath)
+		return err
+	}
+
+	return os.Remove(path)
+}
+
+// compressedFile closes both the decompressor and the underlying file.
+type compressedFile struct {
+	io.ReadCloser
+	file *os.File
+}
+
+func (f *compressedFile) Close() error {
+	_ = f.ReadCloser.Close()
+	return f.file.Close()
+}
+
+// openStored opens the file at path, or the compressed copy that has taken its place, decompressing it as it's read.
+func openStored(path string) (io.ReadCloser, error) {
+	storedPath, exists := findStored(path)
+	if !exists {
+		return nil, logstore.ErrEntityNotFound
+	}
+
+	file, err := os.Open(storedPath)
+	if err != nil {
+		if errors.Is(err, os.ErrNotExist) {
+			return nil, logstore.ErrEntityNotFound
+		}
+		return nil, err
+	}
+
+	for _, format := range compressionFormats {
+		compression := compressions[format]
+		if !strings.HasSuffix(storedPath, compression.extension) {
+			continue
+		}
+
+		decompressor, err := compression.newReader(file)
+		if err != nil {
+			file.Close()
+			return nil, err
+		}
+
+		return &compressedFile{ReadCloser: decompressor, file: file}, nil
+	}
+
+	return file, nil
+}
+
+// segmentedFile reads the segments of a log one after the other, only keeping the one being read open, and then the
+// rest of the log.
+type segmentedFile struct {
+	segments []string
+	current  io.ReadCloser
+	rest     io.ReadCloser
+}
+
+func (f *segmentedFile) Read(p []byte) (int, error) {
+	for {
+		if f.current == nil {
+			switch {
+			case len(f.segments) > 0:
+				segment, err := openStored(f.segments[0])
+				if err != nil {
+					return 0, err
+				}
+				f.current, f.segments = segment, f.segments[1:]
+			case f.rest != nil:
+				f.current, f.rest = f.rest, nil
+			default:
+				return 0, io.EOF
+			}
+		}
+
+		n, err := f.current.Read(p)
+		if errors.Is(err, io.EOF) && (len(f.segments) > 0 || f.rest != nil) {
+			_ = f.current.Close()
+			f.current = nil
+			if n == 0 {
+				continue
+			}
+			err = nil
+		}
+
+		return n, err
+	}
+}
+
+func (f *segmentedFile) Close() error {
+	f.segments = nil
+
+	var err error
+	for _, file := range []io.ReadCloser{f.current, f.rest} {
+		if file == nil {
+			continue
+		}
+		if closeErr := file.Close(); err == nil {
+			err = closeErr
+		}
+	}
+
+	return err
+}
+
+// followedFile turns the lines of a followed file back into a reader.
+type followedFile struct {
+	*io.PipeReader
+	tail *tail.Tail
+}
+
+func (f *followedFile) Close() error {
+	_ = f.PipeReader.Close()
+	return f.tail.Stop()
+}
+
+func (store *Store) Open(ctx context.Context, key string, follow bool) (io.ReadCloser, error) {
+	segments := store.segments(key)
+
+	var rest io.ReadCloser
+	var err error
+
+	// Compressed logs are always finished so there is nothing to follow.
+	if !follow || store.isCompressed(key) {
+		rest, err = openStored(store.path(key))
+	} else {
+		rest, err = store.follow(ctx, key)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	if len(segments) == 0 {
+		return rest, nil
+	}
+
+	return &segmentedFile{segments: segments, rest: rest}, nil
+}
+
+// follow reads the log being written, waiting for more content to be written once it reaches the end.
+func (store *Store) follow(ctx context.Context, key string) (io.ReadCloser, error) {
+	file, err := tail.TailFile(store.path(key), tail.Config{
+		Follow: true,
+		// Rotation moves the file being followed aside and continues the log in a new file of the same name.
+		ReOpen: store.rotateSize > 0,
+		Logger: tail.DiscardingLogger,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	reader, writer := io.Pipe()
+	go func() {
+		for {
+			select {
+			case <-ctx.Done():
+				_ = writer.Close()
+				return
+			case line, ok := <-file.Lines:
+				if !ok {
+					_ = writer.CloseWithError(file.Err())
+					return
+				}
+
+				// Writing only fails once the reader has been closed.
+				_, err := writer.Write([]byte(line.Text + "\n"))
+				if err != nil {
+					return
+				}
+			}
+		}
+	}()
+
+	return &followedFile{PipeReader: reader, tail: file}, nil
+}
+
+func (store *Store) LineSince(key string, since int64) (int64, error) {
+	file, err := os.Open(store.indexPath(key))
+	if err != nil {
+		if errors.Is(err, os.ErrNotExist) {
+			return 1, nil
+		}
+		return 0, err
+	}
+	defer file.Close()
+
+	return logstore.FindLineSince(file, since)
+}
+
+func (store *Store) Checkpoints(key string) (io.ReadCloser, error) {
+	file, err := os.Open(store.indexPath(key))
+	if err != nil {
+		if errors.Is(err, os.ErrNotExist) {
+			return nil, logstore.ErrEntityNotFound
+		}
+		return nil, err
+	}
+
+	return file, nil
+}
+
+func (store *Store) Exists(key string) (bool, error) {
+	_, exists := findStored(store.path(key))
+	return exists, nil
+}
+
+func (store *Store) Delete(key string) error {
+	files := store.files(key)
+	_ = os.Remove(store.indexPath(key))
+
+	if len(files) == 0 {
+		return logstore.ErrEntityNotFound
+	}
+
+	for _, path := range files {
+		err := os.Remove(path)
+		if err != nil && !errors.Is(err, os.ErrNotExist) {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// logFile is a log found within the log directory.
+type logFile struct {
+	paths     []string // The log itself along with any segments it was rotated into.
+	indexPath string
+	size      int64 // Combined size of the log, its segments and index file.
+	modified  time.Time
+	finished  bool
+}
+
+// lookupStored finds the file with the given name, or the compressed copy that has taken its place, within files.
+func lookupStored(files map[string]os.FileInfo, name string) (info os.FileInfo, compressed, exists bool) {
+	for _, format := range compressionFormats {
+		compressedInfo, found := files[name+compressions[format].extension]
+		if found {
+			return compressedInfo, true, true
+		}
+	}
+
+	info, exists = files[name]
+	return info, false, exists
+}
+
+// list returns the logs within the log directory. The directory may be shared with other programs(the default is /tmp)
+// so only files accompanied by an index file are considered logs.
+func (store *Store) list() ([]logFile, error) {
+	entries, err := os.ReadDir(store.dir)
+	if err != nil {
+		return nil, err
+	}
+
+	files := map[string]os.FileInfo{}
+	for _, entry := range entries {
+		if entry.IsDir() {
+			continue
+		}
+
+		info, err := entry.Info()
+		if err != nil {
+			continue
+		}
+
+		files[entry.Name()] = info
+	}
+
+	store.mu.Lock()
+	defer store.mu.Unlock()
+
+	logFiles := []logFile{}
+	for name, indexInfo := range files {
+		if !strings.HasSuffix(name, ".index") {
+			continue
+		}
+
+		key := strings.TrimSuffix(name, ".index")
+
+		info, compressed, exists := lookupStored(files, key)
+		if !exists {
+			continue
+		}
+
+		paths := []string{filepath.Join(store.dir, info.Name())}
+		size := info.Size() + indexInfo.Size()
+
+		for segment := 1; ; segment++ {
+			segmentInfo, _, exists := lookupStored(files, fmt.Sprintf("%s%s%d", key, segmentSuffix, segment))
+			if !exists {
+				break
+			}
+
+			paths = append(paths, filepath.Join(store.dir, segmentInfo.Name()))
+			size += segmentInfo.Size()
+		}
+
+		// With compression on, uncompressed logs are either still being written or will be compressed once they're
+		// done. Without it we can only tell which logs this process is still writing.
+		_, writing := store.writing[key]
+		finished := compressed || (!store.compress && !writing)
+
+		logFiles = append(logFiles, logFile{
+			paths:     paths,
+			indexPath: filepath.Join(store.dir, name),
+			size:      size,
+			modified:  info.ModTime(),
+			finished:  finished,
+		})
+	}
+
+	return logFiles, nil
+}
+
+func (store *Store) EnforceBudget(budget int64) (logstore.Usage, error) {
+	logFiles, err := store.list()
+	if err != nil {
+		return logstore.Usage{}, err
+	}
+
+	usage := logstore.Usage{}
+	for _, logFile := range logFiles {
+		usage.Used += logFile.size
+	}
+
+	if usage.Used <= budget {
+		return usage, nil
+	}
+
+	sort.Slice(logFiles, func(i, j int) bool { return logFiles[i].modified.Before(logFiles[j].modified) })
+
+	for _, logFile := range logFiles {
+		if usage.Used <= budget {
+			break
+		}
+
+		if !logFile.finished || time.Since(logFile.modified) < settleTime {
+			continue
+		}
+
+		for _, path := range logFile.paths {
+			err := os.Remove(path)
+			if err != nil {
+				return usage, fmt.Errorf("could not remove log %q: %w", path, err)
+			}
+		}
+		_ = os.Remove(logFile.indexPath)
+
+		usage.Used -= logFile.size
+		usage.Freed += logFile.size
+		usage.Removed++
+	}
+
+	return usage, nil
+}
@@ -0,0 +1,201 @@
+package file
+
+import (
+	"context"
+	"io"
+	"strings"
+	"testing"
+	"time"
+
+	logstore "github.com/clintjedwards/gofer/internal/logStore"
+)
+
+func TestCompressedLog(t *testing.T) {
+	store, err := New(t.TempDir(), Options{Compress: true})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	writer, err := store.Create("simple_1_build")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	content := "line one\nline two\n"
+
+	_, err = io.WriteString(writer, content)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	checkpoint := time.UnixMilli(1000)
+	err = writer.Checkpoint(checkpoint, 2)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = writer.Close()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if !store.isCompressed("simple_1_build") {
+		t.Fatal("expected log to be compressed")
+	}
+
+	file, err := store.Open(context.Background(), "simple_1_build", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer file.Close()
+
+	got, err := io.ReadAll(file)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if string(got) != content {
+		t.Errorf("unexpected log content; want %q got %q", content, got)
+	}
+
+	line, err := store.LineSince("simple_1_build", checkpoint.UnixMilli())
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if line != 2 {
+		t.Errorf("expected line 2; got %d", line)
+	}
+
+	logFiles, err := store.list()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if len(logFiles) != 1 || !logFiles[0].finished {
+		t.Errorf("expected a single finished log file; got %+v", logFiles)
+	}
+}
+
+func TestRotatedLog(t *testing.T) {
+	store, err := New(t.TempDir(), Options{Compress: true, CompressionFormat: "gzip", RotateSize: 10})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	writer, err := store.Create("simple_1_build")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	lines := []string{"line one\n", "line two\n", "line three\n"}
+	for _, line := range lines {
+		_, err = io.WriteString(writer, line)
+		if err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	segments := store.segments("simple_1_build")
+	if len(segments) != 2 {
+		t.Fatalf("expected log to be rotated into 2 segments; got %v", segments)
+	}
+
+	if _, compressed := findCompressed(segments[0]); !compressed {
+		t.Errorf("expected rotated segment %q to be compressed", segments[0])
+	}
+
+	err = writer.Close()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	file, err := store.Open(context.Background(), "simple_1_build", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer file.Close()
+
+	got, err := io.ReadAll(file)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	want := strings.Join(lines, "")
+	if string(got) != want {
+		t.Errorf("unexpected log content; want %q got %q", want, got)
+	}
+
+	err = store.Delete("simple_1_build")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if files := store.files("simple_1_build"); len(files) != 0 {
+		t.Errorf("expected all segments to be removed; found %v", files)
+	}
+}
+
+func TestCopyLog(t *testing.T) {
+	source, err := New(t.TempDir(), Options{Compress: true})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	destination, err := New(t.TempDir(), Options{})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	writer, err := source.Create("simple_1_build")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	content := "line one\nline two\n"
+
+	_, err = io.WriteString(writer, content)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	checkpoint := time.UnixMilli(1000)
+	err = writer.Checkpoint(checkpoint, 2)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = writer.Close()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = logstore.Copy(source, destination, "simple_1_build")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	file, err := destination.Open(context.Background(), "simple_1_build", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer file.Close()
+
+	got, err := io.ReadAll(file)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if string(got) != content {
+		t.Errorf("unexpected log content; want %q got %q", content, got)
+	}
+
+	line, err := destination.LineSince("simple_1_build", checkpoint.UnixMilli())
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if line != 2 {
+		t.Errorf("expected checkpoints to be copied along with the log; expected line 2 got %d", line)
+	}
+}
@@ -0,0 +1,179 @@
+// Package logStore defines the interface for storing the logs of task runs. Logs are written once, while the task run
+// is in progress, and can be read(and followed) at the same time.
+package logStore
+
+import (
+	"bufio"
+	"context"
+	"errors"
+	"io"
+	"strconv"
+	"strings"
+	"time"
+)
+
+type EngineType string
+
+const (
+	// EngineFile keeps logs as files within a directory on the server's disk.
+	EngineFile EngineType = "file"
+
+	// EngineObjectStore keeps logs within Gofer's object store so that they can be shared between multiple instances
+	// of Gofer.
+	EngineObjectStore EngineType = "object_store"
+)
+
+// ErrEntityNotFound is returned when a certain entity could not be located.
+var ErrEntityNotFound = errors.New("logstore: entity not found")
+
+// Writer writes a single log.
+type Writer interface {
+	io.Writer
+
+	// Checkpoint records which line(starting at 1) was being written at a certain time so that readers are able to
+	// seek through logs by time.
+	Checkpoint(at time.Time, line int64) error
+
+	// Close marks the log as finished. Nothing can be written after.
+	Close() error
+}
+
+type Engine interface {
+	// Create starts a new log under the given key, replacing any log previously stored under it.
+	Create(key string) (Writer, error)
+
+	// Open returns the content of a log. When follow is set the reader waits for more content to be written instead
+	// of ending at the current end of the log. It ends once the context is cancelled.
+	Open(ctx context.Context, key string, follow bool) (io.ReadCloser, error)
+
+	// LineSince returns the first line(starting at 1) written at or after the given epoch millisecond time. It returns
+	// 0 if no lines have been written since and 1 if the log has no checkpoints to go by.
+	LineSince(key string, since int64) (int64, error)
+
+	// Exists reports whether there is a log stored under the key.
+	Exists(key string) (bool, error)
+
+	Delete(key string) error
+}
+
+// Usage describes how much space an engine's logs take up and what was removed to stay within a budget.
+type Usage struct {
+	Used    int64 // Bytes used by all logs after any were removed.
+	Removed int   // The number of logs removed.
+	Freed   int64 // Bytes freed by removing logs.
+}
+
+// Budgeted is implemented by engines that keep logs on the server's own disk. It allows Gofer to keep the space used by
+// logs within a limit.
+type Budgeted interface {
+	// EnforceBudget removes the logs that finished longest ago until all logs fit within the budget in bytes. Logs
+	// still being written count towards the budget but are never removed.
+	EnforceBudget(budget int64) (Usage, error)
+}
+
+// Indexed is implemented by engines that can hand back the checkpoints of a log so that they can be copied along with
+// it.
+type Indexed interface {
+	// Checkpoints returns the checkpoints of a log as "<epoch_milli> <line_number>" pairs, one per line.
+	Checkpoints(key string) (io.ReadCloser, error)
+}
+
+// Copy copies a finished log from one engine to another, along with its checkpoints if the source engine is Indexed.
+// Any log already stored under the key in the destination is replaced. If the copy fails part way through, what was
+// copied is removed again.
+func Copy(source, destination Engine, key string) error {
+	content, err := source.Open(context.Background(), key, false)
+	if err != nil {
+		return err
+	}
+	defer content.Close()
+
+	writer, err := destination.Create(key)
+	if err != nil {
+		return err
+	}
+
+	err = copyLog(source, writer, content, key)
+	if closeErr := writer.Close(); err == nil {
+		err = closeErr
+	}
+	if err != nil {
+		_ = destination.Delete(key)
+		return err
+	}
+
+	return nil
+}
+
+func copyLog(source Engine, writer Writer, content io.Reader, key string) error {
+	if indexed, ok := source.(Indexed); ok {
+		checkpoints, err := indexed.Checkpoints(key)
+		if err != nil && !errors.Is(err, ErrEntityNotFound) {
+			return err
+		}
+
+		if err == nil {
+			defer checkpoints.Close()
+
+			scanner := bufio.NewScanner(checkpoints)
+			for scanner.Scan() {
+				timestamp, line, ok := parseCheckpoint(scanner.Text())
+				if !ok {
+					continue
+				}
+
+				err := writer.Checkpoint(time.UnixMilli(timestamp), line)
+				if err != nil {
+					return err
+				}
+			}
+
+			if err := scanner.Err(); err != nil {
+				return err
+			}
+		}
+	}
+
+	_, err := io.Copy(writer, content)
+	return err
+}
+
+// parseCheckpoint parses a single "<epoch_milli> <line_number>" pair.
+func parseCheckpoint(checkpoint string) (timestamp, line int64, ok bool) {
+	timestampRaw, lineRaw, found := strings.Cut(checkpoint, " ")
+	if !found {
+		return 0, 0, false
+	}
+
+	timestamp, err := strconv.ParseInt(timestampRaw, 10, 64)
+	if err != nil {
+		return 0, 0, false
+	}
+
+	line, err = strconv.ParseInt(lineRaw, 10, 64)
+	if err != nil {
+		return 0, 0, false
+	}
+
+	return timestamp, line, true
+}
+
+// FindLineSince reads checkpoints in the form of "<epoch_milli> <line_number>" pairs and returns the first line
+// written at or after the given time. It returns 0 if no lines have been written since.
+func FindLineSince(checkpoints io.Reader, since int64) (int64, error) {
+	scanner := bufio.NewScanner(checkpoints)
+	for scanner.Scan() {
+		timestamp, line, ok := parseCheckpoint(scanner.Text())
+		if !ok || timestamp < since {
+			continue
+		}
+
+		return line, nil
+	}
+
+	if err := scanner.Err(); err != nil {
+		return 0, err
+	}
+
+	return 0, nil
+}
@@ -0,0 +1,310 @@
+// Package object stores task run logs within Gofer's object store. Since the object store is shared, this allows
+// logs to be read from any instance of Gofer, not just the one running the task.
+package object
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"sync"
+	"time"
+
+	logstore "github.com/clintjedwards/gofer/internal/logStore"
+	objectstore "github.com/clintjedwards/gofer/internal/objectStore"
+)
+
+// KeyPrefix is the prefix of every object used to store logs. Object keys otherwise start with a namespace ID, which
+// can never contain a slash.
+const KeyPrefix = "task_run_logs/"
+
+const (
+	// chunkSize is the amount of log buffered before it's written to the object store as a chunk.
+	chunkSize = 256 * 1024
+
+	// flushInterval is how often logs that haven't filled a chunk are written anyway so that readers don't have to
+	// wait on quiet tasks.
+	flushInterval = time.Second
+
+	// pollInterval is how often readers following a log check for new chunks.
+	pollInterval = time.Second
+)
+
+// Store splits each log into chunks which are stored as separate objects(task_run_logs/<key>/chunk_<n>) alongside
+// an object holding its checkpoints(task_run_logs/<key>/index). A final object(task_run_logs/<key>/finished) marks
+// the log as finished.
+type Store struct {
+	objects objectstore.Engine
+}
+
+func New(objects objectstore.Engine) *Store {
+	return &Store{
+		objects: objects,
+	}
+}
+
+func prefix(key string) string {
+	return KeyPrefix + key + "/"
+}
+
+func chunkKey(key string, chunk int) string {
+	return fmt.Sprintf("%schunk_%08d", prefix(key), chunk)
+}
+
+func indexKey(key string) string {
+	return prefix(key) + "index"
+}
+
+func finishedKey(key string) string {
+	return prefix(key) + "finished"
+}
+
+type writer struct {
+	store *Store
+	key   string
+
+	mu         sync.Mutex
+	buffer     bytes.Buffer
+	index      bytes.Buffer // Checkpoints are few so the whole index is rewritten whenever it changes.
+	indexDirty bool
+	chunk      int
+	err        error // The first error encountered while flushing; once set nothing more is written.
+
+	done    chan struct{}
+	stopped chan struct{}
+}
+
+func (store *Store) Create(key string) (logstore.Writer, error) {
+	err := store.Delete(key)
+	if err != nil && !errors.Is(err, logstore.ErrEntityNotFound) {
+		return nil, fmt.Errorf("could not remove previous log: %w", err)
+	}
+
+	w := &writer{
+		store:   store,
+		key:     key,
+		done:    make(chan struct{}),
+		stopped: make(chan struct{}),
+	}
+
+	go w.flushOnSchedule()
+
+	return w, nil
+}
+
+func (w *writer) Write(p []byte) (int, error) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	if w.err != nil {
+		return 0, w.err
+	}
+
+	w.buffer.Write(p)
+
+	if w.buffer.Len() >= chunkSize {
+		w.flush()
+	}
+
+	return len(p), nil
+}
+
+func (w *writer) Checkpoint(at time.Time, line int64) error {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	fmt.Fprintf(&w.index, "%d %d\n", at.UnixMilli(), line)
+	w.indexDirty = true
+
+	return nil
+}
+
+// flush writes everything buffered to the object store. The caller must hold the lock.
+func (w *writer) flush() {
+	if w.err != nil {
+		return
+	}
+
+	if w.buffer.Len() > 0 {
+		content := append([]byte(nil), w.buffer.Bytes()...)
+		err := w.store.objects.PutObject(chunkKey(w.key, w.chunk), content, true)
+		if err != nil {
+			w.err = fmt.Errorf("could not write log chunk: %w", err)
+			return
+		}
+
+		w.chunk++
+		w.buffer.Reset()
+	}
+
+	if w.indexDirty {
+		content := append([]byte(nil), w.index.Bytes()...)
+		err := w.store.objects.PutObject(indexKey(w.key), content, true)
+		if err != nil {
+			w.err = fmt.Errorf("could not write log index: %w", err)
+			return
+		}
+
+		w.indexDirty = false
+	}
+}
+
+func (w *writer) flushOnSchedule() {
+	defer close(w.stopped)
+
+	ticker := time.NewTicker(flushInterval)
+	defer ticker.Stop()
+
+	for {
+		select {
+		case <-w.done:
+			return
+		case <-ticker.C:
+			w.mu.Lock()
+			w.flush()
+			w.mu.Unlock()
+		}
+	}
+}
+
+func (w *writer) Close() error {
+	close(w.done)
+	<-w.stopped
+
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	w.flush()
+	if w.err != nil {
+		return w.err
+	}
+
+	// The finished marker has to be written last; readers rely on every chunk existing once they see it.
+	return w.store.objects.PutObject(finishedKey(w.key), []byte{}, true)
+}
+
+type reader struct {
+	ctx    context.Context
+	store  *Store
+	key    string
+	follow bool
+
+	chunk   int
+	current []byte // What is left to be read of the current chunk.
+}
+
+func (r *reader) Read(p []byte) (int, error) {
+	for len(r.current) == 0 {
+		// We check whether the log is finished before looking for the next chunk. If it was, every chunk already
+		// exists and not finding the next one means we've reached the end.
+		finished, err := r.store.isFinished(r.key)
+		if err != nil {
+			return 0, err
+		}
+
+		content, err := r.store.objects.GetObject(chunkKey(r.key, r.chunk))
+		if err == nil {
+			r.current = content
+			r.chunk++
+			continue
+		}
+
+		if !errors.Is(err, objectstore.ErrEntityNotFound) {
+			return 0, err
+		}
+
+		if finished || !r.follow {
+			return 0, io.EOF
+		}
+
+		select {
+		case <-r.ctx.Done():
+			return 0, io.EOF
+		case <-time.After(pollInterval):
+		}
+	}
+
+	n := copy(p, r.current)
+	r.current = r.current[n:]
+
+	return n, nil
+}
+
+func (r *reader) Close() error {
+	return nil
+}
+
+func (store *Store) isFinished(key string) (bool, error) {
+	_, err := store.objects.GetObject(finishedKey(key))
+	if err != nil {
+		if errors.Is(err, objectstore.ErrEntityNotFound) {
+			return false, nil
+		}
+		return false, err
+	}
+
+	return true, nil
+}
+
+func (store *Store) Open(ctx context.Context, key string, follow bool) (io.ReadCloser, error) {
+	// A followed log might not have had anything written to it yet.
+	if !follow {
+		exists, err := store.Exists(key)
+		if err != nil {
+			return nil, err
+		}
+
+		if !exists {
+			return nil, logstore.ErrEntityNotFound
+		}
+	}
+
+	return &reader{
+		ctx:    ctx,
+		store:  store,
+		key:    key,
+		follow: follow,
+	}, nil
+}
+
+func (store *Store) LineSince(key string, since int64) (int64, error) {
+	index, err := store.objects.GetObject(indexKey(key))
+	if err != nil {
+		if errors.Is(err, objectstore.ErrEntityNotFound) {
+			return 1, nil
+		}
+		return 0, err
+	}
+
+	return logstore.FindLineSince(bytes.NewReader(index), since)
+}
+
+func (store *Store) Exists(key string) (bool, error) {
+	keys, err := store.objects.ListObjectKeys(prefix(key))
+	if err != nil {
+		return false, err
+	}
+
+	return len(keys) > 0, nil
+}
+
+func (store *Store) Delete(key string) error {
+	keys, err := store.objects.ListObjectKeys(prefix(key))
+	if err != nil {
+		return err
+	}
+
+	if len(keys) == 0 {
+		return logstore.ErrEntityNotFound
+	}
+
+	for objectKey := range keys {
+		err := store.objects.DeleteObject(objectKey)
+		if err != nil && !errors.Is(err, objectstore.ErrEntityNotFound) {
+			return err
+		}
+	}
+
+	return nil
+}
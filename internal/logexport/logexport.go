@@ -0,0 +1,316 @@
+// Package logexport ships the server's structured logs to an OTLP endpoint(ex. an OpenTelemetry collector).
+//
+// The exporter is used as an additional zerolog output. Every log line is converted into an OTLP log record and
+// records are sent in batches using OTLP over HTTP with JSON encoding
+// (https://opentelemetry.io/docs/specs/otlp/#otlphttp). Exporting never blocks logging; if the endpoint can't keep up
+// records are dropped instead.
+package logexport
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"sort"
+	"strconv"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+)
+
+// queueSize is how many records are held waiting to be sent, as a multiple of the batch size.
+const queueSize = 10
+
+// Exporter receives zerolog JSON log lines and sends them to an OTLP endpoint.
+type Exporter struct {
+	endpoint      string
+	headers       map[string]string
+	serviceName   string
+	batchSize     int
+	flushInterval time.Duration
+	client        *http.Client
+
+	records chan logRecord
+	stop    chan struct{}
+	wg      sync.WaitGroup
+
+	// failing tracks whether the last export failed so that failures are only reported once until exports recover.
+	failing bool
+}
+
+// New starts a new exporter. Close should be called on shutdown to send any remaining records.
+func New(conf *config.LogExport) *Exporter {
+	exporter := &Exporter{
+		endpoint:      conf.Endpoint,
+		headers:       conf.Headers,
+		serviceName:   conf.ServiceName,
+		batchSize:     conf.BatchSize,
+		flushInterval: conf.FlushInterval,
+		client:        &http.Client{Timeout: 10 * time.Second},
+		records:       make(chan logRecord, conf.BatchSize*queueSize),
+		stop:          make(chan struct{}),
+	}
+
+	exporter.wg.Add(1)
+	go exporter.run()
+
+	return exporter
+}
+
+// Write converts a single zerolog JSON log line into an OTLP record and queues it to be sent.
+func (e *Exporter) Write(p []byte) (int, error) {
+	record, err := newLogRecord(p, time.Now())
+	if err != nil {
+		// Lines that aren't JSON can't come from zerolog's JSON output; there's nothing meaningful to export.
+		return len(p), nil
+	}
+
+	select {
+	case e.records <- record:
+	default:
+	}
+
+	return len(p), nil
+}
+
+// Close sends the records that are still queued and stops the exporter.
+func (e *Exporter) Close() error {
+	close(e.stop)
+	e.wg.Wait()
+	return nil
+}
+
+func (e *Exporter) run() {
+	defer e.wg.Done()
+
+	ticker := time.NewTicker(e.flushInterval)
+	defer ticker.Stop()
+
+	batch := []logRecord{}
+
+	for {
+		select {
+		case record := <-e.records:
+			batch = append(batch, record)
+			if len(batch) < e.batchSize {
+				continue
+			}
+		case <-ticker.C:
+		case <-e.stop:
+			e.drain(batch)
+			return
+		}
+
+		e.export(batch)
+		batch = []logRecord{}
+	}
+}
+
+// drain sends the given batch along with every record that is still queued.
+func (e *Exporter) drain(batch []logRecord) {
+	for {
+		select {
+		case record := <-e.records:
+			batch = append(batch, record)
+			if len(batch) >= e.batchSize {
+				e.export(batch)
+				batch = []logRecord{}
+			}
+		default:
+			e.export(batch)
+			return
+		}
+	}
+}
+
+// export sends a batch of records. Failures are written to stderr rather than logged since logging them would just
+// queue more records for the failing endpoint.
+func (e *Exporter) export(batch []logRecord) {
+	if len(batch) == 0 {
+		return
+	}
+
+	err := e.send(batch)
+	if err != nil {
+		if !e.failing {
+			fmt.Fprintf(os.Stderr, "could not export logs to %s: %v\n", e.endpoint, err)
+		}
+		e.failing = true
+		return
+	}
+
+	if e.failing {
+		fmt.Fprintf(os.Stderr, "exporting logs to %s has recovered\n", e.endpoint)
+	}
+	e.failing = false
+}
+
+func (e *Exporter) send(batch []logRecord) error {
+	body, err := json.Marshal(exportLogsRequest{
+		ResourceLogs: []resourceLogs{{
+			Resource: resource{
+				Attributes: []attribute{{Key: "service.name", Value: attributeValue{StringValue: &e.serviceName}}},
+			},
+			ScopeLogs: []scopeLogs{{
+				Scope:      scope{Name: "gofer"},
+				LogRecords: batch,
+			}},
+		}},
+	})
+	if err != nil {
+		return err
+	}
+
+	request, err := http.NewRequest(http.MethodPost, e.endpoint, bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+
+	request.Header.Set("Content-Type", "application/json")
+	for key, value := range e.headers {
+		request.Header.Set(key, value)
+	}
+
+	response, err := e.client.Do(request)
+	if err != nil {
+		return err
+	}
+	defer response.Body.Close()
+
+	responseBody, _ := io.ReadAll(io.LimitReader(response.Body, 1<<16))
+
+	if response.StatusCode < 200 || response.StatusCode > 299 {
+		return fmt.Errorf("received status %d: %s", response.StatusCode, bytes.TrimSpace(responseBody))
+	}
+
+	return nil
+}
+
+// severityNumbers maps zerolog's levels to OTLP severity numbers.
+var severityNumbers = map[string]int{
+	"trace": 1,
+	"debug": 5,
+	"info":  9,
+	"warn":  13,
+	"error": 17,
+	"fatal": 21,
+	"panic": 24,
+}
+
+// newLogRecord converts a zerolog JSON log line into an OTLP log record. The level and message become the severity
+// and body of the record, all other fields become attributes.
+func newLogRecord(line []byte, observed time.Time) (logRecord, error) {
+	fields := map[string]json.RawMessage{}
+	err := json.Unmarshal(line, &fields)
+	if err != nil {
+		return logRecord{}, err
+	}
+
+	record := logRecord{
+		TimeUnixNano: strconv.FormatInt(observed.UnixNano(), 10),
+		Attributes:   []attribute{},
+	}
+
+	keys := make([]string, 0, len(fields))
+	for key := range fields {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	for _, key := range keys {
+		rawValue := fields[key]
+
+		switch key {
+		case "level":
+			var level string
+			_ = json.Unmarshal(rawValue, &level)
+			record.SeverityText = level
+			record.SeverityNumber = severityNumbers[level]
+		case "message":
+			var message string
+			_ = json.Unmarshal(rawValue, &message)
+			record.Body = attributeValue{StringValue: &message}
+		case "time":
+			// The time the record was observed is used instead since zerolog's timestamps only have second precision.
+		default:
+			record.Attributes = append(record.Attributes, attribute{Key: key, Value: newAttributeValue(rawValue)})
+		}
+	}
+
+	return record, nil
+}
+
+// newAttributeValue converts a JSON value into an OTLP attribute value. Arrays and objects are kept as JSON strings.
+func newAttributeValue(rawValue json.RawMessage) attributeValue {
+	var value interface{}
+	decoder := json.NewDecoder(bytes.NewReader(rawValue))
+	decoder.UseNumber()
+	_ = decoder.Decode(&value)
+
+	switch v := value.(type) {
+	case string:
+		return attributeValue{StringValue: &v}
+	case bool:
+		return attributeValue{BoolValue: &v}
+	case json.Number:
+		if _, err := v.Int64(); err == nil {
+			intValue := v.String()
+			return attributeValue{IntValue: &intValue}
+		}
+		if floatValue, err := v.Float64(); err == nil {
+			return attributeValue{DoubleValue: &floatValue}
+		}
+	}
+
+	stringValue := string(rawValue)
+	return attributeValue{StringValue: &stringValue}
+}
+
+// The types below mirror the JSON encoding of the OTLP ExportLogsServiceRequest message.
+
+type exportLogsRequest struct {
+	ResourceLogs []resourceLogs `json:"resourceLogs"`
+}
+
+type resourceLogs struct {
+	Resource  resource    `json:"resource"`
+	ScopeLogs []scopeLogs `json:"scopeLogs"`
+}
+
+type resource struct {
+	Attributes []attribute `json:"attributes"`
+}
+
+type scopeLogs struct {
+	Scope      scope       `json:"scope"`
+	LogRecords []logRecord `json:"logRecords"`
+}
+
+type scope struct {
+	Name string `json:"name"`
+}
+
+type logRecord struct {
+	TimeUnixNano   string         `json:"timeUnixNano"`
+	SeverityNumber int            `json:"severityNumber,omitempty"`
+	SeverityText   string         `json:"severityText,omitempty"`
+	Body           attributeValue `json:"body"`
+	Attributes     []attribute    `json:"attributes"`
+}
+
+type attribute struct {
+	Key   string         `json:"key"`
+	Value attributeValue `json:"value"`
+}
+
+// attributeValue is the OTLP AnyValue message; only one field is ever set. 64 bit integers are encoded as strings
+// in OTLP's JSON encoding.
+type attributeValue struct {
+	StringValue *string  `json:"stringValue,omitempty"`
+	BoolValue   *bool    `json:"boolValue,omitempty"`
+	IntValue    *string  `json:"intValue,omitempty"`
+	DoubleValue *float64 `json:"doubleValue,omitempty"`
+}
@@ -0,0 +1,70 @@
+package logexport
+
+import (
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/google/go-cmp/cmp"
+)
+
+func TestExport(t *testing.T) {
+	requests := make(chan exportLogsRequest, 1)
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+
+		request := exportLogsRequest{}
+		err := json.Unmarshal(body, &request)
+		if err != nil {
+			t.Error(err)
+		}
+
+		requests <- request
+	}))
+	defer server.Close()
+
+	exporter := New(&config.LogExport{
+		Endpoint:      server.URL + "/v1/logs",
+		ServiceName:   "gofer",
+		BatchSize:     10,
+		FlushInterval: time.Hour,
+	})
+
+	_, _ = exporter.Write([]byte(`{"level":"info","pipeline":"simple","run":5,"time":1000,"message":"started run"}` + "\n"))
+
+	err := exporter.Close()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	request := <-requests
+	records := request.ResourceLogs[0].ScopeLogs[0].LogRecords
+	if len(records) != 1 {
+		t.Fatalf("expected 1 record; got %d", len(records))
+	}
+
+	pipeline := "simple"
+	run := "5"
+	message := "started run"
+	want := logRecord{
+		SeverityNumber: 9,
+		SeverityText:   "info",
+		Body:           attributeValue{StringValue: &message},
+		Attributes: []attribute{
+			{Key: "pipeline", Value: attributeValue{StringValue: &pipeline}},
+			{Key: "run", Value: attributeValue{IntValue: &run}},
+		},
+	}
+
+	got := records[0]
+	got.TimeUnixNano = ""
+
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("unexpected record (-want +got):\n%s", diff)
+	}
+}
@@ -0,0 +1,159 @@
+// Package loglevel controls which log lines the server writes while it's running.
+//
+// Besides the overall level, levels can be set for individual modules. A module is the package a log line came from,
+// relative to Gofer's internal directory(ex. "scheduler/docker" or "api"), and setting a level for a module also sets
+// it for every module beneath it. This makes it possible to turn up logging for a single noisy part of the server
+// during an incident without drowning in everything else.
+package loglevel
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"strings"
+	"sync"
+
+	"github.com/rs/zerolog"
+)
+
+// Levels are the levels that can be set.
+var Levels = []string{"debug", "info", "warn", "error", "fatal", "panic"}
+
+// Parse returns the zerolog level for the given level name.
+func Parse(level string) (zerolog.Level, error) {
+	for _, name := range Levels {
+		if strings.EqualFold(level, name) {
+			return zerolog.ParseLevel(name)
+		}
+	}
+
+	return zerolog.NoLevel, fmt.Errorf("level %q not recognized; must be one of %s", level,
+		strings.Join(Levels, ", "))
+}
+
+// Filter is a zerolog writer that drops log lines below the level of the module they came from.
+type Filter struct {
+	out io.Writer
+
+	mu      sync.RWMutex
+	level   zerolog.Level
+	modules map[string]zerolog.Level
+}
+
+// NewFilter returns a filter writing to the given output at the given level. Callers should be included in log lines
+// for module levels to work; lines without a caller always use the overall level.
+func NewFilter(out io.Writer, level zerolog.Level) *Filter {
+	filter := &Filter{
+		out:     out,
+		level:   level,
+		modules: map[string]zerolog.Level{},
+	}
+
+	zerolog.SetGlobalLevel(level)
+
+	return filter
+}
+
+// Levels returns the overall level and the level of each module that has one set.
+func (f *Filter) Levels() (zerolog.Level, map[string]zerolog.Level) {
+	f.mu.RLock()
+	defer f.mu.RUnlock()
+
+	modules := make(map[string]zerolog.Level, len(f.modules))
+	for module, level := range f.modules {
+		modules[module] = level
+	}
+
+	return f.level, modules
+}
+
+// Set replaces the overall level and the levels of all modules.
+func (f *Filter) Set(level zerolog.Level, modules map[string]zerolog.Level) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	f.level = level
+	f.modules = map[string]zerolog.Level{}
+
+	// zerolog skips building lines below the global level entirely, so it has to be low enough to let through lines
+	// for the most verbose module.
+	lowest := level
+	for module, moduleLevel := range modules {
+		f.modules[strings.Trim(module, "/")] = moduleLevel
+		if moduleLevel < lowest {
+			lowest = moduleLevel
+		}
+	}
+
+	zerolog.SetGlobalLevel(lowest)
+}
+
+// Write writes lines that don't have a level.
+func (f *Filter) Write(p []byte) (int, error) {
+	return f.out.Write(p)
+}
+
+// WriteLevel writes the line if its level is at or above the level of the module it came from.
+func (f *Filter) WriteLevel(level zerolog.Level, p []byte) (int, error) {
+	f.mu.RLock()
+	threshold := f.level
+	if len(f.modules) > 0 {
+		threshold = f.moduleLevel(callerModule(p))
+	}
+	f.mu.RUnlock()
+
+	if level < threshold {
+		return len(p), nil
+	}
+
+	if writer, ok := f.out.(zerolog.LevelWriter); ok {
+		return writer.WriteLevel(level, p)
+	}
+
+	return f.out.Write(p)
+}
+
+// moduleLevel returns the level of the most specific module set that contains the given module. Must be called with
+// the lock held.
+func (f *Filter) moduleLevel(module string) zerolog.Level {
+	for module != "" {
+		if level, exists := f.modules[module]; exists {
+			return level
+		}
+
+		index := strings.LastIndex(module, "/")
+		if index == -1 {
+			break
+		}
+		module = module[:index]
+	}
+
+	return f.level
+}
+
+// callerModule returns the module of the caller in a zerolog JSON log line(ex.
+// "/src/gofer/internal/scheduler/docker/docker.go:52" becomes "scheduler/docker").
+func callerModule(line []byte) string {
+	fields := struct {
+		Caller string `json:"caller"`
+	}{}
+
+	err := json.Unmarshal(line, &fields)
+	if err != nil {
+		return ""
+	}
+
+	index := strings.LastIndex(fields.Caller, "/internal/")
+	if index == -1 {
+		return ""
+	}
+
+	path := fields.Caller[index+len("/internal/"):]
+
+	index = strings.LastIndex(path, "/")
+	if index == -1 {
+		return ""
+	}
+
+	return path[:index]
+}
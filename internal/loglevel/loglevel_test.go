@@ -0,0 +1,55 @@
+package loglevel
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/rs/zerolog"
+)
+
+func TestFilter(t *testing.T) {
+	output := &bytes.Buffer{}
+	filter := NewFilter(output, zerolog.InfoLevel)
+	filter.Set(zerolog.InfoLevel, map[string]zerolog.Level{
+		"scheduler":        zerolog.DebugLevel,
+		"scheduler/docker": zerolog.ErrorLevel,
+	})
+
+	tests := map[string]struct {
+		level   zerolog.Level
+		line    string
+		written bool
+	}{
+		"overall level": {
+			zerolog.DebugLevel, `{"caller":"/src/gofer/internal/api/runs.go:10","message":"a"}`, false,
+		},
+		"module level": {
+			zerolog.DebugLevel, `{"caller":"/src/gofer/internal/scheduler/scheduler.go:10","message":"a"}`, true,
+		},
+		"most specific module level": {
+			zerolog.WarnLevel, `{"caller":"/src/gofer/internal/scheduler/docker/docker.go:10","message":"a"}`, false,
+		},
+		"no caller": {
+			zerolog.InfoLevel, `{"message":"a"}`, true,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			output.Reset()
+
+			_, err := filter.WriteLevel(test.level, []byte(test.line))
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			if written := output.Len() > 0; written != test.written {
+				t.Errorf("expected written to be %t; got %t", test.written, written)
+			}
+		})
+	}
+
+	if zerolog.GlobalLevel() != zerolog.DebugLevel {
+		t.Errorf("expected global level to be lowered to debug; got %s", zerolog.GlobalLevel())
+	}
+}
@@ -0,0 +1,45 @@
+package models
+
+import "github.com/clintjedwards/gofer/proto"
+
+// Artifact is a file or directory collected from a task run's container once it finished. Its content is kept within
+// the object store; directories are kept as a tar archive.
+type Artifact struct {
+	ID          int64  `json:"id" storm:"id,increment"` // Unique identifier; auto-incrementing.
+	NamespaceID string `json:"namespace_id"`
+	PipelineID  string `json:"pipeline_id"`
+	RunID       int64  `json:"run_id" storm:"index"`
+	TaskRunID   string `json:"task_run_id"`
+	Name        string `json:"name"`
+	Path        string `json:"path"`         // Path within the container it was collected from.
+	ObjectKey   string `json:"object_key"`   // Key of the content within the object store.
+	Size        int64  `json:"size"`         // Size of the content in bytes.
+	Checksum    string `json:"checksum"`     // Hex encoded SHA-256 of the content.
+	ContentType string `json:"content_type"` // Ex: "application/gzip"; directories are "application/x-tar".
+	IsDir       bool   `json:"is_dir"`
+
+	// How many runs the artifact is kept for. Once the pipeline is this many runs past the artifact's run its content
+	// is removed from the object store, but the record of it is kept.
+	Expiry  int64 `json:"expiry"`
+	Expired bool  `json:"expired"`
+	Created int64 `json:"created"` // Time of collection in epoch milli.
+}
+
+func (a *Artifact) ToProto() *proto.Artifact {
+	return &proto.Artifact{
+		Id:          a.ID,
+		NamespaceId: a.NamespaceID,
+		PipelineId:  a.PipelineID,
+		RunId:       a.RunID,
+		TaskRunId:   a.TaskRunID,
+		Name:        a.Name,
+		Path:        a.Path,
+		Size:        a.Size,
+		Checksum:    a.Checksum,
+		ContentType: a.ContentType,
+		IsDir:       a.IsDir,
+		Expiry:      a.Expiry,
+		Expired:     a.Expired,
+		Created:     a.Created,
+	}
+}
@@ -0,0 +1,59 @@
+package models
+
+import "github.com/clintjedwards/gofer/proto"
+
+// AuthzDecision is an entry in the authorization audit log. One is recorded for every authenticated route call,
+// whether the caller was allowed through or not.
+type AuthzDecision struct {
+	ID        int64  `json:"id" storm:"id,increment"` // Unique identifier; auto-incrementing.
+	Caller    string `json:"caller" storm:"index"`    // The credential used. See the CallerX functions below.
+	Resource  string `json:"resource"`                // The namespace and pipeline acted on if any. Ex: "default/simple"
+	Action    string `json:"action"`                  // The verb of the route called. Ex: "delete"
+	Route     string `json:"route"`                   // The full route called. Ex: "/proto.Gofer/DeletePipeline"
+	Allowed   bool   `json:"allowed"`                 // Whether the caller was allowed to perform the action.
+	Reason    string `json:"reason"`                  // Why the caller was denied; empty if allowed.
+	Timestamp int64  `json:"timestamp" storm:"index"` // The time the call was made in epoch milli.
+}
+
+// CallerAnonymous is how a caller that didn't provide any credentials is referred to.
+const CallerAnonymous = "anonymous"
+
+// CallerToken returns how a caller using an API token is referred to. API tokens are identified by their hash since
+// the token itself is never stored.
+func CallerToken(hash string) string {
+	return "token:" + hash
+}
+
+// CallerUser returns how a caller using an API token handed out to a named user is referred to. Tokens are handed out
+// to a user when created through directory login or with a "name" metadata value.
+func CallerUser(name string) string {
+	return "user:" + name
+}
+
+// CallerOIDC returns how a caller using a single sign-on ID token is referred to.
+func CallerOIDC(subject string) string {
+	return "oidc:" + subject
+}
+
+// CallerExternalJWT returns how a caller using a JWT issued by an external identity provider is referred to.
+func CallerExternalJWT(subject string) string {
+	return "jwt:" + subject
+}
+
+// CallerCertificate returns how a caller using a client certificate is referred to.
+func CallerCertificate(san string) string {
+	return "certificate:" + san
+}
+
+func (d *AuthzDecision) ToProto() *proto.AuthzDecision {
+	return &proto.AuthzDecision{
+		Id:        d.ID,
+		Caller:    d.Caller,
+		Resource:  d.Resource,
+		Action:    d.Action,
+		Route:     d.Route,
+		Allowed:   d.Allowed,
+		Reason:    d.Reason,
+		Timestamp: d.Timestamp,
+	}
+}
@@ -0,0 +1,21 @@
+package models
+
+// Cache is a set of paths saved from a task run's container so that later task runs of the same pipeline can start
+// with them in place. Each path's content is kept within the object store as a tar archive.
+type Cache struct {
+	ID          int64       `json:"id" storm:"id,increment"` // Unique identifier; auto-incrementing.
+	NamespaceID string      `json:"namespace_id"`
+	PipelineID  string      `json:"pipeline_id"`
+	Key         string      `json:"key" storm:"index"` // The key it was saved under with the run's variables filled in.
+	Paths       []CachePath `json:"paths"`
+	Size        int64       `json:"size"`      // Total size of the content of all paths in bytes.
+	Created     int64       `json:"created"`   // Time of saving in epoch milli.
+	LastUsed    int64       `json:"last_used"` // Time it was last saved or restored in epoch milli.
+}
+
+// CachePath is a single path of a cache along with where its content is kept.
+type CachePath struct {
+	Path      string `json:"path"`
+	ObjectKey string `json:"object_key"`
+	Size      int64  `json:"size"`
+}
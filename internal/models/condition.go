@@ -0,0 +1,75 @@
+package models
+
+import (
+	"fmt"
+
+	"github.com/hashicorp/hcl/v2"
+	"github.com/hashicorp/hcl/v2/hclsyntax"
+	"github.com/zclconf/go-cty/cty"
+)
+
+// parseTaskCondition parses a task's condition. Conditions use HCL's expression syntax and may only refer to the
+// variables of a run. Ex: vars.BRANCH == "main" && vars.DEPLOY != "false"
+func parseTaskCondition(condition string) (hcl.Expression, error) {
+	expr, diags := hclsyntax.ParseExpression([]byte(condition), "when", hcl.InitialPos)
+	if diags.HasErrors() {
+		return nil, fmt.Errorf("could not parse condition %q; %v", condition, diags)
+	}
+
+	for _, traversal := range expr.Variables() {
+		if traversal.RootName() != "vars" {
+			return nil, fmt.Errorf("condition %q refers to unknown name %q; only run variables(vars.<NAME>) can be used",
+				condition, traversal.RootName())
+		}
+	}
+
+	return expr, nil
+}
+
+// EvaluateCondition reports whether the task should be run given the variables of a run. Tasks without a condition
+// are always run. Variables the run doesn't have are treated as empty strings.
+func (t *Task) EvaluateCondition(vars map[string]string) (bool, error) {
+	if t.When == "" {
+		return true, nil
+	}
+
+	expr, err := parseTaskCondition(t.When)
+	if err != nil {
+		return false, err
+	}
+
+	values := map[string]cty.Value{}
+	for key, value := range vars {
+		values[key] = cty.StringVal(value)
+	}
+
+	for _, traversal := range expr.Variables() {
+		if len(traversal) < 2 {
+			continue
+		}
+
+		attr, ok := traversal[1].(hcl.TraverseAttr)
+		if !ok {
+			continue
+		}
+
+		if _, exists := values[attr.Name]; !exists {
+			values[attr.Name] = cty.StringVal("")
+		}
+	}
+
+	result, diags := expr.Value(&hcl.EvalContext{
+		Variables: map[string]cty.Value{
+			"vars": cty.ObjectVal(values),
+		},
+	})
+	if diags.HasErrors() {
+		return false, fmt.Errorf("could not evaluate condition %q; %v", t.When, diags)
+	}
+
+	if result.IsNull() || !result.IsKnown() || result.Type() != cty.Bool {
+		return false, fmt.Errorf("condition %q must evaluate to true or false", t.When)
+	}
+
+	return result.True(), nil
+}
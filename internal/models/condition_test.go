@@ -0,0 +1,49 @@
+package models
+
+import (
+	"testing"
+)
+
+func TestTaskEvaluateCondition(t *testing.T) {
+	tests := map[string]struct {
+		when     string
+		vars     map[string]string
+		expected bool
+	}{
+		"no condition":      {when: "", vars: map[string]string{}, expected: true},
+		"matching variable": {when: `vars.BRANCH == "main"`, vars: map[string]string{"BRANCH": "main"}, expected: true},
+		"different value":   {when: `vars.BRANCH == "main"`, vars: map[string]string{"BRANCH": "dev"}, expected: false},
+		"missing variable":  {when: `vars.BRANCH == ""`, vars: map[string]string{}, expected: true},
+		"combined": {
+			when:     `vars.BRANCH == "main" && vars.DEPLOY != "false"`,
+			vars:     map[string]string{"BRANCH": "main", "DEPLOY": "false"},
+			expected: false,
+		},
+	}
+
+	for name, test := range tests {
+		t.Run(name, func(t *testing.T) {
+			task := Task{When: test.when}
+
+			result, err := task.EvaluateCondition(test.vars)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			if result != test.expected {
+				t.Errorf("expected %t; got %t", test.expected, result)
+			}
+		})
+	}
+}
+
+func TestTaskConditionValidation(t *testing.T) {
+	if _, err := parseTaskCondition(`env.BRANCH == "main"`); err == nil {
+		t.Error("expected condition referring to something other than run variables to be rejected")
+	}
+
+	task := Task{When: `vars.BRANCH`}
+	if _, err := task.EvaluateCondition(map[string]string{"BRANCH": "main"}); err == nil {
+		t.Error("expected condition that doesn't evaluate to a bool to return an error")
+	}
+}
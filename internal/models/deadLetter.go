@@ -0,0 +1,42 @@
+package models
+
+import "github.com/clintjedwards/gofer/proto"
+
+type DeadLetterKind string
+
+const (
+	DeadLetterKindUnknown             DeadLetterKind = "UNKNOWN"
+	DeadLetterKindRunNotification     DeadLetterKind = "RUN_NOTIFICATION"     // A notification policy webhook call.
+	DeadLetterKindTriggerSubscription DeadLetterKind = "TRIGGER_SUBSCRIPTION" // Restoring a pipeline's trigger subscription.
+	DeadLetterKindDigest              DeadLetterKind = "DIGEST"               // A scheduled digest webhook call.
+)
+
+// DeadLetter is a dispatch to a webhook or extension that still failed after being retried. Instead of being dropped
+// it's kept so that it can be inspected and sent again once the receiving end is fixed.
+type DeadLetter struct {
+	ID          int64          `json:"id" storm:"id,increment"` // Unique identifier; auto-incrementing.
+	Kind        DeadLetterKind `json:"kind"`
+	NamespaceID string         `json:"namespace_id"`
+	PipelineID  string         `json:"pipeline_id"` // Empty for dispatches about a whole namespace.
+	Target      string         `json:"target"`      // Where the dispatch was sent. Ex: a webhook URL or a trigger label.
+	Payload     string         `json:"payload"`     // The JSON that was sent.
+	Error       string         `json:"error"`       // The error from the most recent attempt.
+	Attempts    int64          `json:"attempts"`    // How many times the dispatch has been attempted in total.
+	Created     int64          `json:"created"`     // Time the dispatch was given up on in epoch milli.
+	Updated     int64          `json:"updated"`     // Time of the most recent attempt in epoch milli.
+}
+
+func (d *DeadLetter) ToProto() *proto.DeadLetter {
+	return &proto.DeadLetter{
+		Id:          d.ID,
+		Kind:        proto.DeadLetter_Kind(proto.DeadLetter_Kind_value[string(d.Kind)]),
+		NamespaceId: d.NamespaceID,
+		PipelineId:  d.PipelineID,
+		Target:      d.Target,
+		Payload:     d.Payload,
+		Error:       d.Error,
+		Attempts:    d.Attempts,
+		Created:     d.Created,
+		Updated:     d.Updated,
+	}
+}
@@ -0,0 +1,9 @@
+package models
+
+// EventCursor tracks how far a consumer of the event log has gotten so that it can pick up where it left off after a
+// failure or restart.
+type EventCursor struct {
+	ID      string `json:"id" storm:"id"` // Unique identifier of the consumer. Ex: "sink:event_lake"
+	EventID int64  `json:"event_id"`      // The ID of the last event the consumer processed.
+	Updated int64  `json:"updated"`       // The time the cursor was last moved in epoch milli.
+}
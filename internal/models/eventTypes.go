@@ -13,6 +13,7 @@ import "github.com/clintjedwards/gofer/proto"
 // * The API events handler (eventsHandlers.go)
 // * The Proto package (gofer_message_events.go, gofer_transport.proto)
 // * The Storage package (events.go)
+// * The SDK events package (sdk/events/events.go)
 
 type EventDisabledPipeline struct {
 	Metadata
@@ -102,6 +103,37 @@ func (e *EventAbandonedPipeline) ToProto() *proto.EventAbandonedPipeline {
 	}
 }
 
+type EventRolledBackPipeline struct {
+	Metadata
+	NamespaceID string
+	PipelineID  string
+	RunID       int64 // The run that failed the deployment's health check.
+	FromVersion int64 // The config version that was rolled back.
+	ToVersion   int64 // The config version that was deployed in its place.
+}
+
+func NewEventRolledBackPipeline(namespace, pipeline string, runID, fromVersion, toVersion int64) *EventRolledBackPipeline {
+	return &EventRolledBackPipeline{
+		Metadata:    NewMetadata(RolledBackPipelineEvent),
+		NamespaceID: namespace,
+		PipelineID:  pipeline,
+		RunID:       runID,
+		FromVersion: fromVersion,
+		ToVersion:   toVersion,
+	}
+}
+
+func (e *EventRolledBackPipeline) ToProto() *proto.EventRolledBackPipeline {
+	return &proto.EventRolledBackPipeline{
+		Metadata:    e.Metadata.ToProto(),
+		NamespaceId: e.NamespaceID,
+		PipelineId:  e.PipelineID,
+		RunId:       e.RunID,
+		FromVersion: e.FromVersion,
+		ToVersion:   e.ToVersion,
+	}
+}
+
 type EventCreatedNamespace struct {
 	Metadata
 	NamespaceID string
@@ -123,141 +155,156 @@ func (e *EventCreatedNamespace) ToProto() *proto.EventCreatedNamespace {
 
 type EventStartedRun struct {
 	Metadata
-	NamespaceID string
-	PipelineID  string
-	RunID       int64
+	NamespaceID   string
+	PipelineID    string
+	RunID         int64
+	CorrelationID string
 }
 
 func NewEventStartedRun(run Run) *EventStartedRun {
 	return &EventStartedRun{
-		Metadata:    NewMetadata(StartedRunEvent),
-		NamespaceID: run.NamespaceID,
-		PipelineID:  run.PipelineID,
-		RunID:       run.ID,
+		Metadata:      NewMetadata(StartedRunEvent),
+		NamespaceID:   run.NamespaceID,
+		PipelineID:    run.PipelineID,
+		RunID:         run.ID,
+		CorrelationID: run.CorrelationID,
 	}
 }
 
 func (e *EventStartedRun) ToProto() *proto.EventStartedRun {
 	return &proto.EventStartedRun{
-		Metadata:    e.Metadata.ToProto(),
-		NamespaceId: e.NamespaceID,
-		PipelineId:  e.PipelineID,
-		RunId:       e.RunID,
+		Metadata:      e.Metadata.ToProto(),
+		NamespaceId:   e.NamespaceID,
+		PipelineId:    e.PipelineID,
+		RunId:         e.RunID,
+		CorrelationId: e.CorrelationID,
 	}
 }
 
 type EventCompletedRun struct {
 	Metadata
-	NamespaceID string
-	PipelineID  string
-	RunID       int64
-	State       RunState
+	NamespaceID   string
+	PipelineID    string
+	RunID         int64
+	State         RunState
+	CorrelationID string
 }
 
 func NewEventCompletedRun(run Run) *EventCompletedRun {
 	return &EventCompletedRun{
-		Metadata:    NewMetadata(CompletedRunEvent),
-		NamespaceID: run.NamespaceID,
-		PipelineID:  run.PipelineID,
-		RunID:       run.ID,
-		State:       run.State,
+		Metadata:      NewMetadata(CompletedRunEvent),
+		NamespaceID:   run.NamespaceID,
+		PipelineID:    run.PipelineID,
+		RunID:         run.ID,
+		State:         run.State,
+		CorrelationID: run.CorrelationID,
 	}
 }
 
 func (e *EventCompletedRun) ToProto() *proto.EventCompletedRun {
 	return &proto.EventCompletedRun{
-		Metadata:    e.Metadata.ToProto(),
-		NamespaceId: e.NamespaceID,
-		PipelineId:  e.PipelineID,
-		RunId:       e.RunID,
-		State:       proto.EventCompletedRun_State(proto.EventCompletedRun_State_value[string(e.State)]),
+		Metadata:      e.Metadata.ToProto(),
+		NamespaceId:   e.NamespaceID,
+		PipelineId:    e.PipelineID,
+		RunId:         e.RunID,
+		State:         proto.EventCompletedRun_State(proto.EventCompletedRun_State_value[string(e.State)]),
+		CorrelationId: e.CorrelationID,
 	}
 }
 
 type EventStartedTaskRun struct {
 	Metadata
-	NamespaceID string
-	PipelineID  string
-	RunID       int64
-	TaskRunID   string
+	NamespaceID   string
+	PipelineID    string
+	RunID         int64
+	TaskRunID     string
+	CorrelationID string
 }
 
 func NewEventStartedTaskRun(taskrun TaskRun) *EventStartedTaskRun {
 	return &EventStartedTaskRun{
-		Metadata:    NewMetadata(StartedTaskRunEvent),
-		NamespaceID: taskrun.NamespaceID,
-		PipelineID:  taskrun.PipelineID,
-		RunID:       taskrun.RunID,
-		TaskRunID:   taskrun.ID,
+		Metadata:      NewMetadata(StartedTaskRunEvent),
+		NamespaceID:   taskrun.NamespaceID,
+		PipelineID:    taskrun.PipelineID,
+		RunID:         taskrun.RunID,
+		TaskRunID:     taskrun.ID,
+		CorrelationID: taskrun.CorrelationID,
 	}
 }
 
 func (e *EventStartedTaskRun) ToProto() *proto.EventStartedTaskRun {
 	return &proto.EventStartedTaskRun{
-		Metadata:    e.Metadata.ToProto(),
-		NamespaceId: e.NamespaceID,
-		PipelineId:  e.PipelineID,
-		RunId:       e.RunID,
-		TaskRunId:   e.TaskRunID,
+		Metadata:      e.Metadata.ToProto(),
+		NamespaceId:   e.NamespaceID,
+		PipelineId:    e.PipelineID,
+		RunId:         e.RunID,
+		TaskRunId:     e.TaskRunID,
+		CorrelationId: e.CorrelationID,
 	}
 }
 
 type EventScheduledTaskRun struct {
 	Metadata
-	NamespaceID string
-	PipelineID  string
-	RunID       int64
-	TaskRunID   string
+	NamespaceID   string
+	PipelineID    string
+	RunID         int64
+	TaskRunID     string
+	CorrelationID string
 }
 
 func NewEventScheduledTaskRun(taskrun TaskRun) *EventScheduledTaskRun {
 	return &EventScheduledTaskRun{
-		Metadata:    NewMetadata(ScheduledTaskRunEvent),
-		NamespaceID: taskrun.NamespaceID,
-		PipelineID:  taskrun.PipelineID,
-		RunID:       taskrun.RunID,
-		TaskRunID:   taskrun.ID,
+		Metadata:      NewMetadata(ScheduledTaskRunEvent),
+		NamespaceID:   taskrun.NamespaceID,
+		PipelineID:    taskrun.PipelineID,
+		RunID:         taskrun.RunID,
+		TaskRunID:     taskrun.ID,
+		CorrelationID: taskrun.CorrelationID,
 	}
 }
 
 func (e *EventScheduledTaskRun) ToProto() *proto.EventScheduledTaskRun {
 	return &proto.EventScheduledTaskRun{
-		Metadata:    e.Metadata.ToProto(),
-		NamespaceId: e.NamespaceID,
-		PipelineId:  e.PipelineID,
-		RunId:       e.RunID,
-		TaskRunId:   e.TaskRunID,
+		Metadata:      e.Metadata.ToProto(),
+		NamespaceId:   e.NamespaceID,
+		PipelineId:    e.PipelineID,
+		RunId:         e.RunID,
+		TaskRunId:     e.TaskRunID,
+		CorrelationId: e.CorrelationID,
 	}
 }
 
 type EventCompletedTaskRun struct {
 	Metadata
-	NamespaceID string
-	PipelineID  string
-	RunID       int64
-	TaskRunID   string
-	State       ContainerState
+	NamespaceID   string
+	PipelineID    string
+	RunID         int64
+	TaskRunID     string
+	State         ContainerState
+	CorrelationID string
 }
 
 func NewEventCompletedTaskRun(taskrun TaskRun) *EventCompletedTaskRun {
 	return &EventCompletedTaskRun{
-		Metadata:    NewMetadata(CompletedTaskRunEvent),
-		NamespaceID: taskrun.NamespaceID,
-		PipelineID:  taskrun.PipelineID,
-		RunID:       taskrun.RunID,
-		TaskRunID:   taskrun.ID,
-		State:       taskrun.State,
+		Metadata:      NewMetadata(CompletedTaskRunEvent),
+		NamespaceID:   taskrun.NamespaceID,
+		PipelineID:    taskrun.PipelineID,
+		RunID:         taskrun.RunID,
+		TaskRunID:     taskrun.ID,
+		State:         taskrun.State,
+		CorrelationID: taskrun.CorrelationID,
 	}
 }
 
 func (e *EventCompletedTaskRun) ToProto() *proto.EventCompletedTaskRun {
 	return &proto.EventCompletedTaskRun{
-		Metadata:    e.Metadata.ToProto(),
-		NamespaceId: e.NamespaceID,
-		PipelineId:  e.PipelineID,
-		RunId:       e.RunID,
-		TaskRunId:   e.TaskRunID,
-		State:       proto.EventCompletedTaskRun_State(proto.EventCompletedTaskRun_State_value[string(e.State)]),
+		Metadata:      e.Metadata.ToProto(),
+		NamespaceId:   e.NamespaceID,
+		PipelineId:    e.PipelineID,
+		RunId:         e.RunID,
+		TaskRunId:     e.TaskRunID,
+		State:         proto.EventCompletedTaskRun_State(proto.EventCompletedTaskRun_State_value[string(e.State)]),
+		CorrelationId: e.CorrelationID,
 	}
 }
 
@@ -368,3 +415,84 @@ func (e *EventResolvedTrigger) FromProto(p *proto.EventResolvedTrigger) {
 	e.Result = result
 	e.TriggerMetadata = p.TriggerMetadata
 }
+
+type EventRevokedUserTokens struct {
+	Metadata
+	User  string
+	Count int64
+}
+
+func NewEventRevokedUserTokens(user string, count int64) *EventRevokedUserTokens {
+	return &EventRevokedUserTokens{
+		Metadata: NewMetadata(RevokedUserTokensEvent),
+		User:     user,
+		Count:    count,
+	}
+}
+
+func (e *EventRevokedUserTokens) ToProto() *proto.EventRevokedUserTokens {
+	return &proto.EventRevokedUserTokens{
+		Metadata: e.Metadata.ToProto(),
+		User:     e.User,
+		Count:    e.Count,
+	}
+}
+
+type EventExpiredPipelineObject struct {
+	Metadata
+	NamespaceID string
+	PipelineID  string
+	Key         string
+}
+
+func NewEventExpiredPipelineObject(namespace, pipeline, key string) *EventExpiredPipelineObject {
+	return &EventExpiredPipelineObject{
+		Metadata:    NewMetadata(ExpiredPipelineObjectEvent),
+		NamespaceID: namespace,
+		PipelineID:  pipeline,
+		Key:         key,
+	}
+}
+
+func (e *EventExpiredPipelineObject) ToProto() *proto.EventExpiredPipelineObject {
+	return &proto.EventExpiredPipelineObject{
+		Metadata:    e.Metadata.ToProto(),
+		NamespaceId: e.NamespaceID,
+		PipelineId:  e.PipelineID,
+		Key:         e.Key,
+	}
+}
+
+type EventExtension struct {
+	Metadata
+	Extension   string
+	Name        string // The kind of the event namespaced by its extension(ex. github/pull_request_opened).
+	Summary     string
+	Details     map[string]string
+	NamespaceID string
+	PipelineID  string
+}
+
+func NewEventExtension(extension, kind, summary string, details map[string]string, namespace, pipeline string) *EventExtension {
+	return &EventExtension{
+		Metadata:    NewMetadata(ExtensionEvent),
+		Extension:   extension,
+		Name:        extension + "/" + kind,
+		Summary:     summary,
+		Details:     details,
+		NamespaceID: namespace,
+		PipelineID:  pipeline,
+	}
+}
+
+func (e *EventExtension) ToProto() *proto.EventExtension {
+	return &proto.EventExtension{
+		Metadata:    e.Metadata.ToProto(),
+		Extension:   e.Extension,
+		Name:        e.Name,
+		Summary:     e.Summary,
+		Details:     e.Details,
+		NamespaceId: e.NamespaceID,
+		PipelineId:  e.PipelineID,
+	}
+}
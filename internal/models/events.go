@@ -25,10 +25,11 @@ const (
 	CreatedNamespaceEvent EventType = "CREATED_NAMESPACE"
 
 	// Pipeline events
-	DisabledPipelineEvent  EventType = "DISABLED_PIPELINE"
-	EnabledPipelineEvent   EventType = "ENABLED_PIPELINE"
-	CreatedPipelineEvent   EventType = "CREATED_PIPELINE"
-	AbandonedPipelineEvent EventType = "ABANDONED_PIPELINE"
+	DisabledPipelineEvent   EventType = "DISABLED_PIPELINE"
+	EnabledPipelineEvent    EventType = "ENABLED_PIPELINE"
+	CreatedPipelineEvent    EventType = "CREATED_PIPELINE"
+	AbandonedPipelineEvent  EventType = "ABANDONED_PIPELINE"
+	RolledBackPipelineEvent EventType = "ROLLED_BACK_PIPELINE" // Gofer rolled back a config deployment that failed.
 
 	// Run events
 	StartedRunEvent   EventType = "STARTED_RUN"
@@ -43,6 +44,15 @@ const (
 	FiredTriggerEvent     EventType = "FIRED_TRIGGER"     // Received a new trigger event.
 	ProcessedTriggerEvent EventType = "PROCESSED_TRIGGER" // Currently processing a trigger event that was fired.
 	ResolvedTriggerEvent  EventType = "RESOLVED_TRIGGER"  // Successfully processed the trigger.
+
+	// Token events
+	RevokedUserTokensEvent EventType = "REVOKED_USER_TOKENS"
+
+	// Object events
+	ExpiredPipelineObjectEvent EventType = "EXPIRED_PIPELINE_OBJECT" // A pipeline object outlived its expiry.
+
+	// Extension events; published by extensions themselves. What happened is described by the event's own name.
+	ExtensionEvent EventType = "EXTENSION_EVENT"
 )
 
 var EventMap = map[EventType]string{
@@ -50,10 +60,11 @@ var EventMap = map[EventType]string{
 
 	CreatedNamespaceEvent: string(CreatedNamespaceEvent),
 
-	DisabledPipelineEvent:  string(DisabledPipelineEvent),
-	EnabledPipelineEvent:   string(EnabledPipelineEvent),
-	CreatedPipelineEvent:   string(CreatedPipelineEvent),
-	AbandonedPipelineEvent: string(AbandonedPipelineEvent),
+	DisabledPipelineEvent:   string(DisabledPipelineEvent),
+	EnabledPipelineEvent:    string(EnabledPipelineEvent),
+	CreatedPipelineEvent:    string(CreatedPipelineEvent),
+	AbandonedPipelineEvent:  string(AbandonedPipelineEvent),
+	RolledBackPipelineEvent: string(RolledBackPipelineEvent),
 
 	StartedRunEvent:   string(StartedRunEvent),
 	CompletedRunEvent: string(CompletedRunEvent),
@@ -65,6 +76,12 @@ var EventMap = map[EventType]string{
 	FiredTriggerEvent:     string(FiredTriggerEvent),
 	ProcessedTriggerEvent: string(ProcessedTriggerEvent),
 	ResolvedTriggerEvent:  string(ResolvedTriggerEvent),
+
+	RevokedUserTokensEvent: string(RevokedUserTokensEvent),
+
+	ExpiredPipelineObjectEvent: string(ExpiredPipelineObjectEvent),
+
+	ExtensionEvent: string(ExtensionEvent),
 }
 
 type Metadata struct {
@@ -9,11 +9,14 @@ import (
 // Namespace represents a division of pipelines. Normally it is used to divide teams or logically different
 // sections of workloads. It is the highest level unit.
 type Namespace struct {
-	ID          string `json:"id" storm:"id"` // Unique identifier; user defined.
-	Name        string `json:"name"`          // Humanized name; great for reading from UIs.
-	Description string `json:"description"`   // Short description on what name space is used for.
-	Created     int64  `json:"created"`       // The creation time in epoch milli.
-	Deleted     int64  `json:"deleted"`       // The deletion time in epoch milli; 0 if not deleted.
+	ID             string         `json:"id" storm:"id"`   // Unique identifier; user defined.
+	Name           string         `json:"name"`            // Humanized name; great for reading from UIs.
+	Description    string         `json:"description"`     // Short description on what name space is used for.
+	Created        int64          `json:"created"`         // The creation time in epoch milli.
+	Deleted        int64          `json:"deleted"`         // The deletion time in epoch milli; 0 if not deleted.
+	Quota          NamespaceQuota `json:"quota"`           // The resource limits of the namespace; zero means unlimited.
+	AllowAnonymous bool           `json:"allow_anonymous"` // Whether callers without credentials can view the namespace.
+	ObjectExpiry   int64          `json:"object_expiry"`   // Seconds until pipeline objects expire by default; 0 for never.
 	// Tokens      []string `json:"tokens"`      // List of tokens that have access to this namespace.
 }
 
@@ -31,11 +34,13 @@ func NewNamespace(id, name, description string) *Namespace {
 
 func (n *Namespace) ToProto() *proto.Namespace {
 	return &proto.Namespace{
-		Id:          n.ID,
-		Name:        n.Name,
-		Description: n.Description,
-		Created:     n.Created,
-		Deleted:     n.Deleted,
+		Id:             n.ID,
+		Name:           n.Name,
+		Description:    n.Description,
+		Created:        n.Created,
+		Deleted:        n.Deleted,
+		AllowAnonymous: n.AllowAnonymous,
+		ObjectExpiry:   n.ObjectExpiry,
 	}
 }
 
@@ -44,4 +49,34 @@ func (n *Namespace) FromProto(proto *proto.Namespace) {
 	n.Name = proto.Name
 	n.Description = proto.Description
 	n.Created = proto.Created
+	n.AllowAnonymous = proto.AllowAnonymous
+	n.ObjectExpiry = proto.ObjectExpiry
+}
+
+// NamespaceQuota represents the limits of resources a namespace can consume. A limit of zero means unlimited.
+// The same structure is also used to represent the current consumption of a namespace.
+type NamespaceQuota struct {
+	Pipelines      int64 `json:"pipelines"`       // Total amount of pipelines.
+	RunsPerDay     int64 `json:"runs_per_day"`    // Amount of runs started within the last 24 hours.
+	ConcurrentRuns int64 `json:"concurrent_runs"` // Amount of runs in progress at the same time.
+	ObjectBytes    int64 `json:"object_bytes"`    // Total size of all pipeline and run objects.
+	Secrets        int64 `json:"secrets"`         // Total amount of secrets.
+}
+
+func (q *NamespaceQuota) ToProto() *proto.NamespaceQuota {
+	return &proto.NamespaceQuota{
+		Pipelines:      q.Pipelines,
+		RunsPerDay:     q.RunsPerDay,
+		ConcurrentRuns: q.ConcurrentRuns,
+		ObjectBytes:    q.ObjectBytes,
+		Secrets:        q.Secrets,
+	}
+}
+
+func (q *NamespaceQuota) FromProto(proto *proto.NamespaceQuota) {
+	q.Pipelines = proto.Pipelines
+	q.RunsPerDay = proto.RunsPerDay
+	q.ConcurrentRuns = proto.ConcurrentRuns
+	q.ObjectBytes = proto.ObjectBytes
+	q.Secrets = proto.Secrets
 }
@@ -1,8 +1,10 @@
 package models
 
 import (
+	"fmt"
 	"time"
 
+	"github.com/clintjedwards/gofer/internal/cron"
 	"github.com/clintjedwards/gofer/proto"
 )
 
@@ -46,6 +48,362 @@ type Pipeline struct {
 	// Notifiers is a listing of the notifier labels to their notifier object counterparts in the API.
 	Notifiers map[string]PipelineNotifierConfig `json:"notifiers"`
 	Objects   []string                          `json:"objects"` // Object keys that are stored at the pipeline level.
+	// When each object that expires does so in epoch milliseconds, keyed by object key. Objects without an entry are
+	// kept until they are evicted.
+	ObjectExpiries map[string]int64 `json:"object_expiries"`
+
+	// The service account that owns this pipeline, if any. Runs started by the pipeline's triggers are attributed to
+	// it.
+	ServiceAccount string `json:"service_account"`
+
+	// Owners are the callers that are able to modify the pipeline when it is protected. Owners are referred to the same
+	// way callers are in the audit log(ex. "user:alice", "service_account:ci-runner").
+	Owners []string `json:"owners"`
+	// Protected pipelines can only have new configuration deployed or be abandoned by their owners and namespace
+	// admins.
+	Protected bool `json:"protected"`
+
+	// Which finished runs Gofer notifies about on the pipeline's behalf. Nil if the pipeline has no policy.
+	NotificationPolicy *NotificationPolicy `json:"notification_policy"`
+
+	// What happens to the pipeline's runs that were in progress when Gofer stopped unexpectedly.
+	RecoveryPolicy RecoveryPolicy `json:"recovery_policy"`
+
+	// The config version the pipeline currently uses. 0 for pipelines that haven't been updated since config versions
+	// started being tracked.
+	ConfigVersion int64 `json:"config_version"`
+
+	// A config version being tried out on some of the pipeline's runs before it replaces the current one. Nil if
+	// there is no canary in progress.
+	Canary *PipelineCanary `json:"canary"`
+
+	// What runs must do after a config version is deployed for Gofer not to roll it back. Nil if the pipeline has
+	// none.
+	DeploymentHealth *DeploymentHealth `json:"deployment_health"`
+
+	// The deployment whose health is still being checked. Nil once the deployment has proven itself or if there is no
+	// health check.
+	Deployment *PipelineDeployment `json:"deployment"`
+
+	// Pipelines within the same namespace that share a concurrency group only run one at a time. Empty if the
+	// pipeline isn't part of a group.
+	ConcurrencyGroup string `json:"concurrency_group"`
+
+	// Schedules start runs of the pipeline at the times given by cron expressions, keyed by label.
+	Schedules map[string]PipelineSchedule `json:"schedules"`
+	// The last time each schedule was due in epoch milliseconds, keyed by label. It's kept across config changes so
+	// that the times a schedule was missed while Gofer was down can be found.
+	ScheduleState map[string]int64 `json:"schedule_state"`
+
+	// Upstreams start runs of the pipeline when runs of other pipelines in the namespace finish, keyed by the
+	// upstream pipeline's ID.
+	Upstreams map[string]PipelineUpstream `json:"upstreams"`
+}
+
+// PipelineUpstream starts a run of a pipeline whenever a run of another pipeline in the same namespace finishes in one
+// of the given states.
+type PipelineUpstream struct {
+	Pipeline string     `json:"pipeline"` // The ID of the upstream pipeline.
+	States   []RunState `json:"states"`   // The states the upstream run must finish in; defaults to success.
+}
+
+// Wants returns whether a finished run of the upstream pipeline should start a run.
+func (u *PipelineUpstream) Wants(run *Run) bool {
+	if run.PipelineID != u.Pipeline {
+		return false
+	}
+
+	for _, state := range u.States {
+		if state == run.State {
+			return true
+		}
+	}
+
+	return false
+}
+
+func (u *PipelineUpstream) ToProto() *proto.PipelineUpstream {
+	states := []proto.Run_State{}
+	for _, state := range u.States {
+		states = append(states, proto.Run_State(proto.Run_State_value[string(state)]))
+	}
+
+	return &proto.PipelineUpstream{
+		Pipeline: u.Pipeline,
+		States:   states,
+	}
+}
+
+func (u *PipelineUpstream) FromProto(proto *proto.PipelineUpstream) {
+	u.Pipeline = proto.Pipeline
+	u.States = []RunState{}
+	for _, state := range proto.States {
+		u.States = append(u.States, RunState(state.String()))
+	}
+}
+
+type MissedRunPolicy string
+
+const (
+	// The times a schedule was due while Gofer was down are skipped.
+	MissedRunPolicySkip MissedRunPolicy = "SKIP"
+	// A single run is started for all of the times a schedule was due while Gofer was down.
+	MissedRunPolicyRunOnce MissedRunPolicy = "RUN_ONCE"
+)
+
+// PipelineSchedule starts runs of a pipeline at the times given by a cron expression.
+type PipelineSchedule struct {
+	Label      string          `json:"label"`
+	Cron       string          `json:"cron"`
+	Timezone   string          `json:"timezone"`    // IANA timezone the expression is evaluated in; empty is UTC.
+	MissedRuns MissedRunPolicy `json:"missed_runs"` // What happens to the times the schedule was due while Gofer was down.
+	Jitter     int64           `json:"jitter"`      // Seconds runs are randomly delayed by, at most.
+}
+
+// Location returns the timezone the schedule's expression is evaluated in.
+func (s *PipelineSchedule) Location() (*time.Location, error) {
+	if s.Timezone == "" {
+		return time.UTC, nil
+	}
+
+	location, err := time.LoadLocation(s.Timezone)
+	if err != nil {
+		return nil, fmt.Errorf("could not load timezone %q; %w", s.Timezone, err)
+	}
+
+	return location, nil
+}
+
+// NextDue returns the first time after the given time that the schedule is due. The zero time is returned if the
+// schedule is never due.
+func (s *PipelineSchedule) NextDue(after time.Time) (time.Time, error) {
+	schedule, err := cron.Parse(s.Cron)
+	if err != nil {
+		return time.Time{}, err
+	}
+
+	location, err := s.Location()
+	if err != nil {
+		return time.Time{}, err
+	}
+
+	return schedule.Next(after.In(location)), nil
+}
+
+func (s *PipelineSchedule) ToProto(lastDue int64) *proto.PipelineSchedule {
+	var nextDue int64
+	next, err := s.NextDue(time.Now())
+	if err == nil && !next.IsZero() {
+		nextDue = next.UnixMilli()
+	}
+
+	return &proto.PipelineSchedule{
+		Label:      s.Label,
+		Cron:       s.Cron,
+		Timezone:   s.Timezone,
+		MissedRuns: proto.PipelineSchedule_MissedRunPolicy(proto.PipelineSchedule_MissedRunPolicy_value[string(s.MissedRuns)]),
+		Jitter:     s.Jitter,
+		LastDue:    lastDue,
+		NextDue:    nextDue,
+	}
+}
+
+func (s *PipelineSchedule) FromProto(proto *proto.PipelineSchedule) {
+	s.Label = proto.Label
+	s.Cron = proto.Cron
+	s.Timezone = proto.Timezone
+	s.MissedRuns = MissedRunPolicy(proto.MissedRuns.String())
+	s.Jitter = proto.Jitter
+}
+
+// DeploymentHealth is what a pipeline's runs must do after a new config version is deployed for Gofer to keep it.
+// Deployments that fail the check are rolled back to the config version used before them.
+type DeploymentHealth struct {
+	RequiredSuccesses int64 `json:"required_successes"` // How many runs in a row must succeed after a deployment.
+}
+
+func (h *DeploymentHealth) ToProto() *proto.DeploymentHealth {
+	return &proto.DeploymentHealth{
+		RequiredSuccesses: h.RequiredSuccesses,
+	}
+}
+
+func (h *DeploymentHealth) FromProto(proto *proto.DeploymentHealth) {
+	h.RequiredSuccesses = proto.RequiredSuccesses
+}
+
+type DeploymentResult string
+
+const (
+	DeploymentResultPending DeploymentResult = "PENDING" // Not enough runs have finished to decide.
+	DeploymentResultHealthy DeploymentResult = "HEALTHY" // The deployment passed its health check.
+	DeploymentResultFailed  DeploymentResult = "FAILED"  // The deployment failed its health check.
+)
+
+// PipelineDeployment tracks the health check of a newly deployed config version.
+type PipelineDeployment struct {
+	Version           int64 `json:"version"`            // The config version that was deployed.
+	PreviousVersion   int64 `json:"previous_version"`   // The config version that is rolled back to on failure.
+	RequiredSuccesses int64 `json:"required_successes"` // How many runs in a row must succeed.
+	Successes         int64 `json:"successes"`          // How many runs have succeeded so far.
+	Started           int64 `json:"started"`            // Time the config version was deployed in epoch milli.
+}
+
+func NewPipelineDeployment(version, previousVersion int64, health DeploymentHealth) *PipelineDeployment {
+	return &PipelineDeployment{
+		Version:           version,
+		PreviousVersion:   previousVersion,
+		RequiredSuccesses: health.RequiredSuccesses,
+		Started:           time.Now().UnixMilli(),
+	}
+}
+
+// Record counts a finished run towards the deployment's health check and returns where the check stands. Cancelled
+// runs don't count either way.
+func (d *PipelineDeployment) Record(state RunState) DeploymentResult {
+	switch state {
+	case RunFailed:
+		return DeploymentResultFailed
+	case RunSuccess:
+		d.Successes++
+	}
+
+	if d.Successes >= d.RequiredSuccesses {
+		return DeploymentResultHealthy
+	}
+
+	return DeploymentResultPending
+}
+
+func (d *PipelineDeployment) ToProto() *proto.PipelineDeployment {
+	return &proto.PipelineDeployment{
+		Version:           d.Version,
+		PreviousVersion:   d.PreviousVersion,
+		RequiredSuccesses: d.RequiredSuccesses,
+		Successes:         d.Successes,
+		Started:           d.Started,
+	}
+}
+
+func (d *PipelineDeployment) FromProto(proto *proto.PipelineDeployment) {
+	d.Version = proto.Version
+	d.PreviousVersion = proto.PreviousVersion
+	d.RequiredSuccesses = proto.RequiredSuccesses
+	d.Successes = proto.Successes
+	d.Started = proto.Started
+}
+
+// PipelineCanary is a config version that some of a pipeline's runs use instead of the pipeline's current config
+// version. Only the tasks(and notifiers) of a run come from the canary version; triggers and every other setting stay
+// those of the current version until the canary is promoted.
+type PipelineCanary struct {
+	Version    int64 `json:"version"`     // The config version being tried out.
+	Percentage int64 `json:"percentage"`  // The percentage of runs that use the canary version; 1 to 100.
+	ManualOnly bool  `json:"manual_only"` // Only runs started manually use the canary version; Percentage is unused.
+	Started    int64 `json:"started"`     // Time the canary was started in epoch milli.
+}
+
+func NewPipelineCanary(version, percentage int64, manualOnly bool) *PipelineCanary {
+	return &PipelineCanary{
+		Version:    version,
+		Percentage: percentage,
+		ManualOnly: manualOnly,
+		Started:    time.Now().UnixMilli(),
+	}
+}
+
+// Selects returns whether a new run should use the canary version. Roll is a random number from 0 to 99 which decides
+// whether the run falls within the canary's percentage.
+func (c *PipelineCanary) Selects(manual bool, roll int64) bool {
+	if c.ManualOnly {
+		return manual
+	}
+
+	return roll < c.Percentage
+}
+
+func (c *PipelineCanary) ToProto() *proto.PipelineCanary {
+	return &proto.PipelineCanary{
+		Version:    c.Version,
+		Percentage: c.Percentage,
+		ManualOnly: c.ManualOnly,
+		Started:    c.Started,
+	}
+}
+
+func (c *PipelineCanary) FromProto(proto *proto.PipelineCanary) {
+	c.Version = proto.Version
+	c.Percentage = proto.Percentage
+	c.ManualOnly = proto.ManualOnly
+	c.Started = proto.Started
+}
+
+type RecoveryPolicy string
+
+const (
+	// RecoveryPolicyResume picks runs back up where they left off; tasks still running are tracked again and tasks
+	// that hadn't started yet are started. This is the default.
+	RecoveryPolicyResume RecoveryPolicy = "RESUME"
+	// RecoveryPolicyFail stops whatever is left of the run and fails it so that the pipeline's notification policy
+	// can let someone know.
+	RecoveryPolicyFail RecoveryPolicy = "FAIL"
+	// RecoveryPolicyRerun fails the run like RecoveryPolicyFail and then starts it over from the beginning.
+	RecoveryPolicyRerun RecoveryPolicy = "RERUN"
+)
+
+type NotificationReason string
+
+const (
+	NotificationReasonNone                 NotificationReason = ""
+	NotificationReasonFailure              NotificationReason = "FAILURE"                // The run failed.
+	NotificationReasonRecovery             NotificationReason = "RECOVERY"               // The run succeeded after a failure.
+	NotificationReasonSuccessAfterFailures NotificationReason = "SUCCESS_AFTER_FAILURES" // The run succeeded after several failures.
+)
+
+// NotificationPolicy decides which finished runs of a pipeline Gofer notifies about. Notifications are sent by Gofer
+// itself so they don't depend on any notifier being installed.
+type NotificationPolicy struct {
+	OnFailure              bool     `json:"on_failure"`                // Notify about every failed run.
+	OnRecovery             bool     `json:"on_recovery"`               // Notify about the first successful run after a failure.
+	OnSuccessAfterFailures int64    `json:"on_success_after_failures"` // Notify about the first successful run after this many failures.
+	Webhooks               []string `json:"webhooks"`                  // URLs notifications are posted to.
+}
+
+// Evaluate returns why a run that finished in the given state should be notified about, or NotificationReasonNone if it
+// shouldn't be. Failures is the number of runs that failed in a row right before it; cancelled runs don't count
+// either way.
+func (p *NotificationPolicy) Evaluate(state RunState, failures int64) NotificationReason {
+	switch state {
+	case RunFailed:
+		if p.OnFailure {
+			return NotificationReasonFailure
+		}
+	case RunSuccess:
+		if p.OnSuccessAfterFailures > 0 && failures >= p.OnSuccessAfterFailures {
+			return NotificationReasonSuccessAfterFailures
+		}
+		if p.OnRecovery && failures > 0 {
+			return NotificationReasonRecovery
+		}
+	}
+
+	return NotificationReasonNone
+}
+
+func (p *NotificationPolicy) ToProto() *proto.NotificationPolicy {
+	return &proto.NotificationPolicy{
+		OnFailure:              p.OnFailure,
+		OnRecovery:             p.OnRecovery,
+		OnSuccessAfterFailures: p.OnSuccessAfterFailures,
+		Webhooks:               p.Webhooks,
+	}
+}
+
+func (p *NotificationPolicy) FromProto(proto *proto.NotificationPolicy) {
+	p.OnFailure = proto.OnFailure
+	p.OnRecovery = proto.OnRecovery
+	p.OnSuccessAfterFailures = proto.OnSuccessAfterFailures
+	p.Webhooks = proto.Webhooks
 }
 
 func NewPipeline(location string, pipelineConfig *PipelineConfig) *Pipeline {
@@ -57,6 +415,9 @@ func NewPipeline(location string, pipelineConfig *PipelineConfig) *Pipeline {
 		Triggers:  map[string]PipelineTriggerConfig{},
 		Notifiers: map[string]PipelineNotifierConfig{},
 		Objects:   []string{},
+		Owners:    []string{},
+
+		ScheduleState: map[string]int64{},
 	}
 
 	newPipeline.FromConfig(pipelineConfig)
@@ -71,6 +432,12 @@ func (p *Pipeline) FromConfig(config *PipelineConfig) {
 	p.Namespace = config.Namespace
 	p.Updated = time.Now().UnixMilli()
 	p.Sequential = config.Sequential
+	p.Owners = config.Owners
+	p.Protected = config.Protected
+	p.NotificationPolicy = config.NotificationPolicy
+	p.RecoveryPolicy = config.RecoveryPolicy
+	p.DeploymentHealth = config.DeploymentHealth
+	p.ConcurrencyGroup = config.ConcurrencyGroup
 
 	p.Triggers = map[string]PipelineTriggerConfig{}
 	for _, trigger := range config.Triggers {
@@ -86,6 +453,16 @@ func (p *Pipeline) FromConfig(config *PipelineConfig) {
 	for _, task := range config.Tasks {
 		p.Tasks[task.ID] = task
 	}
+
+	p.Schedules = map[string]PipelineSchedule{}
+	for _, schedule := range config.Schedules {
+		p.Schedules[schedule.Label] = schedule
+	}
+
+	p.Upstreams = map[string]PipelineUpstream{}
+	for _, upstream := range config.Upstreams {
+		p.Upstreams[upstream.Pipeline] = upstream
+	}
 }
 
 func (p *Pipeline) ToProto() *proto.Pipeline {
@@ -96,12 +473,36 @@ func (p *Pipeline) ToProto() *proto.Pipeline {
 			dependson[name] = proto.TaskRequiredParentState(proto.TaskRequiredParentState_value[string(state)])
 		}
 
+		artifacts := map[string]*proto.TaskArtifact{}
+		for name, artifact := range task.Artifacts {
+			artifacts[name] = artifact.ToProto()
+		}
+
+		caches := map[string]*proto.TaskCache{}
+		for name, cache := range task.Caches {
+			caches[name] = cache.ToProto()
+		}
+
+		var gpus *proto.TaskGPUs
+		if task.GPUs != nil {
+			gpus = task.GPUs.ToProto()
+		}
+
 		tasks[id] = &proto.Task{
 			Id:          task.ID,
 			Description: task.Description,
 			Image:       task.Image,
 			DependsOn:   dependson,
 			EnvVars:     task.EnvVars,
+			Retries:     task.Retries,
+			Backoff:     task.Backoff,
+			When:        task.When,
+			Artifacts:   artifacts,
+			Caches:      caches,
+			Resources:   task.Resources.ToProto(),
+			Gpus:        gpus,
+			Privileged:  task.Privileged,
+			CapAdd:      task.CapAdd,
 		}
 	}
 
@@ -124,22 +525,65 @@ func (p *Pipeline) ToProto() *proto.Pipeline {
 		}
 	}
 
+	var notificationPolicy *proto.NotificationPolicy
+	if p.NotificationPolicy != nil {
+		notificationPolicy = p.NotificationPolicy.ToProto()
+	}
+
+	var canary *proto.PipelineCanary
+	if p.Canary != nil {
+		canary = p.Canary.ToProto()
+	}
+
+	var deploymentHealth *proto.DeploymentHealth
+	if p.DeploymentHealth != nil {
+		deploymentHealth = p.DeploymentHealth.ToProto()
+	}
+
+	var deployment *proto.PipelineDeployment
+	if p.Deployment != nil {
+		deployment = p.Deployment.ToProto()
+	}
+
+	schedules := map[string]*proto.PipelineSchedule{}
+	for label, schedule := range p.Schedules {
+		schedules[label] = schedule.ToProto(p.ScheduleState[label])
+	}
+
+	upstreams := map[string]*proto.PipelineUpstream{}
+	for id, upstream := range p.Upstreams {
+		upstreams[id] = upstream.ToProto()
+	}
+
 	return &proto.Pipeline{
-		Location:    p.Location,
-		Created:     p.Created,
-		Description: p.Description,
-		Id:          p.ID,
-		LastRunTime: p.LastRunTime,
-		LastRunId:   p.LastRunID,
-		Updated:     p.Updated,
-		Name:        p.Name,
-		Sequential:  p.Sequential,
-		State:       proto.Pipeline_State(proto.Pipeline_State_value[string(p.State)]),
-		Tasks:       tasks,
-		Triggers:    triggers,
-		Notifiers:   notifiers,
-		Namespace:   p.Namespace,
-		Objects:     p.Objects,
+		Location:       p.Location,
+		Created:        p.Created,
+		Description:    p.Description,
+		Id:             p.ID,
+		LastRunTime:    p.LastRunTime,
+		LastRunId:      p.LastRunID,
+		Updated:        p.Updated,
+		Name:           p.Name,
+		Sequential:     p.Sequential,
+		State:          proto.Pipeline_State(proto.Pipeline_State_value[string(p.State)]),
+		Tasks:          tasks,
+		Triggers:       triggers,
+		Notifiers:      notifiers,
+		Namespace:      p.Namespace,
+		Objects:        p.Objects,
+		ServiceAccount: p.ServiceAccount,
+		Owners:         p.Owners,
+		Protected:      p.Protected,
+
+		NotificationPolicy: notificationPolicy,
+		RecoveryPolicy:     proto.Pipeline_RecoveryPolicy(proto.Pipeline_RecoveryPolicy_value[string(p.RecoveryPolicy)]),
+		ConfigVersion:      p.ConfigVersion,
+		Canary:             canary,
+		DeploymentHealth:   deploymentHealth,
+		Deployment:         deployment,
+		ConcurrencyGroup:   p.ConcurrencyGroup,
+		Schedules:          schedules,
+		Upstreams:          upstreams,
 	}
 }
 
@@ -157,18 +601,95 @@ func (p *Pipeline) FromProto(proto *proto.Pipeline) {
 	p.Tasks = map[string]Task{}
 	p.Namespace = proto.Namespace
 	p.Objects = proto.Objects
+	p.ServiceAccount = proto.ServiceAccount
+	p.Owners = proto.Owners
+	p.Protected = proto.Protected
+	p.NotificationPolicy = nil
+	if proto.NotificationPolicy != nil {
+		p.NotificationPolicy = &NotificationPolicy{}
+		p.NotificationPolicy.FromProto(proto.NotificationPolicy)
+	}
+	p.RecoveryPolicy = ""
+	if proto.RecoveryPolicy != 0 { // Pipelines created before recovery policies existed have none.
+		p.RecoveryPolicy = RecoveryPolicy(proto.RecoveryPolicy.String())
+	}
+	p.ConfigVersion = proto.ConfigVersion
+	p.Canary = nil
+	if proto.Canary != nil {
+		p.Canary = &PipelineCanary{}
+		p.Canary.FromProto(proto.Canary)
+	}
+	p.DeploymentHealth = nil
+	if proto.DeploymentHealth != nil {
+		p.DeploymentHealth = &DeploymentHealth{}
+		p.DeploymentHealth.FromProto(proto.DeploymentHealth)
+	}
+	p.Deployment = nil
+	if proto.Deployment != nil {
+		p.Deployment = &PipelineDeployment{}
+		p.Deployment.FromProto(proto.Deployment)
+	}
+	p.ConcurrencyGroup = proto.ConcurrencyGroup
+	p.Schedules = map[string]PipelineSchedule{}
+	p.ScheduleState = map[string]int64{}
+	for label, protoSchedule := range proto.Schedules {
+		schedule := PipelineSchedule{}
+		schedule.FromProto(protoSchedule)
+		p.Schedules[label] = schedule
+		p.ScheduleState[label] = protoSchedule.LastDue
+	}
+	p.Upstreams = map[string]PipelineUpstream{}
+	for id, protoUpstream := range proto.Upstreams {
+		upstream := PipelineUpstream{}
+		upstream.FromProto(protoUpstream)
+		p.Upstreams[id] = upstream
+	}
 	for id, task := range proto.Tasks {
 		dependson := map[string]RequiredParentState{}
 		for name, state := range task.DependsOn {
 			dependson[name] = RequiredParentState(state.String())
 		}
 
+		artifacts := map[string]TaskArtifact{}
+		for name, protoArtifact := range task.Artifacts {
+			artifact := TaskArtifact{}
+			artifact.FromProto(protoArtifact)
+			artifacts[name] = artifact
+		}
+
+		caches := map[string]TaskCache{}
+		for name, protoCache := range task.Caches {
+			cache := TaskCache{}
+			cache.FromProto(protoCache)
+			caches[name] = cache
+		}
+
+		resources := TaskResources{}
+		if task.Resources != nil {
+			resources.FromProto(task.Resources)
+		}
+
+		var gpus *TaskGPUs
+		if task.Gpus != nil {
+			gpus = &TaskGPUs{}
+			gpus.FromProto(task.Gpus)
+		}
+
 		p.Tasks[id] = Task{
 			ID:          task.Id,
 			Description: task.Description,
 			Image:       task.Image,
 			DependsOn:   dependson,
 			EnvVars:     task.EnvVars,
+			Retries:     task.Retries,
+			Backoff:     task.Backoff,
+			When:        task.When,
+			Artifacts:   artifacts,
+			Caches:      caches,
+			Resources:   resources,
+			GPUs:        gpus,
+			Privileged:  task.Privileged,
+			CapAdd:      task.CapAdd,
 		}
 	}
 	for label, trigger := range proto.Triggers {
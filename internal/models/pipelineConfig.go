@@ -4,8 +4,13 @@ import (
 	"encoding/base64"
 	"errors"
 	"fmt"
+	"net/url"
+	"regexp"
+	"sort"
 	"strings"
+	"time"
 
+	"github.com/clintjedwards/gofer/internal/cron"
 	"github.com/clintjedwards/gofer/internal/dag"
 	validation "github.com/go-ozzo/ozzo-validation/v4"
 	"github.com/go-ozzo/ozzo-validation/v4/is"
@@ -24,6 +29,38 @@ type HCLPipelineExecConfig struct {
 	Script string `json:"script" hcl:"script"`
 }
 
+// HCLPipelineArtifactConfig is a file or directory a task leaves behind that Gofer keeps once the task run finishes.
+type HCLPipelineArtifactConfig struct {
+	Name        string `json:"name" hcl:"name,label"`
+	Path        string `json:"path" hcl:"path"`                          // Ex: "dist/app" or "/build/reports"
+	ContentType string `json:"content_type" hcl:"content_type,optional"` // Ex: "application/zip"
+	Expiry      int64  `json:"expiry" hcl:"expiry,optional"`             // How many runs the artifact is kept for.
+}
+
+// HCLPipelineCacheConfig is a set of paths saved once a task run succeeds and restored into later task runs.
+type HCLPipelineCacheConfig struct {
+	Name        string   `json:"name" hcl:"name,label"`
+	Key         string   `json:"key" hcl:"key"`                            // Ex: "go-modules-{{ BRANCH }}"
+	Paths       []string `json:"paths" hcl:"paths"`                        // Ex: ["/root/go/pkg/mod", ".cache"]
+	RestoreKeys []string `json:"restore_keys" hcl:"restore_keys,optional"` // Ex: ["go-modules-"]
+}
+
+// HCLPipelineResourcesConfig is the CPU and memory a task's container asks for.
+type HCLPipelineResourcesConfig struct {
+	CPURequest      float64 `json:"cpu_request" hcl:"cpu_request,optional"`             // In CPU cores. Ex: 0.5
+	CPULimit        float64 `json:"cpu_limit" hcl:"cpu_limit,optional"`                 // In CPU cores. Ex: 2
+	MemoryRequestMB int64   `json:"memory_request_mb" hcl:"memory_request_mb,optional"` // Ex: 512
+	MemoryLimitMB   int64   `json:"memory_limit_mb" hcl:"memory_limit_mb,optional"`     // Ex: 2048
+}
+
+// HCLPipelineGPUsConfig is the GPUs a task's container asks for.
+type HCLPipelineGPUsConfig struct {
+	Count        int64    `json:"count" hcl:"count,optional"`               // How many GPUs; -1 for all of them.
+	DeviceIDs    []string `json:"device_ids" hcl:"device_ids,optional"`     // Ex: ["0", "1"]
+	Driver       string   `json:"driver" hcl:"driver,optional"`             // Ex: "nvidia"
+	Capabilities []string `json:"capabilities" hcl:"capabilities,optional"` // Ex: ["compute", "utility"]
+}
+
 type HCLPipelineTaskConfig struct {
 	ID          string                 `json:"id" hcl:"id,label"`
 	ImageName   string                 `json:"image_name" hcl:"image_name,label"`
@@ -35,6 +72,31 @@ type HCLPipelineTaskConfig struct {
 	// HCLv2 has many idiosyncrasies, but this one is noteworthy for future reference. The only way to make a block
 	// optional is to make the type a reference to the real struct and then make sure to check for the possible nil.
 	RegistryAuth *HCLPipelineRegistryAuthConfig `json:"registry_auth" hcl:"registry_auth,block"`
+
+	// How many more times the task is attempted after it fails.
+	Retries int64 `json:"retries" hcl:"retries,optional"`
+
+	// How long to wait before the first retry(ex. "30s"); doubles after every attempt.
+	Backoff string `json:"backoff" hcl:"backoff,optional"`
+
+	// A condition the run's variables must meet for the task to be run. Ex: "vars.BRANCH == \"main\""
+	When string `json:"when" hcl:"when,optional"`
+
+	// Files and directories collected from the task run's container once it finishes.
+	Artifacts []HCLPipelineArtifactConfig `json:"artifacts" hcl:"artifact,block"`
+
+	// Paths saved once the task run succeeds and restored into later task runs before they start.
+	Caches []HCLPipelineCacheConfig `json:"caches" hcl:"cache,block"`
+
+	// The CPU and memory the task run's container asks for; the server's defaults are used for anything left out.
+	Resources *HCLPipelineResourcesConfig `json:"resources" hcl:"resources,block"`
+
+	// The GPUs the task run's container asks for.
+	GPUs *HCLPipelineGPUsConfig `json:"gpus" hcl:"gpus,block"`
+
+	// Run the container privileged or with extra Linux capabilities. Only allowed where the server's admins allow it.
+	Privileged bool     `json:"privileged" hcl:"privileged,optional"`
+	CapAdd     []string `json:"cap_add" hcl:"cap_add,optional"` // Ex: ["SYS_ADMIN"]
 }
 
 func (config *HCLPipelineTaskConfig) Validate() error {
@@ -45,6 +107,74 @@ func (config *HCLPipelineTaskConfig) Validate() error {
 		}
 	}
 
+	if config.Backoff != "" {
+		backoff, err := time.ParseDuration(config.Backoff)
+		if err != nil {
+			return fmt.Errorf("backoff %q is not a valid duration; %v", config.Backoff, err)
+		}
+
+		if backoff < 0 {
+			return fmt.Errorf("backoff cannot be negative")
+		}
+	}
+
+	if config.When != "" {
+		_, err := parseTaskCondition(config.When)
+		if err != nil {
+			return err
+		}
+	}
+
+	artifactSet := map[string]struct{}{}
+	for _, artifact := range config.Artifacts {
+		if _, exists := artifactSet[artifact.Name]; exists {
+			return fmt.Errorf("artifact names must be unique within a task; %q is used more than once", artifact.Name)
+		}
+		artifactSet[artifact.Name] = struct{}{}
+
+		taskArtifact := TaskArtifact(artifact)
+		err := taskArtifact.Validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	cacheSet := map[string]struct{}{}
+	for _, cache := range config.Caches {
+		if _, exists := cacheSet[cache.Name]; exists {
+			return fmt.Errorf("cache names must be unique within a task; %q is used more than once", cache.Name)
+		}
+		cacheSet[cache.Name] = struct{}{}
+
+		taskCache := TaskCache(cache)
+		err := taskCache.Validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if config.Resources != nil {
+		resources := TaskResources(*config.Resources)
+		err := resources.Validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	if config.GPUs != nil {
+		gpus := TaskGPUs(*config.GPUs)
+		err := gpus.Validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	for _, capability := range config.CapAdd {
+		if !isCapability(strings.TrimSpace(capability)) {
+			return fmt.Errorf("cap_add %q is not a linux capability name(ex. \"SYS_ADMIN\")", capability)
+		}
+	}
+
 	configDeref := *config
 	return validation.ValidateStruct(&configDeref,
 		// Name cannot be empty, greater than 70 chars, and must not contain spaces/special chars etc.
@@ -52,6 +182,8 @@ func (config *HCLPipelineTaskConfig) Validate() error {
 		// Description cannot be greater than 3000 chars
 		validation.Field(&configDeref.Description, validation.Length(0, 3000)),
 		validation.Field(&configDeref.ImageName, validation.Required),
+		// Retries cannot be negative or greater than 10.
+		validation.Field(&configDeref.Retries, validation.Min(int64(0)), validation.Max(int64(10))),
 	)
 }
 
@@ -91,6 +223,122 @@ func (config *HCLPipelineNotifierConfig) Validate() error {
 	)
 }
 
+// HCLPipelineScheduleConfig starts runs of the pipeline at the times given by a cron expression.
+type HCLPipelineScheduleConfig struct {
+	Label      string `hcl:"label,label"`
+	Cron       string `hcl:"cron"`                 // Ex: "0 2 * * mon-fri"
+	Timezone   string `hcl:"timezone,optional"`    // IANA timezone the expression is evaluated in(ex. "Europe/Berlin").
+	MissedRuns string `hcl:"missed_runs,optional"` // One of "skip" or "run_once"; defaults to "skip".
+	Jitter     string `hcl:"jitter,optional"`      // Randomly delay runs by up to this long(ex. "5m").
+}
+
+func (config *HCLPipelineScheduleConfig) Validate() error {
+	if _, err := cron.Parse(config.Cron); err != nil {
+		return fmt.Errorf("schedule %q: %w", config.Label, err)
+	}
+
+	if config.Timezone != "" {
+		if _, err := time.LoadLocation(config.Timezone); err != nil {
+			return fmt.Errorf("schedule %q timezone %q is not a known timezone", config.Label, config.Timezone)
+		}
+	}
+
+	if config.MissedRuns != "" {
+		switch MissedRunPolicy(strings.ToUpper(config.MissedRuns)) {
+		case MissedRunPolicySkip, MissedRunPolicyRunOnce:
+		default:
+			return fmt.Errorf("schedule %q missed_runs %q must be one of \"skip\" or \"run_once\"", config.Label,
+				config.MissedRuns)
+		}
+	}
+
+	if config.Jitter != "" {
+		jitter, err := time.ParseDuration(config.Jitter)
+		if err != nil {
+			return fmt.Errorf("schedule %q jitter %q is not a valid duration; %v", config.Label, config.Jitter, err)
+		}
+
+		if jitter < 0 {
+			return fmt.Errorf("schedule %q jitter cannot be negative", config.Label)
+		}
+	}
+
+	configDeref := *config
+	return validation.ValidateStruct(&configDeref,
+		// Label cannot be empty, greater than 80 chars, and must not contain spaces/special chars etc.
+		validation.Field(&configDeref.Label, validation.Required, validation.Length(1, 80), validation.By(isRestrictedCharSet)),
+	)
+}
+
+// HCLPipelineUpstreamConfig starts a run of the pipeline whenever a run of another pipeline in the same namespace
+// finishes.
+type HCLPipelineUpstreamConfig struct {
+	Pipeline string   `hcl:"pipeline,label"`  // The ID of the upstream pipeline.
+	States   []string `hcl:"states,optional"` // States the upstream run must finish in; defaults to ["success"].
+}
+
+func (config *HCLPipelineUpstreamConfig) Validate() error {
+	for _, state := range config.States {
+		switch RunState(strings.ToUpper(state)) {
+		case RunSuccess, RunFailed, RunCancelled:
+		default:
+			return fmt.Errorf("after_pipeline %q state %q must be one of \"success\", \"failed\" or \"cancelled\"",
+				config.Pipeline, state)
+		}
+	}
+
+	configDeref := *config
+	return validation.ValidateStruct(&configDeref,
+		// Pipeline cannot be empty, greater than 70 chars, and must not contain spaces/special chars etc.
+		validation.Field(&configDeref.Pipeline, validation.Required, validation.Length(1, 70), validation.By(isRestrictedCharSet)),
+	)
+}
+
+// HCLPipelineNotificationPolicyConfig controls which finished runs Gofer notifies the given webhooks about.
+type HCLPipelineNotificationPolicyConfig struct {
+	OnFailure              bool     `hcl:"on_failure,optional"`                // Notify about every failed run.
+	OnRecovery             bool     `hcl:"on_recovery,optional"`               // Notify about the first successful run after a failure.
+	OnSuccessAfterFailures int64    `hcl:"on_success_after_failures,optional"` // Notify about the first successful run after this many failures.
+	Webhooks               []string `hcl:"webhooks"`                           // URLs notifications are posted to.
+}
+
+func (config *HCLPipelineNotificationPolicyConfig) Validate() error {
+	if !config.OnFailure && !config.OnRecovery && config.OnSuccessAfterFailures == 0 {
+		return fmt.Errorf("notification policy must notify on at least one of on_failure, on_recovery or " +
+			"on_success_after_failures")
+	}
+
+	if config.OnSuccessAfterFailures < 0 {
+		return fmt.Errorf("notification policy on_success_after_failures cannot be negative")
+	}
+
+	if len(config.Webhooks) == 0 {
+		return fmt.Errorf("notification policy requires at least one webhook")
+	}
+
+	for _, webhook := range config.Webhooks {
+		parsed, err := url.Parse(webhook)
+		if err != nil || (parsed.Scheme != "http" && parsed.Scheme != "https") || parsed.Host == "" {
+			return fmt.Errorf("notification policy webhook %q must be an http or https URL", webhook)
+		}
+	}
+
+	return nil
+}
+
+// HCLPipelineDeploymentHealthConfig controls what a newly deployed config version has to do to be kept.
+type HCLPipelineDeploymentHealthConfig struct {
+	RequiredSuccesses int64 `hcl:"required_successes"` // How many runs in a row must succeed after a deployment.
+}
+
+func (config *HCLPipelineDeploymentHealthConfig) Validate() error {
+	if config.RequiredSuccesses < 1 {
+		return fmt.Errorf("deployment health required_successes must be at least 1")
+	}
+
+	return nil
+}
+
 // HCLPipelineConfig represents the structure of a pipeline configuration file in HCL form.
 type HCLPipelineConfig struct {
 	ID          string                      `hcl:"id"`
@@ -98,9 +346,29 @@ type HCLPipelineConfig struct {
 	Name        string                      `hcl:"name"`
 	Namespace   string                      `hcl:"namespace,optional"`  // Namespace pipeline will belong to, if empty is set to "default".
 	Sequential  bool                        `hcl:"sequential,optional"` // Restrict pipeline to only one run at a time.
+	Owners      []string                    `hcl:"owners,optional"`     // Callers able to modify the pipeline when protected.
+	Protected   bool                        `hcl:"protected,optional"`  // Restrict modifying the pipeline to its owners and admins.
 	Tasks       []HCLPipelineTaskConfig     `hcl:"task,block"`          // Each task represents a unit of work wrapped in a docker container.
 	Triggers    []HCLPipelineTriggerConfig  `hcl:"trigger,block"`       // Each trigger represents an automated way to start a pipeline.
 	Notifiers   []HCLPipelineNotifierConfig `hcl:"notify,block"`        // Each notifier represents a pipeline configured communication method.
+
+	// Which finished runs Gofer should notify about on the pipeline's behalf.
+	NotificationPolicy *HCLPipelineNotificationPolicyConfig `hcl:"notification_policy,block"`
+
+	// What to do with runs that were in progress when Gofer stopped unexpectedly; one of "resume", "fail" or "rerun".
+	RecoveryPolicy string `hcl:"recovery_policy,optional"`
+
+	// Pipelines within the same namespace that share a concurrency group only run one at a time.
+	ConcurrencyGroup string `hcl:"concurrency_group,optional"`
+
+	// What runs must do after this config is deployed for Gofer not to roll it back.
+	DeploymentHealth *HCLPipelineDeploymentHealthConfig `hcl:"deployment_health,block"`
+
+	// Each schedule starts runs of the pipeline at the times given by a cron expression.
+	Schedules []HCLPipelineScheduleConfig `hcl:"schedule,block"`
+
+	// Each upstream starts a run of the pipeline when a run of another pipeline finishes.
+	Upstreams []HCLPipelineUpstreamConfig `hcl:"after_pipeline,block"`
 }
 
 // Validate examines the HCL pipeline configuration to make sure it adheres to best practices and formatting mistakes.
@@ -118,6 +386,8 @@ func (config *HCLPipelineConfig) Validate() error {
 		validation.Field(&configDeref.Description, validation.Length(0, 3000)),
 		// Can not have no tasks.
 		validation.Field(&configDeref.Tasks, validation.Length(1, 0)),
+		// Concurrency group cannot be greater than 70 chars.
+		validation.Field(&configDeref.ConcurrencyGroup, validation.Length(0, 70), validation.By(isRestrictedCharSet)),
 	)
 	if err != nil {
 		result = multierror.Append(result, err)
@@ -166,6 +436,74 @@ func (config *HCLPipelineConfig) Validate() error {
 		}
 	}
 
+	// 4) Check owners refer to a kind of caller.
+	for _, owner := range config.Owners {
+		if !strings.Contains(owner, ":") {
+			result = multierror.Append(result,
+				fmt.Errorf("owner %q must be in the form <kind>:<name>; ex. \"user:alice\"", owner))
+		}
+	}
+
+	// 5) Check the notification policy has something to notify about and somewhere to send it.
+	if config.NotificationPolicy != nil {
+		err = config.NotificationPolicy.Validate()
+		if err != nil {
+			result = multierror.Append(result, err)
+		}
+	}
+
+	// 6) Check the recovery policy is one we know how to carry out.
+	if config.RecoveryPolicy != "" {
+		switch RecoveryPolicy(strings.ToUpper(config.RecoveryPolicy)) {
+		case RecoveryPolicyResume, RecoveryPolicyFail, RecoveryPolicyRerun:
+		default:
+			result = multierror.Append(result,
+				fmt.Errorf("recovery_policy %q must be one of \"resume\", \"fail\" or \"rerun\"", config.RecoveryPolicy))
+		}
+	}
+
+	// 7) Check the deployment health check can be passed.
+	if config.DeploymentHealth != nil {
+		err = config.DeploymentHealth.Validate()
+		if err != nil {
+			result = multierror.Append(result, err)
+		}
+	}
+
+	// 8) Check schedules for basic input validation and unique naming.
+	scheduleSet := map[string]struct{}{}
+	for _, schedule := range config.Schedules {
+		_, exists := scheduleSet[schedule.Label]
+		if exists {
+			result = multierror.Append(result, fmt.Errorf("schedule labels must be unique"))
+		}
+
+		scheduleSet[schedule.Label] = struct{}{}
+		err = schedule.Validate()
+		if err != nil {
+			result = multierror.Append(result, err)
+		}
+	}
+
+	// 9) Check upstream pipelines for basic input validation and uniqueness.
+	upstreamSet := map[string]struct{}{}
+	for _, upstream := range config.Upstreams {
+		_, exists := upstreamSet[upstream.Pipeline]
+		if exists {
+			result = multierror.Append(result, fmt.Errorf("after_pipeline %q can only be given once", upstream.Pipeline))
+		}
+
+		if upstream.Pipeline == config.ID {
+			result = multierror.Append(result, fmt.Errorf("pipeline cannot run after itself"))
+		}
+
+		upstreamSet[upstream.Pipeline] = struct{}{}
+		err = upstream.Validate()
+		if err != nil {
+			result = multierror.Append(result, err)
+		}
+	}
+
 	return result
 }
 
@@ -194,9 +532,30 @@ type PipelineConfig struct {
 	Name        string
 	Namespace   string                   // Unique ID for namespace pipeline will belong to.
 	Sequential  bool                     // Restrict pipeline to only one run at a time.
+	Owners      []string                 // Callers able to modify the pipeline when protected.
+	Protected   bool                     // Restrict modifying the pipeline to its owners and admins.
 	Tasks       []Task                   // Each task represents a unit of work wrapped in a docker container.
 	Triggers    []PipelineTriggerConfig  // Each trigger represents an automated way to start a pipeline.
 	Notifiers   []PipelineNotifierConfig // Each notifier represents a communication method defined by a pipeline.
+
+	// Which finished runs Gofer should notify about on the pipeline's behalf. Nil if the pipeline has no policy.
+	NotificationPolicy *NotificationPolicy
+
+	// What happens to runs that were in progress when Gofer stopped unexpectedly.
+	RecoveryPolicy RecoveryPolicy
+
+	// Pipelines within the same namespace that share a concurrency group only run one at a time. Empty if the
+	// pipeline isn't part of a group.
+	ConcurrencyGroup string
+
+	// What runs must do after this config is deployed for Gofer not to roll it back. Nil if the pipeline has none.
+	DeploymentHealth *DeploymentHealth
+
+	// Each schedule starts runs of the pipeline at the times given by a cron expression.
+	Schedules []PipelineSchedule
+
+	// Each upstream starts a run of the pipeline when a run of another pipeline finishes.
+	Upstreams []PipelineUpstream
 }
 
 type PipelineTriggerState string
@@ -225,6 +584,101 @@ type PipelineNotifierConfig struct {
 	Config map[string]string // Any configuration the notifier might need per pipeline.
 }
 
+// Interpolation is a value within a pipeline config written in interpolation syntax(ex. secret{{ example }}) which
+// Gofer fills in from elsewhere when it's used.
+type Interpolation struct {
+	Location string // Where within the config the value was found. Ex: `task "build" env var "TOKEN"`
+	Prefix   string // Where the value is filled in from. Ex: "secret"
+	Key      string // Ex: "example"
+
+	// The prefixes Gofer fills in at this location; values with other prefixes are passed along as is.
+	Sources []string
+}
+
+var interpolationSyntax = regexp.MustCompile(`^\s*([A-Za-z_]+)\{\{(.*)\}\}\s*$`)
+
+// Validate makes sure the interpolation will actually be filled in.
+func (i *Interpolation) Validate() error {
+	supported := false
+	for _, source := range i.Sources {
+		if source == i.Prefix {
+			supported = true
+			break
+		}
+	}
+
+	if !supported {
+		if len(i.Sources) == 0 {
+			return fmt.Errorf("%s: %s{{ %s }} is not filled in here; values are passed along as written",
+				i.Location, i.Prefix, i.Key)
+		}
+
+		sources := []string{}
+		for _, source := range i.Sources {
+			sources = append(sources, source+"{{ }}")
+		}
+		return fmt.Errorf("%s: %s{{ %s }} is not filled in here; use one of %s", i.Location, i.Prefix, i.Key,
+			strings.Join(sources, ", "))
+	}
+
+	if i.Key == "" {
+		return fmt.Errorf("%s: %s{{ }} is missing a key", i.Location, i.Prefix)
+	}
+
+	return nil
+}
+
+// Interpolations returns every value within the config written in interpolation syntax, including those Gofer doesn't
+// know how to fill in so they can be pointed out.
+func (config *PipelineConfig) Interpolations() []Interpolation {
+	interpolations := []Interpolation{}
+
+	find := func(location, value string, sources []string) {
+		match := interpolationSyntax.FindStringSubmatch(value)
+		if match == nil {
+			return
+		}
+
+		interpolations = append(interpolations, Interpolation{
+			Location: location,
+			Prefix:   match[1],
+			Key:      strings.TrimSpace(match[2]),
+			Sources:  sources,
+		})
+	}
+
+	for _, task := range config.Tasks {
+		for _, key := range sortedKeys(task.EnvVars) {
+			find(fmt.Sprintf("task %q env var %q", task.ID, key), task.EnvVars[key], []string{"secret", "pipeline", "run"})
+		}
+		find(fmt.Sprintf("task %q registry_auth pass", task.ID), task.RegistryAuth.Pass, []string{"secret"})
+	}
+
+	for _, trigger := range config.Triggers {
+		for _, key := range sortedKeys(trigger.Config) {
+			find(fmt.Sprintf("trigger %q setting %q", trigger.Label, key), trigger.Config[key],
+				[]string{"secret", "pipeline"})
+		}
+	}
+
+	for _, notifier := range config.Notifiers {
+		for _, key := range sortedKeys(notifier.Config) {
+			find(fmt.Sprintf("notifier %q setting %q", notifier.Label, key), notifier.Config[key], []string{})
+		}
+	}
+
+	return interpolations
+}
+
+func sortedKeys(m map[string]string) []string {
+	keys := []string{}
+	for key := range m {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys
+}
+
 // FromHCL returns a normal config struct from a given HCLConfig struct
 func FromHCL(hcl *HCLPipelineConfig) (*PipelineConfig, error) {
 	triggers := []PipelineTriggerConfig{}
@@ -290,6 +744,60 @@ func FromHCL(hcl *HCLPipelineConfig) (*PipelineConfig, error) {
 		// Encode shell script into base64 so it's easy to store and pass around.
 		exec.Script = base64.StdEncoding.EncodeToString([]byte(exec.Script))
 
+		backoff := time.Duration(0)
+		if task.Backoff != "" {
+			var err error
+			backoff, err = time.ParseDuration(task.Backoff)
+			if err != nil {
+				return nil, fmt.Errorf("could not parse backoff for task %q; %w", task.ID, err)
+			}
+		}
+
+		artifacts := map[string]TaskArtifact{}
+		for _, artifact := range task.Artifacts {
+			artifacts[artifact.Name] = TaskArtifact{
+				Name:        artifact.Name,
+				Path:        strings.TrimSpace(artifact.Path),
+				ContentType: strings.TrimSpace(artifact.ContentType),
+				Expiry:      artifact.Expiry,
+			}
+		}
+
+		caches := map[string]TaskCache{}
+		for _, cache := range task.Caches {
+			paths := []string{}
+			for _, path := range cache.Paths {
+				paths = append(paths, strings.TrimSpace(path))
+			}
+
+			caches[cache.Name] = TaskCache{
+				Name:        cache.Name,
+				Key:         strings.TrimSpace(cache.Key),
+				Paths:       paths,
+				RestoreKeys: cache.RestoreKeys,
+			}
+		}
+
+		resources := TaskResources{}
+		if task.Resources != nil {
+			resources = TaskResources(*task.Resources)
+		}
+
+		var gpus *TaskGPUs
+		if task.GPUs != nil {
+			gpus = &TaskGPUs{
+				Count:        task.GPUs.Count,
+				DeviceIDs:    task.GPUs.DeviceIDs,
+				Driver:       strings.TrimSpace(task.GPUs.Driver),
+				Capabilities: task.GPUs.Capabilities,
+			}
+		}
+
+		capAdd := []string{}
+		for _, capability := range task.CapAdd {
+			capAdd = append(capAdd, NormalizeCapability(capability))
+		}
+
 		tasks = append(tasks, Task{
 			ID:           task.ID,
 			Description:  strings.TrimSpace(task.Description),
@@ -298,6 +806,84 @@ func FromHCL(hcl *HCLPipelineConfig) (*PipelineConfig, error) {
 			DependsOn:    dependson,
 			EnvVars:      task.EnvVars,
 			Exec:         exec,
+			Retries:      task.Retries,
+			Backoff:      int64(backoff.Seconds()),
+			When:         strings.TrimSpace(task.When),
+			Artifacts:    artifacts,
+			Caches:       caches,
+			Resources:    resources,
+			GPUs:         gpus,
+			Privileged:   task.Privileged,
+			CapAdd:       capAdd,
+		})
+	}
+
+	owners := []string{}
+	for _, owner := range hcl.Owners {
+		owners = append(owners, strings.TrimSpace(owner))
+	}
+
+	var notificationPolicy *NotificationPolicy
+	if hcl.NotificationPolicy != nil {
+		notificationPolicy = &NotificationPolicy{
+			OnFailure:              hcl.NotificationPolicy.OnFailure,
+			OnRecovery:             hcl.NotificationPolicy.OnRecovery,
+			OnSuccessAfterFailures: hcl.NotificationPolicy.OnSuccessAfterFailures,
+			Webhooks:               hcl.NotificationPolicy.Webhooks,
+		}
+	}
+
+	recoveryPolicy := RecoveryPolicyResume
+	if hcl.RecoveryPolicy != "" {
+		recoveryPolicy = RecoveryPolicy(strings.ToUpper(hcl.RecoveryPolicy))
+	}
+
+	var deploymentHealth *DeploymentHealth
+	if hcl.DeploymentHealth != nil {
+		deploymentHealth = &DeploymentHealth{
+			RequiredSuccesses: hcl.DeploymentHealth.RequiredSuccesses,
+		}
+	}
+
+	schedules := []PipelineSchedule{}
+	for _, schedule := range hcl.Schedules {
+		missedRuns := MissedRunPolicySkip
+		if schedule.MissedRuns != "" {
+			missedRuns = MissedRunPolicy(strings.ToUpper(schedule.MissedRuns))
+		}
+
+		jitter := time.Duration(0)
+		if schedule.Jitter != "" {
+			var err error
+			jitter, err = time.ParseDuration(schedule.Jitter)
+			if err != nil {
+				return nil, fmt.Errorf("could not parse jitter for schedule %q; %w", schedule.Label, err)
+			}
+		}
+
+		schedules = append(schedules, PipelineSchedule{
+			Label:      schedule.Label,
+			Cron:       strings.TrimSpace(schedule.Cron),
+			Timezone:   schedule.Timezone,
+			MissedRuns: missedRuns,
+			Jitter:     int64(jitter.Seconds()),
+		})
+	}
+
+	upstreams := []PipelineUpstream{}
+	for _, upstream := range hcl.Upstreams {
+		states := []RunState{}
+		for _, state := range upstream.States {
+			states = append(states, RunState(strings.ToUpper(state)))
+		}
+
+		if len(states) == 0 {
+			states = []RunState{RunSuccess}
+		}
+
+		upstreams = append(upstreams, PipelineUpstream{
+			Pipeline: upstream.Pipeline,
+			States:   states,
 		})
 	}
 
@@ -307,9 +893,18 @@ func FromHCL(hcl *HCLPipelineConfig) (*PipelineConfig, error) {
 		Name:        strings.TrimSpace(hcl.Name),
 		Namespace:   hcl.Namespace,
 		Sequential:  hcl.Sequential,
+		Owners:      owners,
+		Protected:   hcl.Protected,
 		Tasks:       tasks,
 		Triggers:    triggers,
 		Notifiers:   notifiers,
+
+		NotificationPolicy: notificationPolicy,
+		RecoveryPolicy:     recoveryPolicy,
+		DeploymentHealth:   deploymentHealth,
+		ConcurrencyGroup:   hcl.ConcurrencyGroup,
+		Schedules:          schedules,
+		Upstreams:          upstreams,
 	}, nil
 }
 
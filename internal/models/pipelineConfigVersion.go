@@ -0,0 +1,49 @@
+package models
+
+import (
+	"time"
+
+	"github.com/clintjedwards/gofer/proto"
+)
+
+// PipelineConfigVersion is a snapshot of a pipeline's configuration taken every time the pipeline is created or
+// updated. Past versions allow a pipeline to be rolled back to a previous configuration.
+type PipelineConfigVersion struct {
+	Namespace string         `json:"namespace"`                    // The namespace the pipeline belongs to.
+	Pipeline  string         `json:"pipeline"`                     // The unique ID of the related pipeline.
+	Version   int64          `json:"version" storm:"id,increment"` // Autoincrementing version number; starts at 1.
+	Location  string         `json:"location"`                     // Where the configuration was received from.
+	Created   int64          `json:"created"`                      // Time the version was registered in epoch milli.
+	Config    PipelineConfig `json:"config"`                       // The configuration as it was at the time.
+
+	// Canary versions were registered to be tried out on some runs and were never the pipeline's config version
+	// themselves. Promoting a canary records its config again as a regular version.
+	Canary bool `json:"canary"`
+}
+
+func NewPipelineConfigVersion(namespace, pipeline, location string, config PipelineConfig) *PipelineConfigVersion {
+	return &PipelineConfigVersion{
+		Namespace: namespace,
+		Pipeline:  pipeline,
+		Location:  location,
+		Created:   time.Now().UnixMilli(),
+		Config:    config,
+	}
+}
+
+func (v *PipelineConfigVersion) ToProto() *proto.PipelineConfigVersion {
+	tasks := []string{}
+	for _, task := range v.Config.Tasks {
+		tasks = append(tasks, task.ID)
+	}
+
+	return &proto.PipelineConfigVersion{
+		NamespaceId: v.Namespace,
+		PipelineId:  v.Pipeline,
+		Version:     v.Version,
+		Location:    v.Location,
+		Created:     v.Created,
+		Tasks:       tasks,
+		Canary:      v.Canary,
+	}
+}
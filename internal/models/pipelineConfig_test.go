@@ -40,6 +40,10 @@ func TestFullParse(t *testing.T) {
 
 	task "2" "hello_world" {
 		description = "test description 1"
+		gpus {
+			count = 1
+			driver = "nvidia"
+		}
 	}
 
 	notify "log" "test_notifier" {
@@ -68,8 +72,20 @@ func TestFullParse(t *testing.T) {
 					"LOGS_HEADER":        "example test string 123",
 					"SECRET_LOGS_HEADER": "secret{{ secret_logs_header }}",
 				},
+				Artifacts: map[string]TaskArtifact{},
+				Caches:    map[string]TaskCache{},
+				CapAdd:    []string{},
+			},
+			{
+				ID:          "2",
+				Description: "test description 1",
+				Image:       "hello_world",
+				DependsOn:   map[string]RequiredParentState{},
+				Artifacts:   map[string]TaskArtifact{},
+				Caches:      map[string]TaskCache{},
+				GPUs:        &TaskGPUs{Count: 1, Driver: "nvidia"},
+				CapAdd:      []string{},
 			},
-			{ID: "2", Description: "test description 1", Image: "hello_world", DependsOn: map[string]RequiredParentState{}},
 		},
 		Triggers: []PipelineTriggerConfig{
 			{
@@ -92,6 +108,10 @@ func TestFullParse(t *testing.T) {
 				Config: map[string]string{"every": "20"},
 			},
 		},
+		Owners:         []string{},
+		RecoveryPolicy: RecoveryPolicyResume,
+		Schedules:      []PipelineSchedule{},
+		Upstreams:      []PipelineUpstream{},
 	}
 
 	hclconf := HCLPipelineConfig{}
@@ -174,3 +194,187 @@ func TestIsRestrictedCharSet(t *testing.T) {
 		})
 	}
 }
+
+func TestRecoveryPolicy(t *testing.T) {
+	tests := map[string]struct {
+		policy   string
+		hasError bool
+		want     RecoveryPolicy
+	}{
+		"default": {
+			policy: "",
+			want:   RecoveryPolicyResume,
+		},
+		"mixed case": {
+			policy: "ReRun",
+			want:   RecoveryPolicyRerun,
+		},
+		"unknown": {
+			policy:   "retry",
+			hasError: true,
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			hclconf := HCLPipelineConfig{
+				ID:             "test_pipeline",
+				Name:           "test pipeline",
+				Tasks:          []HCLPipelineTaskConfig{{ID: "1", ImageName: "hello_world"}},
+				RecoveryPolicy: tc.policy,
+			}
+
+			err := hclconf.Validate()
+			if tc.hasError {
+				if err == nil {
+					t.Fatalf("expected recovery policy %q to be rejected", tc.policy)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			conf, err := FromHCL(&hclconf)
+			if err != nil {
+				t.Fatal(err)
+			}
+
+			if conf.RecoveryPolicy != tc.want {
+				t.Errorf("expected recovery policy %q; got %q", tc.want, conf.RecoveryPolicy)
+			}
+		})
+	}
+}
+
+func TestNotificationPolicyEvaluate(t *testing.T) {
+	policy := NotificationPolicy{OnRecovery: true, OnSuccessAfterFailures: 3}
+
+	tests := map[string]struct {
+		state    RunState
+		failures int64
+		want     NotificationReason
+	}{
+		"failure not notified": {
+			state:    RunFailed,
+			failures: 0,
+			want:     NotificationReasonNone,
+		},
+		"success without failures": {
+			state:    RunSuccess,
+			failures: 0,
+			want:     NotificationReasonNone,
+		},
+		"recovery": {
+			state:    RunSuccess,
+			failures: 1,
+			want:     NotificationReasonRecovery,
+		},
+		"success after failures": {
+			state:    RunSuccess,
+			failures: 3,
+			want:     NotificationReasonSuccessAfterFailures,
+		},
+		"cancelled": {
+			state:    RunCancelled,
+			failures: 5,
+			want:     NotificationReasonNone,
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(r *testing.T) {
+			got := policy.Evaluate(tc.state, tc.failures)
+			if got != tc.want {
+				r.Errorf("Evaluate(%s, %d) = %q; want %q", tc.state, tc.failures, got, tc.want)
+			}
+		})
+	}
+}
+
+func TestPipelineDeploymentRecord(t *testing.T) {
+	tests := map[string]struct {
+		states []RunState
+		want   DeploymentResult
+	}{
+		"not enough runs": {
+			states: []RunState{RunSuccess},
+			want:   DeploymentResultPending,
+		},
+		"cancelled runs don't count": {
+			states: []RunState{RunSuccess, RunCancelled, RunSuccess},
+			want:   DeploymentResultPending,
+		},
+		"healthy": {
+			states: []RunState{RunSuccess, RunSuccess, RunSuccess},
+			want:   DeploymentResultHealthy,
+		},
+		"failed": {
+			states: []RunState{RunSuccess, RunFailed},
+			want:   DeploymentResultFailed,
+		},
+	}
+
+	for name, tc := range tests {
+		t.Run(name, func(t *testing.T) {
+			deployment := NewPipelineDeployment(2, 1, DeploymentHealth{RequiredSuccesses: 3})
+
+			var got DeploymentResult
+			for _, state := range tc.states {
+				got = deployment.Record(state)
+			}
+
+			if got != tc.want {
+				t.Errorf("expected deployment result %q; got %q", tc.want, got)
+			}
+		})
+	}
+}
+
+func TestInterpolations(t *testing.T) {
+	config := PipelineConfig{
+		Tasks: []Task{
+			{
+				ID: "build",
+				EnvVars: map[string]string{
+					"PLAIN":   "just a value",
+					"SECRET":  "secret{{ api_key }}",
+					"TYPO":    "secrets{{ api_key }}",
+					"NO_KEY":  "pipeline{{ }}",
+					"RUN_KEY": "run{{build_output}}",
+				},
+				RegistryAuth: RegistryAuth{User: "me", Pass: "pipeline{{ pass }}"},
+			},
+		},
+		Notifiers: []PipelineNotifierConfig{
+			{Label: "slack", Config: map[string]string{"token": "secret{{ slack_token }}"}},
+		},
+	}
+
+	interpolations := config.Interpolations()
+
+	got := map[string]bool{}
+	for _, interpolation := range interpolations {
+		got[interpolation.Location] = interpolation.Validate() == nil
+	}
+
+	want := map[string]bool{
+		`task "build" env var "NO_KEY"`:    false,
+		`task "build" env var "RUN_KEY"`:   true,
+		`task "build" env var "SECRET"`:    true,
+		`task "build" env var "TYPO"`:      false,
+		`task "build" registry_auth pass`:  false,
+		`notifier "slack" setting "token"`: false,
+	}
+
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("unexpected interpolations (-want +got):\n%s", diff)
+	}
+
+	for _, interpolation := range interpolations {
+		if interpolation.Location == `task "build" env var "SECRET"` &&
+			(interpolation.Prefix != "secret" || interpolation.Key != "api_key") {
+			t.Errorf("expected secret key \"api_key\"; got %s{{ %s }}", interpolation.Prefix, interpolation.Key)
+		}
+	}
+}
@@ -0,0 +1,26 @@
+package models
+
+import "github.com/clintjedwards/gofer/proto"
+
+// RegistryCredential is the login for a container registry that the scheduler uses to pull the images of a
+// namespace's task runs. The password is kept within the secret store.
+type RegistryCredential struct {
+	ID          int64  `json:"id" storm:"id,increment"` // Unique identifier; auto-incrementing.
+	NamespaceID string `json:"namespace_id" storm:"index"`
+	Registry    string `json:"registry"` // The registry and optionally a repository path(ex. "ghcr.io/myorg").
+	User        string `json:"user"`
+	Created     int64  `json:"created"`
+	Updated     int64  `json:"updated"`
+}
+
+// ToProto returns the registry credential; its password is never handed out.
+func (c *RegistryCredential) ToProto() *proto.RegistryCredential {
+	return &proto.RegistryCredential{
+		Id:          c.ID,
+		NamespaceId: c.NamespaceID,
+		Registry:    c.Registry,
+		User:        c.User,
+		Created:     c.Created,
+		Updated:     c.Updated,
+	}
+}
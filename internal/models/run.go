@@ -1,6 +1,8 @@
 package models
 
 import (
+	"crypto/rand"
+	"fmt"
 	"time"
 
 	"github.com/clintjedwards/gofer/proto"
@@ -26,6 +28,7 @@ const (
 	RunFailureKindSchedulerError     RunFailureKind = "SCHEDULER_ERROR"     // Run could not be scheduled properly.
 	RunFailureKindFailedPrecondition RunFailureKind = "FAILED_PRECONDITION" // Run parameters were not acceptable.
 	RunFailureKindCancelled          RunFailureKind = "CANCELLED"           // Run or child task runs were cancelled due to user action.
+	RunFailureKindInterrupted        RunFailureKind = "INTERRUPTED"         // Run was in progress when Gofer stopped unexpectedly.
 )
 
 // Run represents one or more tasks being executed on behalf of some trigger(manual or otherwise). Run is a third level
@@ -49,6 +52,77 @@ type Run struct {
 	Variables      map[string]string `json:"variables"`
 	Objects        []string          `json:"objects"`         // Object keys that are stored at the run level.
 	ObjectsExpired bool              `json:"objects_expired"` // Tracks whether objects for this run have expired already.
+
+	// The run this run was resumed from. Tasks that were not run again take their results from the previous run.
+	ResumedFrom int64 `json:"resumed_from"`
+
+	// The service account that started the run(ex. "service_account:deploy-bot"). Runs started by the triggers of a
+	// pipeline owned by a service account are attributed to it. Empty if the run was not started by a service account.
+	Initiator string `json:"initiator"`
+
+	// Generated when the run is created and passed along to its task runs, events, scheduler calls and logs so that
+	// everything that happened as part of the run can be tied together, even across systems.
+	CorrelationID string `json:"correlation_id"`
+
+	// Set if the run was in progress when Gofer stopped and had to be recovered on startup. Nil otherwise.
+	Recovery *RunRecovery `json:"recovery"`
+
+	// Set if the run was started by a run of an upstream pipeline. Nil otherwise.
+	Upstream *RunUpstream `json:"upstream"`
+
+	// The canary config version the run took its tasks from instead of the pipeline's current config version. 0 if
+	// the run used the current config version.
+	CanaryVersion int64 `json:"canary_version"`
+
+	// The pipeline config version the run took its tasks from. 0 if the pipeline had no recorded config version when
+	// the run started.
+	ConfigVersion int64 `json:"config_version"`
+}
+
+// RunUpstream records the chain of upstream runs that led to a run being started.
+type RunUpstream struct {
+	// The correlation ID of the first run of the chain; the one that wasn't started by an upstream pipeline.
+	CorrelationID string `json:"correlation_id"`
+
+	// The pipelines of the runs earlier in the chain, starting with the first.
+	Pipelines []string `json:"pipelines"`
+}
+
+// Depth returns how many pipelines down the chain the run is.
+func (u *RunUpstream) Depth() int {
+	return len(u.Pipelines)
+}
+
+// Includes returns whether a run of the given pipeline is part of the chain.
+func (u *RunUpstream) Includes(pipeline string) bool {
+	for _, id := range u.Pipelines {
+		if id == pipeline {
+			return true
+		}
+	}
+
+	return false
+}
+
+// RunRecovery records what happened to a run that was in progress when Gofer stopped.
+type RunRecovery struct {
+	Recovered int64          `json:"recovered"` // Time of recovery in epoch milli.
+	Policy    RecoveryPolicy `json:"policy"`    // The recovery policy of the pipeline at the time of recovery.
+	RerunID   int64          `json:"rerun_id"`  // The run started in its place if the policy was to re-run it.
+}
+
+func (r *RunRecovery) ToProto() *proto.RunRecovery {
+	return &proto.RunRecovery{
+		Recovered: r.Recovered,
+		Policy:    proto.Pipeline_RecoveryPolicy(proto.Pipeline_RecoveryPolicy_value[string(r.Policy)]),
+		RerunId:   r.RerunID,
+	}
+}
+
+func (r *RunRecovery) FromProto(proto *proto.RunRecovery) {
+	r.Recovered = proto.Recovered
+	r.Policy = RecoveryPolicy(proto.Policy.String())
+	r.RerunID = proto.RerunId
 }
 
 type RunFailure struct {
@@ -67,9 +141,18 @@ func NewRun(pipelineID, namespaceID, triggerKind, triggerName string, taskFilter
 		Only:        taskFilter,
 		Variables:   vars,
 		Objects:     []string{},
+
+		CorrelationID: newCorrelationID(),
 	}
 }
 
+// newCorrelationID returns a random identifier for a run.
+func newCorrelationID() string {
+	b := make([]byte, 16)
+	_, _ = rand.Read(b)
+	return fmt.Sprintf("%x", b)
+}
+
 // IsComplete returns whether the run has completed and no further state changes will be made.
 func (r *Run) IsComplete() bool {
 	if r.State == RunFailed ||
@@ -117,6 +200,11 @@ func (r *Run) ToProto() *proto.Run {
 		protoOnly = append(protoOnly, id)
 	}
 
+	var recovery *proto.RunRecovery
+	if r.Recovery != nil {
+		recovery = r.Recovery.ToProto()
+	}
+
 	return &proto.Run{
 		Ended:          r.Ended,
 		Id:             r.ID,
@@ -132,6 +220,12 @@ func (r *Run) ToProto() *proto.Run {
 		Variables:      r.Variables,
 		Objects:        r.Objects,
 		ObjectsExpired: r.ObjectsExpired,
+		ResumedFrom:    r.ResumedFrom,
+		Initiator:      r.Initiator,
+		CorrelationId:  r.CorrelationID,
+		Recovery:       recovery,
+		CanaryVersion:  r.CanaryVersion,
+		ConfigVersion:  r.ConfigVersion,
 	}
 }
 
@@ -155,4 +249,14 @@ func (r *Run) FromProto(proto *proto.Run) {
 	r.Objects = proto.Objects
 	r.Variables = proto.Variables
 	r.ObjectsExpired = proto.ObjectsExpired
+	r.ResumedFrom = proto.ResumedFrom
+	r.Initiator = proto.Initiator
+	r.CorrelationID = proto.CorrelationId
+	r.Recovery = nil
+	if proto.Recovery != nil {
+		r.Recovery = &RunRecovery{}
+		r.Recovery.FromProto(proto.Recovery)
+	}
+	r.CanaryVersion = proto.CanaryVersion
+	r.ConfigVersion = proto.ConfigVersion
 }
@@ -0,0 +1,43 @@
+package models
+
+import (
+	"time"
+
+	"github.com/clintjedwards/gofer/proto"
+)
+
+// ServiceAccount is an identity meant for automation rather than a person. Service accounts hold the permissions
+// for all of their tokens, can own pipelines, and are recorded as the initiator of the runs they start.
+type ServiceAccount struct {
+	ID          string    `json:"id" storm:"id"` // Unique identifier; user defined.
+	Description string    `json:"description"`   // Short description of what the service account is used for.
+	Kind        TokenKind `json:"kind"`          // The type of access the service account's tokens have.
+	Namespaces  []string  `json:"namespaces"`    // List of namespaces the service account's tokens have access to.
+	Created     int64     `json:"created"`       // The creation time in epoch milli.
+}
+
+func NewServiceAccount(id, description string, kind TokenKind, namespaces []string) *ServiceAccount {
+	return &ServiceAccount{
+		ID:          id,
+		Description: description,
+		Kind:        kind,
+		Namespaces:  namespaces,
+		Created:     time.Now().UnixMilli(),
+	}
+}
+
+// ServiceAccountInitiator returns how the given service account is referred to when recorded as the initiator of a
+// run.
+func ServiceAccountInitiator(id string) string {
+	return "service_account:" + id
+}
+
+func (s *ServiceAccount) ToProto() *proto.ServiceAccount {
+	return &proto.ServiceAccount{
+		Id:          s.ID,
+		Description: s.Description,
+		Kind:        proto.Token_Kind(proto.Token_Kind_value[string(s.Kind)]),
+		Namespaces:  s.Namespaces,
+		Created:     s.Created,
+	}
+}
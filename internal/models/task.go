@@ -1,6 +1,13 @@
 package models
 
-import "github.com/clintjedwards/gofer/proto"
+import (
+	"encoding/base64"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/proto"
+	validation "github.com/go-ozzo/ozzo-validation/v4"
+)
 
 type RequiredParentState string
 
@@ -28,6 +35,207 @@ func (r *Exec) ToProto() *proto.Exec {
 	}
 }
 
+// TaskArtifact is a file or directory a task leaves behind that is kept once the task run finishes.
+type TaskArtifact struct {
+	Name        string `json:"name"`
+	Path        string `json:"path"`         // Path within the container; relative to its working directory.
+	ContentType string `json:"content_type"` // Overrides the content type that would otherwise be detected.
+
+	// How many runs the artifact is kept for. 0 uses the server's default.
+	Expiry int64 `json:"expiry"`
+}
+
+func (a *TaskArtifact) Validate() error {
+	if strings.TrimSpace(a.Path) == "" {
+		return fmt.Errorf("artifact %q path cannot be empty", a.Name)
+	}
+
+	if a.Expiry < 0 {
+		return fmt.Errorf("artifact %q expiry cannot be negative", a.Name)
+	}
+
+	artifactDeref := *a
+	return validation.ValidateStruct(&artifactDeref,
+		validation.Field(&artifactDeref.Name, validation.Required, validation.Length(1, 80), validation.By(isRestrictedCharSet)),
+	)
+}
+
+func (a *TaskArtifact) ToProto() *proto.TaskArtifact {
+	return &proto.TaskArtifact{
+		Name:        a.Name,
+		Path:        a.Path,
+		ContentType: a.ContentType,
+		Expiry:      a.Expiry,
+	}
+}
+
+func (a *TaskArtifact) FromProto(proto *proto.TaskArtifact) {
+	a.Name = proto.Name
+	a.Path = proto.Path
+	a.ContentType = proto.ContentType
+	a.Expiry = proto.Expiry
+}
+
+// TaskCache is a set of paths within a task's container that are saved once a task run succeeds and restored into
+// later task runs of the pipeline before they start.
+type TaskCache struct {
+	Name  string   `json:"name"`
+	Key   string   `json:"key"` // Can reference the run's variables. Ex: "go-modules-{{ BRANCH }}"
+	Paths []string `json:"paths"`
+
+	// Prefixes of keys to restore from, tried in order, when there is no cache saved for the key itself. The most
+	// recently saved cache matching a prefix is restored.
+	RestoreKeys []string `json:"restore_keys"`
+}
+
+func (c *TaskCache) Validate() error {
+	if strings.TrimSpace(c.Key) == "" {
+		return fmt.Errorf("cache %q key cannot be empty", c.Name)
+	}
+
+	if len(c.Paths) == 0 {
+		return fmt.Errorf("cache %q must have at least one path", c.Name)
+	}
+
+	pathSet := map[string]struct{}{}
+	for _, path := range c.Paths {
+		if strings.TrimSpace(path) == "" {
+			return fmt.Errorf("cache %q paths cannot be empty", c.Name)
+		}
+
+		if _, exists := pathSet[path]; exists {
+			return fmt.Errorf("cache %q path %q is listed more than once", c.Name, path)
+		}
+		pathSet[path] = struct{}{}
+	}
+
+	for _, restoreKey := range c.RestoreKeys {
+		if strings.TrimSpace(restoreKey) == "" {
+			return fmt.Errorf("cache %q restore keys cannot be empty", c.Name)
+		}
+	}
+
+	cacheDeref := *c
+	return validation.ValidateStruct(&cacheDeref,
+		validation.Field(&cacheDeref.Name, validation.Required, validation.Length(1, 80), validation.By(isRestrictedCharSet)),
+	)
+}
+
+func (c *TaskCache) ToProto() *proto.TaskCache {
+	return &proto.TaskCache{
+		Name:        c.Name,
+		Key:         c.Key,
+		Paths:       c.Paths,
+		RestoreKeys: c.RestoreKeys,
+	}
+}
+
+func (c *TaskCache) FromProto(proto *proto.TaskCache) {
+	c.Name = proto.Name
+	c.Key = proto.Key
+	c.Paths = proto.Paths
+	c.RestoreKeys = proto.RestoreKeys
+}
+
+// TaskResources are the CPU and memory a task's container asks for. Requests are what the container is guaranteed
+// when there isn't enough to go around and limits are the most it can ever use. Zero leaves it to the server's
+// defaults.
+type TaskResources struct {
+	CPURequest      float64 `json:"cpu_request"` // In CPU cores. Ex: 0.5
+	CPULimit        float64 `json:"cpu_limit"`   // In CPU cores. Ex: 2
+	MemoryRequestMB int64   `json:"memory_request_mb"`
+	MemoryLimitMB   int64   `json:"memory_limit_mb"`
+}
+
+func (r *TaskResources) Validate() error {
+	if r.CPURequest < 0 || r.CPULimit < 0 {
+		return fmt.Errorf("resources cpu cannot be negative")
+	}
+
+	if r.MemoryRequestMB < 0 || r.MemoryLimitMB < 0 {
+		return fmt.Errorf("resources memory cannot be negative")
+	}
+
+	if r.CPULimit != 0 && r.CPURequest > r.CPULimit {
+		return fmt.Errorf("resources cpu_request %v cannot be more than cpu_limit %v", r.CPURequest, r.CPULimit)
+	}
+
+	if r.MemoryLimitMB != 0 && r.MemoryRequestMB > r.MemoryLimitMB {
+		return fmt.Errorf("resources memory_request_mb %d cannot be more than memory_limit_mb %d", r.MemoryRequestMB,
+			r.MemoryLimitMB)
+	}
+
+	return nil
+}
+
+func (r *TaskResources) ToProto() *proto.TaskResources {
+	return &proto.TaskResources{
+		CpuRequest:      r.CPURequest,
+		CpuLimit:        r.CPULimit,
+		MemoryRequestMb: r.MemoryRequestMB,
+		MemoryLimitMb:   r.MemoryLimitMB,
+	}
+}
+
+func (r *TaskResources) FromProto(proto *proto.TaskResources) {
+	r.CPURequest = proto.CpuRequest
+	r.CPULimit = proto.CpuLimit
+	r.MemoryRequestMB = proto.MemoryRequestMb
+	r.MemoryLimitMB = proto.MemoryLimitMb
+}
+
+// TaskGPUs are the GPUs a task's container asks for.
+type TaskGPUs struct {
+	Count        int64    `json:"count"`        // How many GPUs; -1 for all of them.
+	DeviceIDs    []string `json:"device_ids"`   // Specific GPUs by index or UUID; can't be combined with count.
+	Driver       string   `json:"driver"`       // Ex: "nvidia"; left to the scheduler when empty.
+	Capabilities []string `json:"capabilities"` // Ex: ["compute", "utility"]; defaults to ["gpu"].
+}
+
+func (g *TaskGPUs) Validate() error {
+	if g.Count < -1 {
+		return fmt.Errorf("gpus count must be -1 for all gpus or a positive number")
+	}
+
+	if g.Count != 0 && len(g.DeviceIDs) > 0 {
+		return fmt.Errorf("gpus count and device_ids cannot be combined")
+	}
+
+	if g.Count == 0 && len(g.DeviceIDs) == 0 {
+		return fmt.Errorf("gpus requires either a count or device_ids")
+	}
+
+	for _, id := range g.DeviceIDs {
+		if strings.TrimSpace(id) == "" {
+			return fmt.Errorf("gpus device_ids cannot be empty")
+		}
+	}
+
+	for _, capability := range g.Capabilities {
+		if strings.TrimSpace(capability) == "" {
+			return fmt.Errorf("gpus capabilities cannot be empty")
+		}
+	}
+
+	return nil
+}
+
+func (g *TaskGPUs) ToProto() *proto.TaskGPUs {
+	return &proto.TaskGPUs{
+		Count:        g.Count,
+		DeviceIds:    g.DeviceIDs,
+		Driver:       g.Driver,
+		Capabilities: g.Capabilities,
+	}
+}
+
+func (g *TaskGPUs) FromProto(proto *proto.TaskGPUs) {
+	g.Count = proto.Count
+	g.DeviceIDs = proto.DeviceIds
+	g.Driver = proto.Driver
+	g.Capabilities = proto.Capabilities
+}
+
 type Task struct {
 	ID           string                         `json:"id"`
 	Description  string                         `json:"description"`
@@ -38,6 +246,33 @@ type Task struct {
 	// Secrets are passed in the exact same way as env_vars; but we don't allow the user to list the values.
 	Secrets map[string]string `json:"secrets"`
 	Exec    Exec              `json:"exec"` // Exec is a representation of a script to be run via container.
+
+	// How many more times the task is attempted after it fails. Attempts are spaced out by the backoff, which doubles
+	// after every attempt.
+	Retries int64 `json:"retries"`
+	Backoff int64 `json:"backoff"` // Seconds to wait before the first retry.
+
+	// A condition the run's variables must meet for the task to be run. Tasks whose condition isn't met are skipped.
+	When string `json:"when"`
+
+	// Files and directories collected from the task run's container once it finishes, keyed by name.
+	Artifacts map[string]TaskArtifact `json:"artifacts"`
+
+	// Paths saved once the task run succeeds and restored into later task runs before they start, keyed by name.
+	Caches map[string]TaskCache `json:"caches"`
+
+	// The CPU and memory the task run's container asks for.
+	Resources TaskResources `json:"resources"`
+
+	// The GPUs the task run's container asks for. Nil if the task doesn't need any.
+	GPUs *TaskGPUs `json:"gpus"`
+
+	// Privileged runs the task run's container with every capability and access to the host's devices. Only allowed
+	// for the namespaces and pipelines the server's admins have allowed it for.
+	Privileged bool `json:"privileged"`
+
+	// Linux capabilities added to the task run's container(ex. "SYS_ADMIN"). Allowed the same way as Privileged.
+	CapAdd []string `json:"cap_add"`
 }
 
 func (r *Task) ToProto() *proto.Task {
@@ -53,6 +288,21 @@ func (r *Task) ToProto() *proto.Task {
 		secrets = append(secrets, key)
 	}
 
+	artifacts := map[string]*proto.TaskArtifact{}
+	for name, artifact := range r.Artifacts {
+		artifacts[name] = artifact.ToProto()
+	}
+
+	caches := map[string]*proto.TaskCache{}
+	for name, cache := range r.Caches {
+		caches[name] = cache.ToProto()
+	}
+
+	var gpus *proto.TaskGPUs
+	if r.GPUs != nil {
+		gpus = r.GPUs.ToProto()
+	}
+
 	return &proto.Task{
 		Id:          r.ID,
 		Description: r.Description,
@@ -61,5 +311,160 @@ func (r *Task) ToProto() *proto.Task {
 		EnvVars:     r.EnvVars,
 		Secrets:     secrets,
 		Exec:        r.Exec.ToProto(),
+		Retries:     r.Retries,
+		Backoff:     r.Backoff,
+		When:        r.When,
+		Artifacts:   artifacts,
+		Caches:      caches,
+		Resources:   r.Resources.ToProto(),
+		Gpus:        gpus,
+		Privileged:  r.Privileged,
+		CapAdd:      r.CapAdd,
+	}
+}
+
+// FromProto fills in the task from its proto representation. Secrets are left out since the proto only carries their
+// keys.
+func (r *Task) FromProto(proto *proto.Task) {
+	dependsOn := map[string]RequiredParentState{}
+	for key, value := range proto.DependsOn {
+		dependsOn[key] = RequiredParentState(value.String())
+	}
+
+	r.ID = proto.Id
+	r.Description = proto.Description
+	r.Image = proto.Image
+	r.DependsOn = dependsOn
+	r.EnvVars = proto.EnvVars
+	if r.EnvVars == nil {
+		r.EnvVars = map[string]string{}
+	}
+	r.Secrets = map[string]string{}
+	r.Exec = Exec{}
+	if proto.Exec != nil {
+		r.Exec = Exec{
+			Shell:  proto.Exec.Shell,
+			Script: proto.Exec.Script,
+		}
+	}
+	r.Retries = proto.Retries
+	r.Backoff = proto.Backoff
+	r.When = proto.When
+	r.Artifacts = map[string]TaskArtifact{}
+	for name, protoArtifact := range proto.Artifacts {
+		artifact := TaskArtifact{}
+		artifact.FromProto(protoArtifact)
+		r.Artifacts[name] = artifact
+	}
+	r.Caches = map[string]TaskCache{}
+	for name, protoCache := range proto.Caches {
+		cache := TaskCache{}
+		cache.FromProto(protoCache)
+		r.Caches[name] = cache
+	}
+	r.Resources = TaskResources{}
+	if proto.Resources != nil {
+		r.Resources.FromProto(proto.Resources)
 	}
+	r.GPUs = nil
+	if proto.Gpus != nil {
+		r.GPUs = &TaskGPUs{}
+		r.GPUs.FromProto(proto.Gpus)
+	}
+	r.Privileged = proto.Privileged
+	r.CapAdd = proto.CapAdd
+}
+
+// Validate checks a task that didn't come from a pipeline config, holding it to the same rules.
+func (r *Task) Validate() error {
+	if r.Backoff < 0 {
+		return fmt.Errorf("backoff cannot be negative")
+	}
+
+	if _, err := base64.StdEncoding.DecodeString(r.Exec.Script); err != nil {
+		return fmt.Errorf("exec script must be base64 encoded; %v", err)
+	}
+
+	if r.When != "" {
+		_, err := parseTaskCondition(r.When)
+		if err != nil {
+			return err
+		}
+	}
+
+	for name, artifact := range r.Artifacts {
+		err := artifact.Validate()
+		if err != nil {
+			return err
+		}
+
+		if name != artifact.Name {
+			return fmt.Errorf("artifact %q is keyed under a different name %q", artifact.Name, name)
+		}
+	}
+
+	for name, cache := range r.Caches {
+		err := cache.Validate()
+		if err != nil {
+			return err
+		}
+
+		if name != cache.Name {
+			return fmt.Errorf("cache %q is keyed under a different name %q", cache.Name, name)
+		}
+	}
+
+	err := r.Resources.Validate()
+	if err != nil {
+		return err
+	}
+
+	if r.GPUs != nil {
+		err := r.GPUs.Validate()
+		if err != nil {
+			return err
+		}
+	}
+
+	for _, capability := range r.CapAdd {
+		if !isCapability(capability) {
+			return fmt.Errorf("cap_add %q is not a linux capability name(ex. \"SYS_ADMIN\")", capability)
+		}
+	}
+
+	for parent, state := range r.DependsOn {
+		if state != RequiredParentStateAny && state != RequiredParentStateSuccess && state != RequiredParentStateFail {
+			return fmt.Errorf("dependency %q has invalid required state %q", parent, state)
+		}
+	}
+
+	taskDeref := *r
+	return validation.ValidateStruct(&taskDeref,
+		validation.Field(&taskDeref.ID, validation.Required, validation.Length(1, 80), validation.By(isRestrictedCharSet)),
+		validation.Field(&taskDeref.Description, validation.Length(0, 3000)),
+		validation.Field(&taskDeref.Image, validation.Required),
+		validation.Field(&taskDeref.Retries, validation.Min(int64(0)), validation.Max(int64(10))),
+	)
+}
+
+// isCapability checks that a capability looks like a Linux capability name(ex. "NET_ADMIN" or "CAP_NET_ADMIN"). Whether
+// the capability exists is left to the scheduler.
+func isCapability(capability string) bool {
+	if capability == "" {
+		return false
+	}
+
+	for _, char := range capability {
+		if (char < 'A' || char > 'Z') && (char < 'a' || char > 'z') && char != '_' {
+			return false
+		}
+	}
+
+	return true
+}
+
+// NormalizeCapability returns the canonical form of a Linux capability name; upper case and without the "CAP_"
+// prefix. Ex: "cap_sys_admin" becomes "SYS_ADMIN".
+func NormalizeCapability(capability string) string {
+	return strings.TrimPrefix(strings.ToUpper(strings.TrimSpace(capability)), "CAP_")
 }
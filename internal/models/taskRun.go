@@ -28,6 +28,7 @@ const (
 	TaskRunFailureKindFailedPrecondition TaskRunFailureKind = "FAILED_PRECONDITION" // User error in task run parameters.
 	TaskRunFailureKindCancelled          TaskRunFailureKind = "CANCELLED"           // User invoked cancellation.
 	TaskRunFailureKindOrphaned           TaskRunFailureKind = "ORPHANED"            // Task run was lost due to extreme internal error.
+	TaskRunFailureKindConditionNotMet    TaskRunFailureKind = "CONDITION_NOT_MET"   // Task's condition was false for the run.
 )
 
 // TaskRun is a specific execution of a task/container.
@@ -38,13 +39,18 @@ type TaskRun struct {
 	ExitCode    int            `json:"exit_code"`             // The exit code of the task run.
 	Failure     TaskRunFailure `json:"failure"`               // Detailed reasoning on task run failure.
 	ID          string         `json:"id" storm:"id"`         // Unique ID for task run; taken from the taskID.
+	ImagePull   int64          `json:"image_pull"`            // Time spent pulling the task run's image in milliseconds.
 	LogsExpired bool           `json:"logs_expired"`          // If the logs have past their retention time.
 
 	// If the logs have been removed. This can be due to user request or automatic action based on expiry time.
-	LogsRemoved bool   `json:"logs_removed"`
-	NamespaceID string `json:"namespace_id"` // Unique identifier for namespace.
-	PipelineID  string `json:"pipeline_id"`  // Unique pipeline ID of task run.
-	RunID       int64  `json:"run_id"`       // Unique run ID of task run; sequential.
+	LogsRemoved bool `json:"logs_removed"`
+
+	// If the logs were copied to the object store once the task run finished, so they can still be read after they're
+	// removed from the server's disk.
+	LogsArchived bool   `json:"logs_archived"`
+	NamespaceID  string `json:"namespace_id"` // Unique identifier for namespace.
+	PipelineID   string `json:"pipeline_id"`  // Unique pipeline ID of task run.
+	RunID        int64  `json:"run_id"`       // Unique run ID of task run; sequential.
 
 	// The identifier used by the scheduler to identify this specific task run container. This is provided by the
 	// scheduler.
@@ -52,6 +58,18 @@ type TaskRun struct {
 	Started     int64          `json:"started" storm:"index"` // Time of task run actual start in epoch milliseconds.
 	State       ContainerState `json:"status"`
 	Task        `json:"task"`  // Task information.
+
+	// Taken from the task run's run. Ties the task run to everything else that happened as part of the run.
+	CorrelationID string `json:"correlation_id"`
+
+	// Which attempt at running the task this is, starting at 1. Task runs created before retries existed have 0.
+	Attempt int64 `json:"attempt"`
+	// The attempts that failed before the current one, oldest first.
+	PreviousAttempts []TaskRunAttempt `json:"previous_attempts"`
+
+	// The task run that added this task run to its run while the run was in progress. Empty for task runs that come
+	// from the pipeline config.
+	SpawnedBy string `json:"spawned_by"`
 }
 
 type TaskRunFailure struct {
@@ -59,6 +77,45 @@ type TaskRunFailure struct {
 	Description string             `json:"description"` // Details on why the task run failed.
 }
 
+// TaskRunAttempt is a previous attempt at running a task run's container that failed and was retried.
+type TaskRunAttempt struct {
+	Attempt     int64          `json:"attempt"`
+	Started     int64          `json:"started"`
+	Ended       int64          `json:"ended"`
+	ExitCode    int            `json:"exit_code"`
+	Failure     TaskRunFailure `json:"failure"`
+	SchedulerID string         `json:"scheduler_id"`
+}
+
+func (a *TaskRunAttempt) ToProto() *proto.TaskRunAttempt {
+	return &proto.TaskRunAttempt{
+		Attempt:  a.Attempt,
+		Started:  a.Started,
+		Ended:    a.Ended,
+		ExitCode: int64(a.ExitCode),
+		Failure: &proto.TaskRunFailure{
+			Kind:        proto.TaskRunFailure_Kind(proto.TaskRunFailure_Kind_value[string(a.Failure.Kind)]),
+			Description: a.Failure.Description,
+		},
+		SchedulerId: a.SchedulerID,
+	}
+}
+
+func (a *TaskRunAttempt) FromProto(proto *proto.TaskRunAttempt) {
+	a.Attempt = proto.Attempt
+	a.Started = proto.Started
+	a.Ended = proto.Ended
+	a.ExitCode = int(proto.ExitCode)
+	a.Failure = TaskRunFailure{}
+	if proto.Failure != nil {
+		a.Failure = TaskRunFailure{
+			Kind:        TaskRunFailureKind(proto.Failure.Kind.String()),
+			Description: proto.Failure.Description,
+		}
+	}
+	a.SchedulerID = proto.SchedulerId
+}
+
 func NewTaskRun(run Run, task Task) *TaskRun {
 	return &TaskRun{
 		ID:          task.ID,
@@ -68,6 +125,9 @@ func NewTaskRun(run Run, task Task) *TaskRun {
 		PipelineID:  run.PipelineID,
 		NamespaceID: run.NamespaceID,
 		Task:        task,
+
+		CorrelationID: run.CorrelationID,
+		Attempt:       1,
 	}
 }
 
@@ -97,6 +157,51 @@ func (r *TaskRun) SetFinishedSuccess() {
 	r.Ended = time.Now().UnixMilli()
 }
 
+// CanRetry returns whether the task run failed in a way that is worth attempting again and still has retries left.
+// Task runs that failed before their container was started, or that were cancelled, are never retried.
+func (r *TaskRun) CanRetry() bool {
+	if r.State != ContainerStateFailed {
+		return false
+	}
+
+	if r.Failure.Kind != TaskRunFailureKindAbnormalExit && r.Failure.Kind != TaskRunFailureKindSchedulerError {
+		return false
+	}
+
+	return r.Attempt <= r.Retries
+}
+
+// RetryBackoff returns how long to wait before the next attempt. The backoff doubles after every attempt.
+func (r *TaskRun) RetryBackoff() time.Duration {
+	if r.Attempt < 1 {
+		return time.Duration(r.Backoff) * time.Second
+	}
+
+	return time.Duration(r.Backoff) * time.Second * time.Duration(int64(1)<<(r.Attempt-1))
+}
+
+// NextAttempt records the current attempt as a previous one and resets the task run so that it can be attempted
+// again.
+func (r *TaskRun) NextAttempt() {
+	r.PreviousAttempts = append(r.PreviousAttempts, TaskRunAttempt{
+		Attempt:     r.Attempt,
+		Started:     r.Started,
+		Ended:       r.Ended,
+		ExitCode:    r.ExitCode,
+		Failure:     r.Failure,
+		SchedulerID: r.SchedulerID,
+	})
+
+	r.Attempt++
+	r.State = ContainerStateWaiting
+	r.Started = 0
+	r.Ended = 0
+	r.ExitCode = 0
+	r.Failure = TaskRunFailure{}
+	r.SchedulerID = ""
+	r.ImagePull = 0
+}
+
 func (r *TaskRun) ToProto() *proto.TaskRun {
 	protoFailure := proto.TaskRunFailure{
 		Kind:        proto.TaskRunFailure_Kind(proto.TaskRunFailure_Kind_value[string(r.Failure.Kind)]),
@@ -108,12 +213,18 @@ func (r *TaskRun) ToProto() *proto.TaskRun {
 		dependsOn[key] = proto.TaskRequiredParentState(proto.TaskRequiredParentState_value[string(value)])
 	}
 
+	previousAttempts := []*proto.TaskRunAttempt{}
+	for _, attempt := range r.PreviousAttempts {
+		previousAttempts = append(previousAttempts, attempt.ToProto())
+	}
+
 	return &proto.TaskRun{
 		Ended:       r.Ended,
 		ExitCode:    int64(r.ExitCode),
 		Created:     r.Created,
 		Failure:     &protoFailure,
 		Id:          r.ID,
+		ImagePull:   r.ImagePull,
 		LogsExpired: r.LogsExpired,
 		LogsRemoved: r.LogsRemoved,
 		PipelineId:  r.PipelineID,
@@ -123,6 +234,12 @@ func (r *TaskRun) ToProto() *proto.TaskRun {
 		Started:     r.Started,
 		State:       proto.TaskRun_State(proto.TaskRun_State_value[string(r.State)]),
 		Task:        r.Task.ToProto(),
+
+		CorrelationId:    r.CorrelationID,
+		LogsArchived:     r.LogsArchived,
+		Attempt:          r.Attempt,
+		PreviousAttempts: previousAttempts,
+		SpawnedBy:        r.SpawnedBy,
 	}
 }
 
@@ -142,6 +259,7 @@ func (r *TaskRun) FromProto(proto *proto.TaskRun) {
 	r.Created = proto.Created
 	r.Failure = failure
 	r.ID = proto.Id
+	r.ImagePull = proto.ImagePull
 	r.LogsExpired = proto.LogsExpired
 	r.LogsRemoved = proto.LogsRemoved
 	r.PipelineID = proto.PipelineId
@@ -155,4 +273,17 @@ func (r *TaskRun) FromProto(proto *proto.TaskRun) {
 	r.Image = proto.Task.Image
 	r.DependsOn = dependsOn
 	r.EnvVars = proto.Task.EnvVars
+	r.CorrelationID = proto.CorrelationId
+	r.LogsArchived = proto.LogsArchived
+	r.Retries = proto.Task.Retries
+	r.Backoff = proto.Task.Backoff
+	r.When = proto.Task.When
+	r.Attempt = proto.Attempt
+	r.SpawnedBy = proto.SpawnedBy
+	r.PreviousAttempts = []TaskRunAttempt{}
+	for _, protoAttempt := range proto.PreviousAttempts {
+		attempt := TaskRunAttempt{}
+		attempt.FromProto(protoAttempt)
+		r.PreviousAttempts = append(r.PreviousAttempts, attempt)
+	}
 }
@@ -12,6 +12,10 @@ const (
 	TokenKindUnknown    TokenKind = "UNKNOWN"
 	TokenKindManagement TokenKind = "MANAGEMENT"
 	TokenKindClient     TokenKind = "CLIENT"
+
+	// TokenKindNamespaceAdmin tokens have client access to their namespaces and are additionally able to manage
+	// them(ex. create tokens and service accounts for them) without a management token.
+	TokenKindNamespaceAdmin TokenKind = "NAMESPACE_ADMIN"
 )
 
 // Token is a representation of the API key, belonging to an owner.
@@ -21,8 +25,49 @@ type Token struct {
 	Kind       TokenKind         `json:"kind"`            // The type of token. Management tokens are essentially root.
 	Namespaces []string          `json:"namespaces"`      // List of namespaces this token has access to.
 	Metadata   map[string]string `json:"metadata"`        // Extra information about this token in label form.
+
+	// LDAPUser is the directory user the token was created for. The kind and namespaces of such tokens are derived from
+	// the user's directory groups and refreshed periodically.
+	LDAPUser string `json:"ldap_user"`
+
+	// ServiceAccount is the service account the token belongs to. Such tokens take their kind and namespaces from the
+	// service account.
+	ServiceAccount string `json:"service_account"`
+
+	// OIDCSubject is the single sign-on user the token was exchanged for.
+	OIDCSubject string `json:"oidc_subject"`
+
+	// AllowedCIDRs limits the addresses the token can be used from. An empty list allows any address.
+	AllowedCIDRs []string `json:"allowed_cidrs"`
+
+	// Run is the run the token was created for. Task runs use such tokens to talk to Gofer, and only they may add
+	// task runs to that run.
+	Run *TokenRun `json:"run"`
+
+	// Expires is the time the token stops working in epoch milli. 0 means the token never expires.
+	Expires int64 `json:"expires"`
+
+	// ReplacedBy is the hash of the token that replaced this one when it was rotated. Rotated tokens keep working
+	// until the end of their grace period.
+	ReplacedBy string `json:"replaced_by"`
+
+	// Usage of the token. Usage is collected in memory and written periodically rather than on every request.
+	LastUsed        int64           `json:"last_used"`         // Last time the token was used in epoch milli.
+	LastUsedAddress string          `json:"last_used_address"` // The address the token was last used from.
+	TotalRequests   int64           `json:"total_requests"`    // Total requests made with the token.
+	HourlyRequests  map[int64]int64 `json:"hourly_requests"`   // Requests per hour, keyed by the hour in epoch milli.
+}
+
+// TokenRun identifies the run a run token was created for.
+type TokenRun struct {
+	NamespaceID string `json:"namespace_id"`
+	PipelineID  string `json:"pipeline_id"`
+	RunID       int64  `json:"run_id"`
 }
 
+// TokenUsageWindow is how long the per hour request counts of a token are kept.
+const TokenUsageWindow = 24 * time.Hour
+
 func NewToken(hash string, kind TokenKind, namespaces []string, metadata map[string]string) *Token {
 	return &Token{
 		Created:    time.Now().UnixMilli(),
@@ -33,11 +78,74 @@ func NewToken(hash string, kind TokenKind, namespaces []string, metadata map[str
 	}
 }
 
+// Expired returns whether the token has stopped working.
+func (t *Token) Expired() bool {
+	return t.Expires != 0 && time.Now().UnixMilli() >= t.Expires
+}
+
+// Lifetime returns how long the token lasts from when it was created. 0 means the token never expires.
+func (t *Token) Lifetime() time.Duration {
+	if t.Expires == 0 {
+		return 0
+	}
+
+	return time.Duration(t.Expires-t.Created) * time.Millisecond
+}
+
+// RecordUsage adds the given usage to the token. Hourly request counts older than the usage window are discarded.
+func (t *Token) RecordUsage(lastUsed int64, address string, hourlyRequests map[int64]int64) {
+	if lastUsed > t.LastUsed {
+		t.LastUsed = lastUsed
+		t.LastUsedAddress = address
+	}
+
+	if t.HourlyRequests == nil {
+		t.HourlyRequests = map[int64]int64{}
+	}
+
+	for hour, count := range hourlyRequests {
+		t.HourlyRequests[hour] += count
+		t.TotalRequests += count
+	}
+
+	cutoff := time.Now().Add(-TokenUsageWindow).UnixMilli()
+	for hour := range t.HourlyRequests {
+		if hour < cutoff {
+			delete(t.HourlyRequests, hour)
+		}
+	}
+}
+
+// RequestsLastDay returns the number of requests made with the token within the usage window.
+func (t *Token) RequestsLastDay() int64 {
+	cutoff := time.Now().Add(-TokenUsageWindow).UnixMilli()
+
+	var total int64
+	for hour, count := range t.HourlyRequests {
+		if hour >= cutoff {
+			total += count
+		}
+	}
+
+	return total
+}
+
 func (t *Token) ToProto() *proto.Token {
 	return &proto.Token{
-		Created:    t.Created,
-		Kind:       proto.Token_Kind(proto.Token_Kind_value[string(t.Kind)]),
-		Namespaces: t.Namespaces,
-		Metadata:   t.Metadata,
+		Created:         t.Created,
+		Kind:            proto.Token_Kind(proto.Token_Kind_value[string(t.Kind)]),
+		Namespaces:      t.Namespaces,
+		Metadata:        t.Metadata,
+		LdapUser:        t.LDAPUser,
+		ServiceAccount:  t.ServiceAccount,
+		AllowedCidrs:    t.AllowedCIDRs,
+		Hash:            t.Hash,
+		LastUsed:        t.LastUsed,
+		LastUsedAddress: t.LastUsedAddress,
+		TotalRequests:   t.TotalRequests,
+		RequestsLastDay: t.RequestsLastDay(),
+		Expires:         t.Expires,
+		OidcSubject:     t.OIDCSubject,
+		ReplacedBy:      t.ReplacedBy,
 	}
 }
@@ -0,0 +1,33 @@
+package models
+
+import (
+	"testing"
+	"time"
+)
+
+func TestTokenRecordUsage(t *testing.T) {
+	token := NewToken("hash", TokenKindClient, []string{"default"}, nil)
+
+	now := time.Now()
+	currentHour := now.Truncate(time.Hour).UnixMilli()
+	expiredHour := now.Add(-TokenUsageWindow - time.Hour).Truncate(time.Hour).UnixMilli()
+
+	token.RecordUsage(now.UnixMilli(), "10.0.0.1", map[int64]int64{currentHour: 3, expiredHour: 5})
+	token.RecordUsage(now.Add(-time.Minute).UnixMilli(), "10.0.0.2", map[int64]int64{currentHour: 2})
+
+	if token.LastUsedAddress != "10.0.0.1" {
+		t.Errorf("expected last used address to be from the latest use; got %q", token.LastUsedAddress)
+	}
+
+	if token.TotalRequests != 10 {
+		t.Errorf("expected 10 total requests; got %d", token.TotalRequests)
+	}
+
+	if requests := token.RequestsLastDay(); requests != 5 {
+		t.Errorf("expected 5 requests within the last day; got %d", requests)
+	}
+
+	if _, exists := token.HourlyRequests[expiredHour]; exists {
+		t.Errorf("expected request counts outside of the usage window to be discarded")
+	}
+}
@@ -58,6 +58,9 @@ type Trigger struct {
 	Started       int64          `json:"started"` // The start time of the trigger in epoch milliseconds.
 	State         ContainerState `json:"state"`
 	Documentation string         `json:"documentation"` // The documentation link for this specific trigger.
+
+	// The version of the trigger API the trigger implements. 0 if the trigger was built before versions were reported.
+	APIVersion int64 `json:"api_version"`
 }
 
 func (t *Trigger) ToProto() *proto.Trigger {
@@ -69,5 +72,6 @@ func (t *Trigger) ToProto() *proto.Trigger {
 		Started:       t.Started,
 		State:         proto.Trigger_State(proto.Trigger_State_value[string(t.State)]),
 		Documentation: t.Documentation,
+		ApiVersion:    t.APIVersion,
 	}
 }
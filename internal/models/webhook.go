@@ -0,0 +1,96 @@
+package models
+
+import "github.com/clintjedwards/gofer/proto"
+
+// Webhook is an HTTP endpoint registered to be told about the run lifecycle events of a namespace or a single pipeline.
+// Deliveries are signed with the webhook's secret so the receiving end can tell they came from Gofer.
+type Webhook struct {
+	ID          int64       `json:"id" storm:"id,increment"` // Unique identifier; auto-incrementing.
+	NamespaceID string      `json:"namespace_id" storm:"index"`
+	PipelineID  string      `json:"pipeline_id"` // Empty for webhooks that are told about every pipeline in the namespace.
+	URL         string      `json:"url"`
+	Secret      string      `json:"secret"` // Key used to sign the HMAC of every delivery.
+	Events      []EventType `json:"events"` // The events the webhook is sent; all run lifecycle events if empty.
+	Created     int64       `json:"created"`
+}
+
+// WebhookEvents are the events webhooks can be registered for.
+var WebhookEvents = []EventType{StartedRunEvent, CompletedRunEvent}
+
+// Wants returns whether the webhook should be sent the given event of the given pipeline.
+func (w *Webhook) Wants(pipeline string, event EventType) bool {
+	if w.PipelineID != "" && w.PipelineID != pipeline {
+		return false
+	}
+
+	if len(w.Events) == 0 {
+		return true
+	}
+
+	for _, wanted := range w.Events {
+		if wanted == event {
+			return true
+		}
+	}
+
+	return false
+}
+
+// ToProto returns the webhook without its secret; the secret is only ever handed out when the webhook is created.
+func (w *Webhook) ToProto() *proto.Webhook {
+	events := []string{}
+	for _, event := range w.Events {
+		events = append(events, string(event))
+	}
+
+	return &proto.Webhook{
+		Id:          w.ID,
+		NamespaceId: w.NamespaceID,
+		PipelineId:  w.PipelineID,
+		Url:         w.URL,
+		Events:      events,
+		Created:     w.Created,
+	}
+}
+
+type WebhookDeliveryState string
+
+const (
+	WebhookDeliveryStateUnknown   WebhookDeliveryState = "UNKNOWN"
+	WebhookDeliveryStatePending   WebhookDeliveryState = "PENDING"   // Still being attempted.
+	WebhookDeliveryStateSucceeded WebhookDeliveryState = "SUCCEEDED" // The webhook responded with a 2xx.
+	WebhookDeliveryStateFailed    WebhookDeliveryState = "FAILED"    // Every attempt failed.
+)
+
+// WebhookDelivery is a record of a single event being sent to a webhook.
+type WebhookDelivery struct {
+	ID          int64                `json:"id" storm:"id,increment"` // Unique identifier; auto-incrementing.
+	WebhookID   int64                `json:"webhook_id" storm:"index"`
+	NamespaceID string               `json:"namespace_id"`
+	PipelineID  string               `json:"pipeline_id"`
+	RunID       int64                `json:"run_id"`
+	Event       EventType            `json:"event"`
+	Payload     string               `json:"payload"` // The JSON that was sent.
+	State       WebhookDeliveryState `json:"state"`
+	Attempts    int64                `json:"attempts"`
+	Error       string               `json:"error"` // The error from the most recent attempt.
+	Created     int64                `json:"created"`
+	Updated     int64                `json:"updated"` // Time of the most recent attempt in epoch milli.
+}
+
+func (d *WebhookDelivery) ToProto() *proto.WebhookDelivery {
+	return &proto.WebhookDelivery{
+		Id:          d.ID,
+		WebhookId:   d.WebhookID,
+		NamespaceId: d.NamespaceID,
+		PipelineId:  d.PipelineID,
+		RunId:       d.RunID,
+		Event:       string(d.Event),
+		Payload:     d.Payload,
+		State:       proto.WebhookDelivery_State(proto.WebhookDelivery_State_value[string(d.State)]),
+		Attempts:    d.Attempts,
+		Error:       d.Error,
+		Created:     d.Created,
+		Updated:     d.Updated,
+	}
+}
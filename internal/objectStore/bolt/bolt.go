@@ -1,7 +1,9 @@
 package bolt
 
 import (
+	"bytes"
 	"errors"
+	"io"
 	"time"
 
 	"github.com/asdine/storm/v3"
@@ -34,7 +36,7 @@ func (store *Store) GetObject(key string) ([]byte, error) {
 	err := store.Get(rootBucket, key, &storedObject)
 	if err != nil {
 		if errors.Is(err, storm.ErrNotFound) {
-			return nil, objectStore.ErrEntityNotFound
+			return store.getStreamedObject(key)
 		}
 
 		return nil, err
@@ -49,6 +51,12 @@ func (store *Store) PutObject(key string, content []byte, force bool) error {
 		return err
 	}
 
+	// The object may have been streamed in previously; the stored chunks are no longer needed.
+	err = store.deleteStreamedObject(key)
+	if err != nil && !errors.Is(err, objectStore.ErrEntityNotFound) {
+		return err
+	}
+
 	return nil
 }
 
@@ -56,7 +64,7 @@ func (store *Store) DeleteObject(key string) error {
 	err := store.Delete(rootBucket, key)
 	if err != nil {
 		if errors.Is(err, storm.ErrNotFound) {
-			return objectStore.ErrEntityNotFound
+			return store.deleteStreamedObject(key)
 		}
 
 		return err
@@ -64,3 +72,40 @@ func (store *Store) DeleteObject(key string) error {
 
 	return nil
 }
+
+func (store *Store) ListObjectKeys(prefix string) (map[string]int64, error) {
+	keys := map[string]int64{}
+
+	err := store.Bolt.View(func(tx *bolt.Tx) error {
+		bucket := tx.Bucket([]byte(rootBucket))
+		if bucket == nil {
+			return nil
+		}
+
+		cursor := bucket.Cursor()
+		for key, value := cursor.Seek([]byte(prefix)); key != nil && bytes.HasPrefix(key, []byte(prefix)); key, value = cursor.Next() {
+			keys[string(key)] = int64(len(value))
+		}
+
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	err = store.listStreamedObjects(prefix, keys)
+	if err != nil {
+		return nil, err
+	}
+
+	return keys, nil
+}
+
+// Backup writes a consistent copy of the entire store to the writer using a read transaction, so writes can
+// continue while the backup is taken.
+func (store *Store) Backup(w io.Writer) error {
+	return store.Bolt.View(func(tx *bolt.Tx) error {
+		_, err := tx.WriteTo(w)
+		return err
+	})
+}
@@ -0,0 +1,343 @@
+package bolt
+
+import (
+	"bytes"
+	"crypto/rand"
+	"encoding/binary"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+
+	"github.com/asdine/storm/v3"
+	"github.com/clintjedwards/gofer/internal/objectStore"
+	bolt "go.etcd.io/bbolt"
+)
+
+const (
+	// streamedBucket maps the keys of objects written with PutObjectStream to where their chunks are kept.
+	streamedBucket string = "streamed"
+
+	// chunksBucket holds a bucket of chunks for every streamed object, named after the object's upload ID.
+	chunksBucket string = "chunks"
+
+	// streamChunkSize is the size of each chunk a streamed object is split into. Only the last chunk of an object
+	// can be smaller.
+	streamChunkSize = 1024 * 1024 // 1MiB
+)
+
+// streamedObject records where the chunks of an object written with PutObjectStream are kept. Every upload writes its
+// chunks under a new ID so that an upload in progress never touches the chunks of the object it replaces.
+type streamedObject struct {
+	ID     string `json:"id"`
+	Size   int64  `json:"size"`
+	Chunks uint64 `json:"chunks"`
+}
+
+// PutObjectStream writes each chunk of the object in its own transaction so that only a single chunk is ever held in
+// memory. The object is swapped in for the one already stored under the key once every chunk has been written.
+func (store *Store) PutObjectStream(key string, content io.Reader, force bool) (int64, error) {
+	id, err := newUploadID()
+	if err != nil {
+		return 0, err
+	}
+
+	object := streamedObject{ID: id}
+	buffer := make([]byte, streamChunkSize)
+
+	for {
+		n, readErr := io.ReadFull(content, buffer)
+		if readErr != nil && !errors.Is(readErr, io.EOF) && !errors.Is(readErr, io.ErrUnexpectedEOF) {
+			_ = store.deleteChunks(id)
+			return 0, readErr
+		}
+
+		if n > 0 {
+			err := store.Bolt.Update(func(tx *bolt.Tx) error {
+				chunks, err := tx.CreateBucketIfNotExists([]byte(chunksBucket))
+				if err != nil {
+					return err
+				}
+
+				bucket, err := chunks.CreateBucketIfNotExists([]byte(id))
+				if err != nil {
+					return err
+				}
+
+				return bucket.Put(chunkKey(object.Chunks), buffer[:n])
+			})
+			if err != nil {
+				_ = store.deleteChunks(id)
+				return 0, err
+			}
+
+			object.Chunks++
+			object.Size += int64(n)
+		}
+
+		if readErr != nil {
+			break
+		}
+	}
+
+	encodedObject, err := json.Marshal(object)
+	if err != nil {
+		_ = store.deleteChunks(id)
+		return 0, err
+	}
+
+	err = store.Bolt.Update(func(tx *bolt.Tx) error {
+		streamed, err := tx.CreateBucketIfNotExists([]byte(streamedBucket))
+		if err != nil {
+			return err
+		}
+
+		err = deleteStreamedObject(tx, key)
+		if err != nil && !errors.Is(err, objectStore.ErrEntityNotFound) {
+			return err
+		}
+
+		// The object may have been written with PutObject previously.
+		root := tx.Bucket([]byte(rootBucket))
+		if root != nil {
+			err = root.Delete([]byte(key))
+			if err != nil {
+				return err
+			}
+		}
+
+		return streamed.Put([]byte(key), encodedObject)
+	})
+	if err != nil {
+		_ = store.deleteChunks(id)
+		return 0, err
+	}
+
+	return object.Size, nil
+}
+
+// GetObjectStream returns a reader that reads the object one chunk at a time. Objects written with PutObject are
+// small enough to be read in full.
+func (store *Store) GetObjectStream(key string, offset int64) (io.ReadCloser, int64, error) {
+	if offset < 0 {
+		return nil, 0, fmt.Errorf("%w; offset cannot be negative", objectStore.ErrPreconditionFailure)
+	}
+
+	var storedObject []byte
+
+	err := store.Get(rootBucket, key, &storedObject)
+	if err == nil {
+		if offset > int64(len(storedObject)) {
+			return nil, 0, fmt.Errorf("%w; offset %d is past the end of the object", objectStore.ErrPreconditionFailure,
+				offset)
+		}
+
+		return io.NopCloser(bytes.NewReader(storedObject[offset:])), int64(len(storedObject)), nil
+	}
+	if !errors.Is(err, storm.ErrNotFound) {
+		return nil, 0, err
+	}
+
+	object, err := store.streamedObject(key)
+	if err != nil {
+		return nil, 0, err
+	}
+
+	if offset > object.Size {
+		return nil, 0, fmt.Errorf("%w; offset %d is past the end of the object", objectStore.ErrPreconditionFailure,
+			offset)
+	}
+
+	return &chunkReader{
+		store:  store,
+		object: object,
+		next:   uint64(offset / streamChunkSize),
+		skip:   offset % streamChunkSize,
+	}, object.Size, nil
+}
+
+// getStreamedObject reads an object written with PutObjectStream in full.
+func (store *Store) getStreamedObject(key string) ([]byte, error) {
+	reader, size, err := store.GetObjectStream(key, 0)
+	if err != nil {
+		return nil, err
+	}
+	defer reader.Close()
+
+	content := bytes.NewBuffer(make([]byte, 0, size))
+	_, err = content.ReadFrom(reader)
+	if err != nil {
+		return nil, err
+	}
+
+	return content.Bytes(), nil
+}
+
+func (store *Store) streamedObject(key string) (streamedObject, error) {
+	object := streamedObject{}
+
+	err := store.Bolt.View(func(tx *bolt.Tx) error {
+		streamed := tx.Bucket([]byte(streamedBucket))
+		if streamed == nil {
+			return objectStore.ErrEntityNotFound
+		}
+
+		encodedObject := streamed.Get([]byte(key))
+		if encodedObject == nil {
+			return objectStore.ErrEntityNotFound
+		}
+
+		return json.Unmarshal(encodedObject, &object)
+	})
+	if err != nil {
+		return streamedObject{}, err
+	}
+
+	return object, nil
+}
+
+func (store *Store) deleteStreamedObject(key string) error {
+	return store.Bolt.Update(func(tx *bolt.Tx) error {
+		return deleteStreamedObject(tx, key)
+	})
+}
+
+func (store *Store) listStreamedObjects(prefix string, keys map[string]int64) error {
+	return store.Bolt.View(func(tx *bolt.Tx) error {
+		bucket := tx.Bucket([]byte(streamedBucket))
+		if bucket == nil {
+			return nil
+		}
+
+		cursor := bucket.Cursor()
+		for key, value := cursor.Seek([]byte(prefix)); key != nil && bytes.HasPrefix(key, []byte(prefix)); key, value = cursor.Next() {
+			object := streamedObject{}
+			err := json.Unmarshal(value, &object)
+			if err != nil {
+				return err
+			}
+
+			keys[string(key)] = object.Size
+		}
+
+		return nil
+	})
+}
+
+// deleteChunks removes the chunks of an upload that was never finished.
+func (store *Store) deleteChunks(id string) error {
+	return store.Bolt.Update(func(tx *bolt.Tx) error {
+		return deleteChunks(tx, id)
+	})
+}
+
+func deleteStreamedObject(tx *bolt.Tx, key string) error {
+	streamed := tx.Bucket([]byte(streamedBucket))
+	if streamed == nil {
+		return objectStore.ErrEntityNotFound
+	}
+
+	encodedObject := streamed.Get([]byte(key))
+	if encodedObject == nil {
+		return objectStore.ErrEntityNotFound
+	}
+
+	object := streamedObject{}
+	err := json.Unmarshal(encodedObject, &object)
+	if err != nil {
+		return err
+	}
+
+	err = deleteChunks(tx, object.ID)
+	if err != nil {
+		return err
+	}
+
+	return streamed.Delete([]byte(key))
+}
+
+func deleteChunks(tx *bolt.Tx, id string) error {
+	chunks := tx.Bucket([]byte(chunksBucket))
+	if chunks == nil {
+		return nil
+	}
+
+	err := chunks.DeleteBucket([]byte(id))
+	if err != nil && !errors.Is(err, bolt.ErrBucketNotFound) {
+		return err
+	}
+
+	return nil
+}
+
+// chunkReader reads a streamed object one chunk at a time, each within its own read transaction so that writes
+// aren't held up by a slow reader.
+type chunkReader struct {
+	store  *Store
+	object streamedObject
+	next   uint64 // The index of the next chunk to be read.
+	skip   int64  // How many bytes of the next chunk to skip over.
+	chunk  []byte
+}
+
+func (r *chunkReader) Read(p []byte) (int, error) {
+	if len(r.chunk) == 0 {
+		if r.next >= r.object.Chunks {
+			return 0, io.EOF
+		}
+
+		err := r.store.Bolt.View(func(tx *bolt.Tx) error {
+			chunks := tx.Bucket([]byte(chunksBucket))
+			if chunks == nil {
+				return objectStore.ErrEntityNotFound
+			}
+
+			bucket := chunks.Bucket([]byte(r.object.ID))
+			if bucket == nil {
+				return objectStore.ErrEntityNotFound
+			}
+
+			chunk := bucket.Get(chunkKey(r.next))
+			if chunk == nil {
+				return objectStore.ErrEntityNotFound
+			}
+
+			// Values are only valid for the life of the transaction.
+			r.chunk = append([]byte(nil), chunk[r.skip:]...)
+			return nil
+		})
+		if err != nil {
+			// The object was replaced or removed while it was being read.
+			return 0, err
+		}
+
+		r.next++
+		r.skip = 0
+	}
+
+	n := copy(p, r.chunk)
+	r.chunk = r.chunk[n:]
+	return n, nil
+}
+
+func (r *chunkReader) Close() error {
+	r.chunk = nil
+	return nil
+}
+
+func chunkKey(index uint64) []byte {
+	key := make([]byte, 8)
+	binary.BigEndian.PutUint64(key, index)
+	return key
+}
+
+func newUploadID() (string, error) {
+	id := make([]byte, 16)
+	_, err := rand.Read(id)
+	if err != nil {
+		return "", fmt.Errorf("could not generate upload id: %w", err)
+	}
+
+	return hex.EncodeToString(id), nil
+}
@@ -1,6 +1,9 @@
 package objectStore
 
-import "errors"
+import (
+	"errors"
+	"io"
+)
 
 type EngineType string
 
@@ -24,4 +27,21 @@ type Engine interface {
 	GetObject(key string) ([]byte, error)
 	PutObject(key string, content []byte, force bool) error
 	DeleteObject(key string) error
+
+	// PutObjectStream stores the object read from content a piece at a time so that it never has to be held in
+	// memory in full. The object only replaces what was stored under the key once all of it has been read, so an
+	// upload that fails midway leaves nothing behind. Returns the size of the stored object.
+	PutObjectStream(key string, content io.Reader, force bool) (int64, error)
+
+	// GetObjectStream returns a reader for the object starting at the given byte offset along with the total size of
+	// the object. The reader must be closed once the caller is finished with it.
+	GetObjectStream(key string, offset int64) (io.ReadCloser, int64, error)
+
+	// ListObjectKeys returns all keys that start with the given prefix along with the number of bytes each object
+	// takes up within the store.
+	ListObjectKeys(prefix string) (map[string]int64, error)
+
+	// Backup writes a consistent copy of the entire database to the writer. It is safe to call while the database
+	// is in use.
+	Backup(w io.Writer) error
 }
@@ -1,10 +1,14 @@
 package docker
 
 import (
+	"archive/tar"
 	"context"
 	"encoding/base64"
+	"errors"
 	"fmt"
 	"io"
+	"path"
+	"regexp"
 	"strings"
 	"sync"
 	"time"
@@ -14,6 +18,7 @@ import (
 	"github.com/docker/docker/api/types"
 	"github.com/docker/docker/api/types/container"
 	"github.com/docker/docker/api/types/filters"
+	"github.com/docker/docker/api/types/network"
 	"github.com/docker/docker/client"
 	"github.com/docker/docker/pkg/stdcopy"
 	"github.com/docker/go-connections/nat"
@@ -25,6 +30,12 @@ type cancellations struct {
 	cancelled map[string]time.Time
 }
 
+// imagePulls keeps track of when images were last pulled so that images older than the max age can be pulled again.
+type imagePulls struct {
+	sync.Mutex
+	pulled map[string]time.Time
+}
+
 type Orchestrator struct {
 	// cancelled keeps track of cancelled containers. This is needed due to there being no way to differentiate a
 	// container that was stopped in docker from a container that exited naturally.
@@ -35,11 +46,23 @@ type Orchestrator struct {
 	// creating a situation in which the cancellation is never removed from the map), we automatically clean up
 	// cancellations after they've not been reaped for a day.
 	cancellations *cancellations
+
+	// pulls is only known for images pulled since Gofer started. Images pulled before then go by when they were
+	// built, which is never later than when they were pulled.
+	pulls *imagePulls
 	*client.Client
 }
 
 const envvarFormat = "%s=%s"
 
+const (
+	// defaultCPUShares is the share of CPU time docker gives containers by default; equal to a single core's worth.
+	defaultCPUShares = 1024
+
+	// minCPUShares is the fewest CPU shares docker accepts for a container.
+	minCPUShares = 2
+)
+
 func New(prune bool, pruneInterval time.Duration) (Orchestrator, error) {
 	docker, err := client.NewClientWithOpts(client.FromEnv, client.WithAPIVersionNegotiation())
 	if err != nil {
@@ -52,6 +75,12 @@ func New(prune bool, pruneInterval time.Duration) (Orchestrator, error) {
 		return Orchestrator{}, fmt.Errorf("could not connect to docker; make sure docker is installed and running")
 	}
 
+	return NewFromClient(docker, prune, pruneInterval), nil
+}
+
+// NewFromClient creates an orchestrator that schedules containers through an already connected client. This allows
+// container engines that serve a docker compatible API(ex. podman) to reuse the docker scheduler.
+func NewFromClient(docker *client.Client, prune bool, pruneInterval time.Duration) Orchestrator {
 	// As we run docker containers we might not want to automatically remove them so that its possible for an operator
 	// to debug. But we can't leave them lying around due to the fact that each container takes up some amount of space.
 	// to mitigate these two things we run ContainerPrune on a loop to make sure we're periodically cleaning up containers
@@ -91,7 +120,68 @@ func New(prune bool, pruneInterval time.Duration) (Orchestrator, error) {
 	return Orchestrator{
 		Client:        docker,
 		cancellations: &cancellations,
-	}, nil
+		pulls:         &imagePulls{pulled: map[string]time.Time{}},
+	}
+}
+
+// convertResources translates requests and limits into docker's resource settings. Docker has no notion of a CPU
+// request, so it's expressed as the container's share of CPU time relative to the default share of a single core.
+func convertResources(resources scheduler.Resources) container.Resources {
+	converted := container.Resources{
+		NanoCPUs:          int64(resources.CPULimit * 1e9),
+		Memory:            resources.MemoryLimit,
+		MemoryReservation: resources.MemoryRequest,
+	}
+
+	if resources.CPURequest > 0 {
+		converted.CPUShares = int64(resources.CPURequest * defaultCPUShares)
+
+		// Docker doesn't accept fewer shares than this.
+		if converted.CPUShares < minCPUShares {
+			converted.CPUShares = minCPUShares
+		}
+	}
+
+	return converted
+}
+
+// convertGPURequest translates a GPU request into a docker device request; the same one `docker run --gpus` makes.
+func convertGPURequest(gpus *scheduler.GPURequest) container.DeviceRequest {
+	capabilities := gpus.Capabilities
+	if len(capabilities) == 0 {
+		capabilities = []string{"gpu"}
+	}
+
+	return container.DeviceRequest{
+		Driver:       gpus.Driver,
+		Count:        gpus.Count,
+		DeviceIDs:    gpus.DeviceIDs,
+		Capabilities: [][]string{capabilities},
+	}
+}
+
+func (orch *Orchestrator) recordImagePull(image string) {
+	orch.pulls.Lock()
+	defer orch.pulls.Unlock()
+	orch.pulls.pulled[image] = time.Now()
+}
+
+// imageExpired returns whether an image already on the host is older than the max age and should be pulled again.
+// Created is when the image was built in unix seconds.
+func (orch *Orchestrator) imageExpired(image string, created int64, maxAge time.Duration) bool {
+	if maxAge <= 0 {
+		return false
+	}
+
+	orch.pulls.Lock()
+	pulled, exists := orch.pulls.pulled[image]
+	orch.pulls.Unlock()
+
+	if !exists {
+		pulled = time.Unix(created, 0)
+	}
+
+	return time.Since(pulled) > maxAge
 }
 
 func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (scheduler.StartContainerResponse, error) {
@@ -103,7 +193,10 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 		dockerRegistryAuth = base64.StdEncoding.EncodeToString([]byte(authString))
 	}
 
+	var imagePullDuration time.Duration
+
 	if req.AlwaysPull {
+		pullStart := time.Now()
 		r, err := orch.ImagePull(ctx, req.ImageName, types.ImagePullOptions{
 			RegistryAuth: dockerRegistryAuth,
 		})
@@ -115,6 +208,8 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 			return scheduler.StartContainerResponse{}, err
 		}
 		_, _ = io.Copy(io.Discard, r)
+		imagePullDuration = time.Since(pullStart)
+		orch.recordImagePull(req.ImageName)
 
 		defer r.Close() // We don't care about pull logs only the errors
 	} else {
@@ -124,7 +219,8 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 			}),
 		})
 
-		if len(list) == 0 {
+		if len(list) == 0 || orch.imageExpired(req.ImageName, list[0].Created, req.ImageMaxAge) {
+			pullStart := time.Now()
 			r, err := orch.ImagePull(ctx, req.ImageName, types.ImagePullOptions{
 				RegistryAuth: dockerRegistryAuth,
 			})
@@ -136,6 +232,8 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 				return scheduler.StartContainerResponse{}, err
 			}
 			_, _ = io.Copy(io.Discard, r) // We wait on the readcloser so that we know when it has finished
+			imagePullDuration = time.Since(pullStart)
+			orch.recordImagePull(req.ImageName)
 
 			defer r.Close() // We don't care about pull logs only the errors
 		}
@@ -147,6 +245,7 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 		Image:        req.ImageName,
 		Env:          convertEnvVars(envMap),
 		ExposedPorts: nat.PortSet{},
+		Labels:       req.Labels,
 	}
 
 	// If the user has passed in commands we replace the entrypoint with those commands.
@@ -158,7 +257,15 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 		containerConfig.Entrypoint = []string{req.Exec.Shell, "-c", string(rawScript)}
 	}
 
-	hostConfig := &container.HostConfig{}
+	hostConfig := &container.HostConfig{
+		Resources:  convertResources(req.Resources),
+		Privileged: req.Privileged,
+		CapAdd:     req.CapAdd,
+	}
+
+	if req.GPUs != nil {
+		hostConfig.DeviceRequests = []container.DeviceRequest{convertGPURequest(req.GPUs)}
+	}
 
 	if req.EnableNetworking {
 		port, err := nat.NewPort("tcp", "8080")
@@ -179,6 +286,21 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 		}
 	}
 
+	// Containers on a network of their own can only be reached by the other containers on it.
+	var networkingConfig *network.NetworkingConfig
+	if req.Network != "" {
+		hostConfig.NetworkMode = container.NetworkMode(req.Network)
+
+		endpoint := &network.EndpointSettings{}
+		if req.NetworkAlias != "" {
+			endpoint.Aliases = []string{req.NetworkAlias}
+		}
+
+		networkingConfig = &network.NetworkingConfig{
+			EndpointsConfig: map[string]*network.EndpointSettings{req.Network: endpoint},
+		}
+	}
+
 	removeOptions := types.ContainerRemoveOptions{
 		RemoveVolumes: true,
 		Force:         true,
@@ -186,11 +308,19 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 
 	_ = orch.ContainerRemove(ctx, req.ID, removeOptions)
 
-	createResp, err := orch.ContainerCreate(ctx, containerConfig, hostConfig, nil, nil, req.ID)
+	createResp, err := orch.ContainerCreate(ctx, containerConfig, hostConfig, networkingConfig, nil, req.ID)
 	if err != nil {
 		return scheduler.StartContainerResponse{}, err
 	}
 
+	for _, files := range req.Files {
+		err := orch.copyToContainer(ctx, createResp.ID, files)
+		if err != nil {
+			_ = orch.ContainerRemove(ctx, createResp.ID, removeOptions)
+			return scheduler.StartContainerResponse{}, fmt.Errorf("could not copy files into %q: %w", files.Path, err)
+		}
+	}
+
 	err = orch.ContainerStart(ctx, createResp.ID, types.ContainerStartOptions{})
 	if err != nil {
 		return scheduler.StartContainerResponse{}, err
@@ -216,8 +346,9 @@ func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (s
 	}
 
 	return scheduler.StartContainerResponse{
-		SchedulerID: createResp.ID,
-		URL:         fmt.Sprintf("%s:%s", rawHostPort.HostIP, rawHostPort.HostPort),
+		SchedulerID:       createResp.ID,
+		URL:               fmt.Sprintf("%s:%s", rawHostPort.HostIP, rawHostPort.HostPort),
+		ImagePullDuration: imagePullDuration,
 	}, nil
 }
 
@@ -332,6 +463,258 @@ func (orch *Orchestrator) GetLogs(gl scheduler.GetLogsRequest) (io.Reader, error
 	return demuxr, nil
 }
 
+// AttachContainer starts a new exec session within the container. Since the session has a TTY allocated, docker
+// doesn't multiplex stdout and stderr and the hijacked connection can be handed to the caller as is.
+func (orch *Orchestrator) AttachContainer(request scheduler.AttachContainerRequest) (scheduler.AttachContainerResponse, error) {
+	ctx := context.Background()
+
+	exec, err := orch.ContainerExecCreate(ctx, request.SchedulerID, types.ExecConfig{
+		Tty:          true,
+		AttachStdin:  true,
+		AttachStdout: true,
+		AttachStderr: true,
+		Cmd:          request.Command,
+	})
+	if err != nil {
+		if strings.Contains(err.Error(), "No such container") {
+			return scheduler.AttachContainerResponse{}, scheduler.ErrNoSuchContainer
+		}
+
+		return scheduler.AttachContainerResponse{}, err
+	}
+
+	hijacked, err := orch.ContainerExecAttach(ctx, exec.ID, types.ExecStartCheck{
+		Tty: true,
+	})
+	if err != nil {
+		return scheduler.AttachContainerResponse{}, err
+	}
+
+	resize := func(width, height uint) error {
+		return orch.ContainerExecResize(ctx, exec.ID, types.ResizeOptions{
+			Width:  width,
+			Height: height,
+		})
+	}
+
+	if request.Width > 0 && request.Height > 0 {
+		err := resize(request.Width, request.Height)
+		if err != nil {
+			log.Debug().Err(err).Str("exec", exec.ID).Msg("docker: could not set initial terminal size")
+		}
+	}
+
+	return scheduler.AttachContainerResponse{
+		Conn:   &execConn{hijacked},
+		Resize: resize,
+	}, nil
+}
+
+// execConn reads through the buffered reader docker hands back with a hijacked connection, since it might already
+// contain output, and writes directly to the underlying connection.
+type execConn struct {
+	types.HijackedResponse
+}
+
+func (c *execConn) Read(p []byte) (int, error) {
+	return c.Reader.Read(p)
+}
+
+func (c *execConn) Write(p []byte) (int, error) {
+	return c.Conn.Write(p)
+}
+
+func (c *execConn) Close() error {
+	c.HijackedResponse.Close()
+	return nil
+}
+
+// ListContainers uses the names containers were created with since docker generates its own IDs.
+func (orch *Orchestrator) ListContainers(request scheduler.ListContainersRequest) (scheduler.ListContainersResponse, error) {
+	// Docker matches names as a regular expression and prefixes every name with a slash.
+	containers, err := orch.ContainerList(context.Background(), types.ContainerListOptions{
+		All:     true,
+		Filters: filters.NewArgs(filters.Arg("name", "^/"+regexp.QuoteMeta(request.Prefix))),
+	})
+	if err != nil {
+		return scheduler.ListContainersResponse{}, err
+	}
+
+	response := scheduler.ListContainersResponse{
+		Containers: []scheduler.Container{},
+	}
+
+	for _, container := range containers {
+		for _, name := range container.Names {
+			id := strings.TrimPrefix(name, "/")
+			if !strings.HasPrefix(id, request.Prefix) {
+				continue
+			}
+
+			response.Containers = append(response.Containers, scheduler.Container{
+				ID:          id,
+				SchedulerID: container.ID,
+				Running:     container.State == "running",
+			})
+			break
+		}
+	}
+
+	return response, nil
+}
+
+func (orch *Orchestrator) CopyFromContainer(request scheduler.CopyFromContainerRequest) (scheduler.CopyFromContainerResponse, error) {
+	ctx := context.Background()
+
+	containerPath, err := orch.containerPath(ctx, request.SchedulerID, request.Path)
+	if err != nil {
+		return scheduler.CopyFromContainerResponse{}, err
+	}
+
+	archive, stat, err := orch.Client.CopyFromContainer(ctx, request.SchedulerID, containerPath)
+	if err != nil {
+		if strings.Contains(err.Error(), "No such container") {
+			return scheduler.CopyFromContainerResponse{}, scheduler.ErrNoSuchContainer
+		}
+		if client.IsErrNotFound(err) {
+			return scheduler.CopyFromContainerResponse{}, scheduler.ErrNoSuchFile
+		}
+		return scheduler.CopyFromContainerResponse{}, err
+	}
+
+	return scheduler.CopyFromContainerResponse{
+		Archive: archive,
+		IsDir:   stat.Mode.IsDir(),
+	}, nil
+}
+
+// containerPath returns the absolute path of a path within a container. Docker resolves relative paths from the root
+// of the container so they're resolved from the working directory here instead, the same place the container's
+// commands run from.
+func (orch *Orchestrator) containerPath(ctx context.Context, schedulerID, containerPath string) (string, error) {
+	if path.IsAbs(containerPath) {
+		return path.Clean(containerPath), nil
+	}
+
+	info, err := orch.ContainerInspect(ctx, schedulerID)
+	if err != nil {
+		if strings.Contains(err.Error(), "No such container") {
+			return "", scheduler.ErrNoSuchContainer
+		}
+		return "", err
+	}
+
+	workingDir := "/"
+	if info.Config != nil && info.Config.WorkingDir != "" {
+		workingDir = info.Config.WorkingDir
+	}
+
+	return path.Join(workingDir, containerPath), nil
+}
+
+// copyToContainer extracts files into a container. The archive's entries are moved under the destination and
+// extracted from the root of the container, which has docker create any directories missing along the way.
+func (orch *Orchestrator) copyToContainer(ctx context.Context, schedulerID string, files scheduler.ContainerFiles) error {
+	destination, err := orch.containerPath(ctx, schedulerID, files.Path)
+	if err != nil {
+		return err
+	}
+
+	reader, writer := io.Pipe()
+	defer reader.Close()
+
+	go func() {
+		writer.CloseWithError(prefixArchive(writer, files.Archive, strings.TrimPrefix(destination, "/")))
+	}()
+
+	return orch.CopyToContainer(ctx, schedulerID, "/", reader, types.CopyToContainerOptions{})
+}
+
+// prefixArchive copies the tar archive read from r to w with every entry moved under the prefix.
+func prefixArchive(w io.Writer, r io.Reader, prefix string) error {
+	source := tar.NewReader(r)
+	archive := tar.NewWriter(w)
+
+	for {
+		header, err := source.Next()
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				break
+			}
+			return err
+		}
+
+		header.Name = path.Join(prefix, header.Name)
+		if header.Typeflag == tar.TypeLink {
+			header.Linkname = path.Join(prefix, header.Linkname)
+		}
+
+		err = archive.WriteHeader(header)
+		if err != nil {
+			return err
+		}
+
+		_, err = io.Copy(archive, source)
+		if err != nil {
+			return err
+		}
+	}
+
+	return archive.Close()
+}
+
+func (orch *Orchestrator) RemoveContainer(request scheduler.RemoveContainerRequest) error {
+	err := orch.ContainerRemove(context.Background(), request.SchedulerID, types.ContainerRemoveOptions{
+		RemoveVolumes: true,
+		Force:         true,
+	})
+	if err != nil {
+		if strings.Contains(err.Error(), "No such container") {
+			return scheduler.ErrNoSuchContainer
+		}
+		return err
+	}
+
+	return nil
+}
+
+func (orch *Orchestrator) CreateNetwork(request scheduler.CreateNetworkRequest) error {
+	ctx := context.Background()
+
+	// The network is still around if the run it belongs to is being picked back up.
+	_, err := orch.NetworkInspect(ctx, request.ID, types.NetworkInspectOptions{})
+	if err == nil {
+		return nil
+	}
+
+	if !client.IsErrNotFound(err) {
+		return err
+	}
+
+	_, err = orch.NetworkCreate(ctx, request.ID, types.NetworkCreate{
+		CheckDuplicate: true,
+		Driver:         "bridge",
+		Labels:         request.Labels,
+	})
+	if err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (orch *Orchestrator) RemoveNetwork(request scheduler.RemoveNetworkRequest) error {
+	err := orch.NetworkRemove(context.Background(), request.ID)
+	if err != nil {
+		if client.IsErrNotFound(err) {
+			return nil
+		}
+		return err
+	}
+
+	return nil
+}
+
 func convertEnvVars(envvars map[string]string) []string {
 	output := []string{}
 	for key, value := range envvars {
@@ -0,0 +1,634 @@
+// Package local schedules containers as plain processes on the machine Gofer runs on. There is no container runtime
+// involved, so there is also no isolation between task runs or between task runs and Gofer itself; it is meant for
+// constrained machines where a container runtime isn't available and for testing.
+//
+// Since there is no image to run, a task's script is run with its shell when it has one and otherwise its image is
+// taken as the command to run(ex. "./scripts/build.sh --release"). Each process gets a working directory of its own
+// and only the environment variables it was started with, along with the PATH and HOME of Gofer's own environment so
+// that commands can be found.
+package local
+
+import (
+	"archive/tar"
+	"encoding/base64"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/rs/zerolog/log"
+)
+
+const envvarFormat = "%s=%s"
+
+// outputFile is the name of the file within a process's directory that its stdout and stderr are written to.
+const outputFile = "gofer-output.log"
+
+type Orchestrator struct {
+	// workDir holds the working directory of each process along with its output.
+	workDir string
+
+	mu        sync.Mutex
+	processes map[string]*process
+}
+
+// process is a command started as a container. Processes are kept after they exit so that their state and output can
+// still be read, until they're removed.
+type process struct {
+	id     string
+	dir    string
+	env    []string
+	cmd    *exec.Cmd
+	output *output
+
+	// done is closed once the process has exited; exitCode and cancelled are only safe to read after.
+	done      chan struct{}
+	exitCode  int
+	cancelled bool
+
+	mu         sync.Mutex
+	cancelling bool
+}
+
+func New(workDir string, prune bool, pruneInterval time.Duration) (*Orchestrator, error) {
+	err := os.MkdirAll(workDir, 0o700)
+	if err != nil {
+		return nil, fmt.Errorf("could not create work directory %q: %w", workDir, err)
+	}
+
+	orch := &Orchestrator{
+		workDir:   workDir,
+		processes: map[string]*process{},
+	}
+
+	// Just like with docker exited processes are kept so that they can be debugged, but their directories would
+	// eventually fill the disk.
+	if prune {
+		go func() {
+			for {
+				time.Sleep(pruneInterval)
+				removed := orch.prune()
+				log.Debug().Int("processes_removed", removed).Msg("local: pruned processes")
+			}
+		}()
+	}
+
+	return orch, nil
+}
+
+func (orch *Orchestrator) StartContainer(req scheduler.StartContainerRequest) (scheduler.StartContainerResponse, error) {
+	command, err := processCommand(req)
+	if err != nil {
+		return scheduler.StartContainerResponse{}, err
+	}
+
+	// Just like containers, starting a process with the ID of an existing one replaces it.
+	_ = orch.RemoveContainer(scheduler.RemoveContainerRequest{SchedulerID: req.ID})
+
+	dir := filepath.Join(orch.workDir, req.ID)
+	err = os.MkdirAll(dir, 0o700)
+	if err != nil {
+		return scheduler.StartContainerResponse{}, fmt.Errorf("could not create process directory: %w", err)
+	}
+
+	for _, files := range req.Files {
+		destination := files.Path
+		if !filepath.IsAbs(destination) {
+			destination = filepath.Join(dir, destination)
+		}
+
+		err := extractArchive(files.Archive, filepath.Clean(destination))
+		if err != nil {
+			_ = os.RemoveAll(dir)
+			return scheduler.StartContainerResponse{}, fmt.Errorf("could not copy files into %q: %w", files.Path, err)
+		}
+	}
+
+	file, err := os.Create(filepath.Join(dir, outputFile))
+	if err != nil {
+		return scheduler.StartContainerResponse{}, fmt.Errorf("could not create process output file: %w", err)
+	}
+
+	envVars := map[string]string{}
+	for _, key := range []string{"PATH", "HOME"} {
+		if value, exists := os.LookupEnv(key); exists {
+			envVars[key] = value
+		}
+	}
+	for key, value := range req.EnvVars {
+		envVars[key] = value
+	}
+
+	url := ""
+
+	// Triggers listen on the address they're given; since processes share the host's network each one needs a
+	// port of its own.
+	if req.EnableNetworking {
+		port, err := freePort()
+		if err != nil {
+			file.Close()
+			return scheduler.StartContainerResponse{}, fmt.Errorf("could not find a free port: %w", err)
+		}
+
+		url = fmt.Sprintf("127.0.0.1:%d", port)
+		envVars["GOFER_TRIGGER_HOST"] = url
+	}
+
+	output := newOutput(file)
+
+	cmd := exec.Command(command[0], command[1:]...)
+	cmd.Dir = dir
+	cmd.Env = convertEnvVars(envVars)
+	cmd.Stdout = output
+	cmd.Stderr = output
+	setProcessGroup(cmd)
+
+	err = cmd.Start()
+	if err != nil {
+		output.close()
+		if errors.Is(err, exec.ErrNotFound) || errors.Is(err, os.ErrNotExist) {
+			return scheduler.StartContainerResponse{}, fmt.Errorf("command %q not found: %w", command[0],
+				scheduler.ErrNoSuchImage)
+		}
+		return scheduler.StartContainerResponse{}, err
+	}
+
+	proc := &process{
+		id:     req.ID,
+		dir:    dir,
+		env:    cmd.Env,
+		cmd:    cmd,
+		output: output,
+		done:   make(chan struct{}),
+	}
+
+	go func() {
+		_ = cmd.Wait()
+
+		proc.mu.Lock()
+		proc.exitCode = cmd.ProcessState.ExitCode()
+		proc.cancelled = proc.cancelling
+		proc.mu.Unlock()
+
+		// Followers that reach the end of the output expect the process to be done.
+		close(proc.done)
+		output.close()
+	}()
+
+	orch.mu.Lock()
+	orch.processes[req.ID] = proc
+	orch.mu.Unlock()
+
+	return scheduler.StartContainerResponse{
+		SchedulerID: req.ID,
+		URL:         url,
+	}, nil
+}
+
+// processCommand returns the command a container is run as.
+func processCommand(req scheduler.StartContainerRequest) ([]string, error) {
+	if req.Exec.Shell != "" {
+		rawScript, err := base64.StdEncoding.DecodeString(req.Exec.Script)
+		if err != nil {
+			return nil, err
+		}
+
+		return []string{req.Exec.Shell, "-c", string(rawScript)}, nil
+	}
+
+	command := strings.Fields(req.ImageName)
+	if len(command) == 0 {
+		return nil, fmt.Errorf("no command to run; the local scheduler runs a task's image as its command")
+	}
+
+	return command, nil
+}
+
+func (orch *Orchestrator) getProcess(id string) (*process, error) {
+	orch.mu.Lock()
+	defer orch.mu.Unlock()
+
+	proc, exists := orch.processes[id]
+	if !exists {
+		return nil, scheduler.ErrNoSuchContainer
+	}
+
+	return proc, nil
+}
+
+func (orch *Orchestrator) StopContainer(req scheduler.StopContainerRequest) error {
+	proc, err := orch.getProcess(req.SchedulerID)
+	if err != nil {
+		return err
+	}
+
+	select {
+	case <-proc.done:
+		return nil
+	default:
+	}
+
+	proc.mu.Lock()
+	proc.cancelling = true
+	proc.mu.Unlock()
+
+	err = terminateProcess(proc.cmd)
+	if err != nil {
+		log.Debug().Err(err).Str("process", proc.id).Msg("local: could not terminate process")
+	}
+
+	select {
+	case <-proc.done:
+		return nil
+	case <-time.After(req.Timeout):
+	}
+
+	err = killProcess(proc.cmd)
+	if err != nil {
+		return err
+	}
+
+	<-proc.done
+	return nil
+}
+
+func (orch *Orchestrator) GetState(gs scheduler.GetStateRequest) (scheduler.GetStateResponse, error) {
+	proc, err := orch.getProcess(gs.SchedulerID)
+	if err != nil {
+		return scheduler.GetStateResponse{
+			ExitCode: 0,
+			State:    models.ContainerStateUnknown,
+		}, err
+	}
+
+	select {
+	case <-proc.done:
+	default:
+		return scheduler.GetStateResponse{
+			ExitCode: 0,
+			State:    models.ContainerStateRunning,
+		}, nil
+	}
+
+	switch {
+	case proc.cancelled:
+		return scheduler.GetStateResponse{
+			ExitCode: proc.exitCode,
+			State:    models.ContainerStateCancelled,
+		}, nil
+	case proc.exitCode == 0:
+		return scheduler.GetStateResponse{
+			ExitCode: proc.exitCode,
+			State:    models.ContainerStateSuccess,
+		}, nil
+	default:
+		return scheduler.GetStateResponse{
+			ExitCode: proc.exitCode,
+			State:    models.ContainerStateFailed,
+		}, nil
+	}
+}
+
+// GetLogs follows the process's output from the beginning until the process exits.
+func (orch *Orchestrator) GetLogs(gl scheduler.GetLogsRequest) (io.Reader, error) {
+	proc, err := orch.getProcess(gl.SchedulerID)
+	if err != nil {
+		return nil, err
+	}
+
+	file, err := os.Open(filepath.Join(proc.dir, outputFile))
+	if err != nil {
+		return nil, err
+	}
+
+	return &follower{output: proc.output, file: file}, nil
+}
+
+// AttachContainer runs a command in the process's directory with the process's environment. Processes don't come
+// with a pseudo-terminal, so the command's input and output are plain pipes and resizing does nothing.
+func (orch *Orchestrator) AttachContainer(request scheduler.AttachContainerRequest) (scheduler.AttachContainerResponse, error) {
+	proc, err := orch.getProcess(request.SchedulerID)
+	if err != nil {
+		return scheduler.AttachContainerResponse{}, err
+	}
+
+	select {
+	case <-proc.done:
+		return scheduler.AttachContainerResponse{}, fmt.Errorf("process has already exited")
+	default:
+	}
+
+	if len(request.Command) == 0 {
+		return scheduler.AttachContainerResponse{}, fmt.Errorf("no command to run")
+	}
+
+	cmd := exec.Command(request.Command[0], request.Command[1:]...)
+	cmd.Dir = proc.dir
+	cmd.Env = proc.env
+	setProcessGroup(cmd)
+
+	stdin, err := cmd.StdinPipe()
+	if err != nil {
+		return scheduler.AttachContainerResponse{}, err
+	}
+
+	reader, writer := io.Pipe()
+	cmd.Stdout = writer
+	cmd.Stderr = writer
+
+	err = cmd.Start()
+	if err != nil {
+		return scheduler.AttachContainerResponse{}, err
+	}
+
+	go func() {
+		err := cmd.Wait()
+		if err != nil {
+			log.Debug().Err(err).Str("process", proc.id).Msg("local: attached command exited")
+		}
+		writer.Close()
+	}()
+
+	return scheduler.AttachContainerResponse{
+		Conn: &attachConn{PipeReader: reader, stdin: stdin, cmd: cmd},
+		Resize: func(width, height uint) error {
+			return nil
+		},
+	}, nil
+}
+
+// attachConn reads the output of an attached command and passes writes on as its input. Closing it kills the command
+// if it is still running.
+type attachConn struct {
+	*io.PipeReader
+	stdin io.WriteCloser
+	cmd   *exec.Cmd
+}
+
+func (c *attachConn) Write(p []byte) (int, error) {
+	return c.stdin.Write(p)
+}
+
+func (c *attachConn) Close() error {
+	_ = c.stdin.Close()
+	_ = killProcess(c.cmd)
+	return c.PipeReader.Close()
+}
+
+func (orch *Orchestrator) ListContainers(request scheduler.ListContainersRequest) (scheduler.ListContainersResponse, error) {
+	orch.mu.Lock()
+	defer orch.mu.Unlock()
+
+	response := scheduler.ListContainersResponse{
+		Containers: []scheduler.Container{},
+	}
+
+	for id, proc := range orch.processes {
+		if !strings.HasPrefix(id, request.Prefix) {
+			continue
+		}
+
+		running := true
+		select {
+		case <-proc.done:
+			running = false
+		default:
+		}
+
+		response.Containers = append(response.Containers, scheduler.Container{
+			ID:          id,
+			SchedulerID: id,
+			Running:     running,
+		})
+	}
+
+	return response, nil
+}
+
+// CopyFromContainer archives a path from within the process's working directory, which relative paths are relative
+// to. The archive is written as it is read.
+func (orch *Orchestrator) CopyFromContainer(request scheduler.CopyFromContainerRequest) (scheduler.CopyFromContainerResponse, error) {
+	proc, err := orch.getProcess(request.SchedulerID)
+	if err != nil {
+		return scheduler.CopyFromContainerResponse{}, err
+	}
+
+	path := request.Path
+	if !filepath.IsAbs(path) {
+		path = filepath.Join(proc.dir, path)
+	}
+	path = filepath.Clean(path)
+
+	info, err := os.Stat(path)
+	if err != nil {
+		if errors.Is(err, os.ErrNotExist) {
+			return scheduler.CopyFromContainerResponse{}, scheduler.ErrNoSuchFile
+		}
+		return scheduler.CopyFromContainerResponse{}, err
+	}
+
+	reader, writer := io.Pipe()
+	go func() {
+		writer.CloseWithError(writeArchive(writer, path))
+	}()
+
+	return scheduler.CopyFromContainerResponse{
+		Archive: reader,
+		IsDir:   info.IsDir(),
+	}, nil
+}
+
+// writeArchive writes a tar archive of the path to w with entries named relative to the path's parent, the same way
+// docker does.
+func writeArchive(w io.Writer, path string) error {
+	archive := tar.NewWriter(w)
+	parent := filepath.Dir(path)
+
+	err := filepath.Walk(path, func(file string, info os.FileInfo, err error) error {
+		if err != nil {
+			return err
+		}
+
+		// Only regular files and directories are archived; there's nothing sensible to do with sockets and the like.
+		if !info.Mode().IsRegular() && !info.IsDir() {
+			return nil
+		}
+
+		name, err := filepath.Rel(parent, file)
+		if err != nil {
+			return err
+		}
+
+		header, err := tar.FileInfoHeader(info, "")
+		if err != nil {
+			return err
+		}
+		header.Name = filepath.ToSlash(name)
+
+		err = archive.WriteHeader(header)
+		if err != nil {
+			return err
+		}
+
+		if info.IsDir() {
+			return nil
+		}
+
+		content, err := os.Open(file)
+		if err != nil {
+			return err
+		}
+		defer content.Close()
+
+		_, err = io.Copy(archive, content)
+		return err
+	})
+	if err != nil {
+		return err
+	}
+
+	return archive.Close()
+}
+
+// extractArchive extracts a tar archive written by writeArchive into the destination directory, creating it if it
+// doesn't exist. Entries that would end up outside of the destination are refused.
+func extractArchive(r io.Reader, destination string) error {
+	err := os.MkdirAll(destination, 0o755)
+	if err != nil {
+		return err
+	}
+
+	archive := tar.NewReader(r)
+
+	for {
+		header, err := archive.Next()
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				return nil
+			}
+			return err
+		}
+
+		target := filepath.Join(destination, filepath.FromSlash(header.Name))
+		if target != destination && !strings.HasPrefix(target, destination+string(filepath.Separator)) {
+			return fmt.Errorf("archive entry %q is outside of the destination", header.Name)
+		}
+
+		switch header.Typeflag {
+		case tar.TypeDir:
+			err := os.MkdirAll(target, os.FileMode(header.Mode).Perm())
+			if err != nil {
+				return err
+			}
+		case tar.TypeReg:
+			err := extractFile(archive, target, os.FileMode(header.Mode).Perm())
+			if err != nil {
+				return err
+			}
+		}
+	}
+}
+
+func extractFile(r io.Reader, target string, mode os.FileMode) error {
+	err := os.MkdirAll(filepath.Dir(target), 0o755)
+	if err != nil {
+		return err
+	}
+
+	file, err := os.OpenFile(target, os.O_CREATE|os.O_WRONLY|os.O_TRUNC, mode)
+	if err != nil {
+		return err
+	}
+	defer file.Close()
+
+	_, err = io.Copy(file, r)
+	return err
+}
+
+func (orch *Orchestrator) RemoveContainer(request scheduler.RemoveContainerRequest) error {
+	orch.mu.Lock()
+	proc, exists := orch.processes[request.SchedulerID]
+	delete(orch.processes, request.SchedulerID)
+	orch.mu.Unlock()
+
+	if !exists {
+		return scheduler.ErrNoSuchContainer
+	}
+
+	select {
+	case <-proc.done:
+	default:
+		proc.mu.Lock()
+		proc.cancelling = true
+		proc.mu.Unlock()
+
+		_ = killProcess(proc.cmd)
+		<-proc.done
+	}
+
+	return os.RemoveAll(proc.dir)
+}
+
+// CreateNetwork does nothing; processes share the network of the machine Gofer runs on.
+func (orch *Orchestrator) CreateNetwork(request scheduler.CreateNetworkRequest) error {
+	return nil
+}
+
+// RemoveNetwork does nothing; processes share the network of the machine Gofer runs on.
+func (orch *Orchestrator) RemoveNetwork(request scheduler.RemoveNetworkRequest) error {
+	return nil
+}
+
+// prune removes every process that has exited and returns how many were removed.
+func (orch *Orchestrator) prune() int {
+	orch.mu.Lock()
+	exited := []string{}
+	for id, proc := range orch.processes {
+		select {
+		case <-proc.done:
+			exited = append(exited, id)
+		default:
+		}
+	}
+	orch.mu.Unlock()
+
+	removed := 0
+	for _, id := range exited {
+		err := orch.RemoveContainer(scheduler.RemoveContainerRequest{SchedulerID: id})
+		if err != nil {
+			log.Debug().Err(err).Str("process", id).Msg("local: could not remove process")
+			continue
+		}
+		removed++
+	}
+
+	return removed
+}
+
+// freePort asks the operating system for a port nothing is listening on.
+func freePort() (int, error) {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		return 0, err
+	}
+	defer listener.Close()
+
+	return listener.Addr().(*net.TCPAddr).Port, nil
+}
+
+func convertEnvVars(envvars map[string]string) []string {
+	output := []string{}
+	for key, value := range envvars {
+		output = append(output, fmt.Sprintf(envvarFormat, key, value))
+	}
+
+	return output
+}
@@ -0,0 +1,220 @@
+package local
+
+import (
+	"archive/tar"
+	"encoding/base64"
+	"errors"
+	"io"
+	"testing"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/google/go-cmp/cmp"
+)
+
+func TestProcessLifecycle(t *testing.T) {
+	orch, err := New(t.TempDir(), false, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	script := base64.StdEncoding.EncodeToString([]byte("echo \"hello $NAME\"; exit 3"))
+
+	resp, err := orch.StartContainer(scheduler.StartContainerRequest{
+		ID:      "simple_1_build",
+		EnvVars: map[string]string{"NAME": "gofer"},
+		Exec:    scheduler.Exec{Shell: "/bin/sh", Script: script},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	logs, err := orch.GetLogs(scheduler.GetLogsRequest{SchedulerID: resp.SchedulerID})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	got, err := io.ReadAll(logs)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if string(got) != "hello gofer\n" {
+		t.Errorf("unexpected logs; want %q got %q", "hello gofer\n", got)
+	}
+
+	state, err := orch.GetState(scheduler.GetStateRequest{SchedulerID: resp.SchedulerID})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if state.State != models.ContainerStateFailed || state.ExitCode != 3 {
+		t.Errorf("unexpected state; want %s(3) got %s(%d)", models.ContainerStateFailed, state.State, state.ExitCode)
+	}
+
+	err = orch.RemoveContainer(scheduler.RemoveContainerRequest{SchedulerID: resp.SchedulerID})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	_, err = orch.GetState(scheduler.GetStateRequest{SchedulerID: resp.SchedulerID})
+	if !errors.Is(err, scheduler.ErrNoSuchContainer) {
+		t.Errorf("expected removed process to be gone; got %v", err)
+	}
+}
+
+func TestStopProcess(t *testing.T) {
+	orch, err := New(t.TempDir(), false, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	resp, err := orch.StartContainer(scheduler.StartContainerRequest{
+		ID:        "simple_1_sleep",
+		ImageName: "sleep 60",
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = orch.StopContainer(scheduler.StopContainerRequest{SchedulerID: resp.SchedulerID, Timeout: time.Second})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	state, err := orch.GetState(scheduler.GetStateRequest{SchedulerID: resp.SchedulerID})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if state.State != models.ContainerStateCancelled {
+		t.Errorf("unexpected state; want %s got %s", models.ContainerStateCancelled, state.State)
+	}
+}
+
+func TestCopyFromProcess(t *testing.T) {
+	orch, err := New(t.TempDir(), false, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	script := base64.StdEncoding.EncodeToString([]byte("mkdir dist && echo built > dist/app"))
+
+	resp, err := orch.StartContainer(scheduler.StartContainerRequest{
+		ID:   "simple_1_build",
+		Exec: scheduler.Exec{Shell: "/bin/sh", Script: script},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	// Logs are only finished once the process has exited.
+	logs, err := orch.GetLogs(scheduler.GetLogsRequest{SchedulerID: resp.SchedulerID})
+	if err != nil {
+		t.Fatal(err)
+	}
+	_, _ = io.ReadAll(logs)
+
+	copied, err := orch.CopyFromContainer(scheduler.CopyFromContainerRequest{
+		SchedulerID: resp.SchedulerID,
+		Path:        "dist",
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer copied.Archive.Close()
+
+	if !copied.IsDir {
+		t.Errorf("expected dist to be a directory")
+	}
+
+	files := map[string]string{}
+	archive := tar.NewReader(copied.Archive)
+	for {
+		header, err := archive.Next()
+		if errors.Is(err, io.EOF) {
+			break
+		}
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		content, err := io.ReadAll(archive)
+		if err != nil {
+			t.Fatal(err)
+		}
+		files[header.Name] = string(content)
+	}
+
+	expected := map[string]string{"dist": "", "dist/app": "built\n"}
+	if diff := cmp.Diff(expected, files); diff != "" {
+		t.Errorf("unexpected archive (-want +got):\n%s", diff)
+	}
+
+	_, err = orch.CopyFromContainer(scheduler.CopyFromContainerRequest{
+		SchedulerID: resp.SchedulerID,
+		Path:        "missing",
+	})
+	if !errors.Is(err, scheduler.ErrNoSuchFile) {
+		t.Errorf("expected missing path to not be found; got %v", err)
+	}
+}
+
+// Tests that what's copied out of a process can be put back into a new one before it starts.
+func TestStartProcessWithFiles(t *testing.T) {
+	orch, err := New(t.TempDir(), false, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	script := base64.StdEncoding.EncodeToString([]byte("mkdir -p .cache/deps && echo cached > .cache/deps/lib"))
+
+	first, err := orch.StartContainer(scheduler.StartContainerRequest{
+		ID:   "simple_1_build",
+		Exec: scheduler.Exec{Shell: "/bin/sh", Script: script},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	logs, err := orch.GetLogs(scheduler.GetLogsRequest{SchedulerID: first.SchedulerID})
+	if err != nil {
+		t.Fatal(err)
+	}
+	_, _ = io.ReadAll(logs)
+
+	copied, err := orch.CopyFromContainer(scheduler.CopyFromContainerRequest{
+		SchedulerID: first.SchedulerID,
+		Path:        ".cache",
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer copied.Archive.Close()
+
+	script = base64.StdEncoding.EncodeToString([]byte("cat .cache/deps/lib"))
+
+	second, err := orch.StartContainer(scheduler.StartContainerRequest{
+		ID:    "simple_2_build",
+		Exec:  scheduler.Exec{Shell: "/bin/sh", Script: script},
+		Files: []scheduler.ContainerFiles{{Path: ".", Archive: copied.Archive}},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	logs, err = orch.GetLogs(scheduler.GetLogsRequest{SchedulerID: second.SchedulerID})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	output, err := io.ReadAll(logs)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if string(output) != "cached\n" {
+		t.Errorf("unexpected output; want %q got %q", "cached\n", string(output))
+	}
+}
@@ -0,0 +1,89 @@
+package local
+
+import (
+	"errors"
+	"io"
+	"os"
+	"sync"
+)
+
+// output writes a process's stdout and stderr to a file and lets followers of that file know when there is more to
+// read.
+type output struct {
+	file *os.File
+
+	mu sync.Mutex
+	// written is closed and replaced after every write so that followers can wait on it for more output.
+	written chan struct{}
+	closed  bool
+}
+
+func newOutput(file *os.File) *output {
+	return &output{
+		file:    file,
+		written: make(chan struct{}),
+	}
+}
+
+func (o *output) Write(p []byte) (int, error) {
+	o.mu.Lock()
+	defer o.mu.Unlock()
+
+	n, err := o.file.Write(p)
+	close(o.written)
+	o.written = make(chan struct{})
+
+	return n, err
+}
+
+// close is called once the process has exited and nothing else will be written.
+func (o *output) close() {
+	o.mu.Lock()
+	defer o.mu.Unlock()
+
+	if o.closed {
+		return
+	}
+
+	o.closed = true
+	_ = o.file.Close()
+	close(o.written)
+}
+
+// state returns a channel that is closed on the next write and whether the output is already finished.
+func (o *output) state() (<-chan struct{}, bool) {
+	o.mu.Lock()
+	defer o.mu.Unlock()
+
+	return o.written, o.closed
+}
+
+// follower reads a process's output file from the start, waiting for more to be written until the process exits.
+type follower struct {
+	output *output
+	file   *os.File
+}
+
+func (f *follower) Read(p []byte) (int, error) {
+	for {
+		// The state has to be taken before reading so that a write in between isn't missed.
+		written, closed := f.output.state()
+
+		n, err := f.file.Read(p)
+		if n > 0 {
+			return n, nil
+		}
+
+		if err != nil && !errors.Is(err, io.EOF) {
+			f.file.Close()
+			return 0, err
+		}
+
+		if closed {
+			f.file.Close()
+			return 0, io.EOF
+		}
+
+		<-written
+	}
+}
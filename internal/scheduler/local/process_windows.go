@@ -0,0 +1,19 @@
+//go:build windows
+
+package local
+
+import (
+	"os/exec"
+)
+
+// setProcessGroup does nothing on windows; only the command itself is signalled.
+func setProcessGroup(cmd *exec.Cmd) {}
+
+// terminateProcess kills the command since windows has no equivalent of SIGTERM.
+func terminateProcess(cmd *exec.Cmd) error {
+	return cmd.Process.Kill()
+}
+
+func killProcess(cmd *exec.Cmd) error {
+	return cmd.Process.Kill()
+}
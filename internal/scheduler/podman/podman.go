@@ -0,0 +1,105 @@
+// Package podman schedules containers through podman's REST API. Podman serves a docker compatible version of its API
+// on the same socket, which allows the docker scheduler to be reused for everything apart from where the two APIs
+// differ.
+package podman
+
+import (
+	"context"
+	"fmt"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/scheduler"
+	"github.com/clintjedwards/gofer/internal/scheduler/docker"
+	"github.com/docker/docker/api/types"
+	"github.com/docker/docker/api/types/filters"
+	"github.com/docker/docker/client"
+)
+
+const (
+	// defaultSocket is where rootful podman installs serve their API.
+	defaultSocket = "unix:///run/podman/podman.sock"
+
+	defaultPruneInterval = 24 * time.Hour
+)
+
+type Orchestrator struct {
+	docker.Orchestrator
+}
+
+// New connects to the podman API served on the given socket(ex. unix:///run/podman/podman.sock).
+func New(socket string, prune bool, pruneInterval time.Duration) (Orchestrator, error) {
+	if socket == "" {
+		socket = defaultSocket
+	}
+
+	if pruneInterval <= 0 {
+		pruneInterval = defaultPruneInterval
+	}
+
+	podman, err := client.NewClientWithOpts(client.WithHost(socket), client.WithAPIVersionNegotiation())
+	if err != nil {
+		return Orchestrator{}, fmt.Errorf("could not create podman client: %w", err)
+	}
+
+	// Check connection to podman
+	version, err := podman.ServerVersion(context.Background())
+	if err != nil {
+		return Orchestrator{}, fmt.Errorf("could not connect to podman at %q; make sure the podman API service is "+
+			"running(podman system service)", socket)
+	}
+
+	if !isPodman(version) {
+		return Orchestrator{}, fmt.Errorf("the API served at %q is not podman's", socket)
+	}
+
+	return Orchestrator{
+		Orchestrator: docker.NewFromClient(podman, prune, pruneInterval),
+	}, nil
+}
+
+// isPodman checks the components a server reports to make sure that the socket isn't a docker socket.
+func isPodman(version types.Version) bool {
+	for _, component := range version.Components {
+		if strings.Contains(strings.ToLower(component.Name), "podman") {
+			return true
+		}
+	}
+
+	return false
+}
+
+// ListContainers differs from the docker scheduler's since podman matches the name filter against container names
+// without docker's leading slash. Names are still returned with the slash.
+func (orch *Orchestrator) ListContainers(request scheduler.ListContainersRequest) (scheduler.ListContainersResponse, error) {
+	containers, err := orch.ContainerList(context.Background(), types.ContainerListOptions{
+		All:     true,
+		Filters: filters.NewArgs(filters.Arg("name", "^"+regexp.QuoteMeta(request.Prefix))),
+	})
+	if err != nil {
+		return scheduler.ListContainersResponse{}, err
+	}
+
+	response := scheduler.ListContainersResponse{
+		Containers: []scheduler.Container{},
+	}
+
+	for _, container := range containers {
+		for _, name := range container.Names {
+			id := strings.TrimPrefix(name, "/")
+			if !strings.HasPrefix(id, request.Prefix) {
+				continue
+			}
+
+			response.Containers = append(response.Containers, scheduler.Container{
+				ID:          id,
+				SchedulerID: container.ID,
+				Running:     container.State == "running",
+			})
+			break
+		}
+	}
+
+	return response, nil
+}
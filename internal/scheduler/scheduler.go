@@ -15,6 +15,12 @@ type EngineType string
 const (
 	// EngineDocker uses local docker instance to schedule tasks.
 	EngineDocker EngineType = "docker"
+
+	// EnginePodman uses a podman REST API service to schedule tasks.
+	EnginePodman EngineType = "podman"
+
+	// EngineLocal runs tasks as processes on the local machine without a container runtime.
+	EngineLocal EngineType = "local"
 )
 
 // ErrNoSuchContainer is returned when a container requested could not be located on the scheduler.
@@ -23,6 +29,9 @@ var ErrNoSuchContainer = errors.New("scheduler: entity not found")
 // ErrNoSuchImage is returned when the requested container image could not be pulled.
 var ErrNoSuchImage = errors.New("scheduler: docker image not found")
 
+// ErrNoSuchFile is returned when a path requested from within a container does not exist.
+var ErrNoSuchFile = errors.New("scheduler: file not found")
+
 type Exec struct {
 	Shell  string // The shell in which to run the script under.
 	Script string // List of commands to run in entrypoint of container.
@@ -33,6 +42,10 @@ type StartContainerRequest struct {
 	ImageName string            // The docker image repository endpoint of the container; tag can be included.
 	EnvVars   map[string]string // Environment variables to be passed to the container
 
+	// Labels are attached to the container so that it can be traced back to what Gofer started it for.
+	// Ex: the correlation ID of the run a task run is part of.
+	Labels map[string]string
+
 	RegistryUser string // Username for auth registry
 	RegistryPass string // Password for auth registry
 
@@ -40,14 +53,69 @@ type StartContainerRequest struct {
 	// don't use proper tagging or versioning.
 	AlwaysPull bool
 
+	// ImageMaxAge pulls the image again if the copy already on the host is older than it. Zero keeps using the copy on
+	// the host for as long as it's there.
+	ImageMaxAge time.Duration
+
 	// Networking is used to communicate to the container via RPC. This is only needed by triggers.
 	EnableNetworking bool
 	Exec             Exec
+
+	// Files are put in place within the container before it starts. Ex: caches saved by earlier task runs.
+	Files []ContainerFiles
+
+	// The CPU and memory the container is guaranteed and held to. Schedulers that can't enforce them ignore them.
+	Resources Resources
+
+	// GPUs the container is given access to. Nil if it doesn't need any.
+	GPUs *GPURequest
+
+	// Privileged gives the container every capability and access to the host's devices.
+	Privileged bool
+
+	// CapAdd are Linux capabilities added to the container's default set(ex. "SYS_ADMIN").
+	CapAdd []string
+
+	// Network is a network created with CreateNetwork that the container joins instead of the scheduler's default
+	// one. Other containers on the network can reach it by NetworkAlias.
+	Network      string
+	NetworkAlias string
+}
+
+// Resources are the CPU and memory of a container. Requests are what the container is guaranteed when the host is
+// under contention, limits are the most it can ever use. Zero values are left unset.
+type Resources struct {
+	CPURequest    float64 // In CPU cores.
+	CPULimit      float64 // In CPU cores.
+	MemoryRequest int64   // In bytes.
+	MemoryLimit   int64   // In bytes.
+}
+
+// GPURequest asks for GPUs to be made available to a container. Either Count or DeviceIDs is set.
+type GPURequest struct {
+	Count        int      // How many GPUs; -1 for all of them.
+	DeviceIDs    []string // Specific GPUs by index or UUID.
+	Driver       string   // Ex: "nvidia"; left to the scheduler when empty.
+	Capabilities []string // Ex: ["compute", "utility"]; defaults to ["gpu"].
+}
+
+// ContainerFiles is a tar archive extracted into a directory of a container before it starts.
+type ContainerFiles struct {
+	// The directory the archive is extracted into; it's created if it doesn't exist. Relative paths are relative to
+	// the container's working directory.
+	Path string
+
+	// A tar archive in the same shape as the ones returned by CopyFromContainer, so what's copied out of a path can be
+	// put back by extracting it into the path's parent.
+	Archive io.Reader
 }
 
 type StartContainerResponse struct {
 	SchedulerID string // a unique way to identify the container that has started.
 	URL         string // optional endpoint if "EnableNetworking" was used.
+
+	// How long pulling the container's image took. Zero if the image was not pulled.
+	ImagePullDuration time.Duration
 }
 
 type StopContainerRequest struct {
@@ -68,6 +136,62 @@ type GetLogsRequest struct {
 	SchedulerID string
 }
 
+type AttachContainerRequest struct {
+	SchedulerID string   // unique identification for container to attach to.
+	Command     []string // The command to run within the container.
+	Width       uint     // The initial width of the pseudo-terminal in columns.
+	Height      uint     // The initial height of the pseudo-terminal in rows.
+}
+
+type AttachContainerResponse struct {
+	// Conn is connected to the pseudo-terminal of the command. Anything written is passed to the command as input
+	// and the command's output can be read back from it.
+	Conn io.ReadWriteCloser
+
+	// Resize changes the size of the pseudo-terminal.
+	Resize func(width, height uint) error
+}
+
+type ListContainersRequest struct {
+	// Prefix limits the containers returned to those whose ID(the ID given when starting them) starts with it.
+	Prefix string
+}
+
+type Container struct {
+	ID          string // The ID the container was started with.
+	SchedulerID string
+	Running     bool
+}
+
+type ListContainersResponse struct {
+	Containers []Container
+}
+
+type CopyFromContainerRequest struct {
+	SchedulerID string // unique identification for container to copy from.
+	Path        string // The file or directory to copy; relative paths are relative to the container's working directory.
+}
+
+type CopyFromContainerResponse struct {
+	// Archive is a tar archive of the path. Entries are named relative to the parent of the path, so copying
+	// "/build/dist" results in entries named "dist", "dist/app" and so on. The caller must close it.
+	Archive io.ReadCloser
+	IsDir   bool // Whether the path is a directory.
+}
+
+type RemoveContainerRequest struct {
+	SchedulerID string // unique identification for container to remove.
+}
+
+type CreateNetworkRequest struct {
+	ID     string            // The name of the network.
+	Labels map[string]string // Attached to the network so that it can be traced back to what Gofer created it for.
+}
+
+type RemoveNetworkRequest struct {
+	ID string // The name of the network to remove.
+}
+
 type Engine interface {
 	// StartContainer launches a new container on scheduler. Scheduler should return a unique "schedulerID" to allow
 	// the ability to refers specifically to the container on subsequent calls.
@@ -84,4 +208,29 @@ type Engine interface {
 	// be written to from a goroutine so that they user gets logs as they are streamed from the container.
 	// Finally once finished the io.reader should be close with an EOF denoting that there are no more logs to be read.
 	GetLogs(request GetLogsRequest) (logs io.Reader, err error)
+
+	// AttachContainer runs a command within an already running container with a pseudo-terminal attached so that
+	// interactive programs can be used. The caller is responsible for closing the returned connection.
+	AttachContainer(request AttachContainerRequest) (response AttachContainerResponse, err error)
+
+	// ListContainers returns the containers on the scheduler, including ones that are no longer running. This allows
+	// Gofer to find containers it started before it was restarted.
+	ListContainers(request ListContainersRequest) (response ListContainersResponse, err error)
+
+	// CopyFromContainer returns a file or directory from within a container. It must work on containers that have
+	// exited so that what a task run left behind can be collected after it finishes. Returns ErrNoSuchFile if the path
+	// doesn't exist within the container.
+	CopyFromContainer(request CopyFromContainerRequest) (response CopyFromContainerResponse, err error)
+
+	// RemoveContainer removes a container from the scheduler, stopping it first without waiting if it is still running.
+	RemoveContainer(request RemoveContainerRequest) error
+
+	// CreateNetwork creates an isolated network that containers can be started on to reach each other by their
+	// network alias. Creating a network that already exists is not an error. Schedulers that can't isolate
+	// containers from each other do nothing.
+	CreateNetwork(request CreateNetworkRequest) error
+
+	// RemoveNetwork removes a network created with CreateNetwork. Removing a network that doesn't exist is not an
+	// error.
+	RemoveNetwork(request RemoveNetworkRequest) error
 }
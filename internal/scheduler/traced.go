@@ -0,0 +1,114 @@
+package scheduler
+
+import (
+	"context"
+	"io"
+
+	"github.com/clintjedwards/gofer/internal/tracing"
+)
+
+// TracedEngine records every call made to a scheduler as a span.
+type TracedEngine struct {
+	ctx    context.Context
+	engine Engine
+}
+
+// NewTracedEngine returns the scheduler with every call recorded as a span that is a child of the span within ctx.
+// The scheduler is returned as is while tracing is turned off.
+func NewTracedEngine(ctx context.Context, engine Engine) Engine {
+	if !tracing.Enabled() {
+		return engine
+	}
+
+	return &TracedEngine{ctx: ctx, engine: engine}
+}
+
+func (e *TracedEngine) StartContainer(request StartContainerRequest) (StartContainerResponse, error) {
+	_, span := tracing.Start(e.ctx, "scheduler.StartContainer")
+	defer span.End()
+
+	result, err := e.engine.StartContainer(request)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) StopContainer(request StopContainerRequest) error {
+	_, span := tracing.Start(e.ctx, "scheduler.StopContainer")
+	defer span.End()
+
+	err := e.engine.StopContainer(request)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetState(request GetStateRequest) (GetStateResponse, error) {
+	_, span := tracing.Start(e.ctx, "scheduler.GetState")
+	defer span.End()
+
+	result, err := e.engine.GetState(request)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetLogs(request GetLogsRequest) (io.Reader, error) {
+	_, span := tracing.Start(e.ctx, "scheduler.GetLogs")
+	defer span.End()
+
+	result, err := e.engine.GetLogs(request)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AttachContainer(request AttachContainerRequest) (AttachContainerResponse, error) {
+	_, span := tracing.Start(e.ctx, "scheduler.AttachContainer")
+	defer span.End()
+
+	result, err := e.engine.AttachContainer(request)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) ListContainers(request ListContainersRequest) (ListContainersResponse, error) {
+	_, span := tracing.Start(e.ctx, "scheduler.ListContainers")
+	defer span.End()
+
+	result, err := e.engine.ListContainers(request)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) CopyFromContainer(request CopyFromContainerRequest) (CopyFromContainerResponse, error) {
+	_, span := tracing.Start(e.ctx, "scheduler.CopyFromContainer")
+	defer span.End()
+
+	result, err := e.engine.CopyFromContainer(request)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) RemoveContainer(request RemoveContainerRequest) error {
+	_, span := tracing.Start(e.ctx, "scheduler.RemoveContainer")
+	defer span.End()
+
+	err := e.engine.RemoveContainer(request)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) CreateNetwork(request CreateNetworkRequest) error {
+	_, span := tracing.Start(e.ctx, "scheduler.CreateNetwork")
+	defer span.End()
+
+	err := e.engine.CreateNetwork(request)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) RemoveNetwork(request RemoveNetworkRequest) error {
+	_, span := tracing.Start(e.ctx, "scheduler.RemoveNetwork")
+	defer span.End()
+
+	err := e.engine.RemoveNetwork(request)
+	span.RecordError(err)
+	return err
+}
@@ -1,9 +1,13 @@
 package bolt
 
 import (
+	"bytes"
 	"crypto/aes"
 	"crypto/cipher"
 	"crypto/rand"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
 	"errors"
 	"fmt"
 	"io"
@@ -15,14 +19,32 @@ import (
 	bolt "go.etcd.io/bbolt"
 )
 
-// Store is a representation of the bolt datastore
+// Store is a representation of the bolt datastore.
+//
+// Secrets are stored using envelope encryption: each secret is encrypted with its own randomly generated data key and
+// only that data key is encrypted with the operator supplied encryption key. Rotating the encryption key then only
+// requires re-encrypting the data keys, and secrets encrypted with a previous key stay readable until they have been
+// re-encrypted.
 type Store struct {
-	encryptionKey string
+	currentKeyID string
+	keys         map[string][]byte // Encryption keys by key ID; includes the current key and any previous keys.
 	*storm.DB
 }
 
 const rootBucket string = "root"
 
+// envelopePrefix marks secrets stored using envelope encryption. Secrets stored before envelope encryption was
+// introduced are encrypted with the encryption key directly and are upgraded the next time they are written or
+// re-encrypted.
+var envelopePrefix = []byte("gofer-envelope-v1:")
+
+// envelope is a secret encrypted with a data key of its own along with that data key.
+type envelope struct {
+	KeyID   string `json:"key_id"`   // The ID of the encryption key the data key is encrypted with.
+	DataKey []byte `json:"data_key"` // The key the secret is encrypted with, encrypted with the encryption key.
+	Content []byte `json:"content"`  // The secret, encrypted with the data key.
+}
+
 func encrypt(key []byte, plaintext []byte) ([]byte, error) {
 	c, err := aes.NewCipher(key)
 	if err != nil {
@@ -62,19 +84,121 @@ func decrypt(key []byte, ciphertext []byte) ([]byte, error) {
 	return gcm.Open(nil, nonce, ciphertext, nil)
 }
 
-// New creates a new boltdb with given settings
-func New(path, encryptionKey string) (Store, error) {
+// keyID identifies an encryption key without revealing it so that secrets can record which key they were encrypted
+// with.
+func keyID(key []byte) string {
+	sum := sha256.Sum256(key)
+	return hex.EncodeToString(sum[:8])
+}
+
+// New creates a new boltdb with given settings. Secrets are encrypted with the encryption key; previous keys are only
+// used to read secrets that haven't been re-encrypted since the encryption key was changed.
+func New(path, encryptionKey string, previousKeys ...string) (Store, error) {
 	store, err := storm.Open(path, storm.BoltOptions(0600, &bolt.Options{Timeout: 1 * time.Second}))
 	if err != nil {
 		return Store{}, err
 	}
 
+	keys := map[string][]byte{}
+	for _, key := range previousKeys {
+		keys[keyID([]byte(key))] = []byte(key)
+	}
+	keys[keyID([]byte(encryptionKey))] = []byte(encryptionKey)
+
 	return Store{
-		encryptionKey,
+		keyID([]byte(encryptionKey)),
+		keys,
 		store,
 	}, nil
 }
 
+// seal encrypts the secret with a new data key, which is in turn encrypted with the current encryption key.
+func (store *Store) seal(content []byte) ([]byte, error) {
+	dataKey := make([]byte, 32)
+	if _, err := io.ReadFull(rand.Reader, dataKey); err != nil {
+		return nil, err
+	}
+
+	encryptedContent, err := encrypt(dataKey, content)
+	if err != nil {
+		return nil, err
+	}
+
+	return store.wrap(dataKey, encryptedContent)
+}
+
+// wrap encrypts the data key with the current encryption key and packs it with the content it encrypts.
+func (store *Store) wrap(dataKey, encryptedContent []byte) ([]byte, error) {
+	encryptedDataKey, err := encrypt(store.keys[store.currentKeyID], dataKey)
+	if err != nil {
+		return nil, err
+	}
+
+	encodedEnvelope, err := json.Marshal(envelope{
+		KeyID:   store.currentKeyID,
+		DataKey: encryptedDataKey,
+		Content: encryptedContent,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	return append(append([]byte{}, envelopePrefix...), encodedEnvelope...), nil
+}
+
+// open returns the data key and encrypted content of a stored secret. Secrets stored before envelope encryption have
+// no data key of their own; their content is returned decrypted instead.
+func (store *Store) open(storedSecret []byte) (dataKey []byte, encryptedContent []byte, err error) {
+	if !bytes.HasPrefix(storedSecret, envelopePrefix) {
+		content, err := store.decryptLegacy(storedSecret)
+		if err != nil {
+			return nil, nil, err
+		}
+
+		return nil, content, nil
+	}
+
+	secret := envelope{}
+	err = json.Unmarshal(storedSecret[len(envelopePrefix):], &secret)
+	if err != nil {
+		return nil, nil, fmt.Errorf("could not decode secret: %w", err)
+	}
+
+	key, exists := store.keys[secret.KeyID]
+	if !exists {
+		return nil, nil, fmt.Errorf("secret is encrypted with unknown key %q; it may need to be added to the "+
+			"previous encryption keys", secret.KeyID)
+	}
+
+	dataKey, err = decrypt(key, secret.DataKey)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	return dataKey, secret.Content, nil
+}
+
+// decryptLegacy decrypts a secret encrypted directly with one of the encryption keys, trying the current key first.
+func (store *Store) decryptLegacy(storedSecret []byte) ([]byte, error) {
+	content, err := decrypt(store.keys[store.currentKeyID], storedSecret)
+	if err == nil {
+		return content, nil
+	}
+
+	for id, key := range store.keys {
+		if id == store.currentKeyID {
+			continue
+		}
+
+		content, keyErr := decrypt(key, storedSecret)
+		if keyErr == nil {
+			return content, nil
+		}
+	}
+
+	return nil, err
+}
+
 func (store *Store) GetSecret(key string) (string, error) {
 	var storedSecret []byte
 
@@ -87,7 +211,17 @@ func (store *Store) GetSecret(key string) (string, error) {
 		return "", err
 	}
 
-	decryptedSecret, err := decrypt([]byte(store.encryptionKey), storedSecret)
+	dataKey, content, err := store.open(storedSecret)
+	if err != nil {
+		log.Error().Err(err).Msg("could not decrypt secret")
+		return "", err
+	}
+
+	if dataKey == nil {
+		return string(content), nil
+	}
+
+	decryptedSecret, err := decrypt(dataKey, content)
 	if err != nil {
 		log.Error().Err(err).Msg("could not decrypt secret")
 		return "", err
@@ -97,7 +231,7 @@ func (store *Store) GetSecret(key string) (string, error) {
 }
 
 func (store *Store) PutSecret(key string, content string, force bool) error {
-	encryptedSecret, err := encrypt([]byte(store.encryptionKey), []byte(content))
+	encryptedSecret, err := store.seal([]byte(content))
 	if err != nil {
 		log.Error().Err(err).Msg("could not encrypt secret")
 		return fmt.Errorf("could not encrypt secret")
@@ -129,6 +263,91 @@ func (store *Store) PutSecret(key string, content string, force bool) error {
 	return tx.Commit()
 }
 
+// ReencryptSecrets re-encrypts the secrets that aren't encrypted with the current encryption key one at a time, each
+// within its own transaction, so that secrets can still be read and written while it runs. Secrets that use envelope
+// encryption only have their data key re-encrypted.
+func (store *Store) ReencryptSecrets() (int64, error) {
+	keys, err := store.ListSecretKeys("")
+	if err != nil {
+		return 0, err
+	}
+
+	reencrypted := int64(0)
+
+	for _, key := range keys {
+		changed, err := store.reencryptSecret(key)
+		if err != nil {
+			return reencrypted, fmt.Errorf("could not re-encrypt secret %q: %w", key, err)
+		}
+
+		if changed {
+			reencrypted++
+		}
+	}
+
+	return reencrypted, nil
+}
+
+func (store *Store) reencryptSecret(key string) (bool, error) {
+	tx, err := store.Begin(true)
+	if err != nil {
+		return false, err
+	}
+	defer tx.Rollback() // nolint: errcheck
+
+	var storedSecret []byte
+
+	err = tx.Get(rootBucket, key, &storedSecret)
+	if err != nil {
+		// The secret was removed since the keys were listed.
+		if errors.Is(err, storm.ErrNotFound) {
+			return false, nil
+		}
+
+		return false, err
+	}
+
+	if isCurrent, err := store.encryptedWithCurrentKey(storedSecret); err != nil || isCurrent {
+		return false, err
+	}
+
+	dataKey, content, err := store.open(storedSecret)
+	if err != nil {
+		return false, err
+	}
+
+	var encryptedSecret []byte
+	if dataKey == nil {
+		encryptedSecret, err = store.seal(content)
+	} else {
+		encryptedSecret, err = store.wrap(dataKey, content)
+	}
+	if err != nil {
+		return false, err
+	}
+
+	err = tx.Set(rootBucket, key, encryptedSecret)
+	if err != nil {
+		return false, err
+	}
+
+	return true, tx.Commit()
+}
+
+func (store *Store) encryptedWithCurrentKey(storedSecret []byte) (bool, error) {
+	if !bytes.HasPrefix(storedSecret, envelopePrefix) {
+		return false, nil
+	}
+
+	secret := envelope{}
+	err := json.Unmarshal(storedSecret[len(envelopePrefix):], &secret)
+	if err != nil {
+		return false, fmt.Errorf("could not decode secret: %w", err)
+	}
+
+	return secret.KeyID == store.currentKeyID, nil
+}
+
 func (store *Store) DeleteSecret(key string) error {
 	err := store.Delete(rootBucket, key)
 	if err != nil {
@@ -141,3 +360,35 @@ func (store *Store) DeleteSecret(key string) error {
 
 	return nil
 }
+
+func (store *Store) ListSecretKeys(prefix string) ([]string, error) {
+	keys := []string{}
+
+	err := store.Bolt.View(func(tx *bolt.Tx) error {
+		bucket := tx.Bucket([]byte(rootBucket))
+		if bucket == nil {
+			return nil
+		}
+
+		cursor := bucket.Cursor()
+		for key, _ := cursor.Seek([]byte(prefix)); key != nil && bytes.HasPrefix(key, []byte(prefix)); key, _ = cursor.Next() {
+			keys = append(keys, string(key))
+		}
+
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	return keys, nil
+}
+
+// Backup writes a consistent copy of the entire store to the writer using a read transaction, so writes can
+// continue while the backup is taken.
+func (store *Store) Backup(w io.Writer) error {
+	return store.Bolt.View(func(tx *bolt.Tx) error {
+		_, err := tx.WriteTo(w)
+		return err
+	})
+}
@@ -25,5 +25,94 @@ func TestBolt(t *testing.T) {
 		t.Fatal("secret returns does not equal secret put in")
 	}
 
+	err = store.PutSecret("otherkey", "mysupersecretkey", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	keys, err := store.ListSecretKeys("test")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if len(keys) != 1 || keys[0] != "testkey" {
+		t.Fatalf("list secret keys returned unexpected keys: %v", keys)
+	}
+
 	defer os.Remove("/tmp/test_bolt_secretStore.db")
 }
+
+func TestReencryptSecrets(t *testing.T) {
+	path := "/tmp/test_bolt_secretStore_reencrypt.db"
+	defer os.Remove(path)
+
+	oldKey := "oldencryptionkeyoldencryptionkey"
+	newKey := "newencryptionkeynewencryptionkey"
+
+	store, err := New(path, oldKey)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = store.PutSecret("envelope", "firstsecret", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	// Secrets stored before envelope encryption were encrypted with the encryption key directly.
+	legacySecret, err := encrypt([]byte(oldKey), []byte("secondsecret"))
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = store.Set(rootBucket, "legacy", legacySecret)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	store.Close()
+
+	store, err = New(path, newKey, oldKey)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	reencrypted, err := store.ReencryptSecrets()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if reencrypted != 2 {
+		t.Fatalf("expected 2 secrets to be re-encrypted; got %d", reencrypted)
+	}
+
+	store.Close()
+
+	// Once re-encrypted the previous key is no longer needed.
+	store, err = New(path, newKey)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer store.Close()
+
+	want := map[string]string{"envelope": "firstsecret", "legacy": "secondsecret"}
+	for key, value := range want {
+		secret, err := store.GetSecret(key)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		if secret != value {
+			t.Fatalf("secret %q returned %q after re-encryption; want %q", key, secret, value)
+		}
+	}
+
+	reencrypted, err = store.ReencryptSecrets()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if reencrypted != 0 {
+		t.Fatalf("expected no secrets to be re-encrypted; got %d", reencrypted)
+	}
+}
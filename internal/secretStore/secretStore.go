@@ -1,6 +1,9 @@
 package secretStore
 
-import "errors"
+import (
+	"errors"
+	"io"
+)
 
 type EngineType string
 
@@ -24,4 +27,16 @@ type Engine interface {
 	GetSecret(key string) (string, error)
 	PutSecret(key string, content string, force bool) error
 	DeleteSecret(key string) error
+
+	// ListSecretKeys returns all keys that start with the given prefix.
+	ListSecretKeys(prefix string) ([]string, error)
+
+	// ReencryptSecrets re-encrypts every secret that isn't already encrypted with the store's current encryption key
+	// so that previous keys can be retired. Secrets remain readable while it runs. Returns the number of secrets that
+	// were re-encrypted.
+	ReencryptSecrets() (int64, error)
+
+	// Backup writes a consistent copy of the entire database to the writer. It is safe to call while the database
+	// is in use.
+	Backup(w io.Writer) error
 }
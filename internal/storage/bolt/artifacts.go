@@ -0,0 +1,78 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/asdine/storm/v3/q"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllArtifacts(r storage.GetAllArtifactsRequest) ([]*models.Artifact, error) {
+	bucket := db.From(r.NamespaceID, r.PipelineID)
+
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	matchers := []q.Matcher{}
+	if r.RunID != 0 {
+		matchers = append(matchers, q.Eq("RunID", r.RunID))
+	}
+	if r.UnexpiredOnly {
+		matchers = append(matchers, q.Eq("Expired", false))
+	}
+
+	artifacts := []*models.Artifact{}
+	err := bucket.Select(matchers...).OrderBy("ID").Reverse().Limit(r.Limit).Skip(r.Offset).Find(&artifacts)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return []*models.Artifact{}, nil
+		}
+
+		return nil, err
+	}
+
+	return artifacts, nil
+}
+
+func (db *DB) GetArtifact(r storage.GetArtifactRequest) (*models.Artifact, error) {
+	bucket := db.From(r.NamespaceID, r.PipelineID)
+
+	var artifact models.Artifact
+	err := bucket.Select(q.Eq("RunID", r.RunID), q.Eq("TaskRunID", r.TaskRunID), q.Eq("Name", r.Name)).First(&artifact)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return nil, storage.ErrEntityNotFound
+		}
+
+		return nil, err
+	}
+
+	return &artifact, nil
+}
+
+func (db *DB) AddArtifact(r storage.AddArtifactRequest) error {
+	bucket := db.From(r.Artifact.NamespaceID, r.Artifact.PipelineID)
+	return bucket.Save(r.Artifact)
+}
+
+func (db *DB) UpdateArtifact(r storage.UpdateArtifactRequest) error {
+	bucket := db.From(r.Artifact.NamespaceID, r.Artifact.PipelineID)
+
+	err := bucket.Update(r.Artifact)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
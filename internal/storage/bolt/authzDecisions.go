@@ -0,0 +1,69 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/asdine/storm/v3/q"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllAuthzDecisions(r storage.GetAllAuthzDecisionsRequest) ([]*models.AuthzDecision, error) {
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	matchers := []q.Matcher{}
+	if r.Caller != "" {
+		matchers = append(matchers, q.Eq("Caller", r.Caller))
+	}
+	if r.DeniedOnly {
+		matchers = append(matchers, q.Eq("Allowed", false))
+	}
+	if r.Since != 0 {
+		matchers = append(matchers, q.Gte("Timestamp", r.Since))
+	}
+
+	decisions := []*models.AuthzDecision{}
+	err := db.Select(matchers...).OrderBy("ID").Reverse().Limit(r.Limit).Skip(r.Offset).Find(&decisions)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return []*models.AuthzDecision{}, nil
+		}
+
+		return nil, err
+	}
+
+	return decisions, nil
+}
+
+func (db *DB) AddAuthzDecision(r storage.AddAuthzDecisionRequest) error {
+	return db.Save(r.AuthzDecision)
+}
+
+func (db *DB) DeleteAuthzDecisions(r storage.DeleteAuthzDecisionsRequest) (int, error) {
+	tx, err := db.Begin(true)
+	if err != nil {
+		return 0, err
+	}
+	defer tx.Rollback() // nolint: errcheck
+
+	query := tx.Select(q.Lt("Timestamp", r.Before))
+
+	count, err := query.Count(&models.AuthzDecision{})
+	if err != nil {
+		return 0, err
+	}
+
+	if count == 0 {
+		return 0, nil
+	}
+
+	err = query.Delete(&models.AuthzDecision{})
+	if err != nil {
+		return 0, err
+	}
+
+	return count, tx.Commit()
+}
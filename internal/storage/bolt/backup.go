@@ -0,0 +1,16 @@
+package bolt
+
+import (
+	"io"
+
+	bolt "go.etcd.io/bbolt"
+)
+
+// Backup writes a consistent copy of the entire database to the writer using a read transaction, so writes can
+// continue while the backup is taken.
+func (db *DB) Backup(w io.Writer) error {
+	return db.Bolt.View(func(tx *bolt.Tx) error {
+		_, err := tx.WriteTo(w)
+		return err
+	})
+}
@@ -32,6 +32,12 @@ func New(path string, maxResultsLimit int) (DB, error) {
 		return DB{}, err
 	}
 
+	err = migrate(store.Bolt)
+	if err != nil {
+		_ = store.Close()
+		return DB{}, err
+	}
+
 	return DB{
 		maxResultsLimit,
 		store,
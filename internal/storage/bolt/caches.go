@@ -0,0 +1,84 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllCaches(r storage.GetAllCachesRequest) ([]*models.Cache, error) {
+	bucket := db.From(r.NamespaceID, r.PipelineID)
+
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	caches := []*models.Cache{}
+	err := bucket.All(&caches, storm.Limit(r.Limit), storm.Skip(r.Offset), storm.Reverse())
+	if err != nil && !errors.Is(err, storm.ErrNotFound) {
+		return nil, err
+	}
+
+	return caches, nil
+}
+
+func (db *DB) GetCache(r storage.GetCacheRequest) (*models.Cache, error) {
+	bucket := db.From(r.NamespaceID, r.PipelineID)
+
+	var cache models.Cache
+	err := bucket.One("Key", r.Key, &cache)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return nil, storage.ErrEntityNotFound
+		}
+
+		return nil, err
+	}
+
+	return &cache, nil
+}
+
+func (db *DB) AddCache(r storage.AddCacheRequest) error {
+	bucket := db.From(r.Cache.NamespaceID, r.Cache.PipelineID)
+	return bucket.Save(r.Cache)
+}
+
+func (db *DB) UpdateCache(r storage.UpdateCacheRequest) error {
+	bucket := db.From(r.Cache.NamespaceID, r.Cache.PipelineID)
+
+	err := bucket.Update(r.Cache)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
+
+func (db *DB) DeleteCache(r storage.DeleteCacheRequest) error {
+	bucket := db.From(r.NamespaceID, r.PipelineID)
+
+	err := bucket.DeleteStruct(&models.Cache{ID: r.ID})
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
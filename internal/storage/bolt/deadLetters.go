@@ -0,0 +1,75 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllDeadLetters(r storage.GetAllDeadLettersRequest) ([]*models.DeadLetter, error) {
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	deadLetters := []*models.DeadLetter{}
+	err := db.All(&deadLetters, storm.Limit(r.Limit), storm.Skip(r.Offset), storm.Reverse())
+	if err != nil {
+		return nil, err
+	}
+
+	return deadLetters, nil
+}
+
+func (db *DB) GetDeadLetter(r storage.GetDeadLetterRequest) (*models.DeadLetter, error) {
+	var deadLetter models.DeadLetter
+	err := db.One("ID", r.ID, &deadLetter)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return nil, storage.ErrEntityNotFound
+		}
+
+		return nil, err
+	}
+
+	return &deadLetter, nil
+}
+
+func (db *DB) AddDeadLetter(r storage.AddDeadLetterRequest) error {
+	return db.Save(r.DeadLetter)
+}
+
+func (db *DB) UpdateDeadLetter(r storage.UpdateDeadLetterRequest) error {
+	err := db.Update(r.DeadLetter)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
+
+func (db *DB) DeleteDeadLetter(r storage.DeleteDeadLetterRequest) error {
+	err := db.DeleteStruct(&models.DeadLetter{ID: r.ID})
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
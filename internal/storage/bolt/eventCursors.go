@@ -0,0 +1,64 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllEventCursors(r storage.GetAllEventCursorsRequest) ([]*models.EventCursor, error) {
+	cursors := []*models.EventCursor{}
+
+	err := db.All(&cursors)
+	if err != nil {
+		return nil, err
+	}
+
+	return cursors, nil
+}
+
+func (db *DB) GetEventCursor(r storage.GetEventCursorRequest) (*models.EventCursor, error) {
+	var cursor models.EventCursor
+	err := db.One("ID", r.ID, &cursor)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return nil, storage.ErrEntityNotFound
+		}
+
+		return nil, err
+	}
+
+	return &cursor, nil
+}
+
+func (db *DB) UpdateEventCursor(r storage.UpdateEventCursorRequest) error {
+	err := db.Save(r.EventCursor)
+	if err != nil {
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
+
+func (db *DB) DeleteEventCursor(r storage.DeleteEventCursorRequest) error {
+	err := db.DeleteStruct(&models.EventCursor{ID: r.ID})
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
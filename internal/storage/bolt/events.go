@@ -80,6 +80,13 @@ func (db *DB) getAllEvents(offset, limit int) ([]models.Event, error) {
 					return err
 				}
 				events = append(events, storedEvent)
+			case models.RolledBackPipelineEvent:
+				storedEvent := &models.EventRolledBackPipeline{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
 			case models.StartedRunEvent:
 				storedEvent := &models.EventStartedRun{}
 				err := json.Unmarshal(value, storedEvent)
@@ -136,6 +143,27 @@ func (db *DB) getAllEvents(offset, limit int) ([]models.Event, error) {
 					return err
 				}
 				events = append(events, storedEvent)
+			case models.RevokedUserTokensEvent:
+				storedEvent := &models.EventRevokedUserTokens{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
+			case models.ExpiredPipelineObjectEvent:
+				storedEvent := &models.EventExpiredPipelineObject{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
+			case models.ExtensionEvent:
+				storedEvent := &models.EventExtension{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
 			}
 		}
 		return nil
@@ -215,6 +243,13 @@ func (db *DB) getAllEventsReverse(offset, limit int) ([]models.Event, error) {
 					return err
 				}
 				events = append(events, storedEvent)
+			case models.RolledBackPipelineEvent:
+				storedEvent := &models.EventRolledBackPipeline{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
 			case models.StartedRunEvent:
 				storedEvent := &models.EventStartedRun{}
 				err := json.Unmarshal(value, storedEvent)
@@ -271,6 +306,27 @@ func (db *DB) getAllEventsReverse(offset, limit int) ([]models.Event, error) {
 					return err
 				}
 				events = append(events, storedEvent)
+			case models.RevokedUserTokensEvent:
+				storedEvent := &models.EventRevokedUserTokens{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
+			case models.ExpiredPipelineObjectEvent:
+				storedEvent := &models.EventExpiredPipelineObject{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
+			case models.ExtensionEvent:
+				storedEvent := &models.EventExtension{}
+				err := json.Unmarshal(value, storedEvent)
+				if err != nil {
+					return err
+				}
+				events = append(events, storedEvent)
 			}
 		}
 		return nil
@@ -330,6 +386,13 @@ func (db *DB) GetEvent(r storage.GetEventRequest) (models.Event, error) {
 				return err
 			}
 			event = storedEvent
+		case models.RolledBackPipelineEvent:
+			storedEvent := &models.EventRolledBackPipeline{}
+			err := json.Unmarshal(eventRaw, storedEvent)
+			if err != nil {
+				return err
+			}
+			event = storedEvent
 		case models.StartedRunEvent:
 			storedEvent := &models.EventStartedRun{}
 			err := json.Unmarshal(eventRaw, storedEvent)
@@ -386,6 +449,27 @@ func (db *DB) GetEvent(r storage.GetEventRequest) (models.Event, error) {
 				return err
 			}
 			event = storedEvent
+		case models.RevokedUserTokensEvent:
+			storedEvent := &models.EventRevokedUserTokens{}
+			err := json.Unmarshal(eventRaw, storedEvent)
+			if err != nil {
+				return err
+			}
+			event = storedEvent
+		case models.ExpiredPipelineObjectEvent:
+			storedEvent := &models.EventExpiredPipelineObject{}
+			err := json.Unmarshal(eventRaw, storedEvent)
+			if err != nil {
+				return err
+			}
+			event = storedEvent
+		case models.ExtensionEvent:
+			storedEvent := &models.EventExtension{}
+			err := json.Unmarshal(eventRaw, storedEvent)
+			if err != nil {
+				return err
+			}
+			event = storedEvent
 		}
 
 		return nil
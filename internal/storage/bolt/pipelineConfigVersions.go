@@ -0,0 +1,57 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+// configVersionsBucket is the name of the bucket within a pipeline's bucket that holds its config versions.
+const configVersionsBucket = "config_versions"
+
+// GetAllPipelineConfigVersions returns the config versions of a pipeline, newest first.
+func (db *DB) GetAllPipelineConfigVersions(r storage.GetAllPipelineConfigVersionsRequest) ([]*models.PipelineConfigVersion, error) {
+	bucket := db.From(r.NamespaceID, r.PipelineID, configVersionsBucket)
+
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	versions := []*models.PipelineConfigVersion{}
+	err := bucket.AllByIndex("Version", &versions, storm.Limit(r.Limit), storm.Skip(r.Offset), storm.Reverse())
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return []*models.PipelineConfigVersion{}, nil
+		}
+
+		return nil, err
+	}
+
+	return versions, nil
+}
+
+// GetPipelineConfigVersion returns a single config version of a pipeline.
+func (db *DB) GetPipelineConfigVersion(r storage.GetPipelineConfigVersionRequest) (*models.PipelineConfigVersion, error) {
+	bucket := db.From(r.NamespaceID, r.PipelineID, configVersionsBucket)
+
+	var version models.PipelineConfigVersion
+	err := bucket.One("Version", r.Version, &version)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return nil, storage.ErrEntityNotFound
+		}
+
+		return nil, err
+	}
+
+	return &version, nil
+}
+
+// AddPipelineConfigVersion stores a new config version; the version number is assigned automatically.
+func (db *DB) AddPipelineConfigVersion(r storage.AddPipelineConfigVersionRequest) error {
+	bucket := db.From(r.PipelineConfigVersion.Namespace, r.PipelineConfigVersion.Pipeline, configVersionsBucket)
+
+	return bucket.Save(r.PipelineConfigVersion)
+}
@@ -0,0 +1,67 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllRegistryCredentials(r storage.GetAllRegistryCredentialsRequest) ([]*models.RegistryCredential, error) {
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	credentials := []*models.RegistryCredential{}
+
+	var err error
+	if r.NamespaceID == "" {
+		err = db.All(&credentials, storm.Limit(r.Limit), storm.Skip(r.Offset))
+	} else {
+		err = db.Find("NamespaceID", r.NamespaceID, &credentials, storm.Limit(r.Limit), storm.Skip(r.Offset))
+	}
+	if err != nil && !errors.Is(err, storm.ErrNotFound) {
+		return nil, err
+	}
+
+	return credentials, nil
+}
+
+func (db *DB) AddRegistryCredential(r storage.AddRegistryCredentialRequest) error {
+	return db.Save(r.RegistryCredential)
+}
+
+func (db *DB) UpdateRegistryCredential(r storage.UpdateRegistryCredentialRequest) error {
+	err := db.Update(r.RegistryCredential)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
+
+func (db *DB) DeleteRegistryCredential(r storage.DeleteRegistryCredentialRequest) error {
+	err := db.DeleteStruct(&models.RegistryCredential{ID: r.ID})
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
@@ -0,0 +1,128 @@
+package bolt
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"strconv"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/storage"
+	bolt "go.etcd.io/bbolt"
+)
+
+var (
+	metaBucket       = []byte("meta")
+	schemaVersionKey = []byte("schema_version")
+)
+
+// migration moves the database from one schema version to the next.
+type migration struct {
+	description string
+	migrate     func(tx *bolt.Tx) error
+}
+
+// migrations holds every migration in order; migrations[0] moves the database from schema version 1 to 2 and so on.
+// There must always be exactly storage.SchemaVersion-1 of them.
+var migrations = []migration{}
+
+// readSchemaVersion returns the schema version recorded in the database. Databases created before schema versions
+// were recorded(and brand new ones) don't have one; both have the layout of the first version.
+func readSchemaVersion(tx *bolt.Tx) (int, error) {
+	bucket := tx.Bucket(metaBucket)
+	if bucket == nil {
+		return 1, nil
+	}
+
+	value := bucket.Get(schemaVersionKey)
+	if value == nil {
+		return 1, nil
+	}
+
+	version, err := strconv.Atoi(string(value))
+	if err != nil || version < 1 {
+		return 0, fmt.Errorf("database has an invalid schema version %q", value)
+	}
+
+	return version, nil
+}
+
+func writeSchemaVersion(tx *bolt.Tx, version int) error {
+	bucket, err := tx.CreateBucketIfNotExists(metaBucket)
+	if err != nil {
+		return err
+	}
+
+	return bucket.Put(schemaVersionKey, []byte(strconv.Itoa(version)))
+}
+
+// NewSchemaStatus returns the status of a database with the given schema version.
+func NewSchemaStatus(version int) storage.SchemaStatus {
+	status := storage.SchemaStatus{
+		Version:           version,
+		PendingMigrations: []string{},
+	}
+
+	for v := version; v < storage.SchemaVersion; v++ {
+		status.PendingMigrations = append(status.PendingMigrations,
+			fmt.Sprintf("%d -> %d: %s", v, v+1, migrations[v-1].description))
+	}
+
+	return status
+}
+
+// migrate brings the database up to the current schema version. It refuses to touch databases with a newer schema
+// version than this version of Gofer supports.
+func migrate(db *bolt.DB) error {
+	return db.Update(func(tx *bolt.Tx) error {
+		version, err := readSchemaVersion(tx)
+		if err != nil {
+			return err
+		}
+
+		err = NewSchemaStatus(version).Compatible()
+		if err != nil {
+			return err
+		}
+
+		for v := version; v < storage.SchemaVersion; v++ {
+			err := migrations[v-1].migrate(tx)
+			if err != nil {
+				return fmt.Errorf("could not migrate database from schema version %d to %d(%s): %w",
+					v, v+1, migrations[v-1].description, err)
+			}
+		}
+
+		return writeSchemaVersion(tx, storage.SchemaVersion)
+	})
+}
+
+// InspectSchema reads the schema version of the database at the given path without changing it. The database can't
+// be read while another process has it open, so this fails while a Gofer server is using it.
+func InspectSchema(path string) (storage.SchemaStatus, error) {
+	_, err := os.Stat(path)
+	if err != nil {
+		// A database that doesn't exist yet is created with the current schema.
+		if errors.Is(err, os.ErrNotExist) {
+			return NewSchemaStatus(storage.SchemaVersion), nil
+		}
+		return storage.SchemaStatus{}, err
+	}
+
+	db, err := bolt.Open(path, 0600, &bolt.Options{ReadOnly: true, Timeout: 1 * time.Second})
+	if err != nil {
+		return storage.SchemaStatus{}, err
+	}
+	defer db.Close()
+
+	version := 0
+	err = db.View(func(tx *bolt.Tx) error {
+		version, err = readSchemaVersion(tx)
+		return err
+	})
+	if err != nil {
+		return storage.SchemaStatus{}, err
+	}
+
+	return NewSchemaStatus(version), nil
+}
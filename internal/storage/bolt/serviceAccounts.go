@@ -0,0 +1,84 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllServiceAccounts(r storage.GetAllServiceAccountsRequest) ([]*models.ServiceAccount, error) {
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	accounts := []*models.ServiceAccount{}
+	err := db.All(&accounts, storm.Limit(r.Limit), storm.Skip(r.Offset))
+	if err != nil {
+		return nil, err
+	}
+
+	return accounts, nil
+}
+
+// GetServiceAccount returns a single service account by id.
+func (db *DB) GetServiceAccount(r storage.GetServiceAccountRequest) (*models.ServiceAccount, error) {
+	var account models.ServiceAccount
+	err := db.One("ID", r.ID, &account)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return nil, storage.ErrEntityNotFound
+		}
+
+		return nil, err
+	}
+
+	return &account, nil
+}
+
+func (db *DB) AddServiceAccount(r storage.AddServiceAccountRequest) error {
+	tx, err := db.Begin(true)
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback() // nolint: errcheck
+
+	var account models.ServiceAccount
+	err = tx.One("ID", r.ServiceAccount.ID, &account)
+	if errors.Is(err, storm.ErrNotFound) {
+		err = tx.Save(r.ServiceAccount)
+		if err != nil {
+			if errors.Is(err, storm.ErrNoID) {
+				return storage.ErrPreconditionFailure
+			}
+
+			return err
+		}
+
+		return tx.Commit()
+	}
+
+	if err == nil {
+		return storage.ErrEntityExists
+	}
+
+	return err
+}
+
+func (db *DB) DeleteServiceAccount(r storage.DeleteServiceAccountRequest) error {
+	err := db.DeleteStruct(&models.ServiceAccount{ID: r.ID})
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
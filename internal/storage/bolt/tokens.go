@@ -77,6 +77,33 @@ func (db *DB) AddToken(r storage.AddTokenRequest) error {
 	return err
 }
 
+// UpdateToken replaces a stored token. The whole token is saved since storm's Update skips zero values, which would
+// prevent namespaces from being removed.
+func (db *DB) UpdateToken(r storage.UpdateTokenRequest) error {
+	tx, err := db.Begin(true)
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback() // nolint: errcheck
+
+	var token models.Token
+	err = tx.One("Hash", r.Token.Hash, &token)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		return err
+	}
+
+	err = tx.Save(r.Token)
+	if err != nil {
+		return err
+	}
+
+	return tx.Commit()
+}
+
 func (db *DB) DeleteToken(r storage.DeleteTokenRequest) error {
 	err := db.DeleteStruct(&models.Token{Hash: r.Hash})
 	if err != nil {
@@ -0,0 +1,146 @@
+package bolt
+
+import (
+	"errors"
+
+	"github.com/asdine/storm/v3"
+	"github.com/asdine/storm/v3/q"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
+)
+
+func (db *DB) GetAllWebhooks(r storage.GetAllWebhooksRequest) ([]*models.Webhook, error) {
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	webhooks := []*models.Webhook{}
+
+	var err error
+	if r.NamespaceID == "" {
+		err = db.All(&webhooks, storm.Limit(r.Limit), storm.Skip(r.Offset))
+	} else {
+		err = db.Find("NamespaceID", r.NamespaceID, &webhooks, storm.Limit(r.Limit), storm.Skip(r.Offset))
+	}
+	if err != nil && !errors.Is(err, storm.ErrNotFound) {
+		return nil, err
+	}
+
+	return webhooks, nil
+}
+
+func (db *DB) GetWebhook(r storage.GetWebhookRequest) (*models.Webhook, error) {
+	var webhook models.Webhook
+	err := db.One("ID", r.ID, &webhook)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return nil, storage.ErrEntityNotFound
+		}
+
+		return nil, err
+	}
+
+	return &webhook, nil
+}
+
+func (db *DB) AddWebhook(r storage.AddWebhookRequest) error {
+	return db.Save(r.Webhook)
+}
+
+// DeleteWebhook removes a webhook along with its delivery history.
+func (db *DB) DeleteWebhook(r storage.DeleteWebhookRequest) error {
+	tx, err := db.Begin(true)
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback() // nolint: errcheck
+
+	err = tx.DeleteStruct(&models.Webhook{ID: r.ID})
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	err = tx.Select(q.Eq("WebhookID", r.ID)).Delete(&models.WebhookDelivery{})
+	if err != nil && !errors.Is(err, storm.ErrNotFound) {
+		return err
+	}
+
+	return tx.Commit()
+}
+
+func (db *DB) GetAllWebhookDeliveries(r storage.GetAllWebhookDeliveriesRequest) ([]*models.WebhookDelivery, error) {
+	if r.Limit == 0 || r.Limit > db.maxResultsLimit {
+		r.Limit = db.maxResultsLimit
+	}
+
+	deliveries := []*models.WebhookDelivery{}
+
+	var err error
+	if r.WebhookID == 0 {
+		err = db.All(&deliveries, storm.Limit(r.Limit), storm.Skip(r.Offset), storm.Reverse())
+	} else {
+		err = db.Find("WebhookID", r.WebhookID, &deliveries, storm.Limit(r.Limit), storm.Skip(r.Offset),
+			storm.Reverse())
+	}
+	if err != nil && !errors.Is(err, storm.ErrNotFound) {
+		return nil, err
+	}
+
+	return deliveries, nil
+}
+
+func (db *DB) AddWebhookDelivery(r storage.AddWebhookDeliveryRequest) error {
+	return db.Save(r.WebhookDelivery)
+}
+
+func (db *DB) UpdateWebhookDelivery(r storage.UpdateWebhookDeliveryRequest) error {
+	err := db.Update(r.WebhookDelivery)
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return storage.ErrEntityNotFound
+		}
+
+		if errors.Is(err, storm.ErrNoID) {
+			return storage.ErrPreconditionFailure
+		}
+
+		return err
+	}
+
+	return nil
+}
+
+func (db *DB) DeleteWebhookDeliveries(r storage.DeleteWebhookDeliveriesRequest) (int, error) {
+	tx, err := db.Begin(true)
+	if err != nil {
+		return 0, err
+	}
+	defer tx.Rollback() // nolint: errcheck
+
+	old := []*models.WebhookDelivery{}
+	err = tx.Find("WebhookID", r.WebhookID, &old, storm.Skip(r.Keep), storm.Reverse())
+	if err != nil {
+		if errors.Is(err, storm.ErrNotFound) {
+			return 0, nil
+		}
+
+		return 0, err
+	}
+
+	for _, delivery := range old {
+		err = tx.DeleteStruct(delivery)
+		if err != nil {
+			return 0, err
+		}
+	}
+
+	return len(old), tx.Commit()
+}
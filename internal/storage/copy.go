@@ -0,0 +1,699 @@
+package storage
+
+import (
+	"fmt"
+	"sort"
+
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// The kinds of records copied between storage engines, in the order they are copied.
+const (
+	KindNamespaces             = "namespaces"
+	KindPipelines              = "pipelines"
+	KindPipelineConfigVersions = "pipeline config versions"
+	KindRuns                   = "runs"
+	KindTaskRuns               = "task runs"
+	KindArtifacts              = "artifacts"
+	KindCaches                 = "caches"
+	KindEvents                 = "events"
+	KindEventCursors           = "event cursors"
+	KindDeadLetters            = "dead letters"
+	KindWebhooks               = "webhooks"
+	KindWebhookDeliveries      = "webhook deliveries"
+	KindRegistryCredentials    = "registry credentials"
+	KindTokens                 = "tokens"
+	KindServiceAccounts        = "service accounts"
+	KindAuthorizationDecisions = "authorization decisions"
+	KindTriggers               = "triggers"
+	KindNotifiers              = "notifiers"
+)
+
+// CopyKinds lists every kind of record Copy copies, in the order they are copied.
+var CopyKinds = []string{
+	KindNamespaces, KindPipelines, KindPipelineConfigVersions, KindRuns, KindTaskRuns, KindArtifacts, KindCaches,
+	KindEvents, KindEventCursors, KindDeadLetters, KindWebhooks, KindWebhookDeliveries, KindRegistryCredentials,
+	KindTokens, KindServiceAccounts, KindAuthorizationDecisions, KindTriggers, KindNotifiers,
+}
+
+// RecordCounts is the number of records of each kind, keyed by kind.
+type RecordCounts map[string]int
+
+// Empty returns whether there are no records of any kind.
+func (c RecordCounts) Empty() bool {
+	for _, count := range c {
+		if count != 0 {
+			return false
+		}
+	}
+
+	return true
+}
+
+// Diff returns a description of every kind whose count differs between the two; empty if they match.
+func (c RecordCounts) Diff(other RecordCounts) []string {
+	diff := []string{}
+
+	for _, kind := range CopyKinds {
+		if c[kind] != other[kind] {
+			diff = append(diff, fmt.Sprintf("%s: %d != %d", kind, c[kind], other[kind]))
+		}
+	}
+
+	return diff
+}
+
+// CopyProgress is called with the number of records of a kind copied so far.
+type CopyProgress func(kind string, copied int)
+
+// Copy copies every record from the source engine into the destination engine, which must be empty. Neither engine
+// should be in use while the copy is in progress.
+//
+// Records keep their IDs with the exception of events, which are given new sequential IDs by the destination. Event
+// cursors are moved so that they point at the same events they did in the source.
+func Copy(source, destination Engine, progress CopyProgress) (RecordCounts, error) {
+	if progress == nil {
+		progress = func(string, int) {}
+	}
+
+	existing, err := Count(destination)
+	if err != nil {
+		return nil, fmt.Errorf("could not count records in destination: %w", err)
+	}
+
+	if !existing.Empty() {
+		return nil, fmt.Errorf("%w; destination already has records", ErrPreconditionFailure)
+	}
+
+	c := &copier{
+		source:      source,
+		destination: destination,
+		progress:    progress,
+		counts:      RecordCounts{},
+	}
+
+	steps := []struct {
+		kind string
+		copy func() error
+	}{
+		{KindNamespaces, c.copyNamespaces},
+		{KindEvents, c.copyEvents},
+		{KindEventCursors, c.copyEventCursors},
+		{KindDeadLetters, c.copyDeadLetters},
+		{KindWebhooks, c.copyWebhooks},
+		{KindWebhookDeliveries, c.copyWebhookDeliveries},
+		{KindRegistryCredentials, c.copyRegistryCredentials},
+		{KindTokens, c.copyTokens},
+		{KindServiceAccounts, c.copyServiceAccounts},
+		{KindAuthorizationDecisions, c.copyAuthzDecisions},
+		{KindTriggers, c.copyTriggers},
+		{KindNotifiers, c.copyNotifiers},
+	}
+
+	for _, step := range steps {
+		err := step.copy()
+		if err != nil {
+			return c.counts, fmt.Errorf("could not copy %s: %w", step.kind, err)
+		}
+	}
+
+	return c.counts, nil
+}
+
+// Count returns the number of records of each kind stored within the engine.
+func Count(engine Engine) (RecordCounts, error) {
+	counts := RecordCounts{}
+
+	namespaces, err := allNamespaces(engine)
+	if err != nil {
+		return nil, err
+	}
+	counts[KindNamespaces] = len(namespaces)
+
+	for _, namespace := range namespaces {
+		pipelines, err := allPipelines(engine, namespace.ID)
+		if err != nil {
+			return nil, err
+		}
+		counts[KindPipelines] += len(pipelines)
+
+		for _, pipeline := range pipelines {
+			versions, err := allPipelineConfigVersions(engine, namespace.ID, pipeline.ID)
+			if err != nil {
+				return nil, err
+			}
+			counts[KindPipelineConfigVersions] += len(versions)
+
+			err = eachPage(func(offset int) ([]*models.Run, error) {
+				return engine.GetAllRuns(GetAllRunsRequest{NamespaceID: namespace.ID, PipelineID: pipeline.ID, Offset: offset})
+			}, func(run *models.Run) error {
+				counts[KindRuns]++
+
+				taskRuns, err := engine.GetAllTaskRuns(GetAllTaskRunsRequest{
+					NamespaceID: namespace.ID,
+					PipelineID:  pipeline.ID,
+					RunID:       run.ID,
+				})
+				if err != nil {
+					return err
+				}
+				counts[KindTaskRuns] += len(taskRuns)
+
+				return nil
+			})
+			if err != nil {
+				return nil, err
+			}
+
+			err = countPages(counts, KindArtifacts, func(offset int) ([]*models.Artifact, error) {
+				return engine.GetAllArtifacts(GetAllArtifactsRequest{
+					NamespaceID: namespace.ID,
+					PipelineID:  pipeline.ID,
+					Offset:      offset,
+				})
+			})
+			if err != nil {
+				return nil, err
+			}
+
+			err = countPages(counts, KindCaches, func(offset int) ([]*models.Cache, error) {
+				return engine.GetAllCaches(GetAllCachesRequest{
+					NamespaceID: namespace.ID,
+					PipelineID:  pipeline.ID,
+					Offset:      offset,
+				})
+			})
+			if err != nil {
+				return nil, err
+			}
+		}
+	}
+
+	err = eachEvent(engine, func(models.Event) error {
+		counts[KindEvents]++
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	cursors, err := engine.GetAllEventCursors(GetAllEventCursorsRequest{})
+	if err != nil {
+		return nil, err
+	}
+	counts[KindEventCursors] = len(cursors)
+
+	err = countPages(counts, KindDeadLetters, func(offset int) ([]*models.DeadLetter, error) {
+		return engine.GetAllDeadLetters(GetAllDeadLettersRequest{Offset: offset})
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	err = countPages(counts, KindWebhooks, func(offset int) ([]*models.Webhook, error) {
+		return engine.GetAllWebhooks(GetAllWebhooksRequest{Offset: offset})
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	err = countPages(counts, KindWebhookDeliveries, func(offset int) ([]*models.WebhookDelivery, error) {
+		return engine.GetAllWebhookDeliveries(GetAllWebhookDeliveriesRequest{Offset: offset})
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	err = countPages(counts, KindRegistryCredentials, func(offset int) ([]*models.RegistryCredential, error) {
+		return engine.GetAllRegistryCredentials(GetAllRegistryCredentialsRequest{Offset: offset})
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	err = countPages(counts, KindTokens, func(offset int) ([]*models.Token, error) {
+		return engine.GetAllTokens(GetAllTokensRequest{Offset: offset})
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	err = countPages(counts, KindServiceAccounts, func(offset int) ([]*models.ServiceAccount, error) {
+		return engine.GetAllServiceAccounts(GetAllServiceAccountsRequest{Offset: offset})
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	err = countPages(counts, KindAuthorizationDecisions, func(offset int) ([]*models.AuthzDecision, error) {
+		return engine.GetAllAuthzDecisions(GetAllAuthzDecisionsRequest{Offset: offset})
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	triggers, err := engine.GetAllTriggers(GetAllTriggersRequest{})
+	if err != nil {
+		return nil, err
+	}
+	counts[KindTriggers] = len(triggers)
+
+	notifiers, err := engine.GetAllNotifiers(GetAllNotifiersRequest{})
+	if err != nil {
+		return nil, err
+	}
+	counts[KindNotifiers] = len(notifiers)
+
+	return counts, nil
+}
+
+type copier struct {
+	source      Engine
+	destination Engine
+	progress    CopyProgress
+	counts      RecordCounts
+
+	// eventIDs maps the IDs of events in the source to the IDs they were given in the destination.
+	eventIDs map[int64]int64
+}
+
+func (c *copier) copied(kind string) {
+	c.counts[kind]++
+	c.progress(kind, c.counts[kind])
+}
+
+// copyNamespaces copies each namespace along with its pipelines and everything stored for them.
+func (c *copier) copyNamespaces() error {
+	namespaces, err := allNamespaces(c.source)
+	if err != nil {
+		return err
+	}
+
+	for _, namespace := range namespaces {
+		err := c.destination.AddNamespace(AddNamespaceRequest{Namespace: namespace})
+		if err != nil {
+			return fmt.Errorf("namespace %q: %w", namespace.ID, err)
+		}
+		c.copied(KindNamespaces)
+
+		pipelines, err := allPipelines(c.source, namespace.ID)
+		if err != nil {
+			return err
+		}
+
+		for _, pipeline := range pipelines {
+			err := c.copyPipeline(pipeline)
+			if err != nil {
+				return fmt.Errorf("pipeline %q in namespace %q: %w", pipeline.ID, namespace.ID, err)
+			}
+		}
+	}
+
+	return nil
+}
+
+func (c *copier) copyPipeline(pipeline *models.Pipeline) error {
+	err := c.destination.AddPipeline(AddPipelineRequest{Pipeline: pipeline})
+	if err != nil {
+		return err
+	}
+	c.copied(KindPipelines)
+
+	versions, err := allPipelineConfigVersions(c.source, pipeline.Namespace, pipeline.ID)
+	if err != nil {
+		return err
+	}
+
+	for _, version := range versions {
+		err := c.destination.AddPipelineConfigVersion(AddPipelineConfigVersionRequest{PipelineConfigVersion: version})
+		if err != nil {
+			return fmt.Errorf("config version %d: %w", version.Version, err)
+		}
+		c.copied(KindPipelineConfigVersions)
+	}
+
+	runs := []*models.Run{}
+	err = eachPage(func(offset int) ([]*models.Run, error) {
+		return c.source.GetAllRuns(GetAllRunsRequest{NamespaceID: pipeline.Namespace, PipelineID: pipeline.ID, Offset: offset})
+	}, func(run *models.Run) error {
+		runs = append(runs, run)
+		return nil
+	})
+	if err != nil {
+		return err
+	}
+
+	// Runs are listed newest first but adding a run marks it as the pipeline's latest.
+	sort.Slice(runs, func(i, j int) bool { return runs[i].ID < runs[j].ID })
+
+	for _, run := range runs {
+		err := c.copyRun(run)
+		if err != nil {
+			return fmt.Errorf("run %d: %w", run.ID, err)
+		}
+	}
+
+	err = eachPage(func(offset int) ([]*models.Artifact, error) {
+		return c.source.GetAllArtifacts(GetAllArtifactsRequest{
+			NamespaceID: pipeline.Namespace,
+			PipelineID:  pipeline.ID,
+			Offset:      offset,
+		})
+	}, func(artifact *models.Artifact) error {
+		err := c.destination.AddArtifact(AddArtifactRequest{Artifact: artifact})
+		if err != nil {
+			return fmt.Errorf("artifact %d: %w", artifact.ID, err)
+		}
+		c.copied(KindArtifacts)
+		return nil
+	})
+	if err != nil {
+		return err
+	}
+
+	err = eachPage(func(offset int) ([]*models.Cache, error) {
+		return c.source.GetAllCaches(GetAllCachesRequest{
+			NamespaceID: pipeline.Namespace,
+			PipelineID:  pipeline.ID,
+			Offset:      offset,
+		})
+	}, func(cache *models.Cache) error {
+		err := c.destination.AddCache(AddCacheRequest{Cache: cache})
+		if err != nil {
+			return fmt.Errorf("cache %d: %w", cache.ID, err)
+		}
+		c.copied(KindCaches)
+		return nil
+	})
+	if err != nil {
+		return err
+	}
+
+	// Adding runs updates the pipeline's record of its latest run; put back the pipeline exactly as it was.
+	return c.destination.UpdatePipeline(UpdatePipelineRequest{Pipeline: pipeline})
+}
+
+func (c *copier) copyRun(run *models.Run) error {
+	err := c.destination.AddRun(AddRunRequest{Run: run})
+	if err != nil {
+		return err
+	}
+	c.copied(KindRuns)
+
+	taskRuns, err := c.source.GetAllTaskRuns(GetAllTaskRunsRequest{
+		NamespaceID: run.NamespaceID,
+		PipelineID:  run.PipelineID,
+		RunID:       run.ID,
+	})
+	if err != nil {
+		return err
+	}
+
+	for _, taskRun := range taskRuns {
+		err := c.destination.AddTaskRun(AddTaskRunRequest{TaskRun: taskRun})
+		if err != nil {
+			return fmt.Errorf("task run %q: %w", taskRun.ID, err)
+		}
+		c.copied(KindTaskRuns)
+	}
+
+	// Adding task runs records them on the run a second time; put back the run exactly as it was.
+	return c.destination.UpdateRun(UpdateRunRequest{Run: run})
+}
+
+func (c *copier) copyEvents() error {
+	c.eventIDs = map[int64]int64{}
+
+	return eachEvent(c.source, func(event models.Event) error {
+		id := event.GetID()
+
+		err := c.destination.AddEvent(AddEventRequest{Event: event})
+		if err != nil {
+			return fmt.Errorf("event %d: %w", id, err)
+		}
+
+		c.eventIDs[id] = event.GetID()
+		c.copied(KindEvents)
+		return nil
+	})
+}
+
+// copyEventCursors copies event cursors so that they point at the destination's copy of the event they pointed at in
+// the source. Cursors pointing at an event that no longer exists point at the closest event before it instead.
+func (c *copier) copyEventCursors() error {
+	cursors, err := c.source.GetAllEventCursors(GetAllEventCursorsRequest{})
+	if err != nil {
+		return err
+	}
+
+	sourceIDs := make([]int64, 0, len(c.eventIDs))
+	for id := range c.eventIDs {
+		sourceIDs = append(sourceIDs, id)
+	}
+	sort.Slice(sourceIDs, func(i, j int) bool { return sourceIDs[i] < sourceIDs[j] })
+
+	for _, cursor := range cursors {
+		// Find the newest event the cursor has already processed.
+		index := sort.Search(len(sourceIDs), func(i int) bool { return sourceIDs[i] > cursor.EventID })
+
+		cursor.EventID = 0
+		if index > 0 {
+			cursor.EventID = c.eventIDs[sourceIDs[index-1]]
+		}
+
+		err := c.destination.UpdateEventCursor(UpdateEventCursorRequest{EventCursor: cursor})
+		if err != nil {
+			return fmt.Errorf("event cursor %q: %w", cursor.ID, err)
+		}
+		c.copied(KindEventCursors)
+	}
+
+	return nil
+}
+
+func (c *copier) copyDeadLetters() error {
+	return eachPage(func(offset int) ([]*models.DeadLetter, error) {
+		return c.source.GetAllDeadLetters(GetAllDeadLettersRequest{Offset: offset})
+	}, func(deadLetter *models.DeadLetter) error {
+		err := c.destination.AddDeadLetter(AddDeadLetterRequest{DeadLetter: deadLetter})
+		if err != nil {
+			return fmt.Errorf("dead letter %d: %w", deadLetter.ID, err)
+		}
+		c.copied(KindDeadLetters)
+		return nil
+	})
+}
+
+func (c *copier) copyWebhooks() error {
+	return eachPage(func(offset int) ([]*models.Webhook, error) {
+		return c.source.GetAllWebhooks(GetAllWebhooksRequest{Offset: offset})
+	}, func(webhook *models.Webhook) error {
+		err := c.destination.AddWebhook(AddWebhookRequest{Webhook: webhook})
+		if err != nil {
+			return fmt.Errorf("webhook %d: %w", webhook.ID, err)
+		}
+		c.copied(KindWebhooks)
+		return nil
+	})
+}
+
+func (c *copier) copyWebhookDeliveries() error {
+	return eachPage(func(offset int) ([]*models.WebhookDelivery, error) {
+		return c.source.GetAllWebhookDeliveries(GetAllWebhookDeliveriesRequest{Offset: offset})
+	}, func(delivery *models.WebhookDelivery) error {
+		err := c.destination.AddWebhookDelivery(AddWebhookDeliveryRequest{WebhookDelivery: delivery})
+		if err != nil {
+			return fmt.Errorf("webhook delivery %d: %w", delivery.ID, err)
+		}
+		c.copied(KindWebhookDeliveries)
+		return nil
+	})
+}
+
+func (c *copier) copyRegistryCredentials() error {
+	return eachPage(func(offset int) ([]*models.RegistryCredential, error) {
+		return c.source.GetAllRegistryCredentials(GetAllRegistryCredentialsRequest{Offset: offset})
+	}, func(credential *models.RegistryCredential) error {
+		err := c.destination.AddRegistryCredential(AddRegistryCredentialRequest{RegistryCredential: credential})
+		if err != nil {
+			return fmt.Errorf("registry credential %d: %w", credential.ID, err)
+		}
+		c.copied(KindRegistryCredentials)
+		return nil
+	})
+}
+
+func (c *copier) copyTokens() error {
+	return eachPage(func(offset int) ([]*models.Token, error) {
+		return c.source.GetAllTokens(GetAllTokensRequest{Offset: offset})
+	}, func(token *models.Token) error {
+		err := c.destination.AddToken(AddTokenRequest{Token: token})
+		if err != nil {
+			return err
+		}
+		c.copied(KindTokens)
+		return nil
+	})
+}
+
+func (c *copier) copyServiceAccounts() error {
+	return eachPage(func(offset int) ([]*models.ServiceAccount, error) {
+		return c.source.GetAllServiceAccounts(GetAllServiceAccountsRequest{Offset: offset})
+	}, func(account *models.ServiceAccount) error {
+		err := c.destination.AddServiceAccount(AddServiceAccountRequest{ServiceAccount: account})
+		if err != nil {
+			return fmt.Errorf("service account %q: %w", account.ID, err)
+		}
+		c.copied(KindServiceAccounts)
+		return nil
+	})
+}
+
+func (c *copier) copyAuthzDecisions() error {
+	return eachPage(func(offset int) ([]*models.AuthzDecision, error) {
+		return c.source.GetAllAuthzDecisions(GetAllAuthzDecisionsRequest{Offset: offset})
+	}, func(decision *models.AuthzDecision) error {
+		err := c.destination.AddAuthzDecision(AddAuthzDecisionRequest{AuthzDecision: decision})
+		if err != nil {
+			return err
+		}
+		c.copied(KindAuthorizationDecisions)
+		return nil
+	})
+}
+
+func (c *copier) copyTriggers() error {
+	triggers, err := c.source.GetAllTriggers(GetAllTriggersRequest{})
+	if err != nil {
+		return err
+	}
+
+	for _, trigger := range triggers {
+		err := c.destination.AddTrigger(AddTriggerRequest{Trigger: trigger})
+		if err != nil {
+			return fmt.Errorf("trigger %q: %w", trigger.Kind, err)
+		}
+		c.copied(KindTriggers)
+	}
+
+	return nil
+}
+
+func (c *copier) copyNotifiers() error {
+	notifiers, err := c.source.GetAllNotifiers(GetAllNotifiersRequest{})
+	if err != nil {
+		return err
+	}
+
+	for _, notifier := range notifiers {
+		err := c.destination.AddNotifier(AddNotifierRequest{Notifier: notifier})
+		if err != nil {
+			return fmt.Errorf("notifier %q: %w", notifier.Kind, err)
+		}
+		c.copied(KindNotifiers)
+	}
+
+	return nil
+}
+
+// eachPage calls fn with every record returned by paging through get until it returns an empty page.
+func eachPage[T any](get func(offset int) ([]T, error), fn func(T) error) error {
+	offset := 0
+
+	for {
+		page, err := get(offset)
+		if err != nil {
+			return err
+		}
+
+		if len(page) == 0 {
+			return nil
+		}
+
+		for _, record := range page {
+			err := fn(record)
+			if err != nil {
+				return err
+			}
+		}
+
+		offset += len(page)
+	}
+}
+
+func countPages[T any](counts RecordCounts, kind string, get func(offset int) ([]T, error)) error {
+	return eachPage(get, func(T) error {
+		counts[kind]++
+		return nil
+	})
+}
+
+// eachEvent calls fn with every event from oldest to newest. Events are paged through by ID rather than by position.
+func eachEvent(engine Engine, fn func(models.Event) error) error {
+	lastID := 0
+
+	for {
+		page, err := engine.GetAllEvents(GetAllEventsRequest{Offset: lastID})
+		if err != nil {
+			return err
+		}
+
+		if len(page) == 0 {
+			return nil
+		}
+
+		for _, event := range page {
+			id := event.GetID()
+
+			err := fn(event)
+			if err != nil {
+				return err
+			}
+
+			lastID = int(id)
+		}
+	}
+}
+
+func allNamespaces(engine Engine) ([]*models.Namespace, error) {
+	namespaces := []*models.Namespace{}
+
+	err := eachPage(func(offset int) ([]*models.Namespace, error) {
+		return engine.GetAllNamespaces(GetAllNamespacesRequest{Offset: offset})
+	}, func(namespace *models.Namespace) error {
+		namespaces = append(namespaces, namespace)
+		return nil
+	})
+
+	return namespaces, err
+}
+
+func allPipelines(engine Engine, namespace string) ([]*models.Pipeline, error) {
+	pipelines := []*models.Pipeline{}
+
+	err := eachPage(func(offset int) ([]*models.Pipeline, error) {
+		return engine.GetAllPipelines(GetAllPipelinesRequest{NamespaceID: namespace, Offset: offset})
+	}, func(pipeline *models.Pipeline) error {
+		pipelines = append(pipelines, pipeline)
+		return nil
+	})
+
+	return pipelines, err
+}
+
+func allPipelineConfigVersions(engine Engine, namespace, pipeline string) ([]*models.PipelineConfigVersion, error) {
+	versions := []*models.PipelineConfigVersion{}
+
+	err := eachPage(func(offset int) ([]*models.PipelineConfigVersion, error) {
+		return engine.GetAllPipelineConfigVersions(GetAllPipelineConfigVersionsRequest{
+			NamespaceID: namespace,
+			PipelineID:  pipeline,
+			Offset:      offset,
+		})
+	}, func(version *models.PipelineConfigVersion) error {
+		versions = append(versions, version)
+		return nil
+	})
+
+	return versions, err
+}
@@ -48,6 +48,26 @@ type UpdatePipelineRequest struct {
 	Pipeline *models.Pipeline
 }
 
+// Pipeline Config Versions
+
+type GetAllPipelineConfigVersionsRequest struct {
+	Offset int
+	Limit  int
+
+	NamespaceID string
+	PipelineID  string
+}
+
+type GetPipelineConfigVersionRequest struct {
+	NamespaceID string
+	PipelineID  string
+	Version     int64
+}
+
+type AddPipelineConfigVersionRequest struct {
+	PipelineConfigVersion *models.PipelineConfigVersion
+}
+
 // Runs
 
 type GetAllRunsRequest struct {
@@ -101,6 +121,62 @@ type UpdateTaskRunRequest struct {
 	TaskRun *models.TaskRun
 }
 
+// Artifacts
+
+type GetAllArtifactsRequest struct {
+	Offset        int
+	Limit         int
+	NamespaceID   string
+	PipelineID    string
+	RunID         int64 // Optional; all runs if 0.
+	UnexpiredOnly bool  // Leave out artifacts whose content has already been removed.
+}
+
+type GetArtifactRequest struct {
+	NamespaceID string
+	PipelineID  string
+	RunID       int64
+	TaskRunID   string
+	Name        string
+}
+
+type AddArtifactRequest struct {
+	Artifact *models.Artifact
+}
+
+type UpdateArtifactRequest struct {
+	Artifact *models.Artifact
+}
+
+// Caches
+
+type GetAllCachesRequest struct {
+	Offset      int
+	Limit       int
+	NamespaceID string
+	PipelineID  string
+}
+
+type GetCacheRequest struct {
+	NamespaceID string
+	PipelineID  string
+	Key         string
+}
+
+type AddCacheRequest struct {
+	Cache *models.Cache
+}
+
+type UpdateCacheRequest struct {
+	Cache *models.Cache
+}
+
+type DeleteCacheRequest struct {
+	NamespaceID string
+	PipelineID  string
+	ID          int64
+}
+
 // events
 
 type GetAllEventsRequest struct {
@@ -121,6 +197,102 @@ type DeleteEventRequest struct {
 	ID int64
 }
 
+type GetAllEventCursorsRequest struct{}
+
+type GetEventCursorRequest struct {
+	ID string
+}
+
+type UpdateEventCursorRequest struct {
+	EventCursor *models.EventCursor
+}
+
+type DeleteEventCursorRequest struct {
+	ID string
+}
+
+// dead letters
+
+type GetAllDeadLettersRequest struct {
+	Offset int
+	Limit  int
+}
+
+type GetDeadLetterRequest struct {
+	ID int64
+}
+
+type AddDeadLetterRequest struct {
+	DeadLetter *models.DeadLetter
+}
+
+type UpdateDeadLetterRequest struct {
+	DeadLetter *models.DeadLetter
+}
+
+type DeleteDeadLetterRequest struct {
+	ID int64
+}
+
+// webhooks
+
+type GetAllWebhooksRequest struct {
+	Offset      int
+	Limit       int
+	NamespaceID string // Optional; all namespaces if empty.
+}
+
+type GetWebhookRequest struct {
+	ID int64
+}
+
+type AddWebhookRequest struct {
+	Webhook *models.Webhook
+}
+
+type DeleteWebhookRequest struct {
+	ID int64
+}
+
+type GetAllWebhookDeliveriesRequest struct {
+	Offset    int
+	Limit     int
+	WebhookID int64 // Optional; all webhooks if 0.
+}
+
+type AddWebhookDeliveryRequest struct {
+	WebhookDelivery *models.WebhookDelivery
+}
+
+type UpdateWebhookDeliveryRequest struct {
+	WebhookDelivery *models.WebhookDelivery
+}
+
+type DeleteWebhookDeliveriesRequest struct {
+	WebhookID int64
+	Keep      int // How many of the most recent deliveries to keep.
+}
+
+// registry credentials
+
+type GetAllRegistryCredentialsRequest struct {
+	Offset      int
+	Limit       int
+	NamespaceID string // Optional; all namespaces if empty.
+}
+
+type AddRegistryCredentialRequest struct {
+	RegistryCredential *models.RegistryCredential
+}
+
+type UpdateRegistryCredentialRequest struct {
+	RegistryCredential *models.RegistryCredential
+}
+
+type DeleteRegistryCredentialRequest struct {
+	ID int64
+}
+
 type GetAllTokensRequest struct {
 	Offset     int
 	Limit      int
@@ -131,6 +303,10 @@ type AddTokenRequest struct {
 	Token *models.Token
 }
 
+type UpdateTokenRequest struct {
+	Token *models.Token
+}
+
 type GetTokenRequest struct {
 	Hash string
 }
@@ -139,6 +315,44 @@ type DeleteTokenRequest struct {
 	Hash string
 }
 
+// Service Accounts
+
+type GetAllServiceAccountsRequest struct {
+	Offset int
+	Limit  int
+}
+
+type GetServiceAccountRequest struct {
+	ID string
+}
+
+type AddServiceAccountRequest struct {
+	ServiceAccount *models.ServiceAccount
+}
+
+type DeleteServiceAccountRequest struct {
+	ID string
+}
+
+// Authorization decisions
+
+type GetAllAuthzDecisionsRequest struct {
+	Offset int
+	Limit  int
+
+	Caller     string // Only return decisions for the given caller.
+	DeniedOnly bool   // Only return decisions in which the caller was denied.
+	Since      int64  // Only return decisions made at or after the given time in epoch milli.
+}
+
+type AddAuthzDecisionRequest struct {
+	AuthzDecision *models.AuthzDecision
+}
+
+type DeleteAuthzDecisionsRequest struct {
+	Before int64 // Remove all decisions made before the given time in epoch milli.
+}
+
 // Triggers
 
 type GetAllTriggersRequest struct{}
@@ -6,6 +6,8 @@ package storage
 
 import (
 	"errors"
+	"fmt"
+	"io"
 
 	"github.com/clintjedwards/gofer/internal/config"
 	"github.com/clintjedwards/gofer/internal/models"
@@ -29,8 +31,34 @@ var (
 
 	// ErrPreconditionFailure is returned when there was a validation error with the parameters passed.
 	ErrPreconditionFailure = errors.New("storage: parameters did not pass validation")
+
+	// ErrSchemaIncompatible is returned when the database was written by a version of Gofer this version cannot read.
+	ErrSchemaIncompatible = errors.New("storage: database schema is incompatible")
 )
 
+// SchemaVersion is the version of the stored data layout this version of Gofer reads and writes. It is increased
+// whenever stored data changes in a way that needs to be migrated.
+const SchemaVersion = 1
+
+// SchemaStatus describes how the schema of a database compares to the one this version of Gofer expects.
+type SchemaStatus struct {
+	Version int // The schema version of the database.
+
+	// Descriptions of the migrations that will be applied to the database the next time Gofer starts, in order.
+	PendingMigrations []string
+}
+
+// Compatible returns an error wrapping ErrSchemaIncompatible if this version of Gofer cannot use the database.
+func (s SchemaStatus) Compatible() error {
+	if s.Version > SchemaVersion {
+		return fmt.Errorf("%w; the database has schema version %d but this version of Gofer only supports up to "+
+			"version %d. The database was most likely used by a newer version of Gofer; run that version instead or "+
+			"restore a backup taken before it was installed", ErrSchemaIncompatible, s.Version, SchemaVersion)
+	}
+
+	return nil
+}
+
 // Engine represents backend storage implementations where items can be persisted.
 type Engine interface {
 	GetAllNamespaces(r GetAllNamespacesRequest) ([]*models.Namespace, error)
@@ -40,14 +68,30 @@ type Engine interface {
 
 	GetAllTokens(r GetAllTokensRequest) ([]*models.Token, error)
 	AddToken(r AddTokenRequest) error
+	UpdateToken(r UpdateTokenRequest) error
 	GetToken(r GetTokenRequest) (*models.Token, error)
 	DeleteToken(r DeleteTokenRequest) error
 
+	GetAllServiceAccounts(r GetAllServiceAccountsRequest) ([]*models.ServiceAccount, error)
+	GetServiceAccount(r GetServiceAccountRequest) (*models.ServiceAccount, error)
+	AddServiceAccount(r AddServiceAccountRequest) error
+	DeleteServiceAccount(r DeleteServiceAccountRequest) error
+
+	// GetAllAuthzDecisions returns authorization decisions ordered from newest to oldest.
+	GetAllAuthzDecisions(r GetAllAuthzDecisionsRequest) ([]*models.AuthzDecision, error)
+	AddAuthzDecision(r AddAuthzDecisionRequest) error
+	// DeleteAuthzDecisions removes old authorization decisions and returns how many were removed.
+	DeleteAuthzDecisions(r DeleteAuthzDecisionsRequest) (int, error)
+
 	GetAllPipelines(r GetAllPipelinesRequest) ([]*models.Pipeline, error)
 	GetPipeline(r GetPipelineRequest) (*models.Pipeline, error)
 	AddPipeline(r AddPipelineRequest) error
 	UpdatePipeline(r UpdatePipelineRequest) error
 
+	GetAllPipelineConfigVersions(r GetAllPipelineConfigVersionsRequest) ([]*models.PipelineConfigVersion, error)
+	GetPipelineConfigVersion(r GetPipelineConfigVersionRequest) (*models.PipelineConfigVersion, error)
+	AddPipelineConfigVersion(r AddPipelineConfigVersionRequest) error
+
 	GetAllRuns(r GetAllRunsRequest) ([]*models.Run, error)
 	GetRun(r GetRunRequest) (*models.Run, error)
 	AddRun(r AddRunRequest) error
@@ -58,11 +102,61 @@ type Engine interface {
 	AddTaskRun(r AddTaskRunRequest) error
 	UpdateTaskRun(r UpdateTaskRunRequest) error
 
+	// GetAllArtifacts returns the artifacts of a pipeline, or of a single run of it, ordered from newest to oldest.
+	GetAllArtifacts(r GetAllArtifactsRequest) ([]*models.Artifact, error)
+	// GetArtifact returns the artifact of the given name collected from a task run.
+	GetArtifact(r GetArtifactRequest) (*models.Artifact, error)
+	AddArtifact(r AddArtifactRequest) error
+	UpdateArtifact(r UpdateArtifactRequest) error
+
+	// GetAllCaches returns the caches of a pipeline ordered from newest to oldest.
+	GetAllCaches(r GetAllCachesRequest) ([]*models.Cache, error)
+	// GetCache returns the cache saved under the given key.
+	GetCache(r GetCacheRequest) (*models.Cache, error)
+	AddCache(r AddCacheRequest) error
+	UpdateCache(r UpdateCacheRequest) error
+	DeleteCache(r DeleteCacheRequest) error
+
 	GetAllEvents(r GetAllEventsRequest) ([]models.Event, error)
 	GetEvent(r GetEventRequest) (models.Event, error)
 	AddEvent(r AddEventRequest) error
 	DeleteEvent(r DeleteEventRequest) error
 
+	GetAllEventCursors(r GetAllEventCursorsRequest) ([]*models.EventCursor, error)
+	GetEventCursor(r GetEventCursorRequest) (*models.EventCursor, error)
+	// UpdateEventCursor saves the position of an event cursor, creating the cursor if it doesn't exist.
+	UpdateEventCursor(r UpdateEventCursorRequest) error
+	DeleteEventCursor(r DeleteEventCursorRequest) error
+
+	// GetAllDeadLetters returns dead letters ordered from newest to oldest.
+	GetAllDeadLetters(r GetAllDeadLettersRequest) ([]*models.DeadLetter, error)
+	GetDeadLetter(r GetDeadLetterRequest) (*models.DeadLetter, error)
+	AddDeadLetter(r AddDeadLetterRequest) error
+	UpdateDeadLetter(r UpdateDeadLetterRequest) error
+	DeleteDeadLetter(r DeleteDeadLetterRequest) error
+
+	// GetAllWebhooks returns the webhooks of a namespace, or of every namespace if none is given.
+	GetAllWebhooks(r GetAllWebhooksRequest) ([]*models.Webhook, error)
+	GetWebhook(r GetWebhookRequest) (*models.Webhook, error)
+	AddWebhook(r AddWebhookRequest) error
+	// DeleteWebhook removes a webhook along with its delivery history.
+	DeleteWebhook(r DeleteWebhookRequest) error
+
+	// GetAllWebhookDeliveries returns webhook deliveries ordered from newest to oldest.
+	GetAllWebhookDeliveries(r GetAllWebhookDeliveriesRequest) ([]*models.WebhookDelivery, error)
+	AddWebhookDelivery(r AddWebhookDeliveryRequest) error
+	UpdateWebhookDelivery(r UpdateWebhookDeliveryRequest) error
+	// DeleteWebhookDeliveries removes all but the most recent deliveries of a webhook and returns how many were
+	// removed.
+	DeleteWebhookDeliveries(r DeleteWebhookDeliveriesRequest) (int, error)
+
+	// GetAllRegistryCredentials returns the registry credentials of a namespace, or of every namespace if none is
+	// given.
+	GetAllRegistryCredentials(r GetAllRegistryCredentialsRequest) ([]*models.RegistryCredential, error)
+	AddRegistryCredential(r AddRegistryCredentialRequest) error
+	UpdateRegistryCredential(r UpdateRegistryCredentialRequest) error
+	DeleteRegistryCredential(r DeleteRegistryCredentialRequest) error
+
 	GetAllTriggers(r GetAllTriggersRequest) ([]*config.Trigger, error)
 	GetTrigger(r GetTriggerRequest) (*config.Trigger, error)
 	AddTrigger(r AddTriggerRequest) error
@@ -72,4 +166,8 @@ type Engine interface {
 	GetNotifier(r GetNotifierRequest) (*config.Notifier, error)
 	AddNotifier(r AddNotifierRequest) error
 	DeleteNotifier(r DeleteNotifierRequest) error
+
+	// Backup writes a consistent copy of the entire database to the writer. It is safe to call while the database
+	// is in use.
+	Backup(w io.Writer) error
 }
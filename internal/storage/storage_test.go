@@ -1,11 +1,16 @@
 package storage_test
 
 import (
+	"errors"
 	"io/ioutil"
 	"os"
+	"strconv"
 	"testing"
 
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/storage"
 	"github.com/clintjedwards/gofer/internal/storage/bolt"
+	bbolt "go.etcd.io/bbolt"
 )
 
 // TODO(clintjedwards): Iterate through all implementations and make sure they return records
@@ -31,3 +36,152 @@ func TestBolt(t *testing.T) {
 		t.Fatal(err)
 	}
 }
+
+func TestBoltSchemaVersion(t *testing.T) {
+	path := tempfile()
+
+	db, err := bolt.New(path, 100)
+	if err != nil {
+		t.Fatal(err)
+	}
+	_ = db.Close()
+
+	status, err := bolt.InspectSchema(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if status.Version != storage.SchemaVersion || len(status.PendingMigrations) != 0 {
+		t.Fatalf("expected schema version %d with no pending migrations; got %+v", storage.SchemaVersion, status)
+	}
+
+	// Pretend a newer version of Gofer has used the database.
+	raw, err := bbolt.Open(path, 0600, nil)
+	if err != nil {
+		t.Fatal(err)
+	}
+	err = raw.Update(func(tx *bbolt.Tx) error {
+		return tx.Bucket([]byte("meta")).Put([]byte("schema_version"), []byte(strconv.Itoa(storage.SchemaVersion+1)))
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	_ = raw.Close()
+
+	_, err = bolt.New(path, 100)
+	if !errors.Is(err, storage.ErrSchemaIncompatible) {
+		t.Fatalf("expected newer schema to be refused; got %v", err)
+	}
+}
+
+func TestCopy(t *testing.T) {
+	source, err := bolt.New(tempfile(), 100)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer source.Close()
+
+	err = source.AddNamespace(storage.AddNamespaceRequest{Namespace: &models.Namespace{ID: "default"}})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = source.AddPipeline(storage.AddPipelineRequest{Pipeline: &models.Pipeline{Namespace: "default", ID: "simple"}})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	for id := int64(1); id <= 2; id++ {
+		err = source.AddRun(storage.AddRunRequest{Run: &models.Run{NamespaceID: "default", PipelineID: "simple", ID: id}})
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		err = source.AddTaskRun(storage.AddTaskRunRequest{TaskRun: &models.TaskRun{
+			NamespaceID: "default", PipelineID: "simple", RunID: id, ID: "build",
+		}})
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		err = source.AddArtifact(storage.AddArtifactRequest{Artifact: &models.Artifact{
+			NamespaceID: "default", PipelineID: "simple", RunID: id, TaskRunID: "build", Name: "app",
+		}})
+		if err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	err = source.AddCache(storage.AddCacheRequest{Cache: &models.Cache{
+		NamespaceID: "default", PipelineID: "simple", Key: "go-modules",
+	}})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	for i := 0; i < 3; i++ {
+		err = source.AddEvent(storage.AddEventRequest{Event: models.NewEventExpiredPipelineObject("default", "simple", "key")})
+		if err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	// Pruned events leave a gap in the source's event IDs that the destination won't have.
+	err = source.DeleteEvent(storage.DeleteEventRequest{ID: 1})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	err = source.UpdateEventCursor(storage.UpdateEventCursorRequest{EventCursor: &models.EventCursor{ID: "sink:test", EventID: 2}})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	destination, err := bolt.New(tempfile(), 100)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer destination.Close()
+
+	copied, err := storage.Copy(&source, &destination, nil)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	counts, err := storage.Count(&destination)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if diff := copied.Diff(counts); len(diff) != 0 {
+		t.Fatalf("destination does not match what was copied: %v", diff)
+	}
+
+	if counts[storage.KindRuns] != 2 || counts[storage.KindTaskRuns] != 2 || counts[storage.KindArtifacts] != 2 ||
+		counts[storage.KindCaches] != 1 || counts[storage.KindEvents] != 2 {
+		t.Fatalf("unexpected record counts in destination: %v", counts)
+	}
+
+	run, err := destination.GetRun(storage.GetRunRequest{NamespaceID: "default", PipelineID: "simple", ID: 1})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if len(run.TaskRuns) != 1 {
+		t.Fatalf("expected run to keep a single task run; got %v", run.TaskRuns)
+	}
+
+	cursor, err := destination.GetEventCursor(storage.GetEventCursorRequest{ID: "sink:test"})
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if cursor.EventID != 1 {
+		t.Fatalf("expected event cursor to point at the first copied event; got %d", cursor.EventID)
+	}
+
+	_, err = storage.Copy(&source, &destination, nil)
+	if !errors.Is(err, storage.ErrPreconditionFailure) {
+		t.Fatalf("expected copying into a destination with records to fail; got %v", err)
+	}
+}
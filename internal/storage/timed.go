@@ -0,0 +1,398 @@
+package storage
+
+import (
+	"io"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+)
+
+// TimedEngine wraps a storage engine and reports how long each query takes. Queries are named after the Engine
+// method that was called.
+type TimedEngine struct {
+	engine  Engine
+	observe func(query string, duration time.Duration)
+}
+
+// NewTimedEngine returns the engine wrapped so that observe is called with the duration of every query.
+func NewTimedEngine(engine Engine, observe func(query string, duration time.Duration)) *TimedEngine {
+	return &TimedEngine{
+		engine:  engine,
+		observe: observe,
+	}
+}
+
+func (e *TimedEngine) timed(query string, start time.Time) {
+	e.observe(query, time.Since(start))
+}
+
+func (e *TimedEngine) GetAllNamespaces(r GetAllNamespacesRequest) ([]*models.Namespace, error) {
+	defer e.timed("GetAllNamespaces", time.Now())
+	return e.engine.GetAllNamespaces(r)
+}
+
+func (e *TimedEngine) AddNamespace(r AddNamespaceRequest) error {
+	defer e.timed("AddNamespace", time.Now())
+	return e.engine.AddNamespace(r)
+}
+
+func (e *TimedEngine) GetNamespace(r GetNamespaceRequest) (*models.Namespace, error) {
+	defer e.timed("GetNamespace", time.Now())
+	return e.engine.GetNamespace(r)
+}
+
+func (e *TimedEngine) UpdateNamespace(r UpdateNamespaceRequest) error {
+	defer e.timed("UpdateNamespace", time.Now())
+	return e.engine.UpdateNamespace(r)
+}
+
+func (e *TimedEngine) GetAllTokens(r GetAllTokensRequest) ([]*models.Token, error) {
+	defer e.timed("GetAllTokens", time.Now())
+	return e.engine.GetAllTokens(r)
+}
+
+func (e *TimedEngine) AddToken(r AddTokenRequest) error {
+	defer e.timed("AddToken", time.Now())
+	return e.engine.AddToken(r)
+}
+
+func (e *TimedEngine) UpdateToken(r UpdateTokenRequest) error {
+	defer e.timed("UpdateToken", time.Now())
+	return e.engine.UpdateToken(r)
+}
+
+func (e *TimedEngine) GetToken(r GetTokenRequest) (*models.Token, error) {
+	defer e.timed("GetToken", time.Now())
+	return e.engine.GetToken(r)
+}
+
+func (e *TimedEngine) DeleteToken(r DeleteTokenRequest) error {
+	defer e.timed("DeleteToken", time.Now())
+	return e.engine.DeleteToken(r)
+}
+
+func (e *TimedEngine) GetAllServiceAccounts(r GetAllServiceAccountsRequest) ([]*models.ServiceAccount, error) {
+	defer e.timed("GetAllServiceAccounts", time.Now())
+	return e.engine.GetAllServiceAccounts(r)
+}
+
+func (e *TimedEngine) GetServiceAccount(r GetServiceAccountRequest) (*models.ServiceAccount, error) {
+	defer e.timed("GetServiceAccount", time.Now())
+	return e.engine.GetServiceAccount(r)
+}
+
+func (e *TimedEngine) AddServiceAccount(r AddServiceAccountRequest) error {
+	defer e.timed("AddServiceAccount", time.Now())
+	return e.engine.AddServiceAccount(r)
+}
+
+func (e *TimedEngine) DeleteServiceAccount(r DeleteServiceAccountRequest) error {
+	defer e.timed("DeleteServiceAccount", time.Now())
+	return e.engine.DeleteServiceAccount(r)
+}
+
+func (e *TimedEngine) GetAllAuthzDecisions(r GetAllAuthzDecisionsRequest) ([]*models.AuthzDecision, error) {
+	defer e.timed("GetAllAuthzDecisions", time.Now())
+	return e.engine.GetAllAuthzDecisions(r)
+}
+
+func (e *TimedEngine) AddAuthzDecision(r AddAuthzDecisionRequest) error {
+	defer e.timed("AddAuthzDecision", time.Now())
+	return e.engine.AddAuthzDecision(r)
+}
+
+func (e *TimedEngine) DeleteAuthzDecisions(r DeleteAuthzDecisionsRequest) (int, error) {
+	defer e.timed("DeleteAuthzDecisions", time.Now())
+	return e.engine.DeleteAuthzDecisions(r)
+}
+
+func (e *TimedEngine) GetAllPipelines(r GetAllPipelinesRequest) ([]*models.Pipeline, error) {
+	defer e.timed("GetAllPipelines", time.Now())
+	return e.engine.GetAllPipelines(r)
+}
+
+func (e *TimedEngine) GetPipeline(r GetPipelineRequest) (*models.Pipeline, error) {
+	defer e.timed("GetPipeline", time.Now())
+	return e.engine.GetPipeline(r)
+}
+
+func (e *TimedEngine) AddPipeline(r AddPipelineRequest) error {
+	defer e.timed("AddPipeline", time.Now())
+	return e.engine.AddPipeline(r)
+}
+
+func (e *TimedEngine) UpdatePipeline(r UpdatePipelineRequest) error {
+	defer e.timed("UpdatePipeline", time.Now())
+	return e.engine.UpdatePipeline(r)
+}
+
+func (e *TimedEngine) GetAllPipelineConfigVersions(r GetAllPipelineConfigVersionsRequest) ([]*models.PipelineConfigVersion, error) {
+	defer e.timed("GetAllPipelineConfigVersions", time.Now())
+	return e.engine.GetAllPipelineConfigVersions(r)
+}
+
+func (e *TimedEngine) GetPipelineConfigVersion(r GetPipelineConfigVersionRequest) (*models.PipelineConfigVersion, error) {
+	defer e.timed("GetPipelineConfigVersion", time.Now())
+	return e.engine.GetPipelineConfigVersion(r)
+}
+
+func (e *TimedEngine) AddPipelineConfigVersion(r AddPipelineConfigVersionRequest) error {
+	defer e.timed("AddPipelineConfigVersion", time.Now())
+	return e.engine.AddPipelineConfigVersion(r)
+}
+
+func (e *TimedEngine) GetAllRuns(r GetAllRunsRequest) ([]*models.Run, error) {
+	defer e.timed("GetAllRuns", time.Now())
+	return e.engine.GetAllRuns(r)
+}
+
+func (e *TimedEngine) GetRun(r GetRunRequest) (*models.Run, error) {
+	defer e.timed("GetRun", time.Now())
+	return e.engine.GetRun(r)
+}
+
+func (e *TimedEngine) AddRun(r AddRunRequest) error {
+	defer e.timed("AddRun", time.Now())
+	return e.engine.AddRun(r)
+}
+
+func (e *TimedEngine) UpdateRun(r UpdateRunRequest) error {
+	defer e.timed("UpdateRun", time.Now())
+	return e.engine.UpdateRun(r)
+}
+
+func (e *TimedEngine) GetAllTaskRuns(r GetAllTaskRunsRequest) ([]*models.TaskRun, error) {
+	defer e.timed("GetAllTaskRuns", time.Now())
+	return e.engine.GetAllTaskRuns(r)
+}
+
+func (e *TimedEngine) GetTaskRun(r GetTaskRunRequest) (*models.TaskRun, error) {
+	defer e.timed("GetTaskRun", time.Now())
+	return e.engine.GetTaskRun(r)
+}
+
+func (e *TimedEngine) AddTaskRun(r AddTaskRunRequest) error {
+	defer e.timed("AddTaskRun", time.Now())
+	return e.engine.AddTaskRun(r)
+}
+
+func (e *TimedEngine) UpdateTaskRun(r UpdateTaskRunRequest) error {
+	defer e.timed("UpdateTaskRun", time.Now())
+	return e.engine.UpdateTaskRun(r)
+}
+
+func (e *TimedEngine) GetAllArtifacts(r GetAllArtifactsRequest) ([]*models.Artifact, error) {
+	defer e.timed("GetAllArtifacts", time.Now())
+	return e.engine.GetAllArtifacts(r)
+}
+
+func (e *TimedEngine) GetArtifact(r GetArtifactRequest) (*models.Artifact, error) {
+	defer e.timed("GetArtifact", time.Now())
+	return e.engine.GetArtifact(r)
+}
+
+func (e *TimedEngine) AddArtifact(r AddArtifactRequest) error {
+	defer e.timed("AddArtifact", time.Now())
+	return e.engine.AddArtifact(r)
+}
+
+func (e *TimedEngine) UpdateArtifact(r UpdateArtifactRequest) error {
+	defer e.timed("UpdateArtifact", time.Now())
+	return e.engine.UpdateArtifact(r)
+}
+
+func (e *TimedEngine) GetAllCaches(r GetAllCachesRequest) ([]*models.Cache, error) {
+	defer e.timed("GetAllCaches", time.Now())
+	return e.engine.GetAllCaches(r)
+}
+
+func (e *TimedEngine) GetCache(r GetCacheRequest) (*models.Cache, error) {
+	defer e.timed("GetCache", time.Now())
+	return e.engine.GetCache(r)
+}
+
+func (e *TimedEngine) AddCache(r AddCacheRequest) error {
+	defer e.timed("AddCache", time.Now())
+	return e.engine.AddCache(r)
+}
+
+func (e *TimedEngine) UpdateCache(r UpdateCacheRequest) error {
+	defer e.timed("UpdateCache", time.Now())
+	return e.engine.UpdateCache(r)
+}
+
+func (e *TimedEngine) DeleteCache(r DeleteCacheRequest) error {
+	defer e.timed("DeleteCache", time.Now())
+	return e.engine.DeleteCache(r)
+}
+
+func (e *TimedEngine) GetAllEvents(r GetAllEventsRequest) ([]models.Event, error) {
+	defer e.timed("GetAllEvents", time.Now())
+	return e.engine.GetAllEvents(r)
+}
+
+func (e *TimedEngine) GetEvent(r GetEventRequest) (models.Event, error) {
+	defer e.timed("GetEvent", time.Now())
+	return e.engine.GetEvent(r)
+}
+
+func (e *TimedEngine) AddEvent(r AddEventRequest) error {
+	defer e.timed("AddEvent", time.Now())
+	return e.engine.AddEvent(r)
+}
+
+func (e *TimedEngine) DeleteEvent(r DeleteEventRequest) error {
+	defer e.timed("DeleteEvent", time.Now())
+	return e.engine.DeleteEvent(r)
+}
+
+func (e *TimedEngine) GetAllEventCursors(r GetAllEventCursorsRequest) ([]*models.EventCursor, error) {
+	defer e.timed("GetAllEventCursors", time.Now())
+	return e.engine.GetAllEventCursors(r)
+}
+
+func (e *TimedEngine) GetEventCursor(r GetEventCursorRequest) (*models.EventCursor, error) {
+	defer e.timed("GetEventCursor", time.Now())
+	return e.engine.GetEventCursor(r)
+}
+
+func (e *TimedEngine) UpdateEventCursor(r UpdateEventCursorRequest) error {
+	defer e.timed("UpdateEventCursor", time.Now())
+	return e.engine.UpdateEventCursor(r)
+}
+
+func (e *TimedEngine) DeleteEventCursor(r DeleteEventCursorRequest) error {
+	defer e.timed("DeleteEventCursor", time.Now())
+	return e.engine.DeleteEventCursor(r)
+}
+
+func (e *TimedEngine) GetAllDeadLetters(r GetAllDeadLettersRequest) ([]*models.DeadLetter, error) {
+	defer e.timed("GetAllDeadLetters", time.Now())
+	return e.engine.GetAllDeadLetters(r)
+}
+
+func (e *TimedEngine) GetDeadLetter(r GetDeadLetterRequest) (*models.DeadLetter, error) {
+	defer e.timed("GetDeadLetter", time.Now())
+	return e.engine.GetDeadLetter(r)
+}
+
+func (e *TimedEngine) AddDeadLetter(r AddDeadLetterRequest) error {
+	defer e.timed("AddDeadLetter", time.Now())
+	return e.engine.AddDeadLetter(r)
+}
+
+func (e *TimedEngine) UpdateDeadLetter(r UpdateDeadLetterRequest) error {
+	defer e.timed("UpdateDeadLetter", time.Now())
+	return e.engine.UpdateDeadLetter(r)
+}
+
+func (e *TimedEngine) DeleteDeadLetter(r DeleteDeadLetterRequest) error {
+	defer e.timed("DeleteDeadLetter", time.Now())
+	return e.engine.DeleteDeadLetter(r)
+}
+
+func (e *TimedEngine) GetAllWebhooks(r GetAllWebhooksRequest) ([]*models.Webhook, error) {
+	defer e.timed("GetAllWebhooks", time.Now())
+	return e.engine.GetAllWebhooks(r)
+}
+
+func (e *TimedEngine) GetWebhook(r GetWebhookRequest) (*models.Webhook, error) {
+	defer e.timed("GetWebhook", time.Now())
+	return e.engine.GetWebhook(r)
+}
+
+func (e *TimedEngine) AddWebhook(r AddWebhookRequest) error {
+	defer e.timed("AddWebhook", time.Now())
+	return e.engine.AddWebhook(r)
+}
+
+func (e *TimedEngine) DeleteWebhook(r DeleteWebhookRequest) error {
+	defer e.timed("DeleteWebhook", time.Now())
+	return e.engine.DeleteWebhook(r)
+}
+
+func (e *TimedEngine) GetAllWebhookDeliveries(r GetAllWebhookDeliveriesRequest) ([]*models.WebhookDelivery, error) {
+	defer e.timed("GetAllWebhookDeliveries", time.Now())
+	return e.engine.GetAllWebhookDeliveries(r)
+}
+
+func (e *TimedEngine) AddWebhookDelivery(r AddWebhookDeliveryRequest) error {
+	defer e.timed("AddWebhookDelivery", time.Now())
+	return e.engine.AddWebhookDelivery(r)
+}
+
+func (e *TimedEngine) UpdateWebhookDelivery(r UpdateWebhookDeliveryRequest) error {
+	defer e.timed("UpdateWebhookDelivery", time.Now())
+	return e.engine.UpdateWebhookDelivery(r)
+}
+
+func (e *TimedEngine) DeleteWebhookDeliveries(r DeleteWebhookDeliveriesRequest) (int, error) {
+	defer e.timed("DeleteWebhookDeliveries", time.Now())
+	return e.engine.DeleteWebhookDeliveries(r)
+}
+
+func (e *TimedEngine) GetAllRegistryCredentials(r GetAllRegistryCredentialsRequest) ([]*models.RegistryCredential, error) {
+	defer e.timed("GetAllRegistryCredentials", time.Now())
+	return e.engine.GetAllRegistryCredentials(r)
+}
+
+func (e *TimedEngine) AddRegistryCredential(r AddRegistryCredentialRequest) error {
+	defer e.timed("AddRegistryCredential", time.Now())
+	return e.engine.AddRegistryCredential(r)
+}
+
+func (e *TimedEngine) UpdateRegistryCredential(r UpdateRegistryCredentialRequest) error {
+	defer e.timed("UpdateRegistryCredential", time.Now())
+	return e.engine.UpdateRegistryCredential(r)
+}
+
+func (e *TimedEngine) DeleteRegistryCredential(r DeleteRegistryCredentialRequest) error {
+	defer e.timed("DeleteRegistryCredential", time.Now())
+	return e.engine.DeleteRegistryCredential(r)
+}
+
+func (e *TimedEngine) GetAllTriggers(r GetAllTriggersRequest) ([]*config.Trigger, error) {
+	defer e.timed("GetAllTriggers", time.Now())
+	return e.engine.GetAllTriggers(r)
+}
+
+func (e *TimedEngine) GetTrigger(r GetTriggerRequest) (*config.Trigger, error) {
+	defer e.timed("GetTrigger", time.Now())
+	return e.engine.GetTrigger(r)
+}
+
+func (e *TimedEngine) AddTrigger(r AddTriggerRequest) error {
+	defer e.timed("AddTrigger", time.Now())
+	return e.engine.AddTrigger(r)
+}
+
+func (e *TimedEngine) DeleteTrigger(r DeleteTriggerRequest) error {
+	defer e.timed("DeleteTrigger", time.Now())
+	return e.engine.DeleteTrigger(r)
+}
+
+func (e *TimedEngine) GetAllNotifiers(r GetAllNotifiersRequest) ([]*config.Notifier, error) {
+	defer e.timed("GetAllNotifiers", time.Now())
+	return e.engine.GetAllNotifiers(r)
+}
+
+func (e *TimedEngine) GetNotifier(r GetNotifierRequest) (*config.Notifier, error) {
+	defer e.timed("GetNotifier", time.Now())
+	return e.engine.GetNotifier(r)
+}
+
+func (e *TimedEngine) AddNotifier(r AddNotifierRequest) error {
+	defer e.timed("AddNotifier", time.Now())
+	return e.engine.AddNotifier(r)
+}
+
+func (e *TimedEngine) DeleteNotifier(r DeleteNotifierRequest) error {
+	defer e.timed("DeleteNotifier", time.Now())
+	return e.engine.DeleteNotifier(r)
+}
+
+// Backup isn't timed since it copies the entire database and would always be reported as the slowest query.
+func (e *TimedEngine) Backup(w io.Writer) error {
+	return e.engine.Backup(w)
+}
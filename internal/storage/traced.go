@@ -0,0 +1,692 @@
+package storage
+
+import (
+	"context"
+	"io"
+
+	"github.com/clintjedwards/gofer/internal/config"
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/clintjedwards/gofer/internal/tracing"
+)
+
+// TracedEngine records every call made to an engine as a span.
+type TracedEngine struct {
+	ctx    context.Context
+	engine Engine
+}
+
+// NewTracedEngine returns the engine with every call recorded as a span that is a child of the span within ctx.
+// The engine is returned as is while tracing is turned off.
+func NewTracedEngine(ctx context.Context, engine Engine) Engine {
+	if !tracing.Enabled() {
+		return engine
+	}
+
+	return &TracedEngine{ctx: ctx, engine: engine}
+}
+
+func (e *TracedEngine) GetAllNamespaces(r GetAllNamespacesRequest) ([]*models.Namespace, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllNamespaces")
+	defer span.End()
+
+	result, err := e.engine.GetAllNamespaces(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddNamespace(r AddNamespaceRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddNamespace")
+	defer span.End()
+
+	err := e.engine.AddNamespace(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetNamespace(r GetNamespaceRequest) (*models.Namespace, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetNamespace")
+	defer span.End()
+
+	result, err := e.engine.GetNamespace(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) UpdateNamespace(r UpdateNamespaceRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateNamespace")
+	defer span.End()
+
+	err := e.engine.UpdateNamespace(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllTokens(r GetAllTokensRequest) ([]*models.Token, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllTokens")
+	defer span.End()
+
+	result, err := e.engine.GetAllTokens(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddToken(r AddTokenRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddToken")
+	defer span.End()
+
+	err := e.engine.AddToken(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateToken(r UpdateTokenRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateToken")
+	defer span.End()
+
+	err := e.engine.UpdateToken(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetToken(r GetTokenRequest) (*models.Token, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetToken")
+	defer span.End()
+
+	result, err := e.engine.GetToken(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) DeleteToken(r DeleteTokenRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteToken")
+	defer span.End()
+
+	err := e.engine.DeleteToken(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllServiceAccounts(r GetAllServiceAccountsRequest) ([]*models.ServiceAccount, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllServiceAccounts")
+	defer span.End()
+
+	result, err := e.engine.GetAllServiceAccounts(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetServiceAccount(r GetServiceAccountRequest) (*models.ServiceAccount, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetServiceAccount")
+	defer span.End()
+
+	result, err := e.engine.GetServiceAccount(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddServiceAccount(r AddServiceAccountRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddServiceAccount")
+	defer span.End()
+
+	err := e.engine.AddServiceAccount(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteServiceAccount(r DeleteServiceAccountRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteServiceAccount")
+	defer span.End()
+
+	err := e.engine.DeleteServiceAccount(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllAuthzDecisions(r GetAllAuthzDecisionsRequest) ([]*models.AuthzDecision, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllAuthzDecisions")
+	defer span.End()
+
+	result, err := e.engine.GetAllAuthzDecisions(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddAuthzDecision(r AddAuthzDecisionRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddAuthzDecision")
+	defer span.End()
+
+	err := e.engine.AddAuthzDecision(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteAuthzDecisions(r DeleteAuthzDecisionsRequest) (int, error) {
+	_, span := tracing.Start(e.ctx, "storage.DeleteAuthzDecisions")
+	defer span.End()
+
+	result, err := e.engine.DeleteAuthzDecisions(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetAllPipelines(r GetAllPipelinesRequest) ([]*models.Pipeline, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllPipelines")
+	defer span.End()
+
+	result, err := e.engine.GetAllPipelines(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetPipeline(r GetPipelineRequest) (*models.Pipeline, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetPipeline")
+	defer span.End()
+
+	result, err := e.engine.GetPipeline(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddPipeline(r AddPipelineRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddPipeline")
+	defer span.End()
+
+	err := e.engine.AddPipeline(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdatePipeline(r UpdatePipelineRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdatePipeline")
+	defer span.End()
+
+	err := e.engine.UpdatePipeline(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllPipelineConfigVersions(r GetAllPipelineConfigVersionsRequest) ([]*models.PipelineConfigVersion, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllPipelineConfigVersions")
+	defer span.End()
+
+	result, err := e.engine.GetAllPipelineConfigVersions(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetPipelineConfigVersion(r GetPipelineConfigVersionRequest) (*models.PipelineConfigVersion, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetPipelineConfigVersion")
+	defer span.End()
+
+	result, err := e.engine.GetPipelineConfigVersion(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddPipelineConfigVersion(r AddPipelineConfigVersionRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddPipelineConfigVersion")
+	defer span.End()
+
+	err := e.engine.AddPipelineConfigVersion(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllRuns(r GetAllRunsRequest) ([]*models.Run, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllRuns")
+	defer span.End()
+
+	result, err := e.engine.GetAllRuns(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetRun(r GetRunRequest) (*models.Run, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetRun")
+	defer span.End()
+
+	result, err := e.engine.GetRun(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddRun(r AddRunRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddRun")
+	defer span.End()
+
+	err := e.engine.AddRun(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateRun(r UpdateRunRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateRun")
+	defer span.End()
+
+	err := e.engine.UpdateRun(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllTaskRuns(r GetAllTaskRunsRequest) ([]*models.TaskRun, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllTaskRuns")
+	defer span.End()
+
+	result, err := e.engine.GetAllTaskRuns(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetTaskRun(r GetTaskRunRequest) (*models.TaskRun, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetTaskRun")
+	defer span.End()
+
+	result, err := e.engine.GetTaskRun(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddTaskRun(r AddTaskRunRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddTaskRun")
+	defer span.End()
+
+	err := e.engine.AddTaskRun(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateTaskRun(r UpdateTaskRunRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateTaskRun")
+	defer span.End()
+
+	err := e.engine.UpdateTaskRun(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllArtifacts(r GetAllArtifactsRequest) ([]*models.Artifact, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllArtifacts")
+	defer span.End()
+
+	result, err := e.engine.GetAllArtifacts(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetArtifact(r GetArtifactRequest) (*models.Artifact, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetArtifact")
+	defer span.End()
+
+	result, err := e.engine.GetArtifact(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddArtifact(r AddArtifactRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddArtifact")
+	defer span.End()
+
+	err := e.engine.AddArtifact(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateArtifact(r UpdateArtifactRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateArtifact")
+	defer span.End()
+
+	err := e.engine.UpdateArtifact(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllCaches(r GetAllCachesRequest) ([]*models.Cache, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllCaches")
+	defer span.End()
+
+	result, err := e.engine.GetAllCaches(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetCache(r GetCacheRequest) (*models.Cache, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetCache")
+	defer span.End()
+
+	result, err := e.engine.GetCache(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddCache(r AddCacheRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddCache")
+	defer span.End()
+
+	err := e.engine.AddCache(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateCache(r UpdateCacheRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateCache")
+	defer span.End()
+
+	err := e.engine.UpdateCache(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteCache(r DeleteCacheRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteCache")
+	defer span.End()
+
+	err := e.engine.DeleteCache(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllEvents(r GetAllEventsRequest) ([]models.Event, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllEvents")
+	defer span.End()
+
+	result, err := e.engine.GetAllEvents(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetEvent(r GetEventRequest) (models.Event, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetEvent")
+	defer span.End()
+
+	result, err := e.engine.GetEvent(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddEvent(r AddEventRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddEvent")
+	defer span.End()
+
+	err := e.engine.AddEvent(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteEvent(r DeleteEventRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteEvent")
+	defer span.End()
+
+	err := e.engine.DeleteEvent(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllEventCursors(r GetAllEventCursorsRequest) ([]*models.EventCursor, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllEventCursors")
+	defer span.End()
+
+	result, err := e.engine.GetAllEventCursors(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetEventCursor(r GetEventCursorRequest) (*models.EventCursor, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetEventCursor")
+	defer span.End()
+
+	result, err := e.engine.GetEventCursor(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) UpdateEventCursor(r UpdateEventCursorRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateEventCursor")
+	defer span.End()
+
+	err := e.engine.UpdateEventCursor(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteEventCursor(r DeleteEventCursorRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteEventCursor")
+	defer span.End()
+
+	err := e.engine.DeleteEventCursor(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllDeadLetters(r GetAllDeadLettersRequest) ([]*models.DeadLetter, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllDeadLetters")
+	defer span.End()
+
+	result, err := e.engine.GetAllDeadLetters(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetDeadLetter(r GetDeadLetterRequest) (*models.DeadLetter, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetDeadLetter")
+	defer span.End()
+
+	result, err := e.engine.GetDeadLetter(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddDeadLetter(r AddDeadLetterRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddDeadLetter")
+	defer span.End()
+
+	err := e.engine.AddDeadLetter(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateDeadLetter(r UpdateDeadLetterRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateDeadLetter")
+	defer span.End()
+
+	err := e.engine.UpdateDeadLetter(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteDeadLetter(r DeleteDeadLetterRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteDeadLetter")
+	defer span.End()
+
+	err := e.engine.DeleteDeadLetter(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllWebhooks(r GetAllWebhooksRequest) ([]*models.Webhook, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllWebhooks")
+	defer span.End()
+
+	result, err := e.engine.GetAllWebhooks(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetWebhook(r GetWebhookRequest) (*models.Webhook, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetWebhook")
+	defer span.End()
+
+	result, err := e.engine.GetWebhook(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddWebhook(r AddWebhookRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddWebhook")
+	defer span.End()
+
+	err := e.engine.AddWebhook(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteWebhook(r DeleteWebhookRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteWebhook")
+	defer span.End()
+
+	err := e.engine.DeleteWebhook(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllWebhookDeliveries(r GetAllWebhookDeliveriesRequest) ([]*models.WebhookDelivery, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllWebhookDeliveries")
+	defer span.End()
+
+	result, err := e.engine.GetAllWebhookDeliveries(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddWebhookDelivery(r AddWebhookDeliveryRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddWebhookDelivery")
+	defer span.End()
+
+	err := e.engine.AddWebhookDelivery(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateWebhookDelivery(r UpdateWebhookDeliveryRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateWebhookDelivery")
+	defer span.End()
+
+	err := e.engine.UpdateWebhookDelivery(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteWebhookDeliveries(r DeleteWebhookDeliveriesRequest) (int, error) {
+	_, span := tracing.Start(e.ctx, "storage.DeleteWebhookDeliveries")
+	defer span.End()
+
+	result, err := e.engine.DeleteWebhookDeliveries(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetAllRegistryCredentials(r GetAllRegistryCredentialsRequest) ([]*models.RegistryCredential, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllRegistryCredentials")
+	defer span.End()
+
+	result, err := e.engine.GetAllRegistryCredentials(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddRegistryCredential(r AddRegistryCredentialRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddRegistryCredential")
+	defer span.End()
+
+	err := e.engine.AddRegistryCredential(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) UpdateRegistryCredential(r UpdateRegistryCredentialRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.UpdateRegistryCredential")
+	defer span.End()
+
+	err := e.engine.UpdateRegistryCredential(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteRegistryCredential(r DeleteRegistryCredentialRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteRegistryCredential")
+	defer span.End()
+
+	err := e.engine.DeleteRegistryCredential(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllTriggers(r GetAllTriggersRequest) ([]*config.Trigger, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllTriggers")
+	defer span.End()
+
+	result, err := e.engine.GetAllTriggers(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetTrigger(r GetTriggerRequest) (*config.Trigger, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetTrigger")
+	defer span.End()
+
+	result, err := e.engine.GetTrigger(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddTrigger(r AddTriggerRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddTrigger")
+	defer span.End()
+
+	err := e.engine.AddTrigger(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteTrigger(r DeleteTriggerRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteTrigger")
+	defer span.End()
+
+	err := e.engine.DeleteTrigger(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) GetAllNotifiers(r GetAllNotifiersRequest) ([]*config.Notifier, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetAllNotifiers")
+	defer span.End()
+
+	result, err := e.engine.GetAllNotifiers(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) GetNotifier(r GetNotifierRequest) (*config.Notifier, error) {
+	_, span := tracing.Start(e.ctx, "storage.GetNotifier")
+	defer span.End()
+
+	result, err := e.engine.GetNotifier(r)
+	span.RecordError(err)
+	return result, err
+}
+
+func (e *TracedEngine) AddNotifier(r AddNotifierRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.AddNotifier")
+	defer span.End()
+
+	err := e.engine.AddNotifier(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) DeleteNotifier(r DeleteNotifierRequest) error {
+	_, span := tracing.Start(e.ctx, "storage.DeleteNotifier")
+	defer span.End()
+
+	err := e.engine.DeleteNotifier(r)
+	span.RecordError(err)
+	return err
+}
+
+func (e *TracedEngine) Backup(w io.Writer) error {
+	_, span := tracing.Start(e.ctx, "storage.Backup")
+	defer span.End()
+
+	err := e.engine.Backup(w)
+	span.RecordError(err)
+	return err
+}
@@ -0,0 +1,574 @@
+// Package tracing records the work the server does as spans and exports them to an OTLP endpoint(ex. an
+// OpenTelemetry collector).
+//
+// Spans are sent in batches using OTLP over HTTP with JSON encoding
+// (https://opentelemetry.io/docs/specs/otlp/#otlphttp). Exporting never blocks the work being traced; if the endpoint
+// can't keep up spans are dropped instead.
+//
+// Tracing is turned off until Init is called. While it's off, starting a span returns a nil span whose methods do
+// nothing, so code can be instrumented without checking whether tracing is on.
+package tracing
+
+import (
+	"bytes"
+	"context"
+	"crypto/rand"
+	"crypto/sha256"
+	"encoding/binary"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+)
+
+// queueSize is how many spans are held waiting to be sent, as a multiple of the batch size.
+const queueSize = 10
+
+// tracer is the active tracer; nil means tracing is turned off.
+var tracer *Tracer
+
+// Tracer records finished spans and sends them to an OTLP endpoint.
+type Tracer struct {
+	endpoint      string
+	headers       map[string]string
+	serviceName   string
+	sampleRatio   float64
+	batchSize     int
+	flushInterval time.Duration
+	client        *http.Client
+
+	spans chan span
+	stop  chan struct{}
+	wg    sync.WaitGroup
+
+	// failing tracks whether the last export failed so that failures are only reported once until exports recover.
+	failing bool
+}
+
+// Init turns on tracing. It must be called before anything is traced; Close should be called on shutdown to send any
+// remaining spans.
+func Init(conf *config.Tracing) *Tracer {
+	tracer = &Tracer{
+		endpoint:      conf.Endpoint,
+		headers:       conf.Headers,
+		serviceName:   conf.ServiceName,
+		sampleRatio:   conf.SampleRatio,
+		batchSize:     conf.BatchSize,
+		flushInterval: conf.FlushInterval,
+		client:        &http.Client{Timeout: 10 * time.Second},
+		spans:         make(chan span, conf.BatchSize*queueSize),
+		stop:          make(chan struct{}),
+	}
+
+	tracer.wg.Add(1)
+	go tracer.run()
+
+	return tracer
+}
+
+// Enabled reports whether tracing is turned on.
+func Enabled() bool {
+	return tracer != nil
+}
+
+// Close sends the spans that are still queued and stops the tracer.
+func (t *Tracer) Close() error {
+	close(t.stop)
+	t.wg.Wait()
+	return nil
+}
+
+// SpanContext identifies a span within a trace.
+type SpanContext struct {
+	TraceID [16]byte
+	SpanID  [8]byte
+	Sampled bool // Whether spans within the trace are recorded.
+}
+
+// IsValid reports whether the span context identifies a span.
+func (sc SpanContext) IsValid() bool {
+	return sc.TraceID != [16]byte{} && sc.SpanID != [8]byte{}
+}
+
+// Traceparent formats the span context as a W3C traceparent header(https://www.w3.org/TR/trace-context/).
+func (sc SpanContext) Traceparent() string {
+	flags := "00"
+	if sc.Sampled {
+		flags = "01"
+	}
+
+	return fmt.Sprintf("00-%x-%x-%s", sc.TraceID, sc.SpanID, flags)
+}
+
+// ParseTraceparent reads a span context from a W3C traceparent header. Returns false if the header is malformed.
+func ParseTraceparent(traceparent string) (SpanContext, bool) {
+	parts := strings.Split(strings.TrimSpace(traceparent), "-")
+	if len(parts) < 4 || len(parts[1]) != 32 || len(parts[2]) != 16 || len(parts[3]) != 2 {
+		return SpanContext{}, false
+	}
+
+	sc := SpanContext{}
+	if _, err := hex.Decode(sc.TraceID[:], []byte(parts[1])); err != nil {
+		return SpanContext{}, false
+	}
+	if _, err := hex.Decode(sc.SpanID[:], []byte(parts[2])); err != nil {
+		return SpanContext{}, false
+	}
+
+	flags, err := strconv.ParseUint(parts[3], 16, 8)
+	if err != nil {
+		return SpanContext{}, false
+	}
+	sc.Sampled = flags&1 == 1
+
+	if !sc.IsValid() {
+		return SpanContext{}, false
+	}
+
+	return sc, true
+}
+
+type spanContextKey struct{}
+
+type spanKey struct{}
+
+// ContextWithSpanContext returns a context whose spans are children of the given span.
+func ContextWithSpanContext(ctx context.Context, sc SpanContext) context.Context {
+	return context.WithValue(ctx, spanContextKey{}, sc)
+}
+
+// SpanContextFromContext returns the span context of the span within the context, if any.
+func SpanContextFromContext(ctx context.Context) (SpanContext, bool) {
+	sc, ok := ctx.Value(spanContextKey{}).(SpanContext)
+	return sc, ok && sc.IsValid()
+}
+
+// SpanFromContext returns the span started within the context. Returns nil if there is none or it isn't recorded.
+func SpanFromContext(ctx context.Context) *Span {
+	span, _ := ctx.Value(spanKey{}).(*Span)
+	return span
+}
+
+// Kind describes the relationship of a span to the work around it.
+type Kind int
+
+const (
+	KindInternal Kind = 1 // An operation within the server.
+	KindServer   Kind = 2 // The handling of a request made to the server.
+)
+
+// Start starts a span as a child of the span within the context. A new trace is started if the context has no span.
+// The returned context holds the new span so that spans started with it become its children.
+func Start(ctx context.Context, name string, attributes ...Attribute) (context.Context, *Span) {
+	return StartWithKind(ctx, name, KindInternal, attributes...)
+}
+
+// StartWithKind is Start for spans that aren't internal operations.
+func StartWithKind(ctx context.Context, name string, kind Kind, attributes ...Attribute) (context.Context, *Span) {
+	if tracer == nil {
+		return ctx, nil
+	}
+
+	sc := SpanContext{}
+	parent, hasParent := SpanContextFromContext(ctx)
+	if hasParent {
+		sc.TraceID = parent.TraceID
+		sc.Sampled = parent.Sampled
+	} else {
+		_, _ = rand.Read(sc.TraceID[:])
+		sc.Sampled = tracer.sample(sc.TraceID)
+	}
+	_, _ = rand.Read(sc.SpanID[:])
+
+	return start(ctx, name, kind, sc, parent.SpanID, attributes)
+}
+
+func start(ctx context.Context, name string, kind Kind, sc SpanContext, parent [8]byte,
+	attributes []Attribute,
+) (context.Context, *Span) {
+	ctx = ContextWithSpanContext(ctx, sc)
+	if !sc.Sampled {
+		return ctx, nil
+	}
+
+	span := &Span{
+		name:       name,
+		kind:       kind,
+		context:    sc,
+		parent:     parent,
+		start:      time.Now(),
+		attributes: attributes,
+	}
+
+	return context.WithValue(ctx, spanKey{}, span), span
+}
+
+// sample decides whether a new trace is recorded. The decision is derived from the trace ID so that it's the same
+// wherever it's made.
+func (t *Tracer) sample(traceID [16]byte) bool {
+	if t.sampleRatio >= 1 {
+		return true
+	}
+
+	return float64(binary.BigEndian.Uint64(traceID[8:])>>11)/(1<<53) < t.sampleRatio
+}
+
+// RunSpanContext returns the span context of the span covering the run with the given correlation ID. Every run has
+// a trace of its own whose ID is taken from the run's correlation ID, so work done on behalf of a run can join its
+// trace from anywhere without a context having to be passed along.
+func RunSpanContext(correlationID string) SpanContext {
+	sc := SpanContext{}
+
+	// Correlation IDs are random 16 byte hex strings, exactly like trace IDs. Anything else is hashed into shape.
+	decoded, err := hex.DecodeString(correlationID)
+	if err == nil && len(decoded) == len(sc.TraceID) {
+		copy(sc.TraceID[:], decoded)
+	} else {
+		sum := sha256.Sum256([]byte(correlationID))
+		copy(sc.TraceID[:], sum[:16])
+	}
+
+	sum := sha256.Sum256([]byte("run:" + correlationID))
+	copy(sc.SpanID[:], sum[:8])
+
+	if tracer != nil {
+		sc.Sampled = tracer.sample(sc.TraceID)
+	}
+
+	return sc
+}
+
+// RunContext returns a context whose spans are children of the span covering the run with the given correlation ID.
+func RunContext(correlationID string) context.Context {
+	return ContextWithSpanContext(context.Background(), RunSpanContext(correlationID))
+}
+
+// StartRun starts the span covering the run with the given correlation ID. It's the root of the run's trace.
+func StartRun(correlationID, name string, attributes ...Attribute) (context.Context, *Span) {
+	if tracer == nil {
+		return RunContext(correlationID), nil
+	}
+
+	return start(context.Background(), name, KindInternal, RunSpanContext(correlationID), [8]byte{}, attributes)
+}
+
+// Span is a single operation within a trace. A nil span is valid; all of its methods do nothing.
+type Span struct {
+	mu         sync.Mutex
+	name       string
+	kind       Kind
+	context    SpanContext
+	parent     [8]byte
+	start      time.Time
+	attributes []Attribute
+	links      []SpanContext
+	err        error
+	ended      bool
+}
+
+// SpanContext returns the identity of the span.
+func (s *Span) SpanContext() SpanContext {
+	if s == nil {
+		return SpanContext{}
+	}
+
+	return s.context
+}
+
+// SetAttributes adds attributes to the span, replacing attributes with the same key.
+func (s *Span) SetAttributes(attributes ...Attribute) {
+	if s == nil {
+		return
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	for _, attribute := range attributes {
+		replaced := false
+		for i := range s.attributes {
+			if s.attributes[i].key == attribute.key {
+				s.attributes[i] = attribute
+				replaced = true
+			}
+		}
+
+		if !replaced {
+			s.attributes = append(s.attributes, attribute)
+		}
+	}
+}
+
+// AddLink relates the span to a span in another trace(ex. the request that started a run).
+func (s *Span) AddLink(sc SpanContext) {
+	if s == nil || !sc.IsValid() {
+		return
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.links = append(s.links, sc)
+}
+
+// RecordError marks the span as failed. A nil error is ignored so that the result of any call can be passed in.
+func (s *Span) RecordError(err error) {
+	if s == nil || err == nil {
+		return
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.err = err
+}
+
+// End finishes the span and queues it to be sent. Only the first call has any effect.
+func (s *Span) End() {
+	if s == nil || tracer == nil {
+		return
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	if s.ended {
+		return
+	}
+	s.ended = true
+
+	select {
+	case tracer.spans <- s.export(time.Now()):
+	default:
+	}
+}
+
+func (s *Span) export(end time.Time) span {
+	exported := span{
+		TraceID:           hex.EncodeToString(s.context.TraceID[:]),
+		SpanID:            hex.EncodeToString(s.context.SpanID[:]),
+		Name:              s.name,
+		Kind:              int(s.kind),
+		StartTimeUnixNano: strconv.FormatInt(s.start.UnixNano(), 10),
+		EndTimeUnixNano:   strconv.FormatInt(end.UnixNano(), 10),
+		Attributes:        []attribute{},
+		Links:             []link{},
+	}
+
+	if s.parent != [8]byte{} {
+		exported.ParentSpanID = hex.EncodeToString(s.parent[:])
+	}
+
+	for _, a := range s.attributes {
+		exported.Attributes = append(exported.Attributes, attribute{Key: a.key, Value: a.value})
+	}
+
+	for _, sc := range s.links {
+		exported.Links = append(exported.Links, link{
+			TraceID: hex.EncodeToString(sc.TraceID[:]),
+			SpanID:  hex.EncodeToString(sc.SpanID[:]),
+		})
+	}
+
+	if s.err != nil {
+		exported.Status = status{Code: statusCodeError, Message: s.err.Error()}
+	}
+
+	return exported
+}
+
+// Attribute describes a span.
+type Attribute struct {
+	key   string
+	value attributeValue
+}
+
+func String(key, value string) Attribute {
+	return Attribute{key: key, value: attributeValue{StringValue: &value}}
+}
+
+func Int64(key string, value int64) Attribute {
+	intValue := strconv.FormatInt(value, 10)
+	return Attribute{key: key, value: attributeValue{IntValue: &intValue}}
+}
+
+func Bool(key string, value bool) Attribute {
+	return Attribute{key: key, value: attributeValue{BoolValue: &value}}
+}
+
+func (t *Tracer) run() {
+	defer t.wg.Done()
+
+	ticker := time.NewTicker(t.flushInterval)
+	defer ticker.Stop()
+
+	batch := []span{}
+
+	for {
+		select {
+		case exported := <-t.spans:
+			batch = append(batch, exported)
+			if len(batch) < t.batchSize {
+				continue
+			}
+		case <-ticker.C:
+		case <-t.stop:
+			t.drain(batch)
+			return
+		}
+
+		t.export(batch)
+		batch = []span{}
+	}
+}
+
+// drain sends the given batch along with every span that is still queued.
+func (t *Tracer) drain(batch []span) {
+	for {
+		select {
+		case exported := <-t.spans:
+			batch = append(batch, exported)
+			if len(batch) >= t.batchSize {
+				t.export(batch)
+				batch = []span{}
+			}
+		default:
+			t.export(batch)
+			return
+		}
+	}
+}
+
+// export sends a batch of spans. Failures are written to stderr rather than logged since logs may themselves be
+// exported to the same collector.
+func (t *Tracer) export(batch []span) {
+	if len(batch) == 0 {
+		return
+	}
+
+	err := t.send(batch)
+	if err != nil {
+		if !t.failing {
+			fmt.Fprintf(os.Stderr, "could not export spans to %s: %v\n", t.endpoint, err)
+		}
+		t.failing = true
+		return
+	}
+
+	if t.failing {
+		fmt.Fprintf(os.Stderr, "exporting spans to %s has recovered\n", t.endpoint)
+	}
+	t.failing = false
+}
+
+func (t *Tracer) send(batch []span) error {
+	body, err := json.Marshal(exportTraceRequest{
+		ResourceSpans: []resourceSpans{{
+			Resource: resource{
+				Attributes: []attribute{{Key: "service.name", Value: attributeValue{StringValue: &t.serviceName}}},
+			},
+			ScopeSpans: []scopeSpans{{
+				Scope: scope{Name: "gofer"},
+				Spans: batch,
+			}},
+		}},
+	})
+	if err != nil {
+		return err
+	}
+
+	request, err := http.NewRequest(http.MethodPost, t.endpoint, bytes.NewReader(body))
+	if err != nil {
+		return err
+	}
+
+	request.Header.Set("Content-Type", "application/json")
+	for key, value := range t.headers {
+		request.Header.Set(key, value)
+	}
+
+	response, err := t.client.Do(request)
+	if err != nil {
+		return err
+	}
+	defer response.Body.Close()
+
+	responseBody, _ := io.ReadAll(io.LimitReader(response.Body, 1<<16))
+
+	if response.StatusCode < 200 || response.StatusCode > 299 {
+		return fmt.Errorf("received status %d: %s", response.StatusCode, bytes.TrimSpace(responseBody))
+	}
+
+	return nil
+}
+
+// The types below mirror the JSON encoding of the OTLP ExportTraceServiceRequest message. Trace and span IDs are
+// encoded as hex strings in OTLP's JSON encoding.
+
+const statusCodeError = 2
+
+type exportTraceRequest struct {
+	ResourceSpans []resourceSpans `json:"resourceSpans"`
+}
+
+type resourceSpans struct {
+	Resource   resource     `json:"resource"`
+	ScopeSpans []scopeSpans `json:"scopeSpans"`
+}
+
+type resource struct {
+	Attributes []attribute `json:"attributes"`
+}
+
+type scopeSpans struct {
+	Scope scope  `json:"scope"`
+	Spans []span `json:"spans"`
+}
+
+type scope struct {
+	Name string `json:"name"`
+}
+
+type span struct {
+	TraceID           string      `json:"traceId"`
+	SpanID            string      `json:"spanId"`
+	ParentSpanID      string      `json:"parentSpanId,omitempty"`
+	Name              string      `json:"name"`
+	Kind              int         `json:"kind"`
+	StartTimeUnixNano string      `json:"startTimeUnixNano"`
+	EndTimeUnixNano   string      `json:"endTimeUnixNano"`
+	Attributes        []attribute `json:"attributes"`
+	Links             []link      `json:"links"`
+	Status            status      `json:"status"`
+}
+
+type link struct {
+	TraceID string `json:"traceId"`
+	SpanID  string `json:"spanId"`
+}
+
+type status struct {
+	Code    int    `json:"code,omitempty"`
+	Message string `json:"message,omitempty"`
+}
+
+type attribute struct {
+	Key   string         `json:"key"`
+	Value attributeValue `json:"value"`
+}
+
+// attributeValue is the OTLP AnyValue message; only one field is ever set. 64 bit integers are encoded as strings
+// in OTLP's JSON encoding.
+type attributeValue struct {
+	StringValue *string `json:"stringValue,omitempty"`
+	BoolValue   *bool   `json:"boolValue,omitempty"`
+	IntValue    *string `json:"intValue,omitempty"`
+}
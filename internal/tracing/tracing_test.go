@@ -0,0 +1,100 @@
+package tracing
+
+import (
+	"encoding/json"
+	"errors"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/clintjedwards/gofer/internal/config"
+)
+
+func TestRunTrace(t *testing.T) {
+	requests := make(chan exportTraceRequest, 1)
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+
+		request := exportTraceRequest{}
+		err := json.Unmarshal(body, &request)
+		if err != nil {
+			t.Error(err)
+		}
+
+		requests <- request
+	}))
+	defer server.Close()
+
+	Init(&config.Tracing{
+		Endpoint:      server.URL + "/v1/traces",
+		ServiceName:   "gofer",
+		SampleRatio:   1,
+		BatchSize:     10,
+		FlushInterval: time.Hour,
+	})
+	defer func() { tracer = nil }()
+
+	correlationID := "0af7651916cd43dd8448eb211c80319c"
+
+	_, runSpan := StartRun(correlationID, "run")
+
+	// Task runs join the run's trace without being handed its context.
+	_, taskRunSpan := Start(RunContext(correlationID), "task_run", String("task", "build"))
+	taskRunSpan.RecordError(errors.New("exit code 1"))
+	taskRunSpan.End()
+	runSpan.End()
+
+	err := tracer.Close()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	request := <-requests
+	spans := request.ResourceSpans[0].ScopeSpans[0].Spans
+
+	if len(spans) != 2 {
+		t.Fatalf("expected 2 spans; got %d", len(spans))
+	}
+
+	taskRun, run := spans[0], spans[1]
+
+	if run.TraceID != correlationID || taskRun.TraceID != correlationID {
+		t.Errorf("expected both spans to use the correlation ID as trace ID; got %q and %q", run.TraceID, taskRun.TraceID)
+	}
+
+	if run.ParentSpanID != "" {
+		t.Errorf("expected run span to be the root of the trace; got parent %q", run.ParentSpanID)
+	}
+
+	if taskRun.ParentSpanID != run.SpanID {
+		t.Errorf("expected task run span to be a child of the run span %q; got parent %q", run.SpanID, taskRun.ParentSpanID)
+	}
+
+	if taskRun.Status.Code != statusCodeError || taskRun.Status.Message != "exit code 1" {
+		t.Errorf("expected task run span to be failed; got %+v", taskRun.Status)
+	}
+}
+
+func TestParseTraceparent(t *testing.T) {
+	traceparent := "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+
+	sc, ok := ParseTraceparent(traceparent)
+	if !ok {
+		t.Fatal("expected traceparent to be parsed")
+	}
+
+	if !sc.Sampled {
+		t.Error("expected span context to be sampled")
+	}
+
+	if sc.Traceparent() != traceparent {
+		t.Errorf("expected %q; got %q", traceparent, sc.Traceparent())
+	}
+
+	if _, ok := ParseTraceparent("00-0af7651916cd43dd-b7ad6b7169203331-01"); ok {
+		t.Error("expected malformed traceparent to be rejected")
+	}
+}
@@ -1,9 +1,21 @@
 package main
 
 import (
+	"fmt"
+	"os"
+
 	"github.com/clintjedwards/gofer/internal/cli"
+	"github.com/clintjedwards/gofer/internal/cli/cl"
 )
 
 func main() {
-	_ = cli.RootCmd.Execute()
+	err := cli.RootCmd.Execute()
+	if err != nil {
+		// Structured output silences the formatter which would normally print the error, so it is printed here
+		// instead; on stderr so it never ends up in the data a script is reading.
+		if cl.State != nil && cl.State.Structured() {
+			fmt.Fprintln(os.Stderr, err)
+		}
+		os.Exit(1)
+	}
 }
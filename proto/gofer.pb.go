@@ -24,7 +24,7 @@ var File_gofer_proto protoreflect.FileDescriptor
 var file_gofer_proto_rawDesc = []byte{
 	0x0a, 0x0b, 0x67, 0x6f, 0x66, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x70,
 	0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x15, 0x67, 0x6f, 0x66, 0x65, 0x72, 0x5f, 0x74, 0x72, 0x61, 0x6e,
-	0x73, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x32, 0xfe, 0x1e, 0x0a, 0x05,
+	0x73, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x32, 0xf1, 0x40, 0x0a, 0x05,
 	0x47, 0x6f, 0x66, 0x65, 0x72, 0x12, 0x4d, 0x0a, 0x0e, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x61, 0x6d,
 	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x12, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
 	0x4c, 0x69, 0x73, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x52, 0x65,
@@ -50,339 +50,704 @@ var file_gofer_proto_rawDesc = []byte{
 	0x65, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75,
 	0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65,
 	0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x4c, 0x69, 0x73,
-	0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x52, 0x65, 0x73,
-	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4d, 0x0a, 0x0e, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65,
-	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x6e,
-	0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x0f, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52,
-	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44,
-	0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
-	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x12, 0x1f, 0x2e, 0x70, 0x72,
-	0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c,
-	0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c,
-	0x0a, 0x13, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x42, 0x79, 0x55, 0x52, 0x4c, 0x12, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72,
-	0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52,
-	0x4c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42,
-	0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11,
-	0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61,
-	0x77, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65,
-	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74,
-	0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c, 0x0a, 0x13, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x12, 0x21, 0x2e, 0x70, 0x72,
+	0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75, 0x6f,
+	0x74, 0x61, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75,
+	0x6f, 0x74, 0x61, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5f, 0x0a, 0x14, 0x55,
+	0x70, 0x64, 0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75,
+	0x6f, 0x74, 0x61, 0x12, 0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61,
+	0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51,
+	0x75, 0x6f, 0x74, 0x61, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b,
+	0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x19, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47,
+	0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x73, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4d,
+	0x0a, 0x0e, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x12, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a,
+	0x0f, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x56, 0x0a, 0x11, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x52, 0x61, 0x77, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c, 0x0a, 0x13, 0x43, 0x72, 0x65, 0x61, 0x74,
+	0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x12, 0x21,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72,
 	0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x22,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70,
-	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x12, 0x50, 0x0a, 0x0f, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70,
-	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x62,
-	0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x62, 0x61,
-	0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67,
-	0x65, 0x72, 0x12, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x72,
-	0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x4c, 0x69, 0x73, 0x74, 0x54,
-	0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x4c, 0x69, 0x73, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74,
-	0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x4d, 0x0a, 0x0e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67,
-	0x65, 0x72, 0x12, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x49, 0x6e, 0x73, 0x74, 0x61,
-	0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x1a, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c,
-	0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-	0x53, 0x0a, 0x10, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67,
-	0x67, 0x65, 0x72, 0x12, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x6e, 0x69, 0x6e,
+	0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c, 0x0a,
+	0x13, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42,
+	0x79, 0x55, 0x52, 0x4c, 0x12, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64,
+	0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79,
+	0x55, 0x52, 0x4c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x71, 0x0a, 0x1a, 0x4c,
+	0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69,
+	0x67, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x28, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x6f,
+	0x6e, 0x66, 0x69, 0x67, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x29, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x56, 0x65,
+	0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x65,
+	0x0a, 0x16, 0x52, 0x6f, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x24, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x52, 0x6f, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x25,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x6f, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c, 0x0a, 0x13, 0x53, 0x74, 0x61, 0x72, 0x74, 0x50, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x12, 0x21, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x62, 0x0a, 0x15, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74, 0x65, 0x50, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x12, 0x23, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x24, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74,
+	0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c, 0x0a, 0x13, 0x41, 0x62, 0x6f, 0x72, 0x74,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x12, 0x21,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x50, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x0f, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x54, 0x72,
+	0x69, 0x67, 0x67, 0x65, 0x72, 0x12, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65,
+	0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x4c, 0x69,
+	0x73, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c,
+	0x69, 0x73, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x4d, 0x0a, 0x0e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72,
+	0x69, 0x67, 0x67, 0x65, 0x72, 0x12, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x49, 0x6e,
 	0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x1a, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x6e, 0x69, 0x6e,
-	0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66,
-	0x69, 0x65, 0x72, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x4e,
-	0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69,
-	0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x4c, 0x69,
-	0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x73, 0x12, 0x1b, 0x2e, 0x70, 0x72,
+	0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x49, 0x6e, 0x73, 0x74,
+	0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x53, 0x0a, 0x10, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54,
+	0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x12, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55,
+	0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55,
+	0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x4e, 0x6f,
+	0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47,
+	0x65, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x4e, 0x6f, 0x74,
+	0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a,
+	0x0d, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x73, 0x12, 0x1b,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66,
+	0x69, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72,
 	0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
-	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x73, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x0f, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c,
-	0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x2e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65,
-	0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11, 0x55, 0x6e, 0x69, 0x6e,
-	0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x12, 0x1f, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e,
-	0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c,
-	0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x3b, 0x0a, 0x08, 0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x16, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x43, 0x0a,
-	0x0a, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x18, 0x2e, 0x70, 0x72,
-	0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x52, 0x65,
-	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69,
-	0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x30, 0x01, 0x12, 0x35, 0x0a, 0x06, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x12, 0x14, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x75,
-	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x42, 0x61, 0x74,
-	0x63, 0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65,
-	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x42, 0x61,
-	0x74, 0x63, 0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x12, 0x3b, 0x0a, 0x08, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x12, 0x16,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52,
-	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c,
-	0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-	0x3b, 0x0a, 0x08, 0x53, 0x74, 0x61, 0x72, 0x74, 0x52, 0x75, 0x6e, 0x12, 0x16, 0x2e, 0x70, 0x72,
-	0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x74, 0x61, 0x72,
-	0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x08,
-	0x52, 0x65, 0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x12, 0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x52, 0x65, 0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x1a, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x74, 0x72, 0x79, 0x52, 0x75,
-	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3e, 0x0a, 0x09, 0x43, 0x61, 0x6e,
-	0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x12, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43,
-	0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
-	0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75,
-	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x43, 0x61, 0x6e,
-	0x63, 0x65, 0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75, 0x6e, 0x73, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75, 0x6e, 0x73,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73,
-	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b,
-	0x52, 0x75, 0x6e, 0x12, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54,
-	0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x4c, 0x69, 0x73, 0x74,
-	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73,
-	0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x54, 0x61, 0x73, 0x6b, 0x52,
-	0x75, 0x6e, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65,
-	0x6c, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
-	0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x54, 0x61,
-	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a,
-	0x0e, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x12,
-	0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52,
-	0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x0f, 0x49, 0x6e, 0x73,
+	0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x12, 0x1d, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69,
+	0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66,
+	0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11, 0x55,
+	0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+	0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61,
+	0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74,
+	0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x08, 0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12,
+	0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x43, 0x0a, 0x0a, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x18,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x30, 0x01, 0x12, 0x53, 0x0a, 0x10, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c,
+	0x65, 0x64, 0x67, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x45, 0x76, 0x65,
+	0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x45, 0x76, 0x65,
+	0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x62, 0x0a, 0x15, 0x50, 0x75,
+	0x62, 0x6c, 0x69, 0x73, 0x68, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x76,
+	0x65, 0x6e, 0x74, 0x12, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x62, 0x6c,
+	0x69, 0x73, 0x68, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x76, 0x65, 0x6e,
+	0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x24, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x73, 0x68, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f,
+	0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x59,
+	0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75,
+	0x6d, 0x65, 0x72, 0x73, 0x12, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73,
+	0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c,
+	0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c, 0x0a, 0x13, 0x44, 0x65, 0x6c,
+	0x65, 0x74, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72,
+	0x12, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65,
+	0x74, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x06, 0x47, 0x65, 0x74, 0x52, 0x75,
+	0x6e, 0x12, 0x14, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47,
+	0x0a, 0x0c, 0x42, 0x61, 0x74, 0x63, 0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x12, 0x1a,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x47, 0x65, 0x74, 0x52,
+	0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x08, 0x4c, 0x69, 0x73, 0x74, 0x52,
+	0x75, 0x6e, 0x73, 0x12, 0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+	0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x08, 0x53, 0x74, 0x61, 0x72, 0x74, 0x52, 0x75, 0x6e,
+	0x12, 0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x52, 0x75,
+	0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x3b, 0x0a, 0x08, 0x52, 0x65, 0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x12, 0x16, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65,
+	0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3e,
+	0x0a, 0x09, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x12, 0x17, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e,
+	0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a,
+	0x0a, 0x0d, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75, 0x6e, 0x73, 0x12,
+	0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x6c,
+	0x6c, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75,
+	0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x0a, 0x47, 0x65,
+	0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x12, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x61,
+	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a,
+	0x0c, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x12, 0x1a, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75,
+	0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c,
+	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x61, 0x6e,
+	0x63, 0x65, 0x6c, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x53, 0x70, 0x61, 0x77, 0x6e, 0x54, 0x61, 0x73, 0x6b, 0x52,
+	0x75, 0x6e, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x70, 0x61, 0x77, 0x6e,
+	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x70, 0x61, 0x77, 0x6e, 0x54, 0x61, 0x73, 0x6b,
+	0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x0e, 0x47,
+	0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x12, 0x1c, 0x2e,
 	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e,
-	0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x30, 0x01, 0x12, 0x56,
-	0x0a, 0x11, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c,
-	0x6f, 0x67, 0x73, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65,
-	0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c,
-	0x65, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70,
-	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x1f, 0x2e, 0x70, 0x72,
+	0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f,
+	0x67, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x30, 0x01, 0x12, 0x56, 0x0a, 0x11,
+	0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67,
+	0x73, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
+	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74,
+	0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x52, 0x0a, 0x0f, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f,
+	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41,
+	0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4f, 0x75,
+	0x74, 0x70, 0x75, 0x74, 0x28, 0x01, 0x30, 0x01, 0x12, 0x56, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x1f, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x6a, 0x0a, 0x17, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f,
+	0x62, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x12, 0x25, 0x2e, 0x70, 0x72,
 	0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f,
-	0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56,
-	0x0a, 0x11, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a,
-	0x65, 0x63, 0x74, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74,
-	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5f, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
-	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x22,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x69, 0x70,
+	0x62, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x26, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65,
+	0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x30, 0x01, 0x12, 0x56, 0x0a, 0x11,
+	0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63,
+	0x74, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70,
 	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74,
-	0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x47, 0x65, 0x74, 0x52, 0x75,
-	0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x52,
-	0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x47, 0x0a, 0x0c, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
-	0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f,
-	0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63,
-	0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x0f, 0x44, 0x65, 0x6c,
-	0x65, 0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x1d, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62,
-	0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72,
+	0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x50, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5e, 0x0a, 0x17, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x12,
+	0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x28, 0x01, 0x12, 0x5f, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x22, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x4f,
+	0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65,
+	0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e,
+	0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47,
+	0x0a, 0x0c, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x1a,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a,
+	0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x12, 0x50, 0x75, 0x74, 0x52, 0x75,
+	0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x12, 0x1a, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65,
+	0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x28, 0x01, 0x12, 0x50, 0x0a, 0x0f, 0x44, 0x65, 0x6c, 0x65,
+	0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x1d, 0x2e, 0x70, 0x72,
 	0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a,
-	0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3e, 0x0a, 0x09, 0x47,
-	0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x12, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x47, 0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x1a, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x65, 0x63,
-	0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3e, 0x0a, 0x09, 0x50,
-	0x75, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x12, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x50, 0x75, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x1a, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x53, 0x65, 0x63,
-	0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c, 0x44,
-	0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x12, 0x1a, 0x2e, 0x70, 0x72,
-	0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65,
-	0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65,
-	0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x79,
-	0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x47, 0x0a, 0x0c, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e,
-	0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f,
-	0x72, 0x70, 0x68, 0x61, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f, 0x72, 0x70, 0x68, 0x61,
-	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x59, 0x0a, 0x12, 0x54, 0x6f, 0x67,
-	0x67, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x12,
-	0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x67, 0x67, 0x6c, 0x65, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x1a, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x67, 0x67, 0x6c, 0x65,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f,
-	0x6b, 0x65, 0x6e, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61,
-	0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b,
-	0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4d, 0x0a, 0x0e, 0x42, 0x6f,
-	0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x1c, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54, 0x6f,
-	0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x2e, 0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54, 0x6f, 0x6b, 0x65,
-	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x08, 0x47, 0x65, 0x74,
-	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65,
-	0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
-	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65,
-	0x6c, 0x65, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x1a, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54,
-	0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x26, 0x5a, 0x24,
-	0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x63, 0x6c, 0x69, 0x6e, 0x74,
-	0x6a, 0x65, 0x64, 0x77, 0x61, 0x72, 0x64, 0x73, 0x2f, 0x67, 0x6f, 0x66, 0x65, 0x72, 0x2f, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+	0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65,
+	0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x65, 0x0a, 0x16, 0x43, 0x6f,
+	0x6c, 0x6c, 0x65, 0x63, 0x74, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e, 0x65, 0x64, 0x4f, 0x62, 0x6a,
+	0x65, 0x63, 0x74, 0x73, 0x12, 0x24, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x6f, 0x6c,
+	0x6c, 0x65, 0x63, 0x74, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e, 0x65, 0x64, 0x4f, 0x62, 0x6a, 0x65,
+	0x63, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x25, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e,
+	0x65, 0x64, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x53, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x41, 0x72, 0x74, 0x69,
+	0x66, 0x61, 0x63, 0x74, 0x73, 0x12, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69,
+	0x73, 0x74, 0x52, 0x75, 0x6e, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69,
+	0x73, 0x74, 0x52, 0x75, 0x6e, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x58, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x41, 0x72, 0x74,
+	0x69, 0x66, 0x61, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x12, 0x1f, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x53,
+	0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74,
+	0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x30, 0x01,
+	0x12, 0x3e, 0x0a, 0x09, 0x47, 0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x12, 0x17, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47,
+	0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x3e, 0x0a, 0x09, 0x50, 0x75, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x12, 0x17, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50,
+	0x75, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x47, 0x0a, 0x0c, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74,
+	0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53,
+	0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65,
+	0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x53, 0x0a, 0x10, 0x52, 0x65, 0x65,
+	0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x12, 0x1e, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x53,
+	0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1f, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x53,
+	0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a,
+	0x0a, 0x0d, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x12,
+	0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65,
+	0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e,
+	0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x47, 0x65,
+	0x74, 0x41, 0x75, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x1b, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x41, 0x75, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69,
+	0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x47, 0x65, 0x74, 0x41, 0x75, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4d, 0x0a, 0x0e, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73,
+	0x74, 0x65, 0x6d, 0x53, 0x74, 0x61, 0x74, 0x73, 0x12, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x53, 0x74, 0x61, 0x74, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47,
+	0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x53, 0x74, 0x61, 0x74, 0x73, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x53, 0x6c, 0x6f, 0x77,
+	0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47,
+	0x65, 0x74, 0x53, 0x6c, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53,
+	0x6c, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x47, 0x0a, 0x0c, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f, 0x72, 0x70, 0x68, 0x61,
+	0x6e, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72,
+	0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f, 0x72, 0x70, 0x68,
+	0x61, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x59, 0x0a, 0x12, 0x54, 0x6f,
+	0x67, 0x67, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73,
+	0x12, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x67, 0x67, 0x6c, 0x65, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x67, 0x67, 0x6c,
+	0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x67, 0x4c,
+	0x65, 0x76, 0x65, 0x6c, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74,
+	0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x67, 0x4c, 0x65,
+	0x76, 0x65, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4d, 0x0a, 0x0e, 0x55,
+	0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x1c, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x4c,
+	0x65, 0x76, 0x65, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76,
+	0x65, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x49, 0x0a, 0x0c, 0x43, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x42, 0x61, 0x63, 0x6b, 0x75, 0x70, 0x12, 0x1a, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x42, 0x61, 0x63, 0x6b, 0x75, 0x70, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x42, 0x61, 0x63, 0x6b, 0x75, 0x70, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x30, 0x01, 0x12, 0x44, 0x0a, 0x0b, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54,
+	0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4d, 0x0a, 0x0e, 0x42,
+	0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x1c, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54,
+	0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54, 0x6f, 0x6b,
+	0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x08, 0x47, 0x65,
+	0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47,
+	0x65, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x17,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x0a, 0x4c, 0x69, 0x73, 0x74, 0x54,
+	0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x12, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69,
+	0x73, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6b, 0x65,
+	0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x59, 0x0a, 0x12, 0x4c, 0x69,
+	0x73, 0x74, 0x45, 0x78, 0x70, 0x69, 0x72, 0x69, 0x6e, 0x67, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73,
+	0x12, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x78, 0x70,
+	0x69, 0x72, 0x69, 0x6e, 0x67, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x45,
+	0x78, 0x70, 0x69, 0x72, 0x69, 0x6e, 0x67, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54,
+	0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c,
+	0x65, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x53, 0x0a, 0x10, 0x44,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x12,
+	0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x55, 0x73,
+	0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x55, 0x73,
+	0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x44, 0x0a, 0x0b, 0x52, 0x6f, 0x74, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12,
+	0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x6f, 0x74, 0x61, 0x74, 0x65, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x52, 0x6f, 0x74, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11, 0x45, 0x78, 0x63, 0x68, 0x61, 0x6e,
+	0x67, 0x65, 0x4f, 0x49, 0x44, 0x43, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x1f, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x4f, 0x49, 0x44, 0x43,
+	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x4f, 0x49, 0x44,
+	0x43, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5c,
+	0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63,
+	0x6f, 0x75, 0x6e, 0x74, 0x73, 0x12, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69,
+	0x73, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x22, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f,
+	0x75, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x56, 0x0a, 0x11,
+	0x47, 0x65, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+	0x74, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x65, 0x72,
+	0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x53, 0x65,
+	0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5f, 0x0a, 0x14, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x53, 0x65,
+	0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x22, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69,
+	0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x1a, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x53,
+	0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x5f, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53,
+	0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x22, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x72, 0x76,
+	0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
+	0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x6e, 0x0a, 0x19, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x12, 0x27, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61,
+	0x74, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x28, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69,
+	0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x59, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x75,
+	0x74, 0x68, 0x7a, 0x44, 0x65, 0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x20, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x75, 0x74, 0x68, 0x7a, 0x44, 0x65,
+	0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x21,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x75, 0x74, 0x68, 0x7a,
+	0x44, 0x65, 0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x50, 0x0a, 0x0f, 0x4c, 0x69, 0x73, 0x74, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74,
+	0x74, 0x65, 0x72, 0x73, 0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73,
+	0x74, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74,
+	0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65,
+	0x74, 0x74, 0x65, 0x72, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74,
+	0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x44, 0x65, 0x61,
+	0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x56, 0x0a, 0x11, 0x52, 0x65, 0x64, 0x72, 0x69, 0x76, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65,
+	0x74, 0x74, 0x65, 0x72, 0x12, 0x1f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x64,
+	0x72, 0x69, 0x76, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65,
+	0x64, 0x72, 0x69, 0x76, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x53, 0x0a, 0x10, 0x44, 0x65, 0x6c, 0x65, 0x74,
+	0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x12, 0x1e, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65,
+	0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1f, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65,
+	0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x47, 0x0a, 0x0c,
+	0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x73, 0x12, 0x1a, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b,
+	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x73, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x41, 0x0a, 0x0a, 0x47, 0x65, 0x74, 0x57, 0x65, 0x62, 0x68,
+	0x6f, 0x6f, 0x6b, 0x12, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x57,
+	0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x43, 0x72, 0x65, 0x61,
+	0x74, 0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4a, 0x0a, 0x0d, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x57, 0x65,
+	0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x12, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65,
+	0x6c, 0x65, 0x74, 0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74,
+	0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x62, 0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x44,
+	0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x69, 0x65, 0x73, 0x12, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x44, 0x65, 0x6c,
+	0x69, 0x76, 0x65, 0x72, 0x69, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x24,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f,
+	0x6f, 0x6b, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x69, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x68, 0x0a, 0x17, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x67, 0x69,
+	0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x12,
+	0x25, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x67, 0x69,
+	0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x26, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c,
+	0x69, 0x73, 0x74, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65,
+	0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x62,
+	0x0a, 0x15, 0x50, 0x75, 0x74, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65,
+	0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x12, 0x23, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x50, 0x75, 0x74, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65,
+	0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x24, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x74, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79,
+	0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x6b, 0x0a, 0x18, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x67, 0x69,
+	0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x12, 0x26,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x67,
+	0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x27, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65,
+	0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42,
+	0x26, 0x5a, 0x24, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x63, 0x6c,
+	0x69, 0x6e, 0x74, 0x6a, 0x65, 0x64, 0x77, 0x61, 0x72, 0x64, 0x73, 0x2f, 0x67, 0x6f, 0x66, 0x65,
+	0x72, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
 
 var file_gofer_proto_goTypes = []interface{}{
-	(*ListNamespacesRequest)(nil),        // 0: proto.ListNamespacesRequest
-	(*CreateNamespaceRequest)(nil),       // 1: proto.CreateNamespaceRequest
-	(*GetNamespaceRequest)(nil),          // 2: proto.GetNamespaceRequest
-	(*UpdateNamespaceRequest)(nil),       // 3: proto.UpdateNamespaceRequest
-	(*DeleteNamespaceRequest)(nil),       // 4: proto.DeleteNamespaceRequest
-	(*GetPipelineRequest)(nil),           // 5: proto.GetPipelineRequest
-	(*ListPipelinesRequest)(nil),         // 6: proto.ListPipelinesRequest
-	(*EnablePipelineRequest)(nil),        // 7: proto.EnablePipelineRequest
-	(*DisablePipelineRequest)(nil),       // 8: proto.DisablePipelineRequest
-	(*CreatePipelineRawRequest)(nil),     // 9: proto.CreatePipelineRawRequest
-	(*CreatePipelineByURLRequest)(nil),   // 10: proto.CreatePipelineByURLRequest
-	(*UpdatePipelineRawRequest)(nil),     // 11: proto.UpdatePipelineRawRequest
-	(*UpdatePipelineByURLRequest)(nil),   // 12: proto.UpdatePipelineByURLRequest
-	(*AbandonPipelineRequest)(nil),       // 13: proto.AbandonPipelineRequest
-	(*GetTriggerRequest)(nil),            // 14: proto.GetTriggerRequest
-	(*ListTriggersRequest)(nil),          // 15: proto.ListTriggersRequest
-	(*InstallTriggerRequest)(nil),        // 16: proto.InstallTriggerRequest
-	(*UninstallTriggerRequest)(nil),      // 17: proto.UninstallTriggerRequest
-	(*GetNotifierRequest)(nil),           // 18: proto.GetNotifierRequest
-	(*ListNotifiersRequest)(nil),         // 19: proto.ListNotifiersRequest
-	(*InstallNotifierRequest)(nil),       // 20: proto.InstallNotifierRequest
-	(*UninstallNotifierRequest)(nil),     // 21: proto.UninstallNotifierRequest
-	(*GetEventRequest)(nil),              // 22: proto.GetEventRequest
-	(*ListEventsRequest)(nil),            // 23: proto.ListEventsRequest
-	(*GetRunRequest)(nil),                // 24: proto.GetRunRequest
-	(*BatchGetRunsRequest)(nil),          // 25: proto.BatchGetRunsRequest
-	(*ListRunsRequest)(nil),              // 26: proto.ListRunsRequest
-	(*StartRunRequest)(nil),              // 27: proto.StartRunRequest
-	(*RetryRunRequest)(nil),              // 28: proto.RetryRunRequest
-	(*CancelRunRequest)(nil),             // 29: proto.CancelRunRequest
-	(*CancelAllRunsRequest)(nil),         // 30: proto.CancelAllRunsRequest
-	(*GetTaskRunRequest)(nil),            // 31: proto.GetTaskRunRequest
-	(*ListTaskRunsRequest)(nil),          // 32: proto.ListTaskRunsRequest
-	(*CancelTaskRunRequest)(nil),         // 33: proto.CancelTaskRunRequest
-	(*GetTaskRunLogsRequest)(nil),        // 34: proto.GetTaskRunLogsRequest
-	(*DeleteTaskRunLogsRequest)(nil),     // 35: proto.DeleteTaskRunLogsRequest
-	(*GetPipelineObjectRequest)(nil),     // 36: proto.GetPipelineObjectRequest
-	(*PutPipelineObjectRequest)(nil),     // 37: proto.PutPipelineObjectRequest
-	(*DeletePipelineObjectRequest)(nil),  // 38: proto.DeletePipelineObjectRequest
-	(*GetRunObjectRequest)(nil),          // 39: proto.GetRunObjectRequest
-	(*PutRunObjectRequest)(nil),          // 40: proto.PutRunObjectRequest
-	(*DeleteRunObjectRequest)(nil),       // 41: proto.DeleteRunObjectRequest
-	(*GetSecretRequest)(nil),             // 42: proto.GetSecretRequest
-	(*PutSecretRequest)(nil),             // 43: proto.PutSecretRequest
-	(*DeleteSecretRequest)(nil),          // 44: proto.DeleteSecretRequest
-	(*GetSystemInfoRequest)(nil),         // 45: proto.GetSystemInfoRequest
-	(*RepairOrphanRequest)(nil),          // 46: proto.RepairOrphanRequest
-	(*ToggleEventIngressRequest)(nil),    // 47: proto.ToggleEventIngressRequest
-	(*CreateTokenRequest)(nil),           // 48: proto.CreateTokenRequest
-	(*BootstrapTokenRequest)(nil),        // 49: proto.BootstrapTokenRequest
-	(*GetTokenRequest)(nil),              // 50: proto.GetTokenRequest
-	(*DeleteTokenRequest)(nil),           // 51: proto.DeleteTokenRequest
-	(*ListNamespacesResponse)(nil),       // 52: proto.ListNamespacesResponse
-	(*CreateNamespaceResponse)(nil),      // 53: proto.CreateNamespaceResponse
-	(*GetNamespaceResponse)(nil),         // 54: proto.GetNamespaceResponse
-	(*UpdateNamespaceResponse)(nil),      // 55: proto.UpdateNamespaceResponse
-	(*DeleteNamespaceResponse)(nil),      // 56: proto.DeleteNamespaceResponse
-	(*GetPipelineResponse)(nil),          // 57: proto.GetPipelineResponse
-	(*ListPipelinesResponse)(nil),        // 58: proto.ListPipelinesResponse
-	(*EnablePipelineResponse)(nil),       // 59: proto.EnablePipelineResponse
-	(*DisablePipelineResponse)(nil),      // 60: proto.DisablePipelineResponse
-	(*CreatePipelineRawResponse)(nil),    // 61: proto.CreatePipelineRawResponse
-	(*CreatePipelineByURLResponse)(nil),  // 62: proto.CreatePipelineByURLResponse
-	(*UpdatePipelineRawResponse)(nil),    // 63: proto.UpdatePipelineRawResponse
-	(*UpdatePipelineByURLResponse)(nil),  // 64: proto.UpdatePipelineByURLResponse
-	(*AbandonPipelineResponse)(nil),      // 65: proto.AbandonPipelineResponse
-	(*GetTriggerResponse)(nil),           // 66: proto.GetTriggerResponse
-	(*ListTriggersResponse)(nil),         // 67: proto.ListTriggersResponse
-	(*InstallTriggerResponse)(nil),       // 68: proto.InstallTriggerResponse
-	(*UninstallTriggerResponse)(nil),     // 69: proto.UninstallTriggerResponse
-	(*GetNotifierResponse)(nil),          // 70: proto.GetNotifierResponse
-	(*ListNotifiersResponse)(nil),        // 71: proto.ListNotifiersResponse
-	(*InstallNotifierResponse)(nil),      // 72: proto.InstallNotifierResponse
-	(*UninstallNotifierResponse)(nil),    // 73: proto.UninstallNotifierResponse
-	(*GetEventResponse)(nil),             // 74: proto.GetEventResponse
-	(*ListEventsResponse)(nil),           // 75: proto.ListEventsResponse
-	(*GetRunResponse)(nil),               // 76: proto.GetRunResponse
-	(*BatchGetRunsResponse)(nil),         // 77: proto.BatchGetRunsResponse
-	(*ListRunsResponse)(nil),             // 78: proto.ListRunsResponse
-	(*StartRunResponse)(nil),             // 79: proto.StartRunResponse
-	(*RetryRunResponse)(nil),             // 80: proto.RetryRunResponse
-	(*CancelRunResponse)(nil),            // 81: proto.CancelRunResponse
-	(*CancelAllRunsResponse)(nil),        // 82: proto.CancelAllRunsResponse
-	(*GetTaskRunResponse)(nil),           // 83: proto.GetTaskRunResponse
-	(*ListTaskRunsResponse)(nil),         // 84: proto.ListTaskRunsResponse
-	(*CancelTaskRunResponse)(nil),        // 85: proto.CancelTaskRunResponse
-	(*GetTaskRunLogsResponse)(nil),       // 86: proto.GetTaskRunLogsResponse
-	(*DeleteTaskRunLogsResponse)(nil),    // 87: proto.DeleteTaskRunLogsResponse
-	(*GetPipelineObjectResponse)(nil),    // 88: proto.GetPipelineObjectResponse
-	(*PutPipelineObjectResponse)(nil),    // 89: proto.PutPipelineObjectResponse
-	(*DeletePipelineObjectResponse)(nil), // 90: proto.DeletePipelineObjectResponse
-	(*GetRunObjectResponse)(nil),         // 91: proto.GetRunObjectResponse
-	(*PutRunObjectResponse)(nil),         // 92: proto.PutRunObjectResponse
-	(*DeleteRunObjectResponse)(nil),      // 93: proto.DeleteRunObjectResponse
-	(*GetSecretResponse)(nil),            // 94: proto.GetSecretResponse
-	(*PutSecretResponse)(nil),            // 95: proto.PutSecretResponse
-	(*DeleteSecretResponse)(nil),         // 96: proto.DeleteSecretResponse
-	(*GetSystemInfoResponse)(nil),        // 97: proto.GetSystemInfoResponse
-	(*RepairOrphanResponse)(nil),         // 98: proto.RepairOrphanResponse
-	(*ToggleEventIngressResponse)(nil),   // 99: proto.ToggleEventIngressResponse
-	(*CreateTokenResponse)(nil),          // 100: proto.CreateTokenResponse
-	(*BootstrapTokenResponse)(nil),       // 101: proto.BootstrapTokenResponse
-	(*GetTokenResponse)(nil),             // 102: proto.GetTokenResponse
-	(*DeleteTokenResponse)(nil),          // 103: proto.DeleteTokenResponse
+	(*ListNamespacesRequest)(nil),              // 0: proto.ListNamespacesRequest
+	(*CreateNamespaceRequest)(nil),             // 1: proto.CreateNamespaceRequest
+	(*GetNamespaceRequest)(nil),                // 2: proto.GetNamespaceRequest
+	(*UpdateNamespaceRequest)(nil),             // 3: proto.UpdateNamespaceRequest
+	(*DeleteNamespaceRequest)(nil),             // 4: proto.DeleteNamespaceRequest
+	(*GetNamespaceQuotaRequest)(nil),           // 5: proto.GetNamespaceQuotaRequest
+	(*UpdateNamespaceQuotaRequest)(nil),        // 6: proto.UpdateNamespaceQuotaRequest
+	(*GetPipelineRequest)(nil),                 // 7: proto.GetPipelineRequest
+	(*ListPipelinesRequest)(nil),               // 8: proto.ListPipelinesRequest
+	(*EnablePipelineRequest)(nil),              // 9: proto.EnablePipelineRequest
+	(*DisablePipelineRequest)(nil),             // 10: proto.DisablePipelineRequest
+	(*CreatePipelineRawRequest)(nil),           // 11: proto.CreatePipelineRawRequest
+	(*CreatePipelineByURLRequest)(nil),         // 12: proto.CreatePipelineByURLRequest
+	(*UpdatePipelineRawRequest)(nil),           // 13: proto.UpdatePipelineRawRequest
+	(*UpdatePipelineByURLRequest)(nil),         // 14: proto.UpdatePipelineByURLRequest
+	(*ListPipelineConfigVersionsRequest)(nil),  // 15: proto.ListPipelineConfigVersionsRequest
+	(*RollbackPipelineConfigRequest)(nil),      // 16: proto.RollbackPipelineConfigRequest
+	(*StartPipelineCanaryRequest)(nil),         // 17: proto.StartPipelineCanaryRequest
+	(*PromotePipelineCanaryRequest)(nil),       // 18: proto.PromotePipelineCanaryRequest
+	(*AbortPipelineCanaryRequest)(nil),         // 19: proto.AbortPipelineCanaryRequest
+	(*AbandonPipelineRequest)(nil),             // 20: proto.AbandonPipelineRequest
+	(*GetTriggerRequest)(nil),                  // 21: proto.GetTriggerRequest
+	(*ListTriggersRequest)(nil),                // 22: proto.ListTriggersRequest
+	(*InstallTriggerRequest)(nil),              // 23: proto.InstallTriggerRequest
+	(*UninstallTriggerRequest)(nil),            // 24: proto.UninstallTriggerRequest
+	(*GetNotifierRequest)(nil),                 // 25: proto.GetNotifierRequest
+	(*ListNotifiersRequest)(nil),               // 26: proto.ListNotifiersRequest
+	(*InstallNotifierRequest)(nil),             // 27: proto.InstallNotifierRequest
+	(*UninstallNotifierRequest)(nil),           // 28: proto.UninstallNotifierRequest
+	(*GetEventRequest)(nil),                    // 29: proto.GetEventRequest
+	(*ListEventsRequest)(nil),                  // 30: proto.ListEventsRequest
+	(*AcknowledgeEventRequest)(nil),            // 31: proto.AcknowledgeEventRequest
+	(*PublishExtensionEventRequest)(nil),       // 32: proto.PublishExtensionEventRequest
+	(*ListEventConsumersRequest)(nil),          // 33: proto.ListEventConsumersRequest
+	(*DeleteEventConsumerRequest)(nil),         // 34: proto.DeleteEventConsumerRequest
+	(*GetRunRequest)(nil),                      // 35: proto.GetRunRequest
+	(*BatchGetRunsRequest)(nil),                // 36: proto.BatchGetRunsRequest
+	(*ListRunsRequest)(nil),                    // 37: proto.ListRunsRequest
+	(*StartRunRequest)(nil),                    // 38: proto.StartRunRequest
+	(*RetryRunRequest)(nil),                    // 39: proto.RetryRunRequest
+	(*CancelRunRequest)(nil),                   // 40: proto.CancelRunRequest
+	(*CancelAllRunsRequest)(nil),               // 41: proto.CancelAllRunsRequest
+	(*GetTaskRunRequest)(nil),                  // 42: proto.GetTaskRunRequest
+	(*ListTaskRunsRequest)(nil),                // 43: proto.ListTaskRunsRequest
+	(*CancelTaskRunRequest)(nil),               // 44: proto.CancelTaskRunRequest
+	(*SpawnTaskRunRequest)(nil),                // 45: proto.SpawnTaskRunRequest
+	(*GetTaskRunLogsRequest)(nil),              // 46: proto.GetTaskRunLogsRequest
+	(*DeleteTaskRunLogsRequest)(nil),           // 47: proto.DeleteTaskRunLogsRequest
+	(*AttachToTaskRunRequest)(nil),             // 48: proto.AttachToTaskRunRequest
+	(*GetPipelineObjectRequest)(nil),           // 49: proto.GetPipelineObjectRequest
+	(*GetPipelineObjectStreamRequest)(nil),     // 50: proto.GetPipelineObjectStreamRequest
+	(*PutPipelineObjectRequest)(nil),           // 51: proto.PutPipelineObjectRequest
+	(*DeletePipelineObjectRequest)(nil),        // 52: proto.DeletePipelineObjectRequest
+	(*GetRunObjectRequest)(nil),                // 53: proto.GetRunObjectRequest
+	(*PutRunObjectRequest)(nil),                // 54: proto.PutRunObjectRequest
+	(*DeleteRunObjectRequest)(nil),             // 55: proto.DeleteRunObjectRequest
+	(*CollectOrphanedObjectsRequest)(nil),      // 56: proto.CollectOrphanedObjectsRequest
+	(*ListRunArtifactsRequest)(nil),            // 57: proto.ListRunArtifactsRequest
+	(*GetArtifactStreamRequest)(nil),           // 58: proto.GetArtifactStreamRequest
+	(*GetSecretRequest)(nil),                   // 59: proto.GetSecretRequest
+	(*PutSecretRequest)(nil),                   // 60: proto.PutSecretRequest
+	(*DeleteSecretRequest)(nil),                // 61: proto.DeleteSecretRequest
+	(*ReencryptSecretsRequest)(nil),            // 62: proto.ReencryptSecretsRequest
+	(*GetSystemInfoRequest)(nil),               // 63: proto.GetSystemInfoRequest
+	(*GetAuthConfigRequest)(nil),               // 64: proto.GetAuthConfigRequest
+	(*GetSystemStatsRequest)(nil),              // 65: proto.GetSystemStatsRequest
+	(*GetSlowReportRequest)(nil),               // 66: proto.GetSlowReportRequest
+	(*RepairOrphanRequest)(nil),                // 67: proto.RepairOrphanRequest
+	(*ToggleEventIngressRequest)(nil),          // 68: proto.ToggleEventIngressRequest
+	(*GetLogLevelRequest)(nil),                 // 69: proto.GetLogLevelRequest
+	(*UpdateLogLevelRequest)(nil),              // 70: proto.UpdateLogLevelRequest
+	(*CreateBackupRequest)(nil),                // 71: proto.CreateBackupRequest
+	(*CreateTokenRequest)(nil),                 // 72: proto.CreateTokenRequest
+	(*BootstrapTokenRequest)(nil),              // 73: proto.BootstrapTokenRequest
+	(*GetTokenRequest)(nil),                    // 74: proto.GetTokenRequest
+	(*ListTokensRequest)(nil),                  // 75: proto.ListTokensRequest
+	(*ListExpiringTokensRequest)(nil),          // 76: proto.ListExpiringTokensRequest
+	(*DeleteTokenRequest)(nil),                 // 77: proto.DeleteTokenRequest
+	(*DeleteUserTokensRequest)(nil),            // 78: proto.DeleteUserTokensRequest
+	(*RotateTokenRequest)(nil),                 // 79: proto.RotateTokenRequest
+	(*ExchangeOIDCTokenRequest)(nil),           // 80: proto.ExchangeOIDCTokenRequest
+	(*ListServiceAccountsRequest)(nil),         // 81: proto.ListServiceAccountsRequest
+	(*GetServiceAccountRequest)(nil),           // 82: proto.GetServiceAccountRequest
+	(*CreateServiceAccountRequest)(nil),        // 83: proto.CreateServiceAccountRequest
+	(*DeleteServiceAccountRequest)(nil),        // 84: proto.DeleteServiceAccountRequest
+	(*CreateServiceAccountTokenRequest)(nil),   // 85: proto.CreateServiceAccountTokenRequest
+	(*ListAuthzDecisionsRequest)(nil),          // 86: proto.ListAuthzDecisionsRequest
+	(*ListDeadLettersRequest)(nil),             // 87: proto.ListDeadLettersRequest
+	(*GetDeadLetterRequest)(nil),               // 88: proto.GetDeadLetterRequest
+	(*RedriveDeadLetterRequest)(nil),           // 89: proto.RedriveDeadLetterRequest
+	(*DeleteDeadLetterRequest)(nil),            // 90: proto.DeleteDeadLetterRequest
+	(*ListWebhooksRequest)(nil),                // 91: proto.ListWebhooksRequest
+	(*GetWebhookRequest)(nil),                  // 92: proto.GetWebhookRequest
+	(*CreateWebhookRequest)(nil),               // 93: proto.CreateWebhookRequest
+	(*DeleteWebhookRequest)(nil),               // 94: proto.DeleteWebhookRequest
+	(*ListWebhookDeliveriesRequest)(nil),       // 95: proto.ListWebhookDeliveriesRequest
+	(*ListRegistryCredentialsRequest)(nil),     // 96: proto.ListRegistryCredentialsRequest
+	(*PutRegistryCredentialRequest)(nil),       // 97: proto.PutRegistryCredentialRequest
+	(*DeleteRegistryCredentialRequest)(nil),    // 98: proto.DeleteRegistryCredentialRequest
+	(*ListNamespacesResponse)(nil),             // 99: proto.ListNamespacesResponse
+	(*CreateNamespaceResponse)(nil),            // 100: proto.CreateNamespaceResponse
+	(*GetNamespaceResponse)(nil),               // 101: proto.GetNamespaceResponse
+	(*UpdateNamespaceResponse)(nil),            // 102: proto.UpdateNamespaceResponse
+	(*DeleteNamespaceResponse)(nil),            // 103: proto.DeleteNamespaceResponse
+	(*GetNamespaceQuotaResponse)(nil),          // 104: proto.GetNamespaceQuotaResponse
+	(*UpdateNamespaceQuotaResponse)(nil),       // 105: proto.UpdateNamespaceQuotaResponse
+	(*GetPipelineResponse)(nil),                // 106: proto.GetPipelineResponse
+	(*ListPipelinesResponse)(nil),              // 107: proto.ListPipelinesResponse
+	(*EnablePipelineResponse)(nil),             // 108: proto.EnablePipelineResponse
+	(*DisablePipelineResponse)(nil),            // 109: proto.DisablePipelineResponse
+	(*CreatePipelineRawResponse)(nil),          // 110: proto.CreatePipelineRawResponse
+	(*CreatePipelineByURLResponse)(nil),        // 111: proto.CreatePipelineByURLResponse
+	(*UpdatePipelineRawResponse)(nil),          // 112: proto.UpdatePipelineRawResponse
+	(*UpdatePipelineByURLResponse)(nil),        // 113: proto.UpdatePipelineByURLResponse
+	(*ListPipelineConfigVersionsResponse)(nil), // 114: proto.ListPipelineConfigVersionsResponse
+	(*RollbackPipelineConfigResponse)(nil),     // 115: proto.RollbackPipelineConfigResponse
+	(*StartPipelineCanaryResponse)(nil),        // 116: proto.StartPipelineCanaryResponse
+	(*PromotePipelineCanaryResponse)(nil),      // 117: proto.PromotePipelineCanaryResponse
+	(*AbortPipelineCanaryResponse)(nil),        // 118: proto.AbortPipelineCanaryResponse
+	(*AbandonPipelineResponse)(nil),            // 119: proto.AbandonPipelineResponse
+	(*GetTriggerResponse)(nil),                 // 120: proto.GetTriggerResponse
+	(*ListTriggersResponse)(nil),               // 121: proto.ListTriggersResponse
+	(*InstallTriggerResponse)(nil),             // 122: proto.InstallTriggerResponse
+	(*UninstallTriggerResponse)(nil),           // 123: proto.UninstallTriggerResponse
+	(*GetNotifierResponse)(nil),                // 124: proto.GetNotifierResponse
+	(*ListNotifiersResponse)(nil),              // 125: proto.ListNotifiersResponse
+	(*InstallNotifierResponse)(nil),            // 126: proto.InstallNotifierResponse
+	(*UninstallNotifierResponse)(nil),          // 127: proto.UninstallNotifierResponse
+	(*GetEventResponse)(nil),                   // 128: proto.GetEventResponse
+	(*ListEventsResponse)(nil),                 // 129: proto.ListEventsResponse
+	(*AcknowledgeEventResponse)(nil),           // 130: proto.AcknowledgeEventResponse
+	(*PublishExtensionEventResponse)(nil),      // 131: proto.PublishExtensionEventResponse
+	(*ListEventConsumersResponse)(nil),         // 132: proto.ListEventConsumersResponse
+	(*DeleteEventConsumerResponse)(nil),        // 133: proto.DeleteEventConsumerResponse
+	(*GetRunResponse)(nil),                     // 134: proto.GetRunResponse
+	(*BatchGetRunsResponse)(nil),               // 135: proto.BatchGetRunsResponse
+	(*ListRunsResponse)(nil),                   // 136: proto.ListRunsResponse
+	(*StartRunResponse)(nil),                   // 137: proto.StartRunResponse
+	(*RetryRunResponse)(nil),                   // 138: proto.RetryRunResponse
+	(*CancelRunResponse)(nil),                  // 139: proto.CancelRunResponse
+	(*CancelAllRunsResponse)(nil),              // 140: proto.CancelAllRunsResponse
+	(*GetTaskRunResponse)(nil),                 // 141: proto.GetTaskRunResponse
+	(*ListTaskRunsResponse)(nil),               // 142: proto.ListTaskRunsResponse
+	(*CancelTaskRunResponse)(nil),              // 143: proto.CancelTaskRunResponse
+	(*SpawnTaskRunResponse)(nil),               // 144: proto.SpawnTaskRunResponse
+	(*GetTaskRunLogsResponse)(nil),             // 145: proto.GetTaskRunLogsResponse
+	(*DeleteTaskRunLogsResponse)(nil),          // 146: proto.DeleteTaskRunLogsResponse
+	(*AttachToTaskRunOutput)(nil),              // 147: proto.AttachToTaskRunOutput
+	(*GetPipelineObjectResponse)(nil),          // 148: proto.GetPipelineObjectResponse
+	(*GetPipelineObjectStreamResponse)(nil),    // 149: proto.GetPipelineObjectStreamResponse
+	(*PutPipelineObjectResponse)(nil),          // 150: proto.PutPipelineObjectResponse
+	(*DeletePipelineObjectResponse)(nil),       // 151: proto.DeletePipelineObjectResponse
+	(*GetRunObjectResponse)(nil),               // 152: proto.GetRunObjectResponse
+	(*PutRunObjectResponse)(nil),               // 153: proto.PutRunObjectResponse
+	(*DeleteRunObjectResponse)(nil),            // 154: proto.DeleteRunObjectResponse
+	(*CollectOrphanedObjectsResponse)(nil),     // 155: proto.CollectOrphanedObjectsResponse
+	(*ListRunArtifactsResponse)(nil),           // 156: proto.ListRunArtifactsResponse
+	(*GetArtifactStreamResponse)(nil),          // 157: proto.GetArtifactStreamResponse
+	(*GetSecretResponse)(nil),                  // 158: proto.GetSecretResponse
+	(*PutSecretResponse)(nil),                  // 159: proto.PutSecretResponse
+	(*DeleteSecretResponse)(nil),               // 160: proto.DeleteSecretResponse
+	(*ReencryptSecretsResponse)(nil),           // 161: proto.ReencryptSecretsResponse
+	(*GetSystemInfoResponse)(nil),              // 162: proto.GetSystemInfoResponse
+	(*GetAuthConfigResponse)(nil),              // 163: proto.GetAuthConfigResponse
+	(*GetSystemStatsResponse)(nil),             // 164: proto.GetSystemStatsResponse
+	(*GetSlowReportResponse)(nil),              // 165: proto.GetSlowReportResponse
+	(*RepairOrphanResponse)(nil),               // 166: proto.RepairOrphanResponse
+	(*ToggleEventIngressResponse)(nil),         // 167: proto.ToggleEventIngressResponse
+	(*GetLogLevelResponse)(nil),                // 168: proto.GetLogLevelResponse
+	(*UpdateLogLevelResponse)(nil),             // 169: proto.UpdateLogLevelResponse
+	(*CreateBackupResponse)(nil),               // 170: proto.CreateBackupResponse
+	(*CreateTokenResponse)(nil),                // 171: proto.CreateTokenResponse
+	(*BootstrapTokenResponse)(nil),             // 172: proto.BootstrapTokenResponse
+	(*GetTokenResponse)(nil),                   // 173: proto.GetTokenResponse
+	(*ListTokensResponse)(nil),                 // 174: proto.ListTokensResponse
+	(*ListExpiringTokensResponse)(nil),         // 175: proto.ListExpiringTokensResponse
+	(*DeleteTokenResponse)(nil),                // 176: proto.DeleteTokenResponse
+	(*DeleteUserTokensResponse)(nil),           // 177: proto.DeleteUserTokensResponse
+	(*RotateTokenResponse)(nil),                // 178: proto.RotateTokenResponse
+	(*ExchangeOIDCTokenResponse)(nil),          // 179: proto.ExchangeOIDCTokenResponse
+	(*ListServiceAccountsResponse)(nil),        // 180: proto.ListServiceAccountsResponse
+	(*GetServiceAccountResponse)(nil),          // 181: proto.GetServiceAccountResponse
+	(*CreateServiceAccountResponse)(nil),       // 182: proto.CreateServiceAccountResponse
+	(*DeleteServiceAccountResponse)(nil),       // 183: proto.DeleteServiceAccountResponse
+	(*CreateServiceAccountTokenResponse)(nil),  // 184: proto.CreateServiceAccountTokenResponse
+	(*ListAuthzDecisionsResponse)(nil),         // 185: proto.ListAuthzDecisionsResponse
+	(*ListDeadLettersResponse)(nil),            // 186: proto.ListDeadLettersResponse
+	(*GetDeadLetterResponse)(nil),              // 187: proto.GetDeadLetterResponse
+	(*RedriveDeadLetterResponse)(nil),          // 188: proto.RedriveDeadLetterResponse
+	(*DeleteDeadLetterResponse)(nil),           // 189: proto.DeleteDeadLetterResponse
+	(*ListWebhooksResponse)(nil),               // 190: proto.ListWebhooksResponse
+	(*GetWebhookResponse)(nil),                 // 191: proto.GetWebhookResponse
+	(*CreateWebhookResponse)(nil),              // 192: proto.CreateWebhookResponse
+	(*DeleteWebhookResponse)(nil),              // 193: proto.DeleteWebhookResponse
+	(*ListWebhookDeliveriesResponse)(nil),      // 194: proto.ListWebhookDeliveriesResponse
+	(*ListRegistryCredentialsResponse)(nil),    // 195: proto.ListRegistryCredentialsResponse
+	(*PutRegistryCredentialResponse)(nil),      // 196: proto.PutRegistryCredentialResponse
+	(*DeleteRegistryCredentialResponse)(nil),   // 197: proto.DeleteRegistryCredentialResponse
 }
 var file_gofer_proto_depIdxs = []int32{
 	0,   // 0: proto.Gofer.ListNamespaces:input_type -> proto.ListNamespacesRequest
@@ -390,107 +755,205 @@ var file_gofer_proto_depIdxs = []int32{
 	2,   // 2: proto.Gofer.GetNamespace:input_type -> proto.GetNamespaceRequest
 	3,   // 3: proto.Gofer.UpdateNamespace:input_type -> proto.UpdateNamespaceRequest
 	4,   // 4: proto.Gofer.DeleteNamespace:input_type -> proto.DeleteNamespaceRequest
-	5,   // 5: proto.Gofer.GetPipeline:input_type -> proto.GetPipelineRequest
-	6,   // 6: proto.Gofer.ListPipelines:input_type -> proto.ListPipelinesRequest
-	7,   // 7: proto.Gofer.EnablePipeline:input_type -> proto.EnablePipelineRequest
-	8,   // 8: proto.Gofer.DisablePipeline:input_type -> proto.DisablePipelineRequest
-	9,   // 9: proto.Gofer.CreatePipelineRaw:input_type -> proto.CreatePipelineRawRequest
-	10,  // 10: proto.Gofer.CreatePipelineByURL:input_type -> proto.CreatePipelineByURLRequest
-	11,  // 11: proto.Gofer.UpdatePipelineRaw:input_type -> proto.UpdatePipelineRawRequest
-	12,  // 12: proto.Gofer.UpdatePipelineByURL:input_type -> proto.UpdatePipelineByURLRequest
-	13,  // 13: proto.Gofer.AbandonPipeline:input_type -> proto.AbandonPipelineRequest
-	14,  // 14: proto.Gofer.GetTrigger:input_type -> proto.GetTriggerRequest
-	15,  // 15: proto.Gofer.ListTriggers:input_type -> proto.ListTriggersRequest
-	16,  // 16: proto.Gofer.InstallTrigger:input_type -> proto.InstallTriggerRequest
-	17,  // 17: proto.Gofer.UninstallTrigger:input_type -> proto.UninstallTriggerRequest
-	18,  // 18: proto.Gofer.GetNotifier:input_type -> proto.GetNotifierRequest
-	19,  // 19: proto.Gofer.ListNotifiers:input_type -> proto.ListNotifiersRequest
-	20,  // 20: proto.Gofer.InstallNotifier:input_type -> proto.InstallNotifierRequest
-	21,  // 21: proto.Gofer.UninstallNotifier:input_type -> proto.UninstallNotifierRequest
-	22,  // 22: proto.Gofer.GetEvent:input_type -> proto.GetEventRequest
-	23,  // 23: proto.Gofer.ListEvents:input_type -> proto.ListEventsRequest
-	24,  // 24: proto.Gofer.GetRun:input_type -> proto.GetRunRequest
-	25,  // 25: proto.Gofer.BatchGetRuns:input_type -> proto.BatchGetRunsRequest
-	26,  // 26: proto.Gofer.ListRuns:input_type -> proto.ListRunsRequest
-	27,  // 27: proto.Gofer.StartRun:input_type -> proto.StartRunRequest
-	28,  // 28: proto.Gofer.RetryRun:input_type -> proto.RetryRunRequest
-	29,  // 29: proto.Gofer.CancelRun:input_type -> proto.CancelRunRequest
-	30,  // 30: proto.Gofer.CancelAllRuns:input_type -> proto.CancelAllRunsRequest
-	31,  // 31: proto.Gofer.GetTaskRun:input_type -> proto.GetTaskRunRequest
-	32,  // 32: proto.Gofer.ListTaskRuns:input_type -> proto.ListTaskRunsRequest
-	33,  // 33: proto.Gofer.CancelTaskRun:input_type -> proto.CancelTaskRunRequest
-	34,  // 34: proto.Gofer.GetTaskRunLogs:input_type -> proto.GetTaskRunLogsRequest
-	35,  // 35: proto.Gofer.DeleteTaskRunLogs:input_type -> proto.DeleteTaskRunLogsRequest
-	36,  // 36: proto.Gofer.GetPipelineObject:input_type -> proto.GetPipelineObjectRequest
-	37,  // 37: proto.Gofer.PutPipelineObject:input_type -> proto.PutPipelineObjectRequest
-	38,  // 38: proto.Gofer.DeletePipelineObject:input_type -> proto.DeletePipelineObjectRequest
-	39,  // 39: proto.Gofer.GetRunObject:input_type -> proto.GetRunObjectRequest
-	40,  // 40: proto.Gofer.PutRunObject:input_type -> proto.PutRunObjectRequest
-	41,  // 41: proto.Gofer.DeleteRunObject:input_type -> proto.DeleteRunObjectRequest
-	42,  // 42: proto.Gofer.GetSecret:input_type -> proto.GetSecretRequest
-	43,  // 43: proto.Gofer.PutSecret:input_type -> proto.PutSecretRequest
-	44,  // 44: proto.Gofer.DeleteSecret:input_type -> proto.DeleteSecretRequest
-	45,  // 45: proto.Gofer.GetSystemInfo:input_type -> proto.GetSystemInfoRequest
-	46,  // 46: proto.Gofer.RepairOrphan:input_type -> proto.RepairOrphanRequest
-	47,  // 47: proto.Gofer.ToggleEventIngress:input_type -> proto.ToggleEventIngressRequest
-	48,  // 48: proto.Gofer.CreateToken:input_type -> proto.CreateTokenRequest
-	49,  // 49: proto.Gofer.BootstrapToken:input_type -> proto.BootstrapTokenRequest
-	50,  // 50: proto.Gofer.GetToken:input_type -> proto.GetTokenRequest
-	51,  // 51: proto.Gofer.DeleteToken:input_type -> proto.DeleteTokenRequest
-	52,  // 52: proto.Gofer.ListNamespaces:output_type -> proto.ListNamespacesResponse
-	53,  // 53: proto.Gofer.CreateNamespace:output_type -> proto.CreateNamespaceResponse
-	54,  // 54: proto.Gofer.GetNamespace:output_type -> proto.GetNamespaceResponse
-	55,  // 55: proto.Gofer.UpdateNamespace:output_type -> proto.UpdateNamespaceResponse
-	56,  // 56: proto.Gofer.DeleteNamespace:output_type -> proto.DeleteNamespaceResponse
-	57,  // 57: proto.Gofer.GetPipeline:output_type -> proto.GetPipelineResponse
-	58,  // 58: proto.Gofer.ListPipelines:output_type -> proto.ListPipelinesResponse
-	59,  // 59: proto.Gofer.EnablePipeline:output_type -> proto.EnablePipelineResponse
-	60,  // 60: proto.Gofer.DisablePipeline:output_type -> proto.DisablePipelineResponse
-	61,  // 61: proto.Gofer.CreatePipelineRaw:output_type -> proto.CreatePipelineRawResponse
-	62,  // 62: proto.Gofer.CreatePipelineByURL:output_type -> proto.CreatePipelineByURLResponse
-	63,  // 63: proto.Gofer.UpdatePipelineRaw:output_type -> proto.UpdatePipelineRawResponse
-	64,  // 64: proto.Gofer.UpdatePipelineByURL:output_type -> proto.UpdatePipelineByURLResponse
-	65,  // 65: proto.Gofer.AbandonPipeline:output_type -> proto.AbandonPipelineResponse
-	66,  // 66: proto.Gofer.GetTrigger:output_type -> proto.GetTriggerResponse
-	67,  // 67: proto.Gofer.ListTriggers:output_type -> proto.ListTriggersResponse
-	68,  // 68: proto.Gofer.InstallTrigger:output_type -> proto.InstallTriggerResponse
-	69,  // 69: proto.Gofer.UninstallTrigger:output_type -> proto.UninstallTriggerResponse
-	70,  // 70: proto.Gofer.GetNotifier:output_type -> proto.GetNotifierResponse
-	71,  // 71: proto.Gofer.ListNotifiers:output_type -> proto.ListNotifiersResponse
-	72,  // 72: proto.Gofer.InstallNotifier:output_type -> proto.InstallNotifierResponse
-	73,  // 73: proto.Gofer.UninstallNotifier:output_type -> proto.UninstallNotifierResponse
-	74,  // 74: proto.Gofer.GetEvent:output_type -> proto.GetEventResponse
-	75,  // 75: proto.Gofer.ListEvents:output_type -> proto.ListEventsResponse
-	76,  // 76: proto.Gofer.GetRun:output_type -> proto.GetRunResponse
-	77,  // 77: proto.Gofer.BatchGetRuns:output_type -> proto.BatchGetRunsResponse
-	78,  // 78: proto.Gofer.ListRuns:output_type -> proto.ListRunsResponse
-	79,  // 79: proto.Gofer.StartRun:output_type -> proto.StartRunResponse
-	80,  // 80: proto.Gofer.RetryRun:output_type -> proto.RetryRunResponse
-	81,  // 81: proto.Gofer.CancelRun:output_type -> proto.CancelRunResponse
-	82,  // 82: proto.Gofer.CancelAllRuns:output_type -> proto.CancelAllRunsResponse
-	83,  // 83: proto.Gofer.GetTaskRun:output_type -> proto.GetTaskRunResponse
-	84,  // 84: proto.Gofer.ListTaskRuns:output_type -> proto.ListTaskRunsResponse
-	85,  // 85: proto.Gofer.CancelTaskRun:output_type -> proto.CancelTaskRunResponse
-	86,  // 86: proto.Gofer.GetTaskRunLogs:output_type -> proto.GetTaskRunLogsResponse
-	87,  // 87: proto.Gofer.DeleteTaskRunLogs:output_type -> proto.DeleteTaskRunLogsResponse
-	88,  // 88: proto.Gofer.GetPipelineObject:output_type -> proto.GetPipelineObjectResponse
-	89,  // 89: proto.Gofer.PutPipelineObject:output_type -> proto.PutPipelineObjectResponse
-	90,  // 90: proto.Gofer.DeletePipelineObject:output_type -> proto.DeletePipelineObjectResponse
-	91,  // 91: proto.Gofer.GetRunObject:output_type -> proto.GetRunObjectResponse
-	92,  // 92: proto.Gofer.PutRunObject:output_type -> proto.PutRunObjectResponse
-	93,  // 93: proto.Gofer.DeleteRunObject:output_type -> proto.DeleteRunObjectResponse
-	94,  // 94: proto.Gofer.GetSecret:output_type -> proto.GetSecretResponse
-	95,  // 95: proto.Gofer.PutSecret:output_type -> proto.PutSecretResponse
-	96,  // 96: proto.Gofer.DeleteSecret:output_type -> proto.DeleteSecretResponse
-	97,  // 97: proto.Gofer.GetSystemInfo:output_type -> proto.GetSystemInfoResponse
-	98,  // 98: proto.Gofer.RepairOrphan:output_type -> proto.RepairOrphanResponse
-	99,  // 99: proto.Gofer.ToggleEventIngress:output_type -> proto.ToggleEventIngressResponse
-	100, // 100: proto.Gofer.CreateToken:output_type -> proto.CreateTokenResponse
-	101, // 101: proto.Gofer.BootstrapToken:output_type -> proto.BootstrapTokenResponse
-	102, // 102: proto.Gofer.GetToken:output_type -> proto.GetTokenResponse
-	103, // 103: proto.Gofer.DeleteToken:output_type -> proto.DeleteTokenResponse
-	52,  // [52:104] is the sub-list for method output_type
-	0,   // [0:52] is the sub-list for method input_type
+	5,   // 5: proto.Gofer.GetNamespaceQuota:input_type -> proto.GetNamespaceQuotaRequest
+	6,   // 6: proto.Gofer.UpdateNamespaceQuota:input_type -> proto.UpdateNamespaceQuotaRequest
+	7,   // 7: proto.Gofer.GetPipeline:input_type -> proto.GetPipelineRequest
+	8,   // 8: proto.Gofer.ListPipelines:input_type -> proto.ListPipelinesRequest
+	9,   // 9: proto.Gofer.EnablePipeline:input_type -> proto.EnablePipelineRequest
+	10,  // 10: proto.Gofer.DisablePipeline:input_type -> proto.DisablePipelineRequest
+	11,  // 11: proto.Gofer.CreatePipelineRaw:input_type -> proto.CreatePipelineRawRequest
+	12,  // 12: proto.Gofer.CreatePipelineByURL:input_type -> proto.CreatePipelineByURLRequest
+	13,  // 13: proto.Gofer.UpdatePipelineRaw:input_type -> proto.UpdatePipelineRawRequest
+	14,  // 14: proto.Gofer.UpdatePipelineByURL:input_type -> proto.UpdatePipelineByURLRequest
+	15,  // 15: proto.Gofer.ListPipelineConfigVersions:input_type -> proto.ListPipelineConfigVersionsRequest
+	16,  // 16: proto.Gofer.RollbackPipelineConfig:input_type -> proto.RollbackPipelineConfigRequest
+	17,  // 17: proto.Gofer.StartPipelineCanary:input_type -> proto.StartPipelineCanaryRequest
+	18,  // 18: proto.Gofer.PromotePipelineCanary:input_type -> proto.PromotePipelineCanaryRequest
+	19,  // 19: proto.Gofer.AbortPipelineCanary:input_type -> proto.AbortPipelineCanaryRequest
+	20,  // 20: proto.Gofer.AbandonPipeline:input_type -> proto.AbandonPipelineRequest
+	21,  // 21: proto.Gofer.GetTrigger:input_type -> proto.GetTriggerRequest
+	22,  // 22: proto.Gofer.ListTriggers:input_type -> proto.ListTriggersRequest
+	23,  // 23: proto.Gofer.InstallTrigger:input_type -> proto.InstallTriggerRequest
+	24,  // 24: proto.Gofer.UninstallTrigger:input_type -> proto.UninstallTriggerRequest
+	25,  // 25: proto.Gofer.GetNotifier:input_type -> proto.GetNotifierRequest
+	26,  // 26: proto.Gofer.ListNotifiers:input_type -> proto.ListNotifiersRequest
+	27,  // 27: proto.Gofer.InstallNotifier:input_type -> proto.InstallNotifierRequest
+	28,  // 28: proto.Gofer.UninstallNotifier:input_type -> proto.UninstallNotifierRequest
+	29,  // 29: proto.Gofer.GetEvent:input_type -> proto.GetEventRequest
+	30,  // 30: proto.Gofer.ListEvents:input_type -> proto.ListEventsRequest
+	31,  // 31: proto.Gofer.AcknowledgeEvent:input_type -> proto.AcknowledgeEventRequest
+	32,  // 32: proto.Gofer.PublishExtensionEvent:input_type -> proto.PublishExtensionEventRequest
+	33,  // 33: proto.Gofer.ListEventConsumers:input_type -> proto.ListEventConsumersRequest
+	34,  // 34: proto.Gofer.DeleteEventConsumer:input_type -> proto.DeleteEventConsumerRequest
+	35,  // 35: proto.Gofer.GetRun:input_type -> proto.GetRunRequest
+	36,  // 36: proto.Gofer.BatchGetRuns:input_type -> proto.BatchGetRunsRequest
+	37,  // 37: proto.Gofer.ListRuns:input_type -> proto.ListRunsRequest
+	38,  // 38: proto.Gofer.StartRun:input_type -> proto.StartRunRequest
+	39,  // 39: proto.Gofer.RetryRun:input_type -> proto.RetryRunRequest
+	40,  // 40: proto.Gofer.CancelRun:input_type -> proto.CancelRunRequest
+	41,  // 41: proto.Gofer.CancelAllRuns:input_type -> proto.CancelAllRunsRequest
+	42,  // 42: proto.Gofer.GetTaskRun:input_type -> proto.GetTaskRunRequest
+	43,  // 43: proto.Gofer.ListTaskRuns:input_type -> proto.ListTaskRunsRequest
+	44,  // 44: proto.Gofer.CancelTaskRun:input_type -> proto.CancelTaskRunRequest
+	45,  // 45: proto.Gofer.SpawnTaskRun:input_type -> proto.SpawnTaskRunRequest
+	46,  // 46: proto.Gofer.GetTaskRunLogs:input_type -> proto.GetTaskRunLogsRequest
+	47,  // 47: proto.Gofer.DeleteTaskRunLogs:input_type -> proto.DeleteTaskRunLogsRequest
+	48,  // 48: proto.Gofer.AttachToTaskRun:input_type -> proto.AttachToTaskRunRequest
+	49,  // 49: proto.Gofer.GetPipelineObject:input_type -> proto.GetPipelineObjectRequest
+	50,  // 50: proto.Gofer.GetPipelineObjectStream:input_type -> proto.GetPipelineObjectStreamRequest
+	51,  // 51: proto.Gofer.PutPipelineObject:input_type -> proto.PutPipelineObjectRequest
+	51,  // 52: proto.Gofer.PutPipelineObjectStream:input_type -> proto.PutPipelineObjectRequest
+	52,  // 53: proto.Gofer.DeletePipelineObject:input_type -> proto.DeletePipelineObjectRequest
+	53,  // 54: proto.Gofer.GetRunObject:input_type -> proto.GetRunObjectRequest
+	54,  // 55: proto.Gofer.PutRunObject:input_type -> proto.PutRunObjectRequest
+	54,  // 56: proto.Gofer.PutRunObjectStream:input_type -> proto.PutRunObjectRequest
+	55,  // 57: proto.Gofer.DeleteRunObject:input_type -> proto.DeleteRunObjectRequest
+	56,  // 58: proto.Gofer.CollectOrphanedObjects:input_type -> proto.CollectOrphanedObjectsRequest
+	57,  // 59: proto.Gofer.ListRunArtifacts:input_type -> proto.ListRunArtifactsRequest
+	58,  // 60: proto.Gofer.GetArtifactStream:input_type -> proto.GetArtifactStreamRequest
+	59,  // 61: proto.Gofer.GetSecret:input_type -> proto.GetSecretRequest
+	60,  // 62: proto.Gofer.PutSecret:input_type -> proto.PutSecretRequest
+	61,  // 63: proto.Gofer.DeleteSecret:input_type -> proto.DeleteSecretRequest
+	62,  // 64: proto.Gofer.ReencryptSecrets:input_type -> proto.ReencryptSecretsRequest
+	63,  // 65: proto.Gofer.GetSystemInfo:input_type -> proto.GetSystemInfoRequest
+	64,  // 66: proto.Gofer.GetAuthConfig:input_type -> proto.GetAuthConfigRequest
+	65,  // 67: proto.Gofer.GetSystemStats:input_type -> proto.GetSystemStatsRequest
+	66,  // 68: proto.Gofer.GetSlowReport:input_type -> proto.GetSlowReportRequest
+	67,  // 69: proto.Gofer.RepairOrphan:input_type -> proto.RepairOrphanRequest
+	68,  // 70: proto.Gofer.ToggleEventIngress:input_type -> proto.ToggleEventIngressRequest
+	69,  // 71: proto.Gofer.GetLogLevel:input_type -> proto.GetLogLevelRequest
+	70,  // 72: proto.Gofer.UpdateLogLevel:input_type -> proto.UpdateLogLevelRequest
+	71,  // 73: proto.Gofer.CreateBackup:input_type -> proto.CreateBackupRequest
+	72,  // 74: proto.Gofer.CreateToken:input_type -> proto.CreateTokenRequest
+	73,  // 75: proto.Gofer.BootstrapToken:input_type -> proto.BootstrapTokenRequest
+	74,  // 76: proto.Gofer.GetToken:input_type -> proto.GetTokenRequest
+	75,  // 77: proto.Gofer.ListTokens:input_type -> proto.ListTokensRequest
+	76,  // 78: proto.Gofer.ListExpiringTokens:input_type -> proto.ListExpiringTokensRequest
+	77,  // 79: proto.Gofer.DeleteToken:input_type -> proto.DeleteTokenRequest
+	78,  // 80: proto.Gofer.DeleteUserTokens:input_type -> proto.DeleteUserTokensRequest
+	79,  // 81: proto.Gofer.RotateToken:input_type -> proto.RotateTokenRequest
+	80,  // 82: proto.Gofer.ExchangeOIDCToken:input_type -> proto.ExchangeOIDCTokenRequest
+	81,  // 83: proto.Gofer.ListServiceAccounts:input_type -> proto.ListServiceAccountsRequest
+	82,  // 84: proto.Gofer.GetServiceAccount:input_type -> proto.GetServiceAccountRequest
+	83,  // 85: proto.Gofer.CreateServiceAccount:input_type -> proto.CreateServiceAccountRequest
+	84,  // 86: proto.Gofer.DeleteServiceAccount:input_type -> proto.DeleteServiceAccountRequest
+	85,  // 87: proto.Gofer.CreateServiceAccountToken:input_type -> proto.CreateServiceAccountTokenRequest
+	86,  // 88: proto.Gofer.ListAuthzDecisions:input_type -> proto.ListAuthzDecisionsRequest
+	87,  // 89: proto.Gofer.ListDeadLetters:input_type -> proto.ListDeadLettersRequest
+	88,  // 90: proto.Gofer.GetDeadLetter:input_type -> proto.GetDeadLetterRequest
+	89,  // 91: proto.Gofer.RedriveDeadLetter:input_type -> proto.RedriveDeadLetterRequest
+	90,  // 92: proto.Gofer.DeleteDeadLetter:input_type -> proto.DeleteDeadLetterRequest
+	91,  // 93: proto.Gofer.ListWebhooks:input_type -> proto.ListWebhooksRequest
+	92,  // 94: proto.Gofer.GetWebhook:input_type -> proto.GetWebhookRequest
+	93,  // 95: proto.Gofer.CreateWebhook:input_type -> proto.CreateWebhookRequest
+	94,  // 96: proto.Gofer.DeleteWebhook:input_type -> proto.DeleteWebhookRequest
+	95,  // 97: proto.Gofer.ListWebhookDeliveries:input_type -> proto.ListWebhookDeliveriesRequest
+	96,  // 98: proto.Gofer.ListRegistryCredentials:input_type -> proto.ListRegistryCredentialsRequest
+	97,  // 99: proto.Gofer.PutRegistryCredential:input_type -> proto.PutRegistryCredentialRequest
+	98,  // 100: proto.Gofer.DeleteRegistryCredential:input_type -> proto.DeleteRegistryCredentialRequest
+	99,  // 101: proto.Gofer.ListNamespaces:output_type -> proto.ListNamespacesResponse
+	100, // 102: proto.Gofer.CreateNamespace:output_type -> proto.CreateNamespaceResponse
+	101, // 103: proto.Gofer.GetNamespace:output_type -> proto.GetNamespaceResponse
+	102, // 104: proto.Gofer.UpdateNamespace:output_type -> proto.UpdateNamespaceResponse
+	103, // 105: proto.Gofer.DeleteNamespace:output_type -> proto.DeleteNamespaceResponse
+	104, // 106: proto.Gofer.GetNamespaceQuota:output_type -> proto.GetNamespaceQuotaResponse
+	105, // 107: proto.Gofer.UpdateNamespaceQuota:output_type -> proto.UpdateNamespaceQuotaResponse
+	106, // 108: proto.Gofer.GetPipeline:output_type -> proto.GetPipelineResponse
+	107, // 109: proto.Gofer.ListPipelines:output_type -> proto.ListPipelinesResponse
+	108, // 110: proto.Gofer.EnablePipeline:output_type -> proto.EnablePipelineResponse
+	109, // 111: proto.Gofer.DisablePipeline:output_type -> proto.DisablePipelineResponse
+	110, // 112: proto.Gofer.CreatePipelineRaw:output_type -> proto.CreatePipelineRawResponse
+	111, // 113: proto.Gofer.CreatePipelineByURL:output_type -> proto.CreatePipelineByURLResponse
+	112, // 114: proto.Gofer.UpdatePipelineRaw:output_type -> proto.UpdatePipelineRawResponse
+	113, // 115: proto.Gofer.UpdatePipelineByURL:output_type -> proto.UpdatePipelineByURLResponse
+	114, // 116: proto.Gofer.ListPipelineConfigVersions:output_type -> proto.ListPipelineConfigVersionsResponse
+	115, // 117: proto.Gofer.RollbackPipelineConfig:output_type -> proto.RollbackPipelineConfigResponse
+	116, // 118: proto.Gofer.StartPipelineCanary:output_type -> proto.StartPipelineCanaryResponse
+	117, // 119: proto.Gofer.PromotePipelineCanary:output_type -> proto.PromotePipelineCanaryResponse
+	118, // 120: proto.Gofer.AbortPipelineCanary:output_type -> proto.AbortPipelineCanaryResponse
+	119, // 121: proto.Gofer.AbandonPipeline:output_type -> proto.AbandonPipelineResponse
+	120, // 122: proto.Gofer.GetTrigger:output_type -> proto.GetTriggerResponse
+	121, // 123: proto.Gofer.ListTriggers:output_type -> proto.ListTriggersResponse
+	122, // 124: proto.Gofer.InstallTrigger:output_type -> proto.InstallTriggerResponse
+	123, // 125: proto.Gofer.UninstallTrigger:output_type -> proto.UninstallTriggerResponse
+	124, // 126: proto.Gofer.GetNotifier:output_type -> proto.GetNotifierResponse
+	125, // 127: proto.Gofer.ListNotifiers:output_type -> proto.ListNotifiersResponse
+	126, // 128: proto.Gofer.InstallNotifier:output_type -> proto.InstallNotifierResponse
+	127, // 129: proto.Gofer.UninstallNotifier:output_type -> proto.UninstallNotifierResponse
+	128, // 130: proto.Gofer.GetEvent:output_type -> proto.GetEventResponse
+	129, // 131: proto.Gofer.ListEvents:output_type -> proto.ListEventsResponse
+	130, // 132: proto.Gofer.AcknowledgeEvent:output_type -> proto.AcknowledgeEventResponse
+	131, // 133: proto.Gofer.PublishExtensionEvent:output_type -> proto.PublishExtensionEventResponse
+	132, // 134: proto.Gofer.ListEventConsumers:output_type -> proto.ListEventConsumersResponse
+	133, // 135: proto.Gofer.DeleteEventConsumer:output_type -> proto.DeleteEventConsumerResponse
+	134, // 136: proto.Gofer.GetRun:output_type -> proto.GetRunResponse
+	135, // 137: proto.Gofer.BatchGetRuns:output_type -> proto.BatchGetRunsResponse
+	136, // 138: proto.Gofer.ListRuns:output_type -> proto.ListRunsResponse
+	137, // 139: proto.Gofer.StartRun:output_type -> proto.StartRunResponse
+	138, // 140: proto.Gofer.RetryRun:output_type -> proto.RetryRunResponse
+	139, // 141: proto.Gofer.CancelRun:output_type -> proto.CancelRunResponse
+	140, // 142: proto.Gofer.CancelAllRuns:output_type -> proto.CancelAllRunsResponse
+	141, // 143: proto.Gofer.GetTaskRun:output_type -> proto.GetTaskRunResponse
+	142, // 144: proto.Gofer.ListTaskRuns:output_type -> proto.ListTaskRunsResponse
+	143, // 145: proto.Gofer.CancelTaskRun:output_type -> proto.CancelTaskRunResponse
+	144, // 146: proto.Gofer.SpawnTaskRun:output_type -> proto.SpawnTaskRunResponse
+	145, // 147: proto.Gofer.GetTaskRunLogs:output_type -> proto.GetTaskRunLogsResponse
+	146, // 148: proto.Gofer.DeleteTaskRunLogs:output_type -> proto.DeleteTaskRunLogsResponse
+	147, // 149: proto.Gofer.AttachToTaskRun:output_type -> proto.AttachToTaskRunOutput
+	148, // 150: proto.Gofer.GetPipelineObject:output_type -> proto.GetPipelineObjectResponse
+	149, // 151: proto.Gofer.GetPipelineObjectStream:output_type -> proto.GetPipelineObjectStreamResponse
+	150, // 152: proto.Gofer.PutPipelineObject:output_type -> proto.PutPipelineObjectResponse
+	150, // 153: proto.Gofer.PutPipelineObjectStream:output_type -> proto.PutPipelineObjectResponse
+	151, // 154: proto.Gofer.DeletePipelineObject:output_type -> proto.DeletePipelineObjectResponse
+	152, // 155: proto.Gofer.GetRunObject:output_type -> proto.GetRunObjectResponse
+	153, // 156: proto.Gofer.PutRunObject:output_type -> proto.PutRunObjectResponse
+	153, // 157: proto.Gofer.PutRunObjectStream:output_type -> proto.PutRunObjectResponse
+	154, // 158: proto.Gofer.DeleteRunObject:output_type -> proto.DeleteRunObjectResponse
+	155, // 159: proto.Gofer.CollectOrphanedObjects:output_type -> proto.CollectOrphanedObjectsResponse
+	156, // 160: proto.Gofer.ListRunArtifacts:output_type -> proto.ListRunArtifactsResponse
+	157, // 161: proto.Gofer.GetArtifactStream:output_type -> proto.GetArtifactStreamResponse
+	158, // 162: proto.Gofer.GetSecret:output_type -> proto.GetSecretResponse
+	159, // 163: proto.Gofer.PutSecret:output_type -> proto.PutSecretResponse
+	160, // 164: proto.Gofer.DeleteSecret:output_type -> proto.DeleteSecretResponse
+	161, // 165: proto.Gofer.ReencryptSecrets:output_type -> proto.ReencryptSecretsResponse
+	162, // 166: proto.Gofer.GetSystemInfo:output_type -> proto.GetSystemInfoResponse
+	163, // 167: proto.Gofer.GetAuthConfig:output_type -> proto.GetAuthConfigResponse
+	164, // 168: proto.Gofer.GetSystemStats:output_type -> proto.GetSystemStatsResponse
+	165, // 169: proto.Gofer.GetSlowReport:output_type -> proto.GetSlowReportResponse
+	166, // 170: proto.Gofer.RepairOrphan:output_type -> proto.RepairOrphanResponse
+	167, // 171: proto.Gofer.ToggleEventIngress:output_type -> proto.ToggleEventIngressResponse
+	168, // 172: proto.Gofer.GetLogLevel:output_type -> proto.GetLogLevelResponse
+	169, // 173: proto.Gofer.UpdateLogLevel:output_type -> proto.UpdateLogLevelResponse
+	170, // 174: proto.Gofer.CreateBackup:output_type -> proto.CreateBackupResponse
+	171, // 175: proto.Gofer.CreateToken:output_type -> proto.CreateTokenResponse
+	172, // 176: proto.Gofer.BootstrapToken:output_type -> proto.BootstrapTokenResponse
+	173, // 177: proto.Gofer.GetToken:output_type -> proto.GetTokenResponse
+	174, // 178: proto.Gofer.ListTokens:output_type -> proto.ListTokensResponse
+	175, // 179: proto.Gofer.ListExpiringTokens:output_type -> proto.ListExpiringTokensResponse
+	176, // 180: proto.Gofer.DeleteToken:output_type -> proto.DeleteTokenResponse
+	177, // 181: proto.Gofer.DeleteUserTokens:output_type -> proto.DeleteUserTokensResponse
+	178, // 182: proto.Gofer.RotateToken:output_type -> proto.RotateTokenResponse
+	179, // 183: proto.Gofer.ExchangeOIDCToken:output_type -> proto.ExchangeOIDCTokenResponse
+	180, // 184: proto.Gofer.ListServiceAccounts:output_type -> proto.ListServiceAccountsResponse
+	181, // 185: proto.Gofer.GetServiceAccount:output_type -> proto.GetServiceAccountResponse
+	182, // 186: proto.Gofer.CreateServiceAccount:output_type -> proto.CreateServiceAccountResponse
+	183, // 187: proto.Gofer.DeleteServiceAccount:output_type -> proto.DeleteServiceAccountResponse
+	184, // 188: proto.Gofer.CreateServiceAccountToken:output_type -> proto.CreateServiceAccountTokenResponse
+	185, // 189: proto.Gofer.ListAuthzDecisions:output_type -> proto.ListAuthzDecisionsResponse
+	186, // 190: proto.Gofer.ListDeadLetters:output_type -> proto.ListDeadLettersResponse
+	187, // 191: proto.Gofer.GetDeadLetter:output_type -> proto.GetDeadLetterResponse
+	188, // 192: proto.Gofer.RedriveDeadLetter:output_type -> proto.RedriveDeadLetterResponse
+	189, // 193: proto.Gofer.DeleteDeadLetter:output_type -> proto.DeleteDeadLetterResponse
+	190, // 194: proto.Gofer.ListWebhooks:output_type -> proto.ListWebhooksResponse
+	191, // 195: proto.Gofer.GetWebhook:output_type -> proto.GetWebhookResponse
+	192, // 196: proto.Gofer.CreateWebhook:output_type -> proto.CreateWebhookResponse
+	193, // 197: proto.Gofer.DeleteWebhook:output_type -> proto.DeleteWebhookResponse
+	194, // 198: proto.Gofer.ListWebhookDeliveries:output_type -> proto.ListWebhookDeliveriesResponse
+	195, // 199: proto.Gofer.ListRegistryCredentials:output_type -> proto.ListRegistryCredentialsResponse
+	196, // 200: proto.Gofer.PutRegistryCredential:output_type -> proto.PutRegistryCredentialResponse
+	197, // 201: proto.Gofer.DeleteRegistryCredential:output_type -> proto.DeleteRegistryCredentialResponse
+	101, // [101:202] is the sub-list for method output_type
+	0,   // [0:101] is the sub-list for method input_type
 	0,   // [0:0] is the sub-list for extension type_name
 	0,   // [0:0] is the sub-list for extension extendee
 	0,   // [0:0] is the sub-list for field type_name
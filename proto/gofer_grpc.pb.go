@@ -32,6 +32,11 @@ type GoferClient interface {
 	UpdateNamespace(ctx context.Context, in *UpdateNamespaceRequest, opts ...grpc.CallOption) (*UpdateNamespaceResponse, error)
 	// DeleteNamespace removes a namespace by id.
 	DeleteNamespace(ctx context.Context, in *DeleteNamespaceRequest, opts ...grpc.CallOption) (*DeleteNamespaceResponse, error)
+	// GetNamespaceQuota returns the resource limits of a namespace along with its
+	// current consumption.
+	GetNamespaceQuota(ctx context.Context, in *GetNamespaceQuotaRequest, opts ...grpc.CallOption) (*GetNamespaceQuotaResponse, error)
+	// UpdateNamespaceQuota replaces the resource limits of a namespace.
+	UpdateNamespaceQuota(ctx context.Context, in *UpdateNamespaceQuotaRequest, opts ...grpc.CallOption) (*UpdateNamespaceQuotaResponse, error)
 	// GetPipeline returns a single pipeline by ID.
 	GetPipeline(ctx context.Context, in *GetPipelineRequest, opts ...grpc.CallOption) (*GetPipelineResponse, error)
 	// ListPipelines returns all registered pipelines. Can control pagination by
@@ -72,6 +77,30 @@ type GoferClient interface {
 	//    1) The pipeline must not have any current runs in progress.
 	//    2) The pipeline must be in a disabled state.
 	UpdatePipelineByURL(ctx context.Context, in *UpdatePipelineByURLRequest, opts ...grpc.CallOption) (*UpdatePipelineByURLResponse, error)
+	// ListPipelineConfigVersions returns the configurations a pipeline has been
+	// registered with, newest first. A new version is recorded every time the
+	// pipeline is created, updated or rolled back.
+	ListPipelineConfigVersions(ctx context.Context, in *ListPipelineConfigVersionsRequest, opts ...grpc.CallOption) (*ListPipelineConfigVersionsResponse, error)
+	// RollbackPipelineConfig updates a pipeline to a previously registered
+	// configuration version. Rollback has the same requirements as updating a
+	// pipeline:
+	//    1) The pipeline must not have any current runs in progress.
+	//    2) The pipeline must be in a disabled state.
+	RollbackPipelineConfig(ctx context.Context, in *RollbackPipelineConfigRequest, opts ...grpc.CallOption) (*RollbackPipelineConfigResponse, error)
+	// StartPipelineCanary registers a new config version for a pipeline without
+	// replacing the one it currently uses. Instead, a percentage of new runs(or
+	// only those started manually) use the tasks of the new version until the
+	// canary is promoted or aborted. Only one canary can be in progress at a
+	// time.
+	StartPipelineCanary(ctx context.Context, in *StartPipelineCanaryRequest, opts ...grpc.CallOption) (*StartPipelineCanaryResponse, error)
+	// PromotePipelineCanary updates a pipeline to the config version its canary
+	// is trying out. Promotion has the same requirements as updating a pipeline:
+	//    1) The pipeline must not have any current runs in progress.
+	//    2) The pipeline must be in a disabled state.
+	PromotePipelineCanary(ctx context.Context, in *PromotePipelineCanaryRequest, opts ...grpc.CallOption) (*PromotePipelineCanaryResponse, error)
+	// AbortPipelineCanary stops a pipeline's canary; all new runs use the
+	// pipeline's current config version again.
+	AbortPipelineCanary(ctx context.Context, in *AbortPipelineCanaryRequest, opts ...grpc.CallOption) (*AbortPipelineCanaryResponse, error)
 	// AbandonPipeline disables a pipeline permanently. This removes all triggers
 	// and prevents the pipeline from ever being triggered again.
 	AbandonPipeline(ctx context.Context, in *AbandonPipelineRequest, opts ...grpc.CallOption) (*AbandonPipelineResponse, error)
@@ -96,6 +125,18 @@ type GoferClient interface {
 	// ListEvents returns a streaming list of all events, ordered by
 	// oldest to newest.
 	ListEvents(ctx context.Context, in *ListEventsRequest, opts ...grpc.CallOption) (Gofer_ListEventsClient, error)
+	// AcknowledgeEvent records that an event consumer has processed all events up
+	// to and including the given event.
+	AcknowledgeEvent(ctx context.Context, in *AcknowledgeEventRequest, opts ...grpc.CallOption) (*AcknowledgeEventResponse, error)
+	// PublishExtensionEvent allows extensions to publish their own events(ex. a
+	// pull request being opened) so they show up in the event history and are
+	// sent to event sinks. Extensions authenticate with the key Gofer gave them
+	// on startup instead of an API token.
+	PublishExtensionEvent(ctx context.Context, in *PublishExtensionEventRequest, opts ...grpc.CallOption) (*PublishExtensionEventResponse, error)
+	// ListEventConsumers returns all event consumers and their positions.
+	ListEventConsumers(ctx context.Context, in *ListEventConsumersRequest, opts ...grpc.CallOption) (*ListEventConsumersResponse, error)
+	// DeleteEventConsumer removes an event consumer.
+	DeleteEventConsumer(ctx context.Context, in *DeleteEventConsumerRequest, opts ...grpc.CallOption) (*DeleteEventConsumerResponse, error)
 	// GetRun returns the details of a single run.
 	GetRun(ctx context.Context, in *GetRunRequest, opts ...grpc.CallOption) (*GetRunResponse, error)
 	// BatchGetRuns returns multiple runs by ID.
@@ -110,7 +151,8 @@ type GoferClient interface {
 	StartRun(ctx context.Context, in *StartRunRequest, opts ...grpc.CallOption) (*StartRunResponse, error)
 	// RetryRun simply takes the vars and settings from a previous run and re-uses
 	// those to launch a new run. Useful for if you want the exact settings from a
-	// previous run.
+	// previous run. Passing "from_task" resumes the previous run instead; only the
+	// given task and the tasks that depend on it are run again.
 	RetryRun(ctx context.Context, in *RetryRunRequest, opts ...grpc.CallOption) (*RetryRunResponse, error)
 	// CancelRun stops the execution of a run in progress. Any task runs that
 	// might have been running at the time Are ask to stop gracefully(SIGINT)
@@ -131,6 +173,10 @@ type GoferClient interface {
 	// Task runs that are cancelled can cause other downstream task runs to be
 	// skipped depending on those downstream task run dependencies.
 	CancelTaskRun(ctx context.Context, in *CancelTaskRunRequest, opts ...grpc.CallOption) (*CancelTaskRunResponse, error)
+	// SpawnTaskRun adds a task to a run that is still in progress. It's meant to
+	// be called by the run's own task runs using the token Gofer passes them,
+	// allowing a task to fan out into more tasks based on what it finds.
+	SpawnTaskRun(ctx context.Context, in *SpawnTaskRunRequest, opts ...grpc.CallOption) (*SpawnTaskRunResponse, error)
 	// GetTaskRunLogs returns logs for a specific task run line by line in a
 	// stream. The logs are returns with both STDOUT and STDERR of the associated
 	// container combined.
@@ -138,12 +184,25 @@ type GoferClient interface {
 	// DeleteTaskRunLogs removes a task run's associated log object. This is
 	// useful for if logs mistakenly contain sensitive data.
 	DeleteTaskRunLogs(ctx context.Context, in *DeleteTaskRunLogsRequest, opts ...grpc.CallOption) (*DeleteTaskRunLogsResponse, error)
+	// AttachToTaskRun runs a command inside a running task run's container and
+	// connects the caller to it through a pseudo-terminal. The first message
+	// must be a start message describing the task run to attach to; the messages
+	// that follow carry terminal input and terminal size changes.
+	AttachToTaskRun(ctx context.Context, opts ...grpc.CallOption) (Gofer_AttachToTaskRunClient, error)
 	// GetPipelineObject returns a single pipeline object by pipeline ID and key.
 	GetPipelineObject(ctx context.Context, in *GetPipelineObjectRequest, opts ...grpc.CallOption) (*GetPipelineObjectResponse, error)
+	// GetPipelineObjectStream returns a single pipeline object in chunks so
+	// that large objects never have to be held in memory. Starting from an
+	// offset allows an interrupted download to be resumed.
+	GetPipelineObjectStream(ctx context.Context, in *GetPipelineObjectStreamRequest, opts ...grpc.CallOption) (Gofer_GetPipelineObjectStreamClient, error)
 	// PutPipelineObject uploads a single pipeline object by pipeline ID and key.
 	// Objects which are put under the same key do not count towards the pipeline
 	// object limit.
 	PutPipelineObject(ctx context.Context, in *PutPipelineObjectRequest, opts ...grpc.CallOption) (*PutPipelineObjectResponse, error)
+	// PutPipelineObjectStream uploads a single pipeline object in chunks. The
+	// first message must contain the namespace, pipeline, key and force fields;
+	// only the content of the messages that follow is read.
+	PutPipelineObjectStream(ctx context.Context, opts ...grpc.CallOption) (Gofer_PutPipelineObjectStreamClient, error)
 	// DeletePipelineObject removes a single pipeline object by pipeline ID and
 	// key. Removing a pipeline object decrements the total count of the pipeline
 	// object limit.
@@ -152,8 +211,23 @@ type GoferClient interface {
 	GetRunObject(ctx context.Context, in *GetRunObjectRequest, opts ...grpc.CallOption) (*GetRunObjectResponse, error)
 	// PutRunObject uploads the context of an object by run ID and key.
 	PutRunObject(ctx context.Context, in *PutRunObjectRequest, opts ...grpc.CallOption) (*PutRunObjectResponse, error)
+	// PutRunObjectStream uploads a single run object in chunks. The first
+	// message must contain the namespace, pipeline, run, key and force fields;
+	// only the content of the messages that follow is read.
+	PutRunObjectStream(ctx context.Context, opts ...grpc.CallOption) (Gofer_PutRunObjectStreamClient, error)
 	// DeleteRunObject removes a specific run object by run ID and key.
 	DeleteRunObject(ctx context.Context, in *DeleteRunObjectRequest, opts ...grpc.CallOption) (*DeleteRunObjectResponse, error)
+	// CollectOrphanedObjects removes objects whose pipeline or run no longer
+	// exists. Deleting a pipeline or run doesn't remove its objects so they
+	// otherwise stay in the object store forever. A dry run only reports the
+	// objects that would be removed.
+	CollectOrphanedObjects(ctx context.Context, in *CollectOrphanedObjectsRequest, opts ...grpc.CallOption) (*CollectOrphanedObjectsResponse, error)
+	// ListRunArtifacts returns the artifacts collected from the task runs of a
+	// run.
+	ListRunArtifacts(ctx context.Context, in *ListRunArtifactsRequest, opts ...grpc.CallOption) (*ListRunArtifactsResponse, error)
+	// GetArtifactStream returns the content of a single artifact in chunks.
+	// Directories are returned as a tar archive.
+	GetArtifactStream(ctx context.Context, in *GetArtifactStreamRequest, opts ...grpc.CallOption) (Gofer_GetArtifactStreamClient, error)
 	// GetSecret returns a single secret by pipeline ID and key.
 	GetSecret(ctx context.Context, in *GetSecretRequest, opts ...grpc.CallOption) (*GetSecretResponse, error)
 	// PutSecret uploads a single secret by pipeline ID and key.
@@ -161,8 +235,22 @@ type GoferClient interface {
 	// DeleteSecret removes a single secret by pipeline ID and
 	// key.
 	DeleteSecret(ctx context.Context, in *DeleteSecretRequest, opts ...grpc.CallOption) (*DeleteSecretResponse, error)
+	// ReencryptSecrets re-encrypts every secret that isn't encrypted with the
+	// secret store's current encryption key so that previous keys can be
+	// removed from the configuration. Secrets remain usable while it runs.
+	ReencryptSecrets(ctx context.Context, in *ReencryptSecretsRequest, opts ...grpc.CallOption) (*ReencryptSecretsResponse, error)
 	// GetSystemInfo returns system information and general health.
 	GetSystemInfo(ctx context.Context, in *GetSystemInfoRequest, opts ...grpc.CallOption) (*GetSystemInfoResponse, error)
+	// GetAuthConfig returns the settings clients need to log in through the
+	// identity provider configured for single sign-on. This route does not
+	// require authentication.
+	GetAuthConfig(ctx context.Context, in *GetAuthConfigRequest, opts ...grpc.CallOption) (*GetAuthConfigResponse, error)
+	// GetSystemStats returns a summary of run throughput, failure rates and
+	// queue depth for all namespaces the caller has access to.
+	GetSystemStats(ctx context.Context, in *GetSystemStatsRequest, opts ...grpc.CallOption) (*GetSystemStatsResponse, error)
+	// GetSlowReport returns the API routes and storage queries with the worst
+	// latency over the last few minutes, slowest first.
+	GetSlowReport(ctx context.Context, in *GetSlowReportRequest, opts ...grpc.CallOption) (*GetSlowReportResponse, error)
 	// RepairOrphan is used when a single run has gotten into a state that does
 	// not reflect what actually happened to the run. This can happen if the Gofer
 	// service crashes for unforeseen reasons. Usually this route is not needed as
@@ -176,6 +264,17 @@ type GoferClient interface {
 	// pipelines within Gofer. This can be useful under some security implications
 	// or for the purposes of defining general downtime and service maintenance.
 	ToggleEventIngress(ctx context.Context, in *ToggleEventIngressRequest, opts ...grpc.CallOption) (*ToggleEventIngressResponse, error)
+	// GetLogLevel returns the level the server is currently logging at, along
+	// with the levels set for individual modules.
+	GetLogLevel(ctx context.Context, in *GetLogLevelRequest, opts ...grpc.CallOption) (*GetLogLevelResponse, error)
+	// UpdateLogLevel changes the level the server logs at without restarting
+	// it. Levels can also be set for individual modules, which is useful for
+	// turning up logging for a single part of the server during an incident.
+	UpdateLogLevel(ctx context.Context, in *UpdateLogLevelRequest, opts ...grpc.CallOption) (*UpdateLogLevelResponse, error)
+	// CreateBackup streams a gzipped tarball containing a consistent copy of
+	// Gofer's databases. The backup is taken while Gofer is running; restoring
+	// it is done with the server stopped.
+	CreateBackup(ctx context.Context, in *CreateBackupRequest, opts ...grpc.CallOption) (Gofer_CreateBackupClient, error)
 	// CreateToken manifests a new API token; This token can be a management
 	// token(the equivalent of root in Linux) or a client token. Management tokens
 	// are the only tokens that can generate tokens.
@@ -186,8 +285,79 @@ type GoferClient interface {
 	BootstrapToken(ctx context.Context, in *BootstrapTokenRequest, opts ...grpc.CallOption) (*BootstrapTokenResponse, error)
 	// GetToken returns information about a particular token;
 	GetToken(ctx context.Context, in *GetTokenRequest, opts ...grpc.CallOption) (*GetTokenResponse, error)
+	// ListTokens returns all tokens for management users, along with their
+	// usage. Namespace admins only see the tokens they are able to manage.
+	// Tokens can be filtered by usage to find dormant or abused tokens.
+	ListTokens(ctx context.Context, in *ListTokensRequest, opts ...grpc.CallOption) (*ListTokensResponse, error)
+	// ListExpiringTokens returns the tokens that expire soon, ordered by when
+	// they expire. Namespace admins only see the tokens they are able to manage.
+	ListExpiringTokens(ctx context.Context, in *ListExpiringTokensRequest, opts ...grpc.CallOption) (*ListExpiringTokensResponse, error)
 	// DeleteToken removes a token.
 	DeleteToken(ctx context.Context, in *DeleteTokenRequest, opts ...grpc.CallOption) (*DeleteTokenResponse, error)
+	// DeleteUserTokens removes all tokens belonging to a user in one operation.
+	// A token belongs to a user if it was created for that directory user or if
+	// its "name" metadata matches. Useful when offboarding users or responding to
+	// incidents.
+	DeleteUserTokens(ctx context.Context, in *DeleteUserTokensRequest, opts ...grpc.CallOption) (*DeleteUserTokensResponse, error)
+	// RotateToken replaces a token with a new one that has the exact same
+	// settings. The old token stops working immediately unless a grace period is
+	// given. Tokens that expire are replaced with one that lasts just as long.
+	// Client tokens are only able to rotate themselves.
+	RotateToken(ctx context.Context, in *RotateTokenRequest, opts ...grpc.CallOption) (*RotateTokenResponse, error)
+	// ExchangeOIDCToken trades an ID token issued by the identity provider
+	// configured for single sign-on for a short-lived token. The token has the
+	// permissions mapped from the user's groups and claims. This route does not
+	// require authentication.
+	ExchangeOIDCToken(ctx context.Context, in *ExchangeOIDCTokenRequest, opts ...grpc.CallOption) (*ExchangeOIDCTokenResponse, error)
+	// ListServiceAccounts returns all registered service accounts.
+	ListServiceAccounts(ctx context.Context, in *ListServiceAccountsRequest, opts ...grpc.CallOption) (*ListServiceAccountsResponse, error)
+	// GetServiceAccount returns a single service account by id.
+	GetServiceAccount(ctx context.Context, in *GetServiceAccountRequest, opts ...grpc.CallOption) (*GetServiceAccountResponse, error)
+	// CreateServiceAccount creates a new identity meant for automation. Service
+	// accounts hold the permissions for all of their tokens.
+	CreateServiceAccount(ctx context.Context, in *CreateServiceAccountRequest, opts ...grpc.CallOption) (*CreateServiceAccountResponse, error)
+	// DeleteServiceAccount removes a service account and all of its tokens.
+	DeleteServiceAccount(ctx context.Context, in *DeleteServiceAccountRequest, opts ...grpc.CallOption) (*DeleteServiceAccountResponse, error)
+	// CreateServiceAccountToken creates a new token for a service account. A
+	// service account can hold multiple tokens so that they can be rotated
+	// without downtime.
+	CreateServiceAccountToken(ctx context.Context, in *CreateServiceAccountTokenRequest, opts ...grpc.CallOption) (*CreateServiceAccountTokenResponse, error)
+	// ListAuthzDecisions returns the authorization decisions recorded in the
+	// audit log, newest first. The audit log must be enabled in the server's
+	// configuration.
+	ListAuthzDecisions(ctx context.Context, in *ListAuthzDecisionsRequest, opts ...grpc.CallOption) (*ListAuthzDecisionsResponse, error)
+	// ListDeadLetters returns the webhook and extension dispatches that failed
+	// even after being retried, newest first.
+	ListDeadLetters(ctx context.Context, in *ListDeadLettersRequest, opts ...grpc.CallOption) (*ListDeadLettersResponse, error)
+	// GetDeadLetter returns a single dead letter by id.
+	GetDeadLetter(ctx context.Context, in *GetDeadLetterRequest, opts ...grpc.CallOption) (*GetDeadLetterResponse, error)
+	// RedriveDeadLetter attempts the dispatch again. The dead letter is removed
+	// if it succeeds.
+	RedriveDeadLetter(ctx context.Context, in *RedriveDeadLetterRequest, opts ...grpc.CallOption) (*RedriveDeadLetterResponse, error)
+	// DeleteDeadLetter removes a dead letter without attempting it again.
+	DeleteDeadLetter(ctx context.Context, in *DeleteDeadLetterRequest, opts ...grpc.CallOption) (*DeleteDeadLetterResponse, error)
+	// ListWebhooks returns the webhooks registered for a namespace.
+	ListWebhooks(ctx context.Context, in *ListWebhooksRequest, opts ...grpc.CallOption) (*ListWebhooksResponse, error)
+	// GetWebhook returns a single webhook by id.
+	GetWebhook(ctx context.Context, in *GetWebhookRequest, opts ...grpc.CallOption) (*GetWebhookResponse, error)
+	// CreateWebhook registers a URL to be sent the run lifecycle events of a
+	// namespace or a single pipeline. Deliveries are signed with the returned
+	// secret and retried if they fail.
+	CreateWebhook(ctx context.Context, in *CreateWebhookRequest, opts ...grpc.CallOption) (*CreateWebhookResponse, error)
+	// DeleteWebhook removes a webhook along with its delivery history.
+	DeleteWebhook(ctx context.Context, in *DeleteWebhookRequest, opts ...grpc.CallOption) (*DeleteWebhookResponse, error)
+	// ListWebhookDeliveries returns the most recent deliveries to a webhook,
+	// newest first.
+	ListWebhookDeliveries(ctx context.Context, in *ListWebhookDeliveriesRequest, opts ...grpc.CallOption) (*ListWebhookDeliveriesResponse, error)
+	// ListRegistryCredentials returns the registry credentials of a namespace.
+	// Passwords are never returned.
+	ListRegistryCredentials(ctx context.Context, in *ListRegistryCredentialsRequest, opts ...grpc.CallOption) (*ListRegistryCredentialsResponse, error)
+	// PutRegistryCredential adds the login for a registry to a namespace,
+	// replacing the one already there.
+	PutRegistryCredential(ctx context.Context, in *PutRegistryCredentialRequest, opts ...grpc.CallOption) (*PutRegistryCredentialResponse, error)
+	// DeleteRegistryCredential removes the login for a registry from a
+	// namespace.
+	DeleteRegistryCredential(ctx context.Context, in *DeleteRegistryCredentialRequest, opts ...grpc.CallOption) (*DeleteRegistryCredentialResponse, error)
 }
 
 type goferClient struct {
@@ -243,6 +413,24 @@ func (c *goferClient) DeleteNamespace(ctx context.Context, in *DeleteNamespaceRe
 	return out, nil
 }
 
+func (c *goferClient) GetNamespaceQuota(ctx context.Context, in *GetNamespaceQuotaRequest, opts ...grpc.CallOption) (*GetNamespaceQuotaResponse, error) {
+	out := new(GetNamespaceQuotaResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetNamespaceQuota", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) UpdateNamespaceQuota(ctx context.Context, in *UpdateNamespaceQuotaRequest, opts ...grpc.CallOption) (*UpdateNamespaceQuotaResponse, error) {
+	out := new(UpdateNamespaceQuotaResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/UpdateNamespaceQuota", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *goferClient) GetPipeline(ctx context.Context, in *GetPipelineRequest, opts ...grpc.CallOption) (*GetPipelineResponse, error) {
 	out := new(GetPipelineResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/GetPipeline", in, out, opts...)
@@ -315,6 +503,51 @@ func (c *goferClient) UpdatePipelineByURL(ctx context.Context, in *UpdatePipelin
 	return out, nil
 }
 
+func (c *goferClient) ListPipelineConfigVersions(ctx context.Context, in *ListPipelineConfigVersionsRequest, opts ...grpc.CallOption) (*ListPipelineConfigVersionsResponse, error) {
+	out := new(ListPipelineConfigVersionsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListPipelineConfigVersions", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) RollbackPipelineConfig(ctx context.Context, in *RollbackPipelineConfigRequest, opts ...grpc.CallOption) (*RollbackPipelineConfigResponse, error) {
+	out := new(RollbackPipelineConfigResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/RollbackPipelineConfig", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) StartPipelineCanary(ctx context.Context, in *StartPipelineCanaryRequest, opts ...grpc.CallOption) (*StartPipelineCanaryResponse, error) {
+	out := new(StartPipelineCanaryResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/StartPipelineCanary", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) PromotePipelineCanary(ctx context.Context, in *PromotePipelineCanaryRequest, opts ...grpc.CallOption) (*PromotePipelineCanaryResponse, error) {
+	out := new(PromotePipelineCanaryResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/PromotePipelineCanary", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) AbortPipelineCanary(ctx context.Context, in *AbortPipelineCanaryRequest, opts ...grpc.CallOption) (*AbortPipelineCanaryResponse, error) {
+	out := new(AbortPipelineCanaryResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/AbortPipelineCanary", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *goferClient) AbandonPipeline(ctx context.Context, in *AbandonPipelineRequest, opts ...grpc.CallOption) (*AbandonPipelineResponse, error) {
 	out := new(AbandonPipelineResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/AbandonPipeline", in, out, opts...)
@@ -437,6 +670,42 @@ func (x *goferListEventsClient) Recv() (*ListEventsResponse, error) {
 	return m, nil
 }
 
+func (c *goferClient) AcknowledgeEvent(ctx context.Context, in *AcknowledgeEventRequest, opts ...grpc.CallOption) (*AcknowledgeEventResponse, error) {
+	out := new(AcknowledgeEventResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/AcknowledgeEvent", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) PublishExtensionEvent(ctx context.Context, in *PublishExtensionEventRequest, opts ...grpc.CallOption) (*PublishExtensionEventResponse, error) {
+	out := new(PublishExtensionEventResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/PublishExtensionEvent", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListEventConsumers(ctx context.Context, in *ListEventConsumersRequest, opts ...grpc.CallOption) (*ListEventConsumersResponse, error) {
+	out := new(ListEventConsumersResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListEventConsumers", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) DeleteEventConsumer(ctx context.Context, in *DeleteEventConsumerRequest, opts ...grpc.CallOption) (*DeleteEventConsumerResponse, error) {
+	out := new(DeleteEventConsumerResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteEventConsumer", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *goferClient) GetRun(ctx context.Context, in *GetRunRequest, opts ...grpc.CallOption) (*GetRunResponse, error) {
 	out := new(GetRunResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/GetRun", in, out, opts...)
@@ -527,6 +796,15 @@ func (c *goferClient) CancelTaskRun(ctx context.Context, in *CancelTaskRunReques
 	return out, nil
 }
 
+func (c *goferClient) SpawnTaskRun(ctx context.Context, in *SpawnTaskRunRequest, opts ...grpc.CallOption) (*SpawnTaskRunResponse, error) {
+	out := new(SpawnTaskRunResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/SpawnTaskRun", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *goferClient) GetTaskRunLogs(ctx context.Context, in *GetTaskRunLogsRequest, opts ...grpc.CallOption) (Gofer_GetTaskRunLogsClient, error) {
 	stream, err := c.cc.NewStream(ctx, &Gofer_ServiceDesc.Streams[1], "/proto.Gofer/GetTaskRunLogs", opts...)
 	if err != nil {
@@ -568,6 +846,37 @@ func (c *goferClient) DeleteTaskRunLogs(ctx context.Context, in *DeleteTaskRunLo
 	return out, nil
 }
 
+func (c *goferClient) AttachToTaskRun(ctx context.Context, opts ...grpc.CallOption) (Gofer_AttachToTaskRunClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Gofer_ServiceDesc.Streams[2], "/proto.Gofer/AttachToTaskRun", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &goferAttachToTaskRunClient{stream}
+	return x, nil
+}
+
+type Gofer_AttachToTaskRunClient interface {
+	Send(*AttachToTaskRunRequest) error
+	Recv() (*AttachToTaskRunOutput, error)
+	grpc.ClientStream
+}
+
+type goferAttachToTaskRunClient struct {
+	grpc.ClientStream
+}
+
+func (x *goferAttachToTaskRunClient) Send(m *AttachToTaskRunRequest) error {
+	return x.ClientStream.SendMsg(m)
+}
+
+func (x *goferAttachToTaskRunClient) Recv() (*AttachToTaskRunOutput, error) {
+	m := new(AttachToTaskRunOutput)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func (c *goferClient) GetPipelineObject(ctx context.Context, in *GetPipelineObjectRequest, opts ...grpc.CallOption) (*GetPipelineObjectResponse, error) {
 	out := new(GetPipelineObjectResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/GetPipelineObject", in, out, opts...)
@@ -577,6 +886,38 @@ func (c *goferClient) GetPipelineObject(ctx context.Context, in *GetPipelineObje
 	return out, nil
 }
 
+func (c *goferClient) GetPipelineObjectStream(ctx context.Context, in *GetPipelineObjectStreamRequest, opts ...grpc.CallOption) (Gofer_GetPipelineObjectStreamClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Gofer_ServiceDesc.Streams[3], "/proto.Gofer/GetPipelineObjectStream", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &goferGetPipelineObjectStreamClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type Gofer_GetPipelineObjectStreamClient interface {
+	Recv() (*GetPipelineObjectStreamResponse, error)
+	grpc.ClientStream
+}
+
+type goferGetPipelineObjectStreamClient struct {
+	grpc.ClientStream
+}
+
+func (x *goferGetPipelineObjectStreamClient) Recv() (*GetPipelineObjectStreamResponse, error) {
+	m := new(GetPipelineObjectStreamResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func (c *goferClient) PutPipelineObject(ctx context.Context, in *PutPipelineObjectRequest, opts ...grpc.CallOption) (*PutPipelineObjectResponse, error) {
 	out := new(PutPipelineObjectResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/PutPipelineObject", in, out, opts...)
@@ -586,6 +927,40 @@ func (c *goferClient) PutPipelineObject(ctx context.Context, in *PutPipelineObje
 	return out, nil
 }
 
+func (c *goferClient) PutPipelineObjectStream(ctx context.Context, opts ...grpc.CallOption) (Gofer_PutPipelineObjectStreamClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Gofer_ServiceDesc.Streams[4], "/proto.Gofer/PutPipelineObjectStream", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &goferPutPipelineObjectStreamClient{stream}
+	return x, nil
+}
+
+type Gofer_PutPipelineObjectStreamClient interface {
+	Send(*PutPipelineObjectRequest) error
+	CloseAndRecv() (*PutPipelineObjectResponse, error)
+	grpc.ClientStream
+}
+
+type goferPutPipelineObjectStreamClient struct {
+	grpc.ClientStream
+}
+
+func (x *goferPutPipelineObjectStreamClient) Send(m *PutPipelineObjectRequest) error {
+	return x.ClientStream.SendMsg(m)
+}
+
+func (x *goferPutPipelineObjectStreamClient) CloseAndRecv() (*PutPipelineObjectResponse, error) {
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	m := new(PutPipelineObjectResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func (c *goferClient) DeletePipelineObject(ctx context.Context, in *DeletePipelineObjectRequest, opts ...grpc.CallOption) (*DeletePipelineObjectResponse, error) {
 	out := new(DeletePipelineObjectResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/DeletePipelineObject", in, out, opts...)
@@ -613,6 +988,40 @@ func (c *goferClient) PutRunObject(ctx context.Context, in *PutRunObjectRequest,
 	return out, nil
 }
 
+func (c *goferClient) PutRunObjectStream(ctx context.Context, opts ...grpc.CallOption) (Gofer_PutRunObjectStreamClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Gofer_ServiceDesc.Streams[5], "/proto.Gofer/PutRunObjectStream", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &goferPutRunObjectStreamClient{stream}
+	return x, nil
+}
+
+type Gofer_PutRunObjectStreamClient interface {
+	Send(*PutRunObjectRequest) error
+	CloseAndRecv() (*PutRunObjectResponse, error)
+	grpc.ClientStream
+}
+
+type goferPutRunObjectStreamClient struct {
+	grpc.ClientStream
+}
+
+func (x *goferPutRunObjectStreamClient) Send(m *PutRunObjectRequest) error {
+	return x.ClientStream.SendMsg(m)
+}
+
+func (x *goferPutRunObjectStreamClient) CloseAndRecv() (*PutRunObjectResponse, error) {
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	m := new(PutRunObjectResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func (c *goferClient) DeleteRunObject(ctx context.Context, in *DeleteRunObjectRequest, opts ...grpc.CallOption) (*DeleteRunObjectResponse, error) {
 	out := new(DeleteRunObjectResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteRunObject", in, out, opts...)
@@ -622,6 +1031,56 @@ func (c *goferClient) DeleteRunObject(ctx context.Context, in *DeleteRunObjectRe
 	return out, nil
 }
 
+func (c *goferClient) CollectOrphanedObjects(ctx context.Context, in *CollectOrphanedObjectsRequest, opts ...grpc.CallOption) (*CollectOrphanedObjectsResponse, error) {
+	out := new(CollectOrphanedObjectsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/CollectOrphanedObjects", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListRunArtifacts(ctx context.Context, in *ListRunArtifactsRequest, opts ...grpc.CallOption) (*ListRunArtifactsResponse, error) {
+	out := new(ListRunArtifactsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListRunArtifacts", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) GetArtifactStream(ctx context.Context, in *GetArtifactStreamRequest, opts ...grpc.CallOption) (Gofer_GetArtifactStreamClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Gofer_ServiceDesc.Streams[6], "/proto.Gofer/GetArtifactStream", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &goferGetArtifactStreamClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
+}
+
+type Gofer_GetArtifactStreamClient interface {
+	Recv() (*GetArtifactStreamResponse, error)
+	grpc.ClientStream
+}
+
+type goferGetArtifactStreamClient struct {
+	grpc.ClientStream
+}
+
+func (x *goferGetArtifactStreamClient) Recv() (*GetArtifactStreamResponse, error) {
+	m := new(GetArtifactStreamResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func (c *goferClient) GetSecret(ctx context.Context, in *GetSecretRequest, opts ...grpc.CallOption) (*GetSecretResponse, error) {
 	out := new(GetSecretResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/GetSecret", in, out, opts...)
@@ -649,6 +1108,15 @@ func (c *goferClient) DeleteSecret(ctx context.Context, in *DeleteSecretRequest,
 	return out, nil
 }
 
+func (c *goferClient) ReencryptSecrets(ctx context.Context, in *ReencryptSecretsRequest, opts ...grpc.CallOption) (*ReencryptSecretsResponse, error) {
+	out := new(ReencryptSecretsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ReencryptSecrets", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *goferClient) GetSystemInfo(ctx context.Context, in *GetSystemInfoRequest, opts ...grpc.CallOption) (*GetSystemInfoResponse, error) {
 	out := new(GetSystemInfoResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/GetSystemInfo", in, out, opts...)
@@ -658,6 +1126,33 @@ func (c *goferClient) GetSystemInfo(ctx context.Context, in *GetSystemInfoReques
 	return out, nil
 }
 
+func (c *goferClient) GetAuthConfig(ctx context.Context, in *GetAuthConfigRequest, opts ...grpc.CallOption) (*GetAuthConfigResponse, error) {
+	out := new(GetAuthConfigResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetAuthConfig", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) GetSystemStats(ctx context.Context, in *GetSystemStatsRequest, opts ...grpc.CallOption) (*GetSystemStatsResponse, error) {
+	out := new(GetSystemStatsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetSystemStats", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) GetSlowReport(ctx context.Context, in *GetSlowReportRequest, opts ...grpc.CallOption) (*GetSlowReportResponse, error) {
+	out := new(GetSlowReportResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetSlowReport", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 func (c *goferClient) RepairOrphan(ctx context.Context, in *RepairOrphanRequest, opts ...grpc.CallOption) (*RepairOrphanResponse, error) {
 	out := new(RepairOrphanResponse)
 	err := c.cc.Invoke(ctx, "/proto.Gofer/RepairOrphan", in, out, opts...)
@@ -676,40 +1171,297 @@ func (c *goferClient) ToggleEventIngress(ctx context.Context, in *ToggleEventIng
 	return out, nil
 }
 
-func (c *goferClient) CreateToken(ctx context.Context, in *CreateTokenRequest, opts ...grpc.CallOption) (*CreateTokenResponse, error) {
-	out := new(CreateTokenResponse)
-	err := c.cc.Invoke(ctx, "/proto.Gofer/CreateToken", in, out, opts...)
+func (c *goferClient) GetLogLevel(ctx context.Context, in *GetLogLevelRequest, opts ...grpc.CallOption) (*GetLogLevelResponse, error) {
+	out := new(GetLogLevelResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetLogLevel", in, out, opts...)
 	if err != nil {
 		return nil, err
 	}
 	return out, nil
 }
 
-func (c *goferClient) BootstrapToken(ctx context.Context, in *BootstrapTokenRequest, opts ...grpc.CallOption) (*BootstrapTokenResponse, error) {
-	out := new(BootstrapTokenResponse)
-	err := c.cc.Invoke(ctx, "/proto.Gofer/BootstrapToken", in, out, opts...)
+func (c *goferClient) UpdateLogLevel(ctx context.Context, in *UpdateLogLevelRequest, opts ...grpc.CallOption) (*UpdateLogLevelResponse, error) {
+	out := new(UpdateLogLevelResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/UpdateLogLevel", in, out, opts...)
 	if err != nil {
 		return nil, err
 	}
 	return out, nil
 }
 
-func (c *goferClient) GetToken(ctx context.Context, in *GetTokenRequest, opts ...grpc.CallOption) (*GetTokenResponse, error) {
-	out := new(GetTokenResponse)
-	err := c.cc.Invoke(ctx, "/proto.Gofer/GetToken", in, out, opts...)
+func (c *goferClient) CreateBackup(ctx context.Context, in *CreateBackupRequest, opts ...grpc.CallOption) (Gofer_CreateBackupClient, error) {
+	stream, err := c.cc.NewStream(ctx, &Gofer_ServiceDesc.Streams[7], "/proto.Gofer/CreateBackup", opts...)
 	if err != nil {
 		return nil, err
 	}
-	return out, nil
+	x := &goferCreateBackupClient{stream}
+	if err := x.ClientStream.SendMsg(in); err != nil {
+		return nil, err
+	}
+	if err := x.ClientStream.CloseSend(); err != nil {
+		return nil, err
+	}
+	return x, nil
 }
 
-func (c *goferClient) DeleteToken(ctx context.Context, in *DeleteTokenRequest, opts ...grpc.CallOption) (*DeleteTokenResponse, error) {
-	out := new(DeleteTokenResponse)
-	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteToken", in, out, opts...)
-	if err != nil {
+type Gofer_CreateBackupClient interface {
+	Recv() (*CreateBackupResponse, error)
+	grpc.ClientStream
+}
+
+type goferCreateBackupClient struct {
+	grpc.ClientStream
+}
+
+func (x *goferCreateBackupClient) Recv() (*CreateBackupResponse, error) {
+	m := new(CreateBackupResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
 		return nil, err
 	}
-	return out, nil
+	return m, nil
+}
+
+func (c *goferClient) CreateToken(ctx context.Context, in *CreateTokenRequest, opts ...grpc.CallOption) (*CreateTokenResponse, error) {
+	out := new(CreateTokenResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/CreateToken", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) BootstrapToken(ctx context.Context, in *BootstrapTokenRequest, opts ...grpc.CallOption) (*BootstrapTokenResponse, error) {
+	out := new(BootstrapTokenResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/BootstrapToken", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) GetToken(ctx context.Context, in *GetTokenRequest, opts ...grpc.CallOption) (*GetTokenResponse, error) {
+	out := new(GetTokenResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetToken", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListTokens(ctx context.Context, in *ListTokensRequest, opts ...grpc.CallOption) (*ListTokensResponse, error) {
+	out := new(ListTokensResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListTokens", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListExpiringTokens(ctx context.Context, in *ListExpiringTokensRequest, opts ...grpc.CallOption) (*ListExpiringTokensResponse, error) {
+	out := new(ListExpiringTokensResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListExpiringTokens", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) DeleteToken(ctx context.Context, in *DeleteTokenRequest, opts ...grpc.CallOption) (*DeleteTokenResponse, error) {
+	out := new(DeleteTokenResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteToken", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) DeleteUserTokens(ctx context.Context, in *DeleteUserTokensRequest, opts ...grpc.CallOption) (*DeleteUserTokensResponse, error) {
+	out := new(DeleteUserTokensResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteUserTokens", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) RotateToken(ctx context.Context, in *RotateTokenRequest, opts ...grpc.CallOption) (*RotateTokenResponse, error) {
+	out := new(RotateTokenResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/RotateToken", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ExchangeOIDCToken(ctx context.Context, in *ExchangeOIDCTokenRequest, opts ...grpc.CallOption) (*ExchangeOIDCTokenResponse, error) {
+	out := new(ExchangeOIDCTokenResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ExchangeOIDCToken", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListServiceAccounts(ctx context.Context, in *ListServiceAccountsRequest, opts ...grpc.CallOption) (*ListServiceAccountsResponse, error) {
+	out := new(ListServiceAccountsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListServiceAccounts", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) GetServiceAccount(ctx context.Context, in *GetServiceAccountRequest, opts ...grpc.CallOption) (*GetServiceAccountResponse, error) {
+	out := new(GetServiceAccountResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetServiceAccount", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) CreateServiceAccount(ctx context.Context, in *CreateServiceAccountRequest, opts ...grpc.CallOption) (*CreateServiceAccountResponse, error) {
+	out := new(CreateServiceAccountResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/CreateServiceAccount", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) DeleteServiceAccount(ctx context.Context, in *DeleteServiceAccountRequest, opts ...grpc.CallOption) (*DeleteServiceAccountResponse, error) {
+	out := new(DeleteServiceAccountResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteServiceAccount", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) CreateServiceAccountToken(ctx context.Context, in *CreateServiceAccountTokenRequest, opts ...grpc.CallOption) (*CreateServiceAccountTokenResponse, error) {
+	out := new(CreateServiceAccountTokenResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/CreateServiceAccountToken", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListAuthzDecisions(ctx context.Context, in *ListAuthzDecisionsRequest, opts ...grpc.CallOption) (*ListAuthzDecisionsResponse, error) {
+	out := new(ListAuthzDecisionsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListAuthzDecisions", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListDeadLetters(ctx context.Context, in *ListDeadLettersRequest, opts ...grpc.CallOption) (*ListDeadLettersResponse, error) {
+	out := new(ListDeadLettersResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListDeadLetters", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) GetDeadLetter(ctx context.Context, in *GetDeadLetterRequest, opts ...grpc.CallOption) (*GetDeadLetterResponse, error) {
+	out := new(GetDeadLetterResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetDeadLetter", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) RedriveDeadLetter(ctx context.Context, in *RedriveDeadLetterRequest, opts ...grpc.CallOption) (*RedriveDeadLetterResponse, error) {
+	out := new(RedriveDeadLetterResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/RedriveDeadLetter", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) DeleteDeadLetter(ctx context.Context, in *DeleteDeadLetterRequest, opts ...grpc.CallOption) (*DeleteDeadLetterResponse, error) {
+	out := new(DeleteDeadLetterResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteDeadLetter", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListWebhooks(ctx context.Context, in *ListWebhooksRequest, opts ...grpc.CallOption) (*ListWebhooksResponse, error) {
+	out := new(ListWebhooksResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListWebhooks", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) GetWebhook(ctx context.Context, in *GetWebhookRequest, opts ...grpc.CallOption) (*GetWebhookResponse, error) {
+	out := new(GetWebhookResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/GetWebhook", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) CreateWebhook(ctx context.Context, in *CreateWebhookRequest, opts ...grpc.CallOption) (*CreateWebhookResponse, error) {
+	out := new(CreateWebhookResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/CreateWebhook", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) DeleteWebhook(ctx context.Context, in *DeleteWebhookRequest, opts ...grpc.CallOption) (*DeleteWebhookResponse, error) {
+	out := new(DeleteWebhookResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteWebhook", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListWebhookDeliveries(ctx context.Context, in *ListWebhookDeliveriesRequest, opts ...grpc.CallOption) (*ListWebhookDeliveriesResponse, error) {
+	out := new(ListWebhookDeliveriesResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListWebhookDeliveries", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) ListRegistryCredentials(ctx context.Context, in *ListRegistryCredentialsRequest, opts ...grpc.CallOption) (*ListRegistryCredentialsResponse, error) {
+	out := new(ListRegistryCredentialsResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/ListRegistryCredentials", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) PutRegistryCredential(ctx context.Context, in *PutRegistryCredentialRequest, opts ...grpc.CallOption) (*PutRegistryCredentialResponse, error) {
+	out := new(PutRegistryCredentialResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/PutRegistryCredential", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *goferClient) DeleteRegistryCredential(ctx context.Context, in *DeleteRegistryCredentialRequest, opts ...grpc.CallOption) (*DeleteRegistryCredentialResponse, error) {
+	out := new(DeleteRegistryCredentialResponse)
+	err := c.cc.Invoke(ctx, "/proto.Gofer/DeleteRegistryCredential", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
 }
 
 // GoferServer is the server API for Gofer service.
@@ -726,6 +1478,11 @@ type GoferServer interface {
 	UpdateNamespace(context.Context, *UpdateNamespaceRequest) (*UpdateNamespaceResponse, error)
 	// DeleteNamespace removes a namespace by id.
 	DeleteNamespace(context.Context, *DeleteNamespaceRequest) (*DeleteNamespaceResponse, error)
+	// GetNamespaceQuota returns the resource limits of a namespace along with its
+	// current consumption.
+	GetNamespaceQuota(context.Context, *GetNamespaceQuotaRequest) (*GetNamespaceQuotaResponse, error)
+	// UpdateNamespaceQuota replaces the resource limits of a namespace.
+	UpdateNamespaceQuota(context.Context, *UpdateNamespaceQuotaRequest) (*UpdateNamespaceQuotaResponse, error)
 	// GetPipeline returns a single pipeline by ID.
 	GetPipeline(context.Context, *GetPipelineRequest) (*GetPipelineResponse, error)
 	// ListPipelines returns all registered pipelines. Can control pagination by
@@ -766,6 +1523,30 @@ type GoferServer interface {
 	//    1) The pipeline must not have any current runs in progress.
 	//    2) The pipeline must be in a disabled state.
 	UpdatePipelineByURL(context.Context, *UpdatePipelineByURLRequest) (*UpdatePipelineByURLResponse, error)
+	// ListPipelineConfigVersions returns the configurations a pipeline has been
+	// registered with, newest first. A new version is recorded every time the
+	// pipeline is created, updated or rolled back.
+	ListPipelineConfigVersions(context.Context, *ListPipelineConfigVersionsRequest) (*ListPipelineConfigVersionsResponse, error)
+	// RollbackPipelineConfig updates a pipeline to a previously registered
+	// configuration version. Rollback has the same requirements as updating a
+	// pipeline:
+	//    1) The pipeline must not have any current runs in progress.
+	//    2) The pipeline must be in a disabled state.
+	RollbackPipelineConfig(context.Context, *RollbackPipelineConfigRequest) (*RollbackPipelineConfigResponse, error)
+	// StartPipelineCanary registers a new config version for a pipeline without
+	// replacing the one it currently uses. Instead, a percentage of new runs(or
+	// only those started manually) use the tasks of the new version until the
+	// canary is promoted or aborted. Only one canary can be in progress at a
+	// time.
+	StartPipelineCanary(context.Context, *StartPipelineCanaryRequest) (*StartPipelineCanaryResponse, error)
+	// PromotePipelineCanary updates a pipeline to the config version its canary
+	// is trying out. Promotion has the same requirements as updating a pipeline:
+	//    1) The pipeline must not have any current runs in progress.
+	//    2) The pipeline must be in a disabled state.
+	PromotePipelineCanary(context.Context, *PromotePipelineCanaryRequest) (*PromotePipelineCanaryResponse, error)
+	// AbortPipelineCanary stops a pipeline's canary; all new runs use the
+	// pipeline's current config version again.
+	AbortPipelineCanary(context.Context, *AbortPipelineCanaryRequest) (*AbortPipelineCanaryResponse, error)
 	// AbandonPipeline disables a pipeline permanently. This removes all triggers
 	// and prevents the pipeline from ever being triggered again.
 	AbandonPipeline(context.Context, *AbandonPipelineRequest) (*AbandonPipelineResponse, error)
@@ -790,6 +1571,18 @@ type GoferServer interface {
 	// ListEvents returns a streaming list of all events, ordered by
 	// oldest to newest.
 	ListEvents(*ListEventsRequest, Gofer_ListEventsServer) error
+	// AcknowledgeEvent records that an event consumer has processed all events up
+	// to and including the given event.
+	AcknowledgeEvent(context.Context, *AcknowledgeEventRequest) (*AcknowledgeEventResponse, error)
+	// PublishExtensionEvent allows extensions to publish their own events(ex. a
+	// pull request being opened) so they show up in the event history and are
+	// sent to event sinks. Extensions authenticate with the key Gofer gave them
+	// on startup instead of an API token.
+	PublishExtensionEvent(context.Context, *PublishExtensionEventRequest) (*PublishExtensionEventResponse, error)
+	// ListEventConsumers returns all event consumers and their positions.
+	ListEventConsumers(context.Context, *ListEventConsumersRequest) (*ListEventConsumersResponse, error)
+	// DeleteEventConsumer removes an event consumer.
+	DeleteEventConsumer(context.Context, *DeleteEventConsumerRequest) (*DeleteEventConsumerResponse, error)
 	// GetRun returns the details of a single run.
 	GetRun(context.Context, *GetRunRequest) (*GetRunResponse, error)
 	// BatchGetRuns returns multiple runs by ID.
@@ -804,7 +1597,8 @@ type GoferServer interface {
 	StartRun(context.Context, *StartRunRequest) (*StartRunResponse, error)
 	// RetryRun simply takes the vars and settings from a previous run and re-uses
 	// those to launch a new run. Useful for if you want the exact settings from a
-	// previous run.
+	// previous run. Passing "from_task" resumes the previous run instead; only the
+	// given task and the tasks that depend on it are run again.
 	RetryRun(context.Context, *RetryRunRequest) (*RetryRunResponse, error)
 	// CancelRun stops the execution of a run in progress. Any task runs that
 	// might have been running at the time Are ask to stop gracefully(SIGINT)
@@ -825,6 +1619,10 @@ type GoferServer interface {
 	// Task runs that are cancelled can cause other downstream task runs to be
 	// skipped depending on those downstream task run dependencies.
 	CancelTaskRun(context.Context, *CancelTaskRunRequest) (*CancelTaskRunResponse, error)
+	// SpawnTaskRun adds a task to a run that is still in progress. It's meant to
+	// be called by the run's own task runs using the token Gofer passes them,
+	// allowing a task to fan out into more tasks based on what it finds.
+	SpawnTaskRun(context.Context, *SpawnTaskRunRequest) (*SpawnTaskRunResponse, error)
 	// GetTaskRunLogs returns logs for a specific task run line by line in a
 	// stream. The logs are returns with both STDOUT and STDERR of the associated
 	// container combined.
@@ -832,12 +1630,25 @@ type GoferServer interface {
 	// DeleteTaskRunLogs removes a task run's associated log object. This is
 	// useful for if logs mistakenly contain sensitive data.
 	DeleteTaskRunLogs(context.Context, *DeleteTaskRunLogsRequest) (*DeleteTaskRunLogsResponse, error)
+	// AttachToTaskRun runs a command inside a running task run's container and
+	// connects the caller to it through a pseudo-terminal. The first message
+	// must be a start message describing the task run to attach to; the messages
+	// that follow carry terminal input and terminal size changes.
+	AttachToTaskRun(Gofer_AttachToTaskRunServer) error
 	// GetPipelineObject returns a single pipeline object by pipeline ID and key.
 	GetPipelineObject(context.Context, *GetPipelineObjectRequest) (*GetPipelineObjectResponse, error)
+	// GetPipelineObjectStream returns a single pipeline object in chunks so
+	// that large objects never have to be held in memory. Starting from an
+	// offset allows an interrupted download to be resumed.
+	GetPipelineObjectStream(*GetPipelineObjectStreamRequest, Gofer_GetPipelineObjectStreamServer) error
 	// PutPipelineObject uploads a single pipeline object by pipeline ID and key.
 	// Objects which are put under the same key do not count towards the pipeline
 	// object limit.
 	PutPipelineObject(context.Context, *PutPipelineObjectRequest) (*PutPipelineObjectResponse, error)
+	// PutPipelineObjectStream uploads a single pipeline object in chunks. The
+	// first message must contain the namespace, pipeline, key and force fields;
+	// only the content of the messages that follow is read.
+	PutPipelineObjectStream(Gofer_PutPipelineObjectStreamServer) error
 	// DeletePipelineObject removes a single pipeline object by pipeline ID and
 	// key. Removing a pipeline object decrements the total count of the pipeline
 	// object limit.
@@ -846,8 +1657,23 @@ type GoferServer interface {
 	GetRunObject(context.Context, *GetRunObjectRequest) (*GetRunObjectResponse, error)
 	// PutRunObject uploads the context of an object by run ID and key.
 	PutRunObject(context.Context, *PutRunObjectRequest) (*PutRunObjectResponse, error)
+	// PutRunObjectStream uploads a single run object in chunks. The first
+	// message must contain the namespace, pipeline, run, key and force fields;
+	// only the content of the messages that follow is read.
+	PutRunObjectStream(Gofer_PutRunObjectStreamServer) error
 	// DeleteRunObject removes a specific run object by run ID and key.
 	DeleteRunObject(context.Context, *DeleteRunObjectRequest) (*DeleteRunObjectResponse, error)
+	// CollectOrphanedObjects removes objects whose pipeline or run no longer
+	// exists. Deleting a pipeline or run doesn't remove its objects so they
+	// otherwise stay in the object store forever. A dry run only reports the
+	// objects that would be removed.
+	CollectOrphanedObjects(context.Context, *CollectOrphanedObjectsRequest) (*CollectOrphanedObjectsResponse, error)
+	// ListRunArtifacts returns the artifacts collected from the task runs of a
+	// run.
+	ListRunArtifacts(context.Context, *ListRunArtifactsRequest) (*ListRunArtifactsResponse, error)
+	// GetArtifactStream returns the content of a single artifact in chunks.
+	// Directories are returned as a tar archive.
+	GetArtifactStream(*GetArtifactStreamRequest, Gofer_GetArtifactStreamServer) error
 	// GetSecret returns a single secret by pipeline ID and key.
 	GetSecret(context.Context, *GetSecretRequest) (*GetSecretResponse, error)
 	// PutSecret uploads a single secret by pipeline ID and key.
@@ -855,8 +1681,22 @@ type GoferServer interface {
 	// DeleteSecret removes a single secret by pipeline ID and
 	// key.
 	DeleteSecret(context.Context, *DeleteSecretRequest) (*DeleteSecretResponse, error)
+	// ReencryptSecrets re-encrypts every secret that isn't encrypted with the
+	// secret store's current encryption key so that previous keys can be
+	// removed from the configuration. Secrets remain usable while it runs.
+	ReencryptSecrets(context.Context, *ReencryptSecretsRequest) (*ReencryptSecretsResponse, error)
 	// GetSystemInfo returns system information and general health.
 	GetSystemInfo(context.Context, *GetSystemInfoRequest) (*GetSystemInfoResponse, error)
+	// GetAuthConfig returns the settings clients need to log in through the
+	// identity provider configured for single sign-on. This route does not
+	// require authentication.
+	GetAuthConfig(context.Context, *GetAuthConfigRequest) (*GetAuthConfigResponse, error)
+	// GetSystemStats returns a summary of run throughput, failure rates and
+	// queue depth for all namespaces the caller has access to.
+	GetSystemStats(context.Context, *GetSystemStatsRequest) (*GetSystemStatsResponse, error)
+	// GetSlowReport returns the API routes and storage queries with the worst
+	// latency over the last few minutes, slowest first.
+	GetSlowReport(context.Context, *GetSlowReportRequest) (*GetSlowReportResponse, error)
 	// RepairOrphan is used when a single run has gotten into a state that does
 	// not reflect what actually happened to the run. This can happen if the Gofer
 	// service crashes for unforeseen reasons. Usually this route is not needed as
@@ -870,6 +1710,17 @@ type GoferServer interface {
 	// pipelines within Gofer. This can be useful under some security implications
 	// or for the purposes of defining general downtime and service maintenance.
 	ToggleEventIngress(context.Context, *ToggleEventIngressRequest) (*ToggleEventIngressResponse, error)
+	// GetLogLevel returns the level the server is currently logging at, along
+	// with the levels set for individual modules.
+	GetLogLevel(context.Context, *GetLogLevelRequest) (*GetLogLevelResponse, error)
+	// UpdateLogLevel changes the level the server logs at without restarting
+	// it. Levels can also be set for individual modules, which is useful for
+	// turning up logging for a single part of the server during an incident.
+	UpdateLogLevel(context.Context, *UpdateLogLevelRequest) (*UpdateLogLevelResponse, error)
+	// CreateBackup streams a gzipped tarball containing a consistent copy of
+	// Gofer's databases. The backup is taken while Gofer is running; restoring
+	// it is done with the server stopped.
+	CreateBackup(*CreateBackupRequest, Gofer_CreateBackupServer) error
 	// CreateToken manifests a new API token; This token can be a management
 	// token(the equivalent of root in Linux) or a client token. Management tokens
 	// are the only tokens that can generate tokens.
@@ -880,8 +1731,79 @@ type GoferServer interface {
 	BootstrapToken(context.Context, *BootstrapTokenRequest) (*BootstrapTokenResponse, error)
 	// GetToken returns information about a particular token;
 	GetToken(context.Context, *GetTokenRequest) (*GetTokenResponse, error)
+	// ListTokens returns all tokens for management users, along with their
+	// usage. Namespace admins only see the tokens they are able to manage.
+	// Tokens can be filtered by usage to find dormant or abused tokens.
+	ListTokens(context.Context, *ListTokensRequest) (*ListTokensResponse, error)
+	// ListExpiringTokens returns the tokens that expire soon, ordered by when
+	// they expire. Namespace admins only see the tokens they are able to manage.
+	ListExpiringTokens(context.Context, *ListExpiringTokensRequest) (*ListExpiringTokensResponse, error)
 	// DeleteToken removes a token.
 	DeleteToken(context.Context, *DeleteTokenRequest) (*DeleteTokenResponse, error)
+	// DeleteUserTokens removes all tokens belonging to a user in one operation.
+	// A token belongs to a user if it was created for that directory user or if
+	// its "name" metadata matches. Useful when offboarding users or responding to
+	// incidents.
+	DeleteUserTokens(context.Context, *DeleteUserTokensRequest) (*DeleteUserTokensResponse, error)
+	// RotateToken replaces a token with a new one that has the exact same
+	// settings. The old token stops working immediately unless a grace period is
+	// given. Tokens that expire are replaced with one that lasts just as long.
+	// Client tokens are only able to rotate themselves.
+	RotateToken(context.Context, *RotateTokenRequest) (*RotateTokenResponse, error)
+	// ExchangeOIDCToken trades an ID token issued by the identity provider
+	// configured for single sign-on for a short-lived token. The token has the
+	// permissions mapped from the user's groups and claims. This route does not
+	// require authentication.
+	ExchangeOIDCToken(context.Context, *ExchangeOIDCTokenRequest) (*ExchangeOIDCTokenResponse, error)
+	// ListServiceAccounts returns all registered service accounts.
+	ListServiceAccounts(context.Context, *ListServiceAccountsRequest) (*ListServiceAccountsResponse, error)
+	// GetServiceAccount returns a single service account by id.
+	GetServiceAccount(context.Context, *GetServiceAccountRequest) (*GetServiceAccountResponse, error)
+	// CreateServiceAccount creates a new identity meant for automation. Service
+	// accounts hold the permissions for all of their tokens.
+	CreateServiceAccount(context.Context, *CreateServiceAccountRequest) (*CreateServiceAccountResponse, error)
+	// DeleteServiceAccount removes a service account and all of its tokens.
+	DeleteServiceAccount(context.Context, *DeleteServiceAccountRequest) (*DeleteServiceAccountResponse, error)
+	// CreateServiceAccountToken creates a new token for a service account. A
+	// service account can hold multiple tokens so that they can be rotated
+	// without downtime.
+	CreateServiceAccountToken(context.Context, *CreateServiceAccountTokenRequest) (*CreateServiceAccountTokenResponse, error)
+	// ListAuthzDecisions returns the authorization decisions recorded in the
+	// audit log, newest first. The audit log must be enabled in the server's
+	// configuration.
+	ListAuthzDecisions(context.Context, *ListAuthzDecisionsRequest) (*ListAuthzDecisionsResponse, error)
+	// ListDeadLetters returns the webhook and extension dispatches that failed
+	// even after being retried, newest first.
+	ListDeadLetters(context.Context, *ListDeadLettersRequest) (*ListDeadLettersResponse, error)
+	// GetDeadLetter returns a single dead letter by id.
+	GetDeadLetter(context.Context, *GetDeadLetterRequest) (*GetDeadLetterResponse, error)
+	// RedriveDeadLetter attempts the dispatch again. The dead letter is removed
+	// if it succeeds.
+	RedriveDeadLetter(context.Context, *RedriveDeadLetterRequest) (*RedriveDeadLetterResponse, error)
+	// DeleteDeadLetter removes a dead letter without attempting it again.
+	DeleteDeadLetter(context.Context, *DeleteDeadLetterRequest) (*DeleteDeadLetterResponse, error)
+	// ListWebhooks returns the webhooks registered for a namespace.
+	ListWebhooks(context.Context, *ListWebhooksRequest) (*ListWebhooksResponse, error)
+	// GetWebhook returns a single webhook by id.
+	GetWebhook(context.Context, *GetWebhookRequest) (*GetWebhookResponse, error)
+	// CreateWebhook registers a URL to be sent the run lifecycle events of a
+	// namespace or a single pipeline. Deliveries are signed with the returned
+	// secret and retried if they fail.
+	CreateWebhook(context.Context, *CreateWebhookRequest) (*CreateWebhookResponse, error)
+	// DeleteWebhook removes a webhook along with its delivery history.
+	DeleteWebhook(context.Context, *DeleteWebhookRequest) (*DeleteWebhookResponse, error)
+	// ListWebhookDeliveries returns the most recent deliveries to a webhook,
+	// newest first.
+	ListWebhookDeliveries(context.Context, *ListWebhookDeliveriesRequest) (*ListWebhookDeliveriesResponse, error)
+	// ListRegistryCredentials returns the registry credentials of a namespace.
+	// Passwords are never returned.
+	ListRegistryCredentials(context.Context, *ListRegistryCredentialsRequest) (*ListRegistryCredentialsResponse, error)
+	// PutRegistryCredential adds the login for a registry to a namespace,
+	// replacing the one already there.
+	PutRegistryCredential(context.Context, *PutRegistryCredentialRequest) (*PutRegistryCredentialResponse, error)
+	// DeleteRegistryCredential removes the login for a registry from a
+	// namespace.
+	DeleteRegistryCredential(context.Context, *DeleteRegistryCredentialRequest) (*DeleteRegistryCredentialResponse, error)
 	mustEmbedUnimplementedGoferServer()
 }
 
@@ -904,6 +1826,12 @@ func (UnimplementedGoferServer) UpdateNamespace(context.Context, *UpdateNamespac
 func (UnimplementedGoferServer) DeleteNamespace(context.Context, *DeleteNamespaceRequest) (*DeleteNamespaceResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method DeleteNamespace not implemented")
 }
+func (UnimplementedGoferServer) GetNamespaceQuota(context.Context, *GetNamespaceQuotaRequest) (*GetNamespaceQuotaResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetNamespaceQuota not implemented")
+}
+func (UnimplementedGoferServer) UpdateNamespaceQuota(context.Context, *UpdateNamespaceQuotaRequest) (*UpdateNamespaceQuotaResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateNamespaceQuota not implemented")
+}
 func (UnimplementedGoferServer) GetPipeline(context.Context, *GetPipelineRequest) (*GetPipelineResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetPipeline not implemented")
 }
@@ -928,6 +1856,21 @@ func (UnimplementedGoferServer) UpdatePipelineRaw(context.Context, *UpdatePipeli
 func (UnimplementedGoferServer) UpdatePipelineByURL(context.Context, *UpdatePipelineByURLRequest) (*UpdatePipelineByURLResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method UpdatePipelineByURL not implemented")
 }
+func (UnimplementedGoferServer) ListPipelineConfigVersions(context.Context, *ListPipelineConfigVersionsRequest) (*ListPipelineConfigVersionsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListPipelineConfigVersions not implemented")
+}
+func (UnimplementedGoferServer) RollbackPipelineConfig(context.Context, *RollbackPipelineConfigRequest) (*RollbackPipelineConfigResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RollbackPipelineConfig not implemented")
+}
+func (UnimplementedGoferServer) StartPipelineCanary(context.Context, *StartPipelineCanaryRequest) (*StartPipelineCanaryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method StartPipelineCanary not implemented")
+}
+func (UnimplementedGoferServer) PromotePipelineCanary(context.Context, *PromotePipelineCanaryRequest) (*PromotePipelineCanaryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PromotePipelineCanary not implemented")
+}
+func (UnimplementedGoferServer) AbortPipelineCanary(context.Context, *AbortPipelineCanaryRequest) (*AbortPipelineCanaryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AbortPipelineCanary not implemented")
+}
 func (UnimplementedGoferServer) AbandonPipeline(context.Context, *AbandonPipelineRequest) (*AbandonPipelineResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method AbandonPipeline not implemented")
 }
@@ -961,6 +1904,18 @@ func (UnimplementedGoferServer) GetEvent(context.Context, *GetEventRequest) (*Ge
 func (UnimplementedGoferServer) ListEvents(*ListEventsRequest, Gofer_ListEventsServer) error {
 	return status.Errorf(codes.Unimplemented, "method ListEvents not implemented")
 }
+func (UnimplementedGoferServer) AcknowledgeEvent(context.Context, *AcknowledgeEventRequest) (*AcknowledgeEventResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method AcknowledgeEvent not implemented")
+}
+func (UnimplementedGoferServer) PublishExtensionEvent(context.Context, *PublishExtensionEventRequest) (*PublishExtensionEventResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PublishExtensionEvent not implemented")
+}
+func (UnimplementedGoferServer) ListEventConsumers(context.Context, *ListEventConsumersRequest) (*ListEventConsumersResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListEventConsumers not implemented")
+}
+func (UnimplementedGoferServer) DeleteEventConsumer(context.Context, *DeleteEventConsumerRequest) (*DeleteEventConsumerResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteEventConsumer not implemented")
+}
 func (UnimplementedGoferServer) GetRun(context.Context, *GetRunRequest) (*GetRunResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetRun not implemented")
 }
@@ -991,18 +1946,30 @@ func (UnimplementedGoferServer) ListTaskRuns(context.Context, *ListTaskRunsReque
 func (UnimplementedGoferServer) CancelTaskRun(context.Context, *CancelTaskRunRequest) (*CancelTaskRunResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method CancelTaskRun not implemented")
 }
+func (UnimplementedGoferServer) SpawnTaskRun(context.Context, *SpawnTaskRunRequest) (*SpawnTaskRunResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SpawnTaskRun not implemented")
+}
 func (UnimplementedGoferServer) GetTaskRunLogs(*GetTaskRunLogsRequest, Gofer_GetTaskRunLogsServer) error {
 	return status.Errorf(codes.Unimplemented, "method GetTaskRunLogs not implemented")
 }
 func (UnimplementedGoferServer) DeleteTaskRunLogs(context.Context, *DeleteTaskRunLogsRequest) (*DeleteTaskRunLogsResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method DeleteTaskRunLogs not implemented")
 }
+func (UnimplementedGoferServer) AttachToTaskRun(Gofer_AttachToTaskRunServer) error {
+	return status.Errorf(codes.Unimplemented, "method AttachToTaskRun not implemented")
+}
 func (UnimplementedGoferServer) GetPipelineObject(context.Context, *GetPipelineObjectRequest) (*GetPipelineObjectResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetPipelineObject not implemented")
 }
+func (UnimplementedGoferServer) GetPipelineObjectStream(*GetPipelineObjectStreamRequest, Gofer_GetPipelineObjectStreamServer) error {
+	return status.Errorf(codes.Unimplemented, "method GetPipelineObjectStream not implemented")
+}
 func (UnimplementedGoferServer) PutPipelineObject(context.Context, *PutPipelineObjectRequest) (*PutPipelineObjectResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method PutPipelineObject not implemented")
 }
+func (UnimplementedGoferServer) PutPipelineObjectStream(Gofer_PutPipelineObjectStreamServer) error {
+	return status.Errorf(codes.Unimplemented, "method PutPipelineObjectStream not implemented")
+}
 func (UnimplementedGoferServer) DeletePipelineObject(context.Context, *DeletePipelineObjectRequest) (*DeletePipelineObjectResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method DeletePipelineObject not implemented")
 }
@@ -1012,9 +1979,21 @@ func (UnimplementedGoferServer) GetRunObject(context.Context, *GetRunObjectReque
 func (UnimplementedGoferServer) PutRunObject(context.Context, *PutRunObjectRequest) (*PutRunObjectResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method PutRunObject not implemented")
 }
+func (UnimplementedGoferServer) PutRunObjectStream(Gofer_PutRunObjectStreamServer) error {
+	return status.Errorf(codes.Unimplemented, "method PutRunObjectStream not implemented")
+}
 func (UnimplementedGoferServer) DeleteRunObject(context.Context, *DeleteRunObjectRequest) (*DeleteRunObjectResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method DeleteRunObject not implemented")
 }
+func (UnimplementedGoferServer) CollectOrphanedObjects(context.Context, *CollectOrphanedObjectsRequest) (*CollectOrphanedObjectsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CollectOrphanedObjects not implemented")
+}
+func (UnimplementedGoferServer) ListRunArtifacts(context.Context, *ListRunArtifactsRequest) (*ListRunArtifactsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListRunArtifacts not implemented")
+}
+func (UnimplementedGoferServer) GetArtifactStream(*GetArtifactStreamRequest, Gofer_GetArtifactStreamServer) error {
+	return status.Errorf(codes.Unimplemented, "method GetArtifactStream not implemented")
+}
 func (UnimplementedGoferServer) GetSecret(context.Context, *GetSecretRequest) (*GetSecretResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetSecret not implemented")
 }
@@ -1024,15 +2003,36 @@ func (UnimplementedGoferServer) PutSecret(context.Context, *PutSecretRequest) (*
 func (UnimplementedGoferServer) DeleteSecret(context.Context, *DeleteSecretRequest) (*DeleteSecretResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method DeleteSecret not implemented")
 }
+func (UnimplementedGoferServer) ReencryptSecrets(context.Context, *ReencryptSecretsRequest) (*ReencryptSecretsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ReencryptSecrets not implemented")
+}
 func (UnimplementedGoferServer) GetSystemInfo(context.Context, *GetSystemInfoRequest) (*GetSystemInfoResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetSystemInfo not implemented")
 }
+func (UnimplementedGoferServer) GetAuthConfig(context.Context, *GetAuthConfigRequest) (*GetAuthConfigResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetAuthConfig not implemented")
+}
+func (UnimplementedGoferServer) GetSystemStats(context.Context, *GetSystemStatsRequest) (*GetSystemStatsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetSystemStats not implemented")
+}
+func (UnimplementedGoferServer) GetSlowReport(context.Context, *GetSlowReportRequest) (*GetSlowReportResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetSlowReport not implemented")
+}
 func (UnimplementedGoferServer) RepairOrphan(context.Context, *RepairOrphanRequest) (*RepairOrphanResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method RepairOrphan not implemented")
 }
 func (UnimplementedGoferServer) ToggleEventIngress(context.Context, *ToggleEventIngressRequest) (*ToggleEventIngressResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method ToggleEventIngress not implemented")
 }
+func (UnimplementedGoferServer) GetLogLevel(context.Context, *GetLogLevelRequest) (*GetLogLevelResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetLogLevel not implemented")
+}
+func (UnimplementedGoferServer) UpdateLogLevel(context.Context, *UpdateLogLevelRequest) (*UpdateLogLevelResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method UpdateLogLevel not implemented")
+}
+func (UnimplementedGoferServer) CreateBackup(*CreateBackupRequest, Gofer_CreateBackupServer) error {
+	return status.Errorf(codes.Unimplemented, "method CreateBackup not implemented")
+}
 func (UnimplementedGoferServer) CreateToken(context.Context, *CreateTokenRequest) (*CreateTokenResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method CreateToken not implemented")
 }
@@ -1042,9 +2042,78 @@ func (UnimplementedGoferServer) BootstrapToken(context.Context, *BootstrapTokenR
 func (UnimplementedGoferServer) GetToken(context.Context, *GetTokenRequest) (*GetTokenResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method GetToken not implemented")
 }
+func (UnimplementedGoferServer) ListTokens(context.Context, *ListTokensRequest) (*ListTokensResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListTokens not implemented")
+}
+func (UnimplementedGoferServer) ListExpiringTokens(context.Context, *ListExpiringTokensRequest) (*ListExpiringTokensResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListExpiringTokens not implemented")
+}
 func (UnimplementedGoferServer) DeleteToken(context.Context, *DeleteTokenRequest) (*DeleteTokenResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method DeleteToken not implemented")
 }
+func (UnimplementedGoferServer) DeleteUserTokens(context.Context, *DeleteUserTokensRequest) (*DeleteUserTokensResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteUserTokens not implemented")
+}
+func (UnimplementedGoferServer) RotateToken(context.Context, *RotateTokenRequest) (*RotateTokenResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RotateToken not implemented")
+}
+func (UnimplementedGoferServer) ExchangeOIDCToken(context.Context, *ExchangeOIDCTokenRequest) (*ExchangeOIDCTokenResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ExchangeOIDCToken not implemented")
+}
+func (UnimplementedGoferServer) ListServiceAccounts(context.Context, *ListServiceAccountsRequest) (*ListServiceAccountsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListServiceAccounts not implemented")
+}
+func (UnimplementedGoferServer) GetServiceAccount(context.Context, *GetServiceAccountRequest) (*GetServiceAccountResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetServiceAccount not implemented")
+}
+func (UnimplementedGoferServer) CreateServiceAccount(context.Context, *CreateServiceAccountRequest) (*CreateServiceAccountResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CreateServiceAccount not implemented")
+}
+func (UnimplementedGoferServer) DeleteServiceAccount(context.Context, *DeleteServiceAccountRequest) (*DeleteServiceAccountResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteServiceAccount not implemented")
+}
+func (UnimplementedGoferServer) CreateServiceAccountToken(context.Context, *CreateServiceAccountTokenRequest) (*CreateServiceAccountTokenResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CreateServiceAccountToken not implemented")
+}
+func (UnimplementedGoferServer) ListAuthzDecisions(context.Context, *ListAuthzDecisionsRequest) (*ListAuthzDecisionsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListAuthzDecisions not implemented")
+}
+func (UnimplementedGoferServer) ListDeadLetters(context.Context, *ListDeadLettersRequest) (*ListDeadLettersResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListDeadLetters not implemented")
+}
+func (UnimplementedGoferServer) GetDeadLetter(context.Context, *GetDeadLetterRequest) (*GetDeadLetterResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetDeadLetter not implemented")
+}
+func (UnimplementedGoferServer) RedriveDeadLetter(context.Context, *RedriveDeadLetterRequest) (*RedriveDeadLetterResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RedriveDeadLetter not implemented")
+}
+func (UnimplementedGoferServer) DeleteDeadLetter(context.Context, *DeleteDeadLetterRequest) (*DeleteDeadLetterResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteDeadLetter not implemented")
+}
+func (UnimplementedGoferServer) ListWebhooks(context.Context, *ListWebhooksRequest) (*ListWebhooksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListWebhooks not implemented")
+}
+func (UnimplementedGoferServer) GetWebhook(context.Context, *GetWebhookRequest) (*GetWebhookResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetWebhook not implemented")
+}
+func (UnimplementedGoferServer) CreateWebhook(context.Context, *CreateWebhookRequest) (*CreateWebhookResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method CreateWebhook not implemented")
+}
+func (UnimplementedGoferServer) DeleteWebhook(context.Context, *DeleteWebhookRequest) (*DeleteWebhookResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteWebhook not implemented")
+}
+func (UnimplementedGoferServer) ListWebhookDeliveries(context.Context, *ListWebhookDeliveriesRequest) (*ListWebhookDeliveriesResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListWebhookDeliveries not implemented")
+}
+func (UnimplementedGoferServer) ListRegistryCredentials(context.Context, *ListRegistryCredentialsRequest) (*ListRegistryCredentialsResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListRegistryCredentials not implemented")
+}
+func (UnimplementedGoferServer) PutRegistryCredential(context.Context, *PutRegistryCredentialRequest) (*PutRegistryCredentialResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method PutRegistryCredential not implemented")
+}
+func (UnimplementedGoferServer) DeleteRegistryCredential(context.Context, *DeleteRegistryCredentialRequest) (*DeleteRegistryCredentialResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method DeleteRegistryCredential not implemented")
+}
 func (UnimplementedGoferServer) mustEmbedUnimplementedGoferServer() {}
 
 // UnsafeGoferServer may be embedded to opt out of forward compatibility for this service.
@@ -1148,6 +2217,42 @@ func _Gofer_DeleteNamespace_Handler(srv interface{}, ctx context.Context, dec fu
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_GetNamespaceQuota_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetNamespaceQuotaRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).GetNamespaceQuota(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/GetNamespaceQuota",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).GetNamespaceQuota(ctx, req.(*GetNamespaceQuotaRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_UpdateNamespaceQuota_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateNamespaceQuotaRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).UpdateNamespaceQuota(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/UpdateNamespaceQuota",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).UpdateNamespaceQuota(ctx, req.(*UpdateNamespaceQuotaRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _Gofer_GetPipeline_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(GetPipelineRequest)
 	if err := dec(in); err != nil {
@@ -1292,13 +2397,103 @@ func _Gofer_UpdatePipelineByURL_Handler(srv interface{}, ctx context.Context, de
 	return interceptor(ctx, in, info, handler)
 }
 
-func _Gofer_AbandonPipeline_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(AbandonPipelineRequest)
+func _Gofer_ListPipelineConfigVersions_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListPipelineConfigVersionsRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(GoferServer).AbandonPipeline(ctx, in)
+		return srv.(GoferServer).ListPipelineConfigVersions(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListPipelineConfigVersions",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListPipelineConfigVersions(ctx, req.(*ListPipelineConfigVersionsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_RollbackPipelineConfig_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RollbackPipelineConfigRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).RollbackPipelineConfig(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/RollbackPipelineConfig",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).RollbackPipelineConfig(ctx, req.(*RollbackPipelineConfigRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_StartPipelineCanary_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(StartPipelineCanaryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).StartPipelineCanary(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/StartPipelineCanary",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).StartPipelineCanary(ctx, req.(*StartPipelineCanaryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_PromotePipelineCanary_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PromotePipelineCanaryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).PromotePipelineCanary(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/PromotePipelineCanary",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).PromotePipelineCanary(ctx, req.(*PromotePipelineCanaryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_AbortPipelineCanary_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AbortPipelineCanaryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).AbortPipelineCanary(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/AbortPipelineCanary",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).AbortPipelineCanary(ctx, req.(*AbortPipelineCanaryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_AbandonPipeline_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AbandonPipelineRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).AbandonPipeline(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
@@ -1493,6 +2688,78 @@ func (x *goferListEventsServer) Send(m *ListEventsResponse) error {
 	return x.ServerStream.SendMsg(m)
 }
 
+func _Gofer_AcknowledgeEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(AcknowledgeEventRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).AcknowledgeEvent(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/AcknowledgeEvent",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).AcknowledgeEvent(ctx, req.(*AcknowledgeEventRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_PublishExtensionEvent_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PublishExtensionEventRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).PublishExtensionEvent(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/PublishExtensionEvent",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).PublishExtensionEvent(ctx, req.(*PublishExtensionEventRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListEventConsumers_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListEventConsumersRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListEventConsumers(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListEventConsumers",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListEventConsumers(ctx, req.(*ListEventConsumersRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_DeleteEventConsumer_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteEventConsumerRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).DeleteEventConsumer(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/DeleteEventConsumer",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).DeleteEventConsumer(ctx, req.(*DeleteEventConsumerRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _Gofer_GetRun_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(GetRunRequest)
 	if err := dec(in); err != nil {
@@ -1673,6 +2940,24 @@ func _Gofer_CancelTaskRun_Handler(srv interface{}, ctx context.Context, dec func
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_SpawnTaskRun_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SpawnTaskRunRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).SpawnTaskRun(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/SpawnTaskRun",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).SpawnTaskRun(ctx, req.(*SpawnTaskRunRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _Gofer_GetTaskRunLogs_Handler(srv interface{}, stream grpc.ServerStream) error {
 	m := new(GetTaskRunLogsRequest)
 	if err := stream.RecvMsg(m); err != nil {
@@ -1712,6 +2997,32 @@ func _Gofer_DeleteTaskRunLogs_Handler(srv interface{}, ctx context.Context, dec
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_AttachToTaskRun_Handler(srv interface{}, stream grpc.ServerStream) error {
+	return srv.(GoferServer).AttachToTaskRun(&goferAttachToTaskRunServer{stream})
+}
+
+type Gofer_AttachToTaskRunServer interface {
+	Send(*AttachToTaskRunOutput) error
+	Recv() (*AttachToTaskRunRequest, error)
+	grpc.ServerStream
+}
+
+type goferAttachToTaskRunServer struct {
+	grpc.ServerStream
+}
+
+func (x *goferAttachToTaskRunServer) Send(m *AttachToTaskRunOutput) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func (x *goferAttachToTaskRunServer) Recv() (*AttachToTaskRunRequest, error) {
+	m := new(AttachToTaskRunRequest)
+	if err := x.ServerStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func _Gofer_GetPipelineObject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(GetPipelineObjectRequest)
 	if err := dec(in); err != nil {
@@ -1730,6 +3041,27 @@ func _Gofer_GetPipelineObject_Handler(srv interface{}, ctx context.Context, dec
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_GetPipelineObjectStream_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(GetPipelineObjectStreamRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(GoferServer).GetPipelineObjectStream(m, &goferGetPipelineObjectStreamServer{stream})
+}
+
+type Gofer_GetPipelineObjectStreamServer interface {
+	Send(*GetPipelineObjectStreamResponse) error
+	grpc.ServerStream
+}
+
+type goferGetPipelineObjectStreamServer struct {
+	grpc.ServerStream
+}
+
+func (x *goferGetPipelineObjectStreamServer) Send(m *GetPipelineObjectStreamResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
 func _Gofer_PutPipelineObject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(PutPipelineObjectRequest)
 	if err := dec(in); err != nil {
@@ -1748,6 +3080,32 @@ func _Gofer_PutPipelineObject_Handler(srv interface{}, ctx context.Context, dec
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_PutPipelineObjectStream_Handler(srv interface{}, stream grpc.ServerStream) error {
+	return srv.(GoferServer).PutPipelineObjectStream(&goferPutPipelineObjectStreamServer{stream})
+}
+
+type Gofer_PutPipelineObjectStreamServer interface {
+	SendAndClose(*PutPipelineObjectResponse) error
+	Recv() (*PutPipelineObjectRequest, error)
+	grpc.ServerStream
+}
+
+type goferPutPipelineObjectStreamServer struct {
+	grpc.ServerStream
+}
+
+func (x *goferPutPipelineObjectStreamServer) SendAndClose(m *PutPipelineObjectResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func (x *goferPutPipelineObjectStreamServer) Recv() (*PutPipelineObjectRequest, error) {
+	m := new(PutPipelineObjectRequest)
+	if err := x.ServerStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func _Gofer_DeletePipelineObject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(DeletePipelineObjectRequest)
 	if err := dec(in); err != nil {
@@ -1802,6 +3160,32 @@ func _Gofer_PutRunObject_Handler(srv interface{}, ctx context.Context, dec func(
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_PutRunObjectStream_Handler(srv interface{}, stream grpc.ServerStream) error {
+	return srv.(GoferServer).PutRunObjectStream(&goferPutRunObjectStreamServer{stream})
+}
+
+type Gofer_PutRunObjectStreamServer interface {
+	SendAndClose(*PutRunObjectResponse) error
+	Recv() (*PutRunObjectRequest, error)
+	grpc.ServerStream
+}
+
+type goferPutRunObjectStreamServer struct {
+	grpc.ServerStream
+}
+
+func (x *goferPutRunObjectStreamServer) SendAndClose(m *PutRunObjectResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func (x *goferPutRunObjectStreamServer) Recv() (*PutRunObjectRequest, error) {
+	m := new(PutRunObjectRequest)
+	if err := x.ServerStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 func _Gofer_DeleteRunObject_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(DeleteRunObjectRequest)
 	if err := dec(in); err != nil {
@@ -1820,6 +3204,63 @@ func _Gofer_DeleteRunObject_Handler(srv interface{}, ctx context.Context, dec fu
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_CollectOrphanedObjects_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CollectOrphanedObjectsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).CollectOrphanedObjects(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/CollectOrphanedObjects",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).CollectOrphanedObjects(ctx, req.(*CollectOrphanedObjectsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListRunArtifacts_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListRunArtifactsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListRunArtifacts(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListRunArtifacts",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListRunArtifacts(ctx, req.(*ListRunArtifactsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_GetArtifactStream_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(GetArtifactStreamRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(GoferServer).GetArtifactStream(m, &goferGetArtifactStreamServer{stream})
+}
+
+type Gofer_GetArtifactStreamServer interface {
+	Send(*GetArtifactStreamResponse) error
+	grpc.ServerStream
+}
+
+type goferGetArtifactStreamServer struct {
+	grpc.ServerStream
+}
+
+func (x *goferGetArtifactStreamServer) Send(m *GetArtifactStreamResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
 func _Gofer_GetSecret_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(GetSecretRequest)
 	if err := dec(in); err != nil {
@@ -1874,61 +3315,190 @@ func _Gofer_DeleteSecret_Handler(srv interface{}, ctx context.Context, dec func(
 	return interceptor(ctx, in, info, handler)
 }
 
-func _Gofer_GetSystemInfo_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(GetSystemInfoRequest)
+func _Gofer_ReencryptSecrets_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ReencryptSecretsRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(GoferServer).GetSystemInfo(ctx, in)
+		return srv.(GoferServer).ReencryptSecrets(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/proto.Gofer/GetSystemInfo",
+		FullMethod: "/proto.Gofer/ReencryptSecrets",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(GoferServer).GetSystemInfo(ctx, req.(*GetSystemInfoRequest))
+		return srv.(GoferServer).ReencryptSecrets(ctx, req.(*ReencryptSecretsRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-func _Gofer_RepairOrphan_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(RepairOrphanRequest)
+func _Gofer_GetSystemInfo_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetSystemInfoRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(GoferServer).RepairOrphan(ctx, in)
+		return srv.(GoferServer).GetSystemInfo(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/proto.Gofer/RepairOrphan",
+		FullMethod: "/proto.Gofer/GetSystemInfo",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(GoferServer).RepairOrphan(ctx, req.(*RepairOrphanRequest))
+		return srv.(GoferServer).GetSystemInfo(ctx, req.(*GetSystemInfoRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-func _Gofer_ToggleEventIngress_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
-	in := new(ToggleEventIngressRequest)
+func _Gofer_GetAuthConfig_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetAuthConfigRequest)
 	if err := dec(in); err != nil {
 		return nil, err
 	}
 	if interceptor == nil {
-		return srv.(GoferServer).ToggleEventIngress(ctx, in)
+		return srv.(GoferServer).GetAuthConfig(ctx, in)
 	}
 	info := &grpc.UnaryServerInfo{
 		Server:     srv,
-		FullMethod: "/proto.Gofer/ToggleEventIngress",
+		FullMethod: "/proto.Gofer/GetAuthConfig",
 	}
 	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
-		return srv.(GoferServer).ToggleEventIngress(ctx, req.(*ToggleEventIngressRequest))
+		return srv.(GoferServer).GetAuthConfig(ctx, req.(*GetAuthConfigRequest))
 	}
 	return interceptor(ctx, in, info, handler)
 }
 
-func _Gofer_CreateToken_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+func _Gofer_GetSystemStats_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetSystemStatsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).GetSystemStats(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/GetSystemStats",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).GetSystemStats(ctx, req.(*GetSystemStatsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_GetSlowReport_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetSlowReportRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).GetSlowReport(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/GetSlowReport",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).GetSlowReport(ctx, req.(*GetSlowReportRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_RepairOrphan_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RepairOrphanRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).RepairOrphan(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/RepairOrphan",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).RepairOrphan(ctx, req.(*RepairOrphanRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ToggleEventIngress_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ToggleEventIngressRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ToggleEventIngress(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ToggleEventIngress",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ToggleEventIngress(ctx, req.(*ToggleEventIngressRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_GetLogLevel_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetLogLevelRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).GetLogLevel(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/GetLogLevel",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).GetLogLevel(ctx, req.(*GetLogLevelRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_UpdateLogLevel_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(UpdateLogLevelRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).UpdateLogLevel(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/UpdateLogLevel",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).UpdateLogLevel(ctx, req.(*UpdateLogLevelRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_CreateBackup_Handler(srv interface{}, stream grpc.ServerStream) error {
+	m := new(CreateBackupRequest)
+	if err := stream.RecvMsg(m); err != nil {
+		return err
+	}
+	return srv.(GoferServer).CreateBackup(m, &goferCreateBackupServer{stream})
+}
+
+type Gofer_CreateBackupServer interface {
+	Send(*CreateBackupResponse) error
+	grpc.ServerStream
+}
+
+type goferCreateBackupServer struct {
+	grpc.ServerStream
+}
+
+func (x *goferCreateBackupServer) Send(m *CreateBackupResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func _Gofer_CreateToken_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(CreateTokenRequest)
 	if err := dec(in); err != nil {
 		return nil, err
@@ -1982,6 +3552,42 @@ func _Gofer_GetToken_Handler(srv interface{}, ctx context.Context, dec func(inte
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_ListTokens_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListTokensRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListTokens(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListTokens",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListTokens(ctx, req.(*ListTokensRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListExpiringTokens_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListExpiringTokensRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListExpiringTokens(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListExpiringTokens",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListExpiringTokens(ctx, req.(*ListExpiringTokensRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 func _Gofer_DeleteToken_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
 	in := new(DeleteTokenRequest)
 	if err := dec(in); err != nil {
@@ -2000,6 +3606,384 @@ func _Gofer_DeleteToken_Handler(srv interface{}, ctx context.Context, dec func(i
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Gofer_DeleteUserTokens_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteUserTokensRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).DeleteUserTokens(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/DeleteUserTokens",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).DeleteUserTokens(ctx, req.(*DeleteUserTokensRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_RotateToken_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RotateTokenRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).RotateToken(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/RotateToken",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).RotateToken(ctx, req.(*RotateTokenRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ExchangeOIDCToken_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ExchangeOIDCTokenRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ExchangeOIDCToken(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ExchangeOIDCToken",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ExchangeOIDCToken(ctx, req.(*ExchangeOIDCTokenRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListServiceAccounts_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListServiceAccountsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListServiceAccounts(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListServiceAccounts",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListServiceAccounts(ctx, req.(*ListServiceAccountsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_GetServiceAccount_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetServiceAccountRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).GetServiceAccount(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/GetServiceAccount",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).GetServiceAccount(ctx, req.(*GetServiceAccountRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_CreateServiceAccount_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateServiceAccountRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).CreateServiceAccount(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/CreateServiceAccount",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).CreateServiceAccount(ctx, req.(*CreateServiceAccountRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_DeleteServiceAccount_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteServiceAccountRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).DeleteServiceAccount(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/DeleteServiceAccount",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).DeleteServiceAccount(ctx, req.(*DeleteServiceAccountRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_CreateServiceAccountToken_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateServiceAccountTokenRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).CreateServiceAccountToken(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/CreateServiceAccountToken",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).CreateServiceAccountToken(ctx, req.(*CreateServiceAccountTokenRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListAuthzDecisions_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListAuthzDecisionsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListAuthzDecisions(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListAuthzDecisions",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListAuthzDecisions(ctx, req.(*ListAuthzDecisionsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListDeadLetters_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListDeadLettersRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListDeadLetters(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListDeadLetters",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListDeadLetters(ctx, req.(*ListDeadLettersRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_GetDeadLetter_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetDeadLetterRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).GetDeadLetter(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/GetDeadLetter",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).GetDeadLetter(ctx, req.(*GetDeadLetterRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_RedriveDeadLetter_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RedriveDeadLetterRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).RedriveDeadLetter(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/RedriveDeadLetter",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).RedriveDeadLetter(ctx, req.(*RedriveDeadLetterRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_DeleteDeadLetter_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteDeadLetterRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).DeleteDeadLetter(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/DeleteDeadLetter",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).DeleteDeadLetter(ctx, req.(*DeleteDeadLetterRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListWebhooks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListWebhooksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListWebhooks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListWebhooks",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListWebhooks(ctx, req.(*ListWebhooksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_GetWebhook_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetWebhookRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).GetWebhook(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/GetWebhook",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).GetWebhook(ctx, req.(*GetWebhookRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_CreateWebhook_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(CreateWebhookRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).CreateWebhook(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/CreateWebhook",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).CreateWebhook(ctx, req.(*CreateWebhookRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_DeleteWebhook_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteWebhookRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).DeleteWebhook(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/DeleteWebhook",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).DeleteWebhook(ctx, req.(*DeleteWebhookRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListWebhookDeliveries_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListWebhookDeliveriesRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListWebhookDeliveries(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListWebhookDeliveries",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListWebhookDeliveries(ctx, req.(*ListWebhookDeliveriesRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_ListRegistryCredentials_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListRegistryCredentialsRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).ListRegistryCredentials(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/ListRegistryCredentials",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).ListRegistryCredentials(ctx, req.(*ListRegistryCredentialsRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_PutRegistryCredential_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PutRegistryCredentialRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).PutRegistryCredential(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/PutRegistryCredential",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).PutRegistryCredential(ctx, req.(*PutRegistryCredentialRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Gofer_DeleteRegistryCredential_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DeleteRegistryCredentialRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GoferServer).DeleteRegistryCredential(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/proto.Gofer/DeleteRegistryCredential",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GoferServer).DeleteRegistryCredential(ctx, req.(*DeleteRegistryCredentialRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // Gofer_ServiceDesc is the grpc.ServiceDesc for Gofer service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -2027,6 +4011,14 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "DeleteNamespace",
 			Handler:    _Gofer_DeleteNamespace_Handler,
 		},
+		{
+			MethodName: "GetNamespaceQuota",
+			Handler:    _Gofer_GetNamespaceQuota_Handler,
+		},
+		{
+			MethodName: "UpdateNamespaceQuota",
+			Handler:    _Gofer_UpdateNamespaceQuota_Handler,
+		},
 		{
 			MethodName: "GetPipeline",
 			Handler:    _Gofer_GetPipeline_Handler,
@@ -2059,6 +4051,26 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "UpdatePipelineByURL",
 			Handler:    _Gofer_UpdatePipelineByURL_Handler,
 		},
+		{
+			MethodName: "ListPipelineConfigVersions",
+			Handler:    _Gofer_ListPipelineConfigVersions_Handler,
+		},
+		{
+			MethodName: "RollbackPipelineConfig",
+			Handler:    _Gofer_RollbackPipelineConfig_Handler,
+		},
+		{
+			MethodName: "StartPipelineCanary",
+			Handler:    _Gofer_StartPipelineCanary_Handler,
+		},
+		{
+			MethodName: "PromotePipelineCanary",
+			Handler:    _Gofer_PromotePipelineCanary_Handler,
+		},
+		{
+			MethodName: "AbortPipelineCanary",
+			Handler:    _Gofer_AbortPipelineCanary_Handler,
+		},
 		{
 			MethodName: "AbandonPipeline",
 			Handler:    _Gofer_AbandonPipeline_Handler,
@@ -2099,6 +4111,22 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "GetEvent",
 			Handler:    _Gofer_GetEvent_Handler,
 		},
+		{
+			MethodName: "AcknowledgeEvent",
+			Handler:    _Gofer_AcknowledgeEvent_Handler,
+		},
+		{
+			MethodName: "PublishExtensionEvent",
+			Handler:    _Gofer_PublishExtensionEvent_Handler,
+		},
+		{
+			MethodName: "ListEventConsumers",
+			Handler:    _Gofer_ListEventConsumers_Handler,
+		},
+		{
+			MethodName: "DeleteEventConsumer",
+			Handler:    _Gofer_DeleteEventConsumer_Handler,
+		},
 		{
 			MethodName: "GetRun",
 			Handler:    _Gofer_GetRun_Handler,
@@ -2139,6 +4167,10 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "CancelTaskRun",
 			Handler:    _Gofer_CancelTaskRun_Handler,
 		},
+		{
+			MethodName: "SpawnTaskRun",
+			Handler:    _Gofer_SpawnTaskRun_Handler,
+		},
 		{
 			MethodName: "DeleteTaskRunLogs",
 			Handler:    _Gofer_DeleteTaskRunLogs_Handler,
@@ -2167,6 +4199,14 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "DeleteRunObject",
 			Handler:    _Gofer_DeleteRunObject_Handler,
 		},
+		{
+			MethodName: "CollectOrphanedObjects",
+			Handler:    _Gofer_CollectOrphanedObjects_Handler,
+		},
+		{
+			MethodName: "ListRunArtifacts",
+			Handler:    _Gofer_ListRunArtifacts_Handler,
+		},
 		{
 			MethodName: "GetSecret",
 			Handler:    _Gofer_GetSecret_Handler,
@@ -2179,10 +4219,26 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "DeleteSecret",
 			Handler:    _Gofer_DeleteSecret_Handler,
 		},
+		{
+			MethodName: "ReencryptSecrets",
+			Handler:    _Gofer_ReencryptSecrets_Handler,
+		},
 		{
 			MethodName: "GetSystemInfo",
 			Handler:    _Gofer_GetSystemInfo_Handler,
 		},
+		{
+			MethodName: "GetAuthConfig",
+			Handler:    _Gofer_GetAuthConfig_Handler,
+		},
+		{
+			MethodName: "GetSystemStats",
+			Handler:    _Gofer_GetSystemStats_Handler,
+		},
+		{
+			MethodName: "GetSlowReport",
+			Handler:    _Gofer_GetSlowReport_Handler,
+		},
 		{
 			MethodName: "RepairOrphan",
 			Handler:    _Gofer_RepairOrphan_Handler,
@@ -2191,6 +4247,14 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "ToggleEventIngress",
 			Handler:    _Gofer_ToggleEventIngress_Handler,
 		},
+		{
+			MethodName: "GetLogLevel",
+			Handler:    _Gofer_GetLogLevel_Handler,
+		},
+		{
+			MethodName: "UpdateLogLevel",
+			Handler:    _Gofer_UpdateLogLevel_Handler,
+		},
 		{
 			MethodName: "CreateToken",
 			Handler:    _Gofer_CreateToken_Handler,
@@ -2203,10 +4267,102 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "GetToken",
 			Handler:    _Gofer_GetToken_Handler,
 		},
+		{
+			MethodName: "ListTokens",
+			Handler:    _Gofer_ListTokens_Handler,
+		},
+		{
+			MethodName: "ListExpiringTokens",
+			Handler:    _Gofer_ListExpiringTokens_Handler,
+		},
 		{
 			MethodName: "DeleteToken",
 			Handler:    _Gofer_DeleteToken_Handler,
 		},
+		{
+			MethodName: "DeleteUserTokens",
+			Handler:    _Gofer_DeleteUserTokens_Handler,
+		},
+		{
+			MethodName: "RotateToken",
+			Handler:    _Gofer_RotateToken_Handler,
+		},
+		{
+			MethodName: "ExchangeOIDCToken",
+			Handler:    _Gofer_ExchangeOIDCToken_Handler,
+		},
+		{
+			MethodName: "ListServiceAccounts",
+			Handler:    _Gofer_ListServiceAccounts_Handler,
+		},
+		{
+			MethodName: "GetServiceAccount",
+			Handler:    _Gofer_GetServiceAccount_Handler,
+		},
+		{
+			MethodName: "CreateServiceAccount",
+			Handler:    _Gofer_CreateServiceAccount_Handler,
+		},
+		{
+			MethodName: "DeleteServiceAccount",
+			Handler:    _Gofer_DeleteServiceAccount_Handler,
+		},
+		{
+			MethodName: "CreateServiceAccountToken",
+			Handler:    _Gofer_CreateServiceAccountToken_Handler,
+		},
+		{
+			MethodName: "ListAuthzDecisions",
+			Handler:    _Gofer_ListAuthzDecisions_Handler,
+		},
+		{
+			MethodName: "ListDeadLetters",
+			Handler:    _Gofer_ListDeadLetters_Handler,
+		},
+		{
+			MethodName: "GetDeadLetter",
+			Handler:    _Gofer_GetDeadLetter_Handler,
+		},
+		{
+			MethodName: "RedriveDeadLetter",
+			Handler:    _Gofer_RedriveDeadLetter_Handler,
+		},
+		{
+			MethodName: "DeleteDeadLetter",
+			Handler:    _Gofer_DeleteDeadLetter_Handler,
+		},
+		{
+			MethodName: "ListWebhooks",
+			Handler:    _Gofer_ListWebhooks_Handler,
+		},
+		{
+			MethodName: "GetWebhook",
+			Handler:    _Gofer_GetWebhook_Handler,
+		},
+		{
+			MethodName: "CreateWebhook",
+			Handler:    _Gofer_CreateWebhook_Handler,
+		},
+		{
+			MethodName: "DeleteWebhook",
+			Handler:    _Gofer_DeleteWebhook_Handler,
+		},
+		{
+			MethodName: "ListWebhookDeliveries",
+			Handler:    _Gofer_ListWebhookDeliveries_Handler,
+		},
+		{
+			MethodName: "ListRegistryCredentials",
+			Handler:    _Gofer_ListRegistryCredentials_Handler,
+		},
+		{
+			MethodName: "PutRegistryCredential",
+			Handler:    _Gofer_PutRegistryCredential_Handler,
+		},
+		{
+			MethodName: "DeleteRegistryCredential",
+			Handler:    _Gofer_DeleteRegistryCredential_Handler,
+		},
 	},
 	Streams: []grpc.StreamDesc{
 		{
@@ -2219,6 +4375,37 @@ var Gofer_ServiceDesc = grpc.ServiceDesc{
 			Handler:       _Gofer_GetTaskRunLogs_Handler,
 			ServerStreams: true,
 		},
+		{
+			StreamName:    "AttachToTaskRun",
+			Handler:       _Gofer_AttachToTaskRun_Handler,
+			ServerStreams: true,
+			ClientStreams: true,
+		},
+		{
+			StreamName:    "GetPipelineObjectStream",
+			Handler:       _Gofer_GetPipelineObjectStream_Handler,
+			ServerStreams: true,
+		},
+		{
+			StreamName:    "PutPipelineObjectStream",
+			Handler:       _Gofer_PutPipelineObjectStream_Handler,
+			ClientStreams: true,
+		},
+		{
+			StreamName:    "PutRunObjectStream",
+			Handler:       _Gofer_PutRunObjectStream_Handler,
+			ClientStreams: true,
+		},
+		{
+			StreamName:    "GetArtifactStream",
+			Handler:       _Gofer_GetArtifactStream_Handler,
+			ServerStreams: true,
+		},
+		{
+			StreamName:    "CreateBackup",
+			Handler:       _Gofer_CreateBackup_Handler,
+			ServerStreams: true,
+		},
 	},
 	Metadata: "gofer.proto",
 }
@@ -23,9 +23,10 @@ const (
 type CreateTokenRequest_Kind int32
 
 const (
-	CreateTokenRequest_UNKNOWN    CreateTokenRequest_Kind = 0
-	CreateTokenRequest_MANAGEMENT CreateTokenRequest_Kind = 1
-	CreateTokenRequest_CLIENT     CreateTokenRequest_Kind = 2
+	CreateTokenRequest_UNKNOWN         CreateTokenRequest_Kind = 0
+	CreateTokenRequest_MANAGEMENT      CreateTokenRequest_Kind = 1
+	CreateTokenRequest_CLIENT          CreateTokenRequest_Kind = 2
+	CreateTokenRequest_NAMESPACE_ADMIN CreateTokenRequest_Kind = 3
 )
 
 // Enum value maps for CreateTokenRequest_Kind.
@@ -34,11 +35,13 @@ var (
 		0: "UNKNOWN",
 		1: "MANAGEMENT",
 		2: "CLIENT",
+		3: "NAMESPACE_ADMIN",
 	}
 	CreateTokenRequest_Kind_value = map[string]int32{
-		"UNKNOWN":    0,
-		"MANAGEMENT": 1,
-		"CLIENT":     2,
+		"UNKNOWN":         0,
+		"MANAGEMENT":      1,
+		"CLIENT":          2,
+		"NAMESPACE_ADMIN": 3,
 	}
 )
 
@@ -66,7 +69,7 @@ func (x CreateTokenRequest_Kind) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use CreateTokenRequest_Kind.Descriptor instead.
 func (CreateTokenRequest_Kind) EnumDescriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{100, 0}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{155, 0}
 }
 
 type GetNamespaceRequest struct {
@@ -394,9 +397,14 @@ type UpdateNamespaceRequest struct {
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Id          string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
-	Name        string `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
-	Description string `protobuf:"bytes,3,opt,name=description,proto3" json:"description,omitempty"`
+	Id             string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Name           string `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	Description    string `protobuf:"bytes,3,opt,name=description,proto3" json:"description,omitempty"`
+	AllowAnonymous bool   `protobuf:"varint,4,opt,name=allow_anonymous,json=allowAnonymous,proto3" json:"allow_anonymous,omitempty"` // Whether callers without credentials can view
+	// the namespace.
+	// Seconds until pipeline objects are removed unless they are given an expiry
+	// of their own; 0 keeps them until they are evicted.
+	ObjectExpiry int64 `protobuf:"varint,5,opt,name=object_expiry,json=objectExpiry,proto3" json:"object_expiry,omitempty"`
 }
 
 func (x *UpdateNamespaceRequest) Reset() {
@@ -452,6 +460,20 @@ func (x *UpdateNamespaceRequest) GetDescription() string {
 	return ""
 }
 
+func (x *UpdateNamespaceRequest) GetAllowAnonymous() bool {
+	if x != nil {
+		return x.AllowAnonymous
+	}
+	return false
+}
+
+func (x *UpdateNamespaceRequest) GetObjectExpiry() int64 {
+	if x != nil {
+		return x.ObjectExpiry
+	}
+	return 0
+}
+
 type UpdateNamespaceResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -584,6 +606,210 @@ func (*DeleteNamespaceResponse) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{9}
 }
 
+type GetNamespaceQuotaRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"` // Unique identifier
+}
+
+func (x *GetNamespaceQuotaRequest) Reset() {
+	*x = GetNamespaceQuotaRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[10]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetNamespaceQuotaRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetNamespaceQuotaRequest) ProtoMessage() {}
+
+func (x *GetNamespaceQuotaRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[10]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetNamespaceQuotaRequest.ProtoReflect.Descriptor instead.
+func (*GetNamespaceQuotaRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{10}
+}
+
+func (x *GetNamespaceQuotaRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+type GetNamespaceQuotaResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Quota *NamespaceQuota `protobuf:"bytes,1,opt,name=quota,proto3" json:"quota,omitempty"`
+	Usage *NamespaceQuota `protobuf:"bytes,2,opt,name=usage,proto3" json:"usage,omitempty"` // The current consumption of the namespace.
+}
+
+func (x *GetNamespaceQuotaResponse) Reset() {
+	*x = GetNamespaceQuotaResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[11]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetNamespaceQuotaResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetNamespaceQuotaResponse) ProtoMessage() {}
+
+func (x *GetNamespaceQuotaResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[11]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetNamespaceQuotaResponse.ProtoReflect.Descriptor instead.
+func (*GetNamespaceQuotaResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{11}
+}
+
+func (x *GetNamespaceQuotaResponse) GetQuota() *NamespaceQuota {
+	if x != nil {
+		return x.Quota
+	}
+	return nil
+}
+
+func (x *GetNamespaceQuotaResponse) GetUsage() *NamespaceQuota {
+	if x != nil {
+		return x.Usage
+	}
+	return nil
+}
+
+type UpdateNamespaceQuotaRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id    string          `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"` // Unique identifier
+	Quota *NamespaceQuota `protobuf:"bytes,2,opt,name=quota,proto3" json:"quota,omitempty"`
+}
+
+func (x *UpdateNamespaceQuotaRequest) Reset() {
+	*x = UpdateNamespaceQuotaRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[12]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *UpdateNamespaceQuotaRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateNamespaceQuotaRequest) ProtoMessage() {}
+
+func (x *UpdateNamespaceQuotaRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[12]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateNamespaceQuotaRequest.ProtoReflect.Descriptor instead.
+func (*UpdateNamespaceQuotaRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{12}
+}
+
+func (x *UpdateNamespaceQuotaRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *UpdateNamespaceQuotaRequest) GetQuota() *NamespaceQuota {
+	if x != nil {
+		return x.Quota
+	}
+	return nil
+}
+
+type UpdateNamespaceQuotaResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Quota *NamespaceQuota `protobuf:"bytes,1,opt,name=quota,proto3" json:"quota,omitempty"`
+}
+
+func (x *UpdateNamespaceQuotaResponse) Reset() {
+	*x = UpdateNamespaceQuotaResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[13]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *UpdateNamespaceQuotaResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateNamespaceQuotaResponse) ProtoMessage() {}
+
+func (x *UpdateNamespaceQuotaResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[13]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateNamespaceQuotaResponse.ProtoReflect.Descriptor instead.
+func (*UpdateNamespaceQuotaResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{13}
+}
+
+func (x *UpdateNamespaceQuotaResponse) GetQuota() *NamespaceQuota {
+	if x != nil {
+		return x.Quota
+	}
+	return nil
+}
+
 type GetPipelineRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -596,7 +822,7 @@ type GetPipelineRequest struct {
 func (x *GetPipelineRequest) Reset() {
 	*x = GetPipelineRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[10]
+		mi := &file_gofer_transport_proto_msgTypes[14]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -609,7 +835,7 @@ func (x *GetPipelineRequest) String() string {
 func (*GetPipelineRequest) ProtoMessage() {}
 
 func (x *GetPipelineRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[10]
+	mi := &file_gofer_transport_proto_msgTypes[14]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -622,7 +848,7 @@ func (x *GetPipelineRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetPipelineRequest.ProtoReflect.Descriptor instead.
 func (*GetPipelineRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{10}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{14}
 }
 
 func (x *GetPipelineRequest) GetNamespaceId() string {
@@ -650,7 +876,7 @@ type GetPipelineResponse struct {
 func (x *GetPipelineResponse) Reset() {
 	*x = GetPipelineResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[11]
+		mi := &file_gofer_transport_proto_msgTypes[15]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -663,7 +889,7 @@ func (x *GetPipelineResponse) String() string {
 func (*GetPipelineResponse) ProtoMessage() {}
 
 func (x *GetPipelineResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[11]
+	mi := &file_gofer_transport_proto_msgTypes[15]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -676,7 +902,7 @@ func (x *GetPipelineResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GetPipelineResponse.ProtoReflect.Descriptor instead.
 func (*GetPipelineResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{11}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{15}
 }
 
 func (x *GetPipelineResponse) GetPipeline() *Pipeline {
@@ -703,7 +929,7 @@ type ListPipelinesRequest struct {
 func (x *ListPipelinesRequest) Reset() {
 	*x = ListPipelinesRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[12]
+		mi := &file_gofer_transport_proto_msgTypes[16]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -716,7 +942,7 @@ func (x *ListPipelinesRequest) String() string {
 func (*ListPipelinesRequest) ProtoMessage() {}
 
 func (x *ListPipelinesRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[12]
+	mi := &file_gofer_transport_proto_msgTypes[16]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -729,7 +955,7 @@ func (x *ListPipelinesRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ListPipelinesRequest.ProtoReflect.Descriptor instead.
 func (*ListPipelinesRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{12}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{16}
 }
 
 func (x *ListPipelinesRequest) GetOffset() int64 {
@@ -767,7 +993,7 @@ type ListPipelinesResponse struct {
 func (x *ListPipelinesResponse) Reset() {
 	*x = ListPipelinesResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[13]
+		mi := &file_gofer_transport_proto_msgTypes[17]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -780,7 +1006,7 @@ func (x *ListPipelinesResponse) String() string {
 func (*ListPipelinesResponse) ProtoMessage() {}
 
 func (x *ListPipelinesResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[13]
+	mi := &file_gofer_transport_proto_msgTypes[17]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -793,7 +1019,7 @@ func (x *ListPipelinesResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ListPipelinesResponse.ProtoReflect.Descriptor instead.
 func (*ListPipelinesResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{13}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{17}
 }
 
 func (x *ListPipelinesResponse) GetPipelines() []*Pipeline {
@@ -822,7 +1048,7 @@ type DisablePipelineRequest struct {
 func (x *DisablePipelineRequest) Reset() {
 	*x = DisablePipelineRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[14]
+		mi := &file_gofer_transport_proto_msgTypes[18]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -835,7 +1061,7 @@ func (x *DisablePipelineRequest) String() string {
 func (*DisablePipelineRequest) ProtoMessage() {}
 
 func (x *DisablePipelineRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[14]
+	mi := &file_gofer_transport_proto_msgTypes[18]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -848,7 +1074,7 @@ func (x *DisablePipelineRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use DisablePipelineRequest.ProtoReflect.Descriptor instead.
 func (*DisablePipelineRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{14}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{18}
 }
 
 func (x *DisablePipelineRequest) GetNamespaceId() string {
@@ -874,7 +1100,7 @@ type DisablePipelineResponse struct {
 func (x *DisablePipelineResponse) Reset() {
 	*x = DisablePipelineResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[15]
+		mi := &file_gofer_transport_proto_msgTypes[19]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -887,7 +1113,7 @@ func (x *DisablePipelineResponse) String() string {
 func (*DisablePipelineResponse) ProtoMessage() {}
 
 func (x *DisablePipelineResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[15]
+	mi := &file_gofer_transport_proto_msgTypes[19]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -900,7 +1126,7 @@ func (x *DisablePipelineResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use DisablePipelineResponse.ProtoReflect.Descriptor instead.
 func (*DisablePipelineResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{15}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{19}
 }
 
 type EnablePipelineRequest struct {
@@ -915,7 +1141,7 @@ type EnablePipelineRequest struct {
 func (x *EnablePipelineRequest) Reset() {
 	*x = EnablePipelineRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[16]
+		mi := &file_gofer_transport_proto_msgTypes[20]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -928,7 +1154,7 @@ func (x *EnablePipelineRequest) String() string {
 func (*EnablePipelineRequest) ProtoMessage() {}
 
 func (x *EnablePipelineRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[16]
+	mi := &file_gofer_transport_proto_msgTypes[20]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -941,7 +1167,7 @@ func (x *EnablePipelineRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use EnablePipelineRequest.ProtoReflect.Descriptor instead.
 func (*EnablePipelineRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{16}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{20}
 }
 
 func (x *EnablePipelineRequest) GetNamespaceId() string {
@@ -967,7 +1193,7 @@ type EnablePipelineResponse struct {
 func (x *EnablePipelineResponse) Reset() {
 	*x = EnablePipelineResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[17]
+		mi := &file_gofer_transport_proto_msgTypes[21]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -980,7 +1206,7 @@ func (x *EnablePipelineResponse) String() string {
 func (*EnablePipelineResponse) ProtoMessage() {}
 
 func (x *EnablePipelineResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[17]
+	mi := &file_gofer_transport_proto_msgTypes[21]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -993,7 +1219,7 @@ func (x *EnablePipelineResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use EnablePipelineResponse.ProtoReflect.Descriptor instead.
 func (*EnablePipelineResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{17}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{21}
 }
 
 type CreatePipelineRawRequest struct {
@@ -1016,7 +1242,7 @@ type CreatePipelineRawRequest struct {
 func (x *CreatePipelineRawRequest) Reset() {
 	*x = CreatePipelineRawRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[18]
+		mi := &file_gofer_transport_proto_msgTypes[22]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1029,7 +1255,7 @@ func (x *CreatePipelineRawRequest) String() string {
 func (*CreatePipelineRawRequest) ProtoMessage() {}
 
 func (x *CreatePipelineRawRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[18]
+	mi := &file_gofer_transport_proto_msgTypes[22]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1042,7 +1268,7 @@ func (x *CreatePipelineRawRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreatePipelineRawRequest.ProtoReflect.Descriptor instead.
 func (*CreatePipelineRawRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{18}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{22}
 }
 
 func (x *CreatePipelineRawRequest) GetContent() []byte {
@@ -1070,7 +1296,7 @@ type CreatePipelineRawResponse struct {
 func (x *CreatePipelineRawResponse) Reset() {
 	*x = CreatePipelineRawResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[19]
+		mi := &file_gofer_transport_proto_msgTypes[23]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1083,7 +1309,7 @@ func (x *CreatePipelineRawResponse) String() string {
 func (*CreatePipelineRawResponse) ProtoMessage() {}
 
 func (x *CreatePipelineRawResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[19]
+	mi := &file_gofer_transport_proto_msgTypes[23]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1096,7 +1322,7 @@ func (x *CreatePipelineRawResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreatePipelineRawResponse.ProtoReflect.Descriptor instead.
 func (*CreatePipelineRawResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{19}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{23}
 }
 
 func (x *CreatePipelineRawResponse) GetPipeline() *Pipeline {
@@ -1126,7 +1352,7 @@ type CreatePipelineByURLRequest struct {
 func (x *CreatePipelineByURLRequest) Reset() {
 	*x = CreatePipelineByURLRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[20]
+		mi := &file_gofer_transport_proto_msgTypes[24]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1139,7 +1365,7 @@ func (x *CreatePipelineByURLRequest) String() string {
 func (*CreatePipelineByURLRequest) ProtoMessage() {}
 
 func (x *CreatePipelineByURLRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[20]
+	mi := &file_gofer_transport_proto_msgTypes[24]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1152,7 +1378,7 @@ func (x *CreatePipelineByURLRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreatePipelineByURLRequest.ProtoReflect.Descriptor instead.
 func (*CreatePipelineByURLRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{20}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{24}
 }
 
 func (x *CreatePipelineByURLRequest) GetUrl() string {
@@ -1173,7 +1399,7 @@ type CreatePipelineByURLResponse struct {
 func (x *CreatePipelineByURLResponse) Reset() {
 	*x = CreatePipelineByURLResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[21]
+		mi := &file_gofer_transport_proto_msgTypes[25]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1186,7 +1412,7 @@ func (x *CreatePipelineByURLResponse) String() string {
 func (*CreatePipelineByURLResponse) ProtoMessage() {}
 
 func (x *CreatePipelineByURLResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[21]
+	mi := &file_gofer_transport_proto_msgTypes[25]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1199,7 +1425,7 @@ func (x *CreatePipelineByURLResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreatePipelineByURLResponse.ProtoReflect.Descriptor instead.
 func (*CreatePipelineByURLResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{21}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{25}
 }
 
 func (x *CreatePipelineByURLResponse) GetPipeline() *Pipeline {
@@ -1231,7 +1457,7 @@ type UpdatePipelineRawRequest struct {
 func (x *UpdatePipelineRawRequest) Reset() {
 	*x = UpdatePipelineRawRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[22]
+		mi := &file_gofer_transport_proto_msgTypes[26]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1244,7 +1470,7 @@ func (x *UpdatePipelineRawRequest) String() string {
 func (*UpdatePipelineRawRequest) ProtoMessage() {}
 
 func (x *UpdatePipelineRawRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[22]
+	mi := &file_gofer_transport_proto_msgTypes[26]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1257,7 +1483,7 @@ func (x *UpdatePipelineRawRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UpdatePipelineRawRequest.ProtoReflect.Descriptor instead.
 func (*UpdatePipelineRawRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{22}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{26}
 }
 
 func (x *UpdatePipelineRawRequest) GetNamespaceId() string {
@@ -1299,7 +1525,7 @@ type UpdatePipelineRawResponse struct {
 func (x *UpdatePipelineRawResponse) Reset() {
 	*x = UpdatePipelineRawResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[23]
+		mi := &file_gofer_transport_proto_msgTypes[27]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1312,7 +1538,7 @@ func (x *UpdatePipelineRawResponse) String() string {
 func (*UpdatePipelineRawResponse) ProtoMessage() {}
 
 func (x *UpdatePipelineRawResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[23]
+	mi := &file_gofer_transport_proto_msgTypes[27]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1325,7 +1551,7 @@ func (x *UpdatePipelineRawResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UpdatePipelineRawResponse.ProtoReflect.Descriptor instead.
 func (*UpdatePipelineRawResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{23}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{27}
 }
 
 func (x *UpdatePipelineRawResponse) GetPipeline() *Pipeline {
@@ -1357,7 +1583,7 @@ type UpdatePipelineByURLRequest struct {
 func (x *UpdatePipelineByURLRequest) Reset() {
 	*x = UpdatePipelineByURLRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[24]
+		mi := &file_gofer_transport_proto_msgTypes[28]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1370,7 +1596,7 @@ func (x *UpdatePipelineByURLRequest) String() string {
 func (*UpdatePipelineByURLRequest) ProtoMessage() {}
 
 func (x *UpdatePipelineByURLRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[24]
+	mi := &file_gofer_transport_proto_msgTypes[28]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1383,7 +1609,7 @@ func (x *UpdatePipelineByURLRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UpdatePipelineByURLRequest.ProtoReflect.Descriptor instead.
 func (*UpdatePipelineByURLRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{24}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{28}
 }
 
 func (x *UpdatePipelineByURLRequest) GetNamespaceId() string {
@@ -1418,7 +1644,7 @@ type UpdatePipelineByURLResponse struct {
 func (x *UpdatePipelineByURLResponse) Reset() {
 	*x = UpdatePipelineByURLResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[25]
+		mi := &file_gofer_transport_proto_msgTypes[29]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1431,7 +1657,7 @@ func (x *UpdatePipelineByURLResponse) String() string {
 func (*UpdatePipelineByURLResponse) ProtoMessage() {}
 
 func (x *UpdatePipelineByURLResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[25]
+	mi := &file_gofer_transport_proto_msgTypes[29]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1444,7 +1670,7 @@ func (x *UpdatePipelineByURLResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use UpdatePipelineByURLResponse.ProtoReflect.Descriptor instead.
 func (*UpdatePipelineByURLResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{25}
+	return file_gofer_transport_proto_rawDescGZIP(), []int{29}
 }
 
 func (x *UpdatePipelineByURLResponse) GetPipeline() *Pipeline {
@@ -1454,32 +1680,38 @@ func (x *UpdatePipelineByURLResponse) GetPipeline() *Pipeline {
 	return nil
 }
 
-type AbandonPipelineRequest struct {
+type ListPipelineConfigVersionsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	Id          string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`                                      // Pipeline ID
+	Id          string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`                                      // Unique pipeline identifier
+	// offset is a pagination parameter that defines where to start when
+	// counting the list of versions to return.
+	Offset int64 `protobuf:"varint,3,opt,name=offset,proto3" json:"offset,omitempty"`
+	// limit is a pagination parameter that defines how many versions to return
+	// per result.
+	Limit int64 `protobuf:"varint,4,opt,name=limit,proto3" json:"limit,omitempty"`
 }
 
-func (x *AbandonPipelineRequest) Reset() {
-	*x = AbandonPipelineRequest{}
+func (x *ListPipelineConfigVersionsRequest) Reset() {
+	*x = ListPipelineConfigVersionsRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[26]
+		mi := &file_gofer_transport_proto_msgTypes[30]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AbandonPipelineRequest) String() string {
+func (x *ListPipelineConfigVersionsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AbandonPipelineRequest) ProtoMessage() {}
+func (*ListPipelineConfigVersionsRequest) ProtoMessage() {}
 
-func (x *AbandonPipelineRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[26]
+func (x *ListPipelineConfigVersionsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[30]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1490,48 +1722,64 @@ func (x *AbandonPipelineRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AbandonPipelineRequest.ProtoReflect.Descriptor instead.
-func (*AbandonPipelineRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{26}
+// Deprecated: Use ListPipelineConfigVersionsRequest.ProtoReflect.Descriptor instead.
+func (*ListPipelineConfigVersionsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{30}
 }
 
-func (x *AbandonPipelineRequest) GetNamespaceId() string {
+func (x *ListPipelineConfigVersionsRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *AbandonPipelineRequest) GetId() string {
+func (x *ListPipelineConfigVersionsRequest) GetId() string {
 	if x != nil {
 		return x.Id
 	}
 	return ""
 }
 
-type AbandonPipelineResponse struct {
+func (x *ListPipelineConfigVersionsRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+func (x *ListPipelineConfigVersionsRequest) GetLimit() int64 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+type ListPipelineConfigVersionsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	Versions []*PipelineConfigVersion `protobuf:"bytes,1,rep,name=versions,proto3" json:"versions,omitempty"`
 }
 
-func (x *AbandonPipelineResponse) Reset() {
-	*x = AbandonPipelineResponse{}
+func (x *ListPipelineConfigVersionsResponse) Reset() {
+	*x = ListPipelineConfigVersionsResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[27]
+		mi := &file_gofer_transport_proto_msgTypes[31]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AbandonPipelineResponse) String() string {
+func (x *ListPipelineConfigVersionsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AbandonPipelineResponse) ProtoMessage() {}
+func (*ListPipelineConfigVersionsResponse) ProtoMessage() {}
 
-func (x *AbandonPipelineResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[27]
+func (x *ListPipelineConfigVersionsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[31]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1542,38 +1790,47 @@ func (x *AbandonPipelineResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AbandonPipelineResponse.ProtoReflect.Descriptor instead.
-func (*AbandonPipelineResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{27}
+// Deprecated: Use ListPipelineConfigVersionsResponse.ProtoReflect.Descriptor instead.
+func (*ListPipelineConfigVersionsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{31}
 }
 
-type GetRunRequest struct {
+func (x *ListPipelineConfigVersionsResponse) GetVersions() []*PipelineConfigVersion {
+	if x != nil {
+		return x.Versions
+	}
+	return nil
+}
+
+type RollbackPipelineConfigRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Id          int64  `protobuf:"varint,3,opt,name=id,proto3" json:"id,omitempty"` // Run ID
+	Id          string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`                                      // Unique pipeline identifier
+	// The config version to roll back to; 0 rolls back to the version before the
+	// current one.
+	Version int64 `protobuf:"varint,3,opt,name=version,proto3" json:"version,omitempty"`
 }
 
-func (x *GetRunRequest) Reset() {
-	*x = GetRunRequest{}
+func (x *RollbackPipelineConfigRequest) Reset() {
+	*x = RollbackPipelineConfigRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[28]
+		mi := &file_gofer_transport_proto_msgTypes[32]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetRunRequest) String() string {
+func (x *RollbackPipelineConfigRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetRunRequest) ProtoMessage() {}
+func (*RollbackPipelineConfigRequest) ProtoMessage() {}
 
-func (x *GetRunRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[28]
+func (x *RollbackPipelineConfigRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[32]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1584,57 +1841,59 @@ func (x *GetRunRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetRunRequest.ProtoReflect.Descriptor instead.
-func (*GetRunRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{28}
+// Deprecated: Use RollbackPipelineConfigRequest.ProtoReflect.Descriptor instead.
+func (*RollbackPipelineConfigRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{32}
 }
 
-func (x *GetRunRequest) GetNamespaceId() string {
+func (x *RollbackPipelineConfigRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *GetRunRequest) GetPipelineId() string {
+func (x *RollbackPipelineConfigRequest) GetId() string {
 	if x != nil {
-		return x.PipelineId
+		return x.Id
 	}
 	return ""
 }
 
-func (x *GetRunRequest) GetId() int64 {
+func (x *RollbackPipelineConfigRequest) GetVersion() int64 {
 	if x != nil {
-		return x.Id
+		return x.Version
 	}
 	return 0
 }
 
-type GetRunResponse struct {
+type RollbackPipelineConfigResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Run *Run `protobuf:"bytes,1,opt,name=run,proto3" json:"run,omitempty"`
+	Pipeline *Pipeline `protobuf:"bytes,1,opt,name=pipeline,proto3" json:"pipeline,omitempty"`
+	// The version that was rolled back to.
+	Version int64 `protobuf:"varint,2,opt,name=version,proto3" json:"version,omitempty"`
 }
 
-func (x *GetRunResponse) Reset() {
-	*x = GetRunResponse{}
+func (x *RollbackPipelineConfigResponse) Reset() {
+	*x = RollbackPipelineConfigResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[29]
+		mi := &file_gofer_transport_proto_msgTypes[33]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetRunResponse) String() string {
+func (x *RollbackPipelineConfigResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetRunResponse) ProtoMessage() {}
+func (*RollbackPipelineConfigResponse) ProtoMessage() {}
 
-func (x *GetRunResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[29]
+func (x *RollbackPipelineConfigResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[33]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1645,45 +1904,60 @@ func (x *GetRunResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetRunResponse.ProtoReflect.Descriptor instead.
-func (*GetRunResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{29}
+// Deprecated: Use RollbackPipelineConfigResponse.ProtoReflect.Descriptor instead.
+func (*RollbackPipelineConfigResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{33}
 }
 
-func (x *GetRunResponse) GetRun() *Run {
+func (x *RollbackPipelineConfigResponse) GetPipeline() *Pipeline {
 	if x != nil {
-		return x.Run
+		return x.Pipeline
 	}
 	return nil
 }
 
-type BatchGetRunsRequest struct {
+func (x *RollbackPipelineConfigResponse) GetVersion() int64 {
+	if x != nil {
+		return x.Version
+	}
+	return 0
+}
+
+type StartPipelineCanaryRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string  `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string  `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Ids         []int64 `protobuf:"varint,3,rep,packed,name=ids,proto3" json:"ids,omitempty"` // Run IDs
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	Id          string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`                                      // Unique pipeline identifier
+	// Gofer pipeline config file byte string of the version to try out.
+	Content []byte `protobuf:"bytes,3,opt,name=content,proto3" json:"content,omitempty"`
+	// path is a metadata parameter to where the pipeline file might be stored.
+	Path string `protobuf:"bytes,4,opt,name=path,proto3" json:"path,omitempty"`
+	// The percentage of runs that should use the new config version; 1 to 100.
+	Percentage int64 `protobuf:"varint,5,opt,name=percentage,proto3" json:"percentage,omitempty"`
+	// Only runs started manually use the new config version. Cannot be combined
+	// with percentage.
+	ManualOnly bool `protobuf:"varint,6,opt,name=manual_only,json=manualOnly,proto3" json:"manual_only,omitempty"`
 }
 
-func (x *BatchGetRunsRequest) Reset() {
-	*x = BatchGetRunsRequest{}
+func (x *StartPipelineCanaryRequest) Reset() {
+	*x = StartPipelineCanaryRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[30]
+		mi := &file_gofer_transport_proto_msgTypes[34]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *BatchGetRunsRequest) String() string {
+func (x *StartPipelineCanaryRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*BatchGetRunsRequest) ProtoMessage() {}
+func (*StartPipelineCanaryRequest) ProtoMessage() {}
 
-func (x *BatchGetRunsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[30]
+func (x *StartPipelineCanaryRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[34]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1694,57 +1968,80 @@ func (x *BatchGetRunsRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use BatchGetRunsRequest.ProtoReflect.Descriptor instead.
-func (*BatchGetRunsRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{30}
+// Deprecated: Use StartPipelineCanaryRequest.ProtoReflect.Descriptor instead.
+func (*StartPipelineCanaryRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{34}
 }
 
-func (x *BatchGetRunsRequest) GetNamespaceId() string {
+func (x *StartPipelineCanaryRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *BatchGetRunsRequest) GetPipelineId() string {
+func (x *StartPipelineCanaryRequest) GetId() string {
 	if x != nil {
-		return x.PipelineId
+		return x.Id
 	}
 	return ""
 }
 
-func (x *BatchGetRunsRequest) GetIds() []int64 {
+func (x *StartPipelineCanaryRequest) GetContent() []byte {
 	if x != nil {
-		return x.Ids
+		return x.Content
 	}
 	return nil
 }
 
-type BatchGetRunsResponse struct {
+func (x *StartPipelineCanaryRequest) GetPath() string {
+	if x != nil {
+		return x.Path
+	}
+	return ""
+}
+
+func (x *StartPipelineCanaryRequest) GetPercentage() int64 {
+	if x != nil {
+		return x.Percentage
+	}
+	return 0
+}
+
+func (x *StartPipelineCanaryRequest) GetManualOnly() bool {
+	if x != nil {
+		return x.ManualOnly
+	}
+	return false
+}
+
+type StartPipelineCanaryResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Runs []*Run `protobuf:"bytes,1,rep,name=runs,proto3" json:"runs,omitempty"`
+	Pipeline *Pipeline `protobuf:"bytes,1,opt,name=pipeline,proto3" json:"pipeline,omitempty"`
+	// The config version the canary is trying out.
+	Version int64 `protobuf:"varint,2,opt,name=version,proto3" json:"version,omitempty"`
 }
 
-func (x *BatchGetRunsResponse) Reset() {
-	*x = BatchGetRunsResponse{}
+func (x *StartPipelineCanaryResponse) Reset() {
+	*x = StartPipelineCanaryResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[31]
+		mi := &file_gofer_transport_proto_msgTypes[35]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *BatchGetRunsResponse) String() string {
+func (x *StartPipelineCanaryResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*BatchGetRunsResponse) ProtoMessage() {}
+func (*StartPipelineCanaryResponse) ProtoMessage() {}
 
-func (x *BatchGetRunsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[31]
+func (x *StartPipelineCanaryResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[35]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1755,50 +2052,51 @@ func (x *BatchGetRunsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use BatchGetRunsResponse.ProtoReflect.Descriptor instead.
-func (*BatchGetRunsResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{31}
+// Deprecated: Use StartPipelineCanaryResponse.ProtoReflect.Descriptor instead.
+func (*StartPipelineCanaryResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{35}
 }
 
-func (x *BatchGetRunsResponse) GetRuns() []*Run {
+func (x *StartPipelineCanaryResponse) GetPipeline() *Pipeline {
 	if x != nil {
-		return x.Runs
+		return x.Pipeline
 	}
 	return nil
 }
 
-type ListRunsRequest struct {
+func (x *StartPipelineCanaryResponse) GetVersion() int64 {
+	if x != nil {
+		return x.Version
+	}
+	return 0
+}
+
+type PromotePipelineCanaryRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// offset is a pagination parameter that defines where to start when
-	// counting the list of pipelines to return
-	Offset int64 `protobuf:"varint,1,opt,name=offset,proto3" json:"offset,omitempty"`
-	// limit is a pagination parameter that defines how many pipelines to return
-	// per result.
-	Limit       int64  `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
-	NamespaceId string `protobuf:"bytes,3,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,4,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	Id          string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`                                      // Unique pipeline identifier
 }
 
-func (x *ListRunsRequest) Reset() {
-	*x = ListRunsRequest{}
+func (x *PromotePipelineCanaryRequest) Reset() {
+	*x = PromotePipelineCanaryRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[32]
+		mi := &file_gofer_transport_proto_msgTypes[36]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListRunsRequest) String() string {
+func (x *PromotePipelineCanaryRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListRunsRequest) ProtoMessage() {}
+func (*PromotePipelineCanaryRequest) ProtoMessage() {}
 
-func (x *ListRunsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[32]
+func (x *PromotePipelineCanaryRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[36]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1809,64 +2107,50 @@ func (x *ListRunsRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListRunsRequest.ProtoReflect.Descriptor instead.
-func (*ListRunsRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{32}
-}
-
-func (x *ListRunsRequest) GetOffset() int64 {
-	if x != nil {
-		return x.Offset
-	}
-	return 0
-}
-
-func (x *ListRunsRequest) GetLimit() int64 {
-	if x != nil {
-		return x.Limit
-	}
-	return 0
+// Deprecated: Use PromotePipelineCanaryRequest.ProtoReflect.Descriptor instead.
+func (*PromotePipelineCanaryRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{36}
 }
 
-func (x *ListRunsRequest) GetNamespaceId() string {
+func (x *PromotePipelineCanaryRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *ListRunsRequest) GetPipelineId() string {
+func (x *PromotePipelineCanaryRequest) GetId() string {
 	if x != nil {
-		return x.PipelineId
+		return x.Id
 	}
 	return ""
 }
 
-type ListRunsResponse struct {
+type PromotePipelineCanaryResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Runs []*Run `protobuf:"bytes,1,rep,name=runs,proto3" json:"runs,omitempty"`
+	Pipeline *Pipeline `protobuf:"bytes,1,opt,name=pipeline,proto3" json:"pipeline,omitempty"`
 }
 
-func (x *ListRunsResponse) Reset() {
-	*x = ListRunsResponse{}
+func (x *PromotePipelineCanaryResponse) Reset() {
+	*x = PromotePipelineCanaryResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[33]
+		mi := &file_gofer_transport_proto_msgTypes[37]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListRunsResponse) String() string {
+func (x *PromotePipelineCanaryResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListRunsResponse) ProtoMessage() {}
+func (*PromotePipelineCanaryResponse) ProtoMessage() {}
 
-func (x *ListRunsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[33]
+func (x *PromotePipelineCanaryResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[37]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1877,52 +2161,44 @@ func (x *ListRunsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListRunsResponse.ProtoReflect.Descriptor instead.
-func (*ListRunsResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{33}
+// Deprecated: Use PromotePipelineCanaryResponse.ProtoReflect.Descriptor instead.
+func (*PromotePipelineCanaryResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{37}
 }
 
-func (x *ListRunsResponse) GetRuns() []*Run {
+func (x *PromotePipelineCanaryResponse) GetPipeline() *Pipeline {
 	if x != nil {
-		return x.Runs
+		return x.Pipeline
 	}
 	return nil
 }
 
-type StartRunRequest struct {
+type AbortPipelineCanaryRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	// Only is an array of tasks which will run exclusively for this particular
-	// run. Tasks still carry relevant dependencies. Run will return an error if
-	// the dependencies of a task is also not met in the list of tasks included in
-	// only.
-	Only []string `protobuf:"bytes,3,rep,name=only,proto3" json:"only,omitempty"`
-	// variables allows for the replacement of task environment variables, it
-	// overrides all other environment variables if there is a name collision.
-	Variables map[string]string `protobuf:"bytes,4,rep,name=variables,proto3" json:"variables,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	Id          string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`                                      // Unique pipeline identifier
 }
 
-func (x *StartRunRequest) Reset() {
-	*x = StartRunRequest{}
+func (x *AbortPipelineCanaryRequest) Reset() {
+	*x = AbortPipelineCanaryRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[34]
+		mi := &file_gofer_transport_proto_msgTypes[38]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *StartRunRequest) String() string {
+func (x *AbortPipelineCanaryRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*StartRunRequest) ProtoMessage() {}
+func (*AbortPipelineCanaryRequest) ProtoMessage() {}
 
-func (x *StartRunRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[34]
+func (x *AbortPipelineCanaryRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[38]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1933,64 +2209,50 @@ func (x *StartRunRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use StartRunRequest.ProtoReflect.Descriptor instead.
-func (*StartRunRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{34}
+// Deprecated: Use AbortPipelineCanaryRequest.ProtoReflect.Descriptor instead.
+func (*AbortPipelineCanaryRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{38}
 }
 
-func (x *StartRunRequest) GetNamespaceId() string {
+func (x *AbortPipelineCanaryRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *StartRunRequest) GetPipelineId() string {
+func (x *AbortPipelineCanaryRequest) GetId() string {
 	if x != nil {
-		return x.PipelineId
+		return x.Id
 	}
 	return ""
 }
 
-func (x *StartRunRequest) GetOnly() []string {
-	if x != nil {
-		return x.Only
-	}
-	return nil
-}
-
-func (x *StartRunRequest) GetVariables() map[string]string {
-	if x != nil {
-		return x.Variables
-	}
-	return nil
-}
-
-type StartRunResponse struct {
+type AbortPipelineCanaryResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Run *Run `protobuf:"bytes,1,opt,name=run,proto3" json:"run,omitempty"`
+	Pipeline *Pipeline `protobuf:"bytes,1,opt,name=pipeline,proto3" json:"pipeline,omitempty"`
 }
 
-func (x *StartRunResponse) Reset() {
-	*x = StartRunResponse{}
+func (x *AbortPipelineCanaryResponse) Reset() {
+	*x = AbortPipelineCanaryResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[35]
+		mi := &file_gofer_transport_proto_msgTypes[39]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *StartRunResponse) String() string {
+func (x *AbortPipelineCanaryResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*StartRunResponse) ProtoMessage() {}
+func (*AbortPipelineCanaryResponse) ProtoMessage() {}
 
-func (x *StartRunResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[35]
+func (x *AbortPipelineCanaryResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[39]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2001,45 +2263,44 @@ func (x *StartRunResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use StartRunResponse.ProtoReflect.Descriptor instead.
-func (*StartRunResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{35}
+// Deprecated: Use AbortPipelineCanaryResponse.ProtoReflect.Descriptor instead.
+func (*AbortPipelineCanaryResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{39}
 }
 
-func (x *StartRunResponse) GetRun() *Run {
+func (x *AbortPipelineCanaryResponse) GetPipeline() *Pipeline {
 	if x != nil {
-		return x.Run
+		return x.Pipeline
 	}
 	return nil
 }
 
-type RetryRunRequest struct {
+type AbandonPipelineRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Id          int64  `protobuf:"varint,3,opt,name=id,proto3" json:"id,omitempty"` // Run ID
+	Id          string `protobuf:"bytes,2,opt,name=id,proto3" json:"id,omitempty"`                                      // Pipeline ID
 }
 
-func (x *RetryRunRequest) Reset() {
-	*x = RetryRunRequest{}
+func (x *AbandonPipelineRequest) Reset() {
+	*x = AbandonPipelineRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[36]
+		mi := &file_gofer_transport_proto_msgTypes[40]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *RetryRunRequest) String() string {
+func (x *AbandonPipelineRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*RetryRunRequest) ProtoMessage() {}
+func (*AbandonPipelineRequest) ProtoMessage() {}
 
-func (x *RetryRunRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[36]
+func (x *AbandonPipelineRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[40]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2050,57 +2311,48 @@ func (x *RetryRunRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use RetryRunRequest.ProtoReflect.Descriptor instead.
-func (*RetryRunRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{36}
+// Deprecated: Use AbandonPipelineRequest.ProtoReflect.Descriptor instead.
+func (*AbandonPipelineRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{40}
 }
 
-func (x *RetryRunRequest) GetNamespaceId() string {
+func (x *AbandonPipelineRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *RetryRunRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
-}
-
-func (x *RetryRunRequest) GetId() int64 {
+func (x *AbandonPipelineRequest) GetId() string {
 	if x != nil {
 		return x.Id
 	}
-	return 0
+	return ""
 }
 
-type RetryRunResponse struct {
+type AbandonPipelineResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	Run *Run `protobuf:"bytes,1,opt,name=run,proto3" json:"run,omitempty"`
 }
 
-func (x *RetryRunResponse) Reset() {
-	*x = RetryRunResponse{}
+func (x *AbandonPipelineResponse) Reset() {
+	*x = AbandonPipelineResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[37]
+		mi := &file_gofer_transport_proto_msgTypes[41]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *RetryRunResponse) String() string {
+func (x *AbandonPipelineResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*RetryRunResponse) ProtoMessage() {}
+func (*AbandonPipelineResponse) ProtoMessage() {}
 
-func (x *RetryRunResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[37]
+func (x *AbandonPipelineResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[41]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2111,19 +2363,12 @@ func (x *RetryRunResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use RetryRunResponse.ProtoReflect.Descriptor instead.
-func (*RetryRunResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{37}
-}
-
-func (x *RetryRunResponse) GetRun() *Run {
-	if x != nil {
-		return x.Run
-	}
-	return nil
+// Deprecated: Use AbandonPipelineResponse.ProtoReflect.Descriptor instead.
+func (*AbandonPipelineResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{41}
 }
 
-type CancelRunRequest struct {
+type GetRunRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
@@ -2131,28 +2376,25 @@ type CancelRunRequest struct {
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
 	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
 	Id          int64  `protobuf:"varint,3,opt,name=id,proto3" json:"id,omitempty"` // Run ID
-	// force will cause Gofer to hard kill any outstanding task run containers.
-	// Usually this means that the container receives a SIGKILL.
-	Force bool `protobuf:"varint,4,opt,name=force,proto3" json:"force,omitempty"`
 }
 
-func (x *CancelRunRequest) Reset() {
-	*x = CancelRunRequest{}
+func (x *GetRunRequest) Reset() {
+	*x = GetRunRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[38]
+		mi := &file_gofer_transport_proto_msgTypes[42]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CancelRunRequest) String() string {
+func (x *GetRunRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CancelRunRequest) ProtoMessage() {}
+func (*GetRunRequest) ProtoMessage() {}
 
-func (x *CancelRunRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[38]
+func (x *GetRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[42]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2163,62 +2405,57 @@ func (x *CancelRunRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CancelRunRequest.ProtoReflect.Descriptor instead.
-func (*CancelRunRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{38}
+// Deprecated: Use GetRunRequest.ProtoReflect.Descriptor instead.
+func (*GetRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{42}
 }
 
-func (x *CancelRunRequest) GetNamespaceId() string {
+func (x *GetRunRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *CancelRunRequest) GetPipelineId() string {
+func (x *GetRunRequest) GetPipelineId() string {
 	if x != nil {
 		return x.PipelineId
 	}
 	return ""
 }
 
-func (x *CancelRunRequest) GetId() int64 {
+func (x *GetRunRequest) GetId() int64 {
 	if x != nil {
 		return x.Id
 	}
 	return 0
 }
 
-func (x *CancelRunRequest) GetForce() bool {
-	if x != nil {
-		return x.Force
-	}
-	return false
-}
-
-type CancelRunResponse struct {
+type GetRunResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	Run *Run `protobuf:"bytes,1,opt,name=run,proto3" json:"run,omitempty"`
 }
 
-func (x *CancelRunResponse) Reset() {
-	*x = CancelRunResponse{}
+func (x *GetRunResponse) Reset() {
+	*x = GetRunResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[39]
+		mi := &file_gofer_transport_proto_msgTypes[43]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CancelRunResponse) String() string {
+func (x *GetRunResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CancelRunResponse) ProtoMessage() {}
+func (*GetRunResponse) ProtoMessage() {}
 
-func (x *CancelRunResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[39]
+func (x *GetRunResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[43]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2229,40 +2466,45 @@ func (x *CancelRunResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CancelRunResponse.ProtoReflect.Descriptor instead.
-func (*CancelRunResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{39}
+// Deprecated: Use GetRunResponse.ProtoReflect.Descriptor instead.
+func (*GetRunResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{43}
 }
 
-type CancelAllRunsRequest struct {
+func (x *GetRunResponse) GetRun() *Run {
+	if x != nil {
+		return x.Run
+	}
+	return nil
+}
+
+type BatchGetRunsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	// force will cause Gofer to hard kill any outstanding task run containers.
-	// Usually this means that the container receives a SIGKILL.
-	Force bool `protobuf:"varint,3,opt,name=force,proto3" json:"force,omitempty"`
+	NamespaceId string  `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string  `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Ids         []int64 `protobuf:"varint,3,rep,packed,name=ids,proto3" json:"ids,omitempty"` // Run IDs
 }
 
-func (x *CancelAllRunsRequest) Reset() {
-	*x = CancelAllRunsRequest{}
+func (x *BatchGetRunsRequest) Reset() {
+	*x = BatchGetRunsRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[40]
+		mi := &file_gofer_transport_proto_msgTypes[44]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CancelAllRunsRequest) String() string {
+func (x *BatchGetRunsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CancelAllRunsRequest) ProtoMessage() {}
+func (*BatchGetRunsRequest) ProtoMessage() {}
 
-func (x *CancelAllRunsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[40]
+func (x *BatchGetRunsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[44]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2273,57 +2515,57 @@ func (x *CancelAllRunsRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CancelAllRunsRequest.ProtoReflect.Descriptor instead.
-func (*CancelAllRunsRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{40}
+// Deprecated: Use BatchGetRunsRequest.ProtoReflect.Descriptor instead.
+func (*BatchGetRunsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{44}
 }
 
-func (x *CancelAllRunsRequest) GetNamespaceId() string {
+func (x *BatchGetRunsRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *CancelAllRunsRequest) GetPipelineId() string {
+func (x *BatchGetRunsRequest) GetPipelineId() string {
 	if x != nil {
 		return x.PipelineId
 	}
 	return ""
 }
 
-func (x *CancelAllRunsRequest) GetForce() bool {
+func (x *BatchGetRunsRequest) GetIds() []int64 {
 	if x != nil {
-		return x.Force
+		return x.Ids
 	}
-	return false
+	return nil
 }
 
-type CancelAllRunsResponse struct {
+type BatchGetRunsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Runs []int64 `protobuf:"varint,1,rep,packed,name=runs,proto3" json:"runs,omitempty"`
+	Runs []*Run `protobuf:"bytes,1,rep,name=runs,proto3" json:"runs,omitempty"`
 }
 
-func (x *CancelAllRunsResponse) Reset() {
-	*x = CancelAllRunsResponse{}
+func (x *BatchGetRunsResponse) Reset() {
+	*x = BatchGetRunsResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[41]
+		mi := &file_gofer_transport_proto_msgTypes[45]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CancelAllRunsResponse) String() string {
+func (x *BatchGetRunsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CancelAllRunsResponse) ProtoMessage() {}
+func (*BatchGetRunsResponse) ProtoMessage() {}
 
-func (x *CancelAllRunsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[41]
+func (x *BatchGetRunsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[45]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2334,45 +2576,50 @@ func (x *CancelAllRunsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CancelAllRunsResponse.ProtoReflect.Descriptor instead.
-func (*CancelAllRunsResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{41}
+// Deprecated: Use BatchGetRunsResponse.ProtoReflect.Descriptor instead.
+func (*BatchGetRunsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{45}
 }
 
-func (x *CancelAllRunsResponse) GetRuns() []int64 {
+func (x *BatchGetRunsResponse) GetRuns() []*Run {
 	if x != nil {
 		return x.Runs
 	}
 	return nil
 }
 
-type ListTaskRunsRequest struct {
+type ListRunsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-}
-
-func (x *ListTaskRunsRequest) Reset() {
-	*x = ListTaskRunsRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[42]
+	// offset is a pagination parameter that defines where to start when
+	// counting the list of pipelines to return
+	Offset int64 `protobuf:"varint,1,opt,name=offset,proto3" json:"offset,omitempty"`
+	// limit is a pagination parameter that defines how many pipelines to return
+	// per result.
+	Limit       int64  `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
+	NamespaceId string `protobuf:"bytes,3,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,4,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+}
+
+func (x *ListRunsRequest) Reset() {
+	*x = ListRunsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[46]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListTaskRunsRequest) String() string {
+func (x *ListRunsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListTaskRunsRequest) ProtoMessage() {}
+func (*ListRunsRequest) ProtoMessage() {}
 
-func (x *ListTaskRunsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[42]
+func (x *ListRunsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[46]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2383,57 +2630,64 @@ func (x *ListTaskRunsRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListTaskRunsRequest.ProtoReflect.Descriptor instead.
-func (*ListTaskRunsRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{42}
+// Deprecated: Use ListRunsRequest.ProtoReflect.Descriptor instead.
+func (*ListRunsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{46}
 }
 
-func (x *ListTaskRunsRequest) GetNamespaceId() string {
+func (x *ListRunsRequest) GetOffset() int64 {
 	if x != nil {
-		return x.NamespaceId
+		return x.Offset
 	}
-	return ""
+	return 0
 }
 
-func (x *ListTaskRunsRequest) GetPipelineId() string {
+func (x *ListRunsRequest) GetLimit() int64 {
 	if x != nil {
-		return x.PipelineId
+		return x.Limit
+	}
+	return 0
+}
+
+func (x *ListRunsRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *ListTaskRunsRequest) GetRunId() int64 {
+func (x *ListRunsRequest) GetPipelineId() string {
 	if x != nil {
-		return x.RunId
+		return x.PipelineId
 	}
-	return 0
+	return ""
 }
 
-type ListTaskRunsResponse struct {
+type ListRunsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	TaskRuns []*TaskRun `protobuf:"bytes,1,rep,name=task_runs,json=taskRuns,proto3" json:"task_runs,omitempty"`
+	Runs []*Run `protobuf:"bytes,1,rep,name=runs,proto3" json:"runs,omitempty"`
 }
 
-func (x *ListTaskRunsResponse) Reset() {
-	*x = ListTaskRunsResponse{}
+func (x *ListRunsResponse) Reset() {
+	*x = ListRunsResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[43]
+		mi := &file_gofer_transport_proto_msgTypes[47]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListTaskRunsResponse) String() string {
+func (x *ListRunsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListTaskRunsResponse) ProtoMessage() {}
+func (*ListRunsResponse) ProtoMessage() {}
 
-func (x *ListTaskRunsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[43]
+func (x *ListRunsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[47]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2444,46 +2698,52 @@ func (x *ListTaskRunsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListTaskRunsResponse.ProtoReflect.Descriptor instead.
-func (*ListTaskRunsResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{43}
+// Deprecated: Use ListRunsResponse.ProtoReflect.Descriptor instead.
+func (*ListRunsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{47}
 }
 
-func (x *ListTaskRunsResponse) GetTaskRuns() []*TaskRun {
+func (x *ListRunsResponse) GetRuns() []*Run {
 	if x != nil {
-		return x.TaskRuns
+		return x.Runs
 	}
 	return nil
 }
 
-type GetTaskRunRequest struct {
+type StartRunRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
 	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
+	// Only is an array of tasks which will run exclusively for this particular
+	// run. Tasks still carry relevant dependencies. Run will return an error if
+	// the dependencies of a task is also not met in the list of tasks included in
+	// only.
+	Only []string `protobuf:"bytes,3,rep,name=only,proto3" json:"only,omitempty"`
+	// variables allows for the replacement of task environment variables, it
+	// overrides all other environment variables if there is a name collision.
+	Variables map[string]string `protobuf:"bytes,4,rep,name=variables,proto3" json:"variables,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
 }
 
-func (x *GetTaskRunRequest) Reset() {
-	*x = GetTaskRunRequest{}
+func (x *StartRunRequest) Reset() {
+	*x = StartRunRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[44]
+		mi := &file_gofer_transport_proto_msgTypes[48]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTaskRunRequest) String() string {
+func (x *StartRunRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTaskRunRequest) ProtoMessage() {}
+func (*StartRunRequest) ProtoMessage() {}
 
-func (x *GetTaskRunRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[44]
+func (x *StartRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[48]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2494,64 +2754,64 @@ func (x *GetTaskRunRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTaskRunRequest.ProtoReflect.Descriptor instead.
-func (*GetTaskRunRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{44}
+// Deprecated: Use StartRunRequest.ProtoReflect.Descriptor instead.
+func (*StartRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{48}
 }
 
-func (x *GetTaskRunRequest) GetNamespaceId() string {
+func (x *StartRunRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *GetTaskRunRequest) GetPipelineId() string {
+func (x *StartRunRequest) GetPipelineId() string {
 	if x != nil {
 		return x.PipelineId
 	}
 	return ""
 }
 
-func (x *GetTaskRunRequest) GetRunId() int64 {
+func (x *StartRunRequest) GetOnly() []string {
 	if x != nil {
-		return x.RunId
+		return x.Only
 	}
-	return 0
+	return nil
 }
 
-func (x *GetTaskRunRequest) GetId() string {
+func (x *StartRunRequest) GetVariables() map[string]string {
 	if x != nil {
-		return x.Id
+		return x.Variables
 	}
-	return ""
+	return nil
 }
 
-type GetTaskRunResponse struct {
+type StartRunResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	TaskRun *TaskRun `protobuf:"bytes,1,opt,name=task_run,json=taskRun,proto3" json:"task_run,omitempty"`
+	Run *Run `protobuf:"bytes,1,opt,name=run,proto3" json:"run,omitempty"`
 }
 
-func (x *GetTaskRunResponse) Reset() {
-	*x = GetTaskRunResponse{}
+func (x *StartRunResponse) Reset() {
+	*x = StartRunResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[45]
+		mi := &file_gofer_transport_proto_msgTypes[49]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTaskRunResponse) String() string {
+func (x *StartRunResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTaskRunResponse) ProtoMessage() {}
+func (*StartRunResponse) ProtoMessage() {}
 
-func (x *GetTaskRunResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[45]
+func (x *StartRunResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[49]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2562,49 +2822,49 @@ func (x *GetTaskRunResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTaskRunResponse.ProtoReflect.Descriptor instead.
-func (*GetTaskRunResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{45}
+// Deprecated: Use StartRunResponse.ProtoReflect.Descriptor instead.
+func (*StartRunResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{49}
 }
 
-func (x *GetTaskRunResponse) GetTaskRun() *TaskRun {
+func (x *StartRunResponse) GetRun() *Run {
 	if x != nil {
-		return x.TaskRun
+		return x.Run
 	}
 	return nil
 }
 
-type CancelTaskRunRequest struct {
+type RetryRunRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
 	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
-	// force will cause Gofer to hard kill this task run containers.
-	// Usually this means that the container receives a SIGKILL.
-	Force bool `protobuf:"varint,5,opt,name=force,proto3" json:"force,omitempty"`
+	Id          int64  `protobuf:"varint,3,opt,name=id,proto3" json:"id,omitempty"` // Run ID
+	// from_task resumes the run from the given task. The task and all tasks
+	// downstream of it are run again while the results of all other tasks are
+	// taken from the previous run.
+	FromTask string `protobuf:"bytes,4,opt,name=from_task,json=fromTask,proto3" json:"from_task,omitempty"`
 }
 
-func (x *CancelTaskRunRequest) Reset() {
-	*x = CancelTaskRunRequest{}
+func (x *RetryRunRequest) Reset() {
+	*x = RetryRunRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[46]
+		mi := &file_gofer_transport_proto_msgTypes[50]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CancelTaskRunRequest) String() string {
+func (x *RetryRunRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CancelTaskRunRequest) ProtoMessage() {}
+func (*RetryRunRequest) ProtoMessage() {}
 
-func (x *CancelTaskRunRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[46]
+func (x *RetryRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[50]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2615,69 +2875,64 @@ func (x *CancelTaskRunRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CancelTaskRunRequest.ProtoReflect.Descriptor instead.
-func (*CancelTaskRunRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{46}
+// Deprecated: Use RetryRunRequest.ProtoReflect.Descriptor instead.
+func (*RetryRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{50}
 }
 
-func (x *CancelTaskRunRequest) GetNamespaceId() string {
+func (x *RetryRunRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *CancelTaskRunRequest) GetPipelineId() string {
+func (x *RetryRunRequest) GetPipelineId() string {
 	if x != nil {
 		return x.PipelineId
 	}
 	return ""
 }
 
-func (x *CancelTaskRunRequest) GetRunId() int64 {
+func (x *RetryRunRequest) GetId() int64 {
 	if x != nil {
-		return x.RunId
+		return x.Id
 	}
 	return 0
 }
 
-func (x *CancelTaskRunRequest) GetId() string {
+func (x *RetryRunRequest) GetFromTask() string {
 	if x != nil {
-		return x.Id
+		return x.FromTask
 	}
 	return ""
 }
 
-func (x *CancelTaskRunRequest) GetForce() bool {
-	if x != nil {
-		return x.Force
-	}
-	return false
-}
-
-type CancelTaskRunResponse struct {
+type RetryRunResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	Run *Run `protobuf:"bytes,1,opt,name=run,proto3" json:"run,omitempty"`
 }
 
-func (x *CancelTaskRunResponse) Reset() {
-	*x = CancelTaskRunResponse{}
+func (x *RetryRunResponse) Reset() {
+	*x = RetryRunResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[47]
+		mi := &file_gofer_transport_proto_msgTypes[51]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CancelTaskRunResponse) String() string {
+func (x *RetryRunResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CancelTaskRunResponse) ProtoMessage() {}
+func (*RetryRunResponse) ProtoMessage() {}
 
-func (x *CancelTaskRunResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[47]
+func (x *RetryRunResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[51]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2688,39 +2943,48 @@ func (x *CancelTaskRunResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CancelTaskRunResponse.ProtoReflect.Descriptor instead.
-func (*CancelTaskRunResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{47}
+// Deprecated: Use RetryRunResponse.ProtoReflect.Descriptor instead.
+func (*RetryRunResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{51}
 }
 
-type GetTaskRunLogsRequest struct {
+func (x *RetryRunResponse) GetRun() *Run {
+	if x != nil {
+		return x.Run
+	}
+	return nil
+}
+
+type CancelRunRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
 	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
+	Id          int64  `protobuf:"varint,3,opt,name=id,proto3" json:"id,omitempty"` // Run ID
+	// force will cause Gofer to hard kill any outstanding task run containers.
+	// Usually this means that the container receives a SIGKILL.
+	Force bool `protobuf:"varint,4,opt,name=force,proto3" json:"force,omitempty"`
 }
 
-func (x *GetTaskRunLogsRequest) Reset() {
-	*x = GetTaskRunLogsRequest{}
+func (x *CancelRunRequest) Reset() {
+	*x = CancelRunRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[48]
+		mi := &file_gofer_transport_proto_msgTypes[52]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTaskRunLogsRequest) String() string {
+func (x *CancelRunRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTaskRunLogsRequest) ProtoMessage() {}
+func (*CancelRunRequest) ProtoMessage() {}
 
-func (x *GetTaskRunLogsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[48]
+func (x *CancelRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[52]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2731,65 +2995,62 @@ func (x *GetTaskRunLogsRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTaskRunLogsRequest.ProtoReflect.Descriptor instead.
-func (*GetTaskRunLogsRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{48}
+// Deprecated: Use CancelRunRequest.ProtoReflect.Descriptor instead.
+func (*CancelRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{52}
 }
 
-func (x *GetTaskRunLogsRequest) GetNamespaceId() string {
+func (x *CancelRunRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *GetTaskRunLogsRequest) GetPipelineId() string {
+func (x *CancelRunRequest) GetPipelineId() string {
 	if x != nil {
 		return x.PipelineId
 	}
 	return ""
 }
 
-func (x *GetTaskRunLogsRequest) GetRunId() int64 {
+func (x *CancelRunRequest) GetId() int64 {
 	if x != nil {
-		return x.RunId
+		return x.Id
 	}
 	return 0
 }
 
-func (x *GetTaskRunLogsRequest) GetId() string {
+func (x *CancelRunRequest) GetForce() bool {
 	if x != nil {
-		return x.Id
+		return x.Force
 	}
-	return ""
+	return false
 }
 
-type GetTaskRunLogsResponse struct {
+type CancelRunResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	LogLine string `protobuf:"bytes,1,opt,name=log_line,json=logLine,proto3" json:"log_line,omitempty"`  // The string content of the current log line.
-	LineNum int64  `protobuf:"varint,2,opt,name=line_num,json=lineNum,proto3" json:"line_num,omitempty"` // The current line number.
 }
 
-func (x *GetTaskRunLogsResponse) Reset() {
-	*x = GetTaskRunLogsResponse{}
+func (x *CancelRunResponse) Reset() {
+	*x = CancelRunResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[49]
+		mi := &file_gofer_transport_proto_msgTypes[53]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTaskRunLogsResponse) String() string {
+func (x *CancelRunResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTaskRunLogsResponse) ProtoMessage() {}
+func (*CancelRunResponse) ProtoMessage() {}
 
-func (x *GetTaskRunLogsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[49]
+func (x *CancelRunResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[53]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2800,53 +3061,43 @@ func (x *GetTaskRunLogsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTaskRunLogsResponse.ProtoReflect.Descriptor instead.
-func (*GetTaskRunLogsResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{49}
-}
-
-func (x *GetTaskRunLogsResponse) GetLogLine() string {
-	if x != nil {
-		return x.LogLine
-	}
-	return ""
-}
-
-func (x *GetTaskRunLogsResponse) GetLineNum() int64 {
-	if x != nil {
-		return x.LineNum
-	}
-	return 0
+// Deprecated: Use CancelRunResponse.ProtoReflect.Descriptor instead.
+func (*CancelRunResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{53}
 }
 
-type DeleteTaskRunLogsRequest struct {
+type CancelAllRunsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
 	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
 	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
+	// force will cause Gofer to hard kill any outstanding task run containers.
+	// Usually this means that the container receives a SIGKILL.
+	Force bool `protobuf:"varint,3,opt,name=force,proto3" json:"force,omitempty"`
+	// states limits cancellation to only runs that are currently in one of the
+	// given states. If empty all in-progress runs are cancelled.
+	States []Run_State `protobuf:"varint,4,rep,packed,name=states,proto3,enum=proto.Run_State" json:"states,omitempty"`
 }
 
-func (x *DeleteTaskRunLogsRequest) Reset() {
-	*x = DeleteTaskRunLogsRequest{}
+func (x *CancelAllRunsRequest) Reset() {
+	*x = CancelAllRunsRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[50]
+		mi := &file_gofer_transport_proto_msgTypes[54]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DeleteTaskRunLogsRequest) String() string {
+func (x *CancelAllRunsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteTaskRunLogsRequest) ProtoMessage() {}
+func (*CancelAllRunsRequest) ProtoMessage() {}
 
-func (x *DeleteTaskRunLogsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[50]
+func (x *CancelAllRunsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[54]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2857,62 +3108,64 @@ func (x *DeleteTaskRunLogsRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteTaskRunLogsRequest.ProtoReflect.Descriptor instead.
-func (*DeleteTaskRunLogsRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{50}
+// Deprecated: Use CancelAllRunsRequest.ProtoReflect.Descriptor instead.
+func (*CancelAllRunsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{54}
 }
 
-func (x *DeleteTaskRunLogsRequest) GetNamespaceId() string {
+func (x *CancelAllRunsRequest) GetNamespaceId() string {
 	if x != nil {
 		return x.NamespaceId
 	}
 	return ""
 }
 
-func (x *DeleteTaskRunLogsRequest) GetPipelineId() string {
+func (x *CancelAllRunsRequest) GetPipelineId() string {
 	if x != nil {
 		return x.PipelineId
 	}
 	return ""
 }
 
-func (x *DeleteTaskRunLogsRequest) GetRunId() int64 {
+func (x *CancelAllRunsRequest) GetForce() bool {
 	if x != nil {
-		return x.RunId
+		return x.Force
 	}
-	return 0
+	return false
 }
 
-func (x *DeleteTaskRunLogsRequest) GetId() string {
+func (x *CancelAllRunsRequest) GetStates() []Run_State {
 	if x != nil {
-		return x.Id
+		return x.States
 	}
-	return ""
+	return nil
 }
 
-type DeleteTaskRunLogsResponse struct {
+type CancelAllRunsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	Runs []int64 `protobuf:"varint,1,rep,packed,name=runs,proto3" json:"runs,omitempty"`
 }
 
-func (x *DeleteTaskRunLogsResponse) Reset() {
-	*x = DeleteTaskRunLogsResponse{}
+func (x *CancelAllRunsResponse) Reset() {
+	*x = CancelAllRunsResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[51]
+		mi := &file_gofer_transport_proto_msgTypes[55]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DeleteTaskRunLogsResponse) String() string {
+func (x *CancelAllRunsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteTaskRunLogsResponse) ProtoMessage() {}
+func (*CancelAllRunsResponse) ProtoMessage() {}
 
-func (x *DeleteTaskRunLogsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[51]
+func (x *CancelAllRunsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[55]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2923,36 +3176,45 @@ func (x *DeleteTaskRunLogsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteTaskRunLogsResponse.ProtoReflect.Descriptor instead.
-func (*DeleteTaskRunLogsResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{51}
+// Deprecated: Use CancelAllRunsResponse.ProtoReflect.Descriptor instead.
+func (*CancelAllRunsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{55}
 }
 
-type GetTriggerRequest struct {
+func (x *CancelAllRunsResponse) GetRuns() []int64 {
+	if x != nil {
+		return x.Runs
+	}
+	return nil
+}
+
+type ListTaskRunsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"` // The unique name for a particular trigger
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
 }
 
-func (x *GetTriggerRequest) Reset() {
-	*x = GetTriggerRequest{}
+func (x *ListTaskRunsRequest) Reset() {
+	*x = ListTaskRunsRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[52]
+		mi := &file_gofer_transport_proto_msgTypes[56]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTriggerRequest) String() string {
+func (x *ListTaskRunsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTriggerRequest) ProtoMessage() {}
+func (*ListTaskRunsRequest) ProtoMessage() {}
 
-func (x *GetTriggerRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[52]
+func (x *ListTaskRunsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[56]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2963,43 +3225,57 @@ func (x *GetTriggerRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTriggerRequest.ProtoReflect.Descriptor instead.
-func (*GetTriggerRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{52}
+// Deprecated: Use ListTaskRunsRequest.ProtoReflect.Descriptor instead.
+func (*ListTaskRunsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{56}
 }
 
-func (x *GetTriggerRequest) GetKind() string {
+func (x *ListTaskRunsRequest) GetNamespaceId() string {
 	if x != nil {
-		return x.Kind
+		return x.NamespaceId
 	}
 	return ""
 }
 
-type GetTriggerResponse struct {
+func (x *ListTaskRunsRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *ListTaskRunsRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+type ListTaskRunsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Trigger *Trigger `protobuf:"bytes,1,opt,name=trigger,proto3" json:"trigger,omitempty"`
+	TaskRuns []*TaskRun `protobuf:"bytes,1,rep,name=task_runs,json=taskRuns,proto3" json:"task_runs,omitempty"`
 }
 
-func (x *GetTriggerResponse) Reset() {
-	*x = GetTriggerResponse{}
+func (x *ListTaskRunsResponse) Reset() {
+	*x = ListTaskRunsResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[53]
+		mi := &file_gofer_transport_proto_msgTypes[57]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTriggerResponse) String() string {
+func (x *ListTaskRunsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTriggerResponse) ProtoMessage() {}
+func (*ListTaskRunsResponse) ProtoMessage() {}
 
-func (x *GetTriggerResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[53]
+func (x *ListTaskRunsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[57]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3010,41 +3286,46 @@ func (x *GetTriggerResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTriggerResponse.ProtoReflect.Descriptor instead.
-func (*GetTriggerResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{53}
+// Deprecated: Use ListTaskRunsResponse.ProtoReflect.Descriptor instead.
+func (*ListTaskRunsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{57}
 }
 
-func (x *GetTriggerResponse) GetTrigger() *Trigger {
+func (x *ListTaskRunsResponse) GetTaskRuns() []*TaskRun {
 	if x != nil {
-		return x.Trigger
+		return x.TaskRuns
 	}
 	return nil
 }
 
-type ListTriggersRequest struct {
+type GetTaskRunRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
 }
 
-func (x *ListTriggersRequest) Reset() {
-	*x = ListTriggersRequest{}
+func (x *GetTaskRunRequest) Reset() {
+	*x = GetTaskRunRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[54]
+		mi := &file_gofer_transport_proto_msgTypes[58]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListTriggersRequest) String() string {
+func (x *GetTaskRunRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListTriggersRequest) ProtoMessage() {}
+func (*GetTaskRunRequest) ProtoMessage() {}
 
-func (x *ListTriggersRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[54]
+func (x *GetTaskRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[58]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3055,83 +3336,64 @@ func (x *ListTriggersRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListTriggersRequest.ProtoReflect.Descriptor instead.
-func (*ListTriggersRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{54}
+// Deprecated: Use GetTaskRunRequest.ProtoReflect.Descriptor instead.
+func (*GetTaskRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{58}
 }
 
-type ListTriggersResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	Triggers []*Trigger `protobuf:"bytes,1,rep,name=triggers,proto3" json:"triggers,omitempty"`
+func (x *GetTaskRunRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
 }
 
-func (x *ListTriggersResponse) Reset() {
-	*x = ListTriggersResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[55]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *GetTaskRunRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
 	}
+	return ""
 }
 
-func (x *ListTriggersResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *GetTaskRunRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
 }
 
-func (*ListTriggersResponse) ProtoMessage() {}
-
-func (x *ListTriggersResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[55]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
-	}
-	return mi.MessageOf(x)
-}
-
-// Deprecated: Use ListTriggersResponse.ProtoReflect.Descriptor instead.
-func (*ListTriggersResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{55}
-}
-
-func (x *ListTriggersResponse) GetTriggers() []*Trigger {
+func (x *GetTaskRunRequest) GetId() string {
 	if x != nil {
-		return x.Triggers
+		return x.Id
 	}
-	return nil
+	return ""
 }
 
-type InstallTriggerRequest struct {
+type GetTaskRunResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Trigger *TriggerConfig `protobuf:"bytes,1,opt,name=trigger,proto3" json:"trigger,omitempty"`
+	TaskRun *TaskRun `protobuf:"bytes,1,opt,name=task_run,json=taskRun,proto3" json:"task_run,omitempty"`
 }
 
-func (x *InstallTriggerRequest) Reset() {
-	*x = InstallTriggerRequest{}
+func (x *GetTaskRunResponse) Reset() {
+	*x = GetTaskRunResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[56]
+		mi := &file_gofer_transport_proto_msgTypes[59]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *InstallTriggerRequest) String() string {
+func (x *GetTaskRunResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*InstallTriggerRequest) ProtoMessage() {}
+func (*GetTaskRunResponse) ProtoMessage() {}
 
-func (x *InstallTriggerRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[56]
+func (x *GetTaskRunResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[59]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3142,41 +3404,49 @@ func (x *InstallTriggerRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use InstallTriggerRequest.ProtoReflect.Descriptor instead.
-func (*InstallTriggerRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{56}
+// Deprecated: Use GetTaskRunResponse.ProtoReflect.Descriptor instead.
+func (*GetTaskRunResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{59}
 }
 
-func (x *InstallTriggerRequest) GetTrigger() *TriggerConfig {
+func (x *GetTaskRunResponse) GetTaskRun() *TaskRun {
 	if x != nil {
-		return x.Trigger
+		return x.TaskRun
 	}
 	return nil
 }
 
-type InstallTriggerResponse struct {
+type CancelTaskRunRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
+	// force will cause Gofer to hard kill this task run containers.
+	// Usually this means that the container receives a SIGKILL.
+	Force bool `protobuf:"varint,5,opt,name=force,proto3" json:"force,omitempty"`
 }
 
-func (x *InstallTriggerResponse) Reset() {
-	*x = InstallTriggerResponse{}
+func (x *CancelTaskRunRequest) Reset() {
+	*x = CancelTaskRunRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[57]
+		mi := &file_gofer_transport_proto_msgTypes[60]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *InstallTriggerResponse) String() string {
+func (x *CancelTaskRunRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*InstallTriggerResponse) ProtoMessage() {}
+func (*CancelTaskRunRequest) ProtoMessage() {}
 
-func (x *InstallTriggerResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[57]
+func (x *CancelTaskRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[60]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3187,81 +3457,69 @@ func (x *InstallTriggerResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use InstallTriggerResponse.ProtoReflect.Descriptor instead.
-func (*InstallTriggerResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{57}
-}
-
-type UninstallTriggerRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"`
+// Deprecated: Use CancelTaskRunRequest.ProtoReflect.Descriptor instead.
+func (*CancelTaskRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{60}
 }
 
-func (x *UninstallTriggerRequest) Reset() {
-	*x = UninstallTriggerRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[58]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *CancelTaskRunRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
 	}
+	return ""
 }
 
-func (x *UninstallTriggerRequest) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *CancelTaskRunRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
 }
 
-func (*UninstallTriggerRequest) ProtoMessage() {}
-
-func (x *UninstallTriggerRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[58]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *CancelTaskRunRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
 	}
-	return mi.MessageOf(x)
+	return 0
 }
 
-// Deprecated: Use UninstallTriggerRequest.ProtoReflect.Descriptor instead.
-func (*UninstallTriggerRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{58}
+func (x *CancelTaskRunRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
 }
 
-func (x *UninstallTriggerRequest) GetKind() string {
+func (x *CancelTaskRunRequest) GetForce() bool {
 	if x != nil {
-		return x.Kind
+		return x.Force
 	}
-	return ""
+	return false
 }
 
-type UninstallTriggerResponse struct {
+type CancelTaskRunResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 }
 
-func (x *UninstallTriggerResponse) Reset() {
-	*x = UninstallTriggerResponse{}
+func (x *CancelTaskRunResponse) Reset() {
+	*x = CancelTaskRunResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[59]
+		mi := &file_gofer_transport_proto_msgTypes[61]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *UninstallTriggerResponse) String() string {
+func (x *CancelTaskRunResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*UninstallTriggerResponse) ProtoMessage() {}
+func (*CancelTaskRunResponse) ProtoMessage() {}
 
-func (x *UninstallTriggerResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[59]
+func (x *CancelTaskRunResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[61]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3272,36 +3530,43 @@ func (x *UninstallTriggerResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UninstallTriggerResponse.ProtoReflect.Descriptor instead.
-func (*UninstallTriggerResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{59}
+// Deprecated: Use CancelTaskRunResponse.ProtoReflect.Descriptor instead.
+func (*CancelTaskRunResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{61}
 }
 
-type GetNotifierRequest struct {
+type SpawnTaskRunRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"` // The unique name/kind for a particular notifier
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	// The task to run. Its id must be unique within the run and the tasks it
+	// depends on must already be part of the run. The exec script is base64
+	// encoded, as it is everywhere else tasks are returned.
+	Task      *Task  `protobuf:"bytes,4,opt,name=task,proto3" json:"task,omitempty"`
+	SpawnedBy string `protobuf:"bytes,5,opt,name=spawned_by,json=spawnedBy,proto3" json:"spawned_by,omitempty"` // The id of the task run spawning this one, if any.
 }
 
-func (x *GetNotifierRequest) Reset() {
-	*x = GetNotifierRequest{}
+func (x *SpawnTaskRunRequest) Reset() {
+	*x = SpawnTaskRunRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[60]
+		mi := &file_gofer_transport_proto_msgTypes[62]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetNotifierRequest) String() string {
+func (x *SpawnTaskRunRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetNotifierRequest) ProtoMessage() {}
+func (*SpawnTaskRunRequest) ProtoMessage() {}
 
-func (x *GetNotifierRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[60]
+func (x *SpawnTaskRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[62]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3312,43 +3577,71 @@ func (x *GetNotifierRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetNotifierRequest.ProtoReflect.Descriptor instead.
-func (*GetNotifierRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{60}
+// Deprecated: Use SpawnTaskRunRequest.ProtoReflect.Descriptor instead.
+func (*SpawnTaskRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{62}
 }
 
-func (x *GetNotifierRequest) GetKind() string {
+func (x *SpawnTaskRunRequest) GetNamespaceId() string {
 	if x != nil {
-		return x.Kind
+		return x.NamespaceId
 	}
 	return ""
 }
 
-type GetNotifierResponse struct {
+func (x *SpawnTaskRunRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *SpawnTaskRunRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+func (x *SpawnTaskRunRequest) GetTask() *Task {
+	if x != nil {
+		return x.Task
+	}
+	return nil
+}
+
+func (x *SpawnTaskRunRequest) GetSpawnedBy() string {
+	if x != nil {
+		return x.SpawnedBy
+	}
+	return ""
+}
+
+type SpawnTaskRunResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Notifier *Notifier `protobuf:"bytes,1,opt,name=notifier,proto3" json:"notifier,omitempty"`
+	TaskRun *TaskRun `protobuf:"bytes,1,opt,name=task_run,json=taskRun,proto3" json:"task_run,omitempty"`
 }
 
-func (x *GetNotifierResponse) Reset() {
-	*x = GetNotifierResponse{}
+func (x *SpawnTaskRunResponse) Reset() {
+	*x = SpawnTaskRunResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[61]
+		mi := &file_gofer_transport_proto_msgTypes[63]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetNotifierResponse) String() string {
+func (x *SpawnTaskRunResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetNotifierResponse) ProtoMessage() {}
+func (*SpawnTaskRunResponse) ProtoMessage() {}
 
-func (x *GetNotifierResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[61]
+func (x *SpawnTaskRunResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[63]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3359,41 +3652,56 @@ func (x *GetNotifierResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetNotifierResponse.ProtoReflect.Descriptor instead.
-func (*GetNotifierResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{61}
+// Deprecated: Use SpawnTaskRunResponse.ProtoReflect.Descriptor instead.
+func (*SpawnTaskRunResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{63}
 }
 
-func (x *GetNotifierResponse) GetNotifier() *Notifier {
+func (x *SpawnTaskRunResponse) GetTaskRun() *TaskRun {
 	if x != nil {
-		return x.Notifier
+		return x.TaskRun
 	}
 	return nil
 }
 
-type ListNotifiersRequest struct {
+type GetTaskRunLogsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
+	// Continue streaming new log lines as they are written until the task run
+	// is finished. Otherwise the stream ends once the current end of the log has
+	// been reached. Defaults to true when not set, which is how clients from
+	// before this option existed expect logs to be streamed.
+	Follow *bool `protobuf:"varint,5,opt,name=follow,proto3,oneof" json:"follow,omitempty"`
+	// Only return the last N lines of the log. Zero returns all lines.
+	Tail int64 `protobuf:"varint,6,opt,name=tail,proto3" json:"tail,omitempty"`
+	// Only return log lines written at or after the given time in epoch
+	// milliseconds. Zero returns all lines.
+	Since int64 `protobuf:"varint,7,opt,name=since,proto3" json:"since,omitempty"`
 }
 
-func (x *ListNotifiersRequest) Reset() {
-	*x = ListNotifiersRequest{}
+func (x *GetTaskRunLogsRequest) Reset() {
+	*x = GetTaskRunLogsRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[62]
+		mi := &file_gofer_transport_proto_msgTypes[64]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListNotifiersRequest) String() string {
+func (x *GetTaskRunLogsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListNotifiersRequest) ProtoMessage() {}
+func (*GetTaskRunLogsRequest) ProtoMessage() {}
 
-func (x *ListNotifiersRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[62]
+func (x *GetTaskRunLogsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[64]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3404,83 +3712,89 @@ func (x *ListNotifiersRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListNotifiersRequest.ProtoReflect.Descriptor instead.
-func (*ListNotifiersRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{62}
-}
-
-type ListNotifiersResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	Notifiers []*Notifier `protobuf:"bytes,1,rep,name=notifiers,proto3" json:"notifiers,omitempty"`
+// Deprecated: Use GetTaskRunLogsRequest.ProtoReflect.Descriptor instead.
+func (*GetTaskRunLogsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{64}
 }
 
-func (x *ListNotifiersResponse) Reset() {
-	*x = ListNotifiersResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[63]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *GetTaskRunLogsRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
 	}
+	return ""
 }
 
-func (x *ListNotifiersResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *GetTaskRunLogsRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
 }
 
-func (*ListNotifiersResponse) ProtoMessage() {}
+func (x *GetTaskRunLogsRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
 
-func (x *ListNotifiersResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[63]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *GetTaskRunLogsRequest) GetId() string {
+	if x != nil {
+		return x.Id
 	}
-	return mi.MessageOf(x)
+	return ""
 }
 
-// Deprecated: Use ListNotifiersResponse.ProtoReflect.Descriptor instead.
-func (*ListNotifiersResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{63}
+func (x *GetTaskRunLogsRequest) GetFollow() bool {
+	if x != nil && x.Follow != nil {
+		return *x.Follow
+	}
+	return false
 }
 
-func (x *ListNotifiersResponse) GetNotifiers() []*Notifier {
+func (x *GetTaskRunLogsRequest) GetTail() int64 {
 	if x != nil {
-		return x.Notifiers
+		return x.Tail
 	}
-	return nil
+	return 0
 }
 
-type InstallNotifierRequest struct {
+func (x *GetTaskRunLogsRequest) GetSince() int64 {
+	if x != nil {
+		return x.Since
+	}
+	return 0
+}
+
+type GetTaskRunLogsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Notifier *NotifierConfig `protobuf:"bytes,1,opt,name=notifier,proto3" json:"notifier,omitempty"`
+	LogLine string `protobuf:"bytes,1,opt,name=log_line,json=logLine,proto3" json:"log_line,omitempty"`  // The string content of the current log line.
+	LineNum int64  `protobuf:"varint,2,opt,name=line_num,json=lineNum,proto3" json:"line_num,omitempty"` // The current line number.
+	// Set on messages sent only to keep an idle stream open; they carry no log
+	// line and should be skipped.
+	Keepalive bool `protobuf:"varint,3,opt,name=keepalive,proto3" json:"keepalive,omitempty"`
 }
 
-func (x *InstallNotifierRequest) Reset() {
-	*x = InstallNotifierRequest{}
+func (x *GetTaskRunLogsResponse) Reset() {
+	*x = GetTaskRunLogsResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[64]
+		mi := &file_gofer_transport_proto_msgTypes[65]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *InstallNotifierRequest) String() string {
+func (x *GetTaskRunLogsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*InstallNotifierRequest) ProtoMessage() {}
+func (*GetTaskRunLogsResponse) ProtoMessage() {}
 
-func (x *InstallNotifierRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[64]
+func (x *GetTaskRunLogsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[65]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3491,66 +3805,45 @@ func (x *InstallNotifierRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use InstallNotifierRequest.ProtoReflect.Descriptor instead.
-func (*InstallNotifierRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{64}
+// Deprecated: Use GetTaskRunLogsResponse.ProtoReflect.Descriptor instead.
+func (*GetTaskRunLogsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{65}
 }
 
-func (x *InstallNotifierRequest) GetNotifier() *NotifierConfig {
+func (x *GetTaskRunLogsResponse) GetLogLine() string {
 	if x != nil {
-		return x.Notifier
+		return x.LogLine
 	}
-	return nil
-}
-
-type InstallNotifierResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+	return ""
 }
 
-func (x *InstallNotifierResponse) Reset() {
-	*x = InstallNotifierResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[65]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *GetTaskRunLogsResponse) GetLineNum() int64 {
+	if x != nil {
+		return x.LineNum
 	}
+	return 0
 }
 
-func (x *InstallNotifierResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
-}
-
-func (*InstallNotifierResponse) ProtoMessage() {}
-
-func (x *InstallNotifierResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[65]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *GetTaskRunLogsResponse) GetKeepalive() bool {
+	if x != nil {
+		return x.Keepalive
 	}
-	return mi.MessageOf(x)
-}
-
-// Deprecated: Use InstallNotifierResponse.ProtoReflect.Descriptor instead.
-func (*InstallNotifierResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{65}
+	return false
 }
 
-type UninstallNotifierRequest struct {
+type DeleteTaskRunLogsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"`
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
 }
 
-func (x *UninstallNotifierRequest) Reset() {
-	*x = UninstallNotifierRequest{}
+func (x *DeleteTaskRunLogsRequest) Reset() {
+	*x = DeleteTaskRunLogsRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[66]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3558,13 +3851,13 @@ func (x *UninstallNotifierRequest) Reset() {
 	}
 }
 
-func (x *UninstallNotifierRequest) String() string {
+func (x *DeleteTaskRunLogsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*UninstallNotifierRequest) ProtoMessage() {}
+func (*DeleteTaskRunLogsRequest) ProtoMessage() {}
 
-func (x *UninstallNotifierRequest) ProtoReflect() protoreflect.Message {
+func (x *DeleteTaskRunLogsRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[66]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3576,26 +3869,47 @@ func (x *UninstallNotifierRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UninstallNotifierRequest.ProtoReflect.Descriptor instead.
-func (*UninstallNotifierRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use DeleteTaskRunLogsRequest.ProtoReflect.Descriptor instead.
+func (*DeleteTaskRunLogsRequest) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{66}
 }
 
-func (x *UninstallNotifierRequest) GetKind() string {
+func (x *DeleteTaskRunLogsRequest) GetNamespaceId() string {
 	if x != nil {
-		return x.Kind
+		return x.NamespaceId
 	}
 	return ""
 }
 
-type UninstallNotifierResponse struct {
+func (x *DeleteTaskRunLogsRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *DeleteTaskRunLogsRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+func (x *DeleteTaskRunLogsRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+type DeleteTaskRunLogsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 }
 
-func (x *UninstallNotifierResponse) Reset() {
-	*x = UninstallNotifierResponse{}
+func (x *DeleteTaskRunLogsResponse) Reset() {
+	*x = DeleteTaskRunLogsResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[67]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3603,13 +3917,13 @@ func (x *UninstallNotifierResponse) Reset() {
 	}
 }
 
-func (x *UninstallNotifierResponse) String() string {
+func (x *DeleteTaskRunLogsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*UninstallNotifierResponse) ProtoMessage() {}
+func (*DeleteTaskRunLogsResponse) ProtoMessage() {}
 
-func (x *UninstallNotifierResponse) ProtoReflect() protoreflect.Message {
+func (x *DeleteTaskRunLogsResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[67]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3621,21 +3935,28 @@ func (x *UninstallNotifierResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UninstallNotifierResponse.ProtoReflect.Descriptor instead.
-func (*UninstallNotifierResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use DeleteTaskRunLogsResponse.ProtoReflect.Descriptor instead.
+func (*DeleteTaskRunLogsResponse) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{67}
 }
 
-type GetEventRequest struct {
+type AttachToTaskRunStart struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Id          string `protobuf:"bytes,4,opt,name=id,proto3" json:"id,omitempty"` // Task Run ID
+	// The command to run inside the container. Defaults to "sh".
+	Command []string `protobuf:"bytes,5,rep,name=command,proto3" json:"command,omitempty"`
+	Width   uint32   `protobuf:"varint,6,opt,name=width,proto3" json:"width,omitempty"`   // The initial width of the terminal in columns.
+	Height  uint32   `protobuf:"varint,7,opt,name=height,proto3" json:"height,omitempty"` // The initial height of the terminal in rows.
 }
 
-func (x *GetEventRequest) Reset() {
-	*x = GetEventRequest{}
+func (x *AttachToTaskRunStart) Reset() {
+	*x = AttachToTaskRunStart{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[68]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3643,13 +3964,13 @@ func (x *GetEventRequest) Reset() {
 	}
 }
 
-func (x *GetEventRequest) String() string {
+func (x *AttachToTaskRunStart) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetEventRequest) ProtoMessage() {}
+func (*AttachToTaskRunStart) ProtoMessage() {}
 
-func (x *GetEventRequest) ProtoReflect() protoreflect.Message {
+func (x *AttachToTaskRunStart) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[68]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3661,43 +3982,70 @@ func (x *GetEventRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetEventRequest.ProtoReflect.Descriptor instead.
-func (*GetEventRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use AttachToTaskRunStart.ProtoReflect.Descriptor instead.
+func (*AttachToTaskRunStart) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{68}
 }
 
-func (x *GetEventRequest) GetId() int64 {
+func (x *AttachToTaskRunStart) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *AttachToTaskRunStart) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *AttachToTaskRunStart) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+func (x *AttachToTaskRunStart) GetId() string {
 	if x != nil {
 		return x.Id
 	}
+	return ""
+}
+
+func (x *AttachToTaskRunStart) GetCommand() []string {
+	if x != nil {
+		return x.Command
+	}
+	return nil
+}
+
+func (x *AttachToTaskRunStart) GetWidth() uint32 {
+	if x != nil {
+		return x.Width
+	}
 	return 0
 }
 
-type GetEventResponse struct {
+func (x *AttachToTaskRunStart) GetHeight() uint32 {
+	if x != nil {
+		return x.Height
+	}
+	return 0
+}
+
+type AttachToTaskRunInput struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Kind EventType `protobuf:"varint,1,opt,name=kind,proto3,enum=proto.EventType" json:"kind,omitempty"`
-	// Types that are assignable to Event:
-	//	*GetEventResponse_CreatedNamespaceEvent
-	//	*GetEventResponse_DisabledPipelineEvent
-	//	*GetEventResponse_EnabledPipelineEvent
-	//	*GetEventResponse_CreatedPipelineEvent
-	//	*GetEventResponse_AbandonedPipelineEvent
-	//	*GetEventResponse_StartedRunEvent
-	//	*GetEventResponse_CompletedRunEvent
-	//	*GetEventResponse_StartedTaskRunEvent
-	//	*GetEventResponse_ScheduledTaskRunEvent
-	//	*GetEventResponse_CompletedTaskRunEvent
-	//	*GetEventResponse_FiredTriggerEvent
-	//	*GetEventResponse_ProcessedTriggerEvent
-	//	*GetEventResponse_ResolvedTriggerEvent
-	Event isGetEventResponse_Event `protobuf_oneof:"event"`
+	Input []byte `protobuf:"bytes,1,opt,name=input,proto3" json:"input,omitempty"`
 }
 
-func (x *GetEventResponse) Reset() {
-	*x = GetEventResponse{}
+func (x *AttachToTaskRunInput) Reset() {
+	*x = AttachToTaskRunInput{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[69]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3705,13 +4053,13 @@ func (x *GetEventResponse) Reset() {
 	}
 }
 
-func (x *GetEventResponse) String() string {
+func (x *AttachToTaskRunInput) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetEventResponse) ProtoMessage() {}
+func (*AttachToTaskRunInput) ProtoMessage() {}
 
-func (x *GetEventResponse) ProtoReflect() protoreflect.Message {
+func (x *AttachToTaskRunInput) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[69]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -3723,227 +4071,195 @@ func (x *GetEventResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetEventResponse.ProtoReflect.Descriptor instead.
-func (*GetEventResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use AttachToTaskRunInput.ProtoReflect.Descriptor instead.
+func (*AttachToTaskRunInput) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{69}
 }
 
-func (x *GetEventResponse) GetKind() EventType {
+func (x *AttachToTaskRunInput) GetInput() []byte {
 	if x != nil {
-		return x.Kind
-	}
-	return EventType_UNKNOWN_EVENT_TYPE
-}
-
-func (m *GetEventResponse) GetEvent() isGetEventResponse_Event {
-	if m != nil {
-		return m.Event
-	}
-	return nil
-}
-
-func (x *GetEventResponse) GetCreatedNamespaceEvent() *EventCreatedNamespace {
-	if x, ok := x.GetEvent().(*GetEventResponse_CreatedNamespaceEvent); ok {
-		return x.CreatedNamespaceEvent
+		return x.Input
 	}
 	return nil
 }
 
-func (x *GetEventResponse) GetDisabledPipelineEvent() *EventDisabledPipeline {
-	if x, ok := x.GetEvent().(*GetEventResponse_DisabledPipelineEvent); ok {
-		return x.DisabledPipelineEvent
-	}
-	return nil
-}
+type AttachToTaskRunResize struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
 
-func (x *GetEventResponse) GetEnabledPipelineEvent() *EventEnabledPipeline {
-	if x, ok := x.GetEvent().(*GetEventResponse_EnabledPipelineEvent); ok {
-		return x.EnabledPipelineEvent
-	}
-	return nil
+	Width  uint32 `protobuf:"varint,1,opt,name=width,proto3" json:"width,omitempty"`
+	Height uint32 `protobuf:"varint,2,opt,name=height,proto3" json:"height,omitempty"`
 }
 
-func (x *GetEventResponse) GetCreatedPipelineEvent() *EventCreatedPipeline {
-	if x, ok := x.GetEvent().(*GetEventResponse_CreatedPipelineEvent); ok {
-		return x.CreatedPipelineEvent
+func (x *AttachToTaskRunResize) Reset() {
+	*x = AttachToTaskRunResize{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[70]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return nil
 }
 
-func (x *GetEventResponse) GetAbandonedPipelineEvent() *EventAbandonedPipeline {
-	if x, ok := x.GetEvent().(*GetEventResponse_AbandonedPipelineEvent); ok {
-		return x.AbandonedPipelineEvent
-	}
-	return nil
+func (x *AttachToTaskRunResize) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-func (x *GetEventResponse) GetStartedRunEvent() *EventStartedRun {
-	if x, ok := x.GetEvent().(*GetEventResponse_StartedRunEvent); ok {
-		return x.StartedRunEvent
-	}
-	return nil
-}
+func (*AttachToTaskRunResize) ProtoMessage() {}
 
-func (x *GetEventResponse) GetCompletedRunEvent() *EventCompletedRun {
-	if x, ok := x.GetEvent().(*GetEventResponse_CompletedRunEvent); ok {
-		return x.CompletedRunEvent
+func (x *AttachToTaskRunResize) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[70]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return nil
+	return mi.MessageOf(x)
 }
 
-func (x *GetEventResponse) GetStartedTaskRunEvent() *EventStartedTaskRun {
-	if x, ok := x.GetEvent().(*GetEventResponse_StartedTaskRunEvent); ok {
-		return x.StartedTaskRunEvent
-	}
-	return nil
+// Deprecated: Use AttachToTaskRunResize.ProtoReflect.Descriptor instead.
+func (*AttachToTaskRunResize) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{70}
 }
 
-func (x *GetEventResponse) GetScheduledTaskRunEvent() *EventScheduledTaskRun {
-	if x, ok := x.GetEvent().(*GetEventResponse_ScheduledTaskRunEvent); ok {
-		return x.ScheduledTaskRunEvent
+func (x *AttachToTaskRunResize) GetWidth() uint32 {
+	if x != nil {
+		return x.Width
 	}
-	return nil
+	return 0
 }
 
-func (x *GetEventResponse) GetCompletedTaskRunEvent() *EventCompletedTaskRun {
-	if x, ok := x.GetEvent().(*GetEventResponse_CompletedTaskRunEvent); ok {
-		return x.CompletedTaskRunEvent
+func (x *AttachToTaskRunResize) GetHeight() uint32 {
+	if x != nil {
+		return x.Height
 	}
-	return nil
+	return 0
 }
 
-func (x *GetEventResponse) GetFiredTriggerEvent() *EventFiredTrigger {
-	if x, ok := x.GetEvent().(*GetEventResponse_FiredTriggerEvent); ok {
-		return x.FiredTriggerEvent
-	}
-	return nil
-}
+type AttachToTaskRunRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
 
-func (x *GetEventResponse) GetProcessedTriggerEvent() *EventProcessedTrigger {
-	if x, ok := x.GetEvent().(*GetEventResponse_ProcessedTriggerEvent); ok {
-		return x.ProcessedTriggerEvent
-	}
-	return nil
+	// Types that are assignable to Request:
+	//	*AttachToTaskRunRequest_Start
+	//	*AttachToTaskRunRequest_Input
+	//	*AttachToTaskRunRequest_Resize
+	Request isAttachToTaskRunRequest_Request `protobuf_oneof:"request"`
 }
 
-func (x *GetEventResponse) GetResolvedTriggerEvent() *EventResolvedTrigger {
-	if x, ok := x.GetEvent().(*GetEventResponse_ResolvedTriggerEvent); ok {
-		return x.ResolvedTriggerEvent
+func (x *AttachToTaskRunRequest) Reset() {
+	*x = AttachToTaskRunRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[71]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return nil
-}
-
-type isGetEventResponse_Event interface {
-	isGetEventResponse_Event()
-}
-
-type GetEventResponse_CreatedNamespaceEvent struct {
-	CreatedNamespaceEvent *EventCreatedNamespace `protobuf:"bytes,2,opt,name=created_namespace_event,json=createdNamespaceEvent,proto3,oneof"`
 }
 
-type GetEventResponse_DisabledPipelineEvent struct {
-	DisabledPipelineEvent *EventDisabledPipeline `protobuf:"bytes,5,opt,name=disabled_pipeline_event,json=disabledPipelineEvent,proto3,oneof"`
+func (x *AttachToTaskRunRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-type GetEventResponse_EnabledPipelineEvent struct {
-	EnabledPipelineEvent *EventEnabledPipeline `protobuf:"bytes,6,opt,name=enabled_pipeline_event,json=enabledPipelineEvent,proto3,oneof"`
-}
+func (*AttachToTaskRunRequest) ProtoMessage() {}
 
-type GetEventResponse_CreatedPipelineEvent struct {
-	CreatedPipelineEvent *EventCreatedPipeline `protobuf:"bytes,7,opt,name=created_pipeline_event,json=createdPipelineEvent,proto3,oneof"`
+func (x *AttachToTaskRunRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[71]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
 }
 
-type GetEventResponse_AbandonedPipelineEvent struct {
-	AbandonedPipelineEvent *EventAbandonedPipeline `protobuf:"bytes,8,opt,name=abandoned_pipeline_event,json=abandonedPipelineEvent,proto3,oneof"`
+// Deprecated: Use AttachToTaskRunRequest.ProtoReflect.Descriptor instead.
+func (*AttachToTaskRunRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{71}
 }
 
-type GetEventResponse_StartedRunEvent struct {
-	StartedRunEvent *EventStartedRun `protobuf:"bytes,10,opt,name=started_run_event,json=startedRunEvent,proto3,oneof"`
+func (m *AttachToTaskRunRequest) GetRequest() isAttachToTaskRunRequest_Request {
+	if m != nil {
+		return m.Request
+	}
+	return nil
 }
 
-type GetEventResponse_CompletedRunEvent struct {
-	CompletedRunEvent *EventCompletedRun `protobuf:"bytes,11,opt,name=completed_run_event,json=completedRunEvent,proto3,oneof"`
+func (x *AttachToTaskRunRequest) GetStart() *AttachToTaskRunStart {
+	if x, ok := x.GetRequest().(*AttachToTaskRunRequest_Start); ok {
+		return x.Start
+	}
+	return nil
 }
 
-type GetEventResponse_StartedTaskRunEvent struct {
-	StartedTaskRunEvent *EventStartedTaskRun `protobuf:"bytes,15,opt,name=started_task_run_event,json=startedTaskRunEvent,proto3,oneof"`
+func (x *AttachToTaskRunRequest) GetInput() *AttachToTaskRunInput {
+	if x, ok := x.GetRequest().(*AttachToTaskRunRequest_Input); ok {
+		return x.Input
+	}
+	return nil
 }
 
-type GetEventResponse_ScheduledTaskRunEvent struct {
-	ScheduledTaskRunEvent *EventScheduledTaskRun `protobuf:"bytes,16,opt,name=scheduled_task_run_event,json=scheduledTaskRunEvent,proto3,oneof"`
+func (x *AttachToTaskRunRequest) GetResize() *AttachToTaskRunResize {
+	if x, ok := x.GetRequest().(*AttachToTaskRunRequest_Resize); ok {
+		return x.Resize
+	}
+	return nil
 }
 
-type GetEventResponse_CompletedTaskRunEvent struct {
-	CompletedTaskRunEvent *EventCompletedTaskRun `protobuf:"bytes,17,opt,name=completed_task_run_event,json=completedTaskRunEvent,proto3,oneof"`
+type isAttachToTaskRunRequest_Request interface {
+	isAttachToTaskRunRequest_Request()
 }
 
-type GetEventResponse_FiredTriggerEvent struct {
-	FiredTriggerEvent *EventFiredTrigger `protobuf:"bytes,20,opt,name=fired_trigger_event,json=firedTriggerEvent,proto3,oneof"`
+type AttachToTaskRunRequest_Start struct {
+	Start *AttachToTaskRunStart `protobuf:"bytes,1,opt,name=start,proto3,oneof"`
 }
 
-type GetEventResponse_ProcessedTriggerEvent struct {
-	ProcessedTriggerEvent *EventProcessedTrigger `protobuf:"bytes,21,opt,name=processed_trigger_event,json=processedTriggerEvent,proto3,oneof"`
+type AttachToTaskRunRequest_Input struct {
+	Input *AttachToTaskRunInput `protobuf:"bytes,2,opt,name=input,proto3,oneof"`
 }
 
-type GetEventResponse_ResolvedTriggerEvent struct {
-	ResolvedTriggerEvent *EventResolvedTrigger `protobuf:"bytes,22,opt,name=resolved_trigger_event,json=resolvedTriggerEvent,proto3,oneof"`
+type AttachToTaskRunRequest_Resize struct {
+	Resize *AttachToTaskRunResize `protobuf:"bytes,3,opt,name=resize,proto3,oneof"`
 }
 
-func (*GetEventResponse_CreatedNamespaceEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_DisabledPipelineEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_EnabledPipelineEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_CreatedPipelineEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_AbandonedPipelineEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_StartedRunEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_CompletedRunEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_StartedTaskRunEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_ScheduledTaskRunEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_CompletedTaskRunEvent) isGetEventResponse_Event() {}
-
-func (*GetEventResponse_FiredTriggerEvent) isGetEventResponse_Event() {}
+func (*AttachToTaskRunRequest_Start) isAttachToTaskRunRequest_Request() {}
 
-func (*GetEventResponse_ProcessedTriggerEvent) isGetEventResponse_Event() {}
+func (*AttachToTaskRunRequest_Input) isAttachToTaskRunRequest_Request() {}
 
-func (*GetEventResponse_ResolvedTriggerEvent) isGetEventResponse_Event() {}
+func (*AttachToTaskRunRequest_Resize) isAttachToTaskRunRequest_Request() {}
 
-type ListEventsRequest struct {
+type AttachToTaskRunOutput struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// defaults to false; meaning oldest to newest events by default.
-	Reverse bool `protobuf:"varint,1,opt,name=reverse,proto3" json:"reverse,omitempty"`
-	// Tell Gofer to continually stream new events instead of closing the stream
-	// after it gets to the end.
-	Follow bool `protobuf:"varint,2,opt,name=follow,proto3" json:"follow,omitempty"`
+	Output []byte `protobuf:"bytes,1,opt,name=output,proto3" json:"output,omitempty"`
+	// Set on messages sent only to keep an idle stream open; they carry no
+	// output and should be skipped.
+	Keepalive bool `protobuf:"varint,2,opt,name=keepalive,proto3" json:"keepalive,omitempty"`
 }
 
-func (x *ListEventsRequest) Reset() {
-	*x = ListEventsRequest{}
+func (x *AttachToTaskRunOutput) Reset() {
+	*x = AttachToTaskRunOutput{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[70]
+		mi := &file_gofer_transport_proto_msgTypes[72]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListEventsRequest) String() string {
+func (x *AttachToTaskRunOutput) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListEventsRequest) ProtoMessage() {}
+func (*AttachToTaskRunOutput) ProtoMessage() {}
 
-func (x *ListEventsRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[70]
+func (x *AttachToTaskRunOutput) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[72]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -3954,65 +4270,50 @@ func (x *ListEventsRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListEventsRequest.ProtoReflect.Descriptor instead.
-func (*ListEventsRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{70}
+// Deprecated: Use AttachToTaskRunOutput.ProtoReflect.Descriptor instead.
+func (*AttachToTaskRunOutput) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{72}
 }
 
-func (x *ListEventsRequest) GetReverse() bool {
+func (x *AttachToTaskRunOutput) GetOutput() []byte {
 	if x != nil {
-		return x.Reverse
+		return x.Output
 	}
-	return false
+	return nil
 }
 
-func (x *ListEventsRequest) GetFollow() bool {
+func (x *AttachToTaskRunOutput) GetKeepalive() bool {
 	if x != nil {
-		return x.Follow
+		return x.Keepalive
 	}
 	return false
 }
 
-type ListEventsResponse struct {
+type GetTriggerRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Kind EventType `protobuf:"varint,1,opt,name=kind,proto3,enum=proto.EventType" json:"kind,omitempty"`
-	// Types that are assignable to Event:
-	//	*ListEventsResponse_CreatedNamespaceEvent
-	//	*ListEventsResponse_DisabledPipelineEvent
-	//	*ListEventsResponse_EnabledPipelineEvent
-	//	*ListEventsResponse_CreatedPipelineEvent
-	//	*ListEventsResponse_AbandonedPipelineEvent
-	//	*ListEventsResponse_StartedRunEvent
-	//	*ListEventsResponse_CompletedRunEvent
-	//	*ListEventsResponse_StartedTaskRunEvent
-	//	*ListEventsResponse_ScheduledTaskRunEvent
-	//	*ListEventsResponse_CompletedTaskRunEvent
-	//	*ListEventsResponse_FiredTriggerEvent
-	//	*ListEventsResponse_ProcessedTriggerEvent
-	//	*ListEventsResponse_ResolvedTriggerEvent
-	Event isListEventsResponse_Event `protobuf_oneof:"event"`
+	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"` // The unique name for a particular trigger
 }
 
-func (x *ListEventsResponse) Reset() {
-	*x = ListEventsResponse{}
+func (x *GetTriggerRequest) Reset() {
+	*x = GetTriggerRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[71]
+		mi := &file_gofer_transport_proto_msgTypes[73]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListEventsResponse) String() string {
+func (x *GetTriggerRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListEventsResponse) ProtoMessage() {}
+func (*GetTriggerRequest) ProtoMessage() {}
 
-func (x *ListEventsResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[71]
+func (x *GetTriggerRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[73]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4023,225 +4324,128 @@ func (x *ListEventsResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListEventsResponse.ProtoReflect.Descriptor instead.
-func (*ListEventsResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{71}
+// Deprecated: Use GetTriggerRequest.ProtoReflect.Descriptor instead.
+func (*GetTriggerRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{73}
 }
 
-func (x *ListEventsResponse) GetKind() EventType {
+func (x *GetTriggerRequest) GetKind() string {
 	if x != nil {
 		return x.Kind
 	}
-	return EventType_UNKNOWN_EVENT_TYPE
+	return ""
 }
 
-func (m *ListEventsResponse) GetEvent() isListEventsResponse_Event {
-	if m != nil {
-		return m.Event
-	}
-	return nil
+type GetTriggerResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Trigger *Trigger `protobuf:"bytes,1,opt,name=trigger,proto3" json:"trigger,omitempty"`
 }
 
-func (x *ListEventsResponse) GetCreatedNamespaceEvent() *EventCreatedNamespace {
-	if x, ok := x.GetEvent().(*ListEventsResponse_CreatedNamespaceEvent); ok {
-		return x.CreatedNamespaceEvent
-	}
-	return nil
-}
-
-func (x *ListEventsResponse) GetDisabledPipelineEvent() *EventDisabledPipeline {
-	if x, ok := x.GetEvent().(*ListEventsResponse_DisabledPipelineEvent); ok {
-		return x.DisabledPipelineEvent
-	}
-	return nil
-}
-
-func (x *ListEventsResponse) GetEnabledPipelineEvent() *EventEnabledPipeline {
-	if x, ok := x.GetEvent().(*ListEventsResponse_EnabledPipelineEvent); ok {
-		return x.EnabledPipelineEvent
+func (x *GetTriggerResponse) Reset() {
+	*x = GetTriggerResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[74]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return nil
 }
 
-func (x *ListEventsResponse) GetCreatedPipelineEvent() *EventCreatedPipeline {
-	if x, ok := x.GetEvent().(*ListEventsResponse_CreatedPipelineEvent); ok {
-		return x.CreatedPipelineEvent
-	}
-	return nil
+func (x *GetTriggerResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-func (x *ListEventsResponse) GetAbandonedPipelineEvent() *EventAbandonedPipeline {
-	if x, ok := x.GetEvent().(*ListEventsResponse_AbandonedPipelineEvent); ok {
-		return x.AbandonedPipelineEvent
-	}
-	return nil
-}
+func (*GetTriggerResponse) ProtoMessage() {}
 
-func (x *ListEventsResponse) GetStartedRunEvent() *EventStartedRun {
-	if x, ok := x.GetEvent().(*ListEventsResponse_StartedRunEvent); ok {
-		return x.StartedRunEvent
+func (x *GetTriggerResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[74]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return nil
+	return mi.MessageOf(x)
 }
 
-func (x *ListEventsResponse) GetCompletedRunEvent() *EventCompletedRun {
-	if x, ok := x.GetEvent().(*ListEventsResponse_CompletedRunEvent); ok {
-		return x.CompletedRunEvent
-	}
-	return nil
+// Deprecated: Use GetTriggerResponse.ProtoReflect.Descriptor instead.
+func (*GetTriggerResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{74}
 }
 
-func (x *ListEventsResponse) GetStartedTaskRunEvent() *EventStartedTaskRun {
-	if x, ok := x.GetEvent().(*ListEventsResponse_StartedTaskRunEvent); ok {
-		return x.StartedTaskRunEvent
+func (x *GetTriggerResponse) GetTrigger() *Trigger {
+	if x != nil {
+		return x.Trigger
 	}
 	return nil
 }
 
-func (x *ListEventsResponse) GetScheduledTaskRunEvent() *EventScheduledTaskRun {
-	if x, ok := x.GetEvent().(*ListEventsResponse_ScheduledTaskRunEvent); ok {
-		return x.ScheduledTaskRunEvent
-	}
-	return nil
+type ListTriggersRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
 }
 
-func (x *ListEventsResponse) GetCompletedTaskRunEvent() *EventCompletedTaskRun {
-	if x, ok := x.GetEvent().(*ListEventsResponse_CompletedTaskRunEvent); ok {
-		return x.CompletedTaskRunEvent
+func (x *ListTriggersRequest) Reset() {
+	*x = ListTriggersRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[75]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return nil
 }
 
-func (x *ListEventsResponse) GetFiredTriggerEvent() *EventFiredTrigger {
-	if x, ok := x.GetEvent().(*ListEventsResponse_FiredTriggerEvent); ok {
-		return x.FiredTriggerEvent
-	}
-	return nil
+func (x *ListTriggersRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-func (x *ListEventsResponse) GetProcessedTriggerEvent() *EventProcessedTrigger {
-	if x, ok := x.GetEvent().(*ListEventsResponse_ProcessedTriggerEvent); ok {
-		return x.ProcessedTriggerEvent
-	}
-	return nil
-}
+func (*ListTriggersRequest) ProtoMessage() {}
 
-func (x *ListEventsResponse) GetResolvedTriggerEvent() *EventResolvedTrigger {
-	if x, ok := x.GetEvent().(*ListEventsResponse_ResolvedTriggerEvent); ok {
-		return x.ResolvedTriggerEvent
+func (x *ListTriggersRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[75]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return nil
-}
-
-type isListEventsResponse_Event interface {
-	isListEventsResponse_Event()
-}
-
-type ListEventsResponse_CreatedNamespaceEvent struct {
-	CreatedNamespaceEvent *EventCreatedNamespace `protobuf:"bytes,2,opt,name=created_namespace_event,json=createdNamespaceEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_DisabledPipelineEvent struct {
-	DisabledPipelineEvent *EventDisabledPipeline `protobuf:"bytes,5,opt,name=disabled_pipeline_event,json=disabledPipelineEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_EnabledPipelineEvent struct {
-	EnabledPipelineEvent *EventEnabledPipeline `protobuf:"bytes,6,opt,name=enabled_pipeline_event,json=enabledPipelineEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_CreatedPipelineEvent struct {
-	CreatedPipelineEvent *EventCreatedPipeline `protobuf:"bytes,7,opt,name=created_pipeline_event,json=createdPipelineEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_AbandonedPipelineEvent struct {
-	AbandonedPipelineEvent *EventAbandonedPipeline `protobuf:"bytes,8,opt,name=abandoned_pipeline_event,json=abandonedPipelineEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_StartedRunEvent struct {
-	StartedRunEvent *EventStartedRun `protobuf:"bytes,10,opt,name=started_run_event,json=startedRunEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_CompletedRunEvent struct {
-	CompletedRunEvent *EventCompletedRun `protobuf:"bytes,11,opt,name=completed_run_event,json=completedRunEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_StartedTaskRunEvent struct {
-	StartedTaskRunEvent *EventStartedTaskRun `protobuf:"bytes,15,opt,name=started_task_run_event,json=startedTaskRunEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_ScheduledTaskRunEvent struct {
-	ScheduledTaskRunEvent *EventScheduledTaskRun `protobuf:"bytes,16,opt,name=scheduled_task_run_event,json=scheduledTaskRunEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_CompletedTaskRunEvent struct {
-	CompletedTaskRunEvent *EventCompletedTaskRun `protobuf:"bytes,17,opt,name=completed_task_run_event,json=completedTaskRunEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_FiredTriggerEvent struct {
-	FiredTriggerEvent *EventFiredTrigger `protobuf:"bytes,20,opt,name=fired_trigger_event,json=firedTriggerEvent,proto3,oneof"`
-}
-
-type ListEventsResponse_ProcessedTriggerEvent struct {
-	ProcessedTriggerEvent *EventProcessedTrigger `protobuf:"bytes,21,opt,name=processed_trigger_event,json=processedTriggerEvent,proto3,oneof"`
+	return mi.MessageOf(x)
 }
 
-type ListEventsResponse_ResolvedTriggerEvent struct {
-	ResolvedTriggerEvent *EventResolvedTrigger `protobuf:"bytes,22,opt,name=resolved_trigger_event,json=resolvedTriggerEvent,proto3,oneof"`
+// Deprecated: Use ListTriggersRequest.ProtoReflect.Descriptor instead.
+func (*ListTriggersRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{75}
 }
 
-func (*ListEventsResponse_CreatedNamespaceEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_DisabledPipelineEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_EnabledPipelineEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_CreatedPipelineEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_AbandonedPipelineEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_StartedRunEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_CompletedRunEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_StartedTaskRunEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_ScheduledTaskRunEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_CompletedTaskRunEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_FiredTriggerEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_ProcessedTriggerEvent) isListEventsResponse_Event() {}
-
-func (*ListEventsResponse_ResolvedTriggerEvent) isListEventsResponse_Event() {}
-
-type GetPipelineObjectRequest struct {
+type ListTriggersResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+	Triggers []*Trigger `protobuf:"bytes,1,rep,name=triggers,proto3" json:"triggers,omitempty"`
 }
 
-func (x *GetPipelineObjectRequest) Reset() {
-	*x = GetPipelineObjectRequest{}
+func (x *ListTriggersResponse) Reset() {
+	*x = ListTriggersResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[72]
+		mi := &file_gofer_transport_proto_msgTypes[76]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetPipelineObjectRequest) String() string {
+func (x *ListTriggersResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetPipelineObjectRequest) ProtoMessage() {}
+func (*ListTriggersResponse) ProtoMessage() {}
 
-func (x *GetPipelineObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[72]
+func (x *ListTriggersResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[76]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4252,57 +4456,43 @@ func (x *GetPipelineObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetPipelineObjectRequest.ProtoReflect.Descriptor instead.
-func (*GetPipelineObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{72}
-}
-
-func (x *GetPipelineObjectRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
-}
-
-func (x *GetPipelineObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
+// Deprecated: Use ListTriggersResponse.ProtoReflect.Descriptor instead.
+func (*ListTriggersResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{76}
 }
 
-func (x *GetPipelineObjectRequest) GetKey() string {
+func (x *ListTriggersResponse) GetTriggers() []*Trigger {
 	if x != nil {
-		return x.Key
+		return x.Triggers
 	}
-	return ""
+	return nil
 }
 
-type GetPipelineObjectResponse struct {
+type InstallTriggerRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Content []byte `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
+	Trigger *TriggerConfig `protobuf:"bytes,1,opt,name=trigger,proto3" json:"trigger,omitempty"`
 }
 
-func (x *GetPipelineObjectResponse) Reset() {
-	*x = GetPipelineObjectResponse{}
+func (x *InstallTriggerRequest) Reset() {
+	*x = InstallTriggerRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[73]
+		mi := &file_gofer_transport_proto_msgTypes[77]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetPipelineObjectResponse) String() string {
+func (x *InstallTriggerRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetPipelineObjectResponse) ProtoMessage() {}
+func (*InstallTriggerRequest) ProtoMessage() {}
 
-func (x *GetPipelineObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[73]
+func (x *InstallTriggerRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[77]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4313,44 +4503,41 @@ func (x *GetPipelineObjectResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetPipelineObjectResponse.ProtoReflect.Descriptor instead.
-func (*GetPipelineObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{73}
+// Deprecated: Use InstallTriggerRequest.ProtoReflect.Descriptor instead.
+func (*InstallTriggerRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{77}
 }
 
-func (x *GetPipelineObjectResponse) GetContent() []byte {
+func (x *InstallTriggerRequest) GetTrigger() *TriggerConfig {
 	if x != nil {
-		return x.Content
+		return x.Trigger
 	}
 	return nil
 }
 
-type ListPipelineObjectRequest struct {
+type InstallTriggerResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
 }
 
-func (x *ListPipelineObjectRequest) Reset() {
-	*x = ListPipelineObjectRequest{}
+func (x *InstallTriggerResponse) Reset() {
+	*x = InstallTriggerResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[74]
+		mi := &file_gofer_transport_proto_msgTypes[78]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListPipelineObjectRequest) String() string {
+func (x *InstallTriggerResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListPipelineObjectRequest) ProtoMessage() {}
+func (*InstallTriggerResponse) ProtoMessage() {}
 
-func (x *ListPipelineObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[74]
+func (x *InstallTriggerResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[78]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4361,50 +4548,36 @@ func (x *ListPipelineObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListPipelineObjectRequest.ProtoReflect.Descriptor instead.
-func (*ListPipelineObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{74}
-}
-
-func (x *ListPipelineObjectRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
-}
-
-func (x *ListPipelineObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
+// Deprecated: Use InstallTriggerResponse.ProtoReflect.Descriptor instead.
+func (*InstallTriggerResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{78}
 }
 
-type ListPipelineObjectResponse struct {
+type UninstallTriggerRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Keys []string `protobuf:"bytes,1,rep,name=keys,proto3" json:"keys,omitempty"`
+	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"`
 }
 
-func (x *ListPipelineObjectResponse) Reset() {
-	*x = ListPipelineObjectResponse{}
+func (x *UninstallTriggerRequest) Reset() {
+	*x = UninstallTriggerRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[75]
+		mi := &file_gofer_transport_proto_msgTypes[79]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListPipelineObjectResponse) String() string {
+func (x *UninstallTriggerRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListPipelineObjectResponse) ProtoMessage() {}
+func (*UninstallTriggerRequest) ProtoMessage() {}
 
-func (x *ListPipelineObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[75]
+func (x *UninstallTriggerRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[79]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4415,47 +4588,41 @@ func (x *ListPipelineObjectResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListPipelineObjectResponse.ProtoReflect.Descriptor instead.
-func (*ListPipelineObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{75}
+// Deprecated: Use UninstallTriggerRequest.ProtoReflect.Descriptor instead.
+func (*UninstallTriggerRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{79}
 }
 
-func (x *ListPipelineObjectResponse) GetKeys() []string {
+func (x *UninstallTriggerRequest) GetKind() string {
 	if x != nil {
-		return x.Keys
+		return x.Kind
 	}
-	return nil
+	return ""
 }
 
-type PutPipelineObjectRequest struct {
+type UninstallTriggerResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
-	Content     []byte `protobuf:"bytes,4,opt,name=content,proto3" json:"content,omitempty"`
-	Force       bool   `protobuf:"varint,5,opt,name=force,proto3" json:"force,omitempty"` // Overwrites an already existing value.
 }
 
-func (x *PutPipelineObjectRequest) Reset() {
-	*x = PutPipelineObjectRequest{}
+func (x *UninstallTriggerResponse) Reset() {
+	*x = UninstallTriggerResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[76]
+		mi := &file_gofer_transport_proto_msgTypes[80]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *PutPipelineObjectRequest) String() string {
+func (x *UninstallTriggerResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*PutPipelineObjectRequest) ProtoMessage() {}
+func (*UninstallTriggerResponse) ProtoMessage() {}
 
-func (x *PutPipelineObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[76]
+func (x *UninstallTriggerResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[80]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4466,76 +4633,36 @@ func (x *PutPipelineObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use PutPipelineObjectRequest.ProtoReflect.Descriptor instead.
-func (*PutPipelineObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{76}
-}
-
-func (x *PutPipelineObjectRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
-}
-
-func (x *PutPipelineObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
-}
-
-func (x *PutPipelineObjectRequest) GetKey() string {
-	if x != nil {
-		return x.Key
-	}
-	return ""
-}
-
-func (x *PutPipelineObjectRequest) GetContent() []byte {
-	if x != nil {
-		return x.Content
-	}
-	return nil
-}
-
-func (x *PutPipelineObjectRequest) GetForce() bool {
-	if x != nil {
-		return x.Force
-	}
-	return false
+// Deprecated: Use UninstallTriggerResponse.ProtoReflect.Descriptor instead.
+func (*UninstallTriggerResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{80}
 }
 
-type PutPipelineObjectResponse struct {
+type GetNotifierRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// The number of bytes uploaded.
-	Bytes int64 `protobuf:"varint,1,opt,name=bytes,proto3" json:"bytes,omitempty"`
-	// The total amount of objects for this particular pipeline.
-	ObjectLimit int64 `protobuf:"varint,2,opt,name=object_limit,json=objectLimit,proto3" json:"object_limit,omitempty"`
-	// The key for the object that was evicted for the latest key.
-	ObjectEvicted string `protobuf:"bytes,3,opt,name=object_evicted,json=objectEvicted,proto3" json:"object_evicted,omitempty"`
+	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"` // The unique name/kind for a particular notifier
 }
 
-func (x *PutPipelineObjectResponse) Reset() {
-	*x = PutPipelineObjectResponse{}
+func (x *GetNotifierRequest) Reset() {
+	*x = GetNotifierRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[77]
+		mi := &file_gofer_transport_proto_msgTypes[81]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *PutPipelineObjectResponse) String() string {
+func (x *GetNotifierRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*PutPipelineObjectResponse) ProtoMessage() {}
+func (*GetNotifierRequest) ProtoMessage() {}
 
-func (x *PutPipelineObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[77]
+func (x *GetNotifierRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[81]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4546,59 +4673,43 @@ func (x *PutPipelineObjectResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use PutPipelineObjectResponse.ProtoReflect.Descriptor instead.
-func (*PutPipelineObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{77}
-}
-
-func (x *PutPipelineObjectResponse) GetBytes() int64 {
-	if x != nil {
-		return x.Bytes
-	}
-	return 0
-}
-
-func (x *PutPipelineObjectResponse) GetObjectLimit() int64 {
-	if x != nil {
-		return x.ObjectLimit
-	}
-	return 0
+// Deprecated: Use GetNotifierRequest.ProtoReflect.Descriptor instead.
+func (*GetNotifierRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{81}
 }
 
-func (x *PutPipelineObjectResponse) GetObjectEvicted() string {
+func (x *GetNotifierRequest) GetKind() string {
 	if x != nil {
-		return x.ObjectEvicted
+		return x.Kind
 	}
 	return ""
 }
 
-type DeletePipelineObjectRequest struct {
+type GetNotifierResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+	Notifier *Notifier `protobuf:"bytes,1,opt,name=notifier,proto3" json:"notifier,omitempty"`
 }
 
-func (x *DeletePipelineObjectRequest) Reset() {
-	*x = DeletePipelineObjectRequest{}
+func (x *GetNotifierResponse) Reset() {
+	*x = GetNotifierResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[78]
+		mi := &file_gofer_transport_proto_msgTypes[82]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DeletePipelineObjectRequest) String() string {
+func (x *GetNotifierResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeletePipelineObjectRequest) ProtoMessage() {}
+func (*GetNotifierResponse) ProtoMessage() {}
 
-func (x *DeletePipelineObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[78]
+func (x *GetNotifierResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[82]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4609,55 +4720,41 @@ func (x *DeletePipelineObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeletePipelineObjectRequest.ProtoReflect.Descriptor instead.
-func (*DeletePipelineObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{78}
-}
-
-func (x *DeletePipelineObjectRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
-}
-
-func (x *DeletePipelineObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
+// Deprecated: Use GetNotifierResponse.ProtoReflect.Descriptor instead.
+func (*GetNotifierResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{82}
 }
 
-func (x *DeletePipelineObjectRequest) GetKey() string {
+func (x *GetNotifierResponse) GetNotifier() *Notifier {
 	if x != nil {
-		return x.Key
+		return x.Notifier
 	}
-	return ""
+	return nil
 }
 
-type DeletePipelineObjectResponse struct {
+type ListNotifiersRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 }
 
-func (x *DeletePipelineObjectResponse) Reset() {
-	*x = DeletePipelineObjectResponse{}
+func (x *ListNotifiersRequest) Reset() {
+	*x = ListNotifiersRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[79]
+		mi := &file_gofer_transport_proto_msgTypes[83]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DeletePipelineObjectResponse) String() string {
+func (x *ListNotifiersRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeletePipelineObjectResponse) ProtoMessage() {}
+func (*ListNotifiersRequest) ProtoMessage() {}
 
-func (x *DeletePipelineObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[79]
+func (x *ListNotifiersRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[83]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4668,39 +4765,36 @@ func (x *DeletePipelineObjectResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeletePipelineObjectResponse.ProtoReflect.Descriptor instead.
-func (*DeletePipelineObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{79}
-}
-
-type GetRunObjectRequest struct {
+// Deprecated: Use ListNotifiersRequest.ProtoReflect.Descriptor instead.
+func (*ListNotifiersRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{83}
+}
+
+type ListNotifiersResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-	Key         string `protobuf:"bytes,4,opt,name=key,proto3" json:"key,omitempty"`
+	Notifiers []*Notifier `protobuf:"bytes,1,rep,name=notifiers,proto3" json:"notifiers,omitempty"`
 }
 
-func (x *GetRunObjectRequest) Reset() {
-	*x = GetRunObjectRequest{}
+func (x *ListNotifiersResponse) Reset() {
+	*x = ListNotifiersResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[80]
+		mi := &file_gofer_transport_proto_msgTypes[84]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetRunObjectRequest) String() string {
+func (x *ListNotifiersResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetRunObjectRequest) ProtoMessage() {}
+func (*ListNotifiersResponse) ProtoMessage() {}
 
-func (x *GetRunObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[80]
+func (x *ListNotifiersResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[84]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4711,64 +4805,43 @@ func (x *GetRunObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetRunObjectRequest.ProtoReflect.Descriptor instead.
-func (*GetRunObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{80}
-}
-
-func (x *GetRunObjectRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
-}
-
-func (x *GetRunObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
-}
-
-func (x *GetRunObjectRequest) GetRunId() int64 {
-	if x != nil {
-		return x.RunId
-	}
-	return 0
+// Deprecated: Use ListNotifiersResponse.ProtoReflect.Descriptor instead.
+func (*ListNotifiersResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{84}
 }
 
-func (x *GetRunObjectRequest) GetKey() string {
+func (x *ListNotifiersResponse) GetNotifiers() []*Notifier {
 	if x != nil {
-		return x.Key
+		return x.Notifiers
 	}
-	return ""
+	return nil
 }
 
-type GetRunObjectResponse struct {
+type InstallNotifierRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Content []byte `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
+	Notifier *NotifierConfig `protobuf:"bytes,1,opt,name=notifier,proto3" json:"notifier,omitempty"`
 }
 
-func (x *GetRunObjectResponse) Reset() {
-	*x = GetRunObjectResponse{}
+func (x *InstallNotifierRequest) Reset() {
+	*x = InstallNotifierRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[81]
+		mi := &file_gofer_transport_proto_msgTypes[85]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetRunObjectResponse) String() string {
+func (x *InstallNotifierRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetRunObjectResponse) ProtoMessage() {}
+func (*InstallNotifierRequest) ProtoMessage() {}
 
-func (x *GetRunObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[81]
+func (x *InstallNotifierRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[85]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4779,45 +4852,41 @@ func (x *GetRunObjectResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetRunObjectResponse.ProtoReflect.Descriptor instead.
-func (*GetRunObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{81}
+// Deprecated: Use InstallNotifierRequest.ProtoReflect.Descriptor instead.
+func (*InstallNotifierRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{85}
 }
 
-func (x *GetRunObjectResponse) GetContent() []byte {
+func (x *InstallNotifierRequest) GetNotifier() *NotifierConfig {
 	if x != nil {
-		return x.Content
+		return x.Notifier
 	}
 	return nil
 }
 
-type ListRunObjectRequest struct {
+type InstallNotifierResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
 }
 
-func (x *ListRunObjectRequest) Reset() {
-	*x = ListRunObjectRequest{}
+func (x *InstallNotifierResponse) Reset() {
+	*x = InstallNotifierResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[82]
+		mi := &file_gofer_transport_proto_msgTypes[86]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListRunObjectRequest) String() string {
+func (x *InstallNotifierResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListRunObjectRequest) ProtoMessage() {}
+func (*InstallNotifierResponse) ProtoMessage() {}
 
-func (x *ListRunObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[82]
+func (x *InstallNotifierResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[86]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4828,57 +4897,36 @@ func (x *ListRunObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListRunObjectRequest.ProtoReflect.Descriptor instead.
-func (*ListRunObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{82}
-}
-
-func (x *ListRunObjectRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
-}
-
-func (x *ListRunObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
-}
-
-func (x *ListRunObjectRequest) GetRunId() int64 {
-	if x != nil {
-		return x.RunId
-	}
-	return 0
+// Deprecated: Use InstallNotifierResponse.ProtoReflect.Descriptor instead.
+func (*InstallNotifierResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{86}
 }
 
-type ListRunObjectResponse struct {
+type UninstallNotifierRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Keys []string `protobuf:"bytes,1,rep,name=keys,proto3" json:"keys,omitempty"`
+	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"`
 }
 
-func (x *ListRunObjectResponse) Reset() {
-	*x = ListRunObjectResponse{}
+func (x *UninstallNotifierRequest) Reset() {
+	*x = UninstallNotifierRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[83]
+		mi := &file_gofer_transport_proto_msgTypes[87]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ListRunObjectResponse) String() string {
+func (x *UninstallNotifierRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ListRunObjectResponse) ProtoMessage() {}
+func (*UninstallNotifierRequest) ProtoMessage() {}
 
-func (x *ListRunObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[83]
+func (x *UninstallNotifierRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[87]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4889,48 +4937,41 @@ func (x *ListRunObjectResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ListRunObjectResponse.ProtoReflect.Descriptor instead.
-func (*ListRunObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{83}
+// Deprecated: Use UninstallNotifierRequest.ProtoReflect.Descriptor instead.
+func (*UninstallNotifierRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{87}
 }
 
-func (x *ListRunObjectResponse) GetKeys() []string {
+func (x *UninstallNotifierRequest) GetKind() string {
 	if x != nil {
-		return x.Keys
+		return x.Kind
 	}
-	return nil
+	return ""
 }
 
-type PutRunObjectRequest struct {
+type UninstallNotifierResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-	Key         string `protobuf:"bytes,4,opt,name=key,proto3" json:"key,omitempty"`
-	Content     []byte `protobuf:"bytes,5,opt,name=content,proto3" json:"content,omitempty"`
-	Force       bool   `protobuf:"varint,6,opt,name=force,proto3" json:"force,omitempty"` // Overwrites an already existing value.
 }
 
-func (x *PutRunObjectRequest) Reset() {
-	*x = PutRunObjectRequest{}
+func (x *UninstallNotifierResponse) Reset() {
+	*x = UninstallNotifierResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[84]
+		mi := &file_gofer_transport_proto_msgTypes[88]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *PutRunObjectRequest) String() string {
+func (x *UninstallNotifierResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*PutRunObjectRequest) ProtoMessage() {}
+func (*UninstallNotifierResponse) ProtoMessage() {}
 
-func (x *PutRunObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[84]
+func (x *UninstallNotifierResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[88]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -4941,78 +4982,36 @@ func (x *PutRunObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use PutRunObjectRequest.ProtoReflect.Descriptor instead.
-func (*PutRunObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{84}
-}
-
-func (x *PutRunObjectRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
-}
-
-func (x *PutRunObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
-}
-
-func (x *PutRunObjectRequest) GetRunId() int64 {
-	if x != nil {
-		return x.RunId
-	}
-	return 0
-}
-
-func (x *PutRunObjectRequest) GetKey() string {
-	if x != nil {
-		return x.Key
-	}
-	return ""
-}
-
-func (x *PutRunObjectRequest) GetContent() []byte {
-	if x != nil {
-		return x.Content
-	}
-	return nil
-}
-
-func (x *PutRunObjectRequest) GetForce() bool {
-	if x != nil {
-		return x.Force
-	}
-	return false
+// Deprecated: Use UninstallNotifierResponse.ProtoReflect.Descriptor instead.
+func (*UninstallNotifierResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{88}
 }
 
-type PutRunObjectResponse struct {
+type GetEventRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Bytes int64 `protobuf:"varint,1,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
 }
 
-func (x *PutRunObjectResponse) Reset() {
-	*x = PutRunObjectResponse{}
+func (x *GetEventRequest) Reset() {
+	*x = GetEventRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[85]
+		mi := &file_gofer_transport_proto_msgTypes[89]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *PutRunObjectResponse) String() string {
+func (x *GetEventRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*PutRunObjectResponse) ProtoMessage() {}
+func (*GetEventRequest) ProtoMessage() {}
 
-func (x *PutRunObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[85]
+func (x *GetEventRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[89]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5023,46 +5022,62 @@ func (x *PutRunObjectResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use PutRunObjectResponse.ProtoReflect.Descriptor instead.
-func (*PutRunObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{85}
+// Deprecated: Use GetEventRequest.ProtoReflect.Descriptor instead.
+func (*GetEventRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{89}
 }
 
-func (x *PutRunObjectResponse) GetBytes() int64 {
+func (x *GetEventRequest) GetId() int64 {
 	if x != nil {
-		return x.Bytes
+		return x.Id
 	}
 	return 0
 }
 
-type DeleteRunObjectRequest struct {
+type GetEventResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
-	Key         string `protobuf:"bytes,4,opt,name=key,proto3" json:"key,omitempty"`
+	Kind EventType `protobuf:"varint,1,opt,name=kind,proto3,enum=proto.EventType" json:"kind,omitempty"`
+	// Types that are assignable to Event:
+	//	*GetEventResponse_CreatedNamespaceEvent
+	//	*GetEventResponse_DisabledPipelineEvent
+	//	*GetEventResponse_EnabledPipelineEvent
+	//	*GetEventResponse_CreatedPipelineEvent
+	//	*GetEventResponse_AbandonedPipelineEvent
+	//	*GetEventResponse_RolledBackPipelineEvent
+	//	*GetEventResponse_StartedRunEvent
+	//	*GetEventResponse_CompletedRunEvent
+	//	*GetEventResponse_StartedTaskRunEvent
+	//	*GetEventResponse_ScheduledTaskRunEvent
+	//	*GetEventResponse_CompletedTaskRunEvent
+	//	*GetEventResponse_FiredTriggerEvent
+	//	*GetEventResponse_ProcessedTriggerEvent
+	//	*GetEventResponse_ResolvedTriggerEvent
+	//	*GetEventResponse_RevokedUserTokensEvent
+	//	*GetEventResponse_ExpiredPipelineObjectEvent
+	//	*GetEventResponse_ExtensionEvent
+	Event isGetEventResponse_Event `protobuf_oneof:"event"`
 }
 
-func (x *DeleteRunObjectRequest) Reset() {
-	*x = DeleteRunObjectRequest{}
+func (x *GetEventResponse) Reset() {
+	*x = GetEventResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[86]
+		mi := &file_gofer_transport_proto_msgTypes[90]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *DeleteRunObjectRequest) String() string {
+func (x *GetEventResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteRunObjectRequest) ProtoMessage() {}
+func (*GetEventResponse) ProtoMessage() {}
 
-func (x *DeleteRunObjectRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[86]
+func (x *GetEventResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[90]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5073,277 +5088,273 @@ func (x *DeleteRunObjectRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteRunObjectRequest.ProtoReflect.Descriptor instead.
-func (*DeleteRunObjectRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{86}
+// Deprecated: Use GetEventResponse.ProtoReflect.Descriptor instead.
+func (*GetEventResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{90}
 }
 
-func (x *DeleteRunObjectRequest) GetNamespaceId() string {
+func (x *GetEventResponse) GetKind() EventType {
 	if x != nil {
-		return x.NamespaceId
+		return x.Kind
 	}
-	return ""
+	return EventType_UNKNOWN_EVENT_TYPE
 }
 
-func (x *DeleteRunObjectRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
+func (m *GetEventResponse) GetEvent() isGetEventResponse_Event {
+	if m != nil {
+		return m.Event
 	}
-	return ""
+	return nil
 }
 
-func (x *DeleteRunObjectRequest) GetRunId() int64 {
-	if x != nil {
-		return x.RunId
+func (x *GetEventResponse) GetCreatedNamespaceEvent() *EventCreatedNamespace {
+	if x, ok := x.GetEvent().(*GetEventResponse_CreatedNamespaceEvent); ok {
+		return x.CreatedNamespaceEvent
 	}
-	return 0
+	return nil
 }
 
-func (x *DeleteRunObjectRequest) GetKey() string {
-	if x != nil {
-		return x.Key
+func (x *GetEventResponse) GetDisabledPipelineEvent() *EventDisabledPipeline {
+	if x, ok := x.GetEvent().(*GetEventResponse_DisabledPipelineEvent); ok {
+		return x.DisabledPipelineEvent
 	}
-	return ""
+	return nil
 }
 
-type DeleteRunObjectResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+func (x *GetEventResponse) GetEnabledPipelineEvent() *EventEnabledPipeline {
+	if x, ok := x.GetEvent().(*GetEventResponse_EnabledPipelineEvent); ok {
+		return x.EnabledPipelineEvent
+	}
+	return nil
 }
 
-func (x *DeleteRunObjectResponse) Reset() {
-	*x = DeleteRunObjectResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[87]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *GetEventResponse) GetCreatedPipelineEvent() *EventCreatedPipeline {
+	if x, ok := x.GetEvent().(*GetEventResponse_CreatedPipelineEvent); ok {
+		return x.CreatedPipelineEvent
 	}
+	return nil
 }
 
-func (x *DeleteRunObjectResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *GetEventResponse) GetAbandonedPipelineEvent() *EventAbandonedPipeline {
+	if x, ok := x.GetEvent().(*GetEventResponse_AbandonedPipelineEvent); ok {
+		return x.AbandonedPipelineEvent
+	}
+	return nil
 }
 
-func (*DeleteRunObjectResponse) ProtoMessage() {}
-
-func (x *DeleteRunObjectResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[87]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *GetEventResponse) GetRolledBackPipelineEvent() *EventRolledBackPipeline {
+	if x, ok := x.GetEvent().(*GetEventResponse_RolledBackPipelineEvent); ok {
+		return x.RolledBackPipelineEvent
 	}
-	return mi.MessageOf(x)
+	return nil
 }
 
-// Deprecated: Use DeleteRunObjectResponse.ProtoReflect.Descriptor instead.
-func (*DeleteRunObjectResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{87}
+func (x *GetEventResponse) GetStartedRunEvent() *EventStartedRun {
+	if x, ok := x.GetEvent().(*GetEventResponse_StartedRunEvent); ok {
+		return x.StartedRunEvent
+	}
+	return nil
 }
 
-type GetSecretRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+func (x *GetEventResponse) GetCompletedRunEvent() *EventCompletedRun {
+	if x, ok := x.GetEvent().(*GetEventResponse_CompletedRunEvent); ok {
+		return x.CompletedRunEvent
+	}
+	return nil
+}
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+func (x *GetEventResponse) GetStartedTaskRunEvent() *EventStartedTaskRun {
+	if x, ok := x.GetEvent().(*GetEventResponse_StartedTaskRunEvent); ok {
+		return x.StartedTaskRunEvent
+	}
+	return nil
 }
 
-func (x *GetSecretRequest) Reset() {
-	*x = GetSecretRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[88]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *GetEventResponse) GetScheduledTaskRunEvent() *EventScheduledTaskRun {
+	if x, ok := x.GetEvent().(*GetEventResponse_ScheduledTaskRunEvent); ok {
+		return x.ScheduledTaskRunEvent
 	}
+	return nil
 }
 
-func (x *GetSecretRequest) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *GetEventResponse) GetCompletedTaskRunEvent() *EventCompletedTaskRun {
+	if x, ok := x.GetEvent().(*GetEventResponse_CompletedTaskRunEvent); ok {
+		return x.CompletedTaskRunEvent
+	}
+	return nil
 }
 
-func (*GetSecretRequest) ProtoMessage() {}
+func (x *GetEventResponse) GetFiredTriggerEvent() *EventFiredTrigger {
+	if x, ok := x.GetEvent().(*GetEventResponse_FiredTriggerEvent); ok {
+		return x.FiredTriggerEvent
+	}
+	return nil
+}
 
-func (x *GetSecretRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[88]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *GetEventResponse) GetProcessedTriggerEvent() *EventProcessedTrigger {
+	if x, ok := x.GetEvent().(*GetEventResponse_ProcessedTriggerEvent); ok {
+		return x.ProcessedTriggerEvent
 	}
-	return mi.MessageOf(x)
+	return nil
 }
 
-// Deprecated: Use GetSecretRequest.ProtoReflect.Descriptor instead.
-func (*GetSecretRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{88}
+func (x *GetEventResponse) GetResolvedTriggerEvent() *EventResolvedTrigger {
+	if x, ok := x.GetEvent().(*GetEventResponse_ResolvedTriggerEvent); ok {
+		return x.ResolvedTriggerEvent
+	}
+	return nil
 }
 
-func (x *GetSecretRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
+func (x *GetEventResponse) GetRevokedUserTokensEvent() *EventRevokedUserTokens {
+	if x, ok := x.GetEvent().(*GetEventResponse_RevokedUserTokensEvent); ok {
+		return x.RevokedUserTokensEvent
 	}
-	return ""
+	return nil
 }
 
-func (x *GetSecretRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
+func (x *GetEventResponse) GetExpiredPipelineObjectEvent() *EventExpiredPipelineObject {
+	if x, ok := x.GetEvent().(*GetEventResponse_ExpiredPipelineObjectEvent); ok {
+		return x.ExpiredPipelineObjectEvent
 	}
-	return ""
+	return nil
 }
 
-func (x *GetSecretRequest) GetKey() string {
-	if x != nil {
-		return x.Key
+func (x *GetEventResponse) GetExtensionEvent() *EventExtension {
+	if x, ok := x.GetEvent().(*GetEventResponse_ExtensionEvent); ok {
+		return x.ExtensionEvent
 	}
-	return ""
+	return nil
 }
 
-type GetSecretResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+type isGetEventResponse_Event interface {
+	isGetEventResponse_Event()
+}
 
-	Content string `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
+type GetEventResponse_CreatedNamespaceEvent struct {
+	CreatedNamespaceEvent *EventCreatedNamespace `protobuf:"bytes,2,opt,name=created_namespace_event,json=createdNamespaceEvent,proto3,oneof"`
 }
 
-func (x *GetSecretResponse) Reset() {
-	*x = GetSecretResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[89]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
-	}
+type GetEventResponse_DisabledPipelineEvent struct {
+	DisabledPipelineEvent *EventDisabledPipeline `protobuf:"bytes,5,opt,name=disabled_pipeline_event,json=disabledPipelineEvent,proto3,oneof"`
 }
 
-func (x *GetSecretResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+type GetEventResponse_EnabledPipelineEvent struct {
+	EnabledPipelineEvent *EventEnabledPipeline `protobuf:"bytes,6,opt,name=enabled_pipeline_event,json=enabledPipelineEvent,proto3,oneof"`
 }
 
-func (*GetSecretResponse) ProtoMessage() {}
+type GetEventResponse_CreatedPipelineEvent struct {
+	CreatedPipelineEvent *EventCreatedPipeline `protobuf:"bytes,7,opt,name=created_pipeline_event,json=createdPipelineEvent,proto3,oneof"`
+}
 
-func (x *GetSecretResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[89]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
-	}
-	return mi.MessageOf(x)
+type GetEventResponse_AbandonedPipelineEvent struct {
+	AbandonedPipelineEvent *EventAbandonedPipeline `protobuf:"bytes,8,opt,name=abandoned_pipeline_event,json=abandonedPipelineEvent,proto3,oneof"`
 }
 
-// Deprecated: Use GetSecretResponse.ProtoReflect.Descriptor instead.
-func (*GetSecretResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{89}
+type GetEventResponse_RolledBackPipelineEvent struct {
+	RolledBackPipelineEvent *EventRolledBackPipeline `protobuf:"bytes,9,opt,name=rolled_back_pipeline_event,json=rolledBackPipelineEvent,proto3,oneof"`
 }
 
-func (x *GetSecretResponse) GetContent() string {
-	if x != nil {
-		return x.Content
-	}
-	return ""
+type GetEventResponse_StartedRunEvent struct {
+	StartedRunEvent *EventStartedRun `protobuf:"bytes,10,opt,name=started_run_event,json=startedRunEvent,proto3,oneof"`
 }
 
-type PutSecretRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
-	Content     string `protobuf:"bytes,4,opt,name=content,proto3" json:"content,omitempty"`
-	Force       bool   `protobuf:"varint,5,opt,name=force,proto3" json:"force,omitempty"` // Overwrites an already existing value.
+type GetEventResponse_CompletedRunEvent struct {
+	CompletedRunEvent *EventCompletedRun `protobuf:"bytes,11,opt,name=completed_run_event,json=completedRunEvent,proto3,oneof"`
 }
 
-func (x *PutSecretRequest) Reset() {
-	*x = PutSecretRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[90]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
-	}
+type GetEventResponse_StartedTaskRunEvent struct {
+	StartedTaskRunEvent *EventStartedTaskRun `protobuf:"bytes,15,opt,name=started_task_run_event,json=startedTaskRunEvent,proto3,oneof"`
 }
 
-func (x *PutSecretRequest) String() string {
-	return protoimpl.X.MessageStringOf(x)
+type GetEventResponse_ScheduledTaskRunEvent struct {
+	ScheduledTaskRunEvent *EventScheduledTaskRun `protobuf:"bytes,16,opt,name=scheduled_task_run_event,json=scheduledTaskRunEvent,proto3,oneof"`
 }
 
-func (*PutSecretRequest) ProtoMessage() {}
-
-func (x *PutSecretRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[90]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
-	}
-	return mi.MessageOf(x)
+type GetEventResponse_CompletedTaskRunEvent struct {
+	CompletedTaskRunEvent *EventCompletedTaskRun `protobuf:"bytes,17,opt,name=completed_task_run_event,json=completedTaskRunEvent,proto3,oneof"`
 }
 
-// Deprecated: Use PutSecretRequest.ProtoReflect.Descriptor instead.
-func (*PutSecretRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{90}
+type GetEventResponse_FiredTriggerEvent struct {
+	FiredTriggerEvent *EventFiredTrigger `protobuf:"bytes,20,opt,name=fired_trigger_event,json=firedTriggerEvent,proto3,oneof"`
 }
 
-func (x *PutSecretRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
+type GetEventResponse_ProcessedTriggerEvent struct {
+	ProcessedTriggerEvent *EventProcessedTrigger `protobuf:"bytes,21,opt,name=processed_trigger_event,json=processedTriggerEvent,proto3,oneof"`
 }
 
-func (x *PutSecretRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
-	}
-	return ""
+type GetEventResponse_ResolvedTriggerEvent struct {
+	ResolvedTriggerEvent *EventResolvedTrigger `protobuf:"bytes,22,opt,name=resolved_trigger_event,json=resolvedTriggerEvent,proto3,oneof"`
 }
 
-func (x *PutSecretRequest) GetKey() string {
-	if x != nil {
-		return x.Key
-	}
-	return ""
+type GetEventResponse_RevokedUserTokensEvent struct {
+	RevokedUserTokensEvent *EventRevokedUserTokens `protobuf:"bytes,25,opt,name=revoked_user_tokens_event,json=revokedUserTokensEvent,proto3,oneof"`
 }
 
-func (x *PutSecretRequest) GetContent() string {
-	if x != nil {
-		return x.Content
-	}
-	return ""
+type GetEventResponse_ExpiredPipelineObjectEvent struct {
+	ExpiredPipelineObjectEvent *EventExpiredPipelineObject `protobuf:"bytes,26,opt,name=expired_pipeline_object_event,json=expiredPipelineObjectEvent,proto3,oneof"`
 }
 
-func (x *PutSecretRequest) GetForce() bool {
-	if x != nil {
-		return x.Force
-	}
-	return false
+type GetEventResponse_ExtensionEvent struct {
+	ExtensionEvent *EventExtension `protobuf:"bytes,30,opt,name=extension_event,json=extensionEvent,proto3,oneof"`
 }
 
-type PutSecretResponse struct {
+func (*GetEventResponse_CreatedNamespaceEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_DisabledPipelineEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_EnabledPipelineEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_CreatedPipelineEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_AbandonedPipelineEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_RolledBackPipelineEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_StartedRunEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_CompletedRunEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_StartedTaskRunEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_ScheduledTaskRunEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_CompletedTaskRunEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_FiredTriggerEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_ProcessedTriggerEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_ResolvedTriggerEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_RevokedUserTokensEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_ExpiredPipelineObjectEvent) isGetEventResponse_Event() {}
+
+func (*GetEventResponse_ExtensionEvent) isGetEventResponse_Event() {}
+
+type ListEventsRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// The number of bytes uploaded.
-	Bytes int64 `protobuf:"varint,1,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	// defaults to false; meaning oldest to newest events by default.
+	Reverse bool `protobuf:"varint,1,opt,name=reverse,proto3" json:"reverse,omitempty"`
+	// Tell Gofer to continually stream new events instead of closing the stream
+	// after it gets to the end.
+	Follow bool `protobuf:"varint,2,opt,name=follow,proto3" json:"follow,omitempty"`
+	// Only return events with this ID or higher. Cannot be combined with reverse.
+	FromId int64 `protobuf:"varint,3,opt,name=from_id,json=fromId,proto3" json:"from_id,omitempty"`
+	// Only return events emitted at this time or later in epoch milli. Cannot be
+	// combined with reverse.
+	FromTime int64 `protobuf:"varint,4,opt,name=from_time,json=fromTime,proto3" json:"from_time,omitempty"`
+	// Return events after the last event acknowledged by this consumer. The
+	// consumer is created on first use and starts with the next event published.
+	// Cannot be combined with reverse or from_id.
+	Consumer string `protobuf:"bytes,5,opt,name=consumer,proto3" json:"consumer,omitempty"`
 }
 
-func (x *PutSecretResponse) Reset() {
-	*x = PutSecretResponse{}
+func (x *ListEventsRequest) Reset() {
+	*x = ListEventsRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[91]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -5351,13 +5362,13 @@ func (x *PutSecretResponse) Reset() {
 	}
 }
 
-func (x *PutSecretResponse) String() string {
+func (x *ListEventsRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*PutSecretResponse) ProtoMessage() {}
+func (*ListEventsRequest) ProtoMessage() {}
 
-func (x *PutSecretResponse) ProtoReflect() protoreflect.Message {
+func (x *ListEventsRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[91]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -5369,30 +5380,78 @@ func (x *PutSecretResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use PutSecretResponse.ProtoReflect.Descriptor instead.
-func (*PutSecretResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use ListEventsRequest.ProtoReflect.Descriptor instead.
+func (*ListEventsRequest) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{91}
 }
 
-func (x *PutSecretResponse) GetBytes() int64 {
+func (x *ListEventsRequest) GetReverse() bool {
 	if x != nil {
-		return x.Bytes
+		return x.Reverse
+	}
+	return false
+}
+
+func (x *ListEventsRequest) GetFollow() bool {
+	if x != nil {
+		return x.Follow
+	}
+	return false
+}
+
+func (x *ListEventsRequest) GetFromId() int64 {
+	if x != nil {
+		return x.FromId
 	}
 	return 0
 }
 
-type DeleteSecretRequest struct {
+func (x *ListEventsRequest) GetFromTime() int64 {
+	if x != nil {
+		return x.FromTime
+	}
+	return 0
+}
+
+func (x *ListEventsRequest) GetConsumer() string {
+	if x != nil {
+		return x.Consumer
+	}
+	return ""
+}
+
+type ListEventsResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+	Kind EventType `protobuf:"varint,1,opt,name=kind,proto3,enum=proto.EventType" json:"kind,omitempty"`
+	// Types that are assignable to Event:
+	//	*ListEventsResponse_CreatedNamespaceEvent
+	//	*ListEventsResponse_DisabledPipelineEvent
+	//	*ListEventsResponse_EnabledPipelineEvent
+	//	*ListEventsResponse_CreatedPipelineEvent
+	//	*ListEventsResponse_AbandonedPipelineEvent
+	//	*ListEventsResponse_RolledBackPipelineEvent
+	//	*ListEventsResponse_StartedRunEvent
+	//	*ListEventsResponse_CompletedRunEvent
+	//	*ListEventsResponse_StartedTaskRunEvent
+	//	*ListEventsResponse_ScheduledTaskRunEvent
+	//	*ListEventsResponse_CompletedTaskRunEvent
+	//	*ListEventsResponse_FiredTriggerEvent
+	//	*ListEventsResponse_ProcessedTriggerEvent
+	//	*ListEventsResponse_ResolvedTriggerEvent
+	//	*ListEventsResponse_RevokedUserTokensEvent
+	//	*ListEventsResponse_ExpiredPipelineObjectEvent
+	//	*ListEventsResponse_ExtensionEvent
+	Event isListEventsResponse_Event `protobuf_oneof:"event"`
+	// Set on messages sent only to keep an idle stream open; they carry no event
+	// and should be skipped.
+	Keepalive bool `protobuf:"varint,50,opt,name=keepalive,proto3" json:"keepalive,omitempty"`
 }
 
-func (x *DeleteSecretRequest) Reset() {
-	*x = DeleteSecretRequest{}
+func (x *ListEventsResponse) Reset() {
+	*x = ListEventsResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[92]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -5400,13 +5459,13 @@ func (x *DeleteSecretRequest) Reset() {
 	}
 }
 
-func (x *DeleteSecretRequest) String() string {
+func (x *ListEventsResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteSecretRequest) ProtoMessage() {}
+func (*ListEventsResponse) ProtoMessage() {}
 
-func (x *DeleteSecretRequest) ProtoReflect() protoreflect.Message {
+func (x *ListEventsResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[92]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -5418,214 +5477,283 @@ func (x *DeleteSecretRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteSecretRequest.ProtoReflect.Descriptor instead.
-func (*DeleteSecretRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use ListEventsResponse.ProtoReflect.Descriptor instead.
+func (*ListEventsResponse) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{92}
 }
 
-func (x *DeleteSecretRequest) GetNamespaceId() string {
+func (x *ListEventsResponse) GetKind() EventType {
 	if x != nil {
-		return x.NamespaceId
+		return x.Kind
 	}
-	return ""
+	return EventType_UNKNOWN_EVENT_TYPE
 }
 
-func (x *DeleteSecretRequest) GetPipelineId() string {
-	if x != nil {
-		return x.PipelineId
+func (m *ListEventsResponse) GetEvent() isListEventsResponse_Event {
+	if m != nil {
+		return m.Event
 	}
-	return ""
+	return nil
 }
 
-func (x *DeleteSecretRequest) GetKey() string {
-	if x != nil {
-		return x.Key
+func (x *ListEventsResponse) GetCreatedNamespaceEvent() *EventCreatedNamespace {
+	if x, ok := x.GetEvent().(*ListEventsResponse_CreatedNamespaceEvent); ok {
+		return x.CreatedNamespaceEvent
 	}
-	return ""
+	return nil
 }
 
-type DeleteSecretResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+func (x *ListEventsResponse) GetDisabledPipelineEvent() *EventDisabledPipeline {
+	if x, ok := x.GetEvent().(*ListEventsResponse_DisabledPipelineEvent); ok {
+		return x.DisabledPipelineEvent
+	}
+	return nil
 }
 
-func (x *DeleteSecretResponse) Reset() {
-	*x = DeleteSecretResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[93]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *ListEventsResponse) GetEnabledPipelineEvent() *EventEnabledPipeline {
+	if x, ok := x.GetEvent().(*ListEventsResponse_EnabledPipelineEvent); ok {
+		return x.EnabledPipelineEvent
 	}
+	return nil
 }
 
-func (x *DeleteSecretResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *ListEventsResponse) GetCreatedPipelineEvent() *EventCreatedPipeline {
+	if x, ok := x.GetEvent().(*ListEventsResponse_CreatedPipelineEvent); ok {
+		return x.CreatedPipelineEvent
+	}
+	return nil
 }
 
-func (*DeleteSecretResponse) ProtoMessage() {}
+func (x *ListEventsResponse) GetAbandonedPipelineEvent() *EventAbandonedPipeline {
+	if x, ok := x.GetEvent().(*ListEventsResponse_AbandonedPipelineEvent); ok {
+		return x.AbandonedPipelineEvent
+	}
+	return nil
+}
 
-func (x *DeleteSecretResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[93]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *ListEventsResponse) GetRolledBackPipelineEvent() *EventRolledBackPipeline {
+	if x, ok := x.GetEvent().(*ListEventsResponse_RolledBackPipelineEvent); ok {
+		return x.RolledBackPipelineEvent
 	}
-	return mi.MessageOf(x)
+	return nil
 }
 
-// Deprecated: Use DeleteSecretResponse.ProtoReflect.Descriptor instead.
-func (*DeleteSecretResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{93}
+func (x *ListEventsResponse) GetStartedRunEvent() *EventStartedRun {
+	if x, ok := x.GetEvent().(*ListEventsResponse_StartedRunEvent); ok {
+		return x.StartedRunEvent
+	}
+	return nil
 }
 
-type GetSystemInfoRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-}
-
-func (x *GetSystemInfoRequest) Reset() {
-	*x = GetSystemInfoRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[94]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *ListEventsResponse) GetCompletedRunEvent() *EventCompletedRun {
+	if x, ok := x.GetEvent().(*ListEventsResponse_CompletedRunEvent); ok {
+		return x.CompletedRunEvent
 	}
+	return nil
 }
 
-func (x *GetSystemInfoRequest) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *ListEventsResponse) GetStartedTaskRunEvent() *EventStartedTaskRun {
+	if x, ok := x.GetEvent().(*ListEventsResponse_StartedTaskRunEvent); ok {
+		return x.StartedTaskRunEvent
+	}
+	return nil
 }
 
-func (*GetSystemInfoRequest) ProtoMessage() {}
-
-func (x *GetSystemInfoRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[94]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *ListEventsResponse) GetScheduledTaskRunEvent() *EventScheduledTaskRun {
+	if x, ok := x.GetEvent().(*ListEventsResponse_ScheduledTaskRunEvent); ok {
+		return x.ScheduledTaskRunEvent
 	}
-	return mi.MessageOf(x)
+	return nil
 }
 
-// Deprecated: Use GetSystemInfoRequest.ProtoReflect.Descriptor instead.
-func (*GetSystemInfoRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{94}
+func (x *ListEventsResponse) GetCompletedTaskRunEvent() *EventCompletedTaskRun {
+	if x, ok := x.GetEvent().(*ListEventsResponse_CompletedTaskRunEvent); ok {
+		return x.CompletedTaskRunEvent
+	}
+	return nil
 }
 
-type GetSystemInfoResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	Commit                  string `protobuf:"bytes,1,opt,name=commit,proto3" json:"commit,omitempty"`
-	DevmodeEnabled          bool   `protobuf:"varint,2,opt,name=devmode_enabled,json=devmodeEnabled,proto3" json:"devmode_enabled,omitempty"`
-	FrontendEnabled         bool   `protobuf:"varint,3,opt,name=frontend_enabled,json=frontendEnabled,proto3" json:"frontend_enabled,omitempty"`
-	Version                 string `protobuf:"bytes,4,opt,name=version,proto3" json:"version,omitempty"`
-	IgnorePipelineRunEvents bool   `protobuf:"varint,5,opt,name=ignore_pipeline_run_events,json=ignorePipelineRunEvents,proto3" json:"ignore_pipeline_run_events,omitempty"`
+func (x *ListEventsResponse) GetFiredTriggerEvent() *EventFiredTrigger {
+	if x, ok := x.GetEvent().(*ListEventsResponse_FiredTriggerEvent); ok {
+		return x.FiredTriggerEvent
+	}
+	return nil
 }
 
-func (x *GetSystemInfoResponse) Reset() {
-	*x = GetSystemInfoResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[95]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *ListEventsResponse) GetProcessedTriggerEvent() *EventProcessedTrigger {
+	if x, ok := x.GetEvent().(*ListEventsResponse_ProcessedTriggerEvent); ok {
+		return x.ProcessedTriggerEvent
 	}
+	return nil
 }
 
-func (x *GetSystemInfoResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *ListEventsResponse) GetResolvedTriggerEvent() *EventResolvedTrigger {
+	if x, ok := x.GetEvent().(*ListEventsResponse_ResolvedTriggerEvent); ok {
+		return x.ResolvedTriggerEvent
+	}
+	return nil
 }
 
-func (*GetSystemInfoResponse) ProtoMessage() {}
-
-func (x *GetSystemInfoResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[95]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *ListEventsResponse) GetRevokedUserTokensEvent() *EventRevokedUserTokens {
+	if x, ok := x.GetEvent().(*ListEventsResponse_RevokedUserTokensEvent); ok {
+		return x.RevokedUserTokensEvent
 	}
-	return mi.MessageOf(x)
+	return nil
 }
 
-// Deprecated: Use GetSystemInfoResponse.ProtoReflect.Descriptor instead.
-func (*GetSystemInfoResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{95}
+func (x *ListEventsResponse) GetExpiredPipelineObjectEvent() *EventExpiredPipelineObject {
+	if x, ok := x.GetEvent().(*ListEventsResponse_ExpiredPipelineObjectEvent); ok {
+		return x.ExpiredPipelineObjectEvent
+	}
+	return nil
 }
 
-func (x *GetSystemInfoResponse) GetCommit() string {
-	if x != nil {
-		return x.Commit
+func (x *ListEventsResponse) GetExtensionEvent() *EventExtension {
+	if x, ok := x.GetEvent().(*ListEventsResponse_ExtensionEvent); ok {
+		return x.ExtensionEvent
 	}
-	return ""
+	return nil
 }
 
-func (x *GetSystemInfoResponse) GetDevmodeEnabled() bool {
+func (x *ListEventsResponse) GetKeepalive() bool {
 	if x != nil {
-		return x.DevmodeEnabled
+		return x.Keepalive
 	}
 	return false
 }
 
-func (x *GetSystemInfoResponse) GetFrontendEnabled() bool {
-	if x != nil {
-		return x.FrontendEnabled
-	}
-	return false
+type isListEventsResponse_Event interface {
+	isListEventsResponse_Event()
 }
 
-func (x *GetSystemInfoResponse) GetVersion() string {
-	if x != nil {
-		return x.Version
-	}
-	return ""
+type ListEventsResponse_CreatedNamespaceEvent struct {
+	CreatedNamespaceEvent *EventCreatedNamespace `protobuf:"bytes,2,opt,name=created_namespace_event,json=createdNamespaceEvent,proto3,oneof"`
 }
 
-func (x *GetSystemInfoResponse) GetIgnorePipelineRunEvents() bool {
-	if x != nil {
-		return x.IgnorePipelineRunEvents
-	}
-	return false
+type ListEventsResponse_DisabledPipelineEvent struct {
+	DisabledPipelineEvent *EventDisabledPipeline `protobuf:"bytes,5,opt,name=disabled_pipeline_event,json=disabledPipelineEvent,proto3,oneof"`
 }
 
-type RepairOrphanRequest struct {
+type ListEventsResponse_EnabledPipelineEvent struct {
+	EnabledPipelineEvent *EventEnabledPipeline `protobuf:"bytes,6,opt,name=enabled_pipeline_event,json=enabledPipelineEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_CreatedPipelineEvent struct {
+	CreatedPipelineEvent *EventCreatedPipeline `protobuf:"bytes,7,opt,name=created_pipeline_event,json=createdPipelineEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_AbandonedPipelineEvent struct {
+	AbandonedPipelineEvent *EventAbandonedPipeline `protobuf:"bytes,8,opt,name=abandoned_pipeline_event,json=abandonedPipelineEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_RolledBackPipelineEvent struct {
+	RolledBackPipelineEvent *EventRolledBackPipeline `protobuf:"bytes,9,opt,name=rolled_back_pipeline_event,json=rolledBackPipelineEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_StartedRunEvent struct {
+	StartedRunEvent *EventStartedRun `protobuf:"bytes,10,opt,name=started_run_event,json=startedRunEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_CompletedRunEvent struct {
+	CompletedRunEvent *EventCompletedRun `protobuf:"bytes,11,opt,name=completed_run_event,json=completedRunEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_StartedTaskRunEvent struct {
+	StartedTaskRunEvent *EventStartedTaskRun `protobuf:"bytes,15,opt,name=started_task_run_event,json=startedTaskRunEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_ScheduledTaskRunEvent struct {
+	ScheduledTaskRunEvent *EventScheduledTaskRun `protobuf:"bytes,16,opt,name=scheduled_task_run_event,json=scheduledTaskRunEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_CompletedTaskRunEvent struct {
+	CompletedTaskRunEvent *EventCompletedTaskRun `protobuf:"bytes,17,opt,name=completed_task_run_event,json=completedTaskRunEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_FiredTriggerEvent struct {
+	FiredTriggerEvent *EventFiredTrigger `protobuf:"bytes,20,opt,name=fired_trigger_event,json=firedTriggerEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_ProcessedTriggerEvent struct {
+	ProcessedTriggerEvent *EventProcessedTrigger `protobuf:"bytes,21,opt,name=processed_trigger_event,json=processedTriggerEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_ResolvedTriggerEvent struct {
+	ResolvedTriggerEvent *EventResolvedTrigger `protobuf:"bytes,22,opt,name=resolved_trigger_event,json=resolvedTriggerEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_RevokedUserTokensEvent struct {
+	RevokedUserTokensEvent *EventRevokedUserTokens `protobuf:"bytes,25,opt,name=revoked_user_tokens_event,json=revokedUserTokensEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_ExpiredPipelineObjectEvent struct {
+	ExpiredPipelineObjectEvent *EventExpiredPipelineObject `protobuf:"bytes,26,opt,name=expired_pipeline_object_event,json=expiredPipelineObjectEvent,proto3,oneof"`
+}
+
+type ListEventsResponse_ExtensionEvent struct {
+	ExtensionEvent *EventExtension `protobuf:"bytes,30,opt,name=extension_event,json=extensionEvent,proto3,oneof"`
+}
+
+func (*ListEventsResponse_CreatedNamespaceEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_DisabledPipelineEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_EnabledPipelineEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_CreatedPipelineEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_AbandonedPipelineEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_RolledBackPipelineEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_StartedRunEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_CompletedRunEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_StartedTaskRunEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_ScheduledTaskRunEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_CompletedTaskRunEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_FiredTriggerEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_ProcessedTriggerEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_ResolvedTriggerEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_RevokedUserTokensEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_ExpiredPipelineObjectEvent) isListEventsResponse_Event() {}
+
+func (*ListEventsResponse_ExtensionEvent) isListEventsResponse_Event() {}
+
+type AcknowledgeEventRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
-	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
-	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Consumer string `protobuf:"bytes,1,opt,name=consumer,proto3" json:"consumer,omitempty"`
+	Id       int64  `protobuf:"varint,2,opt,name=id,proto3" json:"id,omitempty"` // The event ID; all events up to and including it are acknowledged.
 }
 
-func (x *RepairOrphanRequest) Reset() {
-	*x = RepairOrphanRequest{}
+func (x *AcknowledgeEventRequest) Reset() {
+	*x = AcknowledgeEventRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[96]
+		mi := &file_gofer_transport_proto_msgTypes[93]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *RepairOrphanRequest) String() string {
+func (x *AcknowledgeEventRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*RepairOrphanRequest) ProtoMessage() {}
+func (*AcknowledgeEventRequest) ProtoMessage() {}
 
-func (x *RepairOrphanRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[96]
+func (x *AcknowledgeEventRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[93]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5636,55 +5764,48 @@ func (x *RepairOrphanRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use RepairOrphanRequest.ProtoReflect.Descriptor instead.
-func (*RepairOrphanRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{96}
-}
-
-func (x *RepairOrphanRequest) GetNamespaceId() string {
-	if x != nil {
-		return x.NamespaceId
-	}
-	return ""
+// Deprecated: Use AcknowledgeEventRequest.ProtoReflect.Descriptor instead.
+func (*AcknowledgeEventRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{93}
 }
 
-func (x *RepairOrphanRequest) GetPipelineId() string {
+func (x *AcknowledgeEventRequest) GetConsumer() string {
 	if x != nil {
-		return x.PipelineId
+		return x.Consumer
 	}
 	return ""
 }
 
-func (x *RepairOrphanRequest) GetRunId() int64 {
+func (x *AcknowledgeEventRequest) GetId() int64 {
 	if x != nil {
-		return x.RunId
+		return x.Id
 	}
 	return 0
 }
 
-type RepairOrphanResponse struct {
+type AcknowledgeEventResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 }
 
-func (x *RepairOrphanResponse) Reset() {
-	*x = RepairOrphanResponse{}
+func (x *AcknowledgeEventResponse) Reset() {
+	*x = AcknowledgeEventResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[97]
+		mi := &file_gofer_transport_proto_msgTypes[94]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *RepairOrphanResponse) String() string {
+func (x *AcknowledgeEventResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*RepairOrphanResponse) ProtoMessage() {}
+func (*AcknowledgeEventResponse) ProtoMessage() {}
 
-func (x *RepairOrphanResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[97]
+func (x *AcknowledgeEventResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[94]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5695,34 +5816,46 @@ func (x *RepairOrphanResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use RepairOrphanResponse.ProtoReflect.Descriptor instead.
-func (*RepairOrphanResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{97}
+// Deprecated: Use AcknowledgeEventResponse.ProtoReflect.Descriptor instead.
+func (*AcknowledgeEventResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{94}
 }
 
-type ToggleEventIngressRequest struct {
+type PublishExtensionEventRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-}
 
-func (x *ToggleEventIngressRequest) Reset() {
-	*x = ToggleEventIngressRequest{}
+	// The kind of the trigger publishing the event.
+	Extension string `protobuf:"bytes,1,opt,name=extension,proto3" json:"extension,omitempty"`
+	// What happened(ex. pull_request_opened). Gofer namespaces it with the
+	// extension's kind.
+	Kind string `protobuf:"bytes,2,opt,name=kind,proto3" json:"kind,omitempty"`
+	// A short human readable description of the event(ex. PR 123 opened).
+	Summary string            `protobuf:"bytes,3,opt,name=summary,proto3" json:"summary,omitempty"`
+	Details map[string]string `protobuf:"bytes,4,rep,name=details,proto3" json:"details,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// The namespace and pipeline the event relates to, if any.
+	NamespaceId string `protobuf:"bytes,5,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	PipelineId  string `protobuf:"bytes,6,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+}
+
+func (x *PublishExtensionEventRequest) Reset() {
+	*x = PublishExtensionEventRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[98]
+		mi := &file_gofer_transport_proto_msgTypes[95]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ToggleEventIngressRequest) String() string {
+func (x *PublishExtensionEventRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ToggleEventIngressRequest) ProtoMessage() {}
+func (*PublishExtensionEventRequest) ProtoMessage() {}
 
-func (x *ToggleEventIngressRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[98]
+func (x *PublishExtensionEventRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[95]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5733,37 +5866,78 @@ func (x *ToggleEventIngressRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ToggleEventIngressRequest.ProtoReflect.Descriptor instead.
-func (*ToggleEventIngressRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{98}
+// Deprecated: Use PublishExtensionEventRequest.ProtoReflect.Descriptor instead.
+func (*PublishExtensionEventRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{95}
 }
 
-type ToggleEventIngressResponse struct {
+func (x *PublishExtensionEventRequest) GetExtension() string {
+	if x != nil {
+		return x.Extension
+	}
+	return ""
+}
+
+func (x *PublishExtensionEventRequest) GetKind() string {
+	if x != nil {
+		return x.Kind
+	}
+	return ""
+}
+
+func (x *PublishExtensionEventRequest) GetSummary() string {
+	if x != nil {
+		return x.Summary
+	}
+	return ""
+}
+
+func (x *PublishExtensionEventRequest) GetDetails() map[string]string {
+	if x != nil {
+		return x.Details
+	}
+	return nil
+}
+
+func (x *PublishExtensionEventRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *PublishExtensionEventRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+type PublishExtensionEventResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// The current value for the boolean that controls event ingress.
-	Value bool `protobuf:"varint,1,opt,name=value,proto3" json:"value,omitempty"`
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"` // The event ID
 }
 
-func (x *ToggleEventIngressResponse) Reset() {
-	*x = ToggleEventIngressResponse{}
+func (x *PublishExtensionEventResponse) Reset() {
+	*x = PublishExtensionEventResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[99]
+		mi := &file_gofer_transport_proto_msgTypes[96]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *ToggleEventIngressResponse) String() string {
+func (x *PublishExtensionEventResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*ToggleEventIngressResponse) ProtoMessage() {}
+func (*PublishExtensionEventResponse) ProtoMessage() {}
 
-func (x *ToggleEventIngressResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[99]
+func (x *PublishExtensionEventResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[96]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5774,45 +5948,41 @@ func (x *ToggleEventIngressResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use ToggleEventIngressResponse.ProtoReflect.Descriptor instead.
-func (*ToggleEventIngressResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{99}
+// Deprecated: Use PublishExtensionEventResponse.ProtoReflect.Descriptor instead.
+func (*PublishExtensionEventResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{96}
 }
 
-func (x *ToggleEventIngressResponse) GetValue() bool {
+func (x *PublishExtensionEventResponse) GetId() int64 {
 	if x != nil {
-		return x.Value
+		return x.Id
 	}
-	return false
+	return 0
 }
 
-type CreateTokenRequest struct {
+type ListEventConsumersRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	Kind       CreateTokenRequest_Kind `protobuf:"varint,1,opt,name=kind,proto3,enum=proto.CreateTokenRequest_Kind" json:"kind,omitempty"`
-	Namespaces []string                `protobuf:"bytes,2,rep,name=namespaces,proto3" json:"namespaces,omitempty"`
-	Metadata   map[string]string       `protobuf:"bytes,3,rep,name=metadata,proto3" json:"metadata,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
 }
 
-func (x *CreateTokenRequest) Reset() {
-	*x = CreateTokenRequest{}
+func (x *ListEventConsumersRequest) Reset() {
+	*x = ListEventConsumersRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[100]
+		mi := &file_gofer_transport_proto_msgTypes[97]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CreateTokenRequest) String() string {
+func (x *ListEventConsumersRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CreateTokenRequest) ProtoMessage() {}
+func (*ListEventConsumersRequest) ProtoMessage() {}
 
-func (x *CreateTokenRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[100]
+func (x *ListEventConsumersRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[97]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5823,58 +5993,83 @@ func (x *CreateTokenRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CreateTokenRequest.ProtoReflect.Descriptor instead.
-func (*CreateTokenRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{100}
+// Deprecated: Use ListEventConsumersRequest.ProtoReflect.Descriptor instead.
+func (*ListEventConsumersRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{97}
 }
 
-func (x *CreateTokenRequest) GetKind() CreateTokenRequest_Kind {
-	if x != nil {
-		return x.Kind
+type ListEventConsumersResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Consumers []*EventConsumer `protobuf:"bytes,1,rep,name=consumers,proto3" json:"consumers,omitempty"`
+}
+
+func (x *ListEventConsumersResponse) Reset() {
+	*x = ListEventConsumersResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[98]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return CreateTokenRequest_UNKNOWN
 }
 
-func (x *CreateTokenRequest) GetNamespaces() []string {
-	if x != nil {
-		return x.Namespaces
+func (x *ListEventConsumersResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListEventConsumersResponse) ProtoMessage() {}
+
+func (x *ListEventConsumersResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[98]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return nil
+	return mi.MessageOf(x)
 }
 
-func (x *CreateTokenRequest) GetMetadata() map[string]string {
+// Deprecated: Use ListEventConsumersResponse.ProtoReflect.Descriptor instead.
+func (*ListEventConsumersResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{98}
+}
+
+func (x *ListEventConsumersResponse) GetConsumers() []*EventConsumer {
 	if x != nil {
-		return x.Metadata
+		return x.Consumers
 	}
 	return nil
 }
 
-type CreateTokenResponse struct {
+type DeleteEventConsumerRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
-	Token   string `protobuf:"bytes,2,opt,name=token,proto3" json:"token,omitempty"`
+	Name string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
 }
 
-func (x *CreateTokenResponse) Reset() {
-	*x = CreateTokenResponse{}
+func (x *DeleteEventConsumerRequest) Reset() {
+	*x = DeleteEventConsumerRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[101]
+		mi := &file_gofer_transport_proto_msgTypes[99]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CreateTokenResponse) String() string {
+func (x *DeleteEventConsumerRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CreateTokenResponse) ProtoMessage() {}
+func (*DeleteEventConsumerRequest) ProtoMessage() {}
 
-func (x *CreateTokenResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[101]
+func (x *DeleteEventConsumerRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[99]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5885,48 +6080,41 @@ func (x *CreateTokenResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CreateTokenResponse.ProtoReflect.Descriptor instead.
-func (*CreateTokenResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{101}
-}
-
-func (x *CreateTokenResponse) GetDetails() *Token {
-	if x != nil {
-		return x.Details
-	}
-	return nil
+// Deprecated: Use DeleteEventConsumerRequest.ProtoReflect.Descriptor instead.
+func (*DeleteEventConsumerRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{99}
 }
 
-func (x *CreateTokenResponse) GetToken() string {
+func (x *DeleteEventConsumerRequest) GetName() string {
 	if x != nil {
-		return x.Token
+		return x.Name
 	}
 	return ""
 }
 
-type BootstrapTokenRequest struct {
+type DeleteEventConsumerResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 }
 
-func (x *BootstrapTokenRequest) Reset() {
-	*x = BootstrapTokenRequest{}
+func (x *DeleteEventConsumerResponse) Reset() {
+	*x = DeleteEventConsumerResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[102]
+		mi := &file_gofer_transport_proto_msgTypes[100]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *BootstrapTokenRequest) String() string {
+func (x *DeleteEventConsumerResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*BootstrapTokenRequest) ProtoMessage() {}
+func (*DeleteEventConsumerResponse) ProtoMessage() {}
 
-func (x *BootstrapTokenRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[102]
+func (x *DeleteEventConsumerResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[100]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5937,37 +6125,38 @@ func (x *BootstrapTokenRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use BootstrapTokenRequest.ProtoReflect.Descriptor instead.
-func (*BootstrapTokenRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{102}
+// Deprecated: Use DeleteEventConsumerResponse.ProtoReflect.Descriptor instead.
+func (*DeleteEventConsumerResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{100}
 }
 
-type BootstrapTokenResponse struct {
+type GetPipelineObjectRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
-	Token   string `protobuf:"bytes,2,opt,name=token,proto3" json:"token,omitempty"`
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
 }
 
-func (x *BootstrapTokenResponse) Reset() {
-	*x = BootstrapTokenResponse{}
+func (x *GetPipelineObjectRequest) Reset() {
+	*x = GetPipelineObjectRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[103]
+		mi := &file_gofer_transport_proto_msgTypes[101]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *BootstrapTokenResponse) String() string {
+func (x *GetPipelineObjectRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*BootstrapTokenResponse) ProtoMessage() {}
+func (*GetPipelineObjectRequest) ProtoMessage() {}
 
-func (x *BootstrapTokenResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[103]
+func (x *GetPipelineObjectRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[101]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -5978,50 +6167,57 @@ func (x *BootstrapTokenResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use BootstrapTokenResponse.ProtoReflect.Descriptor instead.
-func (*BootstrapTokenResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{103}
+// Deprecated: Use GetPipelineObjectRequest.ProtoReflect.Descriptor instead.
+func (*GetPipelineObjectRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{101}
 }
 
-func (x *BootstrapTokenResponse) GetDetails() *Token {
+func (x *GetPipelineObjectRequest) GetNamespaceId() string {
 	if x != nil {
-		return x.Details
+		return x.NamespaceId
 	}
-	return nil
+	return ""
 }
 
-func (x *BootstrapTokenResponse) GetToken() string {
+func (x *GetPipelineObjectRequest) GetPipelineId() string {
 	if x != nil {
-		return x.Token
+		return x.PipelineId
 	}
 	return ""
 }
 
-type GetTokenRequest struct {
+func (x *GetPipelineObjectRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+type GetPipelineObjectResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Token string `protobuf:"bytes,1,opt,name=token,proto3" json:"token,omitempty"`
+	Content []byte `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
 }
 
-func (x *GetTokenRequest) Reset() {
-	*x = GetTokenRequest{}
+func (x *GetPipelineObjectResponse) Reset() {
+	*x = GetPipelineObjectResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[104]
+		mi := &file_gofer_transport_proto_msgTypes[102]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTokenRequest) String() string {
+func (x *GetPipelineObjectResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTokenRequest) ProtoMessage() {}
+func (*GetPipelineObjectResponse) ProtoMessage() {}
 
-func (x *GetTokenRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[104]
+func (x *GetPipelineObjectResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[102]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6032,43 +6228,46 @@ func (x *GetTokenRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTokenRequest.ProtoReflect.Descriptor instead.
-func (*GetTokenRequest) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{104}
+// Deprecated: Use GetPipelineObjectResponse.ProtoReflect.Descriptor instead.
+func (*GetPipelineObjectResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{102}
 }
 
-func (x *GetTokenRequest) GetToken() string {
+func (x *GetPipelineObjectResponse) GetContent() []byte {
 	if x != nil {
-		return x.Token
+		return x.Content
 	}
-	return ""
+	return nil
 }
 
-type GetTokenResponse struct {
+type GetPipelineObjectStreamRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+	Offset      int64  `protobuf:"varint,4,opt,name=offset,proto3" json:"offset,omitempty"` // The byte to start reading the object from.
 }
 
-func (x *GetTokenResponse) Reset() {
-	*x = GetTokenResponse{}
+func (x *GetPipelineObjectStreamRequest) Reset() {
+	*x = GetPipelineObjectStreamRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_gofer_transport_proto_msgTypes[105]
+		mi := &file_gofer_transport_proto_msgTypes[103]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GetTokenResponse) String() string {
+func (x *GetPipelineObjectStreamRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetTokenResponse) ProtoMessage() {}
+func (*GetPipelineObjectStreamRequest) ProtoMessage() {}
 
-func (x *GetTokenResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_gofer_transport_proto_msgTypes[105]
+func (x *GetPipelineObjectStreamRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[103]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -6079,28 +6278,159 @@ func (x *GetTokenResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetTokenResponse.ProtoReflect.Descriptor instead.
-func (*GetTokenResponse) Descriptor() ([]byte, []int) {
-	return file_gofer_transport_proto_rawDescGZIP(), []int{105}
+// Deprecated: Use GetPipelineObjectStreamRequest.ProtoReflect.Descriptor instead.
+func (*GetPipelineObjectStreamRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{103}
 }
 
-func (x *GetTokenResponse) GetDetails() *Token {
+func (x *GetPipelineObjectStreamRequest) GetNamespaceId() string {
 	if x != nil {
-		return x.Details
+		return x.NamespaceId
 	}
-	return nil
+	return ""
 }
 
-type DeleteTokenRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
+func (x *GetPipelineObjectStreamRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
 
-	Token string `protobuf:"bytes,1,opt,name=token,proto3" json:"token,omitempty"`
+func (x *GetPipelineObjectStreamRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
 }
 
-func (x *DeleteTokenRequest) Reset() {
-	*x = DeleteTokenRequest{}
+func (x *GetPipelineObjectStreamRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+type GetPipelineObjectStreamResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Content []byte `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
+	Size    int64  `protobuf:"varint,2,opt,name=size,proto3" json:"size,omitempty"` // Total size of the object; only set on the first message.
+}
+
+func (x *GetPipelineObjectStreamResponse) Reset() {
+	*x = GetPipelineObjectStreamResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[104]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetPipelineObjectStreamResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetPipelineObjectStreamResponse) ProtoMessage() {}
+
+func (x *GetPipelineObjectStreamResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[104]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetPipelineObjectStreamResponse.ProtoReflect.Descriptor instead.
+func (*GetPipelineObjectStreamResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{104}
+}
+
+func (x *GetPipelineObjectStreamResponse) GetContent() []byte {
+	if x != nil {
+		return x.Content
+	}
+	return nil
+}
+
+func (x *GetPipelineObjectStreamResponse) GetSize() int64 {
+	if x != nil {
+		return x.Size
+	}
+	return 0
+}
+
+type ListPipelineObjectRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+}
+
+func (x *ListPipelineObjectRequest) Reset() {
+	*x = ListPipelineObjectRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[105]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListPipelineObjectRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListPipelineObjectRequest) ProtoMessage() {}
+
+func (x *ListPipelineObjectRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[105]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListPipelineObjectRequest.ProtoReflect.Descriptor instead.
+func (*ListPipelineObjectRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{105}
+}
+
+func (x *ListPipelineObjectRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *ListPipelineObjectRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+type ListPipelineObjectResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Keys []string `protobuf:"bytes,1,rep,name=keys,proto3" json:"keys,omitempty"`
+}
+
+func (x *ListPipelineObjectResponse) Reset() {
+	*x = ListPipelineObjectResponse{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[106]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6108,13 +6438,13 @@ func (x *DeleteTokenRequest) Reset() {
 	}
 }
 
-func (x *DeleteTokenRequest) String() string {
+func (x *ListPipelineObjectResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteTokenRequest) ProtoMessage() {}
+func (*ListPipelineObjectResponse) ProtoMessage() {}
 
-func (x *DeleteTokenRequest) ProtoReflect() protoreflect.Message {
+func (x *ListPipelineObjectResponse) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[106]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6126,26 +6456,35 @@ func (x *DeleteTokenRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteTokenRequest.ProtoReflect.Descriptor instead.
-func (*DeleteTokenRequest) Descriptor() ([]byte, []int) {
+// Deprecated: Use ListPipelineObjectResponse.ProtoReflect.Descriptor instead.
+func (*ListPipelineObjectResponse) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{106}
 }
 
-func (x *DeleteTokenRequest) GetToken() string {
+func (x *ListPipelineObjectResponse) GetKeys() []string {
 	if x != nil {
-		return x.Token
+		return x.Keys
 	}
-	return ""
+	return nil
 }
 
-type DeleteTokenResponse struct {
+type PutPipelineObjectRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+	Content     []byte `protobuf:"bytes,4,opt,name=content,proto3" json:"content,omitempty"`
+	Force       bool   `protobuf:"varint,5,opt,name=force,proto3" json:"force,omitempty"` // Overwrites an already existing value.
+	// Seconds until the object is removed. 0 uses the namespace's object expiry
+	// and a negative value keeps the object until it is evicted.
+	ExpiresAfter int64 `protobuf:"varint,6,opt,name=expires_after,json=expiresAfter,proto3" json:"expires_after,omitempty"`
 }
 
-func (x *DeleteTokenResponse) Reset() {
-	*x = DeleteTokenResponse{}
+func (x *PutPipelineObjectRequest) Reset() {
+	*x = PutPipelineObjectRequest{}
 	if protoimpl.UnsafeEnabled {
 		mi := &file_gofer_transport_proto_msgTypes[107]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6153,13 +6492,13 @@ func (x *DeleteTokenResponse) Reset() {
 	}
 }
 
-func (x *DeleteTokenResponse) String() string {
+func (x *PutPipelineObjectRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*DeleteTokenResponse) ProtoMessage() {}
+func (*PutPipelineObjectRequest) ProtoMessage() {}
 
-func (x *DeleteTokenResponse) ProtoReflect() protoreflect.Message {
+func (x *PutPipelineObjectRequest) ProtoReflect() protoreflect.Message {
 	mi := &file_gofer_transport_proto_msgTypes[107]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
@@ -6171,902 +6510,8373 @@ func (x *DeleteTokenResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use DeleteTokenResponse.ProtoReflect.Descriptor instead.
-func (*DeleteTokenResponse) Descriptor() ([]byte, []int) {
+// Deprecated: Use PutPipelineObjectRequest.ProtoReflect.Descriptor instead.
+func (*PutPipelineObjectRequest) Descriptor() ([]byte, []int) {
 	return file_gofer_transport_proto_rawDescGZIP(), []int{107}
 }
 
-var File_gofer_transport_proto protoreflect.FileDescriptor
+func (x *PutPipelineObjectRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
 
-var file_gofer_transport_proto_rawDesc = []byte{
-	0x0a, 0x15, 0x67, 0x6f, 0x66, 0x65, 0x72, 0x5f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72,
-	0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x1a,
-	0x67, 0x6f, 0x66, 0x65, 0x72, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x65, 0x76,
-	0x65, 0x6e, 0x74, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x13, 0x67, 0x6f, 0x66, 0x65,
-	0x72, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22,
-	0x25, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52,
-	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x46, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d,
-	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2e,
-	0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
-	0x61, 0x63, 0x65, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x22, 0x45,
-	0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65,
-	0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12,
-	0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05,
-	0x6c, 0x69, 0x6d, 0x69, 0x74, 0x22, 0x62, 0x0a, 0x16, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x61, 0x6d,
-	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-	0x30, 0x0a, 0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x18, 0x01, 0x20,
-	0x03, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
-	0x73, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x5e, 0x0a, 0x16, 0x43, 0x72, 0x65,
-	0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72,
-	0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64, 0x65,
-	0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x49, 0x0a, 0x17, 0x43, 0x72, 0x65,
-	0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2e, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x22, 0x5e, 0x0a, 0x16, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4e, 0x61,
-	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e,
-	0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x12,
-	0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61,
-	0x6d, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f,
-	0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70,
-	0x74, 0x69, 0x6f, 0x6e, 0x22, 0x49, 0x0a, 0x17, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4e, 0x61,
-	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-	0x2e, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x22,
-	0x28, 0x0a, 0x16, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
-	0x63, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x19, 0x0a, 0x17, 0x44, 0x65, 0x6c,
-	0x65, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x47, 0x0a, 0x12, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c,
-	0x69, 0x6e, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61,
-	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a,
-	0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x42, 0x0a,
-	0x13, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x22, 0x67, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66,
-	0x73, 0x65, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65,
-	0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03,
-	0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x22, 0x5e, 0x0a, 0x15, 0x4c, 0x69,
-	0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x2d, 0x0a, 0x09, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73,
-	0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x09, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x4b, 0x0a, 0x16, 0x44, 0x69,
-	0x73, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x19, 0x0a, 0x17, 0x44, 0x69, 0x73, 0x61, 0x62,
-	0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x22, 0x4a, 0x0a, 0x15, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e,
-	0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x18,
-	0x0a, 0x16, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x48, 0x0a, 0x18, 0x43, 0x72, 0x65, 0x61,
-	0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x12,
-	0x0a, 0x04, 0x70, 0x61, 0x74, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x70, 0x61,
-	0x74, 0x68, 0x22, 0x48, 0x0a, 0x19, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-	0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x2e, 0x0a, 0x1a,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79,
-	0x55, 0x52, 0x4c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x75, 0x72,
-	0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x75, 0x72, 0x6c, 0x22, 0x4a, 0x0a, 0x1b,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79,
-	0x55, 0x52, 0x4c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08,
-	0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x7b, 0x0a, 0x18, 0x55, 0x70, 0x64, 0x61,
-	0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65,
-	0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
-	0x74, 0x12, 0x12, 0x0a, 0x04, 0x70, 0x61, 0x74, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x04, 0x70, 0x61, 0x74, 0x68, 0x22, 0x48, 0x0a, 0x19, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70,
-	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22,
-	0x61, 0x0a, 0x1a, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a,
-	0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64,
-	0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64,
-	0x12, 0x10, 0x0a, 0x03, 0x75, 0x72, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x75,
-	0x72, 0x6c, 0x22, 0x4a, 0x0a, 0x1b, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x4b,
-	0x0a, 0x16, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
-	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69,
-	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x19, 0x0a, 0x17, 0x41,
-	0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x63, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69,
-	0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x2e, 0x0a, 0x0e, 0x47,
-	0x65, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1c, 0x0a,
-	0x03, 0x72, 0x75, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x2e, 0x52, 0x75, 0x6e, 0x52, 0x03, 0x72, 0x75, 0x6e, 0x22, 0x6b, 0x0a, 0x13, 0x42,
-	0x61, 0x74, 0x63, 0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f,
-	0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
-	0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x03, 0x20,
-	0x03, 0x28, 0x03, 0x52, 0x03, 0x69, 0x64, 0x73, 0x22, 0x36, 0x0a, 0x14, 0x42, 0x61, 0x74, 0x63,
-	0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x1e, 0x0a, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x75, 0x6e, 0x52, 0x04, 0x72, 0x75, 0x6e, 0x73,
-	0x22, 0x83, 0x01, 0x0a, 0x0f, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05,
-	0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d,
-	0x69, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f,
-	0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
-	0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x22, 0x32, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75,
-	0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1e, 0x0a, 0x04, 0x72, 0x75,
-	0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x52, 0x75, 0x6e, 0x52, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x22, 0xec, 0x01, 0x0a, 0x0f, 0x53,
-	0x74, 0x61, 0x72, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
-	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
-	0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x6f, 0x6e, 0x6c, 0x79, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09,
-	0x52, 0x04, 0x6f, 0x6e, 0x6c, 0x79, 0x12, 0x43, 0x0a, 0x09, 0x76, 0x61, 0x72, 0x69, 0x61, 0x62,
-	0x6c, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x2e, 0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79,
-	0x52, 0x09, 0x76, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x1a, 0x3c, 0x0a, 0x0e, 0x56,
-	0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a,
-	0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12,
-	0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05,
-	0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x30, 0x0a, 0x10, 0x53, 0x74, 0x61,
-	0x72, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1c, 0x0a,
-	0x03, 0x72, 0x75, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x2e, 0x52, 0x75, 0x6e, 0x52, 0x03, 0x72, 0x75, 0x6e, 0x22, 0x65, 0x0a, 0x0f, 0x52,
-	0x65, 0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
-	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
-	0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02,
-	0x69, 0x64, 0x22, 0x30, 0x0a, 0x10, 0x52, 0x65, 0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1c, 0x0a, 0x03, 0x72, 0x75, 0x6e, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x75, 0x6e, 0x52,
-	0x03, 0x72, 0x75, 0x6e, 0x22, 0x7c, 0x0a, 0x10, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75,
-	0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
-	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02,
-	0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x14, 0x0a, 0x05,
-	0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72,
-	0x63, 0x65, 0x22, 0x13, 0x0a, 0x11, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x52,
-	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x70, 0x0a, 0x14, 0x43, 0x61, 0x6e, 0x63, 0x65,
-	0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
-	0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
-	0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69,
-	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x49, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01,
-	0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x22, 0x2b, 0x0a, 0x15, 0x43, 0x61, 0x6e,
-	0x63, 0x65, 0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x03,
-	0x52, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x22, 0x70, 0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61,
-	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a,
-	0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64,
-	0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18,
-	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49,
-	0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28,
-	0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x22, 0x43, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74,
-	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x2b, 0x0a, 0x09, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x73, 0x18, 0x01, 0x20,
-	0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x61, 0x73, 0x6b,
-	0x52, 0x75, 0x6e, 0x52, 0x08, 0x74, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x22, 0x7e, 0x0a,
-	0x11, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f,
-	0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
-	0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64,
-	0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x0e, 0x0a,
-	0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x3f, 0x0a,
-	0x12, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x29, 0x0a, 0x08, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x61,
-	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x07, 0x74, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x22, 0x97,
-	0x01, 0x0a, 0x14, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72,
-	0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e,
-	0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02,
-	0x69, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28,
-	0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x22, 0x17, 0x0a, 0x15, 0x43, 0x61, 0x6e, 0x63,
-	0x65, 0x6c, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x22, 0x82, 0x01, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e,
-	0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f,
-	0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12,
-	0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52,
-	0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x4e, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73,
-	0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x12, 0x19, 0x0a, 0x08, 0x6c, 0x6f, 0x67, 0x5f, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x07, 0x6c, 0x6f, 0x67, 0x4c, 0x69, 0x6e, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x6c,
-	0x69, 0x6e, 0x65, 0x5f, 0x6e, 0x75, 0x6d, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x6c,
-	0x69, 0x6e, 0x65, 0x4e, 0x75, 0x6d, 0x22, 0x85, 0x01, 0x0a, 0x18, 0x44, 0x65, 0x6c, 0x65, 0x74,
-	0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
-	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70,
-	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69,
-	0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x0e,
-	0x0a, 0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1b,
-	0x0a, 0x19, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c,
-	0x6f, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x27, 0x0a, 0x11, 0x47,
-	0x65, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04,
-	0x6b, 0x69, 0x6e, 0x64, 0x22, 0x3e, 0x0a, 0x12, 0x47, 0x65, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67,
-	0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x28, 0x0a, 0x07, 0x74, 0x72,
-	0x69, 0x67, 0x67, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72,
-	0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x07, 0x74, 0x72, 0x69,
-	0x67, 0x67, 0x65, 0x72, 0x22, 0x15, 0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x72, 0x69, 0x67,
-	0x67, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x42, 0x0a, 0x14, 0x4c,
-	0x69, 0x73, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x2a, 0x0a, 0x08, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x18,
-	0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x72,
-	0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x08, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x22,
-	0x47, 0x0a, 0x15, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65,
-	0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x2e, 0x0a, 0x07, 0x74, 0x72, 0x69, 0x67,
-	0x67, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x2e, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52,
-	0x07, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x22, 0x18, 0x0a, 0x16, 0x49, 0x6e, 0x73, 0x74,
-	0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x22, 0x2d, 0x0a, 0x17, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54,
-	0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a,
-	0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e,
-	0x64, 0x22, 0x1a, 0x0a, 0x18, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72,
-	0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x28, 0x0a,
-	0x12, 0x47, 0x65, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0x42, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x4e, 0x6f,
-	0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b,
-	0x0a, 0x08, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65,
-	0x72, 0x52, 0x08, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x22, 0x16, 0x0a, 0x14, 0x4c,
-	0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x22, 0x46, 0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66,
-	0x69, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2d, 0x0a, 0x09,
-	0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
-	0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
-	0x52, 0x09, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x73, 0x22, 0x4b, 0x0a, 0x16, 0x49,
-	0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65,
-	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x31, 0x0a, 0x08, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65,
-	0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x08,
-	0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x22, 0x19, 0x0a, 0x17, 0x49, 0x6e, 0x73, 0x74,
-	0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x22, 0x2e, 0x0a, 0x18, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c,
-	0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
-	0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b,
-	0x69, 0x6e, 0x64, 0x22, 0x1b, 0x0a, 0x19, 0x55, 0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c,
-	0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x22, 0x21, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52,
-	0x02, 0x69, 0x64, 0x22, 0x86, 0x09, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x24, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45,
-	0x76, 0x65, 0x6e, 0x74, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x56,
-	0x0a, 0x17, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
-	0x61, 0x63, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
-	0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x72, 0x65,
-	0x61, 0x74, 0x65, 0x64, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x48, 0x00, 0x52,
-	0x15, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56, 0x0a, 0x17, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c,
-	0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e,
-	0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70,
-	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x15, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65,
-	0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x53,
-	0x0a, 0x16, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x61, 0x62,
-	0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x14, 0x65,
-	0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x12, 0x53, 0x0a, 0x16, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x70,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x07, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e,
-	0x74, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x48, 0x00, 0x52, 0x14, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c,
-	0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x59, 0x0a, 0x18, 0x61, 0x62, 0x61, 0x6e,
-	0x64, 0x6f, 0x6e, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65,
-	0x76, 0x65, 0x6e, 0x74, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65,
-	0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x16, 0x61, 0x62, 0x61,
-	0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x12, 0x44, 0x0a, 0x11, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x72,
-	0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x16,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x74, 0x61, 0x72,
-	0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x0f, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65,
-	0x64, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x4a, 0x0a, 0x13, 0x63, 0x6f, 0x6d,
-	0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74,
-	0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45,
-	0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e,
-	0x48, 0x00, 0x52, 0x11, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x51, 0x0a, 0x16, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64,
-	0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18,
-	0x0f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75,
-	0x6e, 0x48, 0x00, 0x52, 0x13, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b,
-	0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x57, 0x0a, 0x18, 0x73, 0x63, 0x68, 0x65,
-	0x64, 0x75, 0x6c, 0x65, 0x64, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65,
-	0x76, 0x65, 0x6e, 0x74, 0x18, 0x10, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65,
-	0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x15, 0x73, 0x63, 0x68, 0x65,
-	0x64, 0x75, 0x6c, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e,
-	0x74, 0x12, 0x57, 0x0a, 0x18, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x74,
-	0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x11, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e,
-	0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75,
-	0x6e, 0x48, 0x00, 0x52, 0x15, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x54, 0x61,
-	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x4a, 0x0a, 0x13, 0x66, 0x69,
-	0x72, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e,
-	0x74, 0x18, 0x14, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x46, 0x69, 0x72, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65,
-	0x72, 0x48, 0x00, 0x52, 0x11, 0x66, 0x69, 0x72, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65,
-	0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56, 0x0a, 0x17, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73,
-	0x73, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e,
-	0x74, 0x18, 0x15, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x65, 0x64, 0x54, 0x72,
-	0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x15, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73,
-	0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x53,
-	0x0a, 0x16, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67,
-	0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x16, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x6f,
-	0x6c, 0x76, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x14, 0x72,
-	0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x42, 0x07, 0x0a, 0x05, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x22, 0x45, 0x0a, 0x11,
-	0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x12, 0x18, 0x0a, 0x07, 0x72, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x08, 0x52, 0x07, 0x72, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x66,
-	0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x66, 0x6f, 0x6c,
-	0x6c, 0x6f, 0x77, 0x22, 0x88, 0x09, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e,
-	0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x24, 0x0a, 0x04, 0x6b, 0x69,
-	0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64,
-	0x12, 0x56, 0x0a, 0x17, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x6e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43,
-	0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x48,
-	0x00, 0x52, 0x15, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
-	0x61, 0x63, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56, 0x0a, 0x17, 0x64, 0x69, 0x73, 0x61,
-	0x62, 0x6c, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76,
-	0x65, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x15, 0x64, 0x69, 0x73, 0x61, 0x62,
-	0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74,
-	0x12, 0x53, 0x0a, 0x16, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e,
-	0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52,
-	0x14, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x53, 0x0a, 0x16, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
-	0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18,
-	0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x48, 0x00, 0x52, 0x14, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x50, 0x69, 0x70,
-	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x59, 0x0a, 0x18, 0x61, 0x62,
-	0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f,
-	0x6e, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x16, 0x61,
-	0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x44, 0x0a, 0x11, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64,
-	0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x74,
-	0x61, 0x72, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x0f, 0x73, 0x74, 0x61, 0x72,
-	0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x4a, 0x0a, 0x13, 0x63,
-	0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65,
-	0x6e, 0x74, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-	0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x52,
-	0x75, 0x6e, 0x48, 0x00, 0x52, 0x11, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x52,
-	0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x51, 0x0a, 0x16, 0x73, 0x74, 0x61, 0x72, 0x74,
-	0x65, 0x64, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e,
-	0x74, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b,
-	0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x13, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x54, 0x61,
-	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x57, 0x0a, 0x18, 0x73, 0x63,
-	0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e,
-	0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x10, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75,
-	0x6c, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x15, 0x73, 0x63,
-	0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x12, 0x57, 0x0a, 0x18, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64,
-	0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18,
-	0x11, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76,
-	0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b,
-	0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x15, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64,
-	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x4a, 0x0a, 0x13,
-	0x66, 0x69, 0x72, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76,
-	0x65, 0x6e, 0x74, 0x18, 0x14, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x46, 0x69, 0x72, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67,
-	0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x11, 0x66, 0x69, 0x72, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67,
-	0x67, 0x65, 0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56, 0x0a, 0x17, 0x70, 0x72, 0x6f, 0x63,
-	0x65, 0x73, 0x73, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76,
-	0x65, 0x6e, 0x74, 0x18, 0x15, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x65, 0x64,
-	0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x15, 0x70, 0x72, 0x6f, 0x63, 0x65,
-	0x73, 0x73, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x45, 0x76, 0x65, 0x6e, 0x74,
-	0x12, 0x53, 0x0a, 0x16, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69,
-	0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x16, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65,
-	0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52,
-	0x14, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72,
-	0x45, 0x76, 0x65, 0x6e, 0x74, 0x42, 0x07, 0x0a, 0x05, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x22, 0x70,
-	0x0a, 0x18, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a,
-	0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61,
-	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a,
-	0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10,
-	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
-	0x22, 0x35, 0x0a, 0x19, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f,
-	0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a,
-	0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
-	0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x22, 0x5f, 0x0a, 0x19, 0x4c, 0x69, 0x73, 0x74, 0x50,
-	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c,
-	0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x22, 0x30, 0x0a, 0x1a, 0x4c, 0x69, 0x73, 0x74,
-	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x65, 0x79, 0x73, 0x18, 0x01,
-	0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x65, 0x79, 0x73, 0x22, 0xa0, 0x01, 0x0a, 0x18, 0x50,
-	0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b,
-	0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x18, 0x0a,
-	0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
-	0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65,
-	0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x22, 0x7b, 0x0a,
-	0x19, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65,
-	0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79,
-	0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73,
-	0x12, 0x21, 0x0a, 0x0c, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0b, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x4c, 0x69,
-	0x6d, 0x69, 0x74, 0x12, 0x25, 0x0a, 0x0e, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5f, 0x65, 0x76,
-	0x69, 0x63, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x6f, 0x62, 0x6a,
-	0x65, 0x63, 0x74, 0x45, 0x76, 0x69, 0x63, 0x74, 0x65, 0x64, 0x22, 0x73, 0x0a, 0x1b, 0x44, 0x65,
-	0x6c, 0x65, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65,
-	0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d,
-	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b,
-	0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a,
-	0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22,
-	0x1e, 0x0a, 0x1c, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
-	0x82, 0x01, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73,
-	0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72,
-	0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e,
-	0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x03, 0x6b, 0x65, 0x79, 0x22, 0x30, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62,
-	0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07,
-	0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63,
-	0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x22, 0x71, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75,
-	0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
-	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
-	0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
-	0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x22, 0x2b, 0x0a, 0x15, 0x4c, 0x69, 0x73,
-	0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x65, 0x79, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09,
-	0x52, 0x04, 0x6b, 0x65, 0x79, 0x73, 0x22, 0xb2, 0x01, 0x0a, 0x13, 0x50, 0x75, 0x74, 0x52, 0x75,
-	0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
-	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
-	0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
-	0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
-	0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79,
-	0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x63,
-	0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f,
-	0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x06,
-	0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x22, 0x2c, 0x0a, 0x14, 0x50,
-	0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x03, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x22, 0x85, 0x01, 0x0a, 0x16, 0x44, 0x65,
-	0x6c, 0x65, 0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71,
-	0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
-	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c,
-	0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69,
-	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f,
-	0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12,
-	0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65,
-	0x79, 0x22, 0x19, 0x0a, 0x17, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62,
-	0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x68, 0x0a, 0x10,
-	0x47, 0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
-	0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f,
-	0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
-	0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x2d, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x53, 0x65, 0x63,
-	0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x63,
-	0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f,
-	0x6e, 0x74, 0x65, 0x6e, 0x74, 0x22, 0x98, 0x01, 0x0a, 0x10, 0x50, 0x75, 0x74, 0x53, 0x65, 0x63,
-	0x72, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61,
-	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-	0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a,
-	0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10,
-	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
-	0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28,
-	0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f,
-	0x72, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65,
-	0x22, 0x29, 0x0a, 0x11, 0x50, 0x75, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x73,
-	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x22, 0x6b, 0x0a, 0x13, 0x44,
-	0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f,
-	0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
-	0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-	0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65,
-	0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20,
-	0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x16, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65,
-	0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-	0x22, 0x16, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e, 0x66,
-	0x6f, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0xda, 0x01, 0x0a, 0x15, 0x47, 0x65, 0x74,
-	0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x12, 0x27, 0x0a, 0x0f, 0x64, 0x65,
-	0x76, 0x6d, 0x6f, 0x64, 0x65, 0x5f, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x18, 0x02, 0x20,
-	0x01, 0x28, 0x08, 0x52, 0x0e, 0x64, 0x65, 0x76, 0x6d, 0x6f, 0x64, 0x65, 0x45, 0x6e, 0x61, 0x62,
-	0x6c, 0x65, 0x64, 0x12, 0x29, 0x0a, 0x10, 0x66, 0x72, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x64, 0x5f,
-	0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0f, 0x66,
-	0x72, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x64, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x12, 0x18,
-	0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x3b, 0x0a, 0x1a, 0x69, 0x67, 0x6e, 0x6f,
-	0x72, 0x65, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x72, 0x75, 0x6e, 0x5f,
-	0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x17, 0x69, 0x67,
-	0x6e, 0x6f, 0x72, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x75, 0x6e, 0x45,
-	0x76, 0x65, 0x6e, 0x74, 0x73, 0x22, 0x70, 0x0a, 0x13, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f,
-	0x72, 0x70, 0x68, 0x61, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c,
-	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12,
-	0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02,
-	0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64,
-	0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03,
-	0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x22, 0x16, 0x0a, 0x14, 0x52, 0x65, 0x70, 0x61, 0x69,
-	0x72, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22,
-	0x1b, 0x0a, 0x19, 0x54, 0x6f, 0x67, 0x67, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e,
-	0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x32, 0x0a, 0x1a,
-	0x54, 0x6f, 0x67, 0x67, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65,
-	0x73, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61,
-	0x6c, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65,
-	0x22, 0x9b, 0x02, 0x0a, 0x12, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x32, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72,
-	0x65, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x2e, 0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1e, 0x0a, 0x0a, 0x6e,
-	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52,
-	0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x12, 0x43, 0x0a, 0x08, 0x6d,
-	0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65,
-	0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74,
-	0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
-	0x1a, 0x3b, 0x0a, 0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72,
-	0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03,
-	0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01,
-	0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x2f, 0x0a,
-	0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e,
-	0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x4d, 0x41, 0x4e, 0x41, 0x47, 0x45, 0x4d, 0x45, 0x4e, 0x54,
-	0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x43, 0x4c, 0x49, 0x45, 0x4e, 0x54, 0x10, 0x02, 0x22, 0x53,
-	0x0a, 0x13, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73,
-	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26, 0x0a, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54,
-	0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x12, 0x14, 0x0a,
-	0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f,
-	0x6b, 0x65, 0x6e, 0x22, 0x17, 0x0a, 0x15, 0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70,
-	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x56, 0x0a, 0x16,
-	0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26, 0x0a, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c,
-	0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
-	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x12, 0x14,
-	0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74,
-	0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x27, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x3a, 0x0a,
-	0x10, 0x47, 0x65, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x12, 0x26, 0x0a, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
-	0x52, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x22, 0x2a, 0x0a, 0x12, 0x44, 0x65, 0x6c,
-	0x65, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
-	0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05,
-	0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x15, 0x0a, 0x13, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54,
-	0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x26, 0x5a, 0x24,
-	0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x63, 0x6c, 0x69, 0x6e, 0x74,
-	0x6a, 0x65, 0x64, 0x77, 0x61, 0x72, 0x64, 0x73, 0x2f, 0x67, 0x6f, 0x66, 0x65, 0x72, 0x2f, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+func (x *PutPipelineObjectRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
 }
 
-var (
-	file_gofer_transport_proto_rawDescOnce sync.Once
-	file_gofer_transport_proto_rawDescData = file_gofer_transport_proto_rawDesc
-)
+func (x *PutPipelineObjectRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
 
-func file_gofer_transport_proto_rawDescGZIP() []byte {
-	file_gofer_transport_proto_rawDescOnce.Do(func() {
-		file_gofer_transport_proto_rawDescData = protoimpl.X.CompressGZIP(file_gofer_transport_proto_rawDescData)
-	})
-	return file_gofer_transport_proto_rawDescData
+func (x *PutPipelineObjectRequest) GetContent() []byte {
+	if x != nil {
+		return x.Content
+	}
+	return nil
 }
 
-var file_gofer_transport_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
-var file_gofer_transport_proto_msgTypes = make([]protoimpl.MessageInfo, 110)
-var file_gofer_transport_proto_goTypes = []interface{}{
-	(CreateTokenRequest_Kind)(0),         // 0: proto.CreateTokenRequest.Kind
-	(*GetNamespaceRequest)(nil),          // 1: proto.GetNamespaceRequest
-	(*GetNamespaceResponse)(nil),         // 2: proto.GetNamespaceResponse
-	(*ListNamespacesRequest)(nil),        // 3: proto.ListNamespacesRequest
-	(*ListNamespacesResponse)(nil),       // 4: proto.ListNamespacesResponse
-	(*CreateNamespaceRequest)(nil),       // 5: proto.CreateNamespaceRequest
-	(*CreateNamespaceResponse)(nil),      // 6: proto.CreateNamespaceResponse
-	(*UpdateNamespaceRequest)(nil),       // 7: proto.UpdateNamespaceRequest
-	(*UpdateNamespaceResponse)(nil),      // 8: proto.UpdateNamespaceResponse
-	(*DeleteNamespaceRequest)(nil),       // 9: proto.DeleteNamespaceRequest
-	(*DeleteNamespaceResponse)(nil),      // 10: proto.DeleteNamespaceResponse
-	(*GetPipelineRequest)(nil),           // 11: proto.GetPipelineRequest
-	(*GetPipelineResponse)(nil),          // 12: proto.GetPipelineResponse
-	(*ListPipelinesRequest)(nil),         // 13: proto.ListPipelinesRequest
-	(*ListPipelinesResponse)(nil),        // 14: proto.ListPipelinesResponse
-	(*DisablePipelineRequest)(nil),       // 15: proto.DisablePipelineRequest
-	(*DisablePipelineResponse)(nil),      // 16: proto.DisablePipelineResponse
-	(*EnablePipelineRequest)(nil),        // 17: proto.EnablePipelineRequest
-	(*EnablePipelineResponse)(nil),       // 18: proto.EnablePipelineResponse
-	(*CreatePipelineRawRequest)(nil),     // 19: proto.CreatePipelineRawRequest
-	(*CreatePipelineRawResponse)(nil),    // 20: proto.CreatePipelineRawResponse
-	(*CreatePipelineByURLRequest)(nil),   // 21: proto.CreatePipelineByURLRequest
-	(*CreatePipelineByURLResponse)(nil),  // 22: proto.CreatePipelineByURLResponse
-	(*UpdatePipelineRawRequest)(nil),     // 23: proto.UpdatePipelineRawRequest
-	(*UpdatePipelineRawResponse)(nil),    // 24: proto.UpdatePipelineRawResponse
-	(*UpdatePipelineByURLRequest)(nil),   // 25: proto.UpdatePipelineByURLRequest
-	(*UpdatePipelineByURLResponse)(nil),  // 26: proto.UpdatePipelineByURLResponse
-	(*AbandonPipelineRequest)(nil),       // 27: proto.AbandonPipelineRequest
-	(*AbandonPipelineResponse)(nil),      // 28: proto.AbandonPipelineResponse
-	(*GetRunRequest)(nil),                // 29: proto.GetRunRequest
-	(*GetRunResponse)(nil),               // 30: proto.GetRunResponse
-	(*BatchGetRunsRequest)(nil),          // 31: proto.BatchGetRunsRequest
-	(*BatchGetRunsResponse)(nil),         // 32: proto.BatchGetRunsResponse
-	(*ListRunsRequest)(nil),              // 33: proto.ListRunsRequest
-	(*ListRunsResponse)(nil),             // 34: proto.ListRunsResponse
-	(*StartRunRequest)(nil),              // 35: proto.StartRunRequest
-	(*StartRunResponse)(nil),             // 36: proto.StartRunResponse
-	(*RetryRunRequest)(nil),              // 37: proto.RetryRunRequest
-	(*RetryRunResponse)(nil),             // 38: proto.RetryRunResponse
-	(*CancelRunRequest)(nil),             // 39: proto.CancelRunRequest
-	(*CancelRunResponse)(nil),            // 40: proto.CancelRunResponse
-	(*CancelAllRunsRequest)(nil),         // 41: proto.CancelAllRunsRequest
-	(*CancelAllRunsResponse)(nil),        // 42: proto.CancelAllRunsResponse
-	(*ListTaskRunsRequest)(nil),          // 43: proto.ListTaskRunsRequest
-	(*ListTaskRunsResponse)(nil),         // 44: proto.ListTaskRunsResponse
-	(*GetTaskRunRequest)(nil),            // 45: proto.GetTaskRunRequest
-	(*GetTaskRunResponse)(nil),           // 46: proto.GetTaskRunResponse
-	(*CancelTaskRunRequest)(nil),         // 47: proto.CancelTaskRunRequest
-	(*CancelTaskRunResponse)(nil),        // 48: proto.CancelTaskRunResponse
-	(*GetTaskRunLogsRequest)(nil),        // 49: proto.GetTaskRunLogsRequest
-	(*GetTaskRunLogsResponse)(nil),       // 50: proto.GetTaskRunLogsResponse
-	(*DeleteTaskRunLogsRequest)(nil),     // 51: proto.DeleteTaskRunLogsRequest
-	(*DeleteTaskRunLogsResponse)(nil),    // 52: proto.DeleteTaskRunLogsResponse
-	(*GetTriggerRequest)(nil),            // 53: proto.GetTriggerRequest
-	(*GetTriggerResponse)(nil),           // 54: proto.GetTriggerResponse
-	(*ListTriggersRequest)(nil),          // 55: proto.ListTriggersRequest
-	(*ListTriggersResponse)(nil),         // 56: proto.ListTriggersResponse
-	(*InstallTriggerRequest)(nil),        // 57: proto.InstallTriggerRequest
-	(*InstallTriggerResponse)(nil),       // 58: proto.InstallTriggerResponse
-	(*UninstallTriggerRequest)(nil),      // 59: proto.UninstallTriggerRequest
-	(*UninstallTriggerResponse)(nil),     // 60: proto.UninstallTriggerResponse
-	(*GetNotifierRequest)(nil),           // 61: proto.GetNotifierRequest
-	(*GetNotifierResponse)(nil),          // 62: proto.GetNotifierResponse
-	(*ListNotifiersRequest)(nil),         // 63: proto.ListNotifiersRequest
-	(*ListNotifiersResponse)(nil),        // 64: proto.ListNotifiersResponse
-	(*InstallNotifierRequest)(nil),       // 65: proto.InstallNotifierRequest
-	(*InstallNotifierResponse)(nil),      // 66: proto.InstallNotifierResponse
-	(*UninstallNotifierRequest)(nil),     // 67: proto.UninstallNotifierRequest
-	(*UninstallNotifierResponse)(nil),    // 68: proto.UninstallNotifierResponse
-	(*GetEventRequest)(nil),              // 69: proto.GetEventRequest
-	(*GetEventResponse)(nil),             // 70: proto.GetEventResponse
-	(*ListEventsRequest)(nil),            // 71: proto.ListEventsRequest
-	(*ListEventsResponse)(nil),           // 72: proto.ListEventsResponse
-	(*GetPipelineObjectRequest)(nil),     // 73: proto.GetPipelineObjectRequest
-	(*GetPipelineObjectResponse)(nil),    // 74: proto.GetPipelineObjectResponse
-	(*ListPipelineObjectRequest)(nil),    // 75: proto.ListPipelineObjectRequest
-	(*ListPipelineObjectResponse)(nil),   // 76: proto.ListPipelineObjectResponse
-	(*PutPipelineObjectRequest)(nil),     // 77: proto.PutPipelineObjectRequest
-	(*PutPipelineObjectResponse)(nil),    // 78: proto.PutPipelineObjectResponse
-	(*DeletePipelineObjectRequest)(nil),  // 79: proto.DeletePipelineObjectRequest
-	(*DeletePipelineObjectResponse)(nil), // 80: proto.DeletePipelineObjectResponse
-	(*GetRunObjectRequest)(nil),          // 81: proto.GetRunObjectRequest
-	(*GetRunObjectResponse)(nil),         // 82: proto.GetRunObjectResponse
-	(*ListRunObjectRequest)(nil),         // 83: proto.ListRunObjectRequest
-	(*ListRunObjectResponse)(nil),        // 84: proto.ListRunObjectResponse
-	(*PutRunObjectRequest)(nil),          // 85: proto.PutRunObjectRequest
-	(*PutRunObjectResponse)(nil),         // 86: proto.PutRunObjectResponse
-	(*DeleteRunObjectRequest)(nil),       // 87: proto.DeleteRunObjectRequest
-	(*DeleteRunObjectResponse)(nil),      // 88: proto.DeleteRunObjectResponse
-	(*GetSecretRequest)(nil),             // 89: proto.GetSecretRequest
-	(*GetSecretResponse)(nil),            // 90: proto.GetSecretResponse
-	(*PutSecretRequest)(nil),             // 91: proto.PutSecretRequest
-	(*PutSecretResponse)(nil),            // 92: proto.PutSecretResponse
-	(*DeleteSecretRequest)(nil),          // 93: proto.DeleteSecretRequest
-	(*DeleteSecretResponse)(nil),         // 94: proto.DeleteSecretResponse
-	(*GetSystemInfoRequest)(nil),         // 95: proto.GetSystemInfoRequest
-	(*GetSystemInfoResponse)(nil),        // 96: proto.GetSystemInfoResponse
-	(*RepairOrphanRequest)(nil),          // 97: proto.RepairOrphanRequest
-	(*RepairOrphanResponse)(nil),         // 98: proto.RepairOrphanResponse
-	(*ToggleEventIngressRequest)(nil),    // 99: proto.ToggleEventIngressRequest
-	(*ToggleEventIngressResponse)(nil),   // 100: proto.ToggleEventIngressResponse
-	(*CreateTokenRequest)(nil),           // 101: proto.CreateTokenRequest
-	(*CreateTokenResponse)(nil),          // 102: proto.CreateTokenResponse
-	(*BootstrapTokenRequest)(nil),        // 103: proto.BootstrapTokenRequest
-	(*BootstrapTokenResponse)(nil),       // 104: proto.BootstrapTokenResponse
-	(*GetTokenRequest)(nil),              // 105: proto.GetTokenRequest
-	(*GetTokenResponse)(nil),             // 106: proto.GetTokenResponse
-	(*DeleteTokenRequest)(nil),           // 107: proto.DeleteTokenRequest
-	(*DeleteTokenResponse)(nil),          // 108: proto.DeleteTokenResponse
-	nil,                                  // 109: proto.StartRunRequest.VariablesEntry
-	nil,                                  // 110: proto.CreateTokenRequest.MetadataEntry
-	(*Namespace)(nil),                    // 111: proto.Namespace
-	(*Pipeline)(nil),                     // 112: proto.Pipeline
-	(*Run)(nil),                          // 113: proto.Run
-	(*TaskRun)(nil),                      // 114: proto.TaskRun
-	(*Trigger)(nil),                      // 115: proto.Trigger
-	(*TriggerConfig)(nil),                // 116: proto.TriggerConfig
-	(*Notifier)(nil),                     // 117: proto.Notifier
-	(*NotifierConfig)(nil),               // 118: proto.NotifierConfig
-	(EventType)(0),                       // 119: proto.EventType
-	(*EventCreatedNamespace)(nil),        // 120: proto.EventCreatedNamespace
-	(*EventDisabledPipeline)(nil),        // 121: proto.EventDisabledPipeline
-	(*EventEnabledPipeline)(nil),         // 122: proto.EventEnabledPipeline
-	(*EventCreatedPipeline)(nil),         // 123: proto.EventCreatedPipeline
-	(*EventAbandonedPipeline)(nil),       // 124: proto.EventAbandonedPipeline
-	(*EventStartedRun)(nil),              // 125: proto.EventStartedRun
-	(*EventCompletedRun)(nil),            // 126: proto.EventCompletedRun
-	(*EventStartedTaskRun)(nil),          // 127: proto.EventStartedTaskRun
-	(*EventScheduledTaskRun)(nil),        // 128: proto.EventScheduledTaskRun
-	(*EventCompletedTaskRun)(nil),        // 129: proto.EventCompletedTaskRun
-	(*EventFiredTrigger)(nil),            // 130: proto.EventFiredTrigger
-	(*EventProcessedTrigger)(nil),        // 131: proto.EventProcessedTrigger
-	(*EventResolvedTrigger)(nil),         // 132: proto.EventResolvedTrigger
-	(*Token)(nil),                        // 133: proto.Token
+func (x *PutPipelineObjectRequest) GetForce() bool {
+	if x != nil {
+		return x.Force
+	}
+	return false
 }
-var file_gofer_transport_proto_depIdxs = []int32{
-	111, // 0: proto.GetNamespaceResponse.namespace:type_name -> proto.Namespace
-	111, // 1: proto.ListNamespacesResponse.namespaces:type_name -> proto.Namespace
-	111, // 2: proto.CreateNamespaceResponse.namespace:type_name -> proto.Namespace
-	111, // 3: proto.UpdateNamespaceResponse.namespace:type_name -> proto.Namespace
-	112, // 4: proto.GetPipelineResponse.pipeline:type_name -> proto.Pipeline
-	112, // 5: proto.ListPipelinesResponse.pipelines:type_name -> proto.Pipeline
-	112, // 6: proto.CreatePipelineRawResponse.pipeline:type_name -> proto.Pipeline
-	112, // 7: proto.CreatePipelineByURLResponse.pipeline:type_name -> proto.Pipeline
-	112, // 8: proto.UpdatePipelineRawResponse.pipeline:type_name -> proto.Pipeline
-	112, // 9: proto.UpdatePipelineByURLResponse.pipeline:type_name -> proto.Pipeline
-	113, // 10: proto.GetRunResponse.run:type_name -> proto.Run
-	113, // 11: proto.BatchGetRunsResponse.runs:type_name -> proto.Run
-	113, // 12: proto.ListRunsResponse.runs:type_name -> proto.Run
-	109, // 13: proto.StartRunRequest.variables:type_name -> proto.StartRunRequest.VariablesEntry
-	113, // 14: proto.StartRunResponse.run:type_name -> proto.Run
-	113, // 15: proto.RetryRunResponse.run:type_name -> proto.Run
-	114, // 16: proto.ListTaskRunsResponse.task_runs:type_name -> proto.TaskRun
-	114, // 17: proto.GetTaskRunResponse.task_run:type_name -> proto.TaskRun
-	115, // 18: proto.GetTriggerResponse.trigger:type_name -> proto.Trigger
-	115, // 19: proto.ListTriggersResponse.triggers:type_name -> proto.Trigger
-	116, // 20: proto.InstallTriggerRequest.trigger:type_name -> proto.TriggerConfig
-	117, // 21: proto.GetNotifierResponse.notifier:type_name -> proto.Notifier
-	117, // 22: proto.ListNotifiersResponse.notifiers:type_name -> proto.Notifier
-	118, // 23: proto.InstallNotifierRequest.notifier:type_name -> proto.NotifierConfig
-	119, // 24: proto.GetEventResponse.kind:type_name -> proto.EventType
-	120, // 25: proto.GetEventResponse.created_namespace_event:type_name -> proto.EventCreatedNamespace
-	121, // 26: proto.GetEventResponse.disabled_pipeline_event:type_name -> proto.EventDisabledPipeline
-	122, // 27: proto.GetEventResponse.enabled_pipeline_event:type_name -> proto.EventEnabledPipeline
-	123, // 28: proto.GetEventResponse.created_pipeline_event:type_name -> proto.EventCreatedPipeline
-	124, // 29: proto.GetEventResponse.abandoned_pipeline_event:type_name -> proto.EventAbandonedPipeline
-	125, // 30: proto.GetEventResponse.started_run_event:type_name -> proto.EventStartedRun
-	126, // 31: proto.GetEventResponse.completed_run_event:type_name -> proto.EventCompletedRun
-	127, // 32: proto.GetEventResponse.started_task_run_event:type_name -> proto.EventStartedTaskRun
-	128, // 33: proto.GetEventResponse.scheduled_task_run_event:type_name -> proto.EventScheduledTaskRun
-	129, // 34: proto.GetEventResponse.completed_task_run_event:type_name -> proto.EventCompletedTaskRun
-	130, // 35: proto.GetEventResponse.fired_trigger_event:type_name -> proto.EventFiredTrigger
-	131, // 36: proto.GetEventResponse.processed_trigger_event:type_name -> proto.EventProcessedTrigger
-	132, // 37: proto.GetEventResponse.resolved_trigger_event:type_name -> proto.EventResolvedTrigger
-	119, // 38: proto.ListEventsResponse.kind:type_name -> proto.EventType
-	120, // 39: proto.ListEventsResponse.created_namespace_event:type_name -> proto.EventCreatedNamespace
-	121, // 40: proto.ListEventsResponse.disabled_pipeline_event:type_name -> proto.EventDisabledPipeline
-	122, // 41: proto.ListEventsResponse.enabled_pipeline_event:type_name -> proto.EventEnabledPipeline
-	123, // 42: proto.ListEventsResponse.created_pipeline_event:type_name -> proto.EventCreatedPipeline
-	124, // 43: proto.ListEventsResponse.abandoned_pipeline_event:type_name -> proto.EventAbandonedPipeline
-	125, // 44: proto.ListEventsResponse.started_run_event:type_name -> proto.EventStartedRun
-	126, // 45: proto.ListEventsResponse.completed_run_event:type_name -> proto.EventCompletedRun
-	127, // 46: proto.ListEventsResponse.started_task_run_event:type_name -> proto.EventStartedTaskRun
-	128, // 47: proto.ListEventsResponse.scheduled_task_run_event:type_name -> proto.EventScheduledTaskRun
-	129, // 48: proto.ListEventsResponse.completed_task_run_event:type_name -> proto.EventCompletedTaskRun
-	130, // 49: proto.ListEventsResponse.fired_trigger_event:type_name -> proto.EventFiredTrigger
-	131, // 50: proto.ListEventsResponse.processed_trigger_event:type_name -> proto.EventProcessedTrigger
-	132, // 51: proto.ListEventsResponse.resolved_trigger_event:type_name -> proto.EventResolvedTrigger
-	0,   // 52: proto.CreateTokenRequest.kind:type_name -> proto.CreateTokenRequest.Kind
-	110, // 53: proto.CreateTokenRequest.metadata:type_name -> proto.CreateTokenRequest.MetadataEntry
-	133, // 54: proto.CreateTokenResponse.details:type_name -> proto.Token
-	133, // 55: proto.BootstrapTokenResponse.details:type_name -> proto.Token
-	133, // 56: proto.GetTokenResponse.details:type_name -> proto.Token
-	57,  // [57:57] is the sub-list for method output_type
-	57,  // [57:57] is the sub-list for method input_type
-	57,  // [57:57] is the sub-list for extension type_name
-	57,  // [57:57] is the sub-list for extension extendee
-	0,   // [0:57] is the sub-list for field type_name
+
+func (x *PutPipelineObjectRequest) GetExpiresAfter() int64 {
+	if x != nil {
+		return x.ExpiresAfter
+	}
+	return 0
 }
 
-func init() { file_gofer_transport_proto_init() }
-func file_gofer_transport_proto_init() {
-	if File_gofer_transport_proto != nil {
-		return
+type PutPipelineObjectResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The number of bytes uploaded.
+	Bytes int64 `protobuf:"varint,1,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	// The total amount of objects for this particular pipeline.
+	ObjectLimit int64 `protobuf:"varint,2,opt,name=object_limit,json=objectLimit,proto3" json:"object_limit,omitempty"`
+	// The key for the object that was evicted for the latest key.
+	ObjectEvicted string `protobuf:"bytes,3,opt,name=object_evicted,json=objectEvicted,proto3" json:"object_evicted,omitempty"`
+}
+
+func (x *PutPipelineObjectResponse) Reset() {
+	*x = PutPipelineObjectResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[108]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	file_gofer_message_events_proto_init()
-	file_gofer_message_proto_init()
-	if !protoimpl.UnsafeEnabled {
-		file_gofer_transport_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetNamespaceRequest); i {
+}
+
+func (x *PutPipelineObjectResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PutPipelineObjectResponse) ProtoMessage() {}
+
+func (x *PutPipelineObjectResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[108]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PutPipelineObjectResponse.ProtoReflect.Descriptor instead.
+func (*PutPipelineObjectResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{108}
+}
+
+func (x *PutPipelineObjectResponse) GetBytes() int64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+func (x *PutPipelineObjectResponse) GetObjectLimit() int64 {
+	if x != nil {
+		return x.ObjectLimit
+	}
+	return 0
+}
+
+func (x *PutPipelineObjectResponse) GetObjectEvicted() string {
+	if x != nil {
+		return x.ObjectEvicted
+	}
+	return ""
+}
+
+type DeletePipelineObjectRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+}
+
+func (x *DeletePipelineObjectRequest) Reset() {
+	*x = DeletePipelineObjectRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[109]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeletePipelineObjectRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeletePipelineObjectRequest) ProtoMessage() {}
+
+func (x *DeletePipelineObjectRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[109]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeletePipelineObjectRequest.ProtoReflect.Descriptor instead.
+func (*DeletePipelineObjectRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{109}
+}
+
+func (x *DeletePipelineObjectRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *DeletePipelineObjectRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *DeletePipelineObjectRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+type DeletePipelineObjectResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeletePipelineObjectResponse) Reset() {
+	*x = DeletePipelineObjectResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[110]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeletePipelineObjectResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeletePipelineObjectResponse) ProtoMessage() {}
+
+func (x *DeletePipelineObjectResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[110]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeletePipelineObjectResponse.ProtoReflect.Descriptor instead.
+func (*DeletePipelineObjectResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{110}
+}
+
+type GetRunObjectRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Key         string `protobuf:"bytes,4,opt,name=key,proto3" json:"key,omitempty"`
+}
+
+func (x *GetRunObjectRequest) Reset() {
+	*x = GetRunObjectRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[111]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetRunObjectRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetRunObjectRequest) ProtoMessage() {}
+
+func (x *GetRunObjectRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[111]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetRunObjectRequest.ProtoReflect.Descriptor instead.
+func (*GetRunObjectRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{111}
+}
+
+func (x *GetRunObjectRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *GetRunObjectRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *GetRunObjectRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+func (x *GetRunObjectRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+type GetRunObjectResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Content []byte `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
+}
+
+func (x *GetRunObjectResponse) Reset() {
+	*x = GetRunObjectResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[112]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetRunObjectResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetRunObjectResponse) ProtoMessage() {}
+
+func (x *GetRunObjectResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[112]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetRunObjectResponse.ProtoReflect.Descriptor instead.
+func (*GetRunObjectResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{112}
+}
+
+func (x *GetRunObjectResponse) GetContent() []byte {
+	if x != nil {
+		return x.Content
+	}
+	return nil
+}
+
+type ListRunObjectRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+}
+
+func (x *ListRunObjectRequest) Reset() {
+	*x = ListRunObjectRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[113]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListRunObjectRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRunObjectRequest) ProtoMessage() {}
+
+func (x *ListRunObjectRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[113]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRunObjectRequest.ProtoReflect.Descriptor instead.
+func (*ListRunObjectRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{113}
+}
+
+func (x *ListRunObjectRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *ListRunObjectRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *ListRunObjectRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+type ListRunObjectResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Keys []string `protobuf:"bytes,1,rep,name=keys,proto3" json:"keys,omitempty"`
+}
+
+func (x *ListRunObjectResponse) Reset() {
+	*x = ListRunObjectResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[114]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListRunObjectResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRunObjectResponse) ProtoMessage() {}
+
+func (x *ListRunObjectResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[114]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRunObjectResponse.ProtoReflect.Descriptor instead.
+func (*ListRunObjectResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{114}
+}
+
+func (x *ListRunObjectResponse) GetKeys() []string {
+	if x != nil {
+		return x.Keys
+	}
+	return nil
+}
+
+type PutRunObjectRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Key         string `protobuf:"bytes,4,opt,name=key,proto3" json:"key,omitempty"`
+	Content     []byte `protobuf:"bytes,5,opt,name=content,proto3" json:"content,omitempty"`
+	Force       bool   `protobuf:"varint,6,opt,name=force,proto3" json:"force,omitempty"` // Overwrites an already existing value.
+}
+
+func (x *PutRunObjectRequest) Reset() {
+	*x = PutRunObjectRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[115]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PutRunObjectRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PutRunObjectRequest) ProtoMessage() {}
+
+func (x *PutRunObjectRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[115]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PutRunObjectRequest.ProtoReflect.Descriptor instead.
+func (*PutRunObjectRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{115}
+}
+
+func (x *PutRunObjectRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *PutRunObjectRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *PutRunObjectRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+func (x *PutRunObjectRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+func (x *PutRunObjectRequest) GetContent() []byte {
+	if x != nil {
+		return x.Content
+	}
+	return nil
+}
+
+func (x *PutRunObjectRequest) GetForce() bool {
+	if x != nil {
+		return x.Force
+	}
+	return false
+}
+
+type PutRunObjectResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Bytes int64 `protobuf:"varint,1,opt,name=bytes,proto3" json:"bytes,omitempty"`
+}
+
+func (x *PutRunObjectResponse) Reset() {
+	*x = PutRunObjectResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[116]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PutRunObjectResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PutRunObjectResponse) ProtoMessage() {}
+
+func (x *PutRunObjectResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[116]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PutRunObjectResponse.ProtoReflect.Descriptor instead.
+func (*PutRunObjectResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{116}
+}
+
+func (x *PutRunObjectResponse) GetBytes() int64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+type DeleteRunObjectRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	Key         string `protobuf:"bytes,4,opt,name=key,proto3" json:"key,omitempty"`
+}
+
+func (x *DeleteRunObjectRequest) Reset() {
+	*x = DeleteRunObjectRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[117]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteRunObjectRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteRunObjectRequest) ProtoMessage() {}
+
+func (x *DeleteRunObjectRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[117]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteRunObjectRequest.ProtoReflect.Descriptor instead.
+func (*DeleteRunObjectRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{117}
+}
+
+func (x *DeleteRunObjectRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *DeleteRunObjectRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *DeleteRunObjectRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+func (x *DeleteRunObjectRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+type DeleteRunObjectResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeleteRunObjectResponse) Reset() {
+	*x = DeleteRunObjectResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[118]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteRunObjectResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteRunObjectResponse) ProtoMessage() {}
+
+func (x *DeleteRunObjectResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[118]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteRunObjectResponse.ProtoReflect.Descriptor instead.
+func (*DeleteRunObjectResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{118}
+}
+
+type CollectOrphanedObjectsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	DryRun bool `protobuf:"varint,1,opt,name=dry_run,json=dryRun,proto3" json:"dry_run,omitempty"` // Only report orphaned objects instead of removing them.
+}
+
+func (x *CollectOrphanedObjectsRequest) Reset() {
+	*x = CollectOrphanedObjectsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[119]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CollectOrphanedObjectsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CollectOrphanedObjectsRequest) ProtoMessage() {}
+
+func (x *CollectOrphanedObjectsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[119]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CollectOrphanedObjectsRequest.ProtoReflect.Descriptor instead.
+func (*CollectOrphanedObjectsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{119}
+}
+
+func (x *CollectOrphanedObjectsRequest) GetDryRun() bool {
+	if x != nil {
+		return x.DryRun
+	}
+	return false
+}
+
+type CollectOrphanedObjectsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Objects []*OrphanedObject `protobuf:"bytes,1,rep,name=objects,proto3" json:"objects,omitempty"`
+	Bytes   int64             `protobuf:"varint,2,opt,name=bytes,proto3" json:"bytes,omitempty"` // Total size of the orphaned objects.
+}
+
+func (x *CollectOrphanedObjectsResponse) Reset() {
+	*x = CollectOrphanedObjectsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[120]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CollectOrphanedObjectsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CollectOrphanedObjectsResponse) ProtoMessage() {}
+
+func (x *CollectOrphanedObjectsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[120]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CollectOrphanedObjectsResponse.ProtoReflect.Descriptor instead.
+func (*CollectOrphanedObjectsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{120}
+}
+
+func (x *CollectOrphanedObjectsResponse) GetObjects() []*OrphanedObject {
+	if x != nil {
+		return x.Objects
+	}
+	return nil
+}
+
+func (x *CollectOrphanedObjectsResponse) GetBytes() int64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+type OrphanedObject struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Key    string `protobuf:"bytes,1,opt,name=key,proto3" json:"key,omitempty"` // The full key of the object within the object store.
+	Bytes  int64  `protobuf:"varint,2,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	Reason string `protobuf:"bytes,3,opt,name=reason,proto3" json:"reason,omitempty"` // Why the object is considered orphaned.
+}
+
+func (x *OrphanedObject) Reset() {
+	*x = OrphanedObject{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[121]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *OrphanedObject) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OrphanedObject) ProtoMessage() {}
+
+func (x *OrphanedObject) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[121]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OrphanedObject.ProtoReflect.Descriptor instead.
+func (*OrphanedObject) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{121}
+}
+
+func (x *OrphanedObject) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+func (x *OrphanedObject) GetBytes() int64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+func (x *OrphanedObject) GetReason() string {
+	if x != nil {
+		return x.Reason
+	}
+	return ""
+}
+
+type ListRunArtifactsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+}
+
+func (x *ListRunArtifactsRequest) Reset() {
+	*x = ListRunArtifactsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[122]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListRunArtifactsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRunArtifactsRequest) ProtoMessage() {}
+
+func (x *ListRunArtifactsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[122]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRunArtifactsRequest.ProtoReflect.Descriptor instead.
+func (*ListRunArtifactsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{122}
+}
+
+func (x *ListRunArtifactsRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *ListRunArtifactsRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *ListRunArtifactsRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+type ListRunArtifactsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Artifacts []*Artifact `protobuf:"bytes,1,rep,name=artifacts,proto3" json:"artifacts,omitempty"`
+}
+
+func (x *ListRunArtifactsResponse) Reset() {
+	*x = ListRunArtifactsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[123]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListRunArtifactsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRunArtifactsResponse) ProtoMessage() {}
+
+func (x *ListRunArtifactsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[123]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRunArtifactsResponse.ProtoReflect.Descriptor instead.
+func (*ListRunArtifactsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{123}
+}
+
+func (x *ListRunArtifactsResponse) GetArtifacts() []*Artifact {
+	if x != nil {
+		return x.Artifacts
+	}
+	return nil
+}
+
+type GetArtifactStreamRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+	TaskRunId   string `protobuf:"bytes,4,opt,name=task_run_id,json=taskRunId,proto3" json:"task_run_id,omitempty"`
+	Name        string `protobuf:"bytes,5,opt,name=name,proto3" json:"name,omitempty"`
+	Offset      int64  `protobuf:"varint,6,opt,name=offset,proto3" json:"offset,omitempty"` // The byte to start reading the artifact from.
+}
+
+func (x *GetArtifactStreamRequest) Reset() {
+	*x = GetArtifactStreamRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[124]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetArtifactStreamRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetArtifactStreamRequest) ProtoMessage() {}
+
+func (x *GetArtifactStreamRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[124]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetArtifactStreamRequest.ProtoReflect.Descriptor instead.
+func (*GetArtifactStreamRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{124}
+}
+
+func (x *GetArtifactStreamRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *GetArtifactStreamRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *GetArtifactStreamRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+func (x *GetArtifactStreamRequest) GetTaskRunId() string {
+	if x != nil {
+		return x.TaskRunId
+	}
+	return ""
+}
+
+func (x *GetArtifactStreamRequest) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *GetArtifactStreamRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+type GetArtifactStreamResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Content []byte `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
+	// The artifact being downloaded; only set on the first message.
+	Artifact *Artifact `protobuf:"bytes,2,opt,name=artifact,proto3" json:"artifact,omitempty"`
+}
+
+func (x *GetArtifactStreamResponse) Reset() {
+	*x = GetArtifactStreamResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[125]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetArtifactStreamResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetArtifactStreamResponse) ProtoMessage() {}
+
+func (x *GetArtifactStreamResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[125]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetArtifactStreamResponse.ProtoReflect.Descriptor instead.
+func (*GetArtifactStreamResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{125}
+}
+
+func (x *GetArtifactStreamResponse) GetContent() []byte {
+	if x != nil {
+		return x.Content
+	}
+	return nil
+}
+
+func (x *GetArtifactStreamResponse) GetArtifact() *Artifact {
+	if x != nil {
+		return x.Artifact
+	}
+	return nil
+}
+
+type GetSecretRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+}
+
+func (x *GetSecretRequest) Reset() {
+	*x = GetSecretRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[126]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSecretRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSecretRequest) ProtoMessage() {}
+
+func (x *GetSecretRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[126]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSecretRequest.ProtoReflect.Descriptor instead.
+func (*GetSecretRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{126}
+}
+
+func (x *GetSecretRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *GetSecretRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *GetSecretRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+type GetSecretResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Content string `protobuf:"bytes,1,opt,name=content,proto3" json:"content,omitempty"`
+}
+
+func (x *GetSecretResponse) Reset() {
+	*x = GetSecretResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[127]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSecretResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSecretResponse) ProtoMessage() {}
+
+func (x *GetSecretResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[127]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSecretResponse.ProtoReflect.Descriptor instead.
+func (*GetSecretResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{127}
+}
+
+func (x *GetSecretResponse) GetContent() string {
+	if x != nil {
+		return x.Content
+	}
+	return ""
+}
+
+type PutSecretRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+	Content     string `protobuf:"bytes,4,opt,name=content,proto3" json:"content,omitempty"`
+	Force       bool   `protobuf:"varint,5,opt,name=force,proto3" json:"force,omitempty"` // Overwrites an already existing value.
+}
+
+func (x *PutSecretRequest) Reset() {
+	*x = PutSecretRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[128]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PutSecretRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PutSecretRequest) ProtoMessage() {}
+
+func (x *PutSecretRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[128]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PutSecretRequest.ProtoReflect.Descriptor instead.
+func (*PutSecretRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{128}
+}
+
+func (x *PutSecretRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *PutSecretRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *PutSecretRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+func (x *PutSecretRequest) GetContent() string {
+	if x != nil {
+		return x.Content
+	}
+	return ""
+}
+
+func (x *PutSecretRequest) GetForce() bool {
+	if x != nil {
+		return x.Force
+	}
+	return false
+}
+
+type PutSecretResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The number of bytes uploaded.
+	Bytes int64 `protobuf:"varint,1,opt,name=bytes,proto3" json:"bytes,omitempty"`
+}
+
+func (x *PutSecretResponse) Reset() {
+	*x = PutSecretResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[129]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PutSecretResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PutSecretResponse) ProtoMessage() {}
+
+func (x *PutSecretResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[129]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PutSecretResponse.ProtoReflect.Descriptor instead.
+func (*PutSecretResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{129}
+}
+
+func (x *PutSecretResponse) GetBytes() int64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+type DeleteSecretRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Key         string `protobuf:"bytes,3,opt,name=key,proto3" json:"key,omitempty"`
+}
+
+func (x *DeleteSecretRequest) Reset() {
+	*x = DeleteSecretRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[130]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteSecretRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteSecretRequest) ProtoMessage() {}
+
+func (x *DeleteSecretRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[130]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteSecretRequest.ProtoReflect.Descriptor instead.
+func (*DeleteSecretRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{130}
+}
+
+func (x *DeleteSecretRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *DeleteSecretRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *DeleteSecretRequest) GetKey() string {
+	if x != nil {
+		return x.Key
+	}
+	return ""
+}
+
+type DeleteSecretResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeleteSecretResponse) Reset() {
+	*x = DeleteSecretResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[131]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteSecretResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteSecretResponse) ProtoMessage() {}
+
+func (x *DeleteSecretResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[131]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteSecretResponse.ProtoReflect.Descriptor instead.
+func (*DeleteSecretResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{131}
+}
+
+type ReencryptSecretsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *ReencryptSecretsRequest) Reset() {
+	*x = ReencryptSecretsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[132]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ReencryptSecretsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReencryptSecretsRequest) ProtoMessage() {}
+
+func (x *ReencryptSecretsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[132]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReencryptSecretsRequest.ProtoReflect.Descriptor instead.
+func (*ReencryptSecretsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{132}
+}
+
+type ReencryptSecretsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Reencrypted int64 `protobuf:"varint,1,opt,name=reencrypted,proto3" json:"reencrypted,omitempty"` // The number of secrets that were re-encrypted.
+}
+
+func (x *ReencryptSecretsResponse) Reset() {
+	*x = ReencryptSecretsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[133]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ReencryptSecretsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReencryptSecretsResponse) ProtoMessage() {}
+
+func (x *ReencryptSecretsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[133]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReencryptSecretsResponse.ProtoReflect.Descriptor instead.
+func (*ReencryptSecretsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{133}
+}
+
+func (x *ReencryptSecretsResponse) GetReencrypted() int64 {
+	if x != nil {
+		return x.Reencrypted
+	}
+	return 0
+}
+
+type GetSystemInfoRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *GetSystemInfoRequest) Reset() {
+	*x = GetSystemInfoRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[134]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSystemInfoRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSystemInfoRequest) ProtoMessage() {}
+
+func (x *GetSystemInfoRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[134]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSystemInfoRequest.ProtoReflect.Descriptor instead.
+func (*GetSystemInfoRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{134}
+}
+
+type GetSystemInfoResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Commit                  string `protobuf:"bytes,1,opt,name=commit,proto3" json:"commit,omitempty"`
+	DevmodeEnabled          bool   `protobuf:"varint,2,opt,name=devmode_enabled,json=devmodeEnabled,proto3" json:"devmode_enabled,omitempty"`
+	FrontendEnabled         bool   `protobuf:"varint,3,opt,name=frontend_enabled,json=frontendEnabled,proto3" json:"frontend_enabled,omitempty"`
+	Version                 string `protobuf:"bytes,4,opt,name=version,proto3" json:"version,omitempty"`
+	IgnorePipelineRunEvents bool   `protobuf:"varint,5,opt,name=ignore_pipeline_run_events,json=ignorePipelineRunEvents,proto3" json:"ignore_pipeline_run_events,omitempty"`
+	// The version of the stored data layout the server uses.
+	SchemaVersion int64 `protobuf:"varint,6,opt,name=schema_version,json=schemaVersion,proto3" json:"schema_version,omitempty"`
+}
+
+func (x *GetSystemInfoResponse) Reset() {
+	*x = GetSystemInfoResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[135]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSystemInfoResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSystemInfoResponse) ProtoMessage() {}
+
+func (x *GetSystemInfoResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[135]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSystemInfoResponse.ProtoReflect.Descriptor instead.
+func (*GetSystemInfoResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{135}
+}
+
+func (x *GetSystemInfoResponse) GetCommit() string {
+	if x != nil {
+		return x.Commit
+	}
+	return ""
+}
+
+func (x *GetSystemInfoResponse) GetDevmodeEnabled() bool {
+	if x != nil {
+		return x.DevmodeEnabled
+	}
+	return false
+}
+
+func (x *GetSystemInfoResponse) GetFrontendEnabled() bool {
+	if x != nil {
+		return x.FrontendEnabled
+	}
+	return false
+}
+
+func (x *GetSystemInfoResponse) GetVersion() string {
+	if x != nil {
+		return x.Version
+	}
+	return ""
+}
+
+func (x *GetSystemInfoResponse) GetIgnorePipelineRunEvents() bool {
+	if x != nil {
+		return x.IgnorePipelineRunEvents
+	}
+	return false
+}
+
+func (x *GetSystemInfoResponse) GetSchemaVersion() int64 {
+	if x != nil {
+		return x.SchemaVersion
+	}
+	return 0
+}
+
+type GetAuthConfigRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *GetAuthConfigRequest) Reset() {
+	*x = GetAuthConfigRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[136]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetAuthConfigRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetAuthConfigRequest) ProtoMessage() {}
+
+func (x *GetAuthConfigRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[136]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetAuthConfigRequest.ProtoReflect.Descriptor instead.
+func (*GetAuthConfigRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{136}
+}
+
+type GetAuthConfigResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Whether users are able to log in through an OIDC identity provider. All
+	// other fields are empty if this is false.
+	OidcEnabled                 bool     `protobuf:"varint,1,opt,name=oidc_enabled,json=oidcEnabled,proto3" json:"oidc_enabled,omitempty"`
+	Issuer                      string   `protobuf:"bytes,2,opt,name=issuer,proto3" json:"issuer,omitempty"`
+	ClientId                    string   `protobuf:"bytes,3,opt,name=client_id,json=clientId,proto3" json:"client_id,omitempty"`
+	Scopes                      []string `protobuf:"bytes,4,rep,name=scopes,proto3" json:"scopes,omitempty"`
+	DeviceAuthorizationEndpoint string   `protobuf:"bytes,5,opt,name=device_authorization_endpoint,json=deviceAuthorizationEndpoint,proto3" json:"device_authorization_endpoint,omitempty"`
+	TokenEndpoint               string   `protobuf:"bytes,6,opt,name=token_endpoint,json=tokenEndpoint,proto3" json:"token_endpoint,omitempty"`
+}
+
+func (x *GetAuthConfigResponse) Reset() {
+	*x = GetAuthConfigResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[137]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetAuthConfigResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetAuthConfigResponse) ProtoMessage() {}
+
+func (x *GetAuthConfigResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[137]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetAuthConfigResponse.ProtoReflect.Descriptor instead.
+func (*GetAuthConfigResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{137}
+}
+
+func (x *GetAuthConfigResponse) GetOidcEnabled() bool {
+	if x != nil {
+		return x.OidcEnabled
+	}
+	return false
+}
+
+func (x *GetAuthConfigResponse) GetIssuer() string {
+	if x != nil {
+		return x.Issuer
+	}
+	return ""
+}
+
+func (x *GetAuthConfigResponse) GetClientId() string {
+	if x != nil {
+		return x.ClientId
+	}
+	return ""
+}
+
+func (x *GetAuthConfigResponse) GetScopes() []string {
+	if x != nil {
+		return x.Scopes
+	}
+	return nil
+}
+
+func (x *GetAuthConfigResponse) GetDeviceAuthorizationEndpoint() string {
+	if x != nil {
+		return x.DeviceAuthorizationEndpoint
+	}
+	return ""
+}
+
+func (x *GetAuthConfigResponse) GetTokenEndpoint() string {
+	if x != nil {
+		return x.TokenEndpoint
+	}
+	return ""
+}
+
+type GetSystemStatsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *GetSystemStatsRequest) Reset() {
+	*x = GetSystemStatsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[138]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSystemStatsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSystemStatsRequest) ProtoMessage() {}
+
+func (x *GetSystemStatsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[138]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSystemStatsRequest.ProtoReflect.Descriptor instead.
+func (*GetSystemStatsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{138}
+}
+
+type GetSystemStatsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Run statistics for each time window (ex. the last hour and the last day).
+	Windows []*SystemStatsWindow `protobuf:"bytes,1,rep,name=windows,proto3" json:"windows,omitempty"`
+	// Current queue depth. Only runs started within the largest window are
+	// taken into account.
+	RunsQueued      int64 `protobuf:"varint,2,opt,name=runs_queued,json=runsQueued,proto3" json:"runs_queued,omitempty"`
+	RunsRunning     int64 `protobuf:"varint,3,opt,name=runs_running,json=runsRunning,proto3" json:"runs_running,omitempty"`
+	TaskRunsQueued  int64 `protobuf:"varint,4,opt,name=task_runs_queued,json=taskRunsQueued,proto3" json:"task_runs_queued,omitempty"`
+	TaskRunsRunning int64 `protobuf:"varint,5,opt,name=task_runs_running,json=taskRunsRunning,proto3" json:"task_runs_running,omitempty"`
+	// Distributions of run and task run durations for each pipeline and final
+	// status. These are kept since the server was started.
+	Durations []*DurationDistribution `protobuf:"bytes,6,rep,name=durations,proto3" json:"durations,omitempty"`
+}
+
+func (x *GetSystemStatsResponse) Reset() {
+	*x = GetSystemStatsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[139]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSystemStatsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSystemStatsResponse) ProtoMessage() {}
+
+func (x *GetSystemStatsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[139]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSystemStatsResponse.ProtoReflect.Descriptor instead.
+func (*GetSystemStatsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{139}
+}
+
+func (x *GetSystemStatsResponse) GetWindows() []*SystemStatsWindow {
+	if x != nil {
+		return x.Windows
+	}
+	return nil
+}
+
+func (x *GetSystemStatsResponse) GetRunsQueued() int64 {
+	if x != nil {
+		return x.RunsQueued
+	}
+	return 0
+}
+
+func (x *GetSystemStatsResponse) GetRunsRunning() int64 {
+	if x != nil {
+		return x.RunsRunning
+	}
+	return 0
+}
+
+func (x *GetSystemStatsResponse) GetTaskRunsQueued() int64 {
+	if x != nil {
+		return x.TaskRunsQueued
+	}
+	return 0
+}
+
+func (x *GetSystemStatsResponse) GetTaskRunsRunning() int64 {
+	if x != nil {
+		return x.TaskRunsRunning
+	}
+	return 0
+}
+
+func (x *GetSystemStatsResponse) GetDurations() []*DurationDistribution {
+	if x != nil {
+		return x.Durations
+	}
+	return nil
+}
+
+type DurationDistribution struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// One of task_run_queue_wait, task_run_image_pull, task_run_duration or
+	// run_duration.
+	Metric      string `protobuf:"bytes,1,opt,name=metric,proto3" json:"metric,omitempty"`
+	NamespaceId string `protobuf:"bytes,2,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	PipelineId  string `protobuf:"bytes,3,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	Status      string `protobuf:"bytes,4,opt,name=status,proto3" json:"status,omitempty"` // The final state of the runs or task runs.
+	Count       int64  `protobuf:"varint,5,opt,name=count,proto3" json:"count,omitempty"`
+	Sum         int64  `protobuf:"varint,6,opt,name=sum,proto3" json:"sum,omitempty"` // Total of all durations in milliseconds.
+	// Estimated percentiles in milliseconds.
+	P50 int64 `protobuf:"varint,7,opt,name=p50,proto3" json:"p50,omitempty"`
+	P90 int64 `protobuf:"varint,8,opt,name=p90,proto3" json:"p90,omitempty"`
+	P99 int64 `protobuf:"varint,9,opt,name=p99,proto3" json:"p99,omitempty"`
+}
+
+func (x *DurationDistribution) Reset() {
+	*x = DurationDistribution{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[140]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DurationDistribution) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DurationDistribution) ProtoMessage() {}
+
+func (x *DurationDistribution) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[140]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DurationDistribution.ProtoReflect.Descriptor instead.
+func (*DurationDistribution) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{140}
+}
+
+func (x *DurationDistribution) GetMetric() string {
+	if x != nil {
+		return x.Metric
+	}
+	return ""
+}
+
+func (x *DurationDistribution) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *DurationDistribution) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *DurationDistribution) GetStatus() string {
+	if x != nil {
+		return x.Status
+	}
+	return ""
+}
+
+func (x *DurationDistribution) GetCount() int64 {
+	if x != nil {
+		return x.Count
+	}
+	return 0
+}
+
+func (x *DurationDistribution) GetSum() int64 {
+	if x != nil {
+		return x.Sum
+	}
+	return 0
+}
+
+func (x *DurationDistribution) GetP50() int64 {
+	if x != nil {
+		return x.P50
+	}
+	return 0
+}
+
+func (x *DurationDistribution) GetP90() int64 {
+	if x != nil {
+		return x.P90
+	}
+	return 0
+}
+
+func (x *DurationDistribution) GetP99() int64 {
+	if x != nil {
+		return x.P99
+	}
+	return 0
+}
+
+type GetSlowReportRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Limit int64 `protobuf:"varint,1,opt,name=limit,proto3" json:"limit,omitempty"` // The most routes and queries to return. Defaults to 10.
+}
+
+func (x *GetSlowReportRequest) Reset() {
+	*x = GetSlowReportRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[141]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSlowReportRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSlowReportRequest) ProtoMessage() {}
+
+func (x *GetSlowReportRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[141]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSlowReportRequest.ProtoReflect.Descriptor instead.
+func (*GetSlowReportRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{141}
+}
+
+func (x *GetSlowReportRequest) GetLimit() int64 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+type GetSlowReportResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Window  int64             `protobuf:"varint,1,opt,name=window,proto3" json:"window,omitempty"` // How far back the report covers in milliseconds.
+	Routes  []*LatencySummary `protobuf:"bytes,2,rep,name=routes,proto3" json:"routes,omitempty"`
+	Queries []*LatencySummary `protobuf:"bytes,3,rep,name=queries,proto3" json:"queries,omitempty"`
+}
+
+func (x *GetSlowReportResponse) Reset() {
+	*x = GetSlowReportResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[142]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetSlowReportResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetSlowReportResponse) ProtoMessage() {}
+
+func (x *GetSlowReportResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[142]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetSlowReportResponse.ProtoReflect.Descriptor instead.
+func (*GetSlowReportResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{142}
+}
+
+func (x *GetSlowReportResponse) GetWindow() int64 {
+	if x != nil {
+		return x.Window
+	}
+	return 0
+}
+
+func (x *GetSlowReportResponse) GetRoutes() []*LatencySummary {
+	if x != nil {
+		return x.Routes
+	}
+	return nil
+}
+
+func (x *GetSlowReportResponse) GetQueries() []*LatencySummary {
+	if x != nil {
+		return x.Queries
+	}
+	return nil
+}
+
+type LatencySummary struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Name  string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`    // The name of the route or storage query.
+	Count int64  `protobuf:"varint,2,opt,name=count,proto3" json:"count,omitempty"` // Calls within the window that were sampled.
+	// Latencies in microseconds.
+	P50 int64 `protobuf:"varint,3,opt,name=p50,proto3" json:"p50,omitempty"`
+	P95 int64 `protobuf:"varint,4,opt,name=p95,proto3" json:"p95,omitempty"`
+	P99 int64 `protobuf:"varint,5,opt,name=p99,proto3" json:"p99,omitempty"`
+	Max int64 `protobuf:"varint,6,opt,name=max,proto3" json:"max,omitempty"`
+}
+
+func (x *LatencySummary) Reset() {
+	*x = LatencySummary{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[143]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *LatencySummary) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LatencySummary) ProtoMessage() {}
+
+func (x *LatencySummary) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[143]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LatencySummary.ProtoReflect.Descriptor instead.
+func (*LatencySummary) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{143}
+}
+
+func (x *LatencySummary) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *LatencySummary) GetCount() int64 {
+	if x != nil {
+		return x.Count
+	}
+	return 0
+}
+
+func (x *LatencySummary) GetP50() int64 {
+	if x != nil {
+		return x.P50
+	}
+	return 0
+}
+
+func (x *LatencySummary) GetP95() int64 {
+	if x != nil {
+		return x.P95
+	}
+	return 0
+}
+
+func (x *LatencySummary) GetP99() int64 {
+	if x != nil {
+		return x.P99
+	}
+	return 0
+}
+
+func (x *LatencySummary) GetMax() int64 {
+	if x != nil {
+		return x.Max
+	}
+	return 0
+}
+
+type SystemStatsWindow struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Name          string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`                                         // Short name of the window (ex. 1h).
+	Duration      int64  `protobuf:"varint,2,opt,name=duration,proto3" json:"duration,omitempty"`                                // Length of the window in milliseconds.
+	RunsStarted   int64  `protobuf:"varint,3,opt,name=runs_started,json=runsStarted,proto3" json:"runs_started,omitempty"`       // Runs started within the window.
+	RunsSucceeded int64  `protobuf:"varint,4,opt,name=runs_succeeded,json=runsSucceeded,proto3" json:"runs_succeeded,omitempty"` // Runs started within the window that succeeded.
+	RunsFailed    int64  `protobuf:"varint,5,opt,name=runs_failed,json=runsFailed,proto3" json:"runs_failed,omitempty"`          // Runs started within the window that failed.
+	RunsCancelled int64  `protobuf:"varint,6,opt,name=runs_cancelled,json=runsCancelled,proto3" json:"runs_cancelled,omitempty"` // Runs started within the window that were cancelled.
+	// Average duration in milliseconds of the runs started within the window
+	// that have finished.
+	AverageRunDuration int64 `protobuf:"varint,7,opt,name=average_run_duration,json=averageRunDuration,proto3" json:"average_run_duration,omitempty"`
+}
+
+func (x *SystemStatsWindow) Reset() {
+	*x = SystemStatsWindow{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[144]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SystemStatsWindow) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SystemStatsWindow) ProtoMessage() {}
+
+func (x *SystemStatsWindow) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[144]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SystemStatsWindow.ProtoReflect.Descriptor instead.
+func (*SystemStatsWindow) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{144}
+}
+
+func (x *SystemStatsWindow) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *SystemStatsWindow) GetDuration() int64 {
+	if x != nil {
+		return x.Duration
+	}
+	return 0
+}
+
+func (x *SystemStatsWindow) GetRunsStarted() int64 {
+	if x != nil {
+		return x.RunsStarted
+	}
+	return 0
+}
+
+func (x *SystemStatsWindow) GetRunsSucceeded() int64 {
+	if x != nil {
+		return x.RunsSucceeded
+	}
+	return 0
+}
+
+func (x *SystemStatsWindow) GetRunsFailed() int64 {
+	if x != nil {
+		return x.RunsFailed
+	}
+	return 0
+}
+
+func (x *SystemStatsWindow) GetRunsCancelled() int64 {
+	if x != nil {
+		return x.RunsCancelled
+	}
+	return 0
+}
+
+func (x *SystemStatsWindow) GetAverageRunDuration() int64 {
+	if x != nil {
+		return x.AverageRunDuration
+	}
+	return 0
+}
+
+type RepairOrphanRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Unique namespace identifier
+	PipelineId  string `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"`
+	RunId       int64  `protobuf:"varint,3,opt,name=run_id,json=runId,proto3" json:"run_id,omitempty"`
+}
+
+func (x *RepairOrphanRequest) Reset() {
+	*x = RepairOrphanRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[145]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *RepairOrphanRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RepairOrphanRequest) ProtoMessage() {}
+
+func (x *RepairOrphanRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[145]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RepairOrphanRequest.ProtoReflect.Descriptor instead.
+func (*RepairOrphanRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{145}
+}
+
+func (x *RepairOrphanRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *RepairOrphanRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *RepairOrphanRequest) GetRunId() int64 {
+	if x != nil {
+		return x.RunId
+	}
+	return 0
+}
+
+type RepairOrphanResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *RepairOrphanResponse) Reset() {
+	*x = RepairOrphanResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[146]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *RepairOrphanResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RepairOrphanResponse) ProtoMessage() {}
+
+func (x *RepairOrphanResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[146]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RepairOrphanResponse.ProtoReflect.Descriptor instead.
+func (*RepairOrphanResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{146}
+}
+
+type ToggleEventIngressRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *ToggleEventIngressRequest) Reset() {
+	*x = ToggleEventIngressRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[147]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ToggleEventIngressRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ToggleEventIngressRequest) ProtoMessage() {}
+
+func (x *ToggleEventIngressRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[147]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ToggleEventIngressRequest.ProtoReflect.Descriptor instead.
+func (*ToggleEventIngressRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{147}
+}
+
+type ToggleEventIngressResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The current value for the boolean that controls event ingress.
+	Value bool `protobuf:"varint,1,opt,name=value,proto3" json:"value,omitempty"`
+}
+
+func (x *ToggleEventIngressResponse) Reset() {
+	*x = ToggleEventIngressResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[148]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ToggleEventIngressResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ToggleEventIngressResponse) ProtoMessage() {}
+
+func (x *ToggleEventIngressResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[148]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ToggleEventIngressResponse.ProtoReflect.Descriptor instead.
+func (*ToggleEventIngressResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{148}
+}
+
+func (x *ToggleEventIngressResponse) GetValue() bool {
+	if x != nil {
+		return x.Value
+	}
+	return false
+}
+
+type GetLogLevelRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *GetLogLevelRequest) Reset() {
+	*x = GetLogLevelRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[149]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetLogLevelRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetLogLevelRequest) ProtoMessage() {}
+
+func (x *GetLogLevelRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[149]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetLogLevelRequest.ProtoReflect.Descriptor instead.
+func (*GetLogLevelRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{149}
+}
+
+type GetLogLevelResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Level string `protobuf:"bytes,1,opt,name=level,proto3" json:"level,omitempty"`
+	// The levels of modules that have their own level set, keyed by module(ex.
+	// "scheduler/docker").
+	Modules map[string]string `protobuf:"bytes,2,rep,name=modules,proto3" json:"modules,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+}
+
+func (x *GetLogLevelResponse) Reset() {
+	*x = GetLogLevelResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[150]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetLogLevelResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetLogLevelResponse) ProtoMessage() {}
+
+func (x *GetLogLevelResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[150]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetLogLevelResponse.ProtoReflect.Descriptor instead.
+func (*GetLogLevelResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{150}
+}
+
+func (x *GetLogLevelResponse) GetLevel() string {
+	if x != nil {
+		return x.Level
+	}
+	return ""
+}
+
+func (x *GetLogLevelResponse) GetModules() map[string]string {
+	if x != nil {
+		return x.Modules
+	}
+	return nil
+}
+
+type UpdateLogLevelRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The overall level to log at. Left unchanged if empty.
+	Level string `protobuf:"bytes,1,opt,name=level,proto3" json:"level,omitempty"`
+	// Levels to set for individual modules, keyed by module(ex.
+	// "scheduler/docker"). Setting a module's level also sets it for every module
+	// beneath it. A module set to an empty level goes back to the overall level.
+	Modules map[string]string `protobuf:"bytes,2,rep,name=modules,proto3" json:"modules,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// Removes the levels of all modules before setting the ones given.
+	ResetModules bool `protobuf:"varint,3,opt,name=reset_modules,json=resetModules,proto3" json:"reset_modules,omitempty"`
+}
+
+func (x *UpdateLogLevelRequest) Reset() {
+	*x = UpdateLogLevelRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[151]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *UpdateLogLevelRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateLogLevelRequest) ProtoMessage() {}
+
+func (x *UpdateLogLevelRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[151]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateLogLevelRequest.ProtoReflect.Descriptor instead.
+func (*UpdateLogLevelRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{151}
+}
+
+func (x *UpdateLogLevelRequest) GetLevel() string {
+	if x != nil {
+		return x.Level
+	}
+	return ""
+}
+
+func (x *UpdateLogLevelRequest) GetModules() map[string]string {
+	if x != nil {
+		return x.Modules
+	}
+	return nil
+}
+
+func (x *UpdateLogLevelRequest) GetResetModules() bool {
+	if x != nil {
+		return x.ResetModules
+	}
+	return false
+}
+
+type UpdateLogLevelResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Level   string            `protobuf:"bytes,1,opt,name=level,proto3" json:"level,omitempty"`
+	Modules map[string]string `protobuf:"bytes,2,rep,name=modules,proto3" json:"modules,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+}
+
+func (x *UpdateLogLevelResponse) Reset() {
+	*x = UpdateLogLevelResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[152]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *UpdateLogLevelResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateLogLevelResponse) ProtoMessage() {}
+
+func (x *UpdateLogLevelResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[152]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateLogLevelResponse.ProtoReflect.Descriptor instead.
+func (*UpdateLogLevelResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{152}
+}
+
+func (x *UpdateLogLevelResponse) GetLevel() string {
+	if x != nil {
+		return x.Level
+	}
+	return ""
+}
+
+func (x *UpdateLogLevelResponse) GetModules() map[string]string {
+	if x != nil {
+		return x.Modules
+	}
+	return nil
+}
+
+type CreateBackupRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// skip_objects leaves the object store out of the backup. The object store
+	// is usually by far the largest database and only holds cached artifacts.
+	SkipObjects bool `protobuf:"varint,1,opt,name=skip_objects,json=skipObjects,proto3" json:"skip_objects,omitempty"`
+}
+
+func (x *CreateBackupRequest) Reset() {
+	*x = CreateBackupRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[153]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateBackupRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateBackupRequest) ProtoMessage() {}
+
+func (x *CreateBackupRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[153]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateBackupRequest.ProtoReflect.Descriptor instead.
+func (*CreateBackupRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{153}
+}
+
+func (x *CreateBackupRequest) GetSkipObjects() bool {
+	if x != nil {
+		return x.SkipObjects
+	}
+	return false
+}
+
+type CreateBackupResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Chunk []byte `protobuf:"bytes,1,opt,name=chunk,proto3" json:"chunk,omitempty"`
+}
+
+func (x *CreateBackupResponse) Reset() {
+	*x = CreateBackupResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[154]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateBackupResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateBackupResponse) ProtoMessage() {}
+
+func (x *CreateBackupResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[154]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateBackupResponse.ProtoReflect.Descriptor instead.
+func (*CreateBackupResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{154}
+}
+
+func (x *CreateBackupResponse) GetChunk() []byte {
+	if x != nil {
+		return x.Chunk
+	}
+	return nil
+}
+
+type CreateTokenRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Kind       CreateTokenRequest_Kind `protobuf:"varint,1,opt,name=kind,proto3,enum=proto.CreateTokenRequest_Kind" json:"kind,omitempty"`
+	Namespaces []string                `protobuf:"bytes,2,rep,name=namespaces,proto3" json:"namespaces,omitempty"`
+	Metadata   map[string]string       `protobuf:"bytes,3,rep,name=metadata,proto3" json:"metadata,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// Create the token for a directory user; the kind and namespaces are derived
+	// from the user's directory groups instead.
+	LdapUser string `protobuf:"bytes,4,opt,name=ldap_user,json=ldapUser,proto3" json:"ldap_user,omitempty"`
+	// Limit the addresses the token can be used from; empty allows any address.
+	AllowedCidrs []string `protobuf:"bytes,5,rep,name=allowed_cidrs,json=allowedCidrs,proto3" json:"allowed_cidrs,omitempty"`
+	// How long the token lasts in seconds; 0 means it never expires.
+	Ttl int64 `protobuf:"varint,6,opt,name=ttl,proto3" json:"ttl,omitempty"`
+}
+
+func (x *CreateTokenRequest) Reset() {
+	*x = CreateTokenRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[155]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateTokenRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateTokenRequest) ProtoMessage() {}
+
+func (x *CreateTokenRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[155]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateTokenRequest.ProtoReflect.Descriptor instead.
+func (*CreateTokenRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{155}
+}
+
+func (x *CreateTokenRequest) GetKind() CreateTokenRequest_Kind {
+	if x != nil {
+		return x.Kind
+	}
+	return CreateTokenRequest_UNKNOWN
+}
+
+func (x *CreateTokenRequest) GetNamespaces() []string {
+	if x != nil {
+		return x.Namespaces
+	}
+	return nil
+}
+
+func (x *CreateTokenRequest) GetMetadata() map[string]string {
+	if x != nil {
+		return x.Metadata
+	}
+	return nil
+}
+
+func (x *CreateTokenRequest) GetLdapUser() string {
+	if x != nil {
+		return x.LdapUser
+	}
+	return ""
+}
+
+func (x *CreateTokenRequest) GetAllowedCidrs() []string {
+	if x != nil {
+		return x.AllowedCidrs
+	}
+	return nil
+}
+
+func (x *CreateTokenRequest) GetTtl() int64 {
+	if x != nil {
+		return x.Ttl
+	}
+	return 0
+}
+
+type CreateTokenResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
+	Token   string `protobuf:"bytes,2,opt,name=token,proto3" json:"token,omitempty"`
+}
+
+func (x *CreateTokenResponse) Reset() {
+	*x = CreateTokenResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[156]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateTokenResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateTokenResponse) ProtoMessage() {}
+
+func (x *CreateTokenResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[156]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateTokenResponse.ProtoReflect.Descriptor instead.
+func (*CreateTokenResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{156}
+}
+
+func (x *CreateTokenResponse) GetDetails() *Token {
+	if x != nil {
+		return x.Details
+	}
+	return nil
+}
+
+func (x *CreateTokenResponse) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+type BootstrapTokenRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *BootstrapTokenRequest) Reset() {
+	*x = BootstrapTokenRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[157]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *BootstrapTokenRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BootstrapTokenRequest) ProtoMessage() {}
+
+func (x *BootstrapTokenRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[157]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BootstrapTokenRequest.ProtoReflect.Descriptor instead.
+func (*BootstrapTokenRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{157}
+}
+
+type BootstrapTokenResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
+	Token   string `protobuf:"bytes,2,opt,name=token,proto3" json:"token,omitempty"`
+}
+
+func (x *BootstrapTokenResponse) Reset() {
+	*x = BootstrapTokenResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[158]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *BootstrapTokenResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BootstrapTokenResponse) ProtoMessage() {}
+
+func (x *BootstrapTokenResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[158]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BootstrapTokenResponse.ProtoReflect.Descriptor instead.
+func (*BootstrapTokenResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{158}
+}
+
+func (x *BootstrapTokenResponse) GetDetails() *Token {
+	if x != nil {
+		return x.Details
+	}
+	return nil
+}
+
+func (x *BootstrapTokenResponse) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+type GetTokenRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Token string `protobuf:"bytes,1,opt,name=token,proto3" json:"token,omitempty"`
+}
+
+func (x *GetTokenRequest) Reset() {
+	*x = GetTokenRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[159]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetTokenRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetTokenRequest) ProtoMessage() {}
+
+func (x *GetTokenRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[159]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetTokenRequest.ProtoReflect.Descriptor instead.
+func (*GetTokenRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{159}
+}
+
+func (x *GetTokenRequest) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+type GetTokenResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
+}
+
+func (x *GetTokenResponse) Reset() {
+	*x = GetTokenResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[160]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetTokenResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetTokenResponse) ProtoMessage() {}
+
+func (x *GetTokenResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[160]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetTokenResponse.ProtoReflect.Descriptor instead.
+func (*GetTokenResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{160}
+}
+
+func (x *GetTokenResponse) GetDetails() *Token {
+	if x != nil {
+		return x.Details
+	}
+	return nil
+}
+
+type ListTokensRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// offset is a pagination parameter that defines where to start when
+	// counting the list of tokens to return
+	Offset int64 `protobuf:"varint,1,opt,name=offset,proto3" json:"offset,omitempty"`
+	// limit is a pagination parameter that defines how many tokens to return
+	// per result.
+	Limit int64 `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
+	// Only return tokens that have not been used since the given time in epoch
+	// milli. Tokens that were never used are included.
+	UnusedSince int64 `protobuf:"varint,3,opt,name=unused_since,json=unusedSince,proto3" json:"unused_since,omitempty"`
+	// Only return tokens that made at least the given number of requests within
+	// the last day.
+	MinRequestsLastDay int64 `protobuf:"varint,4,opt,name=min_requests_last_day,json=minRequestsLastDay,proto3" json:"min_requests_last_day,omitempty"`
+}
+
+func (x *ListTokensRequest) Reset() {
+	*x = ListTokensRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[161]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListTokensRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListTokensRequest) ProtoMessage() {}
+
+func (x *ListTokensRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[161]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListTokensRequest.ProtoReflect.Descriptor instead.
+func (*ListTokensRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{161}
+}
+
+func (x *ListTokensRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+func (x *ListTokensRequest) GetLimit() int64 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+func (x *ListTokensRequest) GetUnusedSince() int64 {
+	if x != nil {
+		return x.UnusedSince
+	}
+	return 0
+}
+
+func (x *ListTokensRequest) GetMinRequestsLastDay() int64 {
+	if x != nil {
+		return x.MinRequestsLastDay
+	}
+	return 0
+}
+
+type ListTokensResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Tokens []*Token `protobuf:"bytes,1,rep,name=tokens,proto3" json:"tokens,omitempty"`
+}
+
+func (x *ListTokensResponse) Reset() {
+	*x = ListTokensResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[162]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListTokensResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListTokensResponse) ProtoMessage() {}
+
+func (x *ListTokensResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[162]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListTokensResponse.ProtoReflect.Descriptor instead.
+func (*ListTokensResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{162}
+}
+
+func (x *ListTokensResponse) GetTokens() []*Token {
+	if x != nil {
+		return x.Tokens
+	}
+	return nil
+}
+
+type ListExpiringTokensRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Return tokens that expire within the given amount of seconds. Tokens that
+	// have already expired but have not been removed yet are included.
+	Within int64 `protobuf:"varint,1,opt,name=within,proto3" json:"within,omitempty"`
+}
+
+func (x *ListExpiringTokensRequest) Reset() {
+	*x = ListExpiringTokensRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[163]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListExpiringTokensRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListExpiringTokensRequest) ProtoMessage() {}
+
+func (x *ListExpiringTokensRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[163]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListExpiringTokensRequest.ProtoReflect.Descriptor instead.
+func (*ListExpiringTokensRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{163}
+}
+
+func (x *ListExpiringTokensRequest) GetWithin() int64 {
+	if x != nil {
+		return x.Within
+	}
+	return 0
+}
+
+type ListExpiringTokensResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Tokens []*Token `protobuf:"bytes,1,rep,name=tokens,proto3" json:"tokens,omitempty"`
+}
+
+func (x *ListExpiringTokensResponse) Reset() {
+	*x = ListExpiringTokensResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[164]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListExpiringTokensResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListExpiringTokensResponse) ProtoMessage() {}
+
+func (x *ListExpiringTokensResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[164]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListExpiringTokensResponse.ProtoReflect.Descriptor instead.
+func (*ListExpiringTokensResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{164}
+}
+
+func (x *ListExpiringTokensResponse) GetTokens() []*Token {
+	if x != nil {
+		return x.Tokens
+	}
+	return nil
+}
+
+type DeleteTokenRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Token string `protobuf:"bytes,1,opt,name=token,proto3" json:"token,omitempty"`
+}
+
+func (x *DeleteTokenRequest) Reset() {
+	*x = DeleteTokenRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[165]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteTokenRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteTokenRequest) ProtoMessage() {}
+
+func (x *DeleteTokenRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[165]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteTokenRequest.ProtoReflect.Descriptor instead.
+func (*DeleteTokenRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{165}
+}
+
+func (x *DeleteTokenRequest) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+type DeleteTokenResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeleteTokenResponse) Reset() {
+	*x = DeleteTokenResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[166]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteTokenResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteTokenResponse) ProtoMessage() {}
+
+func (x *DeleteTokenResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[166]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteTokenResponse.ProtoReflect.Descriptor instead.
+func (*DeleteTokenResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{166}
+}
+
+type DeleteUserTokensRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	User string `protobuf:"bytes,1,opt,name=user,proto3" json:"user,omitempty"`
+}
+
+func (x *DeleteUserTokensRequest) Reset() {
+	*x = DeleteUserTokensRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[167]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteUserTokensRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteUserTokensRequest) ProtoMessage() {}
+
+func (x *DeleteUserTokensRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[167]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteUserTokensRequest.ProtoReflect.Descriptor instead.
+func (*DeleteUserTokensRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{167}
+}
+
+func (x *DeleteUserTokensRequest) GetUser() string {
+	if x != nil {
+		return x.User
+	}
+	return ""
+}
+
+type DeleteUserTokensResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Deleted int64 `protobuf:"varint,1,opt,name=deleted,proto3" json:"deleted,omitempty"` // The number of tokens revoked.
+}
+
+func (x *DeleteUserTokensResponse) Reset() {
+	*x = DeleteUserTokensResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[168]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteUserTokensResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteUserTokensResponse) ProtoMessage() {}
+
+func (x *DeleteUserTokensResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[168]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteUserTokensResponse.ProtoReflect.Descriptor instead.
+func (*DeleteUserTokensResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{168}
+}
+
+func (x *DeleteUserTokensResponse) GetDeleted() int64 {
+	if x != nil {
+		return x.Deleted
+	}
+	return 0
+}
+
+type RotateTokenRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Token string `protobuf:"bytes,1,opt,name=token,proto3" json:"token,omitempty"`
+	// How long the old token keeps working after being rotated in seconds; 0
+	// stops it immediately.
+	GracePeriod int64 `protobuf:"varint,2,opt,name=grace_period,json=gracePeriod,proto3" json:"grace_period,omitempty"`
+}
+
+func (x *RotateTokenRequest) Reset() {
+	*x = RotateTokenRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[169]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *RotateTokenRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RotateTokenRequest) ProtoMessage() {}
+
+func (x *RotateTokenRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[169]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RotateTokenRequest.ProtoReflect.Descriptor instead.
+func (*RotateTokenRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{169}
+}
+
+func (x *RotateTokenRequest) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+func (x *RotateTokenRequest) GetGracePeriod() int64 {
+	if x != nil {
+		return x.GracePeriod
+	}
+	return 0
+}
+
+type RotateTokenResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
+	Token   string `protobuf:"bytes,2,opt,name=token,proto3" json:"token,omitempty"`
+}
+
+func (x *RotateTokenResponse) Reset() {
+	*x = RotateTokenResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[170]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *RotateTokenResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RotateTokenResponse) ProtoMessage() {}
+
+func (x *RotateTokenResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[170]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RotateTokenResponse.ProtoReflect.Descriptor instead.
+func (*RotateTokenResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{170}
+}
+
+func (x *RotateTokenResponse) GetDetails() *Token {
+	if x != nil {
+		return x.Details
+	}
+	return nil
+}
+
+func (x *RotateTokenResponse) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+type ExchangeOIDCTokenRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	IdToken string `protobuf:"bytes,1,opt,name=id_token,json=idToken,proto3" json:"id_token,omitempty"`
+}
+
+func (x *ExchangeOIDCTokenRequest) Reset() {
+	*x = ExchangeOIDCTokenRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[171]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ExchangeOIDCTokenRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExchangeOIDCTokenRequest) ProtoMessage() {}
+
+func (x *ExchangeOIDCTokenRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[171]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExchangeOIDCTokenRequest.ProtoReflect.Descriptor instead.
+func (*ExchangeOIDCTokenRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{171}
+}
+
+func (x *ExchangeOIDCTokenRequest) GetIdToken() string {
+	if x != nil {
+		return x.IdToken
+	}
+	return ""
+}
+
+type ExchangeOIDCTokenResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
+	Token   string `protobuf:"bytes,2,opt,name=token,proto3" json:"token,omitempty"`
+}
+
+func (x *ExchangeOIDCTokenResponse) Reset() {
+	*x = ExchangeOIDCTokenResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[172]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ExchangeOIDCTokenResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExchangeOIDCTokenResponse) ProtoMessage() {}
+
+func (x *ExchangeOIDCTokenResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[172]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExchangeOIDCTokenResponse.ProtoReflect.Descriptor instead.
+func (*ExchangeOIDCTokenResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{172}
+}
+
+func (x *ExchangeOIDCTokenResponse) GetDetails() *Token {
+	if x != nil {
+		return x.Details
+	}
+	return nil
+}
+
+func (x *ExchangeOIDCTokenResponse) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+type ListServiceAccountsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Offset int64 `protobuf:"varint,1,opt,name=offset,proto3" json:"offset,omitempty"`
+	Limit  int64 `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
+}
+
+func (x *ListServiceAccountsRequest) Reset() {
+	*x = ListServiceAccountsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[173]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListServiceAccountsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListServiceAccountsRequest) ProtoMessage() {}
+
+func (x *ListServiceAccountsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[173]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListServiceAccountsRequest.ProtoReflect.Descriptor instead.
+func (*ListServiceAccountsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{173}
+}
+
+func (x *ListServiceAccountsRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+func (x *ListServiceAccountsRequest) GetLimit() int64 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+type ListServiceAccountsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	ServiceAccounts []*ServiceAccount `protobuf:"bytes,1,rep,name=service_accounts,json=serviceAccounts,proto3" json:"service_accounts,omitempty"`
+}
+
+func (x *ListServiceAccountsResponse) Reset() {
+	*x = ListServiceAccountsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[174]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListServiceAccountsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListServiceAccountsResponse) ProtoMessage() {}
+
+func (x *ListServiceAccountsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[174]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListServiceAccountsResponse.ProtoReflect.Descriptor instead.
+func (*ListServiceAccountsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{174}
+}
+
+func (x *ListServiceAccountsResponse) GetServiceAccounts() []*ServiceAccount {
+	if x != nil {
+		return x.ServiceAccounts
+	}
+	return nil
+}
+
+type GetServiceAccountRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *GetServiceAccountRequest) Reset() {
+	*x = GetServiceAccountRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[175]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetServiceAccountRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetServiceAccountRequest) ProtoMessage() {}
+
+func (x *GetServiceAccountRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[175]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetServiceAccountRequest.ProtoReflect.Descriptor instead.
+func (*GetServiceAccountRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{175}
+}
+
+func (x *GetServiceAccountRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+type GetServiceAccountResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	ServiceAccount *ServiceAccount `protobuf:"bytes,1,opt,name=service_account,json=serviceAccount,proto3" json:"service_account,omitempty"`
+}
+
+func (x *GetServiceAccountResponse) Reset() {
+	*x = GetServiceAccountResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[176]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetServiceAccountResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetServiceAccountResponse) ProtoMessage() {}
+
+func (x *GetServiceAccountResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[176]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetServiceAccountResponse.ProtoReflect.Descriptor instead.
+func (*GetServiceAccountResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{176}
+}
+
+func (x *GetServiceAccountResponse) GetServiceAccount() *ServiceAccount {
+	if x != nil {
+		return x.ServiceAccount
+	}
+	return nil
+}
+
+type CreateServiceAccountRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id          string     `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Description string     `protobuf:"bytes,2,opt,name=description,proto3" json:"description,omitempty"`
+	Kind        Token_Kind `protobuf:"varint,3,opt,name=kind,proto3,enum=proto.Token_Kind" json:"kind,omitempty"`
+	Namespaces  []string   `protobuf:"bytes,4,rep,name=namespaces,proto3" json:"namespaces,omitempty"`
+}
+
+func (x *CreateServiceAccountRequest) Reset() {
+	*x = CreateServiceAccountRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[177]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateServiceAccountRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateServiceAccountRequest) ProtoMessage() {}
+
+func (x *CreateServiceAccountRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[177]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateServiceAccountRequest.ProtoReflect.Descriptor instead.
+func (*CreateServiceAccountRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{177}
+}
+
+func (x *CreateServiceAccountRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *CreateServiceAccountRequest) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *CreateServiceAccountRequest) GetKind() Token_Kind {
+	if x != nil {
+		return x.Kind
+	}
+	return Token_UNKNOWN
+}
+
+func (x *CreateServiceAccountRequest) GetNamespaces() []string {
+	if x != nil {
+		return x.Namespaces
+	}
+	return nil
+}
+
+type CreateServiceAccountResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	ServiceAccount *ServiceAccount `protobuf:"bytes,1,opt,name=service_account,json=serviceAccount,proto3" json:"service_account,omitempty"`
+}
+
+func (x *CreateServiceAccountResponse) Reset() {
+	*x = CreateServiceAccountResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[178]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateServiceAccountResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateServiceAccountResponse) ProtoMessage() {}
+
+func (x *CreateServiceAccountResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[178]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateServiceAccountResponse.ProtoReflect.Descriptor instead.
+func (*CreateServiceAccountResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{178}
+}
+
+func (x *CreateServiceAccountResponse) GetServiceAccount() *ServiceAccount {
+	if x != nil {
+		return x.ServiceAccount
+	}
+	return nil
+}
+
+type DeleteServiceAccountRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id string `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *DeleteServiceAccountRequest) Reset() {
+	*x = DeleteServiceAccountRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[179]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteServiceAccountRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteServiceAccountRequest) ProtoMessage() {}
+
+func (x *DeleteServiceAccountRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[179]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteServiceAccountRequest.ProtoReflect.Descriptor instead.
+func (*DeleteServiceAccountRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{179}
+}
+
+func (x *DeleteServiceAccountRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+type DeleteServiceAccountResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeleteServiceAccountResponse) Reset() {
+	*x = DeleteServiceAccountResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[180]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteServiceAccountResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteServiceAccountResponse) ProtoMessage() {}
+
+func (x *DeleteServiceAccountResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[180]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteServiceAccountResponse.ProtoReflect.Descriptor instead.
+func (*DeleteServiceAccountResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{180}
+}
+
+type CreateServiceAccountTokenRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id       string            `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Metadata map[string]string `protobuf:"bytes,2,rep,name=metadata,proto3" json:"metadata,omitempty" protobuf_key:"bytes,1,opt,name=key,proto3" protobuf_val:"bytes,2,opt,name=value,proto3"`
+	// Limit the addresses the token can be used from; empty allows any address.
+	AllowedCidrs []string `protobuf:"bytes,3,rep,name=allowed_cidrs,json=allowedCidrs,proto3" json:"allowed_cidrs,omitempty"`
+	// How long the token lasts in seconds; 0 means it never expires.
+	Ttl int64 `protobuf:"varint,4,opt,name=ttl,proto3" json:"ttl,omitempty"`
+}
+
+func (x *CreateServiceAccountTokenRequest) Reset() {
+	*x = CreateServiceAccountTokenRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[181]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateServiceAccountTokenRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateServiceAccountTokenRequest) ProtoMessage() {}
+
+func (x *CreateServiceAccountTokenRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[181]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateServiceAccountTokenRequest.ProtoReflect.Descriptor instead.
+func (*CreateServiceAccountTokenRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{181}
+}
+
+func (x *CreateServiceAccountTokenRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
+}
+
+func (x *CreateServiceAccountTokenRequest) GetMetadata() map[string]string {
+	if x != nil {
+		return x.Metadata
+	}
+	return nil
+}
+
+func (x *CreateServiceAccountTokenRequest) GetAllowedCidrs() []string {
+	if x != nil {
+		return x.AllowedCidrs
+	}
+	return nil
+}
+
+func (x *CreateServiceAccountTokenRequest) GetTtl() int64 {
+	if x != nil {
+		return x.Ttl
+	}
+	return 0
+}
+
+type CreateServiceAccountTokenResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Details *Token `protobuf:"bytes,1,opt,name=details,proto3" json:"details,omitempty"`
+	Token   string `protobuf:"bytes,2,opt,name=token,proto3" json:"token,omitempty"`
+}
+
+func (x *CreateServiceAccountTokenResponse) Reset() {
+	*x = CreateServiceAccountTokenResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[182]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateServiceAccountTokenResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateServiceAccountTokenResponse) ProtoMessage() {}
+
+func (x *CreateServiceAccountTokenResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[182]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateServiceAccountTokenResponse.ProtoReflect.Descriptor instead.
+func (*CreateServiceAccountTokenResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{182}
+}
+
+func (x *CreateServiceAccountTokenResponse) GetDetails() *Token {
+	if x != nil {
+		return x.Details
+	}
+	return nil
+}
+
+func (x *CreateServiceAccountTokenResponse) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+type ListAuthzDecisionsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// offset is a pagination parameter that defines where to start when
+	// counting the list of decisions to return
+	Offset int64 `protobuf:"varint,1,opt,name=offset,proto3" json:"offset,omitempty"`
+	// limit is a pagination parameter that defines how many decisions to return
+	// per result.
+	Limit      int64  `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
+	Caller     string `protobuf:"bytes,3,opt,name=caller,proto3" json:"caller,omitempty"`                            // Only return decisions for the given caller.
+	DeniedOnly bool   `protobuf:"varint,4,opt,name=denied_only,json=deniedOnly,proto3" json:"denied_only,omitempty"` // Only return decisions in which the caller was denied.
+	Since      int64  `protobuf:"varint,5,opt,name=since,proto3" json:"since,omitempty"`                             // Only return decisions made at or after the given time in epoch milli.
+}
+
+func (x *ListAuthzDecisionsRequest) Reset() {
+	*x = ListAuthzDecisionsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[183]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListAuthzDecisionsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListAuthzDecisionsRequest) ProtoMessage() {}
+
+func (x *ListAuthzDecisionsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[183]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListAuthzDecisionsRequest.ProtoReflect.Descriptor instead.
+func (*ListAuthzDecisionsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{183}
+}
+
+func (x *ListAuthzDecisionsRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+func (x *ListAuthzDecisionsRequest) GetLimit() int64 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+func (x *ListAuthzDecisionsRequest) GetCaller() string {
+	if x != nil {
+		return x.Caller
+	}
+	return ""
+}
+
+func (x *ListAuthzDecisionsRequest) GetDeniedOnly() bool {
+	if x != nil {
+		return x.DeniedOnly
+	}
+	return false
+}
+
+func (x *ListAuthzDecisionsRequest) GetSince() int64 {
+	if x != nil {
+		return x.Since
+	}
+	return 0
+}
+
+type ListAuthzDecisionsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Decisions []*AuthzDecision `protobuf:"bytes,1,rep,name=decisions,proto3" json:"decisions,omitempty"`
+}
+
+func (x *ListAuthzDecisionsResponse) Reset() {
+	*x = ListAuthzDecisionsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[184]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListAuthzDecisionsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListAuthzDecisionsResponse) ProtoMessage() {}
+
+func (x *ListAuthzDecisionsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[184]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListAuthzDecisionsResponse.ProtoReflect.Descriptor instead.
+func (*ListAuthzDecisionsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{184}
+}
+
+func (x *ListAuthzDecisionsResponse) GetDecisions() []*AuthzDecision {
+	if x != nil {
+		return x.Decisions
+	}
+	return nil
+}
+
+type ListDeadLettersRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// offset is a pagination parameter that defines where to start when
+	// counting the list of dead letters to return
+	Offset int64 `protobuf:"varint,1,opt,name=offset,proto3" json:"offset,omitempty"`
+	// limit is a pagination parameter that defines how many dead letters to
+	// return per result.
+	Limit int64 `protobuf:"varint,2,opt,name=limit,proto3" json:"limit,omitempty"`
+}
+
+func (x *ListDeadLettersRequest) Reset() {
+	*x = ListDeadLettersRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[185]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListDeadLettersRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListDeadLettersRequest) ProtoMessage() {}
+
+func (x *ListDeadLettersRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[185]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListDeadLettersRequest.ProtoReflect.Descriptor instead.
+func (*ListDeadLettersRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{185}
+}
+
+func (x *ListDeadLettersRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+func (x *ListDeadLettersRequest) GetLimit() int64 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+type ListDeadLettersResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	DeadLetters []*DeadLetter `protobuf:"bytes,1,rep,name=dead_letters,json=deadLetters,proto3" json:"dead_letters,omitempty"`
+}
+
+func (x *ListDeadLettersResponse) Reset() {
+	*x = ListDeadLettersResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[186]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListDeadLettersResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListDeadLettersResponse) ProtoMessage() {}
+
+func (x *ListDeadLettersResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[186]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListDeadLettersResponse.ProtoReflect.Descriptor instead.
+func (*ListDeadLettersResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{186}
+}
+
+func (x *ListDeadLettersResponse) GetDeadLetters() []*DeadLetter {
+	if x != nil {
+		return x.DeadLetters
+	}
+	return nil
+}
+
+type GetDeadLetterRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *GetDeadLetterRequest) Reset() {
+	*x = GetDeadLetterRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[187]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetDeadLetterRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetDeadLetterRequest) ProtoMessage() {}
+
+func (x *GetDeadLetterRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[187]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetDeadLetterRequest.ProtoReflect.Descriptor instead.
+func (*GetDeadLetterRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{187}
+}
+
+func (x *GetDeadLetterRequest) GetId() int64 {
+	if x != nil {
+		return x.Id
+	}
+	return 0
+}
+
+type GetDeadLetterResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	DeadLetter *DeadLetter `protobuf:"bytes,1,opt,name=dead_letter,json=deadLetter,proto3" json:"dead_letter,omitempty"`
+}
+
+func (x *GetDeadLetterResponse) Reset() {
+	*x = GetDeadLetterResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[188]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetDeadLetterResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetDeadLetterResponse) ProtoMessage() {}
+
+func (x *GetDeadLetterResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[188]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetDeadLetterResponse.ProtoReflect.Descriptor instead.
+func (*GetDeadLetterResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{188}
+}
+
+func (x *GetDeadLetterResponse) GetDeadLetter() *DeadLetter {
+	if x != nil {
+		return x.DeadLetter
+	}
+	return nil
+}
+
+type RedriveDeadLetterRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *RedriveDeadLetterRequest) Reset() {
+	*x = RedriveDeadLetterRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[189]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *RedriveDeadLetterRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RedriveDeadLetterRequest) ProtoMessage() {}
+
+func (x *RedriveDeadLetterRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[189]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RedriveDeadLetterRequest.ProtoReflect.Descriptor instead.
+func (*RedriveDeadLetterRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{189}
+}
+
+func (x *RedriveDeadLetterRequest) GetId() int64 {
+	if x != nil {
+		return x.Id
+	}
+	return 0
+}
+
+type RedriveDeadLetterResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *RedriveDeadLetterResponse) Reset() {
+	*x = RedriveDeadLetterResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[190]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *RedriveDeadLetterResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RedriveDeadLetterResponse) ProtoMessage() {}
+
+func (x *RedriveDeadLetterResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[190]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RedriveDeadLetterResponse.ProtoReflect.Descriptor instead.
+func (*RedriveDeadLetterResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{190}
+}
+
+type DeleteDeadLetterRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id int64 `protobuf:"varint,1,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *DeleteDeadLetterRequest) Reset() {
+	*x = DeleteDeadLetterRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[191]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteDeadLetterRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteDeadLetterRequest) ProtoMessage() {}
+
+func (x *DeleteDeadLetterRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[191]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteDeadLetterRequest.ProtoReflect.Descriptor instead.
+func (*DeleteDeadLetterRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{191}
+}
+
+func (x *DeleteDeadLetterRequest) GetId() int64 {
+	if x != nil {
+		return x.Id
+	}
+	return 0
+}
+
+type DeleteDeadLetterResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeleteDeadLetterResponse) Reset() {
+	*x = DeleteDeadLetterResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[192]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteDeadLetterResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteDeadLetterResponse) ProtoMessage() {}
+
+func (x *DeleteDeadLetterResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[192]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteDeadLetterResponse.ProtoReflect.Descriptor instead.
+func (*DeleteDeadLetterResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{192}
+}
+
+type ListWebhooksRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Defaults to the namespace of the caller's token.
+}
+
+func (x *ListWebhooksRequest) Reset() {
+	*x = ListWebhooksRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[193]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListWebhooksRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListWebhooksRequest) ProtoMessage() {}
+
+func (x *ListWebhooksRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[193]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListWebhooksRequest.ProtoReflect.Descriptor instead.
+func (*ListWebhooksRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{193}
+}
+
+func (x *ListWebhooksRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+type ListWebhooksResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Webhooks []*Webhook `protobuf:"bytes,1,rep,name=webhooks,proto3" json:"webhooks,omitempty"`
+}
+
+func (x *ListWebhooksResponse) Reset() {
+	*x = ListWebhooksResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[194]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListWebhooksResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListWebhooksResponse) ProtoMessage() {}
+
+func (x *ListWebhooksResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[194]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListWebhooksResponse.ProtoReflect.Descriptor instead.
+func (*ListWebhooksResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{194}
+}
+
+func (x *ListWebhooksResponse) GetWebhooks() []*Webhook {
+	if x != nil {
+		return x.Webhooks
+	}
+	return nil
+}
+
+type GetWebhookRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	Id          int64  `protobuf:"varint,2,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *GetWebhookRequest) Reset() {
+	*x = GetWebhookRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[195]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetWebhookRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetWebhookRequest) ProtoMessage() {}
+
+func (x *GetWebhookRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[195]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetWebhookRequest.ProtoReflect.Descriptor instead.
+func (*GetWebhookRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{195}
+}
+
+func (x *GetWebhookRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *GetWebhookRequest) GetId() int64 {
+	if x != nil {
+		return x.Id
+	}
+	return 0
+}
+
+type GetWebhookResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Webhook *Webhook `protobuf:"bytes,1,opt,name=webhook,proto3" json:"webhook,omitempty"`
+}
+
+func (x *GetWebhookResponse) Reset() {
+	*x = GetWebhookResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[196]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetWebhookResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetWebhookResponse) ProtoMessage() {}
+
+func (x *GetWebhookResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[196]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetWebhookResponse.ProtoReflect.Descriptor instead.
+func (*GetWebhookResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{196}
+}
+
+func (x *GetWebhookResponse) GetWebhook() *Webhook {
+	if x != nil {
+		return x.Webhook
+	}
+	return nil
+}
+
+type CreateWebhookRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string   `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	PipelineId  string   `protobuf:"bytes,2,opt,name=pipeline_id,json=pipelineId,proto3" json:"pipeline_id,omitempty"` // Optional; every pipeline in the namespace if empty.
+	Url         string   `protobuf:"bytes,3,opt,name=url,proto3" json:"url,omitempty"`
+	Events      []string `protobuf:"bytes,4,rep,name=events,proto3" json:"events,omitempty"` // Optional; all run lifecycle events if empty.
+	Secret      string   `protobuf:"bytes,5,opt,name=secret,proto3" json:"secret,omitempty"` // Optional; one is generated if empty.
+}
+
+func (x *CreateWebhookRequest) Reset() {
+	*x = CreateWebhookRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[197]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateWebhookRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateWebhookRequest) ProtoMessage() {}
+
+func (x *CreateWebhookRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[197]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateWebhookRequest.ProtoReflect.Descriptor instead.
+func (*CreateWebhookRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{197}
+}
+
+func (x *CreateWebhookRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *CreateWebhookRequest) GetPipelineId() string {
+	if x != nil {
+		return x.PipelineId
+	}
+	return ""
+}
+
+func (x *CreateWebhookRequest) GetUrl() string {
+	if x != nil {
+		return x.Url
+	}
+	return ""
+}
+
+func (x *CreateWebhookRequest) GetEvents() []string {
+	if x != nil {
+		return x.Events
+	}
+	return nil
+}
+
+func (x *CreateWebhookRequest) GetSecret() string {
+	if x != nil {
+		return x.Secret
+	}
+	return ""
+}
+
+type CreateWebhookResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Webhook *Webhook `protobuf:"bytes,1,opt,name=webhook,proto3" json:"webhook,omitempty"`
+	// The key used to sign deliveries. It is only ever returned here.
+	Secret string `protobuf:"bytes,2,opt,name=secret,proto3" json:"secret,omitempty"`
+}
+
+func (x *CreateWebhookResponse) Reset() {
+	*x = CreateWebhookResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[198]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateWebhookResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateWebhookResponse) ProtoMessage() {}
+
+func (x *CreateWebhookResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[198]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateWebhookResponse.ProtoReflect.Descriptor instead.
+func (*CreateWebhookResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{198}
+}
+
+func (x *CreateWebhookResponse) GetWebhook() *Webhook {
+	if x != nil {
+		return x.Webhook
+	}
+	return nil
+}
+
+func (x *CreateWebhookResponse) GetSecret() string {
+	if x != nil {
+		return x.Secret
+	}
+	return ""
+}
+
+type DeleteWebhookRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	Id          int64  `protobuf:"varint,2,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *DeleteWebhookRequest) Reset() {
+	*x = DeleteWebhookRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[199]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteWebhookRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteWebhookRequest) ProtoMessage() {}
+
+func (x *DeleteWebhookRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[199]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteWebhookRequest.ProtoReflect.Descriptor instead.
+func (*DeleteWebhookRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{199}
+}
+
+func (x *DeleteWebhookRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *DeleteWebhookRequest) GetId() int64 {
+	if x != nil {
+		return x.Id
+	}
+	return 0
+}
+
+type DeleteWebhookResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeleteWebhookResponse) Reset() {
+	*x = DeleteWebhookResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[200]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteWebhookResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteWebhookResponse) ProtoMessage() {}
+
+func (x *DeleteWebhookResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[200]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteWebhookResponse.ProtoReflect.Descriptor instead.
+func (*DeleteWebhookResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{200}
+}
+
+type ListWebhookDeliveriesRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	WebhookId   int64  `protobuf:"varint,2,opt,name=webhook_id,json=webhookId,proto3" json:"webhook_id,omitempty"`
+	// offset is a pagination parameter that defines where to start when
+	// counting the list of deliveries to return
+	Offset int64 `protobuf:"varint,3,opt,name=offset,proto3" json:"offset,omitempty"`
+	// limit is a pagination parameter that defines how many deliveries to
+	// return per result.
+	Limit int64 `protobuf:"varint,4,opt,name=limit,proto3" json:"limit,omitempty"`
+}
+
+func (x *ListWebhookDeliveriesRequest) Reset() {
+	*x = ListWebhookDeliveriesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[201]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListWebhookDeliveriesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListWebhookDeliveriesRequest) ProtoMessage() {}
+
+func (x *ListWebhookDeliveriesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[201]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListWebhookDeliveriesRequest.ProtoReflect.Descriptor instead.
+func (*ListWebhookDeliveriesRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{201}
+}
+
+func (x *ListWebhookDeliveriesRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *ListWebhookDeliveriesRequest) GetWebhookId() int64 {
+	if x != nil {
+		return x.WebhookId
+	}
+	return 0
+}
+
+func (x *ListWebhookDeliveriesRequest) GetOffset() int64 {
+	if x != nil {
+		return x.Offset
+	}
+	return 0
+}
+
+func (x *ListWebhookDeliveriesRequest) GetLimit() int64 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
+type ListWebhookDeliveriesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Deliveries []*WebhookDelivery `protobuf:"bytes,1,rep,name=deliveries,proto3" json:"deliveries,omitempty"`
+}
+
+func (x *ListWebhookDeliveriesResponse) Reset() {
+	*x = ListWebhookDeliveriesResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[202]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListWebhookDeliveriesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListWebhookDeliveriesResponse) ProtoMessage() {}
+
+func (x *ListWebhookDeliveriesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[202]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListWebhookDeliveriesResponse.ProtoReflect.Descriptor instead.
+func (*ListWebhookDeliveriesResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{202}
+}
+
+func (x *ListWebhookDeliveriesResponse) GetDeliveries() []*WebhookDelivery {
+	if x != nil {
+		return x.Deliveries
+	}
+	return nil
+}
+
+type ListRegistryCredentialsRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"` // Defaults to the namespace of the caller's token.
+}
+
+func (x *ListRegistryCredentialsRequest) Reset() {
+	*x = ListRegistryCredentialsRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[203]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListRegistryCredentialsRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRegistryCredentialsRequest) ProtoMessage() {}
+
+func (x *ListRegistryCredentialsRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[203]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRegistryCredentialsRequest.ProtoReflect.Descriptor instead.
+func (*ListRegistryCredentialsRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{203}
+}
+
+func (x *ListRegistryCredentialsRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+type ListRegistryCredentialsResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Credentials []*RegistryCredential `protobuf:"bytes,1,rep,name=credentials,proto3" json:"credentials,omitempty"`
+}
+
+func (x *ListRegistryCredentialsResponse) Reset() {
+	*x = ListRegistryCredentialsResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[204]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListRegistryCredentialsResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListRegistryCredentialsResponse) ProtoMessage() {}
+
+func (x *ListRegistryCredentialsResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[204]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListRegistryCredentialsResponse.ProtoReflect.Descriptor instead.
+func (*ListRegistryCredentialsResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{204}
+}
+
+func (x *ListRegistryCredentialsResponse) GetCredentials() []*RegistryCredential {
+	if x != nil {
+		return x.Credentials
+	}
+	return nil
+}
+
+type PutRegistryCredentialRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	// The registry and optionally a repository path the login is for.
+	// Ex: ghcr.io or ghcr.io/myorg
+	Registry string `protobuf:"bytes,2,opt,name=registry,proto3" json:"registry,omitempty"`
+	User     string `protobuf:"bytes,3,opt,name=user,proto3" json:"user,omitempty"`
+	Pass     string `protobuf:"bytes,4,opt,name=pass,proto3" json:"pass,omitempty"`
+}
+
+func (x *PutRegistryCredentialRequest) Reset() {
+	*x = PutRegistryCredentialRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[205]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PutRegistryCredentialRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PutRegistryCredentialRequest) ProtoMessage() {}
+
+func (x *PutRegistryCredentialRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[205]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PutRegistryCredentialRequest.ProtoReflect.Descriptor instead.
+func (*PutRegistryCredentialRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{205}
+}
+
+func (x *PutRegistryCredentialRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *PutRegistryCredentialRequest) GetRegistry() string {
+	if x != nil {
+		return x.Registry
+	}
+	return ""
+}
+
+func (x *PutRegistryCredentialRequest) GetUser() string {
+	if x != nil {
+		return x.User
+	}
+	return ""
+}
+
+func (x *PutRegistryCredentialRequest) GetPass() string {
+	if x != nil {
+		return x.Pass
+	}
+	return ""
+}
+
+type PutRegistryCredentialResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Credential *RegistryCredential `protobuf:"bytes,1,opt,name=credential,proto3" json:"credential,omitempty"`
+}
+
+func (x *PutRegistryCredentialResponse) Reset() {
+	*x = PutRegistryCredentialResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[206]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PutRegistryCredentialResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PutRegistryCredentialResponse) ProtoMessage() {}
+
+func (x *PutRegistryCredentialResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[206]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PutRegistryCredentialResponse.ProtoReflect.Descriptor instead.
+func (*PutRegistryCredentialResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{206}
+}
+
+func (x *PutRegistryCredentialResponse) GetCredential() *RegistryCredential {
+	if x != nil {
+		return x.Credential
+	}
+	return nil
+}
+
+type DeleteRegistryCredentialRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	NamespaceId string `protobuf:"bytes,1,opt,name=namespace_id,json=namespaceId,proto3" json:"namespace_id,omitempty"`
+	Registry    string `protobuf:"bytes,2,opt,name=registry,proto3" json:"registry,omitempty"`
+}
+
+func (x *DeleteRegistryCredentialRequest) Reset() {
+	*x = DeleteRegistryCredentialRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[207]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteRegistryCredentialRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteRegistryCredentialRequest) ProtoMessage() {}
+
+func (x *DeleteRegistryCredentialRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[207]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteRegistryCredentialRequest.ProtoReflect.Descriptor instead.
+func (*DeleteRegistryCredentialRequest) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{207}
+}
+
+func (x *DeleteRegistryCredentialRequest) GetNamespaceId() string {
+	if x != nil {
+		return x.NamespaceId
+	}
+	return ""
+}
+
+func (x *DeleteRegistryCredentialRequest) GetRegistry() string {
+	if x != nil {
+		return x.Registry
+	}
+	return ""
+}
+
+type DeleteRegistryCredentialResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *DeleteRegistryCredentialResponse) Reset() {
+	*x = DeleteRegistryCredentialResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_gofer_transport_proto_msgTypes[208]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *DeleteRegistryCredentialResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*DeleteRegistryCredentialResponse) ProtoMessage() {}
+
+func (x *DeleteRegistryCredentialResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_gofer_transport_proto_msgTypes[208]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use DeleteRegistryCredentialResponse.ProtoReflect.Descriptor instead.
+func (*DeleteRegistryCredentialResponse) Descriptor() ([]byte, []int) {
+	return file_gofer_transport_proto_rawDescGZIP(), []int{208}
+}
+
+var File_gofer_transport_proto protoreflect.FileDescriptor
+
+var file_gofer_transport_proto_rawDesc = []byte{
+	0x0a, 0x15, 0x67, 0x6f, 0x66, 0x65, 0x72, 0x5f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x70, 0x6f, 0x72,
+	0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x1a,
+	0x67, 0x6f, 0x66, 0x65, 0x72, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x65, 0x76,
+	0x65, 0x6e, 0x74, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x13, 0x67, 0x6f, 0x66, 0x65,
+	0x72, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22,
+	0x25, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x46, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2e,
+	0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x22, 0x45,
+	0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65,
+	0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12,
+	0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05,
+	0x6c, 0x69, 0x6d, 0x69, 0x74, 0x22, 0x62, 0x0a, 0x16, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x30, 0x0a, 0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x18, 0x01, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x73, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x5e, 0x0a, 0x16, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x02, 0x69, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72,
+	0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64, 0x65,
+	0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x49, 0x0a, 0x17, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2e, 0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x22, 0xac, 0x01, 0x0a, 0x16, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12,
+	0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
+	0x61, 0x6d, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69,
+	0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69,
+	0x70, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x27, 0x0a, 0x0f, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x61,
+	0x6e, 0x6f, 0x6e, 0x79, 0x6d, 0x6f, 0x75, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e,
+	0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x41, 0x6e, 0x6f, 0x6e, 0x79, 0x6d, 0x6f, 0x75, 0x73, 0x12, 0x23,
+	0x0a, 0x0d, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5f, 0x65, 0x78, 0x70, 0x69, 0x72, 0x79, 0x18,
+	0x05, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0c, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x45, 0x78, 0x70,
+	0x69, 0x72, 0x79, 0x22, 0x49, 0x0a, 0x17, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2e,
+	0x0a, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x52, 0x09, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x22, 0x28,
+	0x0a, 0x16, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x19, 0x0a, 0x17, 0x44, 0x65, 0x6c, 0x65,
+	0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x22, 0x2a, 0x0a, 0x18, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22,
+	0x75, 0x0a, 0x19, 0x47, 0x65, 0x74, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51,
+	0x75, 0x6f, 0x74, 0x61, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x05,
+	0x71, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75, 0x6f,
+	0x74, 0x61, 0x52, 0x05, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x12, 0x2b, 0x0a, 0x05, 0x75, 0x73, 0x61,
+	0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x52,
+	0x05, 0x75, 0x73, 0x61, 0x67, 0x65, 0x22, 0x5a, 0x0a, 0x1b, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65,
+	0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x2b, 0x0a, 0x05, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x52, 0x05, 0x71, 0x75, 0x6f,
+	0x74, 0x61, 0x22, 0x4b, 0x0a, 0x1c, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x2b, 0x0a, 0x05, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x52, 0x05, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x22,
+	0x47, 0x0a, 0x12, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x42, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x67, 0x0a, 0x14,
+	0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05,
+	0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d,
+	0x69, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f,
+	0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x49, 0x64, 0x22, 0x5e, 0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2d,
+	0x0a, 0x09, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x52, 0x09, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x12, 0x16, 0x0a,
+	0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f,
+	0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x4b, 0x0a, 0x16, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02,
+	0x69, 0x64, 0x22, 0x19, 0x0a, 0x17, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x4a, 0x0a,
+	0x15, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x18, 0x0a, 0x16, 0x45, 0x6e, 0x61,
+	0x62, 0x6c, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x22, 0x48, 0x0a, 0x18, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x70, 0x61, 0x74,
+	0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x70, 0x61, 0x74, 0x68, 0x22, 0x48, 0x0a,
+	0x19, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52,
+	0x61, 0x77, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x2e, 0x0a, 0x1a, 0x43, 0x72, 0x65, 0x61, 0x74,
+	0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x75, 0x72, 0x6c, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x03, 0x75, 0x72, 0x6c, 0x22, 0x4a, 0x0a, 0x1b, 0x43, 0x72, 0x65, 0x61, 0x74,
+	0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52, 0x4c, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x22, 0x7b, 0x0a, 0x18, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02,
+	0x69, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x12, 0x0a, 0x04,
+	0x70, 0x61, 0x74, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x70, 0x61, 0x74, 0x68,
+	0x22, 0x48, 0x0a, 0x19, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x52, 0x61, 0x77, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a,
+	0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x61, 0x0a, 0x1a, 0x55, 0x70,
+	0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42, 0x79, 0x55, 0x52,
+	0x4c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69,
+	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x75,
+	0x72, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x75, 0x72, 0x6c, 0x22, 0x4a, 0x0a,
+	0x1b, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x42,
+	0x79, 0x55, 0x52, 0x4c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08,
+	0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52,
+	0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x84, 0x01, 0x0a, 0x21, 0x4c, 0x69,
+	0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+	0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02,
+	0x69, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69,
+	0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74,
+	0x22, 0x5e, 0x0a, 0x22, 0x4c, 0x69, 0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x38, 0x0a, 0x08, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+	0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x56,
+	0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x08, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73,
+	0x22, 0x6c, 0x0a, 0x1d, 0x52, 0x6f, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x50, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69,
+	0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x02, 0x69, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x67,
+	0x0a, 0x1e, 0x52, 0x6f, 0x6c, 0x6c, 0x62, 0x61, 0x63, 0x6b, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x12, 0x18, 0x0a,
+	0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07,
+	0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0xbe, 0x01, 0x0a, 0x1a, 0x53, 0x74, 0x61, 0x72,
+	0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e,
+	0x74, 0x65, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74,
+	0x65, 0x6e, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x70, 0x61, 0x74, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x04, 0x70, 0x61, 0x74, 0x68, 0x12, 0x1e, 0x0a, 0x0a, 0x70, 0x65, 0x72, 0x63, 0x65,
+	0x6e, 0x74, 0x61, 0x67, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0a, 0x70, 0x65, 0x72,
+	0x63, 0x65, 0x6e, 0x74, 0x61, 0x67, 0x65, 0x12, 0x1f, 0x0a, 0x0b, 0x6d, 0x61, 0x6e, 0x75, 0x61,
+	0x6c, 0x5f, 0x6f, 0x6e, 0x6c, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0a, 0x6d, 0x61,
+	0x6e, 0x75, 0x61, 0x6c, 0x4f, 0x6e, 0x6c, 0x79, 0x22, 0x64, 0x0a, 0x1b, 0x53, 0x74, 0x61, 0x72,
+	0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x51,
+	0x0a, 0x1c, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
+	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
+	0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69,
+	0x64, 0x22, 0x4c, 0x0a, 0x1d, 0x50, 0x72, 0x6f, 0x6d, 0x6f, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22,
+	0x4f, 0x0a, 0x1a, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a,
+	0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64,
+	0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64,
+	0x22, 0x4a, 0x0a, 0x1b, 0x41, 0x62, 0x6f, 0x72, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x43, 0x61, 0x6e, 0x61, 0x72, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x2b, 0x0a, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x52, 0x08, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x22, 0x4b, 0x0a, 0x16,
+	0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x19, 0x0a, 0x17, 0x41, 0x62, 0x61,
+	0x6e, 0x64, 0x6f, 0x6e, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x63, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x2e, 0x0a, 0x0e, 0x47, 0x65, 0x74,
+	0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1c, 0x0a, 0x03, 0x72,
+	0x75, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x52, 0x75, 0x6e, 0x52, 0x03, 0x72, 0x75, 0x6e, 0x22, 0x6b, 0x0a, 0x13, 0x42, 0x61, 0x74,
+	0x63, 0x68, 0x47, 0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f,
+	0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x69, 0x64, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28,
+	0x03, 0x52, 0x03, 0x69, 0x64, 0x73, 0x22, 0x36, 0x0a, 0x14, 0x42, 0x61, 0x74, 0x63, 0x68, 0x47,
+	0x65, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1e,
+	0x0a, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x75, 0x6e, 0x52, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x22, 0x83,
+	0x01, 0x0a, 0x0f, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69,
+	0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74,
+	0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f,
+	0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x49, 0x64, 0x22, 0x32, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x73,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1e, 0x0a, 0x04, 0x72, 0x75, 0x6e, 0x73,
+	0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52,
+	0x75, 0x6e, 0x52, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x22, 0xec, 0x01, 0x0a, 0x0f, 0x53, 0x74, 0x61,
+	0x72, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12,
+	0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64,
+	0x12, 0x12, 0x0a, 0x04, 0x6f, 0x6e, 0x6c, 0x79, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04,
+	0x6f, 0x6e, 0x6c, 0x79, 0x12, 0x43, 0x0a, 0x09, 0x76, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65,
+	0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x53, 0x74, 0x61, 0x72, 0x74, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e,
+	0x56, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x09,
+	0x76, 0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x1a, 0x3c, 0x0a, 0x0e, 0x56, 0x61, 0x72,
+	0x69, 0x61, 0x62, 0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b,
+	0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a,
+	0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61,
+	0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x30, 0x0a, 0x10, 0x53, 0x74, 0x61, 0x72, 0x74,
+	0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1c, 0x0a, 0x03, 0x72,
+	0x75, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x52, 0x75, 0x6e, 0x52, 0x03, 0x72, 0x75, 0x6e, 0x22, 0x82, 0x01, 0x0a, 0x0f, 0x52, 0x65,
+	0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a,
+	0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64,
+	0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49,
+	0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69,
+	0x64, 0x12, 0x1b, 0x0a, 0x09, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x18, 0x04,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x66, 0x72, 0x6f, 0x6d, 0x54, 0x61, 0x73, 0x6b, 0x22, 0x30,
+	0x0a, 0x10, 0x52, 0x65, 0x74, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x1c, 0x0a, 0x03, 0x72, 0x75, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x0a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x75, 0x6e, 0x52, 0x03, 0x72, 0x75, 0x6e,
+	0x22, 0x7c, 0x0a, 0x10, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63,
+	0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x22, 0x13,
+	0x0a, 0x11, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x22, 0x9a, 0x01, 0x0a, 0x14, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x6c,
+	0x6c, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12,
+	0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64,
+	0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52,
+	0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x12, 0x28, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x65, 0x73,
+	0x18, 0x04, 0x20, 0x03, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52,
+	0x75, 0x6e, 0x2e, 0x53, 0x74, 0x61, 0x74, 0x65, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x65, 0x73,
+	0x22, 0x2b, 0x0a, 0x15, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x6c, 0x6c, 0x52, 0x75, 0x6e,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x72, 0x75, 0x6e,
+	0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x03, 0x52, 0x04, 0x72, 0x75, 0x6e, 0x73, 0x22, 0x70, 0x0a,
+	0x13, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f,
+	0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x22,
+	0x43, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x09, 0x74, 0x61, 0x73, 0x6b, 0x5f,
+	0x72, 0x75, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x08, 0x74, 0x61, 0x73, 0x6b,
+	0x52, 0x75, 0x6e, 0x73, 0x22, 0x7e, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52,
+	0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b,
+	0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a,
+	0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72,
+	0x75, 0x6e, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x02, 0x69, 0x64, 0x22, 0x3f, 0x0a, 0x12, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52,
+	0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x29, 0x0a, 0x08, 0x74, 0x61,
+	0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x07, 0x74, 0x61,
+	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x22, 0x97, 0x01, 0x0a, 0x14, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c,
+	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
+	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
+	0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72,
+	0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x22,
+	0x17, 0x0a, 0x15, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0xb0, 0x01, 0x0a, 0x13, 0x53, 0x70, 0x61,
+	0x77, 0x6e, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f,
+	0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x04, 0x74,
+	0x61, 0x73, 0x6b, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x04, 0x74, 0x61, 0x73, 0x6b, 0x12, 0x1d, 0x0a, 0x0a,
+	0x73, 0x70, 0x61, 0x77, 0x6e, 0x65, 0x64, 0x5f, 0x62, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x09, 0x73, 0x70, 0x61, 0x77, 0x6e, 0x65, 0x64, 0x42, 0x79, 0x22, 0x41, 0x0a, 0x14, 0x53,
+	0x70, 0x61, 0x77, 0x6e, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x29, 0x0a, 0x08, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x61,
+	0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x07, 0x74, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x22, 0xc7,
+	0x01, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67,
+	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06,
+	0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75,
+	0x6e, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x02, 0x69, 0x64, 0x12, 0x19, 0x0a, 0x06, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x18, 0x05, 0x20,
+	0x01, 0x28, 0x08, 0x52, 0x06, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x88, 0x01, 0x01, 0x12, 0x12,
+	0x0a, 0x04, 0x74, 0x61, 0x69, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x03, 0x52, 0x04, 0x74, 0x61,
+	0x69, 0x6c, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28,
+	0x03, 0x52, 0x05, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x22, 0x6c, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x54,
+	0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x6c, 0x6f, 0x67, 0x5f, 0x6c, 0x69, 0x6e, 0x65, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6c, 0x6f, 0x67, 0x4c, 0x69, 0x6e, 0x65, 0x12, 0x19, 0x0a,
+	0x08, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x6e, 0x75, 0x6d, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52,
+	0x07, 0x6c, 0x69, 0x6e, 0x65, 0x4e, 0x75, 0x6d, 0x12, 0x1c, 0x0a, 0x09, 0x6b, 0x65, 0x65, 0x70,
+	0x61, 0x6c, 0x69, 0x76, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x6b, 0x65, 0x65,
+	0x70, 0x61, 0x6c, 0x69, 0x76, 0x65, 0x22, 0x85, 0x01, 0x0a, 0x18, 0x44, 0x65, 0x6c, 0x65, 0x74,
+	0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c, 0x6f, 0x67, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69,
+	0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x0e,
+	0x0a, 0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1b,
+	0x0a, 0x19, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4c,
+	0x6f, 0x67, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0xc9, 0x01, 0x0a, 0x14,
+	0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x53,
+	0x74, 0x61, 0x72, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f,
+	0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12,
+	0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12,
+	0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x18, 0x05, 0x20, 0x03, 0x28, 0x09,
+	0x52, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x77, 0x69, 0x64,
+	0x74, 0x68, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x77, 0x69, 0x64, 0x74, 0x68, 0x12,
+	0x16, 0x0a, 0x06, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0d, 0x52,
+	0x06, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x22, 0x2c, 0x0a, 0x14, 0x41, 0x74, 0x74, 0x61, 0x63,
+	0x68, 0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x49, 0x6e, 0x70, 0x75, 0x74, 0x12,
+	0x14, 0x0a, 0x05, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05,
+	0x69, 0x6e, 0x70, 0x75, 0x74, 0x22, 0x45, 0x0a, 0x15, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x54,
+	0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x12, 0x14,
+	0x0a, 0x05, 0x77, 0x69, 0x64, 0x74, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x77,
+	0x69, 0x64, 0x74, 0x68, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x0d, 0x52, 0x06, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x22, 0xc5, 0x01, 0x0a,
+	0x16, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x33, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41,
+	0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x53, 0x74,
+	0x61, 0x72, 0x74, 0x48, 0x00, 0x52, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x12, 0x33, 0x0a, 0x05,
+	0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b,
+	0x52, 0x75, 0x6e, 0x49, 0x6e, 0x70, 0x75, 0x74, 0x48, 0x00, 0x52, 0x05, 0x69, 0x6e, 0x70, 0x75,
+	0x74, 0x12, 0x36, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68,
+	0x54, 0x6f, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x52, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x48,
+	0x00, 0x52, 0x06, 0x72, 0x65, 0x73, 0x69, 0x7a, 0x65, 0x42, 0x09, 0x0a, 0x07, 0x72, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x22, 0x4d, 0x0a, 0x15, 0x41, 0x74, 0x74, 0x61, 0x63, 0x68, 0x54, 0x6f,
+	0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x4f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x12, 0x16, 0x0a,
+	0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x6f,
+	0x75, 0x74, 0x70, 0x75, 0x74, 0x12, 0x1c, 0x0a, 0x09, 0x6b, 0x65, 0x65, 0x70, 0x61, 0x6c, 0x69,
+	0x76, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x6b, 0x65, 0x65, 0x70, 0x61, 0x6c,
+	0x69, 0x76, 0x65, 0x22, 0x27, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65,
+	0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0x3e, 0x0a, 0x12,
+	0x47, 0x65, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x28, 0x0a, 0x07, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x72, 0x69, 0x67,
+	0x67, 0x65, 0x72, 0x52, 0x07, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x22, 0x15, 0x0a, 0x13,
+	0x4c, 0x69, 0x73, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x22, 0x42, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2a, 0x0a, 0x08, 0x74,
+	0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x08, 0x74,
+	0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x22, 0x47, 0x0a, 0x15, 0x49, 0x6e, 0x73, 0x74, 0x61,
+	0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x2e, 0x0a, 0x07, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x14, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65,
+	0x72, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x07, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72,
+	0x22, 0x18, 0x0a, 0x16, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x2d, 0x0a, 0x17, 0x55, 0x6e,
+	0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0x1a, 0x0a, 0x18, 0x55, 0x6e, 0x69,
+	0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x28, 0x0a, 0x12, 0x47, 0x65, 0x74, 0x4e, 0x6f, 0x74, 0x69,
+	0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6b,
+	0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x22,
+	0x42, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2b, 0x0a, 0x08, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69,
+	0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x08, 0x6e, 0x6f, 0x74, 0x69, 0x66,
+	0x69, 0x65, 0x72, 0x22, 0x16, 0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66,
+	0x69, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x46, 0x0a, 0x15, 0x4c,
+	0x69, 0x73, 0x74, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x73, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2d, 0x0a, 0x09, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+	0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x09, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69,
+	0x65, 0x72, 0x73, 0x22, 0x4b, 0x0a, 0x16, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f,
+	0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x31, 0x0a,
+	0x08, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+	0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x08, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+	0x22, 0x19, 0x0a, 0x17, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66,
+	0x69, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x2e, 0x0a, 0x18, 0x55,
+	0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x22, 0x1b, 0x0a, 0x19, 0x55,
+	0x6e, 0x69, 0x6e, 0x73, 0x74, 0x61, 0x6c, 0x6c, 0x4e, 0x6f, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x21, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69,
+	0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0xeb, 0x0b, 0x0a, 0x10,
+	0x47, 0x65, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x24, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x54, 0x79, 0x70, 0x65,
+	0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x56, 0x0a, 0x17, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x64, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e,
+	0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x4e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x48, 0x00, 0x52, 0x15, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+	0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56,
+	0x0a, 0x17, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x44, 0x69, 0x73,
+	0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52,
+	0x15, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x53, 0x0a, 0x16, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65,
+	0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74,
+	0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x14, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x53, 0x0a, 0x16, 0x63,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f,
+	0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x14, 0x63, 0x72, 0x65, 0x61,
+	0x74, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x12, 0x59, 0x0a, 0x18, 0x61, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x5f, 0x70, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x08, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x48, 0x00, 0x52, 0x16, 0x61, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x50, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x5d, 0x0a, 0x1a, 0x72,
+	0x6f, 0x6c, 0x6c, 0x65, 0x64, 0x5f, 0x62, 0x61, 0x63, 0x6b, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x6f, 0x6c,
+	0x6c, 0x65, 0x64, 0x42, 0x61, 0x63, 0x6b, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48,
+	0x00, 0x52, 0x17, 0x72, 0x6f, 0x6c, 0x6c, 0x65, 0x64, 0x42, 0x61, 0x63, 0x6b, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x44, 0x0a, 0x11, 0x73, 0x74,
+	0x61, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18,
+	0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76,
+	0x65, 0x6e, 0x74, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52,
+	0x0f, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x12, 0x4a, 0x0a, 0x13, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x72, 0x75,
+	0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c,
+	0x65, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x11, 0x63, 0x6f, 0x6d, 0x70, 0x6c,
+	0x65, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x51, 0x0a, 0x16,
+	0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e,
+	0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65,
+	0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x13, 0x73, 0x74, 0x61, 0x72,
+	0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12,
+	0x57, 0x0a, 0x18, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x5f, 0x74, 0x61, 0x73,
+	0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x10, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53,
+	0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x48,
+	0x00, 0x52, 0x15, 0x73, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b,
+	0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x57, 0x0a, 0x18, 0x63, 0x6f, 0x6d, 0x70,
+	0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65,
+	0x76, 0x65, 0x6e, 0x74, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65,
+	0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x15, 0x63, 0x6f, 0x6d, 0x70,
+	0x6c, 0x65, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e,
+	0x74, 0x12, 0x4a, 0x0a, 0x13, 0x66, 0x69, 0x72, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x14, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x46, 0x69, 0x72, 0x65,
+	0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x11, 0x66, 0x69, 0x72, 0x65,
+	0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56, 0x0a,
+	0x17, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x15, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x50, 0x72, 0x6f, 0x63,
+	0x65, 0x73, 0x73, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x15,
+	0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x53, 0x0a, 0x16, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65,
+	0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18,
+	0x16, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76,
+	0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x48, 0x00, 0x52, 0x14, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x54, 0x72,
+	0x69, 0x67, 0x67, 0x65, 0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x5a, 0x0a, 0x19, 0x72, 0x65,
+	0x76, 0x6f, 0x6b, 0x65, 0x64, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+	0x73, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x19, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x76, 0x6f, 0x6b,
+	0x65, 0x64, 0x55, 0x73, 0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x48, 0x00, 0x52, 0x16,
+	0x72, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x55, 0x73, 0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+	0x73, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x66, 0x0a, 0x1d, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65,
+	0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x6f, 0x62, 0x6a, 0x65, 0x63,
+	0x74, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x1a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x78, 0x70, 0x69, 0x72,
+	0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+	0x48, 0x00, 0x52, 0x1a, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x40,
+	0x0a, 0x0f, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e,
+	0x74, 0x18, 0x1e, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00,
+	0x52, 0x0e, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x42, 0x07, 0x0a, 0x05, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x22, 0x97, 0x01, 0x0a, 0x11, 0x4c, 0x69,
+	0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x18, 0x0a, 0x07, 0x72, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08,
+	0x52, 0x07, 0x72, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x66, 0x6f, 0x6c,
+	0x6c, 0x6f, 0x77, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x66, 0x6f, 0x6c, 0x6c, 0x6f,
+	0x77, 0x12, 0x17, 0x0a, 0x07, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x03, 0x52, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x49, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x66, 0x72,
+	0x6f, 0x6d, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x08, 0x66,
+	0x72, 0x6f, 0x6d, 0x54, 0x69, 0x6d, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x73, 0x75,
+	0x6d, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x73, 0x75,
+	0x6d, 0x65, 0x72, 0x22, 0x8b, 0x0c, 0x0a, 0x12, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e,
+	0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x24, 0x0a, 0x04, 0x6b, 0x69,
+	0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x10, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64,
+	0x12, 0x56, 0x0a, 0x17, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x48,
+	0x00, 0x52, 0x15, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x4e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56, 0x0a, 0x17, 0x64, 0x69, 0x73, 0x61,
+	0x62, 0x6c, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76,
+	0x65, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x44, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x15, 0x64, 0x69, 0x73, 0x61, 0x62,
+	0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x12, 0x53, 0x0a, 0x16, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e,
+	0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52,
+	0x14, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x53, 0x0a, 0x16, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+	0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18,
+	0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76,
+	0x65, 0x6e, 0x74, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x48, 0x00, 0x52, 0x14, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x50, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x59, 0x0a, 0x18, 0x61, 0x62,
+	0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x41, 0x62, 0x61, 0x6e, 0x64, 0x6f,
+	0x6e, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x16, 0x61,
+	0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x5d, 0x0a, 0x1a, 0x72, 0x6f, 0x6c, 0x6c, 0x65, 0x64, 0x5f,
+	0x62, 0x61, 0x63, 0x6b, 0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x65, 0x76,
+	0x65, 0x6e, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+	0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x6c, 0x65, 0x64, 0x42, 0x61, 0x63,
+	0x6b, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x48, 0x00, 0x52, 0x17, 0x72, 0x6f, 0x6c,
+	0x6c, 0x65, 0x64, 0x42, 0x61, 0x63, 0x6b, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x12, 0x44, 0x0a, 0x11, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x5f,
+	0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x16, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x74, 0x61,
+	0x72, 0x74, 0x65, 0x64, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x0f, 0x73, 0x74, 0x61, 0x72, 0x74,
+	0x65, 0x64, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x4a, 0x0a, 0x13, 0x63, 0x6f,
+	0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e,
+	0x74, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x52, 0x75,
+	0x6e, 0x48, 0x00, 0x52, 0x11, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x52, 0x75,
+	0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x51, 0x0a, 0x16, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65,
+	0x64, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74,
+	0x18, 0x0f, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52,
+	0x75, 0x6e, 0x48, 0x00, 0x52, 0x13, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73,
+	0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x57, 0x0a, 0x18, 0x73, 0x63, 0x68,
+	0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x5f, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f,
+	0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x10, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c,
+	0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x48, 0x00, 0x52, 0x15, 0x73, 0x63, 0x68,
+	0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65,
+	0x6e, 0x74, 0x12, 0x57, 0x0a, 0x18, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x5f,
+	0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x11,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65,
+	0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x54, 0x61, 0x73, 0x6b, 0x52,
+	0x75, 0x6e, 0x48, 0x00, 0x52, 0x15, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x54,
+	0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x4a, 0x0a, 0x13, 0x66,
+	0x69, 0x72, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65,
+	0x6e, 0x74, 0x18, 0x14, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x46, 0x69, 0x72, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x48, 0x00, 0x52, 0x11, 0x66, 0x69, 0x72, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67,
+	0x65, 0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x56, 0x0a, 0x17, 0x70, 0x72, 0x6f, 0x63, 0x65,
+	0x73, 0x73, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65,
+	0x6e, 0x74, 0x18, 0x15, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x65, 0x64, 0x54,
+	0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x15, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73,
+	0x73, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12,
+	0x53, 0x0a, 0x16, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x67,
+	0x67, 0x65, 0x72, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x16, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1b, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73,
+	0x6f, 0x6c, 0x76, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x48, 0x00, 0x52, 0x14,
+	0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x64, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x12, 0x5a, 0x0a, 0x19, 0x72, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x5f,
+	0x75, 0x73, 0x65, 0x72, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x5f, 0x65, 0x76, 0x65, 0x6e,
+	0x74, 0x18, 0x19, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x55, 0x73, 0x65, 0x72,
+	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x48, 0x00, 0x52, 0x16, 0x72, 0x65, 0x76, 0x6f, 0x6b, 0x65,
+	0x64, 0x55, 0x73, 0x65, 0x72, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x12, 0x66, 0x0a, 0x1d, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x64, 0x5f, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x5f, 0x65, 0x76, 0x65, 0x6e,
+	0x74, 0x18, 0x1a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x78, 0x70, 0x69, 0x72, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x48, 0x00, 0x52, 0x1a, 0x65, 0x78,
+	0x70, 0x69, 0x72, 0x65, 0x64, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a,
+	0x65, 0x63, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x40, 0x0a, 0x0f, 0x65, 0x78, 0x74, 0x65,
+	0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x1e, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45,
+	0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x48, 0x00, 0x52, 0x0e, 0x65, 0x78, 0x74, 0x65,
+	0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x1c, 0x0a, 0x09, 0x6b, 0x65,
+	0x65, 0x70, 0x61, 0x6c, 0x69, 0x76, 0x65, 0x18, 0x32, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x6b,
+	0x65, 0x65, 0x70, 0x61, 0x6c, 0x69, 0x76, 0x65, 0x42, 0x07, 0x0a, 0x05, 0x65, 0x76, 0x65, 0x6e,
+	0x74, 0x22, 0x45, 0x0a, 0x17, 0x41, 0x63, 0x6b, 0x6e, 0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1a, 0x0a, 0x08,
+	0x63, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08,
+	0x63, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1a, 0x0a, 0x18, 0x41, 0x63, 0x6b, 0x6e,
+	0x6f, 0x77, 0x6c, 0x65, 0x64, 0x67, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x22, 0xb6, 0x02, 0x0a, 0x1c, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x73, 0x68,
+	0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1c, 0x0a, 0x09, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69,
+	0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x65, 0x78, 0x74, 0x65, 0x6e, 0x73,
+	0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x75, 0x6d, 0x6d, 0x61,
+	0x72, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x73, 0x75, 0x6d, 0x6d, 0x61, 0x72,
+	0x79, 0x12, 0x4a, 0x0a, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x18, 0x04, 0x20, 0x03,
+	0x28, 0x0b, 0x32, 0x30, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x50, 0x75, 0x62, 0x6c, 0x69,
+	0x73, 0x68, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x44, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x45,
+	0x6e, 0x74, 0x72, 0x79, 0x52, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x12, 0x21, 0x0a,
+	0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64,
+	0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18,
+	0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49,
+	0x64, 0x1a, 0x3a, 0x0a, 0x0c, 0x44, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x45, 0x6e, 0x74, 0x72,
+	0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03,
+	0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x2f, 0x0a,
+	0x1d, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x73, 0x68, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f,
+	0x6e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0e,
+	0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1b,
+	0x0a, 0x19, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75,
+	0x6d, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x50, 0x0a, 0x1a, 0x4c,
+	0x69, 0x73, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x32, 0x0a, 0x09, 0x63, 0x6f, 0x6e,
+	0x73, 0x75, 0x6d, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73, 0x75, 0x6d,
+	0x65, 0x72, 0x52, 0x09, 0x63, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x73, 0x22, 0x30, 0x0a,
+	0x1a, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f, 0x6e, 0x73,
+	0x75, 0x6d, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e,
+	0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x22,
+	0x1d, 0x0a, 0x1b, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x43, 0x6f,
+	0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x70,
+	0x0a, 0x18, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a,
+	0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a,
+	0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10,
+	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x22, 0x35, 0x0a, 0x19, 0x47, 0x65, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f,
+	0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a,
+	0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
+	0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x22, 0x8e, 0x01, 0x0a, 0x1e, 0x47, 0x65, 0x74, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x72,
+	0x65, 0x61, 0x6d, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a,
+	0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10,
+	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x4f, 0x0a, 0x1f, 0x47, 0x65, 0x74, 0x50,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x53, 0x74, 0x72,
+	0x65, 0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x63,
+	0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f,
+	0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x03, 0x52, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x22, 0x5f, 0x0a, 0x19, 0x4c, 0x69, 0x73,
+	0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a,
+	0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x22, 0x30, 0x0a, 0x1a, 0x4c, 0x69,
+	0x73, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x65, 0x79, 0x73,
+	0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x65, 0x79, 0x73, 0x22, 0xc5, 0x01, 0x0a,
+	0x18, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65,
+	0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b,
+	0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a,
+	0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12,
+	0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72,
+	0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x12,
+	0x23, 0x0a, 0x0d, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72,
+	0x18, 0x06, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0c, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x41,
+	0x66, 0x74, 0x65, 0x72, 0x22, 0x7b, 0x0a, 0x19, 0x50, 0x75, 0x74, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x12, 0x21, 0x0a, 0x0c, 0x6f, 0x62, 0x6a, 0x65, 0x63,
+	0x74, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0b, 0x6f,
+	0x62, 0x6a, 0x65, 0x63, 0x74, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x25, 0x0a, 0x0e, 0x6f, 0x62,
+	0x6a, 0x65, 0x63, 0x74, 0x5f, 0x65, 0x76, 0x69, 0x63, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0d, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x45, 0x76, 0x69, 0x63, 0x74, 0x65,
+	0x64, 0x22, 0x73, 0x0a, 0x1b, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c,
+	0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f,
+	0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x1e, 0x0a, 0x1c, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65,
+	0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x82, 0x01, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x52, 0x75,
+	0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
+	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
+	0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65,
+	0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x30, 0x0a, 0x14, 0x47,
+	0x65, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x22, 0x71, 0x0a,
+	0x14, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e,
+	0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64,
+	0x22, 0x2b, 0x0a, 0x15, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63,
+	0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x65, 0x79,
+	0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x65, 0x79, 0x73, 0x22, 0xb2, 0x01,
+	0x0a, 0x13, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e,
+	0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64,
+	0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b,
+	0x65, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x05, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x05,
+	0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05, 0x66, 0x6f, 0x72,
+	0x63, 0x65, 0x22, 0x2c, 0x0a, 0x14, 0x50, 0x75, 0x74, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65,
+	0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79,
+	0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73,
+	0x22, 0x85, 0x01, 0x0a, 0x16, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62,
+	0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f,
+	0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12,
+	0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52,
+	0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x04, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x19, 0x0a, 0x17, 0x44, 0x65, 0x6c, 0x65,
+	0x74, 0x65, 0x52, 0x75, 0x6e, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x22, 0x38, 0x0a, 0x1d, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x4f, 0x72,
+	0x70, 0x68, 0x61, 0x6e, 0x65, 0x64, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x64, 0x72, 0x79, 0x5f, 0x72, 0x75, 0x6e, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x64, 0x72, 0x79, 0x52, 0x75, 0x6e, 0x22, 0x67, 0x0a,
+	0x1e, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e, 0x65, 0x64,
+	0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x2f, 0x0a, 0x07, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+	0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e, 0x65,
+	0x64, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x07, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73,
+	0x12, 0x14, 0x0a, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52,
+	0x05, 0x62, 0x79, 0x74, 0x65, 0x73, 0x22, 0x50, 0x0a, 0x0e, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e,
+	0x65, 0x64, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79,
+	0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73,
+	0x12, 0x16, 0x0a, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x22, 0x74, 0x0a, 0x17, 0x4c, 0x69, 0x73, 0x74,
+	0x52, 0x75, 0x6e, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e, 0x5f, 0x69,
+	0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64, 0x22, 0x49,
+	0x0a, 0x18, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x75, 0x6e, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63,
+	0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2d, 0x0a, 0x09, 0x61, 0x72,
+	0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0f, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x52, 0x09,
+	0x61, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x22, 0xc1, 0x01, 0x0a, 0x18, 0x47, 0x65,
+	0x74, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70,
+	0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a,
+	0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75,
+	0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49,
+	0x64, 0x12, 0x1e, 0x0a, 0x0b, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x69, 0x64,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x74, 0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x49,
+	0x64, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18,
+	0x06, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x22, 0x62, 0x0a,
+	0x19, 0x47, 0x65, 0x74, 0x41, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x53, 0x74, 0x72, 0x65,
+	0x61, 0x6d, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f,
+	0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x63, 0x6f, 0x6e,
+	0x74, 0x65, 0x6e, 0x74, 0x12, 0x2b, 0x0a, 0x08, 0x61, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0f, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41,
+	0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x52, 0x08, 0x61, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63,
+	0x74, 0x22, 0x68, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x2d, 0x0a, 0x11, 0x47,
+	0x65, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x22, 0x98, 0x01, 0x0a, 0x10, 0x50,
+	0x75, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69,
+	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
+	0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12,
+	0x14, 0x0a, 0x05, 0x66, 0x6f, 0x72, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x05,
+	0x66, 0x6f, 0x72, 0x63, 0x65, 0x22, 0x29, 0x0a, 0x11, 0x50, 0x75, 0x74, 0x53, 0x65, 0x63, 0x72,
+	0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x79,
+	0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x62, 0x79, 0x74, 0x65, 0x73,
+	0x22, 0x6b, 0x0a, 0x13, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69,
+	0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x0a, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x6b,
+	0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x16, 0x0a,
+	0x14, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x19, 0x0a, 0x17, 0x52, 0x65, 0x65, 0x6e, 0x63, 0x72, 0x79,
+	0x70, 0x74, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x22, 0x3c, 0x0a, 0x18, 0x52, 0x65, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x53, 0x65, 0x63,
+	0x72, 0x65, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x20, 0x0a, 0x0b,
+	0x72, 0x65, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x03, 0x52, 0x0b, 0x72, 0x65, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x22, 0x16,
+	0x0a, 0x14, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x81, 0x02, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x53, 0x79,
+	0x73, 0x74, 0x65, 0x6d, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x12, 0x27, 0x0a, 0x0f, 0x64, 0x65, 0x76, 0x6d,
+	0x6f, 0x64, 0x65, 0x5f, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x08, 0x52, 0x0e, 0x64, 0x65, 0x76, 0x6d, 0x6f, 0x64, 0x65, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65,
+	0x64, 0x12, 0x29, 0x0a, 0x10, 0x66, 0x72, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x64, 0x5f, 0x65, 0x6e,
+	0x61, 0x62, 0x6c, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0f, 0x66, 0x72, 0x6f,
+	0x6e, 0x74, 0x65, 0x6e, 0x64, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x12, 0x18, 0x0a, 0x07,
+	0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76,
+	0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x3b, 0x0a, 0x1a, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
+	0x5f, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x65, 0x76,
+	0x65, 0x6e, 0x74, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x17, 0x69, 0x67, 0x6e, 0x6f,
+	0x72, 0x65, 0x50, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x52, 0x75, 0x6e, 0x45, 0x76, 0x65,
+	0x6e, 0x74, 0x73, 0x12, 0x25, 0x0a, 0x0e, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x5f, 0x76, 0x65,
+	0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0d, 0x73, 0x63, 0x68,
+	0x65, 0x6d, 0x61, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x16, 0x0a, 0x14, 0x47, 0x65,
+	0x74, 0x41, 0x75, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x22, 0xf2, 0x01, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x41, 0x75, 0x74, 0x68, 0x43, 0x6f,
+	0x6e, 0x66, 0x69, 0x67, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x21, 0x0a, 0x0c,
+	0x6f, 0x69, 0x64, 0x63, 0x5f, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x0b, 0x6f, 0x69, 0x64, 0x63, 0x45, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x12,
+	0x16, 0x0a, 0x06, 0x69, 0x73, 0x73, 0x75, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x06, 0x69, 0x73, 0x73, 0x75, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x63, 0x6c, 0x69, 0x65, 0x6e,
+	0x74, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x63, 0x6c, 0x69, 0x65,
+	0x6e, 0x74, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x18, 0x04,
+	0x20, 0x03, 0x28, 0x09, 0x52, 0x06, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x12, 0x42, 0x0a, 0x1d,
+	0x64, 0x65, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x61, 0x75, 0x74, 0x68, 0x6f, 0x72, 0x69, 0x7a, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x05, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x1b, 0x64, 0x65, 0x76, 0x69, 0x63, 0x65, 0x41, 0x75, 0x74, 0x68, 0x6f,
+	0x72, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x45, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74,
+	0x12, 0x25, 0x0a, 0x0e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69,
+	0x6e, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x45,
+	0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x22, 0x17, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x53, 0x79,
+	0x73, 0x74, 0x65, 0x6d, 0x53, 0x74, 0x61, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x22, 0xa1, 0x02, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x53, 0x74,
+	0x61, 0x74, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x32, 0x0a, 0x07, 0x77,
+	0x69, 0x6e, 0x64, 0x6f, 0x77, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x53, 0x74, 0x61, 0x74, 0x73,
+	0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x52, 0x07, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x73, 0x12,
+	0x1f, 0x0a, 0x0b, 0x72, 0x75, 0x6e, 0x73, 0x5f, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x0a, 0x72, 0x75, 0x6e, 0x73, 0x51, 0x75, 0x65, 0x75, 0x65, 0x64,
+	0x12, 0x21, 0x0a, 0x0c, 0x72, 0x75, 0x6e, 0x73, 0x5f, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0b, 0x72, 0x75, 0x6e, 0x73, 0x52, 0x75, 0x6e, 0x6e,
+	0x69, 0x6e, 0x67, 0x12, 0x28, 0x0a, 0x10, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x73,
+	0x5f, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0e, 0x74,
+	0x61, 0x73, 0x6b, 0x52, 0x75, 0x6e, 0x73, 0x51, 0x75, 0x65, 0x75, 0x65, 0x64, 0x12, 0x2a, 0x0a,
+	0x11, 0x74, 0x61, 0x73, 0x6b, 0x5f, 0x72, 0x75, 0x6e, 0x73, 0x5f, 0x72, 0x75, 0x6e, 0x6e, 0x69,
+	0x6e, 0x67, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0f, 0x74, 0x61, 0x73, 0x6b, 0x52, 0x75,
+	0x6e, 0x73, 0x52, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x12, 0x39, 0x0a, 0x09, 0x64, 0x75, 0x72,
+	0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x44, 0x69, 0x73,
+	0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x09, 0x64, 0x75, 0x72, 0x61, 0x74,
+	0x69, 0x6f, 0x6e, 0x73, 0x22, 0xe8, 0x01, 0x0a, 0x14, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
+	0x6e, 0x44, 0x69, 0x73, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x16, 0x0a,
+	0x06, 0x6d, 0x65, 0x74, 0x72, 0x69, 0x63, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x6d,
+	0x65, 0x74, 0x72, 0x69, 0x63, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x74, 0x61,
+	0x74, 0x75, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75,
+	0x73, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x73, 0x75, 0x6d, 0x18, 0x06,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x73, 0x75, 0x6d, 0x12, 0x10, 0x0a, 0x03, 0x70, 0x35, 0x30,
+	0x18, 0x07, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x70, 0x35, 0x30, 0x12, 0x10, 0x0a, 0x03, 0x70,
+	0x39, 0x30, 0x18, 0x08, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x70, 0x39, 0x30, 0x12, 0x10, 0x0a,
+	0x03, 0x70, 0x39, 0x39, 0x18, 0x09, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x70, 0x39, 0x39, 0x22,
+	0x2c, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x53, 0x6c, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x22, 0x8f, 0x01,
+	0x0a, 0x15, 0x47, 0x65, 0x74, 0x53, 0x6c, 0x6f, 0x77, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x77, 0x69, 0x6e, 0x64, 0x6f,
+	0x77, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x12,
+	0x2d, 0x0a, 0x06, 0x72, 0x6f, 0x75, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x61, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x53,
+	0x75, 0x6d, 0x6d, 0x61, 0x72, 0x79, 0x52, 0x06, 0x72, 0x6f, 0x75, 0x74, 0x65, 0x73, 0x12, 0x2f,
+	0x0a, 0x07, 0x71, 0x75, 0x65, 0x72, 0x69, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x4c, 0x61, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x53,
+	0x75, 0x6d, 0x6d, 0x61, 0x72, 0x79, 0x52, 0x07, 0x71, 0x75, 0x65, 0x72, 0x69, 0x65, 0x73, 0x22,
+	0x82, 0x01, 0x0a, 0x0e, 0x4c, 0x61, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x53, 0x75, 0x6d, 0x6d, 0x61,
+	0x72, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x10, 0x0a, 0x03,
+	0x70, 0x35, 0x30, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x70, 0x35, 0x30, 0x12, 0x10,
+	0x0a, 0x03, 0x70, 0x39, 0x35, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x70, 0x39, 0x35,
+	0x12, 0x10, 0x0a, 0x03, 0x70, 0x39, 0x39, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x70,
+	0x39, 0x39, 0x12, 0x10, 0x0a, 0x03, 0x6d, 0x61, 0x78, 0x18, 0x06, 0x20, 0x01, 0x28, 0x03, 0x52,
+	0x03, 0x6d, 0x61, 0x78, 0x22, 0x87, 0x02, 0x0a, 0x11, 0x53, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x53,
+	0x74, 0x61, 0x74, 0x73, 0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61,
+	0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x1a,
+	0x0a, 0x08, 0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x08, 0x64, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x21, 0x0a, 0x0c, 0x72, 0x75,
+	0x6e, 0x73, 0x5f, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x0b, 0x72, 0x75, 0x6e, 0x73, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x12, 0x25, 0x0a,
+	0x0e, 0x72, 0x75, 0x6e, 0x73, 0x5f, 0x73, 0x75, 0x63, 0x63, 0x65, 0x65, 0x64, 0x65, 0x64, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0d, 0x72, 0x75, 0x6e, 0x73, 0x53, 0x75, 0x63, 0x63, 0x65,
+	0x65, 0x64, 0x65, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x72, 0x75, 0x6e, 0x73, 0x5f, 0x66, 0x61, 0x69,
+	0x6c, 0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0a, 0x72, 0x75, 0x6e, 0x73, 0x46,
+	0x61, 0x69, 0x6c, 0x65, 0x64, 0x12, 0x25, 0x0a, 0x0e, 0x72, 0x75, 0x6e, 0x73, 0x5f, 0x63, 0x61,
+	0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0d, 0x72,
+	0x75, 0x6e, 0x73, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x12, 0x30, 0x0a, 0x14,
+	0x61, 0x76, 0x65, 0x72, 0x61, 0x67, 0x65, 0x5f, 0x72, 0x75, 0x6e, 0x5f, 0x64, 0x75, 0x72, 0x61,
+	0x74, 0x69, 0x6f, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x03, 0x52, 0x12, 0x61, 0x76, 0x65, 0x72,
+	0x61, 0x67, 0x65, 0x52, 0x75, 0x6e, 0x44, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x70,
+	0x0a, 0x13, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61,
+	0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65,
+	0x6c, 0x69, 0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70,
+	0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x72, 0x75, 0x6e,
+	0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x72, 0x75, 0x6e, 0x49, 0x64,
+	0x22, 0x16, 0x0a, 0x14, 0x52, 0x65, 0x70, 0x61, 0x69, 0x72, 0x4f, 0x72, 0x70, 0x68, 0x61, 0x6e,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x1b, 0x0a, 0x19, 0x54, 0x6f, 0x67, 0x67,
+	0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x32, 0x0a, 0x1a, 0x54, 0x6f, 0x67, 0x67, 0x6c, 0x65, 0x45,
+	0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x67, 0x72, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x14, 0x0a, 0x12, 0x47, 0x65, 0x74,
+	0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22,
+	0xaa, 0x01, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x65, 0x76, 0x65, 0x6c,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x41, 0x0a,
+	0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x47, 0x65, 0x74, 0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76,
+	0x65, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c,
+	0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73,
+	0x1a, 0x3a, 0x0a, 0x0c, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79,
+	0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b,
+	0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0xd3, 0x01, 0x0a,
+	0x15, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x12, 0x43, 0x0a, 0x07,
+	0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x29, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x4c,
+	0x65, 0x76, 0x65, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d, 0x6f, 0x64, 0x75,
+	0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x73, 0x12, 0x23, 0x0a, 0x0d, 0x72, 0x65, 0x73, 0x65, 0x74, 0x5f, 0x6d, 0x6f, 0x64, 0x75, 0x6c,
+	0x65, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0c, 0x72, 0x65, 0x73, 0x65, 0x74, 0x4d,
+	0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x1a, 0x3a, 0x0a, 0x0c, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65,
+	0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75,
+	0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02,
+	0x38, 0x01, 0x22, 0xb0, 0x01, 0x0a, 0x16, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x4c, 0x6f, 0x67,
+	0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a,
+	0x05, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x6c, 0x65,
+	0x76, 0x65, 0x6c, 0x12, 0x44, 0x0a, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x18, 0x02,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x55, 0x70, 0x64,
+	0x61, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x2e, 0x4d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79,
+	0x52, 0x07, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x73, 0x1a, 0x3a, 0x0a, 0x0c, 0x4d, 0x6f, 0x64,
+	0x75, 0x6c, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76,
+	0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75,
+	0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x38, 0x0a, 0x13, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x42,
+	0x61, 0x63, 0x6b, 0x75, 0x70, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c,
+	0x73, 0x6b, 0x69, 0x70, 0x5f, 0x6f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x0b, 0x73, 0x6b, 0x69, 0x70, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x22,
+	0x2c, 0x0a, 0x14, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x42, 0x61, 0x63, 0x6b, 0x75, 0x70, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x68, 0x75, 0x6e, 0x6b,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x22, 0x84, 0x03,
+	0x0a, 0x12, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x32, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0e, 0x32, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74,
+	0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4b, 0x69,
+	0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1e, 0x0a, 0x0a, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0a, 0x6e, 0x61,
+	0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x12, 0x43, 0x0a, 0x08, 0x6d, 0x65, 0x74, 0x61,
+	0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e,
+	0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x1b, 0x0a,
+	0x09, 0x6c, 0x64, 0x61, 0x70, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x08, 0x6c, 0x64, 0x61, 0x70, 0x55, 0x73, 0x65, 0x72, 0x12, 0x23, 0x0a, 0x0d, 0x61, 0x6c,
+	0x6c, 0x6f, 0x77, 0x65, 0x64, 0x5f, 0x63, 0x69, 0x64, 0x72, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28,
+	0x09, 0x52, 0x0c, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x43, 0x69, 0x64, 0x72, 0x73, 0x12,
+	0x10, 0x0a, 0x03, 0x74, 0x74, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x74, 0x74,
+	0x6c, 0x1a, 0x3b, 0x0a, 0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74,
+	0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x44,
+	0x0a, 0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57,
+	0x4e, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x4d, 0x41, 0x4e, 0x41, 0x47, 0x45, 0x4d, 0x45, 0x4e,
+	0x54, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x43, 0x4c, 0x49, 0x45, 0x4e, 0x54, 0x10, 0x02, 0x12,
+	0x13, 0x0a, 0x0f, 0x4e, 0x41, 0x4d, 0x45, 0x53, 0x50, 0x41, 0x43, 0x45, 0x5f, 0x41, 0x44, 0x4d,
+	0x49, 0x4e, 0x10, 0x03, 0x22, 0x53, 0x0a, 0x13, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26, 0x0a, 0x07, 0x64,
+	0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64, 0x65, 0x74, 0x61,
+	0x69, 0x6c, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x17, 0x0a, 0x15, 0x42, 0x6f, 0x6f,
+	0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x22, 0x56, 0x0a, 0x16, 0x42, 0x6f, 0x6f, 0x74, 0x73, 0x74, 0x72, 0x61, 0x70, 0x54,
+	0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26, 0x0a, 0x07,
+	0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64, 0x65, 0x74,
+	0x61, 0x69, 0x6c, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x27, 0x0a, 0x0f, 0x47, 0x65,
+	0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a,
+	0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f,
+	0x6b, 0x65, 0x6e, 0x22, 0x3a, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26, 0x0a, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69,
+	0x6c, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x22,
+	0x97, 0x01, 0x0a, 0x11, 0x4c, 0x69, 0x73, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x14, 0x0a,
+	0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x6c, 0x69,
+	0x6d, 0x69, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x75, 0x6e, 0x75, 0x73, 0x65, 0x64, 0x5f, 0x73, 0x69,
+	0x6e, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0b, 0x75, 0x6e, 0x75, 0x73, 0x65,
+	0x64, 0x53, 0x69, 0x6e, 0x63, 0x65, 0x12, 0x31, 0x0a, 0x15, 0x6d, 0x69, 0x6e, 0x5f, 0x72, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x5f, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x64, 0x61, 0x79, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x12, 0x6d, 0x69, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x73, 0x4c, 0x61, 0x73, 0x74, 0x44, 0x61, 0x79, 0x22, 0x3a, 0x0a, 0x12, 0x4c, 0x69, 0x73,
+	0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+	0x24, 0x0a, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x0c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x06, 0x74,
+	0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x22, 0x33, 0x0a, 0x19, 0x4c, 0x69, 0x73, 0x74, 0x45, 0x78, 0x70,
+	0x69, 0x72, 0x69, 0x6e, 0x67, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x03, 0x52, 0x06, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x22, 0x42, 0x0a, 0x1a, 0x4c, 0x69,
+	0x73, 0x74, 0x45, 0x78, 0x70, 0x69, 0x72, 0x69, 0x6e, 0x67, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x24, 0x0a, 0x06, 0x74, 0x6f, 0x6b, 0x65,
+	0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x22, 0x2a,
+	0x0a, 0x12, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x15, 0x0a, 0x13, 0x44, 0x65,
+	0x6c, 0x65, 0x74, 0x65, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x22, 0x2d, 0x0a, 0x17, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x54,
+	0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04,
+	0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72,
+	0x22, 0x34, 0x0a, 0x18, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x55, 0x73, 0x65, 0x72, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07,
+	0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x64,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x22, 0x4d, 0x0a, 0x12, 0x52, 0x6f, 0x74, 0x61, 0x74, 0x65,
+	0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x05,
+	0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b,
+	0x65, 0x6e, 0x12, 0x21, 0x0a, 0x0c, 0x67, 0x72, 0x61, 0x63, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x69,
+	0x6f, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0b, 0x67, 0x72, 0x61, 0x63, 0x65, 0x50,
+	0x65, 0x72, 0x69, 0x6f, 0x64, 0x22, 0x53, 0x0a, 0x13, 0x52, 0x6f, 0x74, 0x61, 0x74, 0x65, 0x54,
+	0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26, 0x0a, 0x07,
+	0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64, 0x65, 0x74,
+	0x61, 0x69, 0x6c, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x35, 0x0a, 0x18, 0x45, 0x78,
+	0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x4f, 0x49, 0x44, 0x43, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x69, 0x64, 0x5f, 0x74, 0x6f, 0x6b,
+	0x65, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x69, 0x64, 0x54, 0x6f, 0x6b, 0x65,
+	0x6e, 0x22, 0x59, 0x0a, 0x19, 0x45, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x4f, 0x49, 0x44,
+	0x43, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26,
+	0x0a, 0x07, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x0c, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64,
+	0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x4a, 0x0a, 0x1a,
+	0x4c, 0x69, 0x73, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66,
+	0x66, 0x73, 0x65, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73,
+	0x65, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x22, 0x5f, 0x0a, 0x1b, 0x4c, 0x69, 0x73, 0x74,
+	0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x40, 0x0a, 0x10, 0x73, 0x65, 0x72, 0x76, 0x69,
+	0x63, 0x65, 0x5f, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+	0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x0f, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+	0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x22, 0x2a, 0x0a, 0x18, 0x47, 0x65, 0x74,
+	0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x5b, 0x0a, 0x19, 0x47, 0x65, 0x74, 0x53, 0x65, 0x72, 0x76,
+	0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x3e, 0x0a, 0x0f, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x61, 0x63,
+	0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x52, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x22, 0x96, 0x01, 0x0a, 0x1b, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x53, 0x65, 0x72,
+	0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02,
+	0x69, 0x64, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f,
+	0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70,
+	0x74, 0x69, 0x6f, 0x6e, 0x12, 0x25, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x0e, 0x32, 0x11, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+	0x2e, 0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1e, 0x0a, 0x0a, 0x6e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52,
+	0x0a, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x73, 0x22, 0x5e, 0x0a, 0x1c, 0x43,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f,
+	0x75, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3e, 0x0a, 0x0f, 0x73,
+	0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x53, 0x65, 0x72,
+	0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x0e, 0x73, 0x65, 0x72,
+	0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x22, 0x2d, 0x0a, 0x1b, 0x44,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f,
+	0x75, 0x6e, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1e, 0x0a, 0x1c, 0x44, 0x65,
+	0x6c, 0x65, 0x74, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0xf9, 0x01, 0x0a, 0x20, 0x43,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f,
+	0x75, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x12,
+	0x51, 0x0a, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x02, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x35, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64,
+	0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61,
+	0x74, 0x61, 0x12, 0x23, 0x0a, 0x0d, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x5f, 0x63, 0x69,
+	0x64, 0x72, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0c, 0x61, 0x6c, 0x6c, 0x6f, 0x77,
+	0x65, 0x64, 0x43, 0x69, 0x64, 0x72, 0x73, 0x12, 0x10, 0x0a, 0x03, 0x74, 0x74, 0x6c, 0x18, 0x04,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x03, 0x74, 0x74, 0x6c, 0x1a, 0x3b, 0x0a, 0x0d, 0x4d, 0x65, 0x74,
+	0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65,
+	0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05,
+	0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c,
+	0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x61, 0x0a, 0x21, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x54, 0x6f,
+	0x6b, 0x65, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x26, 0x0a, 0x07, 0x64,
+	0x65, 0x74, 0x61, 0x69, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x07, 0x64, 0x65, 0x74, 0x61,
+	0x69, 0x6c, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x98, 0x01, 0x0a, 0x19, 0x4c, 0x69,
+	0x73, 0x74, 0x41, 0x75, 0x74, 0x68, 0x7a, 0x44, 0x65, 0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65,
+	0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12,
+	0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05,
+	0x6c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x12, 0x1f, 0x0a,
+	0x0b, 0x64, 0x65, 0x6e, 0x69, 0x65, 0x64, 0x5f, 0x6f, 0x6e, 0x6c, 0x79, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x0a, 0x64, 0x65, 0x6e, 0x69, 0x65, 0x64, 0x4f, 0x6e, 0x6c, 0x79, 0x12, 0x14,
+	0x0a, 0x05, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x73,
+	0x69, 0x6e, 0x63, 0x65, 0x22, 0x50, 0x0a, 0x1a, 0x4c, 0x69, 0x73, 0x74, 0x41, 0x75, 0x74, 0x68,
+	0x7a, 0x44, 0x65, 0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x32, 0x0a, 0x09, 0x64, 0x65, 0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x18,
+	0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x41, 0x75,
+	0x74, 0x68, 0x7a, 0x44, 0x65, 0x63, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x09, 0x64, 0x65, 0x63,
+	0x69, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0x46, 0x0a, 0x16, 0x4c, 0x69, 0x73, 0x74, 0x44, 0x65,
+	0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x16, 0x0a, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x06, 0x6f, 0x66, 0x66, 0x73, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69,
+	0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x22, 0x4f,
+	0x0a, 0x17, 0x4c, 0x69, 0x73, 0x74, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72,
+	0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x34, 0x0a, 0x0c, 0x64, 0x65, 0x61,
+	0x64, 0x5f, 0x6c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x11, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74,
+	0x65, 0x72, 0x52, 0x0b, 0x64, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x73, 0x22,
+	0x26, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x4b, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x44, 0x65,
+	0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x32, 0x0a, 0x0b, 0x64, 0x65, 0x61, 0x64, 0x5f, 0x6c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x44, 0x65,
+	0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x0a, 0x64, 0x65, 0x61, 0x64, 0x4c, 0x65,
+	0x74, 0x74, 0x65, 0x72, 0x22, 0x2a, 0x0a, 0x18, 0x52, 0x65, 0x64, 0x72, 0x69, 0x76, 0x65, 0x44,
+	0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64,
+	0x22, 0x1b, 0x0a, 0x19, 0x52, 0x65, 0x64, 0x72, 0x69, 0x76, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c,
+	0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x29, 0x0a,
+	0x17, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65,
+	0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x1a, 0x0a, 0x18, 0x44, 0x65, 0x6c, 0x65,
+	0x74, 0x65, 0x44, 0x65, 0x61, 0x64, 0x4c, 0x65, 0x74, 0x74, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x38, 0x0a, 0x13, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68,
+	0x6f, 0x6f, 0x6b, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x22, 0x42,
+	0x0a, 0x14, 0x4c, 0x69, 0x73, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2a, 0x0a, 0x08, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f,
+	0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x2e, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x08, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f,
+	0x6b, 0x73, 0x22, 0x46, 0x0a, 0x11, 0x47, 0x65, 0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e,
+	0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22, 0x3e, 0x0a, 0x12, 0x47, 0x65,
+	0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x28, 0x0a, 0x07, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f,
+	0x6b, 0x52, 0x07, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x22, 0x9c, 0x01, 0x0a, 0x14, 0x43,
+	0x72, 0x65, 0x61, 0x74, 0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65,
+	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73,
+	0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69,
+	0x6e, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x69, 0x70,
+	0x65, 0x6c, 0x69, 0x6e, 0x65, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x75, 0x72, 0x6c, 0x18, 0x03,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x75, 0x72, 0x6c, 0x12, 0x16, 0x0a, 0x06, 0x65, 0x76, 0x65,
+	0x6e, 0x74, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x52, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74,
+	0x73, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x06, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x22, 0x59, 0x0a, 0x15, 0x43, 0x72, 0x65,
+	0x61, 0x74, 0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x28, 0x0a, 0x07, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x57, 0x65, 0x62, 0x68,
+	0x6f, 0x6f, 0x6b, 0x52, 0x07, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x12, 0x16, 0x0a, 0x06,
+	0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x65,
+	0x63, 0x72, 0x65, 0x74, 0x22, 0x49, 0x0a, 0x14, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x57, 0x65,
+	0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12,
+	0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x02, 0x69, 0x64, 0x22,
+	0x17, 0x0a, 0x15, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x8e, 0x01, 0x0a, 0x1c, 0x4c, 0x69, 0x73,
+	0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x69,
+	0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d,
+	0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1d, 0x0a, 0x0a,
+	0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03,
+	0x52, 0x09, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x6f,
+	0x66, 0x66, 0x73, 0x65, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x6f, 0x66, 0x66,
+	0x73, 0x65, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x03, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x22, 0x57, 0x0a, 0x1d, 0x4c, 0x69, 0x73,
+	0x74, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x44, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x69,
+	0x65, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x36, 0x0a, 0x0a, 0x64, 0x65,
+	0x6c, 0x69, 0x76, 0x65, 0x72, 0x69, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x44, 0x65,
+	0x6c, 0x69, 0x76, 0x65, 0x72, 0x79, 0x52, 0x0a, 0x64, 0x65, 0x6c, 0x69, 0x76, 0x65, 0x72, 0x69,
+	0x65, 0x73, 0x22, 0x43, 0x0a, 0x1e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74,
+	0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63,
+	0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x22, 0x5e, 0x0a, 0x1f, 0x4c, 0x69, 0x73, 0x74, 0x52,
+	0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61,
+	0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3b, 0x0a, 0x0b, 0x63, 0x72,
+	0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79,
+	0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x0b, 0x63, 0x72, 0x65, 0x64,
+	0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x22, 0x85, 0x01, 0x0a, 0x1c, 0x50, 0x75, 0x74, 0x52,
+	0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61,
+	0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65,
+	0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b,
+	0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x72,
+	0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72,
+	0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x12, 0x0a, 0x04, 0x70,
+	0x61, 0x73, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x70, 0x61, 0x73, 0x73, 0x22,
+	0x5a, 0x0a, 0x1d, 0x50, 0x75, 0x74, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72,
+	0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x39, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x2e, 0x52, 0x65, 0x67,
+	0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52,
+	0x0a, 0x63, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x22, 0x60, 0x0a, 0x1f, 0x44,
+	0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43, 0x72, 0x65,
+	0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x21,
+	0x0a, 0x0c, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x70, 0x61, 0x63, 0x65, 0x49,
+	0x64, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x22, 0x22, 0x0a,
+	0x20, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79, 0x43,
+	0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x42, 0x26, 0x5a, 0x24, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f,
+	0x63, 0x6c, 0x69, 0x6e, 0x74, 0x6a, 0x65, 0x64, 0x77, 0x61, 0x72, 0x64, 0x73, 0x2f, 0x67, 0x6f,
+	0x66, 0x65, 0x72, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x33,
+}
+
+var (
+	file_gofer_transport_proto_rawDescOnce sync.Once
+	file_gofer_transport_proto_rawDescData = file_gofer_transport_proto_rawDesc
+)
+
+func file_gofer_transport_proto_rawDescGZIP() []byte {
+	file_gofer_transport_proto_rawDescOnce.Do(func() {
+		file_gofer_transport_proto_rawDescData = protoimpl.X.CompressGZIP(file_gofer_transport_proto_rawDescData)
+	})
+	return file_gofer_transport_proto_rawDescData
+}
+
+var file_gofer_transport_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
+var file_gofer_transport_proto_msgTypes = make([]protoimpl.MessageInfo, 216)
+var file_gofer_transport_proto_goTypes = []interface{}{
+	(CreateTokenRequest_Kind)(0),               // 0: proto.CreateTokenRequest.Kind
+	(*GetNamespaceRequest)(nil),                // 1: proto.GetNamespaceRequest
+	(*GetNamespaceResponse)(nil),               // 2: proto.GetNamespaceResponse
+	(*ListNamespacesRequest)(nil),              // 3: proto.ListNamespacesRequest
+	(*ListNamespacesResponse)(nil),             // 4: proto.ListNamespacesResponse
+	(*CreateNamespaceRequest)(nil),             // 5: proto.CreateNamespaceRequest
+	(*CreateNamespaceResponse)(nil),            // 6: proto.CreateNamespaceResponse
+	(*UpdateNamespaceRequest)(nil),             // 7: proto.UpdateNamespaceRequest
+	(*UpdateNamespaceResponse)(nil),            // 8: proto.UpdateNamespaceResponse
+	(*DeleteNamespaceRequest)(nil),             // 9: proto.DeleteNamespaceRequest
+	(*DeleteNamespaceResponse)(nil),            // 10: proto.DeleteNamespaceResponse
+	(*GetNamespaceQuotaRequest)(nil),           // 11: proto.GetNamespaceQuotaRequest
+	(*GetNamespaceQuotaResponse)(nil),          // 12: proto.GetNamespaceQuotaResponse
+	(*UpdateNamespaceQuotaRequest)(nil),        // 13: proto.UpdateNamespaceQuotaRequest
+	(*UpdateNamespaceQuotaResponse)(nil),       // 14: proto.UpdateNamespaceQuotaResponse
+	(*GetPipelineRequest)(nil),                 // 15: proto.GetPipelineRequest
+	(*GetPipelineResponse)(nil),                // 16: proto.GetPipelineResponse
+	(*ListPipelinesRequest)(nil),               // 17: proto.ListPipelinesRequest
+	(*ListPipelinesResponse)(nil),              // 18: proto.ListPipelinesResponse
+	(*DisablePipelineRequest)(nil),             // 19: proto.DisablePipelineRequest
+	(*DisablePipelineResponse)(nil),            // 20: proto.DisablePipelineResponse
+	(*EnablePipelineRequest)(nil),              // 21: proto.EnablePipelineRequest
+	(*EnablePipelineResponse)(nil),             // 22: proto.EnablePipelineResponse
+	(*CreatePipelineRawRequest)(nil),           // 23: proto.CreatePipelineRawRequest
+	(*CreatePipelineRawResponse)(nil),          // 24: proto.CreatePipelineRawResponse
+	(*CreatePipelineByURLRequest)(nil),         // 25: proto.CreatePipelineByURLRequest
+	(*CreatePipelineByURLResponse)(nil),        // 26: proto.CreatePipelineByURLResponse
+	(*UpdatePipelineRawRequest)(nil),           // 27: proto.UpdatePipelineRawRequest
+	(*UpdatePipelineRawResponse)(nil),          // 28: proto.UpdatePipelineRawResponse
+	(*UpdatePipelineByURLRequest)(nil),         // 29: proto.UpdatePipelineByURLRequest
+	(*UpdatePipelineByURLResponse)(nil),        // 30: proto.UpdatePipelineByURLResponse
+	(*ListPipelineConfigVersionsRequest)(nil),  // 31: proto.ListPipelineConfigVersionsRequest
+	(*ListPipelineConfigVersionsResponse)(nil), // 32: proto.ListPipelineConfigVersionsResponse
+	(*RollbackPipelineConfigRequest)(nil),      // 33: proto.RollbackPipelineConfigRequest
+	(*RollbackPipelineConfigResponse)(nil),     // 34: proto.RollbackPipelineConfigResponse
+	(*StartPipelineCanaryRequest)(nil),         // 35: proto.StartPipelineCanaryRequest
+	(*StartPipelineCanaryResponse)(nil),        // 36: proto.StartPipelineCanaryResponse
+	(*PromotePipelineCanaryRequest)(nil),       // 37: proto.PromotePipelineCanaryRequest
+	(*PromotePipelineCanaryResponse)(nil),      // 38: proto.PromotePipelineCanaryResponse
+	(*AbortPipelineCanaryRequest)(nil),         // 39: proto.AbortPipelineCanaryRequest
+	(*AbortPipelineCanaryResponse)(nil),        // 40: proto.AbortPipelineCanaryResponse
+	(*AbandonPipelineRequest)(nil),             // 41: proto.AbandonPipelineRequest
+	(*AbandonPipelineResponse)(nil),            // 42: proto.AbandonPipelineResponse
+	(*GetRunRequest)(nil),                      // 43: proto.GetRunRequest
+	(*GetRunResponse)(nil),                     // 44: proto.GetRunResponse
+	(*BatchGetRunsRequest)(nil),                // 45: proto.BatchGetRunsRequest
+	(*BatchGetRunsResponse)(nil),               // 46: proto.BatchGetRunsResponse
+	(*ListRunsRequest)(nil),                    // 47: proto.ListRunsRequest
+	(*ListRunsResponse)(nil),                   // 48: proto.ListRunsResponse
+	(*StartRunRequest)(nil),                    // 49: proto.StartRunRequest
+	(*StartRunResponse)(nil),                   // 50: proto.StartRunResponse
+	(*RetryRunRequest)(nil),                    // 51: proto.RetryRunRequest
+	(*RetryRunResponse)(nil),                   // 52: proto.RetryRunResponse
+	(*CancelRunRequest)(nil),                   // 53: proto.CancelRunRequest
+	(*CancelRunResponse)(nil),                  // 54: proto.CancelRunResponse
+	(*CancelAllRunsRequest)(nil),               // 55: proto.CancelAllRunsRequest
+	(*CancelAllRunsResponse)(nil),              // 56: proto.CancelAllRunsResponse
+	(*ListTaskRunsRequest)(nil),                // 57: proto.ListTaskRunsRequest
+	(*ListTaskRunsResponse)(nil),               // 58: proto.ListTaskRunsResponse
+	(*GetTaskRunRequest)(nil),                  // 59: proto.GetTaskRunRequest
+	(*GetTaskRunResponse)(nil),                 // 60: proto.GetTaskRunResponse
+	(*CancelTaskRunRequest)(nil),               // 61: proto.CancelTaskRunRequest
+	(*CancelTaskRunResponse)(nil),              // 62: proto.CancelTaskRunResponse
+	(*SpawnTaskRunRequest)(nil),                // 63: proto.SpawnTaskRunRequest
+	(*SpawnTaskRunResponse)(nil),               // 64: proto.SpawnTaskRunResponse
+	(*GetTaskRunLogsRequest)(nil),              // 65: proto.GetTaskRunLogsRequest
+	(*GetTaskRunLogsResponse)(nil),             // 66: proto.GetTaskRunLogsResponse
+	(*DeleteTaskRunLogsRequest)(nil),           // 67: proto.DeleteTaskRunLogsRequest
+	(*DeleteTaskRunLogsResponse)(nil),          // 68: proto.DeleteTaskRunLogsResponse
+	(*AttachToTaskRunStart)(nil),               // 69: proto.AttachToTaskRunStart
+	(*AttachToTaskRunInput)(nil),               // 70: proto.AttachToTaskRunInput
+	(*AttachToTaskRunResize)(nil),              // 71: proto.AttachToTaskRunResize
+	(*AttachToTaskRunRequest)(nil),             // 72: proto.AttachToTaskRunRequest
+	(*AttachToTaskRunOutput)(nil),              // 73: proto.AttachToTaskRunOutput
+	(*GetTriggerRequest)(nil),                  // 74: proto.GetTriggerRequest
+	(*GetTriggerResponse)(nil),                 // 75: proto.GetTriggerResponse
+	(*ListTriggersRequest)(nil),                // 76: proto.ListTriggersRequest
+	(*ListTriggersResponse)(nil),               // 77: proto.ListTriggersResponse
+	(*InstallTriggerRequest)(nil),              // 78: proto.InstallTriggerRequest
+	(*InstallTriggerResponse)(nil),             // 79: proto.InstallTriggerResponse
+	(*UninstallTriggerRequest)(nil),            // 80: proto.UninstallTriggerRequest
+	(*UninstallTriggerResponse)(nil),           // 81: proto.UninstallTriggerResponse
+	(*GetNotifierRequest)(nil),                 // 82: proto.GetNotifierRequest
+	(*GetNotifierResponse)(nil),                // 83: proto.GetNotifierResponse
+	(*ListNotifiersRequest)(nil),               // 84: proto.ListNotifiersRequest
+	(*ListNotifiersResponse)(nil),              // 85: proto.ListNotifiersResponse
+	(*InstallNotifierRequest)(nil),             // 86: proto.InstallNotifierRequest
+	(*InstallNotifierResponse)(nil),            // 87: proto.InstallNotifierResponse
+	(*UninstallNotifierRequest)(nil),           // 88: proto.UninstallNotifierRequest
+	(*UninstallNotifierResponse)(nil),          // 89: proto.UninstallNotifierResponse
+	(*GetEventRequest)(nil),                    // 90: proto.GetEventRequest
+	(*GetEventResponse)(nil),                   // 91: proto.GetEventResponse
+	(*ListEventsRequest)(nil),                  // 92: proto.ListEventsRequest
+	(*ListEventsResponse)(nil),                 // 93: proto.ListEventsResponse
+	(*AcknowledgeEventRequest)(nil),            // 94: proto.AcknowledgeEventRequest
+	(*AcknowledgeEventResponse)(nil),           // 95: proto.AcknowledgeEventResponse
+	(*PublishExtensionEventRequest)(nil),       // 96: proto.PublishExtensionEventRequest
+	(*PublishExtensionEventResponse)(nil),      // 97: proto.PublishExtensionEventResponse
+	(*ListEventConsumersRequest)(nil),          // 98: proto.ListEventConsumersRequest
+	(*ListEventConsumersResponse)(nil),         // 99: proto.ListEventConsumersResponse
+	(*DeleteEventConsumerRequest)(nil),         // 100: proto.DeleteEventConsumerRequest
+	(*DeleteEventConsumerResponse)(nil),        // 101: proto.DeleteEventConsumerResponse
+	(*GetPipelineObjectRequest)(nil),           // 102: proto.GetPipelineObjectRequest
+	(*GetPipelineObjectResponse)(nil),          // 103: proto.GetPipelineObjectResponse
+	(*GetPipelineObjectStreamRequest)(nil),     // 104: proto.GetPipelineObjectStreamRequest
+	(*GetPipelineObjectStreamResponse)(nil),    // 105: proto.GetPipelineObjectStreamResponse
+	(*ListPipelineObjectRequest)(nil),          // 106: proto.ListPipelineObjectRequest
+	(*ListPipelineObjectResponse)(nil),         // 107: proto.ListPipelineObjectResponse
+	(*PutPipelineObjectRequest)(nil),           // 108: proto.PutPipelineObjectRequest
+	(*PutPipelineObjectResponse)(nil),          // 109: proto.PutPipelineObjectResponse
+	(*DeletePipelineObjectRequest)(nil),        // 110: proto.DeletePipelineObjectRequest
+	(*DeletePipelineObjectResponse)(nil),       // 111: proto.DeletePipelineObjectResponse
+	(*GetRunObjectRequest)(nil),                // 112: proto.GetRunObjectRequest
+	(*GetRunObjectResponse)(nil),               // 113: proto.GetRunObjectResponse
+	(*ListRunObjectRequest)(nil),               // 114: proto.ListRunObjectRequest
+	(*ListRunObjectResponse)(nil),              // 115: proto.ListRunObjectResponse
+	(*PutRunObjectRequest)(nil),                // 116: proto.PutRunObjectRequest
+	(*PutRunObjectResponse)(nil),               // 117: proto.PutRunObjectResponse
+	(*DeleteRunObjectRequest)(nil),             // 118: proto.DeleteRunObjectRequest
+	(*DeleteRunObjectResponse)(nil),            // 119: proto.DeleteRunObjectResponse
+	(*CollectOrphanedObjectsRequest)(nil),      // 120: proto.CollectOrphanedObjectsRequest
+	(*CollectOrphanedObjectsResponse)(nil),     // 121: proto.CollectOrphanedObjectsResponse
+	(*OrphanedObject)(nil),                     // 122: proto.OrphanedObject
+	(*ListRunArtifactsRequest)(nil),            // 123: proto.ListRunArtifactsRequest
+	(*ListRunArtifactsResponse)(nil),           // 124: proto.ListRunArtifactsResponse
+	(*GetArtifactStreamRequest)(nil),           // 125: proto.GetArtifactStreamRequest
+	(*GetArtifactStreamResponse)(nil),          // 126: proto.GetArtifactStreamResponse
+	(*GetSecretRequest)(nil),                   // 127: proto.GetSecretRequest
+	(*GetSecretResponse)(nil),                  // 128: proto.GetSecretResponse
+	(*PutSecretRequest)(nil),                   // 129: proto.PutSecretRequest
+	(*PutSecretResponse)(nil),                  // 130: proto.PutSecretResponse
+	(*DeleteSecretRequest)(nil),                // 131: proto.DeleteSecretRequest
+	(*DeleteSecretResponse)(nil),               // 132: proto.DeleteSecretResponse
+	(*ReencryptSecretsRequest)(nil),            // 133: proto.ReencryptSecretsRequest
+	(*ReencryptSecretsResponse)(nil),           // 134: proto.ReencryptSecretsResponse
+	(*GetSystemInfoRequest)(nil),               // 135: proto.GetSystemInfoRequest
+	(*GetSystemInfoResponse)(nil),              // 136: proto.GetSystemInfoResponse
+	(*GetAuthConfigRequest)(nil),               // 137: proto.GetAuthConfigRequest
+	(*GetAuthConfigResponse)(nil),              // 138: proto.GetAuthConfigResponse
+	(*GetSystemStatsRequest)(nil),              // 139: proto.GetSystemStatsRequest
+	(*GetSystemStatsResponse)(nil),             // 140: proto.GetSystemStatsResponse
+	(*DurationDistribution)(nil),               // 141: proto.DurationDistribution
+	(*GetSlowReportRequest)(nil),               // 142: proto.GetSlowReportRequest
+	(*GetSlowReportResponse)(nil),              // 143: proto.GetSlowReportResponse
+	(*LatencySummary)(nil),                     // 144: proto.LatencySummary
+	(*SystemStatsWindow)(nil),                  // 145: proto.SystemStatsWindow
+	(*RepairOrphanRequest)(nil),                // 146: proto.RepairOrphanRequest
+	(*RepairOrphanResponse)(nil),               // 147: proto.RepairOrphanResponse
+	(*ToggleEventIngressRequest)(nil),          // 148: proto.ToggleEventIngressRequest
+	(*ToggleEventIngressResponse)(nil),         // 149: proto.ToggleEventIngressResponse
+	(*GetLogLevelRequest)(nil),                 // 150: proto.GetLogLevelRequest
+	(*GetLogLevelResponse)(nil),                // 151: proto.GetLogLevelResponse
+	(*UpdateLogLevelRequest)(nil),              // 152: proto.UpdateLogLevelRequest
+	(*UpdateLogLevelResponse)(nil),             // 153: proto.UpdateLogLevelResponse
+	(*CreateBackupRequest)(nil),                // 154: proto.CreateBackupRequest
+	(*CreateBackupResponse)(nil),               // 155: proto.CreateBackupResponse
+	(*CreateTokenRequest)(nil),                 // 156: proto.CreateTokenRequest
+	(*CreateTokenResponse)(nil),                // 157: proto.CreateTokenResponse
+	(*BootstrapTokenRequest)(nil),              // 158: proto.BootstrapTokenRequest
+	(*BootstrapTokenResponse)(nil),             // 159: proto.BootstrapTokenResponse
+	(*GetTokenRequest)(nil),                    // 160: proto.GetTokenRequest
+	(*GetTokenResponse)(nil),                   // 161: proto.GetTokenResponse
+	(*ListTokensRequest)(nil),                  // 162: proto.ListTokensRequest
+	(*ListTokensResponse)(nil),                 // 163: proto.ListTokensResponse
+	(*ListExpiringTokensRequest)(nil),          // 164: proto.ListExpiringTokensRequest
+	(*ListExpiringTokensResponse)(nil),         // 165: proto.ListExpiringTokensResponse
+	(*DeleteTokenRequest)(nil),                 // 166: proto.DeleteTokenRequest
+	(*DeleteTokenResponse)(nil),                // 167: proto.DeleteTokenResponse
+	(*DeleteUserTokensRequest)(nil),            // 168: proto.DeleteUserTokensRequest
+	(*DeleteUserTokensResponse)(nil),           // 169: proto.DeleteUserTokensResponse
+	(*RotateTokenRequest)(nil),                 // 170: proto.RotateTokenRequest
+	(*RotateTokenResponse)(nil),                // 171: proto.RotateTokenResponse
+	(*ExchangeOIDCTokenRequest)(nil),           // 172: proto.ExchangeOIDCTokenRequest
+	(*ExchangeOIDCTokenResponse)(nil),          // 173: proto.ExchangeOIDCTokenResponse
+	(*ListServiceAccountsRequest)(nil),         // 174: proto.ListServiceAccountsRequest
+	(*ListServiceAccountsResponse)(nil),        // 175: proto.ListServiceAccountsResponse
+	(*GetServiceAccountRequest)(nil),           // 176: proto.GetServiceAccountRequest
+	(*GetServiceAccountResponse)(nil),          // 177: proto.GetServiceAccountResponse
+	(*CreateServiceAccountRequest)(nil),        // 178: proto.CreateServiceAccountRequest
+	(*CreateServiceAccountResponse)(nil),       // 179: proto.CreateServiceAccountResponse
+	(*DeleteServiceAccountRequest)(nil),        // 180: proto.DeleteServiceAccountRequest
+	(*DeleteServiceAccountResponse)(nil),       // 181: proto.DeleteServiceAccountResponse
+	(*CreateServiceAccountTokenRequest)(nil),   // 182: proto.CreateServiceAccountTokenRequest
+	(*CreateServiceAccountTokenResponse)(nil),  // 183: proto.CreateServiceAccountTokenResponse
+	(*ListAuthzDecisionsRequest)(nil),          // 184: proto.ListAuthzDecisionsRequest
+	(*ListAuthzDecisionsResponse)(nil),         // 185: proto.ListAuthzDecisionsResponse
+	(*ListDeadLettersRequest)(nil),             // 186: proto.ListDeadLettersRequest
+	(*ListDeadLettersResponse)(nil),            // 187: proto.ListDeadLettersResponse
+	(*GetDeadLetterRequest)(nil),               // 188: proto.GetDeadLetterRequest
+	(*GetDeadLetterResponse)(nil),              // 189: proto.GetDeadLetterResponse
+	(*RedriveDeadLetterRequest)(nil),           // 190: proto.RedriveDeadLetterRequest
+	(*RedriveDeadLetterResponse)(nil),          // 191: proto.RedriveDeadLetterResponse
+	(*DeleteDeadLetterRequest)(nil),            // 192: proto.DeleteDeadLetterRequest
+	(*DeleteDeadLetterResponse)(nil),           // 193: proto.DeleteDeadLetterResponse
+	(*ListWebhooksRequest)(nil),                // 194: proto.ListWebhooksRequest
+	(*ListWebhooksResponse)(nil),               // 195: proto.ListWebhooksResponse
+	(*GetWebhookRequest)(nil),                  // 196: proto.GetWebhookRequest
+	(*GetWebhookResponse)(nil),                 // 197: proto.GetWebhookResponse
+	(*CreateWebhookRequest)(nil),               // 198: proto.CreateWebhookRequest
+	(*CreateWebhookResponse)(nil),              // 199: proto.CreateWebhookResponse
+	(*DeleteWebhookRequest)(nil),               // 200: proto.DeleteWebhookRequest
+	(*DeleteWebhookResponse)(nil),              // 201: proto.DeleteWebhookResponse
+	(*ListWebhookDeliveriesRequest)(nil),       // 202: proto.ListWebhookDeliveriesRequest
+	(*ListWebhookDeliveriesResponse)(nil),      // 203: proto.ListWebhookDeliveriesResponse
+	(*ListRegistryCredentialsRequest)(nil),     // 204: proto.ListRegistryCredentialsRequest
+	(*ListRegistryCredentialsResponse)(nil),    // 205: proto.ListRegistryCredentialsResponse
+	(*PutRegistryCredentialRequest)(nil),       // 206: proto.PutRegistryCredentialRequest
+	(*PutRegistryCredentialResponse)(nil),      // 207: proto.PutRegistryCredentialResponse
+	(*DeleteRegistryCredentialRequest)(nil),    // 208: proto.DeleteRegistryCredentialRequest
+	(*DeleteRegistryCredentialResponse)(nil),   // 209: proto.DeleteRegistryCredentialResponse
+	nil,                                        // 210: proto.StartRunRequest.VariablesEntry
+	nil,                                        // 211: proto.PublishExtensionEventRequest.DetailsEntry
+	nil,                                        // 212: proto.GetLogLevelResponse.ModulesEntry
+	nil,                                        // 213: proto.UpdateLogLevelRequest.ModulesEntry
+	nil,                                        // 214: proto.UpdateLogLevelResponse.ModulesEntry
+	nil,                                        // 215: proto.CreateTokenRequest.MetadataEntry
+	nil,                                        // 216: proto.CreateServiceAccountTokenRequest.MetadataEntry
+	(*Namespace)(nil),                          // 217: proto.Namespace
+	(*NamespaceQuota)(nil),                     // 218: proto.NamespaceQuota
+	(*Pipeline)(nil),                           // 219: proto.Pipeline
+	(*PipelineConfigVersion)(nil),              // 220: proto.PipelineConfigVersion
+	(*Run)(nil),                                // 221: proto.Run
+	(Run_State)(0),                             // 222: proto.Run.State
+	(*TaskRun)(nil),                            // 223: proto.TaskRun
+	(*Task)(nil),                               // 224: proto.Task
+	(*Trigger)(nil),                            // 225: proto.Trigger
+	(*TriggerConfig)(nil),                      // 226: proto.TriggerConfig
+	(*Notifier)(nil),                           // 227: proto.Notifier
+	(*NotifierConfig)(nil),                     // 228: proto.NotifierConfig
+	(EventType)(0),                             // 229: proto.EventType
+	(*EventCreatedNamespace)(nil),              // 230: proto.EventCreatedNamespace
+	(*EventDisabledPipeline)(nil),              // 231: proto.EventDisabledPipeline
+	(*EventEnabledPipeline)(nil),               // 232: proto.EventEnabledPipeline
+	(*EventCreatedPipeline)(nil),               // 233: proto.EventCreatedPipeline
+	(*EventAbandonedPipeline)(nil),             // 234: proto.EventAbandonedPipeline
+	(*EventRolledBackPipeline)(nil),            // 235: proto.EventRolledBackPipeline
+	(*EventStartedRun)(nil),                    // 236: proto.EventStartedRun
+	(*EventCompletedRun)(nil),                  // 237: proto.EventCompletedRun
+	(*EventStartedTaskRun)(nil),                // 238: proto.EventStartedTaskRun
+	(*EventScheduledTaskRun)(nil),              // 239: proto.EventScheduledTaskRun
+	(*EventCompletedTaskRun)(nil),              // 240: proto.EventCompletedTaskRun
+	(*EventFiredTrigger)(nil),                  // 241: proto.EventFiredTrigger
+	(*EventProcessedTrigger)(nil),              // 242: proto.EventProcessedTrigger
+	(*EventResolvedTrigger)(nil),               // 243: proto.EventResolvedTrigger
+	(*EventRevokedUserTokens)(nil),             // 244: proto.EventRevokedUserTokens
+	(*EventExpiredPipelineObject)(nil),         // 245: proto.EventExpiredPipelineObject
+	(*EventExtension)(nil),                     // 246: proto.EventExtension
+	(*EventConsumer)(nil),                      // 247: proto.EventConsumer
+	(*Artifact)(nil),                           // 248: proto.Artifact
+	(*Token)(nil),                              // 249: proto.Token
+	(*ServiceAccount)(nil),                     // 250: proto.ServiceAccount
+	(Token_Kind)(0),                            // 251: proto.Token.Kind
+	(*AuthzDecision)(nil),                      // 252: proto.AuthzDecision
+	(*DeadLetter)(nil),                         // 253: proto.DeadLetter
+	(*Webhook)(nil),                            // 254: proto.Webhook
+	(*WebhookDelivery)(nil),                    // 255: proto.WebhookDelivery
+	(*RegistryCredential)(nil),                 // 256: proto.RegistryCredential
+}
+var file_gofer_transport_proto_depIdxs = []int32{
+	217, // 0: proto.GetNamespaceResponse.namespace:type_name -> proto.Namespace
+	217, // 1: proto.ListNamespacesResponse.namespaces:type_name -> proto.Namespace
+	217, // 2: proto.CreateNamespaceResponse.namespace:type_name -> proto.Namespace
+	217, // 3: proto.UpdateNamespaceResponse.namespace:type_name -> proto.Namespace
+	218, // 4: proto.GetNamespaceQuotaResponse.quota:type_name -> proto.NamespaceQuota
+	218, // 5: proto.GetNamespaceQuotaResponse.usage:type_name -> proto.NamespaceQuota
+	218, // 6: proto.UpdateNamespaceQuotaRequest.quota:type_name -> proto.NamespaceQuota
+	218, // 7: proto.UpdateNamespaceQuotaResponse.quota:type_name -> proto.NamespaceQuota
+	219, // 8: proto.GetPipelineResponse.pipeline:type_name -> proto.Pipeline
+	219, // 9: proto.ListPipelinesResponse.pipelines:type_name -> proto.Pipeline
+	219, // 10: proto.CreatePipelineRawResponse.pipeline:type_name -> proto.Pipeline
+	219, // 11: proto.CreatePipelineByURLResponse.pipeline:type_name -> proto.Pipeline
+	219, // 12: proto.UpdatePipelineRawResponse.pipeline:type_name -> proto.Pipeline
+	219, // 13: proto.UpdatePipelineByURLResponse.pipeline:type_name -> proto.Pipeline
+	220, // 14: proto.ListPipelineConfigVersionsResponse.versions:type_name -> proto.PipelineConfigVersion
+	219, // 15: proto.RollbackPipelineConfigResponse.pipeline:type_name -> proto.Pipeline
+	219, // 16: proto.StartPipelineCanaryResponse.pipeline:type_name -> proto.Pipeline
+	219, // 17: proto.PromotePipelineCanaryResponse.pipeline:type_name -> proto.Pipeline
+	219, // 18: proto.AbortPipelineCanaryResponse.pipeline:type_name -> proto.Pipeline
+	221, // 19: proto.GetRunResponse.run:type_name -> proto.Run
+	221, // 20: proto.BatchGetRunsResponse.runs:type_name -> proto.Run
+	221, // 21: proto.ListRunsResponse.runs:type_name -> proto.Run
+	210, // 22: proto.StartRunRequest.variables:type_name -> proto.StartRunRequest.VariablesEntry
+	221, // 23: proto.StartRunResponse.run:type_name -> proto.Run
+	221, // 24: proto.RetryRunResponse.run:type_name -> proto.Run
+	222, // 25: proto.CancelAllRunsRequest.states:type_name -> proto.Run.State
+	223, // 26: proto.ListTaskRunsResponse.task_runs:type_name -> proto.TaskRun
+	223, // 27: proto.GetTaskRunResponse.task_run:type_name -> proto.TaskRun
+	224, // 28: proto.SpawnTaskRunRequest.task:type_name -> proto.Task
+	223, // 29: proto.SpawnTaskRunResponse.task_run:type_name -> proto.TaskRun
+	69,  // 30: proto.AttachToTaskRunRequest.start:type_name -> proto.AttachToTaskRunStart
+	70,  // 31: proto.AttachToTaskRunRequest.input:type_name -> proto.AttachToTaskRunInput
+	71,  // 32: proto.AttachToTaskRunRequest.resize:type_name -> proto.AttachToTaskRunResize
+	225, // 33: proto.GetTriggerResponse.trigger:type_name -> proto.Trigger
+	225, // 34: proto.ListTriggersResponse.triggers:type_name -> proto.Trigger
+	226, // 35: proto.InstallTriggerRequest.trigger:type_name -> proto.TriggerConfig
+	227, // 36: proto.GetNotifierResponse.notifier:type_name -> proto.Notifier
+	227, // 37: proto.ListNotifiersResponse.notifiers:type_name -> proto.Notifier
+	228, // 38: proto.InstallNotifierRequest.notifier:type_name -> proto.NotifierConfig
+	229, // 39: proto.GetEventResponse.kind:type_name -> proto.EventType
+	230, // 40: proto.GetEventResponse.created_namespace_event:type_name -> proto.EventCreatedNamespace
+	231, // 41: proto.GetEventResponse.disabled_pipeline_event:type_name -> proto.EventDisabledPipeline
+	232, // 42: proto.GetEventResponse.enabled_pipeline_event:type_name -> proto.EventEnabledPipeline
+	233, // 43: proto.GetEventResponse.created_pipeline_event:type_name -> proto.EventCreatedPipeline
+	234, // 44: proto.GetEventResponse.abandoned_pipeline_event:type_name -> proto.EventAbandonedPipeline
+	235, // 45: proto.GetEventResponse.rolled_back_pipeline_event:type_name -> proto.EventRolledBackPipeline
+	236, // 46: proto.GetEventResponse.started_run_event:type_name -> proto.EventStartedRun
+	237, // 47: proto.GetEventResponse.completed_run_event:type_name -> proto.EventCompletedRun
+	238, // 48: proto.GetEventResponse.started_task_run_event:type_name -> proto.EventStartedTaskRun
+	239, // 49: proto.GetEventResponse.scheduled_task_run_event:type_name -> proto.EventScheduledTaskRun
+	240, // 50: proto.GetEventResponse.completed_task_run_event:type_name -> proto.EventCompletedTaskRun
+	241, // 51: proto.GetEventResponse.fired_trigger_event:type_name -> proto.EventFiredTrigger
+	242, // 52: proto.GetEventResponse.processed_trigger_event:type_name -> proto.EventProcessedTrigger
+	243, // 53: proto.GetEventResponse.resolved_trigger_event:type_name -> proto.EventResolvedTrigger
+	244, // 54: proto.GetEventResponse.revoked_user_tokens_event:type_name -> proto.EventRevokedUserTokens
+	245, // 55: proto.GetEventResponse.expired_pipeline_object_event:type_name -> proto.EventExpiredPipelineObject
+	246, // 56: proto.GetEventResponse.extension_event:type_name -> proto.EventExtension
+	229, // 57: proto.ListEventsResponse.kind:type_name -> proto.EventType
+	230, // 58: proto.ListEventsResponse.created_namespace_event:type_name -> proto.EventCreatedNamespace
+	231, // 59: proto.ListEventsResponse.disabled_pipeline_event:type_name -> proto.EventDisabledPipeline
+	232, // 60: proto.ListEventsResponse.enabled_pipeline_event:type_name -> proto.EventEnabledPipeline
+	233, // 61: proto.ListEventsResponse.created_pipeline_event:type_name -> proto.EventCreatedPipeline
+	234, // 62: proto.ListEventsResponse.abandoned_pipeline_event:type_name -> proto.EventAbandonedPipeline
+	235, // 63: proto.ListEventsResponse.rolled_back_pipeline_event:type_name -> proto.EventRolledBackPipeline
+	236, // 64: proto.ListEventsResponse.started_run_event:type_name -> proto.EventStartedRun
+	237, // 65: proto.ListEventsResponse.completed_run_event:type_name -> proto.EventCompletedRun
+	238, // 66: proto.ListEventsResponse.started_task_run_event:type_name -> proto.EventStartedTaskRun
+	239, // 67: proto.ListEventsResponse.scheduled_task_run_event:type_name -> proto.EventScheduledTaskRun
+	240, // 68: proto.ListEventsResponse.completed_task_run_event:type_name -> proto.EventCompletedTaskRun
+	241, // 69: proto.ListEventsResponse.fired_trigger_event:type_name -> proto.EventFiredTrigger
+	242, // 70: proto.ListEventsResponse.processed_trigger_event:type_name -> proto.EventProcessedTrigger
+	243, // 71: proto.ListEventsResponse.resolved_trigger_event:type_name -> proto.EventResolvedTrigger
+	244, // 72: proto.ListEventsResponse.revoked_user_tokens_event:type_name -> proto.EventRevokedUserTokens
+	245, // 73: proto.ListEventsResponse.expired_pipeline_object_event:type_name -> proto.EventExpiredPipelineObject
+	246, // 74: proto.ListEventsResponse.extension_event:type_name -> proto.EventExtension
+	211, // 75: proto.PublishExtensionEventRequest.details:type_name -> proto.PublishExtensionEventRequest.DetailsEntry
+	247, // 76: proto.ListEventConsumersResponse.consumers:type_name -> proto.EventConsumer
+	122, // 77: proto.CollectOrphanedObjectsResponse.objects:type_name -> proto.OrphanedObject
+	248, // 78: proto.ListRunArtifactsResponse.artifacts:type_name -> proto.Artifact
+	248, // 79: proto.GetArtifactStreamResponse.artifact:type_name -> proto.Artifact
+	145, // 80: proto.GetSystemStatsResponse.windows:type_name -> proto.SystemStatsWindow
+	141, // 81: proto.GetSystemStatsResponse.durations:type_name -> proto.DurationDistribution
+	144, // 82: proto.GetSlowReportResponse.routes:type_name -> proto.LatencySummary
+	144, // 83: proto.GetSlowReportResponse.queries:type_name -> proto.LatencySummary
+	212, // 84: proto.GetLogLevelResponse.modules:type_name -> proto.GetLogLevelResponse.ModulesEntry
+	213, // 85: proto.UpdateLogLevelRequest.modules:type_name -> proto.UpdateLogLevelRequest.ModulesEntry
+	214, // 86: proto.UpdateLogLevelResponse.modules:type_name -> proto.UpdateLogLevelResponse.ModulesEntry
+	0,   // 87: proto.CreateTokenRequest.kind:type_name -> proto.CreateTokenRequest.Kind
+	215, // 88: proto.CreateTokenRequest.metadata:type_name -> proto.CreateTokenRequest.MetadataEntry
+	249, // 89: proto.CreateTokenResponse.details:type_name -> proto.Token
+	249, // 90: proto.BootstrapTokenResponse.details:type_name -> proto.Token
+	249, // 91: proto.GetTokenResponse.details:type_name -> proto.Token
+	249, // 92: proto.ListTokensResponse.tokens:type_name -> proto.Token
+	249, // 93: proto.ListExpiringTokensResponse.tokens:type_name -> proto.Token
+	249, // 94: proto.RotateTokenResponse.details:type_name -> proto.Token
+	249, // 95: proto.ExchangeOIDCTokenResponse.details:type_name -> proto.Token
+	250, // 96: proto.ListServiceAccountsResponse.service_accounts:type_name -> proto.ServiceAccount
+	250, // 97: proto.GetServiceAccountResponse.service_account:type_name -> proto.ServiceAccount
+	251, // 98: proto.CreateServiceAccountRequest.kind:type_name -> proto.Token.Kind
+	250, // 99: proto.CreateServiceAccountResponse.service_account:type_name -> proto.ServiceAccount
+	216, // 100: proto.CreateServiceAccountTokenRequest.metadata:type_name -> proto.CreateServiceAccountTokenRequest.MetadataEntry
+	249, // 101: proto.CreateServiceAccountTokenResponse.details:type_name -> proto.Token
+	252, // 102: proto.ListAuthzDecisionsResponse.decisions:type_name -> proto.AuthzDecision
+	253, // 103: proto.ListDeadLettersResponse.dead_letters:type_name -> proto.DeadLetter
+	253, // 104: proto.GetDeadLetterResponse.dead_letter:type_name -> proto.DeadLetter
+	254, // 105: proto.ListWebhooksResponse.webhooks:type_name -> proto.Webhook
+	254, // 106: proto.GetWebhookResponse.webhook:type_name -> proto.Webhook
+	254, // 107: proto.CreateWebhookResponse.webhook:type_name -> proto.Webhook
+	255, // 108: proto.ListWebhookDeliveriesResponse.deliveries:type_name -> proto.WebhookDelivery
+	256, // 109: proto.ListRegistryCredentialsResponse.credentials:type_name -> proto.RegistryCredential
+	256, // 110: proto.PutRegistryCredentialResponse.credential:type_name -> proto.RegistryCredential
+	111, // [111:111] is the sub-list for method output_type
+	111, // [111:111] is the sub-list for method input_type
+	111, // [111:111] is the sub-list for extension type_name
+	111, // [111:111] is the sub-list for extension extendee
+	0,   // [0:111] is the sub-list for field type_name
+}
+
+func init() { file_gofer_transport_proto_init() }
+func file_gofer_transport_proto_init() {
+	if File_gofer_transport_proto != nil {
+		return
+	}
+	file_gofer_message_events_proto_init()
+	file_gofer_message_proto_init()
+	if !protoimpl.UnsafeEnabled {
+		file_gofer_transport_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetNamespaceRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetNamespaceResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListNamespacesRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListNamespacesResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateNamespaceRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateNamespaceResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdateNamespaceRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdateNamespaceResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteNamespaceRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteNamespaceResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetNamespaceQuotaRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetNamespaceQuotaResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdateNamespaceQuotaRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdateNamespaceQuotaResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetPipelineRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetPipelineResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListPipelinesRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListPipelinesResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DisablePipelineRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DisablePipelineResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*EnablePipelineRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*EnablePipelineResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreatePipelineRawRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreatePipelineRawResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreatePipelineByURLRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreatePipelineByURLResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdatePipelineRawRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdatePipelineRawResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdatePipelineByURLRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdatePipelineByURLResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListPipelineConfigVersionsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListPipelineConfigVersionsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[32].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RollbackPipelineConfigRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[33].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RollbackPipelineConfigResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[34].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StartPipelineCanaryRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[35].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StartPipelineCanaryResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[36].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PromotePipelineCanaryRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[37].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PromotePipelineCanaryResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[38].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AbortPipelineCanaryRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[39].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AbortPipelineCanaryResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[40].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AbandonPipelineRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[41].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AbandonPipelineResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[42].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[43].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetRunResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[44].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchGetRunsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[45].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchGetRunsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[46].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRunsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[47].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRunsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[48].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StartRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[49].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StartRunResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[50].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RetryRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[51].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RetryRunResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[52].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CancelRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[53].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CancelRunResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[54].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CancelAllRunsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[55].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CancelAllRunsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[56].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListTaskRunsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[57].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListTaskRunsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[58].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTaskRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[59].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTaskRunResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[60].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CancelTaskRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[61].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CancelTaskRunResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[62].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SpawnTaskRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[63].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SpawnTaskRunResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[64].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTaskRunLogsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[65].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTaskRunLogsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[66].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteTaskRunLogsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[67].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteTaskRunLogsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[68].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AttachToTaskRunStart); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[69].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AttachToTaskRunInput); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[70].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AttachToTaskRunResize); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[71].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AttachToTaskRunRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[72].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AttachToTaskRunOutput); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[73].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTriggerRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[74].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTriggerResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[75].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListTriggersRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[76].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListTriggersResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[77].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*InstallTriggerRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[78].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*InstallTriggerResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[79].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UninstallTriggerRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[80].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UninstallTriggerResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7077,8 +14887,260 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetNamespaceResponse); i {
+		file_gofer_transport_proto_msgTypes[81].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetNotifierRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[82].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetNotifierResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[83].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListNotifiersRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[84].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListNotifiersResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[85].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*InstallNotifierRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[86].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*InstallNotifierResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[87].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UninstallNotifierRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[88].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UninstallNotifierResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[89].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetEventRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[90].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetEventResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[91].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListEventsRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[92].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListEventsResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[93].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AcknowledgeEventRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[94].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AcknowledgeEventResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[95].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PublishExtensionEventRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[96].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PublishExtensionEventResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[97].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListEventConsumersRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[98].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListEventConsumersResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[99].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteEventConsumerRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[100].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteEventConsumerResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[101].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetPipelineObjectRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_gofer_transport_proto_msgTypes[102].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetPipelineObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7089,8 +15151,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListNamespacesRequest); i {
+		file_gofer_transport_proto_msgTypes[103].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetPipelineObjectStreamRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7101,8 +15163,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListNamespacesResponse); i {
+		file_gofer_transport_proto_msgTypes[104].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetPipelineObjectStreamResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7113,8 +15175,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateNamespaceRequest); i {
+		file_gofer_transport_proto_msgTypes[105].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListPipelineObjectRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7125,8 +15187,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateNamespaceResponse); i {
+		file_gofer_transport_proto_msgTypes[106].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListPipelineObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7137,8 +15199,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UpdateNamespaceRequest); i {
+		file_gofer_transport_proto_msgTypes[107].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutPipelineObjectRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7149,8 +15211,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UpdateNamespaceResponse); i {
+		file_gofer_transport_proto_msgTypes[108].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutPipelineObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7161,8 +15223,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteNamespaceRequest); i {
+		file_gofer_transport_proto_msgTypes[109].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeletePipelineObjectRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7173,8 +15235,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteNamespaceResponse); i {
+		file_gofer_transport_proto_msgTypes[110].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeletePipelineObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7185,8 +15247,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetPipelineRequest); i {
+		file_gofer_transport_proto_msgTypes[111].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetRunObjectRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7197,8 +15259,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetPipelineResponse); i {
+		file_gofer_transport_proto_msgTypes[112].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetRunObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7209,8 +15271,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListPipelinesRequest); i {
+		file_gofer_transport_proto_msgTypes[113].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRunObjectRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7221,8 +15283,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListPipelinesResponse); i {
+		file_gofer_transport_proto_msgTypes[114].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRunObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7233,8 +15295,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DisablePipelineRequest); i {
+		file_gofer_transport_proto_msgTypes[115].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutRunObjectRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7245,8 +15307,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DisablePipelineResponse); i {
+		file_gofer_transport_proto_msgTypes[116].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutRunObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7257,8 +15319,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*EnablePipelineRequest); i {
+		file_gofer_transport_proto_msgTypes[117].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteRunObjectRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7269,8 +15331,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*EnablePipelineResponse); i {
+		file_gofer_transport_proto_msgTypes[118].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteRunObjectResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7281,8 +15343,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreatePipelineRawRequest); i {
+		file_gofer_transport_proto_msgTypes[119].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CollectOrphanedObjectsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7293,8 +15355,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreatePipelineRawResponse); i {
+		file_gofer_transport_proto_msgTypes[120].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CollectOrphanedObjectsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7305,8 +15367,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreatePipelineByURLRequest); i {
+		file_gofer_transport_proto_msgTypes[121].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*OrphanedObject); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7317,8 +15379,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreatePipelineByURLResponse); i {
+		file_gofer_transport_proto_msgTypes[122].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRunArtifactsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7329,8 +15391,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UpdatePipelineRawRequest); i {
+		file_gofer_transport_proto_msgTypes[123].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRunArtifactsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7341,8 +15403,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UpdatePipelineRawResponse); i {
+		file_gofer_transport_proto_msgTypes[124].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetArtifactStreamRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7353,8 +15415,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UpdatePipelineByURLRequest); i {
+		file_gofer_transport_proto_msgTypes[125].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetArtifactStreamResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7365,8 +15427,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UpdatePipelineByURLResponse); i {
+		file_gofer_transport_proto_msgTypes[126].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSecretRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7377,8 +15439,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AbandonPipelineRequest); i {
+		file_gofer_transport_proto_msgTypes[127].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSecretResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7389,8 +15451,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AbandonPipelineResponse); i {
+		file_gofer_transport_proto_msgTypes[128].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutSecretRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7401,8 +15463,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetRunRequest); i {
+		file_gofer_transport_proto_msgTypes[129].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutSecretResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7413,8 +15475,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetRunResponse); i {
+		file_gofer_transport_proto_msgTypes[130].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteSecretRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7425,8 +15487,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchGetRunsRequest); i {
+		file_gofer_transport_proto_msgTypes[131].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteSecretResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7437,8 +15499,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BatchGetRunsResponse); i {
+		file_gofer_transport_proto_msgTypes[132].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ReencryptSecretsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7449,8 +15511,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[32].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListRunsRequest); i {
+		file_gofer_transport_proto_msgTypes[133].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ReencryptSecretsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7461,8 +15523,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[33].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListRunsResponse); i {
+		file_gofer_transport_proto_msgTypes[134].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSystemInfoRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7473,8 +15535,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[34].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*StartRunRequest); i {
+		file_gofer_transport_proto_msgTypes[135].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSystemInfoResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7485,8 +15547,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[35].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*StartRunResponse); i {
+		file_gofer_transport_proto_msgTypes[136].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetAuthConfigRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7497,8 +15559,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[36].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*RetryRunRequest); i {
+		file_gofer_transport_proto_msgTypes[137].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetAuthConfigResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7509,8 +15571,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[37].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*RetryRunResponse); i {
+		file_gofer_transport_proto_msgTypes[138].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSystemStatsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7521,8 +15583,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[38].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CancelRunRequest); i {
+		file_gofer_transport_proto_msgTypes[139].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSystemStatsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7533,8 +15595,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[39].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CancelRunResponse); i {
+		file_gofer_transport_proto_msgTypes[140].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DurationDistribution); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7545,8 +15607,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[40].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CancelAllRunsRequest); i {
+		file_gofer_transport_proto_msgTypes[141].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSlowReportRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7557,8 +15619,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[41].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CancelAllRunsResponse); i {
+		file_gofer_transport_proto_msgTypes[142].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetSlowReportResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7569,8 +15631,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[42].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListTaskRunsRequest); i {
+		file_gofer_transport_proto_msgTypes[143].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*LatencySummary); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7581,8 +15643,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[43].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListTaskRunsResponse); i {
+		file_gofer_transport_proto_msgTypes[144].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SystemStatsWindow); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7593,8 +15655,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[44].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTaskRunRequest); i {
+		file_gofer_transport_proto_msgTypes[145].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RepairOrphanRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7605,8 +15667,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[45].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTaskRunResponse); i {
+		file_gofer_transport_proto_msgTypes[146].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RepairOrphanResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7617,8 +15679,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[46].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CancelTaskRunRequest); i {
+		file_gofer_transport_proto_msgTypes[147].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ToggleEventIngressRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7629,8 +15691,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[47].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CancelTaskRunResponse); i {
+		file_gofer_transport_proto_msgTypes[148].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ToggleEventIngressResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7641,8 +15703,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[48].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTaskRunLogsRequest); i {
+		file_gofer_transport_proto_msgTypes[149].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetLogLevelRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7653,8 +15715,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[49].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTaskRunLogsResponse); i {
+		file_gofer_transport_proto_msgTypes[150].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetLogLevelResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7665,8 +15727,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[50].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteTaskRunLogsRequest); i {
+		file_gofer_transport_proto_msgTypes[151].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdateLogLevelRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7677,8 +15739,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[51].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteTaskRunLogsResponse); i {
+		file_gofer_transport_proto_msgTypes[152].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*UpdateLogLevelResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7689,8 +15751,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[52].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTriggerRequest); i {
+		file_gofer_transport_proto_msgTypes[153].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateBackupRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7701,8 +15763,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[53].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTriggerResponse); i {
+		file_gofer_transport_proto_msgTypes[154].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateBackupResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7713,8 +15775,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[54].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListTriggersRequest); i {
+		file_gofer_transport_proto_msgTypes[155].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateTokenRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7725,8 +15787,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[55].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListTriggersResponse); i {
+		file_gofer_transport_proto_msgTypes[156].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateTokenResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7737,8 +15799,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[56].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*InstallTriggerRequest); i {
+		file_gofer_transport_proto_msgTypes[157].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BootstrapTokenRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7749,8 +15811,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[57].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*InstallTriggerResponse); i {
+		file_gofer_transport_proto_msgTypes[158].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BootstrapTokenResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7761,8 +15823,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[58].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UninstallTriggerRequest); i {
+		file_gofer_transport_proto_msgTypes[159].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTokenRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7773,8 +15835,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[59].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UninstallTriggerResponse); i {
+		file_gofer_transport_proto_msgTypes[160].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetTokenResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7785,8 +15847,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[60].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetNotifierRequest); i {
+		file_gofer_transport_proto_msgTypes[161].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListTokensRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7797,8 +15859,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[61].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetNotifierResponse); i {
+		file_gofer_transport_proto_msgTypes[162].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListTokensResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7809,8 +15871,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[62].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListNotifiersRequest); i {
+		file_gofer_transport_proto_msgTypes[163].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListExpiringTokensRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7821,8 +15883,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[63].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListNotifiersResponse); i {
+		file_gofer_transport_proto_msgTypes[164].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListExpiringTokensResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7833,8 +15895,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[64].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*InstallNotifierRequest); i {
+		file_gofer_transport_proto_msgTypes[165].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteTokenRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7845,8 +15907,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[65].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*InstallNotifierResponse); i {
+		file_gofer_transport_proto_msgTypes[166].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteTokenResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7857,8 +15919,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[66].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UninstallNotifierRequest); i {
+		file_gofer_transport_proto_msgTypes[167].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteUserTokensRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7869,8 +15931,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[67].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*UninstallNotifierResponse); i {
+		file_gofer_transport_proto_msgTypes[168].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteUserTokensResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7881,8 +15943,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[68].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetEventRequest); i {
+		file_gofer_transport_proto_msgTypes[169].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RotateTokenRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7893,8 +15955,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[69].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetEventResponse); i {
+		file_gofer_transport_proto_msgTypes[170].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RotateTokenResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7905,8 +15967,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[70].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListEventsRequest); i {
+		file_gofer_transport_proto_msgTypes[171].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ExchangeOIDCTokenRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7917,8 +15979,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[71].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListEventsResponse); i {
+		file_gofer_transport_proto_msgTypes[172].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ExchangeOIDCTokenResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7929,8 +15991,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[72].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetPipelineObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[173].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListServiceAccountsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7941,8 +16003,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[73].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetPipelineObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[174].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListServiceAccountsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7953,8 +16015,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[74].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListPipelineObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[175].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetServiceAccountRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7965,8 +16027,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[75].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListPipelineObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[176].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetServiceAccountResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7977,8 +16039,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[76].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PutPipelineObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[177].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateServiceAccountRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -7989,8 +16051,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[77].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PutPipelineObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[178].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateServiceAccountResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8001,8 +16063,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[78].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeletePipelineObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[179].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteServiceAccountRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8013,8 +16075,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[79].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeletePipelineObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[180].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteServiceAccountResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8025,8 +16087,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[80].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetRunObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[181].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateServiceAccountTokenRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8037,8 +16099,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[81].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetRunObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[182].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateServiceAccountTokenResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8049,8 +16111,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[82].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListRunObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[183].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListAuthzDecisionsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8061,8 +16123,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[83].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ListRunObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[184].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListAuthzDecisionsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8073,8 +16135,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[84].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PutRunObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[185].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListDeadLettersRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8085,8 +16147,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[85].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PutRunObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[186].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListDeadLettersResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8097,8 +16159,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[86].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteRunObjectRequest); i {
+		file_gofer_transport_proto_msgTypes[187].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetDeadLetterRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8109,8 +16171,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[87].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteRunObjectResponse); i {
+		file_gofer_transport_proto_msgTypes[188].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetDeadLetterResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8121,8 +16183,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[88].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetSecretRequest); i {
+		file_gofer_transport_proto_msgTypes[189].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RedriveDeadLetterRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8133,8 +16195,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[89].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetSecretResponse); i {
+		file_gofer_transport_proto_msgTypes[190].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RedriveDeadLetterResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8145,8 +16207,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[90].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PutSecretRequest); i {
+		file_gofer_transport_proto_msgTypes[191].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteDeadLetterRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8157,8 +16219,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[91].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*PutSecretResponse); i {
+		file_gofer_transport_proto_msgTypes[192].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteDeadLetterResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8169,8 +16231,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[92].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteSecretRequest); i {
+		file_gofer_transport_proto_msgTypes[193].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListWebhooksRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8181,8 +16243,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[93].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteSecretResponse); i {
+		file_gofer_transport_proto_msgTypes[194].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListWebhooksResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8193,8 +16255,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[94].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetSystemInfoRequest); i {
+		file_gofer_transport_proto_msgTypes[195].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetWebhookRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8205,8 +16267,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[95].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetSystemInfoResponse); i {
+		file_gofer_transport_proto_msgTypes[196].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetWebhookResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8217,8 +16279,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[96].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*RepairOrphanRequest); i {
+		file_gofer_transport_proto_msgTypes[197].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateWebhookRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8229,8 +16291,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[97].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*RepairOrphanResponse); i {
+		file_gofer_transport_proto_msgTypes[198].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateWebhookResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8241,8 +16303,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[98].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ToggleEventIngressRequest); i {
+		file_gofer_transport_proto_msgTypes[199].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteWebhookRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8253,8 +16315,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[99].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ToggleEventIngressResponse); i {
+		file_gofer_transport_proto_msgTypes[200].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteWebhookResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8265,8 +16327,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[100].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateTokenRequest); i {
+		file_gofer_transport_proto_msgTypes[201].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListWebhookDeliveriesRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8277,8 +16339,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[101].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateTokenResponse); i {
+		file_gofer_transport_proto_msgTypes[202].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListWebhookDeliveriesResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8289,8 +16351,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[102].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BootstrapTokenRequest); i {
+		file_gofer_transport_proto_msgTypes[203].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRegistryCredentialsRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8301,8 +16363,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[103].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*BootstrapTokenResponse); i {
+		file_gofer_transport_proto_msgTypes[204].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListRegistryCredentialsResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8313,8 +16375,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[104].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTokenRequest); i {
+		file_gofer_transport_proto_msgTypes[205].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutRegistryCredentialRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8325,8 +16387,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[105].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GetTokenResponse); i {
+		file_gofer_transport_proto_msgTypes[206].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PutRegistryCredentialResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8337,8 +16399,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[106].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteTokenRequest); i {
+		file_gofer_transport_proto_msgTypes[207].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteRegistryCredentialRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8349,8 +16411,8 @@ func file_gofer_transport_proto_init() {
 				return nil
 			}
 		}
-		file_gofer_transport_proto_msgTypes[107].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*DeleteTokenResponse); i {
+		file_gofer_transport_proto_msgTypes[208].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*DeleteRegistryCredentialResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -8362,12 +16424,19 @@ func file_gofer_transport_proto_init() {
 			}
 		}
 	}
-	file_gofer_transport_proto_msgTypes[69].OneofWrappers = []interface{}{
+	file_gofer_transport_proto_msgTypes[64].OneofWrappers = []interface{}{}
+	file_gofer_transport_proto_msgTypes[71].OneofWrappers = []interface{}{
+		(*AttachToTaskRunRequest_Start)(nil),
+		(*AttachToTaskRunRequest_Input)(nil),
+		(*AttachToTaskRunRequest_Resize)(nil),
+	}
+	file_gofer_transport_proto_msgTypes[90].OneofWrappers = []interface{}{
 		(*GetEventResponse_CreatedNamespaceEvent)(nil),
 		(*GetEventResponse_DisabledPipelineEvent)(nil),
 		(*GetEventResponse_EnabledPipelineEvent)(nil),
 		(*GetEventResponse_CreatedPipelineEvent)(nil),
 		(*GetEventResponse_AbandonedPipelineEvent)(nil),
+		(*GetEventResponse_RolledBackPipelineEvent)(nil),
 		(*GetEventResponse_StartedRunEvent)(nil),
 		(*GetEventResponse_CompletedRunEvent)(nil),
 		(*GetEventResponse_StartedTaskRunEvent)(nil),
@@ -8376,13 +16445,17 @@ func file_gofer_transport_proto_init() {
 		(*GetEventResponse_FiredTriggerEvent)(nil),
 		(*GetEventResponse_ProcessedTriggerEvent)(nil),
 		(*GetEventResponse_ResolvedTriggerEvent)(nil),
+		(*GetEventResponse_RevokedUserTokensEvent)(nil),
+		(*GetEventResponse_ExpiredPipelineObjectEvent)(nil),
+		(*GetEventResponse_ExtensionEvent)(nil),
 	}
-	file_gofer_transport_proto_msgTypes[71].OneofWrappers = []interface{}{
+	file_gofer_transport_proto_msgTypes[92].OneofWrappers = []interface{}{
 		(*ListEventsResponse_CreatedNamespaceEvent)(nil),
 		(*ListEventsResponse_DisabledPipelineEvent)(nil),
 		(*ListEventsResponse_EnabledPipelineEvent)(nil),
 		(*ListEventsResponse_CreatedPipelineEvent)(nil),
 		(*ListEventsResponse_AbandonedPipelineEvent)(nil),
+		(*ListEventsResponse_RolledBackPipelineEvent)(nil),
 		(*ListEventsResponse_StartedRunEvent)(nil),
 		(*ListEventsResponse_CompletedRunEvent)(nil),
 		(*ListEventsResponse_StartedTaskRunEvent)(nil),
@@ -8391,6 +16464,9 @@ func file_gofer_transport_proto_init() {
 		(*ListEventsResponse_FiredTriggerEvent)(nil),
 		(*ListEventsResponse_ProcessedTriggerEvent)(nil),
 		(*ListEventsResponse_ResolvedTriggerEvent)(nil),
+		(*ListEventsResponse_RevokedUserTokensEvent)(nil),
+		(*ListEventsResponse_ExpiredPipelineObjectEvent)(nil),
+		(*ListEventsResponse_ExtensionEvent)(nil),
 	}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
@@ -8398,7 +16474,7 @@ func file_gofer_transport_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_gofer_transport_proto_rawDesc,
 			NumEnums:      1,
-			NumMessages:   110,
+			NumMessages:   216,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
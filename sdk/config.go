@@ -13,6 +13,8 @@ type Config struct {
 	TLSCert string `split_words:"true" required:"true" json:"-"`
 	TLSKey  string `split_words:"true" required:"true" json:"-"`
 	Host    string `default:"0.0.0.0:8080"`
+	// The address of the main Gofer service; used to publish events.
+	GoferHost string `split_words:"true"`
 }
 
 func initConfig() (*Config, error) {
@@ -0,0 +1,320 @@
+// package events contains typed definitions of the events Gofer emits.
+//
+// Gofer sends events as JSON to event sinks(webhooks, Kafka, NATS). Instead of pulling fields out of loosely typed
+// maps, consumers can use Unmarshal to turn the JSON into the struct that matches the event's kind and then use a
+// type switch to handle the events they care about:
+//
+//	event, err := events.Unmarshal(data)
+//	if err != nil {
+//		return err
+//	}
+//
+//	switch evt := event.(type) {
+//	case *events.CompletedRun:
+//		fmt.Println(evt.NamespaceID, evt.PipelineID, evt.RunID, evt.State)
+//	}
+//
+// Events with a kind this package doesn't know about return an ErrUnknownKind error so consumers built against an
+// older version of the SDK can skip events added after it.
+package events
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+)
+
+// ErrUnknownKind is returned when an event's kind doesn't match any event in this package.
+var ErrUnknownKind = errors.New("events: unknown event kind")
+
+// Kind is the type of the event.
+type Kind string
+
+const (
+	// Namespace events
+	CreatedNamespaceKind Kind = "CREATED_NAMESPACE"
+
+	// Pipeline events
+	DisabledPipelineKind   Kind = "DISABLED_PIPELINE"
+	EnabledPipelineKind    Kind = "ENABLED_PIPELINE"
+	CreatedPipelineKind    Kind = "CREATED_PIPELINE"
+	AbandonedPipelineKind  Kind = "ABANDONED_PIPELINE"
+	RolledBackPipelineKind Kind = "ROLLED_BACK_PIPELINE" // Gofer rolled back a config deployment that failed.
+
+	// Run events
+	StartedRunKind   Kind = "STARTED_RUN"
+	CompletedRunKind Kind = "COMPLETED_RUN"
+
+	// TaskRun events
+	StartedTaskRunKind   Kind = "STARTED_TASK_RUN"   // Task run is getting ready to be scheduled.
+	ScheduledTaskRunKind Kind = "SCHEDULED_TASK_RUN" // Task run has been attempted to be scheduled.
+	CompletedTaskRunKind Kind = "COMPLETED_TASK_RUN" // Task run has completed.
+
+	// Trigger events; these are all from the perspective of the Gofer main process.
+	FiredTriggerKind     Kind = "FIRED_TRIGGER"     // Received a new trigger event.
+	ProcessedTriggerKind Kind = "PROCESSED_TRIGGER" // Currently processing a trigger event that was fired.
+	ResolvedTriggerKind  Kind = "RESOLVED_TRIGGER"  // Successfully processed the trigger.
+
+	// Token events
+	RevokedUserTokensKind Kind = "REVOKED_USER_TOKENS"
+
+	// Object events
+	ExpiredPipelineObjectKind Kind = "EXPIRED_PIPELINE_OBJECT" // A pipeline object outlived its expiry.
+
+	// Extension events; what happened is described by the event's name.
+	ExtensionEventKind Kind = "EXTENSION_EVENT"
+)
+
+// Event is implemented by every event in this package.
+type Event interface {
+	GetID() int64
+	GetKind() Kind
+	GetEmitted() int64
+}
+
+// Metadata is included in every event.
+type Metadata struct {
+	EventID int64 `json:"event_id"` // Unique identifier for event
+	Kind    Kind  `json:"kind"`     // The type of event.
+	Emitted int64 `json:"emitted"`  // Time event was performed in epoch milliseconds.
+}
+
+func (m *Metadata) GetID() int64 {
+	return m.EventID
+}
+
+func (m *Metadata) GetKind() Kind {
+	return m.Kind
+}
+
+func (m *Metadata) GetEmitted() int64 {
+	return m.Emitted
+}
+
+type CreatedNamespace struct {
+	Metadata
+	NamespaceID string `json:"NamespaceID"`
+}
+
+type DisabledPipeline struct {
+	Metadata
+	NamespaceID string `json:"NamespaceID"`
+	PipelineID  string `json:"PipelineID"`
+}
+
+type EnabledPipeline struct {
+	Metadata
+	NamespaceID string `json:"NamespaceID"`
+	PipelineID  string `json:"PipelineID"`
+}
+
+type CreatedPipeline struct {
+	Metadata
+	NamespaceID string `json:"NamespaceID"`
+	PipelineID  string `json:"PipelineID"`
+}
+
+type AbandonedPipeline struct {
+	Metadata
+	NamespaceID string `json:"NamespaceID"`
+	PipelineID  string `json:"PipelineID"`
+}
+
+type RolledBackPipeline struct {
+	Metadata
+	NamespaceID string `json:"NamespaceID"`
+	PipelineID  string `json:"PipelineID"`
+	RunID       int64  `json:"RunID"` // The run that failed the deployment's health check.
+	FromVersion int64  `json:"FromVersion"`
+	ToVersion   int64  `json:"ToVersion"`
+}
+
+type StartedRun struct {
+	Metadata
+	NamespaceID   string `json:"NamespaceID"`
+	PipelineID    string `json:"PipelineID"`
+	RunID         int64  `json:"RunID"`
+	CorrelationID string `json:"CorrelationID"`
+}
+
+type CompletedRun struct {
+	Metadata
+	NamespaceID   string `json:"NamespaceID"`
+	PipelineID    string `json:"PipelineID"`
+	RunID         int64  `json:"RunID"`
+	State         string `json:"State"` // The final state of the run. Ex. SUCCESS, FAILED, CANCELLED
+	CorrelationID string `json:"CorrelationID"`
+}
+
+type StartedTaskRun struct {
+	Metadata
+	NamespaceID   string `json:"NamespaceID"`
+	PipelineID    string `json:"PipelineID"`
+	RunID         int64  `json:"RunID"`
+	TaskRunID     string `json:"TaskRunID"`
+	CorrelationID string `json:"CorrelationID"`
+}
+
+type ScheduledTaskRun struct {
+	Metadata
+	NamespaceID   string `json:"NamespaceID"`
+	PipelineID    string `json:"PipelineID"`
+	RunID         int64  `json:"RunID"`
+	TaskRunID     string `json:"TaskRunID"`
+	CorrelationID string `json:"CorrelationID"`
+}
+
+type CompletedTaskRun struct {
+	Metadata
+	NamespaceID   string `json:"NamespaceID"`
+	PipelineID    string `json:"PipelineID"`
+	RunID         int64  `json:"RunID"`
+	TaskRunID     string `json:"TaskRunID"`
+	State         string `json:"State"` // The final state of the task run. Ex. SUCCESS, FAILED, CANCELLED, SKIPPED
+	CorrelationID string `json:"CorrelationID"`
+}
+
+// TriggerResult is the outcome of a trigger evaluation.
+type TriggerResult struct {
+	Details string `json:"Details"` // Details about the trigger's current result.
+	State   string `json:"State"`   // Ex. SUCCESS, FAILURE, SKIPPED
+}
+
+type FiredTrigger struct {
+	Metadata
+	Label           string            `json:"Label"`
+	Pipeline        string            `json:"Pipeline"`
+	Namespace       string            `json:"Namespace"`
+	Result          TriggerResult     `json:"Result"`
+	TriggerMetadata map[string]string `json:"TriggerMetadata"` // Environment variables to be passed on to the pending run.
+}
+
+type ProcessedTrigger struct {
+	Metadata
+	Label           string            `json:"Label"`
+	Pipeline        string            `json:"Pipeline"`
+	Namespace       string            `json:"Namespace"`
+	Result          TriggerResult     `json:"Result"`
+	TriggerMetadata map[string]string `json:"TriggerMetadata"` // Environment variables to be passed on to the pending run.
+}
+
+type ResolvedTrigger struct {
+	Metadata
+	Label           string            `json:"Label"`
+	Pipeline        string            `json:"Pipeline"`
+	Namespace       string            `json:"Namespace"`
+	Result          TriggerResult     `json:"Result"`
+	TriggerMetadata map[string]string `json:"TriggerMetadata"` // Environment variables to be passed on to the pending run.
+}
+
+type RevokedUserTokens struct {
+	Metadata
+	User  string `json:"User"`
+	Count int64  `json:"Count"`
+}
+
+type ExpiredPipelineObject struct {
+	Metadata
+	NamespaceID string `json:"NamespaceID"`
+	PipelineID  string `json:"PipelineID"`
+	Key         string `json:"Key"`
+}
+
+// ExtensionEvent is an event published by an extension(ex. a pull request being opened).
+type ExtensionEvent struct {
+	Metadata
+	Extension   string            `json:"Extension"`
+	Name        string            `json:"Name"` // Namespaced by the extension(ex. github/pull_request_opened).
+	Summary     string            `json:"Summary"`
+	Details     map[string]string `json:"Details"`
+	NamespaceID string            `json:"NamespaceID"`
+	PipelineID  string            `json:"PipelineID"`
+}
+
+// newEvent returns an empty event for the given kind.
+func newEvent(kind Kind) (Event, error) {
+	switch kind {
+	case CreatedNamespaceKind:
+		return &CreatedNamespace{}, nil
+	case DisabledPipelineKind:
+		return &DisabledPipeline{}, nil
+	case EnabledPipelineKind:
+		return &EnabledPipeline{}, nil
+	case CreatedPipelineKind:
+		return &CreatedPipeline{}, nil
+	case AbandonedPipelineKind:
+		return &AbandonedPipeline{}, nil
+	case RolledBackPipelineKind:
+		return &RolledBackPipeline{}, nil
+	case StartedRunKind:
+		return &StartedRun{}, nil
+	case CompletedRunKind:
+		return &CompletedRun{}, nil
+	case StartedTaskRunKind:
+		return &StartedTaskRun{}, nil
+	case ScheduledTaskRunKind:
+		return &ScheduledTaskRun{}, nil
+	case CompletedTaskRunKind:
+		return &CompletedTaskRun{}, nil
+	case FiredTriggerKind:
+		return &FiredTrigger{}, nil
+	case ProcessedTriggerKind:
+		return &ProcessedTrigger{}, nil
+	case ResolvedTriggerKind:
+		return &ResolvedTrigger{}, nil
+	case RevokedUserTokensKind:
+		return &RevokedUserTokens{}, nil
+	case ExpiredPipelineObjectKind:
+		return &ExpiredPipelineObject{}, nil
+	case ExtensionEventKind:
+		return &ExtensionEvent{}, nil
+	default:
+		return nil, fmt.Errorf("%w: %q", ErrUnknownKind, kind)
+	}
+}
+
+// Unmarshal parses a single JSON encoded event into the struct for its kind.
+func Unmarshal(data []byte) (Event, error) {
+	metadata := Metadata{}
+	err := json.Unmarshal(data, &metadata)
+	if err != nil {
+		return nil, err
+	}
+
+	event, err := newEvent(metadata.Kind)
+	if err != nil {
+		return nil, err
+	}
+
+	err = json.Unmarshal(data, event)
+	if err != nil {
+		return nil, fmt.Errorf("could not parse %s event: %w", metadata.Kind, err)
+	}
+
+	return event, nil
+}
+
+// UnmarshalList parses a JSON array of events, like the ones sent to webhook sinks. Events of an unknown kind are
+// skipped.
+func UnmarshalList(data []byte) ([]Event, error) {
+	rawEvents := []json.RawMessage{}
+	err := json.Unmarshal(data, &rawEvents)
+	if err != nil {
+		return nil, err
+	}
+
+	events := []Event{}
+	for _, rawEvent := range rawEvents {
+		event, err := Unmarshal(rawEvent)
+		if err != nil {
+			if errors.Is(err, ErrUnknownKind) {
+				continue
+			}
+			return nil, err
+		}
+
+		events = append(events, event)
+	}
+
+	return events, nil
+}
@@ -0,0 +1,55 @@
+package events
+
+import (
+	"testing"
+
+	"github.com/clintjedwards/gofer/internal/models"
+	"github.com/google/go-cmp/cmp"
+)
+
+// Every event Gofer can emit should have a typed definition here.
+func TestAllKindsDefined(t *testing.T) {
+	for kind := range models.EventMap {
+		if kind == models.AnyEvent {
+			continue
+		}
+
+		_, err := newEvent(Kind(kind))
+		if err != nil {
+			t.Errorf("event kind %q has no typed definition", kind)
+		}
+	}
+}
+
+func TestUnmarshalList(t *testing.T) {
+	data := []byte(`[
+		{"event_id":1,"kind":"COMPLETED_RUN","emitted":1000,"NamespaceID":"default","PipelineID":"simple",` +
+		`"RunID":5,"State":"SUCCESS"},
+		{"event_id":2,"kind":"SOME_FUTURE_EVENT","emitted":1001},
+		{"event_id":3,"kind":"REVOKED_USER_TOKENS","emitted":1002,"User":"gofer","Count":2}
+	]`)
+
+	got, err := UnmarshalList(data)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	want := []Event{
+		&CompletedRun{
+			Metadata:    Metadata{EventID: 1, Kind: CompletedRunKind, Emitted: 1000},
+			NamespaceID: "default",
+			PipelineID:  "simple",
+			RunID:       5,
+			State:       "SUCCESS",
+		},
+		&RevokedUserTokens{
+			Metadata: Metadata{EventID: 3, Kind: RevokedUserTokensKind, Emitted: 1002},
+			User:     "gofer",
+			Count:    2,
+		},
+	}
+
+	if diff := cmp.Diff(want, got); diff != "" {
+		t.Errorf("unexpected events (-want +got):\n%s", diff)
+	}
+}
@@ -0,0 +1,98 @@
+package sdk
+
+import (
+	"context"
+	"crypto/tls"
+	"fmt"
+	"strings"
+
+	"github.com/clintjedwards/gofer/proto"
+	"google.golang.org/grpc"
+	"google.golang.org/grpc/credentials"
+	"google.golang.org/grpc/metadata"
+)
+
+// Event is an event a trigger publishes onto Gofer's event bus.
+type Event struct {
+	// What happened(ex. pull_request_opened). Gofer namespaces it with the trigger's kind so a "github" trigger
+	// publishing "pull_request_opened" results in an event named "github/pull_request_opened".
+	Kind string
+
+	// A short human readable description of the event(ex. PR 123 opened).
+	Summary string
+
+	Details map[string]string
+
+	// The namespace and pipeline the event relates to, if any.
+	Namespace string
+	Pipeline  string
+}
+
+// PublishEvent publishes an event onto Gofer's event bus. Published events show up in Gofer's event history and are
+// sent to event sinks just like the events Gofer emits itself. The ID of the new event is returned.
+func PublishEvent(ctx context.Context, event Event) (int64, error) {
+	config, err := initConfig()
+	if err != nil {
+		return 0, err
+	}
+
+	if config.GoferHost == "" {
+		return 0, fmt.Errorf("gofer host not set; events can not be published")
+	}
+
+	conn, err := dialGofer(ctx, config.GoferHost)
+	if err != nil {
+		return 0, err
+	}
+	defer conn.Close()
+
+	client := proto.NewGoferClient(conn)
+
+	ctx = metadata.AppendToOutgoingContext(ctx, "authorization", "Bearer "+config.Key)
+	resp, err := client.PublishExtensionEvent(ctx, &proto.PublishExtensionEventRequest{
+		Extension:   config.Kind,
+		Kind:        event.Kind,
+		Summary:     event.Summary,
+		Details:     event.Details,
+		NamespaceId: event.Namespace,
+		PipelineId:  event.Pipeline,
+	})
+	if err != nil {
+		return 0, fmt.Errorf("could not publish event: %w", err)
+	}
+
+	return resp.Id, nil
+}
+
+// ConnectGofer returns a connection to the main Gofer service for triggers that need more from Gofer than publishing
+// events, like following runs they started. Calls made over it need to be authenticated with a Gofer API token; the
+// key Gofer passes to triggers is only accepted for publishing events.
+func ConnectGofer(ctx context.Context) (*grpc.ClientConn, error) {
+	config, err := initConfig()
+	if err != nil {
+		return nil, err
+	}
+
+	if config.GoferHost == "" {
+		return nil, fmt.Errorf("gofer host not set; can not connect to gofer")
+	}
+
+	return dialGofer(ctx, config.GoferHost)
+}
+
+func dialGofer(ctx context.Context, goferHost string) (*grpc.ClientConn, error) {
+	host, _, _ := strings.Cut(goferHost, ":")
+
+	// Gofer running locally is most likely using its development certificates.
+	tlsConfig := &tls.Config{}
+	if host == "localhost" || host == "127.0.0.1" {
+		tlsConfig.InsecureSkipVerify = true
+	}
+
+	conn, err := grpc.DialContext(ctx, goferHost, grpc.WithTransportCredentials(credentials.NewTLS(tlsConfig)))
+	if err != nil {
+		return nil, fmt.Errorf("could not connect to gofer: %w", err)
+	}
+
+	return conn, nil
+}
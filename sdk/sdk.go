@@ -16,6 +16,11 @@ import (
 	"github.com/rs/zerolog/log"
 )
 
+// APIVersion is the version of the API between Gofer and triggers that this SDK implements. Triggers report it to Gofer
+// so that Gofer can tell whether it's able to talk to them. It's increased whenever a change is made that older
+// versions of Gofer or of triggers can't handle.
+const APIVersion = 1
+
 func setupLogging(triggerKind, loglevel string) {
 	zerolog.TimeFieldFormat = zerolog.TimeFormatUnix
 	log.Logger = log.With().Caller().Logger()
@@ -82,10 +82,11 @@ func (t *triggerServer) Info(ctx context.Context, req *sdkProto.InfoRequest) (*s
 	}
 
 	if resp == nil {
-		return &sdkProto.InfoResponse{}, nil
+		return &sdkProto.InfoResponse{ApiVersion: APIVersion}, nil
 	}
 
 	resp.Kind = os.Getenv("GOFER_TRIGGER_KIND")
+	resp.ApiVersion = APIVersion
 
 	return resp, nil
 }